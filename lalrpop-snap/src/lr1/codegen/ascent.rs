@@ -215,7 +215,8 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent,
 
         // extra tokens?
         rust!(self.out, "(Some({}lookahead), _) => {{", self.prefix);
-        rust!(self.out, "Err({}lalrpop_util::ParseError::ExtraToken {{ token: {}lookahead }})",
+        rust!(self.out, "Err({}lalrpop_util::ParseError::ExtraToken {{ token: {}lookahead, \
+               expected: vec![] }})",
               self.prefix, self.prefix);
         rust!(self.out, "}}");
 
@@ -540,6 +540,9 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
                 self.prefix);
             rust!(self.out, "error: {}error,", self.prefix);
             rust!(self.out, "dropped_tokens: {}dropped_tokens,", self.prefix);
+            rust!(self.out, "state: {}states[{}states.len() - 2] as usize,",
+                self.prefix,
+                self.prefix);
             rust!(self.out, "}};");
             rust!(self.out,
                 "{}symbols.push(({}start, {}Symbol::Termerror({}recovery), {}end));",
@@ -642,6 +645,9 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
                 self.prefix);
             rust!(self.out, "error: {}error,", self.prefix);
             rust!(self.out, "dropped_tokens: Vec::new(),");
+            rust!(self.out, "state: {}states[{}states.len() - 2] as usize,",
+                self.prefix,
+                self.prefix);
             rust!(self.out, "}};");
             rust!(self.out,
                 "{}symbols.push(({}last_location.clone(), {}Symbol::Termerror({}recovery), {}last_location.clone()));",
@@ -2295,6 +2295,7 @@ mod ___parse___Grammar {
                             let ___recovery = ___lalrpop_util::ErrorRecovery {
                                 error: ___error,
                                 dropped_tokens: ___dropped_tokens,
+                                state: ___states[___states.len() - 2] as usize,
                             };
                             ___symbols.push((___start, ___Symbol::Termerror(___recovery), ___end));
                             continue '___inner;
@@ -2411,6 +2412,7 @@ mod ___parse___Grammar {
                 let ___recovery = ___lalrpop_util::ErrorRecovery {
                     error: ___error,
                     dropped_tokens: Vec::new(),
+                    state: ___states[___states.len() - 2] as usize,
                 };
                 ___symbols.push((___last_location.clone(), ___Symbol::Termerror(___recovery), ___last_location.clone()));
             }
@@ -9145,6 +9147,7 @@ mod ___parse___MatchMapping {
                             let ___recovery = ___lalrpop_util::ErrorRecovery {
                                 error: ___error,
                                 dropped_tokens: ___dropped_tokens,
+                                state: ___states[___states.len() - 2] as usize,
                             };
                             ___symbols.push((___start, ___Symbol::Termerror(___recovery), ___end));
                             continue '___inner;
@@ -9261,6 +9264,7 @@ mod ___parse___MatchMapping {
                 let ___recovery = ___lalrpop_util::ErrorRecovery {
                     error: ___error,
                     dropped_tokens: Vec::new(),
+                    state: ___states[___states.len() - 2] as usize,
                 };
                 ___symbols.push((___last_location.clone(), ___Symbol::Termerror(___recovery), ___last_location.clone()));
             }
@@ -16565,6 +16569,7 @@ mod ___parse___Pattern {
                             let ___recovery = ___lalrpop_util::ErrorRecovery {
                                 error: ___error,
                                 dropped_tokens: ___dropped_tokens,
+                                state: ___states[___states.len() - 2] as usize,
                             };
                             ___symbols.push((___start, ___Symbol::Termerror(___recovery), ___end));
                             continue '___inner;
@@ -16681,6 +16686,7 @@ mod ___parse___Pattern {
                 let ___recovery = ___lalrpop_util::ErrorRecovery {
                     error: ___error,
                     dropped_tokens: Vec::new(),
+                    state: ___states[___states.len() - 2] as usize,
                 };
                 ___symbols.push((___last_location.clone(), ___Symbol::Termerror(___recovery), ___last_location.clone()));
             }
@@ -23805,6 +23811,7 @@ mod ___parse___TypeRef {
                             let ___recovery = ___lalrpop_util::ErrorRecovery {
                                 error: ___error,
                                 dropped_tokens: ___dropped_tokens,
+                                state: ___states[___states.len() - 2] as usize,
                             };
                             ___symbols.push((___start, ___Symbol::Termerror(___recovery), ___end));
                             continue '___inner;
@@ -23921,6 +23928,7 @@ mod ___parse___TypeRef {
                 let ___recovery = ___lalrpop_util::ErrorRecovery {
                     error: ___error,
                     dropped_tokens: Vec::new(),
+                    state: ___states[___states.len() - 2] as usize,
                 };
                 ___symbols.push((___last_location.clone(), ___Symbol::Termerror(___recovery), ___last_location.clone()));
             }
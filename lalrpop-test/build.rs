@@ -1,10 +1,171 @@
 extern crate lalrpop;
 
+use std::env;
+
 fn main() {
     lalrpop::Configuration::new()
         .emit_comments(true)
         .force_build(true)
         .unit_test()
-        .process_current_dir()
+        .process_dir("src")
+        .unwrap();
+
+    // a lexer that finds the ends of tokens by scanning bytes
+    lalrpop::Configuration::new()
+        .fast_scanning(true)
+        .force_build(true)
+        .process_file("src/scan_tok.lalrpop")
+        .unwrap();
+
+    // a parser that checks its stacks with `debug_assert!`s
+    lalrpop::Configuration::new()
+        .emit_debug_assertions(true)
+        .force_build(true)
+        .process_file("src/debug_assertions.lalrpop")
+        .unwrap();
+
+    // a parser whose reductions show up in CPU profiles
+    lalrpop::Configuration::new()
+        .profile_reductions(true)
+        .force_build(true)
+        .process_file("src/profiled.lalrpop")
+        .unwrap();
+
+    // a grammar with alternatives that depend on cfg settings
+    lalrpop::Configuration::new()
+        .grammar_cfg("feature", "ext-syntax")
+        .force_build(true)
+        .process_file("src/grammar_cfg.lalrpop")
+        .unwrap();
+
+    // parsers that must not panic, whatever the input
+    lalrpop::Configuration::new()
+        .panic_free(true)
+        .force_build(true)
+        .process_file("src/panic_free.lalrpop")
+        .unwrap();
+
+    // a parser that keeps its stacks in a buffer
+    lalrpop::Configuration::new()
+        .fixed_stack(true)
+        .force_build(true)
+        .process_file("src/fixed_stack.lalrpop")
+        .unwrap();
+
+    // a parser with a fixed-size stack that does without `std`
+    lalrpop::Configuration::new()
+        .fixed_stack(true)
+        .force_build(true)
+        .process_file("src/no_std_stack.lalrpop")
+        .unwrap();
+
+    // a parser that memoizes the values of repeated sub-parses
+    lalrpop::Configuration::new()
+        .memoize_actions(true)
+        .force_build(true)
+        .process_file("src/memoized.lalrpop")
+        .unwrap();
+
+    // a parser that can parse from an array of tokens lexed up front
+    lalrpop::Configuration::new()
+        .token_arrays(true)
+        .force_build(true)
+        .process_file("src/token_array.lalrpop")
+        .unwrap();
+
+    // a parser with a limit on how deeply the input can nest
+    lalrpop::Configuration::new()
+        .max_depth(8)
+        .force_build(true)
+        .process_file("src/max_depth.lalrpop")
+        .unwrap();
+
+    // a parser with a limit on how many reductions a parse can take
+    lalrpop::Configuration::new()
+        .max_reductions(10)
+        .force_build(true)
+        .process_file("src/max_reductions.lalrpop")
+        .unwrap();
+
+    // a module that is only compiled for tests, with a parser that is
+    // never compiled (`any()` is always false)
+    lalrpop::Configuration::new()
+        .cfg("test")
+        .parser_cfg("Product", "any()")
+        .force_build(true)
+        .process_file("src/parser_cfg.lalrpop")
+        .unwrap();
+
+    // a lexer whose tokens can be serialized
+    lalrpop::Configuration::new()
+        .derive_serde(true)
+        .force_build(true)
+        .process_file("src/serde_tok.lalrpop")
+        .unwrap();
+
+    // a parser that skips the states that only reduce unit productions,
+    // and the same grammar built without that, to compare them
+    lalrpop::Configuration::new()
+        .eliminate_unit_rules(true)
+        .force_build(true)
+        .process_file("src/unit_rules.lalrpop")
+        .unwrap();
+    lalrpop::Configuration::new()
+        .force_build(true)
+        .process_file("src/unit_rules_kept.lalrpop")
+        .unwrap();
+
+    // a parser with entry points for C, and a header declaring them
+    lalrpop::Configuration::new()
+        .emit_c_abi(true)
+        .force_build(true)
+        .process_file("src/c_abi.lalrpop")
+        .unwrap();
+
+    // a parser module whose generated names have a prefix of our own
+    lalrpop::Configuration::new()
+        .prefix("lp_")
+        .force_build(true)
+        .process_file("src/custom_prefix.lalrpop")
+        .unwrap();
+
+    // a parser whose tables go in a link section of their own
+    let section = if env::var("CARGO_CFG_TARGET_OS").map(|os| os == "macos").unwrap_or(false) {
+        "__DATA,__lalrpop"
+    } else {
+        ".lalrpop_tables"
+    };
+    lalrpop::Configuration::new()
+        .table_link_section(section)
+        .force_build(true)
+        .process_file("src/link_section.lalrpop")
+        .unwrap();
+
+    // a parser that records a profile, and the same grammar laid out
+    // according to a profile it recorded
+    lalrpop::Configuration::new()
+        .emit_profile(true)
+        .force_build(true)
+        .process_file("src/profile_counts.lalrpop")
+        .unwrap();
+    lalrpop::Configuration::new()
+        .use_profile(true)
+        .force_build(true)
+        .process_file("src/profile_layout.lalrpop")
+        .unwrap();
+
+    // the grammars of a directory, and a module declaring their parsers
+    lalrpop::Configuration::new()
+        .emit_parsers_module("src/parsers_module/mod.rs")
+        .force_build(true)
+        .process_dir("src/parsers_module")
+        .unwrap();
+
+    // a grammar with conflicts, which are resolved by default; it is
+    // kept out of `src` so that the build above doesn't reject it
+    lalrpop::Configuration::new()
+        .conflicts_as_warnings(true)
+        .force_build(true)
+        .process_file("conflicts/conflicts_as_warnings.lalrpop")
         .unwrap();
 }
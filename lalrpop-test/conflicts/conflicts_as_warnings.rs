@@ -0,0 +1,1088 @@
+// auto-generated: "lalrpop 0.15.0"
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+pub use self::__parse_table::parse_Expr;
+pub use self::__parse_table::parse_Expr_cancelable;
+pub use self::__parse_table::ExprParser;
+pub use self::__parse_table::parse_Item;
+pub use self::__parse_table::parse_Item_cancelable;
+pub use self::__parse_table::ItemParser;
+
+mod __parse_table {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__intern_token::Token;
+    #[allow(dead_code)]
+    pub enum __Symbol<'input>
+     {
+        Term_22_2b_22(&'input str),
+        Term_22n_22(&'input str),
+        Term_22x_22(&'input str),
+        Termr_23_22_5b0_2d9_5d_2b_22_23(&'input str),
+        NtA(&'static str),
+        NtB(&'static str),
+        NtExpr(String),
+        NtItem(&'static str),
+        NtNum(String),
+        Nt____Expr(String),
+        Nt____Item(&'static str),
+    }
+    impl<'input> ::std::fmt::Debug for __Symbol<'input>
+    {
+        fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            match *self {
+                __Symbol::Term_22_2b_22(..) => __f.write_str("\"+\""),
+                __Symbol::Term_22n_22(..) => __f.write_str("\"n\""),
+                __Symbol::Term_22x_22(..) => __f.write_str("\"x\""),
+                __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(..) => __f.write_str("r#\"[0-9]+\"#"),
+                __Symbol::NtA(..) => __f.write_str("A"),
+                __Symbol::NtB(..) => __f.write_str("B"),
+                __Symbol::NtExpr(..) => __f.write_str("Expr"),
+                __Symbol::NtItem(..) => __f.write_str("Item"),
+                __Symbol::NtNum(..) => __f.write_str("Num"),
+                __Symbol::Nt____Expr(..) => __f.write_str("__Expr"),
+                __Symbol::Nt____Item(..) => __f.write_str("__Item"),
+            }
+        }
+    }
+    const __ACTION: &'static [i8] = &[
+        // State 0
+        0, 0, 0, 4,
+        // State 1
+        5, 0, 0, 0,
+        // State 2
+        -4, 0, 0, 0,
+        // State 3
+        -7, 0, 0, 0,
+        // State 4
+        0, 0, 0, 4,
+        // State 5
+        5, -3, -3, -3,
+        // State 6
+        5, -3, -3, -3,
+        // State 7
+        0, 12, 0, 0,
+        // State 8
+        0, 0, 13, 0,
+        // State 9
+        0, 0, 14, 0,
+        // State 10
+        0, 0, 0, 0,
+        // State 11
+        -2, -2, -2, -2,
+        // State 12
+        -5, -5, -5, -5,
+        // State 13
+        -6, -6, -6, -6,
+    ];
+    const __EOF_ACTION: &'static [i8] = &[
+        // State 0
+        0,
+        // State 1
+        -8,
+        // State 2
+        -4,
+        // State 3
+        -7,
+        // State 4
+        0,
+        // State 5
+        -3,
+        // State 6
+        -3,
+        // State 7
+        0,
+        // State 8
+        0,
+        // State 9
+        0,
+        // State 10
+        -9,
+        // State 11
+        -2,
+        // State 12
+        -5,
+        // State 13
+        -6,
+    ];
+    const __GOTO: &'static [u8] = &[
+        // State 0
+        0, 0, 2, 0, 3, 0, 0,
+        // State 1
+        0, 0, 0, 0, 0, 0, 0,
+        // State 2
+        0, 0, 0, 0, 0, 0, 0,
+        // State 3
+        0, 0, 0, 0, 0, 0, 0,
+        // State 4
+        0, 0, 6, 0, 3, 0, 0,
+        // State 5
+        0, 0, 0, 0, 0, 0, 0,
+        // State 6
+        0, 0, 0, 0, 0, 0, 0,
+        // State 7
+        9, 10, 0, 11, 0, 0, 0,
+        // State 8
+        0, 0, 0, 0, 0, 0, 0,
+        // State 9
+        0, 0, 0, 0, 0, 0, 0,
+        // State 10
+        0, 0, 0, 0, 0, 0, 0,
+        // State 11
+        0, 0, 0, 0, 0, 0, 0,
+        // State 12
+        0, 0, 0, 0, 0, 0, 0,
+        // State 13
+        0, 0, 0, 0, 0, 0, 0,
+    ];
+    const __DEFAULT_ACTION: &'static [i8] = &[
+        // State 0
+        0,
+        // State 1
+        0,
+        // State 2
+        -4,
+        // State 3
+        -7,
+        // State 4
+        0,
+        // State 5
+        0,
+        // State 6
+        0,
+        // State 7
+        0,
+        // State 8
+        0,
+        // State 9
+        0,
+        // State 10
+        0,
+        // State 11
+        -2,
+        // State 12
+        -5,
+        // State 13
+        -6,
+    ];
+    fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+        const __TERMINAL: &'static [&'static str] = &[
+            r###""+""###,
+            r###""n""###,
+            r###""x""###,
+            r###"r#"[0-9]+"#"###,
+        ];
+        __ACTION[(__state * 4)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+            if state == 0 {
+                None
+            } else {
+                Some(terminal.to_string())
+            }
+        }).collect()
+    }
+    #[allow(dead_code)]
+    pub fn parse_Expr<
+        'input,
+    >(
+        input: &'input str,
+    ) -> Result<String, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let mut __tokens = super::__intern_token::__Matcher::new(input);
+        let mut __states = vec![];
+        let mut __symbols = vec![];
+        __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() })
+    }
+    #[allow(dead_code)]
+    pub fn parse_Expr_cancelable<
+        'input,
+    >(
+        input: &'input str,
+        __cancel: &::std::sync::atomic::AtomicBool,
+    ) -> Result<String, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let mut __tokens = super::__intern_token::__Matcher::new(input);
+        let mut __states = vec![];
+        let mut __symbols = vec![];
+        __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() })
+    }
+
+    #[allow(dead_code)]
+    pub struct ExprParser {
+        __states: ::std::vec::Vec<i32>,
+        __symbols: __lalrpop_util::stack::Recycled,
+        __cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+    }
+
+    #[allow(dead_code)]
+    impl ExprParser {
+        pub fn new() -> ExprParser {
+            ExprParser { __states: vec![], __symbols: __lalrpop_util::stack::Recycled::new(), __cancel: None }
+        }
+
+        pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+            self.__cancel = Some(flag);
+        }
+
+        pub fn parse_in_place<'input>(
+            &mut self,
+            input: &'input str,
+        ) -> Result<String, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+        {
+            let mut __tokens = super::__intern_token::__Matcher::new(input);
+            let mut __symbols = self.__symbols.take();
+            let __result = __parse::<>(input, 0, &mut __tokens, &mut self.__states, &mut __symbols, self.__cancel.as_ref().map(|c| &**c)).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() });
+            self.__symbols.put(__symbols);
+            __result
+        }
+    }
+    #[allow(dead_code)]
+    pub fn parse_Item<
+        'input,
+    >(
+        input: &'input str,
+    ) -> Result<&'static str, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let mut __tokens = super::__intern_token::__Matcher::new(input);
+        let mut __states = vec![];
+        let mut __symbols = vec![];
+        __parse::<>(input, 7, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Item(__v) => Ok(__v), _ => unreachable!() })
+    }
+    #[allow(dead_code)]
+    pub fn parse_Item_cancelable<
+        'input,
+    >(
+        input: &'input str,
+        __cancel: &::std::sync::atomic::AtomicBool,
+    ) -> Result<&'static str, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let mut __tokens = super::__intern_token::__Matcher::new(input);
+        let mut __states = vec![];
+        let mut __symbols = vec![];
+        __parse::<>(input, 7, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Item(__v) => Ok(__v), _ => unreachable!() })
+    }
+
+    #[allow(dead_code)]
+    pub struct ItemParser {
+        __states: ::std::vec::Vec<i32>,
+        __symbols: __lalrpop_util::stack::Recycled,
+        __cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+    }
+
+    #[allow(dead_code)]
+    impl ItemParser {
+        pub fn new() -> ItemParser {
+            ItemParser { __states: vec![], __symbols: __lalrpop_util::stack::Recycled::new(), __cancel: None }
+        }
+
+        pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+            self.__cancel = Some(flag);
+        }
+
+        pub fn parse_in_place<'input>(
+            &mut self,
+            input: &'input str,
+        ) -> Result<&'static str, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+        {
+            let mut __tokens = super::__intern_token::__Matcher::new(input);
+            let mut __symbols = self.__symbols.take();
+            let __result = __parse::<>(input, 7, &mut __tokens, &mut self.__states, &mut __symbols, self.__cancel.as_ref().map(|c| &**c)).and_then(|__v| match __v { __Symbol::Nt____Item(__v) => Ok(__v), _ => unreachable!() });
+            self.__symbols.put(__symbols);
+            __result
+        }
+    }
+
+    fn __parse<
+        'input,
+    >(
+        input: &'input str,
+        __start_state: i32,
+        mut __tokens: &mut Iterator<Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+        mut __states: &mut ::std::vec::Vec<i32>,
+        mut __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        __cancel: Option<&::std::sync::atomic::AtomicBool>,
+    ) -> Result<__Symbol<'input>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        __states.clear();
+        __states.push(__start_state);
+        __symbols.clear();
+        let mut __integer;
+        let mut __lookahead;
+        let __last_location: &mut usize = &mut Default::default();
+        let mut __default_reduced = [0_usize; 16];
+        let mut __default_reduced_len = 0;
+        '__shift: loop {
+            loop {
+                let __state = *__states.last().unwrap() as usize;
+                let __action = __DEFAULT_ACTION[__state] as i32;
+                if __action == 0 {
+                    break;
+                }
+                if __default_reduced_len < 16 {
+                    __default_reduced[__default_reduced_len] = __state;
+                    __default_reduced_len += 1;
+                }
+                if let Some(__flag) = __cancel {
+                    if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                        return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                    }
+                }
+                if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                    return r;
+                }
+            }
+            __lookahead = match __tokens.next() {
+                Some(Ok(v)) => v,
+                None => break '__shift,
+                Some(Err(e)) => return Err(e),
+            };
+            *__last_location = __lookahead.2.clone();
+            __integer = match __lookahead.1 {
+                Token(1, _) if true => 0,
+                Token(2, _) if true => 1,
+                Token(3, _) if true => 2,
+                Token(0, _) if true => 3,
+                _ => {
+                    let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| true).unwrap_or(*__states.last().unwrap() as usize);
+                    let __expected = __expected_tokens(__state);
+                    let __error = match Some(__lookahead) {
+                        Some(__token) => {
+                            __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                        }
+                        None => {
+                            __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                        }
+                    };
+                    return Err(__error);
+                }
+            };
+            '__inner: loop {
+                let __state = *__states.last().unwrap() as usize;
+                let __action = __ACTION[__state * 4 + __integer] as i32;
+                if __action > 0 {
+                    if let Some(__flag) = __cancel {
+                        if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                            return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                        }
+                    }
+                    let __symbol = match __integer {
+                        0 => match __lookahead.1 {
+                            Token(1, __tok0) => __Symbol::Term_22_2b_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        1 => match __lookahead.1 {
+                            Token(2, __tok0) => __Symbol::Term_22n_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        2 => match __lookahead.1 {
+                            Token(3, __tok0) => __Symbol::Term_22x_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        3 => match __lookahead.1 {
+                            Token(0, __tok0) => __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        _ => unreachable!(),
+                    };
+                    __states.push(__action - 1);
+                    __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                    __default_reduced_len = 0;
+                    continue '__shift;
+                } else if __action < 0 {
+                    if let Some(__flag) = __cancel {
+                        if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                            return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                        }
+                    }
+                    if let Some(r) = __reduce(input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                        if r.is_err() {
+                            return r;
+                        }
+                        return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] });
+                    }
+                } else {
+                    let mut __err_lookahead = Some(__lookahead);
+                    let mut __err_integer: Option<usize> = Some(__integer);
+                    let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 4 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                    let __expected = __expected_tokens(__state);
+                    let __error = match __err_lookahead {
+                        Some(__token) => {
+                            if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                            } else {
+                                __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                            }
+                        }
+                        None => {
+                            __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                        }
+                    };
+                    return Err(__error)
+                }
+            }
+        }
+        loop {
+            let __state = *__states.last().unwrap() as usize;
+            let __action = __EOF_ACTION[__state] as i32;
+            if __action < 0 {
+                if let Some(__flag) = __cancel {
+                    if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                        return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                    }
+                }
+                if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                    return r;
+                }
+            } else {
+                let mut __err_lookahead = None;
+                let mut __err_integer: Option<usize> = None;
+                let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 4 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                let __expected = __expected_tokens(__state);
+                let __error = match __err_lookahead {
+                    Some(__token) => {
+                        if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                            __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                        } else {
+                            __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                        }
+                    }
+                    None => {
+                        __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                    }
+                };
+                return Err(__error)
+            }
+        }
+    }
+    fn __accepts<
+        'input,
+    >(
+        input: &'input str,
+        __error_state: i32,
+        __states: & [i32],
+        __opt_integer: Option<usize>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> bool
+    {
+        let mut __states = __states.to_vec();
+        __states.push(__error_state);
+        loop {
+            let mut __states_len = __states.len();
+            let __top = __states[__states_len - 1];
+            let __action = match __opt_integer {
+                None => __EOF_ACTION[__top as usize] as i32,
+                Some(__integer) => __ACTION[(__top * 4) as usize + __integer] as i32,
+            };
+            if __action == 0 { return false; }
+            if __action > 0 { return true; }
+            let (__to_pop, __nt) = match -__action {
+                1 => {
+                    (1, 0)
+                }
+                2 => {
+                    (1, 1)
+                }
+                3 => {
+                    (3, 2)
+                }
+                4 => {
+                    (1, 2)
+                }
+                5 => {
+                    (2, 3)
+                }
+                6 => {
+                    (2, 3)
+                }
+                7 => {
+                    (1, 4)
+                }
+                8 => return true,
+                9 => return true,
+                _ => panic!("invalid action code {}", __action)
+            };
+            __states_len -= __to_pop;
+            __states.truncate(__states_len);
+            let __top = __states[__states_len - 1];
+            let __next_state = __GOTO[(__top * 7 + __nt) as usize] as i32 - 1;
+            __states.push(__next_state);
+        }
+    }
+    pub fn __reduce<
+        'input,
+    >(
+        input: &'input str,
+        __action: i32,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Option<Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        let __nonterminal = match -__action {
+            1 => match __reduce_A_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            2 => match __reduce_B_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            3 => match __reduce_Expr_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            4 => match __reduce_Expr_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            5 => match __reduce_Item_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            6 => match __reduce_Item_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            7 => match __reduce_Num_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            8 => match __reduce_____Expr_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            9 => match __reduce_____Item_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            _ => panic!("invalid action code {}", __action)
+        };
+        let __state = *__states.last().unwrap() as usize;
+        let __next_state = __GOTO[__state * 7 + __nonterminal] as i32 - 1;
+        __states.push(__next_state);
+        None
+    }
+
+    fn __reduce_A_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // A = "n" => ActionFn(7);
+        let __sym0 = __pop_Term_22n_22(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action7::<>(input, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtA(__nt), __end));
+        Ok(0)
+    }
+
+    fn __reduce_B_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // B = "n" => ActionFn(6);
+        let __sym0 = __pop_Term_22n_22(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action6::<>(input, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtB(__nt), __end));
+        Ok(1)
+    }
+
+    fn __reduce_Expr_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Expr = Expr, "+", Expr => ActionFn(2);
+        let __sym2 = __pop_NtExpr(__symbols);
+        let __sym1 = __pop_Term_22_2b_22(__symbols);
+        let __sym0 = __pop_NtExpr(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action2::<>(input, __sym0, __sym1, __sym2);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 3);
+        __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+        Ok(2)
+    }
+
+    fn __reduce_Expr_1<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Expr = Num => ActionFn(3);
+        let __sym0 = __pop_NtNum(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action3::<>(input, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+        Ok(2)
+    }
+
+    fn __reduce_Item_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Item = A, "x" => ActionFn(4);
+        let __sym1 = __pop_Term_22x_22(__symbols);
+        let __sym0 = __pop_NtA(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action4::<>(input, __sym0, __sym1);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 2);
+        __symbols.push((__start, __Symbol::NtItem(__nt), __end));
+        Ok(3)
+    }
+
+    fn __reduce_Item_1<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Item = B, "x" => ActionFn(5);
+        let __sym1 = __pop_Term_22x_22(__symbols);
+        let __sym0 = __pop_NtB(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action5::<>(input, __sym0, __sym1);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 2);
+        __symbols.push((__start, __Symbol::NtItem(__nt), __end));
+        Ok(3)
+    }
+
+    fn __reduce_Num_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Num = r#"[0-9]+"# => ActionFn(8);
+        let __sym0 = __pop_Termr_23_22_5b0_2d9_5d_2b_22_23(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action8::<>(input, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtNum(__nt), __end));
+        Ok(4)
+    }
+
+    fn __reduce_____Expr_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // __Expr = Expr => ActionFn(0);
+        let __sym0 = __pop_NtExpr(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action0::<>(input, __sym0);
+        Err(Ok(__Symbol::Nt____Expr(__nt)))
+    }
+
+    fn __reduce_____Item_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // __Item = Item => ActionFn(1);
+        let __sym0 = __pop_NtItem(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action1::<>(input, __sym0);
+        Err(Ok(__Symbol::Nt____Item(__nt)))
+    }
+    fn __pop_Term_22_2b_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2b_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22n_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22n_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22x_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22x_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_5b0_2d9_5d_2b_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtA<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'static str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtA(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtB<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'static str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtB(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtExpr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, String, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtExpr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtItem<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'static str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtItem(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtNum<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, String, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtNum(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____Expr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, String, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Expr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____Item<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'static str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Item(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+}
+mod __intern_token {
+    #![allow(unused_imports)]
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    extern crate regex as __regex;
+    use std::fmt as __fmt;
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Token<'input>(pub usize, pub &'input str);
+    impl<'a> __fmt::Display for Token<'a> {
+        fn fmt(&self, formatter: &mut __fmt::Formatter) -> Result<(), __fmt::Error> {
+            __fmt::Display::fmt(self.1, formatter)
+        }
+    }
+
+    pub struct __Matcher<'input> {
+        text: &'input str,
+        consumed: usize,
+        regex_set: __regex::RegexSet,
+        regex_vec: Vec<__regex::Regex>,
+    }
+
+    impl<'input> __Matcher<'input> {
+        pub fn new(s: &'input str) -> __Matcher<'input> {
+            let __strs: &[&str] = &[
+                "^(?u:[0-9])+",
+                "^(?u:\\+)",
+                "^(?u:n)",
+                "^(?u:x)",
+            ];
+            let __regex_set = __regex::RegexSet::new(__strs).unwrap();
+            let __regex_vec = vec![
+                __regex::Regex::new("^(?u:[0-9])+").unwrap(),
+                __regex::Regex::new("^(?u:\\+)").unwrap(),
+                __regex::Regex::new("^(?u:n)").unwrap(),
+                __regex::Regex::new("^(?u:x)").unwrap(),
+            ];
+            __Matcher {
+                text: s,
+                consumed: 0,
+                regex_set: __regex_set,
+                regex_vec: __regex_vec,
+            }
+        }
+    }
+
+    impl<'input> Iterator for __Matcher<'input> {
+        type Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize,Token<'input>,&'static str>>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let __text = self.text.trim_left();
+            let __whitespace = self.text.len() - __text.len();
+            let __start_offset = self.consumed + __whitespace;
+            if __text.is_empty() {
+                self.text = __text;
+                self.consumed = __start_offset;
+                None
+            } else {
+                let __matches = self.regex_set.matches(__text);
+                if !__matches.matched_any() {
+                    Some(Err(__lalrpop_util::ParseError::InvalidToken {
+                        location: __start_offset,
+                    }))
+                } else {
+                    let mut __longest_match = 0;
+                    let mut __index = 0;
+                    for __i in 0 .. 4 {
+                        if __matches.matched(__i) {
+                            let __match = self.regex_vec[__i].find(__text).unwrap();
+                            let __len = __match.end();
+                            if __len >= __longest_match {
+                                __longest_match = __len;
+                                __index = __i;
+                            }
+                        }
+                    }
+                    let __result = &__text[..__longest_match];
+                    let __remaining = &__text[__longest_match..];
+                    let __end_offset = __start_offset + __longest_match;
+                    self.text = __remaining;
+                    self.consumed = __end_offset;
+                    Some(Ok((__start_offset, Token(__index, __result), __end_offset)))
+                }
+            }
+        }
+    }
+}
+pub use self::__intern_token::Token;
+
+#[allow(unused_variables)]
+fn __action0<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, String, usize),
+) -> String
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'static str, usize),
+) -> &'static str
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, String, usize),
+) -> String
+{
+    //@ conflicts/conflicts_as_warnings.lalrpop:6:30 (1 lines)
+    format!("({} + {})", l, r)
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, String, usize),
+) -> String
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'static str, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> &'static str
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'static str, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> &'static str
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> &'static str
+{
+    //@ conflicts/conflicts_as_warnings.lalrpop:16:26 (1 lines)
+    "B"
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> &'static str
+{
+    //@ conflicts/conflicts_as_warnings.lalrpop:18:26 (1 lines)
+    "A"
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> String
+{
+    //@ conflicts/conflicts_as_warnings.lalrpop:20:28 (1 lines)
+    __0.to_string()
+}
+
+pub trait __ToTriple<'input, > {
+    type Error;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),Self::Error>;
+}
+
+impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize) {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        Ok(value)
+    }
+}
+impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        value
+    }
+}
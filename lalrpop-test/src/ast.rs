@@ -0,0 +1,3996 @@
+// auto-generated: "lalrpop 0.15.0"
+use std::str::FromStr;
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+
+mod __parse__Expr {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    use std::str::FromStr;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__intern_token::Token;
+    use super::Expr;
+    use super::Term;
+    use super::Name;
+    #[allow(dead_code)]
+    pub fn parse_Expr<
+        'input,
+    >(
+        input: &'input str,
+    ) -> Result<Expr<'input>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let __ascent = __ascent::parse_Expr(
+            input,
+        );
+        let __parse_table = __parse_table::parse_Expr(
+            input,
+        );
+        assert_eq!(__ascent, __parse_table);
+        return __ascent;
+    }
+    mod __ascent {
+
+        mod __parse__Expr {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use std::str::FromStr;
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__intern_token::Token;
+            use super::super::super::Expr;
+            use super::super::super::Term;
+            use super::super::super::Name;
+            #[allow(dead_code)]
+            pub fn parse_Expr<
+                'input,
+            >(
+                input: &'input str,
+            ) -> Result<Expr<'input>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let __last_location = ::std::cell::RefCell::new(Default::default());
+                let mut __tokens = __tokens.inspect(|t| if let Ok(ref t) = *t { *__last_location.borrow_mut() = t.2.clone(); });
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                let mut __extra = None;
+                let __result = __state0(input, &mut __tokens, __lookahead, &mut __extra, ::std::marker::PhantomData::<()>);
+                if let Some((__token, __expected)) = __extra {
+                    return Err(match __result {
+                        Ok(_) => __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected },
+                        Err(_) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                    });
+                }
+                match __result {
+                    Ok((Some(__lookahead), _)) => {
+                        Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] })
+                    }
+                    Ok((None, __Nonterminal::____Expr((_, __nt, _)))) => {
+                        Ok(__nt)
+                    }
+                    Ok(_) => unreachable!(),
+                    Err(__lalrpop_util::ParseError::UnrecognizedEof { expected: __expected, .. }) => {
+                        Err(__lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.borrow().clone(), expected: __expected })
+                    }
+                    Err(__error) => Err(__error),
+                }
+            }
+
+            #[allow(dead_code)]
+            pub enum __Nonterminal<'input>
+             {
+                _28_3cExpr_3e_20_22_2c_22_29((usize, Expr<'input>, usize)),
+                _28_3cExpr_3e_20_22_2c_22_29_2a((usize, ::std::vec::Vec<Expr<'input>>, usize)),
+                _28_3cExpr_3e_20_22_2c_22_29_2b((usize, ::std::vec::Vec<Expr<'input>>, usize)),
+                _40L((usize, usize, usize)),
+                _40R((usize, usize, usize)),
+                Expr((usize, Expr<'input>, usize)),
+                Name((usize, Name<'input>, usize)),
+                Num((usize, i32, usize)),
+                Term((usize, Term<'input>, usize)),
+                ____Expr((usize, Expr<'input>, usize)),
+            }
+            impl<'input> ::std::fmt::Debug for __Nonterminal<'input>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Nonterminal::_28_3cExpr_3e_20_22_2c_22_29(..) => __f.write_str("(<Expr> \",\")"),
+                        __Nonterminal::_28_3cExpr_3e_20_22_2c_22_29_2a(..) => __f.write_str("(<Expr> \",\")*"),
+                        __Nonterminal::_28_3cExpr_3e_20_22_2c_22_29_2b(..) => __f.write_str("(<Expr> \",\")+"),
+                        __Nonterminal::_40L(..) => __f.write_str("@L"),
+                        __Nonterminal::_40R(..) => __f.write_str("@R"),
+                        __Nonterminal::Expr(..) => __f.write_str("Expr"),
+                        __Nonterminal::Name(..) => __f.write_str("Name"),
+                        __Nonterminal::Num(..) => __f.write_str("Num"),
+                        __Nonterminal::Term(..) => __f.write_str("Term"),
+                        __Nonterminal::____Expr(..) => __f.write_str("__Expr"),
+                    }
+                }
+            }
+
+            // State 0
+            //     AllInputs = []
+            //     OptionalInputs = []
+            //     FixedInputs = []
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Expr = (*) Expr "+" Term ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = (*) Term ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Name = (*) r#"[a-z]+"# ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Num = (*) r#"[0-9]+"# ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Name "(" (<Expr> ",")+ ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Name "(" ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Num ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     __Expr = (*) Expr ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "(" -> S5
+            //   r#"[0-9]+"# -> S6
+            //   r#"[a-z]+"# -> S7
+            //
+            //     Expr -> S1
+            //     Name -> S2
+            //     Num -> S3
+            //     Term -> S4
+            fn __state0<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(input, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(input, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                            r###"r#"[a-z]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Expr(__sym0) => {
+                            __result = try!(__state1(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Name(__sym0) => {
+                            __result = try!(__state2(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Num(__sym0) => {
+                            __result = try!(__state3(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Term(__sym0) => {
+                            __result = try!(__state4(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 1
+            //     AllInputs = [Expr]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Expr = Expr (*) "+" Term ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     __Expr = Expr (*) ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "+" -> S8
+            //   [EOF] -> __Expr = Expr => ActionFn(0);
+            //
+            fn __state1<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Expr<'input>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(4, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state8(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(input, __sym0);
+                        let __nt = __Nonterminal::____Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""+""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(input, __sym0);
+                        let __nt = __Nonterminal::____Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 2
+            //     AllInputs = [Name]
+            //     OptionalInputs = []
+            //     FixedInputs = [Name]
+            //     WillPushLen = 2
+            //     WillPush = ["(", ")"]
+            //     WillProduce = Some(Term)
+            //
+            //     Term = Name (*) "(" (<Expr> ",")+ ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = Name (*) "(" ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "(" -> S9
+            //
+            fn __state2<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Name<'input>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state9(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 3
+            //     AllInputs = [Num]
+            //     OptionalInputs = []
+            //     FixedInputs = [Num]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Term)
+            //
+            //     Term = Num (*) ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   [")", "+", ",", EOF] -> Term = Num => ActionFn(29);
+            //
+            fn __state3<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, i32, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action29::<>(input, __sym0);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###"",""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action29::<>(input, __sym0);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 4
+            //     AllInputs = [Term]
+            //     OptionalInputs = []
+            //     FixedInputs = [Term]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Expr)
+            //
+            //     Expr = Term (*) ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   [")", "+", ",", EOF] -> Expr = Term => ActionFn(27);
+            //
+            fn __state4<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Term<'input>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action27::<>(input, __sym0);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###"",""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action27::<>(input, __sym0);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 5
+            //     AllInputs = ["("]
+            //     OptionalInputs = []
+            //     FixedInputs = ["("]
+            //     WillPushLen = 2
+            //     WillPush = [Expr, ")"]
+            //     WillProduce = Some(Term)
+            //
+            //     Expr = (*) Expr "+" Term ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = (*) Term ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Name = (*) r#"[a-z]+"# ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Num = (*) r#"[0-9]+"# ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Name "(" (<Expr> ",")+ ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Name "(" ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Num ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = "(" (*) Expr ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "(" -> S5
+            //   r#"[0-9]+"# -> S6
+            //   r#"[a-z]+"# -> S7
+            //
+            //     Expr -> S10
+            //     Name -> S2
+            //     Num -> S3
+            //     Term -> S4
+            fn __state5<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                let __sym0 = &mut Some(__sym0);
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                            r###"r#"[a-z]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    if __sym0.is_none() {
+                        return Ok(__result);
+                    }
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Expr(__sym1) => {
+                            __result = try!(__state10(input, __tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Name(__sym1) => {
+                            __result = try!(__state2(input, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Num(__sym1) => {
+                            __result = try!(__state3(input, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Term(__sym1) => {
+                            __result = try!(__state4(input, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 6
+            //     AllInputs = [r#"[0-9]+"#]
+            //     OptionalInputs = []
+            //     FixedInputs = [r#"[0-9]+"#]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Num)
+            //
+            //     Num = r#"[0-9]+"# (*) ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   [")", "+", ",", EOF] -> Num = r#"[0-9]+"# => ActionFn(7);
+            //
+            fn __state6<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action7::<>(input, __sym0);
+                        let __nt = __Nonterminal::Num((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###"",""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action7::<>(input, __sym0);
+                        let __nt = __Nonterminal::Num((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 7
+            //     AllInputs = [r#"[a-z]+"#]
+            //     OptionalInputs = []
+            //     FixedInputs = [r#"[a-z]+"#]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Name)
+            //
+            //     Name = r#"[a-z]+"# (*) ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   ["("] -> Name = r#"[a-z]+"# => ActionFn(28);
+            //
+            fn __state7<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(2, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action28::<>(input, __sym0);
+                        let __nt = __Nonterminal::Name((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 8
+            //     AllInputs = [Expr, "+"]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr, "+"]
+            //     WillPushLen = 1
+            //     WillPush = [Term]
+            //     WillProduce = Some(Expr)
+            //
+            //     Expr = Expr "+" (*) Term ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Name = (*) r#"[a-z]+"# ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Num = (*) r#"[0-9]+"# ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Name "(" (<Expr> ",")+ ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Name "(" ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Num ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "(" -> S5
+            //   r#"[0-9]+"# -> S6
+            //   r#"[a-z]+"# -> S7
+            //
+            //     Name -> S2
+            //     Num -> S3
+            //     Term -> S11
+            fn __state8<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Expr<'input>, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                            r###"r#"[a-z]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Name(__sym2) => {
+                            __result = try!(__state2(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Num(__sym2) => {
+                            __result = try!(__state3(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Term(__sym2) => {
+                            __result = try!(__state11(input, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 9
+            //     AllInputs = [Name, "("]
+            //     OptionalInputs = []
+            //     FixedInputs = [Name, "("]
+            //     WillPushLen = 1
+            //     WillPush = [")"]
+            //     WillProduce = Some(Term)
+            //
+            //     (<Expr> ",")+ = (*) (<Expr> ",")+ Expr "," ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     (<Expr> ",")+ = (*) Expr "," ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = (*) Expr "+" Term ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = (*) Term ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Name = (*) r#"[a-z]+"# ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Num = (*) r#"[0-9]+"# ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Name "(" (<Expr> ",")+ ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = Name "(" (*) (<Expr> ",")+ ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Name "(" ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = Name "(" (*) ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Num ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "(" -> S5
+            //   ")" -> S14
+            //   r#"[0-9]+"# -> S6
+            //   r#"[a-z]+"# -> S7
+            //
+            //     (<Expr> ",")+ -> S12
+            //     Expr -> S13
+            //     Name -> S2
+            //     Num -> S3
+            //     Term -> S4
+            fn __state9<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Name<'input>, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                let __sym0 = &mut Some(__sym0);
+                let __sym1 = &mut Some(__sym1);
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(3, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        let __sym0 = __sym0.take().unwrap();
+                        let __sym1 = __sym1.take().unwrap();
+                        __result = try!(__state14(input, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###"")""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                            r###"r#"[a-z]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    if __sym1.is_none() {
+                        return Ok(__result);
+                    }
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::_28_3cExpr_3e_20_22_2c_22_29_2b(__sym2) => {
+                            __result = try!(__state12(input, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Expr(__sym2) => {
+                            __result = try!(__state13(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Name(__sym2) => {
+                            __result = try!(__state2(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Num(__sym2) => {
+                            __result = try!(__state3(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Term(__sym2) => {
+                            __result = try!(__state4(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 10
+            //     AllInputs = ["(", Expr]
+            //     OptionalInputs = ["("]
+            //     FixedInputs = [Expr]
+            //     WillPushLen = 1
+            //     WillPush = [")"]
+            //     WillProduce = None
+            //
+            //     Expr = Expr (*) "+" Term ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = "(" Expr (*) ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   ")" -> S15
+            //   "+" -> S8
+            //
+            fn __state10<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: &mut Option<(usize, &'input str, usize)>,
+                __sym1: (usize, Expr<'input>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(3, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        let __sym0 = __sym0.take().unwrap();
+                        __result = try!(__state15(input, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, Token(4, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state8(input, __tokens, __extra, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""+""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 11
+            //     AllInputs = [Expr, "+", Term]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr, "+", Term]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Expr)
+            //
+            //     Expr = Expr "+" Term (*) ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   [")", "+", ",", EOF] -> Expr = Expr, "+", Term => ActionFn(26);
+            //
+            fn __state11<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Expr<'input>, usize),
+                __sym1: (usize, &'input str, usize),
+                __sym2: (usize, Term<'input>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action26::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###"",""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action26::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 12
+            //     AllInputs = [Name, "(", (<Expr> ",")+]
+            //     OptionalInputs = [Name, "("]
+            //     FixedInputs = [(<Expr> ",")+]
+            //     WillPushLen = 1
+            //     WillPush = [")"]
+            //     WillProduce = None
+            //
+            //     (<Expr> ",")+ = (<Expr> ",")+ (*) Expr "," ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = (*) Expr "+" Term ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = (*) Term ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Name = (*) r#"[a-z]+"# ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Num = (*) r#"[0-9]+"# ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Name "(" (<Expr> ",")+ ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = Name "(" (<Expr> ",")+ (*) ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Name "(" ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Num ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "(" -> S5
+            //   ")" -> S17
+            //   r#"[0-9]+"# -> S6
+            //   r#"[a-z]+"# -> S7
+            //
+            //     Expr -> S16
+            //     Name -> S2
+            //     Num -> S3
+            //     Term -> S4
+            fn __state12<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: &mut Option<(usize, Name<'input>, usize)>,
+                __sym1: &mut Option<(usize, &'input str, usize)>,
+                __sym2: (usize, ::std::vec::Vec<Expr<'input>>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __sym2 = &mut Some(__sym2);
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(input, __tokens, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(3, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        let __sym0 = __sym0.take().unwrap();
+                        let __sym1 = __sym1.take().unwrap();
+                        let __sym2 = __sym2.take().unwrap();
+                        __result = try!(__state17(input, __tokens, __extra, __sym0, __sym1, __sym2, __sym3, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(input, __tokens, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###"")""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                            r###"r#"[a-z]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    if __sym2.is_none() {
+                        return Ok(__result);
+                    }
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Expr(__sym3) => {
+                            __result = try!(__state16(input, __tokens, __lookahead, __extra, __sym2, __sym3, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Name(__sym3) => {
+                            __result = try!(__state2(input, __tokens, __lookahead, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Num(__sym3) => {
+                            __result = try!(__state3(input, __tokens, __lookahead, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Term(__sym3) => {
+                            __result = try!(__state4(input, __tokens, __lookahead, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 13
+            //     AllInputs = [Expr]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr]
+            //     WillPushLen = 1
+            //     WillPush = [","]
+            //     WillProduce = None
+            //
+            //     (<Expr> ",")+ = Expr (*) "," ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = Expr (*) "+" Term ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "+" -> S8
+            //   "," -> S18
+            //
+            fn __state13<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Expr<'input>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(4, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state8(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, Token(5, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state18(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""+""###.to_string(),
+                            r###"",""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 14
+            //     AllInputs = [Name, "(", ")"]
+            //     OptionalInputs = []
+            //     FixedInputs = [Name, "(", ")"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Term)
+            //
+            //     Term = Name "(" ")" (*) ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   [")", "+", ",", EOF] -> Term = Name, "(", ")" => ActionFn(30);
+            //
+            fn __state14<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Name<'input>, usize),
+                __sym1: (usize, &'input str, usize),
+                __sym2: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action30::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###"",""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action30::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 15
+            //     AllInputs = ["(", Expr, ")"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["(", Expr, ")"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Term)
+            //
+            //     Term = "(" Expr ")" (*) ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   [")", "+", ",", EOF] -> Term = "(", Expr, ")" => ActionFn(32);
+            //
+            fn __state15<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, Expr<'input>, usize),
+                __sym2: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action32::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###"",""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action32::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 16
+            //     AllInputs = [(<Expr> ",")+, Expr]
+            //     OptionalInputs = [(<Expr> ",")+]
+            //     FixedInputs = [Expr]
+            //     WillPushLen = 1
+            //     WillPush = [","]
+            //     WillProduce = None
+            //
+            //     (<Expr> ",")+ = (<Expr> ",")+ Expr (*) "," ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = Expr (*) "+" Term ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "+" -> S8
+            //   "," -> S19
+            //
+            fn __state16<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: &mut Option<(usize, ::std::vec::Vec<Expr<'input>>, usize)>,
+                __sym1: (usize, Expr<'input>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(4, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state8(input, __tokens, __extra, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, Token(5, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        let __sym0 = __sym0.take().unwrap();
+                        __result = try!(__state19(input, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""+""###.to_string(),
+                            r###"",""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 17
+            //     AllInputs = [Name, "(", (<Expr> ",")+, ")"]
+            //     OptionalInputs = []
+            //     FixedInputs = [Name, "(", (<Expr> ",")+, ")"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Term)
+            //
+            //     Term = Name "(" (<Expr> ",")+ ")" (*) ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   [")", "+", ",", EOF] -> Term = Name, "(", (<Expr> ",")+, ")" => ActionFn(31);
+            //
+            fn __state17<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Name<'input>, usize),
+                __sym1: (usize, &'input str, usize),
+                __sym2: (usize, ::std::vec::Vec<Expr<'input>>, usize),
+                __sym3: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym3.2.clone();
+                        let __nt = super::super::super::__action31::<>(input, __sym0, __sym1, __sym2, __sym3);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###"",""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym3.2.clone();
+                        let __nt = super::super::super::__action31::<>(input, __sym0, __sym1, __sym2, __sym3);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 18
+            //     AllInputs = [Expr, ","]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr, ","]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some((<Expr> ",")+)
+            //
+            //     (<Expr> ",")+ = Expr "," (*) ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   ["(", ")", r#"[0-9]+"#, r#"[a-z]+"#] -> (<Expr> ",")+ = Expr, "," => ActionFn(15);
+            //
+            fn __state18<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Expr<'input>, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(2, _), _)) |
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(0, _), _)) |
+                    Some((_, Token(1, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action15::<>(input, __sym0, __sym1);
+                        let __nt = __Nonterminal::_28_3cExpr_3e_20_22_2c_22_29_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###"")""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                            r###"r#"[a-z]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 19
+            //     AllInputs = [(<Expr> ",")+, Expr, ","]
+            //     OptionalInputs = []
+            //     FixedInputs = [(<Expr> ",")+, Expr, ","]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some((<Expr> ",")+)
+            //
+            //     (<Expr> ",")+ = (<Expr> ",")+ Expr "," (*) ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   ["(", ")", r#"[0-9]+"#, r#"[a-z]+"#] -> (<Expr> ",")+ = (<Expr> ",")+, Expr, "," => ActionFn(16);
+            //
+            fn __state19<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, ::std::vec::Vec<Expr<'input>>, usize),
+                __sym1: (usize, Expr<'input>, usize),
+                __sym2: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(2, _), _)) |
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(0, _), _)) |
+                    Some((_, Token(1, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action16::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::_28_3cExpr_3e_20_22_2c_22_29_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###"")""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                            r###"r#"[a-z]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+        }
+        pub use self::__parse__Expr::parse_Expr;
+    }
+    mod __parse_table {
+
+        mod __parse_table {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use std::str::FromStr;
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__intern_token::Token;
+            use super::super::super::Expr;
+            use super::super::super::Term;
+            use super::super::super::Name;
+            #[allow(dead_code)]
+            pub enum __Symbol<'input>
+             {
+                Term_22_28_22(&'input str),
+                Term_22_29_22(&'input str),
+                Term_22_2b_22(&'input str),
+                Term_22_2c_22(&'input str),
+                Termr_23_22_5b0_2d9_5d_2b_22_23(&'input str),
+                Termr_23_22_5ba_2dz_5d_2b_22_23(&'input str),
+                Nt_28_3cExpr_3e_20_22_2c_22_29(Expr<'input>),
+                Nt_28_3cExpr_3e_20_22_2c_22_29_2a(::std::vec::Vec<Expr<'input>>),
+                Nt_28_3cExpr_3e_20_22_2c_22_29_2b(::std::vec::Vec<Expr<'input>>),
+                Nt_40L(usize),
+                Nt_40R(usize),
+                NtExpr(Expr<'input>),
+                NtName(Name<'input>),
+                NtNum(i32),
+                NtTerm(Term<'input>),
+                Nt____Expr(Expr<'input>),
+            }
+            impl<'input> ::std::fmt::Debug for __Symbol<'input>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Symbol::Term_22_28_22(..) => __f.write_str("\"(\""),
+                        __Symbol::Term_22_29_22(..) => __f.write_str("\")\""),
+                        __Symbol::Term_22_2b_22(..) => __f.write_str("\"+\""),
+                        __Symbol::Term_22_2c_22(..) => __f.write_str("\",\""),
+                        __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(..) => __f.write_str("r#\"[0-9]+\"#"),
+                        __Symbol::Termr_23_22_5ba_2dz_5d_2b_22_23(..) => __f.write_str("r#\"[a-z]+\"#"),
+                        __Symbol::Nt_28_3cExpr_3e_20_22_2c_22_29(..) => __f.write_str("(<Expr> \",\")"),
+                        __Symbol::Nt_28_3cExpr_3e_20_22_2c_22_29_2a(..) => __f.write_str("(<Expr> \",\")*"),
+                        __Symbol::Nt_28_3cExpr_3e_20_22_2c_22_29_2b(..) => __f.write_str("(<Expr> \",\")+"),
+                        __Symbol::Nt_40L(..) => __f.write_str("@L"),
+                        __Symbol::Nt_40R(..) => __f.write_str("@R"),
+                        __Symbol::NtExpr(..) => __f.write_str("Expr"),
+                        __Symbol::NtName(..) => __f.write_str("Name"),
+                        __Symbol::NtNum(..) => __f.write_str("Num"),
+                        __Symbol::NtTerm(..) => __f.write_str("Term"),
+                        __Symbol::Nt____Expr(..) => __f.write_str("__Expr"),
+                    }
+                }
+            }
+            const __ACTION: &'static [i8] = &[
+                // State 0
+                //     Expr = (*) Expr "+" Term ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = (*) Term ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Name = (*) r#"[a-z]+"# ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Num = (*) r#"[0-9]+"# ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Name "(" (<Expr> ",")+ ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Name "(" ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Num ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     __Expr = (*) Expr ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                6,  // on "(", goto 5
+                0,  // on ")", error
+                0,  // on "+", error
+                0,  // on ",", error
+                7,  // on r#"[0-9]+"#, goto 6
+                8,  // on r#"[a-z]+"#, goto 7
+
+                // State 1
+                //     Expr = Expr (*) "+" Term ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     __Expr = Expr (*) ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                9,  // on "+", goto 8
+                0,  // on ",", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 2
+                //     Term = Name (*) "(" (<Expr> ",")+ ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = Name (*) "(" ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                10,  // on "(", goto 9
+                0,  // on ")", error
+                0,  // on "+", error
+                0,  // on ",", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 3
+                //     Term = Num (*) ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                -12,  // on ")", reduce `Term = Num => ActionFn(29);`
+                -12,  // on "+", reduce `Term = Num => ActionFn(29);`
+                -12,  // on ",", reduce `Term = Num => ActionFn(29);`
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 4
+                //     Expr = Term (*) ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                -9,  // on ")", reduce `Expr = Term => ActionFn(27);`
+                -9,  // on "+", reduce `Expr = Term => ActionFn(27);`
+                -9,  // on ",", reduce `Expr = Term => ActionFn(27);`
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 5
+                //     Expr = (*) Expr "+" Term ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = (*) Term ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Name = (*) r#"[a-z]+"# ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Num = (*) r#"[0-9]+"# ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Name "(" (<Expr> ",")+ ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Name "(" ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Num ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = "(" (*) Expr ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                6,  // on "(", goto 5
+                0,  // on ")", error
+                0,  // on "+", error
+                0,  // on ",", error
+                7,  // on r#"[0-9]+"#, goto 6
+                8,  // on r#"[a-z]+"#, goto 7
+
+                // State 6
+                //     Num = r#"[0-9]+"# (*) ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                -11,  // on ")", reduce `Num = r#"[0-9]+"# => ActionFn(7);`
+                -11,  // on "+", reduce `Num = r#"[0-9]+"# => ActionFn(7);`
+                -11,  // on ",", reduce `Num = r#"[0-9]+"# => ActionFn(7);`
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 7
+                //     Name = r#"[a-z]+"# (*) ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                -10,  // on "(", reduce `Name = r#"[a-z]+"# => ActionFn(28);`
+                0,  // on ")", error
+                0,  // on "+", error
+                0,  // on ",", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 8
+                //     Expr = Expr "+" (*) Term ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Name = (*) r#"[a-z]+"# ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Num = (*) r#"[0-9]+"# ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Name "(" (<Expr> ",")+ ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Name "(" ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Num ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                6,  // on "(", goto 5
+                0,  // on ")", error
+                0,  // on "+", error
+                0,  // on ",", error
+                7,  // on r#"[0-9]+"#, goto 6
+                8,  // on r#"[a-z]+"#, goto 7
+
+                // State 9
+                //     (<Expr> ",")+ = (*) (<Expr> ",")+ Expr "," ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     (<Expr> ",")+ = (*) Expr "," ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = (*) Expr "+" Term ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = (*) Term ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Name = (*) r#"[a-z]+"# ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Num = (*) r#"[0-9]+"# ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Name "(" (<Expr> ",")+ ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = Name "(" (*) (<Expr> ",")+ ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Name "(" ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = Name "(" (*) ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Num ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                6,  // on "(", goto 5
+                15,  // on ")", goto 14
+                0,  // on "+", error
+                0,  // on ",", error
+                7,  // on r#"[0-9]+"#, goto 6
+                8,  // on r#"[a-z]+"#, goto 7
+
+                // State 10
+                //     Expr = Expr (*) "+" Term ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = "(" Expr (*) ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                16,  // on ")", goto 15
+                9,  // on "+", goto 8
+                0,  // on ",", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 11
+                //     Expr = Expr "+" Term (*) ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                -8,  // on ")", reduce `Expr = Expr, "+", Term => ActionFn(26);`
+                -8,  // on "+", reduce `Expr = Expr, "+", Term => ActionFn(26);`
+                -8,  // on ",", reduce `Expr = Expr, "+", Term => ActionFn(26);`
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 12
+                //     (<Expr> ",")+ = (<Expr> ",")+ (*) Expr "," ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = (*) Expr "+" Term ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = (*) Term ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Name = (*) r#"[a-z]+"# ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Num = (*) r#"[0-9]+"# ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Name "(" (<Expr> ",")+ ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = Name "(" (<Expr> ",")+ (*) ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Name "(" ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Num ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                6,  // on "(", goto 5
+                18,  // on ")", goto 17
+                0,  // on "+", error
+                0,  // on ",", error
+                7,  // on r#"[0-9]+"#, goto 6
+                8,  // on r#"[a-z]+"#, goto 7
+
+                // State 13
+                //     (<Expr> ",")+ = Expr (*) "," ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = Expr (*) "+" Term ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                9,  // on "+", goto 8
+                19,  // on ",", goto 18
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 14
+                //     Term = Name "(" ")" (*) ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                -13,  // on ")", reduce `Term = Name, "(", ")" => ActionFn(30);`
+                -13,  // on "+", reduce `Term = Name, "(", ")" => ActionFn(30);`
+                -13,  // on ",", reduce `Term = Name, "(", ")" => ActionFn(30);`
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 15
+                //     Term = "(" Expr ")" (*) ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                -15,  // on ")", reduce `Term = "(", Expr, ")" => ActionFn(32);`
+                -15,  // on "+", reduce `Term = "(", Expr, ")" => ActionFn(32);`
+                -15,  // on ",", reduce `Term = "(", Expr, ")" => ActionFn(32);`
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 16
+                //     (<Expr> ",")+ = (<Expr> ",")+ Expr (*) "," ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = Expr (*) "+" Term ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                9,  // on "+", goto 8
+                20,  // on ",", goto 19
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 17
+                //     Term = Name "(" (<Expr> ",")+ ")" (*) ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                -14,  // on ")", reduce `Term = Name, "(", (<Expr> ",")+, ")" => ActionFn(31);`
+                -14,  // on "+", reduce `Term = Name, "(", (<Expr> ",")+, ")" => ActionFn(31);`
+                -14,  // on ",", reduce `Term = Name, "(", (<Expr> ",")+, ")" => ActionFn(31);`
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 18
+                //     (<Expr> ",")+ = Expr "," (*) ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                -4,  // on "(", reduce `(<Expr> ",")+ = Expr, "," => ActionFn(15);`
+                -4,  // on ")", reduce `(<Expr> ",")+ = Expr, "," => ActionFn(15);`
+                0,  // on "+", error
+                0,  // on ",", error
+                -4,  // on r#"[0-9]+"#, reduce `(<Expr> ",")+ = Expr, "," => ActionFn(15);`
+                -4,  // on r#"[a-z]+"#, reduce `(<Expr> ",")+ = Expr, "," => ActionFn(15);`
+
+                // State 19
+                //     (<Expr> ",")+ = (<Expr> ",")+ Expr "," (*) ["(", ")", "+", ",", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                -5,  // on "(", reduce `(<Expr> ",")+ = (<Expr> ",")+, Expr, "," => ActionFn(16);`
+                -5,  // on ")", reduce `(<Expr> ",")+ = (<Expr> ",")+, Expr, "," => ActionFn(16);`
+                0,  // on "+", error
+                0,  // on ",", error
+                -5,  // on r#"[0-9]+"#, reduce `(<Expr> ",")+ = (<Expr> ",")+, Expr, "," => ActionFn(16);`
+                -5,  // on r#"[a-z]+"#, reduce `(<Expr> ",")+ = (<Expr> ",")+, Expr, "," => ActionFn(16);`
+
+            ];
+            const __EOF_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // on EOF, error
+
+                // State 1
+                -16,  // on EOF, reduce `__Expr = Expr => ActionFn(0);`
+
+                // State 2
+                0,  // on EOF, error
+
+                // State 3
+                -12,  // on EOF, reduce `Term = Num => ActionFn(29);`
+
+                // State 4
+                -9,  // on EOF, reduce `Expr = Term => ActionFn(27);`
+
+                // State 5
+                0,  // on EOF, error
+
+                // State 6
+                -11,  // on EOF, reduce `Num = r#"[0-9]+"# => ActionFn(7);`
+
+                // State 7
+                0,  // on EOF, error
+
+                // State 8
+                0,  // on EOF, error
+
+                // State 9
+                0,  // on EOF, error
+
+                // State 10
+                0,  // on EOF, error
+
+                // State 11
+                -8,  // on EOF, reduce `Expr = Expr, "+", Term => ActionFn(26);`
+
+                // State 12
+                0,  // on EOF, error
+
+                // State 13
+                0,  // on EOF, error
+
+                // State 14
+                -13,  // on EOF, reduce `Term = Name, "(", ")" => ActionFn(30);`
+
+                // State 15
+                -15,  // on EOF, reduce `Term = "(", Expr, ")" => ActionFn(32);`
+
+                // State 16
+                0,  // on EOF, error
+
+                // State 17
+                -14,  // on EOF, reduce `Term = Name, "(", (<Expr> ",")+, ")" => ActionFn(31);`
+
+                // State 18
+                0,  // on EOF, error
+
+                // State 19
+                0,  // on EOF, error
+
+            ];
+            const __GOTO: &'static [u8] = &[
+                // State 0
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on @L, error
+                0,  // on @R, error
+                2,  // on Expr, goto 1
+                3,  // on Name, goto 2
+                4,  // on Num, goto 3
+                5,  // on Term, goto 4
+                0,  // on __Expr, error
+
+                // State 1
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expr, error
+                0,  // on Name, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 2
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expr, error
+                0,  // on Name, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 3
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expr, error
+                0,  // on Name, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 4
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expr, error
+                0,  // on Name, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 5
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on @L, error
+                0,  // on @R, error
+                11,  // on Expr, goto 10
+                3,  // on Name, goto 2
+                4,  // on Num, goto 3
+                5,  // on Term, goto 4
+                0,  // on __Expr, error
+
+                // State 6
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expr, error
+                0,  // on Name, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 7
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expr, error
+                0,  // on Name, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 8
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expr, error
+                3,  // on Name, goto 2
+                4,  // on Num, goto 3
+                12,  // on Term, goto 11
+                0,  // on __Expr, error
+
+                // State 9
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                13,  // on (<Expr> ",")+, goto 12
+                0,  // on @L, error
+                0,  // on @R, error
+                14,  // on Expr, goto 13
+                3,  // on Name, goto 2
+                4,  // on Num, goto 3
+                5,  // on Term, goto 4
+                0,  // on __Expr, error
+
+                // State 10
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expr, error
+                0,  // on Name, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 11
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expr, error
+                0,  // on Name, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 12
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on @L, error
+                0,  // on @R, error
+                17,  // on Expr, goto 16
+                3,  // on Name, goto 2
+                4,  // on Num, goto 3
+                5,  // on Term, goto 4
+                0,  // on __Expr, error
+
+                // State 13
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expr, error
+                0,  // on Name, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 14
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expr, error
+                0,  // on Name, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 15
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expr, error
+                0,  // on Name, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 16
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expr, error
+                0,  // on Name, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 17
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expr, error
+                0,  // on Name, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 18
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expr, error
+                0,  // on Name, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 19
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expr, error
+                0,  // on Name, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+            ];
+            const __DEFAULT_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // no default reduction
+
+                // State 1
+                0,  // no default reduction
+
+                // State 2
+                0,  // no default reduction
+
+                // State 3
+                -12,  // on any token, reduce `Term = Num => ActionFn(29);`
+
+                // State 4
+                -9,  // on any token, reduce `Expr = Term => ActionFn(27);`
+
+                // State 5
+                0,  // no default reduction
+
+                // State 6
+                -11,  // on any token, reduce `Num = r#"[0-9]+"# => ActionFn(7);`
+
+                // State 7
+                -10,  // on any token, reduce `Name = r#"[a-z]+"# => ActionFn(28);`
+
+                // State 8
+                0,  // no default reduction
+
+                // State 9
+                0,  // no default reduction
+
+                // State 10
+                0,  // no default reduction
+
+                // State 11
+                -8,  // on any token, reduce `Expr = Expr, "+", Term => ActionFn(26);`
+
+                // State 12
+                0,  // no default reduction
+
+                // State 13
+                0,  // no default reduction
+
+                // State 14
+                -13,  // on any token, reduce `Term = Name, "(", ")" => ActionFn(30);`
+
+                // State 15
+                -15,  // on any token, reduce `Term = "(", Expr, ")" => ActionFn(32);`
+
+                // State 16
+                0,  // no default reduction
+
+                // State 17
+                -14,  // on any token, reduce `Term = Name, "(", (<Expr> ",")+, ")" => ActionFn(31);`
+
+                // State 18
+                -4,  // on any token, reduce `(<Expr> ",")+ = Expr, "," => ActionFn(15);`
+
+                // State 19
+                -5,  // on any token, reduce `(<Expr> ",")+ = (<Expr> ",")+, Expr, "," => ActionFn(16);`
+
+            ];
+            fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+                const __TERMINAL: &'static [&'static str] = &[
+                    r###""(""###,
+                    r###"")""###,
+                    r###""+""###,
+                    r###"",""###,
+                    r###"r#"[0-9]+"#"###,
+                    r###"r#"[a-z]+"#"###,
+                ];
+                __ACTION[(__state * 6)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+                    if state == 0 {
+                        None
+                    } else {
+                        Some(terminal.to_string())
+                    }
+                }).collect()
+            }
+            #[allow(dead_code)]
+            pub fn parse_Expr<
+                'input,
+            >(
+                input: &'input str,
+            ) -> Result<Expr<'input>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() })
+            }
+            #[allow(dead_code)]
+            pub fn parse_Expr_cancelable<
+                'input,
+            >(
+                input: &'input str,
+                __cancel: &::std::sync::atomic::AtomicBool,
+            ) -> Result<Expr<'input>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() })
+            }
+
+            #[allow(dead_code)]
+            pub struct ExprParser {
+                __states: ::std::vec::Vec<i32>,
+                __symbols: __lalrpop_util::stack::Recycled,
+                __cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+            }
+
+            #[allow(dead_code)]
+            impl ExprParser {
+                pub fn new() -> ExprParser {
+                    ExprParser { __states: vec![], __symbols: __lalrpop_util::stack::Recycled::new(), __cancel: None }
+                }
+
+                pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+                    self.__cancel = Some(flag);
+                }
+
+                pub fn parse_in_place<'input>(
+                    &mut self,
+                    input: &'input str,
+                ) -> Result<Expr<'input>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+                {
+                    let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                    let mut __symbols = self.__symbols.take();
+                    let __result = __parse::<>(input, 0, &mut __tokens, &mut self.__states, &mut __symbols, self.__cancel.as_ref().map(|c| &**c)).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() });
+                    self.__symbols.put(__symbols);
+                    __result
+                }
+            }
+
+            fn __parse<
+                'input,
+            >(
+                input: &'input str,
+                __start_state: i32,
+                mut __tokens: &mut Iterator<Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+                mut __states: &mut ::std::vec::Vec<i32>,
+                mut __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                __cancel: Option<&::std::sync::atomic::AtomicBool>,
+            ) -> Result<__Symbol<'input>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                __states.clear();
+                __states.push(__start_state);
+                __symbols.clear();
+                let mut __integer;
+                let mut __lookahead;
+                let __last_location: &mut usize = &mut Default::default();
+                let mut __default_reduced = [0_usize; 16];
+                let mut __default_reduced_len = 0;
+                '__shift: loop {
+                    loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __DEFAULT_ACTION[__state] as i32;
+                        if __action == 0 {
+                            break;
+                        }
+                        if __default_reduced_len < 16 {
+                            __default_reduced[__default_reduced_len] = __state;
+                            __default_reduced_len += 1;
+                        }
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    }
+                    __lookahead = match __tokens.next() {
+                        Some(Ok(v)) => v,
+                        None => break '__shift,
+                        Some(Err(e)) => return Err(e),
+                    };
+                    *__last_location = __lookahead.2.clone();
+                    __integer = match __lookahead.1 {
+                        Token(2, _) if true => 0,
+                        Token(3, _) if true => 1,
+                        Token(4, _) if true => 2,
+                        Token(5, _) if true => 3,
+                        Token(0, _) if true => 4,
+                        Token(1, _) if true => 5,
+                        _ => {
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| true).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match Some(__lookahead) {
+                                Some(__token) => {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error);
+                        }
+                    };
+                    '__inner: loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __ACTION[__state * 6 + __integer] as i32;
+                        if __action > 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                                }
+                            }
+                            let __symbol = match __integer {
+                                0 => match __lookahead.1 {
+                                    Token(2, __tok0) => __Symbol::Term_22_28_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                1 => match __lookahead.1 {
+                                    Token(3, __tok0) => __Symbol::Term_22_29_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                2 => match __lookahead.1 {
+                                    Token(4, __tok0) => __Symbol::Term_22_2b_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                3 => match __lookahead.1 {
+                                    Token(5, __tok0) => __Symbol::Term_22_2c_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                4 => match __lookahead.1 {
+                                    Token(0, __tok0) => __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                5 => match __lookahead.1 {
+                                    Token(1, __tok0) => __Symbol::Termr_23_22_5ba_2dz_5d_2b_22_23((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                _ => unreachable!(),
+                            };
+                            __states.push(__action - 1);
+                            __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                            __default_reduced_len = 0;
+                            continue '__shift;
+                        } else if __action < 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                                }
+                            }
+                            if let Some(r) = __reduce(input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                                if r.is_err() {
+                                    return r;
+                                }
+                                return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] });
+                            }
+                        } else {
+                            let mut __err_lookahead = Some(__lookahead);
+                            let mut __err_integer: Option<usize> = Some(__integer);
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 6 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match __err_lookahead {
+                                Some(__token) => {
+                                    if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                        __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                    } else {
+                                        __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                    }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error)
+                        }
+                    }
+                }
+                loop {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __action = __EOF_ACTION[__state] as i32;
+                    if __action < 0 {
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    } else {
+                        let mut __err_lookahead = None;
+                        let mut __err_integer: Option<usize> = None;
+                        let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 6 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                        let __expected = __expected_tokens(__state);
+                        let __error = match __err_lookahead {
+                            Some(__token) => {
+                                if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                    __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                } else {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                            }
+                            None => {
+                                __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                            }
+                        };
+                        return Err(__error)
+                    }
+                }
+            }
+            fn __accepts<
+                'input,
+            >(
+                input: &'input str,
+                __error_state: i32,
+                __states: & [i32],
+                __opt_integer: Option<usize>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> bool
+            {
+                let mut __states = __states.to_vec();
+                __states.push(__error_state);
+                loop {
+                    let mut __states_len = __states.len();
+                    let __top = __states[__states_len - 1];
+                    let __action = match __opt_integer {
+                        None => __EOF_ACTION[__top as usize] as i32,
+                        Some(__integer) => __ACTION[(__top * 6) as usize + __integer] as i32,
+                    };
+                    if __action == 0 { return false; }
+                    if __action > 0 { return true; }
+                    let (__to_pop, __nt) = match -__action {
+                        // simulate (<Expr> ",") = Expr, "," => ActionFn(10);
+                        1 => {
+                            (2, 0)
+                        }
+                        // simulate (<Expr> ",")* =  => ActionFn(8);
+                        2 => {
+                            (0, 1)
+                        }
+                        // simulate (<Expr> ",")* = (<Expr> ",")+ => ActionFn(9);
+                        3 => {
+                            (1, 1)
+                        }
+                        // simulate (<Expr> ",")+ = Expr, "," => ActionFn(15);
+                        4 => {
+                            (2, 2)
+                        }
+                        // simulate (<Expr> ",")+ = (<Expr> ",")+, Expr, "," => ActionFn(16);
+                        5 => {
+                            (3, 2)
+                        }
+                        // simulate @L =  => ActionFn(12);
+                        6 => {
+                            (0, 3)
+                        }
+                        // simulate @R =  => ActionFn(11);
+                        7 => {
+                            (0, 4)
+                        }
+                        // simulate Expr = Expr, "+", Term => ActionFn(26);
+                        8 => {
+                            (3, 5)
+                        }
+                        // simulate Expr = Term => ActionFn(27);
+                        9 => {
+                            (1, 5)
+                        }
+                        // simulate Name = r#"[a-z]+"# => ActionFn(28);
+                        10 => {
+                            (1, 6)
+                        }
+                        // simulate Num = r#"[0-9]+"# => ActionFn(7);
+                        11 => {
+                            (1, 7)
+                        }
+                        // simulate Term = Num => ActionFn(29);
+                        12 => {
+                            (1, 8)
+                        }
+                        // simulate Term = Name, "(", ")" => ActionFn(30);
+                        13 => {
+                            (3, 8)
+                        }
+                        // simulate Term = Name, "(", (<Expr> ",")+, ")" => ActionFn(31);
+                        14 => {
+                            (4, 8)
+                        }
+                        // simulate Term = "(", Expr, ")" => ActionFn(32);
+                        15 => {
+                            (3, 8)
+                        }
+                        // simulate __Expr = Expr => ActionFn(0);
+                        16 => return true,
+                        _ => panic!("invalid action code {}", __action)
+                    };
+                    __states_len -= __to_pop;
+                    __states.truncate(__states_len);
+                    let __top = __states[__states_len - 1];
+                    let __next_state = __GOTO[(__top * 10 + __nt) as usize] as i32 - 1;
+                    __states.push(__next_state);
+                }
+            }
+            pub fn __reduce<
+                'input,
+            >(
+                input: &'input str,
+                __action: i32,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Option<Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                let __nonterminal = match -__action {
+                    1 => match __reduce__28_3cExpr_3e_20_22_2c_22_29_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    2 => match __reduce__28_3cExpr_3e_20_22_2c_22_29_2a_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    3 => match __reduce__28_3cExpr_3e_20_22_2c_22_29_2a_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    4 => match __reduce__28_3cExpr_3e_20_22_2c_22_29_2b_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    5 => match __reduce__28_3cExpr_3e_20_22_2c_22_29_2b_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    6 => match __reduce__40L_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    7 => match __reduce__40R_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    8 => match __reduce_Expr_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    9 => match __reduce_Expr_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    10 => match __reduce_Name_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    11 => match __reduce_Num_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    12 => match __reduce_Term_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    13 => match __reduce_Term_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    14 => match __reduce_Term_2(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    15 => match __reduce_Term_3(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    16 => match __reduce_____Expr_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    _ => panic!("invalid action code {}", __action)
+                };
+                let __state = *__states.last().unwrap() as usize;
+                let __next_state = __GOTO[__state * 10 + __nonterminal] as i32 - 1;
+                __states.push(__next_state);
+                None
+            }
+
+            fn __reduce__28_3cExpr_3e_20_22_2c_22_29_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<Expr> ",") = Expr, "," => ActionFn(10);
+                let __sym1 = __pop_Term_22_2c_22(__symbols);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action10::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_3cExpr_3e_20_22_2c_22_29(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce__28_3cExpr_3e_20_22_2c_22_29_2a_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<Expr> ",")* =  => ActionFn(8);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action8::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_28_3cExpr_3e_20_22_2c_22_29_2a(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce__28_3cExpr_3e_20_22_2c_22_29_2a_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<Expr> ",")* = (<Expr> ",")+ => ActionFn(9);
+                let __sym0 = __pop_Nt_28_3cExpr_3e_20_22_2c_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action9::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::Nt_28_3cExpr_3e_20_22_2c_22_29_2a(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce__28_3cExpr_3e_20_22_2c_22_29_2b_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<Expr> ",")+ = Expr, "," => ActionFn(15);
+                let __sym1 = __pop_Term_22_2c_22(__symbols);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action15::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_3cExpr_3e_20_22_2c_22_29_2b(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce__28_3cExpr_3e_20_22_2c_22_29_2b_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<Expr> ",")+ = (<Expr> ",")+, Expr, "," => ActionFn(16);
+                let __sym2 = __pop_Term_22_2c_22(__symbols);
+                let __sym1 = __pop_NtExpr(__symbols);
+                let __sym0 = __pop_Nt_28_3cExpr_3e_20_22_2c_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action16::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::Nt_28_3cExpr_3e_20_22_2c_22_29_2b(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce__40L_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // @L =  => ActionFn(12);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action12::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_40L(__nt), __end));
+                Ok(3)
+            }
+
+            fn __reduce__40R_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // @R =  => ActionFn(11);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action11::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_40R(__nt), __end));
+                Ok(4)
+            }
+
+            fn __reduce_Expr_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expr = Expr, "+", Term => ActionFn(26);
+                let __sym2 = __pop_NtTerm(__symbols);
+                let __sym1 = __pop_Term_22_2b_22(__symbols);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action26::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+                Ok(5)
+            }
+
+            fn __reduce_Expr_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expr = Term => ActionFn(27);
+                let __sym0 = __pop_NtTerm(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action27::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+                Ok(5)
+            }
+
+            fn __reduce_Name_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Name = r#"[a-z]+"# => ActionFn(28);
+                let __sym0 = __pop_Termr_23_22_5ba_2dz_5d_2b_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action28::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtName(__nt), __end));
+                Ok(6)
+            }
+
+            fn __reduce_Num_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Num = r#"[0-9]+"# => ActionFn(7);
+                let __sym0 = __pop_Termr_23_22_5b0_2d9_5d_2b_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action7::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtNum(__nt), __end));
+                Ok(7)
+            }
+
+            fn __reduce_Term_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Term = Num => ActionFn(29);
+                let __sym0 = __pop_NtNum(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action29::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+                Ok(8)
+            }
+
+            fn __reduce_Term_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Term = Name, "(", ")" => ActionFn(30);
+                let __sym2 = __pop_Term_22_29_22(__symbols);
+                let __sym1 = __pop_Term_22_28_22(__symbols);
+                let __sym0 = __pop_NtName(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action30::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+                Ok(8)
+            }
+
+            fn __reduce_Term_2<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Term = Name, "(", (<Expr> ",")+, ")" => ActionFn(31);
+                let __sym3 = __pop_Term_22_29_22(__symbols);
+                let __sym2 = __pop_Nt_28_3cExpr_3e_20_22_2c_22_29_2b(__symbols);
+                let __sym1 = __pop_Term_22_28_22(__symbols);
+                let __sym0 = __pop_NtName(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::super::super::__action31::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+                Ok(8)
+            }
+
+            fn __reduce_Term_3<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Term = "(", Expr, ")" => ActionFn(32);
+                let __sym2 = __pop_Term_22_29_22(__symbols);
+                let __sym1 = __pop_NtExpr(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action32::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+                Ok(8)
+            }
+
+            fn __reduce_____Expr_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // __Expr = Expr => ActionFn(0);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action0::<>(input, __sym0);
+                Err(Ok(__Symbol::Nt____Expr(__nt)))
+            }
+            fn __pop_Term_22_28_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_28_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_29_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_29_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_2b_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2b_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_2c_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2c_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Termr_23_22_5b0_2d9_5d_2b_22_23<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Termr_23_22_5ba_2dz_5d_2b_22_23<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Termr_23_22_5ba_2dz_5d_2b_22_23(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_28_3cExpr_3e_20_22_2c_22_29<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Expr<'input>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_28_3cExpr_3e_20_22_2c_22_29(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_28_3cExpr_3e_20_22_2c_22_29_2a<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, ::std::vec::Vec<Expr<'input>>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_28_3cExpr_3e_20_22_2c_22_29_2a(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_28_3cExpr_3e_20_22_2c_22_29_2b<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, ::std::vec::Vec<Expr<'input>>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_28_3cExpr_3e_20_22_2c_22_29_2b(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_40L<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, usize, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_40L(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_40R<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, usize, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_40R(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtExpr<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Expr<'input>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtExpr(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtName<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Name<'input>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtName(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtNum<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, i32, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtNum(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtTerm<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Term<'input>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtTerm(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt____Expr<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Expr<'input>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt____Expr(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+        }
+        pub use self::__parse_table::parse_Expr;
+        pub use self::__parse_table::parse_Expr_cancelable;
+        pub use self::__parse_table::ExprParser;
+    }
+    pub use self::__parse_table::parse_Expr_cancelable;
+    pub use self::__parse_table::ExprParser;
+}
+pub use self::__parse__Expr::parse_Expr;
+pub use self::__parse__Expr::parse_Expr_cancelable;
+pub use self::__parse__Expr::ExprParser;
+mod __intern_token {
+    #![allow(unused_imports)]
+    use std::str::FromStr;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    extern crate regex as __regex;
+    use std::fmt as __fmt;
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Token<'input>(pub usize, pub &'input str);
+    impl<'a> __fmt::Display for Token<'a> {
+        fn fmt(&self, formatter: &mut __fmt::Formatter) -> Result<(), __fmt::Error> {
+            __fmt::Display::fmt(self.1, formatter)
+        }
+    }
+
+    pub struct __Matcher<'input> {
+        text: &'input str,
+        consumed: usize,
+        regex_set: __regex::RegexSet,
+        regex_vec: Vec<__regex::Regex>,
+    }
+
+    impl<'input> __Matcher<'input> {
+        pub fn new(s: &'input str) -> __Matcher<'input> {
+            let __strs: &[&str] = &[
+                "^(?u:[0-9])+",
+                "^(?u:[a-z])+",
+                "^(?u:\\()",
+                "^(?u:\\))",
+                "^(?u:\\+)",
+                "^(?u:,)",
+            ];
+            let __regex_set = __regex::RegexSet::new(__strs).unwrap();
+            let __regex_vec = vec![
+                __regex::Regex::new("^(?u:[0-9])+").unwrap(),
+                __regex::Regex::new("^(?u:[a-z])+").unwrap(),
+                __regex::Regex::new("^(?u:\\()").unwrap(),
+                __regex::Regex::new("^(?u:\\))").unwrap(),
+                __regex::Regex::new("^(?u:\\+)").unwrap(),
+                __regex::Regex::new("^(?u:,)").unwrap(),
+            ];
+            __Matcher {
+                text: s,
+                consumed: 0,
+                regex_set: __regex_set,
+                regex_vec: __regex_vec,
+            }
+        }
+    }
+
+    impl<'input> Iterator for __Matcher<'input> {
+        type Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize,Token<'input>,&'static str>>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let __text = self.text.trim_left();
+            let __whitespace = self.text.len() - __text.len();
+            let __start_offset = self.consumed + __whitespace;
+            if __text.is_empty() {
+                self.text = __text;
+                self.consumed = __start_offset;
+                None
+            } else {
+                let __matches = self.regex_set.matches(__text);
+                if !__matches.matched_any() {
+                    Some(Err(__lalrpop_util::ParseError::InvalidToken {
+                        location: __start_offset,
+                    }))
+                } else {
+                    let mut __longest_match = 0;
+                    let mut __index = 0;
+                    for __i in 0 .. 6 {
+                        if __matches.matched(__i) {
+                            let __match = self.regex_vec[__i].find(__text).unwrap();
+                            let __len = __match.end();
+                            if __len >= __longest_match {
+                                __longest_match = __len;
+                                __index = __i;
+                            }
+                        }
+                    }
+                    let __result = &__text[..__longest_match];
+                    let __remaining = &__text[__longest_match..];
+                    let __end_offset = __start_offset + __longest_match;
+                    self.text = __remaining;
+                    self.consumed = __end_offset;
+                    Some(Ok((__start_offset, Token(__index, __result), __end_offset)))
+                }
+            }
+        }
+    }
+}
+pub use self::__intern_token::Token;
+
+#[allow(unused_variables)]
+fn __action0<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Expr<'input>, usize),
+) -> Expr<'input>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'input,
+>(
+    input: &'input str,
+    (_, __start, _): (usize, usize, usize),
+    (_, left, _): (usize, Expr<'input>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, right, _): (usize, Term<'input>, usize),
+    (_, __end, _): (usize, usize, usize),
+) -> Expr<'input>
+{
+    Expr::Add { start: __start, end: __end, text: &input[__start..__end], left: ::std::boxed::Box::new(left), right: ::std::boxed::Box::new(right) }
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'input,
+>(
+    input: &'input str,
+    (_, __start, _): (usize, usize, usize),
+    (_, term, _): (usize, Term<'input>, usize),
+    (_, __end, _): (usize, usize, usize),
+) -> Expr<'input>
+{
+    Expr::Term { start: __start, end: __end, text: &input[__start..__end], term: ::std::boxed::Box::new(term) }
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'input,
+>(
+    input: &'input str,
+    (_, __start, _): (usize, usize, usize),
+    (_, value, _): (usize, i32, usize),
+    (_, __end, _): (usize, usize, usize),
+) -> Term<'input>
+{
+    Term::Num { start: __start, end: __end, text: &input[__start..__end], value: value }
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'input,
+>(
+    input: &'input str,
+    (_, __start, _): (usize, usize, usize),
+    (_, name, _): (usize, Name<'input>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, args, _): (usize, ::std::vec::Vec<Expr<'input>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, __end, _): (usize, usize, usize),
+) -> Term<'input>
+{
+    Term::Call { start: __start, end: __end, text: &input[__start..__end], name: ::std::boxed::Box::new(name), args: args }
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'input,
+>(
+    input: &'input str,
+    (_, __start, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, expr, _): (usize, Expr<'input>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, __end, _): (usize, usize, usize),
+) -> Term<'input>
+{
+    Term::Paren { start: __start, end: __end, text: &input[__start..__end], expr: ::std::boxed::Box::new(expr) }
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'input,
+>(
+    input: &'input str,
+    (_, __start, _): (usize, usize, usize),
+    (_, name, _): (usize, &'input str, usize),
+    (_, __end, _): (usize, usize, usize),
+) -> Name<'input>
+{
+    Name { start: __start, end: __end, text: &input[__start..__end], name: name }
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> i32
+{
+    //@ src/ast.lalrpop:30:25 (1 lines)
+    i32::from_str(__0).unwrap()
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::vec::Vec<Expr<'input>>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+fn __action9<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<Expr<'input>>, usize),
+) -> ::std::vec::Vec<Expr<'input>>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action10<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Expr<'input>, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> Expr<'input>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action11<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
+{
+    __lookbehind.clone()
+}
+
+#[allow(unused_variables)]
+fn __action12<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
+{
+    __lookahead.clone()
+}
+
+#[allow(unused_variables)]
+fn __action13<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Expr<'input>, usize),
+) -> ::std::vec::Vec<Expr<'input>>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action14<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<Expr<'input>>, usize),
+    (_, e, _): (usize, Expr<'input>, usize),
+) -> ::std::vec::Vec<Expr<'input>>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action15<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Expr<'input>, usize),
+    __1: (usize, &'input str, usize),
+) -> ::std::vec::Vec<Expr<'input>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action10(
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action13(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action16<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::vec::Vec<Expr<'input>>, usize),
+    __1: (usize, Expr<'input>, usize),
+    __2: (usize, &'input str, usize),
+) -> ::std::vec::Vec<Expr<'input>>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action10(
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action14(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action17<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, Name<'input>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, usize, usize),
+) -> Term<'input>
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __3.0.clone();
+    let __temp0 = __action8(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action4(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action18<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, Name<'input>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, ::std::vec::Vec<Expr<'input>>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, usize, usize),
+) -> Term<'input>
+{
+    let __start0 = __3.0.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action9(
+        input,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action4(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action19<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Expr<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Term<'input>, usize),
+    __3: (usize, usize, usize),
+) -> Expr<'input>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action12(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action1(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action20<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Term<'input>, usize),
+    __1: (usize, usize, usize),
+) -> Expr<'input>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action12(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action2(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action21<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, usize, usize),
+) -> Name<'input>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action12(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action6(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action22<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, i32, usize),
+    __1: (usize, usize, usize),
+) -> Term<'input>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action12(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action3(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action23<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Name<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, usize, usize),
+) -> Term<'input>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action12(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action17(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action24<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Name<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, ::std::vec::Vec<Expr<'input>>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, usize, usize),
+) -> Term<'input>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action12(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action18(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action25<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Expr<'input>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, usize, usize),
+) -> Term<'input>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action12(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action5(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action26<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Expr<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Term<'input>, usize),
+) -> Expr<'input>
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action11(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action19(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action27<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Term<'input>, usize),
+) -> Expr<'input>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action11(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action20(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action28<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+) -> Name<'input>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action11(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action21(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action29<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, i32, usize),
+) -> Term<'input>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action11(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action22(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action30<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Name<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, &'input str, usize),
+) -> Term<'input>
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action11(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action23(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action31<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Name<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, ::std::vec::Vec<Expr<'input>>, usize),
+    __3: (usize, &'input str, usize),
+) -> Term<'input>
+{
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action11(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action24(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action32<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Expr<'input>, usize),
+    __2: (usize, &'input str, usize),
+) -> Term<'input>
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action11(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action25(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum Expr<'input> {
+    Add {
+        start: usize,
+        end: usize,
+        text: &'input str,
+        left: ::std::boxed::Box<Expr<'input>>,
+        right: ::std::boxed::Box<Term<'input>>,
+    },
+    Term {
+        start: usize,
+        end: usize,
+        text: &'input str,
+        term: ::std::boxed::Box<Term<'input>>,
+    },
+}
+
+impl<'input> __lalrpop_util::Spanned<usize> for Expr<'input> {
+    fn start(&self) -> usize {
+        match *self {
+            Expr::Add { ref start, .. } => start.clone(),
+            Expr::Term { ref start, .. } => start.clone(),
+        }
+    }
+    fn end(&self) -> usize {
+        match *self {
+            Expr::Add { ref end, .. } => end.clone(),
+            Expr::Term { ref end, .. } => end.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum Term<'input> {
+    Num {
+        start: usize,
+        end: usize,
+        text: &'input str,
+        value: i32,
+    },
+    Call {
+        start: usize,
+        end: usize,
+        text: &'input str,
+        name: ::std::boxed::Box<Name<'input>>,
+        args: ::std::vec::Vec<Expr<'input>>,
+    },
+    Paren {
+        start: usize,
+        end: usize,
+        text: &'input str,
+        expr: ::std::boxed::Box<Expr<'input>>,
+    },
+}
+
+impl<'input> __lalrpop_util::Spanned<usize> for Term<'input> {
+    fn start(&self) -> usize {
+        match *self {
+            Term::Num { ref start, .. } => start.clone(),
+            Term::Call { ref start, .. } => start.clone(),
+            Term::Paren { ref start, .. } => start.clone(),
+        }
+    }
+    fn end(&self) -> usize {
+        match *self {
+            Term::Num { ref end, .. } => end.clone(),
+            Term::Call { ref end, .. } => end.clone(),
+            Term::Paren { ref end, .. } => end.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[allow(dead_code)]
+pub struct Name<'input> {
+    pub start: usize,
+    pub end: usize,
+    pub text: &'input str,
+    pub name: &'input str,
+}
+
+impl<'input> __lalrpop_util::Spanned<usize> for Name<'input> {
+    fn start(&self) -> usize {
+        self.start.clone()
+    }
+    fn end(&self) -> usize {
+        self.end.clone()
+    }
+}
+
+pub trait __ToTriple<'input, > {
+    type Error;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),Self::Error>;
+}
+
+impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize) {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        Ok(value)
+    }
+}
+impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        value
+    }
+}
@@ -0,0 +1,9 @@
+//! The AST that the `boxed` parser builds, whose recursive variants
+//! hold their operands in a `Box`.
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Expr {
+    Num(i32),
+    Neg(Box<Expr>),
+    Add(Box<Expr>, Box<Expr>),
+}
@@ -0,0 +1,55 @@
+/* C interface to the parsers of `c_abi.lalrpop`, generated by LALRPOP. */
+
+#ifndef C_ABI_H
+#define C_ABI_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#ifndef LALRPOP_ERROR_DEFINED
+#define LALRPOP_ERROR_DEFINED
+
+/* The values of `lalrpop_error.kind`. */
+enum {
+    LALRPOP_OK = 0,
+    LALRPOP_INVALID_UTF8 = 1,
+    LALRPOP_INVALID_TOKEN = 2,
+    LALRPOP_UNRECOGNIZED_TOKEN = 3,
+    LALRPOP_UNEXPECTED_EOF = 4,
+    LALRPOP_EXTRA_TOKEN = 5,
+    LALRPOP_USER = 6,
+    LALRPOP_OTHER = 7,
+    LALRPOP_PANIC = 8,
+};
+
+/* Why a parse failed: `start` and `end` are the byte offsets of the
+   offending input. `message` belongs to Rust; free it with the
+   `_free_error` function of the grammar. */
+typedef struct lalrpop_error {
+    uint32_t kind;
+    size_t start;
+    size_t end;
+    char *message;
+} lalrpop_error;
+
+#endif
+
+/* Parses the `len` bytes of UTF-8 text at `input` as the nonterminal `Sum`.
+   Returns NULL on failure, after filling in `*error` (if `error` is
+   not NULL).
+   The result must be freed with `c_abi_free_Sum`. */
+typedef struct c_abi_Sum c_abi_Sum;
+c_abi_Sum *c_abi_parse_Sum(const uint8_t *input, size_t len, lalrpop_error *error);
+void c_abi_free_Sum(c_abi_Sum *value);
+
+void c_abi_free_error(lalrpop_error *error);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
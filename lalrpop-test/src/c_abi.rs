@@ -0,0 +1,695 @@
+// auto-generated: "lalrpop 0.15.0"
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+pub use self::__parse_table::parse_Sum;
+pub use self::__parse_table::parse_Sum_cancelable;
+pub use self::__parse_table::SumParser;
+
+mod __parse_table {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__intern_token::Token;
+    #[allow(dead_code)]
+    pub enum __Symbol<'input>
+     {
+        Term_22_2b_22(&'input str),
+        Termr_23_22_5b0_2d9_5d_2b_22_23(&'input str),
+        NtNum(i32),
+        NtSum(i32),
+        Nt____Sum(i32),
+    }
+    impl<'input> ::std::fmt::Debug for __Symbol<'input>
+    {
+        fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            match *self {
+                __Symbol::Term_22_2b_22(..) => __f.write_str("\"+\""),
+                __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(..) => __f.write_str("r#\"[0-9]+\"#"),
+                __Symbol::NtNum(..) => __f.write_str("Num"),
+                __Symbol::NtSum(..) => __f.write_str("Sum"),
+                __Symbol::Nt____Sum(..) => __f.write_str("__Sum"),
+            }
+        }
+    }
+    const __ACTION: &'static [i8] = &[
+        // State 0
+        0, 4,
+        // State 1
+        -3, 0,
+        // State 2
+        5, 0,
+        // State 3
+        -1, 0,
+        // State 4
+        0, 4,
+        // State 5
+        -2, 0,
+    ];
+    const __EOF_ACTION: &'static [i8] = &[
+        // State 0
+        0,
+        // State 1
+        -3,
+        // State 2
+        -4,
+        // State 3
+        -1,
+        // State 4
+        0,
+        // State 5
+        -2,
+    ];
+    const __GOTO: &'static [u8] = &[
+        // State 0
+        2, 3, 0,
+        // State 1
+        0, 0, 0,
+        // State 2
+        0, 0, 0,
+        // State 3
+        0, 0, 0,
+        // State 4
+        6, 0, 0,
+        // State 5
+        0, 0, 0,
+    ];
+    const __DEFAULT_ACTION: &'static [i8] = &[
+        // State 0
+        0,
+        // State 1
+        -3,
+        // State 2
+        0,
+        // State 3
+        -1,
+        // State 4
+        0,
+        // State 5
+        -2,
+    ];
+    fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+        const __TERMINAL: &'static [&'static str] = &[
+            r###""+""###,
+            r###"r#"[0-9]+"#"###,
+        ];
+        __ACTION[(__state * 2)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+            if state == 0 {
+                None
+            } else {
+                Some(terminal.to_string())
+            }
+        }).collect()
+    }
+    #[allow(dead_code)]
+    pub fn parse_Sum<
+        'input,
+    >(
+        input: &'input str,
+    ) -> Result<i32, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let mut __tokens = super::__intern_token::__Matcher::new(input);
+        let mut __states = vec![];
+        let mut __symbols = vec![];
+        __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Sum(__v) => Ok(__v), _ => unreachable!() })
+    }
+    #[allow(dead_code)]
+    pub fn parse_Sum_cancelable<
+        'input,
+    >(
+        input: &'input str,
+        __cancel: &::std::sync::atomic::AtomicBool,
+    ) -> Result<i32, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let mut __tokens = super::__intern_token::__Matcher::new(input);
+        let mut __states = vec![];
+        let mut __symbols = vec![];
+        __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Sum(__v) => Ok(__v), _ => unreachable!() })
+    }
+
+    #[allow(dead_code)]
+    pub struct SumParser {
+        __states: ::std::vec::Vec<i32>,
+        __symbols: __lalrpop_util::stack::Recycled,
+        __cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+    }
+
+    #[allow(dead_code)]
+    impl SumParser {
+        pub fn new() -> SumParser {
+            SumParser { __states: vec![], __symbols: __lalrpop_util::stack::Recycled::new(), __cancel: None }
+        }
+
+        pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+            self.__cancel = Some(flag);
+        }
+
+        pub fn parse_in_place<'input>(
+            &mut self,
+            input: &'input str,
+        ) -> Result<i32, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+        {
+            let mut __tokens = super::__intern_token::__Matcher::new(input);
+            let mut __symbols = self.__symbols.take();
+            let __result = __parse::<>(input, 0, &mut __tokens, &mut self.__states, &mut __symbols, self.__cancel.as_ref().map(|c| &**c)).and_then(|__v| match __v { __Symbol::Nt____Sum(__v) => Ok(__v), _ => unreachable!() });
+            self.__symbols.put(__symbols);
+            __result
+        }
+    }
+
+    fn __parse<
+        'input,
+    >(
+        input: &'input str,
+        __start_state: i32,
+        mut __tokens: &mut Iterator<Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+        mut __states: &mut ::std::vec::Vec<i32>,
+        mut __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        __cancel: Option<&::std::sync::atomic::AtomicBool>,
+    ) -> Result<__Symbol<'input>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        __states.clear();
+        __states.push(__start_state);
+        __symbols.clear();
+        let mut __integer;
+        let mut __lookahead;
+        let __last_location: &mut usize = &mut Default::default();
+        let mut __default_reduced = [0_usize; 16];
+        let mut __default_reduced_len = 0;
+        '__shift: loop {
+            loop {
+                let __state = *__states.last().unwrap() as usize;
+                let __action = __DEFAULT_ACTION[__state] as i32;
+                if __action == 0 {
+                    break;
+                }
+                if __default_reduced_len < 16 {
+                    __default_reduced[__default_reduced_len] = __state;
+                    __default_reduced_len += 1;
+                }
+                if let Some(__flag) = __cancel {
+                    if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                        return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                    }
+                }
+                if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                    return r;
+                }
+            }
+            __lookahead = match __tokens.next() {
+                Some(Ok(v)) => v,
+                None => break '__shift,
+                Some(Err(e)) => return Err(e),
+            };
+            *__last_location = __lookahead.2.clone();
+            __integer = match __lookahead.1 {
+                Token(1, _) if true => 0,
+                Token(0, _) if true => 1,
+                _ => {
+                    let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| true).unwrap_or(*__states.last().unwrap() as usize);
+                    let __expected = __expected_tokens(__state);
+                    let __error = match Some(__lookahead) {
+                        Some(__token) => {
+                            __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                        }
+                        None => {
+                            __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                        }
+                    };
+                    return Err(__error);
+                }
+            };
+            '__inner: loop {
+                let __state = *__states.last().unwrap() as usize;
+                let __action = __ACTION[__state * 2 + __integer] as i32;
+                if __action > 0 {
+                    if let Some(__flag) = __cancel {
+                        if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                            return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                        }
+                    }
+                    let __symbol = match __integer {
+                        0 => match __lookahead.1 {
+                            Token(1, __tok0) => __Symbol::Term_22_2b_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        1 => match __lookahead.1 {
+                            Token(0, __tok0) => __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        _ => unreachable!(),
+                    };
+                    __states.push(__action - 1);
+                    __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                    __default_reduced_len = 0;
+                    continue '__shift;
+                } else if __action < 0 {
+                    if let Some(__flag) = __cancel {
+                        if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                            return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                        }
+                    }
+                    if let Some(r) = __reduce(input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                        if r.is_err() {
+                            return r;
+                        }
+                        return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] });
+                    }
+                } else {
+                    let mut __err_lookahead = Some(__lookahead);
+                    let mut __err_integer: Option<usize> = Some(__integer);
+                    let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 2 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                    let __expected = __expected_tokens(__state);
+                    let __error = match __err_lookahead {
+                        Some(__token) => {
+                            if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                            } else {
+                                __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                            }
+                        }
+                        None => {
+                            __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                        }
+                    };
+                    return Err(__error)
+                }
+            }
+        }
+        loop {
+            let __state = *__states.last().unwrap() as usize;
+            let __action = __EOF_ACTION[__state] as i32;
+            if __action < 0 {
+                if let Some(__flag) = __cancel {
+                    if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                        return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                    }
+                }
+                if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                    return r;
+                }
+            } else {
+                let mut __err_lookahead = None;
+                let mut __err_integer: Option<usize> = None;
+                let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 2 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                let __expected = __expected_tokens(__state);
+                let __error = match __err_lookahead {
+                    Some(__token) => {
+                        if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                            __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                        } else {
+                            __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                        }
+                    }
+                    None => {
+                        __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                    }
+                };
+                return Err(__error)
+            }
+        }
+    }
+    fn __accepts<
+        'input,
+    >(
+        input: &'input str,
+        __error_state: i32,
+        __states: & [i32],
+        __opt_integer: Option<usize>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> bool
+    {
+        let mut __states = __states.to_vec();
+        __states.push(__error_state);
+        loop {
+            let mut __states_len = __states.len();
+            let __top = __states[__states_len - 1];
+            let __action = match __opt_integer {
+                None => __EOF_ACTION[__top as usize] as i32,
+                Some(__integer) => __ACTION[(__top * 2) as usize + __integer] as i32,
+            };
+            if __action == 0 { return false; }
+            if __action > 0 { return true; }
+            let (__to_pop, __nt) = match -__action {
+                1 => {
+                    (1, 0)
+                }
+                2 => {
+                    (3, 1)
+                }
+                3 => {
+                    (1, 1)
+                }
+                4 => return true,
+                _ => panic!("invalid action code {}", __action)
+            };
+            __states_len -= __to_pop;
+            __states.truncate(__states_len);
+            let __top = __states[__states_len - 1];
+            let __next_state = __GOTO[(__top * 3 + __nt) as usize] as i32 - 1;
+            __states.push(__next_state);
+        }
+    }
+    pub fn __reduce<
+        'input,
+    >(
+        input: &'input str,
+        __action: i32,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Option<Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        let __nonterminal = match -__action {
+            1 => match __reduce_Num_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            2 => match __reduce_Sum_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            3 => match __reduce_Sum_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            4 => match __reduce_____Sum_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            _ => panic!("invalid action code {}", __action)
+        };
+        let __state = *__states.last().unwrap() as usize;
+        let __next_state = __GOTO[__state * 3 + __nonterminal] as i32 - 1;
+        __states.push(__next_state);
+        None
+    }
+
+    fn __reduce_Num_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Num = r#"[0-9]+"# => ActionFn(3);
+        let __sym0 = __pop_Termr_23_22_5b0_2d9_5d_2b_22_23(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action3::<>(input, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtNum(__nt), __end));
+        Ok(0)
+    }
+
+    fn __reduce_Sum_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Sum = Sum, "+", Num => ActionFn(1);
+        let __sym2 = __pop_NtNum(__symbols);
+        let __sym1 = __pop_Term_22_2b_22(__symbols);
+        let __sym0 = __pop_NtSum(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action1::<>(input, __sym0, __sym1, __sym2);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 3);
+        __symbols.push((__start, __Symbol::NtSum(__nt), __end));
+        Ok(1)
+    }
+
+    fn __reduce_Sum_1<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Sum = Num => ActionFn(2);
+        let __sym0 = __pop_NtNum(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action2::<>(input, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtSum(__nt), __end));
+        Ok(1)
+    }
+
+    fn __reduce_____Sum_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // __Sum = Sum => ActionFn(0);
+        let __sym0 = __pop_NtSum(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action0::<>(input, __sym0);
+        Err(Ok(__Symbol::Nt____Sum(__nt)))
+    }
+    fn __pop_Term_22_2b_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2b_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_5b0_2d9_5d_2b_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtNum<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, i32, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtNum(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtSum<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, i32, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtSum(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____Sum<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, i32, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Sum(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+}
+mod __intern_token {
+    #![allow(unused_imports)]
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    extern crate regex as __regex;
+    use std::fmt as __fmt;
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Token<'input>(pub usize, pub &'input str);
+    impl<'a> __fmt::Display for Token<'a> {
+        fn fmt(&self, formatter: &mut __fmt::Formatter) -> Result<(), __fmt::Error> {
+            __fmt::Display::fmt(self.1, formatter)
+        }
+    }
+
+    pub struct __Matcher<'input> {
+        text: &'input str,
+        consumed: usize,
+        regex_set: __regex::RegexSet,
+        regex_vec: Vec<__regex::Regex>,
+    }
+
+    impl<'input> __Matcher<'input> {
+        pub fn new(s: &'input str) -> __Matcher<'input> {
+            let __strs: &[&str] = &[
+                "^(?u:[0-9])+",
+                "^(?u:\\+)",
+            ];
+            let __regex_set = __regex::RegexSet::new(__strs).unwrap();
+            let __regex_vec = vec![
+                __regex::Regex::new("^(?u:[0-9])+").unwrap(),
+                __regex::Regex::new("^(?u:\\+)").unwrap(),
+            ];
+            __Matcher {
+                text: s,
+                consumed: 0,
+                regex_set: __regex_set,
+                regex_vec: __regex_vec,
+            }
+        }
+    }
+
+    impl<'input> Iterator for __Matcher<'input> {
+        type Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize,Token<'input>,&'static str>>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let __text = self.text.trim_left();
+            let __whitespace = self.text.len() - __text.len();
+            let __start_offset = self.consumed + __whitespace;
+            if __text.is_empty() {
+                self.text = __text;
+                self.consumed = __start_offset;
+                None
+            } else {
+                let __matches = self.regex_set.matches(__text);
+                if !__matches.matched_any() {
+                    Some(Err(__lalrpop_util::ParseError::InvalidToken {
+                        location: __start_offset,
+                    }))
+                } else {
+                    let mut __longest_match = 0;
+                    let mut __index = 0;
+                    for __i in 0 .. 2 {
+                        if __matches.matched(__i) {
+                            let __match = self.regex_vec[__i].find(__text).unwrap();
+                            let __len = __match.end();
+                            if __len >= __longest_match {
+                                __longest_match = __len;
+                                __index = __i;
+                            }
+                        }
+                    }
+                    let __result = &__text[..__longest_match];
+                    let __remaining = &__text[__longest_match..];
+                    let __end_offset = __start_offset + __longest_match;
+                    self.text = __remaining;
+                    self.consumed = __end_offset;
+                    Some(Ok((__start_offset, Token(__index, __result), __end_offset)))
+                }
+            }
+        }
+    }
+}
+pub use self::__intern_token::Token;
+
+#[allow(unused_variables)]
+fn __action0<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, i32, usize),
+) -> i32
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, i32, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, i32, usize),
+) -> i32
+{
+    //@ src/c_abi.lalrpop:8:28 (1 lines)
+    l + r
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, i32, usize),
+) -> i32
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> i32
+{
+    //@ src/c_abi.lalrpop:12:25 (1 lines)
+    i32::from_str_radix(__0, 10).unwrap()
+}
+
+pub trait __ToTriple<'input, > {
+    type Error;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),Self::Error>;
+}
+
+impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize) {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        Ok(value)
+    }
+}
+impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        value
+    }
+}
+
+// C entry points; see `lalrpop_util::ffi`.
+
+#[no_mangle]
+#[allow(non_snake_case)]
+pub unsafe extern "C" fn c_abi_parse_Sum(input: *const u8, len: usize, error: *mut __lalrpop_util::ffi::Error) -> *mut ::std::os::raw::c_void {
+    __lalrpop_util::ffi::parse(input, len, error, |text| parse_Sum(text))
+}
+
+#[no_mangle]
+#[allow(non_snake_case)]
+pub unsafe extern "C" fn c_abi_free_Sum(value: *mut ::std::os::raw::c_void) {
+    __lalrpop_util::ffi::free::<i32>(value)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn c_abi_free_error(error: *mut __lalrpop_util::ffi::Error) {
+    __lalrpop_util::ffi::free_error(error)
+}
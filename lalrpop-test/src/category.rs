@@ -0,0 +1,2601 @@
+// auto-generated: "lalrpop 0.15.0"
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+
+mod __parse__Assign {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__intern_token::Token;
+    #[allow(dead_code)]
+    pub fn parse_Assign<
+        'input,
+    >(
+        input: &'input str,
+    ) -> Result<(&'input str, &'input str, i32), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let __ascent = __ascent::parse_Assign(
+            input,
+        );
+        let __parse_table = __parse_table::parse_Assign(
+            input,
+        );
+        assert_eq!(__ascent, __parse_table);
+        return __ascent;
+    }
+    mod __ascent {
+
+        mod __parse__Assign {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__intern_token::Token;
+            #[allow(dead_code)]
+            pub fn parse_Assign<
+                'input,
+            >(
+                input: &'input str,
+            ) -> Result<(&'input str, &'input str, i32), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let __last_location = ::std::cell::RefCell::new(Default::default());
+                let mut __tokens = __tokens.inspect(|t| if let Ok(ref t) = *t { *__last_location.borrow_mut() = t.2.clone(); });
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                let mut __extra = None;
+                let __result = __state0(input, &mut __tokens, __lookahead, &mut __extra, ::std::marker::PhantomData::<()>);
+                if let Some((__token, __expected)) = __extra {
+                    return Err(match __result {
+                        Ok(_) => __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected },
+                        Err(_) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                    });
+                }
+                match __result {
+                    Ok((Some(__lookahead), _)) => {
+                        Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] })
+                    }
+                    Ok((None, __Nonterminal::____Assign((_, __nt, _)))) => {
+                        Ok(__nt)
+                    }
+                    Ok(_) => unreachable!(),
+                    Err(__lalrpop_util::ParseError::UnrecognizedEof { expected: __expected, .. }) => {
+                        Err(__lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.borrow().clone(), expected: __expected })
+                    }
+                    Err(__error) => Err(__error),
+                }
+            }
+
+            #[allow(dead_code)]
+            pub enum __Nonterminal<'input>
+             {
+                Assign((usize, (&'input str, &'input str, i32), usize)),
+                AssignOp((usize, &'input str, usize)),
+                Var((usize, &'input str, usize)),
+                ____Assign((usize, (&'input str, &'input str, i32), usize)),
+            }
+            impl<'input> ::std::fmt::Debug for __Nonterminal<'input>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Nonterminal::Assign(..) => __f.write_str("Assign"),
+                        __Nonterminal::AssignOp(..) => __f.write_str("AssignOp"),
+                        __Nonterminal::Var(..) => __f.write_str("Var"),
+                        __Nonterminal::____Assign(..) => __f.write_str("__Assign"),
+                    }
+                }
+            }
+
+            // State 0
+            //     AllInputs = []
+            //     OptionalInputs = []
+            //     FixedInputs = []
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Assign = (*) "x" "+=" r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+            //     Assign = (*) "x" "-=" r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+            //     Assign = (*) "x" "=" r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+            //     Assign = (*) "y" "+=" r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+            //     Assign = (*) "y" "-=" r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+            //     Assign = (*) "y" "=" r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+            //     __Assign = (*) Assign ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+            //
+            //   "x" -> S2
+            //   "y" -> S3
+            //
+            //     Assign -> S1
+            fn __state0<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(4, __tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state2(input, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(5, __tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state3(input, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""x""###.to_string(),
+                            r###""y""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Assign(__sym0) => {
+                            __result = try!(__state1(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 1
+            //     AllInputs = [Assign]
+            //     OptionalInputs = []
+            //     FixedInputs = [Assign]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(__Assign)
+            //
+            //     __Assign = Assign (*) ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+            //
+            //   [EOF] -> __Assign = Assign => ActionFn(0);
+            //
+            fn __state1<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, (&'input str, &'input str, i32), usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(input, __sym0);
+                        let __nt = __Nonterminal::____Assign((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(input, __sym0);
+                        let __nt = __Nonterminal::____Assign((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 2
+            //     AllInputs = ["x"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["x"]
+            //     WillPushLen = 2
+            //     WillPush = ["+=", r#"[0-9]+"#]
+            //     WillProduce = Some(Assign)
+            //
+            //     Assign = "x" (*) "+=" r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+            //     Assign = "x" (*) "-=" r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+            //     Assign = "x" (*) "=" r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+            //
+            //   "+=" -> S4
+            //   "-=" -> S5
+            //   "=" -> S6
+            //
+            fn __state2<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state4(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, Token(3, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""+=""###.to_string(),
+                            r###""-=""###.to_string(),
+                            r###""=""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 3
+            //     AllInputs = ["y"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["y"]
+            //     WillPushLen = 2
+            //     WillPush = ["+=", r#"[0-9]+"#]
+            //     WillProduce = Some(Assign)
+            //
+            //     Assign = "y" (*) "+=" r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+            //     Assign = "y" (*) "-=" r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+            //     Assign = "y" (*) "=" r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+            //
+            //   "+=" -> S7
+            //   "-=" -> S8
+            //   "=" -> S9
+            //
+            fn __state3<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state8(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, Token(3, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state9(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""+=""###.to_string(),
+                            r###""-=""###.to_string(),
+                            r###""=""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 4
+            //     AllInputs = ["x", "+="]
+            //     OptionalInputs = []
+            //     FixedInputs = ["x", "+="]
+            //     WillPushLen = 1
+            //     WillPush = [r#"[0-9]+"#]
+            //     WillProduce = Some(Assign)
+            //
+            //     Assign = "x" "+=" (*) r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+            //
+            //   r#"[0-9]+"# -> S10
+            //
+            fn __state4<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state10(input, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"r#"[0-9]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 5
+            //     AllInputs = ["x", "-="]
+            //     OptionalInputs = []
+            //     FixedInputs = ["x", "-="]
+            //     WillPushLen = 1
+            //     WillPush = [r#"[0-9]+"#]
+            //     WillProduce = Some(Assign)
+            //
+            //     Assign = "x" "-=" (*) r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+            //
+            //   r#"[0-9]+"# -> S11
+            //
+            fn __state5<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state11(input, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"r#"[0-9]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 6
+            //     AllInputs = ["x", "="]
+            //     OptionalInputs = []
+            //     FixedInputs = ["x", "="]
+            //     WillPushLen = 1
+            //     WillPush = [r#"[0-9]+"#]
+            //     WillProduce = Some(Assign)
+            //
+            //     Assign = "x" "=" (*) r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+            //
+            //   r#"[0-9]+"# -> S12
+            //
+            fn __state6<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state12(input, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"r#"[0-9]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 7
+            //     AllInputs = ["y", "+="]
+            //     OptionalInputs = []
+            //     FixedInputs = ["y", "+="]
+            //     WillPushLen = 1
+            //     WillPush = [r#"[0-9]+"#]
+            //     WillProduce = Some(Assign)
+            //
+            //     Assign = "y" "+=" (*) r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+            //
+            //   r#"[0-9]+"# -> S13
+            //
+            fn __state7<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state13(input, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"r#"[0-9]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 8
+            //     AllInputs = ["y", "-="]
+            //     OptionalInputs = []
+            //     FixedInputs = ["y", "-="]
+            //     WillPushLen = 1
+            //     WillPush = [r#"[0-9]+"#]
+            //     WillProduce = Some(Assign)
+            //
+            //     Assign = "y" "-=" (*) r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+            //
+            //   r#"[0-9]+"# -> S14
+            //
+            fn __state8<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state14(input, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"r#"[0-9]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 9
+            //     AllInputs = ["y", "="]
+            //     OptionalInputs = []
+            //     FixedInputs = ["y", "="]
+            //     WillPushLen = 1
+            //     WillPush = [r#"[0-9]+"#]
+            //     WillProduce = Some(Assign)
+            //
+            //     Assign = "y" "=" (*) r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+            //
+            //   r#"[0-9]+"# -> S15
+            //
+            fn __state9<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state15(input, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"r#"[0-9]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 10
+            //     AllInputs = ["x", "+=", r#"[0-9]+"#]
+            //     OptionalInputs = []
+            //     FixedInputs = ["x", "+=", r#"[0-9]+"#]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Assign)
+            //
+            //     Assign = "x" "+=" r#"[0-9]+"# (*) ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+            //
+            //   [EOF] -> Assign = "x", "+=", r#"[0-9]+"# => ActionFn(12);
+            //
+            fn __state10<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                __sym2: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action12::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Assign((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action12::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Assign((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 11
+            //     AllInputs = ["x", "-=", r#"[0-9]+"#]
+            //     OptionalInputs = []
+            //     FixedInputs = ["x", "-=", r#"[0-9]+"#]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Assign)
+            //
+            //     Assign = "x" "-=" r#"[0-9]+"# (*) ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+            //
+            //   [EOF] -> Assign = "x", "-=", r#"[0-9]+"# => ActionFn(14);
+            //
+            fn __state11<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                __sym2: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action14::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Assign((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action14::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Assign((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 12
+            //     AllInputs = ["x", "=", r#"[0-9]+"#]
+            //     OptionalInputs = []
+            //     FixedInputs = ["x", "=", r#"[0-9]+"#]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Assign)
+            //
+            //     Assign = "x" "=" r#"[0-9]+"# (*) ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+            //
+            //   [EOF] -> Assign = "x", "=", r#"[0-9]+"# => ActionFn(10);
+            //
+            fn __state12<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                __sym2: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action10::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Assign((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action10::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Assign((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 13
+            //     AllInputs = ["y", "+=", r#"[0-9]+"#]
+            //     OptionalInputs = []
+            //     FixedInputs = ["y", "+=", r#"[0-9]+"#]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Assign)
+            //
+            //     Assign = "y" "+=" r#"[0-9]+"# (*) ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+            //
+            //   [EOF] -> Assign = "y", "+=", r#"[0-9]+"# => ActionFn(13);
+            //
+            fn __state13<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                __sym2: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action13::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Assign((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action13::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Assign((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 14
+            //     AllInputs = ["y", "-=", r#"[0-9]+"#]
+            //     OptionalInputs = []
+            //     FixedInputs = ["y", "-=", r#"[0-9]+"#]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Assign)
+            //
+            //     Assign = "y" "-=" r#"[0-9]+"# (*) ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+            //
+            //   [EOF] -> Assign = "y", "-=", r#"[0-9]+"# => ActionFn(15);
+            //
+            fn __state14<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                __sym2: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action15::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Assign((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action15::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Assign((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 15
+            //     AllInputs = ["y", "=", r#"[0-9]+"#]
+            //     OptionalInputs = []
+            //     FixedInputs = ["y", "=", r#"[0-9]+"#]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Assign)
+            //
+            //     Assign = "y" "=" r#"[0-9]+"# (*) ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+            //
+            //   [EOF] -> Assign = "y", "=", r#"[0-9]+"# => ActionFn(11);
+            //
+            fn __state15<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                __sym2: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action11::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Assign((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action11::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Assign((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+        }
+        pub use self::__parse__Assign::parse_Assign;
+    }
+    mod __parse_table {
+
+        mod __parse_table {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__intern_token::Token;
+            #[allow(dead_code)]
+            pub enum __Symbol<'input>
+             {
+                Term_22_2b_3d_22(&'input str),
+                Term_22_2d_3d_22(&'input str),
+                Term_22_3d_22(&'input str),
+                Term_22x_22(&'input str),
+                Term_22y_22(&'input str),
+                Termr_23_22_5b0_2d9_5d_2b_22_23(&'input str),
+                NtAssign((&'input str, &'input str, i32)),
+                NtAssignOp(&'input str),
+                NtVar(&'input str),
+                Nt____Assign((&'input str, &'input str, i32)),
+            }
+            impl<'input> ::std::fmt::Debug for __Symbol<'input>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Symbol::Term_22_2b_3d_22(..) => __f.write_str("\"+=\""),
+                        __Symbol::Term_22_2d_3d_22(..) => __f.write_str("\"-=\""),
+                        __Symbol::Term_22_3d_22(..) => __f.write_str("\"=\""),
+                        __Symbol::Term_22x_22(..) => __f.write_str("\"x\""),
+                        __Symbol::Term_22y_22(..) => __f.write_str("\"y\""),
+                        __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(..) => __f.write_str("r#\"[0-9]+\"#"),
+                        __Symbol::NtAssign(..) => __f.write_str("Assign"),
+                        __Symbol::NtAssignOp(..) => __f.write_str("AssignOp"),
+                        __Symbol::NtVar(..) => __f.write_str("Var"),
+                        __Symbol::Nt____Assign(..) => __f.write_str("__Assign"),
+                    }
+                }
+            }
+            const __ACTION: &'static [i8] = &[
+                // State 0
+                //     Assign = (*) "x" "+=" r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+                //     Assign = (*) "x" "-=" r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+                //     Assign = (*) "x" "=" r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+                //     Assign = (*) "y" "+=" r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+                //     Assign = (*) "y" "-=" r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+                //     Assign = (*) "y" "=" r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+                //     __Assign = (*) Assign ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+                0,  // on "+=", error
+                0,  // on "-=", error
+                0,  // on "=", error
+                3,  // on "x", goto 2
+                4,  // on "y", goto 3
+                0,  // on r#"[0-9]+"#, error
+
+                // State 1
+                //     __Assign = Assign (*) ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+                0,  // on "+=", error
+                0,  // on "-=", error
+                0,  // on "=", error
+                0,  // on "x", error
+                0,  // on "y", error
+                0,  // on r#"[0-9]+"#, error
+
+                // State 2
+                //     Assign = "x" (*) "+=" r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+                //     Assign = "x" (*) "-=" r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+                //     Assign = "x" (*) "=" r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+                5,  // on "+=", goto 4
+                6,  // on "-=", goto 5
+                7,  // on "=", goto 6
+                0,  // on "x", error
+                0,  // on "y", error
+                0,  // on r#"[0-9]+"#, error
+
+                // State 3
+                //     Assign = "y" (*) "+=" r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+                //     Assign = "y" (*) "-=" r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+                //     Assign = "y" (*) "=" r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+                8,  // on "+=", goto 7
+                9,  // on "-=", goto 8
+                10,  // on "=", goto 9
+                0,  // on "x", error
+                0,  // on "y", error
+                0,  // on r#"[0-9]+"#, error
+
+                // State 4
+                //     Assign = "x" "+=" (*) r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+                0,  // on "+=", error
+                0,  // on "-=", error
+                0,  // on "=", error
+                0,  // on "x", error
+                0,  // on "y", error
+                11,  // on r#"[0-9]+"#, goto 10
+
+                // State 5
+                //     Assign = "x" "-=" (*) r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+                0,  // on "+=", error
+                0,  // on "-=", error
+                0,  // on "=", error
+                0,  // on "x", error
+                0,  // on "y", error
+                12,  // on r#"[0-9]+"#, goto 11
+
+                // State 6
+                //     Assign = "x" "=" (*) r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+                0,  // on "+=", error
+                0,  // on "-=", error
+                0,  // on "=", error
+                0,  // on "x", error
+                0,  // on "y", error
+                13,  // on r#"[0-9]+"#, goto 12
+
+                // State 7
+                //     Assign = "y" "+=" (*) r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+                0,  // on "+=", error
+                0,  // on "-=", error
+                0,  // on "=", error
+                0,  // on "x", error
+                0,  // on "y", error
+                14,  // on r#"[0-9]+"#, goto 13
+
+                // State 8
+                //     Assign = "y" "-=" (*) r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+                0,  // on "+=", error
+                0,  // on "-=", error
+                0,  // on "=", error
+                0,  // on "x", error
+                0,  // on "y", error
+                15,  // on r#"[0-9]+"#, goto 14
+
+                // State 9
+                //     Assign = "y" "=" (*) r#"[0-9]+"# ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+                0,  // on "+=", error
+                0,  // on "-=", error
+                0,  // on "=", error
+                0,  // on "x", error
+                0,  // on "y", error
+                16,  // on r#"[0-9]+"#, goto 15
+
+                // State 10
+                //     Assign = "x" "+=" r#"[0-9]+"# (*) ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+                0,  // on "+=", error
+                0,  // on "-=", error
+                0,  // on "=", error
+                0,  // on "x", error
+                0,  // on "y", error
+                0,  // on r#"[0-9]+"#, error
+
+                // State 11
+                //     Assign = "x" "-=" r#"[0-9]+"# (*) ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+                0,  // on "+=", error
+                0,  // on "-=", error
+                0,  // on "=", error
+                0,  // on "x", error
+                0,  // on "y", error
+                0,  // on r#"[0-9]+"#, error
+
+                // State 12
+                //     Assign = "x" "=" r#"[0-9]+"# (*) ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+                0,  // on "+=", error
+                0,  // on "-=", error
+                0,  // on "=", error
+                0,  // on "x", error
+                0,  // on "y", error
+                0,  // on r#"[0-9]+"#, error
+
+                // State 13
+                //     Assign = "y" "+=" r#"[0-9]+"# (*) ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+                0,  // on "+=", error
+                0,  // on "-=", error
+                0,  // on "=", error
+                0,  // on "x", error
+                0,  // on "y", error
+                0,  // on r#"[0-9]+"#, error
+
+                // State 14
+                //     Assign = "y" "-=" r#"[0-9]+"# (*) ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+                0,  // on "+=", error
+                0,  // on "-=", error
+                0,  // on "=", error
+                0,  // on "x", error
+                0,  // on "y", error
+                0,  // on r#"[0-9]+"#, error
+
+                // State 15
+                //     Assign = "y" "=" r#"[0-9]+"# (*) ["+=", "-=", "=", "x", "y", r#"[0-9]+"#, EOF]
+                0,  // on "+=", error
+                0,  // on "-=", error
+                0,  // on "=", error
+                0,  // on "x", error
+                0,  // on "y", error
+                0,  // on r#"[0-9]+"#, error
+
+            ];
+            const __EOF_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // on EOF, error
+
+                // State 1
+                -12,  // on EOF, reduce `__Assign = Assign => ActionFn(0);`
+
+                // State 2
+                0,  // on EOF, error
+
+                // State 3
+                0,  // on EOF, error
+
+                // State 4
+                0,  // on EOF, error
+
+                // State 5
+                0,  // on EOF, error
+
+                // State 6
+                0,  // on EOF, error
+
+                // State 7
+                0,  // on EOF, error
+
+                // State 8
+                0,  // on EOF, error
+
+                // State 9
+                0,  // on EOF, error
+
+                // State 10
+                -3,  // on EOF, reduce `Assign = "x", "+=", r#"[0-9]+"# => ActionFn(12);`
+
+                // State 11
+                -5,  // on EOF, reduce `Assign = "x", "-=", r#"[0-9]+"# => ActionFn(14);`
+
+                // State 12
+                -1,  // on EOF, reduce `Assign = "x", "=", r#"[0-9]+"# => ActionFn(10);`
+
+                // State 13
+                -4,  // on EOF, reduce `Assign = "y", "+=", r#"[0-9]+"# => ActionFn(13);`
+
+                // State 14
+                -6,  // on EOF, reduce `Assign = "y", "-=", r#"[0-9]+"# => ActionFn(15);`
+
+                // State 15
+                -2,  // on EOF, reduce `Assign = "y", "=", r#"[0-9]+"# => ActionFn(11);`
+
+            ];
+            const __GOTO: &'static [u8] = &[
+                // State 0
+                2,  // on Assign, goto 1
+                0,  // on AssignOp, error
+                0,  // on Var, error
+                0,  // on __Assign, error
+
+                // State 1
+                0,  // on Assign, error
+                0,  // on AssignOp, error
+                0,  // on Var, error
+                0,  // on __Assign, error
+
+                // State 2
+                0,  // on Assign, error
+                0,  // on AssignOp, error
+                0,  // on Var, error
+                0,  // on __Assign, error
+
+                // State 3
+                0,  // on Assign, error
+                0,  // on AssignOp, error
+                0,  // on Var, error
+                0,  // on __Assign, error
+
+                // State 4
+                0,  // on Assign, error
+                0,  // on AssignOp, error
+                0,  // on Var, error
+                0,  // on __Assign, error
+
+                // State 5
+                0,  // on Assign, error
+                0,  // on AssignOp, error
+                0,  // on Var, error
+                0,  // on __Assign, error
+
+                // State 6
+                0,  // on Assign, error
+                0,  // on AssignOp, error
+                0,  // on Var, error
+                0,  // on __Assign, error
+
+                // State 7
+                0,  // on Assign, error
+                0,  // on AssignOp, error
+                0,  // on Var, error
+                0,  // on __Assign, error
+
+                // State 8
+                0,  // on Assign, error
+                0,  // on AssignOp, error
+                0,  // on Var, error
+                0,  // on __Assign, error
+
+                // State 9
+                0,  // on Assign, error
+                0,  // on AssignOp, error
+                0,  // on Var, error
+                0,  // on __Assign, error
+
+                // State 10
+                0,  // on Assign, error
+                0,  // on AssignOp, error
+                0,  // on Var, error
+                0,  // on __Assign, error
+
+                // State 11
+                0,  // on Assign, error
+                0,  // on AssignOp, error
+                0,  // on Var, error
+                0,  // on __Assign, error
+
+                // State 12
+                0,  // on Assign, error
+                0,  // on AssignOp, error
+                0,  // on Var, error
+                0,  // on __Assign, error
+
+                // State 13
+                0,  // on Assign, error
+                0,  // on AssignOp, error
+                0,  // on Var, error
+                0,  // on __Assign, error
+
+                // State 14
+                0,  // on Assign, error
+                0,  // on AssignOp, error
+                0,  // on Var, error
+                0,  // on __Assign, error
+
+                // State 15
+                0,  // on Assign, error
+                0,  // on AssignOp, error
+                0,  // on Var, error
+                0,  // on __Assign, error
+
+            ];
+            const __DEFAULT_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // no default reduction
+
+                // State 1
+                0,  // no default reduction
+
+                // State 2
+                0,  // no default reduction
+
+                // State 3
+                0,  // no default reduction
+
+                // State 4
+                0,  // no default reduction
+
+                // State 5
+                0,  // no default reduction
+
+                // State 6
+                0,  // no default reduction
+
+                // State 7
+                0,  // no default reduction
+
+                // State 8
+                0,  // no default reduction
+
+                // State 9
+                0,  // no default reduction
+
+                // State 10
+                -3,  // on any token, reduce `Assign = "x", "+=", r#"[0-9]+"# => ActionFn(12);`
+
+                // State 11
+                -5,  // on any token, reduce `Assign = "x", "-=", r#"[0-9]+"# => ActionFn(14);`
+
+                // State 12
+                -1,  // on any token, reduce `Assign = "x", "=", r#"[0-9]+"# => ActionFn(10);`
+
+                // State 13
+                -4,  // on any token, reduce `Assign = "y", "+=", r#"[0-9]+"# => ActionFn(13);`
+
+                // State 14
+                -6,  // on any token, reduce `Assign = "y", "-=", r#"[0-9]+"# => ActionFn(15);`
+
+                // State 15
+                -2,  // on any token, reduce `Assign = "y", "=", r#"[0-9]+"# => ActionFn(11);`
+
+            ];
+            fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+                const __TERMINAL: &'static [&'static str] = &[
+                    r###""+=""###,
+                    r###""-=""###,
+                    r###""=""###,
+                    r###""x""###,
+                    r###""y""###,
+                    r###"r#"[0-9]+"#"###,
+                ];
+                __ACTION[(__state * 6)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+                    if state == 0 {
+                        None
+                    } else {
+                        Some(terminal.to_string())
+                    }
+                }).collect()
+            }
+            #[allow(dead_code)]
+            pub fn parse_Assign<
+                'input,
+            >(
+                input: &'input str,
+            ) -> Result<(&'input str, &'input str, i32), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Assign(__v) => Ok(__v), _ => unreachable!() })
+            }
+            #[allow(dead_code)]
+            pub fn parse_Assign_cancelable<
+                'input,
+            >(
+                input: &'input str,
+                __cancel: &::std::sync::atomic::AtomicBool,
+            ) -> Result<(&'input str, &'input str, i32), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Assign(__v) => Ok(__v), _ => unreachable!() })
+            }
+
+            #[allow(dead_code)]
+            pub struct AssignParser {
+                __states: ::std::vec::Vec<i32>,
+                __symbols: __lalrpop_util::stack::Recycled,
+                __cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+            }
+
+            #[allow(dead_code)]
+            impl AssignParser {
+                pub fn new() -> AssignParser {
+                    AssignParser { __states: vec![], __symbols: __lalrpop_util::stack::Recycled::new(), __cancel: None }
+                }
+
+                pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+                    self.__cancel = Some(flag);
+                }
+
+                pub fn parse_in_place<'input>(
+                    &mut self,
+                    input: &'input str,
+                ) -> Result<(&'input str, &'input str, i32), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+                {
+                    let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                    let mut __symbols = self.__symbols.take();
+                    let __result = __parse::<>(input, 0, &mut __tokens, &mut self.__states, &mut __symbols, self.__cancel.as_ref().map(|c| &**c)).and_then(|__v| match __v { __Symbol::Nt____Assign(__v) => Ok(__v), _ => unreachable!() });
+                    self.__symbols.put(__symbols);
+                    __result
+                }
+            }
+
+            fn __parse<
+                'input,
+            >(
+                input: &'input str,
+                __start_state: i32,
+                mut __tokens: &mut Iterator<Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+                mut __states: &mut ::std::vec::Vec<i32>,
+                mut __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                __cancel: Option<&::std::sync::atomic::AtomicBool>,
+            ) -> Result<__Symbol<'input>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                __states.clear();
+                __states.push(__start_state);
+                __symbols.clear();
+                let mut __integer;
+                let mut __lookahead;
+                let __last_location: &mut usize = &mut Default::default();
+                let mut __default_reduced = [0_usize; 16];
+                let mut __default_reduced_len = 0;
+                '__shift: loop {
+                    loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __DEFAULT_ACTION[__state] as i32;
+                        if __action == 0 {
+                            break;
+                        }
+                        if __default_reduced_len < 16 {
+                            __default_reduced[__default_reduced_len] = __state;
+                            __default_reduced_len += 1;
+                        }
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    }
+                    __lookahead = match __tokens.next() {
+                        Some(Ok(v)) => v,
+                        None => break '__shift,
+                        Some(Err(e)) => return Err(e),
+                    };
+                    *__last_location = __lookahead.2.clone();
+                    __integer = match __lookahead.1 {
+                        Token(1, _) if true => 0,
+                        Token(2, _) if true => 1,
+                        Token(3, _) if true => 2,
+                        Token(4, _) if true => 3,
+                        Token(5, _) if true => 4,
+                        Token(0, _) if true => 5,
+                        _ => {
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| true).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match Some(__lookahead) {
+                                Some(__token) => {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error);
+                        }
+                    };
+                    '__inner: loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __ACTION[__state * 6 + __integer] as i32;
+                        if __action > 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                                }
+                            }
+                            let __symbol = match __integer {
+                                0 => match __lookahead.1 {
+                                    Token(1, __tok0) => __Symbol::Term_22_2b_3d_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                1 => match __lookahead.1 {
+                                    Token(2, __tok0) => __Symbol::Term_22_2d_3d_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                2 => match __lookahead.1 {
+                                    Token(3, __tok0) => __Symbol::Term_22_3d_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                3 => match __lookahead.1 {
+                                    Token(4, __tok0) => __Symbol::Term_22x_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                4 => match __lookahead.1 {
+                                    Token(5, __tok0) => __Symbol::Term_22y_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                5 => match __lookahead.1 {
+                                    Token(0, __tok0) => __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                _ => unreachable!(),
+                            };
+                            __states.push(__action - 1);
+                            __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                            __default_reduced_len = 0;
+                            continue '__shift;
+                        } else if __action < 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                                }
+                            }
+                            if let Some(r) = __reduce(input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                                if r.is_err() {
+                                    return r;
+                                }
+                                return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] });
+                            }
+                        } else {
+                            let mut __err_lookahead = Some(__lookahead);
+                            let mut __err_integer: Option<usize> = Some(__integer);
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 6 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match __err_lookahead {
+                                Some(__token) => {
+                                    if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                        __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                    } else {
+                                        __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                    }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error)
+                        }
+                    }
+                }
+                loop {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __action = __EOF_ACTION[__state] as i32;
+                    if __action < 0 {
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    } else {
+                        let mut __err_lookahead = None;
+                        let mut __err_integer: Option<usize> = None;
+                        let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 6 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                        let __expected = __expected_tokens(__state);
+                        let __error = match __err_lookahead {
+                            Some(__token) => {
+                                if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                    __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                } else {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                            }
+                            None => {
+                                __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                            }
+                        };
+                        return Err(__error)
+                    }
+                }
+            }
+            fn __accepts<
+                'input,
+            >(
+                input: &'input str,
+                __error_state: i32,
+                __states: & [i32],
+                __opt_integer: Option<usize>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> bool
+            {
+                let mut __states = __states.to_vec();
+                __states.push(__error_state);
+                loop {
+                    let mut __states_len = __states.len();
+                    let __top = __states[__states_len - 1];
+                    let __action = match __opt_integer {
+                        None => __EOF_ACTION[__top as usize] as i32,
+                        Some(__integer) => __ACTION[(__top * 6) as usize + __integer] as i32,
+                    };
+                    if __action == 0 { return false; }
+                    if __action > 0 { return true; }
+                    let (__to_pop, __nt) = match -__action {
+                        // simulate Assign = "x", "=", r#"[0-9]+"# => ActionFn(10);
+                        1 => {
+                            (3, 0)
+                        }
+                        // simulate Assign = "y", "=", r#"[0-9]+"# => ActionFn(11);
+                        2 => {
+                            (3, 0)
+                        }
+                        // simulate Assign = "x", "+=", r#"[0-9]+"# => ActionFn(12);
+                        3 => {
+                            (3, 0)
+                        }
+                        // simulate Assign = "y", "+=", r#"[0-9]+"# => ActionFn(13);
+                        4 => {
+                            (3, 0)
+                        }
+                        // simulate Assign = "x", "-=", r#"[0-9]+"# => ActionFn(14);
+                        5 => {
+                            (3, 0)
+                        }
+                        // simulate Assign = "y", "-=", r#"[0-9]+"# => ActionFn(15);
+                        6 => {
+                            (3, 0)
+                        }
+                        // simulate AssignOp = "=" => ActionFn(1);
+                        7 => {
+                            (1, 1)
+                        }
+                        // simulate AssignOp = "+=" => ActionFn(2);
+                        8 => {
+                            (1, 1)
+                        }
+                        // simulate AssignOp = "-=" => ActionFn(3);
+                        9 => {
+                            (1, 1)
+                        }
+                        // simulate Var = "x" => ActionFn(4);
+                        10 => {
+                            (1, 2)
+                        }
+                        // simulate Var = "y" => ActionFn(5);
+                        11 => {
+                            (1, 2)
+                        }
+                        // simulate __Assign = Assign => ActionFn(0);
+                        12 => return true,
+                        _ => panic!("invalid action code {}", __action)
+                    };
+                    __states_len -= __to_pop;
+                    __states.truncate(__states_len);
+                    let __top = __states[__states_len - 1];
+                    let __next_state = __GOTO[(__top * 4 + __nt) as usize] as i32 - 1;
+                    __states.push(__next_state);
+                }
+            }
+            pub fn __reduce<
+                'input,
+            >(
+                input: &'input str,
+                __action: i32,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Option<Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                let __nonterminal = match -__action {
+                    1 => match __reduce_Assign_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    2 => match __reduce_Assign_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    3 => match __reduce_Assign_2(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    4 => match __reduce_Assign_3(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    5 => match __reduce_Assign_4(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    6 => match __reduce_Assign_5(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    7 => match __reduce_AssignOp_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    8 => match __reduce_AssignOp_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    9 => match __reduce_AssignOp_2(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    10 => match __reduce_Var_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    11 => match __reduce_Var_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    12 => match __reduce_____Assign_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    _ => panic!("invalid action code {}", __action)
+                };
+                let __state = *__states.last().unwrap() as usize;
+                let __next_state = __GOTO[__state * 4 + __nonterminal] as i32 - 1;
+                __states.push(__next_state);
+                None
+            }
+
+            fn __reduce_Assign_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Assign = "x", "=", r#"[0-9]+"# => ActionFn(10);
+                let __sym2 = __pop_Termr_23_22_5b0_2d9_5d_2b_22_23(__symbols);
+                let __sym1 = __pop_Term_22_3d_22(__symbols);
+                let __sym0 = __pop_Term_22x_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action10::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtAssign(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce_Assign_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Assign = "y", "=", r#"[0-9]+"# => ActionFn(11);
+                let __sym2 = __pop_Termr_23_22_5b0_2d9_5d_2b_22_23(__symbols);
+                let __sym1 = __pop_Term_22_3d_22(__symbols);
+                let __sym0 = __pop_Term_22y_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action11::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtAssign(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce_Assign_2<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Assign = "x", "+=", r#"[0-9]+"# => ActionFn(12);
+                let __sym2 = __pop_Termr_23_22_5b0_2d9_5d_2b_22_23(__symbols);
+                let __sym1 = __pop_Term_22_2b_3d_22(__symbols);
+                let __sym0 = __pop_Term_22x_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action12::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtAssign(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce_Assign_3<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Assign = "y", "+=", r#"[0-9]+"# => ActionFn(13);
+                let __sym2 = __pop_Termr_23_22_5b0_2d9_5d_2b_22_23(__symbols);
+                let __sym1 = __pop_Term_22_2b_3d_22(__symbols);
+                let __sym0 = __pop_Term_22y_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action13::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtAssign(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce_Assign_4<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Assign = "x", "-=", r#"[0-9]+"# => ActionFn(14);
+                let __sym2 = __pop_Termr_23_22_5b0_2d9_5d_2b_22_23(__symbols);
+                let __sym1 = __pop_Term_22_2d_3d_22(__symbols);
+                let __sym0 = __pop_Term_22x_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action14::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtAssign(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce_Assign_5<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Assign = "y", "-=", r#"[0-9]+"# => ActionFn(15);
+                let __sym2 = __pop_Termr_23_22_5b0_2d9_5d_2b_22_23(__symbols);
+                let __sym1 = __pop_Term_22_2d_3d_22(__symbols);
+                let __sym0 = __pop_Term_22y_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action15::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtAssign(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce_AssignOp_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // AssignOp = "=" => ActionFn(1);
+                let __sym0 = __pop_Term_22_3d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action1::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAssignOp(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce_AssignOp_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // AssignOp = "+=" => ActionFn(2);
+                let __sym0 = __pop_Term_22_2b_3d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action2::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAssignOp(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce_AssignOp_2<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // AssignOp = "-=" => ActionFn(3);
+                let __sym0 = __pop_Term_22_2d_3d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action3::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAssignOp(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce_Var_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Var = "x" => ActionFn(4);
+                let __sym0 = __pop_Term_22x_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action4::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtVar(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce_Var_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Var = "y" => ActionFn(5);
+                let __sym0 = __pop_Term_22y_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action5::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtVar(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce_____Assign_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // __Assign = Assign => ActionFn(0);
+                let __sym0 = __pop_NtAssign(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action0::<>(input, __sym0);
+                Err(Ok(__Symbol::Nt____Assign(__nt)))
+            }
+            fn __pop_Term_22_2b_3d_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2b_3d_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_2d_3d_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2d_3d_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_3d_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_3d_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22x_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22x_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22y_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22y_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Termr_23_22_5b0_2d9_5d_2b_22_23<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtAssign<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, (&'input str, &'input str, i32), usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtAssign(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtAssignOp<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtAssignOp(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtVar<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtVar(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt____Assign<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, (&'input str, &'input str, i32), usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt____Assign(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+        }
+        pub use self::__parse_table::parse_Assign;
+        pub use self::__parse_table::parse_Assign_cancelable;
+        pub use self::__parse_table::AssignParser;
+    }
+    pub use self::__parse_table::parse_Assign_cancelable;
+    pub use self::__parse_table::AssignParser;
+}
+pub use self::__parse__Assign::parse_Assign;
+pub use self::__parse__Assign::parse_Assign_cancelable;
+pub use self::__parse__Assign::AssignParser;
+mod __intern_token {
+    #![allow(unused_imports)]
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    extern crate regex as __regex;
+    use std::fmt as __fmt;
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Token<'input>(pub usize, pub &'input str);
+    impl<'a> __fmt::Display for Token<'a> {
+        fn fmt(&self, formatter: &mut __fmt::Formatter) -> Result<(), __fmt::Error> {
+            __fmt::Display::fmt(self.1, formatter)
+        }
+    }
+
+    pub struct __Matcher<'input> {
+        text: &'input str,
+        consumed: usize,
+        regex_set: __regex::RegexSet,
+        regex_vec: Vec<__regex::Regex>,
+    }
+
+    impl<'input> __Matcher<'input> {
+        pub fn new(s: &'input str) -> __Matcher<'input> {
+            let __strs: &[&str] = &[
+                "^(?u:[0-9])+",
+                "^(?u:\\+=)",
+                "^(?u:\\-=)",
+                "^(?u:=)",
+                "^(?u:x)",
+                "^(?u:y)",
+            ];
+            let __regex_set = __regex::RegexSet::new(__strs).unwrap();
+            let __regex_vec = vec![
+                __regex::Regex::new("^(?u:[0-9])+").unwrap(),
+                __regex::Regex::new("^(?u:\\+=)").unwrap(),
+                __regex::Regex::new("^(?u:\\-=)").unwrap(),
+                __regex::Regex::new("^(?u:=)").unwrap(),
+                __regex::Regex::new("^(?u:x)").unwrap(),
+                __regex::Regex::new("^(?u:y)").unwrap(),
+            ];
+            __Matcher {
+                text: s,
+                consumed: 0,
+                regex_set: __regex_set,
+                regex_vec: __regex_vec,
+            }
+        }
+    }
+
+    impl<'input> Iterator for __Matcher<'input> {
+        type Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize,Token<'input>,&'static str>>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let __text = self.text.trim_left();
+            let __whitespace = self.text.len() - __text.len();
+            let __start_offset = self.consumed + __whitespace;
+            if __text.is_empty() {
+                self.text = __text;
+                self.consumed = __start_offset;
+                None
+            } else {
+                let __matches = self.regex_set.matches(__text);
+                if !__matches.matched_any() {
+                    Some(Err(__lalrpop_util::ParseError::InvalidToken {
+                        location: __start_offset,
+                    }))
+                } else {
+                    let mut __longest_match = 0;
+                    let mut __index = 0;
+                    for __i in 0 .. 6 {
+                        if __matches.matched(__i) {
+                            let __match = self.regex_vec[__i].find(__text).unwrap();
+                            let __len = __match.end();
+                            if __len >= __longest_match {
+                                __longest_match = __len;
+                                __index = __i;
+                            }
+                        }
+                    }
+                    let __result = &__text[..__longest_match];
+                    let __remaining = &__text[__longest_match..];
+                    let __end_offset = __start_offset + __longest_match;
+                    self.text = __remaining;
+                    self.consumed = __end_offset;
+                    Some(Ok((__start_offset, Token(__index, __result), __end_offset)))
+                }
+            }
+        }
+    }
+}
+pub use self::__intern_token::Token;
+
+#[allow(unused_variables)]
+fn __action0<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, (&'input str, &'input str, i32), usize),
+) -> (&'input str, &'input str, i32)
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> &'input str
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> &'input str
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> &'input str
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> &'input str
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> &'input str
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, &'input str, usize),
+    (_, op, _): (usize, &'input str, usize),
+    (_, n, _): (usize, &'input str, usize),
+) -> (&'input str, &'input str, i32)
+{
+    //@ src/category.lalrpop:10:44 (1 lines)
+    (v, op, n.parse().unwrap())
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, &'input str, usize),
+) -> (&'input str, &'input str, i32)
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action1(
+        input,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action6(
+        input,
+        __0,
+        __temp0,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, &'input str, usize),
+) -> (&'input str, &'input str, i32)
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action2(
+        input,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action6(
+        input,
+        __0,
+        __temp0,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action9<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, &'input str, usize),
+) -> (&'input str, &'input str, i32)
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action3(
+        input,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action6(
+        input,
+        __0,
+        __temp0,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action10<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, &'input str, usize),
+) -> (&'input str, &'input str, i32)
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action4(
+        input,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action7(
+        input,
+        __temp0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action11<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, &'input str, usize),
+) -> (&'input str, &'input str, i32)
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action5(
+        input,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action7(
+        input,
+        __temp0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action12<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, &'input str, usize),
+) -> (&'input str, &'input str, i32)
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action4(
+        input,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action8(
+        input,
+        __temp0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action13<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, &'input str, usize),
+) -> (&'input str, &'input str, i32)
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action5(
+        input,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action8(
+        input,
+        __temp0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action14<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, &'input str, usize),
+) -> (&'input str, &'input str, i32)
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action4(
+        input,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action9(
+        input,
+        __temp0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action15<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, &'input str, usize),
+) -> (&'input str, &'input str, i32)
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action5(
+        input,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action9(
+        input,
+        __temp0,
+        __1,
+        __2,
+    )
+}
+
+pub trait __ToTriple<'input, > {
+    type Error;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),Self::Error>;
+}
+
+impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize) {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        Ok(value)
+    }
+}
+impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        value
+    }
+}
@@ -0,0 +1,6739 @@
+// auto-generated: "lalrpop 0.15.0"
+use std::str::FromStr;
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+
+mod __parse__Call {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    use std::str::FromStr;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__intern_token::Token;
+    #[allow(dead_code)]
+    pub fn parse_Call<
+        'input,
+    >(
+        input: &'input str,
+    ) -> Result<(&'input str, Vec<u32>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let __ascent = __ascent::parse_Call(
+            input,
+        );
+        let __parse_table = __parse_table::parse_Call(
+            input,
+        );
+        assert_eq!(__ascent, __parse_table);
+        return __ascent;
+    }
+    mod __ascent {
+
+        mod __parse__Call {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use std::str::FromStr;
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__intern_token::Token;
+            #[allow(dead_code)]
+            pub fn parse_Call<
+                'input,
+            >(
+                input: &'input str,
+            ) -> Result<(&'input str, Vec<u32>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let __last_location = ::std::cell::RefCell::new(Default::default());
+                let mut __tokens = __tokens.inspect(|t| if let Ok(ref t) = *t { *__last_location.borrow_mut() = t.2.clone(); });
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                let mut __extra = None;
+                let __result = __state0(input, &mut __tokens, __lookahead, &mut __extra, ::std::marker::PhantomData::<()>);
+                if let Some((__token, __expected)) = __extra {
+                    return Err(match __result {
+                        Ok(_) => __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected },
+                        Err(_) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                    });
+                }
+                match __result {
+                    Ok((Some(__lookahead), _)) => {
+                        Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] })
+                    }
+                    Ok((None, __Nonterminal::____Call((_, __nt, _)))) => {
+                        Ok(__nt)
+                    }
+                    Ok(_) => unreachable!(),
+                    Err(__lalrpop_util::ParseError::UnrecognizedEof { expected: __expected, .. }) => {
+                        Err(__lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.borrow().clone(), expected: __expected })
+                    }
+                    Err(__error) => Err(__error),
+                }
+            }
+
+            #[allow(dead_code)]
+            pub enum __Nonterminal<'input>
+             {
+                _28_3cList_3e_20_22_2c_22_29((usize, Vec<u32>, usize)),
+                _28_3cList_3e_20_22_2c_22_29_2a((usize, ::std::vec::Vec<Vec<u32>>, usize)),
+                _28_3cList_3e_20_22_2c_22_29_2b((usize, ::std::vec::Vec<Vec<u32>>, usize)),
+                _28_3cNum_3e_20_22_2c_22_29((usize, u32, usize)),
+                _28_3cNum_3e_20_22_2c_22_29_2a((usize, ::std::vec::Vec<u32>, usize)),
+                _28_3cNum_3e_20_22_2c_22_29_2b((usize, ::std::vec::Vec<u32>, usize)),
+                Call((usize, (&'input str, Vec<u32>), usize)),
+                CommaTrailing_3cList_3e((usize, ::std::vec::Vec<Vec<u32>>, usize)),
+                CommaTrailing_3cNum_3e((usize, ::std::vec::Vec<u32>, usize)),
+                List((usize, Vec<u32>, usize)),
+                List_3f((usize, ::std::option::Option<Vec<u32>>, usize)),
+                Lists((usize, Vec<Vec<u32>>, usize)),
+                Num((usize, u32, usize)),
+                Num_3f((usize, ::std::option::Option<u32>, usize)),
+                ____Call((usize, (&'input str, Vec<u32>), usize)),
+                ____Lists((usize, Vec<Vec<u32>>, usize)),
+            }
+            impl<'input> ::std::fmt::Debug for __Nonterminal<'input>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Nonterminal::_28_3cList_3e_20_22_2c_22_29(..) => __f.write_str("(<List> \",\")"),
+                        __Nonterminal::_28_3cList_3e_20_22_2c_22_29_2a(..) => __f.write_str("(<List> \",\")*"),
+                        __Nonterminal::_28_3cList_3e_20_22_2c_22_29_2b(..) => __f.write_str("(<List> \",\")+"),
+                        __Nonterminal::_28_3cNum_3e_20_22_2c_22_29(..) => __f.write_str("(<Num> \",\")"),
+                        __Nonterminal::_28_3cNum_3e_20_22_2c_22_29_2a(..) => __f.write_str("(<Num> \",\")*"),
+                        __Nonterminal::_28_3cNum_3e_20_22_2c_22_29_2b(..) => __f.write_str("(<Num> \",\")+"),
+                        __Nonterminal::Call(..) => __f.write_str("Call"),
+                        __Nonterminal::CommaTrailing_3cList_3e(..) => __f.write_str("CommaTrailing<List>"),
+                        __Nonterminal::CommaTrailing_3cNum_3e(..) => __f.write_str("CommaTrailing<Num>"),
+                        __Nonterminal::List(..) => __f.write_str("List"),
+                        __Nonterminal::List_3f(..) => __f.write_str("List?"),
+                        __Nonterminal::Lists(..) => __f.write_str("Lists"),
+                        __Nonterminal::Num(..) => __f.write_str("Num"),
+                        __Nonterminal::Num_3f(..) => __f.write_str("Num?"),
+                        __Nonterminal::____Call(..) => __f.write_str("__Call"),
+                        __Nonterminal::____Lists(..) => __f.write_str("__Lists"),
+                    }
+                }
+            }
+
+            // State 0
+            //     AllInputs = []
+            //     OptionalInputs = []
+            //     FixedInputs = []
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Call = (*) r#"[a-z]+"# "(" CommaTrailing<Num> ")" ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     __Call = (*) Call ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   r#"[a-z]+"# -> S2
+            //
+            //     Call -> S1
+            fn __state0<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state2(input, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"r#"[a-z]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Call(__sym0) => {
+                            __result = try!(__state1(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 1
+            //     AllInputs = [Call]
+            //     OptionalInputs = []
+            //     FixedInputs = [Call]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(__Call)
+            //
+            //     __Call = Call (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   [EOF] -> __Call = Call => ActionFn(0);
+            //
+            fn __state1<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, (&'input str, Vec<u32>), usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(input, __sym0);
+                        let __nt = __Nonterminal::____Call((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(input, __sym0);
+                        let __nt = __Nonterminal::____Call((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 2
+            //     AllInputs = [r#"[a-z]+"#]
+            //     OptionalInputs = []
+            //     FixedInputs = [r#"[a-z]+"#]
+            //     WillPushLen = 3
+            //     WillPush = ["(", CommaTrailing<Num>, ")"]
+            //     WillProduce = Some(Call)
+            //
+            //     Call = r#"[a-z]+"# (*) "(" CommaTrailing<Num> ")" ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "(" -> S3
+            //
+            fn __state2<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state3(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 3
+            //     AllInputs = [r#"[a-z]+"#, "("]
+            //     OptionalInputs = []
+            //     FixedInputs = [r#"[a-z]+"#, "("]
+            //     WillPushLen = 2
+            //     WillPush = [CommaTrailing<Num>, ")"]
+            //     WillProduce = Some(Call)
+            //
+            //     (<Num> ",")+ = (*) (<Num> ",")+ Num "," ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     (<Num> ",")+ = (*) Num "," ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Call = r#"[a-z]+"# "(" (*) CommaTrailing<Num> ")" ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     CommaTrailing<Num> = (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     CommaTrailing<Num> = (*) (<Num> ",")+ ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     CommaTrailing<Num> = (*) (<Num> ",")+ Num ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     CommaTrailing<Num> = (*) Num ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Num = (*) r#"[0-9]+"# ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   r#"[0-9]+"# -> S7
+            //   [")"] -> CommaTrailing<Num> =  => ActionFn(35);
+            //
+            //     (<Num> ",")+ -> S4
+            //     CommaTrailing<Num> -> S5
+            //     Num -> S6
+            fn __state3<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((_, Token(3, _), _)) => {
+                        let __start = __sym1.2.clone();
+                        let __end = __lookahead.as_ref().map(|o| o.0.clone()).unwrap_or_else(|| __start.clone());
+                        let __nt = super::super::super::__action35::<>(input, &__start, &__end);
+                        let __nt = __Nonterminal::CommaTrailing_3cNum_3e((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::_28_3cNum_3e_20_22_2c_22_29_2b(__sym2) => {
+                            __result = try!(__state4(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::CommaTrailing_3cNum_3e(__sym2) => {
+                            __result = try!(__state5(input, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        __Nonterminal::Num(__sym2) => {
+                            __result = try!(__state6(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 4
+            //     AllInputs = [(<Num> ",")+]
+            //     OptionalInputs = []
+            //     FixedInputs = [(<Num> ",")+]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     (<Num> ",")+ = (<Num> ",")+ (*) Num "," ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     CommaTrailing<Num> = (<Num> ",")+ (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     CommaTrailing<Num> = (<Num> ",")+ (*) Num ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Num = (*) r#"[0-9]+"# ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   r#"[0-9]+"# -> S7
+            //   [")"] -> CommaTrailing<Num> = (<Num> ",")+ => ActionFn(37);
+            //
+            //     Num -> S8
+            fn __state4<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, ::std::vec::Vec<u32>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((_, Token(3, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action37::<>(input, __sym0);
+                        let __nt = __Nonterminal::CommaTrailing_3cNum_3e((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Num(__sym1) => {
+                            __result = try!(__state8(input, __tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 5
+            //     AllInputs = [r#"[a-z]+"#, "(", CommaTrailing<Num>]
+            //     OptionalInputs = []
+            //     FixedInputs = [r#"[a-z]+"#, "(", CommaTrailing<Num>]
+            //     WillPushLen = 1
+            //     WillPush = [")"]
+            //     WillProduce = Some(Call)
+            //
+            //     Call = r#"[a-z]+"# "(" CommaTrailing<Num> (*) ")" ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   ")" -> S9
+            //
+            fn __state5<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                __sym2: (usize, ::std::vec::Vec<u32>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(3, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state9(input, __tokens, __extra, __sym0, __sym1, __sym2, __sym3, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 6
+            //     AllInputs = [Num]
+            //     OptionalInputs = []
+            //     FixedInputs = [Num]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     (<Num> ",")+ = Num (*) "," ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     CommaTrailing<Num> = Num (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "," -> S10
+            //   [")"] -> CommaTrailing<Num> = Num => ActionFn(34);
+            //
+            fn __state6<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, u32, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(4, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state10(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((_, Token(3, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action34::<>(input, __sym0);
+                        let __nt = __Nonterminal::CommaTrailing_3cNum_3e((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###"",""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 7
+            //     AllInputs = [r#"[0-9]+"#]
+            //     OptionalInputs = []
+            //     FixedInputs = [r#"[0-9]+"#]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Num)
+            //
+            //     Num = r#"[0-9]+"# (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   [")", ","] -> Num = r#"[0-9]+"# => ActionFn(5);
+            //
+            fn __state7<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action5::<>(input, __sym0);
+                        let __nt = __Nonterminal::Num((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###"",""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 8
+            //     AllInputs = [(<Num> ",")+, Num]
+            //     OptionalInputs = []
+            //     FixedInputs = [(<Num> ",")+, Num]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     (<Num> ",")+ = (<Num> ",")+ Num (*) "," ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     CommaTrailing<Num> = (<Num> ",")+ Num (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "," -> S11
+            //   [")"] -> CommaTrailing<Num> = (<Num> ",")+, Num => ActionFn(36);
+            //
+            fn __state8<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, ::std::vec::Vec<u32>, usize),
+                __sym1: (usize, u32, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(4, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state11(input, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((_, Token(3, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action36::<>(input, __sym0, __sym1);
+                        let __nt = __Nonterminal::CommaTrailing_3cNum_3e((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###"",""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 9
+            //     AllInputs = [r#"[a-z]+"#, "(", CommaTrailing<Num>, ")"]
+            //     OptionalInputs = []
+            //     FixedInputs = [r#"[a-z]+"#, "(", CommaTrailing<Num>, ")"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Call)
+            //
+            //     Call = r#"[a-z]+"# "(" CommaTrailing<Num> ")" (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   [EOF] -> Call = r#"[a-z]+"#, "(", CommaTrailing<Num>, ")" => ActionFn(2);
+            //
+            fn __state9<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                __sym2: (usize, ::std::vec::Vec<u32>, usize),
+                __sym3: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym3.2.clone();
+                        let __nt = super::super::super::__action2::<>(input, __sym0, __sym1, __sym2, __sym3);
+                        let __nt = __Nonterminal::Call((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym3.2.clone();
+                        let __nt = super::super::super::__action2::<>(input, __sym0, __sym1, __sym2, __sym3);
+                        let __nt = __Nonterminal::Call((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 10
+            //     AllInputs = [Num, ","]
+            //     OptionalInputs = []
+            //     FixedInputs = [Num, ","]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some((<Num> ",")+)
+            //
+            //     (<Num> ",")+ = Num "," (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   [")", r#"[0-9]+"#] -> (<Num> ",")+ = Num, "," => ActionFn(26);
+            //
+            fn __state10<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, u32, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(0, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action26::<>(input, __sym0, __sym1);
+                        let __nt = __Nonterminal::_28_3cNum_3e_20_22_2c_22_29_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 11
+            //     AllInputs = [(<Num> ",")+, Num, ","]
+            //     OptionalInputs = []
+            //     FixedInputs = [(<Num> ",")+, Num, ","]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some((<Num> ",")+)
+            //
+            //     (<Num> ",")+ = (<Num> ",")+ Num "," (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   [")", r#"[0-9]+"#] -> (<Num> ",")+ = (<Num> ",")+, Num, "," => ActionFn(27);
+            //
+            fn __state11<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, ::std::vec::Vec<u32>, usize),
+                __sym1: (usize, u32, usize),
+                __sym2: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(0, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action27::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::_28_3cNum_3e_20_22_2c_22_29_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+        }
+        pub use self::__parse__Call::parse_Call;
+    }
+    mod __parse_table {
+
+        mod __parse_table {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use std::str::FromStr;
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__intern_token::Token;
+            #[allow(dead_code)]
+            pub enum __Symbol<'input>
+             {
+                Term_22_28_22(&'input str),
+                Term_22_29_22(&'input str),
+                Term_22_2c_22(&'input str),
+                Term_22_5b_22(&'input str),
+                Term_22_5d_22(&'input str),
+                Termr_23_22_5b0_2d9_5d_2b_22_23(&'input str),
+                Termr_23_22_5ba_2dz_5d_2b_22_23(&'input str),
+                Nt_28_3cList_3e_20_22_2c_22_29(Vec<u32>),
+                Nt_28_3cList_3e_20_22_2c_22_29_2a(::std::vec::Vec<Vec<u32>>),
+                Nt_28_3cList_3e_20_22_2c_22_29_2b(::std::vec::Vec<Vec<u32>>),
+                Nt_28_3cNum_3e_20_22_2c_22_29(u32),
+                Nt_28_3cNum_3e_20_22_2c_22_29_2a(::std::vec::Vec<u32>),
+                Nt_28_3cNum_3e_20_22_2c_22_29_2b(::std::vec::Vec<u32>),
+                NtCall((&'input str, Vec<u32>)),
+                NtCommaTrailing_3cList_3e(::std::vec::Vec<Vec<u32>>),
+                NtCommaTrailing_3cNum_3e(::std::vec::Vec<u32>),
+                NtList(Vec<u32>),
+                NtList_3f(::std::option::Option<Vec<u32>>),
+                NtLists(Vec<Vec<u32>>),
+                NtNum(u32),
+                NtNum_3f(::std::option::Option<u32>),
+                Nt____Call((&'input str, Vec<u32>)),
+                Nt____Lists(Vec<Vec<u32>>),
+            }
+            impl<'input> ::std::fmt::Debug for __Symbol<'input>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Symbol::Term_22_28_22(..) => __f.write_str("\"(\""),
+                        __Symbol::Term_22_29_22(..) => __f.write_str("\")\""),
+                        __Symbol::Term_22_2c_22(..) => __f.write_str("\",\""),
+                        __Symbol::Term_22_5b_22(..) => __f.write_str("\"[\""),
+                        __Symbol::Term_22_5d_22(..) => __f.write_str("\"]\""),
+                        __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(..) => __f.write_str("r#\"[0-9]+\"#"),
+                        __Symbol::Termr_23_22_5ba_2dz_5d_2b_22_23(..) => __f.write_str("r#\"[a-z]+\"#"),
+                        __Symbol::Nt_28_3cList_3e_20_22_2c_22_29(..) => __f.write_str("(<List> \",\")"),
+                        __Symbol::Nt_28_3cList_3e_20_22_2c_22_29_2a(..) => __f.write_str("(<List> \",\")*"),
+                        __Symbol::Nt_28_3cList_3e_20_22_2c_22_29_2b(..) => __f.write_str("(<List> \",\")+"),
+                        __Symbol::Nt_28_3cNum_3e_20_22_2c_22_29(..) => __f.write_str("(<Num> \",\")"),
+                        __Symbol::Nt_28_3cNum_3e_20_22_2c_22_29_2a(..) => __f.write_str("(<Num> \",\")*"),
+                        __Symbol::Nt_28_3cNum_3e_20_22_2c_22_29_2b(..) => __f.write_str("(<Num> \",\")+"),
+                        __Symbol::NtCall(..) => __f.write_str("Call"),
+                        __Symbol::NtCommaTrailing_3cList_3e(..) => __f.write_str("CommaTrailing<List>"),
+                        __Symbol::NtCommaTrailing_3cNum_3e(..) => __f.write_str("CommaTrailing<Num>"),
+                        __Symbol::NtList(..) => __f.write_str("List"),
+                        __Symbol::NtList_3f(..) => __f.write_str("List?"),
+                        __Symbol::NtLists(..) => __f.write_str("Lists"),
+                        __Symbol::NtNum(..) => __f.write_str("Num"),
+                        __Symbol::NtNum_3f(..) => __f.write_str("Num?"),
+                        __Symbol::Nt____Call(..) => __f.write_str("__Call"),
+                        __Symbol::Nt____Lists(..) => __f.write_str("__Lists"),
+                    }
+                }
+            }
+            const __ACTION: &'static [i8] = &[
+                // State 0
+                //     Call = (*) r#"[a-z]+"# "(" CommaTrailing<Num> ")" ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     __Call = (*) Call ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                0,  // on ",", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on r#"[0-9]+"#, error
+                3,  // on r#"[a-z]+"#, goto 2
+
+                // State 1
+                //     __Call = Call (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                0,  // on ",", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 2
+                //     Call = r#"[a-z]+"# (*) "(" CommaTrailing<Num> ")" ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                4,  // on "(", goto 3
+                0,  // on ")", error
+                0,  // on ",", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 3
+                //     (<Num> ",")+ = (*) (<Num> ",")+ Num "," ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     (<Num> ",")+ = (*) Num "," ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Call = r#"[a-z]+"# "(" (*) CommaTrailing<Num> ")" ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     CommaTrailing<Num> = (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     CommaTrailing<Num> = (*) (<Num> ",")+ ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     CommaTrailing<Num> = (*) (<Num> ",")+ Num ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     CommaTrailing<Num> = (*) Num ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Num = (*) r#"[0-9]+"# ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                -17,  // on ")", reduce `CommaTrailing<Num> =  => ActionFn(35);`
+                0,  // on ",", error
+                0,  // on "[", error
+                0,  // on "]", error
+                8,  // on r#"[0-9]+"#, goto 7
+                0,  // on r#"[a-z]+"#, error
+
+                // State 4
+                //     (<Num> ",")+ = (<Num> ",")+ (*) Num "," ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     CommaTrailing<Num> = (<Num> ",")+ (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     CommaTrailing<Num> = (<Num> ",")+ (*) Num ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Num = (*) r#"[0-9]+"# ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                -19,  // on ")", reduce `CommaTrailing<Num> = (<Num> ",")+ => ActionFn(37);`
+                0,  // on ",", error
+                0,  // on "[", error
+                0,  // on "]", error
+                8,  // on r#"[0-9]+"#, goto 7
+                0,  // on r#"[a-z]+"#, error
+
+                // State 5
+                //     Call = r#"[a-z]+"# "(" CommaTrailing<Num> (*) ")" ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                10,  // on ")", goto 9
+                0,  // on ",", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 6
+                //     (<Num> ",")+ = Num (*) "," ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     CommaTrailing<Num> = Num (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                -16,  // on ")", reduce `CommaTrailing<Num> = Num => ActionFn(34);`
+                11,  // on ",", goto 10
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 7
+                //     Num = r#"[0-9]+"# (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                -24,  // on ")", reduce `Num = r#"[0-9]+"# => ActionFn(5);`
+                -24,  // on ",", reduce `Num = r#"[0-9]+"# => ActionFn(5);`
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 8
+                //     (<Num> ",")+ = (<Num> ",")+ Num (*) "," ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     CommaTrailing<Num> = (<Num> ",")+ Num (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                -18,  // on ")", reduce `CommaTrailing<Num> = (<Num> ",")+, Num => ActionFn(36);`
+                12,  // on ",", goto 11
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 9
+                //     Call = r#"[a-z]+"# "(" CommaTrailing<Num> ")" (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                0,  // on ",", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 10
+                //     (<Num> ",")+ = Num "," (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                -9,  // on ")", reduce `(<Num> ",")+ = Num, "," => ActionFn(26);`
+                0,  // on ",", error
+                0,  // on "[", error
+                0,  // on "]", error
+                -9,  // on r#"[0-9]+"#, reduce `(<Num> ",")+ = Num, "," => ActionFn(26);`
+                0,  // on r#"[a-z]+"#, error
+
+                // State 11
+                //     (<Num> ",")+ = (<Num> ",")+ Num "," (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                -10,  // on ")", reduce `(<Num> ",")+ = (<Num> ",")+, Num, "," => ActionFn(27);`
+                0,  // on ",", error
+                0,  // on "[", error
+                0,  // on "]", error
+                -10,  // on r#"[0-9]+"#, reduce `(<Num> ",")+ = (<Num> ",")+, Num, "," => ActionFn(27);`
+                0,  // on r#"[a-z]+"#, error
+
+            ];
+            const __EOF_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // on EOF, error
+
+                // State 1
+                -27,  // on EOF, reduce `__Call = Call => ActionFn(0);`
+
+                // State 2
+                0,  // on EOF, error
+
+                // State 3
+                0,  // on EOF, error
+
+                // State 4
+                0,  // on EOF, error
+
+                // State 5
+                0,  // on EOF, error
+
+                // State 6
+                0,  // on EOF, error
+
+                // State 7
+                0,  // on EOF, error
+
+                // State 8
+                0,  // on EOF, error
+
+                // State 9
+                -11,  // on EOF, reduce `Call = r#"[a-z]+"#, "(", CommaTrailing<Num>, ")" => ActionFn(2);`
+
+                // State 10
+                0,  // on EOF, error
+
+                // State 11
+                0,  // on EOF, error
+
+            ];
+            const __GOTO: &'static [u8] = &[
+                // State 0
+                0,  // on (<List> ","), error
+                0,  // on (<List> ",")*, error
+                0,  // on (<List> ",")+, error
+                0,  // on (<Num> ","), error
+                0,  // on (<Num> ",")*, error
+                0,  // on (<Num> ",")+, error
+                2,  // on Call, goto 1
+                0,  // on CommaTrailing<List>, error
+                0,  // on CommaTrailing<Num>, error
+                0,  // on List, error
+                0,  // on List?, error
+                0,  // on Lists, error
+                0,  // on Num, error
+                0,  // on Num?, error
+                0,  // on __Call, error
+                0,  // on __Lists, error
+
+                // State 1
+                0,  // on (<List> ","), error
+                0,  // on (<List> ",")*, error
+                0,  // on (<List> ",")+, error
+                0,  // on (<Num> ","), error
+                0,  // on (<Num> ",")*, error
+                0,  // on (<Num> ",")+, error
+                0,  // on Call, error
+                0,  // on CommaTrailing<List>, error
+                0,  // on CommaTrailing<Num>, error
+                0,  // on List, error
+                0,  // on List?, error
+                0,  // on Lists, error
+                0,  // on Num, error
+                0,  // on Num?, error
+                0,  // on __Call, error
+                0,  // on __Lists, error
+
+                // State 2
+                0,  // on (<List> ","), error
+                0,  // on (<List> ",")*, error
+                0,  // on (<List> ",")+, error
+                0,  // on (<Num> ","), error
+                0,  // on (<Num> ",")*, error
+                0,  // on (<Num> ",")+, error
+                0,  // on Call, error
+                0,  // on CommaTrailing<List>, error
+                0,  // on CommaTrailing<Num>, error
+                0,  // on List, error
+                0,  // on List?, error
+                0,  // on Lists, error
+                0,  // on Num, error
+                0,  // on Num?, error
+                0,  // on __Call, error
+                0,  // on __Lists, error
+
+                // State 3
+                0,  // on (<List> ","), error
+                0,  // on (<List> ",")*, error
+                0,  // on (<List> ",")+, error
+                0,  // on (<Num> ","), error
+                0,  // on (<Num> ",")*, error
+                5,  // on (<Num> ",")+, goto 4
+                0,  // on Call, error
+                0,  // on CommaTrailing<List>, error
+                6,  // on CommaTrailing<Num>, goto 5
+                0,  // on List, error
+                0,  // on List?, error
+                0,  // on Lists, error
+                7,  // on Num, goto 6
+                0,  // on Num?, error
+                0,  // on __Call, error
+                0,  // on __Lists, error
+
+                // State 4
+                0,  // on (<List> ","), error
+                0,  // on (<List> ",")*, error
+                0,  // on (<List> ",")+, error
+                0,  // on (<Num> ","), error
+                0,  // on (<Num> ",")*, error
+                0,  // on (<Num> ",")+, error
+                0,  // on Call, error
+                0,  // on CommaTrailing<List>, error
+                0,  // on CommaTrailing<Num>, error
+                0,  // on List, error
+                0,  // on List?, error
+                0,  // on Lists, error
+                9,  // on Num, goto 8
+                0,  // on Num?, error
+                0,  // on __Call, error
+                0,  // on __Lists, error
+
+                // State 5
+                0,  // on (<List> ","), error
+                0,  // on (<List> ",")*, error
+                0,  // on (<List> ",")+, error
+                0,  // on (<Num> ","), error
+                0,  // on (<Num> ",")*, error
+                0,  // on (<Num> ",")+, error
+                0,  // on Call, error
+                0,  // on CommaTrailing<List>, error
+                0,  // on CommaTrailing<Num>, error
+                0,  // on List, error
+                0,  // on List?, error
+                0,  // on Lists, error
+                0,  // on Num, error
+                0,  // on Num?, error
+                0,  // on __Call, error
+                0,  // on __Lists, error
+
+                // State 6
+                0,  // on (<List> ","), error
+                0,  // on (<List> ",")*, error
+                0,  // on (<List> ",")+, error
+                0,  // on (<Num> ","), error
+                0,  // on (<Num> ",")*, error
+                0,  // on (<Num> ",")+, error
+                0,  // on Call, error
+                0,  // on CommaTrailing<List>, error
+                0,  // on CommaTrailing<Num>, error
+                0,  // on List, error
+                0,  // on List?, error
+                0,  // on Lists, error
+                0,  // on Num, error
+                0,  // on Num?, error
+                0,  // on __Call, error
+                0,  // on __Lists, error
+
+                // State 7
+                0,  // on (<List> ","), error
+                0,  // on (<List> ",")*, error
+                0,  // on (<List> ",")+, error
+                0,  // on (<Num> ","), error
+                0,  // on (<Num> ",")*, error
+                0,  // on (<Num> ",")+, error
+                0,  // on Call, error
+                0,  // on CommaTrailing<List>, error
+                0,  // on CommaTrailing<Num>, error
+                0,  // on List, error
+                0,  // on List?, error
+                0,  // on Lists, error
+                0,  // on Num, error
+                0,  // on Num?, error
+                0,  // on __Call, error
+                0,  // on __Lists, error
+
+                // State 8
+                0,  // on (<List> ","), error
+                0,  // on (<List> ",")*, error
+                0,  // on (<List> ",")+, error
+                0,  // on (<Num> ","), error
+                0,  // on (<Num> ",")*, error
+                0,  // on (<Num> ",")+, error
+                0,  // on Call, error
+                0,  // on CommaTrailing<List>, error
+                0,  // on CommaTrailing<Num>, error
+                0,  // on List, error
+                0,  // on List?, error
+                0,  // on Lists, error
+                0,  // on Num, error
+                0,  // on Num?, error
+                0,  // on __Call, error
+                0,  // on __Lists, error
+
+                // State 9
+                0,  // on (<List> ","), error
+                0,  // on (<List> ",")*, error
+                0,  // on (<List> ",")+, error
+                0,  // on (<Num> ","), error
+                0,  // on (<Num> ",")*, error
+                0,  // on (<Num> ",")+, error
+                0,  // on Call, error
+                0,  // on CommaTrailing<List>, error
+                0,  // on CommaTrailing<Num>, error
+                0,  // on List, error
+                0,  // on List?, error
+                0,  // on Lists, error
+                0,  // on Num, error
+                0,  // on Num?, error
+                0,  // on __Call, error
+                0,  // on __Lists, error
+
+                // State 10
+                0,  // on (<List> ","), error
+                0,  // on (<List> ",")*, error
+                0,  // on (<List> ",")+, error
+                0,  // on (<Num> ","), error
+                0,  // on (<Num> ",")*, error
+                0,  // on (<Num> ",")+, error
+                0,  // on Call, error
+                0,  // on CommaTrailing<List>, error
+                0,  // on CommaTrailing<Num>, error
+                0,  // on List, error
+                0,  // on List?, error
+                0,  // on Lists, error
+                0,  // on Num, error
+                0,  // on Num?, error
+                0,  // on __Call, error
+                0,  // on __Lists, error
+
+                // State 11
+                0,  // on (<List> ","), error
+                0,  // on (<List> ",")*, error
+                0,  // on (<List> ",")+, error
+                0,  // on (<Num> ","), error
+                0,  // on (<Num> ",")*, error
+                0,  // on (<Num> ",")+, error
+                0,  // on Call, error
+                0,  // on CommaTrailing<List>, error
+                0,  // on CommaTrailing<Num>, error
+                0,  // on List, error
+                0,  // on List?, error
+                0,  // on Lists, error
+                0,  // on Num, error
+                0,  // on Num?, error
+                0,  // on __Call, error
+                0,  // on __Lists, error
+
+            ];
+            const __DEFAULT_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // no default reduction
+
+                // State 1
+                0,  // no default reduction
+
+                // State 2
+                0,  // no default reduction
+
+                // State 3
+                0,  // no default reduction
+
+                // State 4
+                0,  // no default reduction
+
+                // State 5
+                0,  // no default reduction
+
+                // State 6
+                0,  // no default reduction
+
+                // State 7
+                -24,  // on any token, reduce `Num = r#"[0-9]+"# => ActionFn(5);`
+
+                // State 8
+                0,  // no default reduction
+
+                // State 9
+                -11,  // on any token, reduce `Call = r#"[a-z]+"#, "(", CommaTrailing<Num>, ")" => ActionFn(2);`
+
+                // State 10
+                -9,  // on any token, reduce `(<Num> ",")+ = Num, "," => ActionFn(26);`
+
+                // State 11
+                -10,  // on any token, reduce `(<Num> ",")+ = (<Num> ",")+, Num, "," => ActionFn(27);`
+
+            ];
+            fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+                const __TERMINAL: &'static [&'static str] = &[
+                    r###""(""###,
+                    r###"")""###,
+                    r###"",""###,
+                    r###""[""###,
+                    r###""]""###,
+                    r###"r#"[0-9]+"#"###,
+                    r###"r#"[a-z]+"#"###,
+                ];
+                __ACTION[(__state * 7)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+                    if state == 0 {
+                        None
+                    } else {
+                        Some(terminal.to_string())
+                    }
+                }).collect()
+            }
+            #[allow(dead_code)]
+            pub fn parse_Call<
+                'input,
+            >(
+                input: &'input str,
+            ) -> Result<(&'input str, Vec<u32>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Call(__v) => Ok(__v), _ => unreachable!() })
+            }
+            #[allow(dead_code)]
+            pub fn parse_Call_cancelable<
+                'input,
+            >(
+                input: &'input str,
+                __cancel: &::std::sync::atomic::AtomicBool,
+            ) -> Result<(&'input str, Vec<u32>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Call(__v) => Ok(__v), _ => unreachable!() })
+            }
+
+            #[allow(dead_code)]
+            pub struct CallParser {
+                __states: ::std::vec::Vec<i32>,
+                __symbols: __lalrpop_util::stack::Recycled,
+                __cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+            }
+
+            #[allow(dead_code)]
+            impl CallParser {
+                pub fn new() -> CallParser {
+                    CallParser { __states: vec![], __symbols: __lalrpop_util::stack::Recycled::new(), __cancel: None }
+                }
+
+                pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+                    self.__cancel = Some(flag);
+                }
+
+                pub fn parse_in_place<'input>(
+                    &mut self,
+                    input: &'input str,
+                ) -> Result<(&'input str, Vec<u32>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+                {
+                    let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                    let mut __symbols = self.__symbols.take();
+                    let __result = __parse::<>(input, 0, &mut __tokens, &mut self.__states, &mut __symbols, self.__cancel.as_ref().map(|c| &**c)).and_then(|__v| match __v { __Symbol::Nt____Call(__v) => Ok(__v), _ => unreachable!() });
+                    self.__symbols.put(__symbols);
+                    __result
+                }
+            }
+
+            fn __parse<
+                'input,
+            >(
+                input: &'input str,
+                __start_state: i32,
+                mut __tokens: &mut Iterator<Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+                mut __states: &mut ::std::vec::Vec<i32>,
+                mut __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                __cancel: Option<&::std::sync::atomic::AtomicBool>,
+            ) -> Result<__Symbol<'input>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                __states.clear();
+                __states.push(__start_state);
+                __symbols.clear();
+                let mut __integer;
+                let mut __lookahead;
+                let __last_location: &mut usize = &mut Default::default();
+                let mut __default_reduced = [0_usize; 16];
+                let mut __default_reduced_len = 0;
+                '__shift: loop {
+                    loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __DEFAULT_ACTION[__state] as i32;
+                        if __action == 0 {
+                            break;
+                        }
+                        if __default_reduced_len < 16 {
+                            __default_reduced[__default_reduced_len] = __state;
+                            __default_reduced_len += 1;
+                        }
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    }
+                    __lookahead = match __tokens.next() {
+                        Some(Ok(v)) => v,
+                        None => break '__shift,
+                        Some(Err(e)) => return Err(e),
+                    };
+                    *__last_location = __lookahead.2.clone();
+                    __integer = match __lookahead.1 {
+                        Token(2, _) if true => 0,
+                        Token(3, _) if true => 1,
+                        Token(4, _) if true => 2,
+                        Token(5, _) if true => 3,
+                        Token(6, _) if true => 4,
+                        Token(0, _) if true => 5,
+                        Token(1, _) if true => 6,
+                        _ => {
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| true).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match Some(__lookahead) {
+                                Some(__token) => {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error);
+                        }
+                    };
+                    '__inner: loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __ACTION[__state * 7 + __integer] as i32;
+                        if __action > 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                                }
+                            }
+                            let __symbol = match __integer {
+                                0 => match __lookahead.1 {
+                                    Token(2, __tok0) => __Symbol::Term_22_28_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                1 => match __lookahead.1 {
+                                    Token(3, __tok0) => __Symbol::Term_22_29_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                2 => match __lookahead.1 {
+                                    Token(4, __tok0) => __Symbol::Term_22_2c_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                3 => match __lookahead.1 {
+                                    Token(5, __tok0) => __Symbol::Term_22_5b_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                4 => match __lookahead.1 {
+                                    Token(6, __tok0) => __Symbol::Term_22_5d_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                5 => match __lookahead.1 {
+                                    Token(0, __tok0) => __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                6 => match __lookahead.1 {
+                                    Token(1, __tok0) => __Symbol::Termr_23_22_5ba_2dz_5d_2b_22_23((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                _ => unreachable!(),
+                            };
+                            __states.push(__action - 1);
+                            __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                            __default_reduced_len = 0;
+                            continue '__shift;
+                        } else if __action < 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                                }
+                            }
+                            if let Some(r) = __reduce(input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                                if r.is_err() {
+                                    return r;
+                                }
+                                return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] });
+                            }
+                        } else {
+                            let mut __err_lookahead = Some(__lookahead);
+                            let mut __err_integer: Option<usize> = Some(__integer);
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 7 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match __err_lookahead {
+                                Some(__token) => {
+                                    if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                        __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                    } else {
+                                        __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                    }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error)
+                        }
+                    }
+                }
+                loop {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __action = __EOF_ACTION[__state] as i32;
+                    if __action < 0 {
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    } else {
+                        let mut __err_lookahead = None;
+                        let mut __err_integer: Option<usize> = None;
+                        let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 7 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                        let __expected = __expected_tokens(__state);
+                        let __error = match __err_lookahead {
+                            Some(__token) => {
+                                if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                    __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                } else {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                            }
+                            None => {
+                                __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                            }
+                        };
+                        return Err(__error)
+                    }
+                }
+            }
+            fn __accepts<
+                'input,
+            >(
+                input: &'input str,
+                __error_state: i32,
+                __states: & [i32],
+                __opt_integer: Option<usize>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> bool
+            {
+                let mut __states = __states.to_vec();
+                __states.push(__error_state);
+                loop {
+                    let mut __states_len = __states.len();
+                    let __top = __states[__states_len - 1];
+                    let __action = match __opt_integer {
+                        None => __EOF_ACTION[__top as usize] as i32,
+                        Some(__integer) => __ACTION[(__top * 7) as usize + __integer] as i32,
+                    };
+                    if __action == 0 { return false; }
+                    if __action > 0 { return true; }
+                    let (__to_pop, __nt) = match -__action {
+                        // simulate (<List> ",") = List, "," => ActionFn(17);
+                        1 => {
+                            (2, 0)
+                        }
+                        // simulate (<List> ",")* =  => ActionFn(15);
+                        2 => {
+                            (0, 1)
+                        }
+                        // simulate (<List> ",")* = (<List> ",")+ => ActionFn(16);
+                        3 => {
+                            (1, 1)
+                        }
+                        // simulate (<List> ",")+ = List, "," => ActionFn(22);
+                        4 => {
+                            (2, 2)
+                        }
+                        // simulate (<List> ",")+ = (<List> ",")+, List, "," => ActionFn(23);
+                        5 => {
+                            (3, 2)
+                        }
+                        // simulate (<Num> ",") = Num, "," => ActionFn(12);
+                        6 => {
+                            (2, 3)
+                        }
+                        // simulate (<Num> ",")* =  => ActionFn(10);
+                        7 => {
+                            (0, 4)
+                        }
+                        // simulate (<Num> ",")* = (<Num> ",")+ => ActionFn(11);
+                        8 => {
+                            (1, 4)
+                        }
+                        // simulate (<Num> ",")+ = Num, "," => ActionFn(26);
+                        9 => {
+                            (2, 5)
+                        }
+                        // simulate (<Num> ",")+ = (<Num> ",")+, Num, "," => ActionFn(27);
+                        10 => {
+                            (3, 5)
+                        }
+                        // simulate Call = r#"[a-z]+"#, "(", CommaTrailing<Num>, ")" => ActionFn(2);
+                        11 => {
+                            (4, 6)
+                        }
+                        // simulate CommaTrailing<List> = List => ActionFn(30);
+                        12 => {
+                            (1, 7)
+                        }
+                        // simulate CommaTrailing<List> =  => ActionFn(31);
+                        13 => {
+                            (0, 7)
+                        }
+                        // simulate CommaTrailing<List> = (<List> ",")+, List => ActionFn(32);
+                        14 => {
+                            (2, 7)
+                        }
+                        // simulate CommaTrailing<List> = (<List> ",")+ => ActionFn(33);
+                        15 => {
+                            (1, 7)
+                        }
+                        // simulate CommaTrailing<Num> = Num => ActionFn(34);
+                        16 => {
+                            (1, 8)
+                        }
+                        // simulate CommaTrailing<Num> =  => ActionFn(35);
+                        17 => {
+                            (0, 8)
+                        }
+                        // simulate CommaTrailing<Num> = (<Num> ",")+, Num => ActionFn(36);
+                        18 => {
+                            (2, 8)
+                        }
+                        // simulate CommaTrailing<Num> = (<Num> ",")+ => ActionFn(37);
+                        19 => {
+                            (1, 8)
+                        }
+                        // simulate List = "[", CommaTrailing<Num>, "]" => ActionFn(4);
+                        20 => {
+                            (3, 9)
+                        }
+                        // simulate List? = List => ActionFn(13);
+                        21 => {
+                            (1, 10)
+                        }
+                        // simulate List? =  => ActionFn(14);
+                        22 => {
+                            (0, 10)
+                        }
+                        // simulate Lists = CommaTrailing<List> => ActionFn(3);
+                        23 => {
+                            (1, 11)
+                        }
+                        // simulate Num = r#"[0-9]+"# => ActionFn(5);
+                        24 => {
+                            (1, 12)
+                        }
+                        // simulate Num? = Num => ActionFn(8);
+                        25 => {
+                            (1, 13)
+                        }
+                        // simulate Num? =  => ActionFn(9);
+                        26 => {
+                            (0, 13)
+                        }
+                        // simulate __Call = Call => ActionFn(0);
+                        27 => return true,
+                        // simulate __Lists = Lists => ActionFn(1);
+                        28 => return true,
+                        _ => panic!("invalid action code {}", __action)
+                    };
+                    __states_len -= __to_pop;
+                    __states.truncate(__states_len);
+                    let __top = __states[__states_len - 1];
+                    let __next_state = __GOTO[(__top * 16 + __nt) as usize] as i32 - 1;
+                    __states.push(__next_state);
+                }
+            }
+            pub fn __reduce<
+                'input,
+            >(
+                input: &'input str,
+                __action: i32,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Option<Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                let __nonterminal = match -__action {
+                    1 => match __reduce__28_3cList_3e_20_22_2c_22_29_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    2 => match __reduce__28_3cList_3e_20_22_2c_22_29_2a_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    3 => match __reduce__28_3cList_3e_20_22_2c_22_29_2a_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    4 => match __reduce__28_3cList_3e_20_22_2c_22_29_2b_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    5 => match __reduce__28_3cList_3e_20_22_2c_22_29_2b_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    6 => match __reduce__28_3cNum_3e_20_22_2c_22_29_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    7 => match __reduce__28_3cNum_3e_20_22_2c_22_29_2a_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    8 => match __reduce__28_3cNum_3e_20_22_2c_22_29_2a_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    9 => match __reduce__28_3cNum_3e_20_22_2c_22_29_2b_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    10 => match __reduce__28_3cNum_3e_20_22_2c_22_29_2b_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    11 => match __reduce_Call_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    12 => match __reduce_CommaTrailing_3cList_3e_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    13 => match __reduce_CommaTrailing_3cList_3e_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    14 => match __reduce_CommaTrailing_3cList_3e_2(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    15 => match __reduce_CommaTrailing_3cList_3e_3(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    16 => match __reduce_CommaTrailing_3cNum_3e_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    17 => match __reduce_CommaTrailing_3cNum_3e_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    18 => match __reduce_CommaTrailing_3cNum_3e_2(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    19 => match __reduce_CommaTrailing_3cNum_3e_3(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    20 => match __reduce_List_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    21 => match __reduce_List_3f_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    22 => match __reduce_List_3f_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    23 => match __reduce_Lists_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    24 => match __reduce_Num_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    25 => match __reduce_Num_3f_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    26 => match __reduce_Num_3f_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    27 => match __reduce_____Call_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    28 => match __reduce_____Lists_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    _ => panic!("invalid action code {}", __action)
+                };
+                let __state = *__states.last().unwrap() as usize;
+                let __next_state = __GOTO[__state * 16 + __nonterminal] as i32 - 1;
+                __states.push(__next_state);
+                None
+            }
+
+            fn __reduce__28_3cList_3e_20_22_2c_22_29_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<List> ",") = List, "," => ActionFn(17);
+                let __sym1 = __pop_Term_22_2c_22(__symbols);
+                let __sym0 = __pop_NtList(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action17::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_3cList_3e_20_22_2c_22_29(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce__28_3cList_3e_20_22_2c_22_29_2a_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<List> ",")* =  => ActionFn(15);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action15::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_28_3cList_3e_20_22_2c_22_29_2a(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce__28_3cList_3e_20_22_2c_22_29_2a_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<List> ",")* = (<List> ",")+ => ActionFn(16);
+                let __sym0 = __pop_Nt_28_3cList_3e_20_22_2c_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action16::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::Nt_28_3cList_3e_20_22_2c_22_29_2a(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce__28_3cList_3e_20_22_2c_22_29_2b_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<List> ",")+ = List, "," => ActionFn(22);
+                let __sym1 = __pop_Term_22_2c_22(__symbols);
+                let __sym0 = __pop_NtList(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action22::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_3cList_3e_20_22_2c_22_29_2b(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce__28_3cList_3e_20_22_2c_22_29_2b_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<List> ",")+ = (<List> ",")+, List, "," => ActionFn(23);
+                let __sym2 = __pop_Term_22_2c_22(__symbols);
+                let __sym1 = __pop_NtList(__symbols);
+                let __sym0 = __pop_Nt_28_3cList_3e_20_22_2c_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action23::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::Nt_28_3cList_3e_20_22_2c_22_29_2b(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce__28_3cNum_3e_20_22_2c_22_29_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<Num> ",") = Num, "," => ActionFn(12);
+                let __sym1 = __pop_Term_22_2c_22(__symbols);
+                let __sym0 = __pop_NtNum(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action12::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_3cNum_3e_20_22_2c_22_29(__nt), __end));
+                Ok(3)
+            }
+
+            fn __reduce__28_3cNum_3e_20_22_2c_22_29_2a_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<Num> ",")* =  => ActionFn(10);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action10::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_28_3cNum_3e_20_22_2c_22_29_2a(__nt), __end));
+                Ok(4)
+            }
+
+            fn __reduce__28_3cNum_3e_20_22_2c_22_29_2a_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<Num> ",")* = (<Num> ",")+ => ActionFn(11);
+                let __sym0 = __pop_Nt_28_3cNum_3e_20_22_2c_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action11::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::Nt_28_3cNum_3e_20_22_2c_22_29_2a(__nt), __end));
+                Ok(4)
+            }
+
+            fn __reduce__28_3cNum_3e_20_22_2c_22_29_2b_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<Num> ",")+ = Num, "," => ActionFn(26);
+                let __sym1 = __pop_Term_22_2c_22(__symbols);
+                let __sym0 = __pop_NtNum(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action26::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_3cNum_3e_20_22_2c_22_29_2b(__nt), __end));
+                Ok(5)
+            }
+
+            fn __reduce__28_3cNum_3e_20_22_2c_22_29_2b_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<Num> ",")+ = (<Num> ",")+, Num, "," => ActionFn(27);
+                let __sym2 = __pop_Term_22_2c_22(__symbols);
+                let __sym1 = __pop_NtNum(__symbols);
+                let __sym0 = __pop_Nt_28_3cNum_3e_20_22_2c_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action27::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::Nt_28_3cNum_3e_20_22_2c_22_29_2b(__nt), __end));
+                Ok(5)
+            }
+
+            fn __reduce_Call_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Call = r#"[a-z]+"#, "(", CommaTrailing<Num>, ")" => ActionFn(2);
+                let __sym3 = __pop_Term_22_29_22(__symbols);
+                let __sym2 = __pop_NtCommaTrailing_3cNum_3e(__symbols);
+                let __sym1 = __pop_Term_22_28_22(__symbols);
+                let __sym0 = __pop_Termr_23_22_5ba_2dz_5d_2b_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::super::super::__action2::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtCall(__nt), __end));
+                Ok(6)
+            }
+
+            fn __reduce_CommaTrailing_3cList_3e_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // CommaTrailing<List> = List => ActionFn(30);
+                let __sym0 = __pop_NtList(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action30::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtCommaTrailing_3cList_3e(__nt), __end));
+                Ok(7)
+            }
+
+            fn __reduce_CommaTrailing_3cList_3e_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // CommaTrailing<List> =  => ActionFn(31);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action31::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::NtCommaTrailing_3cList_3e(__nt), __end));
+                Ok(7)
+            }
+
+            fn __reduce_CommaTrailing_3cList_3e_2<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // CommaTrailing<List> = (<List> ",")+, List => ActionFn(32);
+                let __sym1 = __pop_NtList(__symbols);
+                let __sym0 = __pop_Nt_28_3cList_3e_20_22_2c_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action32::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtCommaTrailing_3cList_3e(__nt), __end));
+                Ok(7)
+            }
+
+            fn __reduce_CommaTrailing_3cList_3e_3<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // CommaTrailing<List> = (<List> ",")+ => ActionFn(33);
+                let __sym0 = __pop_Nt_28_3cList_3e_20_22_2c_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action33::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtCommaTrailing_3cList_3e(__nt), __end));
+                Ok(7)
+            }
+
+            fn __reduce_CommaTrailing_3cNum_3e_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // CommaTrailing<Num> = Num => ActionFn(34);
+                let __sym0 = __pop_NtNum(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action34::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtCommaTrailing_3cNum_3e(__nt), __end));
+                Ok(8)
+            }
+
+            fn __reduce_CommaTrailing_3cNum_3e_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // CommaTrailing<Num> =  => ActionFn(35);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action35::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::NtCommaTrailing_3cNum_3e(__nt), __end));
+                Ok(8)
+            }
+
+            fn __reduce_CommaTrailing_3cNum_3e_2<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // CommaTrailing<Num> = (<Num> ",")+, Num => ActionFn(36);
+                let __sym1 = __pop_NtNum(__symbols);
+                let __sym0 = __pop_Nt_28_3cNum_3e_20_22_2c_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action36::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtCommaTrailing_3cNum_3e(__nt), __end));
+                Ok(8)
+            }
+
+            fn __reduce_CommaTrailing_3cNum_3e_3<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // CommaTrailing<Num> = (<Num> ",")+ => ActionFn(37);
+                let __sym0 = __pop_Nt_28_3cNum_3e_20_22_2c_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action37::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtCommaTrailing_3cNum_3e(__nt), __end));
+                Ok(8)
+            }
+
+            fn __reduce_List_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // List = "[", CommaTrailing<Num>, "]" => ActionFn(4);
+                let __sym2 = __pop_Term_22_5d_22(__symbols);
+                let __sym1 = __pop_NtCommaTrailing_3cNum_3e(__symbols);
+                let __sym0 = __pop_Term_22_5b_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action4::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtList(__nt), __end));
+                Ok(9)
+            }
+
+            fn __reduce_List_3f_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // List? = List => ActionFn(13);
+                let __sym0 = __pop_NtList(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action13::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtList_3f(__nt), __end));
+                Ok(10)
+            }
+
+            fn __reduce_List_3f_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // List? =  => ActionFn(14);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action14::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::NtList_3f(__nt), __end));
+                Ok(10)
+            }
+
+            fn __reduce_Lists_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Lists = CommaTrailing<List> => ActionFn(3);
+                let __sym0 = __pop_NtCommaTrailing_3cList_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action3::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtLists(__nt), __end));
+                Ok(11)
+            }
+
+            fn __reduce_Num_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Num = r#"[0-9]+"# => ActionFn(5);
+                let __sym0 = __pop_Termr_23_22_5b0_2d9_5d_2b_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action5::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtNum(__nt), __end));
+                Ok(12)
+            }
+
+            fn __reduce_Num_3f_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Num? = Num => ActionFn(8);
+                let __sym0 = __pop_NtNum(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action8::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtNum_3f(__nt), __end));
+                Ok(13)
+            }
+
+            fn __reduce_Num_3f_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Num? =  => ActionFn(9);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action9::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::NtNum_3f(__nt), __end));
+                Ok(13)
+            }
+
+            fn __reduce_____Call_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // __Call = Call => ActionFn(0);
+                let __sym0 = __pop_NtCall(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action0::<>(input, __sym0);
+                Err(Ok(__Symbol::Nt____Call(__nt)))
+            }
+
+            fn __reduce_____Lists_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // __Lists = Lists => ActionFn(1);
+                let __sym0 = __pop_NtLists(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action1::<>(input, __sym0);
+                Err(Ok(__Symbol::Nt____Lists(__nt)))
+            }
+            fn __pop_Term_22_28_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_28_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_29_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_29_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_2c_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2c_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_5b_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_5b_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_5d_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_5d_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Termr_23_22_5b0_2d9_5d_2b_22_23<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Termr_23_22_5ba_2dz_5d_2b_22_23<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Termr_23_22_5ba_2dz_5d_2b_22_23(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_28_3cList_3e_20_22_2c_22_29<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Vec<u32>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_28_3cList_3e_20_22_2c_22_29(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_28_3cList_3e_20_22_2c_22_29_2a<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, ::std::vec::Vec<Vec<u32>>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_28_3cList_3e_20_22_2c_22_29_2a(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_28_3cList_3e_20_22_2c_22_29_2b<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, ::std::vec::Vec<Vec<u32>>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_28_3cList_3e_20_22_2c_22_29_2b(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_28_3cNum_3e_20_22_2c_22_29<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, u32, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_28_3cNum_3e_20_22_2c_22_29(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_28_3cNum_3e_20_22_2c_22_29_2a<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, ::std::vec::Vec<u32>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_28_3cNum_3e_20_22_2c_22_29_2a(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_28_3cNum_3e_20_22_2c_22_29_2b<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, ::std::vec::Vec<u32>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_28_3cNum_3e_20_22_2c_22_29_2b(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtCall<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, (&'input str, Vec<u32>), usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtCall(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtCommaTrailing_3cList_3e<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, ::std::vec::Vec<Vec<u32>>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtCommaTrailing_3cList_3e(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtCommaTrailing_3cNum_3e<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, ::std::vec::Vec<u32>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtCommaTrailing_3cNum_3e(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtList<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Vec<u32>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtList(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtList_3f<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, ::std::option::Option<Vec<u32>>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtList_3f(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtLists<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Vec<Vec<u32>>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtLists(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtNum<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, u32, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtNum(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtNum_3f<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, ::std::option::Option<u32>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtNum_3f(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt____Call<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, (&'input str, Vec<u32>), usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt____Call(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt____Lists<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Vec<Vec<u32>>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt____Lists(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+        }
+        pub use self::__parse_table::parse_Call;
+        pub use self::__parse_table::parse_Call_cancelable;
+        pub use self::__parse_table::CallParser;
+    }
+    pub use self::__parse_table::parse_Call_cancelable;
+    pub use self::__parse_table::CallParser;
+}
+pub use self::__parse__Call::parse_Call;
+pub use self::__parse__Call::parse_Call_cancelable;
+pub use self::__parse__Call::CallParser;
+
+mod __parse__Lists {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    use std::str::FromStr;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__intern_token::Token;
+    #[allow(dead_code)]
+    pub fn parse_Lists<
+        'input,
+    >(
+        input: &'input str,
+    ) -> Result<Vec<Vec<u32>>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let __ascent = __ascent::parse_Lists(
+            input,
+        );
+        let __parse_table = __parse_table::parse_Lists(
+            input,
+        );
+        assert_eq!(__ascent, __parse_table);
+        return __ascent;
+    }
+    mod __ascent {
+
+        mod __parse__Lists {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use std::str::FromStr;
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__intern_token::Token;
+            #[allow(dead_code)]
+            pub fn parse_Lists<
+                'input,
+            >(
+                input: &'input str,
+            ) -> Result<Vec<Vec<u32>>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let __last_location = ::std::cell::RefCell::new(Default::default());
+                let mut __tokens = __tokens.inspect(|t| if let Ok(ref t) = *t { *__last_location.borrow_mut() = t.2.clone(); });
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                let mut __extra = None;
+                let __result = __state0(input, &mut __tokens, __lookahead, &mut __extra, ::std::marker::PhantomData::<()>);
+                if let Some((__token, __expected)) = __extra {
+                    return Err(match __result {
+                        Ok(_) => __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected },
+                        Err(_) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                    });
+                }
+                match __result {
+                    Ok((Some(__lookahead), _)) => {
+                        Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] })
+                    }
+                    Ok((None, __Nonterminal::____Lists((_, __nt, _)))) => {
+                        Ok(__nt)
+                    }
+                    Ok(_) => unreachable!(),
+                    Err(__lalrpop_util::ParseError::UnrecognizedEof { expected: __expected, .. }) => {
+                        Err(__lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.borrow().clone(), expected: __expected })
+                    }
+                    Err(__error) => Err(__error),
+                }
+            }
+
+            #[allow(dead_code)]
+            pub enum __Nonterminal<'input>
+             {
+                _28_3cList_3e_20_22_2c_22_29((usize, Vec<u32>, usize)),
+                _28_3cList_3e_20_22_2c_22_29_2a((usize, ::std::vec::Vec<Vec<u32>>, usize)),
+                _28_3cList_3e_20_22_2c_22_29_2b((usize, ::std::vec::Vec<Vec<u32>>, usize)),
+                _28_3cNum_3e_20_22_2c_22_29((usize, u32, usize)),
+                _28_3cNum_3e_20_22_2c_22_29_2a((usize, ::std::vec::Vec<u32>, usize)),
+                _28_3cNum_3e_20_22_2c_22_29_2b((usize, ::std::vec::Vec<u32>, usize)),
+                Call((usize, (&'input str, Vec<u32>), usize)),
+                CommaTrailing_3cList_3e((usize, ::std::vec::Vec<Vec<u32>>, usize)),
+                CommaTrailing_3cNum_3e((usize, ::std::vec::Vec<u32>, usize)),
+                List((usize, Vec<u32>, usize)),
+                List_3f((usize, ::std::option::Option<Vec<u32>>, usize)),
+                Lists((usize, Vec<Vec<u32>>, usize)),
+                Num((usize, u32, usize)),
+                Num_3f((usize, ::std::option::Option<u32>, usize)),
+                ____Call((usize, (&'input str, Vec<u32>), usize)),
+                ____Lists((usize, Vec<Vec<u32>>, usize)),
+            }
+            impl<'input> ::std::fmt::Debug for __Nonterminal<'input>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Nonterminal::_28_3cList_3e_20_22_2c_22_29(..) => __f.write_str("(<List> \",\")"),
+                        __Nonterminal::_28_3cList_3e_20_22_2c_22_29_2a(..) => __f.write_str("(<List> \",\")*"),
+                        __Nonterminal::_28_3cList_3e_20_22_2c_22_29_2b(..) => __f.write_str("(<List> \",\")+"),
+                        __Nonterminal::_28_3cNum_3e_20_22_2c_22_29(..) => __f.write_str("(<Num> \",\")"),
+                        __Nonterminal::_28_3cNum_3e_20_22_2c_22_29_2a(..) => __f.write_str("(<Num> \",\")*"),
+                        __Nonterminal::_28_3cNum_3e_20_22_2c_22_29_2b(..) => __f.write_str("(<Num> \",\")+"),
+                        __Nonterminal::Call(..) => __f.write_str("Call"),
+                        __Nonterminal::CommaTrailing_3cList_3e(..) => __f.write_str("CommaTrailing<List>"),
+                        __Nonterminal::CommaTrailing_3cNum_3e(..) => __f.write_str("CommaTrailing<Num>"),
+                        __Nonterminal::List(..) => __f.write_str("List"),
+                        __Nonterminal::List_3f(..) => __f.write_str("List?"),
+                        __Nonterminal::Lists(..) => __f.write_str("Lists"),
+                        __Nonterminal::Num(..) => __f.write_str("Num"),
+                        __Nonterminal::Num_3f(..) => __f.write_str("Num?"),
+                        __Nonterminal::____Call(..) => __f.write_str("__Call"),
+                        __Nonterminal::____Lists(..) => __f.write_str("__Lists"),
+                    }
+                }
+            }
+
+            // State 0
+            //     AllInputs = []
+            //     OptionalInputs = []
+            //     FixedInputs = []
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     (<List> ",")+ = (*) (<List> ",")+ List "," ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     (<List> ",")+ = (*) List "," ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     CommaTrailing<List> = (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     CommaTrailing<List> = (*) (<List> ",")+ ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     CommaTrailing<List> = (*) (<List> ",")+ List ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     CommaTrailing<List> = (*) List ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     List = (*) "[" CommaTrailing<Num> "]" ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Lists = (*) CommaTrailing<List> ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     __Lists = (*) Lists ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "[" -> S5
+            //   [EOF] -> CommaTrailing<List> =  => ActionFn(31);
+            //
+            //     (<List> ",")+ -> S1
+            //     CommaTrailing<List> -> S2
+            //     List -> S3
+            //     Lists -> S4
+            fn __state0<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(5, __tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(input, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    None => {
+                        let __start: usize = ::std::default::Default::default();
+                        let __end = __lookahead.as_ref().map(|o| o.0.clone()).unwrap_or_else(|| __start.clone());
+                        let __nt = super::super::super::__action31::<>(input, &__start, &__end);
+                        let __nt = __Nonterminal::CommaTrailing_3cList_3e((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""[""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start: usize = ::std::default::Default::default();
+                        let __end = __lookahead.as_ref().map(|o| o.0.clone()).unwrap_or_else(|| __start.clone());
+                        let __nt = super::super::super::__action31::<>(input, &__start, &__end);
+                        let __nt = __Nonterminal::CommaTrailing_3cList_3e((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::_28_3cList_3e_20_22_2c_22_29_2b(__sym0) => {
+                            __result = try!(__state1(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::CommaTrailing_3cList_3e(__sym0) => {
+                            __result = try!(__state2(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::List(__sym0) => {
+                            __result = try!(__state3(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Lists(__sym0) => {
+                            __result = try!(__state4(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 1
+            //     AllInputs = [(<List> ",")+]
+            //     OptionalInputs = []
+            //     FixedInputs = [(<List> ",")+]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     (<List> ",")+ = (<List> ",")+ (*) List "," ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     CommaTrailing<List> = (<List> ",")+ (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     CommaTrailing<List> = (<List> ",")+ (*) List ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     List = (*) "[" CommaTrailing<Num> "]" ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "[" -> S5
+            //   [EOF] -> CommaTrailing<List> = (<List> ",")+ => ActionFn(33);
+            //
+            //     List -> S6
+            fn __state1<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, ::std::vec::Vec<Vec<u32>>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(5, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action33::<>(input, __sym0);
+                        let __nt = __Nonterminal::CommaTrailing_3cList_3e((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""[""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action33::<>(input, __sym0);
+                        let __nt = __Nonterminal::CommaTrailing_3cList_3e((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::List(__sym1) => {
+                            __result = try!(__state6(input, __tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 2
+            //     AllInputs = [CommaTrailing<List>]
+            //     OptionalInputs = []
+            //     FixedInputs = [CommaTrailing<List>]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Lists)
+            //
+            //     Lists = CommaTrailing<List> (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   [EOF] -> Lists = CommaTrailing<List> => ActionFn(3);
+            //
+            fn __state2<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, ::std::vec::Vec<Vec<u32>>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action3::<>(input, __sym0);
+                        let __nt = __Nonterminal::Lists((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action3::<>(input, __sym0);
+                        let __nt = __Nonterminal::Lists((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 3
+            //     AllInputs = [List]
+            //     OptionalInputs = []
+            //     FixedInputs = [List]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     (<List> ",")+ = List (*) "," ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     CommaTrailing<List> = List (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "," -> S7
+            //   [EOF] -> CommaTrailing<List> = List => ActionFn(30);
+            //
+            fn __state3<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Vec<u32>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(4, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action30::<>(input, __sym0);
+                        let __nt = __Nonterminal::CommaTrailing_3cList_3e((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"",""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action30::<>(input, __sym0);
+                        let __nt = __Nonterminal::CommaTrailing_3cList_3e((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 4
+            //     AllInputs = [Lists]
+            //     OptionalInputs = []
+            //     FixedInputs = [Lists]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(__Lists)
+            //
+            //     __Lists = Lists (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   [EOF] -> __Lists = Lists => ActionFn(1);
+            //
+            fn __state4<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Vec<Vec<u32>>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action1::<>(input, __sym0);
+                        let __nt = __Nonterminal::____Lists((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action1::<>(input, __sym0);
+                        let __nt = __Nonterminal::____Lists((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 5
+            //     AllInputs = ["["]
+            //     OptionalInputs = []
+            //     FixedInputs = ["["]
+            //     WillPushLen = 2
+            //     WillPush = [CommaTrailing<Num>, "]"]
+            //     WillProduce = Some(List)
+            //
+            //     (<Num> ",")+ = (*) (<Num> ",")+ Num "," ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     (<Num> ",")+ = (*) Num "," ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     CommaTrailing<Num> = (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     CommaTrailing<Num> = (*) (<Num> ",")+ ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     CommaTrailing<Num> = (*) (<Num> ",")+ Num ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     CommaTrailing<Num> = (*) Num ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     List = "[" (*) CommaTrailing<Num> "]" ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Num = (*) r#"[0-9]+"# ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   r#"[0-9]+"# -> S11
+            //   ["]"] -> CommaTrailing<Num> =  => ActionFn(35);
+            //
+            //     (<Num> ",")+ -> S8
+            //     CommaTrailing<Num> -> S9
+            //     Num -> S10
+            fn __state5<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state11(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((_, Token(6, _), _)) => {
+                        let __start = __sym0.2.clone();
+                        let __end = __lookahead.as_ref().map(|o| o.0.clone()).unwrap_or_else(|| __start.clone());
+                        let __nt = super::super::super::__action35::<>(input, &__start, &__end);
+                        let __nt = __Nonterminal::CommaTrailing_3cNum_3e((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""]""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::_28_3cNum_3e_20_22_2c_22_29_2b(__sym1) => {
+                            __result = try!(__state8(input, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::CommaTrailing_3cNum_3e(__sym1) => {
+                            __result = try!(__state9(input, __tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        __Nonterminal::Num(__sym1) => {
+                            __result = try!(__state10(input, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 6
+            //     AllInputs = [(<List> ",")+, List]
+            //     OptionalInputs = []
+            //     FixedInputs = [(<List> ",")+, List]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     (<List> ",")+ = (<List> ",")+ List (*) "," ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     CommaTrailing<List> = (<List> ",")+ List (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "," -> S12
+            //   [EOF] -> CommaTrailing<List> = (<List> ",")+, List => ActionFn(32);
+            //
+            fn __state6<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, ::std::vec::Vec<Vec<u32>>, usize),
+                __sym1: (usize, Vec<u32>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(4, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state12(input, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action32::<>(input, __sym0, __sym1);
+                        let __nt = __Nonterminal::CommaTrailing_3cList_3e((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"",""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action32::<>(input, __sym0, __sym1);
+                        let __nt = __Nonterminal::CommaTrailing_3cList_3e((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 7
+            //     AllInputs = [List, ","]
+            //     OptionalInputs = []
+            //     FixedInputs = [List, ","]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some((<List> ",")+)
+            //
+            //     (<List> ",")+ = List "," (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   ["[", EOF] -> (<List> ",")+ = List, "," => ActionFn(22);
+            //
+            fn __state7<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Vec<u32>, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(5, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action22::<>(input, __sym0, __sym1);
+                        let __nt = __Nonterminal::_28_3cList_3e_20_22_2c_22_29_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""[""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action22::<>(input, __sym0, __sym1);
+                        let __nt = __Nonterminal::_28_3cList_3e_20_22_2c_22_29_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 8
+            //     AllInputs = [(<Num> ",")+]
+            //     OptionalInputs = []
+            //     FixedInputs = [(<Num> ",")+]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     (<Num> ",")+ = (<Num> ",")+ (*) Num "," ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     CommaTrailing<Num> = (<Num> ",")+ (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     CommaTrailing<Num> = (<Num> ",")+ (*) Num ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Num = (*) r#"[0-9]+"# ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   r#"[0-9]+"# -> S11
+            //   ["]"] -> CommaTrailing<Num> = (<Num> ",")+ => ActionFn(37);
+            //
+            //     Num -> S13
+            fn __state8<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, ::std::vec::Vec<u32>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state11(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((_, Token(6, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action37::<>(input, __sym0);
+                        let __nt = __Nonterminal::CommaTrailing_3cNum_3e((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""]""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Num(__sym1) => {
+                            __result = try!(__state13(input, __tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 9
+            //     AllInputs = ["[", CommaTrailing<Num>]
+            //     OptionalInputs = []
+            //     FixedInputs = ["[", CommaTrailing<Num>]
+            //     WillPushLen = 1
+            //     WillPush = ["]"]
+            //     WillProduce = Some(List)
+            //
+            //     List = "[" CommaTrailing<Num> (*) "]" ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "]" -> S14
+            //
+            fn __state9<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, ::std::vec::Vec<u32>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state14(input, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""]""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 10
+            //     AllInputs = [Num]
+            //     OptionalInputs = []
+            //     FixedInputs = [Num]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     (<Num> ",")+ = Num (*) "," ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     CommaTrailing<Num> = Num (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "," -> S15
+            //   ["]"] -> CommaTrailing<Num> = Num => ActionFn(34);
+            //
+            fn __state10<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, u32, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(4, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state15(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((_, Token(6, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action34::<>(input, __sym0);
+                        let __nt = __Nonterminal::CommaTrailing_3cNum_3e((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"",""###.to_string(),
+                            r###""]""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 11
+            //     AllInputs = [r#"[0-9]+"#]
+            //     OptionalInputs = []
+            //     FixedInputs = [r#"[0-9]+"#]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Num)
+            //
+            //     Num = r#"[0-9]+"# (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   [",", "]"] -> Num = r#"[0-9]+"# => ActionFn(5);
+            //
+            fn __state11<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(6, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action5::<>(input, __sym0);
+                        let __nt = __Nonterminal::Num((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"",""###.to_string(),
+                            r###""]""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 12
+            //     AllInputs = [(<List> ",")+, List, ","]
+            //     OptionalInputs = []
+            //     FixedInputs = [(<List> ",")+, List, ","]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some((<List> ",")+)
+            //
+            //     (<List> ",")+ = (<List> ",")+ List "," (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   ["[", EOF] -> (<List> ",")+ = (<List> ",")+, List, "," => ActionFn(23);
+            //
+            fn __state12<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, ::std::vec::Vec<Vec<u32>>, usize),
+                __sym1: (usize, Vec<u32>, usize),
+                __sym2: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(5, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action23::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::_28_3cList_3e_20_22_2c_22_29_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""[""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action23::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::_28_3cList_3e_20_22_2c_22_29_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 13
+            //     AllInputs = [(<Num> ",")+, Num]
+            //     OptionalInputs = []
+            //     FixedInputs = [(<Num> ",")+, Num]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     (<Num> ",")+ = (<Num> ",")+ Num (*) "," ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     CommaTrailing<Num> = (<Num> ",")+ Num (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "," -> S16
+            //   ["]"] -> CommaTrailing<Num> = (<Num> ",")+, Num => ActionFn(36);
+            //
+            fn __state13<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, ::std::vec::Vec<u32>, usize),
+                __sym1: (usize, u32, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(4, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state16(input, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((_, Token(6, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action36::<>(input, __sym0, __sym1);
+                        let __nt = __Nonterminal::CommaTrailing_3cNum_3e((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"",""###.to_string(),
+                            r###""]""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 14
+            //     AllInputs = ["[", CommaTrailing<Num>, "]"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["[", CommaTrailing<Num>, "]"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(List)
+            //
+            //     List = "[" CommaTrailing<Num> "]" (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   [",", EOF] -> List = "[", CommaTrailing<Num>, "]" => ActionFn(4);
+            //
+            fn __state14<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, ::std::vec::Vec<u32>, usize),
+                __sym2: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(4, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action4::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::List((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"",""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action4::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::List((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 15
+            //     AllInputs = [Num, ","]
+            //     OptionalInputs = []
+            //     FixedInputs = [Num, ","]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some((<Num> ",")+)
+            //
+            //     (<Num> ",")+ = Num "," (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   ["]", r#"[0-9]+"#] -> (<Num> ",")+ = Num, "," => ActionFn(26);
+            //
+            fn __state15<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, u32, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(0, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action26::<>(input, __sym0, __sym1);
+                        let __nt = __Nonterminal::_28_3cNum_3e_20_22_2c_22_29_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""]""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 16
+            //     AllInputs = [(<Num> ",")+, Num, ","]
+            //     OptionalInputs = []
+            //     FixedInputs = [(<Num> ",")+, Num, ","]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some((<Num> ",")+)
+            //
+            //     (<Num> ",")+ = (<Num> ",")+ Num "," (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   ["]", r#"[0-9]+"#] -> (<Num> ",")+ = (<Num> ",")+, Num, "," => ActionFn(27);
+            //
+            fn __state16<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, ::std::vec::Vec<u32>, usize),
+                __sym1: (usize, u32, usize),
+                __sym2: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(0, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action27::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::_28_3cNum_3e_20_22_2c_22_29_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""]""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+        }
+        pub use self::__parse__Lists::parse_Lists;
+    }
+    mod __parse_table {
+
+        mod __parse_table {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use std::str::FromStr;
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__intern_token::Token;
+            #[allow(dead_code)]
+            pub enum __Symbol<'input>
+             {
+                Term_22_28_22(&'input str),
+                Term_22_29_22(&'input str),
+                Term_22_2c_22(&'input str),
+                Term_22_5b_22(&'input str),
+                Term_22_5d_22(&'input str),
+                Termr_23_22_5b0_2d9_5d_2b_22_23(&'input str),
+                Termr_23_22_5ba_2dz_5d_2b_22_23(&'input str),
+                Nt_28_3cList_3e_20_22_2c_22_29(Vec<u32>),
+                Nt_28_3cList_3e_20_22_2c_22_29_2a(::std::vec::Vec<Vec<u32>>),
+                Nt_28_3cList_3e_20_22_2c_22_29_2b(::std::vec::Vec<Vec<u32>>),
+                Nt_28_3cNum_3e_20_22_2c_22_29(u32),
+                Nt_28_3cNum_3e_20_22_2c_22_29_2a(::std::vec::Vec<u32>),
+                Nt_28_3cNum_3e_20_22_2c_22_29_2b(::std::vec::Vec<u32>),
+                NtCall((&'input str, Vec<u32>)),
+                NtCommaTrailing_3cList_3e(::std::vec::Vec<Vec<u32>>),
+                NtCommaTrailing_3cNum_3e(::std::vec::Vec<u32>),
+                NtList(Vec<u32>),
+                NtList_3f(::std::option::Option<Vec<u32>>),
+                NtLists(Vec<Vec<u32>>),
+                NtNum(u32),
+                NtNum_3f(::std::option::Option<u32>),
+                Nt____Call((&'input str, Vec<u32>)),
+                Nt____Lists(Vec<Vec<u32>>),
+            }
+            impl<'input> ::std::fmt::Debug for __Symbol<'input>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Symbol::Term_22_28_22(..) => __f.write_str("\"(\""),
+                        __Symbol::Term_22_29_22(..) => __f.write_str("\")\""),
+                        __Symbol::Term_22_2c_22(..) => __f.write_str("\",\""),
+                        __Symbol::Term_22_5b_22(..) => __f.write_str("\"[\""),
+                        __Symbol::Term_22_5d_22(..) => __f.write_str("\"]\""),
+                        __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(..) => __f.write_str("r#\"[0-9]+\"#"),
+                        __Symbol::Termr_23_22_5ba_2dz_5d_2b_22_23(..) => __f.write_str("r#\"[a-z]+\"#"),
+                        __Symbol::Nt_28_3cList_3e_20_22_2c_22_29(..) => __f.write_str("(<List> \",\")"),
+                        __Symbol::Nt_28_3cList_3e_20_22_2c_22_29_2a(..) => __f.write_str("(<List> \",\")*"),
+                        __Symbol::Nt_28_3cList_3e_20_22_2c_22_29_2b(..) => __f.write_str("(<List> \",\")+"),
+                        __Symbol::Nt_28_3cNum_3e_20_22_2c_22_29(..) => __f.write_str("(<Num> \",\")"),
+                        __Symbol::Nt_28_3cNum_3e_20_22_2c_22_29_2a(..) => __f.write_str("(<Num> \",\")*"),
+                        __Symbol::Nt_28_3cNum_3e_20_22_2c_22_29_2b(..) => __f.write_str("(<Num> \",\")+"),
+                        __Symbol::NtCall(..) => __f.write_str("Call"),
+                        __Symbol::NtCommaTrailing_3cList_3e(..) => __f.write_str("CommaTrailing<List>"),
+                        __Symbol::NtCommaTrailing_3cNum_3e(..) => __f.write_str("CommaTrailing<Num>"),
+                        __Symbol::NtList(..) => __f.write_str("List"),
+                        __Symbol::NtList_3f(..) => __f.write_str("List?"),
+                        __Symbol::NtLists(..) => __f.write_str("Lists"),
+                        __Symbol::NtNum(..) => __f.write_str("Num"),
+                        __Symbol::NtNum_3f(..) => __f.write_str("Num?"),
+                        __Symbol::Nt____Call(..) => __f.write_str("__Call"),
+                        __Symbol::Nt____Lists(..) => __f.write_str("__Lists"),
+                    }
+                }
+            }
+            const __ACTION: &'static [i8] = &[
+                // State 0
+                //     (<List> ",")+ = (*) (<List> ",")+ List "," ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     (<List> ",")+ = (*) List "," ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     CommaTrailing<List> = (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     CommaTrailing<List> = (*) (<List> ",")+ ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     CommaTrailing<List> = (*) (<List> ",")+ List ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     CommaTrailing<List> = (*) List ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     List = (*) "[" CommaTrailing<Num> "]" ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Lists = (*) CommaTrailing<List> ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     __Lists = (*) Lists ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                0,  // on ",", error
+                6,  // on "[", goto 5
+                0,  // on "]", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 1
+                //     (<List> ",")+ = (<List> ",")+ (*) List "," ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     CommaTrailing<List> = (<List> ",")+ (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     CommaTrailing<List> = (<List> ",")+ (*) List ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     List = (*) "[" CommaTrailing<Num> "]" ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                0,  // on ",", error
+                6,  // on "[", goto 5
+                0,  // on "]", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 2
+                //     Lists = CommaTrailing<List> (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                0,  // on ",", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 3
+                //     (<List> ",")+ = List (*) "," ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     CommaTrailing<List> = List (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                8,  // on ",", goto 7
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 4
+                //     __Lists = Lists (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                0,  // on ",", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 5
+                //     (<Num> ",")+ = (*) (<Num> ",")+ Num "," ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     (<Num> ",")+ = (*) Num "," ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     CommaTrailing<Num> = (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     CommaTrailing<Num> = (*) (<Num> ",")+ ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     CommaTrailing<Num> = (*) (<Num> ",")+ Num ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     CommaTrailing<Num> = (*) Num ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     List = "[" (*) CommaTrailing<Num> "]" ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Num = (*) r#"[0-9]+"# ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                0,  // on ",", error
+                0,  // on "[", error
+                -17,  // on "]", reduce `CommaTrailing<Num> =  => ActionFn(35);`
+                12,  // on r#"[0-9]+"#, goto 11
+                0,  // on r#"[a-z]+"#, error
+
+                // State 6
+                //     (<List> ",")+ = (<List> ",")+ List (*) "," ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     CommaTrailing<List> = (<List> ",")+ List (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                13,  // on ",", goto 12
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 7
+                //     (<List> ",")+ = List "," (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                0,  // on ",", error
+                -4,  // on "[", reduce `(<List> ",")+ = List, "," => ActionFn(22);`
+                0,  // on "]", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 8
+                //     (<Num> ",")+ = (<Num> ",")+ (*) Num "," ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     CommaTrailing<Num> = (<Num> ",")+ (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     CommaTrailing<Num> = (<Num> ",")+ (*) Num ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Num = (*) r#"[0-9]+"# ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                0,  // on ",", error
+                0,  // on "[", error
+                -19,  // on "]", reduce `CommaTrailing<Num> = (<Num> ",")+ => ActionFn(37);`
+                12,  // on r#"[0-9]+"#, goto 11
+                0,  // on r#"[a-z]+"#, error
+
+                // State 9
+                //     List = "[" CommaTrailing<Num> (*) "]" ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                0,  // on ",", error
+                0,  // on "[", error
+                15,  // on "]", goto 14
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 10
+                //     (<Num> ",")+ = Num (*) "," ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     CommaTrailing<Num> = Num (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                16,  // on ",", goto 15
+                0,  // on "[", error
+                -16,  // on "]", reduce `CommaTrailing<Num> = Num => ActionFn(34);`
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 11
+                //     Num = r#"[0-9]+"# (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                -24,  // on ",", reduce `Num = r#"[0-9]+"# => ActionFn(5);`
+                0,  // on "[", error
+                -24,  // on "]", reduce `Num = r#"[0-9]+"# => ActionFn(5);`
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 12
+                //     (<List> ",")+ = (<List> ",")+ List "," (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                0,  // on ",", error
+                -5,  // on "[", reduce `(<List> ",")+ = (<List> ",")+, List, "," => ActionFn(23);`
+                0,  // on "]", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 13
+                //     (<Num> ",")+ = (<Num> ",")+ Num (*) "," ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     CommaTrailing<Num> = (<Num> ",")+ Num (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                17,  // on ",", goto 16
+                0,  // on "[", error
+                -18,  // on "]", reduce `CommaTrailing<Num> = (<Num> ",")+, Num => ActionFn(36);`
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 14
+                //     List = "[" CommaTrailing<Num> "]" (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                -20,  // on ",", reduce `List = "[", CommaTrailing<Num>, "]" => ActionFn(4);`
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 15
+                //     (<Num> ",")+ = Num "," (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                0,  // on ",", error
+                0,  // on "[", error
+                -9,  // on "]", reduce `(<Num> ",")+ = Num, "," => ActionFn(26);`
+                -9,  // on r#"[0-9]+"#, reduce `(<Num> ",")+ = Num, "," => ActionFn(26);`
+                0,  // on r#"[a-z]+"#, error
+
+                // State 16
+                //     (<Num> ",")+ = (<Num> ",")+ Num "," (*) ["(", ")", ",", "[", "]", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                0,  // on ",", error
+                0,  // on "[", error
+                -10,  // on "]", reduce `(<Num> ",")+ = (<Num> ",")+, Num, "," => ActionFn(27);`
+                -10,  // on r#"[0-9]+"#, reduce `(<Num> ",")+ = (<Num> ",")+, Num, "," => ActionFn(27);`
+                0,  // on r#"[a-z]+"#, error
+
+            ];
+            const __EOF_ACTION: &'static [i8] = &[
+                // State 0
+                -13,  // on EOF, reduce `CommaTrailing<List> =  => ActionFn(31);`
+
+                // State 1
+                -15,  // on EOF, reduce `CommaTrailing<List> = (<List> ",")+ => ActionFn(33);`
+
+                // State 2
+                -23,  // on EOF, reduce `Lists = CommaTrailing<List> => ActionFn(3);`
+
+                // State 3
+                -12,  // on EOF, reduce `CommaTrailing<List> = List => ActionFn(30);`
+
+                // State 4
+                -28,  // on EOF, reduce `__Lists = Lists => ActionFn(1);`
+
+                // State 5
+                0,  // on EOF, error
+
+                // State 6
+                -14,  // on EOF, reduce `CommaTrailing<List> = (<List> ",")+, List => ActionFn(32);`
+
+                // State 7
+                -4,  // on EOF, reduce `(<List> ",")+ = List, "," => ActionFn(22);`
+
+                // State 8
+                0,  // on EOF, error
+
+                // State 9
+                0,  // on EOF, error
+
+                // State 10
+                0,  // on EOF, error
+
+                // State 11
+                0,  // on EOF, error
+
+                // State 12
+                -5,  // on EOF, reduce `(<List> ",")+ = (<List> ",")+, List, "," => ActionFn(23);`
+
+                // State 13
+                0,  // on EOF, error
+
+                // State 14
+                -20,  // on EOF, reduce `List = "[", CommaTrailing<Num>, "]" => ActionFn(4);`
+
+                // State 15
+                0,  // on EOF, error
+
+                // State 16
+                0,  // on EOF, error
+
+            ];
+            const __GOTO: &'static [u8] = &[
+                // State 0
+                0,  // on (<List> ","), error
+                0,  // on (<List> ",")*, error
+                2,  // on (<List> ",")+, goto 1
+                0,  // on (<Num> ","), error
+                0,  // on (<Num> ",")*, error
+                0,  // on (<Num> ",")+, error
+                0,  // on Call, error
+                3,  // on CommaTrailing<List>, goto 2
+                0,  // on CommaTrailing<Num>, error
+                4,  // on List, goto 3
+                0,  // on List?, error
+                5,  // on Lists, goto 4
+                0,  // on Num, error
+                0,  // on Num?, error
+                0,  // on __Call, error
+                0,  // on __Lists, error
+
+                // State 1
+                0,  // on (<List> ","), error
+                0,  // on (<List> ",")*, error
+                0,  // on (<List> ",")+, error
+                0,  // on (<Num> ","), error
+                0,  // on (<Num> ",")*, error
+                0,  // on (<Num> ",")+, error
+                0,  // on Call, error
+                0,  // on CommaTrailing<List>, error
+                0,  // on CommaTrailing<Num>, error
+                7,  // on List, goto 6
+                0,  // on List?, error
+                0,  // on Lists, error
+                0,  // on Num, error
+                0,  // on Num?, error
+                0,  // on __Call, error
+                0,  // on __Lists, error
+
+                // State 2
+                0,  // on (<List> ","), error
+                0,  // on (<List> ",")*, error
+                0,  // on (<List> ",")+, error
+                0,  // on (<Num> ","), error
+                0,  // on (<Num> ",")*, error
+                0,  // on (<Num> ",")+, error
+                0,  // on Call, error
+                0,  // on CommaTrailing<List>, error
+                0,  // on CommaTrailing<Num>, error
+                0,  // on List, error
+                0,  // on List?, error
+                0,  // on Lists, error
+                0,  // on Num, error
+                0,  // on Num?, error
+                0,  // on __Call, error
+                0,  // on __Lists, error
+
+                // State 3
+                0,  // on (<List> ","), error
+                0,  // on (<List> ",")*, error
+                0,  // on (<List> ",")+, error
+                0,  // on (<Num> ","), error
+                0,  // on (<Num> ",")*, error
+                0,  // on (<Num> ",")+, error
+                0,  // on Call, error
+                0,  // on CommaTrailing<List>, error
+                0,  // on CommaTrailing<Num>, error
+                0,  // on List, error
+                0,  // on List?, error
+                0,  // on Lists, error
+                0,  // on Num, error
+                0,  // on Num?, error
+                0,  // on __Call, error
+                0,  // on __Lists, error
+
+                // State 4
+                0,  // on (<List> ","), error
+                0,  // on (<List> ",")*, error
+                0,  // on (<List> ",")+, error
+                0,  // on (<Num> ","), error
+                0,  // on (<Num> ",")*, error
+                0,  // on (<Num> ",")+, error
+                0,  // on Call, error
+                0,  // on CommaTrailing<List>, error
+                0,  // on CommaTrailing<Num>, error
+                0,  // on List, error
+                0,  // on List?, error
+                0,  // on Lists, error
+                0,  // on Num, error
+                0,  // on Num?, error
+                0,  // on __Call, error
+                0,  // on __Lists, error
+
+                // State 5
+                0,  // on (<List> ","), error
+                0,  // on (<List> ",")*, error
+                0,  // on (<List> ",")+, error
+                0,  // on (<Num> ","), error
+                0,  // on (<Num> ",")*, error
+                9,  // on (<Num> ",")+, goto 8
+                0,  // on Call, error
+                0,  // on CommaTrailing<List>, error
+                10,  // on CommaTrailing<Num>, goto 9
+                0,  // on List, error
+                0,  // on List?, error
+                0,  // on Lists, error
+                11,  // on Num, goto 10
+                0,  // on Num?, error
+                0,  // on __Call, error
+                0,  // on __Lists, error
+
+                // State 6
+                0,  // on (<List> ","), error
+                0,  // on (<List> ",")*, error
+                0,  // on (<List> ",")+, error
+                0,  // on (<Num> ","), error
+                0,  // on (<Num> ",")*, error
+                0,  // on (<Num> ",")+, error
+                0,  // on Call, error
+                0,  // on CommaTrailing<List>, error
+                0,  // on CommaTrailing<Num>, error
+                0,  // on List, error
+                0,  // on List?, error
+                0,  // on Lists, error
+                0,  // on Num, error
+                0,  // on Num?, error
+                0,  // on __Call, error
+                0,  // on __Lists, error
+
+                // State 7
+                0,  // on (<List> ","), error
+                0,  // on (<List> ",")*, error
+                0,  // on (<List> ",")+, error
+                0,  // on (<Num> ","), error
+                0,  // on (<Num> ",")*, error
+                0,  // on (<Num> ",")+, error
+                0,  // on Call, error
+                0,  // on CommaTrailing<List>, error
+                0,  // on CommaTrailing<Num>, error
+                0,  // on List, error
+                0,  // on List?, error
+                0,  // on Lists, error
+                0,  // on Num, error
+                0,  // on Num?, error
+                0,  // on __Call, error
+                0,  // on __Lists, error
+
+                // State 8
+                0,  // on (<List> ","), error
+                0,  // on (<List> ",")*, error
+                0,  // on (<List> ",")+, error
+                0,  // on (<Num> ","), error
+                0,  // on (<Num> ",")*, error
+                0,  // on (<Num> ",")+, error
+                0,  // on Call, error
+                0,  // on CommaTrailing<List>, error
+                0,  // on CommaTrailing<Num>, error
+                0,  // on List, error
+                0,  // on List?, error
+                0,  // on Lists, error
+                14,  // on Num, goto 13
+                0,  // on Num?, error
+                0,  // on __Call, error
+                0,  // on __Lists, error
+
+                // State 9
+                0,  // on (<List> ","), error
+                0,  // on (<List> ",")*, error
+                0,  // on (<List> ",")+, error
+                0,  // on (<Num> ","), error
+                0,  // on (<Num> ",")*, error
+                0,  // on (<Num> ",")+, error
+                0,  // on Call, error
+                0,  // on CommaTrailing<List>, error
+                0,  // on CommaTrailing<Num>, error
+                0,  // on List, error
+                0,  // on List?, error
+                0,  // on Lists, error
+                0,  // on Num, error
+                0,  // on Num?, error
+                0,  // on __Call, error
+                0,  // on __Lists, error
+
+                // State 10
+                0,  // on (<List> ","), error
+                0,  // on (<List> ",")*, error
+                0,  // on (<List> ",")+, error
+                0,  // on (<Num> ","), error
+                0,  // on (<Num> ",")*, error
+                0,  // on (<Num> ",")+, error
+                0,  // on Call, error
+                0,  // on CommaTrailing<List>, error
+                0,  // on CommaTrailing<Num>, error
+                0,  // on List, error
+                0,  // on List?, error
+                0,  // on Lists, error
+                0,  // on Num, error
+                0,  // on Num?, error
+                0,  // on __Call, error
+                0,  // on __Lists, error
+
+                // State 11
+                0,  // on (<List> ","), error
+                0,  // on (<List> ",")*, error
+                0,  // on (<List> ",")+, error
+                0,  // on (<Num> ","), error
+                0,  // on (<Num> ",")*, error
+                0,  // on (<Num> ",")+, error
+                0,  // on Call, error
+                0,  // on CommaTrailing<List>, error
+                0,  // on CommaTrailing<Num>, error
+                0,  // on List, error
+                0,  // on List?, error
+                0,  // on Lists, error
+                0,  // on Num, error
+                0,  // on Num?, error
+                0,  // on __Call, error
+                0,  // on __Lists, error
+
+                // State 12
+                0,  // on (<List> ","), error
+                0,  // on (<List> ",")*, error
+                0,  // on (<List> ",")+, error
+                0,  // on (<Num> ","), error
+                0,  // on (<Num> ",")*, error
+                0,  // on (<Num> ",")+, error
+                0,  // on Call, error
+                0,  // on CommaTrailing<List>, error
+                0,  // on CommaTrailing<Num>, error
+                0,  // on List, error
+                0,  // on List?, error
+                0,  // on Lists, error
+                0,  // on Num, error
+                0,  // on Num?, error
+                0,  // on __Call, error
+                0,  // on __Lists, error
+
+                // State 13
+                0,  // on (<List> ","), error
+                0,  // on (<List> ",")*, error
+                0,  // on (<List> ",")+, error
+                0,  // on (<Num> ","), error
+                0,  // on (<Num> ",")*, error
+                0,  // on (<Num> ",")+, error
+                0,  // on Call, error
+                0,  // on CommaTrailing<List>, error
+                0,  // on CommaTrailing<Num>, error
+                0,  // on List, error
+                0,  // on List?, error
+                0,  // on Lists, error
+                0,  // on Num, error
+                0,  // on Num?, error
+                0,  // on __Call, error
+                0,  // on __Lists, error
+
+                // State 14
+                0,  // on (<List> ","), error
+                0,  // on (<List> ",")*, error
+                0,  // on (<List> ",")+, error
+                0,  // on (<Num> ","), error
+                0,  // on (<Num> ",")*, error
+                0,  // on (<Num> ",")+, error
+                0,  // on Call, error
+                0,  // on CommaTrailing<List>, error
+                0,  // on CommaTrailing<Num>, error
+                0,  // on List, error
+                0,  // on List?, error
+                0,  // on Lists, error
+                0,  // on Num, error
+                0,  // on Num?, error
+                0,  // on __Call, error
+                0,  // on __Lists, error
+
+                // State 15
+                0,  // on (<List> ","), error
+                0,  // on (<List> ",")*, error
+                0,  // on (<List> ",")+, error
+                0,  // on (<Num> ","), error
+                0,  // on (<Num> ",")*, error
+                0,  // on (<Num> ",")+, error
+                0,  // on Call, error
+                0,  // on CommaTrailing<List>, error
+                0,  // on CommaTrailing<Num>, error
+                0,  // on List, error
+                0,  // on List?, error
+                0,  // on Lists, error
+                0,  // on Num, error
+                0,  // on Num?, error
+                0,  // on __Call, error
+                0,  // on __Lists, error
+
+                // State 16
+                0,  // on (<List> ","), error
+                0,  // on (<List> ",")*, error
+                0,  // on (<List> ",")+, error
+                0,  // on (<Num> ","), error
+                0,  // on (<Num> ",")*, error
+                0,  // on (<Num> ",")+, error
+                0,  // on Call, error
+                0,  // on CommaTrailing<List>, error
+                0,  // on CommaTrailing<Num>, error
+                0,  // on List, error
+                0,  // on List?, error
+                0,  // on Lists, error
+                0,  // on Num, error
+                0,  // on Num?, error
+                0,  // on __Call, error
+                0,  // on __Lists, error
+
+            ];
+            const __DEFAULT_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // no default reduction
+
+                // State 1
+                0,  // no default reduction
+
+                // State 2
+                -23,  // on any token, reduce `Lists = CommaTrailing<List> => ActionFn(3);`
+
+                // State 3
+                0,  // no default reduction
+
+                // State 4
+                0,  // no default reduction
+
+                // State 5
+                0,  // no default reduction
+
+                // State 6
+                0,  // no default reduction
+
+                // State 7
+                -4,  // on any token, reduce `(<List> ",")+ = List, "," => ActionFn(22);`
+
+                // State 8
+                0,  // no default reduction
+
+                // State 9
+                0,  // no default reduction
+
+                // State 10
+                0,  // no default reduction
+
+                // State 11
+                -24,  // on any token, reduce `Num = r#"[0-9]+"# => ActionFn(5);`
+
+                // State 12
+                -5,  // on any token, reduce `(<List> ",")+ = (<List> ",")+, List, "," => ActionFn(23);`
+
+                // State 13
+                0,  // no default reduction
+
+                // State 14
+                -20,  // on any token, reduce `List = "[", CommaTrailing<Num>, "]" => ActionFn(4);`
+
+                // State 15
+                -9,  // on any token, reduce `(<Num> ",")+ = Num, "," => ActionFn(26);`
+
+                // State 16
+                -10,  // on any token, reduce `(<Num> ",")+ = (<Num> ",")+, Num, "," => ActionFn(27);`
+
+            ];
+            fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+                const __TERMINAL: &'static [&'static str] = &[
+                    r###""(""###,
+                    r###"")""###,
+                    r###"",""###,
+                    r###""[""###,
+                    r###""]""###,
+                    r###"r#"[0-9]+"#"###,
+                    r###"r#"[a-z]+"#"###,
+                ];
+                __ACTION[(__state * 7)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+                    if state == 0 {
+                        None
+                    } else {
+                        Some(terminal.to_string())
+                    }
+                }).collect()
+            }
+            #[allow(dead_code)]
+            pub fn parse_Lists<
+                'input,
+            >(
+                input: &'input str,
+            ) -> Result<Vec<Vec<u32>>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Lists(__v) => Ok(__v), _ => unreachable!() })
+            }
+            #[allow(dead_code)]
+            pub fn parse_Lists_cancelable<
+                'input,
+            >(
+                input: &'input str,
+                __cancel: &::std::sync::atomic::AtomicBool,
+            ) -> Result<Vec<Vec<u32>>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Lists(__v) => Ok(__v), _ => unreachable!() })
+            }
+
+            #[allow(dead_code)]
+            pub struct ListsParser {
+                __states: ::std::vec::Vec<i32>,
+                __symbols: __lalrpop_util::stack::Recycled,
+                __cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+            }
+
+            #[allow(dead_code)]
+            impl ListsParser {
+                pub fn new() -> ListsParser {
+                    ListsParser { __states: vec![], __symbols: __lalrpop_util::stack::Recycled::new(), __cancel: None }
+                }
+
+                pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+                    self.__cancel = Some(flag);
+                }
+
+                pub fn parse_in_place<'input>(
+                    &mut self,
+                    input: &'input str,
+                ) -> Result<Vec<Vec<u32>>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+                {
+                    let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                    let mut __symbols = self.__symbols.take();
+                    let __result = __parse::<>(input, 0, &mut __tokens, &mut self.__states, &mut __symbols, self.__cancel.as_ref().map(|c| &**c)).and_then(|__v| match __v { __Symbol::Nt____Lists(__v) => Ok(__v), _ => unreachable!() });
+                    self.__symbols.put(__symbols);
+                    __result
+                }
+            }
+
+            fn __parse<
+                'input,
+            >(
+                input: &'input str,
+                __start_state: i32,
+                mut __tokens: &mut Iterator<Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+                mut __states: &mut ::std::vec::Vec<i32>,
+                mut __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                __cancel: Option<&::std::sync::atomic::AtomicBool>,
+            ) -> Result<__Symbol<'input>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                __states.clear();
+                __states.push(__start_state);
+                __symbols.clear();
+                let mut __integer;
+                let mut __lookahead;
+                let __last_location: &mut usize = &mut Default::default();
+                let mut __default_reduced = [0_usize; 16];
+                let mut __default_reduced_len = 0;
+                '__shift: loop {
+                    loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __DEFAULT_ACTION[__state] as i32;
+                        if __action == 0 {
+                            break;
+                        }
+                        if __default_reduced_len < 16 {
+                            __default_reduced[__default_reduced_len] = __state;
+                            __default_reduced_len += 1;
+                        }
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    }
+                    __lookahead = match __tokens.next() {
+                        Some(Ok(v)) => v,
+                        None => break '__shift,
+                        Some(Err(e)) => return Err(e),
+                    };
+                    *__last_location = __lookahead.2.clone();
+                    __integer = match __lookahead.1 {
+                        Token(2, _) if true => 0,
+                        Token(3, _) if true => 1,
+                        Token(4, _) if true => 2,
+                        Token(5, _) if true => 3,
+                        Token(6, _) if true => 4,
+                        Token(0, _) if true => 5,
+                        Token(1, _) if true => 6,
+                        _ => {
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| true).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match Some(__lookahead) {
+                                Some(__token) => {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error);
+                        }
+                    };
+                    '__inner: loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __ACTION[__state * 7 + __integer] as i32;
+                        if __action > 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                                }
+                            }
+                            let __symbol = match __integer {
+                                0 => match __lookahead.1 {
+                                    Token(2, __tok0) => __Symbol::Term_22_28_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                1 => match __lookahead.1 {
+                                    Token(3, __tok0) => __Symbol::Term_22_29_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                2 => match __lookahead.1 {
+                                    Token(4, __tok0) => __Symbol::Term_22_2c_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                3 => match __lookahead.1 {
+                                    Token(5, __tok0) => __Symbol::Term_22_5b_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                4 => match __lookahead.1 {
+                                    Token(6, __tok0) => __Symbol::Term_22_5d_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                5 => match __lookahead.1 {
+                                    Token(0, __tok0) => __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                6 => match __lookahead.1 {
+                                    Token(1, __tok0) => __Symbol::Termr_23_22_5ba_2dz_5d_2b_22_23((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                _ => unreachable!(),
+                            };
+                            __states.push(__action - 1);
+                            __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                            __default_reduced_len = 0;
+                            continue '__shift;
+                        } else if __action < 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                                }
+                            }
+                            if let Some(r) = __reduce(input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                                if r.is_err() {
+                                    return r;
+                                }
+                                return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] });
+                            }
+                        } else {
+                            let mut __err_lookahead = Some(__lookahead);
+                            let mut __err_integer: Option<usize> = Some(__integer);
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 7 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match __err_lookahead {
+                                Some(__token) => {
+                                    if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                        __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                    } else {
+                                        __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                    }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error)
+                        }
+                    }
+                }
+                loop {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __action = __EOF_ACTION[__state] as i32;
+                    if __action < 0 {
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    } else {
+                        let mut __err_lookahead = None;
+                        let mut __err_integer: Option<usize> = None;
+                        let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 7 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                        let __expected = __expected_tokens(__state);
+                        let __error = match __err_lookahead {
+                            Some(__token) => {
+                                if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                    __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                } else {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                            }
+                            None => {
+                                __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                            }
+                        };
+                        return Err(__error)
+                    }
+                }
+            }
+            fn __accepts<
+                'input,
+            >(
+                input: &'input str,
+                __error_state: i32,
+                __states: & [i32],
+                __opt_integer: Option<usize>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> bool
+            {
+                let mut __states = __states.to_vec();
+                __states.push(__error_state);
+                loop {
+                    let mut __states_len = __states.len();
+                    let __top = __states[__states_len - 1];
+                    let __action = match __opt_integer {
+                        None => __EOF_ACTION[__top as usize] as i32,
+                        Some(__integer) => __ACTION[(__top * 7) as usize + __integer] as i32,
+                    };
+                    if __action == 0 { return false; }
+                    if __action > 0 { return true; }
+                    let (__to_pop, __nt) = match -__action {
+                        // simulate (<List> ",") = List, "," => ActionFn(17);
+                        1 => {
+                            (2, 0)
+                        }
+                        // simulate (<List> ",")* =  => ActionFn(15);
+                        2 => {
+                            (0, 1)
+                        }
+                        // simulate (<List> ",")* = (<List> ",")+ => ActionFn(16);
+                        3 => {
+                            (1, 1)
+                        }
+                        // simulate (<List> ",")+ = List, "," => ActionFn(22);
+                        4 => {
+                            (2, 2)
+                        }
+                        // simulate (<List> ",")+ = (<List> ",")+, List, "," => ActionFn(23);
+                        5 => {
+                            (3, 2)
+                        }
+                        // simulate (<Num> ",") = Num, "," => ActionFn(12);
+                        6 => {
+                            (2, 3)
+                        }
+                        // simulate (<Num> ",")* =  => ActionFn(10);
+                        7 => {
+                            (0, 4)
+                        }
+                        // simulate (<Num> ",")* = (<Num> ",")+ => ActionFn(11);
+                        8 => {
+                            (1, 4)
+                        }
+                        // simulate (<Num> ",")+ = Num, "," => ActionFn(26);
+                        9 => {
+                            (2, 5)
+                        }
+                        // simulate (<Num> ",")+ = (<Num> ",")+, Num, "," => ActionFn(27);
+                        10 => {
+                            (3, 5)
+                        }
+                        // simulate Call = r#"[a-z]+"#, "(", CommaTrailing<Num>, ")" => ActionFn(2);
+                        11 => {
+                            (4, 6)
+                        }
+                        // simulate CommaTrailing<List> = List => ActionFn(30);
+                        12 => {
+                            (1, 7)
+                        }
+                        // simulate CommaTrailing<List> =  => ActionFn(31);
+                        13 => {
+                            (0, 7)
+                        }
+                        // simulate CommaTrailing<List> = (<List> ",")+, List => ActionFn(32);
+                        14 => {
+                            (2, 7)
+                        }
+                        // simulate CommaTrailing<List> = (<List> ",")+ => ActionFn(33);
+                        15 => {
+                            (1, 7)
+                        }
+                        // simulate CommaTrailing<Num> = Num => ActionFn(34);
+                        16 => {
+                            (1, 8)
+                        }
+                        // simulate CommaTrailing<Num> =  => ActionFn(35);
+                        17 => {
+                            (0, 8)
+                        }
+                        // simulate CommaTrailing<Num> = (<Num> ",")+, Num => ActionFn(36);
+                        18 => {
+                            (2, 8)
+                        }
+                        // simulate CommaTrailing<Num> = (<Num> ",")+ => ActionFn(37);
+                        19 => {
+                            (1, 8)
+                        }
+                        // simulate List = "[", CommaTrailing<Num>, "]" => ActionFn(4);
+                        20 => {
+                            (3, 9)
+                        }
+                        // simulate List? = List => ActionFn(13);
+                        21 => {
+                            (1, 10)
+                        }
+                        // simulate List? =  => ActionFn(14);
+                        22 => {
+                            (0, 10)
+                        }
+                        // simulate Lists = CommaTrailing<List> => ActionFn(3);
+                        23 => {
+                            (1, 11)
+                        }
+                        // simulate Num = r#"[0-9]+"# => ActionFn(5);
+                        24 => {
+                            (1, 12)
+                        }
+                        // simulate Num? = Num => ActionFn(8);
+                        25 => {
+                            (1, 13)
+                        }
+                        // simulate Num? =  => ActionFn(9);
+                        26 => {
+                            (0, 13)
+                        }
+                        // simulate __Call = Call => ActionFn(0);
+                        27 => return true,
+                        // simulate __Lists = Lists => ActionFn(1);
+                        28 => return true,
+                        _ => panic!("invalid action code {}", __action)
+                    };
+                    __states_len -= __to_pop;
+                    __states.truncate(__states_len);
+                    let __top = __states[__states_len - 1];
+                    let __next_state = __GOTO[(__top * 16 + __nt) as usize] as i32 - 1;
+                    __states.push(__next_state);
+                }
+            }
+            pub fn __reduce<
+                'input,
+            >(
+                input: &'input str,
+                __action: i32,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Option<Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                let __nonterminal = match -__action {
+                    1 => match __reduce__28_3cList_3e_20_22_2c_22_29_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    2 => match __reduce__28_3cList_3e_20_22_2c_22_29_2a_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    3 => match __reduce__28_3cList_3e_20_22_2c_22_29_2a_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    4 => match __reduce__28_3cList_3e_20_22_2c_22_29_2b_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    5 => match __reduce__28_3cList_3e_20_22_2c_22_29_2b_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    6 => match __reduce__28_3cNum_3e_20_22_2c_22_29_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    7 => match __reduce__28_3cNum_3e_20_22_2c_22_29_2a_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    8 => match __reduce__28_3cNum_3e_20_22_2c_22_29_2a_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    9 => match __reduce__28_3cNum_3e_20_22_2c_22_29_2b_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    10 => match __reduce__28_3cNum_3e_20_22_2c_22_29_2b_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    11 => match __reduce_Call_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    12 => match __reduce_CommaTrailing_3cList_3e_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    13 => match __reduce_CommaTrailing_3cList_3e_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    14 => match __reduce_CommaTrailing_3cList_3e_2(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    15 => match __reduce_CommaTrailing_3cList_3e_3(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    16 => match __reduce_CommaTrailing_3cNum_3e_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    17 => match __reduce_CommaTrailing_3cNum_3e_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    18 => match __reduce_CommaTrailing_3cNum_3e_2(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    19 => match __reduce_CommaTrailing_3cNum_3e_3(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    20 => match __reduce_List_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    21 => match __reduce_List_3f_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    22 => match __reduce_List_3f_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    23 => match __reduce_Lists_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    24 => match __reduce_Num_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    25 => match __reduce_Num_3f_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    26 => match __reduce_Num_3f_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    27 => match __reduce_____Call_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    28 => match __reduce_____Lists_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    _ => panic!("invalid action code {}", __action)
+                };
+                let __state = *__states.last().unwrap() as usize;
+                let __next_state = __GOTO[__state * 16 + __nonterminal] as i32 - 1;
+                __states.push(__next_state);
+                None
+            }
+
+            fn __reduce__28_3cList_3e_20_22_2c_22_29_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<List> ",") = List, "," => ActionFn(17);
+                let __sym1 = __pop_Term_22_2c_22(__symbols);
+                let __sym0 = __pop_NtList(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action17::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_3cList_3e_20_22_2c_22_29(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce__28_3cList_3e_20_22_2c_22_29_2a_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<List> ",")* =  => ActionFn(15);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action15::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_28_3cList_3e_20_22_2c_22_29_2a(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce__28_3cList_3e_20_22_2c_22_29_2a_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<List> ",")* = (<List> ",")+ => ActionFn(16);
+                let __sym0 = __pop_Nt_28_3cList_3e_20_22_2c_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action16::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::Nt_28_3cList_3e_20_22_2c_22_29_2a(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce__28_3cList_3e_20_22_2c_22_29_2b_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<List> ",")+ = List, "," => ActionFn(22);
+                let __sym1 = __pop_Term_22_2c_22(__symbols);
+                let __sym0 = __pop_NtList(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action22::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_3cList_3e_20_22_2c_22_29_2b(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce__28_3cList_3e_20_22_2c_22_29_2b_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<List> ",")+ = (<List> ",")+, List, "," => ActionFn(23);
+                let __sym2 = __pop_Term_22_2c_22(__symbols);
+                let __sym1 = __pop_NtList(__symbols);
+                let __sym0 = __pop_Nt_28_3cList_3e_20_22_2c_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action23::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::Nt_28_3cList_3e_20_22_2c_22_29_2b(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce__28_3cNum_3e_20_22_2c_22_29_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<Num> ",") = Num, "," => ActionFn(12);
+                let __sym1 = __pop_Term_22_2c_22(__symbols);
+                let __sym0 = __pop_NtNum(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action12::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_3cNum_3e_20_22_2c_22_29(__nt), __end));
+                Ok(3)
+            }
+
+            fn __reduce__28_3cNum_3e_20_22_2c_22_29_2a_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<Num> ",")* =  => ActionFn(10);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action10::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_28_3cNum_3e_20_22_2c_22_29_2a(__nt), __end));
+                Ok(4)
+            }
+
+            fn __reduce__28_3cNum_3e_20_22_2c_22_29_2a_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<Num> ",")* = (<Num> ",")+ => ActionFn(11);
+                let __sym0 = __pop_Nt_28_3cNum_3e_20_22_2c_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action11::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::Nt_28_3cNum_3e_20_22_2c_22_29_2a(__nt), __end));
+                Ok(4)
+            }
+
+            fn __reduce__28_3cNum_3e_20_22_2c_22_29_2b_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<Num> ",")+ = Num, "," => ActionFn(26);
+                let __sym1 = __pop_Term_22_2c_22(__symbols);
+                let __sym0 = __pop_NtNum(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action26::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_3cNum_3e_20_22_2c_22_29_2b(__nt), __end));
+                Ok(5)
+            }
+
+            fn __reduce__28_3cNum_3e_20_22_2c_22_29_2b_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<Num> ",")+ = (<Num> ",")+, Num, "," => ActionFn(27);
+                let __sym2 = __pop_Term_22_2c_22(__symbols);
+                let __sym1 = __pop_NtNum(__symbols);
+                let __sym0 = __pop_Nt_28_3cNum_3e_20_22_2c_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action27::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::Nt_28_3cNum_3e_20_22_2c_22_29_2b(__nt), __end));
+                Ok(5)
+            }
+
+            fn __reduce_Call_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Call = r#"[a-z]+"#, "(", CommaTrailing<Num>, ")" => ActionFn(2);
+                let __sym3 = __pop_Term_22_29_22(__symbols);
+                let __sym2 = __pop_NtCommaTrailing_3cNum_3e(__symbols);
+                let __sym1 = __pop_Term_22_28_22(__symbols);
+                let __sym0 = __pop_Termr_23_22_5ba_2dz_5d_2b_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::super::super::__action2::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtCall(__nt), __end));
+                Ok(6)
+            }
+
+            fn __reduce_CommaTrailing_3cList_3e_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // CommaTrailing<List> = List => ActionFn(30);
+                let __sym0 = __pop_NtList(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action30::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtCommaTrailing_3cList_3e(__nt), __end));
+                Ok(7)
+            }
+
+            fn __reduce_CommaTrailing_3cList_3e_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // CommaTrailing<List> =  => ActionFn(31);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action31::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::NtCommaTrailing_3cList_3e(__nt), __end));
+                Ok(7)
+            }
+
+            fn __reduce_CommaTrailing_3cList_3e_2<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // CommaTrailing<List> = (<List> ",")+, List => ActionFn(32);
+                let __sym1 = __pop_NtList(__symbols);
+                let __sym0 = __pop_Nt_28_3cList_3e_20_22_2c_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action32::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtCommaTrailing_3cList_3e(__nt), __end));
+                Ok(7)
+            }
+
+            fn __reduce_CommaTrailing_3cList_3e_3<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // CommaTrailing<List> = (<List> ",")+ => ActionFn(33);
+                let __sym0 = __pop_Nt_28_3cList_3e_20_22_2c_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action33::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtCommaTrailing_3cList_3e(__nt), __end));
+                Ok(7)
+            }
+
+            fn __reduce_CommaTrailing_3cNum_3e_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // CommaTrailing<Num> = Num => ActionFn(34);
+                let __sym0 = __pop_NtNum(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action34::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtCommaTrailing_3cNum_3e(__nt), __end));
+                Ok(8)
+            }
+
+            fn __reduce_CommaTrailing_3cNum_3e_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // CommaTrailing<Num> =  => ActionFn(35);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action35::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::NtCommaTrailing_3cNum_3e(__nt), __end));
+                Ok(8)
+            }
+
+            fn __reduce_CommaTrailing_3cNum_3e_2<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // CommaTrailing<Num> = (<Num> ",")+, Num => ActionFn(36);
+                let __sym1 = __pop_NtNum(__symbols);
+                let __sym0 = __pop_Nt_28_3cNum_3e_20_22_2c_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action36::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtCommaTrailing_3cNum_3e(__nt), __end));
+                Ok(8)
+            }
+
+            fn __reduce_CommaTrailing_3cNum_3e_3<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // CommaTrailing<Num> = (<Num> ",")+ => ActionFn(37);
+                let __sym0 = __pop_Nt_28_3cNum_3e_20_22_2c_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action37::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtCommaTrailing_3cNum_3e(__nt), __end));
+                Ok(8)
+            }
+
+            fn __reduce_List_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // List = "[", CommaTrailing<Num>, "]" => ActionFn(4);
+                let __sym2 = __pop_Term_22_5d_22(__symbols);
+                let __sym1 = __pop_NtCommaTrailing_3cNum_3e(__symbols);
+                let __sym0 = __pop_Term_22_5b_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action4::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtList(__nt), __end));
+                Ok(9)
+            }
+
+            fn __reduce_List_3f_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // List? = List => ActionFn(13);
+                let __sym0 = __pop_NtList(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action13::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtList_3f(__nt), __end));
+                Ok(10)
+            }
+
+            fn __reduce_List_3f_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // List? =  => ActionFn(14);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action14::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::NtList_3f(__nt), __end));
+                Ok(10)
+            }
+
+            fn __reduce_Lists_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Lists = CommaTrailing<List> => ActionFn(3);
+                let __sym0 = __pop_NtCommaTrailing_3cList_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action3::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtLists(__nt), __end));
+                Ok(11)
+            }
+
+            fn __reduce_Num_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Num = r#"[0-9]+"# => ActionFn(5);
+                let __sym0 = __pop_Termr_23_22_5b0_2d9_5d_2b_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action5::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtNum(__nt), __end));
+                Ok(12)
+            }
+
+            fn __reduce_Num_3f_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Num? = Num => ActionFn(8);
+                let __sym0 = __pop_NtNum(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action8::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtNum_3f(__nt), __end));
+                Ok(13)
+            }
+
+            fn __reduce_Num_3f_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Num? =  => ActionFn(9);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action9::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::NtNum_3f(__nt), __end));
+                Ok(13)
+            }
+
+            fn __reduce_____Call_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // __Call = Call => ActionFn(0);
+                let __sym0 = __pop_NtCall(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action0::<>(input, __sym0);
+                Err(Ok(__Symbol::Nt____Call(__nt)))
+            }
+
+            fn __reduce_____Lists_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // __Lists = Lists => ActionFn(1);
+                let __sym0 = __pop_NtLists(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action1::<>(input, __sym0);
+                Err(Ok(__Symbol::Nt____Lists(__nt)))
+            }
+            fn __pop_Term_22_28_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_28_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_29_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_29_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_2c_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2c_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_5b_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_5b_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_5d_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_5d_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Termr_23_22_5b0_2d9_5d_2b_22_23<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Termr_23_22_5ba_2dz_5d_2b_22_23<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Termr_23_22_5ba_2dz_5d_2b_22_23(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_28_3cList_3e_20_22_2c_22_29<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Vec<u32>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_28_3cList_3e_20_22_2c_22_29(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_28_3cList_3e_20_22_2c_22_29_2a<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, ::std::vec::Vec<Vec<u32>>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_28_3cList_3e_20_22_2c_22_29_2a(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_28_3cList_3e_20_22_2c_22_29_2b<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, ::std::vec::Vec<Vec<u32>>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_28_3cList_3e_20_22_2c_22_29_2b(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_28_3cNum_3e_20_22_2c_22_29<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, u32, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_28_3cNum_3e_20_22_2c_22_29(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_28_3cNum_3e_20_22_2c_22_29_2a<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, ::std::vec::Vec<u32>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_28_3cNum_3e_20_22_2c_22_29_2a(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_28_3cNum_3e_20_22_2c_22_29_2b<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, ::std::vec::Vec<u32>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_28_3cNum_3e_20_22_2c_22_29_2b(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtCall<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, (&'input str, Vec<u32>), usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtCall(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtCommaTrailing_3cList_3e<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, ::std::vec::Vec<Vec<u32>>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtCommaTrailing_3cList_3e(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtCommaTrailing_3cNum_3e<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, ::std::vec::Vec<u32>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtCommaTrailing_3cNum_3e(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtList<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Vec<u32>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtList(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtList_3f<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, ::std::option::Option<Vec<u32>>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtList_3f(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtLists<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Vec<Vec<u32>>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtLists(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtNum<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, u32, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtNum(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtNum_3f<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, ::std::option::Option<u32>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtNum_3f(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt____Call<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, (&'input str, Vec<u32>), usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt____Call(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt____Lists<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Vec<Vec<u32>>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt____Lists(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+        }
+        pub use self::__parse_table::parse_Lists;
+        pub use self::__parse_table::parse_Lists_cancelable;
+        pub use self::__parse_table::ListsParser;
+    }
+    pub use self::__parse_table::parse_Lists_cancelable;
+    pub use self::__parse_table::ListsParser;
+}
+pub use self::__parse__Lists::parse_Lists;
+pub use self::__parse__Lists::parse_Lists_cancelable;
+pub use self::__parse__Lists::ListsParser;
+mod __intern_token {
+    #![allow(unused_imports)]
+    use std::str::FromStr;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    extern crate regex as __regex;
+    use std::fmt as __fmt;
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Token<'input>(pub usize, pub &'input str);
+    impl<'a> __fmt::Display for Token<'a> {
+        fn fmt(&self, formatter: &mut __fmt::Formatter) -> Result<(), __fmt::Error> {
+            __fmt::Display::fmt(self.1, formatter)
+        }
+    }
+
+    pub struct __Matcher<'input> {
+        text: &'input str,
+        consumed: usize,
+        regex_set: __regex::RegexSet,
+        regex_vec: Vec<__regex::Regex>,
+    }
+
+    impl<'input> __Matcher<'input> {
+        pub fn new(s: &'input str) -> __Matcher<'input> {
+            let __strs: &[&str] = &[
+                "^(?u:[0-9])+",
+                "^(?u:[a-z])+",
+                "^(?u:\\()",
+                "^(?u:\\))",
+                "^(?u:,)",
+                "^(?u:\\[)",
+                "^(?u:\\])",
+            ];
+            let __regex_set = __regex::RegexSet::new(__strs).unwrap();
+            let __regex_vec = vec![
+                __regex::Regex::new("^(?u:[0-9])+").unwrap(),
+                __regex::Regex::new("^(?u:[a-z])+").unwrap(),
+                __regex::Regex::new("^(?u:\\()").unwrap(),
+                __regex::Regex::new("^(?u:\\))").unwrap(),
+                __regex::Regex::new("^(?u:,)").unwrap(),
+                __regex::Regex::new("^(?u:\\[)").unwrap(),
+                __regex::Regex::new("^(?u:\\])").unwrap(),
+            ];
+            __Matcher {
+                text: s,
+                consumed: 0,
+                regex_set: __regex_set,
+                regex_vec: __regex_vec,
+            }
+        }
+    }
+
+    impl<'input> Iterator for __Matcher<'input> {
+        type Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize,Token<'input>,&'static str>>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let __text = self.text.trim_left();
+            let __whitespace = self.text.len() - __text.len();
+            let __start_offset = self.consumed + __whitespace;
+            if __text.is_empty() {
+                self.text = __text;
+                self.consumed = __start_offset;
+                None
+            } else {
+                let __matches = self.regex_set.matches(__text);
+                if !__matches.matched_any() {
+                    Some(Err(__lalrpop_util::ParseError::InvalidToken {
+                        location: __start_offset,
+                    }))
+                } else {
+                    let mut __longest_match = 0;
+                    let mut __index = 0;
+                    for __i in 0 .. 7 {
+                        if __matches.matched(__i) {
+                            let __match = self.regex_vec[__i].find(__text).unwrap();
+                            let __len = __match.end();
+                            if __len >= __longest_match {
+                                __longest_match = __len;
+                                __index = __i;
+                            }
+                        }
+                    }
+                    let __result = &__text[..__longest_match];
+                    let __remaining = &__text[__longest_match..];
+                    let __end_offset = __start_offset + __longest_match;
+                    self.text = __remaining;
+                    self.consumed = __end_offset;
+                    Some(Ok((__start_offset, Token(__index, __result), __end_offset)))
+                }
+            }
+        }
+    }
+}
+pub use self::__intern_token::Token;
+
+#[allow(unused_variables)]
+fn __action0<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, (&'input str, Vec<u32>), usize),
+) -> (&'input str, Vec<u32>)
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Vec<Vec<u32>>, usize),
+) -> Vec<Vec<u32>>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, __1, _): (usize, ::std::vec::Vec<u32>, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> (&'input str, Vec<u32>)
+{
+    (__0, __1)
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, ::std::vec::Vec<Vec<u32>>, usize),
+) -> Vec<Vec<u32>>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, ::std::vec::Vec<u32>, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> Vec<u32>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> u32
+{
+    //@ src/comma_trailing.lalrpop:14:25 (1 lines)
+    u32::from_str(__0).unwrap()
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<Vec<u32>>, usize),
+    (_, e, _): (usize, ::std::option::Option<Vec<u32>>, usize),
+) -> ::std::vec::Vec<Vec<u32>>
+{
+    match e { None => v, Some(e) => { let mut v = v; v.push(e); v } }
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<u32>, usize),
+    (_, e, _): (usize, ::std::option::Option<u32>, usize),
+) -> ::std::vec::Vec<u32>
+{
+    match e { None => v, Some(e) => { let mut v = v; v.push(e); v } }
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, u32, usize),
+) -> ::std::option::Option<u32>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action9<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::option::Option<u32>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action10<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::vec::Vec<u32>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+fn __action11<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<u32>, usize),
+) -> ::std::vec::Vec<u32>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action12<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, u32, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> u32
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action13<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Vec<u32>, usize),
+) -> ::std::option::Option<Vec<u32>>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action14<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::option::Option<Vec<u32>>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action15<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::vec::Vec<Vec<u32>>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+fn __action16<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<Vec<u32>>, usize),
+) -> ::std::vec::Vec<Vec<u32>>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action17<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Vec<u32>, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> Vec<u32>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action18<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Vec<u32>, usize),
+) -> ::std::vec::Vec<Vec<u32>>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action19<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<Vec<u32>>, usize),
+    (_, e, _): (usize, Vec<u32>, usize),
+) -> ::std::vec::Vec<Vec<u32>>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action20<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, u32, usize),
+) -> ::std::vec::Vec<u32>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action21<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<u32>, usize),
+    (_, e, _): (usize, u32, usize),
+) -> ::std::vec::Vec<u32>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action22<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Vec<u32>, usize),
+    __1: (usize, &'input str, usize),
+) -> ::std::vec::Vec<Vec<u32>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action17(
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action18(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action23<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::vec::Vec<Vec<u32>>, usize),
+    __1: (usize, Vec<u32>, usize),
+    __2: (usize, &'input str, usize),
+) -> ::std::vec::Vec<Vec<u32>>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action17(
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action19(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action24<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::option::Option<Vec<u32>>, usize),
+) -> ::std::vec::Vec<Vec<u32>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action15(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action6(
+        input,
+        __temp0,
+        __0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action25<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::vec::Vec<Vec<u32>>, usize),
+    __1: (usize, ::std::option::Option<Vec<u32>>, usize),
+) -> ::std::vec::Vec<Vec<u32>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action16(
+        input,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action6(
+        input,
+        __temp0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action26<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, u32, usize),
+    __1: (usize, &'input str, usize),
+) -> ::std::vec::Vec<u32>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action12(
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action20(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action27<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::vec::Vec<u32>, usize),
+    __1: (usize, u32, usize),
+    __2: (usize, &'input str, usize),
+) -> ::std::vec::Vec<u32>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action12(
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action21(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action28<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::option::Option<u32>, usize),
+) -> ::std::vec::Vec<u32>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action10(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action7(
+        input,
+        __temp0,
+        __0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action29<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::vec::Vec<u32>, usize),
+    __1: (usize, ::std::option::Option<u32>, usize),
+) -> ::std::vec::Vec<u32>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action11(
+        input,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action7(
+        input,
+        __temp0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action30<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Vec<u32>, usize),
+) -> ::std::vec::Vec<Vec<u32>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action13(
+        input,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action24(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action31<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::vec::Vec<Vec<u32>>
+{
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action14(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action24(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action32<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::vec::Vec<Vec<u32>>, usize),
+    __1: (usize, Vec<u32>, usize),
+) -> ::std::vec::Vec<Vec<u32>>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action13(
+        input,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action25(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action33<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::vec::Vec<Vec<u32>>, usize),
+) -> ::std::vec::Vec<Vec<u32>>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action14(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action25(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action34<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, u32, usize),
+) -> ::std::vec::Vec<u32>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action8(
+        input,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action28(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action35<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::vec::Vec<u32>
+{
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action9(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action28(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action36<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::vec::Vec<u32>, usize),
+    __1: (usize, u32, usize),
+) -> ::std::vec::Vec<u32>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action8(
+        input,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action29(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action37<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::vec::Vec<u32>, usize),
+) -> ::std::vec::Vec<u32>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action9(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action29(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+pub trait __ToTriple<'input, > {
+    type Error;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),Self::Error>;
+}
+
+impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize) {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        Ok(value)
+    }
+}
+impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        value
+    }
+}
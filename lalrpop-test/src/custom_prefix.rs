@@ -0,0 +1,676 @@
+// auto-generated: "lalrpop 0.15.0"
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as lp_lalrpop_util;
+pub use self::lp_parse_table::parse_Sum;
+pub use self::lp_parse_table::parse_Sum_cancelable;
+pub use self::lp_parse_table::SumParser;
+
+mod lp_parse_table {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as lp_lalrpop_util;
+    use super::lp_intern_token::Token;
+    #[allow(dead_code)]
+    pub enum lp_Symbol<'input>
+     {
+        Term_22_2b_22(&'input str),
+        Termr_23_22_5b0_2d9_5d_2b_22_23(&'input str),
+        NtNum(i32),
+        NtSum(i32),
+        Ntlp__Sum(i32),
+    }
+    impl<'input> ::std::fmt::Debug for lp_Symbol<'input>
+    {
+        fn fmt(&self, lp_f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            match *self {
+                lp_Symbol::Term_22_2b_22(..) => lp_f.write_str("\"+\""),
+                lp_Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(..) => lp_f.write_str("r#\"[0-9]+\"#"),
+                lp_Symbol::NtNum(..) => lp_f.write_str("Num"),
+                lp_Symbol::NtSum(..) => lp_f.write_str("Sum"),
+                lp_Symbol::Ntlp__Sum(..) => lp_f.write_str("lp_Sum"),
+            }
+        }
+    }
+    const lp_ACTION: &'static [i8] = &[
+        // State 0
+        0, 4,
+        // State 1
+        -3, 0,
+        // State 2
+        5, 0,
+        // State 3
+        -1, 0,
+        // State 4
+        0, 4,
+        // State 5
+        -2, 0,
+    ];
+    const lp_EOF_ACTION: &'static [i8] = &[
+        // State 0
+        0,
+        // State 1
+        -3,
+        // State 2
+        -4,
+        // State 3
+        -1,
+        // State 4
+        0,
+        // State 5
+        -2,
+    ];
+    const lp_GOTO: &'static [u8] = &[
+        // State 0
+        2, 3, 0,
+        // State 1
+        0, 0, 0,
+        // State 2
+        0, 0, 0,
+        // State 3
+        0, 0, 0,
+        // State 4
+        6, 0, 0,
+        // State 5
+        0, 0, 0,
+    ];
+    const lp_DEFAULT_ACTION: &'static [i8] = &[
+        // State 0
+        0,
+        // State 1
+        -3,
+        // State 2
+        0,
+        // State 3
+        -1,
+        // State 4
+        0,
+        // State 5
+        -2,
+    ];
+    fn lp_expected_tokens(lp_state: usize) -> Vec<::std::string::String> {
+        const lp_TERMINAL: &'static [&'static str] = &[
+            r###""+""###,
+            r###"r#"[0-9]+"#"###,
+        ];
+        lp_ACTION[(lp_state * 2)..].iter().zip(lp_TERMINAL).filter_map(|(&state, terminal)| {
+            if state == 0 {
+                None
+            } else {
+                Some(terminal.to_string())
+            }
+        }).collect()
+    }
+    #[allow(dead_code)]
+    pub fn parse_Sum<
+        'input,
+    >(
+        input: &'input str,
+    ) -> Result<i32, lp_lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let mut lp_tokens = super::lp_intern_token::lp_Matcher::new(input);
+        let mut lp_states = vec![];
+        let mut lp_symbols = vec![];
+        lp_parse::<>(input, 0, &mut lp_tokens, &mut lp_states, &mut lp_symbols, None).and_then(|lp_v| match lp_v { lp_Symbol::Ntlp__Sum(lp_v) => Ok(lp_v), _ => unreachable!() })
+    }
+    #[allow(dead_code)]
+    pub fn parse_Sum_cancelable<
+        'input,
+    >(
+        input: &'input str,
+        lp_cancel: &::std::sync::atomic::AtomicBool,
+    ) -> Result<i32, lp_lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let mut lp_tokens = super::lp_intern_token::lp_Matcher::new(input);
+        let mut lp_states = vec![];
+        let mut lp_symbols = vec![];
+        lp_parse::<>(input, 0, &mut lp_tokens, &mut lp_states, &mut lp_symbols, Some(lp_cancel)).and_then(|lp_v| match lp_v { lp_Symbol::Ntlp__Sum(lp_v) => Ok(lp_v), _ => unreachable!() })
+    }
+
+    #[allow(dead_code)]
+    pub struct SumParser {
+        lp_states: ::std::vec::Vec<i32>,
+        lp_symbols: lp_lalrpop_util::stack::Recycled,
+        lp_cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+    }
+
+    #[allow(dead_code)]
+    impl SumParser {
+        pub fn new() -> SumParser {
+            SumParser { lp_states: vec![], lp_symbols: lp_lalrpop_util::stack::Recycled::new(), lp_cancel: None }
+        }
+
+        pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+            self.lp_cancel = Some(flag);
+        }
+
+        pub fn parse_in_place<'input>(
+            &mut self,
+            input: &'input str,
+        ) -> Result<i32, lp_lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+        {
+            let mut lp_tokens = super::lp_intern_token::lp_Matcher::new(input);
+            let mut lp_symbols = self.lp_symbols.take();
+            let lp_result = lp_parse::<>(input, 0, &mut lp_tokens, &mut self.lp_states, &mut lp_symbols, self.lp_cancel.as_ref().map(|c| &**c)).and_then(|lp_v| match lp_v { lp_Symbol::Ntlp__Sum(lp_v) => Ok(lp_v), _ => unreachable!() });
+            self.lp_symbols.put(lp_symbols);
+            lp_result
+        }
+    }
+
+    fn lp_parse<
+        'input,
+    >(
+        input: &'input str,
+        lp_start_state: i32,
+        mut lp_tokens: &mut Iterator<Item = Result<(usize, Token<'input>, usize), lp_lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+        mut lp_states: &mut ::std::vec::Vec<i32>,
+        mut lp_symbols: &mut ::std::vec::Vec<(usize,lp_Symbol<'input>,usize)>,
+        lp_cancel: Option<&::std::sync::atomic::AtomicBool>,
+    ) -> Result<lp_Symbol<'input>, lp_lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        lp_states.clear();
+        lp_states.push(lp_start_state);
+        lp_symbols.clear();
+        let mut lp_integer;
+        let mut lp_lookahead;
+        let lp_last_location: &mut usize = &mut Default::default();
+        let mut lp_default_reduced = [0_usize; 16];
+        let mut lp_default_reduced_len = 0;
+        'lp_shift: loop {
+            loop {
+                let lp_state = *lp_states.last().unwrap() as usize;
+                let lp_action = lp_DEFAULT_ACTION[lp_state] as i32;
+                if lp_action == 0 {
+                    break;
+                }
+                if lp_default_reduced_len < 16 {
+                    lp_default_reduced[lp_default_reduced_len] = lp_state;
+                    lp_default_reduced_len += 1;
+                }
+                if let Some(lp_flag) = lp_cancel {
+                    if lp_flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                        return Err(lp_lalrpop_util::ParseError::Canceled { location: lp_last_location.clone() });
+                    }
+                }
+                if let Some(r) = lp_reduce(input, lp_action, None, &mut lp_states, &mut lp_symbols, ::std::marker::PhantomData::<()>) {
+                    return r;
+                }
+            }
+            lp_lookahead = match lp_tokens.next() {
+                Some(Ok(v)) => v,
+                None => break 'lp_shift,
+                Some(Err(e)) => return Err(e),
+            };
+            *lp_last_location = lp_lookahead.2.clone();
+            lp_integer = match lp_lookahead.1 {
+                Token(1, _) if true => 0,
+                Token(0, _) if true => 1,
+                _ => {
+                    let lp_state = lp_default_reduced[..lp_default_reduced_len].iter().cloned().find(|&lp_s| true).unwrap_or(*lp_states.last().unwrap() as usize);
+                    let lp_expected = lp_expected_tokens(lp_state);
+                    let lp_error = match Some(lp_lookahead) {
+                        Some(lp_token) => {
+                            lp_lalrpop_util::ParseError::UnrecognizedToken { token: Some(lp_token), expected: lp_expected }
+                        }
+                        None => {
+                            lp_lalrpop_util::ParseError::UnrecognizedEof { location: lp_last_location.clone(), expected: lp_expected }
+                        }
+                    };
+                    return Err(lp_error);
+                }
+            };
+            'lp_inner: loop {
+                let lp_state = *lp_states.last().unwrap() as usize;
+                let lp_action = lp_ACTION[lp_state * 2 + lp_integer] as i32;
+                if lp_action > 0 {
+                    if let Some(lp_flag) = lp_cancel {
+                        if lp_flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                            return Err(lp_lalrpop_util::ParseError::Canceled { location: lp_lookahead.0 });
+                        }
+                    }
+                    let lp_symbol = match lp_integer {
+                        0 => match lp_lookahead.1 {
+                            Token(1, lp_tok0) => lp_Symbol::Term_22_2b_22((lp_tok0)),
+                            _ => unreachable!(),
+                        },
+                        1 => match lp_lookahead.1 {
+                            Token(0, lp_tok0) => lp_Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23((lp_tok0)),
+                            _ => unreachable!(),
+                        },
+                        _ => unreachable!(),
+                    };
+                    lp_states.push(lp_action - 1);
+                    lp_symbols.push((lp_lookahead.0, lp_symbol, lp_lookahead.2));
+                    lp_default_reduced_len = 0;
+                    continue 'lp_shift;
+                } else if lp_action < 0 {
+                    if let Some(lp_flag) = lp_cancel {
+                        if lp_flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                            return Err(lp_lalrpop_util::ParseError::Canceled { location: lp_lookahead.0.clone() });
+                        }
+                    }
+                    if let Some(r) = lp_reduce(input, lp_action, Some(&lp_lookahead.0), &mut lp_states, &mut lp_symbols, ::std::marker::PhantomData::<()>) {
+                        if r.is_err() {
+                            return r;
+                        }
+                        return Err(lp_lalrpop_util::ParseError::ExtraToken { token: lp_lookahead, expected: vec![] });
+                    }
+                } else {
+                    let mut lp_err_lookahead = Some(lp_lookahead);
+                    let mut lp_err_integer: Option<usize> = Some(lp_integer);
+                    let lp_state = lp_default_reduced[..lp_default_reduced_len].iter().cloned().find(|&lp_s| match lp_err_integer { Some(lp_i) => lp_ACTION[lp_s * 2 + lp_i] == 0, None => lp_EOF_ACTION[lp_s] == 0 }).unwrap_or(*lp_states.last().unwrap() as usize);
+                    let lp_expected = lp_expected_tokens(lp_state);
+                    let lp_error = match lp_err_lookahead {
+                        Some(lp_token) => {
+                            if lp_accepts(input,  *lp_states.last().unwrap(), &lp_states[..lp_states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                lp_lalrpop_util::ParseError::ExtraToken { token: lp_token, expected: lp_expected }
+                            } else {
+                                lp_lalrpop_util::ParseError::UnrecognizedToken { token: Some(lp_token), expected: lp_expected }
+                            }
+                        }
+                        None => {
+                            lp_lalrpop_util::ParseError::UnrecognizedEof { location: lp_last_location.clone(), expected: lp_expected }
+                        }
+                    };
+                    return Err(lp_error)
+                }
+            }
+        }
+        loop {
+            let lp_state = *lp_states.last().unwrap() as usize;
+            let lp_action = lp_EOF_ACTION[lp_state] as i32;
+            if lp_action < 0 {
+                if let Some(lp_flag) = lp_cancel {
+                    if lp_flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                        return Err(lp_lalrpop_util::ParseError::Canceled { location: lp_last_location.clone() });
+                    }
+                }
+                if let Some(r) = lp_reduce(input, lp_action, None, &mut lp_states, &mut lp_symbols, ::std::marker::PhantomData::<()>) {
+                    return r;
+                }
+            } else {
+                let mut lp_err_lookahead = None;
+                let mut lp_err_integer: Option<usize> = None;
+                let lp_state = lp_default_reduced[..lp_default_reduced_len].iter().cloned().find(|&lp_s| match lp_err_integer { Some(lp_i) => lp_ACTION[lp_s * 2 + lp_i] == 0, None => lp_EOF_ACTION[lp_s] == 0 }).unwrap_or(*lp_states.last().unwrap() as usize);
+                let lp_expected = lp_expected_tokens(lp_state);
+                let lp_error = match lp_err_lookahead {
+                    Some(lp_token) => {
+                        if lp_accepts(input,  *lp_states.last().unwrap(), &lp_states[..lp_states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                            lp_lalrpop_util::ParseError::ExtraToken { token: lp_token, expected: lp_expected }
+                        } else {
+                            lp_lalrpop_util::ParseError::UnrecognizedToken { token: Some(lp_token), expected: lp_expected }
+                        }
+                    }
+                    None => {
+                        lp_lalrpop_util::ParseError::UnrecognizedEof { location: lp_last_location.clone(), expected: lp_expected }
+                    }
+                };
+                return Err(lp_error)
+            }
+        }
+    }
+    fn lp_accepts<
+        'input,
+    >(
+        input: &'input str,
+        lp_error_state: i32,
+        lp_states: & [i32],
+        lp_opt_integer: Option<usize>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> bool
+    {
+        let mut lp_states = lp_states.to_vec();
+        lp_states.push(lp_error_state);
+        loop {
+            let mut lp_states_len = lp_states.len();
+            let lp_top = lp_states[lp_states_len - 1];
+            let lp_action = match lp_opt_integer {
+                None => lp_EOF_ACTION[lp_top as usize] as i32,
+                Some(lp_integer) => lp_ACTION[(lp_top * 2) as usize + lp_integer] as i32,
+            };
+            if lp_action == 0 { return false; }
+            if lp_action > 0 { return true; }
+            let (lp_to_pop, lp_nt) = match -lp_action {
+                1 => {
+                    (1, 0)
+                }
+                2 => {
+                    (3, 1)
+                }
+                3 => {
+                    (1, 1)
+                }
+                4 => return true,
+                _ => panic!("invalid action code {}", lp_action)
+            };
+            lp_states_len -= lp_to_pop;
+            lp_states.truncate(lp_states_len);
+            let lp_top = lp_states[lp_states_len - 1];
+            let lp_next_state = lp_GOTO[(lp_top * 3 + lp_nt) as usize] as i32 - 1;
+            lp_states.push(lp_next_state);
+        }
+    }
+    pub fn lp_reduce<
+        'input,
+    >(
+        input: &'input str,
+        lp_action: i32,
+        lp_lookahead_start: Option<&usize>,
+        lp_states: &mut ::std::vec::Vec<i32>,
+        lp_symbols: &mut ::std::vec::Vec<(usize,lp_Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Option<Result<lp_Symbol<'input>,lp_lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        let lp_nonterminal = match -lp_action {
+            1 => match lp_reduce_Num_0(input, lp_lookahead_start, lp_states, lp_symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            2 => match lp_reduce_Sum_0(input, lp_lookahead_start, lp_states, lp_symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            3 => match lp_reduce_Sum_1(input, lp_lookahead_start, lp_states, lp_symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            4 => match lp_reduce_lp__Sum_0(input, lp_lookahead_start, lp_states, lp_symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            _ => panic!("invalid action code {}", lp_action)
+        };
+        let lp_state = *lp_states.last().unwrap() as usize;
+        let lp_next_state = lp_GOTO[lp_state * 3 + lp_nonterminal] as i32 - 1;
+        lp_states.push(lp_next_state);
+        None
+    }
+
+    fn lp_reduce_Num_0<
+        'input,
+    >(
+        input: &'input str,
+        lp_lookahead_start: Option<&usize>,
+        lp_states: &mut ::std::vec::Vec<i32>,
+        lp_symbols: &mut ::std::vec::Vec<(usize,lp_Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<lp_Symbol<'input>,lp_lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Num = r#"[0-9]+"# => ActionFn(3);
+        let lp_sym0 = lp_pop_Termr_23_22_5b0_2d9_5d_2b_22_23(lp_symbols);
+        let lp_start = lp_sym0.0.clone();
+        let lp_end = lp_sym0.2.clone();
+        let lp_nt = super::lp_action3::<>(input, lp_sym0);
+        let lp_states_len = lp_states.len();
+        lp_states.truncate(lp_states_len - 1);
+        lp_symbols.push((lp_start, lp_Symbol::NtNum(lp_nt), lp_end));
+        Ok(0)
+    }
+
+    fn lp_reduce_Sum_0<
+        'input,
+    >(
+        input: &'input str,
+        lp_lookahead_start: Option<&usize>,
+        lp_states: &mut ::std::vec::Vec<i32>,
+        lp_symbols: &mut ::std::vec::Vec<(usize,lp_Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<lp_Symbol<'input>,lp_lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Sum = Sum, "+", Num => ActionFn(1);
+        let lp_sym2 = lp_pop_NtNum(lp_symbols);
+        let lp_sym1 = lp_pop_Term_22_2b_22(lp_symbols);
+        let lp_sym0 = lp_pop_NtSum(lp_symbols);
+        let lp_start = lp_sym0.0.clone();
+        let lp_end = lp_sym2.2.clone();
+        let lp_nt = super::lp_action1::<>(input, lp_sym0, lp_sym1, lp_sym2);
+        let lp_states_len = lp_states.len();
+        lp_states.truncate(lp_states_len - 3);
+        lp_symbols.push((lp_start, lp_Symbol::NtSum(lp_nt), lp_end));
+        Ok(1)
+    }
+
+    fn lp_reduce_Sum_1<
+        'input,
+    >(
+        input: &'input str,
+        lp_lookahead_start: Option<&usize>,
+        lp_states: &mut ::std::vec::Vec<i32>,
+        lp_symbols: &mut ::std::vec::Vec<(usize,lp_Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<lp_Symbol<'input>,lp_lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Sum = Num => ActionFn(2);
+        let lp_sym0 = lp_pop_NtNum(lp_symbols);
+        let lp_start = lp_sym0.0.clone();
+        let lp_end = lp_sym0.2.clone();
+        let lp_nt = super::lp_action2::<>(input, lp_sym0);
+        let lp_states_len = lp_states.len();
+        lp_states.truncate(lp_states_len - 1);
+        lp_symbols.push((lp_start, lp_Symbol::NtSum(lp_nt), lp_end));
+        Ok(1)
+    }
+
+    fn lp_reduce_lp__Sum_0<
+        'input,
+    >(
+        input: &'input str,
+        lp_lookahead_start: Option<&usize>,
+        lp_states: &mut ::std::vec::Vec<i32>,
+        lp_symbols: &mut ::std::vec::Vec<(usize,lp_Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<lp_Symbol<'input>,lp_lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // lp_Sum = Sum => ActionFn(0);
+        let lp_sym0 = lp_pop_NtSum(lp_symbols);
+        let lp_start = lp_sym0.0.clone();
+        let lp_end = lp_sym0.2.clone();
+        let lp_nt = super::lp_action0::<>(input, lp_sym0);
+        Err(Ok(lp_Symbol::Ntlp__Sum(lp_nt)))
+    }
+    fn lp_pop_Term_22_2b_22<
+      'input,
+    >(
+        lp_symbols: &mut ::std::vec::Vec<(usize,lp_Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match lp_symbols.pop().unwrap() {
+            (lp_l, lp_Symbol::Term_22_2b_22(lp_v), lp_r) => (lp_l, lp_v, lp_r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn lp_pop_Termr_23_22_5b0_2d9_5d_2b_22_23<
+      'input,
+    >(
+        lp_symbols: &mut ::std::vec::Vec<(usize,lp_Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match lp_symbols.pop().unwrap() {
+            (lp_l, lp_Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(lp_v), lp_r) => (lp_l, lp_v, lp_r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn lp_pop_NtNum<
+      'input,
+    >(
+        lp_symbols: &mut ::std::vec::Vec<(usize,lp_Symbol<'input>,usize)>
+    ) -> (usize, i32, usize)
+     {
+        match lp_symbols.pop().unwrap() {
+            (lp_l, lp_Symbol::NtNum(lp_v), lp_r) => (lp_l, lp_v, lp_r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn lp_pop_NtSum<
+      'input,
+    >(
+        lp_symbols: &mut ::std::vec::Vec<(usize,lp_Symbol<'input>,usize)>
+    ) -> (usize, i32, usize)
+     {
+        match lp_symbols.pop().unwrap() {
+            (lp_l, lp_Symbol::NtSum(lp_v), lp_r) => (lp_l, lp_v, lp_r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn lp_pop_Ntlp__Sum<
+      'input,
+    >(
+        lp_symbols: &mut ::std::vec::Vec<(usize,lp_Symbol<'input>,usize)>
+    ) -> (usize, i32, usize)
+     {
+        match lp_symbols.pop().unwrap() {
+            (lp_l, lp_Symbol::Ntlp__Sum(lp_v), lp_r) => (lp_l, lp_v, lp_r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+}
+mod lp_intern_token {
+    #![allow(unused_imports)]
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as lp_lalrpop_util;
+    extern crate regex as lp_regex;
+    use std::fmt as lp_fmt;
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Token<'input>(pub usize, pub &'input str);
+    impl<'a> lp_fmt::Display for Token<'a> {
+        fn fmt(&self, formatter: &mut lp_fmt::Formatter) -> Result<(), lp_fmt::Error> {
+            lp_fmt::Display::fmt(self.1, formatter)
+        }
+    }
+
+    pub struct lp_Matcher<'input> {
+        text: &'input str,
+        consumed: usize,
+        regex_set: lp_regex::RegexSet,
+        regex_vec: Vec<lp_regex::Regex>,
+    }
+
+    impl<'input> lp_Matcher<'input> {
+        pub fn new(s: &'input str) -> lp_Matcher<'input> {
+            let lp_strs: &[&str] = &[
+                "^(?u:[0-9])+",
+                "^(?u:\\+)",
+            ];
+            let lp_regex_set = lp_regex::RegexSet::new(lp_strs).unwrap();
+            let lp_regex_vec = vec![
+                lp_regex::Regex::new("^(?u:[0-9])+").unwrap(),
+                lp_regex::Regex::new("^(?u:\\+)").unwrap(),
+            ];
+            lp_Matcher {
+                text: s,
+                consumed: 0,
+                regex_set: lp_regex_set,
+                regex_vec: lp_regex_vec,
+            }
+        }
+    }
+
+    impl<'input> Iterator for lp_Matcher<'input> {
+        type Item = Result<(usize, Token<'input>, usize), lp_lalrpop_util::ParseError<usize,Token<'input>,&'static str>>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let lp_text = self.text.trim_left();
+            let lp_whitespace = self.text.len() - lp_text.len();
+            let lp_start_offset = self.consumed + lp_whitespace;
+            if lp_text.is_empty() {
+                self.text = lp_text;
+                self.consumed = lp_start_offset;
+                None
+            } else {
+                let lp_matches = self.regex_set.matches(lp_text);
+                if !lp_matches.matched_any() {
+                    Some(Err(lp_lalrpop_util::ParseError::InvalidToken {
+                        location: lp_start_offset,
+                    }))
+                } else {
+                    let mut lp_longest_match = 0;
+                    let mut lp_index = 0;
+                    for lp_i in 0 .. 2 {
+                        if lp_matches.matched(lp_i) {
+                            let lp_match = self.regex_vec[lp_i].find(lp_text).unwrap();
+                            let lp_len = lp_match.end();
+                            if lp_len >= lp_longest_match {
+                                lp_longest_match = lp_len;
+                                lp_index = lp_i;
+                            }
+                        }
+                    }
+                    let lp_result = &lp_text[..lp_longest_match];
+                    let lp_remaining = &lp_text[lp_longest_match..];
+                    let lp_end_offset = lp_start_offset + lp_longest_match;
+                    self.text = lp_remaining;
+                    self.consumed = lp_end_offset;
+                    Some(Ok((lp_start_offset, Token(lp_index, lp_result), lp_end_offset)))
+                }
+            }
+        }
+    }
+}
+pub use self::lp_intern_token::Token;
+
+#[allow(unused_variables)]
+fn lp_action0<
+    'input,
+>(
+    input: &'input str,
+    (_, lp_0, _): (usize, i32, usize),
+) -> i32
+{
+    (lp_0)
+}
+
+#[allow(unused_variables)]
+fn lp_action1<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, i32, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, i32, usize),
+) -> i32
+{
+    //@ src/custom_prefix.lalrpop:8:28 (1 lines)
+    l + r
+}
+
+#[allow(unused_variables)]
+fn lp_action2<
+    'input,
+>(
+    input: &'input str,
+    (_, lp_0, _): (usize, i32, usize),
+) -> i32
+{
+    (lp_0)
+}
+
+#[allow(unused_variables)]
+fn lp_action3<
+    'input,
+>(
+    input: &'input str,
+    (_, lp_0, _): (usize, &'input str, usize),
+) -> i32
+{
+    //@ src/custom_prefix.lalrpop:12:25 (1 lines)
+    i32::from_str_radix(lp_0, 10).unwrap()
+}
+
+pub trait lp_ToTriple<'input, > {
+    type Error;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),Self::Error>;
+}
+
+impl<'input, > lp_ToTriple<'input, > for (usize, Token<'input>, usize) {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        Ok(value)
+    }
+}
+impl<'input, > lp_ToTriple<'input, > for Result<(usize, Token<'input>, usize),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        value
+    }
+}
@@ -0,0 +1,2155 @@
+// auto-generated: "lalrpop 0.15.0"
+use std::str::FromStr;
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+
+mod __parse__Choice {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    use std::str::FromStr;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__intern_token::Token;
+    #[allow(dead_code)]
+    pub enum __Symbol<'input>
+     {
+        Term_22_2d_22(&'input str),
+        Term_22a_22(&'input str),
+        Term_22x_22(&'input str),
+        Term_22y_22(&'input str),
+        Term_22z_22(&'input str),
+        Termr_23_22_5b0_2d9_5d_2b_22_23(&'input str),
+        Nt_28_29(()),
+        Nt_40L(usize),
+        Nt_40R(usize),
+        NtA(&'input str),
+        NtB(&'input str),
+        NtChoice(&'static str),
+        NtEmpty(()),
+        NtExpr(i32),
+        NtNum(i32),
+        NtOpt((usize, usize)),
+        Nt____Choice(&'static str),
+        Nt____Expr(i32),
+        Nt____Opt((usize, usize)),
+    }
+    const __TERMINALS: &'static [&'static str] = &[
+        r###""-""###,
+        r###""a""###,
+        r###""x""###,
+        r###""y""###,
+        r###""z""###,
+        r###"r#"[0-9]+"#"###,
+    ];
+    const __PRODUCTIONS: &'static [__lalrpop_util::earley::Production] = &[
+        // () =  => ActionFn(12);
+        __lalrpop_util::earley::Production { nonterminal: 0, symbols: &[], dprec: 0, merge: None },
+        // @L =  => ActionFn(14);
+        __lalrpop_util::earley::Production { nonterminal: 1, symbols: &[], dprec: 0, merge: None },
+        // @R =  => ActionFn(13);
+        __lalrpop_util::earley::Production { nonterminal: 2, symbols: &[], dprec: 0, merge: None },
+        // A = "a" => ActionFn(8);
+        __lalrpop_util::earley::Production { nonterminal: 3, symbols: &[__lalrpop_util::earley::Symbol::Terminal(1)], dprec: 0, merge: None },
+        // B = "a" => ActionFn(9);
+        __lalrpop_util::earley::Production { nonterminal: 4, symbols: &[__lalrpop_util::earley::Symbol::Terminal(1)], dprec: 0, merge: None },
+        // Choice = A, "x", "y" => ActionFn(6);
+        __lalrpop_util::earley::Production { nonterminal: 5, symbols: &[__lalrpop_util::earley::Symbol::Nonterminal(3), __lalrpop_util::earley::Symbol::Terminal(2), __lalrpop_util::earley::Symbol::Terminal(3)], dprec: 0, merge: None },
+        // Choice = B, "x", "z" => ActionFn(7);
+        __lalrpop_util::earley::Production { nonterminal: 5, symbols: &[__lalrpop_util::earley::Symbol::Nonterminal(4), __lalrpop_util::earley::Symbol::Terminal(2), __lalrpop_util::earley::Symbol::Terminal(4)], dprec: 0, merge: None },
+        // Empty =  => ActionFn(15);
+        __lalrpop_util::earley::Production { nonterminal: 6, symbols: &[], dprec: 0, merge: None },
+        // Expr = Expr, "-", Expr => ActionFn(3);
+        __lalrpop_util::earley::Production { nonterminal: 7, symbols: &[__lalrpop_util::earley::Symbol::Nonterminal(7), __lalrpop_util::earley::Symbol::Terminal(0), __lalrpop_util::earley::Symbol::Nonterminal(7)], dprec: 0, merge: None },
+        // Expr = Num => ActionFn(4);
+        __lalrpop_util::earley::Production { nonterminal: 7, symbols: &[__lalrpop_util::earley::Symbol::Nonterminal(8)], dprec: 0, merge: None },
+        // Num = r#"[0-9]+"# => ActionFn(5);
+        __lalrpop_util::earley::Production { nonterminal: 8, symbols: &[__lalrpop_util::earley::Symbol::Terminal(5)], dprec: 0, merge: None },
+        // Opt = Empty, "a" => ActionFn(17);
+        __lalrpop_util::earley::Production { nonterminal: 9, symbols: &[__lalrpop_util::earley::Symbol::Nonterminal(6), __lalrpop_util::earley::Symbol::Terminal(1)], dprec: 0, merge: None },
+        // __Choice = Choice => ActionFn(1);
+        __lalrpop_util::earley::Production { nonterminal: 10, symbols: &[__lalrpop_util::earley::Symbol::Nonterminal(5)], dprec: 0, merge: None },
+        // __Expr = Expr => ActionFn(0);
+        __lalrpop_util::earley::Production { nonterminal: 11, symbols: &[__lalrpop_util::earley::Symbol::Nonterminal(7)], dprec: 0, merge: None },
+        // __Opt = Opt => ActionFn(2);
+        __lalrpop_util::earley::Production { nonterminal: 12, symbols: &[__lalrpop_util::earley::Symbol::Nonterminal(9)], dprec: 0, merge: None },
+    ];
+    #[allow(dead_code)]
+    pub fn parse_Choice<
+        'input,
+    >(
+        input: &'input str,
+    ) -> Result<&'static str, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let mut __tokens = super::__intern_token::__Matcher::new(input);
+        let mut __triples = vec![];
+        let mut __integers = vec![];
+        let mut __unrecognized = None;
+        for __token in __tokens {
+            let __lookahead = match __token {
+                Ok(v) => v,
+                Err(e) => return Err(e),
+            };
+            let __integer = match __lookahead.1 {
+                Token(1, _) if true => 0,
+                Token(2, _) if true => 1,
+                Token(3, _) if true => 2,
+                Token(4, _) if true => 3,
+                Token(5, _) if true => 4,
+                Token(0, _) if true => 5,
+                _ => {
+                    __unrecognized = Some(__lookahead);
+                    break;
+                }
+            };
+            __triples.push(__lookahead);
+            __integers.push(__integer);
+        }
+        let __chart = __lalrpop_util::earley::Chart::parse(__PRODUCTIONS, 10, &__integers);
+        let __error = match __chart.error() {
+            Some(__index) => Some(__index),
+            None if __unrecognized.is_some() => Some(__integers.len()),
+            None => None,
+        };
+        if let Some(__index) = __error {
+            let __expected = __chart.expected(__index).into_iter().map(|__t| __TERMINALS[__t].to_string()).collect();
+            if __index < __triples.len() {
+                let __token = __triples.swap_remove(__index);
+                if __chart.is_complete(__index) {
+                    return Err(__lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected });
+                }
+                return Err(__lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected });
+            }
+            if __unrecognized.is_some() {
+                return Err(__lalrpop_util::ParseError::UnrecognizedToken { token: __unrecognized, expected: __expected });
+            }
+            return Err(__lalrpop_util::ParseError::UnrecognizedEof { location: __triples.last().map(|__t| __t.2.clone()).unwrap_or_default(), expected: __expected });
+        }
+        __replay(input, __chart.derivation(), __triples, &__integers, ::std::marker::PhantomData::<()>)
+    }
+    #[allow(dead_code)]
+    pub fn parse_Choice_all<
+        'input,
+    >(
+        input: &'input str,
+    ) -> Result<::std::vec::Vec<&'static str>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let mut __tokens = super::__intern_token::__Matcher::new(input);
+        let mut __triples = vec![];
+        let mut __integers = vec![];
+        let mut __unrecognized = None;
+        for __token in __tokens {
+            let __lookahead = match __token {
+                Ok(v) => v,
+                Err(e) => return Err(e),
+            };
+            let __integer = match __lookahead.1 {
+                Token(1, _) if true => 0,
+                Token(2, _) if true => 1,
+                Token(3, _) if true => 2,
+                Token(4, _) if true => 3,
+                Token(5, _) if true => 4,
+                Token(0, _) if true => 5,
+                _ => {
+                    __unrecognized = Some(__lookahead);
+                    break;
+                }
+            };
+            __triples.push(__lookahead);
+            __integers.push(__integer);
+        }
+        let __chart = __lalrpop_util::earley::Chart::parse(__PRODUCTIONS, 10, &__integers);
+        let __error = match __chart.error() {
+            Some(__index) => Some(__index),
+            None if __unrecognized.is_some() => Some(__integers.len()),
+            None => None,
+        };
+        if let Some(__index) = __error {
+            let __expected = __chart.expected(__index).into_iter().map(|__t| __TERMINALS[__t].to_string()).collect();
+            if __index < __triples.len() {
+                let __token = __triples.swap_remove(__index);
+                if __chart.is_complete(__index) {
+                    return Err(__lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected });
+                }
+                return Err(__lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected });
+            }
+            if __unrecognized.is_some() {
+                return Err(__lalrpop_util::ParseError::UnrecognizedToken { token: __unrecognized, expected: __expected });
+            }
+            return Err(__lalrpop_util::ParseError::UnrecognizedEof { location: __triples.last().map(|__t| __t.2.clone()).unwrap_or_default(), expected: __expected });
+        }
+        let mut __results = vec![];
+        let mut __failure = None;
+        for __steps in __chart.forest().derivations() {
+            match __replay(input, __steps, __triples.clone(), &__integers, ::std::marker::PhantomData::<()>) {
+                Ok(v) => __results.push(v),
+                Err(e) => if __failure.is_none() { __failure = Some(e); },
+            }
+        }
+        match __failure {
+            Some(e) if __results.is_empty() => Err(e),
+            _ => Ok(__results),
+        }
+    }
+    fn __replay<
+        'input,
+    >(
+        input: &'input str,
+        __steps: ::std::vec::Vec<__lalrpop_util::earley::Step>,
+        __triples: ::std::vec::Vec<(usize, Token<'input>, usize)>,
+        __integers: &[usize],
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<&'static str, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let mut __symbols = vec![];
+        let mut __tokens = __triples.into_iter().zip(__integers.iter().cloned()).peekable();
+        for __step in __steps {
+            match __step {
+                __lalrpop_util::earley::Step::Shift => {
+                    let (__lookahead, __integer) = __tokens.next().unwrap();
+                    let __symbol = match __integer {
+                        0 => match __lookahead.1 {
+                            Token(1, __tok0) => __Symbol::Term_22_2d_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        1 => match __lookahead.1 {
+                            Token(2, __tok0) => __Symbol::Term_22a_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        2 => match __lookahead.1 {
+                            Token(3, __tok0) => __Symbol::Term_22x_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        3 => match __lookahead.1 {
+                            Token(4, __tok0) => __Symbol::Term_22y_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        4 => match __lookahead.1 {
+                            Token(5, __tok0) => __Symbol::Term_22z_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        5 => match __lookahead.1 {
+                            Token(0, __tok0) => __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        _ => unreachable!(),
+                    };
+                    __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                }
+                __lalrpop_util::earley::Step::Reduce(__production) => {
+                    let __lookahead_start = __tokens.peek().map(|&(ref __t, _)| __t.0.clone());
+                    if let Some(r) = __reduce(input, __production, __lookahead_start.as_ref(), &mut __symbols, ::std::marker::PhantomData::<()>) {
+                        return r;
+                    }
+                }
+                __lalrpop_util::earley::Step::Rewind(_) | __lalrpop_util::earley::Step::Merge(_) | __lalrpop_util::earley::Step::Recall { .. } | __lalrpop_util::earley::Step::Keep(_) => unreachable!(),
+            }
+        }
+        unreachable!()
+    }
+    fn __reduce<
+        'input,
+    >(
+        input: &'input str,
+        __production: usize,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Option<Result<&'static str,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        match __production {
+            0 => {
+                // () =  => ActionFn(12);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action12::<>(input, &__start, &__end);
+                __symbols.push((__start, __Symbol::Nt_28_29(__nt), __end));
+            }
+            1 => {
+                // @L =  => ActionFn(14);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action14::<>(input, &__start, &__end);
+                __symbols.push((__start, __Symbol::Nt_40L(__nt), __end));
+            }
+            2 => {
+                // @R =  => ActionFn(13);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action13::<>(input, &__start, &__end);
+                __symbols.push((__start, __Symbol::Nt_40R(__nt), __end));
+            }
+            3 => {
+                // A = "a" => ActionFn(8);
+                let __sym0 = __pop_Term_22a_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action8::<>(input, __sym0);
+                __symbols.push((__start, __Symbol::NtA(__nt), __end));
+            }
+            4 => {
+                // B = "a" => ActionFn(9);
+                let __sym0 = __pop_Term_22a_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action9::<>(input, __sym0);
+                __symbols.push((__start, __Symbol::NtB(__nt), __end));
+            }
+            5 => {
+                // Choice = A, "x", "y" => ActionFn(6);
+                let __sym2 = __pop_Term_22y_22(__symbols);
+                let __sym1 = __pop_Term_22x_22(__symbols);
+                let __sym0 = __pop_NtA(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action6::<>(input, __sym0, __sym1, __sym2);
+                __symbols.push((__start, __Symbol::NtChoice(__nt), __end));
+            }
+            6 => {
+                // Choice = B, "x", "z" => ActionFn(7);
+                let __sym2 = __pop_Term_22z_22(__symbols);
+                let __sym1 = __pop_Term_22x_22(__symbols);
+                let __sym0 = __pop_NtB(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action7::<>(input, __sym0, __sym1, __sym2);
+                __symbols.push((__start, __Symbol::NtChoice(__nt), __end));
+            }
+            7 => {
+                // Empty =  => ActionFn(15);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action15::<>(input, &__start, &__end);
+                __symbols.push((__start, __Symbol::NtEmpty(__nt), __end));
+            }
+            8 => {
+                // Expr = Expr, "-", Expr => ActionFn(3);
+                let __sym2 = __pop_NtExpr(__symbols);
+                let __sym1 = __pop_Term_22_2d_22(__symbols);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action3::<>(input, __sym0, __sym1, __sym2);
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+            }
+            9 => {
+                // Expr = Num => ActionFn(4);
+                let __sym0 = __pop_NtNum(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action4::<>(input, __sym0);
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+            }
+            10 => {
+                // Num = r#"[0-9]+"# => ActionFn(5);
+                let __sym0 = __pop_Termr_23_22_5b0_2d9_5d_2b_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action5::<>(input, __sym0);
+                __symbols.push((__start, __Symbol::NtNum(__nt), __end));
+            }
+            11 => {
+                // Opt = Empty, "a" => ActionFn(17);
+                let __sym1 = __pop_Term_22a_22(__symbols);
+                let __sym0 = __pop_NtEmpty(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action17::<>(input, __sym0, __sym1);
+                __symbols.push((__start, __Symbol::NtOpt(__nt), __end));
+            }
+            12 => {
+                // __Choice = Choice => ActionFn(1);
+                let __sym0 = __pop_NtChoice(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action1::<>(input, __sym0);
+                return Some(Ok(__nt));
+            }
+            13 => {
+                // __Expr = Expr => ActionFn(0);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action0::<>(input, __sym0);
+                __symbols.push((__start, __Symbol::Nt____Expr(__nt), __end));
+            }
+            14 => {
+                // __Opt = Opt => ActionFn(2);
+                let __sym0 = __pop_NtOpt(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action2::<>(input, __sym0);
+                __symbols.push((__start, __Symbol::Nt____Opt(__nt), __end));
+            }
+            _ => panic!("invalid production {}", __production)
+        }
+        None
+    }
+    fn __pop_Term_22_2d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22a_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22a_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22x_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22x_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22y_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22y_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22z_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22z_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_5b0_2d9_5d_2b_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_29<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (), usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_29(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_40L<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, usize, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_40L(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_40R<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, usize, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_40R(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtA<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtA(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtB<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtB(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtChoice<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'static str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtChoice(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtEmpty<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (), usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtEmpty(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtExpr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, i32, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtExpr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtNum<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, i32, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtNum(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtOpt<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (usize, usize), usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtOpt(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____Choice<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'static str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Choice(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____Expr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, i32, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Expr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____Opt<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (usize, usize), usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Opt(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+}
+pub use self::__parse__Choice::parse_Choice;
+pub use self::__parse__Choice::parse_Choice_all;
+
+mod __parse__Expr {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    use std::str::FromStr;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__intern_token::Token;
+    #[allow(dead_code)]
+    pub enum __Symbol<'input>
+     {
+        Term_22_2d_22(&'input str),
+        Term_22a_22(&'input str),
+        Term_22x_22(&'input str),
+        Term_22y_22(&'input str),
+        Term_22z_22(&'input str),
+        Termr_23_22_5b0_2d9_5d_2b_22_23(&'input str),
+        Nt_28_29(()),
+        Nt_40L(usize),
+        Nt_40R(usize),
+        NtA(&'input str),
+        NtB(&'input str),
+        NtChoice(&'static str),
+        NtEmpty(()),
+        NtExpr(i32),
+        NtNum(i32),
+        NtOpt((usize, usize)),
+        Nt____Choice(&'static str),
+        Nt____Expr(i32),
+        Nt____Opt((usize, usize)),
+    }
+    const __TERMINALS: &'static [&'static str] = &[
+        r###""-""###,
+        r###""a""###,
+        r###""x""###,
+        r###""y""###,
+        r###""z""###,
+        r###"r#"[0-9]+"#"###,
+    ];
+    const __PRODUCTIONS: &'static [__lalrpop_util::earley::Production] = &[
+        // () =  => ActionFn(12);
+        __lalrpop_util::earley::Production { nonterminal: 0, symbols: &[], dprec: 0, merge: None },
+        // @L =  => ActionFn(14);
+        __lalrpop_util::earley::Production { nonterminal: 1, symbols: &[], dprec: 0, merge: None },
+        // @R =  => ActionFn(13);
+        __lalrpop_util::earley::Production { nonterminal: 2, symbols: &[], dprec: 0, merge: None },
+        // A = "a" => ActionFn(8);
+        __lalrpop_util::earley::Production { nonterminal: 3, symbols: &[__lalrpop_util::earley::Symbol::Terminal(1)], dprec: 0, merge: None },
+        // B = "a" => ActionFn(9);
+        __lalrpop_util::earley::Production { nonterminal: 4, symbols: &[__lalrpop_util::earley::Symbol::Terminal(1)], dprec: 0, merge: None },
+        // Choice = A, "x", "y" => ActionFn(6);
+        __lalrpop_util::earley::Production { nonterminal: 5, symbols: &[__lalrpop_util::earley::Symbol::Nonterminal(3), __lalrpop_util::earley::Symbol::Terminal(2), __lalrpop_util::earley::Symbol::Terminal(3)], dprec: 0, merge: None },
+        // Choice = B, "x", "z" => ActionFn(7);
+        __lalrpop_util::earley::Production { nonterminal: 5, symbols: &[__lalrpop_util::earley::Symbol::Nonterminal(4), __lalrpop_util::earley::Symbol::Terminal(2), __lalrpop_util::earley::Symbol::Terminal(4)], dprec: 0, merge: None },
+        // Empty =  => ActionFn(15);
+        __lalrpop_util::earley::Production { nonterminal: 6, symbols: &[], dprec: 0, merge: None },
+        // Expr = Expr, "-", Expr => ActionFn(3);
+        __lalrpop_util::earley::Production { nonterminal: 7, symbols: &[__lalrpop_util::earley::Symbol::Nonterminal(7), __lalrpop_util::earley::Symbol::Terminal(0), __lalrpop_util::earley::Symbol::Nonterminal(7)], dprec: 0, merge: None },
+        // Expr = Num => ActionFn(4);
+        __lalrpop_util::earley::Production { nonterminal: 7, symbols: &[__lalrpop_util::earley::Symbol::Nonterminal(8)], dprec: 0, merge: None },
+        // Num = r#"[0-9]+"# => ActionFn(5);
+        __lalrpop_util::earley::Production { nonterminal: 8, symbols: &[__lalrpop_util::earley::Symbol::Terminal(5)], dprec: 0, merge: None },
+        // Opt = Empty, "a" => ActionFn(17);
+        __lalrpop_util::earley::Production { nonterminal: 9, symbols: &[__lalrpop_util::earley::Symbol::Nonterminal(6), __lalrpop_util::earley::Symbol::Terminal(1)], dprec: 0, merge: None },
+        // __Choice = Choice => ActionFn(1);
+        __lalrpop_util::earley::Production { nonterminal: 10, symbols: &[__lalrpop_util::earley::Symbol::Nonterminal(5)], dprec: 0, merge: None },
+        // __Expr = Expr => ActionFn(0);
+        __lalrpop_util::earley::Production { nonterminal: 11, symbols: &[__lalrpop_util::earley::Symbol::Nonterminal(7)], dprec: 0, merge: None },
+        // __Opt = Opt => ActionFn(2);
+        __lalrpop_util::earley::Production { nonterminal: 12, symbols: &[__lalrpop_util::earley::Symbol::Nonterminal(9)], dprec: 0, merge: None },
+    ];
+    #[allow(dead_code)]
+    pub fn parse_Expr<
+        'input,
+    >(
+        input: &'input str,
+    ) -> Result<i32, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let mut __tokens = super::__intern_token::__Matcher::new(input);
+        let mut __triples = vec![];
+        let mut __integers = vec![];
+        let mut __unrecognized = None;
+        for __token in __tokens {
+            let __lookahead = match __token {
+                Ok(v) => v,
+                Err(e) => return Err(e),
+            };
+            let __integer = match __lookahead.1 {
+                Token(1, _) if true => 0,
+                Token(2, _) if true => 1,
+                Token(3, _) if true => 2,
+                Token(4, _) if true => 3,
+                Token(5, _) if true => 4,
+                Token(0, _) if true => 5,
+                _ => {
+                    __unrecognized = Some(__lookahead);
+                    break;
+                }
+            };
+            __triples.push(__lookahead);
+            __integers.push(__integer);
+        }
+        let __chart = __lalrpop_util::earley::Chart::parse(__PRODUCTIONS, 11, &__integers);
+        let __error = match __chart.error() {
+            Some(__index) => Some(__index),
+            None if __unrecognized.is_some() => Some(__integers.len()),
+            None => None,
+        };
+        if let Some(__index) = __error {
+            let __expected = __chart.expected(__index).into_iter().map(|__t| __TERMINALS[__t].to_string()).collect();
+            if __index < __triples.len() {
+                let __token = __triples.swap_remove(__index);
+                if __chart.is_complete(__index) {
+                    return Err(__lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected });
+                }
+                return Err(__lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected });
+            }
+            if __unrecognized.is_some() {
+                return Err(__lalrpop_util::ParseError::UnrecognizedToken { token: __unrecognized, expected: __expected });
+            }
+            return Err(__lalrpop_util::ParseError::UnrecognizedEof { location: __triples.last().map(|__t| __t.2.clone()).unwrap_or_default(), expected: __expected });
+        }
+        __replay(input, __chart.derivation(), __triples, &__integers, ::std::marker::PhantomData::<()>)
+    }
+    #[allow(dead_code)]
+    pub fn parse_Expr_all<
+        'input,
+    >(
+        input: &'input str,
+    ) -> Result<::std::vec::Vec<i32>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let mut __tokens = super::__intern_token::__Matcher::new(input);
+        let mut __triples = vec![];
+        let mut __integers = vec![];
+        let mut __unrecognized = None;
+        for __token in __tokens {
+            let __lookahead = match __token {
+                Ok(v) => v,
+                Err(e) => return Err(e),
+            };
+            let __integer = match __lookahead.1 {
+                Token(1, _) if true => 0,
+                Token(2, _) if true => 1,
+                Token(3, _) if true => 2,
+                Token(4, _) if true => 3,
+                Token(5, _) if true => 4,
+                Token(0, _) if true => 5,
+                _ => {
+                    __unrecognized = Some(__lookahead);
+                    break;
+                }
+            };
+            __triples.push(__lookahead);
+            __integers.push(__integer);
+        }
+        let __chart = __lalrpop_util::earley::Chart::parse(__PRODUCTIONS, 11, &__integers);
+        let __error = match __chart.error() {
+            Some(__index) => Some(__index),
+            None if __unrecognized.is_some() => Some(__integers.len()),
+            None => None,
+        };
+        if let Some(__index) = __error {
+            let __expected = __chart.expected(__index).into_iter().map(|__t| __TERMINALS[__t].to_string()).collect();
+            if __index < __triples.len() {
+                let __token = __triples.swap_remove(__index);
+                if __chart.is_complete(__index) {
+                    return Err(__lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected });
+                }
+                return Err(__lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected });
+            }
+            if __unrecognized.is_some() {
+                return Err(__lalrpop_util::ParseError::UnrecognizedToken { token: __unrecognized, expected: __expected });
+            }
+            return Err(__lalrpop_util::ParseError::UnrecognizedEof { location: __triples.last().map(|__t| __t.2.clone()).unwrap_or_default(), expected: __expected });
+        }
+        let mut __results = vec![];
+        let mut __failure = None;
+        for __steps in __chart.forest().derivations() {
+            match __replay(input, __steps, __triples.clone(), &__integers, ::std::marker::PhantomData::<()>) {
+                Ok(v) => __results.push(v),
+                Err(e) => if __failure.is_none() { __failure = Some(e); },
+            }
+        }
+        match __failure {
+            Some(e) if __results.is_empty() => Err(e),
+            _ => Ok(__results),
+        }
+    }
+    fn __replay<
+        'input,
+    >(
+        input: &'input str,
+        __steps: ::std::vec::Vec<__lalrpop_util::earley::Step>,
+        __triples: ::std::vec::Vec<(usize, Token<'input>, usize)>,
+        __integers: &[usize],
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<i32, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let mut __symbols = vec![];
+        let mut __tokens = __triples.into_iter().zip(__integers.iter().cloned()).peekable();
+        for __step in __steps {
+            match __step {
+                __lalrpop_util::earley::Step::Shift => {
+                    let (__lookahead, __integer) = __tokens.next().unwrap();
+                    let __symbol = match __integer {
+                        0 => match __lookahead.1 {
+                            Token(1, __tok0) => __Symbol::Term_22_2d_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        1 => match __lookahead.1 {
+                            Token(2, __tok0) => __Symbol::Term_22a_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        2 => match __lookahead.1 {
+                            Token(3, __tok0) => __Symbol::Term_22x_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        3 => match __lookahead.1 {
+                            Token(4, __tok0) => __Symbol::Term_22y_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        4 => match __lookahead.1 {
+                            Token(5, __tok0) => __Symbol::Term_22z_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        5 => match __lookahead.1 {
+                            Token(0, __tok0) => __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        _ => unreachable!(),
+                    };
+                    __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                }
+                __lalrpop_util::earley::Step::Reduce(__production) => {
+                    let __lookahead_start = __tokens.peek().map(|&(ref __t, _)| __t.0.clone());
+                    if let Some(r) = __reduce(input, __production, __lookahead_start.as_ref(), &mut __symbols, ::std::marker::PhantomData::<()>) {
+                        return r;
+                    }
+                }
+                __lalrpop_util::earley::Step::Rewind(_) | __lalrpop_util::earley::Step::Merge(_) | __lalrpop_util::earley::Step::Recall { .. } | __lalrpop_util::earley::Step::Keep(_) => unreachable!(),
+            }
+        }
+        unreachable!()
+    }
+    fn __reduce<
+        'input,
+    >(
+        input: &'input str,
+        __production: usize,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Option<Result<i32,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        match __production {
+            0 => {
+                // () =  => ActionFn(12);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action12::<>(input, &__start, &__end);
+                __symbols.push((__start, __Symbol::Nt_28_29(__nt), __end));
+            }
+            1 => {
+                // @L =  => ActionFn(14);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action14::<>(input, &__start, &__end);
+                __symbols.push((__start, __Symbol::Nt_40L(__nt), __end));
+            }
+            2 => {
+                // @R =  => ActionFn(13);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action13::<>(input, &__start, &__end);
+                __symbols.push((__start, __Symbol::Nt_40R(__nt), __end));
+            }
+            3 => {
+                // A = "a" => ActionFn(8);
+                let __sym0 = __pop_Term_22a_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action8::<>(input, __sym0);
+                __symbols.push((__start, __Symbol::NtA(__nt), __end));
+            }
+            4 => {
+                // B = "a" => ActionFn(9);
+                let __sym0 = __pop_Term_22a_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action9::<>(input, __sym0);
+                __symbols.push((__start, __Symbol::NtB(__nt), __end));
+            }
+            5 => {
+                // Choice = A, "x", "y" => ActionFn(6);
+                let __sym2 = __pop_Term_22y_22(__symbols);
+                let __sym1 = __pop_Term_22x_22(__symbols);
+                let __sym0 = __pop_NtA(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action6::<>(input, __sym0, __sym1, __sym2);
+                __symbols.push((__start, __Symbol::NtChoice(__nt), __end));
+            }
+            6 => {
+                // Choice = B, "x", "z" => ActionFn(7);
+                let __sym2 = __pop_Term_22z_22(__symbols);
+                let __sym1 = __pop_Term_22x_22(__symbols);
+                let __sym0 = __pop_NtB(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action7::<>(input, __sym0, __sym1, __sym2);
+                __symbols.push((__start, __Symbol::NtChoice(__nt), __end));
+            }
+            7 => {
+                // Empty =  => ActionFn(15);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action15::<>(input, &__start, &__end);
+                __symbols.push((__start, __Symbol::NtEmpty(__nt), __end));
+            }
+            8 => {
+                // Expr = Expr, "-", Expr => ActionFn(3);
+                let __sym2 = __pop_NtExpr(__symbols);
+                let __sym1 = __pop_Term_22_2d_22(__symbols);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action3::<>(input, __sym0, __sym1, __sym2);
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+            }
+            9 => {
+                // Expr = Num => ActionFn(4);
+                let __sym0 = __pop_NtNum(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action4::<>(input, __sym0);
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+            }
+            10 => {
+                // Num = r#"[0-9]+"# => ActionFn(5);
+                let __sym0 = __pop_Termr_23_22_5b0_2d9_5d_2b_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action5::<>(input, __sym0);
+                __symbols.push((__start, __Symbol::NtNum(__nt), __end));
+            }
+            11 => {
+                // Opt = Empty, "a" => ActionFn(17);
+                let __sym1 = __pop_Term_22a_22(__symbols);
+                let __sym0 = __pop_NtEmpty(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action17::<>(input, __sym0, __sym1);
+                __symbols.push((__start, __Symbol::NtOpt(__nt), __end));
+            }
+            12 => {
+                // __Choice = Choice => ActionFn(1);
+                let __sym0 = __pop_NtChoice(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action1::<>(input, __sym0);
+                __symbols.push((__start, __Symbol::Nt____Choice(__nt), __end));
+            }
+            13 => {
+                // __Expr = Expr => ActionFn(0);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action0::<>(input, __sym0);
+                return Some(Ok(__nt));
+            }
+            14 => {
+                // __Opt = Opt => ActionFn(2);
+                let __sym0 = __pop_NtOpt(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action2::<>(input, __sym0);
+                __symbols.push((__start, __Symbol::Nt____Opt(__nt), __end));
+            }
+            _ => panic!("invalid production {}", __production)
+        }
+        None
+    }
+    fn __pop_Term_22_2d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22a_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22a_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22x_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22x_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22y_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22y_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22z_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22z_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_5b0_2d9_5d_2b_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_29<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (), usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_29(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_40L<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, usize, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_40L(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_40R<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, usize, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_40R(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtA<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtA(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtB<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtB(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtChoice<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'static str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtChoice(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtEmpty<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (), usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtEmpty(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtExpr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, i32, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtExpr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtNum<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, i32, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtNum(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtOpt<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (usize, usize), usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtOpt(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____Choice<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'static str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Choice(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____Expr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, i32, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Expr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____Opt<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (usize, usize), usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Opt(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+}
+pub use self::__parse__Expr::parse_Expr;
+pub use self::__parse__Expr::parse_Expr_all;
+
+mod __parse__Opt {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    use std::str::FromStr;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__intern_token::Token;
+    #[allow(dead_code)]
+    pub enum __Symbol<'input>
+     {
+        Term_22_2d_22(&'input str),
+        Term_22a_22(&'input str),
+        Term_22x_22(&'input str),
+        Term_22y_22(&'input str),
+        Term_22z_22(&'input str),
+        Termr_23_22_5b0_2d9_5d_2b_22_23(&'input str),
+        Nt_28_29(()),
+        Nt_40L(usize),
+        Nt_40R(usize),
+        NtA(&'input str),
+        NtB(&'input str),
+        NtChoice(&'static str),
+        NtEmpty(()),
+        NtExpr(i32),
+        NtNum(i32),
+        NtOpt((usize, usize)),
+        Nt____Choice(&'static str),
+        Nt____Expr(i32),
+        Nt____Opt((usize, usize)),
+    }
+    const __TERMINALS: &'static [&'static str] = &[
+        r###""-""###,
+        r###""a""###,
+        r###""x""###,
+        r###""y""###,
+        r###""z""###,
+        r###"r#"[0-9]+"#"###,
+    ];
+    const __PRODUCTIONS: &'static [__lalrpop_util::earley::Production] = &[
+        // () =  => ActionFn(12);
+        __lalrpop_util::earley::Production { nonterminal: 0, symbols: &[], dprec: 0, merge: None },
+        // @L =  => ActionFn(14);
+        __lalrpop_util::earley::Production { nonterminal: 1, symbols: &[], dprec: 0, merge: None },
+        // @R =  => ActionFn(13);
+        __lalrpop_util::earley::Production { nonterminal: 2, symbols: &[], dprec: 0, merge: None },
+        // A = "a" => ActionFn(8);
+        __lalrpop_util::earley::Production { nonterminal: 3, symbols: &[__lalrpop_util::earley::Symbol::Terminal(1)], dprec: 0, merge: None },
+        // B = "a" => ActionFn(9);
+        __lalrpop_util::earley::Production { nonterminal: 4, symbols: &[__lalrpop_util::earley::Symbol::Terminal(1)], dprec: 0, merge: None },
+        // Choice = A, "x", "y" => ActionFn(6);
+        __lalrpop_util::earley::Production { nonterminal: 5, symbols: &[__lalrpop_util::earley::Symbol::Nonterminal(3), __lalrpop_util::earley::Symbol::Terminal(2), __lalrpop_util::earley::Symbol::Terminal(3)], dprec: 0, merge: None },
+        // Choice = B, "x", "z" => ActionFn(7);
+        __lalrpop_util::earley::Production { nonterminal: 5, symbols: &[__lalrpop_util::earley::Symbol::Nonterminal(4), __lalrpop_util::earley::Symbol::Terminal(2), __lalrpop_util::earley::Symbol::Terminal(4)], dprec: 0, merge: None },
+        // Empty =  => ActionFn(15);
+        __lalrpop_util::earley::Production { nonterminal: 6, symbols: &[], dprec: 0, merge: None },
+        // Expr = Expr, "-", Expr => ActionFn(3);
+        __lalrpop_util::earley::Production { nonterminal: 7, symbols: &[__lalrpop_util::earley::Symbol::Nonterminal(7), __lalrpop_util::earley::Symbol::Terminal(0), __lalrpop_util::earley::Symbol::Nonterminal(7)], dprec: 0, merge: None },
+        // Expr = Num => ActionFn(4);
+        __lalrpop_util::earley::Production { nonterminal: 7, symbols: &[__lalrpop_util::earley::Symbol::Nonterminal(8)], dprec: 0, merge: None },
+        // Num = r#"[0-9]+"# => ActionFn(5);
+        __lalrpop_util::earley::Production { nonterminal: 8, symbols: &[__lalrpop_util::earley::Symbol::Terminal(5)], dprec: 0, merge: None },
+        // Opt = Empty, "a" => ActionFn(17);
+        __lalrpop_util::earley::Production { nonterminal: 9, symbols: &[__lalrpop_util::earley::Symbol::Nonterminal(6), __lalrpop_util::earley::Symbol::Terminal(1)], dprec: 0, merge: None },
+        // __Choice = Choice => ActionFn(1);
+        __lalrpop_util::earley::Production { nonterminal: 10, symbols: &[__lalrpop_util::earley::Symbol::Nonterminal(5)], dprec: 0, merge: None },
+        // __Expr = Expr => ActionFn(0);
+        __lalrpop_util::earley::Production { nonterminal: 11, symbols: &[__lalrpop_util::earley::Symbol::Nonterminal(7)], dprec: 0, merge: None },
+        // __Opt = Opt => ActionFn(2);
+        __lalrpop_util::earley::Production { nonterminal: 12, symbols: &[__lalrpop_util::earley::Symbol::Nonterminal(9)], dprec: 0, merge: None },
+    ];
+    #[allow(dead_code)]
+    pub fn parse_Opt<
+        'input,
+    >(
+        input: &'input str,
+    ) -> Result<(usize, usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let mut __tokens = super::__intern_token::__Matcher::new(input);
+        let mut __triples = vec![];
+        let mut __integers = vec![];
+        let mut __unrecognized = None;
+        for __token in __tokens {
+            let __lookahead = match __token {
+                Ok(v) => v,
+                Err(e) => return Err(e),
+            };
+            let __integer = match __lookahead.1 {
+                Token(1, _) if true => 0,
+                Token(2, _) if true => 1,
+                Token(3, _) if true => 2,
+                Token(4, _) if true => 3,
+                Token(5, _) if true => 4,
+                Token(0, _) if true => 5,
+                _ => {
+                    __unrecognized = Some(__lookahead);
+                    break;
+                }
+            };
+            __triples.push(__lookahead);
+            __integers.push(__integer);
+        }
+        let __chart = __lalrpop_util::earley::Chart::parse(__PRODUCTIONS, 12, &__integers);
+        let __error = match __chart.error() {
+            Some(__index) => Some(__index),
+            None if __unrecognized.is_some() => Some(__integers.len()),
+            None => None,
+        };
+        if let Some(__index) = __error {
+            let __expected = __chart.expected(__index).into_iter().map(|__t| __TERMINALS[__t].to_string()).collect();
+            if __index < __triples.len() {
+                let __token = __triples.swap_remove(__index);
+                if __chart.is_complete(__index) {
+                    return Err(__lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected });
+                }
+                return Err(__lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected });
+            }
+            if __unrecognized.is_some() {
+                return Err(__lalrpop_util::ParseError::UnrecognizedToken { token: __unrecognized, expected: __expected });
+            }
+            return Err(__lalrpop_util::ParseError::UnrecognizedEof { location: __triples.last().map(|__t| __t.2.clone()).unwrap_or_default(), expected: __expected });
+        }
+        __replay(input, __chart.derivation(), __triples, &__integers, ::std::marker::PhantomData::<()>)
+    }
+    #[allow(dead_code)]
+    pub fn parse_Opt_all<
+        'input,
+    >(
+        input: &'input str,
+    ) -> Result<::std::vec::Vec<(usize, usize)>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let mut __tokens = super::__intern_token::__Matcher::new(input);
+        let mut __triples = vec![];
+        let mut __integers = vec![];
+        let mut __unrecognized = None;
+        for __token in __tokens {
+            let __lookahead = match __token {
+                Ok(v) => v,
+                Err(e) => return Err(e),
+            };
+            let __integer = match __lookahead.1 {
+                Token(1, _) if true => 0,
+                Token(2, _) if true => 1,
+                Token(3, _) if true => 2,
+                Token(4, _) if true => 3,
+                Token(5, _) if true => 4,
+                Token(0, _) if true => 5,
+                _ => {
+                    __unrecognized = Some(__lookahead);
+                    break;
+                }
+            };
+            __triples.push(__lookahead);
+            __integers.push(__integer);
+        }
+        let __chart = __lalrpop_util::earley::Chart::parse(__PRODUCTIONS, 12, &__integers);
+        let __error = match __chart.error() {
+            Some(__index) => Some(__index),
+            None if __unrecognized.is_some() => Some(__integers.len()),
+            None => None,
+        };
+        if let Some(__index) = __error {
+            let __expected = __chart.expected(__index).into_iter().map(|__t| __TERMINALS[__t].to_string()).collect();
+            if __index < __triples.len() {
+                let __token = __triples.swap_remove(__index);
+                if __chart.is_complete(__index) {
+                    return Err(__lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected });
+                }
+                return Err(__lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected });
+            }
+            if __unrecognized.is_some() {
+                return Err(__lalrpop_util::ParseError::UnrecognizedToken { token: __unrecognized, expected: __expected });
+            }
+            return Err(__lalrpop_util::ParseError::UnrecognizedEof { location: __triples.last().map(|__t| __t.2.clone()).unwrap_or_default(), expected: __expected });
+        }
+        let mut __results = vec![];
+        let mut __failure = None;
+        for __steps in __chart.forest().derivations() {
+            match __replay(input, __steps, __triples.clone(), &__integers, ::std::marker::PhantomData::<()>) {
+                Ok(v) => __results.push(v),
+                Err(e) => if __failure.is_none() { __failure = Some(e); },
+            }
+        }
+        match __failure {
+            Some(e) if __results.is_empty() => Err(e),
+            _ => Ok(__results),
+        }
+    }
+    fn __replay<
+        'input,
+    >(
+        input: &'input str,
+        __steps: ::std::vec::Vec<__lalrpop_util::earley::Step>,
+        __triples: ::std::vec::Vec<(usize, Token<'input>, usize)>,
+        __integers: &[usize],
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<(usize, usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let mut __symbols = vec![];
+        let mut __tokens = __triples.into_iter().zip(__integers.iter().cloned()).peekable();
+        for __step in __steps {
+            match __step {
+                __lalrpop_util::earley::Step::Shift => {
+                    let (__lookahead, __integer) = __tokens.next().unwrap();
+                    let __symbol = match __integer {
+                        0 => match __lookahead.1 {
+                            Token(1, __tok0) => __Symbol::Term_22_2d_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        1 => match __lookahead.1 {
+                            Token(2, __tok0) => __Symbol::Term_22a_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        2 => match __lookahead.1 {
+                            Token(3, __tok0) => __Symbol::Term_22x_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        3 => match __lookahead.1 {
+                            Token(4, __tok0) => __Symbol::Term_22y_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        4 => match __lookahead.1 {
+                            Token(5, __tok0) => __Symbol::Term_22z_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        5 => match __lookahead.1 {
+                            Token(0, __tok0) => __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        _ => unreachable!(),
+                    };
+                    __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                }
+                __lalrpop_util::earley::Step::Reduce(__production) => {
+                    let __lookahead_start = __tokens.peek().map(|&(ref __t, _)| __t.0.clone());
+                    if let Some(r) = __reduce(input, __production, __lookahead_start.as_ref(), &mut __symbols, ::std::marker::PhantomData::<()>) {
+                        return r;
+                    }
+                }
+                __lalrpop_util::earley::Step::Rewind(_) | __lalrpop_util::earley::Step::Merge(_) | __lalrpop_util::earley::Step::Recall { .. } | __lalrpop_util::earley::Step::Keep(_) => unreachable!(),
+            }
+        }
+        unreachable!()
+    }
+    fn __reduce<
+        'input,
+    >(
+        input: &'input str,
+        __production: usize,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Option<Result<(usize, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        match __production {
+            0 => {
+                // () =  => ActionFn(12);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action12::<>(input, &__start, &__end);
+                __symbols.push((__start, __Symbol::Nt_28_29(__nt), __end));
+            }
+            1 => {
+                // @L =  => ActionFn(14);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action14::<>(input, &__start, &__end);
+                __symbols.push((__start, __Symbol::Nt_40L(__nt), __end));
+            }
+            2 => {
+                // @R =  => ActionFn(13);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action13::<>(input, &__start, &__end);
+                __symbols.push((__start, __Symbol::Nt_40R(__nt), __end));
+            }
+            3 => {
+                // A = "a" => ActionFn(8);
+                let __sym0 = __pop_Term_22a_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action8::<>(input, __sym0);
+                __symbols.push((__start, __Symbol::NtA(__nt), __end));
+            }
+            4 => {
+                // B = "a" => ActionFn(9);
+                let __sym0 = __pop_Term_22a_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action9::<>(input, __sym0);
+                __symbols.push((__start, __Symbol::NtB(__nt), __end));
+            }
+            5 => {
+                // Choice = A, "x", "y" => ActionFn(6);
+                let __sym2 = __pop_Term_22y_22(__symbols);
+                let __sym1 = __pop_Term_22x_22(__symbols);
+                let __sym0 = __pop_NtA(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action6::<>(input, __sym0, __sym1, __sym2);
+                __symbols.push((__start, __Symbol::NtChoice(__nt), __end));
+            }
+            6 => {
+                // Choice = B, "x", "z" => ActionFn(7);
+                let __sym2 = __pop_Term_22z_22(__symbols);
+                let __sym1 = __pop_Term_22x_22(__symbols);
+                let __sym0 = __pop_NtB(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action7::<>(input, __sym0, __sym1, __sym2);
+                __symbols.push((__start, __Symbol::NtChoice(__nt), __end));
+            }
+            7 => {
+                // Empty =  => ActionFn(15);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action15::<>(input, &__start, &__end);
+                __symbols.push((__start, __Symbol::NtEmpty(__nt), __end));
+            }
+            8 => {
+                // Expr = Expr, "-", Expr => ActionFn(3);
+                let __sym2 = __pop_NtExpr(__symbols);
+                let __sym1 = __pop_Term_22_2d_22(__symbols);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action3::<>(input, __sym0, __sym1, __sym2);
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+            }
+            9 => {
+                // Expr = Num => ActionFn(4);
+                let __sym0 = __pop_NtNum(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action4::<>(input, __sym0);
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+            }
+            10 => {
+                // Num = r#"[0-9]+"# => ActionFn(5);
+                let __sym0 = __pop_Termr_23_22_5b0_2d9_5d_2b_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action5::<>(input, __sym0);
+                __symbols.push((__start, __Symbol::NtNum(__nt), __end));
+            }
+            11 => {
+                // Opt = Empty, "a" => ActionFn(17);
+                let __sym1 = __pop_Term_22a_22(__symbols);
+                let __sym0 = __pop_NtEmpty(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action17::<>(input, __sym0, __sym1);
+                __symbols.push((__start, __Symbol::NtOpt(__nt), __end));
+            }
+            12 => {
+                // __Choice = Choice => ActionFn(1);
+                let __sym0 = __pop_NtChoice(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action1::<>(input, __sym0);
+                __symbols.push((__start, __Symbol::Nt____Choice(__nt), __end));
+            }
+            13 => {
+                // __Expr = Expr => ActionFn(0);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action0::<>(input, __sym0);
+                __symbols.push((__start, __Symbol::Nt____Expr(__nt), __end));
+            }
+            14 => {
+                // __Opt = Opt => ActionFn(2);
+                let __sym0 = __pop_NtOpt(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action2::<>(input, __sym0);
+                return Some(Ok(__nt));
+            }
+            _ => panic!("invalid production {}", __production)
+        }
+        None
+    }
+    fn __pop_Term_22_2d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22a_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22a_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22x_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22x_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22y_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22y_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22z_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22z_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_5b0_2d9_5d_2b_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_29<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (), usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_29(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_40L<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, usize, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_40L(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_40R<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, usize, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_40R(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtA<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtA(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtB<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtB(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtChoice<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'static str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtChoice(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtEmpty<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (), usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtEmpty(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtExpr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, i32, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtExpr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtNum<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, i32, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtNum(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtOpt<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (usize, usize), usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtOpt(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____Choice<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'static str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Choice(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____Expr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, i32, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Expr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____Opt<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (usize, usize), usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Opt(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+}
+pub use self::__parse__Opt::parse_Opt;
+pub use self::__parse__Opt::parse_Opt_all;
+mod __intern_token {
+    #![allow(unused_imports)]
+    use std::str::FromStr;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    extern crate regex as __regex;
+    use std::fmt as __fmt;
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Token<'input>(pub usize, pub &'input str);
+    impl<'a> __fmt::Display for Token<'a> {
+        fn fmt(&self, formatter: &mut __fmt::Formatter) -> Result<(), __fmt::Error> {
+            __fmt::Display::fmt(self.1, formatter)
+        }
+    }
+
+    pub struct __Matcher<'input> {
+        text: &'input str,
+        consumed: usize,
+        regex_set: __regex::RegexSet,
+        regex_vec: Vec<__regex::Regex>,
+    }
+
+    impl<'input> __Matcher<'input> {
+        pub fn new(s: &'input str) -> __Matcher<'input> {
+            let __strs: &[&str] = &[
+                "^(?u:[0-9])+",
+                "^(?u:\\-)",
+                "^(?u:a)",
+                "^(?u:x)",
+                "^(?u:y)",
+                "^(?u:z)",
+            ];
+            let __regex_set = __regex::RegexSet::new(__strs).unwrap();
+            let __regex_vec = vec![
+                __regex::Regex::new("^(?u:[0-9])+").unwrap(),
+                __regex::Regex::new("^(?u:\\-)").unwrap(),
+                __regex::Regex::new("^(?u:a)").unwrap(),
+                __regex::Regex::new("^(?u:x)").unwrap(),
+                __regex::Regex::new("^(?u:y)").unwrap(),
+                __regex::Regex::new("^(?u:z)").unwrap(),
+            ];
+            __Matcher {
+                text: s,
+                consumed: 0,
+                regex_set: __regex_set,
+                regex_vec: __regex_vec,
+            }
+        }
+    }
+
+    impl<'input> Iterator for __Matcher<'input> {
+        type Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize,Token<'input>,&'static str>>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let __text = self.text.trim_left();
+            let __whitespace = self.text.len() - __text.len();
+            let __start_offset = self.consumed + __whitespace;
+            if __text.is_empty() {
+                self.text = __text;
+                self.consumed = __start_offset;
+                None
+            } else {
+                let __matches = self.regex_set.matches(__text);
+                if !__matches.matched_any() {
+                    Some(Err(__lalrpop_util::ParseError::InvalidToken {
+                        location: __start_offset,
+                    }))
+                } else {
+                    let mut __longest_match = 0;
+                    let mut __index = 0;
+                    for __i in 0 .. 6 {
+                        if __matches.matched(__i) {
+                            let __match = self.regex_vec[__i].find(__text).unwrap();
+                            let __len = __match.end();
+                            if __len >= __longest_match {
+                                __longest_match = __len;
+                                __index = __i;
+                            }
+                        }
+                    }
+                    let __result = &__text[..__longest_match];
+                    let __remaining = &__text[__longest_match..];
+                    let __end_offset = __start_offset + __longest_match;
+                    self.text = __remaining;
+                    self.consumed = __end_offset;
+                    Some(Ok((__start_offset, Token(__index, __result), __end_offset)))
+                }
+            }
+        }
+    }
+}
+pub use self::__intern_token::Token;
+
+#[allow(unused_variables)]
+fn __action0<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, i32, usize),
+) -> i32
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'static str, usize),
+) -> &'static str
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, (usize, usize), usize),
+) -> (usize, usize)
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, i32, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, i32, usize),
+) -> i32
+{
+    //@ src/earley.lalrpop:12:30 (1 lines)
+    l - r
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, i32, usize),
+) -> i32
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> i32
+{
+    //@ src/earley.lalrpop:16:25 (1 lines)
+    i32::from_str(__0).unwrap()
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+    (_, __1, _): (usize, &'input str, usize),
+    (_, __2, _): (usize, &'input str, usize),
+) -> &'static str
+{
+    //@ src/earley.lalrpop:20:18 (1 lines)
+    "a"
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+    (_, __1, _): (usize, &'input str, usize),
+    (_, __2, _): (usize, &'input str, usize),
+) -> &'static str
+{
+    //@ src/earley.lalrpop:21:18 (1 lines)
+    "b"
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> &'input str
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action9<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> &'input str
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action10<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, (), usize),
+    (_, r, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> (usize, usize)
+{
+    //@ src/earley.lalrpop:27:54 (1 lines)
+    (l, r)
+}
+
+#[allow(unused_variables)]
+fn __action11<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, (), usize),
+) -> ()
+{
+    //@ src/earley.lalrpop:29:19 (1 lines)
+    ()
+}
+
+#[allow(unused_variables)]
+fn __action12<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ()
+{
+    ()
+}
+
+#[allow(unused_variables)]
+fn __action13<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
+{
+    __lookbehind.clone()
+}
+
+#[allow(unused_variables)]
+fn __action14<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
+{
+    __lookahead.clone()
+}
+
+#[allow(unused_variables)]
+fn __action15<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ()
+{
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action12(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action11(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action16<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, (), usize),
+    __1: (usize, usize, usize),
+    __2: (usize, &'input str, usize),
+) -> (usize, usize)
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action14(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action10(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action17<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, (), usize),
+    __1: (usize, &'input str, usize),
+) -> (usize, usize)
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action13(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action16(
+        input,
+        __0,
+        __temp0,
+        __1,
+    )
+}
+
+pub trait __ToTriple<'input, > {
+    type Error;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),Self::Error>;
+}
+
+impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize) {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        Ok(value)
+    }
+}
+impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        value
+    }
+}
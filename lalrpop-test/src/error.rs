@@ -0,0 +1,931 @@
+// auto-generated: "lalrpop 0.15.0"
+use util::tok::Tok;
+use lalrpop_util::ParseError;
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+
+mod __parse__Items {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    use util::tok::Tok;
+    use lalrpop_util::ParseError;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__ToTriple;
+    #[allow(dead_code)]
+    pub fn parse_Items<
+        __TOKEN: __ToTriple<Error=char>,
+        __TOKENS: IntoIterator<Item=__TOKEN>,
+    >(
+        __tokens0: __TOKENS,
+    ) -> Result<Vec<(usize, usize)>, __lalrpop_util::ParseError<usize, Tok, char>> where
+      __TOKENS: Clone,
+    {
+        let __ascent = __ascent::parse_Items(
+            __tokens0.clone(),
+        );
+        let __parse_table = __parse_table::parse_Items(
+            __tokens0.clone(),
+        );
+        assert_eq!(__ascent, __parse_table);
+        return __ascent;
+    }
+    mod __ascent {
+
+        mod __parse__Items {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use util::tok::Tok;
+            use lalrpop_util::ParseError;
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__ToTriple;
+            #[allow(dead_code)]
+            pub fn parse_Items<
+                __TOKEN: __ToTriple<Error=char>,
+                __TOKENS: IntoIterator<Item=__TOKEN>,
+            >(
+                __tokens0: __TOKENS,
+            ) -> Result<Vec<(usize, usize)>, __lalrpop_util::ParseError<usize, Tok, char>>
+            {
+                let __tokens = __tokens0.into_iter();
+                let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+                let __last_location = ::std::cell::RefCell::new(Default::default());
+                let mut __tokens = __tokens.inspect(|t| if let Ok(ref t) = *t { *__last_location.borrow_mut() = t.2.clone(); });
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                let mut __extra = None;
+                let __result = __state0(&mut __tokens, __lookahead, &mut __extra, ::std::marker::PhantomData::<()>);
+                if let Some((__token, __expected)) = __extra {
+                    return Err(match __result {
+                        Ok(_) => __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected },
+                        Err(_) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                    });
+                }
+                match __result {
+                    Ok((Some(__lookahead), _)) => {
+                        Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] })
+                    }
+                    Ok((None, __Nonterminal::____Items((_, __nt, _)))) => {
+                        Ok(__nt)
+                    }
+                    Ok(_) => unreachable!(),
+                    Err(__lalrpop_util::ParseError::UnrecognizedEof { expected: __expected, .. }) => {
+                        Err(__lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.borrow().clone(), expected: __expected })
+                    }
+                    Err(__error) => Err(__error),
+                }
+            }
+
+            #[allow(dead_code)]
+            pub enum __Nonterminal<>
+             {
+                Items((usize, Vec<(usize, usize)>, usize)),
+                ____Items((usize, Vec<(usize, usize)>, usize)),
+            }
+            impl<> ::std::fmt::Debug for __Nonterminal<>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Nonterminal::Items(..) => __f.write_str("Items"),
+                        __Nonterminal::____Items(..) => __f.write_str("__Items"),
+                    }
+                }
+            }
+
+            // State 0
+            //     AllInputs = []
+            //     OptionalInputs = []
+            //     FixedInputs = []
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Items = (*) ["+", "-", EOF]
+            //     Items = (*) Items "+" ["+", "-", EOF]
+            //     Items = (*) Items "-" ["+", "-", EOF]
+            //     __Items = (*) Items ["+", "-", EOF]
+            //
+            //   ["+", "-", EOF] -> Items =  => ActionFn(1);
+            //
+            //     Items -> S1
+            fn __state0<
+                __TOKENS: Iterator<Item=Result<(usize, Tok, usize),char>>,
+            >(
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Tok, usize)>,
+                __extra: &mut Option<((usize, Tok, usize), ::std::vec::Vec<::std::string::String>)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Tok, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Tok, char>>
+            {
+                let mut __result: (Option<(usize, Tok, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((_, Tok::Plus, _)) |
+                    Some((_, Tok::Minus, _)) |
+                    None => {
+                        let __start: usize = ::std::default::Default::default();
+                        let __end = __lookahead.as_ref().map(|o| o.0.clone()).unwrap_or_else(|| __start.clone());
+                        let __nt = super::super::super::__action1::<>(&__start, &__end);
+                        let __nt = __Nonterminal::Items((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Tok, usize)> = None;
+                        let __start: usize = ::std::default::Default::default();
+                        let __end = __lookahead.as_ref().map(|o| o.0.clone()).unwrap_or_else(|| __start.clone());
+                        let __nt = super::super::super::__action1::<>(&__start, &__end);
+                        let __nt = __Nonterminal::Items((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Items(__sym0) => {
+                            __result = try!(__state1(__tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 1
+            //     AllInputs = [Items]
+            //     OptionalInputs = []
+            //     FixedInputs = [Items]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Items = Items (*) "+" ["+", "-", EOF]
+            //     Items = Items (*) "-" ["+", "-", EOF]
+            //     __Items = Items (*) ["+", "-", EOF]
+            //
+            //   "+" -> S2
+            //   "-" -> S3
+            //   [EOF] -> __Items = Items => ActionFn(0);
+            //
+            fn __state1<
+                __TOKENS: Iterator<Item=Result<(usize, Tok, usize),char>>,
+            >(
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Tok, usize)>,
+                __extra: &mut Option<((usize, Tok, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Vec<(usize, usize)>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Tok, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Tok, char>>
+            {
+                let mut __result: (Option<(usize, Tok, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::Plus, __loc2)) => {
+                        let __sym1 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state2(__tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, __tok @ Tok::Minus, __loc2)) => {
+                        let __sym1 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state3(__tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(__sym0);
+                        let __nt = __Nonterminal::____Items((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Tok, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(__sym0);
+                        let __nt = __Nonterminal::____Items((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 2
+            //     AllInputs = [Items, "+"]
+            //     OptionalInputs = []
+            //     FixedInputs = [Items, "+"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Items)
+            //
+            //     Items = Items "+" (*) ["+", "-", EOF]
+            //
+            //   ["+", "-", EOF] -> Items = Items, "+" => ActionFn(2);
+            //
+            fn __state2<
+                __TOKENS: Iterator<Item=Result<(usize, Tok, usize),char>>,
+            >(
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Tok, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Vec<(usize, usize)>, usize),
+                __sym1: (usize, Tok, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Tok, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Tok, char>>
+            {
+                let mut __result: (Option<(usize, Tok, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                match __lookahead {
+                    Some((_, Tok::Plus, _)) |
+                    Some((_, Tok::Minus, _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = try!(super::super::super::__action2::<>(__sym0, __sym1));
+                        let __nt = __Nonterminal::Items((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Tok, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = try!(super::super::super::__action2::<>(__sym0, __sym1));
+                        let __nt = __Nonterminal::Items((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 3
+            //     AllInputs = [Items, "-"]
+            //     OptionalInputs = []
+            //     FixedInputs = [Items, "-"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Items)
+            //
+            //     Items = Items "-" (*) ["+", "-", EOF]
+            //
+            //   ["+", "-", EOF] -> Items = Items, "-" => ActionFn(3);
+            //
+            fn __state3<
+                __TOKENS: Iterator<Item=Result<(usize, Tok, usize),char>>,
+            >(
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Tok, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Vec<(usize, usize)>, usize),
+                __sym1: (usize, Tok, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Tok, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Tok, char>>
+            {
+                let mut __result: (Option<(usize, Tok, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                match __lookahead {
+                    Some((_, Tok::Plus, _)) |
+                    Some((_, Tok::Minus, _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = try!(super::super::super::__action3::<>(__sym0, __sym1));
+                        let __nt = __Nonterminal::Items((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Tok, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = try!(super::super::super::__action3::<>(__sym0, __sym1));
+                        let __nt = __Nonterminal::Items((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+        }
+        pub use self::__parse__Items::parse_Items;
+    }
+    mod __parse_table {
+
+        mod __parse_table {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use util::tok::Tok;
+            use lalrpop_util::ParseError;
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__ToTriple;
+            #[allow(dead_code)]
+            pub enum __Symbol<>
+             {
+                Term_22_2b_22(Tok),
+                Term_22_2d_22(Tok),
+                NtItems(Vec<(usize, usize)>),
+                Nt____Items(Vec<(usize, usize)>),
+            }
+            impl<> ::std::fmt::Debug for __Symbol<>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Symbol::Term_22_2b_22(..) => __f.write_str("\"+\""),
+                        __Symbol::Term_22_2d_22(..) => __f.write_str("\"-\""),
+                        __Symbol::NtItems(..) => __f.write_str("Items"),
+                        __Symbol::Nt____Items(..) => __f.write_str("__Items"),
+                    }
+                }
+            }
+            const __ACTION: &'static [i8] = &[
+                // State 0
+                //     Items = (*) ["+", "-", EOF]
+                //     Items = (*) Items "+" ["+", "-", EOF]
+                //     Items = (*) Items "-" ["+", "-", EOF]
+                //     __Items = (*) Items ["+", "-", EOF]
+                -1,  // on "+", reduce `Items =  => ActionFn(1);`
+                -1,  // on "-", reduce `Items =  => ActionFn(1);`
+
+                // State 1
+                //     Items = Items (*) "+" ["+", "-", EOF]
+                //     Items = Items (*) "-" ["+", "-", EOF]
+                //     __Items = Items (*) ["+", "-", EOF]
+                3,  // on "+", goto 2
+                4,  // on "-", goto 3
+
+                // State 2
+                //     Items = Items "+" (*) ["+", "-", EOF]
+                -2,  // on "+", reduce `Items = Items, "+" => ActionFn(2);`
+                -2,  // on "-", reduce `Items = Items, "+" => ActionFn(2);`
+
+                // State 3
+                //     Items = Items "-" (*) ["+", "-", EOF]
+                -3,  // on "+", reduce `Items = Items, "-" => ActionFn(3);`
+                -3,  // on "-", reduce `Items = Items, "-" => ActionFn(3);`
+
+            ];
+            const __EOF_ACTION: &'static [i8] = &[
+                // State 0
+                -1,  // on EOF, reduce `Items =  => ActionFn(1);`
+
+                // State 1
+                -4,  // on EOF, reduce `__Items = Items => ActionFn(0);`
+
+                // State 2
+                -2,  // on EOF, reduce `Items = Items, "+" => ActionFn(2);`
+
+                // State 3
+                -3,  // on EOF, reduce `Items = Items, "-" => ActionFn(3);`
+
+            ];
+            const __GOTO: &'static [u8] = &[
+                // State 0
+                2,  // on Items, goto 1
+                0,  // on __Items, error
+
+                // State 1
+                0,  // on Items, error
+                0,  // on __Items, error
+
+                // State 2
+                0,  // on Items, error
+                0,  // on __Items, error
+
+                // State 3
+                0,  // on Items, error
+                0,  // on __Items, error
+
+            ];
+            fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+                const __TERMINAL: &'static [&'static str] = &[
+                    r###""+""###,
+                    r###""-""###,
+                ];
+                __ACTION[(__state * 2)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+                    if state == 0 {
+                        None
+                    } else {
+                        Some(terminal.to_string())
+                    }
+                }).collect()
+            }
+            #[allow(dead_code)]
+            pub fn parse_Items<
+                __TOKEN: __ToTriple<Error=char>,
+                __TOKENS: IntoIterator<Item=__TOKEN>,
+            >(
+                __tokens0: __TOKENS,
+            ) -> Result<Vec<(usize, usize)>, __lalrpop_util::ParseError<usize, Tok, char>>
+            {
+                let __tokens = __tokens0.into_iter();
+                let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Items(__v) => Ok(__v), _ => unreachable!() })
+            }
+            #[allow(dead_code)]
+            pub fn parse_Items_cancelable<
+                __TOKEN: __ToTriple<Error=char>,
+                __TOKENS: IntoIterator<Item=__TOKEN>,
+            >(
+                __tokens0: __TOKENS,
+                __cancel: &::std::sync::atomic::AtomicBool,
+            ) -> Result<Vec<(usize, usize)>, __lalrpop_util::ParseError<usize, Tok, char>>
+            {
+                let __tokens = __tokens0.into_iter();
+                let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Items(__v) => Ok(__v), _ => unreachable!() })
+            }
+
+            #[allow(dead_code)]
+            pub struct ItemsParser {
+                __states: ::std::vec::Vec<i32>,
+                __symbols: __lalrpop_util::stack::Recycled,
+                __cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+            }
+
+            #[allow(dead_code)]
+            impl ItemsParser {
+                pub fn new() -> ItemsParser {
+                    ItemsParser { __states: vec![], __symbols: __lalrpop_util::stack::Recycled::new(), __cancel: None }
+                }
+
+                pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+                    self.__cancel = Some(flag);
+                }
+
+                pub fn parse_in_place<__TOKEN: __ToTriple<Error=char>, __TOKENS: IntoIterator<Item=__TOKEN>>(
+                    &mut self,
+                    __tokens0: __TOKENS,
+                ) -> Result<Vec<(usize, usize)>, __lalrpop_util::ParseError<usize, Tok, char>>
+                {
+                    let __tokens = __tokens0.into_iter();
+                    let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+                    let mut __symbols = self.__symbols.take();
+                    let __result = __parse::<>(0, &mut __tokens, &mut self.__states, &mut __symbols, self.__cancel.as_ref().map(|c| &**c)).and_then(|__v| match __v { __Symbol::Nt____Items(__v) => Ok(__v), _ => unreachable!() });
+                    self.__symbols.put(__symbols);
+                    __result
+                }
+            }
+
+            fn __parse<
+            >(
+                __start_state: i32,
+                mut __tokens: &mut Iterator<Item = Result<(usize, Tok, usize), char>>,
+                mut __states: &mut ::std::vec::Vec<i32>,
+                mut __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+                __cancel: Option<&::std::sync::atomic::AtomicBool>,
+            ) -> Result<__Symbol<>, __lalrpop_util::ParseError<usize, Tok, char>>
+            {
+                __states.clear();
+                __states.push(__start_state);
+                __symbols.clear();
+                let mut __integer;
+                let mut __lookahead;
+                let __last_location: &mut usize = &mut Default::default();
+                '__shift: loop {
+                    __lookahead = match __tokens.next() {
+                        Some(Ok(v)) => v,
+                        None => break '__shift,
+                        Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                    };
+                    *__last_location = __lookahead.2.clone();
+                    __integer = match __lookahead.1 {
+                        Tok::Plus if true => 0,
+                        Tok::Minus if true => 1,
+                        _ => {
+                            let __state = *__states.last().unwrap() as usize;
+                            let __expected = __expected_tokens(__state);
+                            let __error = match Some(__lookahead) {
+                                Some(__token) => {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error);
+                        }
+                    };
+                    '__inner: loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __ACTION[__state * 2 + __integer] as i32;
+                        if __action > 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                                }
+                            }
+                            let __symbol = match __integer {
+                                0 => match __lookahead.1 {
+                                    __tok @ Tok::Plus => __Symbol::Term_22_2b_22((__tok)),
+                                    _ => unreachable!(),
+                                },
+                                1 => match __lookahead.1 {
+                                    __tok @ Tok::Minus => __Symbol::Term_22_2d_22((__tok)),
+                                    _ => unreachable!(),
+                                },
+                                _ => unreachable!(),
+                            };
+                            __states.push(__action - 1);
+                            __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                            continue '__shift;
+                        } else if __action < 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                                }
+                            }
+                            if let Some(r) = __reduce(__action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                                if r.is_err() {
+                                    return r;
+                                }
+                                return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] });
+                            }
+                        } else {
+                            let mut __err_lookahead = Some(__lookahead);
+                            let mut __err_integer: Option<usize> = Some(__integer);
+                            let __state = *__states.last().unwrap() as usize;
+                            let __expected = __expected_tokens(__state);
+                            let __error = match __err_lookahead {
+                                Some(__token) => {
+                                    if __accepts( *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                        __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                    } else {
+                                        __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                    }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error)
+                        }
+                    }
+                }
+                loop {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __action = __EOF_ACTION[__state] as i32;
+                    if __action < 0 {
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(__action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    } else {
+                        let mut __err_lookahead = None;
+                        let mut __err_integer: Option<usize> = None;
+                        let __state = *__states.last().unwrap() as usize;
+                        let __expected = __expected_tokens(__state);
+                        let __error = match __err_lookahead {
+                            Some(__token) => {
+                                if __accepts( *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                    __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                } else {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                            }
+                            None => {
+                                __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                            }
+                        };
+                        return Err(__error)
+                    }
+                }
+            }
+            fn __accepts<
+            >(
+                __error_state: i32,
+                __states: & [i32],
+                __opt_integer: Option<usize>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> bool
+            {
+                let mut __states = __states.to_vec();
+                __states.push(__error_state);
+                loop {
+                    let mut __states_len = __states.len();
+                    let __top = __states[__states_len - 1];
+                    let __action = match __opt_integer {
+                        None => __EOF_ACTION[__top as usize] as i32,
+                        Some(__integer) => __ACTION[(__top * 2) as usize + __integer] as i32,
+                    };
+                    if __action == 0 { return false; }
+                    if __action > 0 { return true; }
+                    let (__to_pop, __nt) = match -__action {
+                        // simulate Items =  => ActionFn(1);
+                        1 => {
+                            (0, 0)
+                        }
+                        // simulate Items = Items, "+" => ActionFn(2);
+                        2 => {
+                            (2, 0)
+                        }
+                        // simulate Items = Items, "-" => ActionFn(3);
+                        3 => {
+                            (2, 0)
+                        }
+                        // simulate __Items = Items => ActionFn(0);
+                        4 => return true,
+                        _ => panic!("invalid action code {}", __action)
+                    };
+                    __states_len -= __to_pop;
+                    __states.truncate(__states_len);
+                    let __top = __states[__states_len - 1];
+                    let __next_state = __GOTO[(__top * 2 + __nt) as usize] as i32 - 1;
+                    __states.push(__next_state);
+                }
+            }
+            pub fn __reduce<
+            >(
+                __action: i32,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Option<Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, char>>>
+            {
+                let __nonterminal = match -__action {
+                    1 => match __reduce_Items_0(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    2 => match __reduce_Items_1(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    3 => match __reduce_Items_2(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    4 => match __reduce_____Items_0(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    _ => panic!("invalid action code {}", __action)
+                };
+                let __state = *__states.last().unwrap() as usize;
+                let __next_state = __GOTO[__state * 2 + __nonterminal] as i32 - 1;
+                __states.push(__next_state);
+                None
+            }
+
+            fn __reduce_Items_0<
+            >(
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, char>>>
+            {
+                // Items =  => ActionFn(1);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action1::<>(&__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::NtItems(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce_Items_1<
+            >(
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, char>>>
+            {
+                // Items = Items, "+" => ActionFn(2);
+                let __sym1 = __pop_Term_22_2b_22(__symbols);
+                let __sym0 = __pop_NtItems(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = match super::super::super::__action2::<>(__sym0, __sym1) {
+                    Ok(v) => v,
+                    Err(e) => return Err(Err(e)),
+                };
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtItems(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce_Items_2<
+            >(
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, char>>>
+            {
+                // Items = Items, "-" => ActionFn(3);
+                let __sym1 = __pop_Term_22_2d_22(__symbols);
+                let __sym0 = __pop_NtItems(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = match super::super::super::__action3::<>(__sym0, __sym1) {
+                    Ok(v) => v,
+                    Err(e) => return Err(Err(e)),
+                };
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtItems(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce_____Items_0<
+            >(
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, char>>>
+            {
+                // __Items = Items => ActionFn(0);
+                let __sym0 = __pop_NtItems(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action0::<>(__sym0);
+                Err(Ok(__Symbol::Nt____Items(__nt)))
+            }
+            fn __pop_Term_22_2b_22<
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+            ) -> (usize, Tok, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2b_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_2d_22<
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+            ) -> (usize, Tok, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2d_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtItems<
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+            ) -> (usize, Vec<(usize, usize)>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtItems(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt____Items<
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+            ) -> (usize, Vec<(usize, usize)>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt____Items(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+        }
+        pub use self::__parse_table::parse_Items;
+        pub use self::__parse_table::parse_Items_cancelable;
+        pub use self::__parse_table::ItemsParser;
+    }
+    pub use self::__parse_table::parse_Items_cancelable;
+    pub use self::__parse_table::ItemsParser;
+}
+pub use self::__parse__Items::parse_Items;
+pub use self::__parse__Items::parse_Items_cancelable;
+pub use self::__parse__Items::ItemsParser;
+
+fn __action0<
+>(
+    (_, __0, _): (usize, Vec<(usize, usize)>, usize),
+) -> Vec<(usize, usize)>
+{
+    (__0)
+}
+
+fn __action1<
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<(usize, usize)>
+{
+    //@ src/error.lalrpop:17:8 (1 lines)
+    vec![]
+}
+
+fn __action2<
+>(
+    (_, __0, _): (usize, Vec<(usize, usize)>, usize),
+    (_, __1, _): (usize, Tok, usize),
+) -> Result<Vec<(usize, usize)>,__lalrpop_util::ParseError<usize,Tok,char>>
+{
+    //@ src/error.lalrpop:18:19 (1 lines)
+    Err(ParseError::User { error: '+' })
+}
+
+fn __action3<
+>(
+    (_, v, _): (usize, Vec<(usize, usize)>, usize),
+    (_, _, _): (usize, Tok, usize),
+) -> Result<Vec<(usize, usize)>,__lalrpop_util::ParseError<usize,Tok,char>>
+{
+    //@ src/error.lalrpop:19:23 (1 lines)
+    Ok(v)
+}
+
+pub trait __ToTriple<> {
+    type Error;
+    fn to_triple(value: Self) -> Result<(usize,Tok,usize),Self::Error>;
+}
+
+impl<> __ToTriple<> for (usize, Tok, usize) {
+    type Error = char;
+    fn to_triple(value: Self) -> Result<(usize,Tok,usize),char> {
+        Ok(value)
+    }
+}
+impl<> __ToTriple<> for Result<(usize, Tok, usize),char> {
+    type Error = char;
+    fn to_triple(value: Self) -> Result<(usize,Tok,usize),char> {
+        value
+    }
+}
@@ -0,0 +1,1665 @@
+// auto-generated: "lalrpop 0.15.0"
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+pub use self::__parse_table::parse_Stmts;
+pub use self::__parse_table::parse_Stmts_cancelable;
+pub use self::__parse_table::StmtsParser;
+
+mod __parse_table {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__intern_token::Token;
+    #[allow(dead_code)]
+    pub enum __Symbol<'input>
+     {
+        Term_22_28_22(&'input str),
+        Term_22_29_22(&'input str),
+        Term_22_3b_22(&'input str),
+        Term_22_7b_22(&'input str),
+        Term_22_7d_22(&'input str),
+        Termr_23_22_5ba_2dz_5d_2b_22_23(&'input str),
+        Termerror(__lalrpop_util::ErrorRecovery<usize, Token<'input>, &'static str>),
+        NtName(String),
+        NtStmt(String),
+        NtStmt_2a(::std::vec::Vec<String>),
+        NtStmt_2b(::std::vec::Vec<String>),
+        NtStmts(Vec<String>),
+        Nt____Stmts(Vec<String>),
+    }
+    impl<'input> ::std::fmt::Debug for __Symbol<'input>
+    {
+        fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            match *self {
+                __Symbol::Term_22_28_22(..) => __f.write_str("\"(\""),
+                __Symbol::Term_22_29_22(..) => __f.write_str("\")\""),
+                __Symbol::Term_22_3b_22(..) => __f.write_str("\";\""),
+                __Symbol::Term_22_7b_22(..) => __f.write_str("\"{\""),
+                __Symbol::Term_22_7d_22(..) => __f.write_str("\"}\""),
+                __Symbol::Termr_23_22_5ba_2dz_5d_2b_22_23(..) => __f.write_str("r#\"[a-z]+\"#"),
+                __Symbol::Termerror(..) => __f.write_str("error"),
+                __Symbol::NtName(..) => __f.write_str("Name"),
+                __Symbol::NtStmt(..) => __f.write_str("Stmt"),
+                __Symbol::NtStmt_2a(..) => __f.write_str("Stmt*"),
+                __Symbol::NtStmt_2b(..) => __f.write_str("Stmt+"),
+                __Symbol::NtStmts(..) => __f.write_str("Stmts"),
+                __Symbol::Nt____Stmts(..) => __f.write_str("__Stmts"),
+            }
+        }
+    }
+    const __ACTION: &'static [i8] = &[
+        // State 0
+        //     Name = (*) r#"[a-z]+"# ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        //     Stmt = (*) Name "(" Name ")" ";" ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        //     Stmt = (*) Name ";" ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        //     Stmt = (*) "{" Stmts "}" ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        //     Stmt = (*) error ";" ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        //     Stmt+ = (*) Stmt ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        //     Stmt+ = (*) Stmt+ Stmt ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        //     Stmts = (*) ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        //     Stmts = (*) Stmt+ ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        //     __Stmts = (*) Stmts ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        0,  // on "(", error
+        0,  // on ")", error
+        0,  // on ";", error
+        6,  // on "{", goto 5
+        0,  // on "}", error
+        7,  // on r#"[a-z]+"#, goto 6
+        8,  // on error, goto 7
+
+        // State 1
+        //     Stmt = Name (*) "(" Name ")" ";" ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        //     Stmt = Name (*) ";" ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        9,  // on "(", goto 8
+        0,  // on ")", error
+        10,  // on ";", goto 9
+        0,  // on "{", error
+        0,  // on "}", error
+        0,  // on r#"[a-z]+"#, error
+        0,  // on error, error
+
+        // State 2
+        //     Stmt+ = Stmt (*) ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        0,  // on "(", error
+        0,  // on ")", error
+        0,  // on ";", error
+        -8,  // on "{", reduce `Stmt+ = Stmt => ActionFn(9);`
+        -8,  // on "}", reduce `Stmt+ = Stmt => ActionFn(9);`
+        -8,  // on r#"[a-z]+"#, reduce `Stmt+ = Stmt => ActionFn(9);`
+        -8,  // on error, reduce `Stmt+ = Stmt => ActionFn(9);`
+
+        // State 3
+        //     Name = (*) r#"[a-z]+"# ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        //     Stmt = (*) Name "(" Name ")" ";" ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        //     Stmt = (*) Name ";" ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        //     Stmt = (*) "{" Stmts "}" ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        //     Stmt = (*) error ";" ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        //     Stmt+ = Stmt+ (*) Stmt ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        //     Stmts = Stmt+ (*) ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        0,  // on "(", error
+        0,  // on ")", error
+        0,  // on ";", error
+        6,  // on "{", goto 5
+        -11,  // on "}", reduce `Stmts = Stmt+ => ActionFn(12);`
+        7,  // on r#"[a-z]+"#, goto 6
+        8,  // on error, goto 7
+
+        // State 4
+        //     __Stmts = Stmts (*) ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        0,  // on "(", error
+        0,  // on ")", error
+        0,  // on ";", error
+        0,  // on "{", error
+        0,  // on "}", error
+        0,  // on r#"[a-z]+"#, error
+        0,  // on error, error
+
+        // State 5
+        //     Name = (*) r#"[a-z]+"# ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        //     Stmt = (*) Name "(" Name ")" ";" ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        //     Stmt = (*) Name ";" ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        //     Stmt = (*) "{" Stmts "}" ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        //     Stmt = "{" (*) Stmts "}" ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        //     Stmt = (*) error ";" ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        //     Stmt+ = (*) Stmt ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        //     Stmt+ = (*) Stmt+ Stmt ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        //     Stmts = (*) ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        //     Stmts = (*) Stmt+ ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        0,  // on "(", error
+        0,  // on ")", error
+        0,  // on ";", error
+        6,  // on "{", goto 5
+        -10,  // on "}", reduce `Stmts =  => ActionFn(11);`
+        7,  // on r#"[a-z]+"#, goto 6
+        8,  // on error, goto 7
+
+        // State 6
+        //     Name = r#"[a-z]+"# (*) ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        -1,  // on "(", reduce `Name = r#"[a-z]+"# => ActionFn(6);`
+        -1,  // on ")", reduce `Name = r#"[a-z]+"# => ActionFn(6);`
+        -1,  // on ";", reduce `Name = r#"[a-z]+"# => ActionFn(6);`
+        0,  // on "{", error
+        0,  // on "}", error
+        0,  // on r#"[a-z]+"#, error
+        0,  // on error, error
+
+        // State 7
+        //     Stmt = error (*) ";" ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        0,  // on "(", error
+        0,  // on ")", error
+        13,  // on ";", goto 12
+        0,  // on "{", error
+        0,  // on "}", error
+        0,  // on r#"[a-z]+"#, error
+        0,  // on error, error
+
+        // State 8
+        //     Name = (*) r#"[a-z]+"# ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        //     Stmt = Name "(" (*) Name ")" ";" ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        0,  // on "(", error
+        0,  // on ")", error
+        0,  // on ";", error
+        0,  // on "{", error
+        0,  // on "}", error
+        7,  // on r#"[a-z]+"#, goto 6
+        0,  // on error, error
+
+        // State 9
+        //     Stmt = Name ";" (*) ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        0,  // on "(", error
+        0,  // on ")", error
+        0,  // on ";", error
+        -2,  // on "{", reduce `Stmt = Name, ";" => ActionFn(2);`
+        -2,  // on "}", reduce `Stmt = Name, ";" => ActionFn(2);`
+        -2,  // on r#"[a-z]+"#, reduce `Stmt = Name, ";" => ActionFn(2);`
+        -2,  // on error, reduce `Stmt = Name, ";" => ActionFn(2);`
+
+        // State 10
+        //     Stmt+ = Stmt+ Stmt (*) ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        0,  // on "(", error
+        0,  // on ")", error
+        0,  // on ";", error
+        -9,  // on "{", reduce `Stmt+ = Stmt+, Stmt => ActionFn(10);`
+        -9,  // on "}", reduce `Stmt+ = Stmt+, Stmt => ActionFn(10);`
+        -9,  // on r#"[a-z]+"#, reduce `Stmt+ = Stmt+, Stmt => ActionFn(10);`
+        -9,  // on error, reduce `Stmt+ = Stmt+, Stmt => ActionFn(10);`
+
+        // State 11
+        //     Stmt = "{" Stmts (*) "}" ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        0,  // on "(", error
+        0,  // on ")", error
+        0,  // on ";", error
+        0,  // on "{", error
+        15,  // on "}", goto 14
+        0,  // on r#"[a-z]+"#, error
+        0,  // on error, error
+
+        // State 12
+        //     Stmt = error ";" (*) ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        0,  // on "(", error
+        0,  // on ")", error
+        0,  // on ";", error
+        -5,  // on "{", reduce `Stmt = error, ";" => ActionFn(5);`
+        -5,  // on "}", reduce `Stmt = error, ";" => ActionFn(5);`
+        -5,  // on r#"[a-z]+"#, reduce `Stmt = error, ";" => ActionFn(5);`
+        -5,  // on error, reduce `Stmt = error, ";" => ActionFn(5);`
+
+        // State 13
+        //     Stmt = Name "(" Name (*) ")" ";" ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        0,  // on "(", error
+        16,  // on ")", goto 15
+        0,  // on ";", error
+        0,  // on "{", error
+        0,  // on "}", error
+        0,  // on r#"[a-z]+"#, error
+        0,  // on error, error
+
+        // State 14
+        //     Stmt = "{" Stmts "}" (*) ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        0,  // on "(", error
+        0,  // on ")", error
+        0,  // on ";", error
+        -4,  // on "{", reduce `Stmt = "{", Stmts, "}" => ActionFn(4);`
+        -4,  // on "}", reduce `Stmt = "{", Stmts, "}" => ActionFn(4);`
+        -4,  // on r#"[a-z]+"#, reduce `Stmt = "{", Stmts, "}" => ActionFn(4);`
+        -4,  // on error, reduce `Stmt = "{", Stmts, "}" => ActionFn(4);`
+
+        // State 15
+        //     Stmt = Name "(" Name ")" (*) ";" ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        0,  // on "(", error
+        0,  // on ")", error
+        17,  // on ";", goto 16
+        0,  // on "{", error
+        0,  // on "}", error
+        0,  // on r#"[a-z]+"#, error
+        0,  // on error, error
+
+        // State 16
+        //     Stmt = Name "(" Name ")" ";" (*) ["(", ")", ";", "{", "}", r#"[a-z]+"#, error, EOF]
+        0,  // on "(", error
+        0,  // on ")", error
+        0,  // on ";", error
+        -3,  // on "{", reduce `Stmt = Name, "(", Name, ")", ";" => ActionFn(3);`
+        -3,  // on "}", reduce `Stmt = Name, "(", Name, ")", ";" => ActionFn(3);`
+        -3,  // on r#"[a-z]+"#, reduce `Stmt = Name, "(", Name, ")", ";" => ActionFn(3);`
+        -3,  // on error, reduce `Stmt = Name, "(", Name, ")", ";" => ActionFn(3);`
+
+    ];
+    const __EOF_ACTION: &'static [i8] = &[
+        // State 0
+        -10,  // on EOF, reduce `Stmts =  => ActionFn(11);`
+
+        // State 1
+        0,  // on EOF, error
+
+        // State 2
+        -8,  // on EOF, reduce `Stmt+ = Stmt => ActionFn(9);`
+
+        // State 3
+        -11,  // on EOF, reduce `Stmts = Stmt+ => ActionFn(12);`
+
+        // State 4
+        -12,  // on EOF, reduce `__Stmts = Stmts => ActionFn(0);`
+
+        // State 5
+        0,  // on EOF, error
+
+        // State 6
+        0,  // on EOF, error
+
+        // State 7
+        0,  // on EOF, error
+
+        // State 8
+        0,  // on EOF, error
+
+        // State 9
+        -2,  // on EOF, reduce `Stmt = Name, ";" => ActionFn(2);`
+
+        // State 10
+        -9,  // on EOF, reduce `Stmt+ = Stmt+, Stmt => ActionFn(10);`
+
+        // State 11
+        0,  // on EOF, error
+
+        // State 12
+        -5,  // on EOF, reduce `Stmt = error, ";" => ActionFn(5);`
+
+        // State 13
+        0,  // on EOF, error
+
+        // State 14
+        -4,  // on EOF, reduce `Stmt = "{", Stmts, "}" => ActionFn(4);`
+
+        // State 15
+        0,  // on EOF, error
+
+        // State 16
+        -3,  // on EOF, reduce `Stmt = Name, "(", Name, ")", ";" => ActionFn(3);`
+
+    ];
+    const __GOTO: &'static [u8] = &[
+        // State 0
+        2,  // on Name, goto 1
+        3,  // on Stmt, goto 2
+        0,  // on Stmt*, error
+        4,  // on Stmt+, goto 3
+        5,  // on Stmts, goto 4
+        0,  // on __Stmts, error
+
+        // State 1
+        0,  // on Name, error
+        0,  // on Stmt, error
+        0,  // on Stmt*, error
+        0,  // on Stmt+, error
+        0,  // on Stmts, error
+        0,  // on __Stmts, error
+
+        // State 2
+        0,  // on Name, error
+        0,  // on Stmt, error
+        0,  // on Stmt*, error
+        0,  // on Stmt+, error
+        0,  // on Stmts, error
+        0,  // on __Stmts, error
+
+        // State 3
+        2,  // on Name, goto 1
+        11,  // on Stmt, goto 10
+        0,  // on Stmt*, error
+        0,  // on Stmt+, error
+        0,  // on Stmts, error
+        0,  // on __Stmts, error
+
+        // State 4
+        0,  // on Name, error
+        0,  // on Stmt, error
+        0,  // on Stmt*, error
+        0,  // on Stmt+, error
+        0,  // on Stmts, error
+        0,  // on __Stmts, error
+
+        // State 5
+        2,  // on Name, goto 1
+        3,  // on Stmt, goto 2
+        0,  // on Stmt*, error
+        4,  // on Stmt+, goto 3
+        12,  // on Stmts, goto 11
+        0,  // on __Stmts, error
+
+        // State 6
+        0,  // on Name, error
+        0,  // on Stmt, error
+        0,  // on Stmt*, error
+        0,  // on Stmt+, error
+        0,  // on Stmts, error
+        0,  // on __Stmts, error
+
+        // State 7
+        0,  // on Name, error
+        0,  // on Stmt, error
+        0,  // on Stmt*, error
+        0,  // on Stmt+, error
+        0,  // on Stmts, error
+        0,  // on __Stmts, error
+
+        // State 8
+        14,  // on Name, goto 13
+        0,  // on Stmt, error
+        0,  // on Stmt*, error
+        0,  // on Stmt+, error
+        0,  // on Stmts, error
+        0,  // on __Stmts, error
+
+        // State 9
+        0,  // on Name, error
+        0,  // on Stmt, error
+        0,  // on Stmt*, error
+        0,  // on Stmt+, error
+        0,  // on Stmts, error
+        0,  // on __Stmts, error
+
+        // State 10
+        0,  // on Name, error
+        0,  // on Stmt, error
+        0,  // on Stmt*, error
+        0,  // on Stmt+, error
+        0,  // on Stmts, error
+        0,  // on __Stmts, error
+
+        // State 11
+        0,  // on Name, error
+        0,  // on Stmt, error
+        0,  // on Stmt*, error
+        0,  // on Stmt+, error
+        0,  // on Stmts, error
+        0,  // on __Stmts, error
+
+        // State 12
+        0,  // on Name, error
+        0,  // on Stmt, error
+        0,  // on Stmt*, error
+        0,  // on Stmt+, error
+        0,  // on Stmts, error
+        0,  // on __Stmts, error
+
+        // State 13
+        0,  // on Name, error
+        0,  // on Stmt, error
+        0,  // on Stmt*, error
+        0,  // on Stmt+, error
+        0,  // on Stmts, error
+        0,  // on __Stmts, error
+
+        // State 14
+        0,  // on Name, error
+        0,  // on Stmt, error
+        0,  // on Stmt*, error
+        0,  // on Stmt+, error
+        0,  // on Stmts, error
+        0,  // on __Stmts, error
+
+        // State 15
+        0,  // on Name, error
+        0,  // on Stmt, error
+        0,  // on Stmt*, error
+        0,  // on Stmt+, error
+        0,  // on Stmts, error
+        0,  // on __Stmts, error
+
+        // State 16
+        0,  // on Name, error
+        0,  // on Stmt, error
+        0,  // on Stmt*, error
+        0,  // on Stmt+, error
+        0,  // on Stmts, error
+        0,  // on __Stmts, error
+
+    ];
+    fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+        const __TERMINAL: &'static [&'static str] = &[
+            r###""(""###,
+            r###"")""###,
+            r###"";""###,
+            r###""{""###,
+            r###""}""###,
+            r###"r#"[a-z]+"#"###,
+        ];
+        __ACTION[(__state * 7)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+            if state == 0 {
+                None
+            } else {
+                Some(terminal.to_string())
+            }
+        }).collect()
+    }
+    #[allow(dead_code)]
+    pub fn parse_Stmts<
+        'input,
+    >(
+        input: &'input str,
+    ) -> Result<Vec<String>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let mut __tokens = super::__intern_token::__Matcher::new(input);
+        let mut __states = vec![];
+        let mut __symbols = vec![];
+        __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Stmts(__v) => Ok(__v), _ => unreachable!() })
+    }
+    #[allow(dead_code)]
+    pub fn parse_Stmts_cancelable<
+        'input,
+    >(
+        input: &'input str,
+        __cancel: &::std::sync::atomic::AtomicBool,
+    ) -> Result<Vec<String>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let mut __tokens = super::__intern_token::__Matcher::new(input);
+        let mut __states = vec![];
+        let mut __symbols = vec![];
+        __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Stmts(__v) => Ok(__v), _ => unreachable!() })
+    }
+
+    #[allow(dead_code)]
+    pub struct StmtsParser {
+        __states: ::std::vec::Vec<i32>,
+        __symbols: __lalrpop_util::stack::Recycled,
+        __cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+    }
+
+    #[allow(dead_code)]
+    impl StmtsParser {
+        pub fn new() -> StmtsParser {
+            StmtsParser { __states: vec![], __symbols: __lalrpop_util::stack::Recycled::new(), __cancel: None }
+        }
+
+        pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+            self.__cancel = Some(flag);
+        }
+
+        pub fn parse_in_place<'input>(
+            &mut self,
+            input: &'input str,
+        ) -> Result<Vec<String>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+        {
+            let mut __tokens = super::__intern_token::__Matcher::new(input);
+            let mut __symbols = self.__symbols.take();
+            let __result = __parse::<>(input, 0, &mut __tokens, &mut self.__states, &mut __symbols, self.__cancel.as_ref().map(|c| &**c)).and_then(|__v| match __v { __Symbol::Nt____Stmts(__v) => Ok(__v), _ => unreachable!() });
+            self.__symbols.put(__symbols);
+            __result
+        }
+    }
+
+    fn __parse<
+        'input,
+    >(
+        input: &'input str,
+        __start_state: i32,
+        mut __tokens: &mut Iterator<Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+        mut __states: &mut ::std::vec::Vec<i32>,
+        mut __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        __cancel: Option<&::std::sync::atomic::AtomicBool>,
+    ) -> Result<__Symbol<'input>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        __states.clear();
+        __states.push(__start_state);
+        __symbols.clear();
+        let mut __integer;
+        let mut __lookahead;
+        let __last_location: &mut usize = &mut Default::default();
+        '__shift: loop {
+            __lookahead = match __tokens.next() {
+                Some(Ok(v)) => v,
+                None => break '__shift,
+                Some(Err(e)) => return Err(e),
+            };
+            *__last_location = __lookahead.2.clone();
+            __integer = match __lookahead.1 {
+                Token(1, _) if true => 0,
+                Token(2, _) if true => 1,
+                Token(3, _) if true => 2,
+                Token(4, _) if true => 3,
+                Token(5, _) if true => 4,
+                Token(0, _) if true => 5,
+                _ => {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __expected = __expected_tokens(__state);
+                    let __error = match Some(__lookahead) {
+                        Some(__token) => {
+                            __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                        }
+                        None => {
+                            __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                        }
+                    };
+                    return Err(__error);
+                }
+            };
+            '__inner: loop {
+                let __state = *__states.last().unwrap() as usize;
+                let __action = __ACTION[__state * 7 + __integer] as i32;
+                if __action > 0 {
+                    if let Some(__flag) = __cancel {
+                        if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                            return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                        }
+                    }
+                    let __symbol = match __integer {
+                        0 => match __lookahead.1 {
+                            Token(1, __tok0) => __Symbol::Term_22_28_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        1 => match __lookahead.1 {
+                            Token(2, __tok0) => __Symbol::Term_22_29_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        2 => match __lookahead.1 {
+                            Token(3, __tok0) => __Symbol::Term_22_3b_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        3 => match __lookahead.1 {
+                            Token(4, __tok0) => __Symbol::Term_22_7b_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        4 => match __lookahead.1 {
+                            Token(5, __tok0) => __Symbol::Term_22_7d_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        5 => match __lookahead.1 {
+                            Token(0, __tok0) => __Symbol::Termr_23_22_5ba_2dz_5d_2b_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        _ => unreachable!(),
+                    };
+                    __states.push(__action - 1);
+                    __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                    continue '__shift;
+                } else if __action < 0 {
+                    if let Some(__flag) = __cancel {
+                        if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                            return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                        }
+                    }
+                    if let Some(r) = __reduce(input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                        if r.is_err() {
+                            return r;
+                        }
+                        return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] });
+                    }
+                } else {
+                    let mut __err_lookahead = Some(__lookahead);
+                    let mut __err_integer: Option<usize> = Some(__integer);
+                    match __error_recovery(input,  &mut __tokens, &mut __states, &mut __symbols, __last_location, &mut __err_lookahead, &mut __err_integer, __cancel, ::std::marker::PhantomData::<()>) {
+                        Err(__e) => return Err(__e),
+                        Ok(Some(__v)) => return Ok(__v),
+                        Ok(None) => (),
+                    }
+                    match (__err_lookahead, __err_integer) {
+                        (Some(__l), Some(__i)) => {
+                            __lookahead = __l;
+                            __integer = __i;
+                            continue '__inner;
+                        }
+                        _ => break '__shift,
+                    }
+                }
+            }
+        }
+        loop {
+            let __state = *__states.last().unwrap() as usize;
+            let __action = __EOF_ACTION[__state] as i32;
+            if __action < 0 {
+                if let Some(__flag) = __cancel {
+                    if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                        return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                    }
+                }
+                if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                    return r;
+                }
+            } else {
+                let mut __err_lookahead = None;
+                let mut __err_integer: Option<usize> = None;
+                match __error_recovery(input,  &mut __tokens, &mut __states, &mut __symbols, __last_location, &mut __err_lookahead, &mut __err_integer, __cancel, ::std::marker::PhantomData::<()>) {
+                    Err(__e) => return Err(__e),
+                    Ok(Some(__v)) => return Ok(__v),
+                    Ok(None) => (),
+                }
+            }
+        }
+    }
+    fn __error_recovery<
+        'input,
+        __I,
+    >(
+        input: &'input str,
+        __tokens: &mut __I,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        __last_location: &mut usize,
+        __opt_lookahead: &mut Option<(usize, Token<'input>, usize)>,
+        __opt_integer: &mut Option<usize>,
+        __cancel: Option<&::std::sync::atomic::AtomicBool>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<Option<__Symbol<'input>>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> where
+      __I: Iterator<Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+    {
+        let __state = *__states.last().unwrap() as usize;
+        let __expected = __expected_tokens(__state);
+        let __error = match __opt_lookahead.clone() {
+            Some(__token) => {
+                if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                    __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                } else {
+                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                }
+            }
+            None => {
+                __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+            }
+        };
+        let mut __dropped_tokens = vec![];
+        loop {
+            let __state = *__states.last().unwrap() as usize;
+            let __action = __ACTION[__state * 7 + 6] as i32;
+            if __action >= 0 {
+                break;
+            }
+            if let Some(__flag) = __cancel {
+                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                    return Err(__lalrpop_util::ParseError::Canceled { location: __opt_lookahead.as_ref().map(|l| l.0.clone()).unwrap_or_else(|| __last_location.clone()) });
+                }
+            }
+            let __lookahead_start = __opt_lookahead.as_ref().map(|l| &l.0);
+            if let Some(r) = __reduce( input,  __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()> ) {
+                return Ok(Some(r?));
+            }
+        }
+        let __states_len = __states.len();
+        let __top0;
+        '__find_state: loop {
+            for __top in (0..__states_len).rev() {
+                let __state = __states[__top];
+                let __action = __ACTION[(__state * 7 + 6) as usize] as i32;
+                if __action <= 0 { continue; }
+                let __error_state = __action - 1;
+                if __accepts(input,  __error_state, &__states[..__top + 1], *__opt_integer, ::std::marker::PhantomData::<()>,) {
+                    __top0 = __top;
+                    break '__find_state;
+                }
+            }
+            '__eof: loop {
+                match __opt_lookahead.take() {
+                    None => {
+                        return Err(__error)
+                    }
+                    Some(mut __lookahead) => {
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                            }
+                        }
+                        __dropped_tokens.push(__lookahead);
+                        if match *__opt_integer { Some(0) | Some(3) => true, _ => false } {
+                            let mut __depth = 1_usize;
+                            while __depth > 0 {
+                                __lookahead = match __tokens.next() {
+                                    Some(Ok(v)) => v,
+                                    None => break '__eof,
+                                    Some(Err(e)) => return Err(e),
+                                };
+                                *__last_location = __lookahead.2.clone();
+                                let __integer;
+                                __integer = match __lookahead.1 {
+                                    Token(1, _) if true => 0,
+                                    Token(2, _) if true => 1,
+                                    Token(3, _) if true => 2,
+                                    Token(4, _) if true => 3,
+                                    Token(5, _) if true => 4,
+                                    Token(0, _) if true => 5,
+                                    _ => {
+                                        let __state = *__states.last().unwrap() as usize;
+                                        let __expected = __expected_tokens(__state);
+                                        let __error = match Some(__lookahead) {
+                                            Some(__token) => {
+                                                __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                            }
+                                            None => {
+                                                __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                            }
+                                        };
+                                        return Err(__error);
+                                    }
+                                };
+                                match __integer {
+                                    0 | 3 => __depth += 1,
+                                    1 | 4 => __depth -= 1,
+                                    _ => { }
+                                }
+                                __dropped_tokens.push(__lookahead);
+                            }
+                        }
+                        __lookahead = match __tokens.next() {
+                            Some(Ok(v)) => v,
+                            None => break '__eof,
+                            Some(Err(e)) => return Err(e),
+                        };
+                        *__last_location = __lookahead.2.clone();
+                        let __integer;
+                        __integer = match __lookahead.1 {
+                            Token(1, _) if true => 0,
+                            Token(2, _) if true => 1,
+                            Token(3, _) if true => 2,
+                            Token(4, _) if true => 3,
+                            Token(5, _) if true => 4,
+                            Token(0, _) if true => 5,
+                            _ => {
+                                let __state = *__states.last().unwrap() as usize;
+                                let __expected = __expected_tokens(__state);
+                                let __error = match Some(__lookahead) {
+                                    Some(__token) => {
+                                        __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                    }
+                                    None => {
+                                        __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                    }
+                                };
+                                return Err(__error);
+                            }
+                        };
+                        *__opt_lookahead = Some(__lookahead);
+                        *__opt_integer = Some(__integer);
+                        continue '__find_state;
+                    }
+                }
+            }
+            *__opt_lookahead = None;
+            *__opt_integer = None;
+        };
+        let __top = __top0;
+        let __start = if let Some(__popped_sym) = __symbols.get(__top) {
+            __popped_sym.0.clone()
+        } else if let Some(__dropped_token) = __dropped_tokens.first() {
+            __dropped_token.0.clone()
+        } else if __top > 0 {
+            __symbols[__top - 1].2.clone()
+        } else {
+            Default::default()
+        };
+        let __end = if let Some(__dropped_token) = __dropped_tokens.last() {
+            __dropped_token.2.clone()
+        } else if __states_len - 1 > __top {
+            __symbols.last().unwrap().2.clone()
+        } else if let Some(__lookahead) = __opt_lookahead.as_ref() {
+            __lookahead.0.clone()
+        } else {
+            __start.clone()
+        };
+        __states.truncate(__top + 1);
+        __symbols.truncate(__top);
+        let __recover_state = __states[__top];
+        let __error_action = __ACTION[(__recover_state * 7 + 6) as usize] as i32;
+        let __error_state = __error_action - 1;
+        __states.push(__error_state);
+        let __recovery = __lalrpop_util::ErrorRecovery {
+            error: __error,
+            dropped_tokens: __dropped_tokens,
+            state: __recover_state as usize,
+        };
+        __symbols.push((__start, __Symbol::Termerror(__recovery), __end));
+        Ok(None)
+    }
+    fn __accepts<
+        'input,
+    >(
+        input: &'input str,
+        __error_state: i32,
+        __states: & [i32],
+        __opt_integer: Option<usize>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> bool
+    {
+        let mut __states = __states.to_vec();
+        __states.push(__error_state);
+        loop {
+            let mut __states_len = __states.len();
+            let __top = __states[__states_len - 1];
+            let __action = match __opt_integer {
+                None => __EOF_ACTION[__top as usize] as i32,
+                Some(__integer) => __ACTION[(__top * 7) as usize + __integer] as i32,
+            };
+            if __action == 0 { return false; }
+            if __action > 0 { return true; }
+            let (__to_pop, __nt) = match -__action {
+                // simulate Name = r#"[a-z]+"# => ActionFn(6);
+                1 => {
+                    (1, 0)
+                }
+                // simulate Stmt = Name, ";" => ActionFn(2);
+                2 => {
+                    (2, 1)
+                }
+                // simulate Stmt = Name, "(", Name, ")", ";" => ActionFn(3);
+                3 => {
+                    (5, 1)
+                }
+                // simulate Stmt = "{", Stmts, "}" => ActionFn(4);
+                4 => {
+                    (3, 1)
+                }
+                // simulate Stmt = error, ";" => ActionFn(5);
+                5 => {
+                    (2, 1)
+                }
+                // simulate Stmt* =  => ActionFn(7);
+                6 => {
+                    (0, 2)
+                }
+                // simulate Stmt* = Stmt+ => ActionFn(8);
+                7 => {
+                    (1, 2)
+                }
+                // simulate Stmt+ = Stmt => ActionFn(9);
+                8 => {
+                    (1, 3)
+                }
+                // simulate Stmt+ = Stmt+, Stmt => ActionFn(10);
+                9 => {
+                    (2, 3)
+                }
+                // simulate Stmts =  => ActionFn(11);
+                10 => {
+                    (0, 4)
+                }
+                // simulate Stmts = Stmt+ => ActionFn(12);
+                11 => {
+                    (1, 4)
+                }
+                // simulate __Stmts = Stmts => ActionFn(0);
+                12 => return true,
+                _ => panic!("invalid action code {}", __action)
+            };
+            __states_len -= __to_pop;
+            __states.truncate(__states_len);
+            let __top = __states[__states_len - 1];
+            let __next_state = __GOTO[(__top * 6 + __nt) as usize] as i32 - 1;
+            __states.push(__next_state);
+        }
+    }
+    pub fn __reduce<
+        'input,
+    >(
+        input: &'input str,
+        __action: i32,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Option<Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        let __nonterminal = match -__action {
+            1 => match __reduce_Name_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            2 => match __reduce_Stmt_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            3 => match __reduce_Stmt_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            4 => match __reduce_Stmt_2(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            5 => match __reduce_Stmt_3(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            6 => match __reduce_Stmt_2a_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            7 => match __reduce_Stmt_2a_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            8 => match __reduce_Stmt_2b_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            9 => match __reduce_Stmt_2b_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            10 => match __reduce_Stmts_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            11 => match __reduce_Stmts_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            12 => match __reduce_____Stmts_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            _ => panic!("invalid action code {}", __action)
+        };
+        let __state = *__states.last().unwrap() as usize;
+        let __next_state = __GOTO[__state * 6 + __nonterminal] as i32 - 1;
+        __states.push(__next_state);
+        None
+    }
+
+    fn __reduce_Name_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Name = r#"[a-z]+"# => ActionFn(6);
+        let __sym0 = __pop_Termr_23_22_5ba_2dz_5d_2b_22_23(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action6::<>(input, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtName(__nt), __end));
+        Ok(0)
+    }
+
+    fn __reduce_Stmt_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Stmt = Name, ";" => ActionFn(2);
+        let __sym1 = __pop_Term_22_3b_22(__symbols);
+        let __sym0 = __pop_NtName(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action2::<>(input, __sym0, __sym1);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 2);
+        __symbols.push((__start, __Symbol::NtStmt(__nt), __end));
+        Ok(1)
+    }
+
+    fn __reduce_Stmt_1<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Stmt = Name, "(", Name, ")", ";" => ActionFn(3);
+        let __sym4 = __pop_Term_22_3b_22(__symbols);
+        let __sym3 = __pop_Term_22_29_22(__symbols);
+        let __sym2 = __pop_NtName(__symbols);
+        let __sym1 = __pop_Term_22_28_22(__symbols);
+        let __sym0 = __pop_NtName(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action3::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 5);
+        __symbols.push((__start, __Symbol::NtStmt(__nt), __end));
+        Ok(1)
+    }
+
+    fn __reduce_Stmt_2<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Stmt = "{", Stmts, "}" => ActionFn(4);
+        let __sym2 = __pop_Term_22_7d_22(__symbols);
+        let __sym1 = __pop_NtStmts(__symbols);
+        let __sym0 = __pop_Term_22_7b_22(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action4::<>(input, __sym0, __sym1, __sym2);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 3);
+        __symbols.push((__start, __Symbol::NtStmt(__nt), __end));
+        Ok(1)
+    }
+
+    fn __reduce_Stmt_3<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Stmt = error, ";" => ActionFn(5);
+        let __sym1 = __pop_Term_22_3b_22(__symbols);
+        let __sym0 = __pop_Termerror(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action5::<>(input, __sym0, __sym1);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 2);
+        __symbols.push((__start, __Symbol::NtStmt(__nt), __end));
+        Ok(1)
+    }
+
+    fn __reduce_Stmt_2a_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Stmt* =  => ActionFn(7);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action7::<>(input, &__start, &__end);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 0);
+        __symbols.push((__start, __Symbol::NtStmt_2a(__nt), __end));
+        Ok(2)
+    }
+
+    fn __reduce_Stmt_2a_1<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Stmt* = Stmt+ => ActionFn(8);
+        let __sym0 = __pop_NtStmt_2b(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action8::<>(input, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtStmt_2a(__nt), __end));
+        Ok(2)
+    }
+
+    fn __reduce_Stmt_2b_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Stmt+ = Stmt => ActionFn(9);
+        let __sym0 = __pop_NtStmt(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action9::<>(input, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtStmt_2b(__nt), __end));
+        Ok(3)
+    }
+
+    fn __reduce_Stmt_2b_1<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Stmt+ = Stmt+, Stmt => ActionFn(10);
+        let __sym1 = __pop_NtStmt(__symbols);
+        let __sym0 = __pop_NtStmt_2b(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action10::<>(input, __sym0, __sym1);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 2);
+        __symbols.push((__start, __Symbol::NtStmt_2b(__nt), __end));
+        Ok(3)
+    }
+
+    fn __reduce_Stmts_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Stmts =  => ActionFn(11);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action11::<>(input, &__start, &__end);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 0);
+        __symbols.push((__start, __Symbol::NtStmts(__nt), __end));
+        Ok(4)
+    }
+
+    fn __reduce_Stmts_1<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Stmts = Stmt+ => ActionFn(12);
+        let __sym0 = __pop_NtStmt_2b(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action12::<>(input, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtStmts(__nt), __end));
+        Ok(4)
+    }
+
+    fn __reduce_____Stmts_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // __Stmts = Stmts => ActionFn(0);
+        let __sym0 = __pop_NtStmts(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action0::<>(input, __sym0);
+        Err(Ok(__Symbol::Nt____Stmts(__nt)))
+    }
+    fn __pop_Term_22_28_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_28_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_29_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_29_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3b_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3b_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_7b_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_7b_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_7d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_7d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_5ba_2dz_5d_2b_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_5ba_2dz_5d_2b_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termerror<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, __lalrpop_util::ErrorRecovery<usize, Token<'input>, &'static str>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termerror(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtName<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, String, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtName(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtStmt<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, String, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtStmt(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtStmt_2a<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<String>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtStmt_2a(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtStmt_2b<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<String>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtStmt_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtStmts<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<String>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtStmts(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____Stmts<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<String>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Stmts(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+}
+mod __intern_token {
+    #![allow(unused_imports)]
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    extern crate regex as __regex;
+    use std::fmt as __fmt;
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Token<'input>(pub usize, pub &'input str);
+    impl<'a> __fmt::Display for Token<'a> {
+        fn fmt(&self, formatter: &mut __fmt::Formatter) -> Result<(), __fmt::Error> {
+            __fmt::Display::fmt(self.1, formatter)
+        }
+    }
+
+    pub struct __Matcher<'input> {
+        text: &'input str,
+        consumed: usize,
+        regex_set: __regex::RegexSet,
+        regex_vec: Vec<__regex::Regex>,
+    }
+
+    impl<'input> __Matcher<'input> {
+        pub fn new(s: &'input str) -> __Matcher<'input> {
+            let __strs: &[&str] = &[
+                "^(?u:[a-z])+",
+                "^(?u:\\()",
+                "^(?u:\\))",
+                "^(?u:;)",
+                "^(?u:\\{)",
+                "^(?u:\\})",
+            ];
+            let __regex_set = __regex::RegexSet::new(__strs).unwrap();
+            let __regex_vec = vec![
+                __regex::Regex::new("^(?u:[a-z])+").unwrap(),
+                __regex::Regex::new("^(?u:\\()").unwrap(),
+                __regex::Regex::new("^(?u:\\))").unwrap(),
+                __regex::Regex::new("^(?u:;)").unwrap(),
+                __regex::Regex::new("^(?u:\\{)").unwrap(),
+                __regex::Regex::new("^(?u:\\})").unwrap(),
+            ];
+            __Matcher {
+                text: s,
+                consumed: 0,
+                regex_set: __regex_set,
+                regex_vec: __regex_vec,
+            }
+        }
+    }
+
+    impl<'input> Iterator for __Matcher<'input> {
+        type Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize,Token<'input>,&'static str>>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let __text = self.text.trim_left();
+            let __whitespace = self.text.len() - __text.len();
+            let __start_offset = self.consumed + __whitespace;
+            if __text.is_empty() {
+                self.text = __text;
+                self.consumed = __start_offset;
+                None
+            } else {
+                let __matches = self.regex_set.matches(__text);
+                if !__matches.matched_any() {
+                    Some(Err(__lalrpop_util::ParseError::InvalidToken {
+                        location: __start_offset,
+                    }))
+                } else {
+                    let mut __longest_match = 0;
+                    let mut __index = 0;
+                    for __i in 0 .. 6 {
+                        if __matches.matched(__i) {
+                            let __match = self.regex_vec[__i].find(__text).unwrap();
+                            let __len = __match.end();
+                            if __len >= __longest_match {
+                                __longest_match = __len;
+                                __index = __i;
+                            }
+                        }
+                    }
+                    let __result = &__text[..__longest_match];
+                    let __remaining = &__text[__longest_match..];
+                    let __end_offset = __start_offset + __longest_match;
+                    self.text = __remaining;
+                    self.consumed = __end_offset;
+                    Some(Ok((__start_offset, Token(__index, __result), __end_offset)))
+                }
+            }
+        }
+    }
+}
+pub use self::__intern_token::Token;
+
+#[allow(unused_variables)]
+fn __action0<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Vec<String>, usize),
+) -> Vec<String>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, ::std::vec::Vec<String>, usize),
+) -> Vec<String>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'input,
+>(
+    input: &'input str,
+    (_, n, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> String
+{
+    //@ src/error_recovery_delimiters.lalrpop:10:21 (1 lines)
+    n
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'input,
+>(
+    input: &'input str,
+    (_, n, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, a, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> String
+{
+    //@ src/error_recovery_delimiters.lalrpop:11:38 (1 lines)
+    format!("{}({})", n, a)
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, s, _): (usize, Vec<String>, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> String
+{
+    //@ src/error_recovery_delimiters.lalrpop:12:26 (1 lines)
+    format!("{{{}}}", s.join(" "))
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, __lalrpop_util::ErrorRecovery<usize, Token<'input>, &'static str>, usize),
+    (_, __1, _): (usize, &'input str, usize),
+) -> String
+{
+    //@ src/error_recovery_delimiters.lalrpop:13:14 (1 lines)
+    "!".to_string()
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> String
+{
+    //@ src/error_recovery_delimiters.lalrpop:16:29 (1 lines)
+    __0.to_string()
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::vec::Vec<String>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<String>, usize),
+) -> ::std::vec::Vec<String>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action9<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, String, usize),
+) -> ::std::vec::Vec<String>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action10<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<String>, usize),
+    (_, e, _): (usize, String, usize),
+) -> ::std::vec::Vec<String>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action11<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<String>
+{
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action7(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action1(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action12<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::vec::Vec<String>, usize),
+) -> Vec<String>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action8(
+        input,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action1(
+        input,
+        __temp0,
+    )
+}
+
+pub trait __ToTriple<'input, > {
+    type Error;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),Self::Error>;
+}
+
+impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize) {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        Ok(value)
+    }
+}
+impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        value
+    }
+}
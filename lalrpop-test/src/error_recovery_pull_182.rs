@@ -0,0 +1,1017 @@
+// auto-generated: "lalrpop 0.15.0"
+use util::tok::Tok;
+use lalrpop_util::ErrorRecovery;
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+pub use self::__parse_table::parse_Item;
+pub use self::__parse_table::parse_Item_cancelable;
+pub use self::__parse_table::ItemParser;
+
+mod __parse_table {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    use util::tok::Tok;
+    use lalrpop_util::ErrorRecovery;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__ToTriple;
+    #[allow(dead_code)]
+    pub enum __Symbol<>
+     {
+        Term_22_2b_22(Tok),
+        Term_22_2f_22(Tok),
+        TermNum(i32),
+        Termerror(__lalrpop_util::ErrorRecovery<(), Tok, &'static str>),
+        NtExpr(()),
+        NtItem(()),
+        NtSkipExtraTokens(()),
+        Nt____Item(()),
+    }
+    impl<> ::std::fmt::Debug for __Symbol<>
+    {
+        fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            match *self {
+                __Symbol::Term_22_2b_22(..) => __f.write_str("\"+\""),
+                __Symbol::Term_22_2f_22(..) => __f.write_str("\"/\""),
+                __Symbol::TermNum(..) => __f.write_str("Num"),
+                __Symbol::Termerror(..) => __f.write_str("error"),
+                __Symbol::NtExpr(..) => __f.write_str("Expr"),
+                __Symbol::NtItem(..) => __f.write_str("Item"),
+                __Symbol::NtSkipExtraTokens(..) => __f.write_str("SkipExtraTokens"),
+                __Symbol::Nt____Item(..) => __f.write_str("__Item"),
+            }
+        }
+    }
+    const __ACTION: &'static [i8] = &[
+        // State 0
+        //     Expr = (*) Num ["+", "/", Num, error, EOF]
+        //     Expr = (*) Num "+" Num ["+", "/", Num, error, EOF]
+        //     Item = (*) Expr SkipExtraTokens "/" ["+", "/", Num, error, EOF]
+        //     Item = (*) error ["+", "/", Num, error, EOF]
+        //     __Item = (*) Item ["+", "/", Num, error, EOF]
+        0,  // on "+", error
+        0,  // on "/", error
+        4,  // on Num, goto 3
+        5,  // on error, goto 4
+
+        // State 1
+        //     Item = Expr (*) SkipExtraTokens "/" ["+", "/", Num, error, EOF]
+        //     SkipExtraTokens = (*) ["+", "/", Num, error, EOF]
+        //     SkipExtraTokens = (*) error ["+", "/", Num, error, EOF]
+        0,  // on "+", error
+        -5,  // on "/", reduce `SkipExtraTokens =  => ActionFn(1);`
+        0,  // on Num, error
+        7,  // on error, goto 6
+
+        // State 2
+        //     __Item = Item (*) ["+", "/", Num, error, EOF]
+        0,  // on "+", error
+        0,  // on "/", error
+        0,  // on Num, error
+        0,  // on error, error
+
+        // State 3
+        //     Expr = Num (*) ["+", "/", Num, error, EOF]
+        //     Expr = Num (*) "+" Num ["+", "/", Num, error, EOF]
+        8,  // on "+", goto 7
+        -1,  // on "/", reduce `Expr = Num => ActionFn(3);`
+        0,  // on Num, error
+        -1,  // on error, reduce `Expr = Num => ActionFn(3);`
+
+        // State 4
+        //     Item = error (*) ["+", "/", Num, error, EOF]
+        0,  // on "+", error
+        0,  // on "/", error
+        0,  // on Num, error
+        0,  // on error, error
+
+        // State 5
+        //     Item = Expr SkipExtraTokens (*) "/" ["+", "/", Num, error, EOF]
+        0,  // on "+", error
+        9,  // on "/", goto 8
+        0,  // on Num, error
+        0,  // on error, error
+
+        // State 6
+        //     SkipExtraTokens = error (*) ["+", "/", Num, error, EOF]
+        0,  // on "+", error
+        -6,  // on "/", reduce `SkipExtraTokens = error => ActionFn(2);`
+        0,  // on Num, error
+        0,  // on error, error
+
+        // State 7
+        //     Expr = Num "+" (*) Num ["+", "/", Num, error, EOF]
+        0,  // on "+", error
+        0,  // on "/", error
+        10,  // on Num, goto 9
+        0,  // on error, error
+
+        // State 8
+        //     Item = Expr SkipExtraTokens "/" (*) ["+", "/", Num, error, EOF]
+        0,  // on "+", error
+        0,  // on "/", error
+        0,  // on Num, error
+        0,  // on error, error
+
+        // State 9
+        //     Expr = Num "+" Num (*) ["+", "/", Num, error, EOF]
+        0,  // on "+", error
+        -2,  // on "/", reduce `Expr = Num, "+", Num => ActionFn(4);`
+        0,  // on Num, error
+        -2,  // on error, reduce `Expr = Num, "+", Num => ActionFn(4);`
+
+    ];
+    const __EOF_ACTION: &'static [i8] = &[
+        // State 0
+        0,  // on EOF, error
+
+        // State 1
+        0,  // on EOF, error
+
+        // State 2
+        -7,  // on EOF, reduce `__Item = Item => ActionFn(0);`
+
+        // State 3
+        0,  // on EOF, error
+
+        // State 4
+        -4,  // on EOF, reduce `Item = error => ActionFn(6);`
+
+        // State 5
+        0,  // on EOF, error
+
+        // State 6
+        0,  // on EOF, error
+
+        // State 7
+        0,  // on EOF, error
+
+        // State 8
+        -3,  // on EOF, reduce `Item = Expr, SkipExtraTokens, "/" => ActionFn(5);`
+
+        // State 9
+        0,  // on EOF, error
+
+    ];
+    const __GOTO: &'static [u8] = &[
+        // State 0
+        2,  // on Expr, goto 1
+        3,  // on Item, goto 2
+        0,  // on SkipExtraTokens, error
+        0,  // on __Item, error
+
+        // State 1
+        0,  // on Expr, error
+        0,  // on Item, error
+        6,  // on SkipExtraTokens, goto 5
+        0,  // on __Item, error
+
+        // State 2
+        0,  // on Expr, error
+        0,  // on Item, error
+        0,  // on SkipExtraTokens, error
+        0,  // on __Item, error
+
+        // State 3
+        0,  // on Expr, error
+        0,  // on Item, error
+        0,  // on SkipExtraTokens, error
+        0,  // on __Item, error
+
+        // State 4
+        0,  // on Expr, error
+        0,  // on Item, error
+        0,  // on SkipExtraTokens, error
+        0,  // on __Item, error
+
+        // State 5
+        0,  // on Expr, error
+        0,  // on Item, error
+        0,  // on SkipExtraTokens, error
+        0,  // on __Item, error
+
+        // State 6
+        0,  // on Expr, error
+        0,  // on Item, error
+        0,  // on SkipExtraTokens, error
+        0,  // on __Item, error
+
+        // State 7
+        0,  // on Expr, error
+        0,  // on Item, error
+        0,  // on SkipExtraTokens, error
+        0,  // on __Item, error
+
+        // State 8
+        0,  // on Expr, error
+        0,  // on Item, error
+        0,  // on SkipExtraTokens, error
+        0,  // on __Item, error
+
+        // State 9
+        0,  // on Expr, error
+        0,  // on Item, error
+        0,  // on SkipExtraTokens, error
+        0,  // on __Item, error
+
+    ];
+    fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+        const __TERMINAL: &'static [&'static str] = &[
+            r###""+""###,
+            r###""/""###,
+            r###"Num"###,
+        ];
+        __ACTION[(__state * 4)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+            if state == 0 {
+                None
+            } else {
+                Some(terminal.to_string())
+            }
+        }).collect()
+    }
+    #[allow(dead_code)]
+    pub fn parse_Item<
+        'input,
+        'e,
+        __TOKEN: __ToTriple<'input, 'e, Error=&'static str>,
+        __TOKENS: IntoIterator<Item=__TOKEN>,
+    >(
+        errors: &'e mut Vec<ErrorRecovery<(), Tok, &'static str>>,
+        __tokens0: __TOKENS,
+    ) -> Result<(), __lalrpop_util::ParseError<(), Tok, &'static str>>
+    {
+        let __tokens = __tokens0.into_iter();
+        let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+        let mut __states = vec![];
+        let mut __symbols = vec![];
+        __parse::<>(errors, 0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Item(__v) => Ok(__v), _ => unreachable!() })
+    }
+    #[allow(dead_code)]
+    pub fn parse_Item_cancelable<
+        'input,
+        'e,
+        __TOKEN: __ToTriple<'input, 'e, Error=&'static str>,
+        __TOKENS: IntoIterator<Item=__TOKEN>,
+    >(
+        errors: &'e mut Vec<ErrorRecovery<(), Tok, &'static str>>,
+        __tokens0: __TOKENS,
+        __cancel: &::std::sync::atomic::AtomicBool,
+    ) -> Result<(), __lalrpop_util::ParseError<(), Tok, &'static str>>
+    {
+        let __tokens = __tokens0.into_iter();
+        let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+        let mut __states = vec![];
+        let mut __symbols = vec![];
+        __parse::<>(errors, 0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Item(__v) => Ok(__v), _ => unreachable!() })
+    }
+
+    #[allow(dead_code)]
+    pub struct ItemParser {
+        __states: ::std::vec::Vec<i32>,
+        __symbols: __lalrpop_util::stack::Recycled,
+        __cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+    }
+
+    #[allow(dead_code)]
+    impl ItemParser {
+        pub fn new() -> ItemParser {
+            ItemParser { __states: vec![], __symbols: __lalrpop_util::stack::Recycled::new(), __cancel: None }
+        }
+
+        pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+            self.__cancel = Some(flag);
+        }
+
+        pub fn parse_in_place<'input, 'e, __TOKEN: __ToTriple<'input, 'e, Error=&'static str>, __TOKENS: IntoIterator<Item=__TOKEN>>(
+            &mut self,
+            errors: &'e mut Vec<ErrorRecovery<(), Tok, &'static str>>,
+            __tokens0: __TOKENS,
+        ) -> Result<(), __lalrpop_util::ParseError<(), Tok, &'static str>>
+        {
+            let __tokens = __tokens0.into_iter();
+            let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+            let mut __symbols = self.__symbols.take();
+            let __result = __parse::<>(errors, 0, &mut __tokens, &mut self.__states, &mut __symbols, self.__cancel.as_ref().map(|c| &**c)).and_then(|__v| match __v { __Symbol::Nt____Item(__v) => Ok(__v), _ => unreachable!() });
+            self.__symbols.put(__symbols);
+            __result
+        }
+    }
+
+    fn __parse<
+        'input,
+        'e,
+    >(
+        errors: &'e mut Vec<ErrorRecovery<(), Tok, &'static str>>,
+        __start_state: i32,
+        mut __tokens: &mut Iterator<Item = Result<((), Tok, ()), &'static str>>,
+        mut __states: &mut ::std::vec::Vec<i32>,
+        mut __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+        __cancel: Option<&::std::sync::atomic::AtomicBool>,
+    ) -> Result<__Symbol<>, __lalrpop_util::ParseError<(), Tok, &'static str>>
+    {
+        __states.clear();
+        __states.push(__start_state);
+        __symbols.clear();
+        let mut __integer;
+        let mut __lookahead;
+        let __last_location: &mut () = &mut Default::default();
+        '__shift: loop {
+            __lookahead = match __tokens.next() {
+                Some(Ok(v)) => v,
+                None => break '__shift,
+                Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+            };
+            *__last_location = __lookahead.2.clone();
+            __integer = match __lookahead.1 {
+                Tok::Plus if true => 0,
+                Tok::Div if true => 1,
+                Tok::Num(_) if true => 2,
+                _ => {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __expected = __expected_tokens(__state);
+                    let __error = match Some(__lookahead) {
+                        Some(__token) => {
+                            __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                        }
+                        None => {
+                            __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                        }
+                    };
+                    return Err(__error);
+                }
+            };
+            '__inner: loop {
+                let __state = *__states.last().unwrap() as usize;
+                let __action = __ACTION[__state * 4 + __integer] as i32;
+                if __action > 0 {
+                    if let Some(__flag) = __cancel {
+                        if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                            return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                        }
+                    }
+                    let __symbol = match __integer {
+                        0 => match __lookahead.1 {
+                            __tok @ Tok::Plus => __Symbol::Term_22_2b_22((__tok)),
+                            _ => unreachable!(),
+                        },
+                        1 => match __lookahead.1 {
+                            __tok @ Tok::Div => __Symbol::Term_22_2f_22((__tok)),
+                            _ => unreachable!(),
+                        },
+                        2 => match __lookahead.1 {
+                            Tok::Num(__tok0) => __Symbol::TermNum((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        _ => unreachable!(),
+                    };
+                    __states.push(__action - 1);
+                    __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                    continue '__shift;
+                } else if __action < 0 {
+                    if let Some(__flag) = __cancel {
+                        if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                            return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                        }
+                    }
+                    if let Some(r) = __reduce(errors, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                        if r.is_err() {
+                            return r;
+                        }
+                        return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] });
+                    }
+                } else {
+                    let mut __err_lookahead = Some(__lookahead);
+                    let mut __err_integer: Option<usize> = Some(__integer);
+                    match __error_recovery(errors,  &mut __tokens, &mut __states, &mut __symbols, __last_location, &mut __err_lookahead, &mut __err_integer, __cancel, ::std::marker::PhantomData::<()>) {
+                        Err(__e) => return Err(__e),
+                        Ok(Some(__v)) => return Ok(__v),
+                        Ok(None) => (),
+                    }
+                    match (__err_lookahead, __err_integer) {
+                        (Some(__l), Some(__i)) => {
+                            __lookahead = __l;
+                            __integer = __i;
+                            continue '__inner;
+                        }
+                        _ => break '__shift,
+                    }
+                }
+            }
+        }
+        loop {
+            let __state = *__states.last().unwrap() as usize;
+            let __action = __EOF_ACTION[__state] as i32;
+            if __action < 0 {
+                if let Some(__flag) = __cancel {
+                    if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                        return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                    }
+                }
+                if let Some(r) = __reduce(errors, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                    return r;
+                }
+            } else {
+                let mut __err_lookahead = None;
+                let mut __err_integer: Option<usize> = None;
+                match __error_recovery(errors,  &mut __tokens, &mut __states, &mut __symbols, __last_location, &mut __err_lookahead, &mut __err_integer, __cancel, ::std::marker::PhantomData::<()>) {
+                    Err(__e) => return Err(__e),
+                    Ok(Some(__v)) => return Ok(__v),
+                    Ok(None) => (),
+                }
+            }
+        }
+    }
+    fn __error_recovery<
+        'input,
+        'e,
+        __I,
+    >(
+        errors: &'e mut Vec<ErrorRecovery<(), Tok, &'static str>>,
+        __tokens: &mut __I,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+        __last_location: &mut (),
+        __opt_lookahead: &mut Option<((), Tok, ())>,
+        __opt_integer: &mut Option<usize>,
+        __cancel: Option<&::std::sync::atomic::AtomicBool>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<Option<__Symbol<>>, __lalrpop_util::ParseError<(), Tok, &'static str>> where
+      __I: Iterator<Item = Result<((), Tok, ()), &'static str>>,
+    {
+        let __state = *__states.last().unwrap() as usize;
+        let __expected = __expected_tokens(__state);
+        let __error = match __opt_lookahead.clone() {
+            Some(__token) => {
+                if __accepts(errors,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                    __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                } else {
+                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                }
+            }
+            None => {
+                __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+            }
+        };
+        let mut __dropped_tokens = vec![];
+        loop {
+            let __state = *__states.last().unwrap() as usize;
+            let __action = __ACTION[__state * 4 + 3] as i32;
+            if __action >= 0 {
+                break;
+            }
+            if let Some(__flag) = __cancel {
+                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                    return Err(__lalrpop_util::ParseError::Canceled { location: __opt_lookahead.as_ref().map(|l| l.0.clone()).unwrap_or_else(|| __last_location.clone()) });
+                }
+            }
+            let __lookahead_start = __opt_lookahead.as_ref().map(|l| &l.0);
+            if let Some(r) = __reduce( errors,  __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()> ) {
+                return Ok(Some(r?));
+            }
+        }
+        let __states_len = __states.len();
+        let __top0;
+        '__find_state: loop {
+            for __top in (0..__states_len).rev() {
+                let __state = __states[__top];
+                let __action = __ACTION[(__state * 4 + 3) as usize] as i32;
+                if __action <= 0 { continue; }
+                let __error_state = __action - 1;
+                if __accepts(errors,  __error_state, &__states[..__top + 1], *__opt_integer, ::std::marker::PhantomData::<()>,) {
+                    __top0 = __top;
+                    break '__find_state;
+                }
+            }
+            '__eof: loop {
+                match __opt_lookahead.take() {
+                    None => {
+                        return Err(__error)
+                    }
+                    Some(mut __lookahead) => {
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                            }
+                        }
+                        __dropped_tokens.push(__lookahead);
+                        __lookahead = match __tokens.next() {
+                            Some(Ok(v)) => v,
+                            None => break '__eof,
+                            Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                        };
+                        *__last_location = __lookahead.2.clone();
+                        let __integer;
+                        __integer = match __lookahead.1 {
+                            Tok::Plus if true => 0,
+                            Tok::Div if true => 1,
+                            Tok::Num(_) if true => 2,
+                            _ => {
+                                let __state = *__states.last().unwrap() as usize;
+                                let __expected = __expected_tokens(__state);
+                                let __error = match Some(__lookahead) {
+                                    Some(__token) => {
+                                        __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                    }
+                                    None => {
+                                        __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                    }
+                                };
+                                return Err(__error);
+                            }
+                        };
+                        *__opt_lookahead = Some(__lookahead);
+                        *__opt_integer = Some(__integer);
+                        continue '__find_state;
+                    }
+                }
+            }
+            *__opt_lookahead = None;
+            *__opt_integer = None;
+        };
+        let __top = __top0;
+        let __start = if let Some(__popped_sym) = __symbols.get(__top) {
+            __popped_sym.0.clone()
+        } else if let Some(__dropped_token) = __dropped_tokens.first() {
+            __dropped_token.0.clone()
+        } else if __top > 0 {
+            __symbols[__top - 1].2.clone()
+        } else {
+            Default::default()
+        };
+        let __end = if let Some(__dropped_token) = __dropped_tokens.last() {
+            __dropped_token.2.clone()
+        } else if __states_len - 1 > __top {
+            __symbols.last().unwrap().2.clone()
+        } else if let Some(__lookahead) = __opt_lookahead.as_ref() {
+            __lookahead.0.clone()
+        } else {
+            __start.clone()
+        };
+        __states.truncate(__top + 1);
+        __symbols.truncate(__top);
+        let __recover_state = __states[__top];
+        let __error_action = __ACTION[(__recover_state * 4 + 3) as usize] as i32;
+        let __error_state = __error_action - 1;
+        __states.push(__error_state);
+        let __recovery = __lalrpop_util::ErrorRecovery {
+            error: __error,
+            dropped_tokens: __dropped_tokens,
+            state: __recover_state as usize,
+        };
+        __symbols.push((__start, __Symbol::Termerror(__recovery), __end));
+        Ok(None)
+    }
+    fn __accepts<
+        'input,
+        'e,
+    >(
+        errors: &'e mut Vec<ErrorRecovery<(), Tok, &'static str>>,
+        __error_state: i32,
+        __states: & [i32],
+        __opt_integer: Option<usize>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> bool
+    {
+        let mut __states = __states.to_vec();
+        __states.push(__error_state);
+        loop {
+            let mut __states_len = __states.len();
+            let __top = __states[__states_len - 1];
+            let __action = match __opt_integer {
+                None => __EOF_ACTION[__top as usize] as i32,
+                Some(__integer) => __ACTION[(__top * 4) as usize + __integer] as i32,
+            };
+            if __action == 0 { return false; }
+            if __action > 0 { return true; }
+            let (__to_pop, __nt) = match -__action {
+                // simulate Expr = Num => ActionFn(3);
+                1 => {
+                    (1, 0)
+                }
+                // simulate Expr = Num, "+", Num => ActionFn(4);
+                2 => {
+                    (3, 0)
+                }
+                // simulate Item = Expr, SkipExtraTokens, "/" => ActionFn(5);
+                3 => {
+                    (3, 1)
+                }
+                // simulate Item = error => ActionFn(6);
+                4 => {
+                    (1, 1)
+                }
+                // simulate SkipExtraTokens =  => ActionFn(1);
+                5 => {
+                    (0, 2)
+                }
+                // simulate SkipExtraTokens = error => ActionFn(2);
+                6 => {
+                    (1, 2)
+                }
+                // simulate __Item = Item => ActionFn(0);
+                7 => return true,
+                _ => panic!("invalid action code {}", __action)
+            };
+            __states_len -= __to_pop;
+            __states.truncate(__states_len);
+            let __top = __states[__states_len - 1];
+            let __next_state = __GOTO[(__top * 4 + __nt) as usize] as i32 - 1;
+            __states.push(__next_state);
+        }
+    }
+    pub fn __reduce<
+        'input,
+        'e,
+    >(
+        errors: &'e mut Vec<ErrorRecovery<(), Tok, &'static str>>,
+        __action: i32,
+        __lookahead_start: Option<&()>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Option<Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+    {
+        let __nonterminal = match -__action {
+            1 => match __reduce_Expr_0(errors, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            2 => match __reduce_Expr_1(errors, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            3 => match __reduce_Item_0(errors, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            4 => match __reduce_Item_1(errors, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            5 => match __reduce_SkipExtraTokens_0(errors, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            6 => match __reduce_SkipExtraTokens_1(errors, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            7 => match __reduce_____Item_0(errors, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            _ => panic!("invalid action code {}", __action)
+        };
+        let __state = *__states.last().unwrap() as usize;
+        let __next_state = __GOTO[__state * 4 + __nonterminal] as i32 - 1;
+        __states.push(__next_state);
+        None
+    }
+
+    fn __reduce_Expr_0<
+        'input,
+        'e,
+    >(
+        errors: &'e mut Vec<ErrorRecovery<(), Tok, &'static str>>,
+        __lookahead_start: Option<&()>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+    {
+        // Expr = Num => ActionFn(3);
+        let __sym0 = __pop_TermNum(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action3::<>(errors, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+        Ok(0)
+    }
+
+    fn __reduce_Expr_1<
+        'input,
+        'e,
+    >(
+        errors: &'e mut Vec<ErrorRecovery<(), Tok, &'static str>>,
+        __lookahead_start: Option<&()>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+    {
+        // Expr = Num, "+", Num => ActionFn(4);
+        let __sym2 = __pop_TermNum(__symbols);
+        let __sym1 = __pop_Term_22_2b_22(__symbols);
+        let __sym0 = __pop_TermNum(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action4::<>(errors, __sym0, __sym1, __sym2);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 3);
+        __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+        Ok(0)
+    }
+
+    fn __reduce_Item_0<
+        'input,
+        'e,
+    >(
+        errors: &'e mut Vec<ErrorRecovery<(), Tok, &'static str>>,
+        __lookahead_start: Option<&()>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+    {
+        // Item = Expr, SkipExtraTokens, "/" => ActionFn(5);
+        let __sym2 = __pop_Term_22_2f_22(__symbols);
+        let __sym1 = __pop_NtSkipExtraTokens(__symbols);
+        let __sym0 = __pop_NtExpr(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action5::<>(errors, __sym0, __sym1, __sym2);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 3);
+        __symbols.push((__start, __Symbol::NtItem(__nt), __end));
+        Ok(1)
+    }
+
+    fn __reduce_Item_1<
+        'input,
+        'e,
+    >(
+        errors: &'e mut Vec<ErrorRecovery<(), Tok, &'static str>>,
+        __lookahead_start: Option<&()>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+    {
+        // Item = error => ActionFn(6);
+        let __sym0 = __pop_Termerror(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action6::<>(errors, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtItem(__nt), __end));
+        Ok(1)
+    }
+
+    fn __reduce_SkipExtraTokens_0<
+        'input,
+        'e,
+    >(
+        errors: &'e mut Vec<ErrorRecovery<(), Tok, &'static str>>,
+        __lookahead_start: Option<&()>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+    {
+        // SkipExtraTokens =  => ActionFn(1);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action1::<>(errors, &__start, &__end);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 0);
+        __symbols.push((__start, __Symbol::NtSkipExtraTokens(__nt), __end));
+        Ok(2)
+    }
+
+    fn __reduce_SkipExtraTokens_1<
+        'input,
+        'e,
+    >(
+        errors: &'e mut Vec<ErrorRecovery<(), Tok, &'static str>>,
+        __lookahead_start: Option<&()>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+    {
+        // SkipExtraTokens = error => ActionFn(2);
+        let __sym0 = __pop_Termerror(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action2::<>(errors, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtSkipExtraTokens(__nt), __end));
+        Ok(2)
+    }
+
+    fn __reduce_____Item_0<
+        'input,
+        'e,
+    >(
+        errors: &'e mut Vec<ErrorRecovery<(), Tok, &'static str>>,
+        __lookahead_start: Option<&()>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+    {
+        // __Item = Item => ActionFn(0);
+        let __sym0 = __pop_NtItem(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action0::<>(errors, __sym0);
+        Err(Ok(__Symbol::Nt____Item(__nt)))
+    }
+    fn __pop_Term_22_2b_22<
+    >(
+        __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+    ) -> ((), Tok, ())
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2b_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2f_22<
+    >(
+        __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+    ) -> ((), Tok, ())
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2f_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_TermNum<
+    >(
+        __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+    ) -> ((), i32, ())
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::TermNum(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termerror<
+    >(
+        __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+    ) -> ((), __lalrpop_util::ErrorRecovery<(), Tok, &'static str>, ())
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termerror(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtExpr<
+    >(
+        __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+    ) -> ((), (), ())
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtExpr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtItem<
+    >(
+        __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+    ) -> ((), (), ())
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtItem(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtSkipExtraTokens<
+    >(
+        __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+    ) -> ((), (), ())
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtSkipExtraTokens(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____Item<
+    >(
+        __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+    ) -> ((), (), ())
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Item(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action0<
+    'input,
+    'e,
+>(
+    errors: &'e mut Vec<ErrorRecovery<(), Tok, &'static str>>,
+    (_, __0, _): ((), (), ()),
+) -> ()
+{
+    ()
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'input,
+    'e,
+>(
+    errors: &'e mut Vec<ErrorRecovery<(), Tok, &'static str>>,
+    __lookbehind: &(),
+    __lookahead: &(),
+) -> ()
+{
+    //@ src/error_recovery_pull_182.lalrpop:16:8 (1 lines)
+    ()
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'input,
+    'e,
+>(
+    errors: &'e mut Vec<ErrorRecovery<(), Tok, &'static str>>,
+    (_, __0, _): ((), __lalrpop_util::ErrorRecovery<(), Tok, &'static str>, ()),
+) -> ()
+{
+    //@ src/error_recovery_pull_182.lalrpop:17:12 (1 lines)
+    errors.push(__0)
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'input,
+    'e,
+>(
+    errors: &'e mut Vec<ErrorRecovery<(), Tok, &'static str>>,
+    (_, __0, _): ((), i32, ()),
+) -> ()
+{
+    //@ src/error_recovery_pull_182.lalrpop:21:12 (1 lines)
+    ()
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'input,
+    'e,
+>(
+    errors: &'e mut Vec<ErrorRecovery<(), Tok, &'static str>>,
+    (_, lhs, _): ((), i32, ()),
+    (_, _, _): ((), Tok, ()),
+    (_, rhs, _): ((), i32, ()),
+) -> ()
+{
+    //@ src/error_recovery_pull_182.lalrpop:23:34 (1 lines)
+    ()
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'input,
+    'e,
+>(
+    errors: &'e mut Vec<ErrorRecovery<(), Tok, &'static str>>,
+    (_, __0, _): ((), (), ()),
+    (_, __1, _): ((), (), ()),
+    (_, __2, _): ((), Tok, ()),
+) -> ()
+{
+    //@ src/error_recovery_pull_182.lalrpop:27:33 (1 lines)
+    ()
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'input,
+    'e,
+>(
+    errors: &'e mut Vec<ErrorRecovery<(), Tok, &'static str>>,
+    (_, __0, _): ((), __lalrpop_util::ErrorRecovery<(), Tok, &'static str>, ()),
+) -> ()
+{
+    //@ src/error_recovery_pull_182.lalrpop:28:12 (1 lines)
+    errors.push(__0)
+}
+
+pub trait __ToTriple<'input, 'e, > {
+    type Error;
+    fn to_triple(value: Self) -> Result<((),Tok,()),Self::Error>;
+}
+
+impl<'input, 'e, > __ToTriple<'input, 'e, > for Tok {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<((),Tok,()),&'static str> {
+        Ok(((), value, ()))
+    }
+}
+impl<'input, 'e, > __ToTriple<'input, 'e, > for Result<(Tok),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<((),Tok,()),&'static str> {
+        value.map(|v| ((), v, ()))
+    }
+}
@@ -0,0 +1,1639 @@
+// auto-generated: "lalrpop 0.15.0"
+use util::tok::Tok;
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+pub use self::__parse_table::parse_Expr;
+pub use self::__parse_table::parse_Expr_cancelable;
+pub use self::__parse_table::ExprParser;
+
+mod __parse_table {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    use util::tok::Tok;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__ToTriple;
+    #[allow(dead_code)]
+    pub enum __Symbol<>
+     {
+        Term_22_28_22(Tok),
+        Term_22_29_22(Tok),
+        Term_22_2b_22(Tok),
+        Term_22_2d_22(Tok),
+        Term_22_2f_22(Tok),
+        TermNum(i32),
+        Termerror(__lalrpop_util::ErrorRecovery<usize, Tok, &'static str>),
+        Nt_40L(usize),
+        Nt_40R(usize),
+        NtExpr(String),
+        NtExpr1(String),
+        NtExpr2(String),
+        Nt____Expr(String),
+    }
+    impl<> ::std::fmt::Debug for __Symbol<>
+    {
+        fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            match *self {
+                __Symbol::Term_22_28_22(..) => __f.write_str("\"(\""),
+                __Symbol::Term_22_29_22(..) => __f.write_str("\")\""),
+                __Symbol::Term_22_2b_22(..) => __f.write_str("\"+\""),
+                __Symbol::Term_22_2d_22(..) => __f.write_str("\"-\""),
+                __Symbol::Term_22_2f_22(..) => __f.write_str("\"/\""),
+                __Symbol::TermNum(..) => __f.write_str("Num"),
+                __Symbol::Termerror(..) => __f.write_str("error"),
+                __Symbol::Nt_40L(..) => __f.write_str("@L"),
+                __Symbol::Nt_40R(..) => __f.write_str("@R"),
+                __Symbol::NtExpr(..) => __f.write_str("Expr"),
+                __Symbol::NtExpr1(..) => __f.write_str("Expr1"),
+                __Symbol::NtExpr2(..) => __f.write_str("Expr2"),
+                __Symbol::Nt____Expr(..) => __f.write_str("__Expr"),
+            }
+        }
+    }
+    const __ACTION: &'static [i8] = &[
+        // State 0
+        //     Expr = (*) Expr "+" Expr1 ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr = (*) Expr "-" Expr1 ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr = (*) Expr "-" error ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr = (*) Expr error ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr = (*) Expr1 ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr = (*) error ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr1 = (*) Expr2 ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr2 = (*) "(" Expr ")" ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr2 = (*) Num ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     __Expr = (*) Expr ["(", ")", "+", "-", "/", Num, error, EOF]
+        5,  // on "(", goto 4
+        0,  // on ")", error
+        0,  // on "+", error
+        0,  // on "-", error
+        0,  // on "/", error
+        6,  // on Num, goto 5
+        7,  // on error, goto 6
+
+        // State 1
+        //     Expr = Expr (*) "+" Expr1 ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr = Expr (*) "-" Expr1 ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr = Expr (*) "-" error ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr = Expr (*) error ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     __Expr = Expr (*) ["(", ")", "+", "-", "/", Num, error, EOF]
+        0,  // on "(", error
+        0,  // on ")", error
+        8,  // on "+", goto 7
+        9,  // on "-", goto 8
+        0,  // on "/", error
+        0,  // on Num, error
+        10,  // on error, goto 9
+
+        // State 2
+        //     Expr = Expr1 (*) ["(", ")", "+", "-", "/", Num, error, EOF]
+        0,  // on "(", error
+        -3,  // on ")", reduce `Expr = Expr1 => ActionFn(1);`
+        -3,  // on "+", reduce `Expr = Expr1 => ActionFn(1);`
+        -3,  // on "-", reduce `Expr = Expr1 => ActionFn(1);`
+        0,  // on "/", error
+        0,  // on Num, error
+        -3,  // on error, reduce `Expr = Expr1 => ActionFn(1);`
+
+        // State 3
+        //     Expr1 = Expr2 (*) ["(", ")", "+", "-", "/", Num, error, EOF]
+        0,  // on "(", error
+        -9,  // on ")", reduce `Expr1 = Expr2 => ActionFn(7);`
+        -9,  // on "+", reduce `Expr1 = Expr2 => ActionFn(7);`
+        -9,  // on "-", reduce `Expr1 = Expr2 => ActionFn(7);`
+        0,  // on "/", error
+        0,  // on Num, error
+        -9,  // on error, reduce `Expr1 = Expr2 => ActionFn(7);`
+
+        // State 4
+        //     Expr = (*) Expr "+" Expr1 ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr = (*) Expr "-" Expr1 ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr = (*) Expr "-" error ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr = (*) Expr error ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr = (*) Expr1 ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr = (*) error ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr1 = (*) Expr2 ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr2 = (*) "(" Expr ")" ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr2 = "(" (*) Expr ")" ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr2 = (*) Num ["(", ")", "+", "-", "/", Num, error, EOF]
+        5,  // on "(", goto 4
+        0,  // on ")", error
+        0,  // on "+", error
+        0,  // on "-", error
+        0,  // on "/", error
+        6,  // on Num, goto 5
+        7,  // on error, goto 6
+
+        // State 5
+        //     Expr2 = Num (*) ["(", ")", "+", "-", "/", Num, error, EOF]
+        0,  // on "(", error
+        -10,  // on ")", reduce `Expr2 = Num => ActionFn(8);`
+        -10,  // on "+", reduce `Expr2 = Num => ActionFn(8);`
+        -10,  // on "-", reduce `Expr2 = Num => ActionFn(8);`
+        0,  // on "/", error
+        0,  // on Num, error
+        -10,  // on error, reduce `Expr2 = Num => ActionFn(8);`
+
+        // State 6
+        //     Expr = error (*) ["(", ")", "+", "-", "/", Num, error, EOF]
+        0,  // on "(", error
+        -8,  // on ")", reduce `Expr = error => ActionFn(17);`
+        -8,  // on "+", reduce `Expr = error => ActionFn(17);`
+        -8,  // on "-", reduce `Expr = error => ActionFn(17);`
+        0,  // on "/", error
+        0,  // on Num, error
+        -8,  // on error, reduce `Expr = error => ActionFn(17);`
+
+        // State 7
+        //     Expr = Expr "+" (*) Expr1 ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr1 = (*) Expr2 ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr2 = (*) "(" Expr ")" ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr2 = (*) Num ["(", ")", "+", "-", "/", Num, error, EOF]
+        5,  // on "(", goto 4
+        0,  // on ")", error
+        0,  // on "+", error
+        0,  // on "-", error
+        0,  // on "/", error
+        6,  // on Num, goto 5
+        0,  // on error, error
+
+        // State 8
+        //     Expr = Expr "-" (*) Expr1 ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr = Expr "-" (*) error ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr1 = (*) Expr2 ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr2 = (*) "(" Expr ")" ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr2 = (*) Num ["(", ")", "+", "-", "/", Num, error, EOF]
+        5,  // on "(", goto 4
+        0,  // on ")", error
+        0,  // on "+", error
+        0,  // on "-", error
+        0,  // on "/", error
+        6,  // on Num, goto 5
+        14,  // on error, goto 13
+
+        // State 9
+        //     Expr = Expr error (*) ["(", ")", "+", "-", "/", Num, error, EOF]
+        0,  // on "(", error
+        -7,  // on ")", reduce `Expr = Expr, error => ActionFn(16);`
+        -7,  // on "+", reduce `Expr = Expr, error => ActionFn(16);`
+        -7,  // on "-", reduce `Expr = Expr, error => ActionFn(16);`
+        0,  // on "/", error
+        0,  // on Num, error
+        -7,  // on error, reduce `Expr = Expr, error => ActionFn(16);`
+
+        // State 10
+        //     Expr = Expr (*) "+" Expr1 ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr = Expr (*) "-" Expr1 ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr = Expr (*) "-" error ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr = Expr (*) error ["(", ")", "+", "-", "/", Num, error, EOF]
+        //     Expr2 = "(" Expr (*) ")" ["(", ")", "+", "-", "/", Num, error, EOF]
+        0,  // on "(", error
+        15,  // on ")", goto 14
+        8,  // on "+", goto 7
+        9,  // on "-", goto 8
+        0,  // on "/", error
+        0,  // on Num, error
+        10,  // on error, goto 9
+
+        // State 11
+        //     Expr = Expr "+" Expr1 (*) ["(", ")", "+", "-", "/", Num, error, EOF]
+        0,  // on "(", error
+        -4,  // on ")", reduce `Expr = Expr, "+", Expr1 => ActionFn(2);`
+        -4,  // on "+", reduce `Expr = Expr, "+", Expr1 => ActionFn(2);`
+        -4,  // on "-", reduce `Expr = Expr, "+", Expr1 => ActionFn(2);`
+        0,  // on "/", error
+        0,  // on Num, error
+        -4,  // on error, reduce `Expr = Expr, "+", Expr1 => ActionFn(2);`
+
+        // State 12
+        //     Expr = Expr "-" Expr1 (*) ["(", ")", "+", "-", "/", Num, error, EOF]
+        0,  // on "(", error
+        -5,  // on ")", reduce `Expr = Expr, "-", Expr1 => ActionFn(3);`
+        -5,  // on "+", reduce `Expr = Expr, "-", Expr1 => ActionFn(3);`
+        -5,  // on "-", reduce `Expr = Expr, "-", Expr1 => ActionFn(3);`
+        0,  // on "/", error
+        0,  // on Num, error
+        -5,  // on error, reduce `Expr = Expr, "-", Expr1 => ActionFn(3);`
+
+        // State 13
+        //     Expr = Expr "-" error (*) ["(", ")", "+", "-", "/", Num, error, EOF]
+        0,  // on "(", error
+        -6,  // on ")", reduce `Expr = Expr, "-", error => ActionFn(15);`
+        -6,  // on "+", reduce `Expr = Expr, "-", error => ActionFn(15);`
+        -6,  // on "-", reduce `Expr = Expr, "-", error => ActionFn(15);`
+        0,  // on "/", error
+        0,  // on Num, error
+        -6,  // on error, reduce `Expr = Expr, "-", error => ActionFn(15);`
+
+        // State 14
+        //     Expr2 = "(" Expr ")" (*) ["(", ")", "+", "-", "/", Num, error, EOF]
+        0,  // on "(", error
+        -11,  // on ")", reduce `Expr2 = "(", Expr, ")" => ActionFn(9);`
+        -11,  // on "+", reduce `Expr2 = "(", Expr, ")" => ActionFn(9);`
+        -11,  // on "-", reduce `Expr2 = "(", Expr, ")" => ActionFn(9);`
+        0,  // on "/", error
+        0,  // on Num, error
+        -11,  // on error, reduce `Expr2 = "(", Expr, ")" => ActionFn(9);`
+
+    ];
+    const __EOF_ACTION: &'static [i8] = &[
+        // State 0
+        0,  // on EOF, error
+
+        // State 1
+        -12,  // on EOF, reduce `__Expr = Expr => ActionFn(0);`
+
+        // State 2
+        -3,  // on EOF, reduce `Expr = Expr1 => ActionFn(1);`
+
+        // State 3
+        -9,  // on EOF, reduce `Expr1 = Expr2 => ActionFn(7);`
+
+        // State 4
+        0,  // on EOF, error
+
+        // State 5
+        -10,  // on EOF, reduce `Expr2 = Num => ActionFn(8);`
+
+        // State 6
+        -8,  // on EOF, reduce `Expr = error => ActionFn(17);`
+
+        // State 7
+        0,  // on EOF, error
+
+        // State 8
+        0,  // on EOF, error
+
+        // State 9
+        -7,  // on EOF, reduce `Expr = Expr, error => ActionFn(16);`
+
+        // State 10
+        0,  // on EOF, error
+
+        // State 11
+        -4,  // on EOF, reduce `Expr = Expr, "+", Expr1 => ActionFn(2);`
+
+        // State 12
+        -5,  // on EOF, reduce `Expr = Expr, "-", Expr1 => ActionFn(3);`
+
+        // State 13
+        -6,  // on EOF, reduce `Expr = Expr, "-", error => ActionFn(15);`
+
+        // State 14
+        -11,  // on EOF, reduce `Expr2 = "(", Expr, ")" => ActionFn(9);`
+
+    ];
+    const __GOTO: &'static [u8] = &[
+        // State 0
+        0,  // on @L, error
+        0,  // on @R, error
+        2,  // on Expr, goto 1
+        3,  // on Expr1, goto 2
+        4,  // on Expr2, goto 3
+        0,  // on __Expr, error
+
+        // State 1
+        0,  // on @L, error
+        0,  // on @R, error
+        0,  // on Expr, error
+        0,  // on Expr1, error
+        0,  // on Expr2, error
+        0,  // on __Expr, error
+
+        // State 2
+        0,  // on @L, error
+        0,  // on @R, error
+        0,  // on Expr, error
+        0,  // on Expr1, error
+        0,  // on Expr2, error
+        0,  // on __Expr, error
+
+        // State 3
+        0,  // on @L, error
+        0,  // on @R, error
+        0,  // on Expr, error
+        0,  // on Expr1, error
+        0,  // on Expr2, error
+        0,  // on __Expr, error
+
+        // State 4
+        0,  // on @L, error
+        0,  // on @R, error
+        11,  // on Expr, goto 10
+        3,  // on Expr1, goto 2
+        4,  // on Expr2, goto 3
+        0,  // on __Expr, error
+
+        // State 5
+        0,  // on @L, error
+        0,  // on @R, error
+        0,  // on Expr, error
+        0,  // on Expr1, error
+        0,  // on Expr2, error
+        0,  // on __Expr, error
+
+        // State 6
+        0,  // on @L, error
+        0,  // on @R, error
+        0,  // on Expr, error
+        0,  // on Expr1, error
+        0,  // on Expr2, error
+        0,  // on __Expr, error
+
+        // State 7
+        0,  // on @L, error
+        0,  // on @R, error
+        0,  // on Expr, error
+        12,  // on Expr1, goto 11
+        4,  // on Expr2, goto 3
+        0,  // on __Expr, error
+
+        // State 8
+        0,  // on @L, error
+        0,  // on @R, error
+        0,  // on Expr, error
+        13,  // on Expr1, goto 12
+        4,  // on Expr2, goto 3
+        0,  // on __Expr, error
+
+        // State 9
+        0,  // on @L, error
+        0,  // on @R, error
+        0,  // on Expr, error
+        0,  // on Expr1, error
+        0,  // on Expr2, error
+        0,  // on __Expr, error
+
+        // State 10
+        0,  // on @L, error
+        0,  // on @R, error
+        0,  // on Expr, error
+        0,  // on Expr1, error
+        0,  // on Expr2, error
+        0,  // on __Expr, error
+
+        // State 11
+        0,  // on @L, error
+        0,  // on @R, error
+        0,  // on Expr, error
+        0,  // on Expr1, error
+        0,  // on Expr2, error
+        0,  // on __Expr, error
+
+        // State 12
+        0,  // on @L, error
+        0,  // on @R, error
+        0,  // on Expr, error
+        0,  // on Expr1, error
+        0,  // on Expr2, error
+        0,  // on __Expr, error
+
+        // State 13
+        0,  // on @L, error
+        0,  // on @R, error
+        0,  // on Expr, error
+        0,  // on Expr1, error
+        0,  // on Expr2, error
+        0,  // on __Expr, error
+
+        // State 14
+        0,  // on @L, error
+        0,  // on @R, error
+        0,  // on Expr, error
+        0,  // on Expr1, error
+        0,  // on Expr2, error
+        0,  // on __Expr, error
+
+    ];
+    fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+        const __TERMINAL: &'static [&'static str] = &[
+            r###""(""###,
+            r###"")""###,
+            r###""+""###,
+            r###""-""###,
+            r###""/""###,
+            r###"Num"###,
+        ];
+        __ACTION[(__state * 7)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+            if state == 0 {
+                None
+            } else {
+                Some(terminal.to_string())
+            }
+        }).collect()
+    }
+    #[allow(dead_code)]
+    pub fn parse_Expr<
+        'e,
+        __TOKEN: __ToTriple<'e, Error=&'static str>,
+        __TOKENS: IntoIterator<Item=__TOKEN>,
+    >(
+        errors: &'e mut Vec<(usize, usize)>,
+        __tokens0: __TOKENS,
+    ) -> Result<String, __lalrpop_util::ParseError<usize, Tok, &'static str>>
+    {
+        let __tokens = __tokens0.into_iter();
+        let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+        let mut __states = vec![];
+        let mut __symbols = vec![];
+        __parse::<>(errors, 0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() })
+    }
+    #[allow(dead_code)]
+    pub fn parse_Expr_cancelable<
+        'e,
+        __TOKEN: __ToTriple<'e, Error=&'static str>,
+        __TOKENS: IntoIterator<Item=__TOKEN>,
+    >(
+        errors: &'e mut Vec<(usize, usize)>,
+        __tokens0: __TOKENS,
+        __cancel: &::std::sync::atomic::AtomicBool,
+    ) -> Result<String, __lalrpop_util::ParseError<usize, Tok, &'static str>>
+    {
+        let __tokens = __tokens0.into_iter();
+        let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+        let mut __states = vec![];
+        let mut __symbols = vec![];
+        __parse::<>(errors, 0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() })
+    }
+
+    #[allow(dead_code)]
+    pub struct ExprParser {
+        __states: ::std::vec::Vec<i32>,
+        __symbols: __lalrpop_util::stack::Recycled,
+        __cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+    }
+
+    #[allow(dead_code)]
+    impl ExprParser {
+        pub fn new() -> ExprParser {
+            ExprParser { __states: vec![], __symbols: __lalrpop_util::stack::Recycled::new(), __cancel: None }
+        }
+
+        pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+            self.__cancel = Some(flag);
+        }
+
+        pub fn parse_in_place<'e, __TOKEN: __ToTriple<'e, Error=&'static str>, __TOKENS: IntoIterator<Item=__TOKEN>>(
+            &mut self,
+            errors: &'e mut Vec<(usize, usize)>,
+            __tokens0: __TOKENS,
+        ) -> Result<String, __lalrpop_util::ParseError<usize, Tok, &'static str>>
+        {
+            let __tokens = __tokens0.into_iter();
+            let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+            let mut __symbols = self.__symbols.take();
+            let __result = __parse::<>(errors, 0, &mut __tokens, &mut self.__states, &mut __symbols, self.__cancel.as_ref().map(|c| &**c)).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() });
+            self.__symbols.put(__symbols);
+            __result
+        }
+    }
+
+    fn __parse<
+        'e,
+    >(
+        errors: &'e mut Vec<(usize, usize)>,
+        __start_state: i32,
+        mut __tokens: &mut Iterator<Item = Result<(usize, Tok, usize), &'static str>>,
+        mut __states: &mut ::std::vec::Vec<i32>,
+        mut __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        __cancel: Option<&::std::sync::atomic::AtomicBool>,
+    ) -> Result<__Symbol<>, __lalrpop_util::ParseError<usize, Tok, &'static str>>
+    {
+        __states.clear();
+        __states.push(__start_state);
+        __symbols.clear();
+        let mut __integer;
+        let mut __lookahead;
+        let __last_location: &mut usize = &mut Default::default();
+        '__shift: loop {
+            __lookahead = match __tokens.next() {
+                Some(Ok(v)) => v,
+                None => break '__shift,
+                Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+            };
+            *__last_location = __lookahead.2.clone();
+            __integer = match __lookahead.1 {
+                Tok::LParen if true => 0,
+                Tok::RParen if true => 1,
+                Tok::Plus if true => 2,
+                Tok::Minus if true => 3,
+                Tok::Div if true => 4,
+                Tok::Num(_) if true => 5,
+                _ => {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __expected = __expected_tokens(__state);
+                    let __error = match Some(__lookahead) {
+                        Some(__token) => {
+                            __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                        }
+                        None => {
+                            __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                        }
+                    };
+                    return Err(__error);
+                }
+            };
+            '__inner: loop {
+                let __state = *__states.last().unwrap() as usize;
+                let __action = __ACTION[__state * 7 + __integer] as i32;
+                if __action > 0 {
+                    if let Some(__flag) = __cancel {
+                        if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                            return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                        }
+                    }
+                    let __symbol = match __integer {
+                        0 => match __lookahead.1 {
+                            __tok @ Tok::LParen => __Symbol::Term_22_28_22((__tok)),
+                            _ => unreachable!(),
+                        },
+                        1 => match __lookahead.1 {
+                            __tok @ Tok::RParen => __Symbol::Term_22_29_22((__tok)),
+                            _ => unreachable!(),
+                        },
+                        2 => match __lookahead.1 {
+                            __tok @ Tok::Plus => __Symbol::Term_22_2b_22((__tok)),
+                            _ => unreachable!(),
+                        },
+                        3 => match __lookahead.1 {
+                            __tok @ Tok::Minus => __Symbol::Term_22_2d_22((__tok)),
+                            _ => unreachable!(),
+                        },
+                        4 => match __lookahead.1 {
+                            __tok @ Tok::Div => __Symbol::Term_22_2f_22((__tok)),
+                            _ => unreachable!(),
+                        },
+                        5 => match __lookahead.1 {
+                            Tok::Num(__tok0) => __Symbol::TermNum((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        _ => unreachable!(),
+                    };
+                    __states.push(__action - 1);
+                    __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                    continue '__shift;
+                } else if __action < 0 {
+                    if let Some(__flag) = __cancel {
+                        if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                            return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                        }
+                    }
+                    if let Some(r) = __reduce(errors, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                        if r.is_err() {
+                            return r;
+                        }
+                        return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] });
+                    }
+                } else {
+                    let mut __err_lookahead = Some(__lookahead);
+                    let mut __err_integer: Option<usize> = Some(__integer);
+                    match __error_recovery(errors,  &mut __tokens, &mut __states, &mut __symbols, __last_location, &mut __err_lookahead, &mut __err_integer, __cancel, ::std::marker::PhantomData::<()>) {
+                        Err(__e) => return Err(__e),
+                        Ok(Some(__v)) => return Ok(__v),
+                        Ok(None) => (),
+                    }
+                    match (__err_lookahead, __err_integer) {
+                        (Some(__l), Some(__i)) => {
+                            __lookahead = __l;
+                            __integer = __i;
+                            continue '__inner;
+                        }
+                        _ => break '__shift,
+                    }
+                }
+            }
+        }
+        loop {
+            let __state = *__states.last().unwrap() as usize;
+            let __action = __EOF_ACTION[__state] as i32;
+            if __action < 0 {
+                if let Some(__flag) = __cancel {
+                    if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                        return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                    }
+                }
+                if let Some(r) = __reduce(errors, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                    return r;
+                }
+            } else {
+                let mut __err_lookahead = None;
+                let mut __err_integer: Option<usize> = None;
+                match __error_recovery(errors,  &mut __tokens, &mut __states, &mut __symbols, __last_location, &mut __err_lookahead, &mut __err_integer, __cancel, ::std::marker::PhantomData::<()>) {
+                    Err(__e) => return Err(__e),
+                    Ok(Some(__v)) => return Ok(__v),
+                    Ok(None) => (),
+                }
+            }
+        }
+    }
+    fn __error_recovery<
+        'e,
+        __I,
+    >(
+        errors: &'e mut Vec<(usize, usize)>,
+        __tokens: &mut __I,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        __last_location: &mut usize,
+        __opt_lookahead: &mut Option<(usize, Tok, usize)>,
+        __opt_integer: &mut Option<usize>,
+        __cancel: Option<&::std::sync::atomic::AtomicBool>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<Option<__Symbol<>>, __lalrpop_util::ParseError<usize, Tok, &'static str>> where
+      __I: Iterator<Item = Result<(usize, Tok, usize), &'static str>>,
+    {
+        let __state = *__states.last().unwrap() as usize;
+        let __expected = __expected_tokens(__state);
+        let __error = match __opt_lookahead.clone() {
+            Some(__token) => {
+                if __accepts(errors,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                    __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                } else {
+                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                }
+            }
+            None => {
+                __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+            }
+        };
+        let mut __dropped_tokens = vec![];
+        loop {
+            let __state = *__states.last().unwrap() as usize;
+            let __action = __ACTION[__state * 7 + 6] as i32;
+            if __action >= 0 {
+                break;
+            }
+            if let Some(__flag) = __cancel {
+                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                    return Err(__lalrpop_util::ParseError::Canceled { location: __opt_lookahead.as_ref().map(|l| l.0.clone()).unwrap_or_else(|| __last_location.clone()) });
+                }
+            }
+            let __lookahead_start = __opt_lookahead.as_ref().map(|l| &l.0);
+            if let Some(r) = __reduce( errors,  __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()> ) {
+                return Ok(Some(r?));
+            }
+        }
+        let __states_len = __states.len();
+        let __top0;
+        '__find_state: loop {
+            for __top in (0..__states_len).rev() {
+                let __state = __states[__top];
+                let __action = __ACTION[(__state * 7 + 6) as usize] as i32;
+                if __action <= 0 { continue; }
+                let __error_state = __action - 1;
+                if __accepts(errors,  __error_state, &__states[..__top + 1], *__opt_integer, ::std::marker::PhantomData::<()>,) {
+                    __top0 = __top;
+                    break '__find_state;
+                }
+            }
+            '__eof: loop {
+                match __opt_lookahead.take() {
+                    None => {
+                        return Err(__error)
+                    }
+                    Some(mut __lookahead) => {
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                            }
+                        }
+                        __dropped_tokens.push(__lookahead);
+                        __lookahead = match __tokens.next() {
+                            Some(Ok(v)) => v,
+                            None => break '__eof,
+                            Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                        };
+                        *__last_location = __lookahead.2.clone();
+                        let __integer;
+                        __integer = match __lookahead.1 {
+                            Tok::LParen if true => 0,
+                            Tok::RParen if true => 1,
+                            Tok::Plus if true => 2,
+                            Tok::Minus if true => 3,
+                            Tok::Div if true => 4,
+                            Tok::Num(_) if true => 5,
+                            _ => {
+                                let __state = *__states.last().unwrap() as usize;
+                                let __expected = __expected_tokens(__state);
+                                let __error = match Some(__lookahead) {
+                                    Some(__token) => {
+                                        __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                    }
+                                    None => {
+                                        __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                    }
+                                };
+                                return Err(__error);
+                            }
+                        };
+                        *__opt_lookahead = Some(__lookahead);
+                        *__opt_integer = Some(__integer);
+                        continue '__find_state;
+                    }
+                }
+            }
+            *__opt_lookahead = None;
+            *__opt_integer = None;
+        };
+        let __top = __top0;
+        let __start = if let Some(__popped_sym) = __symbols.get(__top) {
+            __popped_sym.0.clone()
+        } else if let Some(__dropped_token) = __dropped_tokens.first() {
+            __dropped_token.0.clone()
+        } else if __top > 0 {
+            __symbols[__top - 1].2.clone()
+        } else {
+            Default::default()
+        };
+        let __end = if let Some(__dropped_token) = __dropped_tokens.last() {
+            __dropped_token.2.clone()
+        } else if __states_len - 1 > __top {
+            __symbols.last().unwrap().2.clone()
+        } else if let Some(__lookahead) = __opt_lookahead.as_ref() {
+            __lookahead.0.clone()
+        } else {
+            __start.clone()
+        };
+        __states.truncate(__top + 1);
+        __symbols.truncate(__top);
+        let __recover_state = __states[__top];
+        let __error_action = __ACTION[(__recover_state * 7 + 6) as usize] as i32;
+        let __error_state = __error_action - 1;
+        __states.push(__error_state);
+        let __recovery = __lalrpop_util::ErrorRecovery {
+            error: __error,
+            dropped_tokens: __dropped_tokens,
+            state: __recover_state as usize,
+        };
+        __symbols.push((__start, __Symbol::Termerror(__recovery), __end));
+        Ok(None)
+    }
+    fn __accepts<
+        'e,
+    >(
+        errors: &'e mut Vec<(usize, usize)>,
+        __error_state: i32,
+        __states: & [i32],
+        __opt_integer: Option<usize>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> bool
+    {
+        let mut __states = __states.to_vec();
+        __states.push(__error_state);
+        loop {
+            let mut __states_len = __states.len();
+            let __top = __states[__states_len - 1];
+            let __action = match __opt_integer {
+                None => __EOF_ACTION[__top as usize] as i32,
+                Some(__integer) => __ACTION[(__top * 7) as usize + __integer] as i32,
+            };
+            if __action == 0 { return false; }
+            if __action > 0 { return true; }
+            let (__to_pop, __nt) = match -__action {
+                // simulate @L =  => ActionFn(11);
+                1 => {
+                    (0, 0)
+                }
+                // simulate @R =  => ActionFn(10);
+                2 => {
+                    (0, 1)
+                }
+                // simulate Expr = Expr1 => ActionFn(1);
+                3 => {
+                    (1, 2)
+                }
+                // simulate Expr = Expr, "+", Expr1 => ActionFn(2);
+                4 => {
+                    (3, 2)
+                }
+                // simulate Expr = Expr, "-", Expr1 => ActionFn(3);
+                5 => {
+                    (3, 2)
+                }
+                // simulate Expr = Expr, "-", error => ActionFn(15);
+                6 => {
+                    (3, 2)
+                }
+                // simulate Expr = Expr, error => ActionFn(16);
+                7 => {
+                    (2, 2)
+                }
+                // simulate Expr = error => ActionFn(17);
+                8 => {
+                    (1, 2)
+                }
+                // simulate Expr1 = Expr2 => ActionFn(7);
+                9 => {
+                    (1, 3)
+                }
+                // simulate Expr2 = Num => ActionFn(8);
+                10 => {
+                    (1, 4)
+                }
+                // simulate Expr2 = "(", Expr, ")" => ActionFn(9);
+                11 => {
+                    (3, 4)
+                }
+                // simulate __Expr = Expr => ActionFn(0);
+                12 => return true,
+                _ => panic!("invalid action code {}", __action)
+            };
+            __states_len -= __to_pop;
+            __states.truncate(__states_len);
+            let __top = __states[__states_len - 1];
+            let __next_state = __GOTO[(__top * 6 + __nt) as usize] as i32 - 1;
+            __states.push(__next_state);
+        }
+    }
+    pub fn __reduce<
+        'e,
+    >(
+        errors: &'e mut Vec<(usize, usize)>,
+        __action: i32,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Option<Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+    {
+        let __nonterminal = match -__action {
+            1 => match __reduce__40L_0(errors, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            2 => match __reduce__40R_0(errors, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            3 => match __reduce_Expr_0(errors, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            4 => match __reduce_Expr_1(errors, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            5 => match __reduce_Expr_2(errors, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            6 => match __reduce_Expr_3(errors, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            7 => match __reduce_Expr_4(errors, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            8 => match __reduce_Expr_5(errors, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            9 => match __reduce_Expr1_0(errors, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            10 => match __reduce_Expr2_0(errors, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            11 => match __reduce_Expr2_1(errors, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            12 => match __reduce_____Expr_0(errors, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            _ => panic!("invalid action code {}", __action)
+        };
+        let __state = *__states.last().unwrap() as usize;
+        let __next_state = __GOTO[__state * 6 + __nonterminal] as i32 - 1;
+        __states.push(__next_state);
+        None
+    }
+
+    fn __reduce__40L_0<
+        'e,
+    >(
+        errors: &'e mut Vec<(usize, usize)>,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+    {
+        // @L =  => ActionFn(11);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action11::<>(errors, &__start, &__end);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 0);
+        __symbols.push((__start, __Symbol::Nt_40L(__nt), __end));
+        Ok(0)
+    }
+
+    fn __reduce__40R_0<
+        'e,
+    >(
+        errors: &'e mut Vec<(usize, usize)>,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+    {
+        // @R =  => ActionFn(10);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action10::<>(errors, &__start, &__end);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 0);
+        __symbols.push((__start, __Symbol::Nt_40R(__nt), __end));
+        Ok(1)
+    }
+
+    fn __reduce_Expr_0<
+        'e,
+    >(
+        errors: &'e mut Vec<(usize, usize)>,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+    {
+        // Expr = Expr1 => ActionFn(1);
+        let __sym0 = __pop_NtExpr1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action1::<>(errors, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+        Ok(2)
+    }
+
+    fn __reduce_Expr_1<
+        'e,
+    >(
+        errors: &'e mut Vec<(usize, usize)>,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+    {
+        // Expr = Expr, "+", Expr1 => ActionFn(2);
+        let __sym2 = __pop_NtExpr1(__symbols);
+        let __sym1 = __pop_Term_22_2b_22(__symbols);
+        let __sym0 = __pop_NtExpr(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action2::<>(errors, __sym0, __sym1, __sym2);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 3);
+        __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+        Ok(2)
+    }
+
+    fn __reduce_Expr_2<
+        'e,
+    >(
+        errors: &'e mut Vec<(usize, usize)>,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+    {
+        // Expr = Expr, "-", Expr1 => ActionFn(3);
+        let __sym2 = __pop_NtExpr1(__symbols);
+        let __sym1 = __pop_Term_22_2d_22(__symbols);
+        let __sym0 = __pop_NtExpr(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action3::<>(errors, __sym0, __sym1, __sym2);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 3);
+        __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+        Ok(2)
+    }
+
+    fn __reduce_Expr_3<
+        'e,
+    >(
+        errors: &'e mut Vec<(usize, usize)>,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+    {
+        // Expr = Expr, "-", error => ActionFn(15);
+        let __sym2 = __pop_Termerror(__symbols);
+        let __sym1 = __pop_Term_22_2d_22(__symbols);
+        let __sym0 = __pop_NtExpr(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action15::<>(errors, __sym0, __sym1, __sym2);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 3);
+        __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+        Ok(2)
+    }
+
+    fn __reduce_Expr_4<
+        'e,
+    >(
+        errors: &'e mut Vec<(usize, usize)>,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+    {
+        // Expr = Expr, error => ActionFn(16);
+        let __sym1 = __pop_Termerror(__symbols);
+        let __sym0 = __pop_NtExpr(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action16::<>(errors, __sym0, __sym1);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 2);
+        __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+        Ok(2)
+    }
+
+    fn __reduce_Expr_5<
+        'e,
+    >(
+        errors: &'e mut Vec<(usize, usize)>,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+    {
+        // Expr = error => ActionFn(17);
+        let __sym0 = __pop_Termerror(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action17::<>(errors, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+        Ok(2)
+    }
+
+    fn __reduce_Expr1_0<
+        'e,
+    >(
+        errors: &'e mut Vec<(usize, usize)>,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+    {
+        // Expr1 = Expr2 => ActionFn(7);
+        let __sym0 = __pop_NtExpr2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action7::<>(errors, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtExpr1(__nt), __end));
+        Ok(3)
+    }
+
+    fn __reduce_Expr2_0<
+        'e,
+    >(
+        errors: &'e mut Vec<(usize, usize)>,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+    {
+        // Expr2 = Num => ActionFn(8);
+        let __sym0 = __pop_TermNum(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action8::<>(errors, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtExpr2(__nt), __end));
+        Ok(4)
+    }
+
+    fn __reduce_Expr2_1<
+        'e,
+    >(
+        errors: &'e mut Vec<(usize, usize)>,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+    {
+        // Expr2 = "(", Expr, ")" => ActionFn(9);
+        let __sym2 = __pop_Term_22_29_22(__symbols);
+        let __sym1 = __pop_NtExpr(__symbols);
+        let __sym0 = __pop_Term_22_28_22(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action9::<>(errors, __sym0, __sym1, __sym2);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 3);
+        __symbols.push((__start, __Symbol::NtExpr2(__nt), __end));
+        Ok(4)
+    }
+
+    fn __reduce_____Expr_0<
+        'e,
+    >(
+        errors: &'e mut Vec<(usize, usize)>,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+    {
+        // __Expr = Expr => ActionFn(0);
+        let __sym0 = __pop_NtExpr(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action0::<>(errors, __sym0);
+        Err(Ok(__Symbol::Nt____Expr(__nt)))
+    }
+    fn __pop_Term_22_28_22<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Tok, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_28_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_29_22<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Tok, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_29_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2b_22<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Tok, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2b_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2d_22<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Tok, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2f_22<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Tok, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2f_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_TermNum<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, i32, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::TermNum(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termerror<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, __lalrpop_util::ErrorRecovery<usize, Tok, &'static str>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termerror(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_40L<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, usize, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_40L(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_40R<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, usize, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_40R(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtExpr<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, String, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtExpr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtExpr1<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, String, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtExpr1(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtExpr2<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, String, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtExpr2(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____Expr<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, String, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Expr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action0<
+    'e,
+>(
+    errors: &'e mut Vec<(usize, usize)>,
+    (_, __0, _): (usize, String, usize),
+) -> String
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'e,
+>(
+    errors: &'e mut Vec<(usize, usize)>,
+    (_, __0, _): (usize, String, usize),
+) -> String
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'e,
+>(
+    errors: &'e mut Vec<(usize, usize)>,
+    (_, __0, _): (usize, String, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, __1, _): (usize, String, usize),
+) -> String
+{
+    //@ src/error_recovery_span.lalrpop:19:27 (1 lines)
+    format!("{} + {}", __0, __1)
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'e,
+>(
+    errors: &'e mut Vec<(usize, usize)>,
+    (_, __0, _): (usize, String, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, __1, _): (usize, String, usize),
+) -> String
+{
+    //@ src/error_recovery_span.lalrpop:20:27 (1 lines)
+    format!("{} - {}", __0, __1)
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'e,
+>(
+    errors: &'e mut Vec<(usize, usize)>,
+    (_, n, _): (usize, String, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, __lalrpop_util::ErrorRecovery<usize, Tok, &'static str>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> String
+{
+    //@ src/error_recovery_span.lalrpop:25:37 (4 lines)
+    {
+        errors.push((l, r));
+        format!("{} -!", n)
+    }
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'e,
+>(
+    errors: &'e mut Vec<(usize, usize)>,
+    (_, n, _): (usize, String, usize),
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, __lalrpop_util::ErrorRecovery<usize, Tok, &'static str>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> String
+{
+    //@ src/error_recovery_span.lalrpop:30:33 (4 lines)
+    {
+        errors.push((l, r));
+        n
+    }
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'e,
+>(
+    errors: &'e mut Vec<(usize, usize)>,
+    (_, __0, _): (usize, usize, usize),
+    (_, _, _): (usize, __lalrpop_util::ErrorRecovery<usize, Tok, &'static str>, usize),
+    (_, __1, _): (usize, usize, usize),
+) -> String
+{
+    //@ src/error_recovery_span.lalrpop:35:20 (4 lines)
+    {
+        errors.push((__0, __1));
+        format!("!")
+    }
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'e,
+>(
+    errors: &'e mut Vec<(usize, usize)>,
+    (_, __0, _): (usize, String, usize),
+) -> String
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'e,
+>(
+    errors: &'e mut Vec<(usize, usize)>,
+    (_, __0, _): (usize, i32, usize),
+) -> String
+{
+    //@ src/error_recovery_span.lalrpop:46:12 (1 lines)
+    format!("{:?}", __0)
+}
+
+#[allow(unused_variables)]
+fn __action9<
+    'e,
+>(
+    errors: &'e mut Vec<(usize, usize)>,
+    (_, _, _): (usize, Tok, usize),
+    (_, __0, _): (usize, String, usize),
+    (_, _, _): (usize, Tok, usize),
+) -> String
+{
+    //@ src/error_recovery_span.lalrpop:47:23 (1 lines)
+    format!("({})", __0)
+}
+
+#[allow(unused_variables)]
+fn __action10<
+    'e,
+>(
+    errors: &'e mut Vec<(usize, usize)>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
+{
+    __lookbehind.clone()
+}
+
+#[allow(unused_variables)]
+fn __action11<
+    'e,
+>(
+    errors: &'e mut Vec<(usize, usize)>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
+{
+    __lookahead.clone()
+}
+
+#[allow(unused_variables)]
+fn __action12<
+    'e,
+>(
+    errors: &'e mut Vec<(usize, usize)>,
+    __0: (usize, String, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, __lalrpop_util::ErrorRecovery<usize, Tok, &'static str>, usize),
+    __3: (usize, usize, usize),
+) -> String
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action11(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action4(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action13<
+    'e,
+>(
+    errors: &'e mut Vec<(usize, usize)>,
+    __0: (usize, String, usize),
+    __1: (usize, __lalrpop_util::ErrorRecovery<usize, Tok, &'static str>, usize),
+    __2: (usize, usize, usize),
+) -> String
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action11(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action5(
+        errors,
+        __0,
+        __temp0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action14<
+    'e,
+>(
+    errors: &'e mut Vec<(usize, usize)>,
+    __0: (usize, __lalrpop_util::ErrorRecovery<usize, Tok, &'static str>, usize),
+    __1: (usize, usize, usize),
+) -> String
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action11(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action6(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action15<
+    'e,
+>(
+    errors: &'e mut Vec<(usize, usize)>,
+    __0: (usize, String, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, __lalrpop_util::ErrorRecovery<usize, Tok, &'static str>, usize),
+) -> String
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action10(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action12(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action16<
+    'e,
+>(
+    errors: &'e mut Vec<(usize, usize)>,
+    __0: (usize, String, usize),
+    __1: (usize, __lalrpop_util::ErrorRecovery<usize, Tok, &'static str>, usize),
+) -> String
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action10(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action13(
+        errors,
+        __0,
+        __1,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action17<
+    'e,
+>(
+    errors: &'e mut Vec<(usize, usize)>,
+    __0: (usize, __lalrpop_util::ErrorRecovery<usize, Tok, &'static str>, usize),
+) -> String
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action10(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action14(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+pub trait __ToTriple<'e, > {
+    type Error;
+    fn to_triple(value: Self) -> Result<(usize,Tok,usize),Self::Error>;
+}
+
+impl<'e, > __ToTriple<'e, > for (usize, Tok, usize) {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Tok,usize),&'static str> {
+        Ok(value)
+    }
+}
+impl<'e, > __ToTriple<'e, > for Result<(usize, Tok, usize),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Tok,usize),&'static str> {
+        value
+    }
+}
@@ -0,0 +1,1661 @@
+// auto-generated: "lalrpop 0.15.0"
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+pub use self::__parse_table::parse_Stmts;
+pub use self::__parse_table::parse_Stmts_cancelable;
+pub use self::__parse_table::parse_Stmts_repaired;
+pub use self::__parse_table::parse_Stmts_repaired_cancelable;
+pub use self::__parse_table::StmtsParser;
+
+mod __parse_table {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__intern_token::Token;
+    #[allow(dead_code)]
+    pub enum __Symbol<'input>
+     {
+        Term_22_28_22(&'input str),
+        Term_22_29_22(&'input str),
+        Term_22_2b_22(&'input str),
+        Term_22_3b_22(&'input str),
+        Termr_23_22_5b0_2d9_5d_2b_22_23(&'input str),
+        NtExpr(i32),
+        NtNum(i32),
+        NtStmt(i32),
+        NtStmt_2a(::std::vec::Vec<i32>),
+        NtStmt_2b(::std::vec::Vec<i32>),
+        NtStmts(Vec<i32>),
+        NtTerm(i32),
+        Nt____Stmts(Vec<i32>),
+    }
+    impl<'input> ::std::fmt::Debug for __Symbol<'input>
+    {
+        fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            match *self {
+                __Symbol::Term_22_28_22(..) => __f.write_str("\"(\""),
+                __Symbol::Term_22_29_22(..) => __f.write_str("\")\""),
+                __Symbol::Term_22_2b_22(..) => __f.write_str("\"+\""),
+                __Symbol::Term_22_3b_22(..) => __f.write_str("\";\""),
+                __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(..) => __f.write_str("r#\"[0-9]+\"#"),
+                __Symbol::NtExpr(..) => __f.write_str("Expr"),
+                __Symbol::NtNum(..) => __f.write_str("Num"),
+                __Symbol::NtStmt(..) => __f.write_str("Stmt"),
+                __Symbol::NtStmt_2a(..) => __f.write_str("Stmt*"),
+                __Symbol::NtStmt_2b(..) => __f.write_str("Stmt+"),
+                __Symbol::NtStmts(..) => __f.write_str("Stmts"),
+                __Symbol::NtTerm(..) => __f.write_str("Term"),
+                __Symbol::Nt____Stmts(..) => __f.write_str("__Stmts"),
+            }
+        }
+    }
+    const __ACTION: &'static [i8] = &[
+        // State 0
+        //     Expr = (*) Expr "+" Term ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        //     Expr = (*) Term ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        //     Num = (*) r#"[0-9]+"# ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        //     Stmt = (*) Expr ";" ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        //     Stmt+ = (*) Stmt ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        //     Stmt+ = (*) Stmt+ Stmt ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        //     Stmts = (*) ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        //     Stmts = (*) Stmt+ ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        //     Term = (*) Num ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        //     Term = (*) "(" Expr ")" ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        //     __Stmts = (*) Stmts ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        8,  // on "(", goto 7
+        0,  // on ")", error
+        0,  // on "+", error
+        0,  // on ";", error
+        9,  // on r#"[0-9]+"#, goto 8
+
+        // State 1
+        //     Expr = Expr (*) "+" Term ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        //     Stmt = Expr (*) ";" ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        0,  // on "(", error
+        0,  // on ")", error
+        10,  // on "+", goto 9
+        11,  // on ";", goto 10
+        0,  // on r#"[0-9]+"#, error
+
+        // State 2
+        //     Term = Num (*) ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        0,  // on "(", error
+        -11,  // on ")", reduce `Term = Num => ActionFn(5);`
+        -11,  // on "+", reduce `Term = Num => ActionFn(5);`
+        -11,  // on ";", reduce `Term = Num => ActionFn(5);`
+        0,  // on r#"[0-9]+"#, error
+
+        // State 3
+        //     Stmt+ = Stmt (*) ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        -7,  // on "(", reduce `Stmt+ = Stmt => ActionFn(10);`
+        0,  // on ")", error
+        0,  // on "+", error
+        0,  // on ";", error
+        -7,  // on r#"[0-9]+"#, reduce `Stmt+ = Stmt => ActionFn(10);`
+
+        // State 4
+        //     Expr = (*) Expr "+" Term ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        //     Expr = (*) Term ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        //     Num = (*) r#"[0-9]+"# ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        //     Stmt = (*) Expr ";" ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        //     Stmt+ = Stmt+ (*) Stmt ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        //     Stmts = Stmt+ (*) ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        //     Term = (*) Num ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        //     Term = (*) "(" Expr ")" ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        8,  // on "(", goto 7
+        0,  // on ")", error
+        0,  // on "+", error
+        0,  // on ";", error
+        9,  // on r#"[0-9]+"#, goto 8
+
+        // State 5
+        //     __Stmts = Stmts (*) ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        0,  // on "(", error
+        0,  // on ")", error
+        0,  // on "+", error
+        0,  // on ";", error
+        0,  // on r#"[0-9]+"#, error
+
+        // State 6
+        //     Expr = Term (*) ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        0,  // on "(", error
+        -2,  // on ")", reduce `Expr = Term => ActionFn(4);`
+        -2,  // on "+", reduce `Expr = Term => ActionFn(4);`
+        -2,  // on ";", reduce `Expr = Term => ActionFn(4);`
+        0,  // on r#"[0-9]+"#, error
+
+        // State 7
+        //     Expr = (*) Expr "+" Term ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        //     Expr = (*) Term ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        //     Num = (*) r#"[0-9]+"# ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        //     Term = (*) Num ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        //     Term = (*) "(" Expr ")" ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        //     Term = "(" (*) Expr ")" ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        8,  // on "(", goto 7
+        0,  // on ")", error
+        0,  // on "+", error
+        0,  // on ";", error
+        9,  // on r#"[0-9]+"#, goto 8
+
+        // State 8
+        //     Num = r#"[0-9]+"# (*) ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        0,  // on "(", error
+        -3,  // on ")", reduce `Num = r#"[0-9]+"# => ActionFn(7);`
+        -3,  // on "+", reduce `Num = r#"[0-9]+"# => ActionFn(7);`
+        -3,  // on ";", reduce `Num = r#"[0-9]+"# => ActionFn(7);`
+        0,  // on r#"[0-9]+"#, error
+
+        // State 9
+        //     Expr = Expr "+" (*) Term ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        //     Num = (*) r#"[0-9]+"# ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        //     Term = (*) Num ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        //     Term = (*) "(" Expr ")" ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        8,  // on "(", goto 7
+        0,  // on ")", error
+        0,  // on "+", error
+        0,  // on ";", error
+        9,  // on r#"[0-9]+"#, goto 8
+
+        // State 10
+        //     Stmt = Expr ";" (*) ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        -4,  // on "(", reduce `Stmt = Expr, ";" => ActionFn(2);`
+        0,  // on ")", error
+        0,  // on "+", error
+        0,  // on ";", error
+        -4,  // on r#"[0-9]+"#, reduce `Stmt = Expr, ";" => ActionFn(2);`
+
+        // State 11
+        //     Stmt+ = Stmt+ Stmt (*) ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        -8,  // on "(", reduce `Stmt+ = Stmt+, Stmt => ActionFn(11);`
+        0,  // on ")", error
+        0,  // on "+", error
+        0,  // on ";", error
+        -8,  // on r#"[0-9]+"#, reduce `Stmt+ = Stmt+, Stmt => ActionFn(11);`
+
+        // State 12
+        //     Expr = Expr (*) "+" Term ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        //     Term = "(" Expr (*) ")" ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        0,  // on "(", error
+        15,  // on ")", goto 14
+        10,  // on "+", goto 9
+        0,  // on ";", error
+        0,  // on r#"[0-9]+"#, error
+
+        // State 13
+        //     Expr = Expr "+" Term (*) ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        0,  // on "(", error
+        -1,  // on ")", reduce `Expr = Expr, "+", Term => ActionFn(3);`
+        -1,  // on "+", reduce `Expr = Expr, "+", Term => ActionFn(3);`
+        -1,  // on ";", reduce `Expr = Expr, "+", Term => ActionFn(3);`
+        0,  // on r#"[0-9]+"#, error
+
+        // State 14
+        //     Term = "(" Expr ")" (*) ["(", ")", "+", ";", r#"[0-9]+"#, EOF]
+        0,  // on "(", error
+        -12,  // on ")", reduce `Term = "(", Expr, ")" => ActionFn(6);`
+        -12,  // on "+", reduce `Term = "(", Expr, ")" => ActionFn(6);`
+        -12,  // on ";", reduce `Term = "(", Expr, ")" => ActionFn(6);`
+        0,  // on r#"[0-9]+"#, error
+
+    ];
+    const __EOF_ACTION: &'static [i8] = &[
+        // State 0
+        -9,  // on EOF, reduce `Stmts =  => ActionFn(12);`
+
+        // State 1
+        0,  // on EOF, error
+
+        // State 2
+        0,  // on EOF, error
+
+        // State 3
+        -7,  // on EOF, reduce `Stmt+ = Stmt => ActionFn(10);`
+
+        // State 4
+        -10,  // on EOF, reduce `Stmts = Stmt+ => ActionFn(13);`
+
+        // State 5
+        -13,  // on EOF, reduce `__Stmts = Stmts => ActionFn(0);`
+
+        // State 6
+        0,  // on EOF, error
+
+        // State 7
+        0,  // on EOF, error
+
+        // State 8
+        0,  // on EOF, error
+
+        // State 9
+        0,  // on EOF, error
+
+        // State 10
+        -4,  // on EOF, reduce `Stmt = Expr, ";" => ActionFn(2);`
+
+        // State 11
+        -8,  // on EOF, reduce `Stmt+ = Stmt+, Stmt => ActionFn(11);`
+
+        // State 12
+        0,  // on EOF, error
+
+        // State 13
+        0,  // on EOF, error
+
+        // State 14
+        0,  // on EOF, error
+
+    ];
+    const __GOTO: &'static [u8] = &[
+        // State 0
+        2,  // on Expr, goto 1
+        3,  // on Num, goto 2
+        4,  // on Stmt, goto 3
+        0,  // on Stmt*, error
+        5,  // on Stmt+, goto 4
+        6,  // on Stmts, goto 5
+        7,  // on Term, goto 6
+        0,  // on __Stmts, error
+
+        // State 1
+        0,  // on Expr, error
+        0,  // on Num, error
+        0,  // on Stmt, error
+        0,  // on Stmt*, error
+        0,  // on Stmt+, error
+        0,  // on Stmts, error
+        0,  // on Term, error
+        0,  // on __Stmts, error
+
+        // State 2
+        0,  // on Expr, error
+        0,  // on Num, error
+        0,  // on Stmt, error
+        0,  // on Stmt*, error
+        0,  // on Stmt+, error
+        0,  // on Stmts, error
+        0,  // on Term, error
+        0,  // on __Stmts, error
+
+        // State 3
+        0,  // on Expr, error
+        0,  // on Num, error
+        0,  // on Stmt, error
+        0,  // on Stmt*, error
+        0,  // on Stmt+, error
+        0,  // on Stmts, error
+        0,  // on Term, error
+        0,  // on __Stmts, error
+
+        // State 4
+        2,  // on Expr, goto 1
+        3,  // on Num, goto 2
+        12,  // on Stmt, goto 11
+        0,  // on Stmt*, error
+        0,  // on Stmt+, error
+        0,  // on Stmts, error
+        7,  // on Term, goto 6
+        0,  // on __Stmts, error
+
+        // State 5
+        0,  // on Expr, error
+        0,  // on Num, error
+        0,  // on Stmt, error
+        0,  // on Stmt*, error
+        0,  // on Stmt+, error
+        0,  // on Stmts, error
+        0,  // on Term, error
+        0,  // on __Stmts, error
+
+        // State 6
+        0,  // on Expr, error
+        0,  // on Num, error
+        0,  // on Stmt, error
+        0,  // on Stmt*, error
+        0,  // on Stmt+, error
+        0,  // on Stmts, error
+        0,  // on Term, error
+        0,  // on __Stmts, error
+
+        // State 7
+        13,  // on Expr, goto 12
+        3,  // on Num, goto 2
+        0,  // on Stmt, error
+        0,  // on Stmt*, error
+        0,  // on Stmt+, error
+        0,  // on Stmts, error
+        7,  // on Term, goto 6
+        0,  // on __Stmts, error
+
+        // State 8
+        0,  // on Expr, error
+        0,  // on Num, error
+        0,  // on Stmt, error
+        0,  // on Stmt*, error
+        0,  // on Stmt+, error
+        0,  // on Stmts, error
+        0,  // on Term, error
+        0,  // on __Stmts, error
+
+        // State 9
+        0,  // on Expr, error
+        3,  // on Num, goto 2
+        0,  // on Stmt, error
+        0,  // on Stmt*, error
+        0,  // on Stmt+, error
+        0,  // on Stmts, error
+        14,  // on Term, goto 13
+        0,  // on __Stmts, error
+
+        // State 10
+        0,  // on Expr, error
+        0,  // on Num, error
+        0,  // on Stmt, error
+        0,  // on Stmt*, error
+        0,  // on Stmt+, error
+        0,  // on Stmts, error
+        0,  // on Term, error
+        0,  // on __Stmts, error
+
+        // State 11
+        0,  // on Expr, error
+        0,  // on Num, error
+        0,  // on Stmt, error
+        0,  // on Stmt*, error
+        0,  // on Stmt+, error
+        0,  // on Stmts, error
+        0,  // on Term, error
+        0,  // on __Stmts, error
+
+        // State 12
+        0,  // on Expr, error
+        0,  // on Num, error
+        0,  // on Stmt, error
+        0,  // on Stmt*, error
+        0,  // on Stmt+, error
+        0,  // on Stmts, error
+        0,  // on Term, error
+        0,  // on __Stmts, error
+
+        // State 13
+        0,  // on Expr, error
+        0,  // on Num, error
+        0,  // on Stmt, error
+        0,  // on Stmt*, error
+        0,  // on Stmt+, error
+        0,  // on Stmts, error
+        0,  // on Term, error
+        0,  // on __Stmts, error
+
+        // State 14
+        0,  // on Expr, error
+        0,  // on Num, error
+        0,  // on Stmt, error
+        0,  // on Stmt*, error
+        0,  // on Stmt+, error
+        0,  // on Stmts, error
+        0,  // on Term, error
+        0,  // on __Stmts, error
+
+    ];
+    fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+        const __TERMINAL: &'static [&'static str] = &[
+            r###""(""###,
+            r###"")""###,
+            r###""+""###,
+            r###"";""###,
+            r###"r#"[0-9]+"#"###,
+        ];
+        __ACTION[(__state * 5)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+            if state == 0 {
+                None
+            } else {
+                Some(terminal.to_string())
+            }
+        }).collect()
+    }
+    #[allow(dead_code)]
+    pub fn parse_Stmts<
+        'input,
+    >(
+        input: &'input str,
+    ) -> Result<Vec<i32>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let mut __tokens = super::__intern_token::__Matcher::new(input);
+        let mut __states = vec![];
+        let mut __symbols = vec![];
+        __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Stmts(__v) => Ok(__v), _ => unreachable!() })
+    }
+    #[allow(dead_code)]
+    pub fn parse_Stmts_cancelable<
+        'input,
+    >(
+        input: &'input str,
+        __cancel: &::std::sync::atomic::AtomicBool,
+    ) -> Result<Vec<i32>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let mut __tokens = super::__intern_token::__Matcher::new(input);
+        let mut __states = vec![];
+        let mut __symbols = vec![];
+        __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Stmts(__v) => Ok(__v), _ => unreachable!() })
+    }
+    #[allow(dead_code)]
+    pub fn parse_Stmts_repaired<
+        'input,
+    >(
+        input: &'input str,
+    ) -> (Option<Vec<i32>>, ::std::vec::Vec<__lalrpop_util::repair::Repaired<usize, Token<'input>, &'static str>>)
+    {
+        let mut __tokens = super::__intern_token::__Matcher::new(input);
+        let mut __triples = vec![];
+        let mut __integers = vec![];
+        let mut __errors = vec![];
+        for __token in __tokens {
+            let __lookahead = match __token {
+                Ok(v) => v,
+                Err(e) => {
+                    __errors.push(__lalrpop_util::repair::Repaired { error: e, repairs: vec![] });
+                    return (None, __errors);
+                }
+            };
+            let __integer = match __lookahead.1 {
+                Token(1, _) if true => 0,
+                Token(2, _) if true => 1,
+                Token(3, _) if true => 2,
+                Token(4, _) if true => 3,
+                Token(0, _) if true => 4,
+                _ => {
+                    __errors.push(__lalrpop_util::repair::Repaired { error: __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__lookahead), expected: vec![] }, repairs: vec![] });
+                    return (None, __errors);
+                }
+            };
+            __triples.push(__lookahead);
+            __integers.push(__integer);
+        }
+        loop {
+            let __consumed = ::std::cell::Cell::new(0);
+            let mut __states = vec![];
+            let mut __symbols = vec![];
+            let __result = {
+                let mut __tokens = __triples.iter().cloned().inspect(|_| __consumed.set(__consumed.get() + 1)).map(Ok::<_, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>);
+                __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Stmts(__v) => Ok(__v), _ => unreachable!() })
+            };
+            let __error = match __result {
+                Ok(v) => return (Some(v), __errors),
+                Err(e) => e,
+            };
+            let __index = match __error {
+                __lalrpop_util::ParseError::UnrecognizedToken { token: Some(_), .. } | __lalrpop_util::ParseError::ExtraToken { .. } => __consumed.get() - 1,
+                __lalrpop_util::ParseError::UnrecognizedToken { token: None, .. } | __lalrpop_util::ParseError::UnrecognizedEof { .. } => __triples.len(),
+                _ => {
+                    __errors.push(__lalrpop_util::repair::Repaired { error: __error, repairs: vec![] });
+                    return (None, __errors);
+                }
+            };
+            let __steps = __lalrpop_util::repair::repairs(&__Automaton, &__states, &__integers[__index..], Default::default());
+            let __repairs = __steps.iter().map(|__s| __lalrpop_util::repair::report(__s, __TERMINALS, &__triples[__index..])).collect();
+            let __applied = match __steps.first() {
+                Some(__s) => __lalrpop_util::repair::apply(__s, &mut __triples, &mut __integers, __index, __insert_token),
+                None => false,
+            };
+            __errors.push(__lalrpop_util::repair::Repaired { error: __error, repairs: __repairs });
+            if !__applied {
+                return (None, __errors);
+            }
+        }
+    }
+    #[allow(dead_code)]
+    pub fn parse_Stmts_repaired_cancelable<
+        'input,
+    >(
+        input: &'input str,
+        __cancel: &::std::sync::atomic::AtomicBool,
+    ) -> (Option<Vec<i32>>, ::std::vec::Vec<__lalrpop_util::repair::Repaired<usize, Token<'input>, &'static str>>)
+    {
+        let mut __tokens = super::__intern_token::__Matcher::new(input);
+        let mut __triples = vec![];
+        let mut __integers = vec![];
+        let mut __errors = vec![];
+        for __token in __tokens {
+            let __lookahead = match __token {
+                Ok(v) => v,
+                Err(e) => {
+                    __errors.push(__lalrpop_util::repair::Repaired { error: e, repairs: vec![] });
+                    return (None, __errors);
+                }
+            };
+            let __integer = match __lookahead.1 {
+                Token(1, _) if true => 0,
+                Token(2, _) if true => 1,
+                Token(3, _) if true => 2,
+                Token(4, _) if true => 3,
+                Token(0, _) if true => 4,
+                _ => {
+                    __errors.push(__lalrpop_util::repair::Repaired { error: __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__lookahead), expected: vec![] }, repairs: vec![] });
+                    return (None, __errors);
+                }
+            };
+            __triples.push(__lookahead);
+            __integers.push(__integer);
+        }
+        loop {
+            let __consumed = ::std::cell::Cell::new(0);
+            let mut __states = vec![];
+            let mut __symbols = vec![];
+            let __result = {
+                let mut __tokens = __triples.iter().cloned().inspect(|_| __consumed.set(__consumed.get() + 1)).map(Ok::<_, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>);
+                __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Stmts(__v) => Ok(__v), _ => unreachable!() })
+            };
+            let __error = match __result {
+                Ok(v) => return (Some(v), __errors),
+                Err(e) => e,
+            };
+            let __index = match __error {
+                __lalrpop_util::ParseError::UnrecognizedToken { token: Some(_), .. } | __lalrpop_util::ParseError::ExtraToken { .. } => __consumed.get() - 1,
+                __lalrpop_util::ParseError::UnrecognizedToken { token: None, .. } | __lalrpop_util::ParseError::UnrecognizedEof { .. } => __triples.len(),
+                _ => {
+                    __errors.push(__lalrpop_util::repair::Repaired { error: __error, repairs: vec![] });
+                    return (None, __errors);
+                }
+            };
+            let __steps = __lalrpop_util::repair::repairs(&__Automaton, &__states, &__integers[__index..], Default::default());
+            let __repairs = __steps.iter().map(|__s| __lalrpop_util::repair::report(__s, __TERMINALS, &__triples[__index..])).collect();
+            let __applied = match __steps.first() {
+                Some(__s) => __lalrpop_util::repair::apply(__s, &mut __triples, &mut __integers, __index, __insert_token),
+                None => false,
+            };
+            __errors.push(__lalrpop_util::repair::Repaired { error: __error, repairs: __repairs });
+            if !__applied {
+                return (None, __errors);
+            }
+        }
+    }
+
+    #[allow(dead_code)]
+    pub struct StmtsParser {
+        __states: ::std::vec::Vec<i32>,
+        __symbols: __lalrpop_util::stack::Recycled,
+        __cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+    }
+
+    #[allow(dead_code)]
+    impl StmtsParser {
+        pub fn new() -> StmtsParser {
+            StmtsParser { __states: vec![], __symbols: __lalrpop_util::stack::Recycled::new(), __cancel: None }
+        }
+
+        pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+            self.__cancel = Some(flag);
+        }
+
+        pub fn parse_in_place<'input>(
+            &mut self,
+            input: &'input str,
+        ) -> Result<Vec<i32>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+        {
+            let mut __tokens = super::__intern_token::__Matcher::new(input);
+            let mut __symbols = self.__symbols.take();
+            let __result = __parse::<>(input, 0, &mut __tokens, &mut self.__states, &mut __symbols, self.__cancel.as_ref().map(|c| &**c)).and_then(|__v| match __v { __Symbol::Nt____Stmts(__v) => Ok(__v), _ => unreachable!() });
+            self.__symbols.put(__symbols);
+            __result
+        }
+    }
+
+    fn __parse<
+        'input,
+    >(
+        input: &'input str,
+        __start_state: i32,
+        mut __tokens: &mut Iterator<Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+        mut __states: &mut ::std::vec::Vec<i32>,
+        mut __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        __cancel: Option<&::std::sync::atomic::AtomicBool>,
+    ) -> Result<__Symbol<'input>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        __states.clear();
+        __states.push(__start_state);
+        __symbols.clear();
+        let mut __integer;
+        let mut __lookahead;
+        let __last_location: &mut usize = &mut Default::default();
+        '__shift: loop {
+            __lookahead = match __tokens.next() {
+                Some(Ok(v)) => v,
+                None => break '__shift,
+                Some(Err(e)) => return Err(e),
+            };
+            *__last_location = __lookahead.2.clone();
+            __integer = match __lookahead.1 {
+                Token(1, _) if true => 0,
+                Token(2, _) if true => 1,
+                Token(3, _) if true => 2,
+                Token(4, _) if true => 3,
+                Token(0, _) if true => 4,
+                _ => {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __expected = __expected_tokens(__state);
+                    let __error = match Some(__lookahead) {
+                        Some(__token) => {
+                            __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                        }
+                        None => {
+                            __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                        }
+                    };
+                    return Err(__error);
+                }
+            };
+            '__inner: loop {
+                let __state = *__states.last().unwrap() as usize;
+                let __action = __ACTION[__state * 5 + __integer] as i32;
+                if __action > 0 {
+                    if let Some(__flag) = __cancel {
+                        if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                            return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                        }
+                    }
+                    let __symbol = match __integer {
+                        0 => match __lookahead.1 {
+                            Token(1, __tok0) => __Symbol::Term_22_28_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        1 => match __lookahead.1 {
+                            Token(2, __tok0) => __Symbol::Term_22_29_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        2 => match __lookahead.1 {
+                            Token(3, __tok0) => __Symbol::Term_22_2b_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        3 => match __lookahead.1 {
+                            Token(4, __tok0) => __Symbol::Term_22_3b_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        4 => match __lookahead.1 {
+                            Token(0, __tok0) => __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        _ => unreachable!(),
+                    };
+                    __states.push(__action - 1);
+                    __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                    continue '__shift;
+                } else if __action < 0 {
+                    if let Some(__flag) = __cancel {
+                        if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                            return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                        }
+                    }
+                    if let Some(r) = __reduce(input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                        if r.is_err() {
+                            return r;
+                        }
+                        return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] });
+                    }
+                } else {
+                    let mut __err_lookahead = Some(__lookahead);
+                    let mut __err_integer: Option<usize> = Some(__integer);
+                    let __state = *__states.last().unwrap() as usize;
+                    let __expected = __expected_tokens(__state);
+                    let __error = match __err_lookahead {
+                        Some(__token) => {
+                            if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                            } else {
+                                __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                            }
+                        }
+                        None => {
+                            __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                        }
+                    };
+                    return Err(__error)
+                }
+            }
+        }
+        loop {
+            let __state = *__states.last().unwrap() as usize;
+            let __action = __EOF_ACTION[__state] as i32;
+            if __action < 0 {
+                if let Some(__flag) = __cancel {
+                    if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                        return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                    }
+                }
+                if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                    return r;
+                }
+            } else {
+                let mut __err_lookahead = None;
+                let mut __err_integer: Option<usize> = None;
+                let __state = *__states.last().unwrap() as usize;
+                let __expected = __expected_tokens(__state);
+                let __error = match __err_lookahead {
+                    Some(__token) => {
+                        if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                            __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                        } else {
+                            __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                        }
+                    }
+                    None => {
+                        __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                    }
+                };
+                return Err(__error)
+            }
+        }
+    }
+    const __TERMINALS: &'static [&'static str] = &[
+        r###""(""###,
+        r###"")""###,
+        r###""+""###,
+        r###"";""###,
+        r###"r#"[0-9]+"#"###,
+    ];
+    struct __Automaton;
+    impl __lalrpop_util::repair::Automaton for __Automaton {
+        fn terminals(&self) -> usize { 5 }
+        fn action(&self, __state: i32, __terminal: Option<usize>) -> i32 {
+            match __terminal {
+                Some(__t) => __ACTION[__state as usize * 5 + __t] as i32,
+                None => __EOF_ACTION[__state as usize] as i32,
+            }
+        }
+        fn production(&self, __production: usize) -> Option<(usize, usize)> {
+            match __production {
+                0 => Some((3, 0)),
+                1 => Some((1, 0)),
+                2 => Some((1, 1)),
+                3 => Some((2, 2)),
+                4 => Some((0, 3)),
+                5 => Some((1, 3)),
+                6 => Some((1, 4)),
+                7 => Some((2, 4)),
+                8 => Some((0, 5)),
+                9 => Some((1, 5)),
+                10 => Some((1, 6)),
+                11 => Some((3, 6)),
+                12 => None,
+                _ => panic!("invalid production {}", __production),
+            }
+        }
+        fn goto(&self, __state: i32, __nonterminal: usize) -> i32 {
+            __GOTO[__state as usize * 8 + __nonterminal] as i32 - 1
+        }
+        fn insertable(&self, __terminal: usize) -> bool {
+            __insert_token(__terminal).is_some()
+        }
+    }
+    fn __insert_token<'input>(__terminal: usize) -> Option<Token<'input>> {
+        match __terminal {
+            0 => Some(Token(1, "(")),
+            1 => Some(Token(2, ")")),
+            2 => Some(Token(3, "+")),
+            3 => Some(Token(4, ";")),
+            _ => None,
+        }
+    }
+    fn __accepts<
+        'input,
+    >(
+        input: &'input str,
+        __error_state: i32,
+        __states: & [i32],
+        __opt_integer: Option<usize>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> bool
+    {
+        let mut __states = __states.to_vec();
+        __states.push(__error_state);
+        loop {
+            let mut __states_len = __states.len();
+            let __top = __states[__states_len - 1];
+            let __action = match __opt_integer {
+                None => __EOF_ACTION[__top as usize] as i32,
+                Some(__integer) => __ACTION[(__top * 5) as usize + __integer] as i32,
+            };
+            if __action == 0 { return false; }
+            if __action > 0 { return true; }
+            let (__to_pop, __nt) = match -__action {
+                // simulate Expr = Expr, "+", Term => ActionFn(3);
+                1 => {
+                    (3, 0)
+                }
+                // simulate Expr = Term => ActionFn(4);
+                2 => {
+                    (1, 0)
+                }
+                // simulate Num = r#"[0-9]+"# => ActionFn(7);
+                3 => {
+                    (1, 1)
+                }
+                // simulate Stmt = Expr, ";" => ActionFn(2);
+                4 => {
+                    (2, 2)
+                }
+                // simulate Stmt* =  => ActionFn(8);
+                5 => {
+                    (0, 3)
+                }
+                // simulate Stmt* = Stmt+ => ActionFn(9);
+                6 => {
+                    (1, 3)
+                }
+                // simulate Stmt+ = Stmt => ActionFn(10);
+                7 => {
+                    (1, 4)
+                }
+                // simulate Stmt+ = Stmt+, Stmt => ActionFn(11);
+                8 => {
+                    (2, 4)
+                }
+                // simulate Stmts =  => ActionFn(12);
+                9 => {
+                    (0, 5)
+                }
+                // simulate Stmts = Stmt+ => ActionFn(13);
+                10 => {
+                    (1, 5)
+                }
+                // simulate Term = Num => ActionFn(5);
+                11 => {
+                    (1, 6)
+                }
+                // simulate Term = "(", Expr, ")" => ActionFn(6);
+                12 => {
+                    (3, 6)
+                }
+                // simulate __Stmts = Stmts => ActionFn(0);
+                13 => return true,
+                _ => panic!("invalid action code {}", __action)
+            };
+            __states_len -= __to_pop;
+            __states.truncate(__states_len);
+            let __top = __states[__states_len - 1];
+            let __next_state = __GOTO[(__top * 8 + __nt) as usize] as i32 - 1;
+            __states.push(__next_state);
+        }
+    }
+    pub fn __reduce<
+        'input,
+    >(
+        input: &'input str,
+        __action: i32,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Option<Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        let __nonterminal = match -__action {
+            1 => match __reduce_Expr_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            2 => match __reduce_Expr_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            3 => match __reduce_Num_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            4 => match __reduce_Stmt_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            5 => match __reduce_Stmt_2a_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            6 => match __reduce_Stmt_2a_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            7 => match __reduce_Stmt_2b_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            8 => match __reduce_Stmt_2b_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            9 => match __reduce_Stmts_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            10 => match __reduce_Stmts_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            11 => match __reduce_Term_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            12 => match __reduce_Term_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            13 => match __reduce_____Stmts_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            _ => panic!("invalid action code {}", __action)
+        };
+        let __state = *__states.last().unwrap() as usize;
+        let __next_state = __GOTO[__state * 8 + __nonterminal] as i32 - 1;
+        __states.push(__next_state);
+        None
+    }
+
+    fn __reduce_Expr_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Expr = Expr, "+", Term => ActionFn(3);
+        let __sym2 = __pop_NtTerm(__symbols);
+        let __sym1 = __pop_Term_22_2b_22(__symbols);
+        let __sym0 = __pop_NtExpr(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action3::<>(input, __sym0, __sym1, __sym2);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 3);
+        __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+        Ok(0)
+    }
+
+    fn __reduce_Expr_1<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Expr = Term => ActionFn(4);
+        let __sym0 = __pop_NtTerm(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action4::<>(input, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+        Ok(0)
+    }
+
+    fn __reduce_Num_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Num = r#"[0-9]+"# => ActionFn(7);
+        let __sym0 = __pop_Termr_23_22_5b0_2d9_5d_2b_22_23(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action7::<>(input, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtNum(__nt), __end));
+        Ok(1)
+    }
+
+    fn __reduce_Stmt_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Stmt = Expr, ";" => ActionFn(2);
+        let __sym1 = __pop_Term_22_3b_22(__symbols);
+        let __sym0 = __pop_NtExpr(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action2::<>(input, __sym0, __sym1);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 2);
+        __symbols.push((__start, __Symbol::NtStmt(__nt), __end));
+        Ok(2)
+    }
+
+    fn __reduce_Stmt_2a_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Stmt* =  => ActionFn(8);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action8::<>(input, &__start, &__end);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 0);
+        __symbols.push((__start, __Symbol::NtStmt_2a(__nt), __end));
+        Ok(3)
+    }
+
+    fn __reduce_Stmt_2a_1<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Stmt* = Stmt+ => ActionFn(9);
+        let __sym0 = __pop_NtStmt_2b(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action9::<>(input, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtStmt_2a(__nt), __end));
+        Ok(3)
+    }
+
+    fn __reduce_Stmt_2b_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Stmt+ = Stmt => ActionFn(10);
+        let __sym0 = __pop_NtStmt(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action10::<>(input, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtStmt_2b(__nt), __end));
+        Ok(4)
+    }
+
+    fn __reduce_Stmt_2b_1<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Stmt+ = Stmt+, Stmt => ActionFn(11);
+        let __sym1 = __pop_NtStmt(__symbols);
+        let __sym0 = __pop_NtStmt_2b(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action11::<>(input, __sym0, __sym1);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 2);
+        __symbols.push((__start, __Symbol::NtStmt_2b(__nt), __end));
+        Ok(4)
+    }
+
+    fn __reduce_Stmts_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Stmts =  => ActionFn(12);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action12::<>(input, &__start, &__end);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 0);
+        __symbols.push((__start, __Symbol::NtStmts(__nt), __end));
+        Ok(5)
+    }
+
+    fn __reduce_Stmts_1<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Stmts = Stmt+ => ActionFn(13);
+        let __sym0 = __pop_NtStmt_2b(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action13::<>(input, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtStmts(__nt), __end));
+        Ok(5)
+    }
+
+    fn __reduce_Term_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Term = Num => ActionFn(5);
+        let __sym0 = __pop_NtNum(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action5::<>(input, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+        Ok(6)
+    }
+
+    fn __reduce_Term_1<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Term = "(", Expr, ")" => ActionFn(6);
+        let __sym2 = __pop_Term_22_29_22(__symbols);
+        let __sym1 = __pop_NtExpr(__symbols);
+        let __sym0 = __pop_Term_22_28_22(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action6::<>(input, __sym0, __sym1, __sym2);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 3);
+        __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+        Ok(6)
+    }
+
+    fn __reduce_____Stmts_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // __Stmts = Stmts => ActionFn(0);
+        let __sym0 = __pop_NtStmts(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action0::<>(input, __sym0);
+        Err(Ok(__Symbol::Nt____Stmts(__nt)))
+    }
+    fn __pop_Term_22_28_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_28_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_29_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_29_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2b_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2b_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3b_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3b_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_5b0_2d9_5d_2b_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtExpr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, i32, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtExpr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtNum<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, i32, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtNum(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtStmt<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, i32, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtStmt(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtStmt_2a<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<i32>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtStmt_2a(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtStmt_2b<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<i32>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtStmt_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtStmts<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<i32>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtStmts(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtTerm<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, i32, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtTerm(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____Stmts<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<i32>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Stmts(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+}
+mod __intern_token {
+    #![allow(unused_imports)]
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    extern crate regex as __regex;
+    use std::fmt as __fmt;
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Token<'input>(pub usize, pub &'input str);
+    impl<'a> __fmt::Display for Token<'a> {
+        fn fmt(&self, formatter: &mut __fmt::Formatter) -> Result<(), __fmt::Error> {
+            __fmt::Display::fmt(self.1, formatter)
+        }
+    }
+
+    pub struct __Matcher<'input> {
+        text: &'input str,
+        consumed: usize,
+        regex_set: __regex::RegexSet,
+        regex_vec: Vec<__regex::Regex>,
+    }
+
+    impl<'input> __Matcher<'input> {
+        pub fn new(s: &'input str) -> __Matcher<'input> {
+            let __strs: &[&str] = &[
+                "^(?u:[0-9])+",
+                "^(?u:\\()",
+                "^(?u:\\))",
+                "^(?u:\\+)",
+                "^(?u:;)",
+            ];
+            let __regex_set = __regex::RegexSet::new(__strs).unwrap();
+            let __regex_vec = vec![
+                __regex::Regex::new("^(?u:[0-9])+").unwrap(),
+                __regex::Regex::new("^(?u:\\()").unwrap(),
+                __regex::Regex::new("^(?u:\\))").unwrap(),
+                __regex::Regex::new("^(?u:\\+)").unwrap(),
+                __regex::Regex::new("^(?u:;)").unwrap(),
+            ];
+            __Matcher {
+                text: s,
+                consumed: 0,
+                regex_set: __regex_set,
+                regex_vec: __regex_vec,
+            }
+        }
+    }
+
+    impl<'input> Iterator for __Matcher<'input> {
+        type Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize,Token<'input>,&'static str>>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let __text = self.text.trim_left();
+            let __whitespace = self.text.len() - __text.len();
+            let __start_offset = self.consumed + __whitespace;
+            if __text.is_empty() {
+                self.text = __text;
+                self.consumed = __start_offset;
+                None
+            } else {
+                let __matches = self.regex_set.matches(__text);
+                if !__matches.matched_any() {
+                    Some(Err(__lalrpop_util::ParseError::InvalidToken {
+                        location: __start_offset,
+                    }))
+                } else {
+                    let mut __longest_match = 0;
+                    let mut __index = 0;
+                    for __i in 0 .. 5 {
+                        if __matches.matched(__i) {
+                            let __match = self.regex_vec[__i].find(__text).unwrap();
+                            let __len = __match.end();
+                            if __len >= __longest_match {
+                                __longest_match = __len;
+                                __index = __i;
+                            }
+                        }
+                    }
+                    let __result = &__text[..__longest_match];
+                    let __remaining = &__text[__longest_match..];
+                    let __end_offset = __start_offset + __longest_match;
+                    self.text = __remaining;
+                    self.consumed = __end_offset;
+                    Some(Ok((__start_offset, Token(__index, __result), __end_offset)))
+                }
+            }
+        }
+    }
+}
+pub use self::__intern_token::Token;
+
+#[allow(unused_variables)]
+fn __action0<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Vec<i32>, usize),
+) -> Vec<i32>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, ::std::vec::Vec<i32>, usize),
+) -> Vec<i32>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, i32, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> i32
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, i32, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, i32, usize),
+) -> i32
+{
+    //@ src/error_repair.lalrpop:12:30 (1 lines)
+    l + r
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, i32, usize),
+) -> i32
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, i32, usize),
+) -> i32
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, i32, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> i32
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> i32
+{
+    //@ src/error_repair.lalrpop:21:25 (1 lines)
+    i32::from_str_radix(__0, 10).unwrap()
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::vec::Vec<i32>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+fn __action9<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<i32>, usize),
+) -> ::std::vec::Vec<i32>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action10<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, i32, usize),
+) -> ::std::vec::Vec<i32>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action11<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<i32>, usize),
+    (_, e, _): (usize, i32, usize),
+) -> ::std::vec::Vec<i32>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action12<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<i32>
+{
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action8(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action1(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action13<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::vec::Vec<i32>, usize),
+) -> Vec<i32>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action9(
+        input,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action1(
+        input,
+        __temp0,
+    )
+}
+
+pub trait __ToTriple<'input, > {
+    type Error;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),Self::Error>;
+}
+
+impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize) {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        Ok(value)
+    }
+}
+impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        value
+    }
+}
@@ -0,0 +1,896 @@
+// auto-generated: "lalrpop 0.15.0"
+use util::tok::Tok;
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+pub use self::__parse_table::parse_Expr;
+pub use self::__parse_table::parse_Expr_cancelable;
+pub use self::__parse_table::parse_Expr_repaired;
+pub use self::__parse_table::parse_Expr_repaired_cancelable;
+pub use self::__parse_table::ExprParser;
+
+mod __parse_table {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    use util::tok::Tok;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__ToTriple;
+    #[allow(dead_code)]
+    pub enum __Symbol<>
+     {
+        Term_22_28_22(Tok),
+        Term_22_29_22(Tok),
+        Term_22_2b_22(Tok),
+        TermNum(i32),
+        NtExpr(i32),
+        NtTerm(i32),
+        Nt____Expr(i32),
+    }
+    impl<> ::std::fmt::Debug for __Symbol<>
+    {
+        fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            match *self {
+                __Symbol::Term_22_28_22(..) => __f.write_str("\"(\""),
+                __Symbol::Term_22_29_22(..) => __f.write_str("\")\""),
+                __Symbol::Term_22_2b_22(..) => __f.write_str("\"+\""),
+                __Symbol::TermNum(..) => __f.write_str("Num"),
+                __Symbol::NtExpr(..) => __f.write_str("Expr"),
+                __Symbol::NtTerm(..) => __f.write_str("Term"),
+                __Symbol::Nt____Expr(..) => __f.write_str("__Expr"),
+            }
+        }
+    }
+    const __ACTION: &'static [i8] = &[
+        // State 0
+        //     Expr = (*) Expr "+" Term ["(", ")", "+", Num, EOF]
+        //     Expr = (*) Term ["(", ")", "+", Num, EOF]
+        //     Term = (*) "(" Expr ")" ["(", ")", "+", Num, EOF]
+        //     Term = (*) Num ["(", ")", "+", Num, EOF]
+        //     __Expr = (*) Expr ["(", ")", "+", Num, EOF]
+        4,  // on "(", goto 3
+        0,  // on ")", error
+        0,  // on "+", error
+        5,  // on Num, goto 4
+
+        // State 1
+        //     Expr = Expr (*) "+" Term ["(", ")", "+", Num, EOF]
+        //     __Expr = Expr (*) ["(", ")", "+", Num, EOF]
+        0,  // on "(", error
+        0,  // on ")", error
+        6,  // on "+", goto 5
+        0,  // on Num, error
+
+        // State 2
+        //     Expr = Term (*) ["(", ")", "+", Num, EOF]
+        0,  // on "(", error
+        -2,  // on ")", reduce `Expr = Term => ActionFn(2);`
+        -2,  // on "+", reduce `Expr = Term => ActionFn(2);`
+        0,  // on Num, error
+
+        // State 3
+        //     Expr = (*) Expr "+" Term ["(", ")", "+", Num, EOF]
+        //     Expr = (*) Term ["(", ")", "+", Num, EOF]
+        //     Term = (*) "(" Expr ")" ["(", ")", "+", Num, EOF]
+        //     Term = "(" (*) Expr ")" ["(", ")", "+", Num, EOF]
+        //     Term = (*) Num ["(", ")", "+", Num, EOF]
+        4,  // on "(", goto 3
+        0,  // on ")", error
+        0,  // on "+", error
+        5,  // on Num, goto 4
+
+        // State 4
+        //     Term = Num (*) ["(", ")", "+", Num, EOF]
+        0,  // on "(", error
+        -3,  // on ")", reduce `Term = Num => ActionFn(3);`
+        -3,  // on "+", reduce `Term = Num => ActionFn(3);`
+        0,  // on Num, error
+
+        // State 5
+        //     Expr = Expr "+" (*) Term ["(", ")", "+", Num, EOF]
+        //     Term = (*) "(" Expr ")" ["(", ")", "+", Num, EOF]
+        //     Term = (*) Num ["(", ")", "+", Num, EOF]
+        4,  // on "(", goto 3
+        0,  // on ")", error
+        0,  // on "+", error
+        5,  // on Num, goto 4
+
+        // State 6
+        //     Expr = Expr (*) "+" Term ["(", ")", "+", Num, EOF]
+        //     Term = "(" Expr (*) ")" ["(", ")", "+", Num, EOF]
+        0,  // on "(", error
+        9,  // on ")", goto 8
+        6,  // on "+", goto 5
+        0,  // on Num, error
+
+        // State 7
+        //     Expr = Expr "+" Term (*) ["(", ")", "+", Num, EOF]
+        0,  // on "(", error
+        -1,  // on ")", reduce `Expr = Expr, "+", Term => ActionFn(1);`
+        -1,  // on "+", reduce `Expr = Expr, "+", Term => ActionFn(1);`
+        0,  // on Num, error
+
+        // State 8
+        //     Term = "(" Expr ")" (*) ["(", ")", "+", Num, EOF]
+        0,  // on "(", error
+        -4,  // on ")", reduce `Term = "(", Expr, ")" => ActionFn(4);`
+        -4,  // on "+", reduce `Term = "(", Expr, ")" => ActionFn(4);`
+        0,  // on Num, error
+
+    ];
+    const __EOF_ACTION: &'static [i8] = &[
+        // State 0
+        0,  // on EOF, error
+
+        // State 1
+        -5,  // on EOF, reduce `__Expr = Expr => ActionFn(0);`
+
+        // State 2
+        -2,  // on EOF, reduce `Expr = Term => ActionFn(2);`
+
+        // State 3
+        0,  // on EOF, error
+
+        // State 4
+        -3,  // on EOF, reduce `Term = Num => ActionFn(3);`
+
+        // State 5
+        0,  // on EOF, error
+
+        // State 6
+        0,  // on EOF, error
+
+        // State 7
+        -1,  // on EOF, reduce `Expr = Expr, "+", Term => ActionFn(1);`
+
+        // State 8
+        -4,  // on EOF, reduce `Term = "(", Expr, ")" => ActionFn(4);`
+
+    ];
+    const __GOTO: &'static [u8] = &[
+        // State 0
+        2,  // on Expr, goto 1
+        3,  // on Term, goto 2
+        0,  // on __Expr, error
+
+        // State 1
+        0,  // on Expr, error
+        0,  // on Term, error
+        0,  // on __Expr, error
+
+        // State 2
+        0,  // on Expr, error
+        0,  // on Term, error
+        0,  // on __Expr, error
+
+        // State 3
+        7,  // on Expr, goto 6
+        3,  // on Term, goto 2
+        0,  // on __Expr, error
+
+        // State 4
+        0,  // on Expr, error
+        0,  // on Term, error
+        0,  // on __Expr, error
+
+        // State 5
+        0,  // on Expr, error
+        8,  // on Term, goto 7
+        0,  // on __Expr, error
+
+        // State 6
+        0,  // on Expr, error
+        0,  // on Term, error
+        0,  // on __Expr, error
+
+        // State 7
+        0,  // on Expr, error
+        0,  // on Term, error
+        0,  // on __Expr, error
+
+        // State 8
+        0,  // on Expr, error
+        0,  // on Term, error
+        0,  // on __Expr, error
+
+    ];
+    fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+        const __TERMINAL: &'static [&'static str] = &[
+            r###""(""###,
+            r###"")""###,
+            r###""+""###,
+            r###"Num"###,
+        ];
+        __ACTION[(__state * 4)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+            if state == 0 {
+                None
+            } else {
+                Some(terminal.to_string())
+            }
+        }).collect()
+    }
+    #[allow(dead_code)]
+    pub fn parse_Expr<
+        __TOKEN: __ToTriple<Error=&'static str>,
+        __TOKENS: IntoIterator<Item=__TOKEN>,
+    >(
+        __tokens0: __TOKENS,
+    ) -> Result<i32, __lalrpop_util::ParseError<usize, Tok, &'static str>>
+    {
+        let __tokens = __tokens0.into_iter();
+        let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+        let mut __states = vec![];
+        let mut __symbols = vec![];
+        __parse::<>(0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() })
+    }
+    #[allow(dead_code)]
+    pub fn parse_Expr_cancelable<
+        __TOKEN: __ToTriple<Error=&'static str>,
+        __TOKENS: IntoIterator<Item=__TOKEN>,
+    >(
+        __tokens0: __TOKENS,
+        __cancel: &::std::sync::atomic::AtomicBool,
+    ) -> Result<i32, __lalrpop_util::ParseError<usize, Tok, &'static str>>
+    {
+        let __tokens = __tokens0.into_iter();
+        let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+        let mut __states = vec![];
+        let mut __symbols = vec![];
+        __parse::<>(0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() })
+    }
+    #[allow(dead_code)]
+    pub fn parse_Expr_repaired<
+        __TOKEN: __ToTriple<Error=&'static str>,
+        __TOKENS: IntoIterator<Item=__TOKEN>,
+    >(
+        __tokens0: __TOKENS,
+    ) -> (Option<i32>, ::std::vec::Vec<__lalrpop_util::repair::Repaired<usize, Tok, &'static str>>)
+    {
+        let __tokens = __tokens0.into_iter();
+        let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+        let mut __triples = vec![];
+        let mut __integers = vec![];
+        let mut __errors = vec![];
+        for __token in __tokens {
+            let __lookahead = match __token {
+                Ok(v) => v,
+                Err(e) => {
+                    __errors.push(__lalrpop_util::repair::Repaired { error: __lalrpop_util::ParseError::User { error: e }, repairs: vec![] });
+                    return (None, __errors);
+                }
+            };
+            let __integer = match __lookahead.1 {
+                Tok::LParen if true => 0,
+                Tok::RParen if true => 1,
+                Tok::Plus if true => 2,
+                Tok::Num(_) if true => 3,
+                _ => {
+                    __errors.push(__lalrpop_util::repair::Repaired { error: __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__lookahead), expected: vec![] }, repairs: vec![] });
+                    return (None, __errors);
+                }
+            };
+            __triples.push(__lookahead);
+            __integers.push(__integer);
+        }
+        loop {
+            let __consumed = ::std::cell::Cell::new(0);
+            let mut __states = vec![];
+            let mut __symbols = vec![];
+            let __result = {
+                let mut __tokens = __triples.iter().cloned().inspect(|_| __consumed.set(__consumed.get() + 1)).map(Ok::<_, &'static str>);
+                __parse::<>(0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() })
+            };
+            let __error = match __result {
+                Ok(v) => return (Some(v), __errors),
+                Err(e) => e,
+            };
+            let __index = match __error {
+                __lalrpop_util::ParseError::UnrecognizedToken { token: Some(_), .. } | __lalrpop_util::ParseError::ExtraToken { .. } => __consumed.get() - 1,
+                __lalrpop_util::ParseError::UnrecognizedToken { token: None, .. } | __lalrpop_util::ParseError::UnrecognizedEof { .. } => __triples.len(),
+                _ => {
+                    __errors.push(__lalrpop_util::repair::Repaired { error: __error, repairs: vec![] });
+                    return (None, __errors);
+                }
+            };
+            let __steps = __lalrpop_util::repair::repairs(&__Automaton, &__states, &__integers[__index..], Default::default());
+            let __repairs = __steps.iter().map(|__s| __lalrpop_util::repair::report(__s, __TERMINALS, &__triples[__index..])).collect();
+            let __applied = match __steps.first() {
+                Some(__s) => __lalrpop_util::repair::apply(__s, &mut __triples, &mut __integers, __index, __insert_token),
+                None => false,
+            };
+            __errors.push(__lalrpop_util::repair::Repaired { error: __error, repairs: __repairs });
+            if !__applied {
+                return (None, __errors);
+            }
+        }
+    }
+    #[allow(dead_code)]
+    pub fn parse_Expr_repaired_cancelable<
+        __TOKEN: __ToTriple<Error=&'static str>,
+        __TOKENS: IntoIterator<Item=__TOKEN>,
+    >(
+        __tokens0: __TOKENS,
+        __cancel: &::std::sync::atomic::AtomicBool,
+    ) -> (Option<i32>, ::std::vec::Vec<__lalrpop_util::repair::Repaired<usize, Tok, &'static str>>)
+    {
+        let __tokens = __tokens0.into_iter();
+        let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+        let mut __triples = vec![];
+        let mut __integers = vec![];
+        let mut __errors = vec![];
+        for __token in __tokens {
+            let __lookahead = match __token {
+                Ok(v) => v,
+                Err(e) => {
+                    __errors.push(__lalrpop_util::repair::Repaired { error: __lalrpop_util::ParseError::User { error: e }, repairs: vec![] });
+                    return (None, __errors);
+                }
+            };
+            let __integer = match __lookahead.1 {
+                Tok::LParen if true => 0,
+                Tok::RParen if true => 1,
+                Tok::Plus if true => 2,
+                Tok::Num(_) if true => 3,
+                _ => {
+                    __errors.push(__lalrpop_util::repair::Repaired { error: __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__lookahead), expected: vec![] }, repairs: vec![] });
+                    return (None, __errors);
+                }
+            };
+            __triples.push(__lookahead);
+            __integers.push(__integer);
+        }
+        loop {
+            let __consumed = ::std::cell::Cell::new(0);
+            let mut __states = vec![];
+            let mut __symbols = vec![];
+            let __result = {
+                let mut __tokens = __triples.iter().cloned().inspect(|_| __consumed.set(__consumed.get() + 1)).map(Ok::<_, &'static str>);
+                __parse::<>(0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() })
+            };
+            let __error = match __result {
+                Ok(v) => return (Some(v), __errors),
+                Err(e) => e,
+            };
+            let __index = match __error {
+                __lalrpop_util::ParseError::UnrecognizedToken { token: Some(_), .. } | __lalrpop_util::ParseError::ExtraToken { .. } => __consumed.get() - 1,
+                __lalrpop_util::ParseError::UnrecognizedToken { token: None, .. } | __lalrpop_util::ParseError::UnrecognizedEof { .. } => __triples.len(),
+                _ => {
+                    __errors.push(__lalrpop_util::repair::Repaired { error: __error, repairs: vec![] });
+                    return (None, __errors);
+                }
+            };
+            let __steps = __lalrpop_util::repair::repairs(&__Automaton, &__states, &__integers[__index..], Default::default());
+            let __repairs = __steps.iter().map(|__s| __lalrpop_util::repair::report(__s, __TERMINALS, &__triples[__index..])).collect();
+            let __applied = match __steps.first() {
+                Some(__s) => __lalrpop_util::repair::apply(__s, &mut __triples, &mut __integers, __index, __insert_token),
+                None => false,
+            };
+            __errors.push(__lalrpop_util::repair::Repaired { error: __error, repairs: __repairs });
+            if !__applied {
+                return (None, __errors);
+            }
+        }
+    }
+
+    #[allow(dead_code)]
+    pub struct ExprParser {
+        __states: ::std::vec::Vec<i32>,
+        __symbols: __lalrpop_util::stack::Recycled,
+        __cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+    }
+
+    #[allow(dead_code)]
+    impl ExprParser {
+        pub fn new() -> ExprParser {
+            ExprParser { __states: vec![], __symbols: __lalrpop_util::stack::Recycled::new(), __cancel: None }
+        }
+
+        pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+            self.__cancel = Some(flag);
+        }
+
+        pub fn parse_in_place<__TOKEN: __ToTriple<Error=&'static str>, __TOKENS: IntoIterator<Item=__TOKEN>>(
+            &mut self,
+            __tokens0: __TOKENS,
+        ) -> Result<i32, __lalrpop_util::ParseError<usize, Tok, &'static str>>
+        {
+            let __tokens = __tokens0.into_iter();
+            let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+            let mut __symbols = self.__symbols.take();
+            let __result = __parse::<>(0, &mut __tokens, &mut self.__states, &mut __symbols, self.__cancel.as_ref().map(|c| &**c)).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() });
+            self.__symbols.put(__symbols);
+            __result
+        }
+    }
+
+    fn __parse<
+    >(
+        __start_state: i32,
+        mut __tokens: &mut Iterator<Item = Result<(usize, Tok, usize), &'static str>>,
+        mut __states: &mut ::std::vec::Vec<i32>,
+        mut __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        __cancel: Option<&::std::sync::atomic::AtomicBool>,
+    ) -> Result<__Symbol<>, __lalrpop_util::ParseError<usize, Tok, &'static str>>
+    {
+        __states.clear();
+        __states.push(__start_state);
+        __symbols.clear();
+        let mut __integer;
+        let mut __lookahead;
+        let __last_location: &mut usize = &mut Default::default();
+        '__shift: loop {
+            __lookahead = match __tokens.next() {
+                Some(Ok(v)) => v,
+                None => break '__shift,
+                Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+            };
+            *__last_location = __lookahead.2.clone();
+            __integer = match __lookahead.1 {
+                Tok::LParen if true => 0,
+                Tok::RParen if true => 1,
+                Tok::Plus if true => 2,
+                Tok::Num(_) if true => 3,
+                _ => {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __expected = __expected_tokens(__state);
+                    let __error = match Some(__lookahead) {
+                        Some(__token) => {
+                            __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                        }
+                        None => {
+                            __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                        }
+                    };
+                    return Err(__error);
+                }
+            };
+            '__inner: loop {
+                let __state = *__states.last().unwrap() as usize;
+                let __action = __ACTION[__state * 4 + __integer] as i32;
+                if __action > 0 {
+                    if let Some(__flag) = __cancel {
+                        if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                            return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                        }
+                    }
+                    let __symbol = match __integer {
+                        0 => match __lookahead.1 {
+                            __tok @ Tok::LParen => __Symbol::Term_22_28_22((__tok)),
+                            _ => unreachable!(),
+                        },
+                        1 => match __lookahead.1 {
+                            __tok @ Tok::RParen => __Symbol::Term_22_29_22((__tok)),
+                            _ => unreachable!(),
+                        },
+                        2 => match __lookahead.1 {
+                            __tok @ Tok::Plus => __Symbol::Term_22_2b_22((__tok)),
+                            _ => unreachable!(),
+                        },
+                        3 => match __lookahead.1 {
+                            Tok::Num(__tok0) => __Symbol::TermNum((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        _ => unreachable!(),
+                    };
+                    __states.push(__action - 1);
+                    __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                    continue '__shift;
+                } else if __action < 0 {
+                    if let Some(__flag) = __cancel {
+                        if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                            return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                        }
+                    }
+                    if let Some(r) = __reduce(__action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                        if r.is_err() {
+                            return r;
+                        }
+                        return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] });
+                    }
+                } else {
+                    let mut __err_lookahead = Some(__lookahead);
+                    let mut __err_integer: Option<usize> = Some(__integer);
+                    let __state = *__states.last().unwrap() as usize;
+                    let __expected = __expected_tokens(__state);
+                    let __error = match __err_lookahead {
+                        Some(__token) => {
+                            if __accepts( *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                            } else {
+                                __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                            }
+                        }
+                        None => {
+                            __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                        }
+                    };
+                    return Err(__error)
+                }
+            }
+        }
+        loop {
+            let __state = *__states.last().unwrap() as usize;
+            let __action = __EOF_ACTION[__state] as i32;
+            if __action < 0 {
+                if let Some(__flag) = __cancel {
+                    if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                        return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                    }
+                }
+                if let Some(r) = __reduce(__action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                    return r;
+                }
+            } else {
+                let mut __err_lookahead = None;
+                let mut __err_integer: Option<usize> = None;
+                let __state = *__states.last().unwrap() as usize;
+                let __expected = __expected_tokens(__state);
+                let __error = match __err_lookahead {
+                    Some(__token) => {
+                        if __accepts( *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                            __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                        } else {
+                            __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                        }
+                    }
+                    None => {
+                        __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                    }
+                };
+                return Err(__error)
+            }
+        }
+    }
+    const __TERMINALS: &'static [&'static str] = &[
+        r###""(""###,
+        r###"")""###,
+        r###""+""###,
+        r###"Num"###,
+    ];
+    struct __Automaton;
+    impl __lalrpop_util::repair::Automaton for __Automaton {
+        fn terminals(&self) -> usize { 4 }
+        fn action(&self, __state: i32, __terminal: Option<usize>) -> i32 {
+            match __terminal {
+                Some(__t) => __ACTION[__state as usize * 4 + __t] as i32,
+                None => __EOF_ACTION[__state as usize] as i32,
+            }
+        }
+        fn production(&self, __production: usize) -> Option<(usize, usize)> {
+            match __production {
+                0 => Some((3, 0)),
+                1 => Some((1, 0)),
+                2 => Some((1, 1)),
+                3 => Some((3, 1)),
+                4 => None,
+                _ => panic!("invalid production {}", __production),
+            }
+        }
+        fn goto(&self, __state: i32, __nonterminal: usize) -> i32 {
+            __GOTO[__state as usize * 3 + __nonterminal] as i32 - 1
+        }
+        fn insertable(&self, __terminal: usize) -> bool {
+            __insert_token(__terminal).is_some()
+        }
+    }
+    fn __insert_token<'input>(__terminal: usize) -> Option<Tok> {
+        match __terminal {
+            0 => Some(Tok::LParen),
+            1 => Some(Tok::RParen),
+            2 => Some(Tok::Plus),
+            _ => None,
+        }
+    }
+    fn __accepts<
+    >(
+        __error_state: i32,
+        __states: & [i32],
+        __opt_integer: Option<usize>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> bool
+    {
+        let mut __states = __states.to_vec();
+        __states.push(__error_state);
+        loop {
+            let mut __states_len = __states.len();
+            let __top = __states[__states_len - 1];
+            let __action = match __opt_integer {
+                None => __EOF_ACTION[__top as usize] as i32,
+                Some(__integer) => __ACTION[(__top * 4) as usize + __integer] as i32,
+            };
+            if __action == 0 { return false; }
+            if __action > 0 { return true; }
+            let (__to_pop, __nt) = match -__action {
+                // simulate Expr = Expr, "+", Term => ActionFn(1);
+                1 => {
+                    (3, 0)
+                }
+                // simulate Expr = Term => ActionFn(2);
+                2 => {
+                    (1, 0)
+                }
+                // simulate Term = Num => ActionFn(3);
+                3 => {
+                    (1, 1)
+                }
+                // simulate Term = "(", Expr, ")" => ActionFn(4);
+                4 => {
+                    (3, 1)
+                }
+                // simulate __Expr = Expr => ActionFn(0);
+                5 => return true,
+                _ => panic!("invalid action code {}", __action)
+            };
+            __states_len -= __to_pop;
+            __states.truncate(__states_len);
+            let __top = __states[__states_len - 1];
+            let __next_state = __GOTO[(__top * 3 + __nt) as usize] as i32 - 1;
+            __states.push(__next_state);
+        }
+    }
+    pub fn __reduce<
+    >(
+        __action: i32,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Option<Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+    {
+        let __nonterminal = match -__action {
+            1 => match __reduce_Expr_0(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            2 => match __reduce_Expr_1(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            3 => match __reduce_Term_0(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            4 => match __reduce_Term_1(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            5 => match __reduce_____Expr_0(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            _ => panic!("invalid action code {}", __action)
+        };
+        let __state = *__states.last().unwrap() as usize;
+        let __next_state = __GOTO[__state * 3 + __nonterminal] as i32 - 1;
+        __states.push(__next_state);
+        None
+    }
+
+    fn __reduce_Expr_0<
+    >(
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+    {
+        // Expr = Expr, "+", Term => ActionFn(1);
+        let __sym2 = __pop_NtTerm(__symbols);
+        let __sym1 = __pop_Term_22_2b_22(__symbols);
+        let __sym0 = __pop_NtExpr(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action1::<>(__sym0, __sym1, __sym2);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 3);
+        __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+        Ok(0)
+    }
+
+    fn __reduce_Expr_1<
+    >(
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+    {
+        // Expr = Term => ActionFn(2);
+        let __sym0 = __pop_NtTerm(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action2::<>(__sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+        Ok(0)
+    }
+
+    fn __reduce_Term_0<
+    >(
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+    {
+        // Term = Num => ActionFn(3);
+        let __sym0 = __pop_TermNum(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action3::<>(__sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+        Ok(1)
+    }
+
+    fn __reduce_Term_1<
+    >(
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+    {
+        // Term = "(", Expr, ")" => ActionFn(4);
+        let __sym2 = __pop_Term_22_29_22(__symbols);
+        let __sym1 = __pop_NtExpr(__symbols);
+        let __sym0 = __pop_Term_22_28_22(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action4::<>(__sym0, __sym1, __sym2);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 3);
+        __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+        Ok(1)
+    }
+
+    fn __reduce_____Expr_0<
+    >(
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+    {
+        // __Expr = Expr => ActionFn(0);
+        let __sym0 = __pop_NtExpr(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action0::<>(__sym0);
+        Err(Ok(__Symbol::Nt____Expr(__nt)))
+    }
+    fn __pop_Term_22_28_22<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Tok, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_28_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_29_22<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Tok, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_29_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2b_22<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Tok, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2b_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_TermNum<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, i32, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::TermNum(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtExpr<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, i32, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtExpr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtTerm<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, i32, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtTerm(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____Expr<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, i32, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Expr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+}
+
+fn __action0<
+>(
+    (_, __0, _): (usize, i32, usize),
+) -> i32
+{
+    (__0)
+}
+
+fn __action1<
+>(
+    (_, l, _): (usize, i32, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, r, _): (usize, i32, usize),
+) -> i32
+{
+    //@ src/error_repair_extern.lalrpop:22:30 (1 lines)
+    l + r
+}
+
+fn __action2<
+>(
+    (_, __0, _): (usize, i32, usize),
+) -> i32
+{
+    (__0)
+}
+
+fn __action3<
+>(
+    (_, __0, _): (usize, i32, usize),
+) -> i32
+{
+    (__0)
+}
+
+fn __action4<
+>(
+    (_, _, _): (usize, Tok, usize),
+    (_, __0, _): (usize, i32, usize),
+    (_, _, _): (usize, Tok, usize),
+) -> i32
+{
+    (__0)
+}
+
+pub trait __ToTriple<> {
+    type Error;
+    fn to_triple(value: Self) -> Result<(usize,Tok,usize),Self::Error>;
+}
+
+impl<> __ToTriple<> for (usize, Tok, usize) {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Tok,usize),&'static str> {
+        Ok(value)
+    }
+}
+impl<> __ToTriple<> for Result<(usize, Tok, usize),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Tok,usize),&'static str> {
+        value
+    }
+}
@@ -0,0 +1,4537 @@
+// auto-generated: "lalrpop 0.15.0"
+use expr_arena_ast::{Arena, Node, Op};
+use util::tok::Tok;
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+
+mod __parse__Expr {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    use expr_arena_ast::{Arena, Node, Op};
+    use util::tok::Tok;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__ToTriple;
+    #[allow(dead_code)]
+    pub fn parse_Expr<
+        'ast,
+        __TOKEN: __ToTriple<'ast, Error=&'static str>,
+        __TOKENS: IntoIterator<Item=__TOKEN>,
+    >(
+        arena: &'ast Arena<'ast>,
+        __tokens0: __TOKENS,
+    ) -> Result<&'ast Node<'ast>, __lalrpop_util::ParseError<usize, Tok, &'static str>> where
+      __TOKENS: Clone,
+    {
+        let __ascent = __ascent::parse_Expr(
+            arena,
+            __tokens0.clone(),
+        );
+        let __parse_table = __parse_table::parse_Expr(
+            arena,
+            __tokens0.clone(),
+        );
+        assert_eq!(__ascent, __parse_table);
+        return __ascent;
+    }
+    mod __ascent {
+
+        mod __parse__Expr {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use expr_arena_ast::{Arena, Node, Op};
+            use util::tok::Tok;
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__ToTriple;
+            #[allow(dead_code)]
+            pub fn parse_Expr<
+                'ast,
+                __TOKEN: __ToTriple<'ast, Error=&'static str>,
+                __TOKENS: IntoIterator<Item=__TOKEN>,
+            >(
+                arena: &'ast Arena<'ast>,
+                __tokens0: __TOKENS,
+            ) -> Result<&'ast Node<'ast>, __lalrpop_util::ParseError<usize, Tok, &'static str>>
+            {
+                let __tokens = __tokens0.into_iter();
+                let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+                let __last_location = ::std::cell::RefCell::new(Default::default());
+                let mut __tokens = __tokens.inspect(|t| if let Ok(ref t) = *t { *__last_location.borrow_mut() = t.2.clone(); });
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                let mut __extra = None;
+                let __result = __state0(arena, &mut __tokens, __lookahead, &mut __extra, ::std::marker::PhantomData::<()>);
+                if let Some((__token, __expected)) = __extra {
+                    return Err(match __result {
+                        Ok(_) => __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected },
+                        Err(_) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                    });
+                }
+                match __result {
+                    Ok((Some(__lookahead), _)) => {
+                        Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] })
+                    }
+                    Ok((None, __Nonterminal::____Expr((_, __nt, _)))) => {
+                        Ok(__nt)
+                    }
+                    Ok(_) => unreachable!(),
+                    Err(__lalrpop_util::ParseError::UnrecognizedEof { expected: __expected, .. }) => {
+                        Err(__lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.borrow().clone(), expected: __expected })
+                    }
+                    Err(__error) => Err(__error),
+                }
+            }
+
+            #[allow(dead_code)]
+            pub enum __Nonterminal<'ast>
+             {
+                _28_3cExpr_3e_20_22_2c_22_29((usize, &'ast Node<'ast>, usize)),
+                _28_3cExpr_3e_20_22_2c_22_29_2a((usize, ::std::vec::Vec<&'ast Node<'ast>>, usize)),
+                _28_3cExpr_3e_20_22_2c_22_29_2b((usize, ::std::vec::Vec<&'ast Node<'ast>>, usize)),
+                Comma_3cExpr_3e((usize, Vec<&'ast Node<'ast>>, usize)),
+                Expr((usize, &'ast Node<'ast>, usize)),
+                Expr_3f((usize, ::std::option::Option<&'ast Node<'ast>>, usize)),
+                Factor((usize, &'ast Node<'ast>, usize)),
+                OpExpr((usize, Op, usize)),
+                OpFactor((usize, Op, usize)),
+                Term((usize, &'ast Node<'ast>, usize)),
+                ____Expr((usize, &'ast Node<'ast>, usize)),
+            }
+            impl<'ast> ::std::fmt::Debug for __Nonterminal<'ast>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Nonterminal::_28_3cExpr_3e_20_22_2c_22_29(..) => __f.write_str("(<Expr> \",\")"),
+                        __Nonterminal::_28_3cExpr_3e_20_22_2c_22_29_2a(..) => __f.write_str("(<Expr> \",\")*"),
+                        __Nonterminal::_28_3cExpr_3e_20_22_2c_22_29_2b(..) => __f.write_str("(<Expr> \",\")+"),
+                        __Nonterminal::Comma_3cExpr_3e(..) => __f.write_str("Comma<Expr>"),
+                        __Nonterminal::Expr(..) => __f.write_str("Expr"),
+                        __Nonterminal::Expr_3f(..) => __f.write_str("Expr?"),
+                        __Nonterminal::Factor(..) => __f.write_str("Factor"),
+                        __Nonterminal::OpExpr(..) => __f.write_str("OpExpr"),
+                        __Nonterminal::OpFactor(..) => __f.write_str("OpFactor"),
+                        __Nonterminal::Term(..) => __f.write_str("Term"),
+                        __Nonterminal::____Expr(..) => __f.write_str("__Expr"),
+                    }
+                }
+            }
+
+            // State 0
+            //     AllInputs = []
+            //     OptionalInputs = []
+            //     FixedInputs = []
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Expr = (*) Expr OpExpr Factor ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Expr = (*) Factor ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Factor = (*) Factor OpFactor Term ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Factor = (*) Term ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Factor = (*) "*" "(" Comma<Expr> ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Term = (*) Num ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     __Expr = (*) Expr ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //
+            //   "(" -> S4
+            //   "*" -> S5
+            //   Num -> S6
+            //
+            //     Expr -> S1
+            //     Factor -> S2
+            //     Term -> S3
+            fn __state0<
+                'ast,
+                __TOKENS: Iterator<Item=Result<(usize, Tok, usize),&'static str>>,
+            >(
+                arena: &'ast Arena<'ast>,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Tok, usize)>,
+                __extra: &mut Option<((usize, Tok, usize), ::std::vec::Vec<::std::string::String>)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Tok, usize)>, __Nonterminal<'ast>), __lalrpop_util::ParseError<usize, Tok, &'static str>>
+            {
+                let mut __result: (Option<(usize, Tok, usize)>, __Nonterminal<'ast>);
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::LParen, __loc2)) => {
+                        let __sym0 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state4(arena, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, __tok @ Tok::Times, __loc2)) => {
+                        let __sym0 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state5(arena, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Tok::Num(__tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(arena, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###"Num"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Expr(__sym0) => {
+                            __result = try!(__state1(arena, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Factor(__sym0) => {
+                            __result = try!(__state2(arena, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Term(__sym0) => {
+                            __result = try!(__state3(arena, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 1
+            //     AllInputs = [Expr]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Expr = Expr (*) OpExpr Factor ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     OpExpr = (*) "+" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     OpExpr = (*) "-" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     __Expr = Expr (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //
+            //   "+" -> S8
+            //   "-" -> S9
+            //   [EOF] -> __Expr = Expr => ActionFn(0);
+            //
+            //     OpExpr -> S7
+            fn __state1<
+                'ast,
+                __TOKENS: Iterator<Item=Result<(usize, Tok, usize),&'static str>>,
+            >(
+                arena: &'ast Arena<'ast>,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Tok, usize)>,
+                __extra: &mut Option<((usize, Tok, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'ast Node<'ast>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Tok, usize)>, __Nonterminal<'ast>), __lalrpop_util::ParseError<usize, Tok, &'static str>>
+            {
+                let mut __result: (Option<(usize, Tok, usize)>, __Nonterminal<'ast>);
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::Plus, __loc2)) => {
+                        let __sym1 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state8(arena, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, __tok @ Tok::Minus, __loc2)) => {
+                        let __sym1 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state9(arena, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(arena, __sym0);
+                        let __nt = __Nonterminal::____Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Tok, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(arena, __sym0);
+                        let __nt = __Nonterminal::____Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::OpExpr(__sym1) => {
+                            __result = try!(__state7(arena, __tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 2
+            //     AllInputs = [Factor]
+            //     OptionalInputs = []
+            //     FixedInputs = [Factor]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Expr = Factor (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Factor = Factor (*) OpFactor Term ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     OpFactor = (*) "*" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     OpFactor = (*) "/" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //
+            //   "*" -> S11
+            //   "/" -> S12
+            //   [")", "+", ",", "-", EOF] -> Expr = Factor => ActionFn(2);
+            //
+            //     OpFactor -> S10
+            fn __state2<
+                'ast,
+                __TOKENS: Iterator<Item=Result<(usize, Tok, usize),&'static str>>,
+            >(
+                arena: &'ast Arena<'ast>,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Tok, usize)>,
+                __extra: &mut Option<((usize, Tok, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'ast Node<'ast>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Tok, usize)>, __Nonterminal<'ast>), __lalrpop_util::ParseError<usize, Tok, &'static str>>
+            {
+                let mut __result: (Option<(usize, Tok, usize)>, __Nonterminal<'ast>);
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::Times, __loc2)) => {
+                        let __sym1 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state11(arena, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, __tok @ Tok::Div, __loc2)) => {
+                        let __sym1 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state12(arena, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((_, Tok::RParen, _)) |
+                    Some((_, Tok::Plus, _)) |
+                    Some((_, Tok::Comma, _)) |
+                    Some((_, Tok::Minus, _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action2::<>(arena, __sym0);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###"",""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Tok, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action2::<>(arena, __sym0);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::OpFactor(__sym1) => {
+                            __result = try!(__state10(arena, __tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 3
+            //     AllInputs = [Term]
+            //     OptionalInputs = []
+            //     FixedInputs = [Term]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Factor)
+            //
+            //     Factor = Term (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //
+            //   [")", "*", "+", ",", "-", "/", EOF] -> Factor = Term => ActionFn(7);
+            //
+            fn __state3<
+                'ast,
+                __TOKENS: Iterator<Item=Result<(usize, Tok, usize),&'static str>>,
+            >(
+                arena: &'ast Arena<'ast>,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Tok, usize)>,
+                __extra: &mut Option<((usize, Tok, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'ast Node<'ast>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Tok, usize)>, __Nonterminal<'ast>), __lalrpop_util::ParseError<usize, Tok, &'static str>>
+            {
+                let mut __result: (Option<(usize, Tok, usize)>, __Nonterminal<'ast>);
+                match __lookahead {
+                    Some((_, Tok::RParen, _)) |
+                    Some((_, Tok::Times, _)) |
+                    Some((_, Tok::Plus, _)) |
+                    Some((_, Tok::Comma, _)) |
+                    Some((_, Tok::Minus, _)) |
+                    Some((_, Tok::Div, _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action7::<>(arena, __sym0);
+                        let __nt = __Nonterminal::Factor((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###"",""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Tok, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action7::<>(arena, __sym0);
+                        let __nt = __Nonterminal::Factor((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 4
+            //     AllInputs = ["("]
+            //     OptionalInputs = []
+            //     FixedInputs = ["("]
+            //     WillPushLen = 2
+            //     WillPush = [Expr, ")"]
+            //     WillProduce = Some(Term)
+            //
+            //     Expr = (*) Expr OpExpr Factor ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Expr = (*) Factor ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Factor = (*) Factor OpFactor Term ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Factor = (*) Term ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Factor = (*) "*" "(" Comma<Expr> ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Term = "(" (*) Expr ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Term = (*) Num ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //
+            //   "(" -> S4
+            //   "*" -> S5
+            //   Num -> S6
+            //
+            //     Expr -> S13
+            //     Factor -> S2
+            //     Term -> S3
+            fn __state4<
+                'ast,
+                __TOKENS: Iterator<Item=Result<(usize, Tok, usize),&'static str>>,
+            >(
+                arena: &'ast Arena<'ast>,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Tok, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Tok, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Tok, usize)>, __Nonterminal<'ast>), __lalrpop_util::ParseError<usize, Tok, &'static str>>
+            {
+                let mut __result: (Option<(usize, Tok, usize)>, __Nonterminal<'ast>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                let __sym0 = &mut Some(__sym0);
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::LParen, __loc2)) => {
+                        let __sym1 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state4(arena, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, __tok @ Tok::Times, __loc2)) => {
+                        let __sym1 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state5(arena, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Tok::Num(__tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(arena, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###"Num"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    if __sym0.is_none() {
+                        return Ok(__result);
+                    }
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Expr(__sym1) => {
+                            __result = try!(__state13(arena, __tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Factor(__sym1) => {
+                            __result = try!(__state2(arena, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Term(__sym1) => {
+                            __result = try!(__state3(arena, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 5
+            //     AllInputs = ["*"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["*"]
+            //     WillPushLen = 3
+            //     WillPush = ["(", Comma<Expr>, ")"]
+            //     WillProduce = Some(Factor)
+            //
+            //     Factor = "*" (*) "(" Comma<Expr> ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //
+            //   "(" -> S14
+            //
+            fn __state5<
+                'ast,
+                __TOKENS: Iterator<Item=Result<(usize, Tok, usize),&'static str>>,
+            >(
+                arena: &'ast Arena<'ast>,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Tok, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Tok, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Tok, usize)>, __Nonterminal<'ast>), __lalrpop_util::ParseError<usize, Tok, &'static str>>
+            {
+                let mut __result: (Option<(usize, Tok, usize)>, __Nonterminal<'ast>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::LParen, __loc2)) => {
+                        let __sym1 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state14(arena, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 6
+            //     AllInputs = [Num]
+            //     OptionalInputs = []
+            //     FixedInputs = [Num]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Term)
+            //
+            //     Term = Num (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //
+            //   [")", "*", "+", ",", "-", "/", EOF] -> Term = Num => ActionFn(10);
+            //
+            fn __state6<
+                'ast,
+                __TOKENS: Iterator<Item=Result<(usize, Tok, usize),&'static str>>,
+            >(
+                arena: &'ast Arena<'ast>,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Tok, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, i32, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Tok, usize)>, __Nonterminal<'ast>), __lalrpop_util::ParseError<usize, Tok, &'static str>>
+            {
+                let mut __result: (Option<(usize, Tok, usize)>, __Nonterminal<'ast>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                match __lookahead {
+                    Some((_, Tok::RParen, _)) |
+                    Some((_, Tok::Times, _)) |
+                    Some((_, Tok::Plus, _)) |
+                    Some((_, Tok::Comma, _)) |
+                    Some((_, Tok::Minus, _)) |
+                    Some((_, Tok::Div, _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action10::<>(arena, __sym0);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###"",""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Tok, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action10::<>(arena, __sym0);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 7
+            //     AllInputs = [Expr, OpExpr]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr, OpExpr]
+            //     WillPushLen = 1
+            //     WillPush = [Factor]
+            //     WillProduce = Some(Expr)
+            //
+            //     Expr = Expr OpExpr (*) Factor ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Factor = (*) Factor OpFactor Term ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Factor = (*) Term ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Factor = (*) "*" "(" Comma<Expr> ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Term = (*) Num ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //
+            //   "(" -> S4
+            //   "*" -> S5
+            //   Num -> S6
+            //
+            //     Factor -> S15
+            //     Term -> S3
+            fn __state7<
+                'ast,
+                __TOKENS: Iterator<Item=Result<(usize, Tok, usize),&'static str>>,
+            >(
+                arena: &'ast Arena<'ast>,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Tok, usize)>,
+                __extra: &mut Option<((usize, Tok, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'ast Node<'ast>, usize),
+                __sym1: (usize, Op, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Tok, usize)>, __Nonterminal<'ast>), __lalrpop_util::ParseError<usize, Tok, &'static str>>
+            {
+                let mut __result: (Option<(usize, Tok, usize)>, __Nonterminal<'ast>);
+                let __sym0 = &mut Some(__sym0);
+                let __sym1 = &mut Some(__sym1);
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::LParen, __loc2)) => {
+                        let __sym2 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state4(arena, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, __tok @ Tok::Times, __loc2)) => {
+                        let __sym2 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state5(arena, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Tok::Num(__tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(arena, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###"Num"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    if __sym1.is_none() {
+                        return Ok(__result);
+                    }
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Factor(__sym2) => {
+                            __result = try!(__state15(arena, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Term(__sym2) => {
+                            __result = try!(__state3(arena, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 8
+            //     AllInputs = ["+"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["+"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(OpExpr)
+            //
+            //     OpExpr = "+" (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //
+            //   ["(", "*", Num] -> OpExpr = "+" => ActionFn(4);
+            //
+            fn __state8<
+                'ast,
+                __TOKENS: Iterator<Item=Result<(usize, Tok, usize),&'static str>>,
+            >(
+                arena: &'ast Arena<'ast>,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Tok, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Tok, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Tok, usize)>, __Nonterminal<'ast>), __lalrpop_util::ParseError<usize, Tok, &'static str>>
+            {
+                let mut __result: (Option<(usize, Tok, usize)>, __Nonterminal<'ast>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                match __lookahead {
+                    Some((_, Tok::LParen, _)) |
+                    Some((_, Tok::Times, _)) |
+                    Some((_, Tok::Num(_), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action4::<>(arena, __sym0);
+                        let __nt = __Nonterminal::OpExpr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###"Num"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 9
+            //     AllInputs = ["-"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["-"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(OpExpr)
+            //
+            //     OpExpr = "-" (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //
+            //   ["(", "*", Num] -> OpExpr = "-" => ActionFn(3);
+            //
+            fn __state9<
+                'ast,
+                __TOKENS: Iterator<Item=Result<(usize, Tok, usize),&'static str>>,
+            >(
+                arena: &'ast Arena<'ast>,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Tok, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Tok, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Tok, usize)>, __Nonterminal<'ast>), __lalrpop_util::ParseError<usize, Tok, &'static str>>
+            {
+                let mut __result: (Option<(usize, Tok, usize)>, __Nonterminal<'ast>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                match __lookahead {
+                    Some((_, Tok::LParen, _)) |
+                    Some((_, Tok::Times, _)) |
+                    Some((_, Tok::Num(_), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action3::<>(arena, __sym0);
+                        let __nt = __Nonterminal::OpExpr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###"Num"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 10
+            //     AllInputs = [Factor, OpFactor]
+            //     OptionalInputs = []
+            //     FixedInputs = [Factor, OpFactor]
+            //     WillPushLen = 1
+            //     WillPush = [Term]
+            //     WillProduce = Some(Factor)
+            //
+            //     Factor = Factor OpFactor (*) Term ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Term = (*) Num ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //
+            //   "(" -> S4
+            //   Num -> S6
+            //
+            //     Term -> S16
+            fn __state10<
+                'ast,
+                __TOKENS: Iterator<Item=Result<(usize, Tok, usize),&'static str>>,
+            >(
+                arena: &'ast Arena<'ast>,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Tok, usize)>,
+                __extra: &mut Option<((usize, Tok, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'ast Node<'ast>, usize),
+                __sym1: (usize, Op, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Tok, usize)>, __Nonterminal<'ast>), __lalrpop_util::ParseError<usize, Tok, &'static str>>
+            {
+                let mut __result: (Option<(usize, Tok, usize)>, __Nonterminal<'ast>);
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::LParen, __loc2)) => {
+                        let __sym2 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state4(arena, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Tok::Num(__tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(arena, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###"Num"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Term(__sym2) => {
+                            __result = try!(__state16(arena, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 11
+            //     AllInputs = ["*"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["*"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(OpFactor)
+            //
+            //     OpFactor = "*" (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //
+            //   ["(", Num] -> OpFactor = "*" => ActionFn(8);
+            //
+            fn __state11<
+                'ast,
+                __TOKENS: Iterator<Item=Result<(usize, Tok, usize),&'static str>>,
+            >(
+                arena: &'ast Arena<'ast>,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Tok, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Tok, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Tok, usize)>, __Nonterminal<'ast>), __lalrpop_util::ParseError<usize, Tok, &'static str>>
+            {
+                let mut __result: (Option<(usize, Tok, usize)>, __Nonterminal<'ast>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                match __lookahead {
+                    Some((_, Tok::LParen, _)) |
+                    Some((_, Tok::Num(_), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action8::<>(arena, __sym0);
+                        let __nt = __Nonterminal::OpFactor((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###"Num"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 12
+            //     AllInputs = ["/"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["/"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(OpFactor)
+            //
+            //     OpFactor = "/" (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //
+            //   ["(", Num] -> OpFactor = "/" => ActionFn(9);
+            //
+            fn __state12<
+                'ast,
+                __TOKENS: Iterator<Item=Result<(usize, Tok, usize),&'static str>>,
+            >(
+                arena: &'ast Arena<'ast>,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Tok, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Tok, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Tok, usize)>, __Nonterminal<'ast>), __lalrpop_util::ParseError<usize, Tok, &'static str>>
+            {
+                let mut __result: (Option<(usize, Tok, usize)>, __Nonterminal<'ast>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                match __lookahead {
+                    Some((_, Tok::LParen, _)) |
+                    Some((_, Tok::Num(_), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action9::<>(arena, __sym0);
+                        let __nt = __Nonterminal::OpFactor((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###"Num"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 13
+            //     AllInputs = ["(", Expr]
+            //     OptionalInputs = ["("]
+            //     FixedInputs = [Expr]
+            //     WillPushLen = 1
+            //     WillPush = [")"]
+            //     WillProduce = None
+            //
+            //     Expr = Expr (*) OpExpr Factor ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     OpExpr = (*) "+" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     OpExpr = (*) "-" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Term = "(" Expr (*) ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //
+            //   ")" -> S17
+            //   "+" -> S8
+            //   "-" -> S9
+            //
+            //     OpExpr -> S7
+            fn __state13<
+                'ast,
+                __TOKENS: Iterator<Item=Result<(usize, Tok, usize),&'static str>>,
+            >(
+                arena: &'ast Arena<'ast>,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Tok, usize)>,
+                __extra: &mut Option<((usize, Tok, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: &mut Option<(usize, Tok, usize)>,
+                __sym1: (usize, &'ast Node<'ast>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Tok, usize)>, __Nonterminal<'ast>), __lalrpop_util::ParseError<usize, Tok, &'static str>>
+            {
+                let mut __result: (Option<(usize, Tok, usize)>, __Nonterminal<'ast>);
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::RParen, __loc2)) => {
+                        let __sym2 = (__loc1, (__tok), __loc2);
+                        let __sym0 = __sym0.take().unwrap();
+                        __result = try!(__state17(arena, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, __tok @ Tok::Plus, __loc2)) => {
+                        let __sym2 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state8(arena, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, __tok @ Tok::Minus, __loc2)) => {
+                        let __sym2 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state9(arena, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::OpExpr(__sym2) => {
+                            __result = try!(__state7(arena, __tokens, __lookahead, __extra, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 14
+            //     AllInputs = ["*", "("]
+            //     OptionalInputs = []
+            //     FixedInputs = ["*", "("]
+            //     WillPushLen = 2
+            //     WillPush = [Comma<Expr>, ")"]
+            //     WillProduce = Some(Factor)
+            //
+            //     (<Expr> ",")+ = (*) (<Expr> ",")+ Expr "," ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     (<Expr> ",")+ = (*) Expr "," ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Comma<Expr> = (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Comma<Expr> = (*) (<Expr> ",")+ ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Comma<Expr> = (*) (<Expr> ",")+ Expr ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Comma<Expr> = (*) Expr ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Expr = (*) Expr OpExpr Factor ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Expr = (*) Factor ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Factor = (*) Factor OpFactor Term ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Factor = (*) Term ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Factor = (*) "*" "(" Comma<Expr> ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Factor = "*" "(" (*) Comma<Expr> ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Term = (*) Num ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //
+            //   "(" -> S4
+            //   "*" -> S5
+            //   Num -> S6
+            //   [")"] -> Comma<Expr> =  => ActionFn(25);
+            //
+            //     (<Expr> ",")+ -> S18
+            //     Comma<Expr> -> S19
+            //     Expr -> S20
+            //     Factor -> S2
+            //     Term -> S3
+            fn __state14<
+                'ast,
+                __TOKENS: Iterator<Item=Result<(usize, Tok, usize),&'static str>>,
+            >(
+                arena: &'ast Arena<'ast>,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Tok, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Tok, usize),
+                __sym1: (usize, Tok, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Tok, usize)>, __Nonterminal<'ast>), __lalrpop_util::ParseError<usize, Tok, &'static str>>
+            {
+                let mut __result: (Option<(usize, Tok, usize)>, __Nonterminal<'ast>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::LParen, __loc2)) => {
+                        let __sym2 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state4(arena, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, __tok @ Tok::Times, __loc2)) => {
+                        let __sym2 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state5(arena, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Tok::Num(__tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(arena, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((_, Tok::RParen, _)) => {
+                        let __start = __sym1.2.clone();
+                        let __end = __lookahead.as_ref().map(|o| o.0.clone()).unwrap_or_else(|| __start.clone());
+                        let __nt = super::super::super::__action25::<>(arena, &__start, &__end);
+                        let __nt = __Nonterminal::Comma_3cExpr_3e((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###"Num"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::_28_3cExpr_3e_20_22_2c_22_29_2b(__sym2) => {
+                            __result = try!(__state18(arena, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Comma_3cExpr_3e(__sym2) => {
+                            __result = try!(__state19(arena, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        __Nonterminal::Expr(__sym2) => {
+                            __result = try!(__state20(arena, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Factor(__sym2) => {
+                            __result = try!(__state2(arena, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Term(__sym2) => {
+                            __result = try!(__state3(arena, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 15
+            //     AllInputs = [Expr, OpExpr, Factor]
+            //     OptionalInputs = [Expr, OpExpr]
+            //     FixedInputs = [Factor]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Expr = Expr OpExpr Factor (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Factor = Factor (*) OpFactor Term ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     OpFactor = (*) "*" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     OpFactor = (*) "/" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //
+            //   "*" -> S11
+            //   "/" -> S12
+            //   [")", "+", ",", "-", EOF] -> Expr = Expr, OpExpr, Factor => ActionFn(1);
+            //
+            //     OpFactor -> S10
+            fn __state15<
+                'ast,
+                __TOKENS: Iterator<Item=Result<(usize, Tok, usize),&'static str>>,
+            >(
+                arena: &'ast Arena<'ast>,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Tok, usize)>,
+                __extra: &mut Option<((usize, Tok, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: &mut Option<(usize, &'ast Node<'ast>, usize)>,
+                __sym1: &mut Option<(usize, Op, usize)>,
+                __sym2: (usize, &'ast Node<'ast>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Tok, usize)>, __Nonterminal<'ast>), __lalrpop_util::ParseError<usize, Tok, &'static str>>
+            {
+                let mut __result: (Option<(usize, Tok, usize)>, __Nonterminal<'ast>);
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::Times, __loc2)) => {
+                        let __sym3 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state11(arena, __tokens, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, __tok @ Tok::Div, __loc2)) => {
+                        let __sym3 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state12(arena, __tokens, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((_, Tok::RParen, _)) |
+                    Some((_, Tok::Plus, _)) |
+                    Some((_, Tok::Comma, _)) |
+                    Some((_, Tok::Minus, _)) |
+                    None => {
+                        let __sym0 = __sym0.take().unwrap();
+                        let __sym1 = __sym1.take().unwrap();
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action1::<>(arena, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###"",""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Tok, usize)> = None;
+                        let __sym0 = __sym0.take().unwrap();
+                        let __sym1 = __sym1.take().unwrap();
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action1::<>(arena, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::OpFactor(__sym3) => {
+                            __result = try!(__state10(arena, __tokens, __lookahead, __extra, __sym2, __sym3, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 16
+            //     AllInputs = [Factor, OpFactor, Term]
+            //     OptionalInputs = []
+            //     FixedInputs = [Factor, OpFactor, Term]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Factor)
+            //
+            //     Factor = Factor OpFactor Term (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //
+            //   [")", "*", "+", ",", "-", "/", EOF] -> Factor = Factor, OpFactor, Term => ActionFn(5);
+            //
+            fn __state16<
+                'ast,
+                __TOKENS: Iterator<Item=Result<(usize, Tok, usize),&'static str>>,
+            >(
+                arena: &'ast Arena<'ast>,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Tok, usize)>,
+                __extra: &mut Option<((usize, Tok, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'ast Node<'ast>, usize),
+                __sym1: (usize, Op, usize),
+                __sym2: (usize, &'ast Node<'ast>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Tok, usize)>, __Nonterminal<'ast>), __lalrpop_util::ParseError<usize, Tok, &'static str>>
+            {
+                let mut __result: (Option<(usize, Tok, usize)>, __Nonterminal<'ast>);
+                match __lookahead {
+                    Some((_, Tok::RParen, _)) |
+                    Some((_, Tok::Times, _)) |
+                    Some((_, Tok::Plus, _)) |
+                    Some((_, Tok::Comma, _)) |
+                    Some((_, Tok::Minus, _)) |
+                    Some((_, Tok::Div, _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action5::<>(arena, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Factor((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###"",""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Tok, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action5::<>(arena, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Factor((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 17
+            //     AllInputs = ["(", Expr, ")"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["(", Expr, ")"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Term)
+            //
+            //     Term = "(" Expr ")" (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //
+            //   [")", "*", "+", ",", "-", "/", EOF] -> Term = "(", Expr, ")" => ActionFn(11);
+            //
+            fn __state17<
+                'ast,
+                __TOKENS: Iterator<Item=Result<(usize, Tok, usize),&'static str>>,
+            >(
+                arena: &'ast Arena<'ast>,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Tok, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Tok, usize),
+                __sym1: (usize, &'ast Node<'ast>, usize),
+                __sym2: (usize, Tok, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Tok, usize)>, __Nonterminal<'ast>), __lalrpop_util::ParseError<usize, Tok, &'static str>>
+            {
+                let mut __result: (Option<(usize, Tok, usize)>, __Nonterminal<'ast>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                match __lookahead {
+                    Some((_, Tok::RParen, _)) |
+                    Some((_, Tok::Times, _)) |
+                    Some((_, Tok::Plus, _)) |
+                    Some((_, Tok::Comma, _)) |
+                    Some((_, Tok::Minus, _)) |
+                    Some((_, Tok::Div, _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action11::<>(arena, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###"",""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Tok, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action11::<>(arena, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 18
+            //     AllInputs = [(<Expr> ",")+]
+            //     OptionalInputs = []
+            //     FixedInputs = [(<Expr> ",")+]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     (<Expr> ",")+ = (<Expr> ",")+ (*) Expr "," ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Comma<Expr> = (<Expr> ",")+ (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Comma<Expr> = (<Expr> ",")+ (*) Expr ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Expr = (*) Expr OpExpr Factor ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Expr = (*) Factor ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Factor = (*) Factor OpFactor Term ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Factor = (*) Term ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Factor = (*) "*" "(" Comma<Expr> ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Term = (*) Num ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //
+            //   "(" -> S4
+            //   "*" -> S5
+            //   Num -> S6
+            //   [")"] -> Comma<Expr> = (<Expr> ",")+ => ActionFn(27);
+            //
+            //     Expr -> S21
+            //     Factor -> S2
+            //     Term -> S3
+            fn __state18<
+                'ast,
+                __TOKENS: Iterator<Item=Result<(usize, Tok, usize),&'static str>>,
+            >(
+                arena: &'ast Arena<'ast>,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Tok, usize)>,
+                __extra: &mut Option<((usize, Tok, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, ::std::vec::Vec<&'ast Node<'ast>>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Tok, usize)>, __Nonterminal<'ast>), __lalrpop_util::ParseError<usize, Tok, &'static str>>
+            {
+                let mut __result: (Option<(usize, Tok, usize)>, __Nonterminal<'ast>);
+                let __sym0 = &mut Some(__sym0);
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::LParen, __loc2)) => {
+                        let __sym1 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state4(arena, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, __tok @ Tok::Times, __loc2)) => {
+                        let __sym1 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state5(arena, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Tok::Num(__tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(arena, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((_, Tok::RParen, _)) => {
+                        let __sym0 = __sym0.take().unwrap();
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action27::<>(arena, __sym0);
+                        let __nt = __Nonterminal::Comma_3cExpr_3e((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###"Num"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    if __sym0.is_none() {
+                        return Ok(__result);
+                    }
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Expr(__sym1) => {
+                            __result = try!(__state21(arena, __tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Factor(__sym1) => {
+                            __result = try!(__state2(arena, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Term(__sym1) => {
+                            __result = try!(__state3(arena, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 19
+            //     AllInputs = ["*", "(", Comma<Expr>]
+            //     OptionalInputs = []
+            //     FixedInputs = ["*", "(", Comma<Expr>]
+            //     WillPushLen = 1
+            //     WillPush = [")"]
+            //     WillProduce = Some(Factor)
+            //
+            //     Factor = "*" "(" Comma<Expr> (*) ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //
+            //   ")" -> S22
+            //
+            fn __state19<
+                'ast,
+                __TOKENS: Iterator<Item=Result<(usize, Tok, usize),&'static str>>,
+            >(
+                arena: &'ast Arena<'ast>,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Tok, usize)>,
+                __extra: &mut Option<((usize, Tok, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Tok, usize),
+                __sym1: (usize, Tok, usize),
+                __sym2: (usize, Vec<&'ast Node<'ast>>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Tok, usize)>, __Nonterminal<'ast>), __lalrpop_util::ParseError<usize, Tok, &'static str>>
+            {
+                let mut __result: (Option<(usize, Tok, usize)>, __Nonterminal<'ast>);
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::RParen, __loc2)) => {
+                        let __sym3 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state22(arena, __tokens, __extra, __sym0, __sym1, __sym2, __sym3, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 20
+            //     AllInputs = [Expr]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     (<Expr> ",")+ = Expr (*) "," ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Comma<Expr> = Expr (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Expr = Expr (*) OpExpr Factor ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     OpExpr = (*) "+" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     OpExpr = (*) "-" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //
+            //   "+" -> S8
+            //   "," -> S23
+            //   "-" -> S9
+            //   [")"] -> Comma<Expr> = Expr => ActionFn(24);
+            //
+            //     OpExpr -> S7
+            fn __state20<
+                'ast,
+                __TOKENS: Iterator<Item=Result<(usize, Tok, usize),&'static str>>,
+            >(
+                arena: &'ast Arena<'ast>,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Tok, usize)>,
+                __extra: &mut Option<((usize, Tok, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'ast Node<'ast>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Tok, usize)>, __Nonterminal<'ast>), __lalrpop_util::ParseError<usize, Tok, &'static str>>
+            {
+                let mut __result: (Option<(usize, Tok, usize)>, __Nonterminal<'ast>);
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::Plus, __loc2)) => {
+                        let __sym1 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state8(arena, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, __tok @ Tok::Comma, __loc2)) => {
+                        let __sym1 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state23(arena, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, __tok @ Tok::Minus, __loc2)) => {
+                        let __sym1 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state9(arena, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((_, Tok::RParen, _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action24::<>(arena, __sym0);
+                        let __nt = __Nonterminal::Comma_3cExpr_3e((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###"",""###.to_string(),
+                            r###""-""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::OpExpr(__sym1) => {
+                            __result = try!(__state7(arena, __tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 21
+            //     AllInputs = [(<Expr> ",")+, Expr]
+            //     OptionalInputs = [(<Expr> ",")+]
+            //     FixedInputs = [Expr]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     (<Expr> ",")+ = (<Expr> ",")+ Expr (*) "," ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Comma<Expr> = (<Expr> ",")+ Expr (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     Expr = Expr (*) OpExpr Factor ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     OpExpr = (*) "+" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //     OpExpr = (*) "-" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //
+            //   "+" -> S8
+            //   "," -> S24
+            //   "-" -> S9
+            //   [")"] -> Comma<Expr> = (<Expr> ",")+, Expr => ActionFn(26);
+            //
+            //     OpExpr -> S7
+            fn __state21<
+                'ast,
+                __TOKENS: Iterator<Item=Result<(usize, Tok, usize),&'static str>>,
+            >(
+                arena: &'ast Arena<'ast>,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Tok, usize)>,
+                __extra: &mut Option<((usize, Tok, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: &mut Option<(usize, ::std::vec::Vec<&'ast Node<'ast>>, usize)>,
+                __sym1: (usize, &'ast Node<'ast>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Tok, usize)>, __Nonterminal<'ast>), __lalrpop_util::ParseError<usize, Tok, &'static str>>
+            {
+                let mut __result: (Option<(usize, Tok, usize)>, __Nonterminal<'ast>);
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::Plus, __loc2)) => {
+                        let __sym2 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state8(arena, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, __tok @ Tok::Comma, __loc2)) => {
+                        let __sym2 = (__loc1, (__tok), __loc2);
+                        let __sym0 = __sym0.take().unwrap();
+                        __result = try!(__state24(arena, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, __tok @ Tok::Minus, __loc2)) => {
+                        let __sym2 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state9(arena, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((_, Tok::RParen, _)) => {
+                        let __sym0 = __sym0.take().unwrap();
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action26::<>(arena, __sym0, __sym1);
+                        let __nt = __Nonterminal::Comma_3cExpr_3e((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###"",""###.to_string(),
+                            r###""-""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::OpExpr(__sym2) => {
+                            __result = try!(__state7(arena, __tokens, __lookahead, __extra, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 22
+            //     AllInputs = ["*", "(", Comma<Expr>, ")"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["*", "(", Comma<Expr>, ")"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Factor)
+            //
+            //     Factor = "*" "(" Comma<Expr> ")" (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //
+            //   [")", "*", "+", ",", "-", "/", EOF] -> Factor = "*", "(", Comma<Expr>, ")" => ActionFn(6);
+            //
+            fn __state22<
+                'ast,
+                __TOKENS: Iterator<Item=Result<(usize, Tok, usize),&'static str>>,
+            >(
+                arena: &'ast Arena<'ast>,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Tok, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Tok, usize),
+                __sym1: (usize, Tok, usize),
+                __sym2: (usize, Vec<&'ast Node<'ast>>, usize),
+                __sym3: (usize, Tok, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Tok, usize)>, __Nonterminal<'ast>), __lalrpop_util::ParseError<usize, Tok, &'static str>>
+            {
+                let mut __result: (Option<(usize, Tok, usize)>, __Nonterminal<'ast>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                match __lookahead {
+                    Some((_, Tok::RParen, _)) |
+                    Some((_, Tok::Times, _)) |
+                    Some((_, Tok::Plus, _)) |
+                    Some((_, Tok::Comma, _)) |
+                    Some((_, Tok::Minus, _)) |
+                    Some((_, Tok::Div, _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym3.2.clone();
+                        let __nt = super::super::super::__action6::<>(arena, __sym0, __sym1, __sym2, __sym3);
+                        let __nt = __Nonterminal::Factor((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###"",""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Tok, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym3.2.clone();
+                        let __nt = super::super::super::__action6::<>(arena, __sym0, __sym1, __sym2, __sym3);
+                        let __nt = __Nonterminal::Factor((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 23
+            //     AllInputs = [Expr, ","]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr, ","]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some((<Expr> ",")+)
+            //
+            //     (<Expr> ",")+ = Expr "," (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //
+            //   ["(", ")", "*", Num] -> (<Expr> ",")+ = Expr, "," => ActionFn(20);
+            //
+            fn __state23<
+                'ast,
+                __TOKENS: Iterator<Item=Result<(usize, Tok, usize),&'static str>>,
+            >(
+                arena: &'ast Arena<'ast>,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Tok, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'ast Node<'ast>, usize),
+                __sym1: (usize, Tok, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Tok, usize)>, __Nonterminal<'ast>), __lalrpop_util::ParseError<usize, Tok, &'static str>>
+            {
+                let mut __result: (Option<(usize, Tok, usize)>, __Nonterminal<'ast>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                match __lookahead {
+                    Some((_, Tok::LParen, _)) |
+                    Some((_, Tok::RParen, _)) |
+                    Some((_, Tok::Times, _)) |
+                    Some((_, Tok::Num(_), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action20::<>(arena, __sym0, __sym1);
+                        let __nt = __Nonterminal::_28_3cExpr_3e_20_22_2c_22_29_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###"Num"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 24
+            //     AllInputs = [(<Expr> ",")+, Expr, ","]
+            //     OptionalInputs = []
+            //     FixedInputs = [(<Expr> ",")+, Expr, ","]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some((<Expr> ",")+)
+            //
+            //     (<Expr> ",")+ = (<Expr> ",")+ Expr "," (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+            //
+            //   ["(", ")", "*", Num] -> (<Expr> ",")+ = (<Expr> ",")+, Expr, "," => ActionFn(21);
+            //
+            fn __state24<
+                'ast,
+                __TOKENS: Iterator<Item=Result<(usize, Tok, usize),&'static str>>,
+            >(
+                arena: &'ast Arena<'ast>,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Tok, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, ::std::vec::Vec<&'ast Node<'ast>>, usize),
+                __sym1: (usize, &'ast Node<'ast>, usize),
+                __sym2: (usize, Tok, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Tok, usize)>, __Nonterminal<'ast>), __lalrpop_util::ParseError<usize, Tok, &'static str>>
+            {
+                let mut __result: (Option<(usize, Tok, usize)>, __Nonterminal<'ast>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                match __lookahead {
+                    Some((_, Tok::LParen, _)) |
+                    Some((_, Tok::RParen, _)) |
+                    Some((_, Tok::Times, _)) |
+                    Some((_, Tok::Num(_), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action21::<>(arena, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::_28_3cExpr_3e_20_22_2c_22_29_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###"Num"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+        }
+        pub use self::__parse__Expr::parse_Expr;
+    }
+    mod __parse_table {
+
+        mod __parse_table {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use expr_arena_ast::{Arena, Node, Op};
+            use util::tok::Tok;
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__ToTriple;
+            #[allow(dead_code)]
+            pub enum __Symbol<'ast>
+             {
+                Term_22_28_22(Tok),
+                Term_22_29_22(Tok),
+                Term_22_2a_22(Tok),
+                Term_22_2b_22(Tok),
+                Term_22_2c_22(Tok),
+                Term_22_2d_22(Tok),
+                Term_22_2f_22(Tok),
+                TermNum(i32),
+                Nt_28_3cExpr_3e_20_22_2c_22_29(&'ast Node<'ast>),
+                Nt_28_3cExpr_3e_20_22_2c_22_29_2a(::std::vec::Vec<&'ast Node<'ast>>),
+                Nt_28_3cExpr_3e_20_22_2c_22_29_2b(::std::vec::Vec<&'ast Node<'ast>>),
+                NtComma_3cExpr_3e(Vec<&'ast Node<'ast>>),
+                NtExpr(&'ast Node<'ast>),
+                NtExpr_3f(::std::option::Option<&'ast Node<'ast>>),
+                NtFactor(&'ast Node<'ast>),
+                NtOpExpr(Op),
+                NtOpFactor(Op),
+                NtTerm(&'ast Node<'ast>),
+                Nt____Expr(&'ast Node<'ast>),
+            }
+            impl<'ast> ::std::fmt::Debug for __Symbol<'ast>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Symbol::Term_22_28_22(..) => __f.write_str("\"(\""),
+                        __Symbol::Term_22_29_22(..) => __f.write_str("\")\""),
+                        __Symbol::Term_22_2a_22(..) => __f.write_str("\"*\""),
+                        __Symbol::Term_22_2b_22(..) => __f.write_str("\"+\""),
+                        __Symbol::Term_22_2c_22(..) => __f.write_str("\",\""),
+                        __Symbol::Term_22_2d_22(..) => __f.write_str("\"-\""),
+                        __Symbol::Term_22_2f_22(..) => __f.write_str("\"/\""),
+                        __Symbol::TermNum(..) => __f.write_str("Num"),
+                        __Symbol::Nt_28_3cExpr_3e_20_22_2c_22_29(..) => __f.write_str("(<Expr> \",\")"),
+                        __Symbol::Nt_28_3cExpr_3e_20_22_2c_22_29_2a(..) => __f.write_str("(<Expr> \",\")*"),
+                        __Symbol::Nt_28_3cExpr_3e_20_22_2c_22_29_2b(..) => __f.write_str("(<Expr> \",\")+"),
+                        __Symbol::NtComma_3cExpr_3e(..) => __f.write_str("Comma<Expr>"),
+                        __Symbol::NtExpr(..) => __f.write_str("Expr"),
+                        __Symbol::NtExpr_3f(..) => __f.write_str("Expr?"),
+                        __Symbol::NtFactor(..) => __f.write_str("Factor"),
+                        __Symbol::NtOpExpr(..) => __f.write_str("OpExpr"),
+                        __Symbol::NtOpFactor(..) => __f.write_str("OpFactor"),
+                        __Symbol::NtTerm(..) => __f.write_str("Term"),
+                        __Symbol::Nt____Expr(..) => __f.write_str("__Expr"),
+                    }
+                }
+            }
+            const __ACTION: &'static [i8] = &[
+                // State 0
+                //     Expr = (*) Expr OpExpr Factor ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Expr = (*) Factor ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Factor = (*) Factor OpFactor Term ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Factor = (*) Term ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Factor = (*) "*" "(" Comma<Expr> ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Term = (*) Num ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     __Expr = (*) Expr ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                5,  // on "(", goto 4
+                0,  // on ")", error
+                6,  // on "*", goto 5
+                0,  // on "+", error
+                0,  // on ",", error
+                0,  // on "-", error
+                0,  // on "/", error
+                7,  // on Num, goto 6
+
+                // State 1
+                //     Expr = Expr (*) OpExpr Factor ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     OpExpr = (*) "+" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     OpExpr = (*) "-" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     __Expr = Expr (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                0,  // on "*", error
+                9,  // on "+", goto 8
+                0,  // on ",", error
+                10,  // on "-", goto 9
+                0,  // on "/", error
+                0,  // on Num, error
+
+                // State 2
+                //     Expr = Factor (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Factor = Factor (*) OpFactor Term ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     OpFactor = (*) "*" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     OpFactor = (*) "/" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                0,  // on "(", error
+                -11,  // on ")", reduce `Expr = Factor => ActionFn(2);`
+                12,  // on "*", goto 11
+                -11,  // on "+", reduce `Expr = Factor => ActionFn(2);`
+                -11,  // on ",", reduce `Expr = Factor => ActionFn(2);`
+                -11,  // on "-", reduce `Expr = Factor => ActionFn(2);`
+                13,  // on "/", goto 12
+                0,  // on Num, error
+
+                // State 3
+                //     Factor = Term (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                0,  // on "(", error
+                -16,  // on ")", reduce `Factor = Term => ActionFn(7);`
+                -16,  // on "*", reduce `Factor = Term => ActionFn(7);`
+                -16,  // on "+", reduce `Factor = Term => ActionFn(7);`
+                -16,  // on ",", reduce `Factor = Term => ActionFn(7);`
+                -16,  // on "-", reduce `Factor = Term => ActionFn(7);`
+                -16,  // on "/", reduce `Factor = Term => ActionFn(7);`
+                0,  // on Num, error
+
+                // State 4
+                //     Expr = (*) Expr OpExpr Factor ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Expr = (*) Factor ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Factor = (*) Factor OpFactor Term ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Factor = (*) Term ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Factor = (*) "*" "(" Comma<Expr> ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Term = "(" (*) Expr ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Term = (*) Num ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                5,  // on "(", goto 4
+                0,  // on ")", error
+                6,  // on "*", goto 5
+                0,  // on "+", error
+                0,  // on ",", error
+                0,  // on "-", error
+                0,  // on "/", error
+                7,  // on Num, goto 6
+
+                // State 5
+                //     Factor = "*" (*) "(" Comma<Expr> ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                15,  // on "(", goto 14
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                0,  // on ",", error
+                0,  // on "-", error
+                0,  // on "/", error
+                0,  // on Num, error
+
+                // State 6
+                //     Term = Num (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                0,  // on "(", error
+                -21,  // on ")", reduce `Term = Num => ActionFn(10);`
+                -21,  // on "*", reduce `Term = Num => ActionFn(10);`
+                -21,  // on "+", reduce `Term = Num => ActionFn(10);`
+                -21,  // on ",", reduce `Term = Num => ActionFn(10);`
+                -21,  // on "-", reduce `Term = Num => ActionFn(10);`
+                -21,  // on "/", reduce `Term = Num => ActionFn(10);`
+                0,  // on Num, error
+
+                // State 7
+                //     Expr = Expr OpExpr (*) Factor ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Factor = (*) Factor OpFactor Term ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Factor = (*) Term ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Factor = (*) "*" "(" Comma<Expr> ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Term = (*) Num ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                5,  // on "(", goto 4
+                0,  // on ")", error
+                6,  // on "*", goto 5
+                0,  // on "+", error
+                0,  // on ",", error
+                0,  // on "-", error
+                0,  // on "/", error
+                7,  // on Num, goto 6
+
+                // State 8
+                //     OpExpr = "+" (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                -18,  // on "(", reduce `OpExpr = "+" => ActionFn(4);`
+                0,  // on ")", error
+                -18,  // on "*", reduce `OpExpr = "+" => ActionFn(4);`
+                0,  // on "+", error
+                0,  // on ",", error
+                0,  // on "-", error
+                0,  // on "/", error
+                -18,  // on Num, reduce `OpExpr = "+" => ActionFn(4);`
+
+                // State 9
+                //     OpExpr = "-" (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                -17,  // on "(", reduce `OpExpr = "-" => ActionFn(3);`
+                0,  // on ")", error
+                -17,  // on "*", reduce `OpExpr = "-" => ActionFn(3);`
+                0,  // on "+", error
+                0,  // on ",", error
+                0,  // on "-", error
+                0,  // on "/", error
+                -17,  // on Num, reduce `OpExpr = "-" => ActionFn(3);`
+
+                // State 10
+                //     Factor = Factor OpFactor (*) Term ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Term = (*) Num ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                5,  // on "(", goto 4
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                0,  // on ",", error
+                0,  // on "-", error
+                0,  // on "/", error
+                7,  // on Num, goto 6
+
+                // State 11
+                //     OpFactor = "*" (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                -19,  // on "(", reduce `OpFactor = "*" => ActionFn(8);`
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                0,  // on ",", error
+                0,  // on "-", error
+                0,  // on "/", error
+                -19,  // on Num, reduce `OpFactor = "*" => ActionFn(8);`
+
+                // State 12
+                //     OpFactor = "/" (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                -20,  // on "(", reduce `OpFactor = "/" => ActionFn(9);`
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                0,  // on ",", error
+                0,  // on "-", error
+                0,  // on "/", error
+                -20,  // on Num, reduce `OpFactor = "/" => ActionFn(9);`
+
+                // State 13
+                //     Expr = Expr (*) OpExpr Factor ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     OpExpr = (*) "+" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     OpExpr = (*) "-" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Term = "(" Expr (*) ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                0,  // on "(", error
+                18,  // on ")", goto 17
+                0,  // on "*", error
+                9,  // on "+", goto 8
+                0,  // on ",", error
+                10,  // on "-", goto 9
+                0,  // on "/", error
+                0,  // on Num, error
+
+                // State 14
+                //     (<Expr> ",")+ = (*) (<Expr> ",")+ Expr "," ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     (<Expr> ",")+ = (*) Expr "," ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Comma<Expr> = (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Comma<Expr> = (*) (<Expr> ",")+ ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Comma<Expr> = (*) (<Expr> ",")+ Expr ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Comma<Expr> = (*) Expr ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Expr = (*) Expr OpExpr Factor ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Expr = (*) Factor ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Factor = (*) Factor OpFactor Term ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Factor = (*) Term ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Factor = (*) "*" "(" Comma<Expr> ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Factor = "*" "(" (*) Comma<Expr> ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Term = (*) Num ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                5,  // on "(", goto 4
+                -7,  // on ")", reduce `Comma<Expr> =  => ActionFn(25);`
+                6,  // on "*", goto 5
+                0,  // on "+", error
+                0,  // on ",", error
+                0,  // on "-", error
+                0,  // on "/", error
+                7,  // on Num, goto 6
+
+                // State 15
+                //     Expr = Expr OpExpr Factor (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Factor = Factor (*) OpFactor Term ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     OpFactor = (*) "*" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     OpFactor = (*) "/" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                0,  // on "(", error
+                -10,  // on ")", reduce `Expr = Expr, OpExpr, Factor => ActionFn(1);`
+                12,  // on "*", goto 11
+                -10,  // on "+", reduce `Expr = Expr, OpExpr, Factor => ActionFn(1);`
+                -10,  // on ",", reduce `Expr = Expr, OpExpr, Factor => ActionFn(1);`
+                -10,  // on "-", reduce `Expr = Expr, OpExpr, Factor => ActionFn(1);`
+                13,  // on "/", goto 12
+                0,  // on Num, error
+
+                // State 16
+                //     Factor = Factor OpFactor Term (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                0,  // on "(", error
+                -14,  // on ")", reduce `Factor = Factor, OpFactor, Term => ActionFn(5);`
+                -14,  // on "*", reduce `Factor = Factor, OpFactor, Term => ActionFn(5);`
+                -14,  // on "+", reduce `Factor = Factor, OpFactor, Term => ActionFn(5);`
+                -14,  // on ",", reduce `Factor = Factor, OpFactor, Term => ActionFn(5);`
+                -14,  // on "-", reduce `Factor = Factor, OpFactor, Term => ActionFn(5);`
+                -14,  // on "/", reduce `Factor = Factor, OpFactor, Term => ActionFn(5);`
+                0,  // on Num, error
+
+                // State 17
+                //     Term = "(" Expr ")" (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                0,  // on "(", error
+                -22,  // on ")", reduce `Term = "(", Expr, ")" => ActionFn(11);`
+                -22,  // on "*", reduce `Term = "(", Expr, ")" => ActionFn(11);`
+                -22,  // on "+", reduce `Term = "(", Expr, ")" => ActionFn(11);`
+                -22,  // on ",", reduce `Term = "(", Expr, ")" => ActionFn(11);`
+                -22,  // on "-", reduce `Term = "(", Expr, ")" => ActionFn(11);`
+                -22,  // on "/", reduce `Term = "(", Expr, ")" => ActionFn(11);`
+                0,  // on Num, error
+
+                // State 18
+                //     (<Expr> ",")+ = (<Expr> ",")+ (*) Expr "," ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Comma<Expr> = (<Expr> ",")+ (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Comma<Expr> = (<Expr> ",")+ (*) Expr ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Expr = (*) Expr OpExpr Factor ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Expr = (*) Factor ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Factor = (*) Factor OpFactor Term ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Factor = (*) Term ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Factor = (*) "*" "(" Comma<Expr> ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Term = (*) Num ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                5,  // on "(", goto 4
+                -9,  // on ")", reduce `Comma<Expr> = (<Expr> ",")+ => ActionFn(27);`
+                6,  // on "*", goto 5
+                0,  // on "+", error
+                0,  // on ",", error
+                0,  // on "-", error
+                0,  // on "/", error
+                7,  // on Num, goto 6
+
+                // State 19
+                //     Factor = "*" "(" Comma<Expr> (*) ")" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                0,  // on "(", error
+                23,  // on ")", goto 22
+                0,  // on "*", error
+                0,  // on "+", error
+                0,  // on ",", error
+                0,  // on "-", error
+                0,  // on "/", error
+                0,  // on Num, error
+
+                // State 20
+                //     (<Expr> ",")+ = Expr (*) "," ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Comma<Expr> = Expr (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Expr = Expr (*) OpExpr Factor ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     OpExpr = (*) "+" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     OpExpr = (*) "-" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                0,  // on "(", error
+                -6,  // on ")", reduce `Comma<Expr> = Expr => ActionFn(24);`
+                0,  // on "*", error
+                9,  // on "+", goto 8
+                24,  // on ",", goto 23
+                10,  // on "-", goto 9
+                0,  // on "/", error
+                0,  // on Num, error
+
+                // State 21
+                //     (<Expr> ",")+ = (<Expr> ",")+ Expr (*) "," ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Comma<Expr> = (<Expr> ",")+ Expr (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     Expr = Expr (*) OpExpr Factor ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     OpExpr = (*) "+" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                //     OpExpr = (*) "-" ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                0,  // on "(", error
+                -8,  // on ")", reduce `Comma<Expr> = (<Expr> ",")+, Expr => ActionFn(26);`
+                0,  // on "*", error
+                9,  // on "+", goto 8
+                25,  // on ",", goto 24
+                10,  // on "-", goto 9
+                0,  // on "/", error
+                0,  // on Num, error
+
+                // State 22
+                //     Factor = "*" "(" Comma<Expr> ")" (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                0,  // on "(", error
+                -15,  // on ")", reduce `Factor = "*", "(", Comma<Expr>, ")" => ActionFn(6);`
+                -15,  // on "*", reduce `Factor = "*", "(", Comma<Expr>, ")" => ActionFn(6);`
+                -15,  // on "+", reduce `Factor = "*", "(", Comma<Expr>, ")" => ActionFn(6);`
+                -15,  // on ",", reduce `Factor = "*", "(", Comma<Expr>, ")" => ActionFn(6);`
+                -15,  // on "-", reduce `Factor = "*", "(", Comma<Expr>, ")" => ActionFn(6);`
+                -15,  // on "/", reduce `Factor = "*", "(", Comma<Expr>, ")" => ActionFn(6);`
+                0,  // on Num, error
+
+                // State 23
+                //     (<Expr> ",")+ = Expr "," (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                -4,  // on "(", reduce `(<Expr> ",")+ = Expr, "," => ActionFn(20);`
+                -4,  // on ")", reduce `(<Expr> ",")+ = Expr, "," => ActionFn(20);`
+                -4,  // on "*", reduce `(<Expr> ",")+ = Expr, "," => ActionFn(20);`
+                0,  // on "+", error
+                0,  // on ",", error
+                0,  // on "-", error
+                0,  // on "/", error
+                -4,  // on Num, reduce `(<Expr> ",")+ = Expr, "," => ActionFn(20);`
+
+                // State 24
+                //     (<Expr> ",")+ = (<Expr> ",")+ Expr "," (*) ["(", ")", "*", "+", ",", "-", "/", Num, EOF]
+                -5,  // on "(", reduce `(<Expr> ",")+ = (<Expr> ",")+, Expr, "," => ActionFn(21);`
+                -5,  // on ")", reduce `(<Expr> ",")+ = (<Expr> ",")+, Expr, "," => ActionFn(21);`
+                -5,  // on "*", reduce `(<Expr> ",")+ = (<Expr> ",")+, Expr, "," => ActionFn(21);`
+                0,  // on "+", error
+                0,  // on ",", error
+                0,  // on "-", error
+                0,  // on "/", error
+                -5,  // on Num, reduce `(<Expr> ",")+ = (<Expr> ",")+, Expr, "," => ActionFn(21);`
+
+            ];
+            const __EOF_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // on EOF, error
+
+                // State 1
+                -23,  // on EOF, reduce `__Expr = Expr => ActionFn(0);`
+
+                // State 2
+                -11,  // on EOF, reduce `Expr = Factor => ActionFn(2);`
+
+                // State 3
+                -16,  // on EOF, reduce `Factor = Term => ActionFn(7);`
+
+                // State 4
+                0,  // on EOF, error
+
+                // State 5
+                0,  // on EOF, error
+
+                // State 6
+                -21,  // on EOF, reduce `Term = Num => ActionFn(10);`
+
+                // State 7
+                0,  // on EOF, error
+
+                // State 8
+                0,  // on EOF, error
+
+                // State 9
+                0,  // on EOF, error
+
+                // State 10
+                0,  // on EOF, error
+
+                // State 11
+                0,  // on EOF, error
+
+                // State 12
+                0,  // on EOF, error
+
+                // State 13
+                0,  // on EOF, error
+
+                // State 14
+                0,  // on EOF, error
+
+                // State 15
+                -10,  // on EOF, reduce `Expr = Expr, OpExpr, Factor => ActionFn(1);`
+
+                // State 16
+                -14,  // on EOF, reduce `Factor = Factor, OpFactor, Term => ActionFn(5);`
+
+                // State 17
+                -22,  // on EOF, reduce `Term = "(", Expr, ")" => ActionFn(11);`
+
+                // State 18
+                0,  // on EOF, error
+
+                // State 19
+                0,  // on EOF, error
+
+                // State 20
+                0,  // on EOF, error
+
+                // State 21
+                0,  // on EOF, error
+
+                // State 22
+                -15,  // on EOF, reduce `Factor = "*", "(", Comma<Expr>, ")" => ActionFn(6);`
+
+                // State 23
+                0,  // on EOF, error
+
+                // State 24
+                0,  // on EOF, error
+
+            ];
+            const __GOTO: &'static [u8] = &[
+                // State 0
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on Comma<Expr>, error
+                2,  // on Expr, goto 1
+                0,  // on Expr?, error
+                3,  // on Factor, goto 2
+                0,  // on OpExpr, error
+                0,  // on OpFactor, error
+                4,  // on Term, goto 3
+                0,  // on __Expr, error
+
+                // State 1
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on Comma<Expr>, error
+                0,  // on Expr, error
+                0,  // on Expr?, error
+                0,  // on Factor, error
+                8,  // on OpExpr, goto 7
+                0,  // on OpFactor, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 2
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on Comma<Expr>, error
+                0,  // on Expr, error
+                0,  // on Expr?, error
+                0,  // on Factor, error
+                0,  // on OpExpr, error
+                11,  // on OpFactor, goto 10
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 3
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on Comma<Expr>, error
+                0,  // on Expr, error
+                0,  // on Expr?, error
+                0,  // on Factor, error
+                0,  // on OpExpr, error
+                0,  // on OpFactor, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 4
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on Comma<Expr>, error
+                14,  // on Expr, goto 13
+                0,  // on Expr?, error
+                3,  // on Factor, goto 2
+                0,  // on OpExpr, error
+                0,  // on OpFactor, error
+                4,  // on Term, goto 3
+                0,  // on __Expr, error
+
+                // State 5
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on Comma<Expr>, error
+                0,  // on Expr, error
+                0,  // on Expr?, error
+                0,  // on Factor, error
+                0,  // on OpExpr, error
+                0,  // on OpFactor, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 6
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on Comma<Expr>, error
+                0,  // on Expr, error
+                0,  // on Expr?, error
+                0,  // on Factor, error
+                0,  // on OpExpr, error
+                0,  // on OpFactor, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 7
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on Comma<Expr>, error
+                0,  // on Expr, error
+                0,  // on Expr?, error
+                16,  // on Factor, goto 15
+                0,  // on OpExpr, error
+                0,  // on OpFactor, error
+                4,  // on Term, goto 3
+                0,  // on __Expr, error
+
+                // State 8
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on Comma<Expr>, error
+                0,  // on Expr, error
+                0,  // on Expr?, error
+                0,  // on Factor, error
+                0,  // on OpExpr, error
+                0,  // on OpFactor, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 9
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on Comma<Expr>, error
+                0,  // on Expr, error
+                0,  // on Expr?, error
+                0,  // on Factor, error
+                0,  // on OpExpr, error
+                0,  // on OpFactor, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 10
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on Comma<Expr>, error
+                0,  // on Expr, error
+                0,  // on Expr?, error
+                0,  // on Factor, error
+                0,  // on OpExpr, error
+                0,  // on OpFactor, error
+                17,  // on Term, goto 16
+                0,  // on __Expr, error
+
+                // State 11
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on Comma<Expr>, error
+                0,  // on Expr, error
+                0,  // on Expr?, error
+                0,  // on Factor, error
+                0,  // on OpExpr, error
+                0,  // on OpFactor, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 12
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on Comma<Expr>, error
+                0,  // on Expr, error
+                0,  // on Expr?, error
+                0,  // on Factor, error
+                0,  // on OpExpr, error
+                0,  // on OpFactor, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 13
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on Comma<Expr>, error
+                0,  // on Expr, error
+                0,  // on Expr?, error
+                0,  // on Factor, error
+                8,  // on OpExpr, goto 7
+                0,  // on OpFactor, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 14
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                19,  // on (<Expr> ",")+, goto 18
+                20,  // on Comma<Expr>, goto 19
+                21,  // on Expr, goto 20
+                0,  // on Expr?, error
+                3,  // on Factor, goto 2
+                0,  // on OpExpr, error
+                0,  // on OpFactor, error
+                4,  // on Term, goto 3
+                0,  // on __Expr, error
+
+                // State 15
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on Comma<Expr>, error
+                0,  // on Expr, error
+                0,  // on Expr?, error
+                0,  // on Factor, error
+                0,  // on OpExpr, error
+                11,  // on OpFactor, goto 10
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 16
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on Comma<Expr>, error
+                0,  // on Expr, error
+                0,  // on Expr?, error
+                0,  // on Factor, error
+                0,  // on OpExpr, error
+                0,  // on OpFactor, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 17
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on Comma<Expr>, error
+                0,  // on Expr, error
+                0,  // on Expr?, error
+                0,  // on Factor, error
+                0,  // on OpExpr, error
+                0,  // on OpFactor, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 18
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on Comma<Expr>, error
+                22,  // on Expr, goto 21
+                0,  // on Expr?, error
+                3,  // on Factor, goto 2
+                0,  // on OpExpr, error
+                0,  // on OpFactor, error
+                4,  // on Term, goto 3
+                0,  // on __Expr, error
+
+                // State 19
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on Comma<Expr>, error
+                0,  // on Expr, error
+                0,  // on Expr?, error
+                0,  // on Factor, error
+                0,  // on OpExpr, error
+                0,  // on OpFactor, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 20
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on Comma<Expr>, error
+                0,  // on Expr, error
+                0,  // on Expr?, error
+                0,  // on Factor, error
+                8,  // on OpExpr, goto 7
+                0,  // on OpFactor, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 21
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on Comma<Expr>, error
+                0,  // on Expr, error
+                0,  // on Expr?, error
+                0,  // on Factor, error
+                8,  // on OpExpr, goto 7
+                0,  // on OpFactor, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 22
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on Comma<Expr>, error
+                0,  // on Expr, error
+                0,  // on Expr?, error
+                0,  // on Factor, error
+                0,  // on OpExpr, error
+                0,  // on OpFactor, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 23
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on Comma<Expr>, error
+                0,  // on Expr, error
+                0,  // on Expr?, error
+                0,  // on Factor, error
+                0,  // on OpExpr, error
+                0,  // on OpFactor, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 24
+                0,  // on (<Expr> ","), error
+                0,  // on (<Expr> ",")*, error
+                0,  // on (<Expr> ",")+, error
+                0,  // on Comma<Expr>, error
+                0,  // on Expr, error
+                0,  // on Expr?, error
+                0,  // on Factor, error
+                0,  // on OpExpr, error
+                0,  // on OpFactor, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+            ];
+            const __DEFAULT_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // no default reduction
+
+                // State 1
+                0,  // no default reduction
+
+                // State 2
+                0,  // no default reduction
+
+                // State 3
+                -16,  // on any token, reduce `Factor = Term => ActionFn(7);`
+
+                // State 4
+                0,  // no default reduction
+
+                // State 5
+                0,  // no default reduction
+
+                // State 6
+                -21,  // on any token, reduce `Term = Num => ActionFn(10);`
+
+                // State 7
+                0,  // no default reduction
+
+                // State 8
+                -18,  // on any token, reduce `OpExpr = "+" => ActionFn(4);`
+
+                // State 9
+                -17,  // on any token, reduce `OpExpr = "-" => ActionFn(3);`
+
+                // State 10
+                0,  // no default reduction
+
+                // State 11
+                -19,  // on any token, reduce `OpFactor = "*" => ActionFn(8);`
+
+                // State 12
+                -20,  // on any token, reduce `OpFactor = "/" => ActionFn(9);`
+
+                // State 13
+                0,  // no default reduction
+
+                // State 14
+                0,  // no default reduction
+
+                // State 15
+                0,  // no default reduction
+
+                // State 16
+                -14,  // on any token, reduce `Factor = Factor, OpFactor, Term => ActionFn(5);`
+
+                // State 17
+                -22,  // on any token, reduce `Term = "(", Expr, ")" => ActionFn(11);`
+
+                // State 18
+                0,  // no default reduction
+
+                // State 19
+                0,  // no default reduction
+
+                // State 20
+                0,  // no default reduction
+
+                // State 21
+                0,  // no default reduction
+
+                // State 22
+                -15,  // on any token, reduce `Factor = "*", "(", Comma<Expr>, ")" => ActionFn(6);`
+
+                // State 23
+                -4,  // on any token, reduce `(<Expr> ",")+ = Expr, "," => ActionFn(20);`
+
+                // State 24
+                -5,  // on any token, reduce `(<Expr> ",")+ = (<Expr> ",")+, Expr, "," => ActionFn(21);`
+
+            ];
+            fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+                const __TERMINAL: &'static [&'static str] = &[
+                    r###""(""###,
+                    r###"")""###,
+                    r###""*""###,
+                    r###""+""###,
+                    r###"",""###,
+                    r###""-""###,
+                    r###""/""###,
+                    r###"Num"###,
+                ];
+                __ACTION[(__state * 8)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+                    if state == 0 {
+                        None
+                    } else {
+                        Some(terminal.to_string())
+                    }
+                }).collect()
+            }
+            #[allow(dead_code)]
+            pub fn parse_Expr<
+                'ast,
+                __TOKEN: __ToTriple<'ast, Error=&'static str>,
+                __TOKENS: IntoIterator<Item=__TOKEN>,
+            >(
+                arena: &'ast Arena<'ast>,
+                __tokens0: __TOKENS,
+            ) -> Result<&'ast Node<'ast>, __lalrpop_util::ParseError<usize, Tok, &'static str>>
+            {
+                let __tokens = __tokens0.into_iter();
+                let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(arena, 0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() })
+            }
+            #[allow(dead_code)]
+            pub fn parse_Expr_cancelable<
+                'ast,
+                __TOKEN: __ToTriple<'ast, Error=&'static str>,
+                __TOKENS: IntoIterator<Item=__TOKEN>,
+            >(
+                arena: &'ast Arena<'ast>,
+                __tokens0: __TOKENS,
+                __cancel: &::std::sync::atomic::AtomicBool,
+            ) -> Result<&'ast Node<'ast>, __lalrpop_util::ParseError<usize, Tok, &'static str>>
+            {
+                let __tokens = __tokens0.into_iter();
+                let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(arena, 0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() })
+            }
+
+            #[allow(dead_code)]
+            pub struct ExprParser {
+                __states: ::std::vec::Vec<i32>,
+                __symbols: __lalrpop_util::stack::Recycled,
+                __cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+            }
+
+            #[allow(dead_code)]
+            impl ExprParser {
+                pub fn new() -> ExprParser {
+                    ExprParser { __states: vec![], __symbols: __lalrpop_util::stack::Recycled::new(), __cancel: None }
+                }
+
+                pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+                    self.__cancel = Some(flag);
+                }
+
+                pub fn parse_in_place<'ast, __TOKEN: __ToTriple<'ast, Error=&'static str>, __TOKENS: IntoIterator<Item=__TOKEN>>(
+                    &mut self,
+                    arena: &'ast Arena<'ast>,
+                    __tokens0: __TOKENS,
+                ) -> Result<&'ast Node<'ast>, __lalrpop_util::ParseError<usize, Tok, &'static str>>
+                {
+                    let __tokens = __tokens0.into_iter();
+                    let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+                    let mut __symbols = self.__symbols.take();
+                    let __result = __parse::<>(arena, 0, &mut __tokens, &mut self.__states, &mut __symbols, self.__cancel.as_ref().map(|c| &**c)).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() });
+                    self.__symbols.put(__symbols);
+                    __result
+                }
+            }
+
+            fn __parse<
+                'ast,
+            >(
+                arena: &'ast Arena<'ast>,
+                __start_state: i32,
+                mut __tokens: &mut Iterator<Item = Result<(usize, Tok, usize), &'static str>>,
+                mut __states: &mut ::std::vec::Vec<i32>,
+                mut __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>,
+                __cancel: Option<&::std::sync::atomic::AtomicBool>,
+            ) -> Result<__Symbol<'ast>, __lalrpop_util::ParseError<usize, Tok, &'static str>>
+            {
+                __states.clear();
+                __states.push(__start_state);
+                __symbols.clear();
+                let mut __integer;
+                let mut __lookahead;
+                let __last_location: &mut usize = &mut Default::default();
+                let mut __default_reduced = [0_usize; 16];
+                let mut __default_reduced_len = 0;
+                '__shift: loop {
+                    loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __DEFAULT_ACTION[__state] as i32;
+                        if __action == 0 {
+                            break;
+                        }
+                        if __default_reduced_len < 16 {
+                            __default_reduced[__default_reduced_len] = __state;
+                            __default_reduced_len += 1;
+                        }
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(arena, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    }
+                    __lookahead = match __tokens.next() {
+                        Some(Ok(v)) => v,
+                        None => break '__shift,
+                        Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                    };
+                    *__last_location = __lookahead.2.clone();
+                    __integer = match __lookahead.1 {
+                        Tok::LParen if true => 0,
+                        Tok::RParen if true => 1,
+                        Tok::Times if true => 2,
+                        Tok::Plus if true => 3,
+                        Tok::Comma if true => 4,
+                        Tok::Minus if true => 5,
+                        Tok::Div if true => 6,
+                        Tok::Num(_) if true => 7,
+                        _ => {
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| true).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match Some(__lookahead) {
+                                Some(__token) => {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error);
+                        }
+                    };
+                    '__inner: loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __ACTION[__state * 8 + __integer] as i32;
+                        if __action > 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                                }
+                            }
+                            let __symbol = match __integer {
+                                0 => match __lookahead.1 {
+                                    __tok @ Tok::LParen => __Symbol::Term_22_28_22((__tok)),
+                                    _ => unreachable!(),
+                                },
+                                1 => match __lookahead.1 {
+                                    __tok @ Tok::RParen => __Symbol::Term_22_29_22((__tok)),
+                                    _ => unreachable!(),
+                                },
+                                2 => match __lookahead.1 {
+                                    __tok @ Tok::Times => __Symbol::Term_22_2a_22((__tok)),
+                                    _ => unreachable!(),
+                                },
+                                3 => match __lookahead.1 {
+                                    __tok @ Tok::Plus => __Symbol::Term_22_2b_22((__tok)),
+                                    _ => unreachable!(),
+                                },
+                                4 => match __lookahead.1 {
+                                    __tok @ Tok::Comma => __Symbol::Term_22_2c_22((__tok)),
+                                    _ => unreachable!(),
+                                },
+                                5 => match __lookahead.1 {
+                                    __tok @ Tok::Minus => __Symbol::Term_22_2d_22((__tok)),
+                                    _ => unreachable!(),
+                                },
+                                6 => match __lookahead.1 {
+                                    __tok @ Tok::Div => __Symbol::Term_22_2f_22((__tok)),
+                                    _ => unreachable!(),
+                                },
+                                7 => match __lookahead.1 {
+                                    Tok::Num(__tok0) => __Symbol::TermNum((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                _ => unreachable!(),
+                            };
+                            __states.push(__action - 1);
+                            __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                            __default_reduced_len = 0;
+                            continue '__shift;
+                        } else if __action < 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                                }
+                            }
+                            if let Some(r) = __reduce(arena, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                                if r.is_err() {
+                                    return r;
+                                }
+                                return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] });
+                            }
+                        } else {
+                            let mut __err_lookahead = Some(__lookahead);
+                            let mut __err_integer: Option<usize> = Some(__integer);
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 8 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match __err_lookahead {
+                                Some(__token) => {
+                                    if __accepts(arena,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                        __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                    } else {
+                                        __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                    }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error)
+                        }
+                    }
+                }
+                loop {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __action = __EOF_ACTION[__state] as i32;
+                    if __action < 0 {
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(arena, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    } else {
+                        let mut __err_lookahead = None;
+                        let mut __err_integer: Option<usize> = None;
+                        let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 8 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                        let __expected = __expected_tokens(__state);
+                        let __error = match __err_lookahead {
+                            Some(__token) => {
+                                if __accepts(arena,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                    __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                } else {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                            }
+                            None => {
+                                __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                            }
+                        };
+                        return Err(__error)
+                    }
+                }
+            }
+            fn __accepts<
+                'ast,
+            >(
+                arena: &'ast Arena<'ast>,
+                __error_state: i32,
+                __states: & [i32],
+                __opt_integer: Option<usize>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> bool
+            {
+                let mut __states = __states.to_vec();
+                __states.push(__error_state);
+                loop {
+                    let mut __states_len = __states.len();
+                    let __top = __states[__states_len - 1];
+                    let __action = match __opt_integer {
+                        None => __EOF_ACTION[__top as usize] as i32,
+                        Some(__integer) => __ACTION[(__top * 8) as usize + __integer] as i32,
+                    };
+                    if __action == 0 { return false; }
+                    if __action > 0 { return true; }
+                    let (__to_pop, __nt) = match -__action {
+                        // simulate (<Expr> ",") = Expr, "," => ActionFn(17);
+                        1 => {
+                            (2, 0)
+                        }
+                        // simulate (<Expr> ",")* =  => ActionFn(15);
+                        2 => {
+                            (0, 1)
+                        }
+                        // simulate (<Expr> ",")* = (<Expr> ",")+ => ActionFn(16);
+                        3 => {
+                            (1, 1)
+                        }
+                        // simulate (<Expr> ",")+ = Expr, "," => ActionFn(20);
+                        4 => {
+                            (2, 2)
+                        }
+                        // simulate (<Expr> ",")+ = (<Expr> ",")+, Expr, "," => ActionFn(21);
+                        5 => {
+                            (3, 2)
+                        }
+                        // simulate Comma<Expr> = Expr => ActionFn(24);
+                        6 => {
+                            (1, 3)
+                        }
+                        // simulate Comma<Expr> =  => ActionFn(25);
+                        7 => {
+                            (0, 3)
+                        }
+                        // simulate Comma<Expr> = (<Expr> ",")+, Expr => ActionFn(26);
+                        8 => {
+                            (2, 3)
+                        }
+                        // simulate Comma<Expr> = (<Expr> ",")+ => ActionFn(27);
+                        9 => {
+                            (1, 3)
+                        }
+                        // simulate Expr = Expr, OpExpr, Factor => ActionFn(1);
+                        10 => {
+                            (3, 4)
+                        }
+                        // simulate Expr = Factor => ActionFn(2);
+                        11 => {
+                            (1, 4)
+                        }
+                        // simulate Expr? = Expr => ActionFn(13);
+                        12 => {
+                            (1, 5)
+                        }
+                        // simulate Expr? =  => ActionFn(14);
+                        13 => {
+                            (0, 5)
+                        }
+                        // simulate Factor = Factor, OpFactor, Term => ActionFn(5);
+                        14 => {
+                            (3, 6)
+                        }
+                        // simulate Factor = "*", "(", Comma<Expr>, ")" => ActionFn(6);
+                        15 => {
+                            (4, 6)
+                        }
+                        // simulate Factor = Term => ActionFn(7);
+                        16 => {
+                            (1, 6)
+                        }
+                        // simulate OpExpr = "-" => ActionFn(3);
+                        17 => {
+                            (1, 7)
+                        }
+                        // simulate OpExpr = "+" => ActionFn(4);
+                        18 => {
+                            (1, 7)
+                        }
+                        // simulate OpFactor = "*" => ActionFn(8);
+                        19 => {
+                            (1, 8)
+                        }
+                        // simulate OpFactor = "/" => ActionFn(9);
+                        20 => {
+                            (1, 8)
+                        }
+                        // simulate Term = Num => ActionFn(10);
+                        21 => {
+                            (1, 9)
+                        }
+                        // simulate Term = "(", Expr, ")" => ActionFn(11);
+                        22 => {
+                            (3, 9)
+                        }
+                        // simulate __Expr = Expr => ActionFn(0);
+                        23 => return true,
+                        _ => panic!("invalid action code {}", __action)
+                    };
+                    __states_len -= __to_pop;
+                    __states.truncate(__states_len);
+                    let __top = __states[__states_len - 1];
+                    let __next_state = __GOTO[(__top * 11 + __nt) as usize] as i32 - 1;
+                    __states.push(__next_state);
+                }
+            }
+            pub fn __reduce<
+                'ast,
+            >(
+                arena: &'ast Arena<'ast>,
+                __action: i32,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Option<Result<__Symbol<'ast>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+            {
+                let __nonterminal = match -__action {
+                    1 => match __reduce__28_3cExpr_3e_20_22_2c_22_29_0(arena, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    2 => match __reduce__28_3cExpr_3e_20_22_2c_22_29_2a_0(arena, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    3 => match __reduce__28_3cExpr_3e_20_22_2c_22_29_2a_1(arena, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    4 => match __reduce__28_3cExpr_3e_20_22_2c_22_29_2b_0(arena, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    5 => match __reduce__28_3cExpr_3e_20_22_2c_22_29_2b_1(arena, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    6 => match __reduce_Comma_3cExpr_3e_0(arena, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    7 => match __reduce_Comma_3cExpr_3e_1(arena, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    8 => match __reduce_Comma_3cExpr_3e_2(arena, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    9 => match __reduce_Comma_3cExpr_3e_3(arena, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    10 => match __reduce_Expr_0(arena, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    11 => match __reduce_Expr_1(arena, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    12 => match __reduce_Expr_3f_0(arena, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    13 => match __reduce_Expr_3f_1(arena, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    14 => match __reduce_Factor_0(arena, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    15 => match __reduce_Factor_1(arena, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    16 => match __reduce_Factor_2(arena, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    17 => match __reduce_OpExpr_0(arena, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    18 => match __reduce_OpExpr_1(arena, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    19 => match __reduce_OpFactor_0(arena, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    20 => match __reduce_OpFactor_1(arena, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    21 => match __reduce_Term_0(arena, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    22 => match __reduce_Term_1(arena, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    23 => match __reduce_____Expr_0(arena, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    _ => panic!("invalid action code {}", __action)
+                };
+                let __state = *__states.last().unwrap() as usize;
+                let __next_state = __GOTO[__state * 11 + __nonterminal] as i32 - 1;
+                __states.push(__next_state);
+                None
+            }
+
+            fn __reduce__28_3cExpr_3e_20_22_2c_22_29_0<
+                'ast,
+            >(
+                arena: &'ast Arena<'ast>,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'ast>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+            {
+                // (<Expr> ",") = Expr, "," => ActionFn(17);
+                let __sym1 = __pop_Term_22_2c_22(__symbols);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action17::<>(arena, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_3cExpr_3e_20_22_2c_22_29(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce__28_3cExpr_3e_20_22_2c_22_29_2a_0<
+                'ast,
+            >(
+                arena: &'ast Arena<'ast>,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'ast>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+            {
+                // (<Expr> ",")* =  => ActionFn(15);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action15::<>(arena, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_28_3cExpr_3e_20_22_2c_22_29_2a(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce__28_3cExpr_3e_20_22_2c_22_29_2a_1<
+                'ast,
+            >(
+                arena: &'ast Arena<'ast>,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'ast>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+            {
+                // (<Expr> ",")* = (<Expr> ",")+ => ActionFn(16);
+                let __sym0 = __pop_Nt_28_3cExpr_3e_20_22_2c_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action16::<>(arena, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::Nt_28_3cExpr_3e_20_22_2c_22_29_2a(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce__28_3cExpr_3e_20_22_2c_22_29_2b_0<
+                'ast,
+            >(
+                arena: &'ast Arena<'ast>,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'ast>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+            {
+                // (<Expr> ",")+ = Expr, "," => ActionFn(20);
+                let __sym1 = __pop_Term_22_2c_22(__symbols);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action20::<>(arena, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_3cExpr_3e_20_22_2c_22_29_2b(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce__28_3cExpr_3e_20_22_2c_22_29_2b_1<
+                'ast,
+            >(
+                arena: &'ast Arena<'ast>,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'ast>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+            {
+                // (<Expr> ",")+ = (<Expr> ",")+, Expr, "," => ActionFn(21);
+                let __sym2 = __pop_Term_22_2c_22(__symbols);
+                let __sym1 = __pop_NtExpr(__symbols);
+                let __sym0 = __pop_Nt_28_3cExpr_3e_20_22_2c_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action21::<>(arena, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::Nt_28_3cExpr_3e_20_22_2c_22_29_2b(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce_Comma_3cExpr_3e_0<
+                'ast,
+            >(
+                arena: &'ast Arena<'ast>,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'ast>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+            {
+                // Comma<Expr> = Expr => ActionFn(24);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action24::<>(arena, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtComma_3cExpr_3e(__nt), __end));
+                Ok(3)
+            }
+
+            fn __reduce_Comma_3cExpr_3e_1<
+                'ast,
+            >(
+                arena: &'ast Arena<'ast>,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'ast>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+            {
+                // Comma<Expr> =  => ActionFn(25);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action25::<>(arena, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::NtComma_3cExpr_3e(__nt), __end));
+                Ok(3)
+            }
+
+            fn __reduce_Comma_3cExpr_3e_2<
+                'ast,
+            >(
+                arena: &'ast Arena<'ast>,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'ast>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+            {
+                // Comma<Expr> = (<Expr> ",")+, Expr => ActionFn(26);
+                let __sym1 = __pop_NtExpr(__symbols);
+                let __sym0 = __pop_Nt_28_3cExpr_3e_20_22_2c_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action26::<>(arena, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtComma_3cExpr_3e(__nt), __end));
+                Ok(3)
+            }
+
+            fn __reduce_Comma_3cExpr_3e_3<
+                'ast,
+            >(
+                arena: &'ast Arena<'ast>,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'ast>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+            {
+                // Comma<Expr> = (<Expr> ",")+ => ActionFn(27);
+                let __sym0 = __pop_Nt_28_3cExpr_3e_20_22_2c_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action27::<>(arena, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtComma_3cExpr_3e(__nt), __end));
+                Ok(3)
+            }
+
+            fn __reduce_Expr_0<
+                'ast,
+            >(
+                arena: &'ast Arena<'ast>,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'ast>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+            {
+                // Expr = Expr, OpExpr, Factor => ActionFn(1);
+                let __sym2 = __pop_NtFactor(__symbols);
+                let __sym1 = __pop_NtOpExpr(__symbols);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action1::<>(arena, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+                Ok(4)
+            }
+
+            fn __reduce_Expr_1<
+                'ast,
+            >(
+                arena: &'ast Arena<'ast>,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'ast>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+            {
+                // Expr = Factor => ActionFn(2);
+                let __sym0 = __pop_NtFactor(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action2::<>(arena, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+                Ok(4)
+            }
+
+            fn __reduce_Expr_3f_0<
+                'ast,
+            >(
+                arena: &'ast Arena<'ast>,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'ast>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+            {
+                // Expr? = Expr => ActionFn(13);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action13::<>(arena, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtExpr_3f(__nt), __end));
+                Ok(5)
+            }
+
+            fn __reduce_Expr_3f_1<
+                'ast,
+            >(
+                arena: &'ast Arena<'ast>,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'ast>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+            {
+                // Expr? =  => ActionFn(14);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action14::<>(arena, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::NtExpr_3f(__nt), __end));
+                Ok(5)
+            }
+
+            fn __reduce_Factor_0<
+                'ast,
+            >(
+                arena: &'ast Arena<'ast>,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'ast>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+            {
+                // Factor = Factor, OpFactor, Term => ActionFn(5);
+                let __sym2 = __pop_NtTerm(__symbols);
+                let __sym1 = __pop_NtOpFactor(__symbols);
+                let __sym0 = __pop_NtFactor(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action5::<>(arena, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtFactor(__nt), __end));
+                Ok(6)
+            }
+
+            fn __reduce_Factor_1<
+                'ast,
+            >(
+                arena: &'ast Arena<'ast>,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'ast>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+            {
+                // Factor = "*", "(", Comma<Expr>, ")" => ActionFn(6);
+                let __sym3 = __pop_Term_22_29_22(__symbols);
+                let __sym2 = __pop_NtComma_3cExpr_3e(__symbols);
+                let __sym1 = __pop_Term_22_28_22(__symbols);
+                let __sym0 = __pop_Term_22_2a_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::super::super::__action6::<>(arena, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtFactor(__nt), __end));
+                Ok(6)
+            }
+
+            fn __reduce_Factor_2<
+                'ast,
+            >(
+                arena: &'ast Arena<'ast>,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'ast>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+            {
+                // Factor = Term => ActionFn(7);
+                let __sym0 = __pop_NtTerm(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action7::<>(arena, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtFactor(__nt), __end));
+                Ok(6)
+            }
+
+            fn __reduce_OpExpr_0<
+                'ast,
+            >(
+                arena: &'ast Arena<'ast>,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'ast>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+            {
+                // OpExpr = "-" => ActionFn(3);
+                let __sym0 = __pop_Term_22_2d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action3::<>(arena, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtOpExpr(__nt), __end));
+                Ok(7)
+            }
+
+            fn __reduce_OpExpr_1<
+                'ast,
+            >(
+                arena: &'ast Arena<'ast>,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'ast>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+            {
+                // OpExpr = "+" => ActionFn(4);
+                let __sym0 = __pop_Term_22_2b_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action4::<>(arena, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtOpExpr(__nt), __end));
+                Ok(7)
+            }
+
+            fn __reduce_OpFactor_0<
+                'ast,
+            >(
+                arena: &'ast Arena<'ast>,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'ast>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+            {
+                // OpFactor = "*" => ActionFn(8);
+                let __sym0 = __pop_Term_22_2a_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action8::<>(arena, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtOpFactor(__nt), __end));
+                Ok(8)
+            }
+
+            fn __reduce_OpFactor_1<
+                'ast,
+            >(
+                arena: &'ast Arena<'ast>,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'ast>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+            {
+                // OpFactor = "/" => ActionFn(9);
+                let __sym0 = __pop_Term_22_2f_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action9::<>(arena, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtOpFactor(__nt), __end));
+                Ok(8)
+            }
+
+            fn __reduce_Term_0<
+                'ast,
+            >(
+                arena: &'ast Arena<'ast>,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'ast>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+            {
+                // Term = Num => ActionFn(10);
+                let __sym0 = __pop_TermNum(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action10::<>(arena, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+                Ok(9)
+            }
+
+            fn __reduce_Term_1<
+                'ast,
+            >(
+                arena: &'ast Arena<'ast>,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'ast>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+            {
+                // Term = "(", Expr, ")" => ActionFn(11);
+                let __sym2 = __pop_Term_22_29_22(__symbols);
+                let __sym1 = __pop_NtExpr(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action11::<>(arena, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+                Ok(9)
+            }
+
+            fn __reduce_____Expr_0<
+                'ast,
+            >(
+                arena: &'ast Arena<'ast>,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'ast>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+            {
+                // __Expr = Expr => ActionFn(0);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action0::<>(arena, __sym0);
+                Err(Ok(__Symbol::Nt____Expr(__nt)))
+            }
+            fn __pop_Term_22_28_22<
+              'ast,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>
+            ) -> (usize, Tok, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_28_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_29_22<
+              'ast,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>
+            ) -> (usize, Tok, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_29_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_2a_22<
+              'ast,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>
+            ) -> (usize, Tok, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2a_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_2b_22<
+              'ast,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>
+            ) -> (usize, Tok, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2b_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_2c_22<
+              'ast,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>
+            ) -> (usize, Tok, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2c_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_2d_22<
+              'ast,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>
+            ) -> (usize, Tok, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2d_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_2f_22<
+              'ast,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>
+            ) -> (usize, Tok, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2f_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_TermNum<
+              'ast,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>
+            ) -> (usize, i32, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::TermNum(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_28_3cExpr_3e_20_22_2c_22_29<
+              'ast,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>
+            ) -> (usize, &'ast Node<'ast>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_28_3cExpr_3e_20_22_2c_22_29(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_28_3cExpr_3e_20_22_2c_22_29_2a<
+              'ast,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>
+            ) -> (usize, ::std::vec::Vec<&'ast Node<'ast>>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_28_3cExpr_3e_20_22_2c_22_29_2a(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_28_3cExpr_3e_20_22_2c_22_29_2b<
+              'ast,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>
+            ) -> (usize, ::std::vec::Vec<&'ast Node<'ast>>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_28_3cExpr_3e_20_22_2c_22_29_2b(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtComma_3cExpr_3e<
+              'ast,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>
+            ) -> (usize, Vec<&'ast Node<'ast>>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtComma_3cExpr_3e(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtExpr<
+              'ast,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>
+            ) -> (usize, &'ast Node<'ast>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtExpr(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtExpr_3f<
+              'ast,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>
+            ) -> (usize, ::std::option::Option<&'ast Node<'ast>>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtExpr_3f(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtFactor<
+              'ast,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>
+            ) -> (usize, &'ast Node<'ast>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtFactor(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtOpExpr<
+              'ast,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>
+            ) -> (usize, Op, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtOpExpr(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtOpFactor<
+              'ast,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>
+            ) -> (usize, Op, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtOpFactor(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtTerm<
+              'ast,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>
+            ) -> (usize, &'ast Node<'ast>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtTerm(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt____Expr<
+              'ast,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'ast>,usize)>
+            ) -> (usize, &'ast Node<'ast>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt____Expr(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+        }
+        pub use self::__parse_table::parse_Expr;
+        pub use self::__parse_table::parse_Expr_cancelable;
+        pub use self::__parse_table::ExprParser;
+    }
+    pub use self::__parse_table::parse_Expr_cancelable;
+    pub use self::__parse_table::ExprParser;
+}
+pub use self::__parse__Expr::parse_Expr;
+pub use self::__parse__Expr::parse_Expr_cancelable;
+pub use self::__parse__Expr::ExprParser;
+
+#[allow(unused_variables)]
+fn __action0<
+    'ast,
+>(
+    arena: &'ast Arena<'ast>,
+    (_, __0, _): (usize, &'ast Node<'ast>, usize),
+) -> &'ast Node<'ast>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'ast,
+>(
+    arena: &'ast Arena<'ast>,
+    (_, l, _): (usize, &'ast Node<'ast>, usize),
+    (_, op, _): (usize, Op, usize),
+    (_, r, _): (usize, &'ast Node<'ast>, usize),
+) -> &'ast Node<'ast>
+{
+    //@ src/expr_arena.lalrpop:22:40 (1 lines)
+    arena.alloc(Node::Binary { l:l, op:op, r:r })
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'ast,
+>(
+    arena: &'ast Arena<'ast>,
+    (_, __0, _): (usize, &'ast Node<'ast>, usize),
+) -> &'ast Node<'ast>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'ast,
+>(
+    arena: &'ast Arena<'ast>,
+    (_, __0, _): (usize, Tok, usize),
+) -> Op
+{
+    //@ src/expr_arena.lalrpop:27:12 (1 lines)
+    Op::Sub
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'ast,
+>(
+    arena: &'ast Arena<'ast>,
+    (_, __0, _): (usize, Tok, usize),
+) -> Op
+{
+    //@ src/expr_arena.lalrpop:28:12 (1 lines)
+    Op::Add
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'ast,
+>(
+    arena: &'ast Arena<'ast>,
+    (_, l, _): (usize, &'ast Node<'ast>, usize),
+    (_, op, _): (usize, Op, usize),
+    (_, r, _): (usize, &'ast Node<'ast>, usize),
+) -> &'ast Node<'ast>
+{
+    //@ src/expr_arena.lalrpop:37:42 (1 lines)
+    arena.alloc(Node::Binary { l:l, op:op, r:r })
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'ast,
+>(
+    arena: &'ast Arena<'ast>,
+    (_, _, _): (usize, Tok, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, __0, _): (usize, Vec<&'ast Node<'ast>>, usize),
+    (_, _, _): (usize, Tok, usize),
+) -> &'ast Node<'ast>
+{
+    //@ src/expr_arena.lalrpop:38:34 (1 lines)
+    arena.alloc(Node::Reduce(Op::Mul, __0))
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'ast,
+>(
+    arena: &'ast Arena<'ast>,
+    (_, __0, _): (usize, &'ast Node<'ast>, usize),
+) -> &'ast Node<'ast>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'ast,
+>(
+    arena: &'ast Arena<'ast>,
+    (_, __0, _): (usize, Tok, usize),
+) -> Op
+{
+    //@ src/expr_arena.lalrpop:43:12 (1 lines)
+    Op::Mul
+}
+
+#[allow(unused_variables)]
+fn __action9<
+    'ast,
+>(
+    arena: &'ast Arena<'ast>,
+    (_, __0, _): (usize, Tok, usize),
+) -> Op
+{
+    //@ src/expr_arena.lalrpop:44:12 (1 lines)
+    Op::Div
+}
+
+#[allow(unused_variables)]
+fn __action10<
+    'ast,
+>(
+    arena: &'ast Arena<'ast>,
+    (_, n, _): (usize, i32, usize),
+) -> &'ast Node<'ast>
+{
+    //@ src/expr_arena.lalrpop:48:16 (1 lines)
+    arena.alloc(Node::Value(n))
+}
+
+#[allow(unused_variables)]
+fn __action11<
+    'ast,
+>(
+    arena: &'ast Arena<'ast>,
+    (_, _, _): (usize, Tok, usize),
+    (_, __0, _): (usize, &'ast Node<'ast>, usize),
+    (_, _, _): (usize, Tok, usize),
+) -> &'ast Node<'ast>
+{
+    //@ src/expr_arena.lalrpop:49:23 (1 lines)
+    arena.alloc(Node::Paren(__0))
+}
+
+#[allow(unused_variables)]
+fn __action12<
+    'ast,
+>(
+    arena: &'ast Arena<'ast>,
+    (_, h, _): (usize, ::std::vec::Vec<&'ast Node<'ast>>, usize),
+    (_, t, _): (usize, ::std::option::Option<&'ast Node<'ast>>, usize),
+) -> Vec<&'ast Node<'ast>>
+{
+    h.into_iter().chain(t).collect()
+}
+
+#[allow(unused_variables)]
+fn __action13<
+    'ast,
+>(
+    arena: &'ast Arena<'ast>,
+    (_, __0, _): (usize, &'ast Node<'ast>, usize),
+) -> ::std::option::Option<&'ast Node<'ast>>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action14<
+    'ast,
+>(
+    arena: &'ast Arena<'ast>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::option::Option<&'ast Node<'ast>>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action15<
+    'ast,
+>(
+    arena: &'ast Arena<'ast>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::vec::Vec<&'ast Node<'ast>>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+fn __action16<
+    'ast,
+>(
+    arena: &'ast Arena<'ast>,
+    (_, v, _): (usize, ::std::vec::Vec<&'ast Node<'ast>>, usize),
+) -> ::std::vec::Vec<&'ast Node<'ast>>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action17<
+    'ast,
+>(
+    arena: &'ast Arena<'ast>,
+    (_, __0, _): (usize, &'ast Node<'ast>, usize),
+    (_, _, _): (usize, Tok, usize),
+) -> &'ast Node<'ast>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action18<
+    'ast,
+>(
+    arena: &'ast Arena<'ast>,
+    (_, __0, _): (usize, &'ast Node<'ast>, usize),
+) -> ::std::vec::Vec<&'ast Node<'ast>>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action19<
+    'ast,
+>(
+    arena: &'ast Arena<'ast>,
+    (_, v, _): (usize, ::std::vec::Vec<&'ast Node<'ast>>, usize),
+    (_, e, _): (usize, &'ast Node<'ast>, usize),
+) -> ::std::vec::Vec<&'ast Node<'ast>>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action20<
+    'ast,
+>(
+    arena: &'ast Arena<'ast>,
+    __0: (usize, &'ast Node<'ast>, usize),
+    __1: (usize, Tok, usize),
+) -> ::std::vec::Vec<&'ast Node<'ast>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action17(
+        arena,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action18(
+        arena,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action21<
+    'ast,
+>(
+    arena: &'ast Arena<'ast>,
+    __0: (usize, ::std::vec::Vec<&'ast Node<'ast>>, usize),
+    __1: (usize, &'ast Node<'ast>, usize),
+    __2: (usize, Tok, usize),
+) -> ::std::vec::Vec<&'ast Node<'ast>>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action17(
+        arena,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action19(
+        arena,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action22<
+    'ast,
+>(
+    arena: &'ast Arena<'ast>,
+    __0: (usize, ::std::option::Option<&'ast Node<'ast>>, usize),
+) -> Vec<&'ast Node<'ast>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action15(
+        arena,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action12(
+        arena,
+        __temp0,
+        __0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action23<
+    'ast,
+>(
+    arena: &'ast Arena<'ast>,
+    __0: (usize, ::std::vec::Vec<&'ast Node<'ast>>, usize),
+    __1: (usize, ::std::option::Option<&'ast Node<'ast>>, usize),
+) -> Vec<&'ast Node<'ast>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action16(
+        arena,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action12(
+        arena,
+        __temp0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action24<
+    'ast,
+>(
+    arena: &'ast Arena<'ast>,
+    __0: (usize, &'ast Node<'ast>, usize),
+) -> Vec<&'ast Node<'ast>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action13(
+        arena,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action22(
+        arena,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action25<
+    'ast,
+>(
+    arena: &'ast Arena<'ast>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<&'ast Node<'ast>>
+{
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action14(
+        arena,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action22(
+        arena,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action26<
+    'ast,
+>(
+    arena: &'ast Arena<'ast>,
+    __0: (usize, ::std::vec::Vec<&'ast Node<'ast>>, usize),
+    __1: (usize, &'ast Node<'ast>, usize),
+) -> Vec<&'ast Node<'ast>>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action13(
+        arena,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action23(
+        arena,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action27<
+    'ast,
+>(
+    arena: &'ast Arena<'ast>,
+    __0: (usize, ::std::vec::Vec<&'ast Node<'ast>>, usize),
+) -> Vec<&'ast Node<'ast>>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action14(
+        arena,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action23(
+        arena,
+        __0,
+        __temp0,
+    )
+}
+
+pub trait __ToTriple<'ast, > {
+    type Error;
+    fn to_triple(value: Self) -> Result<(usize,Tok,usize),Self::Error>;
+}
+
+impl<'ast, > __ToTriple<'ast, > for (usize, Tok, usize) {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Tok,usize),&'static str> {
+        Ok(value)
+    }
+}
+impl<'ast, > __ToTriple<'ast, > for Result<(usize, Tok, usize),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Tok,usize),&'static str> {
+        value
+    }
+}
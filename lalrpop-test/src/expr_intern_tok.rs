@@ -0,0 +1,2915 @@
+// auto-generated: "lalrpop 0.15.0"
+use std::str::FromStr;
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+
+mod __parse__Expr {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    use std::str::FromStr;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__intern_token::Token;
+    #[allow(dead_code)]
+    pub fn parse_Expr<
+        'input,
+    >(
+        scale: i32,
+        input: &'input str,
+    ) -> Result<i32, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let __ascent = __ascent::parse_Expr(
+            scale,
+            input,
+        );
+        let __parse_table = __parse_table::parse_Expr(
+            scale,
+            input,
+        );
+        assert_eq!(__ascent, __parse_table);
+        return __ascent;
+    }
+    mod __ascent {
+
+        mod __parse__Expr {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use std::str::FromStr;
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__intern_token::Token;
+            #[allow(dead_code)]
+            pub fn parse_Expr<
+                'input,
+            >(
+                scale: i32,
+                input: &'input str,
+            ) -> Result<i32, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let __last_location = ::std::cell::RefCell::new(Default::default());
+                let mut __tokens = __tokens.inspect(|t| if let Ok(ref t) = *t { *__last_location.borrow_mut() = t.2.clone(); });
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                let mut __extra = None;
+                let __result = __state0(scale, input, &mut __tokens, __lookahead, &mut __extra, ::std::marker::PhantomData::<()>);
+                if let Some((__token, __expected)) = __extra {
+                    return Err(match __result {
+                        Ok(_) => __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected },
+                        Err(_) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                    });
+                }
+                match __result {
+                    Ok((Some(__lookahead), _)) => {
+                        Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] })
+                    }
+                    Ok((None, __Nonterminal::____Expr((_, __nt, _)))) => {
+                        Ok(__nt)
+                    }
+                    Ok(_) => unreachable!(),
+                    Err(__lalrpop_util::ParseError::UnrecognizedEof { expected: __expected, .. }) => {
+                        Err(__lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.borrow().clone(), expected: __expected })
+                    }
+                    Err(__error) => Err(__error),
+                }
+            }
+
+            #[allow(dead_code)]
+            pub enum __Nonterminal<>
+             {
+                Expr((usize, i32, usize)),
+                Factor((usize, i32, usize)),
+                Num((usize, i32, usize)),
+                Term((usize, i32, usize)),
+                ____Expr((usize, i32, usize)),
+            }
+            impl<> ::std::fmt::Debug for __Nonterminal<>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Nonterminal::Expr(..) => __f.write_str("Expr"),
+                        __Nonterminal::Factor(..) => __f.write_str("Factor"),
+                        __Nonterminal::Num(..) => __f.write_str("Num"),
+                        __Nonterminal::Term(..) => __f.write_str("Term"),
+                        __Nonterminal::____Expr(..) => __f.write_str("__Expr"),
+                    }
+                }
+            }
+
+            // State 0
+            //     AllInputs = []
+            //     OptionalInputs = []
+            //     FixedInputs = []
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Expr = (*) Expr "+" Factor ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Expr = (*) Expr "-" Factor ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Expr = (*) Factor ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Factor = (*) Factor "*" Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Factor = (*) Factor "/" Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Factor = (*) Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Num = (*) r#"[0-9]+"# ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Term = (*) Num ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     __Expr = (*) Expr ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //
+            //   "(" -> S5
+            //   r#"[0-9]+"# -> S6
+            //
+            //     Expr -> S1
+            //     Factor -> S2
+            //     Num -> S3
+            //     Term -> S4
+            fn __state0<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                scale: i32,
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(scale, input, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(scale, input, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Expr(__sym0) => {
+                            __result = try!(__state1(scale, input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Factor(__sym0) => {
+                            __result = try!(__state2(scale, input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Num(__sym0) => {
+                            __result = try!(__state3(scale, input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Term(__sym0) => {
+                            __result = try!(__state4(scale, input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 1
+            //     AllInputs = [Expr]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Expr = Expr (*) "+" Factor ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Expr = Expr (*) "-" Factor ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     __Expr = Expr (*) ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //
+            //   "+" -> S7
+            //   "-" -> S8
+            //   [EOF] -> __Expr = Expr => ActionFn(0);
+            //
+            fn __state1<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                scale: i32,
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, i32, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, Token(4, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(scale, input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, Token(5, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state8(scale, input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(scale, input, __sym0);
+                        let __nt = __Nonterminal::____Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(scale, input, __sym0);
+                        let __nt = __Nonterminal::____Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 2
+            //     AllInputs = [Factor]
+            //     OptionalInputs = []
+            //     FixedInputs = [Factor]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Expr = Factor (*) ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Factor = Factor (*) "*" Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Factor = Factor (*) "/" Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //
+            //   "*" -> S9
+            //   "/" -> S10
+            //   [")", "+", "-", EOF] -> Expr = Factor => ActionFn(3);
+            //
+            fn __state2<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                scale: i32,
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, i32, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, Token(3, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state9(scale, input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state10(scale, input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((_, Token(2, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action3::<>(scale, input, __sym0);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action3::<>(scale, input, __sym0);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 3
+            //     AllInputs = [Num]
+            //     OptionalInputs = []
+            //     FixedInputs = [Num]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Term)
+            //
+            //     Term = Num (*) ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //
+            //   [")", "*", "+", "-", "/", EOF] -> Term = Num => ActionFn(7);
+            //
+            fn __state3<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                scale: i32,
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, i32, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((_, Token(2, _), _)) |
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action7::<>(scale, input, __sym0);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action7::<>(scale, input, __sym0);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 4
+            //     AllInputs = [Term]
+            //     OptionalInputs = []
+            //     FixedInputs = [Term]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Factor)
+            //
+            //     Factor = Term (*) ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //
+            //   [")", "*", "+", "-", "/", EOF] -> Factor = Term => ActionFn(6);
+            //
+            fn __state4<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                scale: i32,
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, i32, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((_, Token(2, _), _)) |
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action6::<>(scale, input, __sym0);
+                        let __nt = __Nonterminal::Factor((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action6::<>(scale, input, __sym0);
+                        let __nt = __Nonterminal::Factor((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 5
+            //     AllInputs = ["("]
+            //     OptionalInputs = []
+            //     FixedInputs = ["("]
+            //     WillPushLen = 2
+            //     WillPush = [Expr, ")"]
+            //     WillProduce = Some(Term)
+            //
+            //     Expr = (*) Expr "+" Factor ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Expr = (*) Expr "-" Factor ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Expr = (*) Factor ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Factor = (*) Factor "*" Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Factor = (*) Factor "/" Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Factor = (*) Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Num = (*) r#"[0-9]+"# ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Term = (*) Num ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Term = "(" (*) Expr ")" ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //
+            //   "(" -> S5
+            //   r#"[0-9]+"# -> S6
+            //
+            //     Expr -> S11
+            //     Factor -> S2
+            //     Num -> S3
+            //     Term -> S4
+            fn __state5<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                scale: i32,
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                let __sym0 = &mut Some(__sym0);
+                match __lookahead {
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(scale, input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(scale, input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    if __sym0.is_none() {
+                        return Ok(__result);
+                    }
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Expr(__sym1) => {
+                            __result = try!(__state11(scale, input, __tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Factor(__sym1) => {
+                            __result = try!(__state2(scale, input, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Num(__sym1) => {
+                            __result = try!(__state3(scale, input, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Term(__sym1) => {
+                            __result = try!(__state4(scale, input, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 6
+            //     AllInputs = [r#"[0-9]+"#]
+            //     OptionalInputs = []
+            //     FixedInputs = [r#"[0-9]+"#]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Num)
+            //
+            //     Num = r#"[0-9]+"# (*) ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //
+            //   [")", "*", "+", "-", "/", EOF] -> Num = r#"[0-9]+"# => ActionFn(9);
+            //
+            fn __state6<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                scale: i32,
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(2, _), _)) |
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action9::<>(scale, input, __sym0);
+                        let __nt = __Nonterminal::Num((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action9::<>(scale, input, __sym0);
+                        let __nt = __Nonterminal::Num((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 7
+            //     AllInputs = [Expr, "+"]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr, "+"]
+            //     WillPushLen = 1
+            //     WillPush = [Factor]
+            //     WillProduce = Some(Expr)
+            //
+            //     Expr = Expr "+" (*) Factor ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Factor = (*) Factor "*" Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Factor = (*) Factor "/" Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Factor = (*) Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Num = (*) r#"[0-9]+"# ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Term = (*) Num ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //
+            //   "(" -> S5
+            //   r#"[0-9]+"# -> S6
+            //
+            //     Factor -> S12
+            //     Num -> S3
+            //     Term -> S4
+            fn __state7<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                scale: i32,
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, i32, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                let __sym0 = &mut Some(__sym0);
+                let __sym1 = &mut Some(__sym1);
+                match __lookahead {
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(scale, input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(scale, input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    if __sym1.is_none() {
+                        return Ok(__result);
+                    }
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Factor(__sym2) => {
+                            __result = try!(__state12(scale, input, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Num(__sym2) => {
+                            __result = try!(__state3(scale, input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Term(__sym2) => {
+                            __result = try!(__state4(scale, input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 8
+            //     AllInputs = [Expr, "-"]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr, "-"]
+            //     WillPushLen = 1
+            //     WillPush = [Factor]
+            //     WillProduce = Some(Expr)
+            //
+            //     Expr = Expr "-" (*) Factor ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Factor = (*) Factor "*" Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Factor = (*) Factor "/" Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Factor = (*) Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Num = (*) r#"[0-9]+"# ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Term = (*) Num ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //
+            //   "(" -> S5
+            //   r#"[0-9]+"# -> S6
+            //
+            //     Factor -> S13
+            //     Num -> S3
+            //     Term -> S4
+            fn __state8<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                scale: i32,
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, i32, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                let __sym0 = &mut Some(__sym0);
+                let __sym1 = &mut Some(__sym1);
+                match __lookahead {
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(scale, input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(scale, input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    if __sym1.is_none() {
+                        return Ok(__result);
+                    }
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Factor(__sym2) => {
+                            __result = try!(__state13(scale, input, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Num(__sym2) => {
+                            __result = try!(__state3(scale, input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Term(__sym2) => {
+                            __result = try!(__state4(scale, input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 9
+            //     AllInputs = [Factor, "*"]
+            //     OptionalInputs = []
+            //     FixedInputs = [Factor, "*"]
+            //     WillPushLen = 1
+            //     WillPush = [Term]
+            //     WillProduce = Some(Factor)
+            //
+            //     Factor = Factor "*" (*) Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Num = (*) r#"[0-9]+"# ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Term = (*) Num ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //
+            //   "(" -> S5
+            //   r#"[0-9]+"# -> S6
+            //
+            //     Num -> S3
+            //     Term -> S14
+            fn __state9<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                scale: i32,
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, i32, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(scale, input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(scale, input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Num(__sym2) => {
+                            __result = try!(__state3(scale, input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Term(__sym2) => {
+                            __result = try!(__state14(scale, input, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 10
+            //     AllInputs = [Factor, "/"]
+            //     OptionalInputs = []
+            //     FixedInputs = [Factor, "/"]
+            //     WillPushLen = 1
+            //     WillPush = [Term]
+            //     WillProduce = Some(Factor)
+            //
+            //     Factor = Factor "/" (*) Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Num = (*) r#"[0-9]+"# ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Term = (*) Num ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //
+            //   "(" -> S5
+            //   r#"[0-9]+"# -> S6
+            //
+            //     Num -> S3
+            //     Term -> S15
+            fn __state10<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                scale: i32,
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, i32, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(scale, input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(scale, input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Num(__sym2) => {
+                            __result = try!(__state3(scale, input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Term(__sym2) => {
+                            __result = try!(__state15(scale, input, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 11
+            //     AllInputs = ["(", Expr]
+            //     OptionalInputs = ["("]
+            //     FixedInputs = [Expr]
+            //     WillPushLen = 1
+            //     WillPush = [")"]
+            //     WillProduce = None
+            //
+            //     Expr = Expr (*) "+" Factor ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Expr = Expr (*) "-" Factor ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Term = "(" Expr (*) ")" ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //
+            //   ")" -> S16
+            //   "+" -> S7
+            //   "-" -> S8
+            //
+            fn __state11<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                scale: i32,
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: &mut Option<(usize, &'input str, usize)>,
+                __sym1: (usize, i32, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        let __sym0 = __sym0.take().unwrap();
+                        __result = try!(__state16(scale, input, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, Token(4, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(scale, input, __tokens, __extra, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, Token(5, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state8(scale, input, __tokens, __extra, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 12
+            //     AllInputs = [Expr, "+", Factor]
+            //     OptionalInputs = [Expr, "+"]
+            //     FixedInputs = [Factor]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Expr = Expr "+" Factor (*) ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Factor = Factor (*) "*" Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Factor = Factor (*) "/" Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //
+            //   "*" -> S9
+            //   "/" -> S10
+            //   [")", "+", "-", EOF] -> Expr = Expr, "+", Factor => ActionFn(2);
+            //
+            fn __state12<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                scale: i32,
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: &mut Option<(usize, i32, usize)>,
+                __sym1: &mut Option<(usize, &'input str, usize)>,
+                __sym2: (usize, i32, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, Token(3, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state9(scale, input, __tokens, __extra, __sym2, __sym3, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state10(scale, input, __tokens, __extra, __sym2, __sym3, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((_, Token(2, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    None => {
+                        let __sym0 = __sym0.take().unwrap();
+                        let __sym1 = __sym1.take().unwrap();
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action2::<>(scale, input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __sym0 = __sym0.take().unwrap();
+                        let __sym1 = __sym1.take().unwrap();
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action2::<>(scale, input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 13
+            //     AllInputs = [Expr, "-", Factor]
+            //     OptionalInputs = [Expr, "-"]
+            //     FixedInputs = [Factor]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Expr = Expr "-" Factor (*) ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Factor = Factor (*) "*" Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //     Factor = Factor (*) "/" Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //
+            //   "*" -> S9
+            //   "/" -> S10
+            //   [")", "+", "-", EOF] -> Expr = Expr, "-", Factor => ActionFn(1);
+            //
+            fn __state13<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                scale: i32,
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: &mut Option<(usize, i32, usize)>,
+                __sym1: &mut Option<(usize, &'input str, usize)>,
+                __sym2: (usize, i32, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, Token(3, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state9(scale, input, __tokens, __extra, __sym2, __sym3, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state10(scale, input, __tokens, __extra, __sym2, __sym3, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((_, Token(2, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    None => {
+                        let __sym0 = __sym0.take().unwrap();
+                        let __sym1 = __sym1.take().unwrap();
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action1::<>(scale, input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __sym0 = __sym0.take().unwrap();
+                        let __sym1 = __sym1.take().unwrap();
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action1::<>(scale, input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 14
+            //     AllInputs = [Factor, "*", Term]
+            //     OptionalInputs = []
+            //     FixedInputs = [Factor, "*", Term]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Factor)
+            //
+            //     Factor = Factor "*" Term (*) ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //
+            //   [")", "*", "+", "-", "/", EOF] -> Factor = Factor, "*", Term => ActionFn(4);
+            //
+            fn __state14<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                scale: i32,
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, i32, usize),
+                __sym1: (usize, &'input str, usize),
+                __sym2: (usize, i32, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((_, Token(2, _), _)) |
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action4::<>(scale, input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Factor((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action4::<>(scale, input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Factor((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 15
+            //     AllInputs = [Factor, "/", Term]
+            //     OptionalInputs = []
+            //     FixedInputs = [Factor, "/", Term]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Factor)
+            //
+            //     Factor = Factor "/" Term (*) ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //
+            //   [")", "*", "+", "-", "/", EOF] -> Factor = Factor, "/", Term => ActionFn(5);
+            //
+            fn __state15<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                scale: i32,
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, i32, usize),
+                __sym1: (usize, &'input str, usize),
+                __sym2: (usize, i32, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((_, Token(2, _), _)) |
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action5::<>(scale, input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Factor((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action5::<>(scale, input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Factor((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 16
+            //     AllInputs = ["(", Expr, ")"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["(", Expr, ")"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Term)
+            //
+            //     Term = "(" Expr ")" (*) ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+            //
+            //   [")", "*", "+", "-", "/", EOF] -> Term = "(", Expr, ")" => ActionFn(8);
+            //
+            fn __state16<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                scale: i32,
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, i32, usize),
+                __sym2: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(2, _), _)) |
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action8::<>(scale, input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action8::<>(scale, input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+        }
+        pub use self::__parse__Expr::parse_Expr;
+    }
+    mod __parse_table {
+
+        mod __parse_table {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use std::str::FromStr;
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__intern_token::Token;
+            #[allow(dead_code)]
+            pub enum __Symbol<'input>
+             {
+                Term_22_28_22(&'input str),
+                Term_22_29_22(&'input str),
+                Term_22_2a_22(&'input str),
+                Term_22_2b_22(&'input str),
+                Term_22_2d_22(&'input str),
+                Term_22_2f_22(&'input str),
+                Termr_23_22_5b0_2d9_5d_2b_22_23(&'input str),
+                NtExpr(i32),
+                NtFactor(i32),
+                NtNum(i32),
+                NtTerm(i32),
+                Nt____Expr(i32),
+            }
+            impl<'input> ::std::fmt::Debug for __Symbol<'input>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Symbol::Term_22_28_22(..) => __f.write_str("\"(\""),
+                        __Symbol::Term_22_29_22(..) => __f.write_str("\")\""),
+                        __Symbol::Term_22_2a_22(..) => __f.write_str("\"*\""),
+                        __Symbol::Term_22_2b_22(..) => __f.write_str("\"+\""),
+                        __Symbol::Term_22_2d_22(..) => __f.write_str("\"-\""),
+                        __Symbol::Term_22_2f_22(..) => __f.write_str("\"/\""),
+                        __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(..) => __f.write_str("r#\"[0-9]+\"#"),
+                        __Symbol::NtExpr(..) => __f.write_str("Expr"),
+                        __Symbol::NtFactor(..) => __f.write_str("Factor"),
+                        __Symbol::NtNum(..) => __f.write_str("Num"),
+                        __Symbol::NtTerm(..) => __f.write_str("Term"),
+                        __Symbol::Nt____Expr(..) => __f.write_str("__Expr"),
+                    }
+                }
+            }
+            const __ACTION: &'static [i8] = &[
+                // State 0
+                //     Expr = (*) Expr "+" Factor ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Expr = (*) Expr "-" Factor ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Expr = (*) Factor ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Factor = (*) Factor "*" Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Factor = (*) Factor "/" Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Factor = (*) Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Num = (*) r#"[0-9]+"# ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Term = (*) Num ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     __Expr = (*) Expr ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                6,  // on "(", goto 5
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                0,  // on "-", error
+                0,  // on "/", error
+                7,  // on r#"[0-9]+"#, goto 6
+
+                // State 1
+                //     Expr = Expr (*) "+" Factor ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Expr = Expr (*) "-" Factor ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     __Expr = Expr (*) ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                0,  // on "*", error
+                8,  // on "+", goto 7
+                9,  // on "-", goto 8
+                0,  // on "/", error
+                0,  // on r#"[0-9]+"#, error
+
+                // State 2
+                //     Expr = Factor (*) ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Factor = Factor (*) "*" Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Factor = Factor (*) "/" Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                0,  // on "(", error
+                -3,  // on ")", reduce `Expr = Factor => ActionFn(3);`
+                10,  // on "*", goto 9
+                -3,  // on "+", reduce `Expr = Factor => ActionFn(3);`
+                -3,  // on "-", reduce `Expr = Factor => ActionFn(3);`
+                11,  // on "/", goto 10
+                0,  // on r#"[0-9]+"#, error
+
+                // State 3
+                //     Term = Num (*) ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                0,  // on "(", error
+                -8,  // on ")", reduce `Term = Num => ActionFn(7);`
+                -8,  // on "*", reduce `Term = Num => ActionFn(7);`
+                -8,  // on "+", reduce `Term = Num => ActionFn(7);`
+                -8,  // on "-", reduce `Term = Num => ActionFn(7);`
+                -8,  // on "/", reduce `Term = Num => ActionFn(7);`
+                0,  // on r#"[0-9]+"#, error
+
+                // State 4
+                //     Factor = Term (*) ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                0,  // on "(", error
+                -6,  // on ")", reduce `Factor = Term => ActionFn(6);`
+                -6,  // on "*", reduce `Factor = Term => ActionFn(6);`
+                -6,  // on "+", reduce `Factor = Term => ActionFn(6);`
+                -6,  // on "-", reduce `Factor = Term => ActionFn(6);`
+                -6,  // on "/", reduce `Factor = Term => ActionFn(6);`
+                0,  // on r#"[0-9]+"#, error
+
+                // State 5
+                //     Expr = (*) Expr "+" Factor ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Expr = (*) Expr "-" Factor ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Expr = (*) Factor ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Factor = (*) Factor "*" Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Factor = (*) Factor "/" Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Factor = (*) Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Num = (*) r#"[0-9]+"# ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Term = (*) Num ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Term = "(" (*) Expr ")" ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                6,  // on "(", goto 5
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                0,  // on "-", error
+                0,  // on "/", error
+                7,  // on r#"[0-9]+"#, goto 6
+
+                // State 6
+                //     Num = r#"[0-9]+"# (*) ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                0,  // on "(", error
+                -7,  // on ")", reduce `Num = r#"[0-9]+"# => ActionFn(9);`
+                -7,  // on "*", reduce `Num = r#"[0-9]+"# => ActionFn(9);`
+                -7,  // on "+", reduce `Num = r#"[0-9]+"# => ActionFn(9);`
+                -7,  // on "-", reduce `Num = r#"[0-9]+"# => ActionFn(9);`
+                -7,  // on "/", reduce `Num = r#"[0-9]+"# => ActionFn(9);`
+                0,  // on r#"[0-9]+"#, error
+
+                // State 7
+                //     Expr = Expr "+" (*) Factor ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Factor = (*) Factor "*" Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Factor = (*) Factor "/" Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Factor = (*) Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Num = (*) r#"[0-9]+"# ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Term = (*) Num ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                6,  // on "(", goto 5
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                0,  // on "-", error
+                0,  // on "/", error
+                7,  // on r#"[0-9]+"#, goto 6
+
+                // State 8
+                //     Expr = Expr "-" (*) Factor ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Factor = (*) Factor "*" Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Factor = (*) Factor "/" Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Factor = (*) Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Num = (*) r#"[0-9]+"# ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Term = (*) Num ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                6,  // on "(", goto 5
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                0,  // on "-", error
+                0,  // on "/", error
+                7,  // on r#"[0-9]+"#, goto 6
+
+                // State 9
+                //     Factor = Factor "*" (*) Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Num = (*) r#"[0-9]+"# ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Term = (*) Num ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                6,  // on "(", goto 5
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                0,  // on "-", error
+                0,  // on "/", error
+                7,  // on r#"[0-9]+"#, goto 6
+
+                // State 10
+                //     Factor = Factor "/" (*) Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Num = (*) r#"[0-9]+"# ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Term = (*) Num ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                6,  // on "(", goto 5
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                0,  // on "-", error
+                0,  // on "/", error
+                7,  // on r#"[0-9]+"#, goto 6
+
+                // State 11
+                //     Expr = Expr (*) "+" Factor ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Expr = Expr (*) "-" Factor ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Term = "(" Expr (*) ")" ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                0,  // on "(", error
+                17,  // on ")", goto 16
+                0,  // on "*", error
+                8,  // on "+", goto 7
+                9,  // on "-", goto 8
+                0,  // on "/", error
+                0,  // on r#"[0-9]+"#, error
+
+                // State 12
+                //     Expr = Expr "+" Factor (*) ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Factor = Factor (*) "*" Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Factor = Factor (*) "/" Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                0,  // on "(", error
+                -2,  // on ")", reduce `Expr = Expr, "+", Factor => ActionFn(2);`
+                10,  // on "*", goto 9
+                -2,  // on "+", reduce `Expr = Expr, "+", Factor => ActionFn(2);`
+                -2,  // on "-", reduce `Expr = Expr, "+", Factor => ActionFn(2);`
+                11,  // on "/", goto 10
+                0,  // on r#"[0-9]+"#, error
+
+                // State 13
+                //     Expr = Expr "-" Factor (*) ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Factor = Factor (*) "*" Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                //     Factor = Factor (*) "/" Term ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                0,  // on "(", error
+                -1,  // on ")", reduce `Expr = Expr, "-", Factor => ActionFn(1);`
+                10,  // on "*", goto 9
+                -1,  // on "+", reduce `Expr = Expr, "-", Factor => ActionFn(1);`
+                -1,  // on "-", reduce `Expr = Expr, "-", Factor => ActionFn(1);`
+                11,  // on "/", goto 10
+                0,  // on r#"[0-9]+"#, error
+
+                // State 14
+                //     Factor = Factor "*" Term (*) ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                0,  // on "(", error
+                -4,  // on ")", reduce `Factor = Factor, "*", Term => ActionFn(4);`
+                -4,  // on "*", reduce `Factor = Factor, "*", Term => ActionFn(4);`
+                -4,  // on "+", reduce `Factor = Factor, "*", Term => ActionFn(4);`
+                -4,  // on "-", reduce `Factor = Factor, "*", Term => ActionFn(4);`
+                -4,  // on "/", reduce `Factor = Factor, "*", Term => ActionFn(4);`
+                0,  // on r#"[0-9]+"#, error
+
+                // State 15
+                //     Factor = Factor "/" Term (*) ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                0,  // on "(", error
+                -5,  // on ")", reduce `Factor = Factor, "/", Term => ActionFn(5);`
+                -5,  // on "*", reduce `Factor = Factor, "/", Term => ActionFn(5);`
+                -5,  // on "+", reduce `Factor = Factor, "/", Term => ActionFn(5);`
+                -5,  // on "-", reduce `Factor = Factor, "/", Term => ActionFn(5);`
+                -5,  // on "/", reduce `Factor = Factor, "/", Term => ActionFn(5);`
+                0,  // on r#"[0-9]+"#, error
+
+                // State 16
+                //     Term = "(" Expr ")" (*) ["(", ")", "*", "+", "-", "/", r#"[0-9]+"#, EOF]
+                0,  // on "(", error
+                -9,  // on ")", reduce `Term = "(", Expr, ")" => ActionFn(8);`
+                -9,  // on "*", reduce `Term = "(", Expr, ")" => ActionFn(8);`
+                -9,  // on "+", reduce `Term = "(", Expr, ")" => ActionFn(8);`
+                -9,  // on "-", reduce `Term = "(", Expr, ")" => ActionFn(8);`
+                -9,  // on "/", reduce `Term = "(", Expr, ")" => ActionFn(8);`
+                0,  // on r#"[0-9]+"#, error
+
+            ];
+            const __EOF_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // on EOF, error
+
+                // State 1
+                -10,  // on EOF, reduce `__Expr = Expr => ActionFn(0);`
+
+                // State 2
+                -3,  // on EOF, reduce `Expr = Factor => ActionFn(3);`
+
+                // State 3
+                -8,  // on EOF, reduce `Term = Num => ActionFn(7);`
+
+                // State 4
+                -6,  // on EOF, reduce `Factor = Term => ActionFn(6);`
+
+                // State 5
+                0,  // on EOF, error
+
+                // State 6
+                -7,  // on EOF, reduce `Num = r#"[0-9]+"# => ActionFn(9);`
+
+                // State 7
+                0,  // on EOF, error
+
+                // State 8
+                0,  // on EOF, error
+
+                // State 9
+                0,  // on EOF, error
+
+                // State 10
+                0,  // on EOF, error
+
+                // State 11
+                0,  // on EOF, error
+
+                // State 12
+                -2,  // on EOF, reduce `Expr = Expr, "+", Factor => ActionFn(2);`
+
+                // State 13
+                -1,  // on EOF, reduce `Expr = Expr, "-", Factor => ActionFn(1);`
+
+                // State 14
+                -4,  // on EOF, reduce `Factor = Factor, "*", Term => ActionFn(4);`
+
+                // State 15
+                -5,  // on EOF, reduce `Factor = Factor, "/", Term => ActionFn(5);`
+
+                // State 16
+                -9,  // on EOF, reduce `Term = "(", Expr, ")" => ActionFn(8);`
+
+            ];
+            const __GOTO: &'static [u8] = &[
+                // State 0
+                2,  // on Expr, goto 1
+                3,  // on Factor, goto 2
+                4,  // on Num, goto 3
+                5,  // on Term, goto 4
+                0,  // on __Expr, error
+
+                // State 1
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 2
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 3
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 4
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 5
+                12,  // on Expr, goto 11
+                3,  // on Factor, goto 2
+                4,  // on Num, goto 3
+                5,  // on Term, goto 4
+                0,  // on __Expr, error
+
+                // State 6
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 7
+                0,  // on Expr, error
+                13,  // on Factor, goto 12
+                4,  // on Num, goto 3
+                5,  // on Term, goto 4
+                0,  // on __Expr, error
+
+                // State 8
+                0,  // on Expr, error
+                14,  // on Factor, goto 13
+                4,  // on Num, goto 3
+                5,  // on Term, goto 4
+                0,  // on __Expr, error
+
+                // State 9
+                0,  // on Expr, error
+                0,  // on Factor, error
+                4,  // on Num, goto 3
+                15,  // on Term, goto 14
+                0,  // on __Expr, error
+
+                // State 10
+                0,  // on Expr, error
+                0,  // on Factor, error
+                4,  // on Num, goto 3
+                16,  // on Term, goto 15
+                0,  // on __Expr, error
+
+                // State 11
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 12
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 13
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 14
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 15
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 16
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+            ];
+            const __DEFAULT_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // no default reduction
+
+                // State 1
+                0,  // no default reduction
+
+                // State 2
+                0,  // no default reduction
+
+                // State 3
+                -8,  // on any token, reduce `Term = Num => ActionFn(7);`
+
+                // State 4
+                -6,  // on any token, reduce `Factor = Term => ActionFn(6);`
+
+                // State 5
+                0,  // no default reduction
+
+                // State 6
+                -7,  // on any token, reduce `Num = r#"[0-9]+"# => ActionFn(9);`
+
+                // State 7
+                0,  // no default reduction
+
+                // State 8
+                0,  // no default reduction
+
+                // State 9
+                0,  // no default reduction
+
+                // State 10
+                0,  // no default reduction
+
+                // State 11
+                0,  // no default reduction
+
+                // State 12
+                0,  // no default reduction
+
+                // State 13
+                0,  // no default reduction
+
+                // State 14
+                -4,  // on any token, reduce `Factor = Factor, "*", Term => ActionFn(4);`
+
+                // State 15
+                -5,  // on any token, reduce `Factor = Factor, "/", Term => ActionFn(5);`
+
+                // State 16
+                -9,  // on any token, reduce `Term = "(", Expr, ")" => ActionFn(8);`
+
+            ];
+            fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+                const __TERMINAL: &'static [&'static str] = &[
+                    r###""(""###,
+                    r###"")""###,
+                    r###""*""###,
+                    r###""+""###,
+                    r###""-""###,
+                    r###""/""###,
+                    r###"r#"[0-9]+"#"###,
+                ];
+                __ACTION[(__state * 7)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+                    if state == 0 {
+                        None
+                    } else {
+                        Some(terminal.to_string())
+                    }
+                }).collect()
+            }
+            #[allow(dead_code)]
+            pub fn parse_Expr<
+                'input,
+            >(
+                scale: i32,
+                input: &'input str,
+            ) -> Result<i32, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(scale, input, 0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() })
+            }
+            #[allow(dead_code)]
+            pub fn parse_Expr_cancelable<
+                'input,
+            >(
+                scale: i32,
+                input: &'input str,
+                __cancel: &::std::sync::atomic::AtomicBool,
+            ) -> Result<i32, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(scale, input, 0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() })
+            }
+
+            #[allow(dead_code)]
+            pub struct ExprParser {
+                __states: ::std::vec::Vec<i32>,
+                __symbols: __lalrpop_util::stack::Recycled,
+                __cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+            }
+
+            #[allow(dead_code)]
+            impl ExprParser {
+                pub fn new() -> ExprParser {
+                    ExprParser { __states: vec![], __symbols: __lalrpop_util::stack::Recycled::new(), __cancel: None }
+                }
+
+                pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+                    self.__cancel = Some(flag);
+                }
+
+                pub fn parse_in_place<'input>(
+                    &mut self,
+                    scale: i32,
+                    input: &'input str,
+                ) -> Result<i32, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+                {
+                    let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                    let mut __symbols = self.__symbols.take();
+                    let __result = __parse::<>(scale, input, 0, &mut __tokens, &mut self.__states, &mut __symbols, self.__cancel.as_ref().map(|c| &**c)).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() });
+                    self.__symbols.put(__symbols);
+                    __result
+                }
+            }
+
+            fn __parse<
+                'input,
+            >(
+                scale: i32,
+                input: &'input str,
+                __start_state: i32,
+                mut __tokens: &mut Iterator<Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+                mut __states: &mut ::std::vec::Vec<i32>,
+                mut __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                __cancel: Option<&::std::sync::atomic::AtomicBool>,
+            ) -> Result<__Symbol<'input>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                __states.clear();
+                __states.push(__start_state);
+                __symbols.clear();
+                let mut __integer;
+                let mut __lookahead;
+                let __last_location: &mut usize = &mut Default::default();
+                let mut __default_reduced = [0_usize; 16];
+                let mut __default_reduced_len = 0;
+                '__shift: loop {
+                    loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __DEFAULT_ACTION[__state] as i32;
+                        if __action == 0 {
+                            break;
+                        }
+                        if __default_reduced_len < 16 {
+                            __default_reduced[__default_reduced_len] = __state;
+                            __default_reduced_len += 1;
+                        }
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(scale, input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    }
+                    __lookahead = match __tokens.next() {
+                        Some(Ok(v)) => v,
+                        None => break '__shift,
+                        Some(Err(e)) => return Err(e),
+                    };
+                    *__last_location = __lookahead.2.clone();
+                    __integer = match __lookahead.1 {
+                        Token(1, _) if true => 0,
+                        Token(2, _) if true => 1,
+                        Token(3, _) if true => 2,
+                        Token(4, _) if true => 3,
+                        Token(5, _) if true => 4,
+                        Token(6, _) if true => 5,
+                        Token(0, _) if true => 6,
+                        _ => {
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| true).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match Some(__lookahead) {
+                                Some(__token) => {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error);
+                        }
+                    };
+                    '__inner: loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __ACTION[__state * 7 + __integer] as i32;
+                        if __action > 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                                }
+                            }
+                            let __symbol = match __integer {
+                                0 => match __lookahead.1 {
+                                    Token(1, __tok0) => __Symbol::Term_22_28_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                1 => match __lookahead.1 {
+                                    Token(2, __tok0) => __Symbol::Term_22_29_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                2 => match __lookahead.1 {
+                                    Token(3, __tok0) => __Symbol::Term_22_2a_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                3 => match __lookahead.1 {
+                                    Token(4, __tok0) => __Symbol::Term_22_2b_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                4 => match __lookahead.1 {
+                                    Token(5, __tok0) => __Symbol::Term_22_2d_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                5 => match __lookahead.1 {
+                                    Token(6, __tok0) => __Symbol::Term_22_2f_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                6 => match __lookahead.1 {
+                                    Token(0, __tok0) => __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                _ => unreachable!(),
+                            };
+                            __states.push(__action - 1);
+                            __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                            __default_reduced_len = 0;
+                            continue '__shift;
+                        } else if __action < 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                                }
+                            }
+                            if let Some(r) = __reduce(scale, input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                                if r.is_err() {
+                                    return r;
+                                }
+                                return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] });
+                            }
+                        } else {
+                            let mut __err_lookahead = Some(__lookahead);
+                            let mut __err_integer: Option<usize> = Some(__integer);
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 7 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match __err_lookahead {
+                                Some(__token) => {
+                                    if __accepts(scale, input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                        __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                    } else {
+                                        __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                    }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error)
+                        }
+                    }
+                }
+                loop {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __action = __EOF_ACTION[__state] as i32;
+                    if __action < 0 {
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(scale, input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    } else {
+                        let mut __err_lookahead = None;
+                        let mut __err_integer: Option<usize> = None;
+                        let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 7 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                        let __expected = __expected_tokens(__state);
+                        let __error = match __err_lookahead {
+                            Some(__token) => {
+                                if __accepts(scale, input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                    __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                } else {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                            }
+                            None => {
+                                __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                            }
+                        };
+                        return Err(__error)
+                    }
+                }
+            }
+            fn __accepts<
+                'input,
+            >(
+                scale: i32,
+                input: &'input str,
+                __error_state: i32,
+                __states: & [i32],
+                __opt_integer: Option<usize>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> bool
+            {
+                let mut __states = __states.to_vec();
+                __states.push(__error_state);
+                loop {
+                    let mut __states_len = __states.len();
+                    let __top = __states[__states_len - 1];
+                    let __action = match __opt_integer {
+                        None => __EOF_ACTION[__top as usize] as i32,
+                        Some(__integer) => __ACTION[(__top * 7) as usize + __integer] as i32,
+                    };
+                    if __action == 0 { return false; }
+                    if __action > 0 { return true; }
+                    let (__to_pop, __nt) = match -__action {
+                        // simulate Expr = Expr, "-", Factor => ActionFn(1);
+                        1 => {
+                            (3, 0)
+                        }
+                        // simulate Expr = Expr, "+", Factor => ActionFn(2);
+                        2 => {
+                            (3, 0)
+                        }
+                        // simulate Expr = Factor => ActionFn(3);
+                        3 => {
+                            (1, 0)
+                        }
+                        // simulate Factor = Factor, "*", Term => ActionFn(4);
+                        4 => {
+                            (3, 1)
+                        }
+                        // simulate Factor = Factor, "/", Term => ActionFn(5);
+                        5 => {
+                            (3, 1)
+                        }
+                        // simulate Factor = Term => ActionFn(6);
+                        6 => {
+                            (1, 1)
+                        }
+                        // simulate Num = r#"[0-9]+"# => ActionFn(9);
+                        7 => {
+                            (1, 2)
+                        }
+                        // simulate Term = Num => ActionFn(7);
+                        8 => {
+                            (1, 3)
+                        }
+                        // simulate Term = "(", Expr, ")" => ActionFn(8);
+                        9 => {
+                            (3, 3)
+                        }
+                        // simulate __Expr = Expr => ActionFn(0);
+                        10 => return true,
+                        _ => panic!("invalid action code {}", __action)
+                    };
+                    __states_len -= __to_pop;
+                    __states.truncate(__states_len);
+                    let __top = __states[__states_len - 1];
+                    let __next_state = __GOTO[(__top * 5 + __nt) as usize] as i32 - 1;
+                    __states.push(__next_state);
+                }
+            }
+            pub fn __reduce<
+                'input,
+            >(
+                scale: i32,
+                input: &'input str,
+                __action: i32,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Option<Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                let __nonterminal = match -__action {
+                    1 => match __reduce_Expr_0(scale, input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    2 => match __reduce_Expr_1(scale, input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    3 => match __reduce_Expr_2(scale, input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    4 => match __reduce_Factor_0(scale, input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    5 => match __reduce_Factor_1(scale, input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    6 => match __reduce_Factor_2(scale, input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    7 => match __reduce_Num_0(scale, input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    8 => match __reduce_Term_0(scale, input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    9 => match __reduce_Term_1(scale, input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    10 => match __reduce_____Expr_0(scale, input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    _ => panic!("invalid action code {}", __action)
+                };
+                let __state = *__states.last().unwrap() as usize;
+                let __next_state = __GOTO[__state * 5 + __nonterminal] as i32 - 1;
+                __states.push(__next_state);
+                None
+            }
+
+            fn __reduce_Expr_0<
+                'input,
+            >(
+                scale: i32,
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expr = Expr, "-", Factor => ActionFn(1);
+                let __sym2 = __pop_NtFactor(__symbols);
+                let __sym1 = __pop_Term_22_2d_22(__symbols);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action1::<>(scale, input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce_Expr_1<
+                'input,
+            >(
+                scale: i32,
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expr = Expr, "+", Factor => ActionFn(2);
+                let __sym2 = __pop_NtFactor(__symbols);
+                let __sym1 = __pop_Term_22_2b_22(__symbols);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action2::<>(scale, input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce_Expr_2<
+                'input,
+            >(
+                scale: i32,
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expr = Factor => ActionFn(3);
+                let __sym0 = __pop_NtFactor(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action3::<>(scale, input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce_Factor_0<
+                'input,
+            >(
+                scale: i32,
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Factor = Factor, "*", Term => ActionFn(4);
+                let __sym2 = __pop_NtTerm(__symbols);
+                let __sym1 = __pop_Term_22_2a_22(__symbols);
+                let __sym0 = __pop_NtFactor(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action4::<>(scale, input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtFactor(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce_Factor_1<
+                'input,
+            >(
+                scale: i32,
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Factor = Factor, "/", Term => ActionFn(5);
+                let __sym2 = __pop_NtTerm(__symbols);
+                let __sym1 = __pop_Term_22_2f_22(__symbols);
+                let __sym0 = __pop_NtFactor(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action5::<>(scale, input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtFactor(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce_Factor_2<
+                'input,
+            >(
+                scale: i32,
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Factor = Term => ActionFn(6);
+                let __sym0 = __pop_NtTerm(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action6::<>(scale, input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtFactor(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce_Num_0<
+                'input,
+            >(
+                scale: i32,
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Num = r#"[0-9]+"# => ActionFn(9);
+                let __sym0 = __pop_Termr_23_22_5b0_2d9_5d_2b_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action9::<>(scale, input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtNum(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce_Term_0<
+                'input,
+            >(
+                scale: i32,
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Term = Num => ActionFn(7);
+                let __sym0 = __pop_NtNum(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action7::<>(scale, input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+                Ok(3)
+            }
+
+            fn __reduce_Term_1<
+                'input,
+            >(
+                scale: i32,
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Term = "(", Expr, ")" => ActionFn(8);
+                let __sym2 = __pop_Term_22_29_22(__symbols);
+                let __sym1 = __pop_NtExpr(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action8::<>(scale, input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+                Ok(3)
+            }
+
+            fn __reduce_____Expr_0<
+                'input,
+            >(
+                scale: i32,
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // __Expr = Expr => ActionFn(0);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action0::<>(scale, input, __sym0);
+                Err(Ok(__Symbol::Nt____Expr(__nt)))
+            }
+            fn __pop_Term_22_28_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_28_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_29_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_29_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_2a_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2a_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_2b_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2b_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_2d_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2d_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_2f_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2f_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Termr_23_22_5b0_2d9_5d_2b_22_23<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtExpr<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, i32, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtExpr(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtFactor<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, i32, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtFactor(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtNum<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, i32, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtNum(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtTerm<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, i32, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtTerm(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt____Expr<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, i32, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt____Expr(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+        }
+        pub use self::__parse_table::parse_Expr;
+        pub use self::__parse_table::parse_Expr_cancelable;
+        pub use self::__parse_table::ExprParser;
+    }
+    pub use self::__parse_table::parse_Expr_cancelable;
+    pub use self::__parse_table::ExprParser;
+}
+pub use self::__parse__Expr::parse_Expr;
+pub use self::__parse__Expr::parse_Expr_cancelable;
+pub use self::__parse__Expr::ExprParser;
+mod __intern_token {
+    #![allow(unused_imports)]
+    use std::str::FromStr;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    extern crate regex as __regex;
+    use std::fmt as __fmt;
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Token<'input>(pub usize, pub &'input str);
+    impl<'a> __fmt::Display for Token<'a> {
+        fn fmt(&self, formatter: &mut __fmt::Formatter) -> Result<(), __fmt::Error> {
+            __fmt::Display::fmt(self.1, formatter)
+        }
+    }
+
+    pub struct __Matcher<'input> {
+        text: &'input str,
+        consumed: usize,
+        regex_set: __regex::RegexSet,
+        regex_vec: Vec<__regex::Regex>,
+    }
+
+    impl<'input> __Matcher<'input> {
+        pub fn new(s: &'input str) -> __Matcher<'input> {
+            let __strs: &[&str] = &[
+                "^(?u:[0-9])+",
+                "^(?u:\\()",
+                "^(?u:\\))",
+                "^(?u:\\*)",
+                "^(?u:\\+)",
+                "^(?u:\\-)",
+                "^(?u:/)",
+            ];
+            let __regex_set = __regex::RegexSet::new(__strs).unwrap();
+            let __regex_vec = vec![
+                __regex::Regex::new("^(?u:[0-9])+").unwrap(),
+                __regex::Regex::new("^(?u:\\()").unwrap(),
+                __regex::Regex::new("^(?u:\\))").unwrap(),
+                __regex::Regex::new("^(?u:\\*)").unwrap(),
+                __regex::Regex::new("^(?u:\\+)").unwrap(),
+                __regex::Regex::new("^(?u:\\-)").unwrap(),
+                __regex::Regex::new("^(?u:/)").unwrap(),
+            ];
+            __Matcher {
+                text: s,
+                consumed: 0,
+                regex_set: __regex_set,
+                regex_vec: __regex_vec,
+            }
+        }
+    }
+
+    impl<'input> Iterator for __Matcher<'input> {
+        type Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize,Token<'input>,&'static str>>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let __text = self.text.trim_left();
+            let __whitespace = self.text.len() - __text.len();
+            let __start_offset = self.consumed + __whitespace;
+            if __text.is_empty() {
+                self.text = __text;
+                self.consumed = __start_offset;
+                None
+            } else {
+                let __matches = self.regex_set.matches(__text);
+                if !__matches.matched_any() {
+                    Some(Err(__lalrpop_util::ParseError::InvalidToken {
+                        location: __start_offset,
+                    }))
+                } else {
+                    let mut __longest_match = 0;
+                    let mut __index = 0;
+                    for __i in 0 .. 7 {
+                        if __matches.matched(__i) {
+                            let __match = self.regex_vec[__i].find(__text).unwrap();
+                            let __len = __match.end();
+                            if __len >= __longest_match {
+                                __longest_match = __len;
+                                __index = __i;
+                            }
+                        }
+                    }
+                    let __result = &__text[..__longest_match];
+                    let __remaining = &__text[__longest_match..];
+                    let __end_offset = __start_offset + __longest_match;
+                    self.text = __remaining;
+                    self.consumed = __end_offset;
+                    Some(Ok((__start_offset, Token(__index, __result), __end_offset)))
+                }
+            }
+        }
+    }
+}
+pub use self::__intern_token::Token;
+
+#[allow(unused_variables)]
+fn __action0<
+    'input,
+>(
+    scale: i32,
+    input: &'input str,
+    (_, __0, _): (usize, i32, usize),
+) -> i32
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'input,
+>(
+    scale: i32,
+    input: &'input str,
+    (_, l, _): (usize, i32, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, i32, usize),
+) -> i32
+{
+    //@ src/expr_intern_tok.lalrpop:6:32 (1 lines)
+    l - r
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'input,
+>(
+    scale: i32,
+    input: &'input str,
+    (_, l, _): (usize, i32, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, i32, usize),
+) -> i32
+{
+    //@ src/expr_intern_tok.lalrpop:7:32 (1 lines)
+    l + r
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'input,
+>(
+    scale: i32,
+    input: &'input str,
+    (_, __0, _): (usize, i32, usize),
+) -> i32
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'input,
+>(
+    scale: i32,
+    input: &'input str,
+    (_, l, _): (usize, i32, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, i32, usize),
+) -> i32
+{
+    //@ src/expr_intern_tok.lalrpop:12:32 (1 lines)
+    l * r
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'input,
+>(
+    scale: i32,
+    input: &'input str,
+    (_, l, _): (usize, i32, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, i32, usize),
+) -> i32
+{
+    //@ src/expr_intern_tok.lalrpop:13:32 (1 lines)
+    l / r
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'input,
+>(
+    scale: i32,
+    input: &'input str,
+    (_, __0, _): (usize, i32, usize),
+) -> i32
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'input,
+>(
+    scale: i32,
+    input: &'input str,
+    (_, n, _): (usize, i32, usize),
+) -> i32
+{
+    //@ src/expr_intern_tok.lalrpop:18:16 (1 lines)
+    n * scale
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'input,
+>(
+    scale: i32,
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, i32, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> i32
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action9<
+    'input,
+>(
+    scale: i32,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> i32
+{
+    //@ src/expr_intern_tok.lalrpop:23:18 (1 lines)
+    i32::from_str(__0).unwrap()
+}
+
+pub trait __ToTriple<'input, > {
+    type Error;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),Self::Error>;
+}
+
+impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize) {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        Ok(value)
+    }
+}
+impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        value
+    }
+}
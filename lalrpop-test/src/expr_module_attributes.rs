@@ -0,0 +1,2576 @@
+// auto-generated: "lalrpop 0.15.0"
+#![cfg_attr(feature = "cargo-clippy", allow(clippy))]
+use util::tok::Tok;
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+
+mod __parse__Expr {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    use util::tok::Tok;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__ToTriple;
+    #[allow(dead_code)]
+    pub fn parse_Expr<
+        __TOKEN: __ToTriple<Error=&'static str>,
+        __TOKENS: IntoIterator<Item=__TOKEN>,
+    >(
+        scale: i32,
+        __tokens0: __TOKENS,
+    ) -> Result<i32, __lalrpop_util::ParseError<(), Tok, &'static str>> where
+      __TOKENS: Clone,
+    {
+        let __ascent = __ascent::parse_Expr(
+            scale,
+            __tokens0.clone(),
+        );
+        let __parse_table = __parse_table::parse_Expr(
+            scale,
+            __tokens0.clone(),
+        );
+        assert_eq!(__ascent, __parse_table);
+        return __ascent;
+    }
+    mod __ascent {
+
+        mod __parse__Expr {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use util::tok::Tok;
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__ToTriple;
+            #[allow(dead_code)]
+            pub fn parse_Expr<
+                __TOKEN: __ToTriple<Error=&'static str>,
+                __TOKENS: IntoIterator<Item=__TOKEN>,
+            >(
+                scale: i32,
+                __tokens0: __TOKENS,
+            ) -> Result<i32, __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let __tokens = __tokens0.into_iter();
+                let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+                let __last_location = ::std::cell::RefCell::new(Default::default());
+                let mut __tokens = __tokens.inspect(|t| if let Ok(ref t) = *t { *__last_location.borrow_mut() = t.2.clone(); });
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                let mut __extra = None;
+                let __result = __state0(scale, &mut __tokens, __lookahead, &mut __extra, ::std::marker::PhantomData::<()>);
+                if let Some((__token, __expected)) = __extra {
+                    return Err(match __result {
+                        Ok(_) => __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected },
+                        Err(_) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                    });
+                }
+                match __result {
+                    Ok((Some(__lookahead), _)) => {
+                        Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] })
+                    }
+                    Ok((None, __Nonterminal::____Expr((_, __nt, _)))) => {
+                        Ok(__nt)
+                    }
+                    Ok(_) => unreachable!(),
+                    Err(__lalrpop_util::ParseError::UnrecognizedEof { expected: __expected, .. }) => {
+                        Err(__lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.borrow().clone(), expected: __expected })
+                    }
+                    Err(__error) => Err(__error),
+                }
+            }
+
+            #[allow(dead_code)]
+            pub enum __Nonterminal<>
+             {
+                Expr(((), i32, ())),
+                Factor(((), i32, ())),
+                Term(((), i32, ())),
+                ____Expr(((), i32, ())),
+            }
+            impl<> ::std::fmt::Debug for __Nonterminal<>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Nonterminal::Expr(..) => __f.write_str("Expr"),
+                        __Nonterminal::Factor(..) => __f.write_str("Factor"),
+                        __Nonterminal::Term(..) => __f.write_str("Term"),
+                        __Nonterminal::____Expr(..) => __f.write_str("__Expr"),
+                    }
+                }
+            }
+
+            // State 0
+            //     AllInputs = []
+            //     OptionalInputs = []
+            //     FixedInputs = []
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Expr = (*) Expr "+" Factor ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Expr = (*) Expr "-" Factor ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Expr = (*) Factor ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Factor = (*) Factor "*" Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Factor = (*) Factor "/" Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Factor = (*) Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Term = (*) Num ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     __Expr = (*) Expr ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //
+            //   "(" -> S4
+            //   Num -> S5
+            //
+            //     Expr -> S1
+            //     Factor -> S2
+            //     Term -> S3
+            fn __state0<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                scale: i32,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<((), Tok, ())>,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::LParen, __loc2)) => {
+                        let __sym0 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state4(scale, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Tok::Num(__tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(scale, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###"Num"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Expr(__sym0) => {
+                            __result = try!(__state1(scale, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Factor(__sym0) => {
+                            __result = try!(__state2(scale, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Term(__sym0) => {
+                            __result = try!(__state3(scale, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 1
+            //     AllInputs = [Expr]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Expr = Expr (*) "+" Factor ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Expr = Expr (*) "-" Factor ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     __Expr = Expr (*) ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //
+            //   "+" -> S6
+            //   "-" -> S7
+            //   [EOF] -> __Expr = Expr => ActionFn(0);
+            //
+            fn __state1<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                scale: i32,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<((), Tok, ())>,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), i32, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::Plus, __loc2)) => {
+                        let __sym1 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state6(scale, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, __tok @ Tok::Minus, __loc2)) => {
+                        let __sym1 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state7(scale, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(scale, __sym0);
+                        let __nt = __Nonterminal::____Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<((), Tok, ())> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(scale, __sym0);
+                        let __nt = __Nonterminal::____Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 2
+            //     AllInputs = [Factor]
+            //     OptionalInputs = []
+            //     FixedInputs = [Factor]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Expr = Factor (*) ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Factor = Factor (*) "*" Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Factor = Factor (*) "/" Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //
+            //   "*" -> S8
+            //   "/" -> S9
+            //   [")", "+", "-", EOF] -> Expr = Factor => ActionFn(3);
+            //
+            fn __state2<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                scale: i32,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<((), Tok, ())>,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), i32, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::Times, __loc2)) => {
+                        let __sym1 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state8(scale, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, __tok @ Tok::Div, __loc2)) => {
+                        let __sym1 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state9(scale, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((_, Tok::RParen, _)) |
+                    Some((_, Tok::Plus, _)) |
+                    Some((_, Tok::Minus, _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action3::<>(scale, __sym0);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<((), Tok, ())> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action3::<>(scale, __sym0);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 3
+            //     AllInputs = [Term]
+            //     OptionalInputs = []
+            //     FixedInputs = [Term]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Factor)
+            //
+            //     Factor = Term (*) ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //
+            //   [")", "*", "+", "-", "/", EOF] -> Factor = Term => ActionFn(6);
+            //
+            fn __state3<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                scale: i32,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<((), Tok, ())>,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), i32, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                match __lookahead {
+                    Some((_, Tok::RParen, _)) |
+                    Some((_, Tok::Times, _)) |
+                    Some((_, Tok::Plus, _)) |
+                    Some((_, Tok::Minus, _)) |
+                    Some((_, Tok::Div, _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action6::<>(scale, __sym0);
+                        let __nt = __Nonterminal::Factor((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<((), Tok, ())> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action6::<>(scale, __sym0);
+                        let __nt = __Nonterminal::Factor((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 4
+            //     AllInputs = ["("]
+            //     OptionalInputs = []
+            //     FixedInputs = ["("]
+            //     WillPushLen = 2
+            //     WillPush = [Expr, ")"]
+            //     WillProduce = Some(Term)
+            //
+            //     Expr = (*) Expr "+" Factor ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Expr = (*) Expr "-" Factor ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Expr = (*) Factor ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Factor = (*) Factor "*" Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Factor = (*) Factor "/" Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Factor = (*) Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Term = "(" (*) Expr ")" ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Term = (*) Num ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //
+            //   "(" -> S4
+            //   Num -> S5
+            //
+            //     Expr -> S10
+            //     Factor -> S2
+            //     Term -> S3
+            fn __state4<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                scale: i32,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), Tok, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                let __sym0 = &mut Some(__sym0);
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::LParen, __loc2)) => {
+                        let __sym1 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state4(scale, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Tok::Num(__tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(scale, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###"Num"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    if __sym0.is_none() {
+                        return Ok(__result);
+                    }
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Expr(__sym1) => {
+                            __result = try!(__state10(scale, __tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Factor(__sym1) => {
+                            __result = try!(__state2(scale, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Term(__sym1) => {
+                            __result = try!(__state3(scale, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 5
+            //     AllInputs = [Num]
+            //     OptionalInputs = []
+            //     FixedInputs = [Num]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Term)
+            //
+            //     Term = Num (*) ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //
+            //   [")", "*", "+", "-", "/", EOF] -> Term = Num => ActionFn(7);
+            //
+            fn __state5<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                scale: i32,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), i32, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                match __lookahead {
+                    Some((_, Tok::RParen, _)) |
+                    Some((_, Tok::Times, _)) |
+                    Some((_, Tok::Plus, _)) |
+                    Some((_, Tok::Minus, _)) |
+                    Some((_, Tok::Div, _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action7::<>(scale, __sym0);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<((), Tok, ())> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action7::<>(scale, __sym0);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 6
+            //     AllInputs = [Expr, "+"]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr, "+"]
+            //     WillPushLen = 1
+            //     WillPush = [Factor]
+            //     WillProduce = Some(Expr)
+            //
+            //     Expr = Expr "+" (*) Factor ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Factor = (*) Factor "*" Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Factor = (*) Factor "/" Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Factor = (*) Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Term = (*) Num ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //
+            //   "(" -> S4
+            //   Num -> S5
+            //
+            //     Factor -> S11
+            //     Term -> S3
+            fn __state6<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                scale: i32,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), i32, ()),
+                __sym1: ((), Tok, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                let __sym0 = &mut Some(__sym0);
+                let __sym1 = &mut Some(__sym1);
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::LParen, __loc2)) => {
+                        let __sym2 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state4(scale, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Tok::Num(__tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(scale, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###"Num"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    if __sym1.is_none() {
+                        return Ok(__result);
+                    }
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Factor(__sym2) => {
+                            __result = try!(__state11(scale, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Term(__sym2) => {
+                            __result = try!(__state3(scale, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 7
+            //     AllInputs = [Expr, "-"]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr, "-"]
+            //     WillPushLen = 1
+            //     WillPush = [Factor]
+            //     WillProduce = Some(Expr)
+            //
+            //     Expr = Expr "-" (*) Factor ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Factor = (*) Factor "*" Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Factor = (*) Factor "/" Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Factor = (*) Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Term = (*) Num ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //
+            //   "(" -> S4
+            //   Num -> S5
+            //
+            //     Factor -> S12
+            //     Term -> S3
+            fn __state7<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                scale: i32,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), i32, ()),
+                __sym1: ((), Tok, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                let __sym0 = &mut Some(__sym0);
+                let __sym1 = &mut Some(__sym1);
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::LParen, __loc2)) => {
+                        let __sym2 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state4(scale, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Tok::Num(__tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(scale, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###"Num"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    if __sym1.is_none() {
+                        return Ok(__result);
+                    }
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Factor(__sym2) => {
+                            __result = try!(__state12(scale, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Term(__sym2) => {
+                            __result = try!(__state3(scale, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 8
+            //     AllInputs = [Factor, "*"]
+            //     OptionalInputs = []
+            //     FixedInputs = [Factor, "*"]
+            //     WillPushLen = 1
+            //     WillPush = [Term]
+            //     WillProduce = Some(Factor)
+            //
+            //     Factor = Factor "*" (*) Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Term = (*) Num ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //
+            //   "(" -> S4
+            //   Num -> S5
+            //
+            //     Term -> S13
+            fn __state8<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                scale: i32,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), i32, ()),
+                __sym1: ((), Tok, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::LParen, __loc2)) => {
+                        let __sym2 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state4(scale, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Tok::Num(__tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(scale, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###"Num"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Term(__sym2) => {
+                            __result = try!(__state13(scale, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 9
+            //     AllInputs = [Factor, "/"]
+            //     OptionalInputs = []
+            //     FixedInputs = [Factor, "/"]
+            //     WillPushLen = 1
+            //     WillPush = [Term]
+            //     WillProduce = Some(Factor)
+            //
+            //     Factor = Factor "/" (*) Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Term = (*) Num ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //
+            //   "(" -> S4
+            //   Num -> S5
+            //
+            //     Term -> S14
+            fn __state9<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                scale: i32,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), i32, ()),
+                __sym1: ((), Tok, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::LParen, __loc2)) => {
+                        let __sym2 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state4(scale, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Tok::Num(__tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(scale, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###"Num"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Term(__sym2) => {
+                            __result = try!(__state14(scale, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 10
+            //     AllInputs = ["(", Expr]
+            //     OptionalInputs = ["("]
+            //     FixedInputs = [Expr]
+            //     WillPushLen = 1
+            //     WillPush = [")"]
+            //     WillProduce = None
+            //
+            //     Expr = Expr (*) "+" Factor ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Expr = Expr (*) "-" Factor ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Term = "(" Expr (*) ")" ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //
+            //   ")" -> S15
+            //   "+" -> S6
+            //   "-" -> S7
+            //
+            fn __state10<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                scale: i32,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<((), Tok, ())>,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: &mut Option<((), Tok, ())>,
+                __sym1: ((), i32, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::RParen, __loc2)) => {
+                        let __sym2 = (__loc1, (__tok), __loc2);
+                        let __sym0 = __sym0.take().unwrap();
+                        __result = try!(__state15(scale, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, __tok @ Tok::Plus, __loc2)) => {
+                        let __sym2 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state6(scale, __tokens, __extra, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, __tok @ Tok::Minus, __loc2)) => {
+                        let __sym2 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state7(scale, __tokens, __extra, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 11
+            //     AllInputs = [Expr, "+", Factor]
+            //     OptionalInputs = [Expr, "+"]
+            //     FixedInputs = [Factor]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Expr = Expr "+" Factor (*) ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Factor = Factor (*) "*" Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Factor = Factor (*) "/" Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //
+            //   "*" -> S8
+            //   "/" -> S9
+            //   [")", "+", "-", EOF] -> Expr = Expr, "+", Factor => ActionFn(2);
+            //
+            fn __state11<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                scale: i32,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<((), Tok, ())>,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: &mut Option<((), i32, ())>,
+                __sym1: &mut Option<((), Tok, ())>,
+                __sym2: ((), i32, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::Times, __loc2)) => {
+                        let __sym3 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state8(scale, __tokens, __extra, __sym2, __sym3, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, __tok @ Tok::Div, __loc2)) => {
+                        let __sym3 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state9(scale, __tokens, __extra, __sym2, __sym3, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((_, Tok::RParen, _)) |
+                    Some((_, Tok::Plus, _)) |
+                    Some((_, Tok::Minus, _)) |
+                    None => {
+                        let __sym0 = __sym0.take().unwrap();
+                        let __sym1 = __sym1.take().unwrap();
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action2::<>(scale, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<((), Tok, ())> = None;
+                        let __sym0 = __sym0.take().unwrap();
+                        let __sym1 = __sym1.take().unwrap();
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action2::<>(scale, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 12
+            //     AllInputs = [Expr, "-", Factor]
+            //     OptionalInputs = [Expr, "-"]
+            //     FixedInputs = [Factor]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Expr = Expr "-" Factor (*) ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Factor = Factor (*) "*" Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //     Factor = Factor (*) "/" Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //
+            //   "*" -> S8
+            //   "/" -> S9
+            //   [")", "+", "-", EOF] -> Expr = Expr, "-", Factor => ActionFn(1);
+            //
+            fn __state12<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                scale: i32,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<((), Tok, ())>,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: &mut Option<((), i32, ())>,
+                __sym1: &mut Option<((), Tok, ())>,
+                __sym2: ((), i32, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::Times, __loc2)) => {
+                        let __sym3 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state8(scale, __tokens, __extra, __sym2, __sym3, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, __tok @ Tok::Div, __loc2)) => {
+                        let __sym3 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state9(scale, __tokens, __extra, __sym2, __sym3, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((_, Tok::RParen, _)) |
+                    Some((_, Tok::Plus, _)) |
+                    Some((_, Tok::Minus, _)) |
+                    None => {
+                        let __sym0 = __sym0.take().unwrap();
+                        let __sym1 = __sym1.take().unwrap();
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action1::<>(scale, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<((), Tok, ())> = None;
+                        let __sym0 = __sym0.take().unwrap();
+                        let __sym1 = __sym1.take().unwrap();
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action1::<>(scale, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 13
+            //     AllInputs = [Factor, "*", Term]
+            //     OptionalInputs = []
+            //     FixedInputs = [Factor, "*", Term]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Factor)
+            //
+            //     Factor = Factor "*" Term (*) ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //
+            //   [")", "*", "+", "-", "/", EOF] -> Factor = Factor, "*", Term => ActionFn(4);
+            //
+            fn __state13<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                scale: i32,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<((), Tok, ())>,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), i32, ()),
+                __sym1: ((), Tok, ()),
+                __sym2: ((), i32, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                match __lookahead {
+                    Some((_, Tok::RParen, _)) |
+                    Some((_, Tok::Times, _)) |
+                    Some((_, Tok::Plus, _)) |
+                    Some((_, Tok::Minus, _)) |
+                    Some((_, Tok::Div, _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action4::<>(scale, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Factor((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<((), Tok, ())> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action4::<>(scale, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Factor((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 14
+            //     AllInputs = [Factor, "/", Term]
+            //     OptionalInputs = []
+            //     FixedInputs = [Factor, "/", Term]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Factor)
+            //
+            //     Factor = Factor "/" Term (*) ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //
+            //   [")", "*", "+", "-", "/", EOF] -> Factor = Factor, "/", Term => ActionFn(5);
+            //
+            fn __state14<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                scale: i32,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<((), Tok, ())>,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), i32, ()),
+                __sym1: ((), Tok, ()),
+                __sym2: ((), i32, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                match __lookahead {
+                    Some((_, Tok::RParen, _)) |
+                    Some((_, Tok::Times, _)) |
+                    Some((_, Tok::Plus, _)) |
+                    Some((_, Tok::Minus, _)) |
+                    Some((_, Tok::Div, _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action5::<>(scale, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Factor((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<((), Tok, ())> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action5::<>(scale, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Factor((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 15
+            //     AllInputs = ["(", Expr, ")"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["(", Expr, ")"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Term)
+            //
+            //     Term = "(" Expr ")" (*) ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+            //
+            //   [")", "*", "+", "-", "/", EOF] -> Term = "(", Expr, ")" => ActionFn(8);
+            //
+            fn __state15<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                scale: i32,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), Tok, ()),
+                __sym1: ((), i32, ()),
+                __sym2: ((), Tok, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                match __lookahead {
+                    Some((_, Tok::RParen, _)) |
+                    Some((_, Tok::Times, _)) |
+                    Some((_, Tok::Plus, _)) |
+                    Some((_, Tok::Minus, _)) |
+                    Some((_, Tok::Div, _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action8::<>(scale, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<((), Tok, ())> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action8::<>(scale, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+        }
+        pub use self::__parse__Expr::parse_Expr;
+    }
+    mod __parse_table {
+
+        mod __parse_table {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use util::tok::Tok;
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__ToTriple;
+            #[allow(dead_code)]
+            pub enum __Symbol<>
+             {
+                Term_22_28_22(Tok),
+                Term_22_29_22(Tok),
+                Term_22_2a_22(Tok),
+                Term_22_2b_22(Tok),
+                Term_22_2d_22(Tok),
+                Term_22_2f_22(Tok),
+                TermFraction((i32, i32)),
+                TermNum(i32),
+                NtExpr(i32),
+                NtFactor(i32),
+                NtTerm(i32),
+                Nt____Expr(i32),
+            }
+            impl<> ::std::fmt::Debug for __Symbol<>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Symbol::Term_22_28_22(..) => __f.write_str("\"(\""),
+                        __Symbol::Term_22_29_22(..) => __f.write_str("\")\""),
+                        __Symbol::Term_22_2a_22(..) => __f.write_str("\"*\""),
+                        __Symbol::Term_22_2b_22(..) => __f.write_str("\"+\""),
+                        __Symbol::Term_22_2d_22(..) => __f.write_str("\"-\""),
+                        __Symbol::Term_22_2f_22(..) => __f.write_str("\"/\""),
+                        __Symbol::TermFraction(..) => __f.write_str("Fraction"),
+                        __Symbol::TermNum(..) => __f.write_str("Num"),
+                        __Symbol::NtExpr(..) => __f.write_str("Expr"),
+                        __Symbol::NtFactor(..) => __f.write_str("Factor"),
+                        __Symbol::NtTerm(..) => __f.write_str("Term"),
+                        __Symbol::Nt____Expr(..) => __f.write_str("__Expr"),
+                    }
+                }
+            }
+            const __ACTION: &'static [i8] = &[
+                // State 0
+                //     Expr = (*) Expr "+" Factor ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Expr = (*) Expr "-" Factor ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Expr = (*) Factor ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Factor = (*) Factor "*" Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Factor = (*) Factor "/" Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Factor = (*) Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Term = (*) Num ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     __Expr = (*) Expr ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                5,  // on "(", goto 4
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                0,  // on "-", error
+                0,  // on "/", error
+                0,  // on Fraction, error
+                6,  // on Num, goto 5
+
+                // State 1
+                //     Expr = Expr (*) "+" Factor ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Expr = Expr (*) "-" Factor ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     __Expr = Expr (*) ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                0,  // on "*", error
+                7,  // on "+", goto 6
+                8,  // on "-", goto 7
+                0,  // on "/", error
+                0,  // on Fraction, error
+                0,  // on Num, error
+
+                // State 2
+                //     Expr = Factor (*) ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Factor = Factor (*) "*" Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Factor = Factor (*) "/" Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                0,  // on "(", error
+                -3,  // on ")", reduce `Expr = Factor => ActionFn(3);`
+                9,  // on "*", goto 8
+                -3,  // on "+", reduce `Expr = Factor => ActionFn(3);`
+                -3,  // on "-", reduce `Expr = Factor => ActionFn(3);`
+                10,  // on "/", goto 9
+                0,  // on Fraction, error
+                0,  // on Num, error
+
+                // State 3
+                //     Factor = Term (*) ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                0,  // on "(", error
+                -6,  // on ")", reduce `Factor = Term => ActionFn(6);`
+                -6,  // on "*", reduce `Factor = Term => ActionFn(6);`
+                -6,  // on "+", reduce `Factor = Term => ActionFn(6);`
+                -6,  // on "-", reduce `Factor = Term => ActionFn(6);`
+                -6,  // on "/", reduce `Factor = Term => ActionFn(6);`
+                0,  // on Fraction, error
+                0,  // on Num, error
+
+                // State 4
+                //     Expr = (*) Expr "+" Factor ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Expr = (*) Expr "-" Factor ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Expr = (*) Factor ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Factor = (*) Factor "*" Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Factor = (*) Factor "/" Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Factor = (*) Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Term = "(" (*) Expr ")" ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Term = (*) Num ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                5,  // on "(", goto 4
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                0,  // on "-", error
+                0,  // on "/", error
+                0,  // on Fraction, error
+                6,  // on Num, goto 5
+
+                // State 5
+                //     Term = Num (*) ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                0,  // on "(", error
+                -7,  // on ")", reduce `Term = Num => ActionFn(7);`
+                -7,  // on "*", reduce `Term = Num => ActionFn(7);`
+                -7,  // on "+", reduce `Term = Num => ActionFn(7);`
+                -7,  // on "-", reduce `Term = Num => ActionFn(7);`
+                -7,  // on "/", reduce `Term = Num => ActionFn(7);`
+                0,  // on Fraction, error
+                0,  // on Num, error
+
+                // State 6
+                //     Expr = Expr "+" (*) Factor ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Factor = (*) Factor "*" Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Factor = (*) Factor "/" Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Factor = (*) Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Term = (*) Num ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                5,  // on "(", goto 4
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                0,  // on "-", error
+                0,  // on "/", error
+                0,  // on Fraction, error
+                6,  // on Num, goto 5
+
+                // State 7
+                //     Expr = Expr "-" (*) Factor ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Factor = (*) Factor "*" Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Factor = (*) Factor "/" Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Factor = (*) Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Term = (*) Num ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                5,  // on "(", goto 4
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                0,  // on "-", error
+                0,  // on "/", error
+                0,  // on Fraction, error
+                6,  // on Num, goto 5
+
+                // State 8
+                //     Factor = Factor "*" (*) Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Term = (*) Num ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                5,  // on "(", goto 4
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                0,  // on "-", error
+                0,  // on "/", error
+                0,  // on Fraction, error
+                6,  // on Num, goto 5
+
+                // State 9
+                //     Factor = Factor "/" (*) Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Term = (*) Num ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                5,  // on "(", goto 4
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                0,  // on "-", error
+                0,  // on "/", error
+                0,  // on Fraction, error
+                6,  // on Num, goto 5
+
+                // State 10
+                //     Expr = Expr (*) "+" Factor ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Expr = Expr (*) "-" Factor ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Term = "(" Expr (*) ")" ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                0,  // on "(", error
+                16,  // on ")", goto 15
+                0,  // on "*", error
+                7,  // on "+", goto 6
+                8,  // on "-", goto 7
+                0,  // on "/", error
+                0,  // on Fraction, error
+                0,  // on Num, error
+
+                // State 11
+                //     Expr = Expr "+" Factor (*) ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Factor = Factor (*) "*" Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Factor = Factor (*) "/" Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                0,  // on "(", error
+                -2,  // on ")", reduce `Expr = Expr, "+", Factor => ActionFn(2);`
+                9,  // on "*", goto 8
+                -2,  // on "+", reduce `Expr = Expr, "+", Factor => ActionFn(2);`
+                -2,  // on "-", reduce `Expr = Expr, "+", Factor => ActionFn(2);`
+                10,  // on "/", goto 9
+                0,  // on Fraction, error
+                0,  // on Num, error
+
+                // State 12
+                //     Expr = Expr "-" Factor (*) ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Factor = Factor (*) "*" Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                //     Factor = Factor (*) "/" Term ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                0,  // on "(", error
+                -1,  // on ")", reduce `Expr = Expr, "-", Factor => ActionFn(1);`
+                9,  // on "*", goto 8
+                -1,  // on "+", reduce `Expr = Expr, "-", Factor => ActionFn(1);`
+                -1,  // on "-", reduce `Expr = Expr, "-", Factor => ActionFn(1);`
+                10,  // on "/", goto 9
+                0,  // on Fraction, error
+                0,  // on Num, error
+
+                // State 13
+                //     Factor = Factor "*" Term (*) ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                0,  // on "(", error
+                -4,  // on ")", reduce `Factor = Factor, "*", Term => ActionFn(4);`
+                -4,  // on "*", reduce `Factor = Factor, "*", Term => ActionFn(4);`
+                -4,  // on "+", reduce `Factor = Factor, "*", Term => ActionFn(4);`
+                -4,  // on "-", reduce `Factor = Factor, "*", Term => ActionFn(4);`
+                -4,  // on "/", reduce `Factor = Factor, "*", Term => ActionFn(4);`
+                0,  // on Fraction, error
+                0,  // on Num, error
+
+                // State 14
+                //     Factor = Factor "/" Term (*) ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                0,  // on "(", error
+                -5,  // on ")", reduce `Factor = Factor, "/", Term => ActionFn(5);`
+                -5,  // on "*", reduce `Factor = Factor, "/", Term => ActionFn(5);`
+                -5,  // on "+", reduce `Factor = Factor, "/", Term => ActionFn(5);`
+                -5,  // on "-", reduce `Factor = Factor, "/", Term => ActionFn(5);`
+                -5,  // on "/", reduce `Factor = Factor, "/", Term => ActionFn(5);`
+                0,  // on Fraction, error
+                0,  // on Num, error
+
+                // State 15
+                //     Term = "(" Expr ")" (*) ["(", ")", "*", "+", "-", "/", Fraction, Num, EOF]
+                0,  // on "(", error
+                -8,  // on ")", reduce `Term = "(", Expr, ")" => ActionFn(8);`
+                -8,  // on "*", reduce `Term = "(", Expr, ")" => ActionFn(8);`
+                -8,  // on "+", reduce `Term = "(", Expr, ")" => ActionFn(8);`
+                -8,  // on "-", reduce `Term = "(", Expr, ")" => ActionFn(8);`
+                -8,  // on "/", reduce `Term = "(", Expr, ")" => ActionFn(8);`
+                0,  // on Fraction, error
+                0,  // on Num, error
+
+            ];
+            const __EOF_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // on EOF, error
+
+                // State 1
+                -9,  // on EOF, reduce `__Expr = Expr => ActionFn(0);`
+
+                // State 2
+                -3,  // on EOF, reduce `Expr = Factor => ActionFn(3);`
+
+                // State 3
+                -6,  // on EOF, reduce `Factor = Term => ActionFn(6);`
+
+                // State 4
+                0,  // on EOF, error
+
+                // State 5
+                -7,  // on EOF, reduce `Term = Num => ActionFn(7);`
+
+                // State 6
+                0,  // on EOF, error
+
+                // State 7
+                0,  // on EOF, error
+
+                // State 8
+                0,  // on EOF, error
+
+                // State 9
+                0,  // on EOF, error
+
+                // State 10
+                0,  // on EOF, error
+
+                // State 11
+                -2,  // on EOF, reduce `Expr = Expr, "+", Factor => ActionFn(2);`
+
+                // State 12
+                -1,  // on EOF, reduce `Expr = Expr, "-", Factor => ActionFn(1);`
+
+                // State 13
+                -4,  // on EOF, reduce `Factor = Factor, "*", Term => ActionFn(4);`
+
+                // State 14
+                -5,  // on EOF, reduce `Factor = Factor, "/", Term => ActionFn(5);`
+
+                // State 15
+                -8,  // on EOF, reduce `Term = "(", Expr, ")" => ActionFn(8);`
+
+            ];
+            const __GOTO: &'static [u8] = &[
+                // State 0
+                2,  // on Expr, goto 1
+                3,  // on Factor, goto 2
+                4,  // on Term, goto 3
+                0,  // on __Expr, error
+
+                // State 1
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 2
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 3
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 4
+                11,  // on Expr, goto 10
+                3,  // on Factor, goto 2
+                4,  // on Term, goto 3
+                0,  // on __Expr, error
+
+                // State 5
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 6
+                0,  // on Expr, error
+                12,  // on Factor, goto 11
+                4,  // on Term, goto 3
+                0,  // on __Expr, error
+
+                // State 7
+                0,  // on Expr, error
+                13,  // on Factor, goto 12
+                4,  // on Term, goto 3
+                0,  // on __Expr, error
+
+                // State 8
+                0,  // on Expr, error
+                0,  // on Factor, error
+                14,  // on Term, goto 13
+                0,  // on __Expr, error
+
+                // State 9
+                0,  // on Expr, error
+                0,  // on Factor, error
+                15,  // on Term, goto 14
+                0,  // on __Expr, error
+
+                // State 10
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 11
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 12
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 13
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 14
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 15
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+            ];
+            const __DEFAULT_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // no default reduction
+
+                // State 1
+                0,  // no default reduction
+
+                // State 2
+                0,  // no default reduction
+
+                // State 3
+                -6,  // on any token, reduce `Factor = Term => ActionFn(6);`
+
+                // State 4
+                0,  // no default reduction
+
+                // State 5
+                -7,  // on any token, reduce `Term = Num => ActionFn(7);`
+
+                // State 6
+                0,  // no default reduction
+
+                // State 7
+                0,  // no default reduction
+
+                // State 8
+                0,  // no default reduction
+
+                // State 9
+                0,  // no default reduction
+
+                // State 10
+                0,  // no default reduction
+
+                // State 11
+                0,  // no default reduction
+
+                // State 12
+                0,  // no default reduction
+
+                // State 13
+                -4,  // on any token, reduce `Factor = Factor, "*", Term => ActionFn(4);`
+
+                // State 14
+                -5,  // on any token, reduce `Factor = Factor, "/", Term => ActionFn(5);`
+
+                // State 15
+                -8,  // on any token, reduce `Term = "(", Expr, ")" => ActionFn(8);`
+
+            ];
+            fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+                const __TERMINAL: &'static [&'static str] = &[
+                    r###""(""###,
+                    r###"")""###,
+                    r###""*""###,
+                    r###""+""###,
+                    r###""-""###,
+                    r###""/""###,
+                    r###"Fraction"###,
+                    r###"Num"###,
+                ];
+                __ACTION[(__state * 8)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+                    if state == 0 {
+                        None
+                    } else {
+                        Some(terminal.to_string())
+                    }
+                }).collect()
+            }
+            #[allow(dead_code)]
+            pub fn parse_Expr<
+                __TOKEN: __ToTriple<Error=&'static str>,
+                __TOKENS: IntoIterator<Item=__TOKEN>,
+            >(
+                scale: i32,
+                __tokens0: __TOKENS,
+            ) -> Result<i32, __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let __tokens = __tokens0.into_iter();
+                let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(scale, 0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() })
+            }
+            #[allow(dead_code)]
+            pub fn parse_Expr_cancelable<
+                __TOKEN: __ToTriple<Error=&'static str>,
+                __TOKENS: IntoIterator<Item=__TOKEN>,
+            >(
+                scale: i32,
+                __tokens0: __TOKENS,
+                __cancel: &::std::sync::atomic::AtomicBool,
+            ) -> Result<i32, __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let __tokens = __tokens0.into_iter();
+                let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(scale, 0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() })
+            }
+
+            #[allow(dead_code)]
+            pub struct ExprParser {
+                __states: ::std::vec::Vec<i32>,
+                __symbols: __lalrpop_util::stack::Recycled,
+                __cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+            }
+
+            #[allow(dead_code)]
+            impl ExprParser {
+                pub fn new() -> ExprParser {
+                    ExprParser { __states: vec![], __symbols: __lalrpop_util::stack::Recycled::new(), __cancel: None }
+                }
+
+                pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+                    self.__cancel = Some(flag);
+                }
+
+                pub fn parse_in_place<__TOKEN: __ToTriple<Error=&'static str>, __TOKENS: IntoIterator<Item=__TOKEN>>(
+                    &mut self,
+                    scale: i32,
+                    __tokens0: __TOKENS,
+                ) -> Result<i32, __lalrpop_util::ParseError<(), Tok, &'static str>>
+                {
+                    let __tokens = __tokens0.into_iter();
+                    let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+                    let mut __symbols = self.__symbols.take();
+                    let __result = __parse::<>(scale, 0, &mut __tokens, &mut self.__states, &mut __symbols, self.__cancel.as_ref().map(|c| &**c)).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() });
+                    self.__symbols.put(__symbols);
+                    __result
+                }
+            }
+
+            fn __parse<
+            >(
+                scale: i32,
+                __start_state: i32,
+                mut __tokens: &mut Iterator<Item = Result<((), Tok, ()), &'static str>>,
+                mut __states: &mut ::std::vec::Vec<i32>,
+                mut __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+                __cancel: Option<&::std::sync::atomic::AtomicBool>,
+            ) -> Result<__Symbol<>, __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                __states.clear();
+                __states.push(__start_state);
+                __symbols.clear();
+                let mut __integer;
+                let mut __lookahead;
+                let __last_location: &mut () = &mut Default::default();
+                let mut __default_reduced = [0_usize; 16];
+                let mut __default_reduced_len = 0;
+                '__shift: loop {
+                    loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __DEFAULT_ACTION[__state] as i32;
+                        if __action == 0 {
+                            break;
+                        }
+                        if __default_reduced_len < 16 {
+                            __default_reduced[__default_reduced_len] = __state;
+                            __default_reduced_len += 1;
+                        }
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(scale, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    }
+                    __lookahead = match __tokens.next() {
+                        Some(Ok(v)) => v,
+                        None => break '__shift,
+                        Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                    };
+                    *__last_location = __lookahead.2.clone();
+                    __integer = match __lookahead.1 {
+                        Tok::LParen if true => 0,
+                        Tok::RParen if true => 1,
+                        Tok::Times if true => 2,
+                        Tok::Plus if true => 3,
+                        Tok::Minus if true => 4,
+                        Tok::Div if true => 5,
+                        Tok::Fraction(_, _) if true => 6,
+                        Tok::Num(_) if true => 7,
+                        _ => {
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| true).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match Some(__lookahead) {
+                                Some(__token) => {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error);
+                        }
+                    };
+                    '__inner: loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __ACTION[__state * 8 + __integer] as i32;
+                        if __action > 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                                }
+                            }
+                            let __symbol = match __integer {
+                                0 => match __lookahead.1 {
+                                    __tok @ Tok::LParen => __Symbol::Term_22_28_22((__tok)),
+                                    _ => unreachable!(),
+                                },
+                                1 => match __lookahead.1 {
+                                    __tok @ Tok::RParen => __Symbol::Term_22_29_22((__tok)),
+                                    _ => unreachable!(),
+                                },
+                                2 => match __lookahead.1 {
+                                    __tok @ Tok::Times => __Symbol::Term_22_2a_22((__tok)),
+                                    _ => unreachable!(),
+                                },
+                                3 => match __lookahead.1 {
+                                    __tok @ Tok::Plus => __Symbol::Term_22_2b_22((__tok)),
+                                    _ => unreachable!(),
+                                },
+                                4 => match __lookahead.1 {
+                                    __tok @ Tok::Minus => __Symbol::Term_22_2d_22((__tok)),
+                                    _ => unreachable!(),
+                                },
+                                5 => match __lookahead.1 {
+                                    __tok @ Tok::Div => __Symbol::Term_22_2f_22((__tok)),
+                                    _ => unreachable!(),
+                                },
+                                6 => match __lookahead.1 {
+                                    Tok::Fraction(__tok0, __tok1) => __Symbol::TermFraction((__tok0, __tok1)),
+                                    _ => unreachable!(),
+                                },
+                                7 => match __lookahead.1 {
+                                    Tok::Num(__tok0) => __Symbol::TermNum((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                _ => unreachable!(),
+                            };
+                            __states.push(__action - 1);
+                            __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                            __default_reduced_len = 0;
+                            continue '__shift;
+                        } else if __action < 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                                }
+                            }
+                            if let Some(r) = __reduce(scale, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                                if r.is_err() {
+                                    return r;
+                                }
+                                return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] });
+                            }
+                        } else {
+                            let mut __err_lookahead = Some(__lookahead);
+                            let mut __err_integer: Option<usize> = Some(__integer);
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 8 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match __err_lookahead {
+                                Some(__token) => {
+                                    if __accepts(scale,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                        __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                    } else {
+                                        __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                    }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error)
+                        }
+                    }
+                }
+                loop {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __action = __EOF_ACTION[__state] as i32;
+                    if __action < 0 {
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(scale, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    } else {
+                        let mut __err_lookahead = None;
+                        let mut __err_integer: Option<usize> = None;
+                        let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 8 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                        let __expected = __expected_tokens(__state);
+                        let __error = match __err_lookahead {
+                            Some(__token) => {
+                                if __accepts(scale,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                    __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                } else {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                            }
+                            None => {
+                                __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                            }
+                        };
+                        return Err(__error)
+                    }
+                }
+            }
+            fn __accepts<
+            >(
+                scale: i32,
+                __error_state: i32,
+                __states: & [i32],
+                __opt_integer: Option<usize>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> bool
+            {
+                let mut __states = __states.to_vec();
+                __states.push(__error_state);
+                loop {
+                    let mut __states_len = __states.len();
+                    let __top = __states[__states_len - 1];
+                    let __action = match __opt_integer {
+                        None => __EOF_ACTION[__top as usize] as i32,
+                        Some(__integer) => __ACTION[(__top * 8) as usize + __integer] as i32,
+                    };
+                    if __action == 0 { return false; }
+                    if __action > 0 { return true; }
+                    let (__to_pop, __nt) = match -__action {
+                        // simulate Expr = Expr, "-", Factor => ActionFn(1);
+                        1 => {
+                            (3, 0)
+                        }
+                        // simulate Expr = Expr, "+", Factor => ActionFn(2);
+                        2 => {
+                            (3, 0)
+                        }
+                        // simulate Expr = Factor => ActionFn(3);
+                        3 => {
+                            (1, 0)
+                        }
+                        // simulate Factor = Factor, "*", Term => ActionFn(4);
+                        4 => {
+                            (3, 1)
+                        }
+                        // simulate Factor = Factor, "/", Term => ActionFn(5);
+                        5 => {
+                            (3, 1)
+                        }
+                        // simulate Factor = Term => ActionFn(6);
+                        6 => {
+                            (1, 1)
+                        }
+                        // simulate Term = Num => ActionFn(7);
+                        7 => {
+                            (1, 2)
+                        }
+                        // simulate Term = "(", Expr, ")" => ActionFn(8);
+                        8 => {
+                            (3, 2)
+                        }
+                        // simulate __Expr = Expr => ActionFn(0);
+                        9 => return true,
+                        _ => panic!("invalid action code {}", __action)
+                    };
+                    __states_len -= __to_pop;
+                    __states.truncate(__states_len);
+                    let __top = __states[__states_len - 1];
+                    let __next_state = __GOTO[(__top * 4 + __nt) as usize] as i32 - 1;
+                    __states.push(__next_state);
+                }
+            }
+            pub fn __reduce<
+            >(
+                scale: i32,
+                __action: i32,
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Option<Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+            {
+                let __nonterminal = match -__action {
+                    1 => match __reduce_Expr_0(scale, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    2 => match __reduce_Expr_1(scale, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    3 => match __reduce_Expr_2(scale, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    4 => match __reduce_Factor_0(scale, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    5 => match __reduce_Factor_1(scale, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    6 => match __reduce_Factor_2(scale, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    7 => match __reduce_Term_0(scale, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    8 => match __reduce_Term_1(scale, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    9 => match __reduce_____Expr_0(scale, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    _ => panic!("invalid action code {}", __action)
+                };
+                let __state = *__states.last().unwrap() as usize;
+                let __next_state = __GOTO[__state * 4 + __nonterminal] as i32 - 1;
+                __states.push(__next_state);
+                None
+            }
+
+            fn __reduce_Expr_0<
+            >(
+                scale: i32,
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+            {
+                // Expr = Expr, "-", Factor => ActionFn(1);
+                let __sym2 = __pop_NtFactor(__symbols);
+                let __sym1 = __pop_Term_22_2d_22(__symbols);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action1::<>(scale, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce_Expr_1<
+            >(
+                scale: i32,
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+            {
+                // Expr = Expr, "+", Factor => ActionFn(2);
+                let __sym2 = __pop_NtFactor(__symbols);
+                let __sym1 = __pop_Term_22_2b_22(__symbols);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action2::<>(scale, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce_Expr_2<
+            >(
+                scale: i32,
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+            {
+                // Expr = Factor => ActionFn(3);
+                let __sym0 = __pop_NtFactor(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action3::<>(scale, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce_Factor_0<
+            >(
+                scale: i32,
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+            {
+                // Factor = Factor, "*", Term => ActionFn(4);
+                let __sym2 = __pop_NtTerm(__symbols);
+                let __sym1 = __pop_Term_22_2a_22(__symbols);
+                let __sym0 = __pop_NtFactor(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action4::<>(scale, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtFactor(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce_Factor_1<
+            >(
+                scale: i32,
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+            {
+                // Factor = Factor, "/", Term => ActionFn(5);
+                let __sym2 = __pop_NtTerm(__symbols);
+                let __sym1 = __pop_Term_22_2f_22(__symbols);
+                let __sym0 = __pop_NtFactor(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action5::<>(scale, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtFactor(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce_Factor_2<
+            >(
+                scale: i32,
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+            {
+                // Factor = Term => ActionFn(6);
+                let __sym0 = __pop_NtTerm(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action6::<>(scale, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtFactor(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce_Term_0<
+            >(
+                scale: i32,
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+            {
+                // Term = Num => ActionFn(7);
+                let __sym0 = __pop_TermNum(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action7::<>(scale, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce_Term_1<
+            >(
+                scale: i32,
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+            {
+                // Term = "(", Expr, ")" => ActionFn(8);
+                let __sym2 = __pop_Term_22_29_22(__symbols);
+                let __sym1 = __pop_NtExpr(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action8::<>(scale, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce_____Expr_0<
+            >(
+                scale: i32,
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+            {
+                // __Expr = Expr => ActionFn(0);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action0::<>(scale, __sym0);
+                Err(Ok(__Symbol::Nt____Expr(__nt)))
+            }
+            fn __pop_Term_22_28_22<
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+            ) -> ((), Tok, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_28_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_29_22<
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+            ) -> ((), Tok, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_29_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_2a_22<
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+            ) -> ((), Tok, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2a_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_2b_22<
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+            ) -> ((), Tok, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2b_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_2d_22<
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+            ) -> ((), Tok, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2d_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_2f_22<
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+            ) -> ((), Tok, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2f_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_TermFraction<
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+            ) -> ((), (i32, i32), ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::TermFraction(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_TermNum<
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+            ) -> ((), i32, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::TermNum(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtExpr<
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+            ) -> ((), i32, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtExpr(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtFactor<
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+            ) -> ((), i32, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtFactor(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtTerm<
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+            ) -> ((), i32, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtTerm(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt____Expr<
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+            ) -> ((), i32, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt____Expr(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+        }
+        pub use self::__parse_table::parse_Expr;
+        pub use self::__parse_table::parse_Expr_cancelable;
+        pub use self::__parse_table::ExprParser;
+    }
+    pub use self::__parse_table::parse_Expr_cancelable;
+    pub use self::__parse_table::ExprParser;
+}
+pub use self::__parse__Expr::parse_Expr;
+pub use self::__parse__Expr::parse_Expr_cancelable;
+pub use self::__parse__Expr::ExprParser;
+
+#[allow(unused_variables)]
+fn __action0<
+>(
+    scale: i32,
+    (_, __0, _): ((), i32, ()),
+) -> i32
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action1<
+>(
+    scale: i32,
+    (_, l, _): ((), i32, ()),
+    (_, _, _): ((), Tok, ()),
+    (_, r, _): ((), i32, ()),
+) -> i32
+{
+    //@ src/expr_module_attributes.lalrpop:20:32 (1 lines)
+    l - r
+}
+
+#[allow(unused_variables)]
+fn __action2<
+>(
+    scale: i32,
+    (_, l, _): ((), i32, ()),
+    (_, _, _): ((), Tok, ()),
+    (_, r, _): ((), i32, ()),
+) -> i32
+{
+    //@ src/expr_module_attributes.lalrpop:21:32 (1 lines)
+    l + r
+}
+
+#[allow(unused_variables)]
+fn __action3<
+>(
+    scale: i32,
+    (_, __0, _): ((), i32, ()),
+) -> i32
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action4<
+>(
+    scale: i32,
+    (_, l, _): ((), i32, ()),
+    (_, _, _): ((), Tok, ()),
+    (_, r, _): ((), i32, ()),
+) -> i32
+{
+    //@ src/expr_module_attributes.lalrpop:26:32 (1 lines)
+    l * r
+}
+
+#[allow(unused_variables)]
+fn __action5<
+>(
+    scale: i32,
+    (_, l, _): ((), i32, ()),
+    (_, _, _): ((), Tok, ()),
+    (_, r, _): ((), i32, ()),
+) -> i32
+{
+    //@ src/expr_module_attributes.lalrpop:27:32 (1 lines)
+    l / r
+}
+
+#[allow(unused_variables)]
+fn __action6<
+>(
+    scale: i32,
+    (_, __0, _): ((), i32, ()),
+) -> i32
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action7<
+>(
+    scale: i32,
+    (_, n, _): ((), i32, ()),
+) -> i32
+{
+    //@ src/expr_module_attributes.lalrpop:32:16 (1 lines)
+    n * scale
+}
+
+#[allow(unused_variables)]
+fn __action8<
+>(
+    scale: i32,
+    (_, _, _): ((), Tok, ()),
+    (_, __0, _): ((), i32, ()),
+    (_, _, _): ((), Tok, ()),
+) -> i32
+{
+    (__0)
+}
+
+pub trait __ToTriple<> {
+    type Error;
+    fn to_triple(value: Self) -> Result<((),Tok,()),Self::Error>;
+}
+
+impl<> __ToTriple<> for Tok {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<((),Tok,()),&'static str> {
+        Ok(((), value, ()))
+    }
+}
+impl<> __ToTriple<> for Result<(Tok),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<((),Tok,()),&'static str> {
+        value.map(|v| ((), v, ()))
+    }
+}
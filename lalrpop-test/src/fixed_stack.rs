@@ -0,0 +1,755 @@
+// auto-generated: "lalrpop 0.15.0"
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+pub use self::__parse_table::parse_List_with_stack;
+pub use self::__parse_table::parse_List_with_stack_cancelable;
+pub use self::__parse_table::expected_tokens;
+
+mod __parse_table {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    extern crate core as __core;
+    use super::__intern_token::Token;
+    #[allow(dead_code)]
+    pub enum __Symbol<'input>
+     {
+        Term_22_5b_22(&'input str),
+        Term_22_5d_22(&'input str),
+        Termr_23_22_5ba_2dz_5d_2b_22_23(&'input str),
+        NtItem(String),
+        NtItems(Vec<String>),
+        NtList(Vec<String>),
+        Nt____List(Vec<String>),
+    }
+    impl<'input> __core::fmt::Debug for __Symbol<'input>
+    {
+        fn fmt(&self, __f: &mut __core::fmt::Formatter) -> __core::fmt::Result {
+            match *self {
+                __Symbol::Term_22_5b_22(..) => __f.write_str("\"[\""),
+                __Symbol::Term_22_5d_22(..) => __f.write_str("\"]\""),
+                __Symbol::Termr_23_22_5ba_2dz_5d_2b_22_23(..) => __f.write_str("r#\"[a-z]+\"#"),
+                __Symbol::NtItem(..) => __f.write_str("Item"),
+                __Symbol::NtItems(..) => __f.write_str("Items"),
+                __Symbol::NtList(..) => __f.write_str("List"),
+                __Symbol::Nt____List(..) => __f.write_str("__List"),
+            }
+        }
+    }
+    const __ACTION: &'static [i8] = &[
+        // State 0
+        3, 0, 0,
+        // State 1
+        0, 0, 0,
+        // State 2
+        -3, -3, -3,
+        // State 3
+        3, 7, 8,
+        // State 4
+        -4, -4, -4,
+        // State 5
+        -2, -2, -2,
+        // State 6
+        -5, -5, -5,
+        // State 7
+        -1, -1, -1,
+    ];
+    const __EOF_ACTION: &'static [i8] = &[
+        // State 0
+        0,
+        // State 1
+        -6,
+        // State 2
+        0,
+        // State 3
+        0,
+        // State 4
+        0,
+        // State 5
+        0,
+        // State 6
+        -5,
+        // State 7
+        0,
+    ];
+    const __GOTO: &'static [u8] = &[
+        // State 0
+        0, 0, 2, 0,
+        // State 1
+        0, 0, 0, 0,
+        // State 2
+        0, 4, 0, 0,
+        // State 3
+        5, 0, 6, 0,
+        // State 4
+        0, 0, 0, 0,
+        // State 5
+        0, 0, 0, 0,
+        // State 6
+        0, 0, 0, 0,
+        // State 7
+        0, 0, 0, 0,
+    ];
+    fn __expected_tokens(__state: usize) -> __lalrpop_util::Expected {
+        __lalrpop_util::expected(__state, expected_tokens(__state))
+    }
+    pub fn expected_tokens(__state: usize) -> impl Iterator<Item = &'static str> {
+        const __TERMINAL: &'static [&'static str] = &[
+            r###""[""###,
+            r###""]""###,
+            r###"r#"[a-z]+"#"###,
+        ];
+        __ACTION[(__state * 3)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+            if state == 0 {
+                None
+            } else {
+                Some(*terminal)
+            }
+        })
+    }
+    #[allow(dead_code)]
+    pub fn parse_List_with_stack<
+        'input,
+    >(
+        input: &'input str,
+        __stack: &mut [u8],
+    ) -> Result<Vec<String>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let mut __tokens = super::__intern_token::__Matcher::new(input);
+        let (mut __states, mut __symbols) = __lalrpop_util::stack::split_buffer(__stack);
+        __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____List(__v) => Ok(__v), _ => unreachable!() })
+    }
+    #[allow(dead_code)]
+    pub fn parse_List_with_stack_cancelable<
+        'input,
+    >(
+        input: &'input str,
+        __stack: &mut [u8],
+        __cancel: &__core::sync::atomic::AtomicBool,
+    ) -> Result<Vec<String>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let mut __tokens = super::__intern_token::__Matcher::new(input);
+        let (mut __states, mut __symbols) = __lalrpop_util::stack::split_buffer(__stack);
+        __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____List(__v) => Ok(__v), _ => unreachable!() })
+    }
+
+    fn __parse<
+        'input,
+    >(
+        input: &'input str,
+        __start_state: i32,
+        mut __tokens: &mut Iterator<Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+        mut __states: &mut __lalrpop_util::stack::FixedStack<i32>,
+        mut __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<'input>,usize)>,
+        __cancel: Option<&__core::sync::atomic::AtomicBool>,
+    ) -> Result<__Symbol<'input>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        __states.clear();
+        if __states.capacity() == 0 {
+            return Err(__lalrpop_util::ParseError::StackCapacity { location: Default::default(), capacity: 0 });
+        }
+        __states.push(__start_state);
+        __symbols.clear();
+        let mut __integer;
+        let mut __lookahead;
+        let __last_location: &mut usize = &mut Default::default();
+        '__shift: loop {
+            __lookahead = match __tokens.next() {
+                Some(Ok(v)) => v,
+                None => break '__shift,
+                Some(Err(e)) => return Err(e),
+            };
+            *__last_location = __lookahead.2.clone();
+            __integer = match __lookahead.1 {
+                Token(1, _) if true => 0,
+                Token(2, _) if true => 1,
+                Token(0, _) if true => 2,
+                _ => {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __expected = __expected_tokens(__state);
+                    let __error = match Some(__lookahead) {
+                        Some(__token) => {
+                            __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                        }
+                        None => {
+                            __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                        }
+                    };
+                    return Err(__error);
+                }
+            };
+            '__inner: loop {
+                let __state = *__states.last().unwrap() as usize;
+                let __action = __ACTION[__state * 3 + __integer] as i32;
+                if __action > 0 {
+                    if let Some(__flag) = __cancel {
+                        if __flag.load(__core::sync::atomic::Ordering::Relaxed) {
+                            return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                        }
+                    }
+                    if __symbols.len() >= __symbols.capacity() {
+                        return Err(__lalrpop_util::ParseError::StackCapacity { location: __lookahead.0, capacity: __symbols.capacity() });
+                    }
+                    let __symbol = match __integer {
+                        0 => match __lookahead.1 {
+                            Token(1, __tok0) => __Symbol::Term_22_5b_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        1 => match __lookahead.1 {
+                            Token(2, __tok0) => __Symbol::Term_22_5d_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        2 => match __lookahead.1 {
+                            Token(0, __tok0) => __Symbol::Termr_23_22_5ba_2dz_5d_2b_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        _ => unreachable!(),
+                    };
+                    __states.push(__action - 1);
+                    __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                    continue '__shift;
+                } else if __action < 0 {
+                    if let Some(__flag) = __cancel {
+                        if __flag.load(__core::sync::atomic::Ordering::Relaxed) {
+                            return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                        }
+                    }
+                    if let Some(r) = __reduce(input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, __core::marker::PhantomData::<()>) {
+                        if r.is_err() {
+                            return r;
+                        }
+                        return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: Default::default() });
+                    }
+                } else {
+                    let mut __err_lookahead = Some(__lookahead);
+                    let mut __err_integer: Option<usize> = Some(__integer);
+                    let __state = *__states.last().unwrap() as usize;
+                    let __expected = __expected_tokens(__state);
+                    let __error = match __err_lookahead {
+                        Some(__token) => {
+                            if __states.pop().map_or(false, |__top| __accepts(input,  __top, __states, None, __core::marker::PhantomData::<()>)) {
+                                __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                            } else {
+                                __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                            }
+                        }
+                        None => {
+                            __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                        }
+                    };
+                    return Err(__error)
+                }
+            }
+        }
+        loop {
+            let __state = *__states.last().unwrap() as usize;
+            let __action = __EOF_ACTION[__state] as i32;
+            if __action < 0 {
+                if let Some(__flag) = __cancel {
+                    if __flag.load(__core::sync::atomic::Ordering::Relaxed) {
+                        return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                    }
+                }
+                if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, __core::marker::PhantomData::<()>) {
+                    return r;
+                }
+            } else {
+                let mut __err_lookahead = None;
+                let mut __err_integer: Option<usize> = None;
+                let __state = *__states.last().unwrap() as usize;
+                let __expected = __expected_tokens(__state);
+                let __error = match __err_lookahead {
+                    Some(__token) => {
+                        if __states.pop().map_or(false, |__top| __accepts(input,  __top, __states, None, __core::marker::PhantomData::<()>)) {
+                            __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                        } else {
+                            __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                        }
+                    }
+                    None => {
+                        __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                    }
+                };
+                return Err(__error)
+            }
+        }
+    }
+    fn __accepts<
+        'input,
+    >(
+        input: &'input str,
+        __error_state: i32,
+        __states: &mut __lalrpop_util::stack::FixedStack<i32>,
+        __opt_integer: Option<usize>,
+        _: __core::marker::PhantomData<()>,
+    ) -> bool
+    {
+        __states.push(__error_state);
+        loop {
+            let mut __states_len = __states.len();
+            let __top = __states[__states_len - 1];
+            let __action = match __opt_integer {
+                None => __EOF_ACTION[__top as usize] as i32,
+                Some(__integer) => __ACTION[(__top * 3) as usize + __integer] as i32,
+            };
+            if __action == 0 { return false; }
+            if __action > 0 { return true; }
+            let (__to_pop, __nt) = match -__action {
+                1 => {
+                    (1, 0)
+                }
+                2 => {
+                    (1, 0)
+                }
+                3 => {
+                    (0, 1)
+                }
+                4 => {
+                    (2, 1)
+                }
+                5 => {
+                    (3, 2)
+                }
+                6 => return true,
+                _ => panic!("invalid action code {}", __action)
+            };
+            __states_len -= __to_pop;
+            __states.truncate(__states_len);
+            let __top = __states[__states_len - 1];
+            let __next_state = __GOTO[(__top * 4 + __nt) as usize] as i32 - 1;
+            if __states.len() >= __states.capacity() { return false; }
+            __states.push(__next_state);
+        }
+    }
+    pub fn __reduce<
+        'input,
+    >(
+        input: &'input str,
+        __action: i32,
+        __lookahead_start: Option<&usize>,
+        __states: &mut __lalrpop_util::stack::FixedStack<i32>,
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<'input>,usize)>,
+        _: __core::marker::PhantomData<()>,
+    ) -> Option<Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        let __nonterminal = match -__action {
+            1 => match __reduce_Item_0(input, __lookahead_start, __states, __symbols, __core::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            2 => match __reduce_Item_1(input, __lookahead_start, __states, __symbols, __core::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            3 => match __reduce_Items_0(input, __lookahead_start, __states, __symbols, __core::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            4 => match __reduce_Items_1(input, __lookahead_start, __states, __symbols, __core::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            5 => match __reduce_List_0(input, __lookahead_start, __states, __symbols, __core::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            6 => match __reduce_____List_0(input, __lookahead_start, __states, __symbols, __core::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            _ => panic!("invalid action code {}", __action)
+        };
+        let __state = *__states.last().unwrap() as usize;
+        let __next_state = __GOTO[__state * 4 + __nonterminal] as i32 - 1;
+        __states.push(__next_state);
+        None
+    }
+
+    fn __reduce_Item_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut __lalrpop_util::stack::FixedStack<i32>,
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<'input>,usize)>,
+        _: __core::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Item = r#"[a-z]+"# => ActionFn(4);
+        let __sym0 = __pop_Termr_23_22_5ba_2dz_5d_2b_22_23(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action4::<>(input, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtItem(__nt), __end));
+        Ok(0)
+    }
+
+    fn __reduce_Item_1<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut __lalrpop_util::stack::FixedStack<i32>,
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<'input>,usize)>,
+        _: __core::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Item = List => ActionFn(5);
+        let __sym0 = __pop_NtList(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action5::<>(input, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtItem(__nt), __end));
+        Ok(0)
+    }
+
+    fn __reduce_Items_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut __lalrpop_util::stack::FixedStack<i32>,
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<'input>,usize)>,
+        _: __core::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Items =  => ActionFn(2);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        if __symbols.len() >= __symbols.capacity() {
+            return Err(Err(__lalrpop_util::ParseError::StackCapacity { location: __end.clone(), capacity: __symbols.capacity() }));
+        }
+        let __nt = super::__action2::<>(input, &__start, &__end);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 0);
+        __symbols.push((__start, __Symbol::NtItems(__nt), __end));
+        Ok(1)
+    }
+
+    fn __reduce_Items_1<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut __lalrpop_util::stack::FixedStack<i32>,
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<'input>,usize)>,
+        _: __core::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Items = Items, Item => ActionFn(3);
+        let __sym1 = __pop_NtItem(__symbols);
+        let __sym0 = __pop_NtItems(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action3::<>(input, __sym0, __sym1);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 2);
+        __symbols.push((__start, __Symbol::NtItems(__nt), __end));
+        Ok(1)
+    }
+
+    fn __reduce_List_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut __lalrpop_util::stack::FixedStack<i32>,
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<'input>,usize)>,
+        _: __core::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // List = "[", Items, "]" => ActionFn(1);
+        let __sym2 = __pop_Term_22_5d_22(__symbols);
+        let __sym1 = __pop_NtItems(__symbols);
+        let __sym0 = __pop_Term_22_5b_22(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action1::<>(input, __sym0, __sym1, __sym2);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 3);
+        __symbols.push((__start, __Symbol::NtList(__nt), __end));
+        Ok(2)
+    }
+
+    fn __reduce_____List_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut __lalrpop_util::stack::FixedStack<i32>,
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<'input>,usize)>,
+        _: __core::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // __List = List => ActionFn(0);
+        let __sym0 = __pop_NtList(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action0::<>(input, __sym0);
+        Err(Ok(__Symbol::Nt____List(__nt)))
+    }
+    fn __pop_Term_22_5b_22<
+      'input,
+    >(
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_5b_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_5d_22<
+      'input,
+    >(
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_5d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_5ba_2dz_5d_2b_22_23<
+      'input,
+    >(
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_5ba_2dz_5d_2b_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtItem<
+      'input,
+    >(
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, String, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtItem(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtItems<
+      'input,
+    >(
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<String>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtItems(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtList<
+      'input,
+    >(
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<String>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtList(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____List<
+      'input,
+    >(
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<String>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____List(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+}
+mod __intern_token {
+    #![allow(unused_imports)]
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    extern crate regex as __regex;
+    use std::fmt as __fmt;
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Token<'input>(pub usize, pub &'input str);
+    impl<'a> __fmt::Display for Token<'a> {
+        fn fmt(&self, formatter: &mut __fmt::Formatter) -> Result<(), __fmt::Error> {
+            __fmt::Display::fmt(self.1, formatter)
+        }
+    }
+
+    pub struct __Matcher<'input> {
+        text: &'input str,
+        consumed: usize,
+        regex_set: __regex::RegexSet,
+        regex_vec: Vec<__regex::Regex>,
+    }
+
+    impl<'input> __Matcher<'input> {
+        pub fn new(s: &'input str) -> __Matcher<'input> {
+            let __strs: &[&str] = &[
+                "^(?u:[a-z])+",
+                "^(?u:\\[)",
+                "^(?u:\\])",
+            ];
+            let __regex_set = __regex::RegexSet::new(__strs).unwrap();
+            let __regex_vec = vec![
+                __regex::Regex::new("^(?u:[a-z])+").unwrap(),
+                __regex::Regex::new("^(?u:\\[)").unwrap(),
+                __regex::Regex::new("^(?u:\\])").unwrap(),
+            ];
+            __Matcher {
+                text: s,
+                consumed: 0,
+                regex_set: __regex_set,
+                regex_vec: __regex_vec,
+            }
+        }
+    }
+
+    impl<'input> Iterator for __Matcher<'input> {
+        type Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize,Token<'input>,&'static str>>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let __text = self.text.trim_left();
+            let __whitespace = self.text.len() - __text.len();
+            let __start_offset = self.consumed + __whitespace;
+            if __text.is_empty() {
+                self.text = __text;
+                self.consumed = __start_offset;
+                None
+            } else {
+                let __matches = self.regex_set.matches(__text);
+                if !__matches.matched_any() {
+                    Some(Err(__lalrpop_util::ParseError::InvalidToken {
+                        location: __start_offset,
+                    }))
+                } else {
+                    let mut __longest_match = 0;
+                    let mut __index = 0;
+                    for __i in 0 .. 3 {
+                        if __matches.matched(__i) {
+                            let __match = self.regex_vec[__i].find(__text).unwrap();
+                            let __len = __match.end();
+                            if __len >= __longest_match {
+                                __longest_match = __len;
+                                __index = __i;
+                            }
+                        }
+                    }
+                    let __result = &__text[..__longest_match];
+                    let __remaining = &__text[__longest_match..];
+                    let __end_offset = __start_offset + __longest_match;
+                    self.text = __remaining;
+                    self.consumed = __end_offset;
+                    Some(Ok((__start_offset, Token(__index, __result), __end_offset)))
+                }
+            }
+        }
+    }
+}
+pub use self::__intern_token::Token;
+
+#[allow(unused_variables)]
+fn __action0<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Vec<String>, usize),
+) -> Vec<String>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, Vec<String>, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> Vec<String>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<String>
+{
+    //@ src/fixed_stack.lalrpop:12:8 (1 lines)
+    vec![]
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, Vec<String>, usize),
+    (_, e, _): (usize, String, usize),
+) -> Vec<String>
+{
+    //@ src/fixed_stack.lalrpop:13:27 (5 lines)
+    {
+        let mut v = v;
+        v.push(e);
+        v
+    }
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> String
+{
+    //@ src/fixed_stack.lalrpop:21:18 (1 lines)
+    __0.to_string()
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Vec<String>, usize),
+) -> String
+{
+    //@ src/fixed_stack.lalrpop:22:13 (1 lines)
+    format!("[{}]", __0.join(" "))
+}
+
+pub trait __ToTriple<'input, > {
+    type Error;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),Self::Error>;
+}
+
+impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize) {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        Ok(value)
+    }
+}
+impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        value
+    }
+}
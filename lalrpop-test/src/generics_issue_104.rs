@@ -0,0 +1,1143 @@
+// auto-generated: "lalrpop 0.15.0"
+use generics_issue_104_lib::Generator;
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+
+mod __parse__Schema {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    use generics_issue_104_lib::Generator;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__intern_token::Token;
+    #[allow(dead_code)]
+    pub fn parse_Schema<
+        'input,
+        T,
+    >(
+        input: &'input str,
+    ) -> Result<String, __lalrpop_util::ParseError<usize, Token<'input>, String>> where
+      T:  Generator,
+    {
+        let __ascent = __ascent::parse_Schema::<T>(
+            input,
+        );
+        let __parse_table = __parse_table::parse_Schema::<T>(
+            input,
+        );
+        assert_eq!(__ascent, __parse_table);
+        return __ascent;
+    }
+    mod __ascent {
+
+        mod __parse__Schema {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use generics_issue_104_lib::Generator;
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__intern_token::Token;
+            #[allow(dead_code)]
+            pub fn parse_Schema<
+                'input,
+                T,
+            >(
+                input: &'input str,
+            ) -> Result<String, __lalrpop_util::ParseError<usize, Token<'input>, String>> where
+              T:  Generator,
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let __last_location = ::std::cell::RefCell::new(Default::default());
+                let mut __tokens = __tokens.inspect(|t| if let Ok(ref t) = *t { *__last_location.borrow_mut() = t.2.clone(); });
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                let mut __extra = None;
+                let __result = __state0(input, &mut __tokens, __lookahead, &mut __extra, ::std::marker::PhantomData::<(T)>);
+                if let Some((__token, __expected)) = __extra {
+                    return Err(match __result {
+                        Ok(_) => __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected },
+                        Err(_) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                    });
+                }
+                match __result {
+                    Ok((Some(__lookahead), _)) => {
+                        Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] })
+                    }
+                    Ok((None, __Nonterminal::____Schema((_, __nt, _)))) => {
+                        Ok(__nt)
+                    }
+                    Ok(_) => unreachable!(),
+                    Err(__lalrpop_util::ParseError::UnrecognizedEof { expected: __expected, .. }) => {
+                        Err(__lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.borrow().clone(), expected: __expected })
+                    }
+                    Err(__error) => Err(__error),
+                }
+            }
+
+            #[allow(dead_code)]
+            pub enum __Nonterminal<>
+             {
+                Schema((usize, String, usize)),
+                ____Schema((usize, String, usize)),
+            }
+            impl<> ::std::fmt::Debug for __Nonterminal<>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Nonterminal::Schema(..) => __f.write_str("Schema"),
+                        __Nonterminal::____Schema(..) => __f.write_str("__Schema"),
+                    }
+                }
+            }
+
+            // State 0
+            //     AllInputs = []
+            //     OptionalInputs = []
+            //     FixedInputs = []
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Schema = (*) "grammar" "{" r#"[a-zA-Z0-9]*"# "}" ["grammar", "{", "}", r#"[a-zA-Z0-9]*"#, EOF]
+            //     __Schema = (*) Schema ["grammar", "{", "}", r#"[a-zA-Z0-9]*"#, EOF]
+            //
+            //   "grammar" -> S2
+            //
+            //     Schema -> S1
+            fn __state0<
+                'input,
+                T,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, String>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                _: ::std::marker::PhantomData<(T)>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, String>> where
+              T:  Generator,
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state2(input, __tokens, __extra, __sym0, ::std::marker::PhantomData::<(T)>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""grammar""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Schema(__sym0) => {
+                            __result = try!(__state1(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<(T)>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 1
+            //     AllInputs = [Schema]
+            //     OptionalInputs = []
+            //     FixedInputs = [Schema]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(__Schema)
+            //
+            //     __Schema = Schema (*) ["grammar", "{", "}", r#"[a-zA-Z0-9]*"#, EOF]
+            //
+            //   [EOF] -> __Schema = Schema => ActionFn(0);
+            //
+            fn __state1<
+                'input,
+                T,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, String>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, String, usize),
+                _: ::std::marker::PhantomData<(T)>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, String>> where
+              T:  Generator,
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<T>(input, __sym0);
+                        let __nt = __Nonterminal::____Schema((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<T>(input, __sym0);
+                        let __nt = __Nonterminal::____Schema((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 2
+            //     AllInputs = ["grammar"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["grammar"]
+            //     WillPushLen = 3
+            //     WillPush = ["{", r#"[a-zA-Z0-9]*"#, "}"]
+            //     WillProduce = Some(Schema)
+            //
+            //     Schema = "grammar" (*) "{" r#"[a-zA-Z0-9]*"# "}" ["grammar", "{", "}", r#"[a-zA-Z0-9]*"#, EOF]
+            //
+            //   "{" -> S3
+            //
+            fn __state2<
+                'input,
+                T,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, String>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<(T)>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, String>> where
+              T:  Generator,
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state3(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<(T)>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""{""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 3
+            //     AllInputs = ["grammar", "{"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["grammar", "{"]
+            //     WillPushLen = 2
+            //     WillPush = [r#"[a-zA-Z0-9]*"#, "}"]
+            //     WillProduce = Some(Schema)
+            //
+            //     Schema = "grammar" "{" (*) r#"[a-zA-Z0-9]*"# "}" ["grammar", "{", "}", r#"[a-zA-Z0-9]*"#, EOF]
+            //
+            //   r#"[a-zA-Z0-9]*"# -> S4
+            //
+            fn __state3<
+                'input,
+                T,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, String>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<(T)>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, String>> where
+              T:  Generator,
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state4(input, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<(T)>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"r#"[a-zA-Z0-9]*"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 4
+            //     AllInputs = ["grammar", "{", r#"[a-zA-Z0-9]*"#]
+            //     OptionalInputs = []
+            //     FixedInputs = ["grammar", "{", r#"[a-zA-Z0-9]*"#]
+            //     WillPushLen = 1
+            //     WillPush = ["}"]
+            //     WillProduce = Some(Schema)
+            //
+            //     Schema = "grammar" "{" r#"[a-zA-Z0-9]*"# (*) "}" ["grammar", "{", "}", r#"[a-zA-Z0-9]*"#, EOF]
+            //
+            //   "}" -> S5
+            //
+            fn __state4<
+                'input,
+                T,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, String>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                __sym2: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<(T)>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, String>> where
+              T:  Generator,
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(3, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(input, __tokens, __extra, __sym0, __sym1, __sym2, __sym3, ::std::marker::PhantomData::<(T)>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""}""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 5
+            //     AllInputs = ["grammar", "{", r#"[a-zA-Z0-9]*"#, "}"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["grammar", "{", r#"[a-zA-Z0-9]*"#, "}"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Schema)
+            //
+            //     Schema = "grammar" "{" r#"[a-zA-Z0-9]*"# "}" (*) ["grammar", "{", "}", r#"[a-zA-Z0-9]*"#, EOF]
+            //
+            //   [EOF] -> Schema = "grammar", "{", r#"[a-zA-Z0-9]*"#, "}" => ActionFn(1);
+            //
+            fn __state5<
+                'input,
+                T,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, String>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                __sym2: (usize, &'input str, usize),
+                __sym3: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<(T)>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, String>> where
+              T:  Generator,
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym3.2.clone();
+                        let __nt = super::super::super::__action1::<T>(input, __sym0, __sym1, __sym2, __sym3);
+                        let __nt = __Nonterminal::Schema((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym3.2.clone();
+                        let __nt = super::super::super::__action1::<T>(input, __sym0, __sym1, __sym2, __sym3);
+                        let __nt = __Nonterminal::Schema((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+        }
+        pub use self::__parse__Schema::parse_Schema;
+    }
+    mod __parse_table {
+
+        mod __parse_table {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use generics_issue_104_lib::Generator;
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__intern_token::Token;
+            #[allow(dead_code)]
+            pub enum __Symbol<'input>
+             {
+                Term_22grammar_22(&'input str),
+                Term_22_7b_22(&'input str),
+                Term_22_7d_22(&'input str),
+                Termr_23_22_5ba_2dzA_2dZ0_2d9_5d_2a_22_23(&'input str),
+                NtSchema(String),
+                Nt____Schema(String),
+            }
+            impl<'input> ::std::fmt::Debug for __Symbol<'input>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Symbol::Term_22grammar_22(..) => __f.write_str("\"grammar\""),
+                        __Symbol::Term_22_7b_22(..) => __f.write_str("\"{\""),
+                        __Symbol::Term_22_7d_22(..) => __f.write_str("\"}\""),
+                        __Symbol::Termr_23_22_5ba_2dzA_2dZ0_2d9_5d_2a_22_23(..) => __f.write_str("r#\"[a-zA-Z0-9]*\"#"),
+                        __Symbol::NtSchema(..) => __f.write_str("Schema"),
+                        __Symbol::Nt____Schema(..) => __f.write_str("__Schema"),
+                    }
+                }
+            }
+            const __ACTION: &'static [i8] = &[
+                // State 0
+                //     Schema = (*) "grammar" "{" r#"[a-zA-Z0-9]*"# "}" ["grammar", "{", "}", r#"[a-zA-Z0-9]*"#, EOF]
+                //     __Schema = (*) Schema ["grammar", "{", "}", r#"[a-zA-Z0-9]*"#, EOF]
+                3,  // on "grammar", goto 2
+                0,  // on "{", error
+                0,  // on "}", error
+                0,  // on r#"[a-zA-Z0-9]*"#, error
+
+                // State 1
+                //     __Schema = Schema (*) ["grammar", "{", "}", r#"[a-zA-Z0-9]*"#, EOF]
+                0,  // on "grammar", error
+                0,  // on "{", error
+                0,  // on "}", error
+                0,  // on r#"[a-zA-Z0-9]*"#, error
+
+                // State 2
+                //     Schema = "grammar" (*) "{" r#"[a-zA-Z0-9]*"# "}" ["grammar", "{", "}", r#"[a-zA-Z0-9]*"#, EOF]
+                0,  // on "grammar", error
+                4,  // on "{", goto 3
+                0,  // on "}", error
+                0,  // on r#"[a-zA-Z0-9]*"#, error
+
+                // State 3
+                //     Schema = "grammar" "{" (*) r#"[a-zA-Z0-9]*"# "}" ["grammar", "{", "}", r#"[a-zA-Z0-9]*"#, EOF]
+                0,  // on "grammar", error
+                0,  // on "{", error
+                0,  // on "}", error
+                5,  // on r#"[a-zA-Z0-9]*"#, goto 4
+
+                // State 4
+                //     Schema = "grammar" "{" r#"[a-zA-Z0-9]*"# (*) "}" ["grammar", "{", "}", r#"[a-zA-Z0-9]*"#, EOF]
+                0,  // on "grammar", error
+                0,  // on "{", error
+                6,  // on "}", goto 5
+                0,  // on r#"[a-zA-Z0-9]*"#, error
+
+                // State 5
+                //     Schema = "grammar" "{" r#"[a-zA-Z0-9]*"# "}" (*) ["grammar", "{", "}", r#"[a-zA-Z0-9]*"#, EOF]
+                0,  // on "grammar", error
+                0,  // on "{", error
+                0,  // on "}", error
+                0,  // on r#"[a-zA-Z0-9]*"#, error
+
+            ];
+            const __EOF_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // on EOF, error
+
+                // State 1
+                -2,  // on EOF, reduce `__Schema = Schema => ActionFn(0);`
+
+                // State 2
+                0,  // on EOF, error
+
+                // State 3
+                0,  // on EOF, error
+
+                // State 4
+                0,  // on EOF, error
+
+                // State 5
+                -1,  // on EOF, reduce `Schema = "grammar", "{", r#"[a-zA-Z0-9]*"#, "}" => ActionFn(1);`
+
+            ];
+            const __GOTO: &'static [u8] = &[
+                // State 0
+                2,  // on Schema, goto 1
+                0,  // on __Schema, error
+
+                // State 1
+                0,  // on Schema, error
+                0,  // on __Schema, error
+
+                // State 2
+                0,  // on Schema, error
+                0,  // on __Schema, error
+
+                // State 3
+                0,  // on Schema, error
+                0,  // on __Schema, error
+
+                // State 4
+                0,  // on Schema, error
+                0,  // on __Schema, error
+
+                // State 5
+                0,  // on Schema, error
+                0,  // on __Schema, error
+
+            ];
+            const __DEFAULT_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // no default reduction
+
+                // State 1
+                0,  // no default reduction
+
+                // State 2
+                0,  // no default reduction
+
+                // State 3
+                0,  // no default reduction
+
+                // State 4
+                0,  // no default reduction
+
+                // State 5
+                -1,  // on any token, reduce `Schema = "grammar", "{", r#"[a-zA-Z0-9]*"#, "}" => ActionFn(1);`
+
+            ];
+            fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+                const __TERMINAL: &'static [&'static str] = &[
+                    r###""grammar""###,
+                    r###""{""###,
+                    r###""}""###,
+                    r###"r#"[a-zA-Z0-9]*"#"###,
+                ];
+                __ACTION[(__state * 4)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+                    if state == 0 {
+                        None
+                    } else {
+                        Some(terminal.to_string())
+                    }
+                }).collect()
+            }
+            #[allow(dead_code)]
+            pub fn parse_Schema<
+                'input,
+                T,
+            >(
+                input: &'input str,
+            ) -> Result<String, __lalrpop_util::ParseError<usize, Token<'input>, String>> where
+              T:  Generator,
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<T>(input, 0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Schema(__v) => Ok(__v), _ => unreachable!() })
+            }
+            #[allow(dead_code)]
+            pub fn parse_Schema_cancelable<
+                'input,
+                T,
+            >(
+                input: &'input str,
+                __cancel: &::std::sync::atomic::AtomicBool,
+            ) -> Result<String, __lalrpop_util::ParseError<usize, Token<'input>, String>> where
+              T:  Generator,
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<T>(input, 0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Schema(__v) => Ok(__v), _ => unreachable!() })
+            }
+
+            #[allow(dead_code)]
+            pub struct SchemaParser {
+                __states: ::std::vec::Vec<i32>,
+                __symbols: __lalrpop_util::stack::Recycled,
+                __cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+            }
+
+            #[allow(dead_code)]
+            impl SchemaParser {
+                pub fn new() -> SchemaParser {
+                    SchemaParser { __states: vec![], __symbols: __lalrpop_util::stack::Recycled::new(), __cancel: None }
+                }
+
+                pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+                    self.__cancel = Some(flag);
+                }
+
+                pub fn parse_in_place<'input, T>(
+                    &mut self,
+                    input: &'input str,
+                ) -> Result<String, __lalrpop_util::ParseError<usize, Token<'input>, String>>
+                where T:  Generator
+                {
+                    let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                    let mut __symbols = self.__symbols.take();
+                    let __result = __parse::<T>(input, 0, &mut __tokens, &mut self.__states, &mut __symbols, self.__cancel.as_ref().map(|c| &**c)).and_then(|__v| match __v { __Symbol::Nt____Schema(__v) => Ok(__v), _ => unreachable!() });
+                    self.__symbols.put(__symbols);
+                    __result
+                }
+            }
+
+            fn __parse<
+                'input,
+                T,
+            >(
+                input: &'input str,
+                __start_state: i32,
+                mut __tokens: &mut Iterator<Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize, Token<'input>, String>>>,
+                mut __states: &mut ::std::vec::Vec<i32>,
+                mut __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                __cancel: Option<&::std::sync::atomic::AtomicBool>,
+            ) -> Result<__Symbol<'input>, __lalrpop_util::ParseError<usize, Token<'input>, String>> where
+              T:  Generator,
+            {
+                __states.clear();
+                __states.push(__start_state);
+                __symbols.clear();
+                let mut __integer;
+                let mut __lookahead;
+                let __last_location: &mut usize = &mut Default::default();
+                let mut __default_reduced = [0_usize; 16];
+                let mut __default_reduced_len = 0;
+                '__shift: loop {
+                    loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __DEFAULT_ACTION[__state] as i32;
+                        if __action == 0 {
+                            break;
+                        }
+                        if __default_reduced_len < 16 {
+                            __default_reduced[__default_reduced_len] = __state;
+                            __default_reduced_len += 1;
+                        }
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<(T)>) {
+                            return r;
+                        }
+                    }
+                    __lookahead = match __tokens.next() {
+                        Some(Ok(v)) => v,
+                        None => break '__shift,
+                        Some(Err(e)) => return Err(e),
+                    };
+                    *__last_location = __lookahead.2.clone();
+                    __integer = match __lookahead.1 {
+                        Token(1, _) if true => 0,
+                        Token(2, _) if true => 1,
+                        Token(3, _) if true => 2,
+                        Token(0, _) if true => 3,
+                        _ => {
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| true).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match Some(__lookahead) {
+                                Some(__token) => {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error);
+                        }
+                    };
+                    '__inner: loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __ACTION[__state * 4 + __integer] as i32;
+                        if __action > 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                                }
+                            }
+                            let __symbol = match __integer {
+                                0 => match __lookahead.1 {
+                                    Token(1, __tok0) => __Symbol::Term_22grammar_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                1 => match __lookahead.1 {
+                                    Token(2, __tok0) => __Symbol::Term_22_7b_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                2 => match __lookahead.1 {
+                                    Token(3, __tok0) => __Symbol::Term_22_7d_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                3 => match __lookahead.1 {
+                                    Token(0, __tok0) => __Symbol::Termr_23_22_5ba_2dzA_2dZ0_2d9_5d_2a_22_23((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                _ => unreachable!(),
+                            };
+                            __states.push(__action - 1);
+                            __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                            __default_reduced_len = 0;
+                            continue '__shift;
+                        } else if __action < 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                                }
+                            }
+                            if let Some(r) = __reduce(input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<(T)>) {
+                                if r.is_err() {
+                                    return r;
+                                }
+                                return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] });
+                            }
+                        } else {
+                            let mut __err_lookahead = Some(__lookahead);
+                            let mut __err_integer: Option<usize> = Some(__integer);
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 4 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match __err_lookahead {
+                                Some(__token) => {
+                                    if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<(T)>) {
+                                        __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                    } else {
+                                        __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                    }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error)
+                        }
+                    }
+                }
+                loop {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __action = __EOF_ACTION[__state] as i32;
+                    if __action < 0 {
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<(T)>) {
+                            return r;
+                        }
+                    } else {
+                        let mut __err_lookahead = None;
+                        let mut __err_integer: Option<usize> = None;
+                        let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 4 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                        let __expected = __expected_tokens(__state);
+                        let __error = match __err_lookahead {
+                            Some(__token) => {
+                                if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<(T)>) {
+                                    __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                } else {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                            }
+                            None => {
+                                __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                            }
+                        };
+                        return Err(__error)
+                    }
+                }
+            }
+            fn __accepts<
+                'input,
+                T,
+            >(
+                input: &'input str,
+                __error_state: i32,
+                __states: & [i32],
+                __opt_integer: Option<usize>,
+                _: ::std::marker::PhantomData<(T)>,
+            ) -> bool where
+              T:  Generator,
+            {
+                let mut __states = __states.to_vec();
+                __states.push(__error_state);
+                loop {
+                    let mut __states_len = __states.len();
+                    let __top = __states[__states_len - 1];
+                    let __action = match __opt_integer {
+                        None => __EOF_ACTION[__top as usize] as i32,
+                        Some(__integer) => __ACTION[(__top * 4) as usize + __integer] as i32,
+                    };
+                    if __action == 0 { return false; }
+                    if __action > 0 { return true; }
+                    let (__to_pop, __nt) = match -__action {
+                        // simulate Schema = "grammar", "{", r#"[a-zA-Z0-9]*"#, "}" => ActionFn(1);
+                        1 => {
+                            (4, 0)
+                        }
+                        // simulate __Schema = Schema => ActionFn(0);
+                        2 => return true,
+                        _ => panic!("invalid action code {}", __action)
+                    };
+                    __states_len -= __to_pop;
+                    __states.truncate(__states_len);
+                    let __top = __states[__states_len - 1];
+                    let __next_state = __GOTO[(__top * 2 + __nt) as usize] as i32 - 1;
+                    __states.push(__next_state);
+                }
+            }
+            pub fn __reduce<
+                'input,
+                T,
+            >(
+                input: &'input str,
+                __action: i32,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<(T)>,
+            ) -> Option<Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, String>>> where
+              T:  Generator,
+            {
+                let __nonterminal = match -__action {
+                    1 => match __reduce_Schema_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<(T)>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    2 => match __reduce_____Schema_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<(T)>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    _ => panic!("invalid action code {}", __action)
+                };
+                let __state = *__states.last().unwrap() as usize;
+                let __next_state = __GOTO[__state * 2 + __nonterminal] as i32 - 1;
+                __states.push(__next_state);
+                None
+            }
+
+            fn __reduce_Schema_0<
+                'input,
+                T,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<(T)>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, String>>> where
+              T:  Generator,
+            {
+                // Schema = "grammar", "{", r#"[a-zA-Z0-9]*"#, "}" => ActionFn(1);
+                let __sym3 = __pop_Term_22_7d_22(__symbols);
+                let __sym2 = __pop_Termr_23_22_5ba_2dzA_2dZ0_2d9_5d_2a_22_23(__symbols);
+                let __sym1 = __pop_Term_22_7b_22(__symbols);
+                let __sym0 = __pop_Term_22grammar_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::super::super::__action1::<T>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtSchema(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce_____Schema_0<
+                'input,
+                T,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<(T)>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, String>>> where
+              T:  Generator,
+            {
+                // __Schema = Schema => ActionFn(0);
+                let __sym0 = __pop_NtSchema(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action0::<T>(input, __sym0);
+                Err(Ok(__Symbol::Nt____Schema(__nt)))
+            }
+            fn __pop_Term_22grammar_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22grammar_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_7b_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_7b_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_7d_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_7d_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Termr_23_22_5ba_2dzA_2dZ0_2d9_5d_2a_22_23<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Termr_23_22_5ba_2dzA_2dZ0_2d9_5d_2a_22_23(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtSchema<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, String, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtSchema(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt____Schema<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, String, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt____Schema(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+        }
+        pub use self::__parse_table::parse_Schema;
+        pub use self::__parse_table::parse_Schema_cancelable;
+        pub use self::__parse_table::SchemaParser;
+    }
+    pub use self::__parse_table::parse_Schema_cancelable;
+    pub use self::__parse_table::SchemaParser;
+}
+pub use self::__parse__Schema::parse_Schema;
+pub use self::__parse__Schema::parse_Schema_cancelable;
+pub use self::__parse__Schema::SchemaParser;
+mod __intern_token {
+    #![allow(unused_imports)]
+    use generics_issue_104_lib::Generator;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    extern crate regex as __regex;
+    use std::fmt as __fmt;
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Token<'input>(pub usize, pub &'input str);
+    impl<'a> __fmt::Display for Token<'a> {
+        fn fmt(&self, formatter: &mut __fmt::Formatter) -> Result<(), __fmt::Error> {
+            __fmt::Display::fmt(self.1, formatter)
+        }
+    }
+
+    pub struct __Matcher<'input> {
+        text: &'input str,
+        consumed: usize,
+        regex_set: __regex::RegexSet,
+        regex_vec: Vec<__regex::Regex>,
+    }
+
+    impl<'input> __Matcher<'input> {
+        pub fn new(s: &'input str) -> __Matcher<'input> {
+            let __strs: &[&str] = &[
+                "^(?u:[0-9A-Za-z])*",
+                "^(?u:grammar)",
+                "^(?u:\\{)",
+                "^(?u:\\})",
+            ];
+            let __regex_set = __regex::RegexSet::new(__strs).unwrap();
+            let __regex_vec = vec![
+                __regex::Regex::new("^(?u:[0-9A-Za-z])*").unwrap(),
+                __regex::Regex::new("^(?u:grammar)").unwrap(),
+                __regex::Regex::new("^(?u:\\{)").unwrap(),
+                __regex::Regex::new("^(?u:\\})").unwrap(),
+            ];
+            __Matcher {
+                text: s,
+                consumed: 0,
+                regex_set: __regex_set,
+                regex_vec: __regex_vec,
+            }
+        }
+    }
+
+    impl<'input> Iterator for __Matcher<'input> {
+        type Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize,Token<'input>,String>>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let __text = self.text.trim_left();
+            let __whitespace = self.text.len() - __text.len();
+            let __start_offset = self.consumed + __whitespace;
+            if __text.is_empty() {
+                self.text = __text;
+                self.consumed = __start_offset;
+                None
+            } else {
+                let __matches = self.regex_set.matches(__text);
+                if !__matches.matched_any() {
+                    Some(Err(__lalrpop_util::ParseError::InvalidToken {
+                        location: __start_offset,
+                    }))
+                } else {
+                    let mut __longest_match = 0;
+                    let mut __index = 0;
+                    for __i in 0 .. 4 {
+                        if __matches.matched(__i) {
+                            let __match = self.regex_vec[__i].find(__text).unwrap();
+                            let __len = __match.end();
+                            if __len >= __longest_match {
+                                __longest_match = __len;
+                                __index = __i;
+                            }
+                        }
+                    }
+                    let __result = &__text[..__longest_match];
+                    let __remaining = &__text[__longest_match..];
+                    let __end_offset = __start_offset + __longest_match;
+                    self.text = __remaining;
+                    self.consumed = __end_offset;
+                    Some(Ok((__start_offset, Token(__index, __result), __end_offset)))
+                }
+            }
+        }
+    }
+}
+pub use self::__intern_token::Token;
+
+#[allow(unused_variables)]
+fn __action0<
+    'input,
+    T,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, String, usize),
+) -> String where
+  T:  Generator,
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'input,
+    T,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, id, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> String where
+  T:  Generator,
+{
+    //@ src/generics_issue_104.lalrpop:9:65 (1 lines)
+    T::schema(id)
+}
+
+pub trait __ToTriple<'input, T, > {
+    type Error;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),Self::Error>;
+}
+
+impl<'input, T, > __ToTriple<'input, T, > for (usize, Token<'input>, usize) {
+    type Error = String;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),String> {
+        Ok(value)
+    }
+}
+impl<'input, T, > __ToTriple<'input, T, > for Result<(usize, Token<'input>, usize),String> {
+    type Error = String;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),String> {
+        value
+    }
+}
@@ -0,0 +1,3080 @@
+// auto-generated: "lalrpop 0.15.0"
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+
+mod __parse__E {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__intern_token::Token;
+    #[allow(dead_code)]
+    pub fn parse_E<
+        'input,
+    >(
+        input: &'input str,
+    ) -> Result<(Vec<&'input str>, &'input str, Vec<&'input str>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let __ascent = __ascent::parse_E(
+            input,
+        );
+        let __parse_table = __parse_table::parse_E(
+            input,
+        );
+        assert_eq!(__ascent, __parse_table);
+        return __ascent;
+    }
+    mod __ascent {
+
+        mod __parse__E {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__intern_token::Token;
+            #[allow(dead_code)]
+            pub fn parse_E<
+                'input,
+            >(
+                input: &'input str,
+            ) -> Result<(Vec<&'input str>, &'input str, Vec<&'input str>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let __last_location = ::std::cell::RefCell::new(Default::default());
+                let mut __tokens = __tokens.inspect(|t| if let Ok(ref t) = *t { *__last_location.borrow_mut() = t.2.clone(); });
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                let mut __extra = None;
+                let __result = __state0(input, &mut __tokens, __lookahead, &mut __extra, ::std::marker::PhantomData::<()>);
+                if let Some((__token, __expected)) = __extra {
+                    return Err(match __result {
+                        Ok(_) => __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected },
+                        Err(_) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                    });
+                }
+                match __result {
+                    Ok((Some(__lookahead), _)) => {
+                        Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] })
+                    }
+                    Ok((None, __Nonterminal::____E((_, __nt, _)))) => {
+                        Ok(__nt)
+                    }
+                    Ok(_) => unreachable!(),
+                    Err(__lalrpop_util::ParseError::UnrecognizedEof { expected: __expected, .. }) => {
+                        Err(__lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.borrow().clone(), expected: __expected })
+                    }
+                    Err(__error) => Err(__error),
+                }
+            }
+
+            #[allow(dead_code)]
+            pub enum __Nonterminal<'input>
+             {
+                AT((usize, &'input str, usize)),
+                AT_2a((usize, ::std::vec::Vec<&'input str>, usize)),
+                AT_2b((usize, ::std::vec::Vec<&'input str>, usize)),
+                E((usize, (Vec<&'input str>, &'input str, Vec<&'input str>), usize)),
+                ET((usize, &'input str, usize)),
+                ____E((usize, (Vec<&'input str>, &'input str, Vec<&'input str>), usize)),
+            }
+            impl<'input> ::std::fmt::Debug for __Nonterminal<'input>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Nonterminal::AT(..) => __f.write_str("AT"),
+                        __Nonterminal::AT_2a(..) => __f.write_str("AT*"),
+                        __Nonterminal::AT_2b(..) => __f.write_str("AT+"),
+                        __Nonterminal::E(..) => __f.write_str("E"),
+                        __Nonterminal::ET(..) => __f.write_str("ET"),
+                        __Nonterminal::____E(..) => __f.write_str("__E"),
+                    }
+                }
+            }
+
+            // State 0
+            //     AllInputs = []
+            //     OptionalInputs = []
+            //     FixedInputs = []
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     E = (*) "{" AT+ ET AT+ "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //     E = (*) "{" AT+ ET "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //     E = (*) "{" ET AT+ "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //     E = (*) "{" ET "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //     __E = (*) E [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //
+            //   "{" -> S2
+            //
+            //     E -> S1
+            fn __state0<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(4, __tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state2(input, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""{""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::E(__sym0) => {
+                            __result = try!(__state1(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 1
+            //     AllInputs = [E]
+            //     OptionalInputs = []
+            //     FixedInputs = [E]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(__E)
+            //
+            //     __E = E (*) [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //
+            //   [EOF] -> __E = E => ActionFn(0);
+            //
+            fn __state1<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, (Vec<&'input str>, &'input str, Vec<&'input str>), usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(input, __sym0);
+                        let __nt = __Nonterminal::____E((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(input, __sym0);
+                        let __nt = __Nonterminal::____E((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 2
+            //     AllInputs = ["{"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["{"]
+            //     WillPushLen = 2
+            //     WillPush = [ET, "}"]
+            //     WillProduce = Some(E)
+            //
+            //     AT = (*) "type" r#"[a-zA-Z]+"# ";" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //     AT+ = (*) AT [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //     AT+ = (*) AT+ AT [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //     E = "{" (*) AT+ ET AT+ "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //     E = "{" (*) AT+ ET "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //     E = "{" (*) ET AT+ "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //     E = "{" (*) ET "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //     ET = (*) "enum" r#"[a-zA-Z]+"# "{" "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //
+            //   "enum" -> S6
+            //   "type" -> S7
+            //
+            //     AT -> S3
+            //     AT+ -> S4
+            //     ET -> S5
+            fn __state2<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                let __sym0 = &mut Some(__sym0);
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(3, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""enum""###.to_string(),
+                            r###""type""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    if __sym0.is_none() {
+                        return Ok(__result);
+                    }
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::AT(__sym1) => {
+                            __result = try!(__state3(input, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::AT_2b(__sym1) => {
+                            __result = try!(__state4(input, __tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::ET(__sym1) => {
+                            let __sym0 = __sym0.take().unwrap();
+                            __result = try!(__state5(input, __tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 3
+            //     AllInputs = [AT]
+            //     OptionalInputs = []
+            //     FixedInputs = [AT]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(AT+)
+            //
+            //     AT+ = AT (*) [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //
+            //   ["enum", "type", "}"] -> AT+ = AT => ActionFn(6);
+            //
+            fn __state3<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((_, Token(2, _), _)) |
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(5, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action6::<>(input, __sym0);
+                        let __nt = __Nonterminal::AT_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""enum""###.to_string(),
+                            r###""type""###.to_string(),
+                            r###""}""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 4
+            //     AllInputs = ["{", AT+]
+            //     OptionalInputs = ["{"]
+            //     FixedInputs = [AT+]
+            //     WillPushLen = 1
+            //     WillPush = [AT]
+            //     WillProduce = None
+            //
+            //     AT = (*) "type" r#"[a-zA-Z]+"# ";" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //     AT+ = AT+ (*) AT [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //     E = "{" AT+ (*) ET AT+ "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //     E = "{" AT+ (*) ET "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //     ET = (*) "enum" r#"[a-zA-Z]+"# "{" "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //
+            //   "enum" -> S6
+            //   "type" -> S7
+            //
+            //     AT -> S8
+            //     ET -> S9
+            fn __state4<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: &mut Option<(usize, &'input str, usize)>,
+                __sym1: (usize, ::std::vec::Vec<&'input str>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(3, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""enum""###.to_string(),
+                            r###""type""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::AT(__sym2) => {
+                            __result = try!(__state8(input, __tokens, __lookahead, __extra, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        __Nonterminal::ET(__sym2) => {
+                            let __sym0 = __sym0.take().unwrap();
+                            __result = try!(__state9(input, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 5
+            //     AllInputs = ["{", ET]
+            //     OptionalInputs = []
+            //     FixedInputs = ["{", ET]
+            //     WillPushLen = 1
+            //     WillPush = ["}"]
+            //     WillProduce = Some(E)
+            //
+            //     AT = (*) "type" r#"[a-zA-Z]+"# ";" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //     AT+ = (*) AT [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //     AT+ = (*) AT+ AT [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //     E = "{" ET (*) AT+ "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //     E = "{" ET (*) "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //
+            //   "type" -> S7
+            //   "}" -> S11
+            //
+            //     AT -> S3
+            //     AT+ -> S10
+            fn __state5<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __sym0 = &mut Some(__sym0);
+                let __sym1 = &mut Some(__sym1);
+                match __lookahead {
+                    Some((__loc1, Token(3, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(5, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        let __sym0 = __sym0.take().unwrap();
+                        let __sym1 = __sym1.take().unwrap();
+                        __result = try!(__state11(input, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""type""###.to_string(),
+                            r###""}""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    if __sym1.is_none() {
+                        return Ok(__result);
+                    }
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::AT(__sym2) => {
+                            __result = try!(__state3(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::AT_2b(__sym2) => {
+                            __result = try!(__state10(input, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 6
+            //     AllInputs = ["enum"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["enum"]
+            //     WillPushLen = 3
+            //     WillPush = [r#"[a-zA-Z]+"#, "{", "}"]
+            //     WillProduce = Some(ET)
+            //
+            //     ET = "enum" (*) r#"[a-zA-Z]+"# "{" "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //
+            //   r#"[a-zA-Z]+"# -> S12
+            //
+            fn __state6<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state12(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"r#"[a-zA-Z]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 7
+            //     AllInputs = ["type"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["type"]
+            //     WillPushLen = 2
+            //     WillPush = [r#"[a-zA-Z]+"#, ";"]
+            //     WillProduce = Some(AT)
+            //
+            //     AT = "type" (*) r#"[a-zA-Z]+"# ";" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //
+            //   r#"[a-zA-Z]+"# -> S13
+            //
+            fn __state7<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state13(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"r#"[a-zA-Z]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 8
+            //     AllInputs = [AT+, AT]
+            //     OptionalInputs = []
+            //     FixedInputs = [AT+, AT]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(AT+)
+            //
+            //     AT+ = AT+ AT (*) [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //
+            //   ["enum", "type", "}"] -> AT+ = AT+, AT => ActionFn(7);
+            //
+            fn __state8<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, ::std::vec::Vec<&'input str>, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((_, Token(2, _), _)) |
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(5, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action7::<>(input, __sym0, __sym1);
+                        let __nt = __Nonterminal::AT_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""enum""###.to_string(),
+                            r###""type""###.to_string(),
+                            r###""}""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 9
+            //     AllInputs = ["{", AT+, ET]
+            //     OptionalInputs = []
+            //     FixedInputs = ["{", AT+, ET]
+            //     WillPushLen = 1
+            //     WillPush = ["}"]
+            //     WillProduce = Some(E)
+            //
+            //     AT = (*) "type" r#"[a-zA-Z]+"# ";" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //     AT+ = (*) AT [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //     AT+ = (*) AT+ AT [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //     E = "{" AT+ ET (*) AT+ "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //     E = "{" AT+ ET (*) "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //
+            //   "type" -> S7
+            //   "}" -> S15
+            //
+            //     AT -> S3
+            //     AT+ -> S14
+            fn __state9<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, ::std::vec::Vec<&'input str>, usize),
+                __sym2: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __sym0 = &mut Some(__sym0);
+                let __sym1 = &mut Some(__sym1);
+                let __sym2 = &mut Some(__sym2);
+                match __lookahead {
+                    Some((__loc1, Token(3, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(5, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        let __sym0 = __sym0.take().unwrap();
+                        let __sym1 = __sym1.take().unwrap();
+                        let __sym2 = __sym2.take().unwrap();
+                        __result = try!(__state15(input, __tokens, __extra, __sym0, __sym1, __sym2, __sym3, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""type""###.to_string(),
+                            r###""}""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    if __sym2.is_none() {
+                        return Ok(__result);
+                    }
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::AT(__sym3) => {
+                            __result = try!(__state3(input, __tokens, __lookahead, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::AT_2b(__sym3) => {
+                            __result = try!(__state14(input, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, __sym3, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 10
+            //     AllInputs = ["{", ET, AT+]
+            //     OptionalInputs = ["{", ET]
+            //     FixedInputs = [AT+]
+            //     WillPushLen = 1
+            //     WillPush = [AT]
+            //     WillProduce = None
+            //
+            //     AT = (*) "type" r#"[a-zA-Z]+"# ";" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //     AT+ = AT+ (*) AT [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //     E = "{" ET AT+ (*) "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //
+            //   "type" -> S7
+            //   "}" -> S16
+            //
+            //     AT -> S8
+            fn __state10<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: &mut Option<(usize, &'input str, usize)>,
+                __sym1: &mut Option<(usize, &'input str, usize)>,
+                __sym2: (usize, ::std::vec::Vec<&'input str>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(3, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(5, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        let __sym0 = __sym0.take().unwrap();
+                        let __sym1 = __sym1.take().unwrap();
+                        __result = try!(__state16(input, __tokens, __extra, __sym0, __sym1, __sym2, __sym3, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""type""###.to_string(),
+                            r###""}""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::AT(__sym3) => {
+                            __result = try!(__state8(input, __tokens, __lookahead, __extra, __sym2, __sym3, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 11
+            //     AllInputs = ["{", ET, "}"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["{", ET, "}"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(E)
+            //
+            //     E = "{" ET "}" (*) [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //
+            //   [EOF] -> E = "{", ET, "}" => ActionFn(8);
+            //
+            fn __state11<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                __sym2: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action8::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::E((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action8::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::E((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 12
+            //     AllInputs = ["enum", r#"[a-zA-Z]+"#]
+            //     OptionalInputs = []
+            //     FixedInputs = ["enum", r#"[a-zA-Z]+"#]
+            //     WillPushLen = 2
+            //     WillPush = ["{", "}"]
+            //     WillProduce = Some(ET)
+            //
+            //     ET = "enum" r#"[a-zA-Z]+"# (*) "{" "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //
+            //   "{" -> S17
+            //
+            fn __state12<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(4, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state17(input, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""{""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 13
+            //     AllInputs = ["type", r#"[a-zA-Z]+"#]
+            //     OptionalInputs = []
+            //     FixedInputs = ["type", r#"[a-zA-Z]+"#]
+            //     WillPushLen = 1
+            //     WillPush = [";"]
+            //     WillProduce = Some(AT)
+            //
+            //     AT = "type" r#"[a-zA-Z]+"# (*) ";" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //
+            //   ";" -> S18
+            //
+            fn __state13<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state18(input, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"";""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 14
+            //     AllInputs = ["{", AT+, ET, AT+]
+            //     OptionalInputs = ["{", AT+, ET]
+            //     FixedInputs = [AT+]
+            //     WillPushLen = 1
+            //     WillPush = [AT]
+            //     WillProduce = None
+            //
+            //     AT = (*) "type" r#"[a-zA-Z]+"# ";" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //     AT+ = AT+ (*) AT [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //     E = "{" AT+ ET AT+ (*) "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //
+            //   "type" -> S7
+            //   "}" -> S19
+            //
+            //     AT -> S8
+            fn __state14<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: &mut Option<(usize, &'input str, usize)>,
+                __sym1: &mut Option<(usize, ::std::vec::Vec<&'input str>, usize)>,
+                __sym2: &mut Option<(usize, &'input str, usize)>,
+                __sym3: (usize, ::std::vec::Vec<&'input str>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(3, __tok0), __loc2)) => {
+                        let __sym4 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym4, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(5, __tok0), __loc2)) => {
+                        let __sym4 = (__loc1, (__tok0), __loc2);
+                        let __sym0 = __sym0.take().unwrap();
+                        let __sym1 = __sym1.take().unwrap();
+                        let __sym2 = __sym2.take().unwrap();
+                        __result = try!(__state19(input, __tokens, __extra, __sym0, __sym1, __sym2, __sym3, __sym4, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""type""###.to_string(),
+                            r###""}""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::AT(__sym4) => {
+                            __result = try!(__state8(input, __tokens, __lookahead, __extra, __sym3, __sym4, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 15
+            //     AllInputs = ["{", AT+, ET, "}"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["{", AT+, ET, "}"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(E)
+            //
+            //     E = "{" AT+ ET "}" (*) [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //
+            //   [EOF] -> E = "{", AT+, ET, "}" => ActionFn(10);
+            //
+            fn __state15<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, ::std::vec::Vec<&'input str>, usize),
+                __sym2: (usize, &'input str, usize),
+                __sym3: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym3.2.clone();
+                        let __nt = super::super::super::__action10::<>(input, __sym0, __sym1, __sym2, __sym3);
+                        let __nt = __Nonterminal::E((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym3.2.clone();
+                        let __nt = super::super::super::__action10::<>(input, __sym0, __sym1, __sym2, __sym3);
+                        let __nt = __Nonterminal::E((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 16
+            //     AllInputs = ["{", ET, AT+, "}"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["{", ET, AT+, "}"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(E)
+            //
+            //     E = "{" ET AT+ "}" (*) [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //
+            //   [EOF] -> E = "{", ET, AT+, "}" => ActionFn(9);
+            //
+            fn __state16<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                __sym2: (usize, ::std::vec::Vec<&'input str>, usize),
+                __sym3: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym3.2.clone();
+                        let __nt = super::super::super::__action9::<>(input, __sym0, __sym1, __sym2, __sym3);
+                        let __nt = __Nonterminal::E((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym3.2.clone();
+                        let __nt = super::super::super::__action9::<>(input, __sym0, __sym1, __sym2, __sym3);
+                        let __nt = __Nonterminal::E((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 17
+            //     AllInputs = ["enum", r#"[a-zA-Z]+"#, "{"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["enum", r#"[a-zA-Z]+"#, "{"]
+            //     WillPushLen = 1
+            //     WillPush = ["}"]
+            //     WillProduce = Some(ET)
+            //
+            //     ET = "enum" r#"[a-zA-Z]+"# "{" (*) "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //
+            //   "}" -> S20
+            //
+            fn __state17<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                __sym2: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(5, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state20(input, __tokens, __extra, __sym0, __sym1, __sym2, __sym3, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""}""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 18
+            //     AllInputs = ["type", r#"[a-zA-Z]+"#, ";"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["type", r#"[a-zA-Z]+"#, ";"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(AT)
+            //
+            //     AT = "type" r#"[a-zA-Z]+"# ";" (*) [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //
+            //   ["enum", "type", "}"] -> AT = "type", r#"[a-zA-Z]+"#, ";" => ActionFn(2);
+            //
+            fn __state18<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                __sym2: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(2, _), _)) |
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(5, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action2::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::AT((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""enum""###.to_string(),
+                            r###""type""###.to_string(),
+                            r###""}""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 19
+            //     AllInputs = ["{", AT+, ET, AT+, "}"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["{", AT+, ET, AT+, "}"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(E)
+            //
+            //     E = "{" AT+ ET AT+ "}" (*) [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //
+            //   [EOF] -> E = "{", AT+, ET, AT+, "}" => ActionFn(11);
+            //
+            fn __state19<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, ::std::vec::Vec<&'input str>, usize),
+                __sym2: (usize, &'input str, usize),
+                __sym3: (usize, ::std::vec::Vec<&'input str>, usize),
+                __sym4: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym4.2.clone();
+                        let __nt = super::super::super::__action11::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                        let __nt = __Nonterminal::E((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym4.2.clone();
+                        let __nt = super::super::super::__action11::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                        let __nt = __Nonterminal::E((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 20
+            //     AllInputs = ["enum", r#"[a-zA-Z]+"#, "{", "}"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["enum", r#"[a-zA-Z]+"#, "{", "}"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(ET)
+            //
+            //     ET = "enum" r#"[a-zA-Z]+"# "{" "}" (*) [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+            //
+            //   ["type", "}"] -> ET = "enum", r#"[a-zA-Z]+"#, "{", "}" => ActionFn(3);
+            //
+            fn __state20<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                __sym2: (usize, &'input str, usize),
+                __sym3: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(5, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym3.2.clone();
+                        let __nt = super::super::super::__action3::<>(input, __sym0, __sym1, __sym2, __sym3);
+                        let __nt = __Nonterminal::ET((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""type""###.to_string(),
+                            r###""}""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+        }
+        pub use self::__parse__E::parse_E;
+    }
+    mod __parse_table {
+
+        mod __parse_table {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__intern_token::Token;
+            #[allow(dead_code)]
+            pub enum __Symbol<'input>
+             {
+                Term_22_3b_22(&'input str),
+                Term_22enum_22(&'input str),
+                Term_22type_22(&'input str),
+                Term_22_7b_22(&'input str),
+                Term_22_7d_22(&'input str),
+                Termr_23_22_5ba_2dzA_2dZ_5d_2b_22_23(&'input str),
+                NtAT(&'input str),
+                NtAT_2a(::std::vec::Vec<&'input str>),
+                NtAT_2b(::std::vec::Vec<&'input str>),
+                NtE((Vec<&'input str>, &'input str, Vec<&'input str>)),
+                NtET(&'input str),
+                Nt____E((Vec<&'input str>, &'input str, Vec<&'input str>)),
+            }
+            impl<'input> ::std::fmt::Debug for __Symbol<'input>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Symbol::Term_22_3b_22(..) => __f.write_str("\";\""),
+                        __Symbol::Term_22enum_22(..) => __f.write_str("\"enum\""),
+                        __Symbol::Term_22type_22(..) => __f.write_str("\"type\""),
+                        __Symbol::Term_22_7b_22(..) => __f.write_str("\"{\""),
+                        __Symbol::Term_22_7d_22(..) => __f.write_str("\"}\""),
+                        __Symbol::Termr_23_22_5ba_2dzA_2dZ_5d_2b_22_23(..) => __f.write_str("r#\"[a-zA-Z]+\"#"),
+                        __Symbol::NtAT(..) => __f.write_str("AT"),
+                        __Symbol::NtAT_2a(..) => __f.write_str("AT*"),
+                        __Symbol::NtAT_2b(..) => __f.write_str("AT+"),
+                        __Symbol::NtE(..) => __f.write_str("E"),
+                        __Symbol::NtET(..) => __f.write_str("ET"),
+                        __Symbol::Nt____E(..) => __f.write_str("__E"),
+                    }
+                }
+            }
+            const __ACTION: &'static [i8] = &[
+                // State 0
+                //     E = (*) "{" AT+ ET AT+ "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                //     E = (*) "{" AT+ ET "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                //     E = (*) "{" ET AT+ "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                //     E = (*) "{" ET "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                //     __E = (*) E [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                0,  // on ";", error
+                0,  // on "enum", error
+                0,  // on "type", error
+                3,  // on "{", goto 2
+                0,  // on "}", error
+                0,  // on r#"[a-zA-Z]+"#, error
+
+                // State 1
+                //     __E = E (*) [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                0,  // on ";", error
+                0,  // on "enum", error
+                0,  // on "type", error
+                0,  // on "{", error
+                0,  // on "}", error
+                0,  // on r#"[a-zA-Z]+"#, error
+
+                // State 2
+                //     AT = (*) "type" r#"[a-zA-Z]+"# ";" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                //     AT+ = (*) AT [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                //     AT+ = (*) AT+ AT [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                //     E = "{" (*) AT+ ET AT+ "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                //     E = "{" (*) AT+ ET "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                //     E = "{" (*) ET AT+ "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                //     E = "{" (*) ET "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                //     ET = (*) "enum" r#"[a-zA-Z]+"# "{" "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                0,  // on ";", error
+                7,  // on "enum", goto 6
+                8,  // on "type", goto 7
+                0,  // on "{", error
+                0,  // on "}", error
+                0,  // on r#"[a-zA-Z]+"#, error
+
+                // State 3
+                //     AT+ = AT (*) [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                0,  // on ";", error
+                -4,  // on "enum", reduce `AT+ = AT => ActionFn(6);`
+                -4,  // on "type", reduce `AT+ = AT => ActionFn(6);`
+                0,  // on "{", error
+                -4,  // on "}", reduce `AT+ = AT => ActionFn(6);`
+                0,  // on r#"[a-zA-Z]+"#, error
+
+                // State 4
+                //     AT = (*) "type" r#"[a-zA-Z]+"# ";" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                //     AT+ = AT+ (*) AT [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                //     E = "{" AT+ (*) ET AT+ "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                //     E = "{" AT+ (*) ET "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                //     ET = (*) "enum" r#"[a-zA-Z]+"# "{" "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                0,  // on ";", error
+                7,  // on "enum", goto 6
+                8,  // on "type", goto 7
+                0,  // on "{", error
+                0,  // on "}", error
+                0,  // on r#"[a-zA-Z]+"#, error
+
+                // State 5
+                //     AT = (*) "type" r#"[a-zA-Z]+"# ";" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                //     AT+ = (*) AT [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                //     AT+ = (*) AT+ AT [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                //     E = "{" ET (*) AT+ "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                //     E = "{" ET (*) "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                0,  // on ";", error
+                0,  // on "enum", error
+                8,  // on "type", goto 7
+                0,  // on "{", error
+                12,  // on "}", goto 11
+                0,  // on r#"[a-zA-Z]+"#, error
+
+                // State 6
+                //     ET = "enum" (*) r#"[a-zA-Z]+"# "{" "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                0,  // on ";", error
+                0,  // on "enum", error
+                0,  // on "type", error
+                0,  // on "{", error
+                0,  // on "}", error
+                13,  // on r#"[a-zA-Z]+"#, goto 12
+
+                // State 7
+                //     AT = "type" (*) r#"[a-zA-Z]+"# ";" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                0,  // on ";", error
+                0,  // on "enum", error
+                0,  // on "type", error
+                0,  // on "{", error
+                0,  // on "}", error
+                14,  // on r#"[a-zA-Z]+"#, goto 13
+
+                // State 8
+                //     AT+ = AT+ AT (*) [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                0,  // on ";", error
+                -5,  // on "enum", reduce `AT+ = AT+, AT => ActionFn(7);`
+                -5,  // on "type", reduce `AT+ = AT+, AT => ActionFn(7);`
+                0,  // on "{", error
+                -5,  // on "}", reduce `AT+ = AT+, AT => ActionFn(7);`
+                0,  // on r#"[a-zA-Z]+"#, error
+
+                // State 9
+                //     AT = (*) "type" r#"[a-zA-Z]+"# ";" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                //     AT+ = (*) AT [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                //     AT+ = (*) AT+ AT [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                //     E = "{" AT+ ET (*) AT+ "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                //     E = "{" AT+ ET (*) "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                0,  // on ";", error
+                0,  // on "enum", error
+                8,  // on "type", goto 7
+                0,  // on "{", error
+                16,  // on "}", goto 15
+                0,  // on r#"[a-zA-Z]+"#, error
+
+                // State 10
+                //     AT = (*) "type" r#"[a-zA-Z]+"# ";" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                //     AT+ = AT+ (*) AT [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                //     E = "{" ET AT+ (*) "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                0,  // on ";", error
+                0,  // on "enum", error
+                8,  // on "type", goto 7
+                0,  // on "{", error
+                17,  // on "}", goto 16
+                0,  // on r#"[a-zA-Z]+"#, error
+
+                // State 11
+                //     E = "{" ET "}" (*) [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                0,  // on ";", error
+                0,  // on "enum", error
+                0,  // on "type", error
+                0,  // on "{", error
+                0,  // on "}", error
+                0,  // on r#"[a-zA-Z]+"#, error
+
+                // State 12
+                //     ET = "enum" r#"[a-zA-Z]+"# (*) "{" "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                0,  // on ";", error
+                0,  // on "enum", error
+                0,  // on "type", error
+                18,  // on "{", goto 17
+                0,  // on "}", error
+                0,  // on r#"[a-zA-Z]+"#, error
+
+                // State 13
+                //     AT = "type" r#"[a-zA-Z]+"# (*) ";" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                19,  // on ";", goto 18
+                0,  // on "enum", error
+                0,  // on "type", error
+                0,  // on "{", error
+                0,  // on "}", error
+                0,  // on r#"[a-zA-Z]+"#, error
+
+                // State 14
+                //     AT = (*) "type" r#"[a-zA-Z]+"# ";" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                //     AT+ = AT+ (*) AT [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                //     E = "{" AT+ ET AT+ (*) "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                0,  // on ";", error
+                0,  // on "enum", error
+                8,  // on "type", goto 7
+                0,  // on "{", error
+                20,  // on "}", goto 19
+                0,  // on r#"[a-zA-Z]+"#, error
+
+                // State 15
+                //     E = "{" AT+ ET "}" (*) [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                0,  // on ";", error
+                0,  // on "enum", error
+                0,  // on "type", error
+                0,  // on "{", error
+                0,  // on "}", error
+                0,  // on r#"[a-zA-Z]+"#, error
+
+                // State 16
+                //     E = "{" ET AT+ "}" (*) [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                0,  // on ";", error
+                0,  // on "enum", error
+                0,  // on "type", error
+                0,  // on "{", error
+                0,  // on "}", error
+                0,  // on r#"[a-zA-Z]+"#, error
+
+                // State 17
+                //     ET = "enum" r#"[a-zA-Z]+"# "{" (*) "}" [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                0,  // on ";", error
+                0,  // on "enum", error
+                0,  // on "type", error
+                0,  // on "{", error
+                21,  // on "}", goto 20
+                0,  // on r#"[a-zA-Z]+"#, error
+
+                // State 18
+                //     AT = "type" r#"[a-zA-Z]+"# ";" (*) [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                0,  // on ";", error
+                -1,  // on "enum", reduce `AT = "type", r#"[a-zA-Z]+"#, ";" => ActionFn(2);`
+                -1,  // on "type", reduce `AT = "type", r#"[a-zA-Z]+"#, ";" => ActionFn(2);`
+                0,  // on "{", error
+                -1,  // on "}", reduce `AT = "type", r#"[a-zA-Z]+"#, ";" => ActionFn(2);`
+                0,  // on r#"[a-zA-Z]+"#, error
+
+                // State 19
+                //     E = "{" AT+ ET AT+ "}" (*) [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                0,  // on ";", error
+                0,  // on "enum", error
+                0,  // on "type", error
+                0,  // on "{", error
+                0,  // on "}", error
+                0,  // on r#"[a-zA-Z]+"#, error
+
+                // State 20
+                //     ET = "enum" r#"[a-zA-Z]+"# "{" "}" (*) [";", "enum", "type", "{", "}", r#"[a-zA-Z]+"#, EOF]
+                0,  // on ";", error
+                0,  // on "enum", error
+                -10,  // on "type", reduce `ET = "enum", r#"[a-zA-Z]+"#, "{", "}" => ActionFn(3);`
+                0,  // on "{", error
+                -10,  // on "}", reduce `ET = "enum", r#"[a-zA-Z]+"#, "{", "}" => ActionFn(3);`
+                0,  // on r#"[a-zA-Z]+"#, error
+
+            ];
+            const __EOF_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // on EOF, error
+
+                // State 1
+                -11,  // on EOF, reduce `__E = E => ActionFn(0);`
+
+                // State 2
+                0,  // on EOF, error
+
+                // State 3
+                0,  // on EOF, error
+
+                // State 4
+                0,  // on EOF, error
+
+                // State 5
+                0,  // on EOF, error
+
+                // State 6
+                0,  // on EOF, error
+
+                // State 7
+                0,  // on EOF, error
+
+                // State 8
+                0,  // on EOF, error
+
+                // State 9
+                0,  // on EOF, error
+
+                // State 10
+                0,  // on EOF, error
+
+                // State 11
+                -6,  // on EOF, reduce `E = "{", ET, "}" => ActionFn(8);`
+
+                // State 12
+                0,  // on EOF, error
+
+                // State 13
+                0,  // on EOF, error
+
+                // State 14
+                0,  // on EOF, error
+
+                // State 15
+                -8,  // on EOF, reduce `E = "{", AT+, ET, "}" => ActionFn(10);`
+
+                // State 16
+                -7,  // on EOF, reduce `E = "{", ET, AT+, "}" => ActionFn(9);`
+
+                // State 17
+                0,  // on EOF, error
+
+                // State 18
+                0,  // on EOF, error
+
+                // State 19
+                -9,  // on EOF, reduce `E = "{", AT+, ET, AT+, "}" => ActionFn(11);`
+
+                // State 20
+                0,  // on EOF, error
+
+            ];
+            const __GOTO: &'static [u8] = &[
+                // State 0
+                0,  // on AT, error
+                0,  // on AT*, error
+                0,  // on AT+, error
+                2,  // on E, goto 1
+                0,  // on ET, error
+                0,  // on __E, error
+
+                // State 1
+                0,  // on AT, error
+                0,  // on AT*, error
+                0,  // on AT+, error
+                0,  // on E, error
+                0,  // on ET, error
+                0,  // on __E, error
+
+                // State 2
+                4,  // on AT, goto 3
+                0,  // on AT*, error
+                5,  // on AT+, goto 4
+                0,  // on E, error
+                6,  // on ET, goto 5
+                0,  // on __E, error
+
+                // State 3
+                0,  // on AT, error
+                0,  // on AT*, error
+                0,  // on AT+, error
+                0,  // on E, error
+                0,  // on ET, error
+                0,  // on __E, error
+
+                // State 4
+                9,  // on AT, goto 8
+                0,  // on AT*, error
+                0,  // on AT+, error
+                0,  // on E, error
+                10,  // on ET, goto 9
+                0,  // on __E, error
+
+                // State 5
+                4,  // on AT, goto 3
+                0,  // on AT*, error
+                11,  // on AT+, goto 10
+                0,  // on E, error
+                0,  // on ET, error
+                0,  // on __E, error
+
+                // State 6
+                0,  // on AT, error
+                0,  // on AT*, error
+                0,  // on AT+, error
+                0,  // on E, error
+                0,  // on ET, error
+                0,  // on __E, error
+
+                // State 7
+                0,  // on AT, error
+                0,  // on AT*, error
+                0,  // on AT+, error
+                0,  // on E, error
+                0,  // on ET, error
+                0,  // on __E, error
+
+                // State 8
+                0,  // on AT, error
+                0,  // on AT*, error
+                0,  // on AT+, error
+                0,  // on E, error
+                0,  // on ET, error
+                0,  // on __E, error
+
+                // State 9
+                4,  // on AT, goto 3
+                0,  // on AT*, error
+                15,  // on AT+, goto 14
+                0,  // on E, error
+                0,  // on ET, error
+                0,  // on __E, error
+
+                // State 10
+                9,  // on AT, goto 8
+                0,  // on AT*, error
+                0,  // on AT+, error
+                0,  // on E, error
+                0,  // on ET, error
+                0,  // on __E, error
+
+                // State 11
+                0,  // on AT, error
+                0,  // on AT*, error
+                0,  // on AT+, error
+                0,  // on E, error
+                0,  // on ET, error
+                0,  // on __E, error
+
+                // State 12
+                0,  // on AT, error
+                0,  // on AT*, error
+                0,  // on AT+, error
+                0,  // on E, error
+                0,  // on ET, error
+                0,  // on __E, error
+
+                // State 13
+                0,  // on AT, error
+                0,  // on AT*, error
+                0,  // on AT+, error
+                0,  // on E, error
+                0,  // on ET, error
+                0,  // on __E, error
+
+                // State 14
+                9,  // on AT, goto 8
+                0,  // on AT*, error
+                0,  // on AT+, error
+                0,  // on E, error
+                0,  // on ET, error
+                0,  // on __E, error
+
+                // State 15
+                0,  // on AT, error
+                0,  // on AT*, error
+                0,  // on AT+, error
+                0,  // on E, error
+                0,  // on ET, error
+                0,  // on __E, error
+
+                // State 16
+                0,  // on AT, error
+                0,  // on AT*, error
+                0,  // on AT+, error
+                0,  // on E, error
+                0,  // on ET, error
+                0,  // on __E, error
+
+                // State 17
+                0,  // on AT, error
+                0,  // on AT*, error
+                0,  // on AT+, error
+                0,  // on E, error
+                0,  // on ET, error
+                0,  // on __E, error
+
+                // State 18
+                0,  // on AT, error
+                0,  // on AT*, error
+                0,  // on AT+, error
+                0,  // on E, error
+                0,  // on ET, error
+                0,  // on __E, error
+
+                // State 19
+                0,  // on AT, error
+                0,  // on AT*, error
+                0,  // on AT+, error
+                0,  // on E, error
+                0,  // on ET, error
+                0,  // on __E, error
+
+                // State 20
+                0,  // on AT, error
+                0,  // on AT*, error
+                0,  // on AT+, error
+                0,  // on E, error
+                0,  // on ET, error
+                0,  // on __E, error
+
+            ];
+            const __DEFAULT_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // no default reduction
+
+                // State 1
+                0,  // no default reduction
+
+                // State 2
+                0,  // no default reduction
+
+                // State 3
+                -4,  // on any token, reduce `AT+ = AT => ActionFn(6);`
+
+                // State 4
+                0,  // no default reduction
+
+                // State 5
+                0,  // no default reduction
+
+                // State 6
+                0,  // no default reduction
+
+                // State 7
+                0,  // no default reduction
+
+                // State 8
+                -5,  // on any token, reduce `AT+ = AT+, AT => ActionFn(7);`
+
+                // State 9
+                0,  // no default reduction
+
+                // State 10
+                0,  // no default reduction
+
+                // State 11
+                -6,  // on any token, reduce `E = "{", ET, "}" => ActionFn(8);`
+
+                // State 12
+                0,  // no default reduction
+
+                // State 13
+                0,  // no default reduction
+
+                // State 14
+                0,  // no default reduction
+
+                // State 15
+                -8,  // on any token, reduce `E = "{", AT+, ET, "}" => ActionFn(10);`
+
+                // State 16
+                -7,  // on any token, reduce `E = "{", ET, AT+, "}" => ActionFn(9);`
+
+                // State 17
+                0,  // no default reduction
+
+                // State 18
+                -1,  // on any token, reduce `AT = "type", r#"[a-zA-Z]+"#, ";" => ActionFn(2);`
+
+                // State 19
+                -9,  // on any token, reduce `E = "{", AT+, ET, AT+, "}" => ActionFn(11);`
+
+                // State 20
+                -10,  // on any token, reduce `ET = "enum", r#"[a-zA-Z]+"#, "{", "}" => ActionFn(3);`
+
+            ];
+            fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+                const __TERMINAL: &'static [&'static str] = &[
+                    r###"";""###,
+                    r###""enum""###,
+                    r###""type""###,
+                    r###""{""###,
+                    r###""}""###,
+                    r###"r#"[a-zA-Z]+"#"###,
+                ];
+                __ACTION[(__state * 6)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+                    if state == 0 {
+                        None
+                    } else {
+                        Some(terminal.to_string())
+                    }
+                }).collect()
+            }
+            #[allow(dead_code)]
+            pub fn parse_E<
+                'input,
+            >(
+                input: &'input str,
+            ) -> Result<(Vec<&'input str>, &'input str, Vec<&'input str>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____E(__v) => Ok(__v), _ => unreachable!() })
+            }
+            #[allow(dead_code)]
+            pub fn parse_E_cancelable<
+                'input,
+            >(
+                input: &'input str,
+                __cancel: &::std::sync::atomic::AtomicBool,
+            ) -> Result<(Vec<&'input str>, &'input str, Vec<&'input str>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____E(__v) => Ok(__v), _ => unreachable!() })
+            }
+
+            #[allow(dead_code)]
+            pub struct EParser {
+                __states: ::std::vec::Vec<i32>,
+                __symbols: __lalrpop_util::stack::Recycled,
+                __cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+            }
+
+            #[allow(dead_code)]
+            impl EParser {
+                pub fn new() -> EParser {
+                    EParser { __states: vec![], __symbols: __lalrpop_util::stack::Recycled::new(), __cancel: None }
+                }
+
+                pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+                    self.__cancel = Some(flag);
+                }
+
+                pub fn parse_in_place<'input>(
+                    &mut self,
+                    input: &'input str,
+                ) -> Result<(Vec<&'input str>, &'input str, Vec<&'input str>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+                {
+                    let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                    let mut __symbols = self.__symbols.take();
+                    let __result = __parse::<>(input, 0, &mut __tokens, &mut self.__states, &mut __symbols, self.__cancel.as_ref().map(|c| &**c)).and_then(|__v| match __v { __Symbol::Nt____E(__v) => Ok(__v), _ => unreachable!() });
+                    self.__symbols.put(__symbols);
+                    __result
+                }
+            }
+
+            fn __parse<
+                'input,
+            >(
+                input: &'input str,
+                __start_state: i32,
+                mut __tokens: &mut Iterator<Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+                mut __states: &mut ::std::vec::Vec<i32>,
+                mut __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                __cancel: Option<&::std::sync::atomic::AtomicBool>,
+            ) -> Result<__Symbol<'input>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                __states.clear();
+                __states.push(__start_state);
+                __symbols.clear();
+                let mut __integer;
+                let mut __lookahead;
+                let __last_location: &mut usize = &mut Default::default();
+                let mut __default_reduced = [0_usize; 16];
+                let mut __default_reduced_len = 0;
+                '__shift: loop {
+                    loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __DEFAULT_ACTION[__state] as i32;
+                        if __action == 0 {
+                            break;
+                        }
+                        if __default_reduced_len < 16 {
+                            __default_reduced[__default_reduced_len] = __state;
+                            __default_reduced_len += 1;
+                        }
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    }
+                    __lookahead = match __tokens.next() {
+                        Some(Ok(v)) => v,
+                        None => break '__shift,
+                        Some(Err(e)) => return Err(e),
+                    };
+                    *__last_location = __lookahead.2.clone();
+                    __integer = match __lookahead.1 {
+                        Token(1, _) if true => 0,
+                        Token(2, _) if true => 1,
+                        Token(3, _) if true => 2,
+                        Token(4, _) if true => 3,
+                        Token(5, _) if true => 4,
+                        Token(0, _) if true => 5,
+                        _ => {
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| true).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match Some(__lookahead) {
+                                Some(__token) => {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error);
+                        }
+                    };
+                    '__inner: loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __ACTION[__state * 6 + __integer] as i32;
+                        if __action > 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                                }
+                            }
+                            let __symbol = match __integer {
+                                0 => match __lookahead.1 {
+                                    Token(1, __tok0) => __Symbol::Term_22_3b_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                1 => match __lookahead.1 {
+                                    Token(2, __tok0) => __Symbol::Term_22enum_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                2 => match __lookahead.1 {
+                                    Token(3, __tok0) => __Symbol::Term_22type_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                3 => match __lookahead.1 {
+                                    Token(4, __tok0) => __Symbol::Term_22_7b_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                4 => match __lookahead.1 {
+                                    Token(5, __tok0) => __Symbol::Term_22_7d_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                5 => match __lookahead.1 {
+                                    Token(0, __tok0) => __Symbol::Termr_23_22_5ba_2dzA_2dZ_5d_2b_22_23((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                _ => unreachable!(),
+                            };
+                            __states.push(__action - 1);
+                            __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                            __default_reduced_len = 0;
+                            continue '__shift;
+                        } else if __action < 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                                }
+                            }
+                            if let Some(r) = __reduce(input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                                if r.is_err() {
+                                    return r;
+                                }
+                                return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] });
+                            }
+                        } else {
+                            let mut __err_lookahead = Some(__lookahead);
+                            let mut __err_integer: Option<usize> = Some(__integer);
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 6 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match __err_lookahead {
+                                Some(__token) => {
+                                    if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                        __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                    } else {
+                                        __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                    }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error)
+                        }
+                    }
+                }
+                loop {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __action = __EOF_ACTION[__state] as i32;
+                    if __action < 0 {
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    } else {
+                        let mut __err_lookahead = None;
+                        let mut __err_integer: Option<usize> = None;
+                        let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 6 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                        let __expected = __expected_tokens(__state);
+                        let __error = match __err_lookahead {
+                            Some(__token) => {
+                                if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                    __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                } else {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                            }
+                            None => {
+                                __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                            }
+                        };
+                        return Err(__error)
+                    }
+                }
+            }
+            fn __accepts<
+                'input,
+            >(
+                input: &'input str,
+                __error_state: i32,
+                __states: & [i32],
+                __opt_integer: Option<usize>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> bool
+            {
+                let mut __states = __states.to_vec();
+                __states.push(__error_state);
+                loop {
+                    let mut __states_len = __states.len();
+                    let __top = __states[__states_len - 1];
+                    let __action = match __opt_integer {
+                        None => __EOF_ACTION[__top as usize] as i32,
+                        Some(__integer) => __ACTION[(__top * 6) as usize + __integer] as i32,
+                    };
+                    if __action == 0 { return false; }
+                    if __action > 0 { return true; }
+                    let (__to_pop, __nt) = match -__action {
+                        // simulate AT = "type", r#"[a-zA-Z]+"#, ";" => ActionFn(2);
+                        1 => {
+                            (3, 0)
+                        }
+                        // simulate AT* =  => ActionFn(4);
+                        2 => {
+                            (0, 1)
+                        }
+                        // simulate AT* = AT+ => ActionFn(5);
+                        3 => {
+                            (1, 1)
+                        }
+                        // simulate AT+ = AT => ActionFn(6);
+                        4 => {
+                            (1, 2)
+                        }
+                        // simulate AT+ = AT+, AT => ActionFn(7);
+                        5 => {
+                            (2, 2)
+                        }
+                        // simulate E = "{", ET, "}" => ActionFn(8);
+                        6 => {
+                            (3, 3)
+                        }
+                        // simulate E = "{", ET, AT+, "}" => ActionFn(9);
+                        7 => {
+                            (4, 3)
+                        }
+                        // simulate E = "{", AT+, ET, "}" => ActionFn(10);
+                        8 => {
+                            (4, 3)
+                        }
+                        // simulate E = "{", AT+, ET, AT+, "}" => ActionFn(11);
+                        9 => {
+                            (5, 3)
+                        }
+                        // simulate ET = "enum", r#"[a-zA-Z]+"#, "{", "}" => ActionFn(3);
+                        10 => {
+                            (4, 4)
+                        }
+                        // simulate __E = E => ActionFn(0);
+                        11 => return true,
+                        _ => panic!("invalid action code {}", __action)
+                    };
+                    __states_len -= __to_pop;
+                    __states.truncate(__states_len);
+                    let __top = __states[__states_len - 1];
+                    let __next_state = __GOTO[(__top * 6 + __nt) as usize] as i32 - 1;
+                    __states.push(__next_state);
+                }
+            }
+            pub fn __reduce<
+                'input,
+            >(
+                input: &'input str,
+                __action: i32,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Option<Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                let __nonterminal = match -__action {
+                    1 => match __reduce_AT_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    2 => match __reduce_AT_2a_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    3 => match __reduce_AT_2a_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    4 => match __reduce_AT_2b_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    5 => match __reduce_AT_2b_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    6 => match __reduce_E_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    7 => match __reduce_E_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    8 => match __reduce_E_2(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    9 => match __reduce_E_3(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    10 => match __reduce_ET_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    11 => match __reduce_____E_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    _ => panic!("invalid action code {}", __action)
+                };
+                let __state = *__states.last().unwrap() as usize;
+                let __next_state = __GOTO[__state * 6 + __nonterminal] as i32 - 1;
+                __states.push(__next_state);
+                None
+            }
+
+            fn __reduce_AT_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // AT = "type", r#"[a-zA-Z]+"#, ";" => ActionFn(2);
+                let __sym2 = __pop_Term_22_3b_22(__symbols);
+                let __sym1 = __pop_Termr_23_22_5ba_2dzA_2dZ_5d_2b_22_23(__symbols);
+                let __sym0 = __pop_Term_22type_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action2::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtAT(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce_AT_2a_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // AT* =  => ActionFn(4);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action4::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::NtAT_2a(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce_AT_2a_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // AT* = AT+ => ActionFn(5);
+                let __sym0 = __pop_NtAT_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action5::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAT_2a(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce_AT_2b_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // AT+ = AT => ActionFn(6);
+                let __sym0 = __pop_NtAT(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action6::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAT_2b(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce_AT_2b_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // AT+ = AT+, AT => ActionFn(7);
+                let __sym1 = __pop_NtAT(__symbols);
+                let __sym0 = __pop_NtAT_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action7::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtAT_2b(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce_E_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // E = "{", ET, "}" => ActionFn(8);
+                let __sym2 = __pop_Term_22_7d_22(__symbols);
+                let __sym1 = __pop_NtET(__symbols);
+                let __sym0 = __pop_Term_22_7b_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action8::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtE(__nt), __end));
+                Ok(3)
+            }
+
+            fn __reduce_E_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // E = "{", ET, AT+, "}" => ActionFn(9);
+                let __sym3 = __pop_Term_22_7d_22(__symbols);
+                let __sym2 = __pop_NtAT_2b(__symbols);
+                let __sym1 = __pop_NtET(__symbols);
+                let __sym0 = __pop_Term_22_7b_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::super::super::__action9::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtE(__nt), __end));
+                Ok(3)
+            }
+
+            fn __reduce_E_2<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // E = "{", AT+, ET, "}" => ActionFn(10);
+                let __sym3 = __pop_Term_22_7d_22(__symbols);
+                let __sym2 = __pop_NtET(__symbols);
+                let __sym1 = __pop_NtAT_2b(__symbols);
+                let __sym0 = __pop_Term_22_7b_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::super::super::__action10::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtE(__nt), __end));
+                Ok(3)
+            }
+
+            fn __reduce_E_3<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // E = "{", AT+, ET, AT+, "}" => ActionFn(11);
+                let __sym4 = __pop_Term_22_7d_22(__symbols);
+                let __sym3 = __pop_NtAT_2b(__symbols);
+                let __sym2 = __pop_NtET(__symbols);
+                let __sym1 = __pop_NtAT_2b(__symbols);
+                let __sym0 = __pop_Term_22_7b_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = super::super::super::__action11::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 5);
+                __symbols.push((__start, __Symbol::NtE(__nt), __end));
+                Ok(3)
+            }
+
+            fn __reduce_ET_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // ET = "enum", r#"[a-zA-Z]+"#, "{", "}" => ActionFn(3);
+                let __sym3 = __pop_Term_22_7d_22(__symbols);
+                let __sym2 = __pop_Term_22_7b_22(__symbols);
+                let __sym1 = __pop_Termr_23_22_5ba_2dzA_2dZ_5d_2b_22_23(__symbols);
+                let __sym0 = __pop_Term_22enum_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::super::super::__action3::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtET(__nt), __end));
+                Ok(4)
+            }
+
+            fn __reduce_____E_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // __E = E => ActionFn(0);
+                let __sym0 = __pop_NtE(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action0::<>(input, __sym0);
+                Err(Ok(__Symbol::Nt____E(__nt)))
+            }
+            fn __pop_Term_22_3b_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_3b_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22enum_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22enum_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22type_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22type_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_7b_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_7b_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_7d_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_7d_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Termr_23_22_5ba_2dzA_2dZ_5d_2b_22_23<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Termr_23_22_5ba_2dzA_2dZ_5d_2b_22_23(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtAT<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtAT(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtAT_2a<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, ::std::vec::Vec<&'input str>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtAT_2a(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtAT_2b<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, ::std::vec::Vec<&'input str>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtAT_2b(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtE<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, (Vec<&'input str>, &'input str, Vec<&'input str>), usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtE(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtET<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtET(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt____E<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, (Vec<&'input str>, &'input str, Vec<&'input str>), usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt____E(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+        }
+        pub use self::__parse_table::parse_E;
+        pub use self::__parse_table::parse_E_cancelable;
+        pub use self::__parse_table::EParser;
+    }
+    pub use self::__parse_table::parse_E_cancelable;
+    pub use self::__parse_table::EParser;
+}
+pub use self::__parse__E::parse_E;
+pub use self::__parse__E::parse_E_cancelable;
+pub use self::__parse__E::EParser;
+mod __intern_token {
+    #![allow(unused_imports)]
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    extern crate regex as __regex;
+    use std::fmt as __fmt;
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Token<'input>(pub usize, pub &'input str);
+    impl<'a> __fmt::Display for Token<'a> {
+        fn fmt(&self, formatter: &mut __fmt::Formatter) -> Result<(), __fmt::Error> {
+            __fmt::Display::fmt(self.1, formatter)
+        }
+    }
+
+    pub struct __Matcher<'input> {
+        text: &'input str,
+        consumed: usize,
+        regex_set: __regex::RegexSet,
+        regex_vec: Vec<__regex::Regex>,
+    }
+
+    impl<'input> __Matcher<'input> {
+        pub fn new(s: &'input str) -> __Matcher<'input> {
+            let __strs: &[&str] = &[
+                "^(?u:[A-Za-z])+",
+                "^(?u:;)",
+                "^(?u:enum)",
+                "^(?u:type)",
+                "^(?u:\\{)",
+                "^(?u:\\})",
+            ];
+            let __regex_set = __regex::RegexSet::new(__strs).unwrap();
+            let __regex_vec = vec![
+                __regex::Regex::new("^(?u:[A-Za-z])+").unwrap(),
+                __regex::Regex::new("^(?u:;)").unwrap(),
+                __regex::Regex::new("^(?u:enum)").unwrap(),
+                __regex::Regex::new("^(?u:type)").unwrap(),
+                __regex::Regex::new("^(?u:\\{)").unwrap(),
+                __regex::Regex::new("^(?u:\\})").unwrap(),
+            ];
+            __Matcher {
+                text: s,
+                consumed: 0,
+                regex_set: __regex_set,
+                regex_vec: __regex_vec,
+            }
+        }
+    }
+
+    impl<'input> Iterator for __Matcher<'input> {
+        type Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize,Token<'input>,&'static str>>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let __text = self.text.trim_left();
+            let __whitespace = self.text.len() - __text.len();
+            let __start_offset = self.consumed + __whitespace;
+            if __text.is_empty() {
+                self.text = __text;
+                self.consumed = __start_offset;
+                None
+            } else {
+                let __matches = self.regex_set.matches(__text);
+                if !__matches.matched_any() {
+                    Some(Err(__lalrpop_util::ParseError::InvalidToken {
+                        location: __start_offset,
+                    }))
+                } else {
+                    let mut __longest_match = 0;
+                    let mut __index = 0;
+                    for __i in 0 .. 6 {
+                        if __matches.matched(__i) {
+                            let __match = self.regex_vec[__i].find(__text).unwrap();
+                            let __len = __match.end();
+                            if __len >= __longest_match {
+                                __longest_match = __len;
+                                __index = __i;
+                            }
+                        }
+                    }
+                    let __result = &__text[..__longest_match];
+                    let __remaining = &__text[__longest_match..];
+                    let __end_offset = __start_offset + __longest_match;
+                    self.text = __remaining;
+                    self.consumed = __end_offset;
+                    Some(Ok((__start_offset, Token(__index, __result), __end_offset)))
+                }
+            }
+        }
+    }
+}
+pub use self::__intern_token::Token;
+
+#[allow(unused_variables)]
+fn __action0<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, (Vec<&'input str>, &'input str, Vec<&'input str>), usize),
+) -> (Vec<&'input str>, &'input str, Vec<&'input str>)
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, ::std::vec::Vec<&'input str>, usize),
+    (_, __1, _): (usize, &'input str, usize),
+    (_, __2, _): (usize, ::std::vec::Vec<&'input str>, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> (Vec<&'input str>, &'input str, Vec<&'input str>)
+{
+    (__0, __1, __2)
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> &'input str
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> &'input str
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::vec::Vec<&'input str>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<&'input str>, usize),
+) -> ::std::vec::Vec<&'input str>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> ::std::vec::Vec<&'input str>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<&'input str>, usize),
+    (_, e, _): (usize, &'input str, usize),
+) -> ::std::vec::Vec<&'input str>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, &'input str, usize),
+) -> (Vec<&'input str>, &'input str, Vec<&'input str>)
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __start1 = __1.2.clone();
+    let __end1 = __2.0.clone();
+    let __temp0 = __action4(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action4(
+        input,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action1(
+        input,
+        __0,
+        __temp0,
+        __1,
+        __temp1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action9<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, ::std::vec::Vec<&'input str>, usize),
+    __3: (usize, &'input str, usize),
+) -> (Vec<&'input str>, &'input str, Vec<&'input str>)
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __start1 = __2.0.clone();
+    let __end1 = __2.2.clone();
+    let __temp0 = __action4(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action5(
+        input,
+        __2,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action1(
+        input,
+        __0,
+        __temp0,
+        __1,
+        __temp1,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action10<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, ::std::vec::Vec<&'input str>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, &'input str, usize),
+) -> (Vec<&'input str>, &'input str, Vec<&'input str>)
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __start1 = __2.2.clone();
+    let __end1 = __3.0.clone();
+    let __temp0 = __action5(
+        input,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action4(
+        input,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action1(
+        input,
+        __0,
+        __temp0,
+        __2,
+        __temp1,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action11<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, ::std::vec::Vec<&'input str>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, ::std::vec::Vec<&'input str>, usize),
+    __4: (usize, &'input str, usize),
+) -> (Vec<&'input str>, &'input str, Vec<&'input str>)
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __start1 = __3.0.clone();
+    let __end1 = __3.2.clone();
+    let __temp0 = __action5(
+        input,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action5(
+        input,
+        __3,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action1(
+        input,
+        __0,
+        __temp0,
+        __2,
+        __temp1,
+        __4,
+    )
+}
+
+pub trait __ToTriple<'input, > {
+    type Error;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),Self::Error>;
+}
+
+impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize) {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        Ok(value)
+    }
+}
+impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        value
+    }
+}
@@ -0,0 +1,1195 @@
+// auto-generated: "lalrpop 0.15.0"
+use lifetime_tok_lib::LtTok;
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+
+mod __parse__Expr {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    use lifetime_tok_lib::LtTok;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__ToTriple;
+    #[allow(dead_code)]
+    pub fn parse_Expr<
+        'input,
+        __TOKEN: __ToTriple<'input, Error=&'static str>,
+        __TOKENS: IntoIterator<Item=__TOKEN>,
+    >(
+        __tokens0: __TOKENS,
+    ) -> Result<Vec<&'input str>, __lalrpop_util::ParseError<(), LtTok<'input>, &'static str>> where
+      __TOKENS: Clone,
+    {
+        let __ascent = __ascent::parse_Expr(
+            __tokens0.clone(),
+        );
+        let __parse_table = __parse_table::parse_Expr(
+            __tokens0.clone(),
+        );
+        assert_eq!(__ascent, __parse_table);
+        return __ascent;
+    }
+    mod __ascent {
+
+        mod __parse__Expr {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use lifetime_tok_lib::LtTok;
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__ToTriple;
+            #[allow(dead_code)]
+            pub fn parse_Expr<
+                'input,
+                __TOKEN: __ToTriple<'input, Error=&'static str>,
+                __TOKENS: IntoIterator<Item=__TOKEN>,
+            >(
+                __tokens0: __TOKENS,
+            ) -> Result<Vec<&'input str>, __lalrpop_util::ParseError<(), LtTok<'input>, &'static str>>
+            {
+                let __tokens = __tokens0.into_iter();
+                let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+                let __last_location = ::std::cell::RefCell::new(Default::default());
+                let mut __tokens = __tokens.inspect(|t| if let Ok(ref t) = *t { *__last_location.borrow_mut() = t.2.clone(); });
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                let mut __extra = None;
+                let __result = __state0(&mut __tokens, __lookahead, &mut __extra, ::std::marker::PhantomData::<()>);
+                if let Some((__token, __expected)) = __extra {
+                    return Err(match __result {
+                        Ok(_) => __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected },
+                        Err(_) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                    });
+                }
+                match __result {
+                    Ok((Some(__lookahead), _)) => {
+                        Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] })
+                    }
+                    Ok((None, __Nonterminal::____Expr((_, __nt, _)))) => {
+                        Ok(__nt)
+                    }
+                    Ok(_) => unreachable!(),
+                    Err(__lalrpop_util::ParseError::UnrecognizedEof { expected: __expected, .. }) => {
+                        Err(__lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.borrow().clone(), expected: __expected })
+                    }
+                    Err(__error) => Err(__error),
+                }
+            }
+
+            #[allow(dead_code)]
+            pub enum __Nonterminal<'input>
+             {
+                Expr(((), Vec<&'input str>, ())),
+                Other_2a(((), ::std::vec::Vec<&'input str>, ())),
+                Other_2b(((), ::std::vec::Vec<&'input str>, ())),
+                ____Expr(((), Vec<&'input str>, ())),
+            }
+            impl<'input> ::std::fmt::Debug for __Nonterminal<'input>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Nonterminal::Expr(..) => __f.write_str("Expr"),
+                        __Nonterminal::Other_2a(..) => __f.write_str("Other*"),
+                        __Nonterminal::Other_2b(..) => __f.write_str("Other+"),
+                        __Nonterminal::____Expr(..) => __f.write_str("__Expr"),
+                    }
+                }
+            }
+
+            // State 0
+            //     AllInputs = []
+            //     OptionalInputs = []
+            //     FixedInputs = []
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Expr = (*) [Other, EOF]
+            //     Expr = (*) Other+ [Other, EOF]
+            //     Other+ = (*) Other+ Other [Other, EOF]
+            //     Other+ = (*) Other [Other, EOF]
+            //     __Expr = (*) Expr [Other, EOF]
+            //
+            //   Other -> S3
+            //   [EOF] -> Expr =  => ActionFn(6);
+            //
+            //     Expr -> S1
+            //     Other+ -> S2
+            fn __state0<
+                'input,
+                __TOKENS: Iterator<Item=Result<((), LtTok<'input>, ()),&'static str>>,
+            >(
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<((), LtTok<'input>, ())>,
+                __extra: &mut Option<(((), LtTok<'input>, ()), ::std::vec::Vec<::std::string::String>)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), LtTok<'input>, ())>, __Nonterminal<'input>), __lalrpop_util::ParseError<(), LtTok<'input>, &'static str>>
+            {
+                let mut __result: (Option<((), LtTok<'input>, ())>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, LtTok::Other(__tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state3(__tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    None => {
+                        let __start: () = ::std::default::Default::default();
+                        let __end = __lookahead.as_ref().map(|o| o.0.clone()).unwrap_or_else(|| __start.clone());
+                        let __nt = super::super::super::__action6::<>(&__start, &__end);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"Other"###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<((), LtTok<'input>, ())> = None;
+                        let __start: () = ::std::default::Default::default();
+                        let __end = __lookahead.as_ref().map(|o| o.0.clone()).unwrap_or_else(|| __start.clone());
+                        let __nt = super::super::super::__action6::<>(&__start, &__end);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Expr(__sym0) => {
+                            __result = try!(__state1(__tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Other_2b(__sym0) => {
+                            __result = try!(__state2(__tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 1
+            //     AllInputs = [Expr]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(__Expr)
+            //
+            //     __Expr = Expr (*) [Other, EOF]
+            //
+            //   [EOF] -> __Expr = Expr => ActionFn(0);
+            //
+            fn __state1<
+                'input,
+                __TOKENS: Iterator<Item=Result<((), LtTok<'input>, ()),&'static str>>,
+            >(
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<((), LtTok<'input>, ())>,
+                __extra: &mut Option<(((), LtTok<'input>, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), Vec<&'input str>, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), LtTok<'input>, ())>, __Nonterminal<'input>), __lalrpop_util::ParseError<(), LtTok<'input>, &'static str>>
+            {
+                let mut __result: (Option<((), LtTok<'input>, ())>, __Nonterminal<'input>);
+                match __lookahead {
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(__sym0);
+                        let __nt = __Nonterminal::____Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<((), LtTok<'input>, ())> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(__sym0);
+                        let __nt = __Nonterminal::____Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 2
+            //     AllInputs = [Other+]
+            //     OptionalInputs = []
+            //     FixedInputs = [Other+]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Expr = Other+ (*) [Other, EOF]
+            //     Other+ = Other+ (*) Other [Other, EOF]
+            //
+            //   Other -> S4
+            //   [EOF] -> Expr = Other+ => ActionFn(7);
+            //
+            fn __state2<
+                'input,
+                __TOKENS: Iterator<Item=Result<((), LtTok<'input>, ()),&'static str>>,
+            >(
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<((), LtTok<'input>, ())>,
+                __extra: &mut Option<(((), LtTok<'input>, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), ::std::vec::Vec<&'input str>, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), LtTok<'input>, ())>, __Nonterminal<'input>), __lalrpop_util::ParseError<(), LtTok<'input>, &'static str>>
+            {
+                let mut __result: (Option<((), LtTok<'input>, ())>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, LtTok::Other(__tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state4(__tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action7::<>(__sym0);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"Other"###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<((), LtTok<'input>, ())> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action7::<>(__sym0);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 3
+            //     AllInputs = [Other]
+            //     OptionalInputs = []
+            //     FixedInputs = [Other]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Other+)
+            //
+            //     Other+ = Other (*) [Other, EOF]
+            //
+            //   [Other, EOF] -> Other+ = Other => ActionFn(4);
+            //
+            fn __state3<
+                'input,
+                __TOKENS: Iterator<Item=Result<((), LtTok<'input>, ()),&'static str>>,
+            >(
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<(((), LtTok<'input>, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), &'input str, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), LtTok<'input>, ())>, __Nonterminal<'input>), __lalrpop_util::ParseError<(), LtTok<'input>, &'static str>>
+            {
+                let mut __result: (Option<((), LtTok<'input>, ())>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                match __lookahead {
+                    Some((_, LtTok::Other(_), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action4::<>(__sym0);
+                        let __nt = __Nonterminal::Other_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"Other"###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<((), LtTok<'input>, ())> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action4::<>(__sym0);
+                        let __nt = __Nonterminal::Other_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 4
+            //     AllInputs = [Other+, Other]
+            //     OptionalInputs = []
+            //     FixedInputs = [Other+, Other]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Other+)
+            //
+            //     Other+ = Other+ Other (*) [Other, EOF]
+            //
+            //   [Other, EOF] -> Other+ = Other+, Other => ActionFn(5);
+            //
+            fn __state4<
+                'input,
+                __TOKENS: Iterator<Item=Result<((), LtTok<'input>, ()),&'static str>>,
+            >(
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<(((), LtTok<'input>, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), ::std::vec::Vec<&'input str>, ()),
+                __sym1: ((), &'input str, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), LtTok<'input>, ())>, __Nonterminal<'input>), __lalrpop_util::ParseError<(), LtTok<'input>, &'static str>>
+            {
+                let mut __result: (Option<((), LtTok<'input>, ())>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                match __lookahead {
+                    Some((_, LtTok::Other(_), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action5::<>(__sym0, __sym1);
+                        let __nt = __Nonterminal::Other_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"Other"###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<((), LtTok<'input>, ())> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action5::<>(__sym0, __sym1);
+                        let __nt = __Nonterminal::Other_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+        }
+        pub use self::__parse__Expr::parse_Expr;
+    }
+    mod __parse_table {
+
+        mod __parse_table {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use lifetime_tok_lib::LtTok;
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__ToTriple;
+            #[allow(dead_code)]
+            pub enum __Symbol<'input>
+             {
+                TermOther(&'input str),
+                NtExpr(Vec<&'input str>),
+                NtOther_2a(::std::vec::Vec<&'input str>),
+                NtOther_2b(::std::vec::Vec<&'input str>),
+                Nt____Expr(Vec<&'input str>),
+            }
+            impl<'input> ::std::fmt::Debug for __Symbol<'input>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Symbol::TermOther(..) => __f.write_str("Other"),
+                        __Symbol::NtExpr(..) => __f.write_str("Expr"),
+                        __Symbol::NtOther_2a(..) => __f.write_str("Other*"),
+                        __Symbol::NtOther_2b(..) => __f.write_str("Other+"),
+                        __Symbol::Nt____Expr(..) => __f.write_str("__Expr"),
+                    }
+                }
+            }
+            const __ACTION: &'static [i8] = &[
+                // State 0
+                //     Expr = (*) [Other, EOF]
+                //     Expr = (*) Other+ [Other, EOF]
+                //     Other+ = (*) Other+ Other [Other, EOF]
+                //     Other+ = (*) Other [Other, EOF]
+                //     __Expr = (*) Expr [Other, EOF]
+                4,  // on Other, goto 3
+
+                // State 1
+                //     __Expr = Expr (*) [Other, EOF]
+                0,  // on Other, error
+
+                // State 2
+                //     Expr = Other+ (*) [Other, EOF]
+                //     Other+ = Other+ (*) Other [Other, EOF]
+                5,  // on Other, goto 4
+
+                // State 3
+                //     Other+ = Other (*) [Other, EOF]
+                -5,  // on Other, reduce `Other+ = Other => ActionFn(4);`
+
+                // State 4
+                //     Other+ = Other+ Other (*) [Other, EOF]
+                -6,  // on Other, reduce `Other+ = Other+, Other => ActionFn(5);`
+
+            ];
+            const __EOF_ACTION: &'static [i8] = &[
+                // State 0
+                -1,  // on EOF, reduce `Expr =  => ActionFn(6);`
+
+                // State 1
+                -7,  // on EOF, reduce `__Expr = Expr => ActionFn(0);`
+
+                // State 2
+                -2,  // on EOF, reduce `Expr = Other+ => ActionFn(7);`
+
+                // State 3
+                -5,  // on EOF, reduce `Other+ = Other => ActionFn(4);`
+
+                // State 4
+                -6,  // on EOF, reduce `Other+ = Other+, Other => ActionFn(5);`
+
+            ];
+            const __GOTO: &'static [u8] = &[
+                // State 0
+                2,  // on Expr, goto 1
+                0,  // on Other*, error
+                3,  // on Other+, goto 2
+                0,  // on __Expr, error
+
+                // State 1
+                0,  // on Expr, error
+                0,  // on Other*, error
+                0,  // on Other+, error
+                0,  // on __Expr, error
+
+                // State 2
+                0,  // on Expr, error
+                0,  // on Other*, error
+                0,  // on Other+, error
+                0,  // on __Expr, error
+
+                // State 3
+                0,  // on Expr, error
+                0,  // on Other*, error
+                0,  // on Other+, error
+                0,  // on __Expr, error
+
+                // State 4
+                0,  // on Expr, error
+                0,  // on Other*, error
+                0,  // on Other+, error
+                0,  // on __Expr, error
+
+            ];
+            const __DEFAULT_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // no default reduction
+
+                // State 1
+                0,  // no default reduction
+
+                // State 2
+                0,  // no default reduction
+
+                // State 3
+                -5,  // on any token, reduce `Other+ = Other => ActionFn(4);`
+
+                // State 4
+                -6,  // on any token, reduce `Other+ = Other+, Other => ActionFn(5);`
+
+            ];
+            fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+                const __TERMINAL: &'static [&'static str] = &[
+                    r###"Other"###,
+                ];
+                __ACTION[(__state * 1)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+                    if state == 0 {
+                        None
+                    } else {
+                        Some(terminal.to_string())
+                    }
+                }).collect()
+            }
+            #[allow(dead_code)]
+            pub fn parse_Expr<
+                'input,
+                __TOKEN: __ToTriple<'input, Error=&'static str>,
+                __TOKENS: IntoIterator<Item=__TOKEN>,
+            >(
+                __tokens0: __TOKENS,
+            ) -> Result<Vec<&'input str>, __lalrpop_util::ParseError<(), LtTok<'input>, &'static str>>
+            {
+                let __tokens = __tokens0.into_iter();
+                let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() })
+            }
+            #[allow(dead_code)]
+            pub fn parse_Expr_cancelable<
+                'input,
+                __TOKEN: __ToTriple<'input, Error=&'static str>,
+                __TOKENS: IntoIterator<Item=__TOKEN>,
+            >(
+                __tokens0: __TOKENS,
+                __cancel: &::std::sync::atomic::AtomicBool,
+            ) -> Result<Vec<&'input str>, __lalrpop_util::ParseError<(), LtTok<'input>, &'static str>>
+            {
+                let __tokens = __tokens0.into_iter();
+                let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() })
+            }
+
+            #[allow(dead_code)]
+            pub struct ExprParser {
+                __states: ::std::vec::Vec<i32>,
+                __symbols: __lalrpop_util::stack::Recycled,
+                __cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+            }
+
+            #[allow(dead_code)]
+            impl ExprParser {
+                pub fn new() -> ExprParser {
+                    ExprParser { __states: vec![], __symbols: __lalrpop_util::stack::Recycled::new(), __cancel: None }
+                }
+
+                pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+                    self.__cancel = Some(flag);
+                }
+
+                pub fn parse_in_place<'input, __TOKEN: __ToTriple<'input, Error=&'static str>, __TOKENS: IntoIterator<Item=__TOKEN>>(
+                    &mut self,
+                    __tokens0: __TOKENS,
+                ) -> Result<Vec<&'input str>, __lalrpop_util::ParseError<(), LtTok<'input>, &'static str>>
+                {
+                    let __tokens = __tokens0.into_iter();
+                    let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+                    let mut __symbols = self.__symbols.take();
+                    let __result = __parse::<>(0, &mut __tokens, &mut self.__states, &mut __symbols, self.__cancel.as_ref().map(|c| &**c)).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() });
+                    self.__symbols.put(__symbols);
+                    __result
+                }
+            }
+
+            fn __parse<
+                'input,
+            >(
+                __start_state: i32,
+                mut __tokens: &mut Iterator<Item = Result<((), LtTok<'input>, ()), &'static str>>,
+                mut __states: &mut ::std::vec::Vec<i32>,
+                mut __symbols: &mut ::std::vec::Vec<((),__Symbol<'input>,())>,
+                __cancel: Option<&::std::sync::atomic::AtomicBool>,
+            ) -> Result<__Symbol<'input>, __lalrpop_util::ParseError<(), LtTok<'input>, &'static str>>
+            {
+                __states.clear();
+                __states.push(__start_state);
+                __symbols.clear();
+                let mut __integer;
+                let mut __lookahead;
+                let __last_location: &mut () = &mut Default::default();
+                let mut __default_reduced = [0_usize; 16];
+                let mut __default_reduced_len = 0;
+                '__shift: loop {
+                    loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __DEFAULT_ACTION[__state] as i32;
+                        if __action == 0 {
+                            break;
+                        }
+                        if __default_reduced_len < 16 {
+                            __default_reduced[__default_reduced_len] = __state;
+                            __default_reduced_len += 1;
+                        }
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(__action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    }
+                    __lookahead = match __tokens.next() {
+                        Some(Ok(v)) => v,
+                        None => break '__shift,
+                        Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                    };
+                    *__last_location = __lookahead.2.clone();
+                    __integer = match __lookahead.1 {
+                        LtTok::Other(_) if true => 0,
+                        _ => {
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| true).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match Some(__lookahead) {
+                                Some(__token) => {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error);
+                        }
+                    };
+                    '__inner: loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __ACTION[__state * 1 + __integer] as i32;
+                        if __action > 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                                }
+                            }
+                            let __symbol = match __integer {
+                                0 => match __lookahead.1 {
+                                    LtTok::Other(__tok0) => __Symbol::TermOther((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                _ => unreachable!(),
+                            };
+                            __states.push(__action - 1);
+                            __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                            __default_reduced_len = 0;
+                            continue '__shift;
+                        } else if __action < 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                                }
+                            }
+                            if let Some(r) = __reduce(__action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                                if r.is_err() {
+                                    return r;
+                                }
+                                return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] });
+                            }
+                        } else {
+                            let mut __err_lookahead = Some(__lookahead);
+                            let mut __err_integer: Option<usize> = Some(__integer);
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 1 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match __err_lookahead {
+                                Some(__token) => {
+                                    if __accepts( *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                        __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                    } else {
+                                        __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                    }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error)
+                        }
+                    }
+                }
+                loop {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __action = __EOF_ACTION[__state] as i32;
+                    if __action < 0 {
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(__action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    } else {
+                        let mut __err_lookahead = None;
+                        let mut __err_integer: Option<usize> = None;
+                        let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 1 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                        let __expected = __expected_tokens(__state);
+                        let __error = match __err_lookahead {
+                            Some(__token) => {
+                                if __accepts( *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                    __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                } else {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                            }
+                            None => {
+                                __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                            }
+                        };
+                        return Err(__error)
+                    }
+                }
+            }
+            fn __accepts<
+                'input,
+            >(
+                __error_state: i32,
+                __states: & [i32],
+                __opt_integer: Option<usize>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> bool
+            {
+                let mut __states = __states.to_vec();
+                __states.push(__error_state);
+                loop {
+                    let mut __states_len = __states.len();
+                    let __top = __states[__states_len - 1];
+                    let __action = match __opt_integer {
+                        None => __EOF_ACTION[__top as usize] as i32,
+                        Some(__integer) => __ACTION[(__top * 1) as usize + __integer] as i32,
+                    };
+                    if __action == 0 { return false; }
+                    if __action > 0 { return true; }
+                    let (__to_pop, __nt) = match -__action {
+                        // simulate Expr =  => ActionFn(6);
+                        1 => {
+                            (0, 0)
+                        }
+                        // simulate Expr = Other+ => ActionFn(7);
+                        2 => {
+                            (1, 0)
+                        }
+                        // simulate Other* =  => ActionFn(2);
+                        3 => {
+                            (0, 1)
+                        }
+                        // simulate Other* = Other+ => ActionFn(3);
+                        4 => {
+                            (1, 1)
+                        }
+                        // simulate Other+ = Other => ActionFn(4);
+                        5 => {
+                            (1, 2)
+                        }
+                        // simulate Other+ = Other+, Other => ActionFn(5);
+                        6 => {
+                            (2, 2)
+                        }
+                        // simulate __Expr = Expr => ActionFn(0);
+                        7 => return true,
+                        _ => panic!("invalid action code {}", __action)
+                    };
+                    __states_len -= __to_pop;
+                    __states.truncate(__states_len);
+                    let __top = __states[__states_len - 1];
+                    let __next_state = __GOTO[(__top * 4 + __nt) as usize] as i32 - 1;
+                    __states.push(__next_state);
+                }
+            }
+            pub fn __reduce<
+                'input,
+            >(
+                __action: i32,
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<'input>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Option<Result<__Symbol<'input>,__lalrpop_util::ParseError<(), LtTok<'input>, &'static str>>>
+            {
+                let __nonterminal = match -__action {
+                    1 => match __reduce_Expr_0(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    2 => match __reduce_Expr_1(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    3 => match __reduce_Other_2a_0(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    4 => match __reduce_Other_2a_1(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    5 => match __reduce_Other_2b_0(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    6 => match __reduce_Other_2b_1(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    7 => match __reduce_____Expr_0(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    _ => panic!("invalid action code {}", __action)
+                };
+                let __state = *__states.last().unwrap() as usize;
+                let __next_state = __GOTO[__state * 4 + __nonterminal] as i32 - 1;
+                __states.push(__next_state);
+                None
+            }
+
+            fn __reduce_Expr_0<
+                'input,
+            >(
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<'input>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<(), LtTok<'input>, &'static str>>>
+            {
+                // Expr =  => ActionFn(6);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action6::<>(&__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce_Expr_1<
+                'input,
+            >(
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<'input>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<(), LtTok<'input>, &'static str>>>
+            {
+                // Expr = Other+ => ActionFn(7);
+                let __sym0 = __pop_NtOther_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action7::<>(__sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce_Other_2a_0<
+                'input,
+            >(
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<'input>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<(), LtTok<'input>, &'static str>>>
+            {
+                // Other* =  => ActionFn(2);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action2::<>(&__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::NtOther_2a(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce_Other_2a_1<
+                'input,
+            >(
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<'input>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<(), LtTok<'input>, &'static str>>>
+            {
+                // Other* = Other+ => ActionFn(3);
+                let __sym0 = __pop_NtOther_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action3::<>(__sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtOther_2a(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce_Other_2b_0<
+                'input,
+            >(
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<'input>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<(), LtTok<'input>, &'static str>>>
+            {
+                // Other+ = Other => ActionFn(4);
+                let __sym0 = __pop_TermOther(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action4::<>(__sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtOther_2b(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce_Other_2b_1<
+                'input,
+            >(
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<'input>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<(), LtTok<'input>, &'static str>>>
+            {
+                // Other+ = Other+, Other => ActionFn(5);
+                let __sym1 = __pop_TermOther(__symbols);
+                let __sym0 = __pop_NtOther_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action5::<>(__sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtOther_2b(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce_____Expr_0<
+                'input,
+            >(
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<'input>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<(), LtTok<'input>, &'static str>>>
+            {
+                // __Expr = Expr => ActionFn(0);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action0::<>(__sym0);
+                Err(Ok(__Symbol::Nt____Expr(__nt)))
+            }
+            fn __pop_TermOther<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<'input>,())>
+            ) -> ((), &'input str, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::TermOther(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtExpr<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<'input>,())>
+            ) -> ((), Vec<&'input str>, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtExpr(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtOther_2a<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<'input>,())>
+            ) -> ((), ::std::vec::Vec<&'input str>, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtOther_2a(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtOther_2b<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<'input>,())>
+            ) -> ((), ::std::vec::Vec<&'input str>, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtOther_2b(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt____Expr<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<'input>,())>
+            ) -> ((), Vec<&'input str>, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt____Expr(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+        }
+        pub use self::__parse_table::parse_Expr;
+        pub use self::__parse_table::parse_Expr_cancelable;
+        pub use self::__parse_table::ExprParser;
+    }
+    pub use self::__parse_table::parse_Expr_cancelable;
+    pub use self::__parse_table::ExprParser;
+}
+pub use self::__parse__Expr::parse_Expr;
+pub use self::__parse__Expr::parse_Expr_cancelable;
+pub use self::__parse__Expr::ExprParser;
+
+fn __action0<
+    'input,
+>(
+    (_, __0, _): ((), Vec<&'input str>, ()),
+) -> Vec<&'input str>
+{
+    (__0)
+}
+
+fn __action1<
+    'input,
+>(
+    (_, __0, _): ((), ::std::vec::Vec<&'input str>, ()),
+) -> Vec<&'input str>
+{
+    (__0)
+}
+
+fn __action2<
+    'input,
+>(
+    __lookbehind: &(),
+    __lookahead: &(),
+) -> ::std::vec::Vec<&'input str>
+{
+    vec![]
+}
+
+fn __action3<
+    'input,
+>(
+    (_, v, _): ((), ::std::vec::Vec<&'input str>, ()),
+) -> ::std::vec::Vec<&'input str>
+{
+    v
+}
+
+fn __action4<
+    'input,
+>(
+    (_, __0, _): ((), &'input str, ()),
+) -> ::std::vec::Vec<&'input str>
+{
+    vec![__0]
+}
+
+fn __action5<
+    'input,
+>(
+    (_, v, _): ((), ::std::vec::Vec<&'input str>, ()),
+    (_, e, _): ((), &'input str, ()),
+) -> ::std::vec::Vec<&'input str>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+fn __action6<
+    'input,
+>(
+    __lookbehind: &(),
+    __lookahead: &(),
+) -> Vec<&'input str>
+{
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action2(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action1(
+        __temp0,
+    )
+}
+
+fn __action7<
+    'input,
+>(
+    __0: ((), ::std::vec::Vec<&'input str>, ()),
+) -> Vec<&'input str>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action3(
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action1(
+        __temp0,
+    )
+}
+
+pub trait __ToTriple<'input, > {
+    type Error;
+    fn to_triple(value: Self) -> Result<((),LtTok<'input>,()),Self::Error>;
+}
+
+impl<'input, > __ToTriple<'input, > for LtTok<'input> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<((),LtTok<'input>,()),&'static str> {
+        Ok(((), value, ()))
+    }
+}
+impl<'input, > __ToTriple<'input, > for Result<(LtTok<'input>),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<((),LtTok<'input>,()),&'static str> {
+        value.map(|v| ((), v, ()))
+    }
+}
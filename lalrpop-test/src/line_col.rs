@@ -0,0 +1,2241 @@
+// auto-generated: "lalrpop 0.15.0"
+use lalrpop_util::LineCol;
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+
+mod __parse__Items {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    use lalrpop_util::LineCol;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__intern_token::Token;
+    #[allow(dead_code)]
+    pub fn parse_Items<
+        'input,
+    >(
+        input: &'input str,
+    ) -> Result<Vec<(LineCol, LineCol)>, __lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>
+    {
+        let __result: Result<Vec<(LineCol, LineCol)>, __lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>> = (|| {
+            let __ascent = __ascent::parse_Items(
+                input,
+            );
+            let __parse_table = __parse_table::parse_Items(
+                input,
+            );
+            assert_eq!(__ascent, __parse_table);
+            return __ascent;
+        })();
+        __result.map_err(super::__intern_token::__reserved_word)
+    }
+    mod __ascent {
+
+        mod __parse__Items {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use lalrpop_util::LineCol;
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__intern_token::Token;
+            #[allow(dead_code)]
+            pub fn parse_Items<
+                'input,
+            >(
+                input: &'input str,
+            ) -> Result<Vec<(LineCol, LineCol)>, __lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>
+            {
+                let __result: Result<Vec<(LineCol, LineCol)>, __lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>> = (|| {
+                    let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                    let mut __tokens = __lalrpop_util::Locations::<LineCol, _>::new(input, __tokens);
+                    let __last_location = ::std::cell::RefCell::new(Default::default());
+                    let mut __tokens = __tokens.inspect(|t| if let Ok(ref t) = *t { *__last_location.borrow_mut() = t.2.clone(); });
+                    let __lookahead = match __tokens.next() {
+                        Some(Ok(v)) => Some(v),
+                        None => None,
+                        Some(Err(e)) => return Err(e),
+                    };
+                    let mut __extra = None;
+                    let __result = __state0(input, &mut __tokens, __lookahead, &mut __extra, ::std::marker::PhantomData::<()>);
+                    if let Some((__token, __expected)) = __extra {
+                        return Err(match __result {
+                            Ok(_) => __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected },
+                            Err(_) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                        });
+                    }
+                    match __result {
+                        Ok((Some(__lookahead), _)) => {
+                            Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] })
+                        }
+                        Ok((None, __Nonterminal::____Items((_, __nt, _)))) => {
+                            Ok(__nt)
+                        }
+                        Ok(_) => unreachable!(),
+                        Err(__lalrpop_util::ParseError::UnrecognizedEof { expected: __expected, .. }) => {
+                            Err(__lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.borrow().clone(), expected: __expected })
+                        }
+                        Err(__error) => Err(__error),
+                    }
+                })();
+                __result.map_err(super::super::super::__intern_token::__reserved_word)
+            }
+
+            #[allow(dead_code)]
+            pub enum __Nonterminal<>
+             {
+                _40L((LineCol, LineCol, LineCol)),
+                _40R((LineCol, LineCol, LineCol)),
+                Item((LineCol, (), LineCol)),
+                Items((LineCol, Vec<(LineCol, LineCol)>, LineCol)),
+                Spanned_3cItem_3e((LineCol, (LineCol, LineCol), LineCol)),
+                Spanned_3cItem_3e_2a((LineCol, ::std::vec::Vec<(LineCol, LineCol)>, LineCol)),
+                Spanned_3cItem_3e_2b((LineCol, ::std::vec::Vec<(LineCol, LineCol)>, LineCol)),
+                ____Items((LineCol, Vec<(LineCol, LineCol)>, LineCol)),
+            }
+            impl<> ::std::fmt::Debug for __Nonterminal<>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Nonterminal::_40L(..) => __f.write_str("@L"),
+                        __Nonterminal::_40R(..) => __f.write_str("@R"),
+                        __Nonterminal::Item(..) => __f.write_str("Item"),
+                        __Nonterminal::Items(..) => __f.write_str("Items"),
+                        __Nonterminal::Spanned_3cItem_3e(..) => __f.write_str("Spanned<Item>"),
+                        __Nonterminal::Spanned_3cItem_3e_2a(..) => __f.write_str("Spanned<Item>*"),
+                        __Nonterminal::Spanned_3cItem_3e_2b(..) => __f.write_str("Spanned<Item>+"),
+                        __Nonterminal::____Items(..) => __f.write_str("__Items"),
+                    }
+                }
+            }
+
+            // State 0
+            //     AllInputs = []
+            //     OptionalInputs = []
+            //     FixedInputs = []
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Item = (*) "let" NAME "=" NAME ["=", "let", NAME, EOF]
+            //     Item = (*) NAME ["=", "let", NAME, EOF]
+            //     Items = (*) ["=", "let", NAME, EOF]
+            //     Items = (*) Spanned<Item>+ ["=", "let", NAME, EOF]
+            //     Spanned<Item> = (*) Item ["=", "let", NAME, EOF]
+            //     Spanned<Item>+ = (*) Spanned<Item> ["=", "let", NAME, EOF]
+            //     Spanned<Item>+ = (*) Spanned<Item>+ Spanned<Item> ["=", "let", NAME, EOF]
+            //     __Items = (*) Items ["=", "let", NAME, EOF]
+            //
+            //   "let" -> S5
+            //   NAME -> S6
+            //   [EOF] -> Items =  => ActionFn(13);
+            //
+            //     Item -> S1
+            //     Items -> S2
+            //     Spanned<Item> -> S3
+            //     Spanned<Item>+ -> S4
+            fn __state0<
+                'input,
+                __TOKENS: Iterator<Item=Result<(LineCol, Token<'input>, LineCol),__lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(LineCol, Token<'input>, LineCol)>,
+                __extra: &mut Option<((LineCol, Token<'input>, LineCol), ::std::vec::Vec<::std::string::String>)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(LineCol, Token<'input>, LineCol)>, __Nonterminal<>), __lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(LineCol, Token<'input>, LineCol)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(input, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(input, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    None => {
+                        let __start: LineCol = ::std::default::Default::default();
+                        let __end = __lookahead.as_ref().map(|o| o.0.clone()).unwrap_or_else(|| __start.clone());
+                        let __nt = super::super::super::__action13::<>(input, &__start, &__end);
+                        let __nt = __Nonterminal::Items((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""let""###.to_string(),
+                            r###"NAME"###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(LineCol, Token<'input>, LineCol)> = None;
+                        let __start: LineCol = ::std::default::Default::default();
+                        let __end = __lookahead.as_ref().map(|o| o.0.clone()).unwrap_or_else(|| __start.clone());
+                        let __nt = super::super::super::__action13::<>(input, &__start, &__end);
+                        let __nt = __Nonterminal::Items((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Item(__sym0) => {
+                            __result = try!(__state1(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Items(__sym0) => {
+                            __result = try!(__state2(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Spanned_3cItem_3e(__sym0) => {
+                            __result = try!(__state3(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Spanned_3cItem_3e_2b(__sym0) => {
+                            __result = try!(__state4(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 1
+            //     AllInputs = [Item]
+            //     OptionalInputs = []
+            //     FixedInputs = [Item]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Spanned<Item>)
+            //
+            //     Spanned<Item> = Item (*) ["=", "let", NAME, EOF]
+            //
+            //   ["let", NAME, EOF] -> Spanned<Item> = Item => ActionFn(12);
+            //
+            fn __state1<
+                'input,
+                __TOKENS: Iterator<Item=Result<(LineCol, Token<'input>, LineCol),__lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(LineCol, Token<'input>, LineCol)>,
+                __extra: &mut Option<((LineCol, Token<'input>, LineCol), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (LineCol, (), LineCol),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(LineCol, Token<'input>, LineCol)>, __Nonterminal<>), __lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(LineCol, Token<'input>, LineCol)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((_, Token(2, _), _)) |
+                    Some((_, Token(0, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action12::<>(input, __sym0);
+                        let __nt = __Nonterminal::Spanned_3cItem_3e((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""let""###.to_string(),
+                            r###"NAME"###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(LineCol, Token<'input>, LineCol)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action12::<>(input, __sym0);
+                        let __nt = __Nonterminal::Spanned_3cItem_3e((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 2
+            //     AllInputs = [Items]
+            //     OptionalInputs = []
+            //     FixedInputs = [Items]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(__Items)
+            //
+            //     __Items = Items (*) ["=", "let", NAME, EOF]
+            //
+            //   [EOF] -> __Items = Items => ActionFn(0);
+            //
+            fn __state2<
+                'input,
+                __TOKENS: Iterator<Item=Result<(LineCol, Token<'input>, LineCol),__lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(LineCol, Token<'input>, LineCol)>,
+                __extra: &mut Option<((LineCol, Token<'input>, LineCol), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (LineCol, Vec<(LineCol, LineCol)>, LineCol),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(LineCol, Token<'input>, LineCol)>, __Nonterminal<>), __lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(LineCol, Token<'input>, LineCol)>, __Nonterminal<>);
+                match __lookahead {
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(input, __sym0);
+                        let __nt = __Nonterminal::____Items((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(LineCol, Token<'input>, LineCol)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(input, __sym0);
+                        let __nt = __Nonterminal::____Items((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 3
+            //     AllInputs = [Spanned<Item>]
+            //     OptionalInputs = []
+            //     FixedInputs = [Spanned<Item>]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Spanned<Item>+)
+            //
+            //     Spanned<Item>+ = Spanned<Item> (*) ["=", "let", NAME, EOF]
+            //
+            //   ["let", NAME, EOF] -> Spanned<Item>+ = Spanned<Item> => ActionFn(9);
+            //
+            fn __state3<
+                'input,
+                __TOKENS: Iterator<Item=Result<(LineCol, Token<'input>, LineCol),__lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(LineCol, Token<'input>, LineCol)>,
+                __extra: &mut Option<((LineCol, Token<'input>, LineCol), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (LineCol, (LineCol, LineCol), LineCol),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(LineCol, Token<'input>, LineCol)>, __Nonterminal<>), __lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(LineCol, Token<'input>, LineCol)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((_, Token(2, _), _)) |
+                    Some((_, Token(0, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action9::<>(input, __sym0);
+                        let __nt = __Nonterminal::Spanned_3cItem_3e_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""let""###.to_string(),
+                            r###"NAME"###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(LineCol, Token<'input>, LineCol)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action9::<>(input, __sym0);
+                        let __nt = __Nonterminal::Spanned_3cItem_3e_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 4
+            //     AllInputs = [Spanned<Item>+]
+            //     OptionalInputs = []
+            //     FixedInputs = [Spanned<Item>+]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Item = (*) "let" NAME "=" NAME ["=", "let", NAME, EOF]
+            //     Item = (*) NAME ["=", "let", NAME, EOF]
+            //     Items = Spanned<Item>+ (*) ["=", "let", NAME, EOF]
+            //     Spanned<Item> = (*) Item ["=", "let", NAME, EOF]
+            //     Spanned<Item>+ = Spanned<Item>+ (*) Spanned<Item> ["=", "let", NAME, EOF]
+            //
+            //   "let" -> S5
+            //   NAME -> S6
+            //   [EOF] -> Items = Spanned<Item>+ => ActionFn(14);
+            //
+            //     Item -> S1
+            //     Spanned<Item> -> S7
+            fn __state4<
+                'input,
+                __TOKENS: Iterator<Item=Result<(LineCol, Token<'input>, LineCol),__lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(LineCol, Token<'input>, LineCol)>,
+                __extra: &mut Option<((LineCol, Token<'input>, LineCol), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (LineCol, ::std::vec::Vec<(LineCol, LineCol)>, LineCol),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(LineCol, Token<'input>, LineCol)>, __Nonterminal<>), __lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(LineCol, Token<'input>, LineCol)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action14::<>(input, __sym0);
+                        let __nt = __Nonterminal::Items((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""let""###.to_string(),
+                            r###"NAME"###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(LineCol, Token<'input>, LineCol)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action14::<>(input, __sym0);
+                        let __nt = __Nonterminal::Items((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Item(__sym1) => {
+                            __result = try!(__state1(input, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Spanned_3cItem_3e(__sym1) => {
+                            __result = try!(__state7(input, __tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 5
+            //     AllInputs = ["let"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["let"]
+            //     WillPushLen = 3
+            //     WillPush = [NAME, "=", NAME]
+            //     WillProduce = Some(Item)
+            //
+            //     Item = "let" (*) NAME "=" NAME ["=", "let", NAME, EOF]
+            //
+            //   NAME -> S8
+            //
+            fn __state5<
+                'input,
+                __TOKENS: Iterator<Item=Result<(LineCol, Token<'input>, LineCol),__lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((LineCol, Token<'input>, LineCol), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (LineCol, &'input str, LineCol),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(LineCol, Token<'input>, LineCol)>, __Nonterminal<>), __lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(LineCol, Token<'input>, LineCol)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state8(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"NAME"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 6
+            //     AllInputs = [NAME]
+            //     OptionalInputs = []
+            //     FixedInputs = [NAME]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Item)
+            //
+            //     Item = NAME (*) ["=", "let", NAME, EOF]
+            //
+            //   ["let", NAME, EOF] -> Item = NAME => ActionFn(2);
+            //
+            fn __state6<
+                'input,
+                __TOKENS: Iterator<Item=Result<(LineCol, Token<'input>, LineCol),__lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((LineCol, Token<'input>, LineCol), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (LineCol, &'input str, LineCol),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(LineCol, Token<'input>, LineCol)>, __Nonterminal<>), __lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(LineCol, Token<'input>, LineCol)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(2, _), _)) |
+                    Some((_, Token(0, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action2::<>(input, __sym0);
+                        let __nt = __Nonterminal::Item((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""let""###.to_string(),
+                            r###"NAME"###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(LineCol, Token<'input>, LineCol)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action2::<>(input, __sym0);
+                        let __nt = __Nonterminal::Item((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 7
+            //     AllInputs = [Spanned<Item>+, Spanned<Item>]
+            //     OptionalInputs = []
+            //     FixedInputs = [Spanned<Item>+, Spanned<Item>]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Spanned<Item>+)
+            //
+            //     Spanned<Item>+ = Spanned<Item>+ Spanned<Item> (*) ["=", "let", NAME, EOF]
+            //
+            //   ["let", NAME, EOF] -> Spanned<Item>+ = Spanned<Item>+, Spanned<Item> => ActionFn(10);
+            //
+            fn __state7<
+                'input,
+                __TOKENS: Iterator<Item=Result<(LineCol, Token<'input>, LineCol),__lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(LineCol, Token<'input>, LineCol)>,
+                __extra: &mut Option<((LineCol, Token<'input>, LineCol), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (LineCol, ::std::vec::Vec<(LineCol, LineCol)>, LineCol),
+                __sym1: (LineCol, (LineCol, LineCol), LineCol),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(LineCol, Token<'input>, LineCol)>, __Nonterminal<>), __lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(LineCol, Token<'input>, LineCol)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((_, Token(2, _), _)) |
+                    Some((_, Token(0, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action10::<>(input, __sym0, __sym1);
+                        let __nt = __Nonterminal::Spanned_3cItem_3e_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""let""###.to_string(),
+                            r###"NAME"###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(LineCol, Token<'input>, LineCol)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action10::<>(input, __sym0, __sym1);
+                        let __nt = __Nonterminal::Spanned_3cItem_3e_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 8
+            //     AllInputs = ["let", NAME]
+            //     OptionalInputs = []
+            //     FixedInputs = ["let", NAME]
+            //     WillPushLen = 2
+            //     WillPush = ["=", NAME]
+            //     WillProduce = Some(Item)
+            //
+            //     Item = "let" NAME (*) "=" NAME ["=", "let", NAME, EOF]
+            //
+            //   "=" -> S9
+            //
+            fn __state8<
+                'input,
+                __TOKENS: Iterator<Item=Result<(LineCol, Token<'input>, LineCol),__lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((LineCol, Token<'input>, LineCol), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (LineCol, &'input str, LineCol),
+                __sym1: (LineCol, &'input str, LineCol),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(LineCol, Token<'input>, LineCol)>, __Nonterminal<>), __lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(LineCol, Token<'input>, LineCol)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state9(input, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""=""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 9
+            //     AllInputs = ["let", NAME, "="]
+            //     OptionalInputs = []
+            //     FixedInputs = ["let", NAME, "="]
+            //     WillPushLen = 1
+            //     WillPush = [NAME]
+            //     WillProduce = Some(Item)
+            //
+            //     Item = "let" NAME "=" (*) NAME ["=", "let", NAME, EOF]
+            //
+            //   NAME -> S10
+            //
+            fn __state9<
+                'input,
+                __TOKENS: Iterator<Item=Result<(LineCol, Token<'input>, LineCol),__lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((LineCol, Token<'input>, LineCol), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (LineCol, &'input str, LineCol),
+                __sym1: (LineCol, &'input str, LineCol),
+                __sym2: (LineCol, &'input str, LineCol),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(LineCol, Token<'input>, LineCol)>, __Nonterminal<>), __lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(LineCol, Token<'input>, LineCol)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state10(input, __tokens, __extra, __sym0, __sym1, __sym2, __sym3, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"NAME"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 10
+            //     AllInputs = ["let", NAME, "=", NAME]
+            //     OptionalInputs = []
+            //     FixedInputs = ["let", NAME, "=", NAME]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Item)
+            //
+            //     Item = "let" NAME "=" NAME (*) ["=", "let", NAME, EOF]
+            //
+            //   ["let", NAME, EOF] -> Item = "let", NAME, "=", NAME => ActionFn(3);
+            //
+            fn __state10<
+                'input,
+                __TOKENS: Iterator<Item=Result<(LineCol, Token<'input>, LineCol),__lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((LineCol, Token<'input>, LineCol), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (LineCol, &'input str, LineCol),
+                __sym1: (LineCol, &'input str, LineCol),
+                __sym2: (LineCol, &'input str, LineCol),
+                __sym3: (LineCol, &'input str, LineCol),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(LineCol, Token<'input>, LineCol)>, __Nonterminal<>), __lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(LineCol, Token<'input>, LineCol)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(2, _), _)) |
+                    Some((_, Token(0, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym3.2.clone();
+                        let __nt = super::super::super::__action3::<>(input, __sym0, __sym1, __sym2, __sym3);
+                        let __nt = __Nonterminal::Item((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""let""###.to_string(),
+                            r###"NAME"###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(LineCol, Token<'input>, LineCol)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym3.2.clone();
+                        let __nt = super::super::super::__action3::<>(input, __sym0, __sym1, __sym2, __sym3);
+                        let __nt = __Nonterminal::Item((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+        }
+        pub use self::__parse__Items::parse_Items;
+    }
+    mod __parse_table {
+
+        mod __parse_table {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use lalrpop_util::LineCol;
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__intern_token::Token;
+            #[allow(dead_code)]
+            pub enum __Symbol<'input>
+             {
+                Term_22_3d_22(&'input str),
+                Term_22let_22(&'input str),
+                TermNAME(&'input str),
+                Nt_40L(LineCol),
+                Nt_40R(LineCol),
+                NtItem(()),
+                NtItems(Vec<(LineCol, LineCol)>),
+                NtSpanned_3cItem_3e((LineCol, LineCol)),
+                NtSpanned_3cItem_3e_2a(::std::vec::Vec<(LineCol, LineCol)>),
+                NtSpanned_3cItem_3e_2b(::std::vec::Vec<(LineCol, LineCol)>),
+                Nt____Items(Vec<(LineCol, LineCol)>),
+            }
+            impl<'input> ::std::fmt::Debug for __Symbol<'input>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Symbol::Term_22_3d_22(..) => __f.write_str("\"=\""),
+                        __Symbol::Term_22let_22(..) => __f.write_str("\"let\""),
+                        __Symbol::TermNAME(..) => __f.write_str("NAME"),
+                        __Symbol::Nt_40L(..) => __f.write_str("@L"),
+                        __Symbol::Nt_40R(..) => __f.write_str("@R"),
+                        __Symbol::NtItem(..) => __f.write_str("Item"),
+                        __Symbol::NtItems(..) => __f.write_str("Items"),
+                        __Symbol::NtSpanned_3cItem_3e(..) => __f.write_str("Spanned<Item>"),
+                        __Symbol::NtSpanned_3cItem_3e_2a(..) => __f.write_str("Spanned<Item>*"),
+                        __Symbol::NtSpanned_3cItem_3e_2b(..) => __f.write_str("Spanned<Item>+"),
+                        __Symbol::Nt____Items(..) => __f.write_str("__Items"),
+                    }
+                }
+            }
+            const __ACTION: &'static [i8] = &[
+                // State 0
+                //     Item = (*) "let" NAME "=" NAME ["=", "let", NAME, EOF]
+                //     Item = (*) NAME ["=", "let", NAME, EOF]
+                //     Items = (*) ["=", "let", NAME, EOF]
+                //     Items = (*) Spanned<Item>+ ["=", "let", NAME, EOF]
+                //     Spanned<Item> = (*) Item ["=", "let", NAME, EOF]
+                //     Spanned<Item>+ = (*) Spanned<Item> ["=", "let", NAME, EOF]
+                //     Spanned<Item>+ = (*) Spanned<Item>+ Spanned<Item> ["=", "let", NAME, EOF]
+                //     __Items = (*) Items ["=", "let", NAME, EOF]
+                0,  // on "=", error
+                6,  // on "let", goto 5
+                7,  // on NAME, goto 6
+
+                // State 1
+                //     Spanned<Item> = Item (*) ["=", "let", NAME, EOF]
+                0,  // on "=", error
+                -7,  // on "let", reduce `Spanned<Item> = Item => ActionFn(12);`
+                -7,  // on NAME, reduce `Spanned<Item> = Item => ActionFn(12);`
+
+                // State 2
+                //     __Items = Items (*) ["=", "let", NAME, EOF]
+                0,  // on "=", error
+                0,  // on "let", error
+                0,  // on NAME, error
+
+                // State 3
+                //     Spanned<Item>+ = Spanned<Item> (*) ["=", "let", NAME, EOF]
+                0,  // on "=", error
+                -10,  // on "let", reduce `Spanned<Item>+ = Spanned<Item> => ActionFn(9);`
+                -10,  // on NAME, reduce `Spanned<Item>+ = Spanned<Item> => ActionFn(9);`
+
+                // State 4
+                //     Item = (*) "let" NAME "=" NAME ["=", "let", NAME, EOF]
+                //     Item = (*) NAME ["=", "let", NAME, EOF]
+                //     Items = Spanned<Item>+ (*) ["=", "let", NAME, EOF]
+                //     Spanned<Item> = (*) Item ["=", "let", NAME, EOF]
+                //     Spanned<Item>+ = Spanned<Item>+ (*) Spanned<Item> ["=", "let", NAME, EOF]
+                0,  // on "=", error
+                6,  // on "let", goto 5
+                7,  // on NAME, goto 6
+
+                // State 5
+                //     Item = "let" (*) NAME "=" NAME ["=", "let", NAME, EOF]
+                0,  // on "=", error
+                0,  // on "let", error
+                9,  // on NAME, goto 8
+
+                // State 6
+                //     Item = NAME (*) ["=", "let", NAME, EOF]
+                0,  // on "=", error
+                -3,  // on "let", reduce `Item = NAME => ActionFn(2);`
+                -3,  // on NAME, reduce `Item = NAME => ActionFn(2);`
+
+                // State 7
+                //     Spanned<Item>+ = Spanned<Item>+ Spanned<Item> (*) ["=", "let", NAME, EOF]
+                0,  // on "=", error
+                -11,  // on "let", reduce `Spanned<Item>+ = Spanned<Item>+, Spanned<Item> => ActionFn(10);`
+                -11,  // on NAME, reduce `Spanned<Item>+ = Spanned<Item>+, Spanned<Item> => ActionFn(10);`
+
+                // State 8
+                //     Item = "let" NAME (*) "=" NAME ["=", "let", NAME, EOF]
+                10,  // on "=", goto 9
+                0,  // on "let", error
+                0,  // on NAME, error
+
+                // State 9
+                //     Item = "let" NAME "=" (*) NAME ["=", "let", NAME, EOF]
+                0,  // on "=", error
+                0,  // on "let", error
+                11,  // on NAME, goto 10
+
+                // State 10
+                //     Item = "let" NAME "=" NAME (*) ["=", "let", NAME, EOF]
+                0,  // on "=", error
+                -4,  // on "let", reduce `Item = "let", NAME, "=", NAME => ActionFn(3);`
+                -4,  // on NAME, reduce `Item = "let", NAME, "=", NAME => ActionFn(3);`
+
+            ];
+            const __EOF_ACTION: &'static [i8] = &[
+                // State 0
+                -5,  // on EOF, reduce `Items =  => ActionFn(13);`
+
+                // State 1
+                -7,  // on EOF, reduce `Spanned<Item> = Item => ActionFn(12);`
+
+                // State 2
+                -12,  // on EOF, reduce `__Items = Items => ActionFn(0);`
+
+                // State 3
+                -10,  // on EOF, reduce `Spanned<Item>+ = Spanned<Item> => ActionFn(9);`
+
+                // State 4
+                -6,  // on EOF, reduce `Items = Spanned<Item>+ => ActionFn(14);`
+
+                // State 5
+                0,  // on EOF, error
+
+                // State 6
+                -3,  // on EOF, reduce `Item = NAME => ActionFn(2);`
+
+                // State 7
+                -11,  // on EOF, reduce `Spanned<Item>+ = Spanned<Item>+, Spanned<Item> => ActionFn(10);`
+
+                // State 8
+                0,  // on EOF, error
+
+                // State 9
+                0,  // on EOF, error
+
+                // State 10
+                -4,  // on EOF, reduce `Item = "let", NAME, "=", NAME => ActionFn(3);`
+
+            ];
+            const __GOTO: &'static [u8] = &[
+                // State 0
+                0,  // on @L, error
+                0,  // on @R, error
+                2,  // on Item, goto 1
+                3,  // on Items, goto 2
+                4,  // on Spanned<Item>, goto 3
+                0,  // on Spanned<Item>*, error
+                5,  // on Spanned<Item>+, goto 4
+                0,  // on __Items, error
+
+                // State 1
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Item, error
+                0,  // on Items, error
+                0,  // on Spanned<Item>, error
+                0,  // on Spanned<Item>*, error
+                0,  // on Spanned<Item>+, error
+                0,  // on __Items, error
+
+                // State 2
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Item, error
+                0,  // on Items, error
+                0,  // on Spanned<Item>, error
+                0,  // on Spanned<Item>*, error
+                0,  // on Spanned<Item>+, error
+                0,  // on __Items, error
+
+                // State 3
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Item, error
+                0,  // on Items, error
+                0,  // on Spanned<Item>, error
+                0,  // on Spanned<Item>*, error
+                0,  // on Spanned<Item>+, error
+                0,  // on __Items, error
+
+                // State 4
+                0,  // on @L, error
+                0,  // on @R, error
+                2,  // on Item, goto 1
+                0,  // on Items, error
+                8,  // on Spanned<Item>, goto 7
+                0,  // on Spanned<Item>*, error
+                0,  // on Spanned<Item>+, error
+                0,  // on __Items, error
+
+                // State 5
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Item, error
+                0,  // on Items, error
+                0,  // on Spanned<Item>, error
+                0,  // on Spanned<Item>*, error
+                0,  // on Spanned<Item>+, error
+                0,  // on __Items, error
+
+                // State 6
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Item, error
+                0,  // on Items, error
+                0,  // on Spanned<Item>, error
+                0,  // on Spanned<Item>*, error
+                0,  // on Spanned<Item>+, error
+                0,  // on __Items, error
+
+                // State 7
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Item, error
+                0,  // on Items, error
+                0,  // on Spanned<Item>, error
+                0,  // on Spanned<Item>*, error
+                0,  // on Spanned<Item>+, error
+                0,  // on __Items, error
+
+                // State 8
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Item, error
+                0,  // on Items, error
+                0,  // on Spanned<Item>, error
+                0,  // on Spanned<Item>*, error
+                0,  // on Spanned<Item>+, error
+                0,  // on __Items, error
+
+                // State 9
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Item, error
+                0,  // on Items, error
+                0,  // on Spanned<Item>, error
+                0,  // on Spanned<Item>*, error
+                0,  // on Spanned<Item>+, error
+                0,  // on __Items, error
+
+                // State 10
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Item, error
+                0,  // on Items, error
+                0,  // on Spanned<Item>, error
+                0,  // on Spanned<Item>*, error
+                0,  // on Spanned<Item>+, error
+                0,  // on __Items, error
+
+            ];
+            const __DEFAULT_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // no default reduction
+
+                // State 1
+                -7,  // on any token, reduce `Spanned<Item> = Item => ActionFn(12);`
+
+                // State 2
+                0,  // no default reduction
+
+                // State 3
+                -10,  // on any token, reduce `Spanned<Item>+ = Spanned<Item> => ActionFn(9);`
+
+                // State 4
+                0,  // no default reduction
+
+                // State 5
+                0,  // no default reduction
+
+                // State 6
+                -3,  // on any token, reduce `Item = NAME => ActionFn(2);`
+
+                // State 7
+                -11,  // on any token, reduce `Spanned<Item>+ = Spanned<Item>+, Spanned<Item> => ActionFn(10);`
+
+                // State 8
+                0,  // no default reduction
+
+                // State 9
+                0,  // no default reduction
+
+                // State 10
+                -4,  // on any token, reduce `Item = "let", NAME, "=", NAME => ActionFn(3);`
+
+            ];
+            fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+                const __TERMINAL: &'static [&'static str] = &[
+                    r###""=""###,
+                    r###""let""###,
+                    r###"NAME"###,
+                ];
+                __ACTION[(__state * 3)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+                    if state == 0 {
+                        None
+                    } else {
+                        Some(terminal.to_string())
+                    }
+                }).collect()
+            }
+            #[allow(dead_code)]
+            pub fn parse_Items<
+                'input,
+            >(
+                input: &'input str,
+            ) -> Result<Vec<(LineCol, LineCol)>, __lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>
+            {
+                let __result: Result<Vec<(LineCol, LineCol)>, __lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>> = (|| {
+                    let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                    let mut __tokens = __lalrpop_util::Locations::<LineCol, _>::new(input, __tokens);
+                    let mut __states = vec![];
+                    let mut __symbols = vec![];
+                    __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Items(__v) => Ok(__v), _ => unreachable!() })
+                })();
+                __result.map_err(super::super::super::__intern_token::__reserved_word)
+            }
+            #[allow(dead_code)]
+            pub fn parse_Items_cancelable<
+                'input,
+            >(
+                input: &'input str,
+                __cancel: &::std::sync::atomic::AtomicBool,
+            ) -> Result<Vec<(LineCol, LineCol)>, __lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>
+            {
+                let __result: Result<Vec<(LineCol, LineCol)>, __lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>> = (|| {
+                    let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                    let mut __tokens = __lalrpop_util::Locations::<LineCol, _>::new(input, __tokens);
+                    let mut __states = vec![];
+                    let mut __symbols = vec![];
+                    __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Items(__v) => Ok(__v), _ => unreachable!() })
+                })();
+                __result.map_err(super::super::super::__intern_token::__reserved_word)
+            }
+
+            #[allow(dead_code)]
+            pub struct ItemsParser {
+                __states: ::std::vec::Vec<i32>,
+                __symbols: __lalrpop_util::stack::Recycled,
+                __cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+            }
+
+            #[allow(dead_code)]
+            impl ItemsParser {
+                pub fn new() -> ItemsParser {
+                    ItemsParser { __states: vec![], __symbols: __lalrpop_util::stack::Recycled::new(), __cancel: None }
+                }
+
+                pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+                    self.__cancel = Some(flag);
+                }
+
+                pub fn parse_in_place<'input>(
+                    &mut self,
+                    input: &'input str,
+                ) -> Result<Vec<(LineCol, LineCol)>, __lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>
+                {
+                    let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                    let mut __tokens = __lalrpop_util::Locations::<LineCol, _>::new(input, __tokens);
+                    let mut __symbols = self.__symbols.take();
+                    let __result = __parse::<>(input, 0, &mut __tokens, &mut self.__states, &mut __symbols, self.__cancel.as_ref().map(|c| &**c)).and_then(|__v| match __v { __Symbol::Nt____Items(__v) => Ok(__v), _ => unreachable!() });
+                    self.__symbols.put(__symbols);
+                    __result
+                }
+            }
+
+            fn __parse<
+                'input,
+            >(
+                input: &'input str,
+                __start_state: i32,
+                mut __tokens: &mut Iterator<Item = Result<(LineCol, Token<'input>, LineCol), __lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>>,
+                mut __states: &mut ::std::vec::Vec<i32>,
+                mut __symbols: &mut ::std::vec::Vec<(LineCol,__Symbol<'input>,LineCol)>,
+                __cancel: Option<&::std::sync::atomic::AtomicBool>,
+            ) -> Result<__Symbol<'input>, __lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>
+            {
+                __states.clear();
+                __states.push(__start_state);
+                __symbols.clear();
+                let mut __integer;
+                let mut __lookahead;
+                let __last_location: &mut LineCol = &mut Default::default();
+                let mut __default_reduced = [0_usize; 16];
+                let mut __default_reduced_len = 0;
+                '__shift: loop {
+                    loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __DEFAULT_ACTION[__state] as i32;
+                        if __action == 0 {
+                            break;
+                        }
+                        if __default_reduced_len < 16 {
+                            __default_reduced[__default_reduced_len] = __state;
+                            __default_reduced_len += 1;
+                        }
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    }
+                    __lookahead = match __tokens.next() {
+                        Some(Ok(v)) => v,
+                        None => break '__shift,
+                        Some(Err(e)) => return Err(e),
+                    };
+                    *__last_location = __lookahead.2.clone();
+                    __integer = match __lookahead.1 {
+                        Token(1, _) if true => 0,
+                        Token(2, _) if true => 1,
+                        Token(0, _) if true => 2,
+                        _ => {
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| true).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match Some(__lookahead) {
+                                Some(__token) => {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error);
+                        }
+                    };
+                    '__inner: loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __ACTION[__state * 3 + __integer] as i32;
+                        if __action > 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                                }
+                            }
+                            let __symbol = match __integer {
+                                0 => match __lookahead.1 {
+                                    Token(1, __tok0) => __Symbol::Term_22_3d_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                1 => match __lookahead.1 {
+                                    Token(2, __tok0) => __Symbol::Term_22let_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                2 => match __lookahead.1 {
+                                    Token(0, __tok0) => __Symbol::TermNAME((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                _ => unreachable!(),
+                            };
+                            __states.push(__action - 1);
+                            __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                            __default_reduced_len = 0;
+                            continue '__shift;
+                        } else if __action < 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                                }
+                            }
+                            if let Some(r) = __reduce(input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                                if r.is_err() {
+                                    return r;
+                                }
+                                return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] });
+                            }
+                        } else {
+                            let mut __err_lookahead = Some(__lookahead);
+                            let mut __err_integer: Option<usize> = Some(__integer);
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 3 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match __err_lookahead {
+                                Some(__token) => {
+                                    if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                        __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                    } else {
+                                        __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                    }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error)
+                        }
+                    }
+                }
+                loop {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __action = __EOF_ACTION[__state] as i32;
+                    if __action < 0 {
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    } else {
+                        let mut __err_lookahead = None;
+                        let mut __err_integer: Option<usize> = None;
+                        let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 3 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                        let __expected = __expected_tokens(__state);
+                        let __error = match __err_lookahead {
+                            Some(__token) => {
+                                if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                    __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                } else {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                            }
+                            None => {
+                                __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                            }
+                        };
+                        return Err(__error)
+                    }
+                }
+            }
+            fn __accepts<
+                'input,
+            >(
+                input: &'input str,
+                __error_state: i32,
+                __states: & [i32],
+                __opt_integer: Option<usize>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> bool
+            {
+                let mut __states = __states.to_vec();
+                __states.push(__error_state);
+                loop {
+                    let mut __states_len = __states.len();
+                    let __top = __states[__states_len - 1];
+                    let __action = match __opt_integer {
+                        None => __EOF_ACTION[__top as usize] as i32,
+                        Some(__integer) => __ACTION[(__top * 3) as usize + __integer] as i32,
+                    };
+                    if __action == 0 { return false; }
+                    if __action > 0 { return true; }
+                    let (__to_pop, __nt) = match -__action {
+                        // simulate @L =  => ActionFn(8);
+                        1 => {
+                            (0, 0)
+                        }
+                        // simulate @R =  => ActionFn(7);
+                        2 => {
+                            (0, 1)
+                        }
+                        // simulate Item = NAME => ActionFn(2);
+                        3 => {
+                            (1, 2)
+                        }
+                        // simulate Item = "let", NAME, "=", NAME => ActionFn(3);
+                        4 => {
+                            (4, 2)
+                        }
+                        // simulate Items =  => ActionFn(13);
+                        5 => {
+                            (0, 3)
+                        }
+                        // simulate Items = Spanned<Item>+ => ActionFn(14);
+                        6 => {
+                            (1, 3)
+                        }
+                        // simulate Spanned<Item> = Item => ActionFn(12);
+                        7 => {
+                            (1, 4)
+                        }
+                        // simulate Spanned<Item>* =  => ActionFn(4);
+                        8 => {
+                            (0, 5)
+                        }
+                        // simulate Spanned<Item>* = Spanned<Item>+ => ActionFn(5);
+                        9 => {
+                            (1, 5)
+                        }
+                        // simulate Spanned<Item>+ = Spanned<Item> => ActionFn(9);
+                        10 => {
+                            (1, 6)
+                        }
+                        // simulate Spanned<Item>+ = Spanned<Item>+, Spanned<Item> => ActionFn(10);
+                        11 => {
+                            (2, 6)
+                        }
+                        // simulate __Items = Items => ActionFn(0);
+                        12 => return true,
+                        _ => panic!("invalid action code {}", __action)
+                    };
+                    __states_len -= __to_pop;
+                    __states.truncate(__states_len);
+                    let __top = __states[__states_len - 1];
+                    let __next_state = __GOTO[(__top * 8 + __nt) as usize] as i32 - 1;
+                    __states.push(__next_state);
+                }
+            }
+            pub fn __reduce<
+                'input,
+            >(
+                input: &'input str,
+                __action: i32,
+                __lookahead_start: Option<&LineCol>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(LineCol,__Symbol<'input>,LineCol)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Option<Result<__Symbol<'input>,__lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>>
+            {
+                let __nonterminal = match -__action {
+                    1 => match __reduce__40L_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    2 => match __reduce__40R_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    3 => match __reduce_Item_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    4 => match __reduce_Item_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    5 => match __reduce_Items_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    6 => match __reduce_Items_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    7 => match __reduce_Spanned_3cItem_3e_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    8 => match __reduce_Spanned_3cItem_3e_2a_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    9 => match __reduce_Spanned_3cItem_3e_2a_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    10 => match __reduce_Spanned_3cItem_3e_2b_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    11 => match __reduce_Spanned_3cItem_3e_2b_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    12 => match __reduce_____Items_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    _ => panic!("invalid action code {}", __action)
+                };
+                let __state = *__states.last().unwrap() as usize;
+                let __next_state = __GOTO[__state * 8 + __nonterminal] as i32 - 1;
+                __states.push(__next_state);
+                None
+            }
+
+            fn __reduce__40L_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&LineCol>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(LineCol,__Symbol<'input>,LineCol)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>>
+            {
+                // @L =  => ActionFn(8);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action8::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_40L(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce__40R_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&LineCol>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(LineCol,__Symbol<'input>,LineCol)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>>
+            {
+                // @R =  => ActionFn(7);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action7::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_40R(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce_Item_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&LineCol>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(LineCol,__Symbol<'input>,LineCol)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>>
+            {
+                // Item = NAME => ActionFn(2);
+                let __sym0 = __pop_TermNAME(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action2::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtItem(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce_Item_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&LineCol>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(LineCol,__Symbol<'input>,LineCol)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>>
+            {
+                // Item = "let", NAME, "=", NAME => ActionFn(3);
+                let __sym3 = __pop_TermNAME(__symbols);
+                let __sym2 = __pop_Term_22_3d_22(__symbols);
+                let __sym1 = __pop_TermNAME(__symbols);
+                let __sym0 = __pop_Term_22let_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::super::super::__action3::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtItem(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce_Items_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&LineCol>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(LineCol,__Symbol<'input>,LineCol)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>>
+            {
+                // Items =  => ActionFn(13);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action13::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::NtItems(__nt), __end));
+                Ok(3)
+            }
+
+            fn __reduce_Items_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&LineCol>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(LineCol,__Symbol<'input>,LineCol)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>>
+            {
+                // Items = Spanned<Item>+ => ActionFn(14);
+                let __sym0 = __pop_NtSpanned_3cItem_3e_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action14::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtItems(__nt), __end));
+                Ok(3)
+            }
+
+            fn __reduce_Spanned_3cItem_3e_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&LineCol>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(LineCol,__Symbol<'input>,LineCol)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>>
+            {
+                // Spanned<Item> = Item => ActionFn(12);
+                let __sym0 = __pop_NtItem(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action12::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtSpanned_3cItem_3e(__nt), __end));
+                Ok(4)
+            }
+
+            fn __reduce_Spanned_3cItem_3e_2a_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&LineCol>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(LineCol,__Symbol<'input>,LineCol)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>>
+            {
+                // Spanned<Item>* =  => ActionFn(4);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action4::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::NtSpanned_3cItem_3e_2a(__nt), __end));
+                Ok(5)
+            }
+
+            fn __reduce_Spanned_3cItem_3e_2a_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&LineCol>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(LineCol,__Symbol<'input>,LineCol)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>>
+            {
+                // Spanned<Item>* = Spanned<Item>+ => ActionFn(5);
+                let __sym0 = __pop_NtSpanned_3cItem_3e_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action5::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtSpanned_3cItem_3e_2a(__nt), __end));
+                Ok(5)
+            }
+
+            fn __reduce_Spanned_3cItem_3e_2b_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&LineCol>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(LineCol,__Symbol<'input>,LineCol)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>>
+            {
+                // Spanned<Item>+ = Spanned<Item> => ActionFn(9);
+                let __sym0 = __pop_NtSpanned_3cItem_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action9::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtSpanned_3cItem_3e_2b(__nt), __end));
+                Ok(6)
+            }
+
+            fn __reduce_Spanned_3cItem_3e_2b_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&LineCol>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(LineCol,__Symbol<'input>,LineCol)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>>
+            {
+                // Spanned<Item>+ = Spanned<Item>+, Spanned<Item> => ActionFn(10);
+                let __sym1 = __pop_NtSpanned_3cItem_3e(__symbols);
+                let __sym0 = __pop_NtSpanned_3cItem_3e_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action10::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtSpanned_3cItem_3e_2b(__nt), __end));
+                Ok(6)
+            }
+
+            fn __reduce_____Items_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&LineCol>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(LineCol,__Symbol<'input>,LineCol)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<LineCol, Token<'input>, &'static str>>>
+            {
+                // __Items = Items => ActionFn(0);
+                let __sym0 = __pop_NtItems(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action0::<>(input, __sym0);
+                Err(Ok(__Symbol::Nt____Items(__nt)))
+            }
+            fn __pop_Term_22_3d_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(LineCol,__Symbol<'input>,LineCol)>
+            ) -> (LineCol, &'input str, LineCol)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_3d_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22let_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(LineCol,__Symbol<'input>,LineCol)>
+            ) -> (LineCol, &'input str, LineCol)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22let_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_TermNAME<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(LineCol,__Symbol<'input>,LineCol)>
+            ) -> (LineCol, &'input str, LineCol)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::TermNAME(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_40L<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(LineCol,__Symbol<'input>,LineCol)>
+            ) -> (LineCol, LineCol, LineCol)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_40L(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_40R<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(LineCol,__Symbol<'input>,LineCol)>
+            ) -> (LineCol, LineCol, LineCol)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_40R(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtItem<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(LineCol,__Symbol<'input>,LineCol)>
+            ) -> (LineCol, (), LineCol)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtItem(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtItems<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(LineCol,__Symbol<'input>,LineCol)>
+            ) -> (LineCol, Vec<(LineCol, LineCol)>, LineCol)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtItems(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtSpanned_3cItem_3e<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(LineCol,__Symbol<'input>,LineCol)>
+            ) -> (LineCol, (LineCol, LineCol), LineCol)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtSpanned_3cItem_3e(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtSpanned_3cItem_3e_2a<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(LineCol,__Symbol<'input>,LineCol)>
+            ) -> (LineCol, ::std::vec::Vec<(LineCol, LineCol)>, LineCol)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtSpanned_3cItem_3e_2a(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtSpanned_3cItem_3e_2b<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(LineCol,__Symbol<'input>,LineCol)>
+            ) -> (LineCol, ::std::vec::Vec<(LineCol, LineCol)>, LineCol)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtSpanned_3cItem_3e_2b(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt____Items<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(LineCol,__Symbol<'input>,LineCol)>
+            ) -> (LineCol, Vec<(LineCol, LineCol)>, LineCol)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt____Items(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+        }
+        pub use self::__parse_table::parse_Items;
+        pub use self::__parse_table::parse_Items_cancelable;
+        pub use self::__parse_table::ItemsParser;
+    }
+    pub use self::__parse_table::parse_Items_cancelable;
+    pub use self::__parse_table::ItemsParser;
+}
+pub use self::__parse__Items::parse_Items;
+pub use self::__parse__Items::parse_Items_cancelable;
+pub use self::__parse__Items::ItemsParser;
+mod __intern_token {
+    #![allow(unused_imports)]
+    use lalrpop_util::LineCol;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    extern crate regex as __regex;
+    use std::fmt as __fmt;
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Token<'input>(pub usize, pub &'input str);
+    impl<'a> __fmt::Display for Token<'a> {
+        fn fmt(&self, formatter: &mut __fmt::Formatter) -> Result<(), __fmt::Error> {
+            __fmt::Display::fmt(self.1, formatter)
+        }
+    }
+
+    pub struct __Matcher<'input> {
+        text: &'input str,
+        consumed: usize,
+        regex_set: __regex::RegexSet,
+        regex_vec: Vec<__regex::Regex>,
+    }
+
+    impl<'input> __Matcher<'input> {
+        pub fn new(s: &'input str) -> __Matcher<'input> {
+            let __strs: &[&str] = &[
+                "^(?u:[a-z])+",
+                "^(?u:=)",
+                "^(?u:let)",
+            ];
+            let __regex_set = __regex::RegexSet::new(__strs).unwrap();
+            let __regex_vec = vec![
+                __regex::Regex::new("^(?u:[a-z])+").unwrap(),
+                __regex::Regex::new("^(?u:=)").unwrap(),
+                __regex::Regex::new("^(?u:let)").unwrap(),
+            ];
+            __Matcher {
+                text: s,
+                consumed: 0,
+                regex_set: __regex_set,
+                regex_vec: __regex_vec,
+            }
+        }
+    }
+
+    impl<'input> Iterator for __Matcher<'input> {
+        type Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize,Token<'input>,&'static str>>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let __text = self.text.trim_left();
+            let __whitespace = self.text.len() - __text.len();
+            let __start_offset = self.consumed + __whitespace;
+            if __text.is_empty() {
+                self.text = __text;
+                self.consumed = __start_offset;
+                None
+            } else {
+                let __matches = self.regex_set.matches(__text);
+                if !__matches.matched_any() {
+                    Some(Err(__lalrpop_util::ParseError::InvalidToken {
+                        location: __start_offset,
+                    }))
+                } else {
+                    let mut __longest_match = 0;
+                    let mut __index = 0;
+                    for __i in 0 .. 3 {
+                        if __matches.matched(__i) {
+                            let __match = self.regex_vec[__i].find(__text).unwrap();
+                            let __len = __match.end();
+                            if __len >= __longest_match {
+                                __longest_match = __len;
+                                __index = __i;
+                            }
+                        }
+                    }
+                    let __result = &__text[..__longest_match];
+                    let __remaining = &__text[__longest_match..];
+                    let __end_offset = __start_offset + __longest_match;
+                    self.text = __remaining;
+                    self.consumed = __end_offset;
+                    Some(Ok((__start_offset, Token(__index, __result), __end_offset)))
+                }
+            }
+        }
+    }
+
+    pub fn __reserved_word<'input, L, E>(__error: __lalrpop_util::ParseError<L, Token<'input>, E>) -> __lalrpop_util::ParseError<L, Token<'input>, E> {
+        match __error {
+            __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected } => {
+                let __description = match (__token.1).0 {
+                    2 if __expected.iter().any(|__e| __e == r###"NAME"###) => "identifier",
+                    _ => {
+                        return __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected };
+                    }
+                };
+                __lalrpop_util::ParseError::ReservedWord { token: __token, description: ::std::string::String::from(__description), expected: __expected }
+            }
+            __error => __error,
+        }
+    }
+}
+pub use self::__intern_token::Token;
+
+#[allow(unused_variables)]
+fn __action0<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (LineCol, Vec<(LineCol, LineCol)>, LineCol),
+) -> Vec<(LineCol, LineCol)>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (LineCol, ::std::vec::Vec<(LineCol, LineCol)>, LineCol),
+) -> Vec<(LineCol, LineCol)>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (LineCol, &'input str, LineCol),
+) -> ()
+{
+    //@ src/line_col.lalrpop:22:13 (1 lines)
+    ()
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (LineCol, &'input str, LineCol),
+    (_, __1, _): (LineCol, &'input str, LineCol),
+    (_, __2, _): (LineCol, &'input str, LineCol),
+    (_, __3, _): (LineCol, &'input str, LineCol),
+) -> ()
+{
+    //@ src/line_col.lalrpop:23:28 (1 lines)
+    ()
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &LineCol,
+    __lookahead: &LineCol,
+) -> ::std::vec::Vec<(LineCol, LineCol)>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (LineCol, ::std::vec::Vec<(LineCol, LineCol)>, LineCol),
+) -> ::std::vec::Vec<(LineCol, LineCol)>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (LineCol, LineCol, LineCol),
+    (_, _, _): (LineCol, (), LineCol),
+    (_, __1, _): (LineCol, LineCol, LineCol),
+) -> (LineCol, LineCol)
+{
+    (__0, __1)
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &LineCol,
+    __lookahead: &LineCol,
+) -> LineCol
+{
+    __lookbehind.clone()
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &LineCol,
+    __lookahead: &LineCol,
+) -> LineCol
+{
+    __lookahead.clone()
+}
+
+#[allow(unused_variables)]
+fn __action9<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (LineCol, (LineCol, LineCol), LineCol),
+) -> ::std::vec::Vec<(LineCol, LineCol)>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action10<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (LineCol, ::std::vec::Vec<(LineCol, LineCol)>, LineCol),
+    (_, e, _): (LineCol, (LineCol, LineCol), LineCol),
+) -> ::std::vec::Vec<(LineCol, LineCol)>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action11<
+    'input,
+>(
+    input: &'input str,
+    __0: (LineCol, (), LineCol),
+    __1: (LineCol, LineCol, LineCol),
+) -> (LineCol, LineCol)
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action8(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action6(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action12<
+    'input,
+>(
+    input: &'input str,
+    __0: (LineCol, (), LineCol),
+) -> (LineCol, LineCol)
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action7(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action11(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action13<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &LineCol,
+    __lookahead: &LineCol,
+) -> Vec<(LineCol, LineCol)>
+{
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action4(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action1(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action14<
+    'input,
+>(
+    input: &'input str,
+    __0: (LineCol, ::std::vec::Vec<(LineCol, LineCol)>, LineCol),
+) -> Vec<(LineCol, LineCol)>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action5(
+        input,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action1(
+        input,
+        __temp0,
+    )
+}
+
+pub trait __ToTriple<'input, > {
+    type Error;
+    fn to_triple(value: Self) -> Result<(LineCol,Token<'input>,LineCol),Self::Error>;
+}
+
+impl<'input, > __ToTriple<'input, > for (LineCol, Token<'input>, LineCol) {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(LineCol,Token<'input>,LineCol),&'static str> {
+        Ok(value)
+    }
+}
+impl<'input, > __ToTriple<'input, > for Result<(LineCol, Token<'input>, LineCol),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(LineCol,Token<'input>,LineCol),&'static str> {
+        value
+    }
+}
@@ -0,0 +1,3914 @@
+// auto-generated: "lalrpop 0.15.0"
+use loc_issue_90_lib::Expr;
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+
+mod __parse__Expression2 {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    use loc_issue_90_lib::Expr;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__intern_token::Token;
+    #[allow(dead_code)]
+    pub fn parse_Expression2<
+        'input,
+    >(
+        input: &'input str,
+    ) -> Result<Box<Expr<'input>>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let __ascent = __ascent::parse_Expression2(
+            input,
+        );
+        let __parse_table = __parse_table::parse_Expression2(
+            input,
+        );
+        assert_eq!(__ascent, __parse_table);
+        return __ascent;
+    }
+    mod __ascent {
+
+        mod __parse__Expression2 {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use loc_issue_90_lib::Expr;
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__intern_token::Token;
+            #[allow(dead_code)]
+            pub fn parse_Expression2<
+                'input,
+            >(
+                input: &'input str,
+            ) -> Result<Box<Expr<'input>>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let __last_location = ::std::cell::RefCell::new(Default::default());
+                let mut __tokens = __tokens.inspect(|t| if let Ok(ref t) = *t { *__last_location.borrow_mut() = t.2.clone(); });
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                let mut __extra = None;
+                let __result = __state0(input, &mut __tokens, __lookahead, &mut __extra, ::std::marker::PhantomData::<()>);
+                if let Some((__token, __expected)) = __extra {
+                    return Err(match __result {
+                        Ok(_) => __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected },
+                        Err(_) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                    });
+                }
+                match __result {
+                    Ok((Some(__lookahead), _)) => {
+                        Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] })
+                    }
+                    Ok((None, __Nonterminal::____Expression2((_, __nt, _)))) => {
+                        Ok(__nt)
+                    }
+                    Ok(_) => unreachable!(),
+                    Err(__lalrpop_util::ParseError::UnrecognizedEof { expected: __expected, .. }) => {
+                        Err(__lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.borrow().clone(), expected: __expected })
+                    }
+                    Err(__error) => Err(__error),
+                }
+            }
+
+            #[allow(dead_code)]
+            pub enum __Nonterminal<'input>
+             {
+                _40L((usize, usize, usize)),
+                _40R((usize, usize, usize)),
+                Expression1((usize, Box<Expr<'input>>, usize)),
+                Expression2((usize, Box<Expr<'input>>, usize)),
+                Expression2Op((usize, Box<Expr<'input>>, usize)),
+                Maybe((usize, Box<Expr<'input>>, usize)),
+                Wacky((usize, Box<Expr<'input>>, usize)),
+                Wonky((usize, Box<Expr<'input>>, usize)),
+                ____Expression2((usize, Box<Expr<'input>>, usize)),
+            }
+            impl<'input> ::std::fmt::Debug for __Nonterminal<'input>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Nonterminal::_40L(..) => __f.write_str("@L"),
+                        __Nonterminal::_40R(..) => __f.write_str("@R"),
+                        __Nonterminal::Expression1(..) => __f.write_str("Expression1"),
+                        __Nonterminal::Expression2(..) => __f.write_str("Expression2"),
+                        __Nonterminal::Expression2Op(..) => __f.write_str("Expression2Op"),
+                        __Nonterminal::Maybe(..) => __f.write_str("Maybe"),
+                        __Nonterminal::Wacky(..) => __f.write_str("Wacky"),
+                        __Nonterminal::Wonky(..) => __f.write_str("Wonky"),
+                        __Nonterminal::____Expression2(..) => __f.write_str("__Expression2"),
+                    }
+                }
+            }
+
+            // State 0
+            //     AllInputs = []
+            //     OptionalInputs = []
+            //     FixedInputs = []
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Expression1 = (*) Wacky ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Expression1 = (*) "&" Maybe Expression1 ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Expression1 = (*) "(" Expression2 ")" ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Expression1 = (*) "wonky" Wonky ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Expression1 = (*) r#"\\w+"# ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Expression2 = (*) Expression1 ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Expression2 = (*) Expression2 Expression2Op Expression1 ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Wacky = (*) "wacky" ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     __Expression2 = (*) Expression2 ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //
+            //   "&" -> S4
+            //   "(" -> S5
+            //   "wacky" -> S6
+            //   "wonky" -> S7
+            //   r#"\\w+"# -> S8
+            //
+            //     Expression1 -> S1
+            //     Expression2 -> S2
+            //     Wacky -> S3
+            fn __state0<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state4(input, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(input, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(7, __tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(input, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(8, __tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state8(input, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""&""###.to_string(),
+                            r###""(""###.to_string(),
+                            r###""wacky""###.to_string(),
+                            r###""wonky""###.to_string(),
+                            r###"r#"\\w+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Expression1(__sym0) => {
+                            __result = try!(__state1(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Expression2(__sym0) => {
+                            __result = try!(__state2(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Wacky(__sym0) => {
+                            __result = try!(__state3(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 1
+            //     AllInputs = [Expression1]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expression1]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Expression2)
+            //
+            //     Expression2 = Expression1 (*) ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //
+            //   [")", "*", EOF] -> Expression2 = Expression1 => ActionFn(29);
+            //
+            fn __state1<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Box<Expr<'input>>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action29::<>(input, __sym0);
+                        let __nt = __Nonterminal::Expression2((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action29::<>(input, __sym0);
+                        let __nt = __Nonterminal::Expression2((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 2
+            //     AllInputs = [Expression2]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expression2]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Expression2 = Expression2 (*) Expression2Op Expression1 ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Expression2Op = (*) "*" ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     __Expression2 = Expression2 (*) ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //
+            //   "*" -> S10
+            //   [EOF] -> __Expression2 = Expression2 => ActionFn(0);
+            //
+            //     Expression2Op -> S9
+            fn __state2<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Box<Expr<'input>>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(4, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state10(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(input, __sym0);
+                        let __nt = __Nonterminal::____Expression2((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""*""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(input, __sym0);
+                        let __nt = __Nonterminal::____Expression2((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Expression2Op(__sym1) => {
+                            __result = try!(__state9(input, __tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 3
+            //     AllInputs = [Wacky]
+            //     OptionalInputs = []
+            //     FixedInputs = [Wacky]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Expression1)
+            //
+            //     Expression1 = Wacky (*) ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //
+            //   [")", "*", EOF] -> Expression1 = Wacky => ActionFn(8);
+            //
+            fn __state3<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Box<Expr<'input>>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action8::<>(input, __sym0);
+                        let __nt = __Nonterminal::Expression1((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action8::<>(input, __sym0);
+                        let __nt = __Nonterminal::Expression1((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 4
+            //     AllInputs = ["&"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["&"]
+            //     WillPushLen = 2
+            //     WillPush = [Maybe, Expression1]
+            //     WillProduce = Some(Expression1)
+            //
+            //     Expression1 = "&" (*) Maybe Expression1 ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Maybe = (*) ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Maybe = (*) "[" "]" ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //
+            //   "[" -> S12
+            //   ["&", "(", "wacky", "wonky", r#"\\w+"#] -> Maybe =  => ActionFn(31);
+            //
+            //     Maybe -> S11
+            fn __state4<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(5, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state12(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((_, Token(1, _), _)) |
+                    Some((_, Token(2, _), _)) |
+                    Some((_, Token(7, _), _)) |
+                    Some((_, Token(8, _), _)) |
+                    Some((_, Token(0, _), _)) => {
+                        let __start = __sym0.2.clone();
+                        let __end = __lookahead.as_ref().map(|o| o.0.clone()).unwrap_or_else(|| __start.clone());
+                        let __nt = super::super::super::__action31::<>(input, &__start, &__end);
+                        let __nt = __Nonterminal::Maybe((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""&""###.to_string(),
+                            r###""(""###.to_string(),
+                            r###""[""###.to_string(),
+                            r###""wacky""###.to_string(),
+                            r###""wonky""###.to_string(),
+                            r###"r#"\\w+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Maybe(__sym1) => {
+                            __result = try!(__state11(input, __tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 5
+            //     AllInputs = ["("]
+            //     OptionalInputs = []
+            //     FixedInputs = ["("]
+            //     WillPushLen = 2
+            //     WillPush = [Expression2, ")"]
+            //     WillProduce = Some(Expression1)
+            //
+            //     Expression1 = (*) Wacky ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Expression1 = (*) "&" Maybe Expression1 ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Expression1 = (*) "(" Expression2 ")" ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Expression1 = "(" (*) Expression2 ")" ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Expression1 = (*) "wonky" Wonky ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Expression1 = (*) r#"\\w+"# ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Expression2 = (*) Expression1 ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Expression2 = (*) Expression2 Expression2Op Expression1 ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Wacky = (*) "wacky" ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //
+            //   "&" -> S4
+            //   "(" -> S5
+            //   "wacky" -> S6
+            //   "wonky" -> S7
+            //   r#"\\w+"# -> S8
+            //
+            //     Expression1 -> S1
+            //     Expression2 -> S13
+            //     Wacky -> S3
+            fn __state5<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                let __sym0 = &mut Some(__sym0);
+                match __lookahead {
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state4(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(7, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(8, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state8(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""&""###.to_string(),
+                            r###""(""###.to_string(),
+                            r###""wacky""###.to_string(),
+                            r###""wonky""###.to_string(),
+                            r###"r#"\\w+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    if __sym0.is_none() {
+                        return Ok(__result);
+                    }
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Expression1(__sym1) => {
+                            __result = try!(__state1(input, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Expression2(__sym1) => {
+                            __result = try!(__state13(input, __tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Wacky(__sym1) => {
+                            __result = try!(__state3(input, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 6
+            //     AllInputs = ["wacky"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["wacky"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Wacky)
+            //
+            //     Wacky = "wacky" (*) ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //
+            //   [")", "*", EOF] -> Wacky = "wacky" => ActionFn(33);
+            //
+            fn __state6<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action33::<>(input, __sym0);
+                        let __nt = __Nonterminal::Wacky((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action33::<>(input, __sym0);
+                        let __nt = __Nonterminal::Wacky((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 7
+            //     AllInputs = ["wonky"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["wonky"]
+            //     WillPushLen = 1
+            //     WillPush = [Wonky]
+            //     WillProduce = Some(Expression1)
+            //
+            //     Expression1 = "wonky" (*) Wonky ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Wonky = (*) ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //
+            //   [")", "*", EOF] -> Wonky =  => ActionFn(34);
+            //
+            //     Wonky -> S14
+            fn __state7<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    None => {
+                        let __start = __sym0.2.clone();
+                        let __end = __lookahead.as_ref().map(|o| o.0.clone()).unwrap_or_else(|| __start.clone());
+                        let __nt = super::super::super::__action34::<>(input, &__start, &__end);
+                        let __nt = __Nonterminal::Wonky((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.2.clone();
+                        let __end = __lookahead.as_ref().map(|o| o.0.clone()).unwrap_or_else(|| __start.clone());
+                        let __nt = super::super::super::__action34::<>(input, &__start, &__end);
+                        let __nt = __Nonterminal::Wonky((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Wonky(__sym1) => {
+                            __result = try!(__state14(input, __tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 8
+            //     AllInputs = [r#"\\w+"#]
+            //     OptionalInputs = []
+            //     FixedInputs = [r#"\\w+"#]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Expression1)
+            //
+            //     Expression1 = r#"\\w+"# (*) ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //
+            //   [")", "*", EOF] -> Expression1 = r#"\\w+"# => ActionFn(26);
+            //
+            fn __state8<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action26::<>(input, __sym0);
+                        let __nt = __Nonterminal::Expression1((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action26::<>(input, __sym0);
+                        let __nt = __Nonterminal::Expression1((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 9
+            //     AllInputs = [Expression2, Expression2Op]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expression2, Expression2Op]
+            //     WillPushLen = 1
+            //     WillPush = [Expression1]
+            //     WillProduce = Some(Expression2)
+            //
+            //     Expression1 = (*) Wacky ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Expression1 = (*) "&" Maybe Expression1 ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Expression1 = (*) "(" Expression2 ")" ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Expression1 = (*) "wonky" Wonky ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Expression1 = (*) r#"\\w+"# ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Expression2 = Expression2 Expression2Op (*) Expression1 ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Wacky = (*) "wacky" ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //
+            //   "&" -> S4
+            //   "(" -> S5
+            //   "wacky" -> S6
+            //   "wonky" -> S7
+            //   r#"\\w+"# -> S8
+            //
+            //     Expression1 -> S15
+            //     Wacky -> S3
+            fn __state9<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Box<Expr<'input>>, usize),
+                __sym1: (usize, Box<Expr<'input>>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state4(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(7, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(8, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state8(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""&""###.to_string(),
+                            r###""(""###.to_string(),
+                            r###""wacky""###.to_string(),
+                            r###""wonky""###.to_string(),
+                            r###"r#"\\w+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Expression1(__sym2) => {
+                            __result = try!(__state15(input, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        __Nonterminal::Wacky(__sym2) => {
+                            __result = try!(__state3(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 10
+            //     AllInputs = ["*"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["*"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Expression2Op)
+            //
+            //     Expression2Op = "*" (*) ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //
+            //   ["&", "(", "wacky", "wonky", r#"\\w+"#] -> Expression2Op = "*" => ActionFn(30);
+            //
+            fn __state10<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(1, _), _)) |
+                    Some((_, Token(2, _), _)) |
+                    Some((_, Token(7, _), _)) |
+                    Some((_, Token(8, _), _)) |
+                    Some((_, Token(0, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action30::<>(input, __sym0);
+                        let __nt = __Nonterminal::Expression2Op((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""&""###.to_string(),
+                            r###""(""###.to_string(),
+                            r###""wacky""###.to_string(),
+                            r###""wonky""###.to_string(),
+                            r###"r#"\\w+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 11
+            //     AllInputs = ["&", Maybe]
+            //     OptionalInputs = []
+            //     FixedInputs = ["&", Maybe]
+            //     WillPushLen = 1
+            //     WillPush = [Expression1]
+            //     WillProduce = Some(Expression1)
+            //
+            //     Expression1 = (*) Wacky ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Expression1 = (*) "&" Maybe Expression1 ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Expression1 = "&" Maybe (*) Expression1 ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Expression1 = (*) "(" Expression2 ")" ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Expression1 = (*) "wonky" Wonky ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Expression1 = (*) r#"\\w+"# ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Wacky = (*) "wacky" ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //
+            //   "&" -> S4
+            //   "(" -> S5
+            //   "wacky" -> S6
+            //   "wonky" -> S7
+            //   r#"\\w+"# -> S8
+            //
+            //     Expression1 -> S16
+            //     Wacky -> S3
+            fn __state11<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, Box<Expr<'input>>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state4(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(7, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(8, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state8(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""&""###.to_string(),
+                            r###""(""###.to_string(),
+                            r###""wacky""###.to_string(),
+                            r###""wonky""###.to_string(),
+                            r###"r#"\\w+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Expression1(__sym2) => {
+                            __result = try!(__state16(input, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        __Nonterminal::Wacky(__sym2) => {
+                            __result = try!(__state3(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 12
+            //     AllInputs = ["["]
+            //     OptionalInputs = []
+            //     FixedInputs = ["["]
+            //     WillPushLen = 1
+            //     WillPush = ["]"]
+            //     WillProduce = Some(Maybe)
+            //
+            //     Maybe = "[" (*) "]" ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //
+            //   "]" -> S17
+            //
+            fn __state12<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state17(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""]""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 13
+            //     AllInputs = ["(", Expression2]
+            //     OptionalInputs = ["("]
+            //     FixedInputs = [Expression2]
+            //     WillPushLen = 1
+            //     WillPush = [")"]
+            //     WillProduce = None
+            //
+            //     Expression1 = "(" Expression2 (*) ")" ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Expression2 = Expression2 (*) Expression2Op Expression1 ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //     Expression2Op = (*) "*" ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //
+            //   ")" -> S18
+            //   "*" -> S10
+            //
+            //     Expression2Op -> S9
+            fn __state13<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: &mut Option<(usize, &'input str, usize)>,
+                __sym1: (usize, Box<Expr<'input>>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(3, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        let __sym0 = __sym0.take().unwrap();
+                        __result = try!(__state18(input, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, Token(4, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state10(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Expression2Op(__sym2) => {
+                            __result = try!(__state9(input, __tokens, __lookahead, __extra, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 14
+            //     AllInputs = ["wonky", Wonky]
+            //     OptionalInputs = []
+            //     FixedInputs = ["wonky", Wonky]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Expression1)
+            //
+            //     Expression1 = "wonky" Wonky (*) ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //
+            //   [")", "*", EOF] -> Expression1 = "wonky", Wonky => ActionFn(7);
+            //
+            fn __state14<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, Box<Expr<'input>>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action7::<>(input, __sym0, __sym1);
+                        let __nt = __Nonterminal::Expression1((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action7::<>(input, __sym0, __sym1);
+                        let __nt = __Nonterminal::Expression1((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 15
+            //     AllInputs = [Expression2, Expression2Op, Expression1]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expression2, Expression2Op, Expression1]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Expression2)
+            //
+            //     Expression2 = Expression2 Expression2Op Expression1 (*) ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //
+            //   [")", "*", EOF] -> Expression2 = Expression2, Expression2Op, Expression1 => ActionFn(28);
+            //
+            fn __state15<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Box<Expr<'input>>, usize),
+                __sym1: (usize, Box<Expr<'input>>, usize),
+                __sym2: (usize, Box<Expr<'input>>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action28::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expression2((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action28::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expression2((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 16
+            //     AllInputs = ["&", Maybe, Expression1]
+            //     OptionalInputs = []
+            //     FixedInputs = ["&", Maybe, Expression1]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Expression1)
+            //
+            //     Expression1 = "&" Maybe Expression1 (*) ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //
+            //   [")", "*", EOF] -> Expression1 = "&", Maybe, Expression1 => ActionFn(27);
+            //
+            fn __state16<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, Box<Expr<'input>>, usize),
+                __sym2: (usize, Box<Expr<'input>>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action27::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expression1((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action27::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expression1((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 17
+            //     AllInputs = ["[", "]"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["[", "]"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Maybe)
+            //
+            //     Maybe = "[" "]" (*) ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //
+            //   ["&", "(", "wacky", "wonky", r#"\\w+"#] -> Maybe = "[", "]" => ActionFn(32);
+            //
+            fn __state17<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(1, _), _)) |
+                    Some((_, Token(2, _), _)) |
+                    Some((_, Token(7, _), _)) |
+                    Some((_, Token(8, _), _)) |
+                    Some((_, Token(0, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action32::<>(input, __sym0, __sym1);
+                        let __nt = __Nonterminal::Maybe((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""&""###.to_string(),
+                            r###""(""###.to_string(),
+                            r###""wacky""###.to_string(),
+                            r###""wonky""###.to_string(),
+                            r###"r#"\\w+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 18
+            //     AllInputs = ["(", Expression2, ")"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["(", Expression2, ")"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Expression1)
+            //
+            //     Expression1 = "(" Expression2 ")" (*) ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+            //
+            //   [")", "*", EOF] -> Expression1 = "(", Expression2, ")" => ActionFn(25);
+            //
+            fn __state18<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, Box<Expr<'input>>, usize),
+                __sym2: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action25::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expression1((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action25::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expression1((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+        }
+        pub use self::__parse__Expression2::parse_Expression2;
+    }
+    mod __parse_table {
+
+        mod __parse_table {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use loc_issue_90_lib::Expr;
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__intern_token::Token;
+            #[allow(dead_code)]
+            pub enum __Symbol<'input>
+             {
+                Term_22_26_22(&'input str),
+                Term_22_28_22(&'input str),
+                Term_22_29_22(&'input str),
+                Term_22_2a_22(&'input str),
+                Term_22_5b_22(&'input str),
+                Term_22_5d_22(&'input str),
+                Term_22wacky_22(&'input str),
+                Term_22wonky_22(&'input str),
+                Termr_23_22_5c_5cw_2b_22_23(&'input str),
+                Nt_40L(usize),
+                Nt_40R(usize),
+                NtExpression1(Box<Expr<'input>>),
+                NtExpression2(Box<Expr<'input>>),
+                NtExpression2Op(Box<Expr<'input>>),
+                NtMaybe(Box<Expr<'input>>),
+                NtWacky(Box<Expr<'input>>),
+                NtWonky(Box<Expr<'input>>),
+                Nt____Expression2(Box<Expr<'input>>),
+            }
+            impl<'input> ::std::fmt::Debug for __Symbol<'input>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Symbol::Term_22_26_22(..) => __f.write_str("\"&\""),
+                        __Symbol::Term_22_28_22(..) => __f.write_str("\"(\""),
+                        __Symbol::Term_22_29_22(..) => __f.write_str("\")\""),
+                        __Symbol::Term_22_2a_22(..) => __f.write_str("\"*\""),
+                        __Symbol::Term_22_5b_22(..) => __f.write_str("\"[\""),
+                        __Symbol::Term_22_5d_22(..) => __f.write_str("\"]\""),
+                        __Symbol::Term_22wacky_22(..) => __f.write_str("\"wacky\""),
+                        __Symbol::Term_22wonky_22(..) => __f.write_str("\"wonky\""),
+                        __Symbol::Termr_23_22_5c_5cw_2b_22_23(..) => __f.write_str("r#\"\\\\w+\"#"),
+                        __Symbol::Nt_40L(..) => __f.write_str("@L"),
+                        __Symbol::Nt_40R(..) => __f.write_str("@R"),
+                        __Symbol::NtExpression1(..) => __f.write_str("Expression1"),
+                        __Symbol::NtExpression2(..) => __f.write_str("Expression2"),
+                        __Symbol::NtExpression2Op(..) => __f.write_str("Expression2Op"),
+                        __Symbol::NtMaybe(..) => __f.write_str("Maybe"),
+                        __Symbol::NtWacky(..) => __f.write_str("Wacky"),
+                        __Symbol::NtWonky(..) => __f.write_str("Wonky"),
+                        __Symbol::Nt____Expression2(..) => __f.write_str("__Expression2"),
+                    }
+                }
+            }
+            const __ACTION: &'static [i8] = &[
+                // State 0
+                //     Expression1 = (*) Wacky ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Expression1 = (*) "&" Maybe Expression1 ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Expression1 = (*) "(" Expression2 ")" ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Expression1 = (*) "wonky" Wonky ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Expression1 = (*) r#"\\w+"# ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Expression2 = (*) Expression1 ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Expression2 = (*) Expression2 Expression2Op Expression1 ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Wacky = (*) "wacky" ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     __Expression2 = (*) Expression2 ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                5,  // on "&", goto 4
+                6,  // on "(", goto 5
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "[", error
+                0,  // on "]", error
+                7,  // on "wacky", goto 6
+                8,  // on "wonky", goto 7
+                9,  // on r#"\\w+"#, goto 8
+
+                // State 1
+                //     Expression2 = Expression1 (*) ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                0,  // on "&", error
+                0,  // on "(", error
+                -9,  // on ")", reduce `Expression2 = Expression1 => ActionFn(29);`
+                -9,  // on "*", reduce `Expression2 = Expression1 => ActionFn(29);`
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "wacky", error
+                0,  // on "wonky", error
+                0,  // on r#"\\w+"#, error
+
+                // State 2
+                //     Expression2 = Expression2 (*) Expression2Op Expression1 ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Expression2Op = (*) "*" ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     __Expression2 = Expression2 (*) ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                0,  // on "&", error
+                0,  // on "(", error
+                0,  // on ")", error
+                11,  // on "*", goto 10
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "wacky", error
+                0,  // on "wonky", error
+                0,  // on r#"\\w+"#, error
+
+                // State 3
+                //     Expression1 = Wacky (*) ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                0,  // on "&", error
+                0,  // on "(", error
+                -7,  // on ")", reduce `Expression1 = Wacky => ActionFn(8);`
+                -7,  // on "*", reduce `Expression1 = Wacky => ActionFn(8);`
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "wacky", error
+                0,  // on "wonky", error
+                0,  // on r#"\\w+"#, error
+
+                // State 4
+                //     Expression1 = "&" (*) Maybe Expression1 ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Maybe = (*) ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Maybe = (*) "[" "]" ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                -11,  // on "&", reduce `Maybe =  => ActionFn(31);`
+                -11,  // on "(", reduce `Maybe =  => ActionFn(31);`
+                0,  // on ")", error
+                0,  // on "*", error
+                13,  // on "[", goto 12
+                0,  // on "]", error
+                -11,  // on "wacky", reduce `Maybe =  => ActionFn(31);`
+                -11,  // on "wonky", reduce `Maybe =  => ActionFn(31);`
+                -11,  // on r#"\\w+"#, reduce `Maybe =  => ActionFn(31);`
+
+                // State 5
+                //     Expression1 = (*) Wacky ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Expression1 = (*) "&" Maybe Expression1 ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Expression1 = (*) "(" Expression2 ")" ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Expression1 = "(" (*) Expression2 ")" ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Expression1 = (*) "wonky" Wonky ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Expression1 = (*) r#"\\w+"# ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Expression2 = (*) Expression1 ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Expression2 = (*) Expression2 Expression2Op Expression1 ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Wacky = (*) "wacky" ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                5,  // on "&", goto 4
+                6,  // on "(", goto 5
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "[", error
+                0,  // on "]", error
+                7,  // on "wacky", goto 6
+                8,  // on "wonky", goto 7
+                9,  // on r#"\\w+"#, goto 8
+
+                // State 6
+                //     Wacky = "wacky" (*) ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                0,  // on "&", error
+                0,  // on "(", error
+                -13,  // on ")", reduce `Wacky = "wacky" => ActionFn(33);`
+                -13,  // on "*", reduce `Wacky = "wacky" => ActionFn(33);`
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "wacky", error
+                0,  // on "wonky", error
+                0,  // on r#"\\w+"#, error
+
+                // State 7
+                //     Expression1 = "wonky" (*) Wonky ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Wonky = (*) ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                0,  // on "&", error
+                0,  // on "(", error
+                -14,  // on ")", reduce `Wonky =  => ActionFn(34);`
+                -14,  // on "*", reduce `Wonky =  => ActionFn(34);`
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "wacky", error
+                0,  // on "wonky", error
+                0,  // on r#"\\w+"#, error
+
+                // State 8
+                //     Expression1 = r#"\\w+"# (*) ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                0,  // on "&", error
+                0,  // on "(", error
+                -4,  // on ")", reduce `Expression1 = r#"\\w+"# => ActionFn(26);`
+                -4,  // on "*", reduce `Expression1 = r#"\\w+"# => ActionFn(26);`
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "wacky", error
+                0,  // on "wonky", error
+                0,  // on r#"\\w+"#, error
+
+                // State 9
+                //     Expression1 = (*) Wacky ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Expression1 = (*) "&" Maybe Expression1 ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Expression1 = (*) "(" Expression2 ")" ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Expression1 = (*) "wonky" Wonky ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Expression1 = (*) r#"\\w+"# ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Expression2 = Expression2 Expression2Op (*) Expression1 ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Wacky = (*) "wacky" ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                5,  // on "&", goto 4
+                6,  // on "(", goto 5
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "[", error
+                0,  // on "]", error
+                7,  // on "wacky", goto 6
+                8,  // on "wonky", goto 7
+                9,  // on r#"\\w+"#, goto 8
+
+                // State 10
+                //     Expression2Op = "*" (*) ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                -10,  // on "&", reduce `Expression2Op = "*" => ActionFn(30);`
+                -10,  // on "(", reduce `Expression2Op = "*" => ActionFn(30);`
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "[", error
+                0,  // on "]", error
+                -10,  // on "wacky", reduce `Expression2Op = "*" => ActionFn(30);`
+                -10,  // on "wonky", reduce `Expression2Op = "*" => ActionFn(30);`
+                -10,  // on r#"\\w+"#, reduce `Expression2Op = "*" => ActionFn(30);`
+
+                // State 11
+                //     Expression1 = (*) Wacky ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Expression1 = (*) "&" Maybe Expression1 ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Expression1 = "&" Maybe (*) Expression1 ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Expression1 = (*) "(" Expression2 ")" ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Expression1 = (*) "wonky" Wonky ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Expression1 = (*) r#"\\w+"# ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Wacky = (*) "wacky" ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                5,  // on "&", goto 4
+                6,  // on "(", goto 5
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "[", error
+                0,  // on "]", error
+                7,  // on "wacky", goto 6
+                8,  // on "wonky", goto 7
+                9,  // on r#"\\w+"#, goto 8
+
+                // State 12
+                //     Maybe = "[" (*) "]" ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                0,  // on "&", error
+                0,  // on "(", error
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "[", error
+                18,  // on "]", goto 17
+                0,  // on "wacky", error
+                0,  // on "wonky", error
+                0,  // on r#"\\w+"#, error
+
+                // State 13
+                //     Expression1 = "(" Expression2 (*) ")" ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Expression2 = Expression2 (*) Expression2Op Expression1 ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                //     Expression2Op = (*) "*" ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                0,  // on "&", error
+                0,  // on "(", error
+                19,  // on ")", goto 18
+                11,  // on "*", goto 10
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "wacky", error
+                0,  // on "wonky", error
+                0,  // on r#"\\w+"#, error
+
+                // State 14
+                //     Expression1 = "wonky" Wonky (*) ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                0,  // on "&", error
+                0,  // on "(", error
+                -6,  // on ")", reduce `Expression1 = "wonky", Wonky => ActionFn(7);`
+                -6,  // on "*", reduce `Expression1 = "wonky", Wonky => ActionFn(7);`
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "wacky", error
+                0,  // on "wonky", error
+                0,  // on r#"\\w+"#, error
+
+                // State 15
+                //     Expression2 = Expression2 Expression2Op Expression1 (*) ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                0,  // on "&", error
+                0,  // on "(", error
+                -8,  // on ")", reduce `Expression2 = Expression2, Expression2Op, Expression1 => ActionFn(28);`
+                -8,  // on "*", reduce `Expression2 = Expression2, Expression2Op, Expression1 => ActionFn(28);`
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "wacky", error
+                0,  // on "wonky", error
+                0,  // on r#"\\w+"#, error
+
+                // State 16
+                //     Expression1 = "&" Maybe Expression1 (*) ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                0,  // on "&", error
+                0,  // on "(", error
+                -5,  // on ")", reduce `Expression1 = "&", Maybe, Expression1 => ActionFn(27);`
+                -5,  // on "*", reduce `Expression1 = "&", Maybe, Expression1 => ActionFn(27);`
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "wacky", error
+                0,  // on "wonky", error
+                0,  // on r#"\\w+"#, error
+
+                // State 17
+                //     Maybe = "[" "]" (*) ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                -12,  // on "&", reduce `Maybe = "[", "]" => ActionFn(32);`
+                -12,  // on "(", reduce `Maybe = "[", "]" => ActionFn(32);`
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "[", error
+                0,  // on "]", error
+                -12,  // on "wacky", reduce `Maybe = "[", "]" => ActionFn(32);`
+                -12,  // on "wonky", reduce `Maybe = "[", "]" => ActionFn(32);`
+                -12,  // on r#"\\w+"#, reduce `Maybe = "[", "]" => ActionFn(32);`
+
+                // State 18
+                //     Expression1 = "(" Expression2 ")" (*) ["&", "(", ")", "*", "[", "]", "wacky", "wonky", r#"\\w+"#, EOF]
+                0,  // on "&", error
+                0,  // on "(", error
+                -3,  // on ")", reduce `Expression1 = "(", Expression2, ")" => ActionFn(25);`
+                -3,  // on "*", reduce `Expression1 = "(", Expression2, ")" => ActionFn(25);`
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "wacky", error
+                0,  // on "wonky", error
+                0,  // on r#"\\w+"#, error
+
+            ];
+            const __EOF_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // on EOF, error
+
+                // State 1
+                -9,  // on EOF, reduce `Expression2 = Expression1 => ActionFn(29);`
+
+                // State 2
+                -15,  // on EOF, reduce `__Expression2 = Expression2 => ActionFn(0);`
+
+                // State 3
+                -7,  // on EOF, reduce `Expression1 = Wacky => ActionFn(8);`
+
+                // State 4
+                0,  // on EOF, error
+
+                // State 5
+                0,  // on EOF, error
+
+                // State 6
+                -13,  // on EOF, reduce `Wacky = "wacky" => ActionFn(33);`
+
+                // State 7
+                -14,  // on EOF, reduce `Wonky =  => ActionFn(34);`
+
+                // State 8
+                -4,  // on EOF, reduce `Expression1 = r#"\\w+"# => ActionFn(26);`
+
+                // State 9
+                0,  // on EOF, error
+
+                // State 10
+                0,  // on EOF, error
+
+                // State 11
+                0,  // on EOF, error
+
+                // State 12
+                0,  // on EOF, error
+
+                // State 13
+                0,  // on EOF, error
+
+                // State 14
+                -6,  // on EOF, reduce `Expression1 = "wonky", Wonky => ActionFn(7);`
+
+                // State 15
+                -8,  // on EOF, reduce `Expression2 = Expression2, Expression2Op, Expression1 => ActionFn(28);`
+
+                // State 16
+                -5,  // on EOF, reduce `Expression1 = "&", Maybe, Expression1 => ActionFn(27);`
+
+                // State 17
+                0,  // on EOF, error
+
+                // State 18
+                -3,  // on EOF, reduce `Expression1 = "(", Expression2, ")" => ActionFn(25);`
+
+            ];
+            const __GOTO: &'static [u8] = &[
+                // State 0
+                0,  // on @L, error
+                0,  // on @R, error
+                2,  // on Expression1, goto 1
+                3,  // on Expression2, goto 2
+                0,  // on Expression2Op, error
+                0,  // on Maybe, error
+                4,  // on Wacky, goto 3
+                0,  // on Wonky, error
+                0,  // on __Expression2, error
+
+                // State 1
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expression1, error
+                0,  // on Expression2, error
+                0,  // on Expression2Op, error
+                0,  // on Maybe, error
+                0,  // on Wacky, error
+                0,  // on Wonky, error
+                0,  // on __Expression2, error
+
+                // State 2
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expression1, error
+                0,  // on Expression2, error
+                10,  // on Expression2Op, goto 9
+                0,  // on Maybe, error
+                0,  // on Wacky, error
+                0,  // on Wonky, error
+                0,  // on __Expression2, error
+
+                // State 3
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expression1, error
+                0,  // on Expression2, error
+                0,  // on Expression2Op, error
+                0,  // on Maybe, error
+                0,  // on Wacky, error
+                0,  // on Wonky, error
+                0,  // on __Expression2, error
+
+                // State 4
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expression1, error
+                0,  // on Expression2, error
+                0,  // on Expression2Op, error
+                12,  // on Maybe, goto 11
+                0,  // on Wacky, error
+                0,  // on Wonky, error
+                0,  // on __Expression2, error
+
+                // State 5
+                0,  // on @L, error
+                0,  // on @R, error
+                2,  // on Expression1, goto 1
+                14,  // on Expression2, goto 13
+                0,  // on Expression2Op, error
+                0,  // on Maybe, error
+                4,  // on Wacky, goto 3
+                0,  // on Wonky, error
+                0,  // on __Expression2, error
+
+                // State 6
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expression1, error
+                0,  // on Expression2, error
+                0,  // on Expression2Op, error
+                0,  // on Maybe, error
+                0,  // on Wacky, error
+                0,  // on Wonky, error
+                0,  // on __Expression2, error
+
+                // State 7
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expression1, error
+                0,  // on Expression2, error
+                0,  // on Expression2Op, error
+                0,  // on Maybe, error
+                0,  // on Wacky, error
+                15,  // on Wonky, goto 14
+                0,  // on __Expression2, error
+
+                // State 8
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expression1, error
+                0,  // on Expression2, error
+                0,  // on Expression2Op, error
+                0,  // on Maybe, error
+                0,  // on Wacky, error
+                0,  // on Wonky, error
+                0,  // on __Expression2, error
+
+                // State 9
+                0,  // on @L, error
+                0,  // on @R, error
+                16,  // on Expression1, goto 15
+                0,  // on Expression2, error
+                0,  // on Expression2Op, error
+                0,  // on Maybe, error
+                4,  // on Wacky, goto 3
+                0,  // on Wonky, error
+                0,  // on __Expression2, error
+
+                // State 10
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expression1, error
+                0,  // on Expression2, error
+                0,  // on Expression2Op, error
+                0,  // on Maybe, error
+                0,  // on Wacky, error
+                0,  // on Wonky, error
+                0,  // on __Expression2, error
+
+                // State 11
+                0,  // on @L, error
+                0,  // on @R, error
+                17,  // on Expression1, goto 16
+                0,  // on Expression2, error
+                0,  // on Expression2Op, error
+                0,  // on Maybe, error
+                4,  // on Wacky, goto 3
+                0,  // on Wonky, error
+                0,  // on __Expression2, error
+
+                // State 12
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expression1, error
+                0,  // on Expression2, error
+                0,  // on Expression2Op, error
+                0,  // on Maybe, error
+                0,  // on Wacky, error
+                0,  // on Wonky, error
+                0,  // on __Expression2, error
+
+                // State 13
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expression1, error
+                0,  // on Expression2, error
+                10,  // on Expression2Op, goto 9
+                0,  // on Maybe, error
+                0,  // on Wacky, error
+                0,  // on Wonky, error
+                0,  // on __Expression2, error
+
+                // State 14
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expression1, error
+                0,  // on Expression2, error
+                0,  // on Expression2Op, error
+                0,  // on Maybe, error
+                0,  // on Wacky, error
+                0,  // on Wonky, error
+                0,  // on __Expression2, error
+
+                // State 15
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expression1, error
+                0,  // on Expression2, error
+                0,  // on Expression2Op, error
+                0,  // on Maybe, error
+                0,  // on Wacky, error
+                0,  // on Wonky, error
+                0,  // on __Expression2, error
+
+                // State 16
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expression1, error
+                0,  // on Expression2, error
+                0,  // on Expression2Op, error
+                0,  // on Maybe, error
+                0,  // on Wacky, error
+                0,  // on Wonky, error
+                0,  // on __Expression2, error
+
+                // State 17
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expression1, error
+                0,  // on Expression2, error
+                0,  // on Expression2Op, error
+                0,  // on Maybe, error
+                0,  // on Wacky, error
+                0,  // on Wonky, error
+                0,  // on __Expression2, error
+
+                // State 18
+                0,  // on @L, error
+                0,  // on @R, error
+                0,  // on Expression1, error
+                0,  // on Expression2, error
+                0,  // on Expression2Op, error
+                0,  // on Maybe, error
+                0,  // on Wacky, error
+                0,  // on Wonky, error
+                0,  // on __Expression2, error
+
+            ];
+            const __DEFAULT_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // no default reduction
+
+                // State 1
+                -9,  // on any token, reduce `Expression2 = Expression1 => ActionFn(29);`
+
+                // State 2
+                0,  // no default reduction
+
+                // State 3
+                -7,  // on any token, reduce `Expression1 = Wacky => ActionFn(8);`
+
+                // State 4
+                0,  // no default reduction
+
+                // State 5
+                0,  // no default reduction
+
+                // State 6
+                -13,  // on any token, reduce `Wacky = "wacky" => ActionFn(33);`
+
+                // State 7
+                0,  // no default reduction
+
+                // State 8
+                -4,  // on any token, reduce `Expression1 = r#"\\w+"# => ActionFn(26);`
+
+                // State 9
+                0,  // no default reduction
+
+                // State 10
+                -10,  // on any token, reduce `Expression2Op = "*" => ActionFn(30);`
+
+                // State 11
+                0,  // no default reduction
+
+                // State 12
+                0,  // no default reduction
+
+                // State 13
+                0,  // no default reduction
+
+                // State 14
+                -6,  // on any token, reduce `Expression1 = "wonky", Wonky => ActionFn(7);`
+
+                // State 15
+                -8,  // on any token, reduce `Expression2 = Expression2, Expression2Op, Expression1 => ActionFn(28);`
+
+                // State 16
+                -5,  // on any token, reduce `Expression1 = "&", Maybe, Expression1 => ActionFn(27);`
+
+                // State 17
+                -12,  // on any token, reduce `Maybe = "[", "]" => ActionFn(32);`
+
+                // State 18
+                -3,  // on any token, reduce `Expression1 = "(", Expression2, ")" => ActionFn(25);`
+
+            ];
+            fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+                const __TERMINAL: &'static [&'static str] = &[
+                    r###""&""###,
+                    r###""(""###,
+                    r###"")""###,
+                    r###""*""###,
+                    r###""[""###,
+                    r###""]""###,
+                    r###""wacky""###,
+                    r###""wonky""###,
+                    r###"r#"\\w+"#"###,
+                ];
+                __ACTION[(__state * 9)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+                    if state == 0 {
+                        None
+                    } else {
+                        Some(terminal.to_string())
+                    }
+                }).collect()
+            }
+            #[allow(dead_code)]
+            pub fn parse_Expression2<
+                'input,
+            >(
+                input: &'input str,
+            ) -> Result<Box<Expr<'input>>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Expression2(__v) => Ok(__v), _ => unreachable!() })
+            }
+            #[allow(dead_code)]
+            pub fn parse_Expression2_cancelable<
+                'input,
+            >(
+                input: &'input str,
+                __cancel: &::std::sync::atomic::AtomicBool,
+            ) -> Result<Box<Expr<'input>>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Expression2(__v) => Ok(__v), _ => unreachable!() })
+            }
+
+            #[allow(dead_code)]
+            pub struct Expression2Parser {
+                __states: ::std::vec::Vec<i32>,
+                __symbols: __lalrpop_util::stack::Recycled,
+                __cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+            }
+
+            #[allow(dead_code)]
+            impl Expression2Parser {
+                pub fn new() -> Expression2Parser {
+                    Expression2Parser { __states: vec![], __symbols: __lalrpop_util::stack::Recycled::new(), __cancel: None }
+                }
+
+                pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+                    self.__cancel = Some(flag);
+                }
+
+                pub fn parse_in_place<'input>(
+                    &mut self,
+                    input: &'input str,
+                ) -> Result<Box<Expr<'input>>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+                {
+                    let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                    let mut __symbols = self.__symbols.take();
+                    let __result = __parse::<>(input, 0, &mut __tokens, &mut self.__states, &mut __symbols, self.__cancel.as_ref().map(|c| &**c)).and_then(|__v| match __v { __Symbol::Nt____Expression2(__v) => Ok(__v), _ => unreachable!() });
+                    self.__symbols.put(__symbols);
+                    __result
+                }
+            }
+
+            fn __parse<
+                'input,
+            >(
+                input: &'input str,
+                __start_state: i32,
+                mut __tokens: &mut Iterator<Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+                mut __states: &mut ::std::vec::Vec<i32>,
+                mut __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                __cancel: Option<&::std::sync::atomic::AtomicBool>,
+            ) -> Result<__Symbol<'input>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                __states.clear();
+                __states.push(__start_state);
+                __symbols.clear();
+                let mut __integer;
+                let mut __lookahead;
+                let __last_location: &mut usize = &mut Default::default();
+                let mut __default_reduced = [0_usize; 16];
+                let mut __default_reduced_len = 0;
+                '__shift: loop {
+                    loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __DEFAULT_ACTION[__state] as i32;
+                        if __action == 0 {
+                            break;
+                        }
+                        if __default_reduced_len < 16 {
+                            __default_reduced[__default_reduced_len] = __state;
+                            __default_reduced_len += 1;
+                        }
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    }
+                    __lookahead = match __tokens.next() {
+                        Some(Ok(v)) => v,
+                        None => break '__shift,
+                        Some(Err(e)) => return Err(e),
+                    };
+                    *__last_location = __lookahead.2.clone();
+                    __integer = match __lookahead.1 {
+                        Token(1, _) if true => 0,
+                        Token(2, _) if true => 1,
+                        Token(3, _) if true => 2,
+                        Token(4, _) if true => 3,
+                        Token(5, _) if true => 4,
+                        Token(6, _) if true => 5,
+                        Token(7, _) if true => 6,
+                        Token(8, _) if true => 7,
+                        Token(0, _) if true => 8,
+                        _ => {
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| true).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match Some(__lookahead) {
+                                Some(__token) => {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error);
+                        }
+                    };
+                    '__inner: loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __ACTION[__state * 9 + __integer] as i32;
+                        if __action > 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                                }
+                            }
+                            let __symbol = match __integer {
+                                0 => match __lookahead.1 {
+                                    Token(1, __tok0) => __Symbol::Term_22_26_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                1 => match __lookahead.1 {
+                                    Token(2, __tok0) => __Symbol::Term_22_28_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                2 => match __lookahead.1 {
+                                    Token(3, __tok0) => __Symbol::Term_22_29_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                3 => match __lookahead.1 {
+                                    Token(4, __tok0) => __Symbol::Term_22_2a_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                4 => match __lookahead.1 {
+                                    Token(5, __tok0) => __Symbol::Term_22_5b_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                5 => match __lookahead.1 {
+                                    Token(6, __tok0) => __Symbol::Term_22_5d_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                6 => match __lookahead.1 {
+                                    Token(7, __tok0) => __Symbol::Term_22wacky_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                7 => match __lookahead.1 {
+                                    Token(8, __tok0) => __Symbol::Term_22wonky_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                8 => match __lookahead.1 {
+                                    Token(0, __tok0) => __Symbol::Termr_23_22_5c_5cw_2b_22_23((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                _ => unreachable!(),
+                            };
+                            __states.push(__action - 1);
+                            __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                            __default_reduced_len = 0;
+                            continue '__shift;
+                        } else if __action < 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                                }
+                            }
+                            if let Some(r) = __reduce(input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                                if r.is_err() {
+                                    return r;
+                                }
+                                return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] });
+                            }
+                        } else {
+                            let mut __err_lookahead = Some(__lookahead);
+                            let mut __err_integer: Option<usize> = Some(__integer);
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 9 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match __err_lookahead {
+                                Some(__token) => {
+                                    if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                        __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                    } else {
+                                        __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                    }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error)
+                        }
+                    }
+                }
+                loop {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __action = __EOF_ACTION[__state] as i32;
+                    if __action < 0 {
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    } else {
+                        let mut __err_lookahead = None;
+                        let mut __err_integer: Option<usize> = None;
+                        let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 9 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                        let __expected = __expected_tokens(__state);
+                        let __error = match __err_lookahead {
+                            Some(__token) => {
+                                if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                    __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                } else {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                            }
+                            None => {
+                                __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                            }
+                        };
+                        return Err(__error)
+                    }
+                }
+            }
+            fn __accepts<
+                'input,
+            >(
+                input: &'input str,
+                __error_state: i32,
+                __states: & [i32],
+                __opt_integer: Option<usize>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> bool
+            {
+                let mut __states = __states.to_vec();
+                __states.push(__error_state);
+                loop {
+                    let mut __states_len = __states.len();
+                    let __top = __states[__states_len - 1];
+                    let __action = match __opt_integer {
+                        None => __EOF_ACTION[__top as usize] as i32,
+                        Some(__integer) => __ACTION[(__top * 9) as usize + __integer] as i32,
+                    };
+                    if __action == 0 { return false; }
+                    if __action > 0 { return true; }
+                    let (__to_pop, __nt) = match -__action {
+                        // simulate @L =  => ActionFn(14);
+                        1 => {
+                            (0, 0)
+                        }
+                        // simulate @R =  => ActionFn(13);
+                        2 => {
+                            (0, 1)
+                        }
+                        // simulate Expression1 = "(", Expression2, ")" => ActionFn(25);
+                        3 => {
+                            (3, 2)
+                        }
+                        // simulate Expression1 = r#"\\w+"# => ActionFn(26);
+                        4 => {
+                            (1, 2)
+                        }
+                        // simulate Expression1 = "&", Maybe, Expression1 => ActionFn(27);
+                        5 => {
+                            (3, 2)
+                        }
+                        // simulate Expression1 = "wonky", Wonky => ActionFn(7);
+                        6 => {
+                            (2, 2)
+                        }
+                        // simulate Expression1 = Wacky => ActionFn(8);
+                        7 => {
+                            (1, 2)
+                        }
+                        // simulate Expression2 = Expression2, Expression2Op, Expression1 => ActionFn(28);
+                        8 => {
+                            (3, 3)
+                        }
+                        // simulate Expression2 = Expression1 => ActionFn(29);
+                        9 => {
+                            (1, 3)
+                        }
+                        // simulate Expression2Op = "*" => ActionFn(30);
+                        10 => {
+                            (1, 4)
+                        }
+                        // simulate Maybe =  => ActionFn(31);
+                        11 => {
+                            (0, 5)
+                        }
+                        // simulate Maybe = "[", "]" => ActionFn(32);
+                        12 => {
+                            (2, 5)
+                        }
+                        // simulate Wacky = "wacky" => ActionFn(33);
+                        13 => {
+                            (1, 6)
+                        }
+                        // simulate Wonky =  => ActionFn(34);
+                        14 => {
+                            (0, 7)
+                        }
+                        // simulate __Expression2 = Expression2 => ActionFn(0);
+                        15 => return true,
+                        _ => panic!("invalid action code {}", __action)
+                    };
+                    __states_len -= __to_pop;
+                    __states.truncate(__states_len);
+                    let __top = __states[__states_len - 1];
+                    let __next_state = __GOTO[(__top * 9 + __nt) as usize] as i32 - 1;
+                    __states.push(__next_state);
+                }
+            }
+            pub fn __reduce<
+                'input,
+            >(
+                input: &'input str,
+                __action: i32,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Option<Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                let __nonterminal = match -__action {
+                    1 => match __reduce__40L_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    2 => match __reduce__40R_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    3 => match __reduce_Expression1_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    4 => match __reduce_Expression1_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    5 => match __reduce_Expression1_2(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    6 => match __reduce_Expression1_3(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    7 => match __reduce_Expression1_4(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    8 => match __reduce_Expression2_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    9 => match __reduce_Expression2_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    10 => match __reduce_Expression2Op_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    11 => match __reduce_Maybe_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    12 => match __reduce_Maybe_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    13 => match __reduce_Wacky_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    14 => match __reduce_Wonky_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    15 => match __reduce_____Expression2_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    _ => panic!("invalid action code {}", __action)
+                };
+                let __state = *__states.last().unwrap() as usize;
+                let __next_state = __GOTO[__state * 9 + __nonterminal] as i32 - 1;
+                __states.push(__next_state);
+                None
+            }
+
+            fn __reduce__40L_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // @L =  => ActionFn(14);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action14::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_40L(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce__40R_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // @R =  => ActionFn(13);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action13::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_40R(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce_Expression1_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expression1 = "(", Expression2, ")" => ActionFn(25);
+                let __sym2 = __pop_Term_22_29_22(__symbols);
+                let __sym1 = __pop_NtExpression2(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action25::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtExpression1(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce_Expression1_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expression1 = r#"\\w+"# => ActionFn(26);
+                let __sym0 = __pop_Termr_23_22_5c_5cw_2b_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action26::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtExpression1(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce_Expression1_2<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expression1 = "&", Maybe, Expression1 => ActionFn(27);
+                let __sym2 = __pop_NtExpression1(__symbols);
+                let __sym1 = __pop_NtMaybe(__symbols);
+                let __sym0 = __pop_Term_22_26_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action27::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtExpression1(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce_Expression1_3<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expression1 = "wonky", Wonky => ActionFn(7);
+                let __sym1 = __pop_NtWonky(__symbols);
+                let __sym0 = __pop_Term_22wonky_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action7::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtExpression1(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce_Expression1_4<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expression1 = Wacky => ActionFn(8);
+                let __sym0 = __pop_NtWacky(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action8::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtExpression1(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce_Expression2_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expression2 = Expression2, Expression2Op, Expression1 => ActionFn(28);
+                let __sym2 = __pop_NtExpression1(__symbols);
+                let __sym1 = __pop_NtExpression2Op(__symbols);
+                let __sym0 = __pop_NtExpression2(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action28::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtExpression2(__nt), __end));
+                Ok(3)
+            }
+
+            fn __reduce_Expression2_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expression2 = Expression1 => ActionFn(29);
+                let __sym0 = __pop_NtExpression1(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action29::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtExpression2(__nt), __end));
+                Ok(3)
+            }
+
+            fn __reduce_Expression2Op_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expression2Op = "*" => ActionFn(30);
+                let __sym0 = __pop_Term_22_2a_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action30::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtExpression2Op(__nt), __end));
+                Ok(4)
+            }
+
+            fn __reduce_Maybe_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Maybe =  => ActionFn(31);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action31::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::NtMaybe(__nt), __end));
+                Ok(5)
+            }
+
+            fn __reduce_Maybe_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Maybe = "[", "]" => ActionFn(32);
+                let __sym1 = __pop_Term_22_5d_22(__symbols);
+                let __sym0 = __pop_Term_22_5b_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action32::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtMaybe(__nt), __end));
+                Ok(5)
+            }
+
+            fn __reduce_Wacky_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Wacky = "wacky" => ActionFn(33);
+                let __sym0 = __pop_Term_22wacky_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action33::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtWacky(__nt), __end));
+                Ok(6)
+            }
+
+            fn __reduce_Wonky_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Wonky =  => ActionFn(34);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action34::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::NtWonky(__nt), __end));
+                Ok(7)
+            }
+
+            fn __reduce_____Expression2_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // __Expression2 = Expression2 => ActionFn(0);
+                let __sym0 = __pop_NtExpression2(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action0::<>(input, __sym0);
+                Err(Ok(__Symbol::Nt____Expression2(__nt)))
+            }
+            fn __pop_Term_22_26_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_26_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_28_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_28_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_29_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_29_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_2a_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2a_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_5b_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_5b_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_5d_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_5d_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22wacky_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22wacky_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22wonky_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22wonky_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Termr_23_22_5c_5cw_2b_22_23<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Termr_23_22_5c_5cw_2b_22_23(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_40L<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, usize, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_40L(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_40R<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, usize, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_40R(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtExpression1<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Box<Expr<'input>>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtExpression1(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtExpression2<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Box<Expr<'input>>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtExpression2(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtExpression2Op<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Box<Expr<'input>>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtExpression2Op(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtMaybe<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Box<Expr<'input>>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtMaybe(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtWacky<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Box<Expr<'input>>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtWacky(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtWonky<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Box<Expr<'input>>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtWonky(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt____Expression2<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Box<Expr<'input>>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt____Expression2(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+        }
+        pub use self::__parse_table::parse_Expression2;
+        pub use self::__parse_table::parse_Expression2_cancelable;
+        pub use self::__parse_table::Expression2Parser;
+    }
+    pub use self::__parse_table::parse_Expression2_cancelable;
+    pub use self::__parse_table::Expression2Parser;
+}
+pub use self::__parse__Expression2::parse_Expression2;
+pub use self::__parse__Expression2::parse_Expression2_cancelable;
+pub use self::__parse__Expression2::Expression2Parser;
+mod __intern_token {
+    #![allow(unused_imports)]
+    use loc_issue_90_lib::Expr;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    extern crate regex as __regex;
+    use std::fmt as __fmt;
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Token<'input>(pub usize, pub &'input str);
+    impl<'a> __fmt::Display for Token<'a> {
+        fn fmt(&self, formatter: &mut __fmt::Formatter) -> Result<(), __fmt::Error> {
+            __fmt::Display::fmt(self.1, formatter)
+        }
+    }
+
+    pub struct __Matcher<'input> {
+        text: &'input str,
+        consumed: usize,
+        regex_set: __regex::RegexSet,
+        regex_vec: Vec<__regex::Regex>,
+    }
+
+    impl<'input> __Matcher<'input> {
+        pub fn new(s: &'input str) -> __Matcher<'input> {
+            let __strs: &[&str] = &[
+                "^(?u:[0-9A-Z_-_a-zª-ªµ-µº-ºÀ-ÖØ-öø-ˁˆ-ˑˠ-ˤˬ-ˬˮ-ˮ\u{300}-ʹͶ-ͷͺ-ͽͿ-ͿΆ-ΆΈ-ΊΌ-ΌΎ-ΡΣ-ϵϷ-ҁ\u{483}-ԯԱ-Ֆՙ-ՙա-և\u{591}-\u{5bd}\u{5bf}-\u{5bf}\u{5c1}-\u{5c2}\u{5c4}-\u{5c5}\u{5c7}-\u{5c7}א-תװ-ײ\u{610}-\u{61a}ؠ-٩ٮ-ۓە-\u{6dc}\u{6df}-\u{6e8}\u{6ea}-ۼۿ-ۿܐ-\u{74a}ݍ-ޱ߀-ߵߺ-ߺࠀ-\u{82d}ࡀ-\u{85b}ࡠ-ࡪࢠ-ࢴࢶ-ࢽ\u{8d4}-\u{8e1}\u{8e3}-\u{963}०-९ॱ-ঃঅ-ঌএ-ঐও-নপ-রল-লশ-হ\u{9bc}-\u{9c4}ে-ৈো-ৎ\u{9d7}-\u{9d7}ড়-ঢ়য়-\u{9e3}০-ৱৼ-ৼ\u{a01}-ਃਅ-ਊਏ-ਐਓ-ਨਪ-ਰਲ-ਲ਼ਵ-ਸ਼ਸ-ਹ\u{a3c}-\u{a3c}ਾ-\u{a42}\u{a47}-\u{a48}\u{a4b}-\u{a4d}\u{a51}-\u{a51}ਖ਼-ੜਫ਼-ਫ਼੦-\u{a75}\u{a81}-ઃઅ-ઍએ-ઑઓ-નપ-રલ-ળવ-હ\u{abc}-\u{ac5}\u{ac7}-ૉો-\u{acd}ૐ-ૐૠ-\u{ae3}૦-૯ૹ-\u{aff}\u{b01}-ଃଅ-ଌଏ-ଐଓ-ନପ-ରଲ-ଳଵ-ହ\u{b3c}-\u{b44}େ-ୈୋ-\u{b4d}\u{b56}-\u{b57}ଡ଼-ଢ଼ୟ-\u{b63}୦-୯ୱ-ୱ\u{b82}-ஃஅ-ஊஎ-ஐஒ-கங-சஜ-ஜஞ-டண-தந-பம-ஹ\u{bbe}-ூெ-ைொ-\u{bcd}ௐ-ௐ\u{bd7}-\u{bd7}௦-௯\u{c00}-ఃఅ-ఌఎ-ఐఒ-నప-హఽ-ౄ\u{c46}-\u{c48}\u{c4a}-\u{c4d}\u{c55}-\u{c56}ౘ-ౚౠ-\u{c63}౦-౯ಀ-ಃಅ-ಌಎ-ಐಒ-ನಪ-ಳವ-ಹ\u{cbc}-ೄ\u{cc6}-\u{cc8}\u{cca}-\u{ccd}\u{cd5}-\u{cd6}ೞ-ೞೠ-\u{ce3}೦-೯ೱ-ೲ\u{d00}-ഃഅ-ഌഎ-ഐഒ-\u{d44}െ-ൈൊ-ൎൔ-\u{d57}ൟ-\u{d63}൦-൯ൺ-ൿං-ඃඅ-ඖක-නඳ-රල-ලව-ෆ\u{dca}-\u{dca}\u{dcf}-\u{dd4}\u{dd6}-\u{dd6}ෘ-\u{ddf}෦-෯ෲ-ෳก-\u{e3a}เ-\u{e4e}๐-๙ກ-ຂຄ-ຄງ-ຈຊ-ຊຍ-ຍດ-ທນ-ຟມ-ຣລ-ລວ-ວສ-ຫອ-\u{eb9}\u{ebb}-ຽເ-ໄໆ-ໆ\u{ec8}-\u{ecd}໐-໙ໜ-ໟༀ-ༀ\u{f18}-\u{f19}༠-༩\u{f35}-\u{f35}\u{f37}-\u{f37}\u{f39}-\u{f39}༾-ཇཉ-ཬ\u{f71}-\u{f84}\u{f86}-\u{f97}\u{f99}-\u{fbc}\u{fc6}-\u{fc6}က-၉ၐ-\u{109d}Ⴀ-ჅჇ-ჇჍ-Ⴭა-ჺჼ-ቈቊ-ቍቐ-ቖቘ-ቘቚ-ቝበ-ኈኊ-ኍነ-ኰኲ-ኵኸ-ኾዀ-ዀዂ-ዅወ-ዖዘ-ጐጒ-ጕጘ-ፚ\u{135d}-\u{135f}ᎀ-ᎏᎠ-Ᏽᏸ-ᏽᐁ-ᙬᙯ-ᙿᚁ-ᚚᚠ-ᛪᛮ-ᛸᜀ-ᜌᜎ-\u{1714}ᜠ-\u{1734}ᝀ-\u{1753}ᝠ-ᝬᝮ-ᝰ\u{1772}-\u{1773}ក-\u{17d3}ៗ-ៗៜ-\u{17dd}០-៩\u{180b}-\u{180d}᠐-᠙ᠠ-ᡷᢀ-ᢪᢰ-ᣵᤀ-ᤞ\u{1920}-ᤫᤰ-\u{193b}᥆-ᥭᥰ-ᥴᦀ-ᦫᦰ-ᧉ᧐-᧙ᨀ-\u{1a1b}ᨠ-\u{1a5e}\u{1a60}-\u{1a7c}\u{1a7f}-᪉᪐-᪙ᪧ-ᪧ\u{1ab0}-\u{1abe}\u{1b00}-ᭋ᭐-᭙\u{1b6b}-\u{1b73}\u{1b80}-\u{1bf3}ᰀ-\u{1c37}᱀-᱉ᱍ-ᱽᲀ-ᲈ\u{1cd0}-\u{1cd2}\u{1cd4}-\u{1cf9}ᴀ-\u{1df9}\u{1dfb}-ἕἘ-Ἕἠ-ὅὈ-Ὅὐ-ὗὙ-ὙὛ-ὛὝ-ὝὟ-ώᾀ-ᾴᾶ-ᾼι-ιῂ-ῄῆ-ῌῐ-ΐῖ-Ίῠ-Ῥῲ-ῴῶ-ῼ\u{200c}-\u{200d}‿-⁀⁔-⁔ⁱ-ⁱⁿ-ⁿₐ-ₜ\u{20d0}-\u{20f0}ℂ-ℂℇ-ℇℊ-ℓℕ-ℕℙ-ℝℤ-ℤΩ-Ωℨ-ℨK-ℭℯ-ℹℼ-ℿⅅ-ⅉⅎ-ⅎⅠ-ↈⒶ-ⓩⰀ-Ⱞⰰ-ⱞⱠ-ⳤⳫ-ⳳⴀ-ⴥⴧ-ⴧⴭ-ⴭⴰ-ⵧⵯ-ⵯ\u{2d7f}-ⶖⶠ-ⶦⶨ-ⶮⶰ-ⶶⶸ-ⶾⷀ-ⷆⷈ-ⷎⷐ-ⷖⷘ-ⷞ\u{2de0}-\u{2dff}ⸯ-ⸯ々-〇〡-\u{302f}〱-〵〸-〼ぁ-ゖ\u{3099}-\u{309a}ゝ-ゟァ-ヺー-ヿㄅ-ㄮㄱ-ㆎㆠ-ㆺㇰ-ㇿ㐀-䶵一-鿪ꀀ-ꒌꓐ-ꓽꔀ-ꘌꘐ-ꘫꙀ-\u{a672}\u{a674}-\u{a67d}ꙿ-\u{a6f1}ꜗ-ꜟꜢ-ꞈꞋ-ꞮꞰ-ꞷꟷ-ꠧꡀ-ꡳꢀ-\u{a8c5}꣐-꣙\u{a8e0}-ꣷꣻ-ꣻꣽ-ꣽ꤀-\u{a92d}ꤰ-\u{a953}ꥠ-ꥼ\u{a980}-\u{a9c0}ꧏ-꧙ꧠ-ꧾꨀ-\u{aa36}ꩀ-ꩍ꩐-꩙ꩠ-ꩶꩺ-ꫂꫛ-ꫝꫠ-ꫯꫲ-\u{aaf6}ꬁ-ꬆꬉ-ꬎꬑ-ꬖꬠ-ꬦꬨ-ꬮꬰ-ꭚꭜ-ꭥꭰ-ꯪ꯬-\u{abed}꯰-꯹가-힣ힰ-ퟆퟋ-ퟻ豈-舘並-龎ﬀ-ﬆﬓ-ﬗיִ-ﬨשׁ-זּטּ-לּמּ-מּנּ-סּףּ-פּצּ-ﮱﯓ-ﴽﵐ-ﶏﶒ-ﷇﷰ-ﷻ\u{fe00}-\u{fe0f}\u{fe20}-\u{fe2f}︳-︴﹍-﹏ﹰ-ﹴﹶ-ﻼ０-９Ａ-Ｚ＿-＿ａ-ｚｦ-ﾾￂ-ￇￊ-ￏￒ-ￗￚ-ￜ𐀀-𐀋𐀍-𐀦𐀨-𐀺𐀼-𐀽𐀿-𐁍𐁐-𐁝𐂀-𐃺𐅀-𐅴\u{101fd}-\u{101fd}𐊀-𐊜𐊠-𐋐\u{102e0}-\u{102e0}𐌀-𐌟𐌭-𐍊𐍐-\u{1037a}𐎀-𐎝𐎠-𐏃𐏈-𐏏𐏑-𐏕𐐀-𐒝𐒠-𐒩𐒰-𐓓𐓘-𐓻𐔀-𐔧𐔰-𐕣𐘀-𐜶𐝀-𐝕𐝠-𐝧𐠀-𐠅𐠈-𐠈𐠊-𐠵𐠷-𐠸𐠼-𐠼𐠿-𐡕𐡠-𐡶𐢀-𐢞𐣠-𐣲𐣴-𐣵𐤀-𐤕𐤠-𐤹𐦀-𐦷𐦾-𐦿𐨀-\u{10a03}\u{10a05}-\u{10a06}\u{10a0c}-𐨓𐨕-𐨗𐨙-𐨳\u{10a38}-\u{10a3a}\u{10a3f}-\u{10a3f}𐩠-𐩼𐪀-𐪜𐫀-𐫇𐫉-\u{10ae6}𐬀-𐬵𐭀-𐭕𐭠-𐭲𐮀-𐮑𐰀-𐱈𐲀-𐲲𐳀-𐳲𑀀-\u{11046}𑁦-𑁯\u{1107f}-\u{110ba}𑃐-𑃨𑃰-𑃹\u{11100}-\u{11134}𑄶-𑄿𑅐-\u{11173}𑅶-𑅶\u{11180}-𑇄\u{111ca}-\u{111cc}𑇐-𑇚𑇜-𑇜𑈀-𑈑𑈓-\u{11237}\u{1123e}-\u{1123e}𑊀-𑊆𑊈-𑊈𑊊-𑊍𑊏-𑊝𑊟-𑊨𑊰-\u{112ea}𑋰-𑋹\u{11300}-𑌃𑌅-𑌌𑌏-𑌐𑌓-𑌨𑌪-𑌰𑌲-𑌳𑌵-𑌹\u{1133c}-𑍄𑍇-𑍈𑍋-\u{1134d}𑍐-𑍐\u{11357}-\u{11357}𑍝-𑍣\u{11366}-\u{1136c}\u{11370}-\u{11374}𑐀-𑑊𑑐-𑑙𑒀-𑓅𑓇-𑓇𑓐-𑓙𑖀-\u{115b5}𑖸-\u{115c0}𑗘-\u{115dd}𑘀-\u{11640}𑙄-𑙄𑙐-𑙙𑚀-\u{116b7}𑛀-𑛉𑜀-𑜙\u{1171d}-\u{1172b}𑜰-𑜹𑢠-𑣩𑣿-𑣿𑨀-\u{11a3e}\u{11a47}-\u{11a47}𑩐-𑪃𑪆-\u{11a99}𑫀-𑫸𑰀-𑰈𑰊-\u{11c36}\u{11c38}-𑱀𑱐-𑱙𑱲-𑲏\u{11c92}-\u{11ca7}𑲩-\u{11cb6}𑴀-𑴆𑴈-𑴉𑴋-\u{11d36}\u{11d3a}-\u{11d3a}\u{11d3c}-\u{11d3d}\u{11d3f}-\u{11d47}𑵐-𑵙𒀀-𒎙𒐀-𒑮𒒀-𒕃𓀀-𓐮𔐀-𔙆𖠀-𖨸𖩀-𖩞𖩠-𖩩𖫐-𖫭\u{16af0}-\u{16af4}𖬀-\u{16b36}𖭀-𖭃𖭐-𖭙𖭣-𖭷𖭽-𖮏𖼀-𖽄𖽐-𖽾\u{16f8f}-𖾟𖿠-𖿡𗀀-𘟬𘠀-𘫲𛀀-𛄞𛅰-𛋻𛰀-𛱪𛱰-𛱼𛲀-𛲈𛲐-𛲙\u{1bc9d}-\u{1bc9e}\u{1d165}-\u{1d169}\u{1d16d}-\u{1d172}\u{1d17b}-\u{1d182}\u{1d185}-\u{1d18b}\u{1d1aa}-\u{1d1ad}\u{1d242}-\u{1d244}𝐀-𝑔𝑖-𝒜𝒞-𝒟𝒢-𝒢𝒥-𝒦𝒩-𝒬𝒮-𝒹𝒻-𝒻𝒽-𝓃𝓅-𝔅𝔇-𝔊𝔍-𝔔𝔖-𝔜𝔞-𝔹𝔻-𝔾𝕀-𝕄𝕆-𝕆𝕊-𝕐𝕒-𝚥𝚨-𝛀𝛂-𝛚𝛜-𝛺𝛼-𝜔𝜖-𝜴𝜶-𝝎𝝐-𝝮𝝰-𝞈𝞊-𝞨𝞪-𝟂𝟄-𝟋𝟎-𝟿\u{1da00}-\u{1da36}\u{1da3b}-\u{1da6c}\u{1da75}-\u{1da75}\u{1da84}-\u{1da84}\u{1da9b}-\u{1da9f}\u{1daa1}-\u{1daaf}\u{1e000}-\u{1e006}\u{1e008}-\u{1e018}\u{1e01b}-\u{1e021}\u{1e023}-\u{1e024}\u{1e026}-\u{1e02a}𞠀-𞣄\u{1e8d0}-\u{1e8d6}𞤀-\u{1e94a}𞥐-𞥙𞸀-𞸃𞸅-𞸟𞸡-𞸢𞸤-𞸤𞸧-𞸧𞸩-𞸲𞸴-𞸷𞸹-𞸹𞸻-𞸻𞹂-𞹂𞹇-𞹇𞹉-𞹉𞹋-𞹋𞹍-𞹏𞹑-𞹒𞹔-𞹔𞹗-𞹗𞹙-𞹙𞹛-𞹛𞹝-𞹝𞹟-𞹟𞹡-𞹢𞹤-𞹤𞹧-𞹪𞹬-𞹲𞹴-𞹷𞹹-𞹼𞹾-𞹾𞺀-𞺉𞺋-𞺛𞺡-𞺣𞺥-𞺩𞺫-𞺻🄰-🅉🅐-🅩🅰-🆉𠀀-𪛖𪜀-𫜴𫝀-𫠝𫠠-𬺡𬺰-𮯠丽-𪘀\u{e0100}-\u{e01ef}])+",
+                "^(?u:\\&)",
+                "^(?u:\\()",
+                "^(?u:\\))",
+                "^(?u:\\*)",
+                "^(?u:\\[)",
+                "^(?u:\\])",
+                "^(?u:wacky)",
+                "^(?u:wonky)",
+            ];
+            let __regex_set = __regex::RegexSet::new(__strs).unwrap();
+            let __regex_vec = vec![
+                __regex::Regex::new("^(?u:[0-9A-Z_-_a-zª-ªµ-µº-ºÀ-ÖØ-öø-ˁˆ-ˑˠ-ˤˬ-ˬˮ-ˮ\u{300}-ʹͶ-ͷͺ-ͽͿ-ͿΆ-ΆΈ-ΊΌ-ΌΎ-ΡΣ-ϵϷ-ҁ\u{483}-ԯԱ-Ֆՙ-ՙա-և\u{591}-\u{5bd}\u{5bf}-\u{5bf}\u{5c1}-\u{5c2}\u{5c4}-\u{5c5}\u{5c7}-\u{5c7}א-תװ-ײ\u{610}-\u{61a}ؠ-٩ٮ-ۓە-\u{6dc}\u{6df}-\u{6e8}\u{6ea}-ۼۿ-ۿܐ-\u{74a}ݍ-ޱ߀-ߵߺ-ߺࠀ-\u{82d}ࡀ-\u{85b}ࡠ-ࡪࢠ-ࢴࢶ-ࢽ\u{8d4}-\u{8e1}\u{8e3}-\u{963}०-९ॱ-ঃঅ-ঌএ-ঐও-নপ-রল-লশ-হ\u{9bc}-\u{9c4}ে-ৈো-ৎ\u{9d7}-\u{9d7}ড়-ঢ়য়-\u{9e3}০-ৱৼ-ৼ\u{a01}-ਃਅ-ਊਏ-ਐਓ-ਨਪ-ਰਲ-ਲ਼ਵ-ਸ਼ਸ-ਹ\u{a3c}-\u{a3c}ਾ-\u{a42}\u{a47}-\u{a48}\u{a4b}-\u{a4d}\u{a51}-\u{a51}ਖ਼-ੜਫ਼-ਫ਼੦-\u{a75}\u{a81}-ઃઅ-ઍએ-ઑઓ-નપ-રલ-ળવ-હ\u{abc}-\u{ac5}\u{ac7}-ૉો-\u{acd}ૐ-ૐૠ-\u{ae3}૦-૯ૹ-\u{aff}\u{b01}-ଃଅ-ଌଏ-ଐଓ-ନପ-ରଲ-ଳଵ-ହ\u{b3c}-\u{b44}େ-ୈୋ-\u{b4d}\u{b56}-\u{b57}ଡ଼-ଢ଼ୟ-\u{b63}୦-୯ୱ-ୱ\u{b82}-ஃஅ-ஊஎ-ஐஒ-கங-சஜ-ஜஞ-டண-தந-பம-ஹ\u{bbe}-ூெ-ைொ-\u{bcd}ௐ-ௐ\u{bd7}-\u{bd7}௦-௯\u{c00}-ఃఅ-ఌఎ-ఐఒ-నప-హఽ-ౄ\u{c46}-\u{c48}\u{c4a}-\u{c4d}\u{c55}-\u{c56}ౘ-ౚౠ-\u{c63}౦-౯ಀ-ಃಅ-ಌಎ-ಐಒ-ನಪ-ಳವ-ಹ\u{cbc}-ೄ\u{cc6}-\u{cc8}\u{cca}-\u{ccd}\u{cd5}-\u{cd6}ೞ-ೞೠ-\u{ce3}೦-೯ೱ-ೲ\u{d00}-ഃഅ-ഌഎ-ഐഒ-\u{d44}െ-ൈൊ-ൎൔ-\u{d57}ൟ-\u{d63}൦-൯ൺ-ൿං-ඃඅ-ඖක-නඳ-රල-ලව-ෆ\u{dca}-\u{dca}\u{dcf}-\u{dd4}\u{dd6}-\u{dd6}ෘ-\u{ddf}෦-෯ෲ-ෳก-\u{e3a}เ-\u{e4e}๐-๙ກ-ຂຄ-ຄງ-ຈຊ-ຊຍ-ຍດ-ທນ-ຟມ-ຣລ-ລວ-ວສ-ຫອ-\u{eb9}\u{ebb}-ຽເ-ໄໆ-ໆ\u{ec8}-\u{ecd}໐-໙ໜ-ໟༀ-ༀ\u{f18}-\u{f19}༠-༩\u{f35}-\u{f35}\u{f37}-\u{f37}\u{f39}-\u{f39}༾-ཇཉ-ཬ\u{f71}-\u{f84}\u{f86}-\u{f97}\u{f99}-\u{fbc}\u{fc6}-\u{fc6}က-၉ၐ-\u{109d}Ⴀ-ჅჇ-ჇჍ-Ⴭა-ჺჼ-ቈቊ-ቍቐ-ቖቘ-ቘቚ-ቝበ-ኈኊ-ኍነ-ኰኲ-ኵኸ-ኾዀ-ዀዂ-ዅወ-ዖዘ-ጐጒ-ጕጘ-ፚ\u{135d}-\u{135f}ᎀ-ᎏᎠ-Ᏽᏸ-ᏽᐁ-ᙬᙯ-ᙿᚁ-ᚚᚠ-ᛪᛮ-ᛸᜀ-ᜌᜎ-\u{1714}ᜠ-\u{1734}ᝀ-\u{1753}ᝠ-ᝬᝮ-ᝰ\u{1772}-\u{1773}ក-\u{17d3}ៗ-ៗៜ-\u{17dd}០-៩\u{180b}-\u{180d}᠐-᠙ᠠ-ᡷᢀ-ᢪᢰ-ᣵᤀ-ᤞ\u{1920}-ᤫᤰ-\u{193b}᥆-ᥭᥰ-ᥴᦀ-ᦫᦰ-ᧉ᧐-᧙ᨀ-\u{1a1b}ᨠ-\u{1a5e}\u{1a60}-\u{1a7c}\u{1a7f}-᪉᪐-᪙ᪧ-ᪧ\u{1ab0}-\u{1abe}\u{1b00}-ᭋ᭐-᭙\u{1b6b}-\u{1b73}\u{1b80}-\u{1bf3}ᰀ-\u{1c37}᱀-᱉ᱍ-ᱽᲀ-ᲈ\u{1cd0}-\u{1cd2}\u{1cd4}-\u{1cf9}ᴀ-\u{1df9}\u{1dfb}-ἕἘ-Ἕἠ-ὅὈ-Ὅὐ-ὗὙ-ὙὛ-ὛὝ-ὝὟ-ώᾀ-ᾴᾶ-ᾼι-ιῂ-ῄῆ-ῌῐ-ΐῖ-Ίῠ-Ῥῲ-ῴῶ-ῼ\u{200c}-\u{200d}‿-⁀⁔-⁔ⁱ-ⁱⁿ-ⁿₐ-ₜ\u{20d0}-\u{20f0}ℂ-ℂℇ-ℇℊ-ℓℕ-ℕℙ-ℝℤ-ℤΩ-Ωℨ-ℨK-ℭℯ-ℹℼ-ℿⅅ-ⅉⅎ-ⅎⅠ-ↈⒶ-ⓩⰀ-Ⱞⰰ-ⱞⱠ-ⳤⳫ-ⳳⴀ-ⴥⴧ-ⴧⴭ-ⴭⴰ-ⵧⵯ-ⵯ\u{2d7f}-ⶖⶠ-ⶦⶨ-ⶮⶰ-ⶶⶸ-ⶾⷀ-ⷆⷈ-ⷎⷐ-ⷖⷘ-ⷞ\u{2de0}-\u{2dff}ⸯ-ⸯ々-〇〡-\u{302f}〱-〵〸-〼ぁ-ゖ\u{3099}-\u{309a}ゝ-ゟァ-ヺー-ヿㄅ-ㄮㄱ-ㆎㆠ-ㆺㇰ-ㇿ㐀-䶵一-鿪ꀀ-ꒌꓐ-ꓽꔀ-ꘌꘐ-ꘫꙀ-\u{a672}\u{a674}-\u{a67d}ꙿ-\u{a6f1}ꜗ-ꜟꜢ-ꞈꞋ-ꞮꞰ-ꞷꟷ-ꠧꡀ-ꡳꢀ-\u{a8c5}꣐-꣙\u{a8e0}-ꣷꣻ-ꣻꣽ-ꣽ꤀-\u{a92d}ꤰ-\u{a953}ꥠ-ꥼ\u{a980}-\u{a9c0}ꧏ-꧙ꧠ-ꧾꨀ-\u{aa36}ꩀ-ꩍ꩐-꩙ꩠ-ꩶꩺ-ꫂꫛ-ꫝꫠ-ꫯꫲ-\u{aaf6}ꬁ-ꬆꬉ-ꬎꬑ-ꬖꬠ-ꬦꬨ-ꬮꬰ-ꭚꭜ-ꭥꭰ-ꯪ꯬-\u{abed}꯰-꯹가-힣ힰ-ퟆퟋ-ퟻ豈-舘並-龎ﬀ-ﬆﬓ-ﬗיִ-ﬨשׁ-זּטּ-לּמּ-מּנּ-סּףּ-פּצּ-ﮱﯓ-ﴽﵐ-ﶏﶒ-ﷇﷰ-ﷻ\u{fe00}-\u{fe0f}\u{fe20}-\u{fe2f}︳-︴﹍-﹏ﹰ-ﹴﹶ-ﻼ０-９Ａ-Ｚ＿-＿ａ-ｚｦ-ﾾￂ-ￇￊ-ￏￒ-ￗￚ-ￜ𐀀-𐀋𐀍-𐀦𐀨-𐀺𐀼-𐀽𐀿-𐁍𐁐-𐁝𐂀-𐃺𐅀-𐅴\u{101fd}-\u{101fd}𐊀-𐊜𐊠-𐋐\u{102e0}-\u{102e0}𐌀-𐌟𐌭-𐍊𐍐-\u{1037a}𐎀-𐎝𐎠-𐏃𐏈-𐏏𐏑-𐏕𐐀-𐒝𐒠-𐒩𐒰-𐓓𐓘-𐓻𐔀-𐔧𐔰-𐕣𐘀-𐜶𐝀-𐝕𐝠-𐝧𐠀-𐠅𐠈-𐠈𐠊-𐠵𐠷-𐠸𐠼-𐠼𐠿-𐡕𐡠-𐡶𐢀-𐢞𐣠-𐣲𐣴-𐣵𐤀-𐤕𐤠-𐤹𐦀-𐦷𐦾-𐦿𐨀-\u{10a03}\u{10a05}-\u{10a06}\u{10a0c}-𐨓𐨕-𐨗𐨙-𐨳\u{10a38}-\u{10a3a}\u{10a3f}-\u{10a3f}𐩠-𐩼𐪀-𐪜𐫀-𐫇𐫉-\u{10ae6}𐬀-𐬵𐭀-𐭕𐭠-𐭲𐮀-𐮑𐰀-𐱈𐲀-𐲲𐳀-𐳲𑀀-\u{11046}𑁦-𑁯\u{1107f}-\u{110ba}𑃐-𑃨𑃰-𑃹\u{11100}-\u{11134}𑄶-𑄿𑅐-\u{11173}𑅶-𑅶\u{11180}-𑇄\u{111ca}-\u{111cc}𑇐-𑇚𑇜-𑇜𑈀-𑈑𑈓-\u{11237}\u{1123e}-\u{1123e}𑊀-𑊆𑊈-𑊈𑊊-𑊍𑊏-𑊝𑊟-𑊨𑊰-\u{112ea}𑋰-𑋹\u{11300}-𑌃𑌅-𑌌𑌏-𑌐𑌓-𑌨𑌪-𑌰𑌲-𑌳𑌵-𑌹\u{1133c}-𑍄𑍇-𑍈𑍋-\u{1134d}𑍐-𑍐\u{11357}-\u{11357}𑍝-𑍣\u{11366}-\u{1136c}\u{11370}-\u{11374}𑐀-𑑊𑑐-𑑙𑒀-𑓅𑓇-𑓇𑓐-𑓙𑖀-\u{115b5}𑖸-\u{115c0}𑗘-\u{115dd}𑘀-\u{11640}𑙄-𑙄𑙐-𑙙𑚀-\u{116b7}𑛀-𑛉𑜀-𑜙\u{1171d}-\u{1172b}𑜰-𑜹𑢠-𑣩𑣿-𑣿𑨀-\u{11a3e}\u{11a47}-\u{11a47}𑩐-𑪃𑪆-\u{11a99}𑫀-𑫸𑰀-𑰈𑰊-\u{11c36}\u{11c38}-𑱀𑱐-𑱙𑱲-𑲏\u{11c92}-\u{11ca7}𑲩-\u{11cb6}𑴀-𑴆𑴈-𑴉𑴋-\u{11d36}\u{11d3a}-\u{11d3a}\u{11d3c}-\u{11d3d}\u{11d3f}-\u{11d47}𑵐-𑵙𒀀-𒎙𒐀-𒑮𒒀-𒕃𓀀-𓐮𔐀-𔙆𖠀-𖨸𖩀-𖩞𖩠-𖩩𖫐-𖫭\u{16af0}-\u{16af4}𖬀-\u{16b36}𖭀-𖭃𖭐-𖭙𖭣-𖭷𖭽-𖮏𖼀-𖽄𖽐-𖽾\u{16f8f}-𖾟𖿠-𖿡𗀀-𘟬𘠀-𘫲𛀀-𛄞𛅰-𛋻𛰀-𛱪𛱰-𛱼𛲀-𛲈𛲐-𛲙\u{1bc9d}-\u{1bc9e}\u{1d165}-\u{1d169}\u{1d16d}-\u{1d172}\u{1d17b}-\u{1d182}\u{1d185}-\u{1d18b}\u{1d1aa}-\u{1d1ad}\u{1d242}-\u{1d244}𝐀-𝑔𝑖-𝒜𝒞-𝒟𝒢-𝒢𝒥-𝒦𝒩-𝒬𝒮-𝒹𝒻-𝒻𝒽-𝓃𝓅-𝔅𝔇-𝔊𝔍-𝔔𝔖-𝔜𝔞-𝔹𝔻-𝔾𝕀-𝕄𝕆-𝕆𝕊-𝕐𝕒-𝚥𝚨-𝛀𝛂-𝛚𝛜-𝛺𝛼-𝜔𝜖-𝜴𝜶-𝝎𝝐-𝝮𝝰-𝞈𝞊-𝞨𝞪-𝟂𝟄-𝟋𝟎-𝟿\u{1da00}-\u{1da36}\u{1da3b}-\u{1da6c}\u{1da75}-\u{1da75}\u{1da84}-\u{1da84}\u{1da9b}-\u{1da9f}\u{1daa1}-\u{1daaf}\u{1e000}-\u{1e006}\u{1e008}-\u{1e018}\u{1e01b}-\u{1e021}\u{1e023}-\u{1e024}\u{1e026}-\u{1e02a}𞠀-𞣄\u{1e8d0}-\u{1e8d6}𞤀-\u{1e94a}𞥐-𞥙𞸀-𞸃𞸅-𞸟𞸡-𞸢𞸤-𞸤𞸧-𞸧𞸩-𞸲𞸴-𞸷𞸹-𞸹𞸻-𞸻𞹂-𞹂𞹇-𞹇𞹉-𞹉𞹋-𞹋𞹍-𞹏𞹑-𞹒𞹔-𞹔𞹗-𞹗𞹙-𞹙𞹛-𞹛𞹝-𞹝𞹟-𞹟𞹡-𞹢𞹤-𞹤𞹧-𞹪𞹬-𞹲𞹴-𞹷𞹹-𞹼𞹾-𞹾𞺀-𞺉𞺋-𞺛𞺡-𞺣𞺥-𞺩𞺫-𞺻🄰-🅉🅐-🅩🅰-🆉𠀀-𪛖𪜀-𫜴𫝀-𫠝𫠠-𬺡𬺰-𮯠丽-𪘀\u{e0100}-\u{e01ef}])+").unwrap(),
+                __regex::Regex::new("^(?u:\\&)").unwrap(),
+                __regex::Regex::new("^(?u:\\()").unwrap(),
+                __regex::Regex::new("^(?u:\\))").unwrap(),
+                __regex::Regex::new("^(?u:\\*)").unwrap(),
+                __regex::Regex::new("^(?u:\\[)").unwrap(),
+                __regex::Regex::new("^(?u:\\])").unwrap(),
+                __regex::Regex::new("^(?u:wacky)").unwrap(),
+                __regex::Regex::new("^(?u:wonky)").unwrap(),
+            ];
+            __Matcher {
+                text: s,
+                consumed: 0,
+                regex_set: __regex_set,
+                regex_vec: __regex_vec,
+            }
+        }
+    }
+
+    impl<'input> Iterator for __Matcher<'input> {
+        type Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize,Token<'input>,&'static str>>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let __text = self.text.trim_left();
+            let __whitespace = self.text.len() - __text.len();
+            let __start_offset = self.consumed + __whitespace;
+            if __text.is_empty() {
+                self.text = __text;
+                self.consumed = __start_offset;
+                None
+            } else {
+                let __matches = self.regex_set.matches(__text);
+                if !__matches.matched_any() {
+                    Some(Err(__lalrpop_util::ParseError::InvalidToken {
+                        location: __start_offset,
+                    }))
+                } else {
+                    let mut __longest_match = 0;
+                    let mut __index = 0;
+                    for __i in 0 .. 9 {
+                        if __matches.matched(__i) {
+                            let __match = self.regex_vec[__i].find(__text).unwrap();
+                            let __len = __match.end();
+                            if __len >= __longest_match {
+                                __longest_match = __len;
+                                __index = __i;
+                            }
+                        }
+                    }
+                    let __result = &__text[..__longest_match];
+                    let __remaining = &__text[__longest_match..];
+                    let __end_offset = __start_offset + __longest_match;
+                    self.text = __remaining;
+                    self.consumed = __end_offset;
+                    Some(Ok((__start_offset, Token(__index, __result), __end_offset)))
+                }
+            }
+        }
+    }
+}
+pub use self::__intern_token::Token;
+
+#[allow(unused_variables)]
+fn __action0<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Box<Expr<'input>>, usize),
+) -> Box<Expr<'input>>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, usize, usize),
+    (_, __1, _): (usize, Box<Expr<'input>>, usize),
+    (_, __2, _): (usize, Box<Expr<'input>>, usize),
+    (_, __3, _): (usize, Box<Expr<'input>>, usize),
+    (_, __4, _): (usize, usize, usize),
+) -> Box<Expr<'input>>
+{
+    //@ src/loc_issue_90.lalrpop:9:52 (1 lines)
+    Box::new(Expr::Adjacent(__0, __1, __2, __3, __4))
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, usize, usize),
+    (_, __1, _): (usize, Box<Expr<'input>>, usize),
+    (_, __2, _): (usize, usize, usize),
+) -> Box<Expr<'input>>
+{
+    //@ src/loc_issue_90.lalrpop:10:26 (1 lines)
+    Box::new(Expr::Upgrade(__0, __1, __2))
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, __1, _): (usize, usize, usize),
+) -> Box<Expr<'input>>
+{
+    //@ src/loc_issue_90.lalrpop:14:22 (1 lines)
+    Box::new(Expr::Mul(__0, __1))
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, __1, _): (usize, Box<Expr<'input>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, __2, _): (usize, usize, usize),
+) -> Box<Expr<'input>>
+{
+    //@ src/loc_issue_90.lalrpop:18:40 (1 lines)
+    Box::new(Expr::Paren(__0, __1, __2))
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, usize, usize),
+    (_, __1, _): (usize, &'input str, usize),
+    (_, __2, _): (usize, usize, usize),
+) -> Box<Expr<'input>>
+{
+    //@ src/loc_issue_90.lalrpop:19:21 (1 lines)
+    Box::new(Expr::Ident(__0, __1, __2))
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, __1, _): (usize, Box<Expr<'input>>, usize),
+    (_, __2, _): (usize, Box<Expr<'input>>, usize),
+    (_, __3, _): (usize, usize, usize),
+) -> Box<Expr<'input>>
+{
+    //@ src/loc_issue_90.lalrpop:20:44 (1 lines)
+    Box::new(Expr::Ref(__0, __1, __2, __3))
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, Box<Expr<'input>>, usize),
+) -> Box<Expr<'input>>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Box<Expr<'input>>, usize),
+) -> Box<Expr<'input>>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action9<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, usize, usize),
+    (_, __1, _): (usize, usize, usize),
+) -> Box<Expr<'input>>
+{
+    //@ src/loc_issue_90.lalrpop:27:14 (1 lines)
+    Box::new(Expr::Maybe(__0, __1))
+}
+
+#[allow(unused_variables)]
+fn __action10<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, __1, _): (usize, usize, usize),
+) -> Box<Expr<'input>>
+{
+    //@ src/loc_issue_90.lalrpop:28:26 (1 lines)
+    Box::new(Expr::Maybe(__0, __1))
+}
+
+#[allow(unused_variables)]
+fn __action11<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, __1, _): (usize, usize, usize),
+) -> Box<Expr<'input>>
+{
+    //@ src/loc_issue_90.lalrpop:33:26 (1 lines)
+    Box::new(Expr::Wacky(__0, __1))
+}
+
+#[allow(unused_variables)]
+fn __action12<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, usize, usize),
+    (_, __1, _): (usize, usize, usize),
+) -> Box<Expr<'input>>
+{
+    //@ src/loc_issue_90.lalrpop:38:18 (1 lines)
+    Box::new(Expr::Wonky(__0, __1))
+}
+
+#[allow(unused_variables)]
+fn __action13<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
+{
+    __lookbehind.clone()
+}
+
+#[allow(unused_variables)]
+fn __action14<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
+{
+    __lookahead.clone()
+}
+
+#[allow(unused_variables)]
+fn __action15<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Expr<'input>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, usize, usize),
+) -> Box<Expr<'input>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action14(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action4(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action16<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, usize, usize),
+) -> Box<Expr<'input>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action14(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action5(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action17<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Expr<'input>>, usize),
+    __2: (usize, Box<Expr<'input>>, usize),
+    __3: (usize, usize, usize),
+) -> Box<Expr<'input>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action14(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action6(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action18<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Box<Expr<'input>>, usize),
+    __1: (usize, Box<Expr<'input>>, usize),
+    __2: (usize, Box<Expr<'input>>, usize),
+    __3: (usize, usize, usize),
+) -> Box<Expr<'input>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action14(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action1(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action19<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Box<Expr<'input>>, usize),
+    __1: (usize, usize, usize),
+) -> Box<Expr<'input>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action14(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action2(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action20<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, usize, usize),
+) -> Box<Expr<'input>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action14(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action3(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action21<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, usize, usize),
+) -> Box<Expr<'input>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action14(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action9(
+        input,
+        __temp0,
+        __0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action22<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, usize, usize),
+) -> Box<Expr<'input>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action14(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action10(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action23<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+) -> Box<Expr<'input>>
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action14(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action11(
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action24<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, usize, usize),
+) -> Box<Expr<'input>>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action14(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action12(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action25<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Expr<'input>>, usize),
+    __2: (usize, &'input str, usize),
+) -> Box<Expr<'input>>
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action13(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action15(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action26<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+) -> Box<Expr<'input>>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action13(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action16(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action27<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Expr<'input>>, usize),
+    __2: (usize, Box<Expr<'input>>, usize),
+) -> Box<Expr<'input>>
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action13(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action17(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action28<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Box<Expr<'input>>, usize),
+    __1: (usize, Box<Expr<'input>>, usize),
+    __2: (usize, Box<Expr<'input>>, usize),
+) -> Box<Expr<'input>>
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action13(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action18(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action29<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Box<Expr<'input>>, usize),
+) -> Box<Expr<'input>>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action13(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action19(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action30<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+) -> Box<Expr<'input>>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action13(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action20(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action31<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Box<Expr<'input>>
+{
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action13(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action21(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action32<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+) -> Box<Expr<'input>>
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action13(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action22(
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action33<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+) -> Box<Expr<'input>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action13(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action23(
+        input,
+        __temp0,
+        __0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action34<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Box<Expr<'input>>
+{
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action13(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action24(
+        input,
+        __temp0,
+    )
+}
+
+pub trait __ToTriple<'input, > {
+    type Error;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),Self::Error>;
+}
+
+impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize) {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        Ok(value)
+    }
+}
+impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        value
+    }
+}
@@ -1,9 +1,11 @@
 extern crate diff;
 extern crate lalrpop_util;
+extern crate serde;
+extern crate serde_json;
 
 use std::cell::RefCell;
 
-use lalrpop_util::{ErrorRecovery, ParseError};
+use lalrpop_util::{ErrorRecovery, LineCol, ParseError};
 
 use util::tok::Tok;
 
@@ -62,6 +64,9 @@ mod lifetime_tok;
 /// library for lifetime_tok test
 mod lifetime_tok_lib;
 
+/// test that exercises a token type whose lifetime is not declared
+mod borrowed_tok;
+
 /// test that exercises locations and spans
 mod loc;
 
@@ -88,6 +93,18 @@ mod error_recovery_lalr_loop;
 mod error_recovery_lock_in;
 mod error_recovery_span;
 
+/// test error recovery declared with `#[recover]`
+mod error_recovery_rules;
+
+/// test error recovery that skips `#[recover_delimiters]` groups
+mod error_recovery_delimiters;
+
+/// test error repair enabled with `#![repair]`
+mod error_repair;
+
+/// test error repair with an extern token enum.
+mod error_repair_extern;
+
 /// test for inlining expansion issue #55
 mod issue_55;
 
@@ -103,16 +120,203 @@ mod partial_parse;
 /// regression test for issue #278.
 mod error_issue_278;
 
+/// test the Earley backend.
+mod earley;
+
+/// test `#[dprec]` and `#[merge]` in the Earley backend.
+mod earley_disambiguation;
+
+/// test `Configuration::fast_scanning`, which `build.rs` enables for
+/// this grammar.
+mod scan_tok;
+
+/// test operators declared in a `#[pratt]` nonterminal.
+mod pratt;
+
+/// test guards on alternatives.
+mod guard;
+
+/// test terminal categories.
+mod category;
+
+/// test guards on the patterns of an extern token enum.
+mod token_guard;
+
+/// test token adapters in an extern section.
+mod token_adapter;
+
+/// test a `#![token_stream]` grammar, which reads token trees.
+mod token_stream;
+
+/// library for token_adapter test
+mod token_adapter_lib;
+
+/// test `#[cfg(...)]` annotations, with `feature = "ext-syntax"`
+/// enabled by the build script.
+mod grammar_cfg;
+
+/// test `#[since]` annotations and the version parameter they add.
+mod versioned;
+
+/// test a grammar that extends another, `dialect_base`.
+mod dialect_base;
+mod dialect;
+
+/// test two grammars that import the token module `shared_tokens`.
+mod shared_tokens_expr;
+mod shared_tokens_program;
+
+/// test `#[boxed]` nonterminals, which build the AST in `boxed_ast`.
+mod boxed;
+mod boxed_ast;
+
+/// test `#[unescape]` match entries.
+mod unescape;
+
+/// test `#[normalize]` on `match` and `extern` sections.
+mod normalize;
+mod normalize_extern;
+
+/// test `Configuration::panic_free`, which `build.rs` enables for
+/// this grammar.
+mod panic_free;
+
+/// test `Configuration::fixed_stack`, which `build.rs` enables for
+/// this grammar.
+mod fixed_stack;
+
+/// test that a parser with a fixed-size stack builds without `std`
+/// and parses without allocating; `build.rs` enables
+/// `Configuration::fixed_stack` for this grammar.
+mod no_std_stack;
+
+/// test `Configuration::emit_c_abi`, which `build.rs` enables for this
+/// grammar.
+mod c_abi;
+
+/// test `Configuration::prefix`, which `build.rs` uses for this
+/// grammar.
+mod custom_prefix;
+
+/// test `Configuration::table_link_section`, which `build.rs` uses for
+/// this grammar.
+mod link_section;
+
+/// test `Configuration::emit_profile` and `Configuration::use_profile`,
+/// which `build.rs` enables for these grammars.
+mod profile_counts;
+mod profile_layout;
+
+/// test `Configuration::emit_parsers_module`, with which `build.rs`
+/// writes the `mod.rs` of this directory.
+mod parsers_module;
+
+/// test `Configuration::memoize_actions`, which `build.rs` enables for
+/// this grammar.
+mod memoized;
+
+/// test `Configuration::token_arrays`, which `build.rs` enables for
+/// this grammar.
+mod token_array;
+
+/// test `Configuration::emit_debug_assertions`, which `build.rs`
+/// enables for this grammar.
+mod debug_assertions;
+
+/// test `Configuration::profile_reductions`, which `build.rs` enables
+/// for this grammar.
+mod profiled;
+
+/// test `Configuration::max_depth`, which `build.rs` enables for this
+/// grammar.
+mod max_depth;
+
+/// test `Configuration::max_reductions`, which `build.rs` enables for
+/// this grammar.
+mod max_reductions;
+
+/// test `Configuration::cfg` and `Configuration::parser_cfg`, which
+/// `build.rs` uses for this grammar.
+mod parser_cfg;
+
+/// test `Configuration::derive_serde`, which `build.rs` enables for
+/// this grammar.
+mod serde_tok;
+
+/// test `Configuration::eliminate_unit_rules`, which `build.rs` enables
+/// for `unit_rules` but not for `unit_rules_kept`, the same grammar.
+mod unit_rules;
+mod unit_rules_kept;
+
+/// test `Configuration::conflicts_as_warnings`, which `build.rs`
+/// enables for this grammar, kept out of `src` as it has conflicts.
+#[path = "../conflicts/conflicts_as_warnings.rs"]
+mod conflicts_as_warnings;
+
+/// test `#[max_nesting]` on nonterminals.
+mod max_nesting;
+
+/// test canceling a parse from one of its actions.
+mod cancel;
+
+/// test `#[unparse]`, which writes the AST in `unparse_ast` back as
+/// text.
+mod unparse;
+mod unparse_ast;
+
+/// test `#[ast]`, which declares the types of nonterminals, with the
+/// span of every node.
+mod ast;
+
+/// test `#[lookahead = "2"]`, which resolves conflicts with the token
+/// after the next one.
+mod two_tokens;
+
+/// test `#[reserved]` keywords.
+mod reserved;
+
+/// test `#[contextual]` keywords.
+mod contextual;
+
+/// test the built-in `CommaTrailing<T>`.
+mod comma_trailing;
+
+/// test `#[newlines]`.
+mod newlines;
+
+/// test a `Location` type declared for the built-in tokenizer.
+mod line_col;
+
 // Check that error recovery (which requires cloneable tokens) is not created if it is not used
 #[allow(unused)]
 mod no_clone_tok;
 
 mod util;
 
+#[global_allocator]
+static ALLOCATOR: util::count_alloc::CountingAllocator = util::count_alloc::CountingAllocator;
+
 /// This constant is here so that some of the generator parsers can
 /// refer to it in order to test `super::` handling in action code.
 const ZERO: i32 = 0;
 
+/// The hook of the backquoted strings of `unescape`.
+fn backquoted_text(text: &str) -> std::borrow::Cow<str> {
+    std::borrow::Cow::Borrowed(&text[1..text.len() - 1])
+}
+
+/// The normalizers of `normalize` and `normalize_extern`.
+fn fold_case(text: &str) -> String {
+    text.to_lowercase()
+}
+
+fn whole_fractions(tok: Tok) -> Tok {
+    match tok {
+        Tok::Fraction(n, 1) => Tok::Num(n),
+        tok => tok,
+    }
+}
+
 #[test]
 fn expr_test1() {
     util::test(|v| expr::parse_Expr(1, v), "22 - 3", 22 - 3);
@@ -148,6 +352,49 @@ fn expr_intern_tok_test2() {
     assert_eq!(expr_intern_tok::parse_Expr(1, "22 - (3 - 5) - 13").unwrap(), 22 - (3 - 5) - 13);
 }
 
+#[test]
+fn expr_intern_tok_test_err_after_default_reductions() {
+    // the `3` is only found to be an error after `2` has been
+    // reduced (without looking at the `3`) to a `Num` and beyond; we
+    // should still report what could follow the `2`
+    match expr_intern_tok::parse_Expr(1, "(1 + 2 3") {
+        //                                012345678
+        Err(ParseError::UnrecognizedToken { token: Some((7, _, 8)), expected }) => {
+            assert_eq!(expected, vec!["\")\"", "\"*\"", "\"+\"", "\"-\"", "\"/\""]);
+        }
+        r => {
+            panic!("invalid result {:?}", r);
+        }
+    }
+}
+
+#[test]
+fn expr_intern_tok_test_extra_token() {
+    // `22 - 3` is a complete expression, so the `4` is extra
+    match expr_intern_tok::parse_Expr(1, "22 - 3 4") {
+        //                                01234567
+        Err(ParseError::ExtraToken { token: (7, _, 8), expected }) => {
+            assert_eq!(expected, vec!["\")\"", "\"*\"", "\"+\"", "\"-\"", "\"/\""]);
+        }
+        r => {
+            panic!("invalid result {:?}", r);
+        }
+    }
+}
+
+#[test]
+fn expr_intern_tok_test_unrecognized_eof() {
+    match expr_intern_tok::parse_Expr(1, "(1 + 2") {
+        //                                0123456
+        Err(ParseError::UnrecognizedEof { location: 6, expected }) => {
+            assert_eq!(expected, vec!["\")\"", "\"+\"", "\"-\""]);
+        }
+        r => {
+            panic!("invalid result {:?}", r);
+        }
+    }
+}
+
 #[test]
 fn expr_intern_tok_test_err() {
     match expr_intern_tok::parse_Expr(1, "22 - (3 - 5) - X") {
@@ -204,6 +451,16 @@ fn expr_lifetime_tok1() {
     assert_eq!(tree, vec!["x"]);
 }
 
+#[test]
+fn borrowed_tok_implicit_lifetime() {
+    let input = String::from("a b c");
+    let tokens = lifetime_tok_lib::lt_tokenize(&input);
+    let (first, rest) = borrowed_tok::parse_Words(tokens).unwrap();
+    assert_eq!((first, rest), ("a", vec!["b", "c"]));
+    // the words are slices of the input, not copies
+    assert_eq!(first.as_ptr(), input.as_ptr());
+}
+
 #[test]
 fn expr_lalr_test1() {
     util::test(|v| expr_lalr::parse_Expr(1, v), "22 - 3", 22 - 3);
@@ -259,6 +516,102 @@ fn sub_table_test1() {
     util::test(sub_table::parse_S, "22 - 3", 22 - 3);
 }
 
+#[test]
+fn sub_table_different_token_iterators() {
+    // the parse loop is shared across token iterator types; make sure
+    // that feeding the same parser from different iterators works
+    let tokens: Vec<Tok> = util::tok::tokenize("22 - (3 - 5)")
+        .into_iter()
+        .map(|(_, tok, _)| tok)
+        .collect();
+    assert_eq!(sub_table::parse_S(tokens.clone()).unwrap(), 24);
+    assert_eq!(sub_table::parse_S(tokens.iter().cloned()).unwrap(), 24);
+    assert_eq!(sub_table::parse_S(tokens.into_iter().map(Ok::<Tok, &'static str>)).unwrap(), 24);
+}
+
+#[test]
+fn sub_table_parser_reuse() {
+    // the same parser object can be used for many parses, including
+    // after a parse error has left its stacks in a dirty state
+    let mut parser = sub_table::SParser::new();
+    let tokens = |input: &str| -> Vec<Tok> {
+        util::tok::tokenize(input).into_iter().map(|(_, tok, _)| tok).collect()
+    };
+    assert_eq!(parser.parse_in_place(tokens("22 - 3")).unwrap(), 19);
+    assert!(parser.parse_in_place(tokens("22 - (3")).is_err());
+    assert_eq!(parser.parse_in_place(tokens("22 - (3 - 5)")).unwrap(), 24);
+}
+
+#[test]
+fn sub_table_parser_cancel() {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    let flag = Arc::new(AtomicBool::new(false));
+    let mut parser = sub_table::SParser::new();
+    parser.set_cancel_flag(flag.clone());
+    let tokens: Vec<Tok> = util::tok::tokenize("22 - 3")
+        .into_iter()
+        .map(|(_, tok, _)| tok)
+        .collect();
+    assert_eq!(parser.parse_in_place(tokens.clone()).unwrap(), 19);
+
+    flag.store(true, Ordering::Relaxed);
+    match parser.parse_in_place(tokens) {
+        Err(ParseError::Canceled { location: () }) => { /* OK! */ }
+        r => panic!("unexpected result {:?}", r),
+    }
+}
+
+#[test]
+fn cancel_mid_parse() {
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicBool;
+
+    let flag = Arc::new(AtomicBool::new(false));
+    let mut parser = cancel::SumParser::new();
+    parser.set_cancel_flag(flag.clone());
+    assert_eq!(parser.parse_in_place(&flag, "1 + 2").unwrap(), 3);
+
+    // the flag is set while reducing `0`, with no token left to
+    // shift, so the parse stops before reducing `Sum`
+    match parser.parse_in_place(&flag, "1 + 0") {
+        Err(ParseError::Canceled { location: 5 }) => { /* OK! */ }
+        r => panic!("unexpected result {:?}", r),
+    }
+}
+
+#[test]
+fn cancel_mid_parse_free_fn() {
+    use std::sync::atomic::AtomicBool;
+
+    let flag = AtomicBool::new(false);
+    assert_eq!(cancel::parse_Sum_cancelable(&flag, "1 + 2", &flag).unwrap(), 3);
+    match cancel::parse_Sum_cancelable(&flag, "1 + 0", &flag) {
+        Err(ParseError::Canceled { location: 5 }) => { /* OK! */ }
+        r => panic!("unexpected result {:?}", r),
+    }
+
+    // `parse_Sum` has no flag to check
+    let flag = AtomicBool::new(false);
+    assert_eq!(cancel::parse_Sum(&flag, "1 + 0").unwrap(), 1);
+}
+
+#[test]
+fn expr_intern_tok_parser_reuse() {
+    // the parser object does not borrow from the input, so it can
+    // outlive the strings that it parses
+    let mut parser = expr_intern_tok::ExprParser::new();
+    {
+        let input = String::from("22 - (3 - 5) - 13");
+        assert_eq!(parser.parse_in_place(1, &input).unwrap(), 22 - (3 - 5) - 13);
+    }
+    {
+        let input = String::from("22 - 3");
+        assert_eq!(parser.parse_in_place(1, &input).unwrap(), 22 - 3);
+    }
+}
+
 #[test]
 fn expr_arena_test1() {
     use expr_arena_ast::*;
@@ -308,6 +661,20 @@ fn expr_generic_test1() {
     assert_eq!(expected, actual);
 }
 
+#[test]
+fn expr_generic_parser_reuse() {
+    // the symbols hold values of type `F`, but the parser struct only
+    // keeps their buffer, so it is generic over neither `F` nor the
+    // lifetime of the input
+    let mut parser = expr_generic::ExprParser::new();
+    {
+        let input = String::from("22 * 3 - 6");
+        assert_eq!(parser.parse_in_place::<i32>(&input).unwrap(), 22 * 3 - 6);
+    }
+    assert!(parser.parse_in_place::<i32>("22 * (3").is_err());
+    assert_eq!(parser.parse_in_place::<i32>("(22 - 3) * 2").unwrap(), 38);
+}
+
 #[test]
 fn intern_tok_test1() {
     let expected = vec![(0, 0), // spans of `+` characters, measured in bytes
@@ -364,11 +731,12 @@ fn error_recovery_eof() {
 
     assert_eq!(errors.borrow().len(), 1);
     assert_eq!(errors.borrow()[0], ErrorRecovery {
-        error: ParseError::UnrecognizedToken {
-            token: None,
+        error: ParseError::UnrecognizedEof {
+            location: (),
             expected: vec!["\"-\"".to_string()],
         },
         dropped_tokens: vec![],
+        state: 7,
     });
 }
 
@@ -377,8 +745,8 @@ fn error_recovery_eof_without_recovery() {
     let errors = RefCell::new(vec![]);
     let tokens = util::tok::tokenize("-").into_iter().map(|t| t.1);
     let result = error_recovery::parse_Item(&errors, tokens);
-    assert_eq!(result, Err(ParseError::UnrecognizedToken {
-        token: None,
+    assert_eq!(result, Err(ParseError::UnrecognizedEof {
+        location: (),
         expected: vec!["\"-\"".to_string()],
     }));
 }
@@ -395,6 +763,7 @@ fn error_recovery_extra_token() {
             expected: vec!["\")\"".to_string()],
         },
         dropped_tokens: vec![((), Tok::Plus, ())],
+        state: 2,
     });
 }
 
@@ -410,6 +779,7 @@ fn error_recovery_dont_drop_unrecognized_token() {
             expected: vec!["\"-\"".to_string()],
         },
         dropped_tokens: vec![],
+        state: 7,
     });
 }
 
@@ -425,6 +795,7 @@ fn error_recovery_multiple_extra_tokens() {
             expected: vec!["\")\"".to_string()],
         },
         dropped_tokens: vec![((), Tok::Plus, ()), ((), Tok::Plus, ())],
+        state: 2,
     });
 }
 
@@ -463,6 +834,7 @@ fn error_recovery_issue_240() {
             expected: vec!["\")\"".to_string()],
         },
         dropped_tokens: vec![(6, Tok::Div, 7)],
+        state: 0,
     }]);
 }
 
@@ -510,7 +882,7 @@ fn error_recovery_lock_in() {
     match util::test_err_gen(|v| error_recovery_lock_in::parse_A(&mut errors, v), "(1/22") {
         Ok(()) => {
             assert_eq!(errors.len(), 1); // should not drop any tokens
-            let (l, ErrorRecovery { error: _, dropped_tokens }, r) = errors.pop().unwrap();
+            let (l, ErrorRecovery { dropped_tokens, .. }, r) = errors.pop().unwrap();
             assert_eq!((l .. r), (0 .. 3)); // span should cover the `(1` but not the `/`
             assert_eq!(dropped_tokens, vec![]);
         }
@@ -721,3 +1093,775 @@ fn error_issue_278() {
         }
     }
 }
+
+#[test]
+fn scan_tok_test() {
+    let text = "fn fnord  x_1 \t\n3.25 \"a long string literal, with é\" \"\" _";
+    assert_eq!(scan_tok::parse_Items(text).unwrap(),
+               vec!["fn", "fnord", "x_1", "3.25", "\"a long string literal, with é\"", "\"\"", "_"]);
+    assert!(scan_tok::parse_Items("\"unterminated").is_err());
+}
+
+#[test]
+fn earley_ambiguous() {
+    assert_eq!(earley::parse_Expr("10 - 3 - 2").unwrap(), 5);
+    assert_eq!(earley::parse_Expr("7").unwrap(), 7);
+}
+
+#[test]
+fn earley_all_derivations() {
+    assert_eq!(earley::parse_Expr_all("10 - 3 - 2").unwrap(), vec![5, 9]);
+    assert_eq!(earley::parse_Expr_all("7").unwrap(), vec![7]);
+    assert!(earley::parse_Expr_all("7 -").is_err());
+}
+
+#[test]
+fn earley_lookahead() {
+    assert_eq!(earley::parse_Choice("a x y").unwrap(), "a");
+    assert_eq!(earley::parse_Choice("a x z").unwrap(), "b");
+}
+
+#[test]
+fn earley_empty_production_span() {
+    // as in LR parsers, an empty production at the start of the input
+    // spans from the default location to the start of the next token
+    assert_eq!(earley::parse_Opt("  a").unwrap(), (0, 2));
+}
+
+#[test]
+fn earley_errors() {
+    match earley::parse_Expr("10 - - 3") {
+        Err(ParseError::UnrecognizedToken { token: Some((5, _, 6)), expected }) => {
+            assert_eq!(expected, vec![r###"r#"[0-9]+"#"###.to_string()]);
+        }
+        r => panic!("unexpected result: {:?}", r),
+    }
+    match earley::parse_Expr("10 -") {
+        Err(ParseError::UnrecognizedEof { location: 4, .. }) => {}
+        r => panic!("unexpected result: {:?}", r),
+    }
+    match earley::parse_Expr("10 - 3 3") {
+        Err(ParseError::ExtraToken { token: (7, _, 8), expected }) => {
+            assert_eq!(expected, vec!["\"-\"".to_string()]);
+        }
+        r => panic!("unexpected result: {:?}", r),
+    }
+    match earley::parse_Choice("a x x") {
+        Err(ParseError::UnrecognizedToken { token: Some((4, _, 5)), ref expected }) => {
+            assert_eq!(expected.len(), 2);
+        }
+        r => panic!("unexpected result: {:?}", r),
+    }
+}
+
+#[test]
+fn pratt_precedence() {
+    assert_eq!(pratt::parse_Expr("1 + 2 * 3").unwrap(), 7);
+    assert_eq!(pratt::parse_Expr("10 - 4 - 3").unwrap(), 3);
+    assert_eq!(pratt::parse_Expr("2 ^ 3 ^ 2").unwrap(), 512);
+    assert_eq!(pratt::parse_Expr("-2 ^ 2").unwrap(), -4);
+    assert_eq!(pratt::parse_Expr("2 * 3!").unwrap(), 12);
+    assert_eq!(pratt::parse_Expr("(1 + 2) * 3").unwrap(), 9);
+}
+
+#[test]
+fn category_binds_terminal() {
+    assert_eq!(category::parse_Assign("x += 3").unwrap(), ("x", "+=", 3));
+    assert_eq!(category::parse_Assign("y = 1").unwrap(), ("y", "=", 1));
+    assert!(category::parse_Assign("x *= 1").is_err());
+}
+
+#[test]
+fn token_guard_test() {
+    let tokens = |input: &str| -> Vec<Tok> {
+        util::tok::tokenize(input).into_iter().map(|(_, tok, _)| tok).collect()
+    };
+    assert_eq!(token_guard::parse_Expr(tokens("12 / 4")).unwrap(), 3);
+    assert_eq!(token_guard::parse_Expr(tokens("0 / 4")).unwrap(), 0);
+    assert!(token_guard::parse_Expr(tokens("12 / 0")).is_err());
+}
+
+#[test]
+fn token_stream_test() {
+    use lalrpop_util::token_stream::TokenStream;
+    let parse = |input: &str| {
+        let stream: TokenStream = input.parse().unwrap();
+        token_stream::parse_Bindings(token_stream::tokens(stream))
+    };
+    let binding = |n: &str, t: &str, v: &str| (n.to_string(), t.to_string(), v.to_string());
+    assert_eq!(parse("let xs: Vec<Vec<u8>> => [1 -2 [3]]; y: u8 => \"y\";").unwrap(),
+               vec![binding("xs", "Vec<Vec<u8>>", "[1 -2 [3]]"),
+                    binding("y", "u8", "\"y\"")]);
+    // `let` is a keyword, and `=` and `>` apart are not `=>`
+    assert!(parse("let: u8 => 1;").is_err());
+    assert!(parse("x: u8 = > 1;").is_err());
+    assert!(parse("x: u8 => (1);").is_err());
+}
+
+#[test]
+fn token_adapter_test() {
+    use token_adapter_lib::{Tok, PpTok, CoreTok};
+    let tokens = vec![Tok::Pp(PpTok::Define),
+                      Tok::Core(CoreTok::Num(1)),
+                      Tok::Core(CoreTok::Semi),
+                      Tok::Core(CoreTok::Num(2)),
+                      Tok::Core(CoreTok::Semi)];
+    assert_eq!(token_adapter::parse_Items(tokens).unwrap(), vec![(true, 1), (false, 2)]);
+    assert!(token_adapter::parse_Items(vec![Tok::Pp(PpTok::Define)]).is_err());
+}
+
+#[test]
+fn grammar_cfg_test() {
+    assert_eq!(grammar_cfg::parse_Expr("1 + 2").unwrap(), 3);
+    assert_eq!(grammar_cfg::parse_Expr("1 + 2 * 3").unwrap(), 7);
+    assert!(grammar_cfg::parse_Expr("8 / 2").is_err());
+}
+
+#[test]
+fn versioned_test() {
+    use lalrpop_util::Version;
+
+    assert_eq!(versioned::parse_Expr(Version::new(2, 0, 0), "5 - (1 + 1)").unwrap(), 3);
+    assert_eq!(versioned::parse_Expr(Version::new(1, 1, 2), "5 + (1 + 1)").unwrap(), 7);
+    assert_eq!(versioned::parse_Expr(Version::new(1, 1, 2), "1 + 5 - 1").unwrap_err(),
+               ParseError::VersionTooOld { start: 0, end: 9, since: Version::new(2, 0, 0) });
+    assert_eq!(versioned::parse_Expr(Version::new(1, 0, 0), "1 + (2)").unwrap_err(),
+               ParseError::VersionTooOld { start: 4, end: 7, since: Version::new(1, 1, 2) });
+}
+
+#[test]
+fn dialect_test() {
+    assert_eq!(dialect_base::parse_Expr("1 + (2 + 3)").unwrap(), 6);
+    assert!(dialect_base::parse_Expr("2 * 3").is_err());
+    assert_eq!(dialect::parse_Expr("1 + 2 * (-3 + 5)").unwrap(), 5);
+}
+
+#[test]
+fn shared_tokens_test() {
+    assert_eq!(shared_tokens_expr::parse_Expr("1 + 2").unwrap(), 3);
+    assert!(shared_tokens_expr::parse_Expr("let").is_err());
+    assert_eq!(shared_tokens_program::parse_Program("let x = 1 + 2; let y = 3;").unwrap(),
+               vec![(format!("x"), 3), (format!("y"), 3)]);
+}
+
+#[test]
+fn boxed_test() {
+    use boxed_ast::Expr;
+
+    assert_eq!(boxed::parse_Expr("1 + -(2)").unwrap(),
+               Box::new(Expr::Add(Box::new(Expr::Num(1)),
+                                  Box::new(Expr::Neg(Box::new(Expr::Num(2)))))));
+}
+
+#[test]
+fn guard_test() {
+    assert_eq!(guard::parse_Items(2, "1 new 3").unwrap(), vec![1, 300]);
+    assert_eq!(guard::parse_Items(1, "1 new 3").unwrap_err(),
+               ParseError::GuardFailed { start: 2, end: 7 });
+    assert_eq!(guard::parse_Items(2, "5 100").unwrap_err(),
+               ParseError::GuardFailed { start: 2, end: 5 });
+    assert_eq!(guard::parse_Empty(1, "").unwrap(), ());
+    assert_eq!(guard::parse_Empty(0, "").unwrap_err(),
+               ParseError::GuardFailed { start: 0, end: 0 });
+}
+
+#[test]
+fn earley_dprec_and_merge() {
+    assert_eq!(earley_disambiguation::parse_Expr("10 - 3 - 2").unwrap(), 9);
+    assert_eq!(earley_disambiguation::parse_Expr("10 - 3 - 2 - 1").unwrap(), 8);
+    assert_eq!(earley_disambiguation::parse_Count("x").unwrap(), 1);
+    assert_eq!(earley_disambiguation::parse_Count("x + x + x").unwrap(), 2);
+    assert_eq!(earley_disambiguation::parse_Count("x + x + x + x").unwrap(), 5);
+}
+
+#[test]
+fn error_recovery_rules() {
+    let mut errors = vec![];
+    let stmts = error_recovery_rules::parse_Stmts(&mut errors, "a = b; c d e; f = g;").unwrap();
+    assert_eq!(stmts, vec!["a=b", "", "f=g"]);
+    assert_eq!(errors, vec![2]);
+}
+
+#[test]
+fn error_repair() {
+    let (stmts, errors) = error_repair::parse_Stmts_repaired("1 + 2; (3 + 4; 5;");
+    assert_eq!(stmts, Some(vec![3, 7, 5]));
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].to_string(),
+               "Unrecognized token `;` found at 13:14\n\
+                Expected one of \")\" or \"+\"\n\
+                Possible repair: insert \")\" before `;`");
+
+    let (stmts, errors) = error_repair::parse_Stmts_repaired("1 + + 2;");
+    assert_eq!(stmts, Some(vec![3]));
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].repairs.len(), 1);
+}
+
+#[test]
+fn error_repair_extern_tokens() {
+    use lalrpop_util::repair::Repair;
+
+    let (expr, errors) = error_repair_extern::parse_Expr_repaired(util::tok::tokenize("(1 + 2"));
+    assert_eq!(expr, Some(3));
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].repairs, vec![vec![Repair::Insert("\")\"".to_string())]]);
+
+    // a token that matches none of the terminals
+    let (expr, errors) = error_repair_extern::parse_Expr_repaired(util::tok::tokenize("1 + 2 * 3"));
+    assert_eq!(expr, None);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].error,
+               ParseError::UnrecognizedToken {
+                   token: Some((6, Tok::Times, 7)),
+                   expected: vec![],
+               });
+    assert!(errors[0].repairs.is_empty());
+}
+
+#[test]
+fn unescape_match_entries() {
+    use std::borrow::Cow;
+
+    let input = r#"a = "plain", b = "tab\tquote\"\u{e9}", c = '\'', d = `raw\n`"#;
+    let entries = unescape::parse_Entries(input).unwrap();
+    assert_eq!(entries,
+               vec![("a", Cow::Borrowed("plain")),
+                    ("b", Cow::Owned(String::from("tab\tquote\"\u{e9}"))),
+                    ("c", Cow::Owned(String::from("'"))),
+                    ("d", Cow::Borrowed("raw\\n"))]);
+
+    // values without escapes are not copied
+    match entries[0].1 {
+        Cow::Borrowed(value) => assert_eq!(value.as_ptr(), input[5..].as_ptr()),
+        Cow::Owned(_) => panic!("copied a string without escapes"),
+    }
+
+    // the lexer rejects invalid escapes
+    assert_eq!(unescape::parse_Entries(r#"a = "ok", b = "bad\q""#).unwrap_err(),
+               ParseError::InvalidToken { location: 18 });
+}
+
+#[test]
+fn normalize_match() {
+    assert_eq!(normalize::parse_Stmts("BEGIN x y z End Print W").unwrap(),
+               vec!["block of 3", "print W"]);
+    // keywords are not identifiers, whatever their case
+    assert!(normalize::parse_Stmts("print BEGIN").is_err());
+}
+
+#[test]
+fn normalize_extern() {
+    let tokens = vec![(0, Tok::Num(1), 1), (1, Tok::Plus, 2), (2, Tok::Fraction(4, 1), 5)];
+    assert_eq!(normalize_extern::parse_Sum(tokens).unwrap(), 5);
+    let tokens = vec![(0, Tok::Num(1), 1), (1, Tok::Plus, 2), (2, Tok::Fraction(4, 3), 5)];
+    assert!(normalize_extern::parse_Sum(tokens).is_err());
+}
+
+#[test]
+fn panic_free_random_input() {
+    assert_eq!(panic_free::parse_Expr("2 * (3 + -4) - 5").unwrap(), -7);
+    assert_eq!(panic_free::parse_Expr("99999999999").unwrap_err(),
+               ParseError::User { error: "number is too big" });
+
+    // any panic fails the test; internal errors would be bugs
+    let pieces = ["1", "42", "99999999999", "+", "-", "*", "(", ")", " ", "x", "\u{e9}", "\t"];
+    let mut seed: u32 = 0x9e37_79b9;
+    let mut random = || {
+        // xorshift
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        seed as usize
+    };
+    for _ in 0..5000 {
+        let mut input = String::new();
+        for _ in 0..random() % 12 {
+            input.push_str(pieces[random() % pieces.len()]);
+        }
+        match panic_free::parse_Expr(&input) {
+            Err(ParseError::Internal { message }) => panic!("{:?}: {}", input, message),
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn memoized_actions() {
+    use std::cell::Cell;
+
+    // each of the 10 ways to split a span of `x + x + x + x` in two
+    // is reduced once, however many groupings share it, as is each `x`
+    let calls = Cell::new(0);
+    assert_eq!(memoized::parse_Count(&calls, "x + x + x + x").unwrap(), 5);
+    assert_eq!(calls.get(), 14);
+
+    // the second derivation of `x + x + x` reuses the `x`s of the first
+    let calls = Cell::new(0);
+    assert_eq!(memoized::parse_Count_all(&calls, "x + x + x").unwrap(), vec![1, 1]);
+    assert_eq!(calls.get(), 7);
+}
+
+#[test]
+fn fixed_stack_buffer_sizes() {
+    let mut buffer = [0u8; 1024];
+    assert_eq!(fixed_stack::parse_List_with_stack("[a [b c] []]", &mut buffer).unwrap(),
+               vec!["a", "[b c]", "[]"]);
+    assert_eq!(fixed_stack::parse_List_with_stack("[]", &mut []).unwrap_err(),
+               ParseError::StackCapacity { location: 0, capacity: 0 });
+
+    // a buffer that is too small is an error, never a panic, and a
+    // larger one does not make it so
+    let mut fits = false;
+    for len in 0..buffer.len() {
+        match fixed_stack::parse_List_with_stack("[[a] [[]]]", &mut buffer[..len]) {
+            Ok(items) => {
+                assert_eq!(items, vec!["[a]", "[[]]"]);
+                fits = true;
+            }
+            Err(ParseError::StackCapacity { .. }) => assert!(!fits, "{} bytes", len),
+            Err(e) => panic!("{} bytes: {:?}", len, e),
+        }
+    }
+    assert!(fits);
+}
+
+#[test]
+fn no_std_stack_allocations() {
+    let tokens = util::tok::tokenize("2 * (3 + 4) + 5");
+    let mut buffer = [0u8; 512];
+    let allocations = util::count_alloc::allocations();
+    assert_eq!(no_std_stack::parse_Expr_with_stack(tokens.iter().cloned(), &mut buffer), Ok(19));
+    assert_eq!(no_std_stack::parse_Expr_with_stack(tokens.iter().cloned(), &mut buffer[..8]),
+               Err(ParseError::StackCapacity { location: 0, capacity: 0 }));
+    assert_eq!(util::count_alloc::allocations(), allocations);
+
+    // with the `std` feature of `lalrpop_util`, the tokens that were
+    // expected are collected into the error; without it, the error
+    // has the state to find them with
+    let tokens = util::tok::tokenize("2 * (3 +");
+    match no_std_stack::parse_Expr_with_stack(tokens.iter().cloned(), &mut buffer) {
+        Err(ParseError::UnrecognizedEof { expected, .. }) => {
+            assert_eq!(expected, vec![r#""(""#, "Num"]);
+        }
+        r => panic!("unexpected response from parser: {:?}", r),
+    }
+    assert_eq!(no_std_stack::expected_tokens(0).collect::<Vec<_>>(), vec![r#""(""#, "Num"]);
+
+    // the generated code does not use `std`, or anything that needs
+    // an allocator
+    let source = include_str!("no_std_stack.rs");
+    for name in &["std::", "Vec", "String", "Box", "vec!", "format!", "to_vec", "collect"] {
+        assert!(!source.contains(name), "{}", name);
+    }
+}
+
+#[test]
+fn c_abi_entry_points() {
+    use lalrpop_util::ffi;
+    use std::ffi::CStr;
+    use std::ptr;
+
+    let mut error = ffi::Error { kind: ffi::OK, start: 0, end: 0, message: ptr::null_mut() };
+    unsafe {
+        let input = "1 + 22 + 333";
+        let value = c_abi::c_abi_parse_Sum(input.as_ptr(), input.len(), &mut error);
+        assert!(!value.is_null());
+        assert_eq!(error.kind, ffi::OK);
+        assert_eq!(*(value as *const i32), 356);
+        c_abi::c_abi_free_Sum(value);
+
+        let input = "1 + + 2";
+        let value = c_abi::c_abi_parse_Sum(input.as_ptr(), input.len(), &mut error);
+        assert!(value.is_null());
+        assert_eq!((error.kind, error.start, error.end), (ffi::UNRECOGNIZED_TOKEN, 4, 5));
+        assert!(CStr::from_ptr(error.message).to_str().unwrap().contains("Unrecognized token"));
+        c_abi::c_abi_free_error(&mut error);
+        assert_eq!(error.kind, ffi::OK);
+        assert!(error.message.is_null());
+
+        let input = b"1 + \xff";
+        let value = c_abi::c_abi_parse_Sum(input.as_ptr(), input.len(), &mut error);
+        assert!(value.is_null());
+        assert_eq!(error.kind, ffi::INVALID_UTF8);
+        c_abi::c_abi_free_error(&mut error);
+    }
+
+    let header = include_str!("c_abi.h");
+    assert!(header.contains("c_abi_parse_Sum("));
+    assert!(header.contains("c_abi_free_Sum("));
+    assert!(header.contains("c_abi_free_error("));
+}
+
+#[test]
+fn custom_prefix() {
+    assert_eq!(custom_prefix::parse_Sum("1 + 22 + 333").unwrap(), 356);
+
+    let source = include_str!("custom_prefix.rs");
+    assert!(source.contains("mod lp_parse_table {"));
+    let mut idents = source.split(|c: char| !c.is_alphanumeric() && c != '_');
+    assert!(!idents.any(|ident| ident.starts_with("__")));
+}
+
+#[test]
+fn table_link_section() {
+    assert_eq!(link_section::parse_Sum("1 + 22 + 333").unwrap(), 356);
+
+    // every table is a `static` placed in the configured section
+    let source = include_str!("link_section.rs");
+    assert!(source.contains("static __ACTION: ["));
+    assert!(!source.contains("const __ACTION"));
+    let statics = source.lines().filter(|l| l.trim_start().starts_with("static __")).count();
+    assert!(statics > 0);
+    assert_eq!(source.matches("#[link_section = ").count(), statics);
+}
+
+#[test]
+fn profile_counts() {
+    profile_counts::parse_Expr("1 + 2 * (3 - 4)").unwrap();
+    profile_counts::parse_Expr("22 * 3").unwrap();
+
+    let mut out = vec![];
+    profile_counts::write_profile_Expr(&mut out).unwrap();
+    let profile = String::from_utf8(out).unwrap();
+    assert!(profile.lines().any(|l| l == "Expr state 0 2"));
+    for line in profile.lines() {
+        let fields: Vec<_> = line.split(' ').collect();
+        assert_eq!(fields.len(), 4, "bad profile line {:?}", line);
+        assert_eq!(fields[0], "Expr");
+        assert!(fields[1] == "state" || fields[1] == "reduce");
+        assert!(fields[2].parse::<usize>().is_ok() && fields[3].parse::<u64>().unwrap() > 0);
+    }
+}
+
+#[test]
+fn profile_layout() {
+    for input in &["1 + 2 * (3 - 4)", "22 * 3 + 4 * 5 - 6", "((1)) + 2"] {
+        assert_eq!(profile_layout::parse_Expr(input).unwrap(), profile_counts::parse_Expr(input).unwrap());
+    }
+    assert!(profile_layout::parse_Expr("1 + * 2").is_err());
+
+    // the same grammar, but the states are numbered by how often they were used
+    fn action_table(source: &str) -> &str {
+        let start = source.find("__ACTION: ").unwrap();
+        &source[start..start + source[start..].find("];").unwrap()]
+    }
+    assert!(action_table(include_str!("profile_layout.rs")) !=
+            action_table(include_str!("profile_counts.rs")));
+}
+
+#[test]
+fn parsers_module() {
+    assert_eq!(parsers_module::sum::parse_Sum("1 + 22 + 333").unwrap(), 356);
+    assert_eq!(parsers_module::nested::product::parse_Product("2 * 3 * 7").unwrap(), 42);
+}
+
+#[test]
+fn token_arrays() {
+    let input = "1 + 22 +\n333";
+    let tokens = token_array::tokenize(input).unwrap();
+    assert_eq!(tokens.len(), 5);
+    assert_eq!(tokens.span(4), (9, 12));
+    assert_eq!(tokens.text(input, 2), "22");
+    assert_eq!(tokens.kind(0), tokens.kind(2));
+    assert!(tokens.kind(1) != tokens.kind(0));
+
+    // the same tokens can be parsed again, as any public nonterminal
+    assert_eq!(token_array::parse_Sum_tokens(input, &tokens).unwrap(), 356);
+    assert_eq!(token_array::parse_Sum_tokens(input, &tokens).unwrap(), 356);
+    assert_eq!(token_array::parse_Sum(input).unwrap(), 356);
+    match token_array::parse_Num_tokens(input, &tokens) {
+        Err(ParseError::ExtraToken { token: (2, token_array::Token(_, "+"), 3), .. }) => { }
+        r => panic!("unexpected response from parser: {:?}", r),
+    }
+
+    assert_eq!(token_array::tokenize("1 - 2").unwrap_err(),
+               ParseError::InvalidToken { location: 2 });
+}
+
+#[test]
+fn debug_assertions_hold() {
+    assert_eq!(debug_assertions::parse_Stmts("a = 1 + (2 + 3); { b = (4); c 5; } d = ;").unwrap(),
+               vec!["a=6", "{b=4 !}", "!"]);
+    assert!(debug_assertions::parse_Stmts("{ a = 1;").is_err());
+}
+
+#[test]
+fn profiled_reductions() {
+    assert_eq!(profiled::parse_Expr("10 - (4 - 1) - 2").unwrap(), 5);
+    assert!(profiled::parse_Expr("10 - ").is_err());
+
+    // one never-inlined function for each of the five productions,
+    // and for the start production
+    let source = include_str!("profiled.rs");
+    assert_eq!(source.matches("#[inline(never)]").count(), 6);
+}
+
+#[test]
+fn max_depth_limit() {
+    assert_eq!(max_depth::parse_Parens("(((x)))").unwrap(), 3);
+    assert_eq!(max_depth::parse_Parens("((((((x))))))").unwrap(), 6);
+
+    // seven `(`s and the `Parens` within them fill the stack, which
+    // leaves no room for the first `)`
+    assert_eq!(max_depth::parse_Parens("(((((((x)))))))").unwrap_err(),
+               ParseError::DepthLimit { location: 8, depth: 8 });
+    assert_eq!(max_depth::parse_Parens(&"(".repeat(1000)).unwrap_err(),
+               ParseError::DepthLimit { location: 8, depth: 8 });
+}
+
+#[test]
+fn max_reductions_under_limit() {
+    // each number is reduced to `Num` and then to `Sum`, and the
+    // whole input once more at the end, so four numbers take nine
+    // reductions; the count starts afresh for each parse
+    assert_eq!(max_reductions::parse_Sum("1 + 2 + 3 + 4").unwrap(), 10);
+    assert_eq!(max_reductions::parse_Sum("4 + 3 + 2 + 1").unwrap(), 10);
+}
+
+#[test]
+fn max_reductions_over_limit() {
+    // five numbers only go over the limit with the final reduction,
+    // at the end of the input
+    assert_eq!(max_reductions::parse_Sum("1 + 2 + 3 + 4 + 5").unwrap_err(),
+               ParseError::ReductionLimit { location: 17, limit: 10 });
+
+    // the eleventh reduction, of the sixth number, is made as soon as
+    // that number is shifted, whatever follows it
+    assert_eq!(max_reductions::parse_Sum("1 + 2 + 3 + 4 + 5 + 6").unwrap_err(),
+               ParseError::ReductionLimit { location: 21, limit: 10 });
+    assert_eq!(max_reductions::parse_Sum("1 + 2 + 3 + 4 + 5 + 6 + 7").unwrap_err(),
+               ParseError::ReductionLimit { location: 21, limit: 10 });
+}
+
+#[test]
+fn cfg_gated_parsers() {
+    assert_eq!(parser_cfg::parse_Sum("1 + 2 + 3").unwrap(), 6);
+
+    // the parser for `Product` is there, but compiled out; its tables
+    // are shared with the parser for `Sum`, so only its fns are
+    let source = include_str!("parser_cfg.rs");
+    assert!(source.contains("\n#![cfg(test)]\n"));
+    assert!(source.contains("#[cfg(any())]\npub use self::__parse_table::parse_Product;"));
+    assert!(source.contains("#[cfg(any())]\n    #[allow(dead_code)]\n    pub fn parse_Product<"));
+    assert!(source.contains("\npub use self::__parse_table::parse_Sum;"));
+    assert!(!source.contains("#[cfg(any())]\nmod "));
+}
+
+#[test]
+fn serde_tokens() {
+    assert_eq!(serde_tok::parse_Words("ab cd ;").unwrap(), 2);
+
+    let token = match serde_tok::parse_Words("ab ; cd") {
+        Err(ParseError::ExtraToken { token: (5, token, 7), .. }) => token,
+        r => panic!("unexpected response from parser: {:?}", r),
+    };
+    let json = serde_json::to_string(&token).unwrap();
+    assert!(json.ends_with(",\"cd\"]"), "{}", json);
+    assert_eq!(serde_json::from_str::<serde_tok::Token>(&json).unwrap(), token);
+}
+
+#[test]
+fn unit_rules_eliminated() {
+    // the two parsers agree on what they accept, and on the errors
+    // they report for what they do not
+    for input in &["1", "(((7)))", "1 + 2 * 3 - 4", "-(1 - -2) * 3", "2 * (3 + 4) * -5",
+                   "", "1 +", "(1", "1)", "1 2", "* 3", "(((", "1 + (2 * )"] {
+        assert_eq!(format!("{:?}", unit_rules::parse_Expr(input)),
+                   format!("{:?}", unit_rules_kept::parse_Expr(input)),
+                   "{:?}", input);
+    }
+    assert_eq!(unit_rules::parse_Expr("1 + 2 * 3 - 4").unwrap(), 3);
+
+    // the states that only reduced `Term = Factor`, `Factor = Atom`
+    // and the like are gone
+    let states = |source: &str| source.matches("// State ").count();
+    assert!(states(include_str!("unit_rules.rs")) < states(include_str!("unit_rules_kept.rs")));
+}
+
+#[test]
+fn max_nesting_depth() {
+    assert_eq!(max_nesting::parse_Value("[[1, [2]], [], 3]").unwrap(), 3);
+    assert_eq!(max_nesting::parse_Value("[[[[]]]]").unwrap_err(),
+               ParseError::DepthLimit { location: 3, depth: 3 });
+    assert_eq!(max_nesting::parse_Value("[[1], [[1, [2, [3]]]]]").unwrap_err(),
+               ParseError::DepthLimit { location: 11, depth: 3 });
+
+    // arrays that were closed no longer count
+    let wide = format!("[{}]", vec!["[[1]]"; 100].join(", "));
+    assert_eq!(max_nesting::parse_Value(&wide).unwrap(), 3);
+}
+
+#[test]
+fn unparse_round_trip() {
+    use unparse_ast::{Expr, Op};
+
+    for text in &["1 - (2 - 3) * -x / f[y; 4 + 5;]",
+                  "let x: int = let y = (1); -(y) - g[];",
+                  "((a))"] {
+        let expr = unparse::parse_Expr(text).unwrap();
+        let unparsed = unparse::unparse_Expr(&expr).unwrap();
+        assert_eq!(unparse::parse_Expr(&unparsed).unwrap(), expr, "{}", unparsed);
+    }
+
+    // parentheses are only added where they are needed
+    assert_eq!(unparse::unparse_Expr(&unparse::parse_Expr("((1) + (2 * 3))").unwrap()),
+               Some(format!("1 + 2 * 3")));
+    let num = |n| Box::new(Expr::Num(n));
+    let expr = Expr::Op(num(1), Op::Mul, Box::new(Expr::Op(num(2), Op::Sub, num(3))));
+    assert_eq!(unparse::unparse_Expr(&Box::new(expr)), Some(format!("1 * (2 - 3)")));
+    let call = Expr::Call(format!("f"), vec![num(1), Box::new(Expr::Neg(num(2)))]);
+    assert_eq!(unparse::unparse_Expr(&Box::new(call)), Some(format!("f [1; - 2;]")));
+}
+
+#[test]
+fn ast_spans() {
+    use ast::{Expr, Term};
+    use lalrpop_util::Spanned;
+
+    let expr = ast::parse_Expr("1 + f((2), 3,)").unwrap();
+    assert_eq!(expr.span(), (0, 14));
+    match expr {
+        Expr::Add { ref left, ref right, text, .. } => {
+            assert_eq!(text, "1 + f((2), 3,)");
+            assert_eq!(left.span(), (0, 1));
+            match **right {
+                Term::Call { ref name, ref args, text, .. } => {
+                    assert_eq!(name.name, "f");
+                    assert_eq!(text, "f((2), 3,)");
+                    let spans: Vec<_> = args.iter().map(|arg| arg.span()).collect();
+                    assert_eq!(spans, vec![(6, 9), (11, 12)]);
+                }
+                ref term => panic!("unexpected term {:?}", term),
+            }
+        }
+        ref expr => panic!("unexpected expression {:?}", expr),
+    }
+}
+
+#[test]
+fn ast_derives() {
+    use std::collections::BTreeSet;
+
+    fn is_eq<T: Eq>(_: &T) {}
+    is_eq(&ast::parse_Expr("f()").unwrap());
+
+    // `Name` derives more traits than the rest
+    let names: BTreeSet<_> = ["g(f(), f(),)", "f()"]
+        .iter()
+        .map(|text| match ast::parse_Expr(text).unwrap() {
+            ast::Expr::Term { term, .. } => match *term {
+                ast::Term::Call { name, .. } => *name,
+                term => panic!("unexpected term {:?}", term),
+            },
+            expr => panic!("unexpected expression {:?}", expr),
+        })
+        .collect();
+    assert_eq!(names.len(), 2);
+}
+
+#[test]
+fn two_token_lookahead() {
+    assert_eq!(two_tokens::parse_Rules("a: b c d: e: f a").unwrap(),
+               vec![("a", vec!["b", "c"]), ("d", vec![]), ("e", vec!["f", "a"])]);
+    assert_eq!(two_tokens::parse_Rules("").unwrap(), vec![]);
+    assert_eq!(two_tokens::parse_Rules("a: b c:").unwrap(),
+               vec![("a", vec!["b"]), ("c", vec![])]);
+    assert!(two_tokens::parse_Rules("a b: c").is_err());
+}
+
+#[test]
+fn reserved_words() {
+    assert_eq!(reserved::parse_Stmts("let x = nil; while x;").unwrap(), vec!["x", "x"]);
+
+    let error = reserved::parse_Stmts("let while = 1;").unwrap_err();
+    assert_eq!(error.to_string(), "Expected identifier, found keyword `while` at 4");
+    match error {
+        ParseError::ReservedWord { token: (4, _, 9), ref expected, .. } => {
+            assert_eq!(expected, &vec![r#"NAME"#.to_string()]);
+        }
+        _ => panic!("{:?}", error),
+    }
+    assert_eq!(reserved::parse_Stmts("while nil;").unwrap_err().to_string(),
+               "Expected variable name, found keyword `nil` at 6");
+
+    // where no name is expected, the keyword is just unexpected
+    match reserved::parse_Stmts("let x = while;").unwrap_err() {
+        ParseError::UnrecognizedToken { .. } => {}
+        error => panic!("{:?}", error),
+    }
+}
+
+#[test]
+fn contextual_keywords() {
+    assert_eq!(contextual::parse_Stmts("union u; let union = union; union union;").unwrap(),
+               vec!["union u", "let union = union", "union union"]);
+
+    // a name where the keyword is expected is still an error
+    match contextual::parse_Stmts("union u; unions u;").unwrap_err() {
+        ParseError::ExtraToken { token: (9, _, 15), .. } => {}
+        error => panic!("{:?}", error),
+    }
+}
+
+#[test]
+fn comma_trailing() {
+    assert_eq!(comma_trailing::parse_Call("f()").unwrap(), ("f", vec![]));
+    assert_eq!(comma_trailing::parse_Call("f(1)").unwrap(), ("f", vec![1]));
+    assert_eq!(comma_trailing::parse_Call("f(1, 2)").unwrap(), ("f", vec![1, 2]));
+    assert_eq!(comma_trailing::parse_Call("f(1, 2,)").unwrap(), ("f", vec![1, 2]));
+    assert!(comma_trailing::parse_Call("f(,)").is_err());
+    assert!(comma_trailing::parse_Call("f(1,,)").is_err());
+
+    assert_eq!(comma_trailing::parse_Lists("[1,], [], [2, 3],").unwrap(),
+               vec![vec![1], vec![], vec![2, 3]]);
+}
+
+#[test]
+fn newlines() {
+    assert_eq!(newlines::parse_Stmts("a = 1 +\n  2\nb = (a\n  + 1)\n\nc = b").unwrap(),
+               vec!["a = (1 + 2)", "b = (a + 1)", "c = b"]);
+    assert_eq!(newlines::parse_Stmts("a = 1; b = 2;\n").unwrap(), vec!["a = 1", "b = 2"]);
+
+    // the inserted `;` spans the line break
+    match newlines::parse_Stmts("a\n= 1").unwrap_err() {
+        ParseError::UnrecognizedToken { token: Some((1, _, 2)), .. } => {}
+        error => panic!("{:?}", error),
+    }
+}
+
+#[test]
+fn line_col_locations() {
+    let line_col = |location: LineCol| (location.line, location.column);
+    let spans: Vec<_> = line_col::parse_Items("a\n  let b = c\n\td").unwrap()
+        .into_iter()
+        .map(|(l, r)| (line_col(l), line_col(r)))
+        .collect();
+    assert_eq!(spans, vec![((1, 1), (1, 2)), ((2, 3), (2, 12)), ((3, 2), (3, 3))]);
+
+    match line_col::parse_Items("a\n  !").unwrap_err() {
+        ParseError::InvalidToken { location } => {
+            assert_eq!(location, LineCol { offset: 4, line: 2, column: 3 });
+        }
+        error => panic!("{:?}", error),
+    }
+    assert_eq!(line_col::parse_Items("a\nlet let").unwrap_err().to_string(),
+               "Expected identifier, found keyword `let` at 2:5");
+}
+
+#[test]
+fn error_recovery_delimiters() {
+    assert_eq!(error_recovery_delimiters::parse_Stmts("a; b c (d; e); f(g);").unwrap(),
+               vec!["a", "!", "f(g)"]);
+    assert_eq!(error_recovery_delimiters::parse_Stmts("{ a; x y { b; }; } c;").unwrap(),
+               vec!["{a !}", "c"]);
+}
+
+#[test]
+fn conflicts_resolved_by_default() {
+    // the shift wins, so `+` is right-associative...
+    assert_eq!(conflicts_as_warnings::parse_Expr("1 + 2 + 3").unwrap(), "(1 + (2 + 3))");
+
+    // ...and `B`, which is defined first, wins over `A`
+    assert_eq!(conflicts_as_warnings::parse_Item("n x").unwrap(), "B");
+}
@@ -0,0 +1,455 @@
+// auto-generated: "lalrpop 0.15.0"
+use std::cell::Cell;
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+
+mod __parse__Count {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    use std::cell::Cell;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__intern_token::Token;
+    #[allow(dead_code)]
+    #[derive(Clone)]
+    pub enum __Symbol<'input>
+     {
+        Term_22_2b_22(&'input str),
+        Term_22x_22(&'input str),
+        NtCount(u32),
+        Nt____Count(u32),
+    }
+    const __TERMINALS: &'static [&'static str] = &[
+        r###""+""###,
+        r###""x""###,
+    ];
+    const __PRODUCTIONS: &'static [__lalrpop_util::earley::Production] = &[
+        // Count = Count, "+", Count => ActionFn(1);
+        __lalrpop_util::earley::Production { nonterminal: 0, symbols: &[__lalrpop_util::earley::Symbol::Nonterminal(0), __lalrpop_util::earley::Symbol::Terminal(0), __lalrpop_util::earley::Symbol::Nonterminal(0)], dprec: 0, merge: Some(0) },
+        // Count = "x" => ActionFn(2);
+        __lalrpop_util::earley::Production { nonterminal: 0, symbols: &[__lalrpop_util::earley::Symbol::Terminal(1)], dprec: 0, merge: None },
+        // __Count = Count => ActionFn(0);
+        __lalrpop_util::earley::Production { nonterminal: 1, symbols: &[__lalrpop_util::earley::Symbol::Nonterminal(0)], dprec: 0, merge: None },
+    ];
+    #[allow(dead_code)]
+    pub fn parse_Count<
+        'input,
+    >(
+        calls: &Cell<u32>,
+        input: &'input str,
+    ) -> Result<u32, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let mut __tokens = super::__intern_token::__Matcher::new(input);
+        let mut __triples = vec![];
+        let mut __integers = vec![];
+        let mut __unrecognized = None;
+        for __token in __tokens {
+            let __lookahead = match __token {
+                Ok(v) => v,
+                Err(e) => return Err(e),
+            };
+            let __integer = match __lookahead.1 {
+                Token(0, _) if true => 0,
+                Token(1, _) if true => 1,
+                _ => {
+                    __unrecognized = Some(__lookahead);
+                    break;
+                }
+            };
+            __triples.push(__lookahead);
+            __integers.push(__integer);
+        }
+        let __chart = __lalrpop_util::earley::Chart::parse(__PRODUCTIONS, 1, &__integers);
+        let __error = match __chart.error() {
+            Some(__index) => Some(__index),
+            None if __unrecognized.is_some() => Some(__integers.len()),
+            None => None,
+        };
+        if let Some(__index) = __error {
+            let __expected = __chart.expected(__index).into_iter().map(|__t| __TERMINALS[__t].to_string()).collect();
+            if __index < __triples.len() {
+                let __token = __triples.swap_remove(__index);
+                if __chart.is_complete(__index) {
+                    return Err(__lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected });
+                }
+                return Err(__lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected });
+            }
+            if __unrecognized.is_some() {
+                return Err(__lalrpop_util::ParseError::UnrecognizedToken { token: __unrecognized, expected: __expected });
+            }
+            return Err(__lalrpop_util::ParseError::UnrecognizedEof { location: __triples.last().map(|__t| __t.2.clone()).unwrap_or_default(), expected: __expected });
+        }
+        __replay(calls, input, __chart.forest().resolve_memoized(__PRODUCTIONS), __triples, &__integers, &mut ::std::collections::HashMap::new(), ::std::marker::PhantomData::<()>)
+    }
+    #[allow(dead_code)]
+    pub fn parse_Count_all<
+        'input,
+    >(
+        calls: &Cell<u32>,
+        input: &'input str,
+    ) -> Result<::std::vec::Vec<u32>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let mut __tokens = super::__intern_token::__Matcher::new(input);
+        let mut __triples = vec![];
+        let mut __integers = vec![];
+        let mut __unrecognized = None;
+        for __token in __tokens {
+            let __lookahead = match __token {
+                Ok(v) => v,
+                Err(e) => return Err(e),
+            };
+            let __integer = match __lookahead.1 {
+                Token(0, _) if true => 0,
+                Token(1, _) if true => 1,
+                _ => {
+                    __unrecognized = Some(__lookahead);
+                    break;
+                }
+            };
+            __triples.push(__lookahead);
+            __integers.push(__integer);
+        }
+        let __chart = __lalrpop_util::earley::Chart::parse(__PRODUCTIONS, 1, &__integers);
+        let __error = match __chart.error() {
+            Some(__index) => Some(__index),
+            None if __unrecognized.is_some() => Some(__integers.len()),
+            None => None,
+        };
+        if let Some(__index) = __error {
+            let __expected = __chart.expected(__index).into_iter().map(|__t| __TERMINALS[__t].to_string()).collect();
+            if __index < __triples.len() {
+                let __token = __triples.swap_remove(__index);
+                if __chart.is_complete(__index) {
+                    return Err(__lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected });
+                }
+                return Err(__lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected });
+            }
+            if __unrecognized.is_some() {
+                return Err(__lalrpop_util::ParseError::UnrecognizedToken { token: __unrecognized, expected: __expected });
+            }
+            return Err(__lalrpop_util::ParseError::UnrecognizedEof { location: __triples.last().map(|__t| __t.2.clone()).unwrap_or_default(), expected: __expected });
+        }
+        let mut __results = vec![];
+        let mut __failure = None;
+        let mut __memo = ::std::collections::HashMap::new();
+        for __steps in __chart.forest().derivations_memoized() {
+            match __replay(calls, input, __steps, __triples.clone(), &__integers, &mut __memo, ::std::marker::PhantomData::<()>) {
+                Ok(v) => __results.push(v),
+                Err(e) => if __failure.is_none() { __failure = Some(e); },
+            }
+        }
+        match __failure {
+            Some(e) if __results.is_empty() => Err(e),
+            _ => Ok(__results),
+        }
+    }
+    fn __replay<
+        'input,
+    >(
+        calls: &Cell<u32>,
+        input: &'input str,
+        __steps: ::std::vec::Vec<__lalrpop_util::earley::Step>,
+        __triples: ::std::vec::Vec<(usize, Token<'input>, usize)>,
+        __integers: &[usize],
+        __memo: &mut ::std::collections::HashMap<usize, (usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<u32, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let mut __symbols = vec![];
+        let mut __next = 0;
+        let mut __steps = __steps.into_iter();
+        while let Some(__step) = __steps.next() {
+            match __step {
+                __lalrpop_util::earley::Step::Shift => {
+                    let (__lookahead, __integer) = (__triples[__next].clone(), __integers[__next]);
+                    __next += 1;
+                    let __symbol = match __integer {
+                        0 => match __lookahead.1 {
+                            Token(0, __tok0) => __Symbol::Term_22_2b_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        1 => match __lookahead.1 {
+                            Token(1, __tok0) => __Symbol::Term_22x_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        _ => unreachable!(),
+                    };
+                    __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                }
+                __lalrpop_util::earley::Step::Reduce(__production) => {
+                    let __lookahead_start = __triples.get(__next).map(|__t| __t.0.clone());
+                    if let Some(r) = __reduce(calls, input, __production, __lookahead_start.as_ref(), &mut __symbols, ::std::marker::PhantomData::<()>) {
+                        return r;
+                    }
+                }
+                __lalrpop_util::earley::Step::Rewind(__index) => __next = __index,
+                __lalrpop_util::earley::Step::Merge(__index) => __merge(calls, input, __index, &mut __symbols, ::std::marker::PhantomData::<()>),
+                __lalrpop_util::earley::Step::Recall { node: __node, end: __end, skip: __skip } => {
+                    if let Some(__symbol) = __memo.get(&__node).cloned() {
+                        __symbols.push(__symbol);
+                        __next = __end;
+                        for _ in 0..__skip { __steps.next(); }
+                    }
+                }
+                __lalrpop_util::earley::Step::Keep(__node) => { __memo.insert(__node, __symbols.last().unwrap().clone()); }
+            }
+        }
+        unreachable!()
+    }
+    fn __reduce<
+        'input,
+    >(
+        calls: &Cell<u32>,
+        input: &'input str,
+        __production: usize,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Option<Result<u32,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        match __production {
+            0 => {
+                // Count = Count, "+", Count => ActionFn(1);
+                let __sym2 = __pop_NtCount(__symbols);
+                let __sym1 = __pop_Term_22_2b_22(__symbols);
+                let __sym0 = __pop_NtCount(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action1::<>(calls, input, __sym0, __sym1, __sym2);
+                __symbols.push((__start, __Symbol::NtCount(__nt), __end));
+            }
+            1 => {
+                // Count = "x" => ActionFn(2);
+                let __sym0 = __pop_Term_22x_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action2::<>(calls, input, __sym0);
+                __symbols.push((__start, __Symbol::NtCount(__nt), __end));
+            }
+            2 => {
+                // __Count = Count => ActionFn(0);
+                let __sym0 = __pop_NtCount(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action0::<>(calls, input, __sym0);
+                return Some(Ok(__nt));
+            }
+            _ => panic!("invalid production {}", __production)
+        }
+        None
+    }
+    fn __merge<
+        'input,
+    >(
+        calls: &Cell<u32>,
+        input: &'input str,
+        __merge: usize,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> ()
+    {
+        match __merge {
+            0 => {
+                let (_, __second, __end) = __pop_NtCount(__symbols);
+                let (__start, __first, _) = __pop_NtCount(__symbols);
+                __symbols.push((__start, __Symbol::NtCount(::std::ops::Add::add(__first, __second)), __end));
+            }
+            _ => panic!("invalid merge {}", __merge)
+        }
+    }
+    fn __pop_Term_22_2b_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2b_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22x_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22x_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtCount<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, u32, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtCount(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____Count<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, u32, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Count(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+}
+pub use self::__parse__Count::parse_Count;
+pub use self::__parse__Count::parse_Count_all;
+mod __intern_token {
+    #![allow(unused_imports)]
+    use std::cell::Cell;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    extern crate regex as __regex;
+    use std::fmt as __fmt;
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Token<'input>(pub usize, pub &'input str);
+    impl<'a> __fmt::Display for Token<'a> {
+        fn fmt(&self, formatter: &mut __fmt::Formatter) -> Result<(), __fmt::Error> {
+            __fmt::Display::fmt(self.1, formatter)
+        }
+    }
+
+    pub struct __Matcher<'input> {
+        text: &'input str,
+        consumed: usize,
+        regex_set: __regex::RegexSet,
+        regex_vec: Vec<__regex::Regex>,
+    }
+
+    impl<'input> __Matcher<'input> {
+        pub fn new(s: &'input str) -> __Matcher<'input> {
+            let __strs: &[&str] = &[
+                "^(?u:\\+)",
+                "^(?u:x)",
+            ];
+            let __regex_set = __regex::RegexSet::new(__strs).unwrap();
+            let __regex_vec = vec![
+                __regex::Regex::new("^(?u:\\+)").unwrap(),
+                __regex::Regex::new("^(?u:x)").unwrap(),
+            ];
+            __Matcher {
+                text: s,
+                consumed: 0,
+                regex_set: __regex_set,
+                regex_vec: __regex_vec,
+            }
+        }
+    }
+
+    impl<'input> Iterator for __Matcher<'input> {
+        type Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize,Token<'input>,&'static str>>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let __text = self.text.trim_left();
+            let __whitespace = self.text.len() - __text.len();
+            let __start_offset = self.consumed + __whitespace;
+            if __text.is_empty() {
+                self.text = __text;
+                self.consumed = __start_offset;
+                None
+            } else {
+                let __matches = self.regex_set.matches(__text);
+                if !__matches.matched_any() {
+                    Some(Err(__lalrpop_util::ParseError::InvalidToken {
+                        location: __start_offset,
+                    }))
+                } else {
+                    let mut __longest_match = 0;
+                    let mut __index = 0;
+                    for __i in 0 .. 2 {
+                        if __matches.matched(__i) {
+                            let __match = self.regex_vec[__i].find(__text).unwrap();
+                            let __len = __match.end();
+                            if __len >= __longest_match {
+                                __longest_match = __len;
+                                __index = __i;
+                            }
+                        }
+                    }
+                    let __result = &__text[..__longest_match];
+                    let __remaining = &__text[__longest_match..];
+                    let __end_offset = __start_offset + __longest_match;
+                    self.text = __remaining;
+                    self.consumed = __end_offset;
+                    Some(Ok((__start_offset, Token(__index, __result), __end_offset)))
+                }
+            }
+        }
+    }
+}
+pub use self::__intern_token::Token;
+
+#[allow(unused_variables)]
+fn __action0<
+    'input,
+>(
+    calls: &Cell<u32>,
+    input: &'input str,
+    (_, __0, _): (usize, u32, usize),
+) -> u32
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'input,
+>(
+    calls: &Cell<u32>,
+    input: &'input str,
+    (_, l, _): (usize, u32, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, u32, usize),
+) -> u32
+{
+    //@ src/memoized.lalrpop:10:66 (4 lines)
+    {
+        calls.set(calls.get() + 1);
+        l * r
+    }
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'input,
+>(
+    calls: &Cell<u32>,
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> u32
+{
+    //@ src/memoized.lalrpop:14:12 (4 lines)
+    {
+        calls.set(calls.get() + 1);
+        1
+    }
+}
+
+pub trait __ToTriple<'input, > {
+    type Error;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),Self::Error>;
+}
+
+impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize) {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        Ok(value)
+    }
+}
+impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        value
+    }
+}
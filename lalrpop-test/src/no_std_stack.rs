@@ -0,0 +1,723 @@
+// auto-generated: "lalrpop 0.15.0"
+use util::tok::Tok;
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+pub use self::__parse_table::parse_Expr_with_stack;
+pub use self::__parse_table::parse_Expr_with_stack_cancelable;
+pub use self::__parse_table::expected_tokens;
+
+mod __parse_table {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    use util::tok::Tok;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    extern crate core as __core;
+    use super::__ToTriple;
+    #[allow(dead_code)]
+    pub enum __Symbol<>
+     {
+        Term_22_28_22(Tok),
+        Term_22_29_22(Tok),
+        Term_22_2a_22(Tok),
+        Term_22_2b_22(Tok),
+        TermNum(i32),
+        NtExpr(i32),
+        NtFactor(i32),
+        NtTerm(i32),
+        Nt____Expr(i32),
+    }
+    impl<> __core::fmt::Debug for __Symbol<>
+    {
+        fn fmt(&self, __f: &mut __core::fmt::Formatter) -> __core::fmt::Result {
+            match *self {
+                __Symbol::Term_22_28_22(..) => __f.write_str("\"(\""),
+                __Symbol::Term_22_29_22(..) => __f.write_str("\")\""),
+                __Symbol::Term_22_2a_22(..) => __f.write_str("\"*\""),
+                __Symbol::Term_22_2b_22(..) => __f.write_str("\"+\""),
+                __Symbol::TermNum(..) => __f.write_str("Num"),
+                __Symbol::NtExpr(..) => __f.write_str("Expr"),
+                __Symbol::NtFactor(..) => __f.write_str("Factor"),
+                __Symbol::NtTerm(..) => __f.write_str("Term"),
+                __Symbol::Nt____Expr(..) => __f.write_str("__Expr"),
+            }
+        }
+    }
+    const __ACTION: &'static [i8] = &[
+        // State 0
+        5, 0, 0, 0, 6,
+        // State 1
+        0, 0, 0, 7, 0,
+        // State 2
+        0, -2, 8, -2, 0,
+        // State 3
+        0, -4, -4, -4, 0,
+        // State 4
+        5, 0, 0, 0, 6,
+        // State 5
+        0, -5, -5, -5, 0,
+        // State 6
+        5, 0, 0, 0, 6,
+        // State 7
+        5, 0, 0, 0, 6,
+        // State 8
+        0, 12, 0, 7, 0,
+        // State 9
+        0, -1, 8, -1, 0,
+        // State 10
+        0, -3, -3, -3, 0,
+        // State 11
+        0, -6, -6, -6, 0,
+    ];
+    const __EOF_ACTION: &'static [i8] = &[
+        // State 0
+        0,
+        // State 1
+        -7,
+        // State 2
+        -2,
+        // State 3
+        -4,
+        // State 4
+        0,
+        // State 5
+        -5,
+        // State 6
+        0,
+        // State 7
+        0,
+        // State 8
+        0,
+        // State 9
+        -1,
+        // State 10
+        -3,
+        // State 11
+        -6,
+    ];
+    const __GOTO: &'static [u8] = &[
+        // State 0
+        2, 3, 4, 0,
+        // State 1
+        0, 0, 0, 0,
+        // State 2
+        0, 0, 0, 0,
+        // State 3
+        0, 0, 0, 0,
+        // State 4
+        9, 3, 4, 0,
+        // State 5
+        0, 0, 0, 0,
+        // State 6
+        0, 10, 4, 0,
+        // State 7
+        0, 0, 11, 0,
+        // State 8
+        0, 0, 0, 0,
+        // State 9
+        0, 0, 0, 0,
+        // State 10
+        0, 0, 0, 0,
+        // State 11
+        0, 0, 0, 0,
+    ];
+    fn __expected_tokens(__state: usize) -> __lalrpop_util::Expected {
+        __lalrpop_util::expected(__state, expected_tokens(__state))
+    }
+    pub fn expected_tokens(__state: usize) -> impl Iterator<Item = &'static str> {
+        const __TERMINAL: &'static [&'static str] = &[
+            r###""(""###,
+            r###"")""###,
+            r###""*""###,
+            r###""+""###,
+            r###"Num"###,
+        ];
+        __ACTION[(__state * 5)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+            if state == 0 {
+                None
+            } else {
+                Some(*terminal)
+            }
+        })
+    }
+    #[allow(dead_code)]
+    pub fn parse_Expr_with_stack<
+        __TOKEN: __ToTriple<Error=&'static str>,
+        __TOKENS: IntoIterator<Item=__TOKEN>,
+    >(
+        __tokens0: __TOKENS,
+        __stack: &mut [u8],
+    ) -> Result<i32, __lalrpop_util::ParseError<usize, Tok, &'static str>>
+    {
+        let __tokens = __tokens0.into_iter();
+        let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+        let (mut __states, mut __symbols) = __lalrpop_util::stack::split_buffer(__stack);
+        __parse::<>(0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() })
+    }
+    #[allow(dead_code)]
+    pub fn parse_Expr_with_stack_cancelable<
+        __TOKEN: __ToTriple<Error=&'static str>,
+        __TOKENS: IntoIterator<Item=__TOKEN>,
+    >(
+        __tokens0: __TOKENS,
+        __stack: &mut [u8],
+        __cancel: &__core::sync::atomic::AtomicBool,
+    ) -> Result<i32, __lalrpop_util::ParseError<usize, Tok, &'static str>>
+    {
+        let __tokens = __tokens0.into_iter();
+        let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+        let (mut __states, mut __symbols) = __lalrpop_util::stack::split_buffer(__stack);
+        __parse::<>(0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() })
+    }
+
+    fn __parse<
+    >(
+        __start_state: i32,
+        mut __tokens: &mut Iterator<Item = Result<(usize, Tok, usize), &'static str>>,
+        mut __states: &mut __lalrpop_util::stack::FixedStack<i32>,
+        mut __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<>,usize)>,
+        __cancel: Option<&__core::sync::atomic::AtomicBool>,
+    ) -> Result<__Symbol<>, __lalrpop_util::ParseError<usize, Tok, &'static str>>
+    {
+        __states.clear();
+        if __states.capacity() == 0 {
+            return Err(__lalrpop_util::ParseError::StackCapacity { location: Default::default(), capacity: 0 });
+        }
+        __states.push(__start_state);
+        __symbols.clear();
+        let mut __integer;
+        let mut __lookahead;
+        let __last_location: &mut usize = &mut Default::default();
+        '__shift: loop {
+            __lookahead = match __tokens.next() {
+                Some(Ok(v)) => v,
+                None => break '__shift,
+                Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+            };
+            *__last_location = __lookahead.2.clone();
+            __integer = match __lookahead.1 {
+                Tok::LParen if true => 0,
+                Tok::RParen if true => 1,
+                Tok::Times if true => 2,
+                Tok::Plus if true => 3,
+                Tok::Num(_) if true => 4,
+                _ => {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __expected = __expected_tokens(__state);
+                    let __error = match Some(__lookahead) {
+                        Some(__token) => {
+                            __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                        }
+                        None => {
+                            __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                        }
+                    };
+                    return Err(__error);
+                }
+            };
+            '__inner: loop {
+                let __state = *__states.last().unwrap() as usize;
+                let __action = __ACTION[__state * 5 + __integer] as i32;
+                if __action > 0 {
+                    if let Some(__flag) = __cancel {
+                        if __flag.load(__core::sync::atomic::Ordering::Relaxed) {
+                            return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                        }
+                    }
+                    if __symbols.len() >= __symbols.capacity() {
+                        return Err(__lalrpop_util::ParseError::StackCapacity { location: __lookahead.0, capacity: __symbols.capacity() });
+                    }
+                    let __symbol = match __integer {
+                        0 => match __lookahead.1 {
+                            __tok @ Tok::LParen => __Symbol::Term_22_28_22((__tok)),
+                            _ => unreachable!(),
+                        },
+                        1 => match __lookahead.1 {
+                            __tok @ Tok::RParen => __Symbol::Term_22_29_22((__tok)),
+                            _ => unreachable!(),
+                        },
+                        2 => match __lookahead.1 {
+                            __tok @ Tok::Times => __Symbol::Term_22_2a_22((__tok)),
+                            _ => unreachable!(),
+                        },
+                        3 => match __lookahead.1 {
+                            __tok @ Tok::Plus => __Symbol::Term_22_2b_22((__tok)),
+                            _ => unreachable!(),
+                        },
+                        4 => match __lookahead.1 {
+                            Tok::Num(__tok0) => __Symbol::TermNum((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        _ => unreachable!(),
+                    };
+                    __states.push(__action - 1);
+                    __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                    continue '__shift;
+                } else if __action < 0 {
+                    if let Some(__flag) = __cancel {
+                        if __flag.load(__core::sync::atomic::Ordering::Relaxed) {
+                            return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                        }
+                    }
+                    if let Some(r) = __reduce(__action, Some(&__lookahead.0), &mut __states, &mut __symbols, __core::marker::PhantomData::<()>) {
+                        if r.is_err() {
+                            return r;
+                        }
+                        return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: Default::default() });
+                    }
+                } else {
+                    let mut __err_lookahead = Some(__lookahead);
+                    let mut __err_integer: Option<usize> = Some(__integer);
+                    let __state = *__states.last().unwrap() as usize;
+                    let __expected = __expected_tokens(__state);
+                    let __error = match __err_lookahead {
+                        Some(__token) => {
+                            if __states.pop().map_or(false, |__top| __accepts( __top, __states, None, __core::marker::PhantomData::<()>)) {
+                                __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                            } else {
+                                __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                            }
+                        }
+                        None => {
+                            __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                        }
+                    };
+                    return Err(__error)
+                }
+            }
+        }
+        loop {
+            let __state = *__states.last().unwrap() as usize;
+            let __action = __EOF_ACTION[__state] as i32;
+            if __action < 0 {
+                if let Some(__flag) = __cancel {
+                    if __flag.load(__core::sync::atomic::Ordering::Relaxed) {
+                        return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                    }
+                }
+                if let Some(r) = __reduce(__action, None, &mut __states, &mut __symbols, __core::marker::PhantomData::<()>) {
+                    return r;
+                }
+            } else {
+                let mut __err_lookahead = None;
+                let mut __err_integer: Option<usize> = None;
+                let __state = *__states.last().unwrap() as usize;
+                let __expected = __expected_tokens(__state);
+                let __error = match __err_lookahead {
+                    Some(__token) => {
+                        if __states.pop().map_or(false, |__top| __accepts( __top, __states, None, __core::marker::PhantomData::<()>)) {
+                            __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                        } else {
+                            __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                        }
+                    }
+                    None => {
+                        __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                    }
+                };
+                return Err(__error)
+            }
+        }
+    }
+    fn __accepts<
+    >(
+        __error_state: i32,
+        __states: &mut __lalrpop_util::stack::FixedStack<i32>,
+        __opt_integer: Option<usize>,
+        _: __core::marker::PhantomData<()>,
+    ) -> bool
+    {
+        __states.push(__error_state);
+        loop {
+            let mut __states_len = __states.len();
+            let __top = __states[__states_len - 1];
+            let __action = match __opt_integer {
+                None => __EOF_ACTION[__top as usize] as i32,
+                Some(__integer) => __ACTION[(__top * 5) as usize + __integer] as i32,
+            };
+            if __action == 0 { return false; }
+            if __action > 0 { return true; }
+            let (__to_pop, __nt) = match -__action {
+                1 => {
+                    (3, 0)
+                }
+                2 => {
+                    (1, 0)
+                }
+                3 => {
+                    (3, 1)
+                }
+                4 => {
+                    (1, 1)
+                }
+                5 => {
+                    (1, 2)
+                }
+                6 => {
+                    (3, 2)
+                }
+                7 => return true,
+                _ => panic!("invalid action code {}", __action)
+            };
+            __states_len -= __to_pop;
+            __states.truncate(__states_len);
+            let __top = __states[__states_len - 1];
+            let __next_state = __GOTO[(__top * 4 + __nt) as usize] as i32 - 1;
+            if __states.len() >= __states.capacity() { return false; }
+            __states.push(__next_state);
+        }
+    }
+    pub fn __reduce<
+    >(
+        __action: i32,
+        __lookahead_start: Option<&usize>,
+        __states: &mut __lalrpop_util::stack::FixedStack<i32>,
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<>,usize)>,
+        _: __core::marker::PhantomData<()>,
+    ) -> Option<Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+    {
+        let __nonterminal = match -__action {
+            1 => match __reduce_Expr_0(__lookahead_start, __states, __symbols, __core::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            2 => match __reduce_Expr_1(__lookahead_start, __states, __symbols, __core::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            3 => match __reduce_Factor_0(__lookahead_start, __states, __symbols, __core::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            4 => match __reduce_Factor_1(__lookahead_start, __states, __symbols, __core::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            5 => match __reduce_Term_0(__lookahead_start, __states, __symbols, __core::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            6 => match __reduce_Term_1(__lookahead_start, __states, __symbols, __core::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            7 => match __reduce_____Expr_0(__lookahead_start, __states, __symbols, __core::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            _ => panic!("invalid action code {}", __action)
+        };
+        let __state = *__states.last().unwrap() as usize;
+        let __next_state = __GOTO[__state * 4 + __nonterminal] as i32 - 1;
+        __states.push(__next_state);
+        None
+    }
+
+    fn __reduce_Expr_0<
+    >(
+        __lookahead_start: Option<&usize>,
+        __states: &mut __lalrpop_util::stack::FixedStack<i32>,
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<>,usize)>,
+        _: __core::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+    {
+        // Expr = Expr, "+", Factor => ActionFn(1);
+        let __sym2 = __pop_NtFactor(__symbols);
+        let __sym1 = __pop_Term_22_2b_22(__symbols);
+        let __sym0 = __pop_NtExpr(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action1::<>(__sym0, __sym1, __sym2);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 3);
+        __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+        Ok(0)
+    }
+
+    fn __reduce_Expr_1<
+    >(
+        __lookahead_start: Option<&usize>,
+        __states: &mut __lalrpop_util::stack::FixedStack<i32>,
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<>,usize)>,
+        _: __core::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+    {
+        // Expr = Factor => ActionFn(2);
+        let __sym0 = __pop_NtFactor(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action2::<>(__sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+        Ok(0)
+    }
+
+    fn __reduce_Factor_0<
+    >(
+        __lookahead_start: Option<&usize>,
+        __states: &mut __lalrpop_util::stack::FixedStack<i32>,
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<>,usize)>,
+        _: __core::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+    {
+        // Factor = Factor, "*", Term => ActionFn(3);
+        let __sym2 = __pop_NtTerm(__symbols);
+        let __sym1 = __pop_Term_22_2a_22(__symbols);
+        let __sym0 = __pop_NtFactor(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action3::<>(__sym0, __sym1, __sym2);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 3);
+        __symbols.push((__start, __Symbol::NtFactor(__nt), __end));
+        Ok(1)
+    }
+
+    fn __reduce_Factor_1<
+    >(
+        __lookahead_start: Option<&usize>,
+        __states: &mut __lalrpop_util::stack::FixedStack<i32>,
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<>,usize)>,
+        _: __core::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+    {
+        // Factor = Term => ActionFn(4);
+        let __sym0 = __pop_NtTerm(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action4::<>(__sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtFactor(__nt), __end));
+        Ok(1)
+    }
+
+    fn __reduce_Term_0<
+    >(
+        __lookahead_start: Option<&usize>,
+        __states: &mut __lalrpop_util::stack::FixedStack<i32>,
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<>,usize)>,
+        _: __core::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+    {
+        // Term = Num => ActionFn(5);
+        let __sym0 = __pop_TermNum(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action5::<>(__sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+        Ok(2)
+    }
+
+    fn __reduce_Term_1<
+    >(
+        __lookahead_start: Option<&usize>,
+        __states: &mut __lalrpop_util::stack::FixedStack<i32>,
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<>,usize)>,
+        _: __core::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+    {
+        // Term = "(", Expr, ")" => ActionFn(6);
+        let __sym2 = __pop_Term_22_29_22(__symbols);
+        let __sym1 = __pop_NtExpr(__symbols);
+        let __sym0 = __pop_Term_22_28_22(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action6::<>(__sym0, __sym1, __sym2);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 3);
+        __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+        Ok(2)
+    }
+
+    fn __reduce_____Expr_0<
+    >(
+        __lookahead_start: Option<&usize>,
+        __states: &mut __lalrpop_util::stack::FixedStack<i32>,
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<>,usize)>,
+        _: __core::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<usize, Tok, &'static str>>>
+    {
+        // __Expr = Expr => ActionFn(0);
+        let __sym0 = __pop_NtExpr(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action0::<>(__sym0);
+        Err(Ok(__Symbol::Nt____Expr(__nt)))
+    }
+    fn __pop_Term_22_28_22<
+    >(
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<>,usize)>
+    ) -> (usize, Tok, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_28_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_29_22<
+    >(
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<>,usize)>
+    ) -> (usize, Tok, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_29_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2a_22<
+    >(
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<>,usize)>
+    ) -> (usize, Tok, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2a_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2b_22<
+    >(
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<>,usize)>
+    ) -> (usize, Tok, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2b_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_TermNum<
+    >(
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<>,usize)>
+    ) -> (usize, i32, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::TermNum(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtExpr<
+    >(
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<>,usize)>
+    ) -> (usize, i32, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtExpr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtFactor<
+    >(
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<>,usize)>
+    ) -> (usize, i32, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtFactor(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtTerm<
+    >(
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<>,usize)>
+    ) -> (usize, i32, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtTerm(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____Expr<
+    >(
+        __symbols: &mut __lalrpop_util::stack::FixedStack<(usize,__Symbol<>,usize)>
+    ) -> (usize, i32, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Expr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+}
+
+fn __action0<
+>(
+    (_, __0, _): (usize, i32, usize),
+) -> i32
+{
+    (__0)
+}
+
+fn __action1<
+>(
+    (_, l, _): (usize, i32, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, r, _): (usize, i32, usize),
+) -> i32
+{
+    //@ src/no_std_stack.lalrpop:22:32 (1 lines)
+    l + r
+}
+
+fn __action2<
+>(
+    (_, __0, _): (usize, i32, usize),
+) -> i32
+{
+    (__0)
+}
+
+fn __action3<
+>(
+    (_, l, _): (usize, i32, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, r, _): (usize, i32, usize),
+) -> i32
+{
+    //@ src/no_std_stack.lalrpop:27:32 (1 lines)
+    l * r
+}
+
+fn __action4<
+>(
+    (_, __0, _): (usize, i32, usize),
+) -> i32
+{
+    (__0)
+}
+
+fn __action5<
+>(
+    (_, __0, _): (usize, i32, usize),
+) -> i32
+{
+    (__0)
+}
+
+fn __action6<
+>(
+    (_, _, _): (usize, Tok, usize),
+    (_, __0, _): (usize, i32, usize),
+    (_, _, _): (usize, Tok, usize),
+) -> i32
+{
+    (__0)
+}
+
+pub trait __ToTriple<> {
+    type Error;
+    fn to_triple(value: Self) -> Result<(usize,Tok,usize),Self::Error>;
+}
+
+impl<> __ToTriple<> for (usize, Tok, usize) {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Tok,usize),&'static str> {
+        Ok(value)
+    }
+}
+impl<> __ToTriple<> for Result<(usize, Tok, usize),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Tok,usize),&'static str> {
+        value
+    }
+}
@@ -0,0 +1,1157 @@
+// auto-generated: "lalrpop 0.15.0"
+use std::str::FromStr;
+use lalrpop_util::ParseError;
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+pub use self::__parse_table::parse_Expr;
+pub use self::__parse_table::parse_Expr_cancelable;
+pub use self::__parse_table::ExprParser;
+
+mod __parse_table {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    use std::str::FromStr;
+    use lalrpop_util::ParseError;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__intern_token::Token;
+    #[allow(dead_code)]
+    pub enum __Symbol<'input>
+     {
+        Term_22_28_22(&'input str),
+        Term_22_29_22(&'input str),
+        Term_22_2a_22(&'input str),
+        Term_22_2b_22(&'input str),
+        Term_22_2d_22(&'input str),
+        Termr_23_22_5b0_2d9_5d_2b_22_23(&'input str),
+        NtExpr(i32),
+        NtFactor(i32),
+        NtNum(i32),
+        NtTerm(i32),
+        Nt____Expr(i32),
+    }
+    impl<'input> ::std::fmt::Debug for __Symbol<'input>
+    {
+        fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            match *self {
+                __Symbol::Term_22_28_22(..) => __f.write_str("\"(\""),
+                __Symbol::Term_22_29_22(..) => __f.write_str("\")\""),
+                __Symbol::Term_22_2a_22(..) => __f.write_str("\"*\""),
+                __Symbol::Term_22_2b_22(..) => __f.write_str("\"+\""),
+                __Symbol::Term_22_2d_22(..) => __f.write_str("\"-\""),
+                __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(..) => __f.write_str("r#\"[0-9]+\"#"),
+                __Symbol::NtExpr(..) => __f.write_str("Expr"),
+                __Symbol::NtFactor(..) => __f.write_str("Factor"),
+                __Symbol::NtNum(..) => __f.write_str("Num"),
+                __Symbol::NtTerm(..) => __f.write_str("Term"),
+                __Symbol::Nt____Expr(..) => __f.write_str("__Expr"),
+            }
+        }
+    }
+    const __ACTION: &'static [i8] = &[
+        // State 0
+        6, 0, 0, 0, 7, 8,
+        // State 1
+        0, 0, 0, 9, 10, 0,
+        // State 2
+        0, -3, 11, -3, -3, 0,
+        // State 3
+        0, -7, -7, -7, -7, 0,
+        // State 4
+        0, -5, -5, -5, -5, 0,
+        // State 5
+        6, 0, 0, 0, 7, 8,
+        // State 6
+        6, 0, 0, 0, 7, 8,
+        // State 7
+        0, -6, -6, -6, -6, 0,
+        // State 8
+        6, 0, 0, 0, 7, 8,
+        // State 9
+        6, 0, 0, 0, 7, 8,
+        // State 10
+        6, 0, 0, 0, 7, 8,
+        // State 11
+        0, 17, 0, 9, 10, 0,
+        // State 12
+        0, -9, -9, -9, -9, 0,
+        // State 13
+        0, -1, 11, -1, -1, 0,
+        // State 14
+        0, -2, 11, -2, -2, 0,
+        // State 15
+        0, -4, -4, -4, -4, 0,
+        // State 16
+        0, -8, -8, -8, -8, 0,
+    ];
+    const __EOF_ACTION: &'static [i8] = &[
+        // State 0
+        0,
+        // State 1
+        -10,
+        // State 2
+        -3,
+        // State 3
+        -7,
+        // State 4
+        -5,
+        // State 5
+        0,
+        // State 6
+        0,
+        // State 7
+        -6,
+        // State 8
+        0,
+        // State 9
+        0,
+        // State 10
+        0,
+        // State 11
+        0,
+        // State 12
+        -9,
+        // State 13
+        -1,
+        // State 14
+        -2,
+        // State 15
+        -4,
+        // State 16
+        -8,
+    ];
+    const __GOTO: &'static [u8] = &[
+        // State 0
+        2, 3, 4, 5, 0,
+        // State 1
+        0, 0, 0, 0, 0,
+        // State 2
+        0, 0, 0, 0, 0,
+        // State 3
+        0, 0, 0, 0, 0,
+        // State 4
+        0, 0, 0, 0, 0,
+        // State 5
+        12, 3, 4, 5, 0,
+        // State 6
+        0, 0, 4, 13, 0,
+        // State 7
+        0, 0, 0, 0, 0,
+        // State 8
+        0, 14, 4, 5, 0,
+        // State 9
+        0, 15, 4, 5, 0,
+        // State 10
+        0, 0, 4, 16, 0,
+        // State 11
+        0, 0, 0, 0, 0,
+        // State 12
+        0, 0, 0, 0, 0,
+        // State 13
+        0, 0, 0, 0, 0,
+        // State 14
+        0, 0, 0, 0, 0,
+        // State 15
+        0, 0, 0, 0, 0,
+        // State 16
+        0, 0, 0, 0, 0,
+    ];
+    const __DEFAULT_ACTION: &'static [i8] = &[
+        // State 0
+        0,
+        // State 1
+        0,
+        // State 2
+        0,
+        // State 3
+        -7,
+        // State 4
+        -5,
+        // State 5
+        0,
+        // State 6
+        0,
+        // State 7
+        0,
+        // State 8
+        0,
+        // State 9
+        0,
+        // State 10
+        0,
+        // State 11
+        0,
+        // State 12
+        -9,
+        // State 13
+        0,
+        // State 14
+        0,
+        // State 15
+        -4,
+        // State 16
+        -8,
+    ];
+    fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+        const __TERMINAL: &'static [&'static str] = &[
+            r###""(""###,
+            r###"")""###,
+            r###""*""###,
+            r###""+""###,
+            r###""-""###,
+            r###"r#"[0-9]+"#"###,
+        ];
+        __ACTION.iter().skip(__state * 6).zip(__TERMINAL).filter_map(|(&state, terminal)| {
+            if state == 0 {
+                None
+            } else {
+                Some(terminal.to_string())
+            }
+        }).collect()
+    }
+    #[allow(dead_code)]
+    pub fn parse_Expr<
+        'input,
+    >(
+        input: &'input str,
+    ) -> Result<i32, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let mut __tokens = super::__intern_token::__Matcher::new(input);
+        let mut __states = vec![];
+        let mut __symbols = vec![];
+        __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("symbol type mismatch") }) })
+    }
+    #[allow(dead_code)]
+    pub fn parse_Expr_cancelable<
+        'input,
+    >(
+        input: &'input str,
+        __cancel: &::std::sync::atomic::AtomicBool,
+    ) -> Result<i32, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let mut __tokens = super::__intern_token::__Matcher::new(input);
+        let mut __states = vec![];
+        let mut __symbols = vec![];
+        __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("symbol type mismatch") }) })
+    }
+
+    #[allow(dead_code)]
+    pub struct ExprParser {
+        __states: ::std::vec::Vec<i32>,
+        __symbols: __lalrpop_util::stack::Recycled,
+        __cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+    }
+
+    #[allow(dead_code)]
+    impl ExprParser {
+        pub fn new() -> ExprParser {
+            ExprParser { __states: vec![], __symbols: __lalrpop_util::stack::Recycled::new(), __cancel: None }
+        }
+
+        pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+            self.__cancel = Some(flag);
+        }
+
+        pub fn parse_in_place<'input>(
+            &mut self,
+            input: &'input str,
+        ) -> Result<i32, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+        {
+            let mut __tokens = super::__intern_token::__Matcher::new(input);
+            let mut __symbols = self.__symbols.take();
+            let __result = __parse::<>(input, 0, &mut __tokens, &mut self.__states, &mut __symbols, self.__cancel.as_ref().map(|c| &**c)).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("symbol type mismatch") }) });
+            self.__symbols.put(__symbols);
+            __result
+        }
+    }
+
+    fn __parse<
+        'input,
+    >(
+        input: &'input str,
+        __start_state: i32,
+        mut __tokens: &mut Iterator<Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+        mut __states: &mut ::std::vec::Vec<i32>,
+        mut __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        __cancel: Option<&::std::sync::atomic::AtomicBool>,
+    ) -> Result<__Symbol<'input>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        __states.clear();
+        __states.push(__start_state);
+        __symbols.clear();
+        let mut __integer;
+        let mut __lookahead;
+        let __last_location: &mut usize = &mut Default::default();
+        let mut __default_reduced = [0_usize; 16];
+        let mut __default_reduced_len = 0;
+        '__shift: loop {
+            loop {
+                let __state = match __states.last() { Some(&__s) => __s as usize, None => return Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("empty state stack") }) };
+                let __action = (match __DEFAULT_ACTION.get(__state) { Some(&__a) => __a, None => return Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("invalid state") }) }) as i32;
+                if __action == 0 {
+                    break;
+                }
+                if __default_reduced_len < 16 {
+                    __default_reduced[__default_reduced_len] = __state;
+                    __default_reduced_len += 1;
+                }
+                if let Some(__flag) = __cancel {
+                    if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                        return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                    }
+                }
+                if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                    return r;
+                }
+            }
+            __lookahead = match __tokens.next() {
+                Some(Ok(v)) => v,
+                None => break '__shift,
+                Some(Err(e)) => return Err(e),
+            };
+            *__last_location = __lookahead.2.clone();
+            __integer = match __lookahead.1 {
+                Token(1, _) if true => 0,
+                Token(2, _) if true => 1,
+                Token(3, _) if true => 2,
+                Token(4, _) if true => 3,
+                Token(5, _) if true => 4,
+                Token(0, _) if true => 5,
+                _ => {
+                    let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| true).unwrap_or(match __states.last() { Some(&__s) => __s as usize, None => return Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("empty state stack") }) });
+                    let __expected = __expected_tokens(__state);
+                    let __error = match Some(__lookahead) {
+                        Some(__token) => {
+                            __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                        }
+                        None => {
+                            __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                        }
+                    };
+                    return Err(__error);
+                }
+            };
+            '__inner: loop {
+                let __state = match __states.last() { Some(&__s) => __s as usize, None => return Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("empty state stack") }) };
+                let __action = (match __ACTION.get(__state * 6 + __integer) { Some(&__a) => __a, None => return Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("invalid state") }) }) as i32;
+                if __action > 0 {
+                    if let Some(__flag) = __cancel {
+                        if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                            return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                        }
+                    }
+                    let __symbol = match __integer {
+                        0 => match __lookahead.1 {
+                            Token(1, __tok0) => __Symbol::Term_22_28_22((__tok0)),
+                            _ => return Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("token does not match its terminal") }),
+                        },
+                        1 => match __lookahead.1 {
+                            Token(2, __tok0) => __Symbol::Term_22_29_22((__tok0)),
+                            _ => return Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("token does not match its terminal") }),
+                        },
+                        2 => match __lookahead.1 {
+                            Token(3, __tok0) => __Symbol::Term_22_2a_22((__tok0)),
+                            _ => return Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("token does not match its terminal") }),
+                        },
+                        3 => match __lookahead.1 {
+                            Token(4, __tok0) => __Symbol::Term_22_2b_22((__tok0)),
+                            _ => return Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("token does not match its terminal") }),
+                        },
+                        4 => match __lookahead.1 {
+                            Token(5, __tok0) => __Symbol::Term_22_2d_22((__tok0)),
+                            _ => return Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("token does not match its terminal") }),
+                        },
+                        5 => match __lookahead.1 {
+                            Token(0, __tok0) => __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23((__tok0)),
+                            _ => return Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("token does not match its terminal") }),
+                        },
+                        _ => return Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("token does not match its terminal") }),
+                    };
+                    __states.push(__action - 1);
+                    __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                    __default_reduced_len = 0;
+                    continue '__shift;
+                } else if __action < 0 {
+                    if let Some(__flag) = __cancel {
+                        if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                            return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                        }
+                    }
+                    if let Some(r) = __reduce(input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                        if r.is_err() {
+                            return r;
+                        }
+                        return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] });
+                    }
+                } else {
+                    let mut __err_lookahead = Some(__lookahead);
+                    let mut __err_integer: Option<usize> = Some(__integer);
+                    let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION.get(__s * 6 + __i).map_or(true, |&__a| __a == 0), None => __EOF_ACTION.get(__s).map_or(true, |&__a| __a == 0) }).unwrap_or(match __states.last() { Some(&__s) => __s as usize, None => return Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("empty state stack") }) });
+                    let __expected = __expected_tokens(__state);
+                    let __error = match __err_lookahead {
+                        Some(__token) => {
+                            if __states.split_last().map_or(false, |(&__top, __rest)| __accepts(input,  __top, __rest, None, ::std::marker::PhantomData::<()>)) {
+                                __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                            } else {
+                                __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                            }
+                        }
+                        None => {
+                            __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                        }
+                    };
+                    return Err(__error)
+                }
+            }
+        }
+        loop {
+            let __state = match __states.last() { Some(&__s) => __s as usize, None => return Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("empty state stack") }) };
+            let __action = (match __EOF_ACTION.get(__state) { Some(&__a) => __a, None => return Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("invalid state") }) }) as i32;
+            if __action < 0 {
+                if let Some(__flag) = __cancel {
+                    if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                        return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                    }
+                }
+                if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                    return r;
+                }
+            } else {
+                let mut __err_lookahead = None;
+                let mut __err_integer: Option<usize> = None;
+                let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION.get(__s * 6 + __i).map_or(true, |&__a| __a == 0), None => __EOF_ACTION.get(__s).map_or(true, |&__a| __a == 0) }).unwrap_or(match __states.last() { Some(&__s) => __s as usize, None => return Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("empty state stack") }) });
+                let __expected = __expected_tokens(__state);
+                let __error = match __err_lookahead {
+                    Some(__token) => {
+                        if __states.split_last().map_or(false, |(&__top, __rest)| __accepts(input,  __top, __rest, None, ::std::marker::PhantomData::<()>)) {
+                            __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                        } else {
+                            __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                        }
+                    }
+                    None => {
+                        __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                    }
+                };
+                return Err(__error)
+            }
+        }
+    }
+    fn __accepts<
+        'input,
+    >(
+        input: &'input str,
+        __error_state: i32,
+        __states: & [i32],
+        __opt_integer: Option<usize>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> bool
+    {
+        let mut __states = __states.to_vec();
+        __states.push(__error_state);
+        loop {
+            let mut __states_len = __states.len();
+            let __top = match __states.last() { Some(&__s) => __s, None => return false };
+            let __action = match __opt_integer {
+                None => (match __EOF_ACTION.get(__top as usize) { Some(&__a) => __a, None => return false }) as i32,
+                Some(__integer) => (match __ACTION.get((__top * 6) as usize + __integer) { Some(&__a) => __a, None => return false }) as i32,
+            };
+            if __action == 0 { return false; }
+            if __action > 0 { return true; }
+            let (__to_pop, __nt) = match -__action {
+                1 => {
+                    (3, 0)
+                }
+                2 => {
+                    (3, 0)
+                }
+                3 => {
+                    (1, 0)
+                }
+                4 => {
+                    (3, 1)
+                }
+                5 => {
+                    (1, 1)
+                }
+                6 => {
+                    (1, 2)
+                }
+                7 => {
+                    (1, 3)
+                }
+                8 => {
+                    (3, 3)
+                }
+                9 => {
+                    (2, 3)
+                }
+                10 => return true,
+                _ => return false,
+            };
+            if __to_pop > __states_len { return false; }
+            __states_len -= __to_pop;
+            __states.truncate(__states_len);
+            let __top = match __states.last() { Some(&__s) => __s, None => return false };
+            let __next_state = (match __GOTO.get((__top * 5 + __nt) as usize) { Some(&__a) => __a, None => return false }) as i32 - 1;
+            __states.push(__next_state);
+        }
+    }
+    pub fn __reduce<
+        'input,
+    >(
+        input: &'input str,
+        __action: i32,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Option<Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        let __nonterminal = match -__action {
+            1 => match __reduce_Expr_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            2 => match __reduce_Expr_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            3 => match __reduce_Expr_2(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            4 => match __reduce_Factor_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            5 => match __reduce_Factor_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            6 => match __reduce_Num_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            7 => match __reduce_Term_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            8 => match __reduce_Term_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            9 => match __reduce_Term_2(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            10 => match __reduce_____Expr_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            _ => return Some(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("invalid action code") })),
+        };
+        let __state = match __states.last() { Some(&__s) => __s as usize, None => return Some(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("empty state stack") })) };
+        let __next_state = match __GOTO.get(__state * 5 + __nonterminal) { Some(&__g) if __g > 0 => __g as i32 - 1, _ => return Some(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("invalid goto") })) };
+        __states.push(__next_state);
+        None
+    }
+
+    fn __reduce_Expr_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Expr = Expr, "+", Factor => ActionFn(1);
+        let __sym2 = match __pop_NtFactor(__symbols) { Some(__v) => __v, None => return Err(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("symbol type mismatch") })) };
+        let __sym1 = match __pop_Term_22_2b_22(__symbols) { Some(__v) => __v, None => return Err(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("symbol type mismatch") })) };
+        let __sym0 = match __pop_NtExpr(__symbols) { Some(__v) => __v, None => return Err(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("symbol type mismatch") })) };
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action1::<>(input, __sym0, __sym1, __sym2);
+        let __states_len = __states.len();
+        if __states_len < 3 { return Err(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("state stack underflow") })); }
+        __states.truncate(__states_len - 3);
+        __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+        Ok(0)
+    }
+
+    fn __reduce_Expr_1<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Expr = Expr, "-", Factor => ActionFn(2);
+        let __sym2 = match __pop_NtFactor(__symbols) { Some(__v) => __v, None => return Err(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("symbol type mismatch") })) };
+        let __sym1 = match __pop_Term_22_2d_22(__symbols) { Some(__v) => __v, None => return Err(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("symbol type mismatch") })) };
+        let __sym0 = match __pop_NtExpr(__symbols) { Some(__v) => __v, None => return Err(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("symbol type mismatch") })) };
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action2::<>(input, __sym0, __sym1, __sym2);
+        let __states_len = __states.len();
+        if __states_len < 3 { return Err(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("state stack underflow") })); }
+        __states.truncate(__states_len - 3);
+        __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+        Ok(0)
+    }
+
+    fn __reduce_Expr_2<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Expr = Factor => ActionFn(3);
+        let __sym0 = match __pop_NtFactor(__symbols) { Some(__v) => __v, None => return Err(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("symbol type mismatch") })) };
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action3::<>(input, __sym0);
+        let __states_len = __states.len();
+        if __states_len < 1 { return Err(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("state stack underflow") })); }
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+        Ok(0)
+    }
+
+    fn __reduce_Factor_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Factor = Factor, "*", Term => ActionFn(4);
+        let __sym2 = match __pop_NtTerm(__symbols) { Some(__v) => __v, None => return Err(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("symbol type mismatch") })) };
+        let __sym1 = match __pop_Term_22_2a_22(__symbols) { Some(__v) => __v, None => return Err(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("symbol type mismatch") })) };
+        let __sym0 = match __pop_NtFactor(__symbols) { Some(__v) => __v, None => return Err(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("symbol type mismatch") })) };
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action4::<>(input, __sym0, __sym1, __sym2);
+        let __states_len = __states.len();
+        if __states_len < 3 { return Err(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("state stack underflow") })); }
+        __states.truncate(__states_len - 3);
+        __symbols.push((__start, __Symbol::NtFactor(__nt), __end));
+        Ok(1)
+    }
+
+    fn __reduce_Factor_1<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Factor = Term => ActionFn(5);
+        let __sym0 = match __pop_NtTerm(__symbols) { Some(__v) => __v, None => return Err(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("symbol type mismatch") })) };
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action5::<>(input, __sym0);
+        let __states_len = __states.len();
+        if __states_len < 1 { return Err(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("state stack underflow") })); }
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtFactor(__nt), __end));
+        Ok(1)
+    }
+
+    fn __reduce_Num_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Num = r#"[0-9]+"# => ActionFn(9);
+        let __sym0 = match __pop_Termr_23_22_5b0_2d9_5d_2b_22_23(__symbols) { Some(__v) => __v, None => return Err(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("symbol type mismatch") })) };
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = match super::__action9::<>(input, __sym0) {
+            Ok(v) => v,
+            Err(e) => return Err(Err(e)),
+        };
+        let __states_len = __states.len();
+        if __states_len < 1 { return Err(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("state stack underflow") })); }
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtNum(__nt), __end));
+        Ok(2)
+    }
+
+    fn __reduce_Term_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Term = Num => ActionFn(6);
+        let __sym0 = match __pop_NtNum(__symbols) { Some(__v) => __v, None => return Err(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("symbol type mismatch") })) };
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action6::<>(input, __sym0);
+        let __states_len = __states.len();
+        if __states_len < 1 { return Err(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("state stack underflow") })); }
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+        Ok(3)
+    }
+
+    fn __reduce_Term_1<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Term = "(", Expr, ")" => ActionFn(7);
+        let __sym2 = match __pop_Term_22_29_22(__symbols) { Some(__v) => __v, None => return Err(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("symbol type mismatch") })) };
+        let __sym1 = match __pop_NtExpr(__symbols) { Some(__v) => __v, None => return Err(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("symbol type mismatch") })) };
+        let __sym0 = match __pop_Term_22_28_22(__symbols) { Some(__v) => __v, None => return Err(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("symbol type mismatch") })) };
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action7::<>(input, __sym0, __sym1, __sym2);
+        let __states_len = __states.len();
+        if __states_len < 3 { return Err(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("state stack underflow") })); }
+        __states.truncate(__states_len - 3);
+        __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+        Ok(3)
+    }
+
+    fn __reduce_Term_2<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Term = "-", Term => ActionFn(8);
+        let __sym1 = match __pop_NtTerm(__symbols) { Some(__v) => __v, None => return Err(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("symbol type mismatch") })) };
+        let __sym0 = match __pop_Term_22_2d_22(__symbols) { Some(__v) => __v, None => return Err(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("symbol type mismatch") })) };
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action8::<>(input, __sym0, __sym1);
+        let __states_len = __states.len();
+        if __states_len < 2 { return Err(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("state stack underflow") })); }
+        __states.truncate(__states_len - 2);
+        __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+        Ok(3)
+    }
+
+    fn __reduce_____Expr_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // __Expr = Expr => ActionFn(0);
+        let __sym0 = match __pop_NtExpr(__symbols) { Some(__v) => __v, None => return Err(Err(__lalrpop_util::ParseError::Internal { message: ::std::convert::From::from("symbol type mismatch") })) };
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action0::<>(input, __sym0);
+        Err(Ok(__Symbol::Nt____Expr(__nt)))
+    }
+    fn __pop_Term_22_28_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> Option<(usize, &'input str, usize)>
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Term_22_28_22(__v), __r)) => Some((__l, __v, __r)),
+            _ => None,
+        }
+    }
+    fn __pop_Term_22_29_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> Option<(usize, &'input str, usize)>
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Term_22_29_22(__v), __r)) => Some((__l, __v, __r)),
+            _ => None,
+        }
+    }
+    fn __pop_Term_22_2a_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> Option<(usize, &'input str, usize)>
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Term_22_2a_22(__v), __r)) => Some((__l, __v, __r)),
+            _ => None,
+        }
+    }
+    fn __pop_Term_22_2b_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> Option<(usize, &'input str, usize)>
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Term_22_2b_22(__v), __r)) => Some((__l, __v, __r)),
+            _ => None,
+        }
+    }
+    fn __pop_Term_22_2d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> Option<(usize, &'input str, usize)>
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Term_22_2d_22(__v), __r)) => Some((__l, __v, __r)),
+            _ => None,
+        }
+    }
+    fn __pop_Termr_23_22_5b0_2d9_5d_2b_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> Option<(usize, &'input str, usize)>
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(__v), __r)) => Some((__l, __v, __r)),
+            _ => None,
+        }
+    }
+    fn __pop_NtExpr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> Option<(usize, i32, usize)>
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::NtExpr(__v), __r)) => Some((__l, __v, __r)),
+            _ => None,
+        }
+    }
+    fn __pop_NtFactor<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> Option<(usize, i32, usize)>
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::NtFactor(__v), __r)) => Some((__l, __v, __r)),
+            _ => None,
+        }
+    }
+    fn __pop_NtNum<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> Option<(usize, i32, usize)>
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::NtNum(__v), __r)) => Some((__l, __v, __r)),
+            _ => None,
+        }
+    }
+    fn __pop_NtTerm<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> Option<(usize, i32, usize)>
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::NtTerm(__v), __r)) => Some((__l, __v, __r)),
+            _ => None,
+        }
+    }
+    fn __pop_Nt____Expr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> Option<(usize, i32, usize)>
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Nt____Expr(__v), __r)) => Some((__l, __v, __r)),
+            _ => None,
+        }
+    }
+}
+mod __intern_token {
+    #![allow(unused_imports)]
+    use std::str::FromStr;
+    use lalrpop_util::ParseError;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    extern crate regex as __regex;
+    use std::fmt as __fmt;
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Token<'input>(pub usize, pub &'input str);
+    impl<'a> __fmt::Display for Token<'a> {
+        fn fmt(&self, formatter: &mut __fmt::Formatter) -> Result<(), __fmt::Error> {
+            __fmt::Display::fmt(self.1, formatter)
+        }
+    }
+
+    pub struct __Matcher<'input> {
+        text: &'input str,
+        consumed: usize,
+        regex_set: Option<__regex::RegexSet>,
+        regex_vec: Vec<Option<__regex::Regex>>,
+    }
+
+    impl<'input> __Matcher<'input> {
+        pub fn new(s: &'input str) -> __Matcher<'input> {
+            let __strs: &[&str] = &[
+                "^(?u:[0-9])+",
+                "^(?u:\\()",
+                "^(?u:\\))",
+                "^(?u:\\*)",
+                "^(?u:\\+)",
+                "^(?u:\\-)",
+            ];
+            let __regex_set = __regex::RegexSet::new(__strs).ok();
+            let __regex_vec = vec![
+                __regex::Regex::new("^(?u:[0-9])+").ok(),
+                __regex::Regex::new("^(?u:\\()").ok(),
+                __regex::Regex::new("^(?u:\\))").ok(),
+                __regex::Regex::new("^(?u:\\*)").ok(),
+                __regex::Regex::new("^(?u:\\+)").ok(),
+                __regex::Regex::new("^(?u:\\-)").ok(),
+            ];
+            __Matcher {
+                text: s,
+                consumed: 0,
+                regex_set: __regex_set,
+                regex_vec: __regex_vec,
+            }
+        }
+    }
+
+    impl<'input> Iterator for __Matcher<'input> {
+        type Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize,Token<'input>,&'static str>>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let __text = self.text.trim_left();
+            let __whitespace = self.text.len() - __text.len();
+            let __start_offset = self.consumed + __whitespace;
+            if __text.is_empty() {
+                self.text = __text;
+                self.consumed = __start_offset;
+                None
+            } else {
+                let __matches = match self.regex_set {
+                    Some(ref __regex_set) => __regex_set.matches(__text),
+                    None => return Some(Err(__lalrpop_util::ParseError::Internal { message: ::std::string::String::from("invalid regex") })),
+                };
+                if !__matches.matched_any() {
+                    Some(Err(__lalrpop_util::ParseError::InvalidToken {
+                        location: __start_offset,
+                    }))
+                } else {
+                    let mut __longest_match = 0;
+                    let mut __index = 0;
+                    for __i in 0 .. 6 {
+                        if __matches.matched(__i) {
+                            let __len = match self.regex_vec.get(__i) {
+                                Some(&Some(ref __regex)) => match __regex.find(__text) {
+                                    Some(__match) => __match.end(),
+                                    None => return Some(Err(__lalrpop_util::ParseError::Internal { message: ::std::string::String::from("regex set and regex disagree") })),
+                                },
+                                _ => return Some(Err(__lalrpop_util::ParseError::Internal { message: ::std::string::String::from("invalid regex") })),
+                            };
+                            if __len >= __longest_match {
+                                __longest_match = __len;
+                                __index = __i;
+                            }
+                        }
+                    }
+                    if !__text.is_char_boundary(__longest_match) {
+                        return Some(Err(__lalrpop_util::ParseError::Internal { message: ::std::string::String::from("match ends inside a character") }));
+                    }
+                    let __result = &__text[..__longest_match];
+                    let __remaining = &__text[__longest_match..];
+                    let __end_offset = __start_offset + __longest_match;
+                    self.text = __remaining;
+                    self.consumed = __end_offset;
+                    Some(Ok((__start_offset, Token(__index, __result), __end_offset)))
+                }
+            }
+        }
+    }
+}
+pub use self::__intern_token::Token;
+
+#[allow(unused_variables)]
+fn __action0<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, i32, usize),
+) -> i32
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, i32, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, i32, usize),
+) -> i32
+{
+    //@ src/panic_free.lalrpop:15:32 (1 lines)
+    l.wrapping_add(r)
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, i32, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, i32, usize),
+) -> i32
+{
+    //@ src/panic_free.lalrpop:16:32 (1 lines)
+    l.wrapping_sub(r)
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, i32, usize),
+) -> i32
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, i32, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, i32, usize),
+) -> i32
+{
+    //@ src/panic_free.lalrpop:21:32 (1 lines)
+    l.wrapping_mul(r)
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, i32, usize),
+) -> i32
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, i32, usize),
+) -> i32
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, i32, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> i32
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, i32, usize),
+) -> i32
+{
+    //@ src/panic_free.lalrpop:28:19 (1 lines)
+    __0.wrapping_neg()
+}
+
+#[allow(unused_variables)]
+fn __action9<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Result<i32,__lalrpop_util::ParseError<usize,Token<'input>,&'static str>>
+{
+    //@ src/panic_free.lalrpop:31:26 (3 lines)
+    i32::from_str(__0).map_err(|_| ParseError::User {
+    error: "number is too big"
+})
+}
+
+pub trait __ToTriple<'input, > {
+    type Error;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),Self::Error>;
+}
+
+impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize) {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        Ok(value)
+    }
+}
+impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        value
+    }
+}
@@ -0,0 +1,11 @@
+// auto-generated: "lalrpop 0.15.0"
+#[doc(hidden)]
+#[path = "nested/product.rs"]
+pub mod __nested__product;
+
+#[path = "sum.rs"]
+pub mod sum;
+
+pub mod nested {
+    pub use super::__nested__product as product;
+}
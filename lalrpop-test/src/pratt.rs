@@ -0,0 +1,4123 @@
+// auto-generated: "lalrpop 0.15.0"
+use std::str::FromStr;
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+
+mod __parse__Expr {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    use std::str::FromStr;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__intern_token::Token;
+    #[allow(dead_code)]
+    pub fn parse_Expr<
+        'input,
+    >(
+        input: &'input str,
+    ) -> Result<i32, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let __ascent = __ascent::parse_Expr(
+            input,
+        );
+        let __parse_table = __parse_table::parse_Expr(
+            input,
+        );
+        assert_eq!(__ascent, __parse_table);
+        return __ascent;
+    }
+    mod __ascent {
+
+        mod __parse__Expr {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use std::str::FromStr;
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__intern_token::Token;
+            #[allow(dead_code)]
+            pub fn parse_Expr<
+                'input,
+            >(
+                input: &'input str,
+            ) -> Result<i32, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let __last_location = ::std::cell::RefCell::new(Default::default());
+                let mut __tokens = __tokens.inspect(|t| if let Ok(ref t) = *t { *__last_location.borrow_mut() = t.2.clone(); });
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                let mut __extra = None;
+                let __result = __state0(input, &mut __tokens, __lookahead, &mut __extra, ::std::marker::PhantomData::<()>);
+                if let Some((__token, __expected)) = __extra {
+                    return Err(match __result {
+                        Ok(_) => __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected },
+                        Err(_) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                    });
+                }
+                match __result {
+                    Ok((Some(__lookahead), _)) => {
+                        Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] })
+                    }
+                    Ok((None, __Nonterminal::____Expr((_, __nt, _)))) => {
+                        Ok(__nt)
+                    }
+                    Ok(_) => unreachable!(),
+                    Err(__lalrpop_util::ParseError::UnrecognizedEof { expected: __expected, .. }) => {
+                        Err(__lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.borrow().clone(), expected: __expected })
+                    }
+                    Err(__error) => Err(__error),
+                }
+            }
+
+            #[allow(dead_code)]
+            pub enum __Nonterminal<>
+             {
+                Expr((usize, i32, usize)),
+                Expr_232((usize, i32, usize)),
+                Expr_233((usize, i32, usize)),
+                Expr_234((usize, i32, usize)),
+                Expr_235((usize, i32, usize)),
+                Expr_23atom((usize, i32, usize)),
+                ____Expr((usize, i32, usize)),
+            }
+            impl<> ::std::fmt::Debug for __Nonterminal<>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Nonterminal::Expr(..) => __f.write_str("Expr"),
+                        __Nonterminal::Expr_232(..) => __f.write_str("Expr#2"),
+                        __Nonterminal::Expr_233(..) => __f.write_str("Expr#3"),
+                        __Nonterminal::Expr_234(..) => __f.write_str("Expr#4"),
+                        __Nonterminal::Expr_235(..) => __f.write_str("Expr#5"),
+                        __Nonterminal::Expr_23atom(..) => __f.write_str("Expr#atom"),
+                        __Nonterminal::____Expr(..) => __f.write_str("__Expr"),
+                    }
+                }
+            }
+
+            // State 0
+            //     AllInputs = []
+            //     OptionalInputs = []
+            //     FixedInputs = []
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Expr = (*) Expr "+" Expr#2 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr = (*) Expr "-" Expr#2 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr = (*) Expr#2 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#2 = (*) Expr#2 "*" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#2 = (*) Expr#2 "/" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#2 = (*) Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#3 = (*) Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#3 = (*) "-" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#4 = (*) Expr#5 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#4 = (*) Expr#5 "^" Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#5 = (*) Expr#5 "!" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#5 = (*) Expr#atom ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#atom = (*) "(" Expr ")" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#atom = (*) r#"[0-9]+"# ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     __Expr = (*) Expr ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //
+            //   "(" -> S7
+            //   "-" -> S8
+            //   r#"[0-9]+"# -> S9
+            //
+            //     Expr -> S1
+            //     Expr#2 -> S2
+            //     Expr#3 -> S3
+            //     Expr#4 -> S4
+            //     Expr#5 -> S5
+            //     Expr#atom -> S6
+            fn __state0<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state8(input, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state9(input, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Expr(__sym0) => {
+                            __result = try!(__state1(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Expr_232(__sym0) => {
+                            __result = try!(__state2(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Expr_233(__sym0) => {
+                            __result = try!(__state3(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Expr_234(__sym0) => {
+                            __result = try!(__state4(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Expr_235(__sym0) => {
+                            __result = try!(__state5(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Expr_23atom(__sym0) => {
+                            __result = try!(__state6(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 1
+            //     AllInputs = [Expr]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Expr = Expr (*) "+" Expr#2 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr = Expr (*) "-" Expr#2 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     __Expr = Expr (*) ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //
+            //   "+" -> S10
+            //   "-" -> S11
+            //   [EOF] -> __Expr = Expr => ActionFn(0);
+            //
+            fn __state1<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, i32, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, Token(5, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state10(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state11(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(input, __sym0);
+                        let __nt = __Nonterminal::____Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(input, __sym0);
+                        let __nt = __Nonterminal::____Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 2
+            //     AllInputs = [Expr#2]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr#2]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Expr = Expr#2 (*) ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#2 = Expr#2 (*) "*" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#2 = Expr#2 (*) "/" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //
+            //   "*" -> S12
+            //   "/" -> S13
+            //   [")", "+", "-", EOF] -> Expr = Expr#2 => ActionFn(3);
+            //
+            fn __state2<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, i32, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, Token(4, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state12(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, Token(7, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state13(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action3::<>(input, __sym0);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action3::<>(input, __sym0);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 3
+            //     AllInputs = [Expr#3]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr#3]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Expr#2)
+            //
+            //     Expr#2 = Expr#3 (*) ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //
+            //   [")", "*", "+", "-", "/", EOF] -> Expr#2 = Expr#3 => ActionFn(6);
+            //
+            fn __state3<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, i32, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(7, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action6::<>(input, __sym0);
+                        let __nt = __Nonterminal::Expr_232((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action6::<>(input, __sym0);
+                        let __nt = __Nonterminal::Expr_232((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 4
+            //     AllInputs = [Expr#4]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr#4]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Expr#3)
+            //
+            //     Expr#3 = Expr#4 (*) ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //
+            //   [")", "*", "+", "-", "/", EOF] -> Expr#3 = Expr#4 => ActionFn(8);
+            //
+            fn __state4<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, i32, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(7, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action8::<>(input, __sym0);
+                        let __nt = __Nonterminal::Expr_233((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action8::<>(input, __sym0);
+                        let __nt = __Nonterminal::Expr_233((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 5
+            //     AllInputs = [Expr#5]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr#5]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Expr#4 = Expr#5 (*) ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#4 = Expr#5 (*) "^" Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#5 = Expr#5 (*) "!" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //
+            //   "!" -> S14
+            //   "^" -> S15
+            //   [")", "*", "+", "-", "/", EOF] -> Expr#4 = Expr#5 => ActionFn(10);
+            //
+            fn __state5<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, i32, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state14(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, Token(8, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state15(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(7, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action10::<>(input, __sym0);
+                        let __nt = __Nonterminal::Expr_234((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""!""###.to_string(),
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                            r###""^""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action10::<>(input, __sym0);
+                        let __nt = __Nonterminal::Expr_234((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 6
+            //     AllInputs = [Expr#atom]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr#atom]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Expr#5)
+            //
+            //     Expr#5 = Expr#atom (*) ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //
+            //   ["!", ")", "*", "+", "-", "/", "^", EOF] -> Expr#5 = Expr#atom => ActionFn(12);
+            //
+            fn __state6<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, i32, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((_, Token(1, _), _)) |
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(7, _), _)) |
+                    Some((_, Token(8, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action12::<>(input, __sym0);
+                        let __nt = __Nonterminal::Expr_235((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""!""###.to_string(),
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                            r###""^""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action12::<>(input, __sym0);
+                        let __nt = __Nonterminal::Expr_235((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 7
+            //     AllInputs = ["("]
+            //     OptionalInputs = []
+            //     FixedInputs = ["("]
+            //     WillPushLen = 2
+            //     WillPush = [Expr, ")"]
+            //     WillProduce = Some(Expr#atom)
+            //
+            //     Expr = (*) Expr "+" Expr#2 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr = (*) Expr "-" Expr#2 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr = (*) Expr#2 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#2 = (*) Expr#2 "*" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#2 = (*) Expr#2 "/" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#2 = (*) Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#3 = (*) Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#3 = (*) "-" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#4 = (*) Expr#5 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#4 = (*) Expr#5 "^" Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#5 = (*) Expr#5 "!" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#5 = (*) Expr#atom ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#atom = (*) "(" Expr ")" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#atom = "(" (*) Expr ")" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#atom = (*) r#"[0-9]+"# ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //
+            //   "(" -> S7
+            //   "-" -> S8
+            //   r#"[0-9]+"# -> S9
+            //
+            //     Expr -> S16
+            //     Expr#2 -> S2
+            //     Expr#3 -> S3
+            //     Expr#4 -> S4
+            //     Expr#5 -> S5
+            //     Expr#atom -> S6
+            fn __state7<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                let __sym0 = &mut Some(__sym0);
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state8(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state9(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    if __sym0.is_none() {
+                        return Ok(__result);
+                    }
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Expr(__sym1) => {
+                            __result = try!(__state16(input, __tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Expr_232(__sym1) => {
+                            __result = try!(__state2(input, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Expr_233(__sym1) => {
+                            __result = try!(__state3(input, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Expr_234(__sym1) => {
+                            __result = try!(__state4(input, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Expr_235(__sym1) => {
+                            __result = try!(__state5(input, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Expr_23atom(__sym1) => {
+                            __result = try!(__state6(input, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 8
+            //     AllInputs = ["-"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["-"]
+            //     WillPushLen = 1
+            //     WillPush = [Expr#3]
+            //     WillProduce = Some(Expr#3)
+            //
+            //     Expr#3 = (*) Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#3 = (*) "-" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#3 = "-" (*) Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#4 = (*) Expr#5 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#4 = (*) Expr#5 "^" Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#5 = (*) Expr#5 "!" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#5 = (*) Expr#atom ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#atom = (*) "(" Expr ")" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#atom = (*) r#"[0-9]+"# ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //
+            //   "(" -> S7
+            //   "-" -> S8
+            //   r#"[0-9]+"# -> S9
+            //
+            //     Expr#3 -> S17
+            //     Expr#4 -> S4
+            //     Expr#5 -> S5
+            //     Expr#atom -> S6
+            fn __state8<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state8(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state9(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Expr_233(__sym1) => {
+                            __result = try!(__state17(input, __tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        __Nonterminal::Expr_234(__sym1) => {
+                            __result = try!(__state4(input, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Expr_235(__sym1) => {
+                            __result = try!(__state5(input, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Expr_23atom(__sym1) => {
+                            __result = try!(__state6(input, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 9
+            //     AllInputs = [r#"[0-9]+"#]
+            //     OptionalInputs = []
+            //     FixedInputs = [r#"[0-9]+"#]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Expr#atom)
+            //
+            //     Expr#atom = r#"[0-9]+"# (*) ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //
+            //   ["!", ")", "*", "+", "-", "/", "^", EOF] -> Expr#atom = r#"[0-9]+"# => ActionFn(13);
+            //
+            fn __state9<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(1, _), _)) |
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(7, _), _)) |
+                    Some((_, Token(8, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action13::<>(input, __sym0);
+                        let __nt = __Nonterminal::Expr_23atom((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""!""###.to_string(),
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                            r###""^""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action13::<>(input, __sym0);
+                        let __nt = __Nonterminal::Expr_23atom((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 10
+            //     AllInputs = [Expr, "+"]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr, "+"]
+            //     WillPushLen = 1
+            //     WillPush = [Expr#2]
+            //     WillProduce = Some(Expr)
+            //
+            //     Expr = Expr "+" (*) Expr#2 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#2 = (*) Expr#2 "*" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#2 = (*) Expr#2 "/" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#2 = (*) Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#3 = (*) Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#3 = (*) "-" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#4 = (*) Expr#5 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#4 = (*) Expr#5 "^" Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#5 = (*) Expr#5 "!" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#5 = (*) Expr#atom ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#atom = (*) "(" Expr ")" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#atom = (*) r#"[0-9]+"# ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //
+            //   "(" -> S7
+            //   "-" -> S8
+            //   r#"[0-9]+"# -> S9
+            //
+            //     Expr#2 -> S18
+            //     Expr#3 -> S3
+            //     Expr#4 -> S4
+            //     Expr#5 -> S5
+            //     Expr#atom -> S6
+            fn __state10<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, i32, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                let __sym0 = &mut Some(__sym0);
+                let __sym1 = &mut Some(__sym1);
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state8(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state9(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    if __sym1.is_none() {
+                        return Ok(__result);
+                    }
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Expr_232(__sym2) => {
+                            __result = try!(__state18(input, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Expr_233(__sym2) => {
+                            __result = try!(__state3(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Expr_234(__sym2) => {
+                            __result = try!(__state4(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Expr_235(__sym2) => {
+                            __result = try!(__state5(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Expr_23atom(__sym2) => {
+                            __result = try!(__state6(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 11
+            //     AllInputs = [Expr, "-"]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr, "-"]
+            //     WillPushLen = 1
+            //     WillPush = [Expr#2]
+            //     WillProduce = Some(Expr)
+            //
+            //     Expr = Expr "-" (*) Expr#2 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#2 = (*) Expr#2 "*" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#2 = (*) Expr#2 "/" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#2 = (*) Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#3 = (*) Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#3 = (*) "-" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#4 = (*) Expr#5 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#4 = (*) Expr#5 "^" Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#5 = (*) Expr#5 "!" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#5 = (*) Expr#atom ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#atom = (*) "(" Expr ")" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#atom = (*) r#"[0-9]+"# ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //
+            //   "(" -> S7
+            //   "-" -> S8
+            //   r#"[0-9]+"# -> S9
+            //
+            //     Expr#2 -> S19
+            //     Expr#3 -> S3
+            //     Expr#4 -> S4
+            //     Expr#5 -> S5
+            //     Expr#atom -> S6
+            fn __state11<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, i32, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                let __sym0 = &mut Some(__sym0);
+                let __sym1 = &mut Some(__sym1);
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state8(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state9(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    if __sym1.is_none() {
+                        return Ok(__result);
+                    }
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Expr_232(__sym2) => {
+                            __result = try!(__state19(input, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Expr_233(__sym2) => {
+                            __result = try!(__state3(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Expr_234(__sym2) => {
+                            __result = try!(__state4(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Expr_235(__sym2) => {
+                            __result = try!(__state5(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Expr_23atom(__sym2) => {
+                            __result = try!(__state6(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 12
+            //     AllInputs = [Expr#2, "*"]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr#2, "*"]
+            //     WillPushLen = 1
+            //     WillPush = [Expr#3]
+            //     WillProduce = Some(Expr#2)
+            //
+            //     Expr#2 = Expr#2 "*" (*) Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#3 = (*) Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#3 = (*) "-" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#4 = (*) Expr#5 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#4 = (*) Expr#5 "^" Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#5 = (*) Expr#5 "!" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#5 = (*) Expr#atom ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#atom = (*) "(" Expr ")" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#atom = (*) r#"[0-9]+"# ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //
+            //   "(" -> S7
+            //   "-" -> S8
+            //   r#"[0-9]+"# -> S9
+            //
+            //     Expr#3 -> S20
+            //     Expr#4 -> S4
+            //     Expr#5 -> S5
+            //     Expr#atom -> S6
+            fn __state12<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, i32, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state8(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state9(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Expr_233(__sym2) => {
+                            __result = try!(__state20(input, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        __Nonterminal::Expr_234(__sym2) => {
+                            __result = try!(__state4(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Expr_235(__sym2) => {
+                            __result = try!(__state5(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Expr_23atom(__sym2) => {
+                            __result = try!(__state6(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 13
+            //     AllInputs = [Expr#2, "/"]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr#2, "/"]
+            //     WillPushLen = 1
+            //     WillPush = [Expr#3]
+            //     WillProduce = Some(Expr#2)
+            //
+            //     Expr#2 = Expr#2 "/" (*) Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#3 = (*) Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#3 = (*) "-" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#4 = (*) Expr#5 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#4 = (*) Expr#5 "^" Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#5 = (*) Expr#5 "!" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#5 = (*) Expr#atom ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#atom = (*) "(" Expr ")" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#atom = (*) r#"[0-9]+"# ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //
+            //   "(" -> S7
+            //   "-" -> S8
+            //   r#"[0-9]+"# -> S9
+            //
+            //     Expr#3 -> S21
+            //     Expr#4 -> S4
+            //     Expr#5 -> S5
+            //     Expr#atom -> S6
+            fn __state13<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, i32, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state8(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state9(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Expr_233(__sym2) => {
+                            __result = try!(__state21(input, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        __Nonterminal::Expr_234(__sym2) => {
+                            __result = try!(__state4(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Expr_235(__sym2) => {
+                            __result = try!(__state5(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Expr_23atom(__sym2) => {
+                            __result = try!(__state6(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 14
+            //     AllInputs = [Expr#5, "!"]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr#5, "!"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Expr#5)
+            //
+            //     Expr#5 = Expr#5 "!" (*) ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //
+            //   ["!", ")", "*", "+", "-", "/", "^", EOF] -> Expr#5 = Expr#5, "!" => ActionFn(11);
+            //
+            fn __state14<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, i32, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(1, _), _)) |
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(7, _), _)) |
+                    Some((_, Token(8, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action11::<>(input, __sym0, __sym1);
+                        let __nt = __Nonterminal::Expr_235((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""!""###.to_string(),
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                            r###""^""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action11::<>(input, __sym0, __sym1);
+                        let __nt = __Nonterminal::Expr_235((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 15
+            //     AllInputs = [Expr#5, "^"]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr#5, "^"]
+            //     WillPushLen = 1
+            //     WillPush = [Expr#4]
+            //     WillProduce = Some(Expr#4)
+            //
+            //     Expr#4 = (*) Expr#5 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#4 = (*) Expr#5 "^" Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#4 = Expr#5 "^" (*) Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#5 = (*) Expr#5 "!" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#5 = (*) Expr#atom ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#atom = (*) "(" Expr ")" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#atom = (*) r#"[0-9]+"# ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //
+            //   "(" -> S7
+            //   r#"[0-9]+"# -> S9
+            //
+            //     Expr#4 -> S22
+            //     Expr#5 -> S5
+            //     Expr#atom -> S6
+            fn __state15<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, i32, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state9(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Expr_234(__sym2) => {
+                            __result = try!(__state22(input, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        __Nonterminal::Expr_235(__sym2) => {
+                            __result = try!(__state5(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Expr_23atom(__sym2) => {
+                            __result = try!(__state6(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 16
+            //     AllInputs = ["(", Expr]
+            //     OptionalInputs = ["("]
+            //     FixedInputs = [Expr]
+            //     WillPushLen = 1
+            //     WillPush = [")"]
+            //     WillProduce = None
+            //
+            //     Expr = Expr (*) "+" Expr#2 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr = Expr (*) "-" Expr#2 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#atom = "(" Expr (*) ")" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //
+            //   ")" -> S23
+            //   "+" -> S10
+            //   "-" -> S11
+            //
+            fn __state16<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: &mut Option<(usize, &'input str, usize)>,
+                __sym1: (usize, i32, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, Token(3, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        let __sym0 = __sym0.take().unwrap();
+                        __result = try!(__state23(input, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, Token(5, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state10(input, __tokens, __extra, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state11(input, __tokens, __extra, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 17
+            //     AllInputs = ["-", Expr#3]
+            //     OptionalInputs = []
+            //     FixedInputs = ["-", Expr#3]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Expr#3)
+            //
+            //     Expr#3 = "-" Expr#3 (*) ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //
+            //   [")", "*", "+", "-", "/", EOF] -> Expr#3 = "-", Expr#3 => ActionFn(7);
+            //
+            fn __state17<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, i32, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(7, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action7::<>(input, __sym0, __sym1);
+                        let __nt = __Nonterminal::Expr_233((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action7::<>(input, __sym0, __sym1);
+                        let __nt = __Nonterminal::Expr_233((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 18
+            //     AllInputs = [Expr, "+", Expr#2]
+            //     OptionalInputs = [Expr, "+"]
+            //     FixedInputs = [Expr#2]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Expr = Expr "+" Expr#2 (*) ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#2 = Expr#2 (*) "*" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#2 = Expr#2 (*) "/" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //
+            //   "*" -> S12
+            //   "/" -> S13
+            //   [")", "+", "-", EOF] -> Expr = Expr, "+", Expr#2 => ActionFn(1);
+            //
+            fn __state18<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: &mut Option<(usize, i32, usize)>,
+                __sym1: &mut Option<(usize, &'input str, usize)>,
+                __sym2: (usize, i32, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, Token(4, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state12(input, __tokens, __extra, __sym2, __sym3, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, Token(7, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state13(input, __tokens, __extra, __sym2, __sym3, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    None => {
+                        let __sym0 = __sym0.take().unwrap();
+                        let __sym1 = __sym1.take().unwrap();
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action1::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __sym0 = __sym0.take().unwrap();
+                        let __sym1 = __sym1.take().unwrap();
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action1::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 19
+            //     AllInputs = [Expr, "-", Expr#2]
+            //     OptionalInputs = [Expr, "-"]
+            //     FixedInputs = [Expr#2]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Expr = Expr "-" Expr#2 (*) ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#2 = Expr#2 (*) "*" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //     Expr#2 = Expr#2 (*) "/" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //
+            //   "*" -> S12
+            //   "/" -> S13
+            //   [")", "+", "-", EOF] -> Expr = Expr, "-", Expr#2 => ActionFn(2);
+            //
+            fn __state19<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: &mut Option<(usize, i32, usize)>,
+                __sym1: &mut Option<(usize, &'input str, usize)>,
+                __sym2: (usize, i32, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, Token(4, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state12(input, __tokens, __extra, __sym2, __sym3, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, Token(7, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state13(input, __tokens, __extra, __sym2, __sym3, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    None => {
+                        let __sym0 = __sym0.take().unwrap();
+                        let __sym1 = __sym1.take().unwrap();
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action2::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __sym0 = __sym0.take().unwrap();
+                        let __sym1 = __sym1.take().unwrap();
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action2::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 20
+            //     AllInputs = [Expr#2, "*", Expr#3]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr#2, "*", Expr#3]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Expr#2)
+            //
+            //     Expr#2 = Expr#2 "*" Expr#3 (*) ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //
+            //   [")", "*", "+", "-", "/", EOF] -> Expr#2 = Expr#2, "*", Expr#3 => ActionFn(4);
+            //
+            fn __state20<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, i32, usize),
+                __sym1: (usize, &'input str, usize),
+                __sym2: (usize, i32, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(7, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action4::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expr_232((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action4::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expr_232((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 21
+            //     AllInputs = [Expr#2, "/", Expr#3]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr#2, "/", Expr#3]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Expr#2)
+            //
+            //     Expr#2 = Expr#2 "/" Expr#3 (*) ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //
+            //   [")", "*", "+", "-", "/", EOF] -> Expr#2 = Expr#2, "/", Expr#3 => ActionFn(5);
+            //
+            fn __state21<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, i32, usize),
+                __sym1: (usize, &'input str, usize),
+                __sym2: (usize, i32, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(7, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action5::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expr_232((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action5::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expr_232((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 22
+            //     AllInputs = [Expr#5, "^", Expr#4]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr#5, "^", Expr#4]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Expr#4)
+            //
+            //     Expr#4 = Expr#5 "^" Expr#4 (*) ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //
+            //   [")", "*", "+", "-", "/", EOF] -> Expr#4 = Expr#5, "^", Expr#4 => ActionFn(9);
+            //
+            fn __state22<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, i32, usize),
+                __sym1: (usize, &'input str, usize),
+                __sym2: (usize, i32, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(7, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action9::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expr_234((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action9::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expr_234((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 23
+            //     AllInputs = ["(", Expr, ")"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["(", Expr, ")"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Expr#atom)
+            //
+            //     Expr#atom = "(" Expr ")" (*) ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+            //
+            //   ["!", ")", "*", "+", "-", "/", "^", EOF] -> Expr#atom = "(", Expr, ")" => ActionFn(14);
+            //
+            fn __state23<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, i32, usize),
+                __sym2: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(1, _), _)) |
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(7, _), _)) |
+                    Some((_, Token(8, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action14::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expr_23atom((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""!""###.to_string(),
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                            r###""^""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action14::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expr_23atom((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+        }
+        pub use self::__parse__Expr::parse_Expr;
+    }
+    mod __parse_table {
+
+        mod __parse_table {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use std::str::FromStr;
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__intern_token::Token;
+            #[allow(dead_code)]
+            pub enum __Symbol<'input>
+             {
+                Term_22_21_22(&'input str),
+                Term_22_28_22(&'input str),
+                Term_22_29_22(&'input str),
+                Term_22_2a_22(&'input str),
+                Term_22_2b_22(&'input str),
+                Term_22_2d_22(&'input str),
+                Term_22_2f_22(&'input str),
+                Term_22_5e_22(&'input str),
+                Termr_23_22_5b0_2d9_5d_2b_22_23(&'input str),
+                NtExpr(i32),
+                NtExpr_232(i32),
+                NtExpr_233(i32),
+                NtExpr_234(i32),
+                NtExpr_235(i32),
+                NtExpr_23atom(i32),
+                Nt____Expr(i32),
+            }
+            impl<'input> ::std::fmt::Debug for __Symbol<'input>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Symbol::Term_22_21_22(..) => __f.write_str("\"!\""),
+                        __Symbol::Term_22_28_22(..) => __f.write_str("\"(\""),
+                        __Symbol::Term_22_29_22(..) => __f.write_str("\")\""),
+                        __Symbol::Term_22_2a_22(..) => __f.write_str("\"*\""),
+                        __Symbol::Term_22_2b_22(..) => __f.write_str("\"+\""),
+                        __Symbol::Term_22_2d_22(..) => __f.write_str("\"-\""),
+                        __Symbol::Term_22_2f_22(..) => __f.write_str("\"/\""),
+                        __Symbol::Term_22_5e_22(..) => __f.write_str("\"^\""),
+                        __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(..) => __f.write_str("r#\"[0-9]+\"#"),
+                        __Symbol::NtExpr(..) => __f.write_str("Expr"),
+                        __Symbol::NtExpr_232(..) => __f.write_str("Expr#2"),
+                        __Symbol::NtExpr_233(..) => __f.write_str("Expr#3"),
+                        __Symbol::NtExpr_234(..) => __f.write_str("Expr#4"),
+                        __Symbol::NtExpr_235(..) => __f.write_str("Expr#5"),
+                        __Symbol::NtExpr_23atom(..) => __f.write_str("Expr#atom"),
+                        __Symbol::Nt____Expr(..) => __f.write_str("__Expr"),
+                    }
+                }
+            }
+            const __ACTION: &'static [i8] = &[
+                // State 0
+                //     Expr = (*) Expr "+" Expr#2 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr = (*) Expr "-" Expr#2 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr = (*) Expr#2 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#2 = (*) Expr#2 "*" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#2 = (*) Expr#2 "/" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#2 = (*) Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#3 = (*) Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#3 = (*) "-" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#4 = (*) Expr#5 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#4 = (*) Expr#5 "^" Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#5 = (*) Expr#5 "!" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#5 = (*) Expr#atom ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#atom = (*) "(" Expr ")" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#atom = (*) r#"[0-9]+"# ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     __Expr = (*) Expr ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                0,  // on "!", error
+                8,  // on "(", goto 7
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                9,  // on "-", goto 8
+                0,  // on "/", error
+                0,  // on "^", error
+                10,  // on r#"[0-9]+"#, goto 9
+
+                // State 1
+                //     Expr = Expr (*) "+" Expr#2 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr = Expr (*) "-" Expr#2 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     __Expr = Expr (*) ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                0,  // on "!", error
+                0,  // on "(", error
+                0,  // on ")", error
+                0,  // on "*", error
+                11,  // on "+", goto 10
+                12,  // on "-", goto 11
+                0,  // on "/", error
+                0,  // on "^", error
+                0,  // on r#"[0-9]+"#, error
+
+                // State 2
+                //     Expr = Expr#2 (*) ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#2 = Expr#2 (*) "*" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#2 = Expr#2 (*) "/" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                0,  // on "!", error
+                0,  // on "(", error
+                -3,  // on ")", reduce `Expr = Expr#2 => ActionFn(3);`
+                13,  // on "*", goto 12
+                -3,  // on "+", reduce `Expr = Expr#2 => ActionFn(3);`
+                -3,  // on "-", reduce `Expr = Expr#2 => ActionFn(3);`
+                14,  // on "/", goto 13
+                0,  // on "^", error
+                0,  // on r#"[0-9]+"#, error
+
+                // State 3
+                //     Expr#2 = Expr#3 (*) ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                0,  // on "!", error
+                0,  // on "(", error
+                -6,  // on ")", reduce `Expr#2 = Expr#3 => ActionFn(6);`
+                -6,  // on "*", reduce `Expr#2 = Expr#3 => ActionFn(6);`
+                -6,  // on "+", reduce `Expr#2 = Expr#3 => ActionFn(6);`
+                -6,  // on "-", reduce `Expr#2 = Expr#3 => ActionFn(6);`
+                -6,  // on "/", reduce `Expr#2 = Expr#3 => ActionFn(6);`
+                0,  // on "^", error
+                0,  // on r#"[0-9]+"#, error
+
+                // State 4
+                //     Expr#3 = Expr#4 (*) ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                0,  // on "!", error
+                0,  // on "(", error
+                -8,  // on ")", reduce `Expr#3 = Expr#4 => ActionFn(8);`
+                -8,  // on "*", reduce `Expr#3 = Expr#4 => ActionFn(8);`
+                -8,  // on "+", reduce `Expr#3 = Expr#4 => ActionFn(8);`
+                -8,  // on "-", reduce `Expr#3 = Expr#4 => ActionFn(8);`
+                -8,  // on "/", reduce `Expr#3 = Expr#4 => ActionFn(8);`
+                0,  // on "^", error
+                0,  // on r#"[0-9]+"#, error
+
+                // State 5
+                //     Expr#4 = Expr#5 (*) ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#4 = Expr#5 (*) "^" Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#5 = Expr#5 (*) "!" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                15,  // on "!", goto 14
+                0,  // on "(", error
+                -10,  // on ")", reduce `Expr#4 = Expr#5 => ActionFn(10);`
+                -10,  // on "*", reduce `Expr#4 = Expr#5 => ActionFn(10);`
+                -10,  // on "+", reduce `Expr#4 = Expr#5 => ActionFn(10);`
+                -10,  // on "-", reduce `Expr#4 = Expr#5 => ActionFn(10);`
+                -10,  // on "/", reduce `Expr#4 = Expr#5 => ActionFn(10);`
+                16,  // on "^", goto 15
+                0,  // on r#"[0-9]+"#, error
+
+                // State 6
+                //     Expr#5 = Expr#atom (*) ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                -12,  // on "!", reduce `Expr#5 = Expr#atom => ActionFn(12);`
+                0,  // on "(", error
+                -12,  // on ")", reduce `Expr#5 = Expr#atom => ActionFn(12);`
+                -12,  // on "*", reduce `Expr#5 = Expr#atom => ActionFn(12);`
+                -12,  // on "+", reduce `Expr#5 = Expr#atom => ActionFn(12);`
+                -12,  // on "-", reduce `Expr#5 = Expr#atom => ActionFn(12);`
+                -12,  // on "/", reduce `Expr#5 = Expr#atom => ActionFn(12);`
+                -12,  // on "^", reduce `Expr#5 = Expr#atom => ActionFn(12);`
+                0,  // on r#"[0-9]+"#, error
+
+                // State 7
+                //     Expr = (*) Expr "+" Expr#2 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr = (*) Expr "-" Expr#2 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr = (*) Expr#2 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#2 = (*) Expr#2 "*" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#2 = (*) Expr#2 "/" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#2 = (*) Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#3 = (*) Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#3 = (*) "-" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#4 = (*) Expr#5 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#4 = (*) Expr#5 "^" Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#5 = (*) Expr#5 "!" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#5 = (*) Expr#atom ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#atom = (*) "(" Expr ")" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#atom = "(" (*) Expr ")" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#atom = (*) r#"[0-9]+"# ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                0,  // on "!", error
+                8,  // on "(", goto 7
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                9,  // on "-", goto 8
+                0,  // on "/", error
+                0,  // on "^", error
+                10,  // on r#"[0-9]+"#, goto 9
+
+                // State 8
+                //     Expr#3 = (*) Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#3 = (*) "-" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#3 = "-" (*) Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#4 = (*) Expr#5 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#4 = (*) Expr#5 "^" Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#5 = (*) Expr#5 "!" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#5 = (*) Expr#atom ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#atom = (*) "(" Expr ")" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#atom = (*) r#"[0-9]+"# ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                0,  // on "!", error
+                8,  // on "(", goto 7
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                9,  // on "-", goto 8
+                0,  // on "/", error
+                0,  // on "^", error
+                10,  // on r#"[0-9]+"#, goto 9
+
+                // State 9
+                //     Expr#atom = r#"[0-9]+"# (*) ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                -13,  // on "!", reduce `Expr#atom = r#"[0-9]+"# => ActionFn(13);`
+                0,  // on "(", error
+                -13,  // on ")", reduce `Expr#atom = r#"[0-9]+"# => ActionFn(13);`
+                -13,  // on "*", reduce `Expr#atom = r#"[0-9]+"# => ActionFn(13);`
+                -13,  // on "+", reduce `Expr#atom = r#"[0-9]+"# => ActionFn(13);`
+                -13,  // on "-", reduce `Expr#atom = r#"[0-9]+"# => ActionFn(13);`
+                -13,  // on "/", reduce `Expr#atom = r#"[0-9]+"# => ActionFn(13);`
+                -13,  // on "^", reduce `Expr#atom = r#"[0-9]+"# => ActionFn(13);`
+                0,  // on r#"[0-9]+"#, error
+
+                // State 10
+                //     Expr = Expr "+" (*) Expr#2 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#2 = (*) Expr#2 "*" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#2 = (*) Expr#2 "/" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#2 = (*) Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#3 = (*) Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#3 = (*) "-" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#4 = (*) Expr#5 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#4 = (*) Expr#5 "^" Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#5 = (*) Expr#5 "!" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#5 = (*) Expr#atom ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#atom = (*) "(" Expr ")" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#atom = (*) r#"[0-9]+"# ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                0,  // on "!", error
+                8,  // on "(", goto 7
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                9,  // on "-", goto 8
+                0,  // on "/", error
+                0,  // on "^", error
+                10,  // on r#"[0-9]+"#, goto 9
+
+                // State 11
+                //     Expr = Expr "-" (*) Expr#2 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#2 = (*) Expr#2 "*" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#2 = (*) Expr#2 "/" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#2 = (*) Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#3 = (*) Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#3 = (*) "-" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#4 = (*) Expr#5 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#4 = (*) Expr#5 "^" Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#5 = (*) Expr#5 "!" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#5 = (*) Expr#atom ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#atom = (*) "(" Expr ")" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#atom = (*) r#"[0-9]+"# ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                0,  // on "!", error
+                8,  // on "(", goto 7
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                9,  // on "-", goto 8
+                0,  // on "/", error
+                0,  // on "^", error
+                10,  // on r#"[0-9]+"#, goto 9
+
+                // State 12
+                //     Expr#2 = Expr#2 "*" (*) Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#3 = (*) Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#3 = (*) "-" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#4 = (*) Expr#5 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#4 = (*) Expr#5 "^" Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#5 = (*) Expr#5 "!" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#5 = (*) Expr#atom ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#atom = (*) "(" Expr ")" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#atom = (*) r#"[0-9]+"# ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                0,  // on "!", error
+                8,  // on "(", goto 7
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                9,  // on "-", goto 8
+                0,  // on "/", error
+                0,  // on "^", error
+                10,  // on r#"[0-9]+"#, goto 9
+
+                // State 13
+                //     Expr#2 = Expr#2 "/" (*) Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#3 = (*) Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#3 = (*) "-" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#4 = (*) Expr#5 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#4 = (*) Expr#5 "^" Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#5 = (*) Expr#5 "!" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#5 = (*) Expr#atom ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#atom = (*) "(" Expr ")" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#atom = (*) r#"[0-9]+"# ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                0,  // on "!", error
+                8,  // on "(", goto 7
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                9,  // on "-", goto 8
+                0,  // on "/", error
+                0,  // on "^", error
+                10,  // on r#"[0-9]+"#, goto 9
+
+                // State 14
+                //     Expr#5 = Expr#5 "!" (*) ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                -11,  // on "!", reduce `Expr#5 = Expr#5, "!" => ActionFn(11);`
+                0,  // on "(", error
+                -11,  // on ")", reduce `Expr#5 = Expr#5, "!" => ActionFn(11);`
+                -11,  // on "*", reduce `Expr#5 = Expr#5, "!" => ActionFn(11);`
+                -11,  // on "+", reduce `Expr#5 = Expr#5, "!" => ActionFn(11);`
+                -11,  // on "-", reduce `Expr#5 = Expr#5, "!" => ActionFn(11);`
+                -11,  // on "/", reduce `Expr#5 = Expr#5, "!" => ActionFn(11);`
+                -11,  // on "^", reduce `Expr#5 = Expr#5, "!" => ActionFn(11);`
+                0,  // on r#"[0-9]+"#, error
+
+                // State 15
+                //     Expr#4 = (*) Expr#5 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#4 = (*) Expr#5 "^" Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#4 = Expr#5 "^" (*) Expr#4 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#5 = (*) Expr#5 "!" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#5 = (*) Expr#atom ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#atom = (*) "(" Expr ")" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#atom = (*) r#"[0-9]+"# ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                0,  // on "!", error
+                8,  // on "(", goto 7
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                0,  // on "-", error
+                0,  // on "/", error
+                0,  // on "^", error
+                10,  // on r#"[0-9]+"#, goto 9
+
+                // State 16
+                //     Expr = Expr (*) "+" Expr#2 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr = Expr (*) "-" Expr#2 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#atom = "(" Expr (*) ")" ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                0,  // on "!", error
+                0,  // on "(", error
+                24,  // on ")", goto 23
+                0,  // on "*", error
+                11,  // on "+", goto 10
+                12,  // on "-", goto 11
+                0,  // on "/", error
+                0,  // on "^", error
+                0,  // on r#"[0-9]+"#, error
+
+                // State 17
+                //     Expr#3 = "-" Expr#3 (*) ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                0,  // on "!", error
+                0,  // on "(", error
+                -7,  // on ")", reduce `Expr#3 = "-", Expr#3 => ActionFn(7);`
+                -7,  // on "*", reduce `Expr#3 = "-", Expr#3 => ActionFn(7);`
+                -7,  // on "+", reduce `Expr#3 = "-", Expr#3 => ActionFn(7);`
+                -7,  // on "-", reduce `Expr#3 = "-", Expr#3 => ActionFn(7);`
+                -7,  // on "/", reduce `Expr#3 = "-", Expr#3 => ActionFn(7);`
+                0,  // on "^", error
+                0,  // on r#"[0-9]+"#, error
+
+                // State 18
+                //     Expr = Expr "+" Expr#2 (*) ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#2 = Expr#2 (*) "*" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#2 = Expr#2 (*) "/" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                0,  // on "!", error
+                0,  // on "(", error
+                -1,  // on ")", reduce `Expr = Expr, "+", Expr#2 => ActionFn(1);`
+                13,  // on "*", goto 12
+                -1,  // on "+", reduce `Expr = Expr, "+", Expr#2 => ActionFn(1);`
+                -1,  // on "-", reduce `Expr = Expr, "+", Expr#2 => ActionFn(1);`
+                14,  // on "/", goto 13
+                0,  // on "^", error
+                0,  // on r#"[0-9]+"#, error
+
+                // State 19
+                //     Expr = Expr "-" Expr#2 (*) ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#2 = Expr#2 (*) "*" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                //     Expr#2 = Expr#2 (*) "/" Expr#3 ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                0,  // on "!", error
+                0,  // on "(", error
+                -2,  // on ")", reduce `Expr = Expr, "-", Expr#2 => ActionFn(2);`
+                13,  // on "*", goto 12
+                -2,  // on "+", reduce `Expr = Expr, "-", Expr#2 => ActionFn(2);`
+                -2,  // on "-", reduce `Expr = Expr, "-", Expr#2 => ActionFn(2);`
+                14,  // on "/", goto 13
+                0,  // on "^", error
+                0,  // on r#"[0-9]+"#, error
+
+                // State 20
+                //     Expr#2 = Expr#2 "*" Expr#3 (*) ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                0,  // on "!", error
+                0,  // on "(", error
+                -4,  // on ")", reduce `Expr#2 = Expr#2, "*", Expr#3 => ActionFn(4);`
+                -4,  // on "*", reduce `Expr#2 = Expr#2, "*", Expr#3 => ActionFn(4);`
+                -4,  // on "+", reduce `Expr#2 = Expr#2, "*", Expr#3 => ActionFn(4);`
+                -4,  // on "-", reduce `Expr#2 = Expr#2, "*", Expr#3 => ActionFn(4);`
+                -4,  // on "/", reduce `Expr#2 = Expr#2, "*", Expr#3 => ActionFn(4);`
+                0,  // on "^", error
+                0,  // on r#"[0-9]+"#, error
+
+                // State 21
+                //     Expr#2 = Expr#2 "/" Expr#3 (*) ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                0,  // on "!", error
+                0,  // on "(", error
+                -5,  // on ")", reduce `Expr#2 = Expr#2, "/", Expr#3 => ActionFn(5);`
+                -5,  // on "*", reduce `Expr#2 = Expr#2, "/", Expr#3 => ActionFn(5);`
+                -5,  // on "+", reduce `Expr#2 = Expr#2, "/", Expr#3 => ActionFn(5);`
+                -5,  // on "-", reduce `Expr#2 = Expr#2, "/", Expr#3 => ActionFn(5);`
+                -5,  // on "/", reduce `Expr#2 = Expr#2, "/", Expr#3 => ActionFn(5);`
+                0,  // on "^", error
+                0,  // on r#"[0-9]+"#, error
+
+                // State 22
+                //     Expr#4 = Expr#5 "^" Expr#4 (*) ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                0,  // on "!", error
+                0,  // on "(", error
+                -9,  // on ")", reduce `Expr#4 = Expr#5, "^", Expr#4 => ActionFn(9);`
+                -9,  // on "*", reduce `Expr#4 = Expr#5, "^", Expr#4 => ActionFn(9);`
+                -9,  // on "+", reduce `Expr#4 = Expr#5, "^", Expr#4 => ActionFn(9);`
+                -9,  // on "-", reduce `Expr#4 = Expr#5, "^", Expr#4 => ActionFn(9);`
+                -9,  // on "/", reduce `Expr#4 = Expr#5, "^", Expr#4 => ActionFn(9);`
+                0,  // on "^", error
+                0,  // on r#"[0-9]+"#, error
+
+                // State 23
+                //     Expr#atom = "(" Expr ")" (*) ["!", "(", ")", "*", "+", "-", "/", "^", r#"[0-9]+"#, EOF]
+                -14,  // on "!", reduce `Expr#atom = "(", Expr, ")" => ActionFn(14);`
+                0,  // on "(", error
+                -14,  // on ")", reduce `Expr#atom = "(", Expr, ")" => ActionFn(14);`
+                -14,  // on "*", reduce `Expr#atom = "(", Expr, ")" => ActionFn(14);`
+                -14,  // on "+", reduce `Expr#atom = "(", Expr, ")" => ActionFn(14);`
+                -14,  // on "-", reduce `Expr#atom = "(", Expr, ")" => ActionFn(14);`
+                -14,  // on "/", reduce `Expr#atom = "(", Expr, ")" => ActionFn(14);`
+                -14,  // on "^", reduce `Expr#atom = "(", Expr, ")" => ActionFn(14);`
+                0,  // on r#"[0-9]+"#, error
+
+            ];
+            const __EOF_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // on EOF, error
+
+                // State 1
+                -15,  // on EOF, reduce `__Expr = Expr => ActionFn(0);`
+
+                // State 2
+                -3,  // on EOF, reduce `Expr = Expr#2 => ActionFn(3);`
+
+                // State 3
+                -6,  // on EOF, reduce `Expr#2 = Expr#3 => ActionFn(6);`
+
+                // State 4
+                -8,  // on EOF, reduce `Expr#3 = Expr#4 => ActionFn(8);`
+
+                // State 5
+                -10,  // on EOF, reduce `Expr#4 = Expr#5 => ActionFn(10);`
+
+                // State 6
+                -12,  // on EOF, reduce `Expr#5 = Expr#atom => ActionFn(12);`
+
+                // State 7
+                0,  // on EOF, error
+
+                // State 8
+                0,  // on EOF, error
+
+                // State 9
+                -13,  // on EOF, reduce `Expr#atom = r#"[0-9]+"# => ActionFn(13);`
+
+                // State 10
+                0,  // on EOF, error
+
+                // State 11
+                0,  // on EOF, error
+
+                // State 12
+                0,  // on EOF, error
+
+                // State 13
+                0,  // on EOF, error
+
+                // State 14
+                -11,  // on EOF, reduce `Expr#5 = Expr#5, "!" => ActionFn(11);`
+
+                // State 15
+                0,  // on EOF, error
+
+                // State 16
+                0,  // on EOF, error
+
+                // State 17
+                -7,  // on EOF, reduce `Expr#3 = "-", Expr#3 => ActionFn(7);`
+
+                // State 18
+                -1,  // on EOF, reduce `Expr = Expr, "+", Expr#2 => ActionFn(1);`
+
+                // State 19
+                -2,  // on EOF, reduce `Expr = Expr, "-", Expr#2 => ActionFn(2);`
+
+                // State 20
+                -4,  // on EOF, reduce `Expr#2 = Expr#2, "*", Expr#3 => ActionFn(4);`
+
+                // State 21
+                -5,  // on EOF, reduce `Expr#2 = Expr#2, "/", Expr#3 => ActionFn(5);`
+
+                // State 22
+                -9,  // on EOF, reduce `Expr#4 = Expr#5, "^", Expr#4 => ActionFn(9);`
+
+                // State 23
+                -14,  // on EOF, reduce `Expr#atom = "(", Expr, ")" => ActionFn(14);`
+
+            ];
+            const __GOTO: &'static [u8] = &[
+                // State 0
+                2,  // on Expr, goto 1
+                3,  // on Expr#2, goto 2
+                4,  // on Expr#3, goto 3
+                5,  // on Expr#4, goto 4
+                6,  // on Expr#5, goto 5
+                7,  // on Expr#atom, goto 6
+                0,  // on __Expr, error
+
+                // State 1
+                0,  // on Expr, error
+                0,  // on Expr#2, error
+                0,  // on Expr#3, error
+                0,  // on Expr#4, error
+                0,  // on Expr#5, error
+                0,  // on Expr#atom, error
+                0,  // on __Expr, error
+
+                // State 2
+                0,  // on Expr, error
+                0,  // on Expr#2, error
+                0,  // on Expr#3, error
+                0,  // on Expr#4, error
+                0,  // on Expr#5, error
+                0,  // on Expr#atom, error
+                0,  // on __Expr, error
+
+                // State 3
+                0,  // on Expr, error
+                0,  // on Expr#2, error
+                0,  // on Expr#3, error
+                0,  // on Expr#4, error
+                0,  // on Expr#5, error
+                0,  // on Expr#atom, error
+                0,  // on __Expr, error
+
+                // State 4
+                0,  // on Expr, error
+                0,  // on Expr#2, error
+                0,  // on Expr#3, error
+                0,  // on Expr#4, error
+                0,  // on Expr#5, error
+                0,  // on Expr#atom, error
+                0,  // on __Expr, error
+
+                // State 5
+                0,  // on Expr, error
+                0,  // on Expr#2, error
+                0,  // on Expr#3, error
+                0,  // on Expr#4, error
+                0,  // on Expr#5, error
+                0,  // on Expr#atom, error
+                0,  // on __Expr, error
+
+                // State 6
+                0,  // on Expr, error
+                0,  // on Expr#2, error
+                0,  // on Expr#3, error
+                0,  // on Expr#4, error
+                0,  // on Expr#5, error
+                0,  // on Expr#atom, error
+                0,  // on __Expr, error
+
+                // State 7
+                17,  // on Expr, goto 16
+                3,  // on Expr#2, goto 2
+                4,  // on Expr#3, goto 3
+                5,  // on Expr#4, goto 4
+                6,  // on Expr#5, goto 5
+                7,  // on Expr#atom, goto 6
+                0,  // on __Expr, error
+
+                // State 8
+                0,  // on Expr, error
+                0,  // on Expr#2, error
+                18,  // on Expr#3, goto 17
+                5,  // on Expr#4, goto 4
+                6,  // on Expr#5, goto 5
+                7,  // on Expr#atom, goto 6
+                0,  // on __Expr, error
+
+                // State 9
+                0,  // on Expr, error
+                0,  // on Expr#2, error
+                0,  // on Expr#3, error
+                0,  // on Expr#4, error
+                0,  // on Expr#5, error
+                0,  // on Expr#atom, error
+                0,  // on __Expr, error
+
+                // State 10
+                0,  // on Expr, error
+                19,  // on Expr#2, goto 18
+                4,  // on Expr#3, goto 3
+                5,  // on Expr#4, goto 4
+                6,  // on Expr#5, goto 5
+                7,  // on Expr#atom, goto 6
+                0,  // on __Expr, error
+
+                // State 11
+                0,  // on Expr, error
+                20,  // on Expr#2, goto 19
+                4,  // on Expr#3, goto 3
+                5,  // on Expr#4, goto 4
+                6,  // on Expr#5, goto 5
+                7,  // on Expr#atom, goto 6
+                0,  // on __Expr, error
+
+                // State 12
+                0,  // on Expr, error
+                0,  // on Expr#2, error
+                21,  // on Expr#3, goto 20
+                5,  // on Expr#4, goto 4
+                6,  // on Expr#5, goto 5
+                7,  // on Expr#atom, goto 6
+                0,  // on __Expr, error
+
+                // State 13
+                0,  // on Expr, error
+                0,  // on Expr#2, error
+                22,  // on Expr#3, goto 21
+                5,  // on Expr#4, goto 4
+                6,  // on Expr#5, goto 5
+                7,  // on Expr#atom, goto 6
+                0,  // on __Expr, error
+
+                // State 14
+                0,  // on Expr, error
+                0,  // on Expr#2, error
+                0,  // on Expr#3, error
+                0,  // on Expr#4, error
+                0,  // on Expr#5, error
+                0,  // on Expr#atom, error
+                0,  // on __Expr, error
+
+                // State 15
+                0,  // on Expr, error
+                0,  // on Expr#2, error
+                0,  // on Expr#3, error
+                23,  // on Expr#4, goto 22
+                6,  // on Expr#5, goto 5
+                7,  // on Expr#atom, goto 6
+                0,  // on __Expr, error
+
+                // State 16
+                0,  // on Expr, error
+                0,  // on Expr#2, error
+                0,  // on Expr#3, error
+                0,  // on Expr#4, error
+                0,  // on Expr#5, error
+                0,  // on Expr#atom, error
+                0,  // on __Expr, error
+
+                // State 17
+                0,  // on Expr, error
+                0,  // on Expr#2, error
+                0,  // on Expr#3, error
+                0,  // on Expr#4, error
+                0,  // on Expr#5, error
+                0,  // on Expr#atom, error
+                0,  // on __Expr, error
+
+                // State 18
+                0,  // on Expr, error
+                0,  // on Expr#2, error
+                0,  // on Expr#3, error
+                0,  // on Expr#4, error
+                0,  // on Expr#5, error
+                0,  // on Expr#atom, error
+                0,  // on __Expr, error
+
+                // State 19
+                0,  // on Expr, error
+                0,  // on Expr#2, error
+                0,  // on Expr#3, error
+                0,  // on Expr#4, error
+                0,  // on Expr#5, error
+                0,  // on Expr#atom, error
+                0,  // on __Expr, error
+
+                // State 20
+                0,  // on Expr, error
+                0,  // on Expr#2, error
+                0,  // on Expr#3, error
+                0,  // on Expr#4, error
+                0,  // on Expr#5, error
+                0,  // on Expr#atom, error
+                0,  // on __Expr, error
+
+                // State 21
+                0,  // on Expr, error
+                0,  // on Expr#2, error
+                0,  // on Expr#3, error
+                0,  // on Expr#4, error
+                0,  // on Expr#5, error
+                0,  // on Expr#atom, error
+                0,  // on __Expr, error
+
+                // State 22
+                0,  // on Expr, error
+                0,  // on Expr#2, error
+                0,  // on Expr#3, error
+                0,  // on Expr#4, error
+                0,  // on Expr#5, error
+                0,  // on Expr#atom, error
+                0,  // on __Expr, error
+
+                // State 23
+                0,  // on Expr, error
+                0,  // on Expr#2, error
+                0,  // on Expr#3, error
+                0,  // on Expr#4, error
+                0,  // on Expr#5, error
+                0,  // on Expr#atom, error
+                0,  // on __Expr, error
+
+            ];
+            const __DEFAULT_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // no default reduction
+
+                // State 1
+                0,  // no default reduction
+
+                // State 2
+                0,  // no default reduction
+
+                // State 3
+                -6,  // on any token, reduce `Expr#2 = Expr#3 => ActionFn(6);`
+
+                // State 4
+                -8,  // on any token, reduce `Expr#3 = Expr#4 => ActionFn(8);`
+
+                // State 5
+                0,  // no default reduction
+
+                // State 6
+                -12,  // on any token, reduce `Expr#5 = Expr#atom => ActionFn(12);`
+
+                // State 7
+                0,  // no default reduction
+
+                // State 8
+                0,  // no default reduction
+
+                // State 9
+                -13,  // on any token, reduce `Expr#atom = r#"[0-9]+"# => ActionFn(13);`
+
+                // State 10
+                0,  // no default reduction
+
+                // State 11
+                0,  // no default reduction
+
+                // State 12
+                0,  // no default reduction
+
+                // State 13
+                0,  // no default reduction
+
+                // State 14
+                -11,  // on any token, reduce `Expr#5 = Expr#5, "!" => ActionFn(11);`
+
+                // State 15
+                0,  // no default reduction
+
+                // State 16
+                0,  // no default reduction
+
+                // State 17
+                -7,  // on any token, reduce `Expr#3 = "-", Expr#3 => ActionFn(7);`
+
+                // State 18
+                0,  // no default reduction
+
+                // State 19
+                0,  // no default reduction
+
+                // State 20
+                -4,  // on any token, reduce `Expr#2 = Expr#2, "*", Expr#3 => ActionFn(4);`
+
+                // State 21
+                -5,  // on any token, reduce `Expr#2 = Expr#2, "/", Expr#3 => ActionFn(5);`
+
+                // State 22
+                -9,  // on any token, reduce `Expr#4 = Expr#5, "^", Expr#4 => ActionFn(9);`
+
+                // State 23
+                -14,  // on any token, reduce `Expr#atom = "(", Expr, ")" => ActionFn(14);`
+
+            ];
+            fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+                const __TERMINAL: &'static [&'static str] = &[
+                    r###""!""###,
+                    r###""(""###,
+                    r###"")""###,
+                    r###""*""###,
+                    r###""+""###,
+                    r###""-""###,
+                    r###""/""###,
+                    r###""^""###,
+                    r###"r#"[0-9]+"#"###,
+                ];
+                __ACTION[(__state * 9)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+                    if state == 0 {
+                        None
+                    } else {
+                        Some(terminal.to_string())
+                    }
+                }).collect()
+            }
+            #[allow(dead_code)]
+            pub fn parse_Expr<
+                'input,
+            >(
+                input: &'input str,
+            ) -> Result<i32, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() })
+            }
+            #[allow(dead_code)]
+            pub fn parse_Expr_cancelable<
+                'input,
+            >(
+                input: &'input str,
+                __cancel: &::std::sync::atomic::AtomicBool,
+            ) -> Result<i32, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() })
+            }
+
+            #[allow(dead_code)]
+            pub struct ExprParser {
+                __states: ::std::vec::Vec<i32>,
+                __symbols: __lalrpop_util::stack::Recycled,
+                __cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+            }
+
+            #[allow(dead_code)]
+            impl ExprParser {
+                pub fn new() -> ExprParser {
+                    ExprParser { __states: vec![], __symbols: __lalrpop_util::stack::Recycled::new(), __cancel: None }
+                }
+
+                pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+                    self.__cancel = Some(flag);
+                }
+
+                pub fn parse_in_place<'input>(
+                    &mut self,
+                    input: &'input str,
+                ) -> Result<i32, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+                {
+                    let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                    let mut __symbols = self.__symbols.take();
+                    let __result = __parse::<>(input, 0, &mut __tokens, &mut self.__states, &mut __symbols, self.__cancel.as_ref().map(|c| &**c)).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() });
+                    self.__symbols.put(__symbols);
+                    __result
+                }
+            }
+
+            fn __parse<
+                'input,
+            >(
+                input: &'input str,
+                __start_state: i32,
+                mut __tokens: &mut Iterator<Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+                mut __states: &mut ::std::vec::Vec<i32>,
+                mut __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                __cancel: Option<&::std::sync::atomic::AtomicBool>,
+            ) -> Result<__Symbol<'input>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                __states.clear();
+                __states.push(__start_state);
+                __symbols.clear();
+                let mut __integer;
+                let mut __lookahead;
+                let __last_location: &mut usize = &mut Default::default();
+                let mut __default_reduced = [0_usize; 16];
+                let mut __default_reduced_len = 0;
+                '__shift: loop {
+                    loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __DEFAULT_ACTION[__state] as i32;
+                        if __action == 0 {
+                            break;
+                        }
+                        if __default_reduced_len < 16 {
+                            __default_reduced[__default_reduced_len] = __state;
+                            __default_reduced_len += 1;
+                        }
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    }
+                    __lookahead = match __tokens.next() {
+                        Some(Ok(v)) => v,
+                        None => break '__shift,
+                        Some(Err(e)) => return Err(e),
+                    };
+                    *__last_location = __lookahead.2.clone();
+                    __integer = match __lookahead.1 {
+                        Token(1, _) if true => 0,
+                        Token(2, _) if true => 1,
+                        Token(3, _) if true => 2,
+                        Token(4, _) if true => 3,
+                        Token(5, _) if true => 4,
+                        Token(6, _) if true => 5,
+                        Token(7, _) if true => 6,
+                        Token(8, _) if true => 7,
+                        Token(0, _) if true => 8,
+                        _ => {
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| true).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match Some(__lookahead) {
+                                Some(__token) => {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error);
+                        }
+                    };
+                    '__inner: loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __ACTION[__state * 9 + __integer] as i32;
+                        if __action > 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                                }
+                            }
+                            let __symbol = match __integer {
+                                0 => match __lookahead.1 {
+                                    Token(1, __tok0) => __Symbol::Term_22_21_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                1 => match __lookahead.1 {
+                                    Token(2, __tok0) => __Symbol::Term_22_28_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                2 => match __lookahead.1 {
+                                    Token(3, __tok0) => __Symbol::Term_22_29_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                3 => match __lookahead.1 {
+                                    Token(4, __tok0) => __Symbol::Term_22_2a_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                4 => match __lookahead.1 {
+                                    Token(5, __tok0) => __Symbol::Term_22_2b_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                5 => match __lookahead.1 {
+                                    Token(6, __tok0) => __Symbol::Term_22_2d_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                6 => match __lookahead.1 {
+                                    Token(7, __tok0) => __Symbol::Term_22_2f_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                7 => match __lookahead.1 {
+                                    Token(8, __tok0) => __Symbol::Term_22_5e_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                8 => match __lookahead.1 {
+                                    Token(0, __tok0) => __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                _ => unreachable!(),
+                            };
+                            __states.push(__action - 1);
+                            __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                            __default_reduced_len = 0;
+                            continue '__shift;
+                        } else if __action < 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                                }
+                            }
+                            if let Some(r) = __reduce(input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                                if r.is_err() {
+                                    return r;
+                                }
+                                return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] });
+                            }
+                        } else {
+                            let mut __err_lookahead = Some(__lookahead);
+                            let mut __err_integer: Option<usize> = Some(__integer);
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 9 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match __err_lookahead {
+                                Some(__token) => {
+                                    if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                        __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                    } else {
+                                        __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                    }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error)
+                        }
+                    }
+                }
+                loop {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __action = __EOF_ACTION[__state] as i32;
+                    if __action < 0 {
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    } else {
+                        let mut __err_lookahead = None;
+                        let mut __err_integer: Option<usize> = None;
+                        let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 9 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                        let __expected = __expected_tokens(__state);
+                        let __error = match __err_lookahead {
+                            Some(__token) => {
+                                if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                    __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                } else {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                            }
+                            None => {
+                                __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                            }
+                        };
+                        return Err(__error)
+                    }
+                }
+            }
+            fn __accepts<
+                'input,
+            >(
+                input: &'input str,
+                __error_state: i32,
+                __states: & [i32],
+                __opt_integer: Option<usize>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> bool
+            {
+                let mut __states = __states.to_vec();
+                __states.push(__error_state);
+                loop {
+                    let mut __states_len = __states.len();
+                    let __top = __states[__states_len - 1];
+                    let __action = match __opt_integer {
+                        None => __EOF_ACTION[__top as usize] as i32,
+                        Some(__integer) => __ACTION[(__top * 9) as usize + __integer] as i32,
+                    };
+                    if __action == 0 { return false; }
+                    if __action > 0 { return true; }
+                    let (__to_pop, __nt) = match -__action {
+                        // simulate Expr = Expr, "+", Expr#2 => ActionFn(1);
+                        1 => {
+                            (3, 0)
+                        }
+                        // simulate Expr = Expr, "-", Expr#2 => ActionFn(2);
+                        2 => {
+                            (3, 0)
+                        }
+                        // simulate Expr = Expr#2 => ActionFn(3);
+                        3 => {
+                            (1, 0)
+                        }
+                        // simulate Expr#2 = Expr#2, "*", Expr#3 => ActionFn(4);
+                        4 => {
+                            (3, 1)
+                        }
+                        // simulate Expr#2 = Expr#2, "/", Expr#3 => ActionFn(5);
+                        5 => {
+                            (3, 1)
+                        }
+                        // simulate Expr#2 = Expr#3 => ActionFn(6);
+                        6 => {
+                            (1, 1)
+                        }
+                        // simulate Expr#3 = "-", Expr#3 => ActionFn(7);
+                        7 => {
+                            (2, 2)
+                        }
+                        // simulate Expr#3 = Expr#4 => ActionFn(8);
+                        8 => {
+                            (1, 2)
+                        }
+                        // simulate Expr#4 = Expr#5, "^", Expr#4 => ActionFn(9);
+                        9 => {
+                            (3, 3)
+                        }
+                        // simulate Expr#4 = Expr#5 => ActionFn(10);
+                        10 => {
+                            (1, 3)
+                        }
+                        // simulate Expr#5 = Expr#5, "!" => ActionFn(11);
+                        11 => {
+                            (2, 4)
+                        }
+                        // simulate Expr#5 = Expr#atom => ActionFn(12);
+                        12 => {
+                            (1, 4)
+                        }
+                        // simulate Expr#atom = r#"[0-9]+"# => ActionFn(13);
+                        13 => {
+                            (1, 5)
+                        }
+                        // simulate Expr#atom = "(", Expr, ")" => ActionFn(14);
+                        14 => {
+                            (3, 5)
+                        }
+                        // simulate __Expr = Expr => ActionFn(0);
+                        15 => return true,
+                        _ => panic!("invalid action code {}", __action)
+                    };
+                    __states_len -= __to_pop;
+                    __states.truncate(__states_len);
+                    let __top = __states[__states_len - 1];
+                    let __next_state = __GOTO[(__top * 7 + __nt) as usize] as i32 - 1;
+                    __states.push(__next_state);
+                }
+            }
+            pub fn __reduce<
+                'input,
+            >(
+                input: &'input str,
+                __action: i32,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Option<Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                let __nonterminal = match -__action {
+                    1 => match __reduce_Expr_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    2 => match __reduce_Expr_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    3 => match __reduce_Expr_2(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    4 => match __reduce_Expr_232_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    5 => match __reduce_Expr_232_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    6 => match __reduce_Expr_232_2(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    7 => match __reduce_Expr_233_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    8 => match __reduce_Expr_233_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    9 => match __reduce_Expr_234_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    10 => match __reduce_Expr_234_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    11 => match __reduce_Expr_235_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    12 => match __reduce_Expr_235_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    13 => match __reduce_Expr_23atom_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    14 => match __reduce_Expr_23atom_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    15 => match __reduce_____Expr_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    _ => panic!("invalid action code {}", __action)
+                };
+                let __state = *__states.last().unwrap() as usize;
+                let __next_state = __GOTO[__state * 7 + __nonterminal] as i32 - 1;
+                __states.push(__next_state);
+                None
+            }
+
+            fn __reduce_Expr_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expr = Expr, "+", Expr#2 => ActionFn(1);
+                let __sym2 = __pop_NtExpr_232(__symbols);
+                let __sym1 = __pop_Term_22_2b_22(__symbols);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action1::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce_Expr_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expr = Expr, "-", Expr#2 => ActionFn(2);
+                let __sym2 = __pop_NtExpr_232(__symbols);
+                let __sym1 = __pop_Term_22_2d_22(__symbols);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action2::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce_Expr_2<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expr = Expr#2 => ActionFn(3);
+                let __sym0 = __pop_NtExpr_232(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action3::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce_Expr_232_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expr#2 = Expr#2, "*", Expr#3 => ActionFn(4);
+                let __sym2 = __pop_NtExpr_233(__symbols);
+                let __sym1 = __pop_Term_22_2a_22(__symbols);
+                let __sym0 = __pop_NtExpr_232(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action4::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtExpr_232(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce_Expr_232_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expr#2 = Expr#2, "/", Expr#3 => ActionFn(5);
+                let __sym2 = __pop_NtExpr_233(__symbols);
+                let __sym1 = __pop_Term_22_2f_22(__symbols);
+                let __sym0 = __pop_NtExpr_232(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action5::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtExpr_232(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce_Expr_232_2<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expr#2 = Expr#3 => ActionFn(6);
+                let __sym0 = __pop_NtExpr_233(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action6::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtExpr_232(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce_Expr_233_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expr#3 = "-", Expr#3 => ActionFn(7);
+                let __sym1 = __pop_NtExpr_233(__symbols);
+                let __sym0 = __pop_Term_22_2d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action7::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtExpr_233(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce_Expr_233_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expr#3 = Expr#4 => ActionFn(8);
+                let __sym0 = __pop_NtExpr_234(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action8::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtExpr_233(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce_Expr_234_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expr#4 = Expr#5, "^", Expr#4 => ActionFn(9);
+                let __sym2 = __pop_NtExpr_234(__symbols);
+                let __sym1 = __pop_Term_22_5e_22(__symbols);
+                let __sym0 = __pop_NtExpr_235(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action9::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtExpr_234(__nt), __end));
+                Ok(3)
+            }
+
+            fn __reduce_Expr_234_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expr#4 = Expr#5 => ActionFn(10);
+                let __sym0 = __pop_NtExpr_235(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action10::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtExpr_234(__nt), __end));
+                Ok(3)
+            }
+
+            fn __reduce_Expr_235_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expr#5 = Expr#5, "!" => ActionFn(11);
+                let __sym1 = __pop_Term_22_21_22(__symbols);
+                let __sym0 = __pop_NtExpr_235(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action11::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtExpr_235(__nt), __end));
+                Ok(4)
+            }
+
+            fn __reduce_Expr_235_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expr#5 = Expr#atom => ActionFn(12);
+                let __sym0 = __pop_NtExpr_23atom(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action12::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtExpr_235(__nt), __end));
+                Ok(4)
+            }
+
+            fn __reduce_Expr_23atom_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expr#atom = r#"[0-9]+"# => ActionFn(13);
+                let __sym0 = __pop_Termr_23_22_5b0_2d9_5d_2b_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action13::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtExpr_23atom(__nt), __end));
+                Ok(5)
+            }
+
+            fn __reduce_Expr_23atom_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expr#atom = "(", Expr, ")" => ActionFn(14);
+                let __sym2 = __pop_Term_22_29_22(__symbols);
+                let __sym1 = __pop_NtExpr(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action14::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtExpr_23atom(__nt), __end));
+                Ok(5)
+            }
+
+            fn __reduce_____Expr_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // __Expr = Expr => ActionFn(0);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action0::<>(input, __sym0);
+                Err(Ok(__Symbol::Nt____Expr(__nt)))
+            }
+            fn __pop_Term_22_21_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_21_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_28_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_28_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_29_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_29_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_2a_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2a_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_2b_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2b_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_2d_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2d_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_2f_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2f_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_5e_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_5e_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Termr_23_22_5b0_2d9_5d_2b_22_23<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtExpr<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, i32, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtExpr(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtExpr_232<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, i32, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtExpr_232(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtExpr_233<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, i32, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtExpr_233(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtExpr_234<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, i32, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtExpr_234(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtExpr_235<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, i32, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtExpr_235(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtExpr_23atom<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, i32, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtExpr_23atom(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt____Expr<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, i32, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt____Expr(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+        }
+        pub use self::__parse_table::parse_Expr;
+        pub use self::__parse_table::parse_Expr_cancelable;
+        pub use self::__parse_table::ExprParser;
+    }
+    pub use self::__parse_table::parse_Expr_cancelable;
+    pub use self::__parse_table::ExprParser;
+}
+pub use self::__parse__Expr::parse_Expr;
+pub use self::__parse__Expr::parse_Expr_cancelable;
+pub use self::__parse__Expr::ExprParser;
+mod __intern_token {
+    #![allow(unused_imports)]
+    use std::str::FromStr;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    extern crate regex as __regex;
+    use std::fmt as __fmt;
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Token<'input>(pub usize, pub &'input str);
+    impl<'a> __fmt::Display for Token<'a> {
+        fn fmt(&self, formatter: &mut __fmt::Formatter) -> Result<(), __fmt::Error> {
+            __fmt::Display::fmt(self.1, formatter)
+        }
+    }
+
+    pub struct __Matcher<'input> {
+        text: &'input str,
+        consumed: usize,
+        regex_set: __regex::RegexSet,
+        regex_vec: Vec<__regex::Regex>,
+    }
+
+    impl<'input> __Matcher<'input> {
+        pub fn new(s: &'input str) -> __Matcher<'input> {
+            let __strs: &[&str] = &[
+                "^(?u:[0-9])+",
+                "^(?u:!)",
+                "^(?u:\\()",
+                "^(?u:\\))",
+                "^(?u:\\*)",
+                "^(?u:\\+)",
+                "^(?u:\\-)",
+                "^(?u:/)",
+                "^(?u:\\^)",
+            ];
+            let __regex_set = __regex::RegexSet::new(__strs).unwrap();
+            let __regex_vec = vec![
+                __regex::Regex::new("^(?u:[0-9])+").unwrap(),
+                __regex::Regex::new("^(?u:!)").unwrap(),
+                __regex::Regex::new("^(?u:\\()").unwrap(),
+                __regex::Regex::new("^(?u:\\))").unwrap(),
+                __regex::Regex::new("^(?u:\\*)").unwrap(),
+                __regex::Regex::new("^(?u:\\+)").unwrap(),
+                __regex::Regex::new("^(?u:\\-)").unwrap(),
+                __regex::Regex::new("^(?u:/)").unwrap(),
+                __regex::Regex::new("^(?u:\\^)").unwrap(),
+            ];
+            __Matcher {
+                text: s,
+                consumed: 0,
+                regex_set: __regex_set,
+                regex_vec: __regex_vec,
+            }
+        }
+    }
+
+    impl<'input> Iterator for __Matcher<'input> {
+        type Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize,Token<'input>,&'static str>>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let __text = self.text.trim_left();
+            let __whitespace = self.text.len() - __text.len();
+            let __start_offset = self.consumed + __whitespace;
+            if __text.is_empty() {
+                self.text = __text;
+                self.consumed = __start_offset;
+                None
+            } else {
+                let __matches = self.regex_set.matches(__text);
+                if !__matches.matched_any() {
+                    Some(Err(__lalrpop_util::ParseError::InvalidToken {
+                        location: __start_offset,
+                    }))
+                } else {
+                    let mut __longest_match = 0;
+                    let mut __index = 0;
+                    for __i in 0 .. 9 {
+                        if __matches.matched(__i) {
+                            let __match = self.regex_vec[__i].find(__text).unwrap();
+                            let __len = __match.end();
+                            if __len >= __longest_match {
+                                __longest_match = __len;
+                                __index = __i;
+                            }
+                        }
+                    }
+                    let __result = &__text[..__longest_match];
+                    let __remaining = &__text[__longest_match..];
+                    let __end_offset = __start_offset + __longest_match;
+                    self.text = __remaining;
+                    self.consumed = __end_offset;
+                    Some(Ok((__start_offset, Token(__index, __result), __end_offset)))
+                }
+            }
+        }
+    }
+}
+pub use self::__intern_token::Token;
+
+#[allow(unused_variables)]
+fn __action0<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, i32, usize),
+) -> i32
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, i32, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, i32, usize),
+) -> i32
+{
+    //@ src/pratt.lalrpop:10:44 (1 lines)
+    l + r
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, i32, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, i32, usize),
+) -> i32
+{
+    //@ src/pratt.lalrpop:11:44 (1 lines)
+    l - r
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, i32, usize),
+) -> i32
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, i32, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, i32, usize),
+) -> i32
+{
+    //@ src/pratt.lalrpop:12:44 (1 lines)
+    l * r
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, i32, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, i32, usize),
+) -> i32
+{
+    //@ src/pratt.lalrpop:13:44 (1 lines)
+    l / r
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, i32, usize),
+) -> i32
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, i32, usize),
+) -> i32
+{
+    //@ src/pratt.lalrpop:14:37 (1 lines)
+    -e
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, i32, usize),
+) -> i32
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action9<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, i32, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, i32, usize),
+) -> i32
+{
+    //@ src/pratt.lalrpop:15:45 (1 lines)
+    l.pow(r as u32)
+}
+
+#[allow(unused_variables)]
+fn __action10<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, i32, usize),
+) -> i32
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action11<
+    'input,
+>(
+    input: &'input str,
+    (_, e, _): (usize, i32, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> i32
+{
+    //@ src/pratt.lalrpop:16:38 (1 lines)
+    (1..e + 1).product()
+}
+
+#[allow(unused_variables)]
+fn __action12<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, i32, usize),
+) -> i32
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action13<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> i32
+{
+    //@ src/pratt.lalrpop:17:18 (1 lines)
+    i32::from_str(__0).unwrap()
+}
+
+#[allow(unused_variables)]
+fn __action14<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, i32, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> i32
+{
+    (__0)
+}
+
+pub trait __ToTriple<'input, > {
+    type Error;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),Self::Error>;
+}
+
+impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize) {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        Ok(value)
+    }
+}
+impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        value
+    }
+}
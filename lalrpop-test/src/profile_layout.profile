@@ -0,0 +1,21 @@
+# Recorded by the parser of `profile_counts.lalrpop` (built with
+# `Configuration::emit_profile`) over a few sums and products.
+Expr state 0 3
+Expr state 1 9
+Expr state 2 7
+Expr state 5 3
+Expr state 7 5
+Expr state 8 2
+Expr state 9 5
+Expr state 10 4
+Expr state 11 9
+Expr state 12 2
+Expr reduce 1 5
+Expr reduce 2 2
+Expr reduce 3 6
+Expr reduce 4 5
+Expr reduce 5 13
+Expr reduce 6 15
+Expr reduce 7 15
+Expr reduce 8 3
+Expr reduce 9 3
@@ -0,0 +1,2520 @@
+// auto-generated: "lalrpop 0.15.0"
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+
+mod __parse__Stmts {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__intern_token::Token;
+    #[allow(dead_code)]
+    pub fn parse_Stmts<
+        'input,
+    >(
+        input: &'input str,
+    ) -> Result<Vec<&'input str>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let __result: Result<Vec<&'input str>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> = (|| {
+            let __ascent = __ascent::parse_Stmts(
+                input,
+            );
+            let __parse_table = __parse_table::parse_Stmts(
+                input,
+            );
+            assert_eq!(__ascent, __parse_table);
+            return __ascent;
+        })();
+        __result.map_err(super::__intern_token::__reserved_word)
+    }
+    mod __ascent {
+
+        mod __parse__Stmts {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__intern_token::Token;
+            #[allow(dead_code)]
+            pub fn parse_Stmts<
+                'input,
+            >(
+                input: &'input str,
+            ) -> Result<Vec<&'input str>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let __result: Result<Vec<&'input str>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> = (|| {
+                    let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                    let __last_location = ::std::cell::RefCell::new(Default::default());
+                    let mut __tokens = __tokens.inspect(|t| if let Ok(ref t) = *t { *__last_location.borrow_mut() = t.2.clone(); });
+                    let __lookahead = match __tokens.next() {
+                        Some(Ok(v)) => Some(v),
+                        None => None,
+                        Some(Err(e)) => return Err(e),
+                    };
+                    let mut __extra = None;
+                    let __result = __state0(input, &mut __tokens, __lookahead, &mut __extra, ::std::marker::PhantomData::<()>);
+                    if let Some((__token, __expected)) = __extra {
+                        return Err(match __result {
+                            Ok(_) => __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected },
+                            Err(_) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                        });
+                    }
+                    match __result {
+                        Ok((Some(__lookahead), _)) => {
+                            Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] })
+                        }
+                        Ok((None, __Nonterminal::____Stmts((_, __nt, _)))) => {
+                            Ok(__nt)
+                        }
+                        Ok(_) => unreachable!(),
+                        Err(__lalrpop_util::ParseError::UnrecognizedEof { expected: __expected, .. }) => {
+                            Err(__lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.borrow().clone(), expected: __expected })
+                        }
+                        Err(__error) => Err(__error),
+                    }
+                })();
+                __result.map_err(super::super::super::__intern_token::__reserved_word)
+            }
+
+            #[allow(dead_code)]
+            pub enum __Nonterminal<'input>
+             {
+                Stmt((usize, &'input str, usize)),
+                Stmt_2a((usize, ::std::vec::Vec<&'input str>, usize)),
+                Stmt_2b((usize, ::std::vec::Vec<&'input str>, usize)),
+                Stmts((usize, Vec<&'input str>, usize)),
+                Value((usize, (), usize)),
+                ____Stmts((usize, Vec<&'input str>, usize)),
+            }
+            impl<'input> ::std::fmt::Debug for __Nonterminal<'input>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Nonterminal::Stmt(..) => __f.write_str("Stmt"),
+                        __Nonterminal::Stmt_2a(..) => __f.write_str("Stmt*"),
+                        __Nonterminal::Stmt_2b(..) => __f.write_str("Stmt+"),
+                        __Nonterminal::Stmts(..) => __f.write_str("Stmts"),
+                        __Nonterminal::Value(..) => __f.write_str("Value"),
+                        __Nonterminal::____Stmts(..) => __f.write_str("__Stmts"),
+                    }
+                }
+            }
+
+            // State 0
+            //     AllInputs = []
+            //     OptionalInputs = []
+            //     FixedInputs = []
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Stmt = (*) "let" NAME "=" Value ";" [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+            //     Stmt = (*) "while" NAME ";" [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+            //     Stmt+ = (*) Stmt [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+            //     Stmt+ = (*) Stmt+ Stmt [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+            //     Stmts = (*) [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+            //     Stmts = (*) Stmt+ [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+            //     __Stmts = (*) Stmts [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+            //
+            //   "let" -> S4
+            //   "while" -> S5
+            //   [EOF] -> Stmts =  => ActionFn(10);
+            //
+            //     Stmt -> S1
+            //     Stmt+ -> S2
+            //     Stmts -> S3
+            fn __state0<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(4, __tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state4(input, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(input, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    None => {
+                        let __start: usize = ::std::default::Default::default();
+                        let __end = __lookahead.as_ref().map(|o| o.0.clone()).unwrap_or_else(|| __start.clone());
+                        let __nt = super::super::super::__action10::<>(input, &__start, &__end);
+                        let __nt = __Nonterminal::Stmts((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""let""###.to_string(),
+                            r###""while""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start: usize = ::std::default::Default::default();
+                        let __end = __lookahead.as_ref().map(|o| o.0.clone()).unwrap_or_else(|| __start.clone());
+                        let __nt = super::super::super::__action10::<>(input, &__start, &__end);
+                        let __nt = __Nonterminal::Stmts((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Stmt(__sym0) => {
+                            __result = try!(__state1(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Stmt_2b(__sym0) => {
+                            __result = try!(__state2(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Stmts(__sym0) => {
+                            __result = try!(__state3(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 1
+            //     AllInputs = [Stmt]
+            //     OptionalInputs = []
+            //     FixedInputs = [Stmt]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Stmt+)
+            //
+            //     Stmt+ = Stmt (*) [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+            //
+            //   ["let", "while", EOF] -> Stmt+ = Stmt => ActionFn(8);
+            //
+            fn __state1<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action8::<>(input, __sym0);
+                        let __nt = __Nonterminal::Stmt_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""let""###.to_string(),
+                            r###""while""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action8::<>(input, __sym0);
+                        let __nt = __Nonterminal::Stmt_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 2
+            //     AllInputs = [Stmt+]
+            //     OptionalInputs = []
+            //     FixedInputs = [Stmt+]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Stmt = (*) "let" NAME "=" Value ";" [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+            //     Stmt = (*) "while" NAME ";" [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+            //     Stmt+ = Stmt+ (*) Stmt [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+            //     Stmts = Stmt+ (*) [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+            //
+            //   "let" -> S4
+            //   "while" -> S5
+            //   [EOF] -> Stmts = Stmt+ => ActionFn(11);
+            //
+            //     Stmt -> S6
+            fn __state2<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, ::std::vec::Vec<&'input str>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(4, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state4(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action11::<>(input, __sym0);
+                        let __nt = __Nonterminal::Stmts((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""let""###.to_string(),
+                            r###""while""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action11::<>(input, __sym0);
+                        let __nt = __Nonterminal::Stmts((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Stmt(__sym1) => {
+                            __result = try!(__state6(input, __tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 3
+            //     AllInputs = [Stmts]
+            //     OptionalInputs = []
+            //     FixedInputs = [Stmts]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(__Stmts)
+            //
+            //     __Stmts = Stmts (*) [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+            //
+            //   [EOF] -> __Stmts = Stmts => ActionFn(0);
+            //
+            fn __state3<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Vec<&'input str>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(input, __sym0);
+                        let __nt = __Nonterminal::____Stmts((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(input, __sym0);
+                        let __nt = __Nonterminal::____Stmts((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 4
+            //     AllInputs = ["let"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["let"]
+            //     WillPushLen = 4
+            //     WillPush = [NAME, "=", Value, ";"]
+            //     WillProduce = Some(Stmt)
+            //
+            //     Stmt = "let" (*) NAME "=" Value ";" [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+            //
+            //   NAME -> S7
+            //
+            fn __state4<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"NAME"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 5
+            //     AllInputs = ["while"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["while"]
+            //     WillPushLen = 2
+            //     WillPush = [NAME, ";"]
+            //     WillProduce = Some(Stmt)
+            //
+            //     Stmt = "while" (*) NAME ";" [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+            //
+            //   NAME -> S8
+            //
+            fn __state5<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state8(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"NAME"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 6
+            //     AllInputs = [Stmt+, Stmt]
+            //     OptionalInputs = []
+            //     FixedInputs = [Stmt+, Stmt]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Stmt+)
+            //
+            //     Stmt+ = Stmt+ Stmt (*) [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+            //
+            //   ["let", "while", EOF] -> Stmt+ = Stmt+, Stmt => ActionFn(9);
+            //
+            fn __state6<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, ::std::vec::Vec<&'input str>, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action9::<>(input, __sym0, __sym1);
+                        let __nt = __Nonterminal::Stmt_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""let""###.to_string(),
+                            r###""while""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action9::<>(input, __sym0, __sym1);
+                        let __nt = __Nonterminal::Stmt_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 7
+            //     AllInputs = ["let", NAME]
+            //     OptionalInputs = []
+            //     FixedInputs = ["let", NAME]
+            //     WillPushLen = 3
+            //     WillPush = ["=", Value, ";"]
+            //     WillProduce = Some(Stmt)
+            //
+            //     Stmt = "let" NAME (*) "=" Value ";" [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+            //
+            //   "=" -> S9
+            //
+            fn __state7<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(3, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state9(input, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""=""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 8
+            //     AllInputs = ["while", NAME]
+            //     OptionalInputs = []
+            //     FixedInputs = ["while", NAME]
+            //     WillPushLen = 1
+            //     WillPush = [";"]
+            //     WillProduce = Some(Stmt)
+            //
+            //     Stmt = "while" NAME (*) ";" [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+            //
+            //   ";" -> S10
+            //
+            fn __state8<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state10(input, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"";""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 9
+            //     AllInputs = ["let", NAME, "="]
+            //     OptionalInputs = []
+            //     FixedInputs = ["let", NAME, "="]
+            //     WillPushLen = 2
+            //     WillPush = [Value, ";"]
+            //     WillProduce = Some(Stmt)
+            //
+            //     Stmt = "let" NAME "=" (*) Value ";" [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+            //     Value = (*) "nil" [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+            //     Value = (*) NUM [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+            //
+            //   "nil" -> S12
+            //   NUM -> S13
+            //
+            //     Value -> S11
+            fn __state9<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                __sym2: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(5, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state12(input, __tokens, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state13(input, __tokens, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""nil""###.to_string(),
+                            r###"NUM"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Value(__sym3) => {
+                            __result = try!(__state11(input, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, __sym3, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 10
+            //     AllInputs = ["while", NAME, ";"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["while", NAME, ";"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Stmt)
+            //
+            //     Stmt = "while" NAME ";" (*) [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+            //
+            //   ["let", "while", EOF] -> Stmt = "while", NAME, ";" => ActionFn(3);
+            //
+            fn __state10<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                __sym2: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action3::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Stmt((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""let""###.to_string(),
+                            r###""while""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action3::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Stmt((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 11
+            //     AllInputs = ["let", NAME, "=", Value]
+            //     OptionalInputs = []
+            //     FixedInputs = ["let", NAME, "=", Value]
+            //     WillPushLen = 1
+            //     WillPush = [";"]
+            //     WillProduce = Some(Stmt)
+            //
+            //     Stmt = "let" NAME "=" Value (*) ";" [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+            //
+            //   ";" -> S14
+            //
+            fn __state11<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                __sym2: (usize, &'input str, usize),
+                __sym3: (usize, (), usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym4 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state14(input, __tokens, __extra, __sym0, __sym1, __sym2, __sym3, __sym4, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"";""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 12
+            //     AllInputs = ["nil"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["nil"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Value)
+            //
+            //     Value = "nil" (*) [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+            //
+            //   [";"] -> Value = "nil" => ActionFn(5);
+            //
+            fn __state12<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(2, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action5::<>(input, __sym0);
+                        let __nt = __Nonterminal::Value((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"";""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 13
+            //     AllInputs = [NUM]
+            //     OptionalInputs = []
+            //     FixedInputs = [NUM]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Value)
+            //
+            //     Value = NUM (*) [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+            //
+            //   [";"] -> Value = NUM => ActionFn(4);
+            //
+            fn __state13<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(2, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action4::<>(input, __sym0);
+                        let __nt = __Nonterminal::Value((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"";""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 14
+            //     AllInputs = ["let", NAME, "=", Value, ";"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["let", NAME, "=", Value, ";"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Stmt)
+            //
+            //     Stmt = "let" NAME "=" Value ";" (*) [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+            //
+            //   ["let", "while", EOF] -> Stmt = "let", NAME, "=", Value, ";" => ActionFn(2);
+            //
+            fn __state14<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                __sym2: (usize, &'input str, usize),
+                __sym3: (usize, (), usize),
+                __sym4: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym4.2.clone();
+                        let __nt = super::super::super::__action2::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                        let __nt = __Nonterminal::Stmt((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""let""###.to_string(),
+                            r###""while""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym4.2.clone();
+                        let __nt = super::super::super::__action2::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                        let __nt = __Nonterminal::Stmt((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+        }
+        pub use self::__parse__Stmts::parse_Stmts;
+    }
+    mod __parse_table {
+
+        mod __parse_table {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__intern_token::Token;
+            #[allow(dead_code)]
+            pub enum __Symbol<'input>
+             {
+                Term_22_3b_22(&'input str),
+                Term_22_3d_22(&'input str),
+                Term_22let_22(&'input str),
+                Term_22nil_22(&'input str),
+                Term_22while_22(&'input str),
+                TermNAME(&'input str),
+                TermNUM(&'input str),
+                NtStmt(&'input str),
+                NtStmt_2a(::std::vec::Vec<&'input str>),
+                NtStmt_2b(::std::vec::Vec<&'input str>),
+                NtStmts(Vec<&'input str>),
+                NtValue(()),
+                Nt____Stmts(Vec<&'input str>),
+            }
+            impl<'input> ::std::fmt::Debug for __Symbol<'input>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Symbol::Term_22_3b_22(..) => __f.write_str("\";\""),
+                        __Symbol::Term_22_3d_22(..) => __f.write_str("\"=\""),
+                        __Symbol::Term_22let_22(..) => __f.write_str("\"let\""),
+                        __Symbol::Term_22nil_22(..) => __f.write_str("\"nil\""),
+                        __Symbol::Term_22while_22(..) => __f.write_str("\"while\""),
+                        __Symbol::TermNAME(..) => __f.write_str("NAME"),
+                        __Symbol::TermNUM(..) => __f.write_str("NUM"),
+                        __Symbol::NtStmt(..) => __f.write_str("Stmt"),
+                        __Symbol::NtStmt_2a(..) => __f.write_str("Stmt*"),
+                        __Symbol::NtStmt_2b(..) => __f.write_str("Stmt+"),
+                        __Symbol::NtStmts(..) => __f.write_str("Stmts"),
+                        __Symbol::NtValue(..) => __f.write_str("Value"),
+                        __Symbol::Nt____Stmts(..) => __f.write_str("__Stmts"),
+                    }
+                }
+            }
+            const __ACTION: &'static [i8] = &[
+                // State 0
+                //     Stmt = (*) "let" NAME "=" Value ";" [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+                //     Stmt = (*) "while" NAME ";" [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+                //     Stmt+ = (*) Stmt [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+                //     Stmt+ = (*) Stmt+ Stmt [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+                //     Stmts = (*) [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+                //     Stmts = (*) Stmt+ [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+                //     __Stmts = (*) Stmts [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+                0,  // on ";", error
+                0,  // on "=", error
+                5,  // on "let", goto 4
+                0,  // on "nil", error
+                6,  // on "while", goto 5
+                0,  // on NAME, error
+                0,  // on NUM, error
+
+                // State 1
+                //     Stmt+ = Stmt (*) [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+                0,  // on ";", error
+                0,  // on "=", error
+                -5,  // on "let", reduce `Stmt+ = Stmt => ActionFn(8);`
+                0,  // on "nil", error
+                -5,  // on "while", reduce `Stmt+ = Stmt => ActionFn(8);`
+                0,  // on NAME, error
+                0,  // on NUM, error
+
+                // State 2
+                //     Stmt = (*) "let" NAME "=" Value ";" [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+                //     Stmt = (*) "while" NAME ";" [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+                //     Stmt+ = Stmt+ (*) Stmt [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+                //     Stmts = Stmt+ (*) [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+                0,  // on ";", error
+                0,  // on "=", error
+                5,  // on "let", goto 4
+                0,  // on "nil", error
+                6,  // on "while", goto 5
+                0,  // on NAME, error
+                0,  // on NUM, error
+
+                // State 3
+                //     __Stmts = Stmts (*) [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+                0,  // on ";", error
+                0,  // on "=", error
+                0,  // on "let", error
+                0,  // on "nil", error
+                0,  // on "while", error
+                0,  // on NAME, error
+                0,  // on NUM, error
+
+                // State 4
+                //     Stmt = "let" (*) NAME "=" Value ";" [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+                0,  // on ";", error
+                0,  // on "=", error
+                0,  // on "let", error
+                0,  // on "nil", error
+                0,  // on "while", error
+                8,  // on NAME, goto 7
+                0,  // on NUM, error
+
+                // State 5
+                //     Stmt = "while" (*) NAME ";" [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+                0,  // on ";", error
+                0,  // on "=", error
+                0,  // on "let", error
+                0,  // on "nil", error
+                0,  // on "while", error
+                9,  // on NAME, goto 8
+                0,  // on NUM, error
+
+                // State 6
+                //     Stmt+ = Stmt+ Stmt (*) [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+                0,  // on ";", error
+                0,  // on "=", error
+                -6,  // on "let", reduce `Stmt+ = Stmt+, Stmt => ActionFn(9);`
+                0,  // on "nil", error
+                -6,  // on "while", reduce `Stmt+ = Stmt+, Stmt => ActionFn(9);`
+                0,  // on NAME, error
+                0,  // on NUM, error
+
+                // State 7
+                //     Stmt = "let" NAME (*) "=" Value ";" [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+                0,  // on ";", error
+                10,  // on "=", goto 9
+                0,  // on "let", error
+                0,  // on "nil", error
+                0,  // on "while", error
+                0,  // on NAME, error
+                0,  // on NUM, error
+
+                // State 8
+                //     Stmt = "while" NAME (*) ";" [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+                11,  // on ";", goto 10
+                0,  // on "=", error
+                0,  // on "let", error
+                0,  // on "nil", error
+                0,  // on "while", error
+                0,  // on NAME, error
+                0,  // on NUM, error
+
+                // State 9
+                //     Stmt = "let" NAME "=" (*) Value ";" [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+                //     Value = (*) "nil" [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+                //     Value = (*) NUM [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+                0,  // on ";", error
+                0,  // on "=", error
+                0,  // on "let", error
+                13,  // on "nil", goto 12
+                0,  // on "while", error
+                0,  // on NAME, error
+                14,  // on NUM, goto 13
+
+                // State 10
+                //     Stmt = "while" NAME ";" (*) [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+                0,  // on ";", error
+                0,  // on "=", error
+                -2,  // on "let", reduce `Stmt = "while", NAME, ";" => ActionFn(3);`
+                0,  // on "nil", error
+                -2,  // on "while", reduce `Stmt = "while", NAME, ";" => ActionFn(3);`
+                0,  // on NAME, error
+                0,  // on NUM, error
+
+                // State 11
+                //     Stmt = "let" NAME "=" Value (*) ";" [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+                15,  // on ";", goto 14
+                0,  // on "=", error
+                0,  // on "let", error
+                0,  // on "nil", error
+                0,  // on "while", error
+                0,  // on NAME, error
+                0,  // on NUM, error
+
+                // State 12
+                //     Value = "nil" (*) [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+                -10,  // on ";", reduce `Value = "nil" => ActionFn(5);`
+                0,  // on "=", error
+                0,  // on "let", error
+                0,  // on "nil", error
+                0,  // on "while", error
+                0,  // on NAME, error
+                0,  // on NUM, error
+
+                // State 13
+                //     Value = NUM (*) [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+                -9,  // on ";", reduce `Value = NUM => ActionFn(4);`
+                0,  // on "=", error
+                0,  // on "let", error
+                0,  // on "nil", error
+                0,  // on "while", error
+                0,  // on NAME, error
+                0,  // on NUM, error
+
+                // State 14
+                //     Stmt = "let" NAME "=" Value ";" (*) [";", "=", "let", "nil", "while", NAME, NUM, EOF]
+                0,  // on ";", error
+                0,  // on "=", error
+                -1,  // on "let", reduce `Stmt = "let", NAME, "=", Value, ";" => ActionFn(2);`
+                0,  // on "nil", error
+                -1,  // on "while", reduce `Stmt = "let", NAME, "=", Value, ";" => ActionFn(2);`
+                0,  // on NAME, error
+                0,  // on NUM, error
+
+            ];
+            const __EOF_ACTION: &'static [i8] = &[
+                // State 0
+                -7,  // on EOF, reduce `Stmts =  => ActionFn(10);`
+
+                // State 1
+                -5,  // on EOF, reduce `Stmt+ = Stmt => ActionFn(8);`
+
+                // State 2
+                -8,  // on EOF, reduce `Stmts = Stmt+ => ActionFn(11);`
+
+                // State 3
+                -11,  // on EOF, reduce `__Stmts = Stmts => ActionFn(0);`
+
+                // State 4
+                0,  // on EOF, error
+
+                // State 5
+                0,  // on EOF, error
+
+                // State 6
+                -6,  // on EOF, reduce `Stmt+ = Stmt+, Stmt => ActionFn(9);`
+
+                // State 7
+                0,  // on EOF, error
+
+                // State 8
+                0,  // on EOF, error
+
+                // State 9
+                0,  // on EOF, error
+
+                // State 10
+                -2,  // on EOF, reduce `Stmt = "while", NAME, ";" => ActionFn(3);`
+
+                // State 11
+                0,  // on EOF, error
+
+                // State 12
+                0,  // on EOF, error
+
+                // State 13
+                0,  // on EOF, error
+
+                // State 14
+                -1,  // on EOF, reduce `Stmt = "let", NAME, "=", Value, ";" => ActionFn(2);`
+
+            ];
+            const __GOTO: &'static [u8] = &[
+                // State 0
+                2,  // on Stmt, goto 1
+                0,  // on Stmt*, error
+                3,  // on Stmt+, goto 2
+                4,  // on Stmts, goto 3
+                0,  // on Value, error
+                0,  // on __Stmts, error
+
+                // State 1
+                0,  // on Stmt, error
+                0,  // on Stmt*, error
+                0,  // on Stmt+, error
+                0,  // on Stmts, error
+                0,  // on Value, error
+                0,  // on __Stmts, error
+
+                // State 2
+                7,  // on Stmt, goto 6
+                0,  // on Stmt*, error
+                0,  // on Stmt+, error
+                0,  // on Stmts, error
+                0,  // on Value, error
+                0,  // on __Stmts, error
+
+                // State 3
+                0,  // on Stmt, error
+                0,  // on Stmt*, error
+                0,  // on Stmt+, error
+                0,  // on Stmts, error
+                0,  // on Value, error
+                0,  // on __Stmts, error
+
+                // State 4
+                0,  // on Stmt, error
+                0,  // on Stmt*, error
+                0,  // on Stmt+, error
+                0,  // on Stmts, error
+                0,  // on Value, error
+                0,  // on __Stmts, error
+
+                // State 5
+                0,  // on Stmt, error
+                0,  // on Stmt*, error
+                0,  // on Stmt+, error
+                0,  // on Stmts, error
+                0,  // on Value, error
+                0,  // on __Stmts, error
+
+                // State 6
+                0,  // on Stmt, error
+                0,  // on Stmt*, error
+                0,  // on Stmt+, error
+                0,  // on Stmts, error
+                0,  // on Value, error
+                0,  // on __Stmts, error
+
+                // State 7
+                0,  // on Stmt, error
+                0,  // on Stmt*, error
+                0,  // on Stmt+, error
+                0,  // on Stmts, error
+                0,  // on Value, error
+                0,  // on __Stmts, error
+
+                // State 8
+                0,  // on Stmt, error
+                0,  // on Stmt*, error
+                0,  // on Stmt+, error
+                0,  // on Stmts, error
+                0,  // on Value, error
+                0,  // on __Stmts, error
+
+                // State 9
+                0,  // on Stmt, error
+                0,  // on Stmt*, error
+                0,  // on Stmt+, error
+                0,  // on Stmts, error
+                12,  // on Value, goto 11
+                0,  // on __Stmts, error
+
+                // State 10
+                0,  // on Stmt, error
+                0,  // on Stmt*, error
+                0,  // on Stmt+, error
+                0,  // on Stmts, error
+                0,  // on Value, error
+                0,  // on __Stmts, error
+
+                // State 11
+                0,  // on Stmt, error
+                0,  // on Stmt*, error
+                0,  // on Stmt+, error
+                0,  // on Stmts, error
+                0,  // on Value, error
+                0,  // on __Stmts, error
+
+                // State 12
+                0,  // on Stmt, error
+                0,  // on Stmt*, error
+                0,  // on Stmt+, error
+                0,  // on Stmts, error
+                0,  // on Value, error
+                0,  // on __Stmts, error
+
+                // State 13
+                0,  // on Stmt, error
+                0,  // on Stmt*, error
+                0,  // on Stmt+, error
+                0,  // on Stmts, error
+                0,  // on Value, error
+                0,  // on __Stmts, error
+
+                // State 14
+                0,  // on Stmt, error
+                0,  // on Stmt*, error
+                0,  // on Stmt+, error
+                0,  // on Stmts, error
+                0,  // on Value, error
+                0,  // on __Stmts, error
+
+            ];
+            const __DEFAULT_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // no default reduction
+
+                // State 1
+                -5,  // on any token, reduce `Stmt+ = Stmt => ActionFn(8);`
+
+                // State 2
+                0,  // no default reduction
+
+                // State 3
+                0,  // no default reduction
+
+                // State 4
+                0,  // no default reduction
+
+                // State 5
+                0,  // no default reduction
+
+                // State 6
+                -6,  // on any token, reduce `Stmt+ = Stmt+, Stmt => ActionFn(9);`
+
+                // State 7
+                0,  // no default reduction
+
+                // State 8
+                0,  // no default reduction
+
+                // State 9
+                0,  // no default reduction
+
+                // State 10
+                -2,  // on any token, reduce `Stmt = "while", NAME, ";" => ActionFn(3);`
+
+                // State 11
+                0,  // no default reduction
+
+                // State 12
+                -10,  // on any token, reduce `Value = "nil" => ActionFn(5);`
+
+                // State 13
+                -9,  // on any token, reduce `Value = NUM => ActionFn(4);`
+
+                // State 14
+                -1,  // on any token, reduce `Stmt = "let", NAME, "=", Value, ";" => ActionFn(2);`
+
+            ];
+            fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+                const __TERMINAL: &'static [&'static str] = &[
+                    r###"";""###,
+                    r###""=""###,
+                    r###""let""###,
+                    r###""nil""###,
+                    r###""while""###,
+                    r###"NAME"###,
+                    r###"NUM"###,
+                ];
+                __ACTION[(__state * 7)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+                    if state == 0 {
+                        None
+                    } else {
+                        Some(terminal.to_string())
+                    }
+                }).collect()
+            }
+            #[allow(dead_code)]
+            pub fn parse_Stmts<
+                'input,
+            >(
+                input: &'input str,
+            ) -> Result<Vec<&'input str>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let __result: Result<Vec<&'input str>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> = (|| {
+                    let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                    let mut __states = vec![];
+                    let mut __symbols = vec![];
+                    __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Stmts(__v) => Ok(__v), _ => unreachable!() })
+                })();
+                __result.map_err(super::super::super::__intern_token::__reserved_word)
+            }
+            #[allow(dead_code)]
+            pub fn parse_Stmts_cancelable<
+                'input,
+            >(
+                input: &'input str,
+                __cancel: &::std::sync::atomic::AtomicBool,
+            ) -> Result<Vec<&'input str>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let __result: Result<Vec<&'input str>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> = (|| {
+                    let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                    let mut __states = vec![];
+                    let mut __symbols = vec![];
+                    __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Stmts(__v) => Ok(__v), _ => unreachable!() })
+                })();
+                __result.map_err(super::super::super::__intern_token::__reserved_word)
+            }
+
+            #[allow(dead_code)]
+            pub struct StmtsParser {
+                __states: ::std::vec::Vec<i32>,
+                __symbols: __lalrpop_util::stack::Recycled,
+                __cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+            }
+
+            #[allow(dead_code)]
+            impl StmtsParser {
+                pub fn new() -> StmtsParser {
+                    StmtsParser { __states: vec![], __symbols: __lalrpop_util::stack::Recycled::new(), __cancel: None }
+                }
+
+                pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+                    self.__cancel = Some(flag);
+                }
+
+                pub fn parse_in_place<'input>(
+                    &mut self,
+                    input: &'input str,
+                ) -> Result<Vec<&'input str>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+                {
+                    let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                    let mut __symbols = self.__symbols.take();
+                    let __result = __parse::<>(input, 0, &mut __tokens, &mut self.__states, &mut __symbols, self.__cancel.as_ref().map(|c| &**c)).and_then(|__v| match __v { __Symbol::Nt____Stmts(__v) => Ok(__v), _ => unreachable!() });
+                    self.__symbols.put(__symbols);
+                    __result
+                }
+            }
+
+            fn __parse<
+                'input,
+            >(
+                input: &'input str,
+                __start_state: i32,
+                mut __tokens: &mut Iterator<Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+                mut __states: &mut ::std::vec::Vec<i32>,
+                mut __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                __cancel: Option<&::std::sync::atomic::AtomicBool>,
+            ) -> Result<__Symbol<'input>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                __states.clear();
+                __states.push(__start_state);
+                __symbols.clear();
+                let mut __integer;
+                let mut __lookahead;
+                let __last_location: &mut usize = &mut Default::default();
+                let mut __default_reduced = [0_usize; 16];
+                let mut __default_reduced_len = 0;
+                '__shift: loop {
+                    loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __DEFAULT_ACTION[__state] as i32;
+                        if __action == 0 {
+                            break;
+                        }
+                        if __default_reduced_len < 16 {
+                            __default_reduced[__default_reduced_len] = __state;
+                            __default_reduced_len += 1;
+                        }
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    }
+                    __lookahead = match __tokens.next() {
+                        Some(Ok(v)) => v,
+                        None => break '__shift,
+                        Some(Err(e)) => return Err(e),
+                    };
+                    *__last_location = __lookahead.2.clone();
+                    __integer = match __lookahead.1 {
+                        Token(2, _) if true => 0,
+                        Token(3, _) if true => 1,
+                        Token(4, _) if true => 2,
+                        Token(5, _) if true => 3,
+                        Token(6, _) if true => 4,
+                        Token(1, _) if true => 5,
+                        Token(0, _) if true => 6,
+                        _ => {
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| true).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match Some(__lookahead) {
+                                Some(__token) => {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error);
+                        }
+                    };
+                    '__inner: loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __ACTION[__state * 7 + __integer] as i32;
+                        if __action > 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                                }
+                            }
+                            let __symbol = match __integer {
+                                0 => match __lookahead.1 {
+                                    Token(2, __tok0) => __Symbol::Term_22_3b_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                1 => match __lookahead.1 {
+                                    Token(3, __tok0) => __Symbol::Term_22_3d_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                2 => match __lookahead.1 {
+                                    Token(4, __tok0) => __Symbol::Term_22let_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                3 => match __lookahead.1 {
+                                    Token(5, __tok0) => __Symbol::Term_22nil_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                4 => match __lookahead.1 {
+                                    Token(6, __tok0) => __Symbol::Term_22while_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                5 => match __lookahead.1 {
+                                    Token(1, __tok0) => __Symbol::TermNAME((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                6 => match __lookahead.1 {
+                                    Token(0, __tok0) => __Symbol::TermNUM((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                _ => unreachable!(),
+                            };
+                            __states.push(__action - 1);
+                            __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                            __default_reduced_len = 0;
+                            continue '__shift;
+                        } else if __action < 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                                }
+                            }
+                            if let Some(r) = __reduce(input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                                if r.is_err() {
+                                    return r;
+                                }
+                                return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] });
+                            }
+                        } else {
+                            let mut __err_lookahead = Some(__lookahead);
+                            let mut __err_integer: Option<usize> = Some(__integer);
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 7 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match __err_lookahead {
+                                Some(__token) => {
+                                    if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                        __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                    } else {
+                                        __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                    }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error)
+                        }
+                    }
+                }
+                loop {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __action = __EOF_ACTION[__state] as i32;
+                    if __action < 0 {
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    } else {
+                        let mut __err_lookahead = None;
+                        let mut __err_integer: Option<usize> = None;
+                        let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 7 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                        let __expected = __expected_tokens(__state);
+                        let __error = match __err_lookahead {
+                            Some(__token) => {
+                                if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                    __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                } else {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                            }
+                            None => {
+                                __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                            }
+                        };
+                        return Err(__error)
+                    }
+                }
+            }
+            fn __accepts<
+                'input,
+            >(
+                input: &'input str,
+                __error_state: i32,
+                __states: & [i32],
+                __opt_integer: Option<usize>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> bool
+            {
+                let mut __states = __states.to_vec();
+                __states.push(__error_state);
+                loop {
+                    let mut __states_len = __states.len();
+                    let __top = __states[__states_len - 1];
+                    let __action = match __opt_integer {
+                        None => __EOF_ACTION[__top as usize] as i32,
+                        Some(__integer) => __ACTION[(__top * 7) as usize + __integer] as i32,
+                    };
+                    if __action == 0 { return false; }
+                    if __action > 0 { return true; }
+                    let (__to_pop, __nt) = match -__action {
+                        // simulate Stmt = "let", NAME, "=", Value, ";" => ActionFn(2);
+                        1 => {
+                            (5, 0)
+                        }
+                        // simulate Stmt = "while", NAME, ";" => ActionFn(3);
+                        2 => {
+                            (3, 0)
+                        }
+                        // simulate Stmt* =  => ActionFn(6);
+                        3 => {
+                            (0, 1)
+                        }
+                        // simulate Stmt* = Stmt+ => ActionFn(7);
+                        4 => {
+                            (1, 1)
+                        }
+                        // simulate Stmt+ = Stmt => ActionFn(8);
+                        5 => {
+                            (1, 2)
+                        }
+                        // simulate Stmt+ = Stmt+, Stmt => ActionFn(9);
+                        6 => {
+                            (2, 2)
+                        }
+                        // simulate Stmts =  => ActionFn(10);
+                        7 => {
+                            (0, 3)
+                        }
+                        // simulate Stmts = Stmt+ => ActionFn(11);
+                        8 => {
+                            (1, 3)
+                        }
+                        // simulate Value = NUM => ActionFn(4);
+                        9 => {
+                            (1, 4)
+                        }
+                        // simulate Value = "nil" => ActionFn(5);
+                        10 => {
+                            (1, 4)
+                        }
+                        // simulate __Stmts = Stmts => ActionFn(0);
+                        11 => return true,
+                        _ => panic!("invalid action code {}", __action)
+                    };
+                    __states_len -= __to_pop;
+                    __states.truncate(__states_len);
+                    let __top = __states[__states_len - 1];
+                    let __next_state = __GOTO[(__top * 6 + __nt) as usize] as i32 - 1;
+                    __states.push(__next_state);
+                }
+            }
+            pub fn __reduce<
+                'input,
+            >(
+                input: &'input str,
+                __action: i32,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Option<Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                let __nonterminal = match -__action {
+                    1 => match __reduce_Stmt_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    2 => match __reduce_Stmt_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    3 => match __reduce_Stmt_2a_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    4 => match __reduce_Stmt_2a_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    5 => match __reduce_Stmt_2b_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    6 => match __reduce_Stmt_2b_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    7 => match __reduce_Stmts_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    8 => match __reduce_Stmts_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    9 => match __reduce_Value_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    10 => match __reduce_Value_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    11 => match __reduce_____Stmts_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    _ => panic!("invalid action code {}", __action)
+                };
+                let __state = *__states.last().unwrap() as usize;
+                let __next_state = __GOTO[__state * 6 + __nonterminal] as i32 - 1;
+                __states.push(__next_state);
+                None
+            }
+
+            fn __reduce_Stmt_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Stmt = "let", NAME, "=", Value, ";" => ActionFn(2);
+                let __sym4 = __pop_Term_22_3b_22(__symbols);
+                let __sym3 = __pop_NtValue(__symbols);
+                let __sym2 = __pop_Term_22_3d_22(__symbols);
+                let __sym1 = __pop_TermNAME(__symbols);
+                let __sym0 = __pop_Term_22let_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = super::super::super::__action2::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 5);
+                __symbols.push((__start, __Symbol::NtStmt(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce_Stmt_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Stmt = "while", NAME, ";" => ActionFn(3);
+                let __sym2 = __pop_Term_22_3b_22(__symbols);
+                let __sym1 = __pop_TermNAME(__symbols);
+                let __sym0 = __pop_Term_22while_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action3::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtStmt(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce_Stmt_2a_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Stmt* =  => ActionFn(6);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action6::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::NtStmt_2a(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce_Stmt_2a_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Stmt* = Stmt+ => ActionFn(7);
+                let __sym0 = __pop_NtStmt_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action7::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtStmt_2a(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce_Stmt_2b_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Stmt+ = Stmt => ActionFn(8);
+                let __sym0 = __pop_NtStmt(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action8::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtStmt_2b(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce_Stmt_2b_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Stmt+ = Stmt+, Stmt => ActionFn(9);
+                let __sym1 = __pop_NtStmt(__symbols);
+                let __sym0 = __pop_NtStmt_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action9::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtStmt_2b(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce_Stmts_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Stmts =  => ActionFn(10);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action10::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::NtStmts(__nt), __end));
+                Ok(3)
+            }
+
+            fn __reduce_Stmts_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Stmts = Stmt+ => ActionFn(11);
+                let __sym0 = __pop_NtStmt_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action11::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtStmts(__nt), __end));
+                Ok(3)
+            }
+
+            fn __reduce_Value_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Value = NUM => ActionFn(4);
+                let __sym0 = __pop_TermNUM(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action4::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtValue(__nt), __end));
+                Ok(4)
+            }
+
+            fn __reduce_Value_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Value = "nil" => ActionFn(5);
+                let __sym0 = __pop_Term_22nil_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action5::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtValue(__nt), __end));
+                Ok(4)
+            }
+
+            fn __reduce_____Stmts_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // __Stmts = Stmts => ActionFn(0);
+                let __sym0 = __pop_NtStmts(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action0::<>(input, __sym0);
+                Err(Ok(__Symbol::Nt____Stmts(__nt)))
+            }
+            fn __pop_Term_22_3b_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_3b_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_3d_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_3d_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22let_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22let_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22nil_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22nil_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22while_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22while_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_TermNAME<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::TermNAME(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_TermNUM<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::TermNUM(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtStmt<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtStmt(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtStmt_2a<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, ::std::vec::Vec<&'input str>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtStmt_2a(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtStmt_2b<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, ::std::vec::Vec<&'input str>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtStmt_2b(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtStmts<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Vec<&'input str>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtStmts(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtValue<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, (), usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtValue(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt____Stmts<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Vec<&'input str>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt____Stmts(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+        }
+        pub use self::__parse_table::parse_Stmts;
+        pub use self::__parse_table::parse_Stmts_cancelable;
+        pub use self::__parse_table::StmtsParser;
+    }
+    pub use self::__parse_table::parse_Stmts_cancelable;
+    pub use self::__parse_table::StmtsParser;
+}
+pub use self::__parse__Stmts::parse_Stmts;
+pub use self::__parse__Stmts::parse_Stmts_cancelable;
+pub use self::__parse__Stmts::StmtsParser;
+mod __intern_token {
+    #![allow(unused_imports)]
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    extern crate regex as __regex;
+    use std::fmt as __fmt;
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Token<'input>(pub usize, pub &'input str);
+    impl<'a> __fmt::Display for Token<'a> {
+        fn fmt(&self, formatter: &mut __fmt::Formatter) -> Result<(), __fmt::Error> {
+            __fmt::Display::fmt(self.1, formatter)
+        }
+    }
+
+    pub struct __Matcher<'input> {
+        text: &'input str,
+        consumed: usize,
+        regex_set: __regex::RegexSet,
+        regex_vec: Vec<__regex::Regex>,
+    }
+
+    impl<'input> __Matcher<'input> {
+        pub fn new(s: &'input str) -> __Matcher<'input> {
+            let __strs: &[&str] = &[
+                "^(?u:[0-9])+",
+                "^(?u:[a-z])+",
+                "^(?u:;)",
+                "^(?u:=)",
+                "^(?u:let)",
+                "^(?u:nil)",
+                "^(?u:while)",
+            ];
+            let __regex_set = __regex::RegexSet::new(__strs).unwrap();
+            let __regex_vec = vec![
+                __regex::Regex::new("^(?u:[0-9])+").unwrap(),
+                __regex::Regex::new("^(?u:[a-z])+").unwrap(),
+                __regex::Regex::new("^(?u:;)").unwrap(),
+                __regex::Regex::new("^(?u:=)").unwrap(),
+                __regex::Regex::new("^(?u:let)").unwrap(),
+                __regex::Regex::new("^(?u:nil)").unwrap(),
+                __regex::Regex::new("^(?u:while)").unwrap(),
+            ];
+            __Matcher {
+                text: s,
+                consumed: 0,
+                regex_set: __regex_set,
+                regex_vec: __regex_vec,
+            }
+        }
+    }
+
+    impl<'input> Iterator for __Matcher<'input> {
+        type Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize,Token<'input>,&'static str>>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let __text = self.text.trim_left();
+            let __whitespace = self.text.len() - __text.len();
+            let __start_offset = self.consumed + __whitespace;
+            if __text.is_empty() {
+                self.text = __text;
+                self.consumed = __start_offset;
+                None
+            } else {
+                let __matches = self.regex_set.matches(__text);
+                if !__matches.matched_any() {
+                    Some(Err(__lalrpop_util::ParseError::InvalidToken {
+                        location: __start_offset,
+                    }))
+                } else {
+                    let mut __longest_match = 0;
+                    let mut __index = 0;
+                    for __i in 0 .. 7 {
+                        if __matches.matched(__i) {
+                            let __match = self.regex_vec[__i].find(__text).unwrap();
+                            let __len = __match.end();
+                            if __len >= __longest_match {
+                                __longest_match = __len;
+                                __index = __i;
+                            }
+                        }
+                    }
+                    let __result = &__text[..__longest_match];
+                    let __remaining = &__text[__longest_match..];
+                    let __end_offset = __start_offset + __longest_match;
+                    self.text = __remaining;
+                    self.consumed = __end_offset;
+                    Some(Ok((__start_offset, Token(__index, __result), __end_offset)))
+                }
+            }
+        }
+    }
+
+    pub fn __reserved_word<'input, L, E>(__error: __lalrpop_util::ParseError<L, Token<'input>, E>) -> __lalrpop_util::ParseError<L, Token<'input>, E> {
+        match __error {
+            __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected } => {
+                let __description = match (__token.1).0 {
+                    5 if __expected.iter().any(|__e| __e == r###"NAME"###) => "variable name",
+                    6 if __expected.iter().any(|__e| __e == r###"NAME"###) => "identifier",
+                    _ => {
+                        return __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected };
+                    }
+                };
+                __lalrpop_util::ParseError::ReservedWord { token: __token, description: ::std::string::String::from(__description), expected: __expected }
+            }
+            __error => __error,
+        }
+    }
+}
+pub use self::__intern_token::Token;
+
+#[allow(unused_variables)]
+fn __action0<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Vec<&'input str>, usize),
+) -> Vec<&'input str>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, ::std::vec::Vec<&'input str>, usize),
+) -> Vec<&'input str>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, (), usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> &'input str
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> &'input str
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> ()
+{
+    //@ src/reserved.lalrpop:25:12 (1 lines)
+    ()
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> ()
+{
+    //@ src/reserved.lalrpop:26:14 (1 lines)
+    ()
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::vec::Vec<&'input str>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<&'input str>, usize),
+) -> ::std::vec::Vec<&'input str>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> ::std::vec::Vec<&'input str>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action9<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<&'input str>, usize),
+    (_, e, _): (usize, &'input str, usize),
+) -> ::std::vec::Vec<&'input str>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action10<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<&'input str>
+{
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action6(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action1(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action11<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::vec::Vec<&'input str>, usize),
+) -> Vec<&'input str>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action7(
+        input,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action1(
+        input,
+        __temp0,
+    )
+}
+
+pub trait __ToTriple<'input, > {
+    type Error;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),Self::Error>;
+}
+
+impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize) {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        Ok(value)
+    }
+}
+impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        value
+    }
+}
@@ -0,0 +1,1593 @@
+// auto-generated: "lalrpop 0.15.0"
+use util::tok::Tok;
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+
+mod __parse__S {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    use util::tok::Tok;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__ToTriple;
+    #[allow(dead_code)]
+    pub fn parse_S<
+        __TOKEN: __ToTriple<Error=&'static str>,
+        __TOKENS: IntoIterator<Item=__TOKEN>,
+    >(
+        __tokens0: __TOKENS,
+    ) -> Result<i32, __lalrpop_util::ParseError<(), Tok, &'static str>> where
+      __TOKENS: Clone,
+    {
+        let __ascent = __ascent::parse_S(
+            __tokens0.clone(),
+        );
+        let __parse_table = __parse_table::parse_S(
+            __tokens0.clone(),
+        );
+        assert_eq!(__ascent, __parse_table);
+        return __ascent;
+    }
+    mod __ascent {
+
+        mod __parse__S {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use util::tok::Tok;
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__ToTriple;
+            #[allow(dead_code)]
+            pub fn parse_S<
+                __TOKEN: __ToTriple<Error=&'static str>,
+                __TOKENS: IntoIterator<Item=__TOKEN>,
+            >(
+                __tokens0: __TOKENS,
+            ) -> Result<i32, __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let __tokens = __tokens0.into_iter();
+                let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+                let __last_location = ::std::cell::RefCell::new(Default::default());
+                let mut __tokens = __tokens.inspect(|t| if let Ok(ref t) = *t { *__last_location.borrow_mut() = t.2.clone(); });
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                let mut __extra = None;
+                let __result = __state0(&mut __tokens, __lookahead, &mut __extra, ::std::marker::PhantomData::<()>);
+                if let Some((__token, __expected)) = __extra {
+                    return Err(match __result {
+                        Ok(_) => __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected },
+                        Err(_) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                    });
+                }
+                match __result {
+                    Ok((Some(__lookahead), _)) => {
+                        Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] })
+                    }
+                    Ok((None, __Nonterminal::____S((_, __nt, _)))) => {
+                        Ok(__nt)
+                    }
+                    Ok(_) => unreachable!(),
+                    Err(__lalrpop_util::ParseError::UnrecognizedEof { expected: __expected, .. }) => {
+                        Err(__lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.borrow().clone(), expected: __expected })
+                    }
+                    Err(__error) => Err(__error),
+                }
+            }
+
+            #[allow(dead_code)]
+            pub enum __Nonterminal<>
+             {
+                E(((), i32, ())),
+                S(((), i32, ())),
+                T(((), i32, ())),
+                ____S(((), i32, ())),
+            }
+            impl<> ::std::fmt::Debug for __Nonterminal<>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Nonterminal::E(..) => __f.write_str("E"),
+                        __Nonterminal::S(..) => __f.write_str("S"),
+                        __Nonterminal::T(..) => __f.write_str("T"),
+                        __Nonterminal::____S(..) => __f.write_str("__S"),
+                    }
+                }
+            }
+
+            // State 0
+            //     AllInputs = []
+            //     OptionalInputs = []
+            //     FixedInputs = []
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     E = (*) E "-" T ["(", ")", "-", Num, EOF]
+            //     E = (*) T ["(", ")", "-", Num, EOF]
+            //     S = (*) E ["(", ")", "-", Num, EOF]
+            //     T = (*) "(" E ")" ["(", ")", "-", Num, EOF]
+            //     T = (*) Num ["(", ")", "-", Num, EOF]
+            //     __S = (*) S ["(", ")", "-", Num, EOF]
+            //
+            //   "(" -> S4
+            //   Num -> S5
+            //
+            //     E -> S1
+            //     S -> S2
+            //     T -> S3
+            fn __state0<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<((), Tok, ())>,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::LParen, __loc2)) => {
+                        let __sym0 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state4(__tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Tok::Num(__tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(__tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###"Num"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::E(__sym0) => {
+                            __result = try!(__state1(__tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::S(__sym0) => {
+                            __result = try!(__state2(__tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::T(__sym0) => {
+                            __result = try!(__state3(__tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 1
+            //     AllInputs = [E]
+            //     OptionalInputs = []
+            //     FixedInputs = [E]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     E = E (*) "-" T ["(", ")", "-", Num, EOF]
+            //     S = E (*) ["(", ")", "-", Num, EOF]
+            //
+            //   "-" -> S6
+            //   [EOF] -> S = E => ActionFn(1);
+            //
+            fn __state1<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<((), Tok, ())>,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), i32, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::Minus, __loc2)) => {
+                        let __sym1 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state6(__tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action1::<>(__sym0);
+                        let __nt = __Nonterminal::S((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""-""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<((), Tok, ())> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action1::<>(__sym0);
+                        let __nt = __Nonterminal::S((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 2
+            //     AllInputs = [S]
+            //     OptionalInputs = []
+            //     FixedInputs = [S]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(__S)
+            //
+            //     __S = S (*) ["(", ")", "-", Num, EOF]
+            //
+            //   [EOF] -> __S = S => ActionFn(0);
+            //
+            fn __state2<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<((), Tok, ())>,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), i32, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                match __lookahead {
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(__sym0);
+                        let __nt = __Nonterminal::____S((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<((), Tok, ())> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(__sym0);
+                        let __nt = __Nonterminal::____S((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 3
+            //     AllInputs = [T]
+            //     OptionalInputs = []
+            //     FixedInputs = [T]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(E)
+            //
+            //     E = T (*) ["(", ")", "-", Num, EOF]
+            //
+            //   [")", "-", EOF] -> E = T => ActionFn(3);
+            //
+            fn __state3<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<((), Tok, ())>,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), i32, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                match __lookahead {
+                    Some((_, Tok::RParen, _)) |
+                    Some((_, Tok::Minus, _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action3::<>(__sym0);
+                        let __nt = __Nonterminal::E((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""-""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<((), Tok, ())> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action3::<>(__sym0);
+                        let __nt = __Nonterminal::E((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 4
+            //     AllInputs = ["("]
+            //     OptionalInputs = []
+            //     FixedInputs = ["("]
+            //     WillPushLen = 2
+            //     WillPush = [E, ")"]
+            //     WillProduce = Some(T)
+            //
+            //     E = (*) E "-" T ["(", ")", "-", Num, EOF]
+            //     E = (*) T ["(", ")", "-", Num, EOF]
+            //     T = (*) "(" E ")" ["(", ")", "-", Num, EOF]
+            //     T = "(" (*) E ")" ["(", ")", "-", Num, EOF]
+            //     T = (*) Num ["(", ")", "-", Num, EOF]
+            //
+            //   "(" -> S4
+            //   Num -> S5
+            //
+            //     E -> S7
+            //     T -> S3
+            fn __state4<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), Tok, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                let __sym0 = &mut Some(__sym0);
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::LParen, __loc2)) => {
+                        let __sym1 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state4(__tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Tok::Num(__tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(__tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###"Num"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    if __sym0.is_none() {
+                        return Ok(__result);
+                    }
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::E(__sym1) => {
+                            __result = try!(__state7(__tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::T(__sym1) => {
+                            __result = try!(__state3(__tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 5
+            //     AllInputs = [Num]
+            //     OptionalInputs = []
+            //     FixedInputs = [Num]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(T)
+            //
+            //     T = Num (*) ["(", ")", "-", Num, EOF]
+            //
+            //   [")", "-", EOF] -> T = Num => ActionFn(4);
+            //
+            fn __state5<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), i32, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                match __lookahead {
+                    Some((_, Tok::RParen, _)) |
+                    Some((_, Tok::Minus, _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action4::<>(__sym0);
+                        let __nt = __Nonterminal::T((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""-""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<((), Tok, ())> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action4::<>(__sym0);
+                        let __nt = __Nonterminal::T((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 6
+            //     AllInputs = [E, "-"]
+            //     OptionalInputs = []
+            //     FixedInputs = [E, "-"]
+            //     WillPushLen = 1
+            //     WillPush = [T]
+            //     WillProduce = Some(E)
+            //
+            //     E = E "-" (*) T ["(", ")", "-", Num, EOF]
+            //     T = (*) "(" E ")" ["(", ")", "-", Num, EOF]
+            //     T = (*) Num ["(", ")", "-", Num, EOF]
+            //
+            //   "(" -> S4
+            //   Num -> S5
+            //
+            //     T -> S8
+            fn __state6<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), i32, ()),
+                __sym1: ((), Tok, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::LParen, __loc2)) => {
+                        let __sym2 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state4(__tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Tok::Num(__tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(__tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###"Num"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::T(__sym2) => {
+                            __result = try!(__state8(__tokens, __lookahead, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 7
+            //     AllInputs = ["(", E]
+            //     OptionalInputs = ["("]
+            //     FixedInputs = [E]
+            //     WillPushLen = 1
+            //     WillPush = [")"]
+            //     WillProduce = None
+            //
+            //     E = E (*) "-" T ["(", ")", "-", Num, EOF]
+            //     T = "(" E (*) ")" ["(", ")", "-", Num, EOF]
+            //
+            //   ")" -> S9
+            //   "-" -> S6
+            //
+            fn __state7<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<((), Tok, ())>,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: &mut Option<((), Tok, ())>,
+                __sym1: ((), i32, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::RParen, __loc2)) => {
+                        let __sym2 = (__loc1, (__tok), __loc2);
+                        let __sym0 = __sym0.take().unwrap();
+                        __result = try!(__state9(__tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, __tok @ Tok::Minus, __loc2)) => {
+                        let __sym2 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state6(__tokens, __extra, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""-""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 8
+            //     AllInputs = [E, "-", T]
+            //     OptionalInputs = []
+            //     FixedInputs = [E, "-", T]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(E)
+            //
+            //     E = E "-" T (*) ["(", ")", "-", Num, EOF]
+            //
+            //   [")", "-", EOF] -> E = E, "-", T => ActionFn(2);
+            //
+            fn __state8<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<((), Tok, ())>,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), i32, ()),
+                __sym1: ((), Tok, ()),
+                __sym2: ((), i32, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                match __lookahead {
+                    Some((_, Tok::RParen, _)) |
+                    Some((_, Tok::Minus, _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action2::<>(__sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::E((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""-""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<((), Tok, ())> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action2::<>(__sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::E((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 9
+            //     AllInputs = ["(", E, ")"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["(", E, ")"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(T)
+            //
+            //     T = "(" E ")" (*) ["(", ")", "-", Num, EOF]
+            //
+            //   [")", "-", EOF] -> T = "(", E, ")" => ActionFn(5);
+            //
+            fn __state9<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), Tok, ()),
+                __sym1: ((), i32, ()),
+                __sym2: ((), Tok, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                match __lookahead {
+                    Some((_, Tok::RParen, _)) |
+                    Some((_, Tok::Minus, _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action5::<>(__sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::T((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""-""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<((), Tok, ())> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action5::<>(__sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::T((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+        }
+        pub use self::__parse__S::parse_S;
+    }
+    mod __parse_table {
+
+        mod __parse_table {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use util::tok::Tok;
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__ToTriple;
+            #[allow(dead_code)]
+            pub enum __Symbol<>
+             {
+                Term_22_28_22(Tok),
+                Term_22_29_22(Tok),
+                Term_22_2d_22(Tok),
+                TermNum(i32),
+                NtE(i32),
+                NtS(i32),
+                NtT(i32),
+                Nt____S(i32),
+            }
+            impl<> ::std::fmt::Debug for __Symbol<>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Symbol::Term_22_28_22(..) => __f.write_str("\"(\""),
+                        __Symbol::Term_22_29_22(..) => __f.write_str("\")\""),
+                        __Symbol::Term_22_2d_22(..) => __f.write_str("\"-\""),
+                        __Symbol::TermNum(..) => __f.write_str("Num"),
+                        __Symbol::NtE(..) => __f.write_str("E"),
+                        __Symbol::NtS(..) => __f.write_str("S"),
+                        __Symbol::NtT(..) => __f.write_str("T"),
+                        __Symbol::Nt____S(..) => __f.write_str("__S"),
+                    }
+                }
+            }
+            const __ACTION: &'static [i8] = &[
+                // State 0
+                //     E = (*) E "-" T ["(", ")", "-", Num, EOF]
+                //     E = (*) T ["(", ")", "-", Num, EOF]
+                //     S = (*) E ["(", ")", "-", Num, EOF]
+                //     T = (*) "(" E ")" ["(", ")", "-", Num, EOF]
+                //     T = (*) Num ["(", ")", "-", Num, EOF]
+                //     __S = (*) S ["(", ")", "-", Num, EOF]
+                5,  // on "(", goto 4
+                0,  // on ")", error
+                0,  // on "-", error
+                6,  // on Num, goto 5
+
+                // State 1
+                //     E = E (*) "-" T ["(", ")", "-", Num, EOF]
+                //     S = E (*) ["(", ")", "-", Num, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                7,  // on "-", goto 6
+                0,  // on Num, error
+
+                // State 2
+                //     __S = S (*) ["(", ")", "-", Num, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                0,  // on "-", error
+                0,  // on Num, error
+
+                // State 3
+                //     E = T (*) ["(", ")", "-", Num, EOF]
+                0,  // on "(", error
+                -2,  // on ")", reduce `E = T => ActionFn(3);`
+                -2,  // on "-", reduce `E = T => ActionFn(3);`
+                0,  // on Num, error
+
+                // State 4
+                //     E = (*) E "-" T ["(", ")", "-", Num, EOF]
+                //     E = (*) T ["(", ")", "-", Num, EOF]
+                //     T = (*) "(" E ")" ["(", ")", "-", Num, EOF]
+                //     T = "(" (*) E ")" ["(", ")", "-", Num, EOF]
+                //     T = (*) Num ["(", ")", "-", Num, EOF]
+                5,  // on "(", goto 4
+                0,  // on ")", error
+                0,  // on "-", error
+                6,  // on Num, goto 5
+
+                // State 5
+                //     T = Num (*) ["(", ")", "-", Num, EOF]
+                0,  // on "(", error
+                -4,  // on ")", reduce `T = Num => ActionFn(4);`
+                -4,  // on "-", reduce `T = Num => ActionFn(4);`
+                0,  // on Num, error
+
+                // State 6
+                //     E = E "-" (*) T ["(", ")", "-", Num, EOF]
+                //     T = (*) "(" E ")" ["(", ")", "-", Num, EOF]
+                //     T = (*) Num ["(", ")", "-", Num, EOF]
+                5,  // on "(", goto 4
+                0,  // on ")", error
+                0,  // on "-", error
+                6,  // on Num, goto 5
+
+                // State 7
+                //     E = E (*) "-" T ["(", ")", "-", Num, EOF]
+                //     T = "(" E (*) ")" ["(", ")", "-", Num, EOF]
+                0,  // on "(", error
+                10,  // on ")", goto 9
+                7,  // on "-", goto 6
+                0,  // on Num, error
+
+                // State 8
+                //     E = E "-" T (*) ["(", ")", "-", Num, EOF]
+                0,  // on "(", error
+                -1,  // on ")", reduce `E = E, "-", T => ActionFn(2);`
+                -1,  // on "-", reduce `E = E, "-", T => ActionFn(2);`
+                0,  // on Num, error
+
+                // State 9
+                //     T = "(" E ")" (*) ["(", ")", "-", Num, EOF]
+                0,  // on "(", error
+                -5,  // on ")", reduce `T = "(", E, ")" => ActionFn(5);`
+                -5,  // on "-", reduce `T = "(", E, ")" => ActionFn(5);`
+                0,  // on Num, error
+
+            ];
+            const __EOF_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // on EOF, error
+
+                // State 1
+                -3,  // on EOF, reduce `S = E => ActionFn(1);`
+
+                // State 2
+                -6,  // on EOF, reduce `__S = S => ActionFn(0);`
+
+                // State 3
+                -2,  // on EOF, reduce `E = T => ActionFn(3);`
+
+                // State 4
+                0,  // on EOF, error
+
+                // State 5
+                -4,  // on EOF, reduce `T = Num => ActionFn(4);`
+
+                // State 6
+                0,  // on EOF, error
+
+                // State 7
+                0,  // on EOF, error
+
+                // State 8
+                -1,  // on EOF, reduce `E = E, "-", T => ActionFn(2);`
+
+                // State 9
+                -5,  // on EOF, reduce `T = "(", E, ")" => ActionFn(5);`
+
+            ];
+            const __GOTO: &'static [u8] = &[
+                // State 0
+                2,  // on E, goto 1
+                3,  // on S, goto 2
+                4,  // on T, goto 3
+                0,  // on __S, error
+
+                // State 1
+                0,  // on E, error
+                0,  // on S, error
+                0,  // on T, error
+                0,  // on __S, error
+
+                // State 2
+                0,  // on E, error
+                0,  // on S, error
+                0,  // on T, error
+                0,  // on __S, error
+
+                // State 3
+                0,  // on E, error
+                0,  // on S, error
+                0,  // on T, error
+                0,  // on __S, error
+
+                // State 4
+                8,  // on E, goto 7
+                0,  // on S, error
+                4,  // on T, goto 3
+                0,  // on __S, error
+
+                // State 5
+                0,  // on E, error
+                0,  // on S, error
+                0,  // on T, error
+                0,  // on __S, error
+
+                // State 6
+                0,  // on E, error
+                0,  // on S, error
+                9,  // on T, goto 8
+                0,  // on __S, error
+
+                // State 7
+                0,  // on E, error
+                0,  // on S, error
+                0,  // on T, error
+                0,  // on __S, error
+
+                // State 8
+                0,  // on E, error
+                0,  // on S, error
+                0,  // on T, error
+                0,  // on __S, error
+
+                // State 9
+                0,  // on E, error
+                0,  // on S, error
+                0,  // on T, error
+                0,  // on __S, error
+
+            ];
+            const __DEFAULT_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // no default reduction
+
+                // State 1
+                0,  // no default reduction
+
+                // State 2
+                0,  // no default reduction
+
+                // State 3
+                -2,  // on any token, reduce `E = T => ActionFn(3);`
+
+                // State 4
+                0,  // no default reduction
+
+                // State 5
+                -4,  // on any token, reduce `T = Num => ActionFn(4);`
+
+                // State 6
+                0,  // no default reduction
+
+                // State 7
+                0,  // no default reduction
+
+                // State 8
+                -1,  // on any token, reduce `E = E, "-", T => ActionFn(2);`
+
+                // State 9
+                -5,  // on any token, reduce `T = "(", E, ")" => ActionFn(5);`
+
+            ];
+            fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+                const __TERMINAL: &'static [&'static str] = &[
+                    r###""(""###,
+                    r###"")""###,
+                    r###""-""###,
+                    r###"Num"###,
+                ];
+                __ACTION[(__state * 4)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+                    if state == 0 {
+                        None
+                    } else {
+                        Some(terminal.to_string())
+                    }
+                }).collect()
+            }
+            #[allow(dead_code)]
+            pub fn parse_S<
+                __TOKEN: __ToTriple<Error=&'static str>,
+                __TOKENS: IntoIterator<Item=__TOKEN>,
+            >(
+                __tokens0: __TOKENS,
+            ) -> Result<i32, __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let __tokens = __tokens0.into_iter();
+                let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____S(__v) => Ok(__v), _ => unreachable!() })
+            }
+            #[allow(dead_code)]
+            pub fn parse_S_cancelable<
+                __TOKEN: __ToTriple<Error=&'static str>,
+                __TOKENS: IntoIterator<Item=__TOKEN>,
+            >(
+                __tokens0: __TOKENS,
+                __cancel: &::std::sync::atomic::AtomicBool,
+            ) -> Result<i32, __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let __tokens = __tokens0.into_iter();
+                let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____S(__v) => Ok(__v), _ => unreachable!() })
+            }
+
+            #[allow(dead_code)]
+            pub struct SParser {
+                __states: ::std::vec::Vec<i32>,
+                __symbols: __lalrpop_util::stack::Recycled,
+                __cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+            }
+
+            #[allow(dead_code)]
+            impl SParser {
+                pub fn new() -> SParser {
+                    SParser { __states: vec![], __symbols: __lalrpop_util::stack::Recycled::new(), __cancel: None }
+                }
+
+                pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+                    self.__cancel = Some(flag);
+                }
+
+                pub fn parse_in_place<__TOKEN: __ToTriple<Error=&'static str>, __TOKENS: IntoIterator<Item=__TOKEN>>(
+                    &mut self,
+                    __tokens0: __TOKENS,
+                ) -> Result<i32, __lalrpop_util::ParseError<(), Tok, &'static str>>
+                {
+                    let __tokens = __tokens0.into_iter();
+                    let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+                    let mut __symbols = self.__symbols.take();
+                    let __result = __parse::<>(0, &mut __tokens, &mut self.__states, &mut __symbols, self.__cancel.as_ref().map(|c| &**c)).and_then(|__v| match __v { __Symbol::Nt____S(__v) => Ok(__v), _ => unreachable!() });
+                    self.__symbols.put(__symbols);
+                    __result
+                }
+            }
+
+            fn __parse<
+            >(
+                __start_state: i32,
+                mut __tokens: &mut Iterator<Item = Result<((), Tok, ()), &'static str>>,
+                mut __states: &mut ::std::vec::Vec<i32>,
+                mut __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+                __cancel: Option<&::std::sync::atomic::AtomicBool>,
+            ) -> Result<__Symbol<>, __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                __states.clear();
+                __states.push(__start_state);
+                __symbols.clear();
+                let mut __integer;
+                let mut __lookahead;
+                let __last_location: &mut () = &mut Default::default();
+                let mut __default_reduced = [0_usize; 16];
+                let mut __default_reduced_len = 0;
+                '__shift: loop {
+                    loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __DEFAULT_ACTION[__state] as i32;
+                        if __action == 0 {
+                            break;
+                        }
+                        if __default_reduced_len < 16 {
+                            __default_reduced[__default_reduced_len] = __state;
+                            __default_reduced_len += 1;
+                        }
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(__action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    }
+                    __lookahead = match __tokens.next() {
+                        Some(Ok(v)) => v,
+                        None => break '__shift,
+                        Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                    };
+                    *__last_location = __lookahead.2.clone();
+                    __integer = match __lookahead.1 {
+                        Tok::LParen if true => 0,
+                        Tok::RParen if true => 1,
+                        Tok::Minus if true => 2,
+                        Tok::Num(_) if true => 3,
+                        _ => {
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| true).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match Some(__lookahead) {
+                                Some(__token) => {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error);
+                        }
+                    };
+                    '__inner: loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __ACTION[__state * 4 + __integer] as i32;
+                        if __action > 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                                }
+                            }
+                            let __symbol = match __integer {
+                                0 => match __lookahead.1 {
+                                    __tok @ Tok::LParen => __Symbol::Term_22_28_22((__tok)),
+                                    _ => unreachable!(),
+                                },
+                                1 => match __lookahead.1 {
+                                    __tok @ Tok::RParen => __Symbol::Term_22_29_22((__tok)),
+                                    _ => unreachable!(),
+                                },
+                                2 => match __lookahead.1 {
+                                    __tok @ Tok::Minus => __Symbol::Term_22_2d_22((__tok)),
+                                    _ => unreachable!(),
+                                },
+                                3 => match __lookahead.1 {
+                                    Tok::Num(__tok0) => __Symbol::TermNum((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                _ => unreachable!(),
+                            };
+                            __states.push(__action - 1);
+                            __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                            __default_reduced_len = 0;
+                            continue '__shift;
+                        } else if __action < 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                                }
+                            }
+                            if let Some(r) = __reduce(__action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                                if r.is_err() {
+                                    return r;
+                                }
+                                return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] });
+                            }
+                        } else {
+                            let mut __err_lookahead = Some(__lookahead);
+                            let mut __err_integer: Option<usize> = Some(__integer);
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 4 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match __err_lookahead {
+                                Some(__token) => {
+                                    if __accepts( *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                        __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                    } else {
+                                        __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                    }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error)
+                        }
+                    }
+                }
+                loop {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __action = __EOF_ACTION[__state] as i32;
+                    if __action < 0 {
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(__action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    } else {
+                        let mut __err_lookahead = None;
+                        let mut __err_integer: Option<usize> = None;
+                        let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 4 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                        let __expected = __expected_tokens(__state);
+                        let __error = match __err_lookahead {
+                            Some(__token) => {
+                                if __accepts( *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                    __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                } else {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                            }
+                            None => {
+                                __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                            }
+                        };
+                        return Err(__error)
+                    }
+                }
+            }
+            fn __accepts<
+            >(
+                __error_state: i32,
+                __states: & [i32],
+                __opt_integer: Option<usize>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> bool
+            {
+                let mut __states = __states.to_vec();
+                __states.push(__error_state);
+                loop {
+                    let mut __states_len = __states.len();
+                    let __top = __states[__states_len - 1];
+                    let __action = match __opt_integer {
+                        None => __EOF_ACTION[__top as usize] as i32,
+                        Some(__integer) => __ACTION[(__top * 4) as usize + __integer] as i32,
+                    };
+                    if __action == 0 { return false; }
+                    if __action > 0 { return true; }
+                    let (__to_pop, __nt) = match -__action {
+                        // simulate E = E, "-", T => ActionFn(2);
+                        1 => {
+                            (3, 0)
+                        }
+                        // simulate E = T => ActionFn(3);
+                        2 => {
+                            (1, 0)
+                        }
+                        // simulate S = E => ActionFn(1);
+                        3 => {
+                            (1, 1)
+                        }
+                        // simulate T = Num => ActionFn(4);
+                        4 => {
+                            (1, 2)
+                        }
+                        // simulate T = "(", E, ")" => ActionFn(5);
+                        5 => {
+                            (3, 2)
+                        }
+                        // simulate __S = S => ActionFn(0);
+                        6 => return true,
+                        _ => panic!("invalid action code {}", __action)
+                    };
+                    __states_len -= __to_pop;
+                    __states.truncate(__states_len);
+                    let __top = __states[__states_len - 1];
+                    let __next_state = __GOTO[(__top * 4 + __nt) as usize] as i32 - 1;
+                    __states.push(__next_state);
+                }
+            }
+            pub fn __reduce<
+            >(
+                __action: i32,
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Option<Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+            {
+                let __nonterminal = match -__action {
+                    1 => match __reduce_E_0(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    2 => match __reduce_E_1(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    3 => match __reduce_S_0(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    4 => match __reduce_T_0(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    5 => match __reduce_T_1(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    6 => match __reduce_____S_0(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    _ => panic!("invalid action code {}", __action)
+                };
+                let __state = *__states.last().unwrap() as usize;
+                let __next_state = __GOTO[__state * 4 + __nonterminal] as i32 - 1;
+                __states.push(__next_state);
+                None
+            }
+
+            fn __reduce_E_0<
+            >(
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+            {
+                // E = E, "-", T => ActionFn(2);
+                let __sym2 = __pop_NtT(__symbols);
+                let __sym1 = __pop_Term_22_2d_22(__symbols);
+                let __sym0 = __pop_NtE(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action2::<>(__sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtE(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce_E_1<
+            >(
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+            {
+                // E = T => ActionFn(3);
+                let __sym0 = __pop_NtT(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action3::<>(__sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtE(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce_S_0<
+            >(
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+            {
+                // S = E => ActionFn(1);
+                let __sym0 = __pop_NtE(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action1::<>(__sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtS(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce_T_0<
+            >(
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+            {
+                // T = Num => ActionFn(4);
+                let __sym0 = __pop_TermNum(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action4::<>(__sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtT(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce_T_1<
+            >(
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+            {
+                // T = "(", E, ")" => ActionFn(5);
+                let __sym2 = __pop_Term_22_29_22(__symbols);
+                let __sym1 = __pop_NtE(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action5::<>(__sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtT(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce_____S_0<
+            >(
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+            {
+                // __S = S => ActionFn(0);
+                let __sym0 = __pop_NtS(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action0::<>(__sym0);
+                Err(Ok(__Symbol::Nt____S(__nt)))
+            }
+            fn __pop_Term_22_28_22<
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+            ) -> ((), Tok, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_28_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_29_22<
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+            ) -> ((), Tok, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_29_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_2d_22<
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+            ) -> ((), Tok, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2d_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_TermNum<
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+            ) -> ((), i32, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::TermNum(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtE<
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+            ) -> ((), i32, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtE(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtS<
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+            ) -> ((), i32, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtS(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtT<
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+            ) -> ((), i32, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtT(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt____S<
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+            ) -> ((), i32, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt____S(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+        }
+        pub use self::__parse_table::parse_S;
+        pub use self::__parse_table::parse_S_cancelable;
+        pub use self::__parse_table::SParser;
+    }
+    pub use self::__parse_table::parse_S_cancelable;
+    pub use self::__parse_table::SParser;
+}
+pub use self::__parse__S::parse_S;
+pub use self::__parse__S::parse_S_cancelable;
+pub use self::__parse__S::SParser;
+
+fn __action0<
+>(
+    (_, __0, _): ((), i32, ()),
+) -> i32
+{
+    (__0)
+}
+
+fn __action1<
+>(
+    (_, __0, _): ((), i32, ()),
+) -> i32
+{
+    (__0)
+}
+
+fn __action2<
+>(
+    (_, l, _): ((), i32, ()),
+    (_, _, _): ((), Tok, ()),
+    (_, r, _): ((), i32, ()),
+) -> i32
+{
+    //@ src/sub.lalrpop:17:24 (1 lines)
+    l - r
+}
+
+fn __action3<
+>(
+    (_, t, _): ((), i32, ()),
+) -> i32
+{
+    //@ src/sub.lalrpop:18:14 (1 lines)
+    t - super::ZERO
+}
+
+fn __action4<
+>(
+    (_, __0, _): ((), i32, ()),
+) -> i32
+{
+    (__0)
+}
+
+fn __action5<
+>(
+    (_, _, _): ((), Tok, ()),
+    (_, __0, _): ((), i32, ()),
+    (_, _, _): ((), Tok, ()),
+) -> i32
+{
+    (__0)
+}
+
+pub trait __ToTriple<> {
+    type Error;
+    fn to_triple(value: Self) -> Result<((),Tok,()),Self::Error>;
+}
+
+impl<> __ToTriple<> for Tok {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<((),Tok,()),&'static str> {
+        Ok(((), value, ()))
+    }
+}
+impl<> __ToTriple<> for Result<(Tok),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<((),Tok,()),&'static str> {
+        value.map(|v| ((), v, ()))
+    }
+}
@@ -0,0 +1,784 @@
+// auto-generated: "lalrpop 0.15.0"
+use util::tok::Tok;
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+
+mod __parse__S {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    use util::tok::Tok;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__ToTriple;
+    #[allow(dead_code)]
+    pub fn parse_S<
+        __TOKEN: __ToTriple<Error=&'static str>,
+        __TOKENS: IntoIterator<Item=__TOKEN>,
+    >(
+        __tokens0: __TOKENS,
+    ) -> Result<i32, __lalrpop_util::ParseError<(), Tok, &'static str>>
+    {
+        let __tokens = __tokens0.into_iter();
+        let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+        let __last_location = ::std::cell::RefCell::new(Default::default());
+        let mut __tokens = __tokens.inspect(|t| if let Ok(ref t) = *t { *__last_location.borrow_mut() = t.2.clone(); });
+        let __lookahead = match __tokens.next() {
+            Some(Ok(v)) => Some(v),
+            None => None,
+            Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+        };
+        let mut __extra = None;
+        let __result = __state0(&mut __tokens, __lookahead, &mut __extra, ::std::marker::PhantomData::<()>);
+        if let Some((__token, __expected)) = __extra {
+            return Err(match __result {
+                Ok(_) => __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected },
+                Err(_) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+            });
+        }
+        match __result {
+            Ok((Some(__lookahead), _)) => {
+                Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] })
+            }
+            Ok((None, __Nonterminal::____S((_, __nt, _)))) => {
+                Ok(__nt)
+            }
+            Ok(_) => unreachable!(),
+            Err(__lalrpop_util::ParseError::UnrecognizedEof { expected: __expected, .. }) => {
+                Err(__lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.borrow().clone(), expected: __expected })
+            }
+            Err(__error) => Err(__error),
+        }
+    }
+
+    #[allow(dead_code)]
+    pub enum __Nonterminal<>
+     {
+        E(((), i32, ())),
+        S(((), i32, ())),
+        T(((), i32, ())),
+        ____S(((), i32, ())),
+    }
+    impl<> ::std::fmt::Debug for __Nonterminal<>
+    {
+        fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            match *self {
+                __Nonterminal::E(..) => __f.write_str("E"),
+                __Nonterminal::S(..) => __f.write_str("S"),
+                __Nonterminal::T(..) => __f.write_str("T"),
+                __Nonterminal::____S(..) => __f.write_str("__S"),
+            }
+        }
+    }
+
+    // State 0
+    //     AllInputs = []
+    //     OptionalInputs = []
+    //     FixedInputs = []
+    //     WillPushLen = 0
+    //     WillPush = []
+    //     WillProduce = None
+    //
+    //     E = (*) E "-" T ["(", ")", "-", Fraction, Num, EOF]
+    //     E = (*) T ["(", ")", "-", Fraction, Num, EOF]
+    //     S = (*) E ["(", ")", "-", Fraction, Num, EOF]
+    //     T = (*) "(" E ")" ["(", ")", "-", Fraction, Num, EOF]
+    //     T = (*) Num ["(", ")", "-", Fraction, Num, EOF]
+    //     __S = (*) S ["(", ")", "-", Fraction, Num, EOF]
+    //
+    //   "(" -> S4
+    //   Num -> S5
+    //
+    //     E -> S1
+    //     S -> S2
+    //     T -> S3
+    fn __state0<
+        __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+    >(
+        __tokens: &mut __TOKENS,
+        __lookahead: Option<((), Tok, ())>,
+        __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+    {
+        let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+        match __lookahead {
+            Some((__loc1, __tok @ Tok::LParen, __loc2)) => {
+                let __sym0 = (__loc1, (__tok), __loc2);
+                __result = try!(__state4(__tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+            }
+            Some((__loc1, Tok::Num(__tok0), __loc2)) => {
+                let __sym0 = (__loc1, (__tok0), __loc2);
+                __result = try!(__state5(__tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+            }
+            _ => {
+                let __expected = vec![
+                    r###""(""###.to_string(),
+                    r###"Num"###.to_string(),
+                ];
+                return Err(match __lookahead {
+                    Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                    None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                });
+            }
+        }
+        loop {
+            let (__lookahead, __nt) = __result;
+            match __nt {
+                __Nonterminal::E(__sym0) => {
+                    __result = try!(__state1(__tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                }
+                __Nonterminal::S(__sym0) => {
+                    __result = try!(__state2(__tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                }
+                __Nonterminal::T(__sym0) => {
+                    __result = try!(__state3(__tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                }
+                _ => {
+                    return Ok((__lookahead, __nt));
+                }
+            }
+        }
+    }
+
+    // State 1
+    //     AllInputs = [E]
+    //     OptionalInputs = []
+    //     FixedInputs = [E]
+    //     WillPushLen = 0
+    //     WillPush = []
+    //     WillProduce = None
+    //
+    //     E = E (*) "-" T ["(", ")", "-", Fraction, Num, EOF]
+    //     S = E (*) ["(", ")", "-", Fraction, Num, EOF]
+    //
+    //   "-" -> S6
+    //   [EOF] -> S = E => ActionFn(1);
+    //
+    fn __state1<
+        __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+    >(
+        __tokens: &mut __TOKENS,
+        __lookahead: Option<((), Tok, ())>,
+        __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+        __sym0: ((), i32, ()),
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+    {
+        let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+        match __lookahead {
+            Some((__loc1, __tok @ Tok::Minus, __loc2)) => {
+                let __sym1 = (__loc1, (__tok), __loc2);
+                __result = try!(__state6(__tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                return Ok(__result);
+            }
+            None => {
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action1::<>(__sym0);
+                let __nt = __Nonterminal::S((
+                    __start,
+                    __nt,
+                    __end,
+                ));
+                __result = (__lookahead, __nt);
+                return Ok(__result);
+            }
+            _ => {
+                let __expected = vec![
+                    r###""-""###.to_string(),
+                ];
+                if let Some(__token) = __lookahead {
+                    *__extra = Some((__token, __expected));
+                }
+                let __lookahead: Option<((), Tok, ())> = None;
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action1::<>(__sym0);
+                let __nt = __Nonterminal::S((
+                    __start,
+                    __nt,
+                    __end,
+                ));
+                __result = (__lookahead, __nt);
+                return Ok(__result);
+            }
+        }
+    }
+
+    // State 2
+    //     AllInputs = [S]
+    //     OptionalInputs = []
+    //     FixedInputs = [S]
+    //     WillPushLen = 0
+    //     WillPush = []
+    //     WillProduce = Some(__S)
+    //
+    //     __S = S (*) ["(", ")", "-", Fraction, Num, EOF]
+    //
+    //   [EOF] -> __S = S => ActionFn(0);
+    //
+    fn __state2<
+        __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+    >(
+        __tokens: &mut __TOKENS,
+        __lookahead: Option<((), Tok, ())>,
+        __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+        __sym0: ((), i32, ()),
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+    {
+        let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+        match __lookahead {
+            None => {
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action0::<>(__sym0);
+                let __nt = __Nonterminal::____S((
+                    __start,
+                    __nt,
+                    __end,
+                ));
+                __result = (__lookahead, __nt);
+                return Ok(__result);
+            }
+            _ => {
+                let __expected = vec![
+                ];
+                if let Some(__token) = __lookahead {
+                    *__extra = Some((__token, __expected));
+                }
+                let __lookahead: Option<((), Tok, ())> = None;
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action0::<>(__sym0);
+                let __nt = __Nonterminal::____S((
+                    __start,
+                    __nt,
+                    __end,
+                ));
+                __result = (__lookahead, __nt);
+                return Ok(__result);
+            }
+        }
+    }
+
+    // State 3
+    //     AllInputs = [T]
+    //     OptionalInputs = []
+    //     FixedInputs = [T]
+    //     WillPushLen = 0
+    //     WillPush = []
+    //     WillProduce = Some(E)
+    //
+    //     E = T (*) ["(", ")", "-", Fraction, Num, EOF]
+    //
+    //   [")", "-", EOF] -> E = T => ActionFn(3);
+    //
+    fn __state3<
+        __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+    >(
+        __tokens: &mut __TOKENS,
+        __lookahead: Option<((), Tok, ())>,
+        __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+        __sym0: ((), i32, ()),
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+    {
+        let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+        match __lookahead {
+            Some((_, Tok::RParen, _)) |
+            Some((_, Tok::Minus, _)) |
+            None => {
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action3::<>(__sym0);
+                let __nt = __Nonterminal::E((
+                    __start,
+                    __nt,
+                    __end,
+                ));
+                __result = (__lookahead, __nt);
+                return Ok(__result);
+            }
+            _ => {
+                let __expected = vec![
+                    r###"")""###.to_string(),
+                    r###""-""###.to_string(),
+                ];
+                if let Some(__token) = __lookahead {
+                    *__extra = Some((__token, __expected));
+                }
+                let __lookahead: Option<((), Tok, ())> = None;
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action3::<>(__sym0);
+                let __nt = __Nonterminal::E((
+                    __start,
+                    __nt,
+                    __end,
+                ));
+                __result = (__lookahead, __nt);
+                return Ok(__result);
+            }
+        }
+    }
+
+    // State 4
+    //     AllInputs = ["("]
+    //     OptionalInputs = []
+    //     FixedInputs = ["("]
+    //     WillPushLen = 2
+    //     WillPush = [E, ")"]
+    //     WillProduce = Some(T)
+    //
+    //     E = (*) E "-" T ["(", ")", "-", Fraction, Num, EOF]
+    //     E = (*) T ["(", ")", "-", Fraction, Num, EOF]
+    //     T = (*) "(" E ")" ["(", ")", "-", Fraction, Num, EOF]
+    //     T = "(" (*) E ")" ["(", ")", "-", Fraction, Num, EOF]
+    //     T = (*) Num ["(", ")", "-", Fraction, Num, EOF]
+    //
+    //   "(" -> S4
+    //   Num -> S5
+    //
+    //     E -> S7
+    //     T -> S3
+    fn __state4<
+        __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+    >(
+        __tokens: &mut __TOKENS,
+        __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+        __sym0: ((), Tok, ()),
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+    {
+        let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+        let __lookahead = match __tokens.next() {
+            Some(Ok(v)) => Some(v),
+            None => None,
+            Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+        };
+        let __sym0 = &mut Some(__sym0);
+        match __lookahead {
+            Some((__loc1, __tok @ Tok::LParen, __loc2)) => {
+                let __sym1 = (__loc1, (__tok), __loc2);
+                __result = try!(__state4(__tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+            }
+            Some((__loc1, Tok::Num(__tok0), __loc2)) => {
+                let __sym1 = (__loc1, (__tok0), __loc2);
+                __result = try!(__state5(__tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+            }
+            _ => {
+                let __expected = vec![
+                    r###""(""###.to_string(),
+                    r###"Num"###.to_string(),
+                ];
+                return Err(match __lookahead {
+                    Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                    None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                });
+            }
+        }
+        loop {
+            if __sym0.is_none() {
+                return Ok(__result);
+            }
+            let (__lookahead, __nt) = __result;
+            match __nt {
+                __Nonterminal::E(__sym1) => {
+                    __result = try!(__state7(__tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                }
+                __Nonterminal::T(__sym1) => {
+                    __result = try!(__state3(__tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                }
+                _ => {
+                    return Ok((__lookahead, __nt));
+                }
+            }
+        }
+    }
+
+    // State 5
+    //     AllInputs = [Num]
+    //     OptionalInputs = []
+    //     FixedInputs = [Num]
+    //     WillPushLen = 0
+    //     WillPush = []
+    //     WillProduce = Some(T)
+    //
+    //     T = Num (*) ["(", ")", "-", Fraction, Num, EOF]
+    //
+    //   [")", "-", EOF] -> T = Num => ActionFn(4);
+    //
+    fn __state5<
+        __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+    >(
+        __tokens: &mut __TOKENS,
+        __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+        __sym0: ((), i32, ()),
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+    {
+        let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+        let __lookahead = match __tokens.next() {
+            Some(Ok(v)) => Some(v),
+            None => None,
+            Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+        };
+        match __lookahead {
+            Some((_, Tok::RParen, _)) |
+            Some((_, Tok::Minus, _)) |
+            None => {
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action4::<>(__sym0);
+                let __nt = __Nonterminal::T((
+                    __start,
+                    __nt,
+                    __end,
+                ));
+                __result = (__lookahead, __nt);
+                return Ok(__result);
+            }
+            _ => {
+                let __expected = vec![
+                    r###"")""###.to_string(),
+                    r###""-""###.to_string(),
+                ];
+                if let Some(__token) = __lookahead {
+                    *__extra = Some((__token, __expected));
+                }
+                let __lookahead: Option<((), Tok, ())> = None;
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action4::<>(__sym0);
+                let __nt = __Nonterminal::T((
+                    __start,
+                    __nt,
+                    __end,
+                ));
+                __result = (__lookahead, __nt);
+                return Ok(__result);
+            }
+        }
+    }
+
+    // State 6
+    //     AllInputs = [E, "-"]
+    //     OptionalInputs = []
+    //     FixedInputs = [E, "-"]
+    //     WillPushLen = 1
+    //     WillPush = [T]
+    //     WillProduce = Some(E)
+    //
+    //     E = E "-" (*) T ["(", ")", "-", Fraction, Num, EOF]
+    //     T = (*) "(" E ")" ["(", ")", "-", Fraction, Num, EOF]
+    //     T = (*) Num ["(", ")", "-", Fraction, Num, EOF]
+    //
+    //   "(" -> S4
+    //   Num -> S5
+    //
+    //     T -> S8
+    fn __state6<
+        __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+    >(
+        __tokens: &mut __TOKENS,
+        __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+        __sym0: ((), i32, ()),
+        __sym1: ((), Tok, ()),
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+    {
+        let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+        let __lookahead = match __tokens.next() {
+            Some(Ok(v)) => Some(v),
+            None => None,
+            Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+        };
+        match __lookahead {
+            Some((__loc1, __tok @ Tok::LParen, __loc2)) => {
+                let __sym2 = (__loc1, (__tok), __loc2);
+                __result = try!(__state4(__tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+            }
+            Some((__loc1, Tok::Num(__tok0), __loc2)) => {
+                let __sym2 = (__loc1, (__tok0), __loc2);
+                __result = try!(__state5(__tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+            }
+            _ => {
+                let __expected = vec![
+                    r###""(""###.to_string(),
+                    r###"Num"###.to_string(),
+                ];
+                return Err(match __lookahead {
+                    Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                    None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                });
+            }
+        }
+        loop {
+            let (__lookahead, __nt) = __result;
+            match __nt {
+                __Nonterminal::T(__sym2) => {
+                    __result = try!(__state8(__tokens, __lookahead, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                    return Ok(__result);
+                }
+                _ => {
+                    return Ok((__lookahead, __nt));
+                }
+            }
+        }
+    }
+
+    // State 7
+    //     AllInputs = ["(", E]
+    //     OptionalInputs = ["("]
+    //     FixedInputs = [E]
+    //     WillPushLen = 1
+    //     WillPush = [")"]
+    //     WillProduce = None
+    //
+    //     E = E (*) "-" T ["(", ")", "-", Fraction, Num, EOF]
+    //     T = "(" E (*) ")" ["(", ")", "-", Fraction, Num, EOF]
+    //
+    //   ")" -> S9
+    //   "-" -> S6
+    //
+    fn __state7<
+        __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+    >(
+        __tokens: &mut __TOKENS,
+        __lookahead: Option<((), Tok, ())>,
+        __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+        __sym0: &mut Option<((), Tok, ())>,
+        __sym1: ((), i32, ()),
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+    {
+        let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+        match __lookahead {
+            Some((__loc1, __tok @ Tok::RParen, __loc2)) => {
+                let __sym2 = (__loc1, (__tok), __loc2);
+                let __sym0 = __sym0.take().unwrap();
+                __result = try!(__state9(__tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                return Ok(__result);
+            }
+            Some((__loc1, __tok @ Tok::Minus, __loc2)) => {
+                let __sym2 = (__loc1, (__tok), __loc2);
+                __result = try!(__state6(__tokens, __extra, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                return Ok(__result);
+            }
+            _ => {
+                let __expected = vec![
+                    r###"")""###.to_string(),
+                    r###""-""###.to_string(),
+                ];
+                return Err(match __lookahead {
+                    Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                    None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                });
+            }
+        }
+    }
+
+    // State 8
+    //     AllInputs = [E, "-", T]
+    //     OptionalInputs = []
+    //     FixedInputs = [E, "-", T]
+    //     WillPushLen = 0
+    //     WillPush = []
+    //     WillProduce = Some(E)
+    //
+    //     E = E "-" T (*) ["(", ")", "-", Fraction, Num, EOF]
+    //
+    //   [")", "-", EOF] -> E = E, "-", T => ActionFn(2);
+    //
+    fn __state8<
+        __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+    >(
+        __tokens: &mut __TOKENS,
+        __lookahead: Option<((), Tok, ())>,
+        __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+        __sym0: ((), i32, ()),
+        __sym1: ((), Tok, ()),
+        __sym2: ((), i32, ()),
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+    {
+        let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+        match __lookahead {
+            Some((_, Tok::RParen, _)) |
+            Some((_, Tok::Minus, _)) |
+            None => {
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action2::<>(__sym0, __sym1, __sym2);
+                let __nt = __Nonterminal::E((
+                    __start,
+                    __nt,
+                    __end,
+                ));
+                __result = (__lookahead, __nt);
+                return Ok(__result);
+            }
+            _ => {
+                let __expected = vec![
+                    r###"")""###.to_string(),
+                    r###""-""###.to_string(),
+                ];
+                if let Some(__token) = __lookahead {
+                    *__extra = Some((__token, __expected));
+                }
+                let __lookahead: Option<((), Tok, ())> = None;
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action2::<>(__sym0, __sym1, __sym2);
+                let __nt = __Nonterminal::E((
+                    __start,
+                    __nt,
+                    __end,
+                ));
+                __result = (__lookahead, __nt);
+                return Ok(__result);
+            }
+        }
+    }
+
+    // State 9
+    //     AllInputs = ["(", E, ")"]
+    //     OptionalInputs = []
+    //     FixedInputs = ["(", E, ")"]
+    //     WillPushLen = 0
+    //     WillPush = []
+    //     WillProduce = Some(T)
+    //
+    //     T = "(" E ")" (*) ["(", ")", "-", Fraction, Num, EOF]
+    //
+    //   [")", "-", EOF] -> T = "(", E, ")" => ActionFn(5);
+    //
+    fn __state9<
+        __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+    >(
+        __tokens: &mut __TOKENS,
+        __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+        __sym0: ((), Tok, ()),
+        __sym1: ((), i32, ()),
+        __sym2: ((), Tok, ()),
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+    {
+        let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+        let __lookahead = match __tokens.next() {
+            Some(Ok(v)) => Some(v),
+            None => None,
+            Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+        };
+        match __lookahead {
+            Some((_, Tok::RParen, _)) |
+            Some((_, Tok::Minus, _)) |
+            None => {
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action5::<>(__sym0, __sym1, __sym2);
+                let __nt = __Nonterminal::T((
+                    __start,
+                    __nt,
+                    __end,
+                ));
+                __result = (__lookahead, __nt);
+                return Ok(__result);
+            }
+            _ => {
+                let __expected = vec![
+                    r###"")""###.to_string(),
+                    r###""-""###.to_string(),
+                ];
+                if let Some(__token) = __lookahead {
+                    *__extra = Some((__token, __expected));
+                }
+                let __lookahead: Option<((), Tok, ())> = None;
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action5::<>(__sym0, __sym1, __sym2);
+                let __nt = __Nonterminal::T((
+                    __start,
+                    __nt,
+                    __end,
+                ));
+                __result = (__lookahead, __nt);
+                return Ok(__result);
+            }
+        }
+    }
+}
+pub use self::__parse__S::parse_S;
+
+fn __action0<
+>(
+    (_, __0, _): ((), i32, ()),
+) -> i32
+{
+    (__0)
+}
+
+fn __action1<
+>(
+    (_, __0, _): ((), i32, ()),
+) -> i32
+{
+    (__0)
+}
+
+fn __action2<
+>(
+    (_, l, _): ((), i32, ()),
+    (_, _, _): ((), Tok, ()),
+    (_, r, _): ((), i32, ()),
+) -> i32
+{
+    //@ src/sub_ascent.lalrpop:21:24 (1 lines)
+    l - r
+}
+
+fn __action3<
+>(
+    (_, t, _): ((), i32, ()),
+) -> i32
+{
+    //@ src/sub_ascent.lalrpop:22:14 (1 lines)
+    t - super::ZERO
+}
+
+fn __action4<
+>(
+    (_, __0, _): ((), i32, ()),
+) -> i32
+{
+    (__0)
+}
+
+fn __action5<
+>(
+    (_, _, _): ((), Tok, ()),
+    (_, __0, _): ((), i32, ()),
+    (_, _, _): ((), Tok, ()),
+) -> i32
+{
+    (__0)
+}
+
+pub trait __ToTriple<> {
+    type Error;
+    fn to_triple(value: Self) -> Result<((),Tok,()),Self::Error>;
+}
+
+impl<> __ToTriple<> for Tok {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<((),Tok,()),&'static str> {
+        Ok(((), value, ()))
+    }
+}
+impl<> __ToTriple<> for Result<(Tok),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<((),Tok,()),&'static str> {
+        value.map(|v| ((), v, ()))
+    }
+}
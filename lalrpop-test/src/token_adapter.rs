@@ -0,0 +1,1702 @@
+// auto-generated: "lalrpop 0.15.0"
+use token_adapter_lib::{Tok, PpTok, CoreTok};
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+
+mod __parse__Items {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    use token_adapter_lib::{Tok, PpTok, CoreTok};
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__ToTriple;
+    #[allow(dead_code)]
+    pub fn parse_Items<
+        __TOKEN: __ToTriple<Error=&'static str>,
+        __TOKENS: IntoIterator<Item=__TOKEN>,
+    >(
+        __tokens0: __TOKENS,
+    ) -> Result<::std::vec::Vec<(bool, i32)>, __lalrpop_util::ParseError<(), Tok, &'static str>> where
+      __TOKENS: Clone,
+    {
+        let __ascent = __ascent::parse_Items(
+            __tokens0.clone(),
+        );
+        let __parse_table = __parse_table::parse_Items(
+            __tokens0.clone(),
+        );
+        assert_eq!(__ascent, __parse_table);
+        return __ascent;
+    }
+    mod __ascent {
+
+        mod __parse__Items {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use token_adapter_lib::{Tok, PpTok, CoreTok};
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__ToTriple;
+            #[allow(dead_code)]
+            pub fn parse_Items<
+                __TOKEN: __ToTriple<Error=&'static str>,
+                __TOKENS: IntoIterator<Item=__TOKEN>,
+            >(
+                __tokens0: __TOKENS,
+            ) -> Result<::std::vec::Vec<(bool, i32)>, __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let __tokens = __tokens0.into_iter();
+                let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+                let __last_location = ::std::cell::RefCell::new(Default::default());
+                let mut __tokens = __tokens.inspect(|t| if let Ok(ref t) = *t { *__last_location.borrow_mut() = t.2.clone(); });
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                let mut __extra = None;
+                let __result = __state0(&mut __tokens, __lookahead, &mut __extra, ::std::marker::PhantomData::<()>);
+                if let Some((__token, __expected)) = __extra {
+                    return Err(match __result {
+                        Ok(_) => __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected },
+                        Err(_) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                    });
+                }
+                match __result {
+                    Ok((Some(__lookahead), _)) => {
+                        Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] })
+                    }
+                    Ok((None, __Nonterminal::____Items((_, __nt, _)))) => {
+                        Ok(__nt)
+                    }
+                    Ok(_) => unreachable!(),
+                    Err(__lalrpop_util::ParseError::UnrecognizedEof { expected: __expected, .. }) => {
+                        Err(__lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.borrow().clone(), expected: __expected })
+                    }
+                    Err(__error) => Err(__error),
+                }
+            }
+
+            #[allow(dead_code)]
+            pub enum __Nonterminal<>
+             {
+                Item(((), (bool, i32), ())),
+                Item_2a(((), ::std::vec::Vec<(bool, i32)>, ())),
+                Item_2b(((), ::std::vec::Vec<(bool, i32)>, ())),
+                Items(((), ::std::vec::Vec<(bool, i32)>, ())),
+                ____Items(((), ::std::vec::Vec<(bool, i32)>, ())),
+            }
+            impl<> ::std::fmt::Debug for __Nonterminal<>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Nonterminal::Item(..) => __f.write_str("Item"),
+                        __Nonterminal::Item_2a(..) => __f.write_str("Item*"),
+                        __Nonterminal::Item_2b(..) => __f.write_str("Item+"),
+                        __Nonterminal::Items(..) => __f.write_str("Items"),
+                        __Nonterminal::____Items(..) => __f.write_str("__Items"),
+                    }
+                }
+            }
+
+            // State 0
+            //     AllInputs = []
+            //     OptionalInputs = []
+            //     FixedInputs = []
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Item = (*) "#define" Num ";" ["#define", ";", Num, EOF]
+            //     Item = (*) Num ";" ["#define", ";", Num, EOF]
+            //     Item+ = (*) Item ["#define", ";", Num, EOF]
+            //     Item+ = (*) Item+ Item ["#define", ";", Num, EOF]
+            //     Items = (*) ["#define", ";", Num, EOF]
+            //     Items = (*) Item+ ["#define", ";", Num, EOF]
+            //     __Items = (*) Items ["#define", ";", Num, EOF]
+            //
+            //   "#define" -> S4
+            //   Num -> S5
+            //   [EOF] -> Items =  => ActionFn(8);
+            //
+            //     Item -> S1
+            //     Item+ -> S2
+            //     Items -> S3
+            fn __state0<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<((), Tok, ())>,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::Pp(PpTok::Define), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state4(__tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Tok::Core(CoreTok::Num(__tok0)), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(__tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    None => {
+                        let __start: () = ::std::default::Default::default();
+                        let __end = __lookahead.as_ref().map(|o| o.0.clone()).unwrap_or_else(|| __start.clone());
+                        let __nt = super::super::super::__action8::<>(&__start, &__end);
+                        let __nt = __Nonterminal::Items((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""#define""###.to_string(),
+                            r###"Num"###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<((), Tok, ())> = None;
+                        let __start: () = ::std::default::Default::default();
+                        let __end = __lookahead.as_ref().map(|o| o.0.clone()).unwrap_or_else(|| __start.clone());
+                        let __nt = super::super::super::__action8::<>(&__start, &__end);
+                        let __nt = __Nonterminal::Items((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Item(__sym0) => {
+                            __result = try!(__state1(__tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Item_2b(__sym0) => {
+                            __result = try!(__state2(__tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Items(__sym0) => {
+                            __result = try!(__state3(__tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 1
+            //     AllInputs = [Item]
+            //     OptionalInputs = []
+            //     FixedInputs = [Item]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Item+)
+            //
+            //     Item+ = Item (*) ["#define", ";", Num, EOF]
+            //
+            //   ["#define", Num, EOF] -> Item+ = Item => ActionFn(6);
+            //
+            fn __state1<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<((), Tok, ())>,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), (bool, i32), ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                match __lookahead {
+                    Some((_, Tok::Pp(PpTok::Define), _)) |
+                    Some((_, Tok::Core(CoreTok::Num(_)), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action6::<>(__sym0);
+                        let __nt = __Nonterminal::Item_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""#define""###.to_string(),
+                            r###"Num"###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<((), Tok, ())> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action6::<>(__sym0);
+                        let __nt = __Nonterminal::Item_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 2
+            //     AllInputs = [Item+]
+            //     OptionalInputs = []
+            //     FixedInputs = [Item+]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Item = (*) "#define" Num ";" ["#define", ";", Num, EOF]
+            //     Item = (*) Num ";" ["#define", ";", Num, EOF]
+            //     Item+ = Item+ (*) Item ["#define", ";", Num, EOF]
+            //     Items = Item+ (*) ["#define", ";", Num, EOF]
+            //
+            //   "#define" -> S4
+            //   Num -> S5
+            //   [EOF] -> Items = Item+ => ActionFn(9);
+            //
+            //     Item -> S6
+            fn __state2<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<((), Tok, ())>,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), ::std::vec::Vec<(bool, i32)>, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::Pp(PpTok::Define), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state4(__tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Tok::Core(CoreTok::Num(__tok0)), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state5(__tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action9::<>(__sym0);
+                        let __nt = __Nonterminal::Items((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""#define""###.to_string(),
+                            r###"Num"###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<((), Tok, ())> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action9::<>(__sym0);
+                        let __nt = __Nonterminal::Items((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Item(__sym1) => {
+                            __result = try!(__state6(__tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 3
+            //     AllInputs = [Items]
+            //     OptionalInputs = []
+            //     FixedInputs = [Items]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(__Items)
+            //
+            //     __Items = Items (*) ["#define", ";", Num, EOF]
+            //
+            //   [EOF] -> __Items = Items => ActionFn(0);
+            //
+            fn __state3<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<((), Tok, ())>,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), ::std::vec::Vec<(bool, i32)>, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                match __lookahead {
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(__sym0);
+                        let __nt = __Nonterminal::____Items((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<((), Tok, ())> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(__sym0);
+                        let __nt = __Nonterminal::____Items((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 4
+            //     AllInputs = ["#define"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["#define"]
+            //     WillPushLen = 2
+            //     WillPush = [Num, ";"]
+            //     WillProduce = Some(Item)
+            //
+            //     Item = "#define" (*) Num ";" ["#define", ";", Num, EOF]
+            //
+            //   Num -> S7
+            //
+            fn __state4<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), Tok, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                match __lookahead {
+                    Some((__loc1, Tok::Core(CoreTok::Num(__tok0)), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(__tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"Num"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 5
+            //     AllInputs = [Num]
+            //     OptionalInputs = []
+            //     FixedInputs = [Num]
+            //     WillPushLen = 1
+            //     WillPush = [";"]
+            //     WillProduce = Some(Item)
+            //
+            //     Item = Num (*) ";" ["#define", ";", Num, EOF]
+            //
+            //   ";" -> S8
+            //
+            fn __state5<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), i32, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::Core(CoreTok::Semi), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state8(__tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"";""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 6
+            //     AllInputs = [Item+, Item]
+            //     OptionalInputs = []
+            //     FixedInputs = [Item+, Item]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Item+)
+            //
+            //     Item+ = Item+ Item (*) ["#define", ";", Num, EOF]
+            //
+            //   ["#define", Num, EOF] -> Item+ = Item+, Item => ActionFn(7);
+            //
+            fn __state6<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<((), Tok, ())>,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), ::std::vec::Vec<(bool, i32)>, ()),
+                __sym1: ((), (bool, i32), ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                match __lookahead {
+                    Some((_, Tok::Pp(PpTok::Define), _)) |
+                    Some((_, Tok::Core(CoreTok::Num(_)), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action7::<>(__sym0, __sym1);
+                        let __nt = __Nonterminal::Item_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""#define""###.to_string(),
+                            r###"Num"###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<((), Tok, ())> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action7::<>(__sym0, __sym1);
+                        let __nt = __Nonterminal::Item_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 7
+            //     AllInputs = ["#define", Num]
+            //     OptionalInputs = []
+            //     FixedInputs = ["#define", Num]
+            //     WillPushLen = 1
+            //     WillPush = [";"]
+            //     WillProduce = Some(Item)
+            //
+            //     Item = "#define" Num (*) ";" ["#define", ";", Num, EOF]
+            //
+            //   ";" -> S9
+            //
+            fn __state7<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), Tok, ()),
+                __sym1: ((), i32, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                match __lookahead {
+                    Some((__loc1, __tok @ Tok::Core(CoreTok::Semi), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok), __loc2);
+                        __result = try!(__state9(__tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"";""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 8
+            //     AllInputs = [Num, ";"]
+            //     OptionalInputs = []
+            //     FixedInputs = [Num, ";"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Item)
+            //
+            //     Item = Num ";" (*) ["#define", ";", Num, EOF]
+            //
+            //   ["#define", Num, EOF] -> Item = Num, ";" => ActionFn(3);
+            //
+            fn __state8<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), i32, ()),
+                __sym1: ((), Tok, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                match __lookahead {
+                    Some((_, Tok::Pp(PpTok::Define), _)) |
+                    Some((_, Tok::Core(CoreTok::Num(_)), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action3::<>(__sym0, __sym1);
+                        let __nt = __Nonterminal::Item((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""#define""###.to_string(),
+                            r###"Num"###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<((), Tok, ())> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action3::<>(__sym0, __sym1);
+                        let __nt = __Nonterminal::Item((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 9
+            //     AllInputs = ["#define", Num, ";"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["#define", Num, ";"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Item)
+            //
+            //     Item = "#define" Num ";" (*) ["#define", ";", Num, EOF]
+            //
+            //   ["#define", Num, EOF] -> Item = "#define", Num, ";" => ActionFn(2);
+            //
+            fn __state9<
+                __TOKENS: Iterator<Item=Result<((), Tok, ()),&'static str>>,
+            >(
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<(((), Tok, ()), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: ((), Tok, ()),
+                __sym1: ((), i32, ()),
+                __sym2: ((), Tok, ()),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<((), Tok, ())>, __Nonterminal<>), __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let mut __result: (Option<((), Tok, ())>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                match __lookahead {
+                    Some((_, Tok::Pp(PpTok::Define), _)) |
+                    Some((_, Tok::Core(CoreTok::Num(_)), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action2::<>(__sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Item((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""#define""###.to_string(),
+                            r###"Num"###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<((), Tok, ())> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action2::<>(__sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Item((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+        }
+        pub use self::__parse__Items::parse_Items;
+    }
+    mod __parse_table {
+
+        mod __parse_table {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use token_adapter_lib::{Tok, PpTok, CoreTok};
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__ToTriple;
+            #[allow(dead_code)]
+            pub enum __Symbol<>
+             {
+                Term_22_23define_22(Tok),
+                Term_22_3b_22(Tok),
+                TermNum(i32),
+                NtItem((bool, i32)),
+                NtItem_2a(::std::vec::Vec<(bool, i32)>),
+                NtItem_2b(::std::vec::Vec<(bool, i32)>),
+                NtItems(::std::vec::Vec<(bool, i32)>),
+                Nt____Items(::std::vec::Vec<(bool, i32)>),
+            }
+            impl<> ::std::fmt::Debug for __Symbol<>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Symbol::Term_22_23define_22(..) => __f.write_str("\"#define\""),
+                        __Symbol::Term_22_3b_22(..) => __f.write_str("\";\""),
+                        __Symbol::TermNum(..) => __f.write_str("Num"),
+                        __Symbol::NtItem(..) => __f.write_str("Item"),
+                        __Symbol::NtItem_2a(..) => __f.write_str("Item*"),
+                        __Symbol::NtItem_2b(..) => __f.write_str("Item+"),
+                        __Symbol::NtItems(..) => __f.write_str("Items"),
+                        __Symbol::Nt____Items(..) => __f.write_str("__Items"),
+                    }
+                }
+            }
+            const __ACTION: &'static [i8] = &[
+                // State 0
+                //     Item = (*) "#define" Num ";" ["#define", ";", Num, EOF]
+                //     Item = (*) Num ";" ["#define", ";", Num, EOF]
+                //     Item+ = (*) Item ["#define", ";", Num, EOF]
+                //     Item+ = (*) Item+ Item ["#define", ";", Num, EOF]
+                //     Items = (*) ["#define", ";", Num, EOF]
+                //     Items = (*) Item+ ["#define", ";", Num, EOF]
+                //     __Items = (*) Items ["#define", ";", Num, EOF]
+                5,  // on "#define", goto 4
+                0,  // on ";", error
+                6,  // on Num, goto 5
+
+                // State 1
+                //     Item+ = Item (*) ["#define", ";", Num, EOF]
+                -5,  // on "#define", reduce `Item+ = Item => ActionFn(6);`
+                0,  // on ";", error
+                -5,  // on Num, reduce `Item+ = Item => ActionFn(6);`
+
+                // State 2
+                //     Item = (*) "#define" Num ";" ["#define", ";", Num, EOF]
+                //     Item = (*) Num ";" ["#define", ";", Num, EOF]
+                //     Item+ = Item+ (*) Item ["#define", ";", Num, EOF]
+                //     Items = Item+ (*) ["#define", ";", Num, EOF]
+                5,  // on "#define", goto 4
+                0,  // on ";", error
+                6,  // on Num, goto 5
+
+                // State 3
+                //     __Items = Items (*) ["#define", ";", Num, EOF]
+                0,  // on "#define", error
+                0,  // on ";", error
+                0,  // on Num, error
+
+                // State 4
+                //     Item = "#define" (*) Num ";" ["#define", ";", Num, EOF]
+                0,  // on "#define", error
+                0,  // on ";", error
+                8,  // on Num, goto 7
+
+                // State 5
+                //     Item = Num (*) ";" ["#define", ";", Num, EOF]
+                0,  // on "#define", error
+                9,  // on ";", goto 8
+                0,  // on Num, error
+
+                // State 6
+                //     Item+ = Item+ Item (*) ["#define", ";", Num, EOF]
+                -6,  // on "#define", reduce `Item+ = Item+, Item => ActionFn(7);`
+                0,  // on ";", error
+                -6,  // on Num, reduce `Item+ = Item+, Item => ActionFn(7);`
+
+                // State 7
+                //     Item = "#define" Num (*) ";" ["#define", ";", Num, EOF]
+                0,  // on "#define", error
+                10,  // on ";", goto 9
+                0,  // on Num, error
+
+                // State 8
+                //     Item = Num ";" (*) ["#define", ";", Num, EOF]
+                -2,  // on "#define", reduce `Item = Num, ";" => ActionFn(3);`
+                0,  // on ";", error
+                -2,  // on Num, reduce `Item = Num, ";" => ActionFn(3);`
+
+                // State 9
+                //     Item = "#define" Num ";" (*) ["#define", ";", Num, EOF]
+                -1,  // on "#define", reduce `Item = "#define", Num, ";" => ActionFn(2);`
+                0,  // on ";", error
+                -1,  // on Num, reduce `Item = "#define", Num, ";" => ActionFn(2);`
+
+            ];
+            const __EOF_ACTION: &'static [i8] = &[
+                // State 0
+                -7,  // on EOF, reduce `Items =  => ActionFn(8);`
+
+                // State 1
+                -5,  // on EOF, reduce `Item+ = Item => ActionFn(6);`
+
+                // State 2
+                -8,  // on EOF, reduce `Items = Item+ => ActionFn(9);`
+
+                // State 3
+                -9,  // on EOF, reduce `__Items = Items => ActionFn(0);`
+
+                // State 4
+                0,  // on EOF, error
+
+                // State 5
+                0,  // on EOF, error
+
+                // State 6
+                -6,  // on EOF, reduce `Item+ = Item+, Item => ActionFn(7);`
+
+                // State 7
+                0,  // on EOF, error
+
+                // State 8
+                -2,  // on EOF, reduce `Item = Num, ";" => ActionFn(3);`
+
+                // State 9
+                -1,  // on EOF, reduce `Item = "#define", Num, ";" => ActionFn(2);`
+
+            ];
+            const __GOTO: &'static [u8] = &[
+                // State 0
+                2,  // on Item, goto 1
+                0,  // on Item*, error
+                3,  // on Item+, goto 2
+                4,  // on Items, goto 3
+                0,  // on __Items, error
+
+                // State 1
+                0,  // on Item, error
+                0,  // on Item*, error
+                0,  // on Item+, error
+                0,  // on Items, error
+                0,  // on __Items, error
+
+                // State 2
+                7,  // on Item, goto 6
+                0,  // on Item*, error
+                0,  // on Item+, error
+                0,  // on Items, error
+                0,  // on __Items, error
+
+                // State 3
+                0,  // on Item, error
+                0,  // on Item*, error
+                0,  // on Item+, error
+                0,  // on Items, error
+                0,  // on __Items, error
+
+                // State 4
+                0,  // on Item, error
+                0,  // on Item*, error
+                0,  // on Item+, error
+                0,  // on Items, error
+                0,  // on __Items, error
+
+                // State 5
+                0,  // on Item, error
+                0,  // on Item*, error
+                0,  // on Item+, error
+                0,  // on Items, error
+                0,  // on __Items, error
+
+                // State 6
+                0,  // on Item, error
+                0,  // on Item*, error
+                0,  // on Item+, error
+                0,  // on Items, error
+                0,  // on __Items, error
+
+                // State 7
+                0,  // on Item, error
+                0,  // on Item*, error
+                0,  // on Item+, error
+                0,  // on Items, error
+                0,  // on __Items, error
+
+                // State 8
+                0,  // on Item, error
+                0,  // on Item*, error
+                0,  // on Item+, error
+                0,  // on Items, error
+                0,  // on __Items, error
+
+                // State 9
+                0,  // on Item, error
+                0,  // on Item*, error
+                0,  // on Item+, error
+                0,  // on Items, error
+                0,  // on __Items, error
+
+            ];
+            const __DEFAULT_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // no default reduction
+
+                // State 1
+                -5,  // on any token, reduce `Item+ = Item => ActionFn(6);`
+
+                // State 2
+                0,  // no default reduction
+
+                // State 3
+                0,  // no default reduction
+
+                // State 4
+                0,  // no default reduction
+
+                // State 5
+                0,  // no default reduction
+
+                // State 6
+                -6,  // on any token, reduce `Item+ = Item+, Item => ActionFn(7);`
+
+                // State 7
+                0,  // no default reduction
+
+                // State 8
+                -2,  // on any token, reduce `Item = Num, ";" => ActionFn(3);`
+
+                // State 9
+                -1,  // on any token, reduce `Item = "#define", Num, ";" => ActionFn(2);`
+
+            ];
+            fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+                const __TERMINAL: &'static [&'static str] = &[
+                    r###""#define""###,
+                    r###"";""###,
+                    r###"Num"###,
+                ];
+                __ACTION[(__state * 3)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+                    if state == 0 {
+                        None
+                    } else {
+                        Some(terminal.to_string())
+                    }
+                }).collect()
+            }
+            #[allow(dead_code)]
+            pub fn parse_Items<
+                __TOKEN: __ToTriple<Error=&'static str>,
+                __TOKENS: IntoIterator<Item=__TOKEN>,
+            >(
+                __tokens0: __TOKENS,
+            ) -> Result<::std::vec::Vec<(bool, i32)>, __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let __tokens = __tokens0.into_iter();
+                let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Items(__v) => Ok(__v), _ => unreachable!() })
+            }
+            #[allow(dead_code)]
+            pub fn parse_Items_cancelable<
+                __TOKEN: __ToTriple<Error=&'static str>,
+                __TOKENS: IntoIterator<Item=__TOKEN>,
+            >(
+                __tokens0: __TOKENS,
+                __cancel: &::std::sync::atomic::AtomicBool,
+            ) -> Result<::std::vec::Vec<(bool, i32)>, __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                let __tokens = __tokens0.into_iter();
+                let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Items(__v) => Ok(__v), _ => unreachable!() })
+            }
+
+            #[allow(dead_code)]
+            pub struct ItemsParser {
+                __states: ::std::vec::Vec<i32>,
+                __symbols: __lalrpop_util::stack::Recycled,
+                __cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+            }
+
+            #[allow(dead_code)]
+            impl ItemsParser {
+                pub fn new() -> ItemsParser {
+                    ItemsParser { __states: vec![], __symbols: __lalrpop_util::stack::Recycled::new(), __cancel: None }
+                }
+
+                pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+                    self.__cancel = Some(flag);
+                }
+
+                pub fn parse_in_place<__TOKEN: __ToTriple<Error=&'static str>, __TOKENS: IntoIterator<Item=__TOKEN>>(
+                    &mut self,
+                    __tokens0: __TOKENS,
+                ) -> Result<::std::vec::Vec<(bool, i32)>, __lalrpop_util::ParseError<(), Tok, &'static str>>
+                {
+                    let __tokens = __tokens0.into_iter();
+                    let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+                    let mut __symbols = self.__symbols.take();
+                    let __result = __parse::<>(0, &mut __tokens, &mut self.__states, &mut __symbols, self.__cancel.as_ref().map(|c| &**c)).and_then(|__v| match __v { __Symbol::Nt____Items(__v) => Ok(__v), _ => unreachable!() });
+                    self.__symbols.put(__symbols);
+                    __result
+                }
+            }
+
+            fn __parse<
+            >(
+                __start_state: i32,
+                mut __tokens: &mut Iterator<Item = Result<((), Tok, ()), &'static str>>,
+                mut __states: &mut ::std::vec::Vec<i32>,
+                mut __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+                __cancel: Option<&::std::sync::atomic::AtomicBool>,
+            ) -> Result<__Symbol<>, __lalrpop_util::ParseError<(), Tok, &'static str>>
+            {
+                __states.clear();
+                __states.push(__start_state);
+                __symbols.clear();
+                let mut __integer;
+                let mut __lookahead;
+                let __last_location: &mut () = &mut Default::default();
+                let mut __default_reduced = [0_usize; 16];
+                let mut __default_reduced_len = 0;
+                '__shift: loop {
+                    loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __DEFAULT_ACTION[__state] as i32;
+                        if __action == 0 {
+                            break;
+                        }
+                        if __default_reduced_len < 16 {
+                            __default_reduced[__default_reduced_len] = __state;
+                            __default_reduced_len += 1;
+                        }
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(__action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    }
+                    __lookahead = match __tokens.next() {
+                        Some(Ok(v)) => v,
+                        None => break '__shift,
+                        Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                    };
+                    *__last_location = __lookahead.2.clone();
+                    __integer = match __lookahead.1 {
+                        Tok::Pp(PpTok::Define) if true => 0,
+                        Tok::Core(CoreTok::Semi) if true => 1,
+                        Tok::Core(CoreTok::Num(_)) if true => 2,
+                        _ => {
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| true).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match Some(__lookahead) {
+                                Some(__token) => {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error);
+                        }
+                    };
+                    '__inner: loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __ACTION[__state * 3 + __integer] as i32;
+                        if __action > 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                                }
+                            }
+                            let __symbol = match __integer {
+                                0 => match __lookahead.1 {
+                                    __tok @ Tok::Pp(PpTok::Define) => __Symbol::Term_22_23define_22((__tok)),
+                                    _ => unreachable!(),
+                                },
+                                1 => match __lookahead.1 {
+                                    __tok @ Tok::Core(CoreTok::Semi) => __Symbol::Term_22_3b_22((__tok)),
+                                    _ => unreachable!(),
+                                },
+                                2 => match __lookahead.1 {
+                                    Tok::Core(CoreTok::Num(__tok0)) => __Symbol::TermNum((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                _ => unreachable!(),
+                            };
+                            __states.push(__action - 1);
+                            __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                            __default_reduced_len = 0;
+                            continue '__shift;
+                        } else if __action < 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                                }
+                            }
+                            if let Some(r) = __reduce(__action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                                if r.is_err() {
+                                    return r;
+                                }
+                                return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] });
+                            }
+                        } else {
+                            let mut __err_lookahead = Some(__lookahead);
+                            let mut __err_integer: Option<usize> = Some(__integer);
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 3 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match __err_lookahead {
+                                Some(__token) => {
+                                    if __accepts( *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                        __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                    } else {
+                                        __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                    }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error)
+                        }
+                    }
+                }
+                loop {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __action = __EOF_ACTION[__state] as i32;
+                    if __action < 0 {
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(__action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    } else {
+                        let mut __err_lookahead = None;
+                        let mut __err_integer: Option<usize> = None;
+                        let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 3 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                        let __expected = __expected_tokens(__state);
+                        let __error = match __err_lookahead {
+                            Some(__token) => {
+                                if __accepts( *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                    __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                } else {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                            }
+                            None => {
+                                __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                            }
+                        };
+                        return Err(__error)
+                    }
+                }
+            }
+            fn __accepts<
+            >(
+                __error_state: i32,
+                __states: & [i32],
+                __opt_integer: Option<usize>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> bool
+            {
+                let mut __states = __states.to_vec();
+                __states.push(__error_state);
+                loop {
+                    let mut __states_len = __states.len();
+                    let __top = __states[__states_len - 1];
+                    let __action = match __opt_integer {
+                        None => __EOF_ACTION[__top as usize] as i32,
+                        Some(__integer) => __ACTION[(__top * 3) as usize + __integer] as i32,
+                    };
+                    if __action == 0 { return false; }
+                    if __action > 0 { return true; }
+                    let (__to_pop, __nt) = match -__action {
+                        // simulate Item = "#define", Num, ";" => ActionFn(2);
+                        1 => {
+                            (3, 0)
+                        }
+                        // simulate Item = Num, ";" => ActionFn(3);
+                        2 => {
+                            (2, 0)
+                        }
+                        // simulate Item* =  => ActionFn(4);
+                        3 => {
+                            (0, 1)
+                        }
+                        // simulate Item* = Item+ => ActionFn(5);
+                        4 => {
+                            (1, 1)
+                        }
+                        // simulate Item+ = Item => ActionFn(6);
+                        5 => {
+                            (1, 2)
+                        }
+                        // simulate Item+ = Item+, Item => ActionFn(7);
+                        6 => {
+                            (2, 2)
+                        }
+                        // simulate Items =  => ActionFn(8);
+                        7 => {
+                            (0, 3)
+                        }
+                        // simulate Items = Item+ => ActionFn(9);
+                        8 => {
+                            (1, 3)
+                        }
+                        // simulate __Items = Items => ActionFn(0);
+                        9 => return true,
+                        _ => panic!("invalid action code {}", __action)
+                    };
+                    __states_len -= __to_pop;
+                    __states.truncate(__states_len);
+                    let __top = __states[__states_len - 1];
+                    let __next_state = __GOTO[(__top * 5 + __nt) as usize] as i32 - 1;
+                    __states.push(__next_state);
+                }
+            }
+            pub fn __reduce<
+            >(
+                __action: i32,
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Option<Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+            {
+                let __nonterminal = match -__action {
+                    1 => match __reduce_Item_0(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    2 => match __reduce_Item_1(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    3 => match __reduce_Item_2a_0(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    4 => match __reduce_Item_2a_1(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    5 => match __reduce_Item_2b_0(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    6 => match __reduce_Item_2b_1(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    7 => match __reduce_Items_0(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    8 => match __reduce_Items_1(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    9 => match __reduce_____Items_0(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    _ => panic!("invalid action code {}", __action)
+                };
+                let __state = *__states.last().unwrap() as usize;
+                let __next_state = __GOTO[__state * 5 + __nonterminal] as i32 - 1;
+                __states.push(__next_state);
+                None
+            }
+
+            fn __reduce_Item_0<
+            >(
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+            {
+                // Item = "#define", Num, ";" => ActionFn(2);
+                let __sym2 = __pop_Term_22_3b_22(__symbols);
+                let __sym1 = __pop_TermNum(__symbols);
+                let __sym0 = __pop_Term_22_23define_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action2::<>(__sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtItem(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce_Item_1<
+            >(
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+            {
+                // Item = Num, ";" => ActionFn(3);
+                let __sym1 = __pop_Term_22_3b_22(__symbols);
+                let __sym0 = __pop_TermNum(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action3::<>(__sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtItem(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce_Item_2a_0<
+            >(
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+            {
+                // Item* =  => ActionFn(4);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action4::<>(&__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::NtItem_2a(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce_Item_2a_1<
+            >(
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+            {
+                // Item* = Item+ => ActionFn(5);
+                let __sym0 = __pop_NtItem_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action5::<>(__sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtItem_2a(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce_Item_2b_0<
+            >(
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+            {
+                // Item+ = Item => ActionFn(6);
+                let __sym0 = __pop_NtItem(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action6::<>(__sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtItem_2b(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce_Item_2b_1<
+            >(
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+            {
+                // Item+ = Item+, Item => ActionFn(7);
+                let __sym1 = __pop_NtItem(__symbols);
+                let __sym0 = __pop_NtItem_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action7::<>(__sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtItem_2b(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce_Items_0<
+            >(
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+            {
+                // Items =  => ActionFn(8);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action8::<>(&__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::NtItems(__nt), __end));
+                Ok(3)
+            }
+
+            fn __reduce_Items_1<
+            >(
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+            {
+                // Items = Item+ => ActionFn(9);
+                let __sym0 = __pop_NtItem_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action9::<>(__sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtItems(__nt), __end));
+                Ok(3)
+            }
+
+            fn __reduce_____Items_0<
+            >(
+                __lookahead_start: Option<&()>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<(), Tok, &'static str>>>
+            {
+                // __Items = Items => ActionFn(0);
+                let __sym0 = __pop_NtItems(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action0::<>(__sym0);
+                Err(Ok(__Symbol::Nt____Items(__nt)))
+            }
+            fn __pop_Term_22_23define_22<
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+            ) -> ((), Tok, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_23define_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_3b_22<
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+            ) -> ((), Tok, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_3b_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_TermNum<
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+            ) -> ((), i32, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::TermNum(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtItem<
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+            ) -> ((), (bool, i32), ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtItem(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtItem_2a<
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+            ) -> ((), ::std::vec::Vec<(bool, i32)>, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtItem_2a(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtItem_2b<
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+            ) -> ((), ::std::vec::Vec<(bool, i32)>, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtItem_2b(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtItems<
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+            ) -> ((), ::std::vec::Vec<(bool, i32)>, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtItems(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt____Items<
+            >(
+                __symbols: &mut ::std::vec::Vec<((),__Symbol<>,())>
+            ) -> ((), ::std::vec::Vec<(bool, i32)>, ())
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt____Items(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+        }
+        pub use self::__parse_table::parse_Items;
+        pub use self::__parse_table::parse_Items_cancelable;
+        pub use self::__parse_table::ItemsParser;
+    }
+    pub use self::__parse_table::parse_Items_cancelable;
+    pub use self::__parse_table::ItemsParser;
+}
+pub use self::__parse__Items::parse_Items;
+pub use self::__parse__Items::parse_Items_cancelable;
+pub use self::__parse__Items::ItemsParser;
+
+fn __action0<
+>(
+    (_, __0, _): ((), ::std::vec::Vec<(bool, i32)>, ()),
+) -> ::std::vec::Vec<(bool, i32)>
+{
+    (__0)
+}
+
+fn __action1<
+>(
+    (_, __0, _): ((), ::std::vec::Vec<(bool, i32)>, ()),
+) -> ::std::vec::Vec<(bool, i32)>
+{
+    (__0)
+}
+
+fn __action2<
+>(
+    (_, _, _): ((), Tok, ()),
+    (_, __0, _): ((), i32, ()),
+    (_, _, _): ((), Tok, ()),
+) -> (bool, i32)
+{
+    //@ src/token_adapter.lalrpop:22:28 (1 lines)
+    (true, __0)
+}
+
+fn __action3<
+>(
+    (_, __0, _): ((), i32, ()),
+    (_, _, _): ((), Tok, ()),
+) -> (bool, i32)
+{
+    //@ src/token_adapter.lalrpop:23:18 (1 lines)
+    (false, __0)
+}
+
+fn __action4<
+>(
+    __lookbehind: &(),
+    __lookahead: &(),
+) -> ::std::vec::Vec<(bool, i32)>
+{
+    vec![]
+}
+
+fn __action5<
+>(
+    (_, v, _): ((), ::std::vec::Vec<(bool, i32)>, ()),
+) -> ::std::vec::Vec<(bool, i32)>
+{
+    v
+}
+
+fn __action6<
+>(
+    (_, __0, _): ((), (bool, i32), ()),
+) -> ::std::vec::Vec<(bool, i32)>
+{
+    vec![__0]
+}
+
+fn __action7<
+>(
+    (_, v, _): ((), ::std::vec::Vec<(bool, i32)>, ()),
+    (_, e, _): ((), (bool, i32), ()),
+) -> ::std::vec::Vec<(bool, i32)>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+fn __action8<
+>(
+    __lookbehind: &(),
+    __lookahead: &(),
+) -> ::std::vec::Vec<(bool, i32)>
+{
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action4(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action1(
+        __temp0,
+    )
+}
+
+fn __action9<
+>(
+    __0: ((), ::std::vec::Vec<(bool, i32)>, ()),
+) -> ::std::vec::Vec<(bool, i32)>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action5(
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action1(
+        __temp0,
+    )
+}
+
+pub trait __ToTriple<> {
+    type Error;
+    fn to_triple(value: Self) -> Result<((),Tok,()),Self::Error>;
+}
+
+impl<> __ToTriple<> for Tok {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<((),Tok,()),&'static str> {
+        Ok(((), value, ()))
+    }
+}
+impl<> __ToTriple<> for Result<(Tok),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<((),Tok,()),&'static str> {
+        value.map(|v| ((), v, ()))
+    }
+}
@@ -0,0 +1,19 @@
+//! Tokens from two sources, merged into the one enum that the
+//! `token_adapter` parser takes.
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PpTok {
+    Define,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CoreTok {
+    Num(i32),
+    Semi,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Tok {
+    Pp(PpTok),
+    Core(CoreTok),
+}
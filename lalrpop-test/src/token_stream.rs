@@ -0,0 +1,2538 @@
+// auto-generated: "lalrpop 0.15.0"
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+pub use self::__parse_table::parse_Bindings;
+pub use self::__parse_table::parse_Bindings_cancelable;
+pub use self::__parse_table::BindingsParser;
+
+mod __parse_table {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__ToTriple;
+    #[allow(dead_code)]
+    pub enum __Symbol<>
+     {
+        Term_22_2d_22(__lalrpop_util::token_stream::Token),
+        Term_22_3a_22(__lalrpop_util::token_stream::Token),
+        Term_22_3b_22(__lalrpop_util::token_stream::Token),
+        Term_22_3c_22(__lalrpop_util::token_stream::Token),
+        Term_22_3d_3e_22(__lalrpop_util::token_stream::Token),
+        Term_22_3e_22(__lalrpop_util::token_stream::Token),
+        Term_22_5b_22(__lalrpop_util::token_stream::Token),
+        Term_22_5d_22(__lalrpop_util::token_stream::Token),
+        Term_22let_22(__lalrpop_util::token_stream::Token),
+        TermIdent(__lalrpop_util::token_stream::Ident),
+        TermLiteral(__lalrpop_util::token_stream::Literal),
+        Nt_22let_22_3f(::std::option::Option<__lalrpop_util::token_stream::Token>),
+        NtBinding((String, String, String)),
+        NtBinding_2a(::std::vec::Vec<(String, String, String)>),
+        NtBinding_2b(::std::vec::Vec<(String, String, String)>),
+        NtBindings(Vec<(String, String, String)>),
+        NtType(String),
+        NtValue(String),
+        NtValue_2a(::std::vec::Vec<String>),
+        NtValue_2b(::std::vec::Vec<String>),
+        Nt____Bindings(Vec<(String, String, String)>),
+    }
+    impl<> ::std::fmt::Debug for __Symbol<>
+    {
+        fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            match *self {
+                __Symbol::Term_22_2d_22(..) => __f.write_str("\"-\""),
+                __Symbol::Term_22_3a_22(..) => __f.write_str("\":\""),
+                __Symbol::Term_22_3b_22(..) => __f.write_str("\";\""),
+                __Symbol::Term_22_3c_22(..) => __f.write_str("\"<\""),
+                __Symbol::Term_22_3d_3e_22(..) => __f.write_str("\"=>\""),
+                __Symbol::Term_22_3e_22(..) => __f.write_str("\">\""),
+                __Symbol::Term_22_5b_22(..) => __f.write_str("\"[\""),
+                __Symbol::Term_22_5d_22(..) => __f.write_str("\"]\""),
+                __Symbol::Term_22let_22(..) => __f.write_str("\"let\""),
+                __Symbol::TermIdent(..) => __f.write_str("Ident"),
+                __Symbol::TermLiteral(..) => __f.write_str("Literal"),
+                __Symbol::Nt_22let_22_3f(..) => __f.write_str("\"let\"?"),
+                __Symbol::NtBinding(..) => __f.write_str("Binding"),
+                __Symbol::NtBinding_2a(..) => __f.write_str("Binding*"),
+                __Symbol::NtBinding_2b(..) => __f.write_str("Binding+"),
+                __Symbol::NtBindings(..) => __f.write_str("Bindings"),
+                __Symbol::NtType(..) => __f.write_str("Type"),
+                __Symbol::NtValue(..) => __f.write_str("Value"),
+                __Symbol::NtValue_2a(..) => __f.write_str("Value*"),
+                __Symbol::NtValue_2b(..) => __f.write_str("Value+"),
+                __Symbol::Nt____Bindings(..) => __f.write_str("__Bindings"),
+            }
+        }
+    }
+    const __ACTION: &'static [i8] = &[
+        // State 0
+        //     Binding = (*) "let" Ident ":" Type "=>" Value ";" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Binding = (*) Ident ":" Type "=>" Value ";" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Binding+ = (*) Binding ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Binding+ = (*) Binding+ Binding ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Bindings = (*) ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Bindings = (*) Binding+ ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     __Bindings = (*) Bindings ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        0,  // on "-", error
+        0,  // on ":", error
+        0,  // on ";", error
+        0,  // on "<", error
+        0,  // on "=>", error
+        0,  // on ">", error
+        0,  // on "[", error
+        0,  // on "]", error
+        5,  // on "let", goto 4
+        6,  // on Ident, goto 5
+        0,  // on Literal, error
+
+        // State 1
+        //     Binding+ = Binding (*) ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        0,  // on "-", error
+        0,  // on ":", error
+        0,  // on ";", error
+        0,  // on "<", error
+        0,  // on "=>", error
+        0,  // on ">", error
+        0,  // on "[", error
+        0,  // on "]", error
+        -7,  // on "let", reduce `Binding+ = Binding => ActionFn(14);`
+        -7,  // on Ident, reduce `Binding+ = Binding => ActionFn(14);`
+        0,  // on Literal, error
+
+        // State 2
+        //     Binding = (*) "let" Ident ":" Type "=>" Value ";" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Binding = (*) Ident ":" Type "=>" Value ";" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Binding+ = Binding+ (*) Binding ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Bindings = Binding+ (*) ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        0,  // on "-", error
+        0,  // on ":", error
+        0,  // on ";", error
+        0,  // on "<", error
+        0,  // on "=>", error
+        0,  // on ">", error
+        0,  // on "[", error
+        0,  // on "]", error
+        5,  // on "let", goto 4
+        6,  // on Ident, goto 5
+        0,  // on Literal, error
+
+        // State 3
+        //     __Bindings = Bindings (*) ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        0,  // on "-", error
+        0,  // on ":", error
+        0,  // on ";", error
+        0,  // on "<", error
+        0,  // on "=>", error
+        0,  // on ">", error
+        0,  // on "[", error
+        0,  // on "]", error
+        0,  // on "let", error
+        0,  // on Ident, error
+        0,  // on Literal, error
+
+        // State 4
+        //     Binding = "let" (*) Ident ":" Type "=>" Value ";" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        0,  // on "-", error
+        0,  // on ":", error
+        0,  // on ";", error
+        0,  // on "<", error
+        0,  // on "=>", error
+        0,  // on ">", error
+        0,  // on "[", error
+        0,  // on "]", error
+        0,  // on "let", error
+        8,  // on Ident, goto 7
+        0,  // on Literal, error
+
+        // State 5
+        //     Binding = Ident (*) ":" Type "=>" Value ";" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        0,  // on "-", error
+        9,  // on ":", goto 8
+        0,  // on ";", error
+        0,  // on "<", error
+        0,  // on "=>", error
+        0,  // on ">", error
+        0,  // on "[", error
+        0,  // on "]", error
+        0,  // on "let", error
+        0,  // on Ident, error
+        0,  // on Literal, error
+
+        // State 6
+        //     Binding+ = Binding+ Binding (*) ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        0,  // on "-", error
+        0,  // on ":", error
+        0,  // on ";", error
+        0,  // on "<", error
+        0,  // on "=>", error
+        0,  // on ">", error
+        0,  // on "[", error
+        0,  // on "]", error
+        -8,  // on "let", reduce `Binding+ = Binding+, Binding => ActionFn(15);`
+        -8,  // on Ident, reduce `Binding+ = Binding+, Binding => ActionFn(15);`
+        0,  // on Literal, error
+
+        // State 7
+        //     Binding = "let" Ident (*) ":" Type "=>" Value ";" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        0,  // on "-", error
+        10,  // on ":", goto 9
+        0,  // on ";", error
+        0,  // on "<", error
+        0,  // on "=>", error
+        0,  // on ">", error
+        0,  // on "[", error
+        0,  // on "]", error
+        0,  // on "let", error
+        0,  // on Ident, error
+        0,  // on Literal, error
+
+        // State 8
+        //     Binding = Ident ":" (*) Type "=>" Value ";" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Type = (*) Ident ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Type = (*) Ident "<" Type ">" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        0,  // on "-", error
+        0,  // on ":", error
+        0,  // on ";", error
+        0,  // on "<", error
+        0,  // on "=>", error
+        0,  // on ">", error
+        0,  // on "[", error
+        0,  // on "]", error
+        0,  // on "let", error
+        12,  // on Ident, goto 11
+        0,  // on Literal, error
+
+        // State 9
+        //     Binding = "let" Ident ":" (*) Type "=>" Value ";" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Type = (*) Ident ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Type = (*) Ident "<" Type ">" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        0,  // on "-", error
+        0,  // on ":", error
+        0,  // on ";", error
+        0,  // on "<", error
+        0,  // on "=>", error
+        0,  // on ">", error
+        0,  // on "[", error
+        0,  // on "]", error
+        0,  // on "let", error
+        12,  // on Ident, goto 11
+        0,  // on Literal, error
+
+        // State 10
+        //     Binding = Ident ":" Type (*) "=>" Value ";" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        0,  // on "-", error
+        0,  // on ":", error
+        0,  // on ";", error
+        0,  // on "<", error
+        14,  // on "=>", goto 13
+        0,  // on ">", error
+        0,  // on "[", error
+        0,  // on "]", error
+        0,  // on "let", error
+        0,  // on Ident, error
+        0,  // on Literal, error
+
+        // State 11
+        //     Type = Ident (*) ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Type = Ident (*) "<" Type ">" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        0,  // on "-", error
+        0,  // on ":", error
+        0,  // on ";", error
+        15,  // on "<", goto 14
+        -11,  // on "=>", reduce `Type = Ident => ActionFn(3);`
+        -11,  // on ">", reduce `Type = Ident => ActionFn(3);`
+        0,  // on "[", error
+        0,  // on "]", error
+        0,  // on "let", error
+        0,  // on Ident, error
+        0,  // on Literal, error
+
+        // State 12
+        //     Binding = "let" Ident ":" Type (*) "=>" Value ";" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        0,  // on "-", error
+        0,  // on ":", error
+        0,  // on ";", error
+        0,  // on "<", error
+        16,  // on "=>", goto 15
+        0,  // on ">", error
+        0,  // on "[", error
+        0,  // on "]", error
+        0,  // on "let", error
+        0,  // on Ident, error
+        0,  // on Literal, error
+
+        // State 13
+        //     Binding = Ident ":" Type "=>" (*) Value ";" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Value = (*) "-" Value ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Value = (*) "[" Value+ "]" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Value = (*) "[" "]" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Value = (*) Literal ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        18,  // on "-", goto 17
+        0,  // on ":", error
+        0,  // on ";", error
+        0,  // on "<", error
+        0,  // on "=>", error
+        0,  // on ">", error
+        19,  // on "[", goto 18
+        0,  // on "]", error
+        0,  // on "let", error
+        0,  // on Ident, error
+        20,  // on Literal, goto 19
+
+        // State 14
+        //     Type = (*) Ident ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Type = (*) Ident "<" Type ">" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Type = Ident "<" (*) Type ">" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        0,  // on "-", error
+        0,  // on ":", error
+        0,  // on ";", error
+        0,  // on "<", error
+        0,  // on "=>", error
+        0,  // on ">", error
+        0,  // on "[", error
+        0,  // on "]", error
+        0,  // on "let", error
+        12,  // on Ident, goto 11
+        0,  // on Literal, error
+
+        // State 15
+        //     Binding = "let" Ident ":" Type "=>" (*) Value ";" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Value = (*) "-" Value ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Value = (*) "[" Value+ "]" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Value = (*) "[" "]" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Value = (*) Literal ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        18,  // on "-", goto 17
+        0,  // on ":", error
+        0,  // on ";", error
+        0,  // on "<", error
+        0,  // on "=>", error
+        0,  // on ">", error
+        19,  // on "[", goto 18
+        0,  // on "]", error
+        0,  // on "let", error
+        0,  // on Ident, error
+        20,  // on Literal, goto 19
+
+        // State 16
+        //     Binding = Ident ":" Type "=>" Value (*) ";" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        0,  // on "-", error
+        0,  // on ":", error
+        23,  // on ";", goto 22
+        0,  // on "<", error
+        0,  // on "=>", error
+        0,  // on ">", error
+        0,  // on "[", error
+        0,  // on "]", error
+        0,  // on "let", error
+        0,  // on Ident, error
+        0,  // on Literal, error
+
+        // State 17
+        //     Value = (*) "-" Value ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Value = "-" (*) Value ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Value = (*) "[" Value+ "]" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Value = (*) "[" "]" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Value = (*) Literal ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        18,  // on "-", goto 17
+        0,  // on ":", error
+        0,  // on ";", error
+        0,  // on "<", error
+        0,  // on "=>", error
+        0,  // on ">", error
+        19,  // on "[", goto 18
+        0,  // on "]", error
+        0,  // on "let", error
+        0,  // on Ident, error
+        20,  // on Literal, goto 19
+
+        // State 18
+        //     Value = (*) "-" Value ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Value = (*) "[" Value+ "]" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Value = "[" (*) Value+ "]" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Value = (*) "[" "]" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Value = "[" (*) "]" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Value = (*) Literal ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Value+ = (*) Value ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Value+ = (*) Value+ Value ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        18,  // on "-", goto 17
+        0,  // on ":", error
+        0,  // on ";", error
+        0,  // on "<", error
+        0,  // on "=>", error
+        0,  // on ">", error
+        19,  // on "[", goto 18
+        27,  // on "]", goto 26
+        0,  // on "let", error
+        0,  // on Ident, error
+        20,  // on Literal, goto 19
+
+        // State 19
+        //     Value = Literal (*) ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        -13,  // on "-", reduce `Value = Literal => ActionFn(5);`
+        0,  // on ":", error
+        -13,  // on ";", reduce `Value = Literal => ActionFn(5);`
+        0,  // on "<", error
+        0,  // on "=>", error
+        0,  // on ">", error
+        -13,  // on "[", reduce `Value = Literal => ActionFn(5);`
+        -13,  // on "]", reduce `Value = Literal => ActionFn(5);`
+        0,  // on "let", error
+        0,  // on Ident, error
+        -13,  // on Literal, reduce `Value = Literal => ActionFn(5);`
+
+        // State 20
+        //     Type = Ident "<" Type (*) ">" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        0,  // on "-", error
+        0,  // on ":", error
+        0,  // on ";", error
+        0,  // on "<", error
+        0,  // on "=>", error
+        28,  // on ">", goto 27
+        0,  // on "[", error
+        0,  // on "]", error
+        0,  // on "let", error
+        0,  // on Ident, error
+        0,  // on Literal, error
+
+        // State 21
+        //     Binding = "let" Ident ":" Type "=>" Value (*) ";" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        0,  // on "-", error
+        0,  // on ":", error
+        29,  // on ";", goto 28
+        0,  // on "<", error
+        0,  // on "=>", error
+        0,  // on ">", error
+        0,  // on "[", error
+        0,  // on "]", error
+        0,  // on "let", error
+        0,  // on Ident, error
+        0,  // on Literal, error
+
+        // State 22
+        //     Binding = Ident ":" Type "=>" Value ";" (*) ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        0,  // on "-", error
+        0,  // on ":", error
+        0,  // on ";", error
+        0,  // on "<", error
+        0,  // on "=>", error
+        0,  // on ">", error
+        0,  // on "[", error
+        0,  // on "]", error
+        -4,  // on "let", reduce `Binding = Ident, ":", Type, "=>", Value, ";" => ActionFn(19);`
+        -4,  // on Ident, reduce `Binding = Ident, ":", Type, "=>", Value, ";" => ActionFn(19);`
+        0,  // on Literal, error
+
+        // State 23
+        //     Value = "-" Value (*) ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        -14,  // on "-", reduce `Value = "-", Value => ActionFn(6);`
+        0,  // on ":", error
+        -14,  // on ";", reduce `Value = "-", Value => ActionFn(6);`
+        0,  // on "<", error
+        0,  // on "=>", error
+        0,  // on ">", error
+        -14,  // on "[", reduce `Value = "-", Value => ActionFn(6);`
+        -14,  // on "]", reduce `Value = "-", Value => ActionFn(6);`
+        0,  // on "let", error
+        0,  // on Ident, error
+        -14,  // on Literal, reduce `Value = "-", Value => ActionFn(6);`
+
+        // State 24
+        //     Value+ = Value (*) ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        -19,  // on "-", reduce `Value+ = Value => ActionFn(16);`
+        0,  // on ":", error
+        0,  // on ";", error
+        0,  // on "<", error
+        0,  // on "=>", error
+        0,  // on ">", error
+        -19,  // on "[", reduce `Value+ = Value => ActionFn(16);`
+        -19,  // on "]", reduce `Value+ = Value => ActionFn(16);`
+        0,  // on "let", error
+        0,  // on Ident, error
+        -19,  // on Literal, reduce `Value+ = Value => ActionFn(16);`
+
+        // State 25
+        //     Value = (*) "-" Value ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Value = (*) "[" Value+ "]" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Value = "[" Value+ (*) "]" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Value = (*) "[" "]" ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Value = (*) Literal ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        //     Value+ = Value+ (*) Value ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        18,  // on "-", goto 17
+        0,  // on ":", error
+        0,  // on ";", error
+        0,  // on "<", error
+        0,  // on "=>", error
+        0,  // on ">", error
+        19,  // on "[", goto 18
+        31,  // on "]", goto 30
+        0,  // on "let", error
+        0,  // on Ident, error
+        20,  // on Literal, goto 19
+
+        // State 26
+        //     Value = "[" "]" (*) ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        -15,  // on "-", reduce `Value = "[", "]" => ActionFn(22);`
+        0,  // on ":", error
+        -15,  // on ";", reduce `Value = "[", "]" => ActionFn(22);`
+        0,  // on "<", error
+        0,  // on "=>", error
+        0,  // on ">", error
+        -15,  // on "[", reduce `Value = "[", "]" => ActionFn(22);`
+        -15,  // on "]", reduce `Value = "[", "]" => ActionFn(22);`
+        0,  // on "let", error
+        0,  // on Ident, error
+        -15,  // on Literal, reduce `Value = "[", "]" => ActionFn(22);`
+
+        // State 27
+        //     Type = Ident "<" Type ">" (*) ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        0,  // on "-", error
+        0,  // on ":", error
+        0,  // on ";", error
+        0,  // on "<", error
+        -12,  // on "=>", reduce `Type = Ident, "<", Type, ">" => ActionFn(4);`
+        -12,  // on ">", reduce `Type = Ident, "<", Type, ">" => ActionFn(4);`
+        0,  // on "[", error
+        0,  // on "]", error
+        0,  // on "let", error
+        0,  // on Ident, error
+        0,  // on Literal, error
+
+        // State 28
+        //     Binding = "let" Ident ":" Type "=>" Value ";" (*) ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        0,  // on "-", error
+        0,  // on ":", error
+        0,  // on ";", error
+        0,  // on "<", error
+        0,  // on "=>", error
+        0,  // on ">", error
+        0,  // on "[", error
+        0,  // on "]", error
+        -3,  // on "let", reduce `Binding = "let", Ident, ":", Type, "=>", Value, ";" => ActionFn(18);`
+        -3,  // on Ident, reduce `Binding = "let", Ident, ":", Type, "=>", Value, ";" => ActionFn(18);`
+        0,  // on Literal, error
+
+        // State 29
+        //     Value+ = Value+ Value (*) ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        -20,  // on "-", reduce `Value+ = Value+, Value => ActionFn(17);`
+        0,  // on ":", error
+        0,  // on ";", error
+        0,  // on "<", error
+        0,  // on "=>", error
+        0,  // on ">", error
+        -20,  // on "[", reduce `Value+ = Value+, Value => ActionFn(17);`
+        -20,  // on "]", reduce `Value+ = Value+, Value => ActionFn(17);`
+        0,  // on "let", error
+        0,  // on Ident, error
+        -20,  // on Literal, reduce `Value+ = Value+, Value => ActionFn(17);`
+
+        // State 30
+        //     Value = "[" Value+ "]" (*) ["-", ":", ";", "<", "=>", ">", "[", "]", "let", Ident, Literal, EOF]
+        -16,  // on "-", reduce `Value = "[", Value+, "]" => ActionFn(23);`
+        0,  // on ":", error
+        -16,  // on ";", reduce `Value = "[", Value+, "]" => ActionFn(23);`
+        0,  // on "<", error
+        0,  // on "=>", error
+        0,  // on ">", error
+        -16,  // on "[", reduce `Value = "[", Value+, "]" => ActionFn(23);`
+        -16,  // on "]", reduce `Value = "[", Value+, "]" => ActionFn(23);`
+        0,  // on "let", error
+        0,  // on Ident, error
+        -16,  // on Literal, reduce `Value = "[", Value+, "]" => ActionFn(23);`
+
+    ];
+    const __EOF_ACTION: &'static [i8] = &[
+        // State 0
+        -9,  // on EOF, reduce `Bindings =  => ActionFn(20);`
+
+        // State 1
+        -7,  // on EOF, reduce `Binding+ = Binding => ActionFn(14);`
+
+        // State 2
+        -10,  // on EOF, reduce `Bindings = Binding+ => ActionFn(21);`
+
+        // State 3
+        -21,  // on EOF, reduce `__Bindings = Bindings => ActionFn(0);`
+
+        // State 4
+        0,  // on EOF, error
+
+        // State 5
+        0,  // on EOF, error
+
+        // State 6
+        -8,  // on EOF, reduce `Binding+ = Binding+, Binding => ActionFn(15);`
+
+        // State 7
+        0,  // on EOF, error
+
+        // State 8
+        0,  // on EOF, error
+
+        // State 9
+        0,  // on EOF, error
+
+        // State 10
+        0,  // on EOF, error
+
+        // State 11
+        0,  // on EOF, error
+
+        // State 12
+        0,  // on EOF, error
+
+        // State 13
+        0,  // on EOF, error
+
+        // State 14
+        0,  // on EOF, error
+
+        // State 15
+        0,  // on EOF, error
+
+        // State 16
+        0,  // on EOF, error
+
+        // State 17
+        0,  // on EOF, error
+
+        // State 18
+        0,  // on EOF, error
+
+        // State 19
+        0,  // on EOF, error
+
+        // State 20
+        0,  // on EOF, error
+
+        // State 21
+        0,  // on EOF, error
+
+        // State 22
+        -4,  // on EOF, reduce `Binding = Ident, ":", Type, "=>", Value, ";" => ActionFn(19);`
+
+        // State 23
+        0,  // on EOF, error
+
+        // State 24
+        0,  // on EOF, error
+
+        // State 25
+        0,  // on EOF, error
+
+        // State 26
+        0,  // on EOF, error
+
+        // State 27
+        0,  // on EOF, error
+
+        // State 28
+        -3,  // on EOF, reduce `Binding = "let", Ident, ":", Type, "=>", Value, ";" => ActionFn(18);`
+
+        // State 29
+        0,  // on EOF, error
+
+        // State 30
+        0,  // on EOF, error
+
+    ];
+    const __GOTO: &'static [u8] = &[
+        // State 0
+        0,  // on "let"?, error
+        2,  // on Binding, goto 1
+        0,  // on Binding*, error
+        3,  // on Binding+, goto 2
+        4,  // on Bindings, goto 3
+        0,  // on Type, error
+        0,  // on Value, error
+        0,  // on Value*, error
+        0,  // on Value+, error
+        0,  // on __Bindings, error
+
+        // State 1
+        0,  // on "let"?, error
+        0,  // on Binding, error
+        0,  // on Binding*, error
+        0,  // on Binding+, error
+        0,  // on Bindings, error
+        0,  // on Type, error
+        0,  // on Value, error
+        0,  // on Value*, error
+        0,  // on Value+, error
+        0,  // on __Bindings, error
+
+        // State 2
+        0,  // on "let"?, error
+        7,  // on Binding, goto 6
+        0,  // on Binding*, error
+        0,  // on Binding+, error
+        0,  // on Bindings, error
+        0,  // on Type, error
+        0,  // on Value, error
+        0,  // on Value*, error
+        0,  // on Value+, error
+        0,  // on __Bindings, error
+
+        // State 3
+        0,  // on "let"?, error
+        0,  // on Binding, error
+        0,  // on Binding*, error
+        0,  // on Binding+, error
+        0,  // on Bindings, error
+        0,  // on Type, error
+        0,  // on Value, error
+        0,  // on Value*, error
+        0,  // on Value+, error
+        0,  // on __Bindings, error
+
+        // State 4
+        0,  // on "let"?, error
+        0,  // on Binding, error
+        0,  // on Binding*, error
+        0,  // on Binding+, error
+        0,  // on Bindings, error
+        0,  // on Type, error
+        0,  // on Value, error
+        0,  // on Value*, error
+        0,  // on Value+, error
+        0,  // on __Bindings, error
+
+        // State 5
+        0,  // on "let"?, error
+        0,  // on Binding, error
+        0,  // on Binding*, error
+        0,  // on Binding+, error
+        0,  // on Bindings, error
+        0,  // on Type, error
+        0,  // on Value, error
+        0,  // on Value*, error
+        0,  // on Value+, error
+        0,  // on __Bindings, error
+
+        // State 6
+        0,  // on "let"?, error
+        0,  // on Binding, error
+        0,  // on Binding*, error
+        0,  // on Binding+, error
+        0,  // on Bindings, error
+        0,  // on Type, error
+        0,  // on Value, error
+        0,  // on Value*, error
+        0,  // on Value+, error
+        0,  // on __Bindings, error
+
+        // State 7
+        0,  // on "let"?, error
+        0,  // on Binding, error
+        0,  // on Binding*, error
+        0,  // on Binding+, error
+        0,  // on Bindings, error
+        0,  // on Type, error
+        0,  // on Value, error
+        0,  // on Value*, error
+        0,  // on Value+, error
+        0,  // on __Bindings, error
+
+        // State 8
+        0,  // on "let"?, error
+        0,  // on Binding, error
+        0,  // on Binding*, error
+        0,  // on Binding+, error
+        0,  // on Bindings, error
+        11,  // on Type, goto 10
+        0,  // on Value, error
+        0,  // on Value*, error
+        0,  // on Value+, error
+        0,  // on __Bindings, error
+
+        // State 9
+        0,  // on "let"?, error
+        0,  // on Binding, error
+        0,  // on Binding*, error
+        0,  // on Binding+, error
+        0,  // on Bindings, error
+        13,  // on Type, goto 12
+        0,  // on Value, error
+        0,  // on Value*, error
+        0,  // on Value+, error
+        0,  // on __Bindings, error
+
+        // State 10
+        0,  // on "let"?, error
+        0,  // on Binding, error
+        0,  // on Binding*, error
+        0,  // on Binding+, error
+        0,  // on Bindings, error
+        0,  // on Type, error
+        0,  // on Value, error
+        0,  // on Value*, error
+        0,  // on Value+, error
+        0,  // on __Bindings, error
+
+        // State 11
+        0,  // on "let"?, error
+        0,  // on Binding, error
+        0,  // on Binding*, error
+        0,  // on Binding+, error
+        0,  // on Bindings, error
+        0,  // on Type, error
+        0,  // on Value, error
+        0,  // on Value*, error
+        0,  // on Value+, error
+        0,  // on __Bindings, error
+
+        // State 12
+        0,  // on "let"?, error
+        0,  // on Binding, error
+        0,  // on Binding*, error
+        0,  // on Binding+, error
+        0,  // on Bindings, error
+        0,  // on Type, error
+        0,  // on Value, error
+        0,  // on Value*, error
+        0,  // on Value+, error
+        0,  // on __Bindings, error
+
+        // State 13
+        0,  // on "let"?, error
+        0,  // on Binding, error
+        0,  // on Binding*, error
+        0,  // on Binding+, error
+        0,  // on Bindings, error
+        0,  // on Type, error
+        17,  // on Value, goto 16
+        0,  // on Value*, error
+        0,  // on Value+, error
+        0,  // on __Bindings, error
+
+        // State 14
+        0,  // on "let"?, error
+        0,  // on Binding, error
+        0,  // on Binding*, error
+        0,  // on Binding+, error
+        0,  // on Bindings, error
+        21,  // on Type, goto 20
+        0,  // on Value, error
+        0,  // on Value*, error
+        0,  // on Value+, error
+        0,  // on __Bindings, error
+
+        // State 15
+        0,  // on "let"?, error
+        0,  // on Binding, error
+        0,  // on Binding*, error
+        0,  // on Binding+, error
+        0,  // on Bindings, error
+        0,  // on Type, error
+        22,  // on Value, goto 21
+        0,  // on Value*, error
+        0,  // on Value+, error
+        0,  // on __Bindings, error
+
+        // State 16
+        0,  // on "let"?, error
+        0,  // on Binding, error
+        0,  // on Binding*, error
+        0,  // on Binding+, error
+        0,  // on Bindings, error
+        0,  // on Type, error
+        0,  // on Value, error
+        0,  // on Value*, error
+        0,  // on Value+, error
+        0,  // on __Bindings, error
+
+        // State 17
+        0,  // on "let"?, error
+        0,  // on Binding, error
+        0,  // on Binding*, error
+        0,  // on Binding+, error
+        0,  // on Bindings, error
+        0,  // on Type, error
+        24,  // on Value, goto 23
+        0,  // on Value*, error
+        0,  // on Value+, error
+        0,  // on __Bindings, error
+
+        // State 18
+        0,  // on "let"?, error
+        0,  // on Binding, error
+        0,  // on Binding*, error
+        0,  // on Binding+, error
+        0,  // on Bindings, error
+        0,  // on Type, error
+        25,  // on Value, goto 24
+        0,  // on Value*, error
+        26,  // on Value+, goto 25
+        0,  // on __Bindings, error
+
+        // State 19
+        0,  // on "let"?, error
+        0,  // on Binding, error
+        0,  // on Binding*, error
+        0,  // on Binding+, error
+        0,  // on Bindings, error
+        0,  // on Type, error
+        0,  // on Value, error
+        0,  // on Value*, error
+        0,  // on Value+, error
+        0,  // on __Bindings, error
+
+        // State 20
+        0,  // on "let"?, error
+        0,  // on Binding, error
+        0,  // on Binding*, error
+        0,  // on Binding+, error
+        0,  // on Bindings, error
+        0,  // on Type, error
+        0,  // on Value, error
+        0,  // on Value*, error
+        0,  // on Value+, error
+        0,  // on __Bindings, error
+
+        // State 21
+        0,  // on "let"?, error
+        0,  // on Binding, error
+        0,  // on Binding*, error
+        0,  // on Binding+, error
+        0,  // on Bindings, error
+        0,  // on Type, error
+        0,  // on Value, error
+        0,  // on Value*, error
+        0,  // on Value+, error
+        0,  // on __Bindings, error
+
+        // State 22
+        0,  // on "let"?, error
+        0,  // on Binding, error
+        0,  // on Binding*, error
+        0,  // on Binding+, error
+        0,  // on Bindings, error
+        0,  // on Type, error
+        0,  // on Value, error
+        0,  // on Value*, error
+        0,  // on Value+, error
+        0,  // on __Bindings, error
+
+        // State 23
+        0,  // on "let"?, error
+        0,  // on Binding, error
+        0,  // on Binding*, error
+        0,  // on Binding+, error
+        0,  // on Bindings, error
+        0,  // on Type, error
+        0,  // on Value, error
+        0,  // on Value*, error
+        0,  // on Value+, error
+        0,  // on __Bindings, error
+
+        // State 24
+        0,  // on "let"?, error
+        0,  // on Binding, error
+        0,  // on Binding*, error
+        0,  // on Binding+, error
+        0,  // on Bindings, error
+        0,  // on Type, error
+        0,  // on Value, error
+        0,  // on Value*, error
+        0,  // on Value+, error
+        0,  // on __Bindings, error
+
+        // State 25
+        0,  // on "let"?, error
+        0,  // on Binding, error
+        0,  // on Binding*, error
+        0,  // on Binding+, error
+        0,  // on Bindings, error
+        0,  // on Type, error
+        30,  // on Value, goto 29
+        0,  // on Value*, error
+        0,  // on Value+, error
+        0,  // on __Bindings, error
+
+        // State 26
+        0,  // on "let"?, error
+        0,  // on Binding, error
+        0,  // on Binding*, error
+        0,  // on Binding+, error
+        0,  // on Bindings, error
+        0,  // on Type, error
+        0,  // on Value, error
+        0,  // on Value*, error
+        0,  // on Value+, error
+        0,  // on __Bindings, error
+
+        // State 27
+        0,  // on "let"?, error
+        0,  // on Binding, error
+        0,  // on Binding*, error
+        0,  // on Binding+, error
+        0,  // on Bindings, error
+        0,  // on Type, error
+        0,  // on Value, error
+        0,  // on Value*, error
+        0,  // on Value+, error
+        0,  // on __Bindings, error
+
+        // State 28
+        0,  // on "let"?, error
+        0,  // on Binding, error
+        0,  // on Binding*, error
+        0,  // on Binding+, error
+        0,  // on Bindings, error
+        0,  // on Type, error
+        0,  // on Value, error
+        0,  // on Value*, error
+        0,  // on Value+, error
+        0,  // on __Bindings, error
+
+        // State 29
+        0,  // on "let"?, error
+        0,  // on Binding, error
+        0,  // on Binding*, error
+        0,  // on Binding+, error
+        0,  // on Bindings, error
+        0,  // on Type, error
+        0,  // on Value, error
+        0,  // on Value*, error
+        0,  // on Value+, error
+        0,  // on __Bindings, error
+
+        // State 30
+        0,  // on "let"?, error
+        0,  // on Binding, error
+        0,  // on Binding*, error
+        0,  // on Binding+, error
+        0,  // on Bindings, error
+        0,  // on Type, error
+        0,  // on Value, error
+        0,  // on Value*, error
+        0,  // on Value+, error
+        0,  // on __Bindings, error
+
+    ];
+    const __DEFAULT_ACTION: &'static [i8] = &[
+        // State 0
+        0,  // no default reduction
+
+        // State 1
+        -7,  // on any token, reduce `Binding+ = Binding => ActionFn(14);`
+
+        // State 2
+        0,  // no default reduction
+
+        // State 3
+        0,  // no default reduction
+
+        // State 4
+        0,  // no default reduction
+
+        // State 5
+        0,  // no default reduction
+
+        // State 6
+        -8,  // on any token, reduce `Binding+ = Binding+, Binding => ActionFn(15);`
+
+        // State 7
+        0,  // no default reduction
+
+        // State 8
+        0,  // no default reduction
+
+        // State 9
+        0,  // no default reduction
+
+        // State 10
+        0,  // no default reduction
+
+        // State 11
+        0,  // no default reduction
+
+        // State 12
+        0,  // no default reduction
+
+        // State 13
+        0,  // no default reduction
+
+        // State 14
+        0,  // no default reduction
+
+        // State 15
+        0,  // no default reduction
+
+        // State 16
+        0,  // no default reduction
+
+        // State 17
+        0,  // no default reduction
+
+        // State 18
+        0,  // no default reduction
+
+        // State 19
+        -13,  // on any token, reduce `Value = Literal => ActionFn(5);`
+
+        // State 20
+        0,  // no default reduction
+
+        // State 21
+        0,  // no default reduction
+
+        // State 22
+        -4,  // on any token, reduce `Binding = Ident, ":", Type, "=>", Value, ";" => ActionFn(19);`
+
+        // State 23
+        -14,  // on any token, reduce `Value = "-", Value => ActionFn(6);`
+
+        // State 24
+        -19,  // on any token, reduce `Value+ = Value => ActionFn(16);`
+
+        // State 25
+        0,  // no default reduction
+
+        // State 26
+        -15,  // on any token, reduce `Value = "[", "]" => ActionFn(22);`
+
+        // State 27
+        -12,  // on any token, reduce `Type = Ident, "<", Type, ">" => ActionFn(4);`
+
+        // State 28
+        -3,  // on any token, reduce `Binding = "let", Ident, ":", Type, "=>", Value, ";" => ActionFn(18);`
+
+        // State 29
+        -20,  // on any token, reduce `Value+ = Value+, Value => ActionFn(17);`
+
+        // State 30
+        -16,  // on any token, reduce `Value = "[", Value+, "]" => ActionFn(23);`
+
+    ];
+    fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+        const __TERMINAL: &'static [&'static str] = &[
+            r###""-""###,
+            r###"":""###,
+            r###"";""###,
+            r###""<""###,
+            r###""=>""###,
+            r###"">""###,
+            r###""[""###,
+            r###""]""###,
+            r###""let""###,
+            r###"Ident"###,
+            r###"Literal"###,
+        ];
+        __ACTION[(__state * 11)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+            if state == 0 {
+                None
+            } else {
+                Some(terminal.to_string())
+            }
+        }).collect()
+    }
+    #[allow(dead_code)]
+    pub fn parse_Bindings<
+        __TOKEN: __ToTriple<Error=&'static str>,
+        __TOKENS: IntoIterator<Item=__TOKEN>,
+    >(
+        __tokens0: __TOKENS,
+    ) -> Result<Vec<(String, String, String)>, __lalrpop_util::ParseError<__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, &'static str>>
+    {
+        let __tokens = __tokens0.into_iter();
+        let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+        let mut __states = vec![];
+        let mut __symbols = vec![];
+        __parse::<>(0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Bindings(__v) => Ok(__v), _ => unreachable!() })
+    }
+    #[allow(dead_code)]
+    pub fn parse_Bindings_cancelable<
+        __TOKEN: __ToTriple<Error=&'static str>,
+        __TOKENS: IntoIterator<Item=__TOKEN>,
+    >(
+        __tokens0: __TOKENS,
+        __cancel: &::std::sync::atomic::AtomicBool,
+    ) -> Result<Vec<(String, String, String)>, __lalrpop_util::ParseError<__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, &'static str>>
+    {
+        let __tokens = __tokens0.into_iter();
+        let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+        let mut __states = vec![];
+        let mut __symbols = vec![];
+        __parse::<>(0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Bindings(__v) => Ok(__v), _ => unreachable!() })
+    }
+
+    #[allow(dead_code)]
+    pub struct BindingsParser {
+        __states: ::std::vec::Vec<i32>,
+        __symbols: __lalrpop_util::stack::Recycled,
+        __cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+    }
+
+    #[allow(dead_code)]
+    impl BindingsParser {
+        pub fn new() -> BindingsParser {
+            BindingsParser { __states: vec![], __symbols: __lalrpop_util::stack::Recycled::new(), __cancel: None }
+        }
+
+        pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+            self.__cancel = Some(flag);
+        }
+
+        pub fn parse_in_place<__TOKEN: __ToTriple<Error=&'static str>, __TOKENS: IntoIterator<Item=__TOKEN>>(
+            &mut self,
+            __tokens0: __TOKENS,
+        ) -> Result<Vec<(String, String, String)>, __lalrpop_util::ParseError<__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, &'static str>>
+        {
+            let __tokens = __tokens0.into_iter();
+            let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+            let mut __symbols = self.__symbols.take();
+            let __result = __parse::<>(0, &mut __tokens, &mut self.__states, &mut __symbols, self.__cancel.as_ref().map(|c| &**c)).and_then(|__v| match __v { __Symbol::Nt____Bindings(__v) => Ok(__v), _ => unreachable!() });
+            self.__symbols.put(__symbols);
+            __result
+        }
+    }
+
+    fn __parse<
+    >(
+        __start_state: i32,
+        mut __tokens: &mut Iterator<Item = Result<(__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation), &'static str>>,
+        mut __states: &mut ::std::vec::Vec<i32>,
+        mut __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>,
+        __cancel: Option<&::std::sync::atomic::AtomicBool>,
+    ) -> Result<__Symbol<>, __lalrpop_util::ParseError<__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, &'static str>>
+    {
+        __states.clear();
+        __states.push(__start_state);
+        __symbols.clear();
+        let mut __integer;
+        let mut __lookahead;
+        let __last_location: &mut __lalrpop_util::token_stream::SpanLocation = &mut Default::default();
+        let mut __default_reduced = [0_usize; 16];
+        let mut __default_reduced_len = 0;
+        '__shift: loop {
+            loop {
+                let __state = *__states.last().unwrap() as usize;
+                let __action = __DEFAULT_ACTION[__state] as i32;
+                if __action == 0 {
+                    break;
+                }
+                if __default_reduced_len < 16 {
+                    __default_reduced[__default_reduced_len] = __state;
+                    __default_reduced_len += 1;
+                }
+                if let Some(__flag) = __cancel {
+                    if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                        return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                    }
+                }
+                if let Some(r) = __reduce(__action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                    return r;
+                }
+            }
+            __lookahead = match __tokens.next() {
+                Some(Ok(v)) => v,
+                None => break '__shift,
+                Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+            };
+            *__last_location = __lookahead.2.clone();
+            __integer = match __lookahead.1 {
+                __lalrpop_util::token_stream::Token::Fixed(0) if true => 0,
+                __lalrpop_util::token_stream::Token::Fixed(1) if true => 1,
+                __lalrpop_util::token_stream::Token::Fixed(2) if true => 2,
+                __lalrpop_util::token_stream::Token::Fixed(3) if true => 3,
+                __lalrpop_util::token_stream::Token::Fixed(4) if true => 4,
+                __lalrpop_util::token_stream::Token::Fixed(5) if true => 5,
+                __lalrpop_util::token_stream::Token::Fixed(6) if true => 6,
+                __lalrpop_util::token_stream::Token::Fixed(7) if true => 7,
+                __lalrpop_util::token_stream::Token::Fixed(8) if true => 8,
+                __lalrpop_util::token_stream::Token::Ident(_) if true => 9,
+                __lalrpop_util::token_stream::Token::Literal(_) if true => 10,
+                _ => {
+                    let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| true).unwrap_or(*__states.last().unwrap() as usize);
+                    let __expected = __expected_tokens(__state);
+                    let __error = match Some(__lookahead) {
+                        Some(__token) => {
+                            __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                        }
+                        None => {
+                            __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                        }
+                    };
+                    return Err(__error);
+                }
+            };
+            '__inner: loop {
+                let __state = *__states.last().unwrap() as usize;
+                let __action = __ACTION[__state * 11 + __integer] as i32;
+                if __action > 0 {
+                    if let Some(__flag) = __cancel {
+                        if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                            return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                        }
+                    }
+                    let __symbol = match __integer {
+                        0 => match __lookahead.1 {
+                            __tok @ __lalrpop_util::token_stream::Token::Fixed(0) => __Symbol::Term_22_2d_22((__tok)),
+                            _ => unreachable!(),
+                        },
+                        1 => match __lookahead.1 {
+                            __tok @ __lalrpop_util::token_stream::Token::Fixed(1) => __Symbol::Term_22_3a_22((__tok)),
+                            _ => unreachable!(),
+                        },
+                        2 => match __lookahead.1 {
+                            __tok @ __lalrpop_util::token_stream::Token::Fixed(2) => __Symbol::Term_22_3b_22((__tok)),
+                            _ => unreachable!(),
+                        },
+                        3 => match __lookahead.1 {
+                            __tok @ __lalrpop_util::token_stream::Token::Fixed(3) => __Symbol::Term_22_3c_22((__tok)),
+                            _ => unreachable!(),
+                        },
+                        4 => match __lookahead.1 {
+                            __tok @ __lalrpop_util::token_stream::Token::Fixed(4) => __Symbol::Term_22_3d_3e_22((__tok)),
+                            _ => unreachable!(),
+                        },
+                        5 => match __lookahead.1 {
+                            __tok @ __lalrpop_util::token_stream::Token::Fixed(5) => __Symbol::Term_22_3e_22((__tok)),
+                            _ => unreachable!(),
+                        },
+                        6 => match __lookahead.1 {
+                            __tok @ __lalrpop_util::token_stream::Token::Fixed(6) => __Symbol::Term_22_5b_22((__tok)),
+                            _ => unreachable!(),
+                        },
+                        7 => match __lookahead.1 {
+                            __tok @ __lalrpop_util::token_stream::Token::Fixed(7) => __Symbol::Term_22_5d_22((__tok)),
+                            _ => unreachable!(),
+                        },
+                        8 => match __lookahead.1 {
+                            __tok @ __lalrpop_util::token_stream::Token::Fixed(8) => __Symbol::Term_22let_22((__tok)),
+                            _ => unreachable!(),
+                        },
+                        9 => match __lookahead.1 {
+                            __lalrpop_util::token_stream::Token::Ident(__tok0) => __Symbol::TermIdent((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        10 => match __lookahead.1 {
+                            __lalrpop_util::token_stream::Token::Literal(__tok0) => __Symbol::TermLiteral((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        _ => unreachable!(),
+                    };
+                    __states.push(__action - 1);
+                    __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                    __default_reduced_len = 0;
+                    continue '__shift;
+                } else if __action < 0 {
+                    if let Some(__flag) = __cancel {
+                        if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                            return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                        }
+                    }
+                    if let Some(r) = __reduce(__action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                        if r.is_err() {
+                            return r;
+                        }
+                        return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] });
+                    }
+                } else {
+                    let mut __err_lookahead = Some(__lookahead);
+                    let mut __err_integer: Option<usize> = Some(__integer);
+                    let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 11 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                    let __expected = __expected_tokens(__state);
+                    let __error = match __err_lookahead {
+                        Some(__token) => {
+                            if __accepts( *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                            } else {
+                                __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                            }
+                        }
+                        None => {
+                            __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                        }
+                    };
+                    return Err(__error)
+                }
+            }
+        }
+        loop {
+            let __state = *__states.last().unwrap() as usize;
+            let __action = __EOF_ACTION[__state] as i32;
+            if __action < 0 {
+                if let Some(__flag) = __cancel {
+                    if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                        return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                    }
+                }
+                if let Some(r) = __reduce(__action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                    return r;
+                }
+            } else {
+                let mut __err_lookahead = None;
+                let mut __err_integer: Option<usize> = None;
+                let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 11 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                let __expected = __expected_tokens(__state);
+                let __error = match __err_lookahead {
+                    Some(__token) => {
+                        if __accepts( *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                            __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                        } else {
+                            __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                        }
+                    }
+                    None => {
+                        __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                    }
+                };
+                return Err(__error)
+            }
+        }
+    }
+    fn __accepts<
+    >(
+        __error_state: i32,
+        __states: & [i32],
+        __opt_integer: Option<usize>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> bool
+    {
+        let mut __states = __states.to_vec();
+        __states.push(__error_state);
+        loop {
+            let mut __states_len = __states.len();
+            let __top = __states[__states_len - 1];
+            let __action = match __opt_integer {
+                None => __EOF_ACTION[__top as usize] as i32,
+                Some(__integer) => __ACTION[(__top * 11) as usize + __integer] as i32,
+            };
+            if __action == 0 { return false; }
+            if __action > 0 { return true; }
+            let (__to_pop, __nt) = match -__action {
+                // simulate "let"? = "let" => ActionFn(10);
+                1 => {
+                    (1, 0)
+                }
+                // simulate "let"? =  => ActionFn(11);
+                2 => {
+                    (0, 0)
+                }
+                // simulate Binding = "let", Ident, ":", Type, "=>", Value, ";" => ActionFn(18);
+                3 => {
+                    (7, 1)
+                }
+                // simulate Binding = Ident, ":", Type, "=>", Value, ";" => ActionFn(19);
+                4 => {
+                    (6, 1)
+                }
+                // simulate Binding* =  => ActionFn(12);
+                5 => {
+                    (0, 2)
+                }
+                // simulate Binding* = Binding+ => ActionFn(13);
+                6 => {
+                    (1, 2)
+                }
+                // simulate Binding+ = Binding => ActionFn(14);
+                7 => {
+                    (1, 3)
+                }
+                // simulate Binding+ = Binding+, Binding => ActionFn(15);
+                8 => {
+                    (2, 3)
+                }
+                // simulate Bindings =  => ActionFn(20);
+                9 => {
+                    (0, 4)
+                }
+                // simulate Bindings = Binding+ => ActionFn(21);
+                10 => {
+                    (1, 4)
+                }
+                // simulate Type = Ident => ActionFn(3);
+                11 => {
+                    (1, 5)
+                }
+                // simulate Type = Ident, "<", Type, ">" => ActionFn(4);
+                12 => {
+                    (4, 5)
+                }
+                // simulate Value = Literal => ActionFn(5);
+                13 => {
+                    (1, 6)
+                }
+                // simulate Value = "-", Value => ActionFn(6);
+                14 => {
+                    (2, 6)
+                }
+                // simulate Value = "[", "]" => ActionFn(22);
+                15 => {
+                    (2, 6)
+                }
+                // simulate Value = "[", Value+, "]" => ActionFn(23);
+                16 => {
+                    (3, 6)
+                }
+                // simulate Value* =  => ActionFn(8);
+                17 => {
+                    (0, 7)
+                }
+                // simulate Value* = Value+ => ActionFn(9);
+                18 => {
+                    (1, 7)
+                }
+                // simulate Value+ = Value => ActionFn(16);
+                19 => {
+                    (1, 8)
+                }
+                // simulate Value+ = Value+, Value => ActionFn(17);
+                20 => {
+                    (2, 8)
+                }
+                // simulate __Bindings = Bindings => ActionFn(0);
+                21 => return true,
+                _ => panic!("invalid action code {}", __action)
+            };
+            __states_len -= __to_pop;
+            __states.truncate(__states_len);
+            let __top = __states[__states_len - 1];
+            let __next_state = __GOTO[(__top * 10 + __nt) as usize] as i32 - 1;
+            __states.push(__next_state);
+        }
+    }
+    pub fn __reduce<
+    >(
+        __action: i32,
+        __lookahead_start: Option<&__lalrpop_util::token_stream::SpanLocation>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Option<Result<__Symbol<>,__lalrpop_util::ParseError<__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, &'static str>>>
+    {
+        let __nonterminal = match -__action {
+            1 => match __reduce__22let_22_3f_0(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            2 => match __reduce__22let_22_3f_1(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            3 => match __reduce_Binding_0(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            4 => match __reduce_Binding_1(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            5 => match __reduce_Binding_2a_0(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            6 => match __reduce_Binding_2a_1(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            7 => match __reduce_Binding_2b_0(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            8 => match __reduce_Binding_2b_1(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            9 => match __reduce_Bindings_0(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            10 => match __reduce_Bindings_1(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            11 => match __reduce_Type_0(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            12 => match __reduce_Type_1(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            13 => match __reduce_Value_0(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            14 => match __reduce_Value_1(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            15 => match __reduce_Value_2(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            16 => match __reduce_Value_3(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            17 => match __reduce_Value_2a_0(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            18 => match __reduce_Value_2a_1(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            19 => match __reduce_Value_2b_0(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            20 => match __reduce_Value_2b_1(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            21 => match __reduce_____Bindings_0(__lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            _ => panic!("invalid action code {}", __action)
+        };
+        let __state = *__states.last().unwrap() as usize;
+        let __next_state = __GOTO[__state * 10 + __nonterminal] as i32 - 1;
+        __states.push(__next_state);
+        None
+    }
+
+    fn __reduce__22let_22_3f_0<
+    >(
+        __lookahead_start: Option<&__lalrpop_util::token_stream::SpanLocation>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, &'static str>>>
+    {
+        // "let"? = "let" => ActionFn(10);
+        let __sym0 = __pop_Term_22let_22(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action10::<>(__sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::Nt_22let_22_3f(__nt), __end));
+        Ok(0)
+    }
+
+    fn __reduce__22let_22_3f_1<
+    >(
+        __lookahead_start: Option<&__lalrpop_util::token_stream::SpanLocation>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, &'static str>>>
+    {
+        // "let"? =  => ActionFn(11);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action11::<>(&__start, &__end);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 0);
+        __symbols.push((__start, __Symbol::Nt_22let_22_3f(__nt), __end));
+        Ok(0)
+    }
+
+    fn __reduce_Binding_0<
+    >(
+        __lookahead_start: Option<&__lalrpop_util::token_stream::SpanLocation>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, &'static str>>>
+    {
+        // Binding = "let", Ident, ":", Type, "=>", Value, ";" => ActionFn(18);
+        let __sym6 = __pop_Term_22_3b_22(__symbols);
+        let __sym5 = __pop_NtValue(__symbols);
+        let __sym4 = __pop_Term_22_3d_3e_22(__symbols);
+        let __sym3 = __pop_NtType(__symbols);
+        let __sym2 = __pop_Term_22_3a_22(__symbols);
+        let __sym1 = __pop_TermIdent(__symbols);
+        let __sym0 = __pop_Term_22let_22(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action18::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 7);
+        __symbols.push((__start, __Symbol::NtBinding(__nt), __end));
+        Ok(1)
+    }
+
+    fn __reduce_Binding_1<
+    >(
+        __lookahead_start: Option<&__lalrpop_util::token_stream::SpanLocation>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, &'static str>>>
+    {
+        // Binding = Ident, ":", Type, "=>", Value, ";" => ActionFn(19);
+        let __sym5 = __pop_Term_22_3b_22(__symbols);
+        let __sym4 = __pop_NtValue(__symbols);
+        let __sym3 = __pop_Term_22_3d_3e_22(__symbols);
+        let __sym2 = __pop_NtType(__symbols);
+        let __sym1 = __pop_Term_22_3a_22(__symbols);
+        let __sym0 = __pop_TermIdent(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action19::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 6);
+        __symbols.push((__start, __Symbol::NtBinding(__nt), __end));
+        Ok(1)
+    }
+
+    fn __reduce_Binding_2a_0<
+    >(
+        __lookahead_start: Option<&__lalrpop_util::token_stream::SpanLocation>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, &'static str>>>
+    {
+        // Binding* =  => ActionFn(12);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action12::<>(&__start, &__end);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 0);
+        __symbols.push((__start, __Symbol::NtBinding_2a(__nt), __end));
+        Ok(2)
+    }
+
+    fn __reduce_Binding_2a_1<
+    >(
+        __lookahead_start: Option<&__lalrpop_util::token_stream::SpanLocation>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, &'static str>>>
+    {
+        // Binding* = Binding+ => ActionFn(13);
+        let __sym0 = __pop_NtBinding_2b(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action13::<>(__sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtBinding_2a(__nt), __end));
+        Ok(2)
+    }
+
+    fn __reduce_Binding_2b_0<
+    >(
+        __lookahead_start: Option<&__lalrpop_util::token_stream::SpanLocation>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, &'static str>>>
+    {
+        // Binding+ = Binding => ActionFn(14);
+        let __sym0 = __pop_NtBinding(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action14::<>(__sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtBinding_2b(__nt), __end));
+        Ok(3)
+    }
+
+    fn __reduce_Binding_2b_1<
+    >(
+        __lookahead_start: Option<&__lalrpop_util::token_stream::SpanLocation>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, &'static str>>>
+    {
+        // Binding+ = Binding+, Binding => ActionFn(15);
+        let __sym1 = __pop_NtBinding(__symbols);
+        let __sym0 = __pop_NtBinding_2b(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action15::<>(__sym0, __sym1);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 2);
+        __symbols.push((__start, __Symbol::NtBinding_2b(__nt), __end));
+        Ok(3)
+    }
+
+    fn __reduce_Bindings_0<
+    >(
+        __lookahead_start: Option<&__lalrpop_util::token_stream::SpanLocation>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, &'static str>>>
+    {
+        // Bindings =  => ActionFn(20);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action20::<>(&__start, &__end);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 0);
+        __symbols.push((__start, __Symbol::NtBindings(__nt), __end));
+        Ok(4)
+    }
+
+    fn __reduce_Bindings_1<
+    >(
+        __lookahead_start: Option<&__lalrpop_util::token_stream::SpanLocation>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, &'static str>>>
+    {
+        // Bindings = Binding+ => ActionFn(21);
+        let __sym0 = __pop_NtBinding_2b(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action21::<>(__sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtBindings(__nt), __end));
+        Ok(4)
+    }
+
+    fn __reduce_Type_0<
+    >(
+        __lookahead_start: Option<&__lalrpop_util::token_stream::SpanLocation>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, &'static str>>>
+    {
+        // Type = Ident => ActionFn(3);
+        let __sym0 = __pop_TermIdent(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action3::<>(__sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtType(__nt), __end));
+        Ok(5)
+    }
+
+    fn __reduce_Type_1<
+    >(
+        __lookahead_start: Option<&__lalrpop_util::token_stream::SpanLocation>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, &'static str>>>
+    {
+        // Type = Ident, "<", Type, ">" => ActionFn(4);
+        let __sym3 = __pop_Term_22_3e_22(__symbols);
+        let __sym2 = __pop_NtType(__symbols);
+        let __sym1 = __pop_Term_22_3c_22(__symbols);
+        let __sym0 = __pop_TermIdent(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action4::<>(__sym0, __sym1, __sym2, __sym3);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 4);
+        __symbols.push((__start, __Symbol::NtType(__nt), __end));
+        Ok(5)
+    }
+
+    fn __reduce_Value_0<
+    >(
+        __lookahead_start: Option<&__lalrpop_util::token_stream::SpanLocation>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, &'static str>>>
+    {
+        // Value = Literal => ActionFn(5);
+        let __sym0 = __pop_TermLiteral(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action5::<>(__sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtValue(__nt), __end));
+        Ok(6)
+    }
+
+    fn __reduce_Value_1<
+    >(
+        __lookahead_start: Option<&__lalrpop_util::token_stream::SpanLocation>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, &'static str>>>
+    {
+        // Value = "-", Value => ActionFn(6);
+        let __sym1 = __pop_NtValue(__symbols);
+        let __sym0 = __pop_Term_22_2d_22(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action6::<>(__sym0, __sym1);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 2);
+        __symbols.push((__start, __Symbol::NtValue(__nt), __end));
+        Ok(6)
+    }
+
+    fn __reduce_Value_2<
+    >(
+        __lookahead_start: Option<&__lalrpop_util::token_stream::SpanLocation>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, &'static str>>>
+    {
+        // Value = "[", "]" => ActionFn(22);
+        let __sym1 = __pop_Term_22_5d_22(__symbols);
+        let __sym0 = __pop_Term_22_5b_22(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action22::<>(__sym0, __sym1);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 2);
+        __symbols.push((__start, __Symbol::NtValue(__nt), __end));
+        Ok(6)
+    }
+
+    fn __reduce_Value_3<
+    >(
+        __lookahead_start: Option<&__lalrpop_util::token_stream::SpanLocation>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, &'static str>>>
+    {
+        // Value = "[", Value+, "]" => ActionFn(23);
+        let __sym2 = __pop_Term_22_5d_22(__symbols);
+        let __sym1 = __pop_NtValue_2b(__symbols);
+        let __sym0 = __pop_Term_22_5b_22(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action23::<>(__sym0, __sym1, __sym2);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 3);
+        __symbols.push((__start, __Symbol::NtValue(__nt), __end));
+        Ok(6)
+    }
+
+    fn __reduce_Value_2a_0<
+    >(
+        __lookahead_start: Option<&__lalrpop_util::token_stream::SpanLocation>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, &'static str>>>
+    {
+        // Value* =  => ActionFn(8);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action8::<>(&__start, &__end);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 0);
+        __symbols.push((__start, __Symbol::NtValue_2a(__nt), __end));
+        Ok(7)
+    }
+
+    fn __reduce_Value_2a_1<
+    >(
+        __lookahead_start: Option<&__lalrpop_util::token_stream::SpanLocation>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, &'static str>>>
+    {
+        // Value* = Value+ => ActionFn(9);
+        let __sym0 = __pop_NtValue_2b(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action9::<>(__sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtValue_2a(__nt), __end));
+        Ok(7)
+    }
+
+    fn __reduce_Value_2b_0<
+    >(
+        __lookahead_start: Option<&__lalrpop_util::token_stream::SpanLocation>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, &'static str>>>
+    {
+        // Value+ = Value => ActionFn(16);
+        let __sym0 = __pop_NtValue(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action16::<>(__sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtValue_2b(__nt), __end));
+        Ok(8)
+    }
+
+    fn __reduce_Value_2b_1<
+    >(
+        __lookahead_start: Option<&__lalrpop_util::token_stream::SpanLocation>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, &'static str>>>
+    {
+        // Value+ = Value+, Value => ActionFn(17);
+        let __sym1 = __pop_NtValue(__symbols);
+        let __sym0 = __pop_NtValue_2b(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action17::<>(__sym0, __sym1);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 2);
+        __symbols.push((__start, __Symbol::NtValue_2b(__nt), __end));
+        Ok(8)
+    }
+
+    fn __reduce_____Bindings_0<
+    >(
+        __lookahead_start: Option<&__lalrpop_util::token_stream::SpanLocation>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<>,__lalrpop_util::ParseError<__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, &'static str>>>
+    {
+        // __Bindings = Bindings => ActionFn(0);
+        let __sym0 = __pop_NtBindings(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action0::<>(__sym0);
+        Err(Ok(__Symbol::Nt____Bindings(__nt)))
+    }
+    fn __pop_Term_22_2d_22<
+    >(
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>
+    ) -> (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3a_22<
+    >(
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>
+    ) -> (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3a_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3b_22<
+    >(
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>
+    ) -> (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3b_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3c_22<
+    >(
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>
+    ) -> (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3c_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3d_3e_22<
+    >(
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>
+    ) -> (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3d_3e_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3e_22<
+    >(
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>
+    ) -> (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3e_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_5b_22<
+    >(
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>
+    ) -> (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_5b_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_5d_22<
+    >(
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>
+    ) -> (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_5d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22let_22<
+    >(
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>
+    ) -> (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22let_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_TermIdent<
+    >(
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>
+    ) -> (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Ident, __lalrpop_util::token_stream::SpanLocation)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::TermIdent(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_TermLiteral<
+    >(
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>
+    ) -> (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Literal, __lalrpop_util::token_stream::SpanLocation)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::TermLiteral(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_22let_22_3f<
+    >(
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>
+    ) -> (__lalrpop_util::token_stream::SpanLocation, ::std::option::Option<__lalrpop_util::token_stream::Token>, __lalrpop_util::token_stream::SpanLocation)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_22let_22_3f(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtBinding<
+    >(
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>
+    ) -> (__lalrpop_util::token_stream::SpanLocation, (String, String, String), __lalrpop_util::token_stream::SpanLocation)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtBinding(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtBinding_2a<
+    >(
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>
+    ) -> (__lalrpop_util::token_stream::SpanLocation, ::std::vec::Vec<(String, String, String)>, __lalrpop_util::token_stream::SpanLocation)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtBinding_2a(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtBinding_2b<
+    >(
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>
+    ) -> (__lalrpop_util::token_stream::SpanLocation, ::std::vec::Vec<(String, String, String)>, __lalrpop_util::token_stream::SpanLocation)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtBinding_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtBindings<
+    >(
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>
+    ) -> (__lalrpop_util::token_stream::SpanLocation, Vec<(String, String, String)>, __lalrpop_util::token_stream::SpanLocation)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtBindings(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtType<
+    >(
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>
+    ) -> (__lalrpop_util::token_stream::SpanLocation, String, __lalrpop_util::token_stream::SpanLocation)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtType(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtValue<
+    >(
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>
+    ) -> (__lalrpop_util::token_stream::SpanLocation, String, __lalrpop_util::token_stream::SpanLocation)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtValue(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtValue_2a<
+    >(
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>
+    ) -> (__lalrpop_util::token_stream::SpanLocation, ::std::vec::Vec<String>, __lalrpop_util::token_stream::SpanLocation)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtValue_2a(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtValue_2b<
+    >(
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>
+    ) -> (__lalrpop_util::token_stream::SpanLocation, ::std::vec::Vec<String>, __lalrpop_util::token_stream::SpanLocation)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtValue_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____Bindings<
+    >(
+        __symbols: &mut ::std::vec::Vec<(__lalrpop_util::token_stream::SpanLocation,__Symbol<>,__lalrpop_util::token_stream::SpanLocation)>
+    ) -> (__lalrpop_util::token_stream::SpanLocation, Vec<(String, String, String)>, __lalrpop_util::token_stream::SpanLocation)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Bindings(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+}
+
+pub fn tokens(stream: __lalrpop_util::token_stream::TokenStream) -> __lalrpop_util::token_stream::Tokens {
+    __lalrpop_util::token_stream::Tokens::new(stream, &["-", ":", ";", "<", "=>", ">", "[", "]", "let"])
+}
+
+fn __action0<
+>(
+    (_, __0, _): (__lalrpop_util::token_stream::SpanLocation, Vec<(String, String, String)>, __lalrpop_util::token_stream::SpanLocation),
+) -> Vec<(String, String, String)>
+{
+    (__0)
+}
+
+fn __action1<
+>(
+    (_, __0, _): (__lalrpop_util::token_stream::SpanLocation, ::std::vec::Vec<(String, String, String)>, __lalrpop_util::token_stream::SpanLocation),
+) -> Vec<(String, String, String)>
+{
+    (__0)
+}
+
+fn __action2<
+>(
+    (_, _, _): (__lalrpop_util::token_stream::SpanLocation, ::std::option::Option<__lalrpop_util::token_stream::Token>, __lalrpop_util::token_stream::SpanLocation),
+    (_, n, _): (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Ident, __lalrpop_util::token_stream::SpanLocation),
+    (_, _, _): (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation),
+    (_, t, _): (__lalrpop_util::token_stream::SpanLocation, String, __lalrpop_util::token_stream::SpanLocation),
+    (_, _, _): (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation),
+    (_, v, _): (__lalrpop_util::token_stream::SpanLocation, String, __lalrpop_util::token_stream::SpanLocation),
+    (_, _, _): (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation),
+) -> (String, String, String)
+{
+    //@ src/token_stream.lalrpop:12:57 (1 lines)
+    (n.to_string(), t, v)
+}
+
+fn __action3<
+>(
+    (_, __0, _): (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Ident, __lalrpop_util::token_stream::SpanLocation),
+) -> String
+{
+    //@ src/token_stream.lalrpop:15:14 (1 lines)
+    __0.to_string()
+}
+
+fn __action4<
+>(
+    (_, i, _): (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Ident, __lalrpop_util::token_stream::SpanLocation),
+    (_, _, _): (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation),
+    (_, t, _): (__lalrpop_util::token_stream::SpanLocation, String, __lalrpop_util::token_stream::SpanLocation),
+    (_, _, _): (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation),
+) -> String
+{
+    //@ src/token_stream.lalrpop:16:35 (1 lines)
+    format!("{}<{}>", i, t)
+}
+
+fn __action5<
+>(
+    (_, __0, _): (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Literal, __lalrpop_util::token_stream::SpanLocation),
+) -> String
+{
+    //@ src/token_stream.lalrpop:20:16 (1 lines)
+    __0.to_string()
+}
+
+fn __action6<
+>(
+    (_, _, _): (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation),
+    (_, v, _): (__lalrpop_util::token_stream::SpanLocation, String, __lalrpop_util::token_stream::SpanLocation),
+) -> String
+{
+    //@ src/token_stream.lalrpop:21:22 (1 lines)
+    format!("-{}", v)
+}
+
+fn __action7<
+>(
+    (_, _, _): (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation),
+    (_, v, _): (__lalrpop_util::token_stream::SpanLocation, ::std::vec::Vec<String>, __lalrpop_util::token_stream::SpanLocation),
+    (_, _, _): (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation),
+) -> String
+{
+    //@ src/token_stream.lalrpop:22:27 (1 lines)
+    format!("[{}]", v.join(" "))
+}
+
+fn __action8<
+>(
+    __lookbehind: &__lalrpop_util::token_stream::SpanLocation,
+    __lookahead: &__lalrpop_util::token_stream::SpanLocation,
+) -> ::std::vec::Vec<String>
+{
+    vec![]
+}
+
+fn __action9<
+>(
+    (_, v, _): (__lalrpop_util::token_stream::SpanLocation, ::std::vec::Vec<String>, __lalrpop_util::token_stream::SpanLocation),
+) -> ::std::vec::Vec<String>
+{
+    v
+}
+
+fn __action10<
+>(
+    (_, __0, _): (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation),
+) -> ::std::option::Option<__lalrpop_util::token_stream::Token>
+{
+    Some(__0)
+}
+
+fn __action11<
+>(
+    __lookbehind: &__lalrpop_util::token_stream::SpanLocation,
+    __lookahead: &__lalrpop_util::token_stream::SpanLocation,
+) -> ::std::option::Option<__lalrpop_util::token_stream::Token>
+{
+    None
+}
+
+fn __action12<
+>(
+    __lookbehind: &__lalrpop_util::token_stream::SpanLocation,
+    __lookahead: &__lalrpop_util::token_stream::SpanLocation,
+) -> ::std::vec::Vec<(String, String, String)>
+{
+    vec![]
+}
+
+fn __action13<
+>(
+    (_, v, _): (__lalrpop_util::token_stream::SpanLocation, ::std::vec::Vec<(String, String, String)>, __lalrpop_util::token_stream::SpanLocation),
+) -> ::std::vec::Vec<(String, String, String)>
+{
+    v
+}
+
+fn __action14<
+>(
+    (_, __0, _): (__lalrpop_util::token_stream::SpanLocation, (String, String, String), __lalrpop_util::token_stream::SpanLocation),
+) -> ::std::vec::Vec<(String, String, String)>
+{
+    vec![__0]
+}
+
+fn __action15<
+>(
+    (_, v, _): (__lalrpop_util::token_stream::SpanLocation, ::std::vec::Vec<(String, String, String)>, __lalrpop_util::token_stream::SpanLocation),
+    (_, e, _): (__lalrpop_util::token_stream::SpanLocation, (String, String, String), __lalrpop_util::token_stream::SpanLocation),
+) -> ::std::vec::Vec<(String, String, String)>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+fn __action16<
+>(
+    (_, __0, _): (__lalrpop_util::token_stream::SpanLocation, String, __lalrpop_util::token_stream::SpanLocation),
+) -> ::std::vec::Vec<String>
+{
+    vec![__0]
+}
+
+fn __action17<
+>(
+    (_, v, _): (__lalrpop_util::token_stream::SpanLocation, ::std::vec::Vec<String>, __lalrpop_util::token_stream::SpanLocation),
+    (_, e, _): (__lalrpop_util::token_stream::SpanLocation, String, __lalrpop_util::token_stream::SpanLocation),
+) -> ::std::vec::Vec<String>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+fn __action18<
+>(
+    __0: (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation),
+    __1: (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Ident, __lalrpop_util::token_stream::SpanLocation),
+    __2: (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation),
+    __3: (__lalrpop_util::token_stream::SpanLocation, String, __lalrpop_util::token_stream::SpanLocation),
+    __4: (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation),
+    __5: (__lalrpop_util::token_stream::SpanLocation, String, __lalrpop_util::token_stream::SpanLocation),
+    __6: (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation),
+) -> (String, String, String)
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action10(
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action2(
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
+}
+
+fn __action19<
+>(
+    __0: (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Ident, __lalrpop_util::token_stream::SpanLocation),
+    __1: (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation),
+    __2: (__lalrpop_util::token_stream::SpanLocation, String, __lalrpop_util::token_stream::SpanLocation),
+    __3: (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation),
+    __4: (__lalrpop_util::token_stream::SpanLocation, String, __lalrpop_util::token_stream::SpanLocation),
+    __5: (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation),
+) -> (String, String, String)
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action11(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action2(
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+fn __action20<
+>(
+    __lookbehind: &__lalrpop_util::token_stream::SpanLocation,
+    __lookahead: &__lalrpop_util::token_stream::SpanLocation,
+) -> Vec<(String, String, String)>
+{
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action12(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action1(
+        __temp0,
+    )
+}
+
+fn __action21<
+>(
+    __0: (__lalrpop_util::token_stream::SpanLocation, ::std::vec::Vec<(String, String, String)>, __lalrpop_util::token_stream::SpanLocation),
+) -> Vec<(String, String, String)>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action13(
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action1(
+        __temp0,
+    )
+}
+
+fn __action22<
+>(
+    __0: (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation),
+    __1: (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation),
+) -> String
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action8(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action7(
+        __0,
+        __temp0,
+        __1,
+    )
+}
+
+fn __action23<
+>(
+    __0: (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation),
+    __1: (__lalrpop_util::token_stream::SpanLocation, ::std::vec::Vec<String>, __lalrpop_util::token_stream::SpanLocation),
+    __2: (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation),
+) -> String
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action9(
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action7(
+        __0,
+        __temp0,
+        __2,
+    )
+}
+
+pub trait __ToTriple<> {
+    type Error;
+    fn to_triple(value: Self) -> Result<(__lalrpop_util::token_stream::SpanLocation,__lalrpop_util::token_stream::Token,__lalrpop_util::token_stream::SpanLocation),Self::Error>;
+}
+
+impl<> __ToTriple<> for (__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation) {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(__lalrpop_util::token_stream::SpanLocation,__lalrpop_util::token_stream::Token,__lalrpop_util::token_stream::SpanLocation),&'static str> {
+        Ok(value)
+    }
+}
+impl<> __ToTriple<> for Result<(__lalrpop_util::token_stream::SpanLocation, __lalrpop_util::token_stream::Token, __lalrpop_util::token_stream::SpanLocation),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(__lalrpop_util::token_stream::SpanLocation,__lalrpop_util::token_stream::Token,__lalrpop_util::token_stream::SpanLocation),&'static str> {
+        value
+    }
+}
@@ -0,0 +1,1464 @@
+// auto-generated: "lalrpop 0.15.0"
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+pub use self::__parse_table::parse_Rules;
+pub use self::__parse_table::parse_Rules_cancelable;
+pub use self::__parse_table::RulesParser;
+
+mod __parse_table {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__intern_token::Token;
+    #[allow(dead_code)]
+    pub enum __Symbol<'input>
+     {
+        Term_22_3a_22(&'input str),
+        Termr_23_22_5ba_2dz_5d_2b_22_23(&'input str),
+        NtName(&'input str),
+        NtName_2a(::std::vec::Vec<&'input str>),
+        NtName_2b(::std::vec::Vec<&'input str>),
+        NtRule((&'input str, Vec<&'input str>)),
+        NtRule_2a(::std::vec::Vec<(&'input str, Vec<&'input str>)>),
+        NtRule_2b(::std::vec::Vec<(&'input str, Vec<&'input str>)>),
+        NtRules(::std::vec::Vec<(&'input str, Vec<&'input str>)>),
+        Nt____Rules(::std::vec::Vec<(&'input str, Vec<&'input str>)>),
+    }
+    impl<'input> ::std::fmt::Debug for __Symbol<'input>
+    {
+        fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            match *self {
+                __Symbol::Term_22_3a_22(..) => __f.write_str("\":\""),
+                __Symbol::Termr_23_22_5ba_2dz_5d_2b_22_23(..) => __f.write_str("r#\"[a-z]+\"#"),
+                __Symbol::NtName(..) => __f.write_str("Name"),
+                __Symbol::NtName_2a(..) => __f.write_str("Name*"),
+                __Symbol::NtName_2b(..) => __f.write_str("Name+"),
+                __Symbol::NtRule(..) => __f.write_str("Rule"),
+                __Symbol::NtRule_2a(..) => __f.write_str("Rule*"),
+                __Symbol::NtRule_2b(..) => __f.write_str("Rule+"),
+                __Symbol::NtRules(..) => __f.write_str("Rules"),
+                __Symbol::Nt____Rules(..) => __f.write_str("__Rules"),
+            }
+        }
+    }
+    const __ACTION: &'static [i8] = &[
+        // State 0
+        //     Name = (*) r#"[a-z]+"# [":"]
+        //     Rule = (*) Name ":" [r#"[a-z]+"#, EOF]
+        //     Rule = (*) Name ":" Name+ [r#"[a-z]+"#, EOF]
+        //     Rule+ = (*) Rule [r#"[a-z]+"#, EOF]
+        //     Rule+ = (*) Rule+ Rule [r#"[a-z]+"#, EOF]
+        //     Rules = (*) [EOF]
+        //     Rules = (*) Rule+ [EOF]
+        //     __Rules = (*) Rules [EOF]
+        0,  // on ":", error
+        6,  // on r#"[a-z]+"#, goto 5
+
+        // State 1
+        //     Rule = Name (*) ":" [r#"[a-z]+"#, EOF]
+        //     Rule = Name (*) ":" Name+ [r#"[a-z]+"#, EOF]
+        7,  // on ":", goto 6
+        0,  // on r#"[a-z]+"#, error
+
+        // State 2
+        //     Rule+ = Rule (*) [r#"[a-z]+"#, EOF]
+        0,  // on ":", error
+        -10,  // on r#"[a-z]+"#, reduce `Rule+ = Rule => ActionFn(8);`
+
+        // State 3
+        //     Name = (*) r#"[a-z]+"# [":"]
+        //     Rule = (*) Name ":" [r#"[a-z]+"#, EOF]
+        //     Rule = (*) Name ":" Name+ [r#"[a-z]+"#, EOF]
+        //     Rule+ = Rule+ (*) Rule [r#"[a-z]+"#, EOF]
+        //     Rules = Rule+ (*) [EOF]
+        0,  // on ":", error
+        6,  // on r#"[a-z]+"#, goto 5
+
+        // State 4
+        //     __Rules = Rules (*) [EOF]
+        0,  // on ":", error
+        0,  // on r#"[a-z]+"#, error
+
+        // State 5
+        //     Name = r#"[a-z]+"# (*) [":"]
+        -1,  // on ":", reduce `Name = r#"[a-z]+"# => ActionFn(3);`
+        0,  // on r#"[a-z]+"#, error
+
+        // State 6
+        //     Name = (*) r#"[a-z]+"# [r#"[a-z]+"#, EOF]
+        //     Name+ = (*) Name [r#"[a-z]+"#, EOF]
+        //     Name+ = (*) Name+ Name [r#"[a-z]+"#, EOF]
+        //     Rule = Name ":" (*) [r#"[a-z]+"#, EOF]
+        //     Rule = Name ":" (*) Name+ [r#"[a-z]+"#, EOF]
+        0,  // on ":", error
+        11,  // on r#"[a-z]+"#, goto 10
+
+        // State 7
+        //     Rule+ = Rule+ Rule (*) [r#"[a-z]+"#, EOF]
+        0,  // on ":", error
+        -11,  // on r#"[a-z]+"#, reduce `Rule+ = Rule+, Rule => ActionFn(9);`
+
+        // State 8
+        //     Name+ = Name (*) [r#"[a-z]+"#, EOF]
+        0,  // on ":", error
+        -4,  // on r#"[a-z]+"#, reduce `Name+ = Name => ActionFn(10);`
+
+        // State 9
+        //     Name = (*) r#"[a-z]+"# [r#"[a-z]+"#, EOF]
+        //     Name+ = Name+ (*) Name [r#"[a-z]+"#, EOF]
+        //     Rule = Name ":" Name+ (*) [r#"[a-z]+"#, EOF]
+        0,  // on ":", error
+        11,  // on r#"[a-z]+"#, goto 10
+
+        // State 10
+        //     Name = r#"[a-z]+"# (*) [r#"[a-z]+"#, EOF]
+        0,  // on ":", error
+        -1,  // on r#"[a-z]+"#, reduce `Name = r#"[a-z]+"# => ActionFn(3);`
+
+        // State 11
+        //     Name+ = Name+ Name (*) [r#"[a-z]+"#, EOF]
+        0,  // on ":", error
+        -5,  // on r#"[a-z]+"#, reduce `Name+ = Name+, Name => ActionFn(11);`
+
+    ];
+    const __EOF_ACTION: &'static [i8] = &[
+        // State 0
+        -12,  // on EOF, reduce `Rules =  => ActionFn(14);`
+
+        // State 1
+        0,  // on EOF, error
+
+        // State 2
+        -10,  // on EOF, reduce `Rule+ = Rule => ActionFn(8);`
+
+        // State 3
+        -13,  // on EOF, reduce `Rules = Rule+ => ActionFn(15);`
+
+        // State 4
+        -14,  // on EOF, reduce `__Rules = Rules => ActionFn(0);`
+
+        // State 5
+        0,  // on EOF, error
+
+        // State 6
+        -6,  // on EOF, reduce `Rule = Name, ":" => ActionFn(12);`
+
+        // State 7
+        -11,  // on EOF, reduce `Rule+ = Rule+, Rule => ActionFn(9);`
+
+        // State 8
+        -4,  // on EOF, reduce `Name+ = Name => ActionFn(10);`
+
+        // State 9
+        -7,  // on EOF, reduce `Rule = Name, ":", Name+ => ActionFn(13);`
+
+        // State 10
+        -1,  // on EOF, reduce `Name = r#"[a-z]+"# => ActionFn(3);`
+
+        // State 11
+        -5,  // on EOF, reduce `Name+ = Name+, Name => ActionFn(11);`
+
+    ];
+    const __GOTO: &'static [u8] = &[
+        // State 0
+        2,  // on Name, goto 1
+        0,  // on Name*, error
+        0,  // on Name+, error
+        3,  // on Rule, goto 2
+        0,  // on Rule*, error
+        4,  // on Rule+, goto 3
+        5,  // on Rules, goto 4
+        0,  // on __Rules, error
+
+        // State 1
+        0,  // on Name, error
+        0,  // on Name*, error
+        0,  // on Name+, error
+        0,  // on Rule, error
+        0,  // on Rule*, error
+        0,  // on Rule+, error
+        0,  // on Rules, error
+        0,  // on __Rules, error
+
+        // State 2
+        0,  // on Name, error
+        0,  // on Name*, error
+        0,  // on Name+, error
+        0,  // on Rule, error
+        0,  // on Rule*, error
+        0,  // on Rule+, error
+        0,  // on Rules, error
+        0,  // on __Rules, error
+
+        // State 3
+        2,  // on Name, goto 1
+        0,  // on Name*, error
+        0,  // on Name+, error
+        8,  // on Rule, goto 7
+        0,  // on Rule*, error
+        0,  // on Rule+, error
+        0,  // on Rules, error
+        0,  // on __Rules, error
+
+        // State 4
+        0,  // on Name, error
+        0,  // on Name*, error
+        0,  // on Name+, error
+        0,  // on Rule, error
+        0,  // on Rule*, error
+        0,  // on Rule+, error
+        0,  // on Rules, error
+        0,  // on __Rules, error
+
+        // State 5
+        0,  // on Name, error
+        0,  // on Name*, error
+        0,  // on Name+, error
+        0,  // on Rule, error
+        0,  // on Rule*, error
+        0,  // on Rule+, error
+        0,  // on Rules, error
+        0,  // on __Rules, error
+
+        // State 6
+        9,  // on Name, goto 8
+        0,  // on Name*, error
+        10,  // on Name+, goto 9
+        0,  // on Rule, error
+        0,  // on Rule*, error
+        0,  // on Rule+, error
+        0,  // on Rules, error
+        0,  // on __Rules, error
+
+        // State 7
+        0,  // on Name, error
+        0,  // on Name*, error
+        0,  // on Name+, error
+        0,  // on Rule, error
+        0,  // on Rule*, error
+        0,  // on Rule+, error
+        0,  // on Rules, error
+        0,  // on __Rules, error
+
+        // State 8
+        0,  // on Name, error
+        0,  // on Name*, error
+        0,  // on Name+, error
+        0,  // on Rule, error
+        0,  // on Rule*, error
+        0,  // on Rule+, error
+        0,  // on Rules, error
+        0,  // on __Rules, error
+
+        // State 9
+        12,  // on Name, goto 11
+        0,  // on Name*, error
+        0,  // on Name+, error
+        0,  // on Rule, error
+        0,  // on Rule*, error
+        0,  // on Rule+, error
+        0,  // on Rules, error
+        0,  // on __Rules, error
+
+        // State 10
+        0,  // on Name, error
+        0,  // on Name*, error
+        0,  // on Name+, error
+        0,  // on Rule, error
+        0,  // on Rule*, error
+        0,  // on Rule+, error
+        0,  // on Rules, error
+        0,  // on __Rules, error
+
+        // State 11
+        0,  // on Name, error
+        0,  // on Name*, error
+        0,  // on Name+, error
+        0,  // on Rule, error
+        0,  // on Rule*, error
+        0,  // on Rule+, error
+        0,  // on Rules, error
+        0,  // on __Rules, error
+
+    ];
+    const __DEFAULT_ACTION: &'static [i8] = &[
+        // State 0
+        0,  // no default reduction
+
+        // State 1
+        0,  // no default reduction
+
+        // State 2
+        -10,  // on any token, reduce `Rule+ = Rule => ActionFn(8);`
+
+        // State 3
+        0,  // no default reduction
+
+        // State 4
+        0,  // no default reduction
+
+        // State 5
+        -1,  // on any token, reduce `Name = r#"[a-z]+"# => ActionFn(3);`
+
+        // State 6
+        0,  // no default reduction
+
+        // State 7
+        -11,  // on any token, reduce `Rule+ = Rule+, Rule => ActionFn(9);`
+
+        // State 8
+        -4,  // on any token, reduce `Name+ = Name => ActionFn(10);`
+
+        // State 9
+        0,  // no default reduction
+
+        // State 10
+        -1,  // on any token, reduce `Name = r#"[a-z]+"# => ActionFn(3);`
+
+        // State 11
+        -5,  // on any token, reduce `Name+ = Name+, Name => ActionFn(11);`
+
+    ];
+    fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+        const __TERMINAL: &'static [&'static str] = &[
+            r###"":""###,
+            r###"r#"[a-z]+"#"###,
+        ];
+        __ACTION[(__state * 2)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+            if state == 0 {
+                None
+            } else {
+                Some(terminal.to_string())
+            }
+        }).collect()
+    }
+    #[allow(dead_code)]
+    pub fn parse_Rules<
+        'input,
+    >(
+        input: &'input str,
+    ) -> Result<::std::vec::Vec<(&'input str, Vec<&'input str>)>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let mut __tokens = super::__intern_token::__Matcher::new(input);
+        let mut __states = vec![];
+        let mut __symbols = vec![];
+        __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Rules(__v) => Ok(__v), _ => unreachable!() })
+    }
+    #[allow(dead_code)]
+    pub fn parse_Rules_cancelable<
+        'input,
+    >(
+        input: &'input str,
+        __cancel: &::std::sync::atomic::AtomicBool,
+    ) -> Result<::std::vec::Vec<(&'input str, Vec<&'input str>)>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let mut __tokens = super::__intern_token::__Matcher::new(input);
+        let mut __states = vec![];
+        let mut __symbols = vec![];
+        __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Rules(__v) => Ok(__v), _ => unreachable!() })
+    }
+
+    #[allow(dead_code)]
+    pub struct RulesParser {
+        __states: ::std::vec::Vec<i32>,
+        __symbols: __lalrpop_util::stack::Recycled,
+        __cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+    }
+
+    #[allow(dead_code)]
+    impl RulesParser {
+        pub fn new() -> RulesParser {
+            RulesParser { __states: vec![], __symbols: __lalrpop_util::stack::Recycled::new(), __cancel: None }
+        }
+
+        pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+            self.__cancel = Some(flag);
+        }
+
+        pub fn parse_in_place<'input>(
+            &mut self,
+            input: &'input str,
+        ) -> Result<::std::vec::Vec<(&'input str, Vec<&'input str>)>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+        {
+            let mut __tokens = super::__intern_token::__Matcher::new(input);
+            let mut __symbols = self.__symbols.take();
+            let __result = __parse::<>(input, 0, &mut __tokens, &mut self.__states, &mut __symbols, self.__cancel.as_ref().map(|c| &**c)).and_then(|__v| match __v { __Symbol::Nt____Rules(__v) => Ok(__v), _ => unreachable!() });
+            self.__symbols.put(__symbols);
+            __result
+        }
+    }
+
+    fn __parse<
+        'input,
+    >(
+        input: &'input str,
+        __start_state: i32,
+        mut __tokens: &mut Iterator<Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+        mut __states: &mut ::std::vec::Vec<i32>,
+        mut __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        __cancel: Option<&::std::sync::atomic::AtomicBool>,
+    ) -> Result<__Symbol<'input>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        __states.clear();
+        __states.push(__start_state);
+        __symbols.clear();
+        let mut __integer;
+        let mut __lookahead;
+        let mut __peeked = None;
+        let __last_location: &mut usize = &mut Default::default();
+        let mut __default_reduced = [0_usize; 16];
+        let mut __default_reduced_len = 0;
+        '__shift: loop {
+            loop {
+                let __state = *__states.last().unwrap() as usize;
+                let __action = __DEFAULT_ACTION[__state] as i32;
+                if __action == 0 {
+                    break;
+                }
+                if __default_reduced_len < 16 {
+                    __default_reduced[__default_reduced_len] = __state;
+                    __default_reduced_len += 1;
+                }
+                if let Some(__flag) = __cancel {
+                    if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                        return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                    }
+                }
+                if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                    return r;
+                }
+            }
+            __lookahead = match __peeked.take().unwrap_or_else(|| __tokens.next()) {
+                Some(Ok(v)) => v,
+                None => break '__shift,
+                Some(Err(e)) => return Err(e),
+            };
+            *__last_location = __lookahead.2.clone();
+            __integer = match __lookahead.1 {
+                Token(1, _) if true => 0,
+                Token(0, _) if true => 1,
+                _ => {
+                    let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| true).unwrap_or(*__states.last().unwrap() as usize);
+                    let __expected = __expected_tokens(__state);
+                    let __error = match Some(__lookahead) {
+                        Some(__token) => {
+                            __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                        }
+                        None => {
+                            __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                        }
+                    };
+                    return Err(__error);
+                }
+            };
+            '__inner: loop {
+                let __state = *__states.last().unwrap() as usize;
+                let __action = __ACTION[__state * 2 + __integer] as i32;
+                let __action = match (__state, __integer) {
+                    (6, 1) | (9, 1) => {
+                        if __peeked.is_none() {
+                            __peeked = Some(__tokens.next());
+                        }
+                        let __following = match __peeked {
+                            Some(Some(Ok(ref __next))) => match __next.1 {
+                                Token(1, _) if true => 0,
+                                Token(0, _) if true => 1,
+                                _ => 3,
+                            },
+                            Some(None) => 2,
+                            _ => 3,
+                        };
+                        match (__state, __integer, __following) {
+                            (6, 1, 0) => -6,
+                            (9, 1, 0) => -7,
+                            _ => __action,
+                        }
+                    }
+                    _ => __action,
+                };
+                if __action > 0 {
+                    if let Some(__flag) = __cancel {
+                        if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                            return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                        }
+                    }
+                    let __symbol = match __integer {
+                        0 => match __lookahead.1 {
+                            Token(1, __tok0) => __Symbol::Term_22_3a_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        1 => match __lookahead.1 {
+                            Token(0, __tok0) => __Symbol::Termr_23_22_5ba_2dz_5d_2b_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        _ => unreachable!(),
+                    };
+                    __states.push(__action - 1);
+                    __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                    __default_reduced_len = 0;
+                    continue '__shift;
+                } else if __action < 0 {
+                    if let Some(__flag) = __cancel {
+                        if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                            return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                        }
+                    }
+                    if let Some(r) = __reduce(input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                        if r.is_err() {
+                            return r;
+                        }
+                        return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] });
+                    }
+                } else {
+                    let mut __err_lookahead = Some(__lookahead);
+                    let mut __err_integer: Option<usize> = Some(__integer);
+                    let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 2 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                    let __expected = __expected_tokens(__state);
+                    let __error = match __err_lookahead {
+                        Some(__token) => {
+                            if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                            } else {
+                                __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                            }
+                        }
+                        None => {
+                            __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                        }
+                    };
+                    return Err(__error)
+                }
+            }
+        }
+        loop {
+            let __state = *__states.last().unwrap() as usize;
+            let __action = __EOF_ACTION[__state] as i32;
+            if __action < 0 {
+                if let Some(__flag) = __cancel {
+                    if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                        return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                    }
+                }
+                if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                    return r;
+                }
+            } else {
+                let mut __err_lookahead = None;
+                let mut __err_integer: Option<usize> = None;
+                let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 2 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                let __expected = __expected_tokens(__state);
+                let __error = match __err_lookahead {
+                    Some(__token) => {
+                        if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                            __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                        } else {
+                            __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                        }
+                    }
+                    None => {
+                        __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                    }
+                };
+                return Err(__error)
+            }
+        }
+    }
+    fn __accepts<
+        'input,
+    >(
+        input: &'input str,
+        __error_state: i32,
+        __states: & [i32],
+        __opt_integer: Option<usize>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> bool
+    {
+        let mut __states = __states.to_vec();
+        __states.push(__error_state);
+        loop {
+            let mut __states_len = __states.len();
+            let __top = __states[__states_len - 1];
+            let __action = match __opt_integer {
+                None => __EOF_ACTION[__top as usize] as i32,
+                Some(__integer) => __ACTION[(__top * 2) as usize + __integer] as i32,
+            };
+            if __action == 0 { return false; }
+            if __action > 0 { return true; }
+            let (__to_pop, __nt) = match -__action {
+                // simulate Name = r#"[a-z]+"# => ActionFn(3);
+                1 => {
+                    (1, 0)
+                }
+                // simulate Name* =  => ActionFn(4);
+                2 => {
+                    (0, 1)
+                }
+                // simulate Name* = Name+ => ActionFn(5);
+                3 => {
+                    (1, 1)
+                }
+                // simulate Name+ = Name => ActionFn(10);
+                4 => {
+                    (1, 2)
+                }
+                // simulate Name+ = Name+, Name => ActionFn(11);
+                5 => {
+                    (2, 2)
+                }
+                // simulate Rule = Name, ":" => ActionFn(12);
+                6 => {
+                    (2, 3)
+                }
+                // simulate Rule = Name, ":", Name+ => ActionFn(13);
+                7 => {
+                    (3, 3)
+                }
+                // simulate Rule* =  => ActionFn(6);
+                8 => {
+                    (0, 4)
+                }
+                // simulate Rule* = Rule+ => ActionFn(7);
+                9 => {
+                    (1, 4)
+                }
+                // simulate Rule+ = Rule => ActionFn(8);
+                10 => {
+                    (1, 5)
+                }
+                // simulate Rule+ = Rule+, Rule => ActionFn(9);
+                11 => {
+                    (2, 5)
+                }
+                // simulate Rules =  => ActionFn(14);
+                12 => {
+                    (0, 6)
+                }
+                // simulate Rules = Rule+ => ActionFn(15);
+                13 => {
+                    (1, 6)
+                }
+                // simulate __Rules = Rules => ActionFn(0);
+                14 => return true,
+                _ => panic!("invalid action code {}", __action)
+            };
+            __states_len -= __to_pop;
+            __states.truncate(__states_len);
+            let __top = __states[__states_len - 1];
+            let __next_state = __GOTO[(__top * 8 + __nt) as usize] as i32 - 1;
+            __states.push(__next_state);
+        }
+    }
+    pub fn __reduce<
+        'input,
+    >(
+        input: &'input str,
+        __action: i32,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Option<Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        let __nonterminal = match -__action {
+            1 => match __reduce_Name_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            2 => match __reduce_Name_2a_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            3 => match __reduce_Name_2a_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            4 => match __reduce_Name_2b_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            5 => match __reduce_Name_2b_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            6 => match __reduce_Rule_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            7 => match __reduce_Rule_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            8 => match __reduce_Rule_2a_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            9 => match __reduce_Rule_2a_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            10 => match __reduce_Rule_2b_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            11 => match __reduce_Rule_2b_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            12 => match __reduce_Rules_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            13 => match __reduce_Rules_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            14 => match __reduce_____Rules_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                Ok(v) => v,
+                Err(r) => return Some(r),
+            },
+            _ => panic!("invalid action code {}", __action)
+        };
+        let __state = *__states.last().unwrap() as usize;
+        let __next_state = __GOTO[__state * 8 + __nonterminal] as i32 - 1;
+        __states.push(__next_state);
+        None
+    }
+
+    fn __reduce_Name_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Name = r#"[a-z]+"# => ActionFn(3);
+        let __sym0 = __pop_Termr_23_22_5ba_2dz_5d_2b_22_23(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action3::<>(input, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtName(__nt), __end));
+        Ok(0)
+    }
+
+    fn __reduce_Name_2a_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Name* =  => ActionFn(4);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action4::<>(input, &__start, &__end);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 0);
+        __symbols.push((__start, __Symbol::NtName_2a(__nt), __end));
+        Ok(1)
+    }
+
+    fn __reduce_Name_2a_1<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Name* = Name+ => ActionFn(5);
+        let __sym0 = __pop_NtName_2b(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action5::<>(input, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtName_2a(__nt), __end));
+        Ok(1)
+    }
+
+    fn __reduce_Name_2b_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Name+ = Name => ActionFn(10);
+        let __sym0 = __pop_NtName(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action10::<>(input, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtName_2b(__nt), __end));
+        Ok(2)
+    }
+
+    fn __reduce_Name_2b_1<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Name+ = Name+, Name => ActionFn(11);
+        let __sym1 = __pop_NtName(__symbols);
+        let __sym0 = __pop_NtName_2b(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action11::<>(input, __sym0, __sym1);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 2);
+        __symbols.push((__start, __Symbol::NtName_2b(__nt), __end));
+        Ok(2)
+    }
+
+    fn __reduce_Rule_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Rule = Name, ":" => ActionFn(12);
+        let __sym1 = __pop_Term_22_3a_22(__symbols);
+        let __sym0 = __pop_NtName(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action12::<>(input, __sym0, __sym1);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 2);
+        __symbols.push((__start, __Symbol::NtRule(__nt), __end));
+        Ok(3)
+    }
+
+    fn __reduce_Rule_1<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Rule = Name, ":", Name+ => ActionFn(13);
+        let __sym2 = __pop_NtName_2b(__symbols);
+        let __sym1 = __pop_Term_22_3a_22(__symbols);
+        let __sym0 = __pop_NtName(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action13::<>(input, __sym0, __sym1, __sym2);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 3);
+        __symbols.push((__start, __Symbol::NtRule(__nt), __end));
+        Ok(3)
+    }
+
+    fn __reduce_Rule_2a_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Rule* =  => ActionFn(6);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action6::<>(input, &__start, &__end);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 0);
+        __symbols.push((__start, __Symbol::NtRule_2a(__nt), __end));
+        Ok(4)
+    }
+
+    fn __reduce_Rule_2a_1<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Rule* = Rule+ => ActionFn(7);
+        let __sym0 = __pop_NtRule_2b(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action7::<>(input, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtRule_2a(__nt), __end));
+        Ok(4)
+    }
+
+    fn __reduce_Rule_2b_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Rule+ = Rule => ActionFn(8);
+        let __sym0 = __pop_NtRule(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action8::<>(input, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtRule_2b(__nt), __end));
+        Ok(5)
+    }
+
+    fn __reduce_Rule_2b_1<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Rule+ = Rule+, Rule => ActionFn(9);
+        let __sym1 = __pop_NtRule(__symbols);
+        let __sym0 = __pop_NtRule_2b(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action9::<>(input, __sym0, __sym1);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 2);
+        __symbols.push((__start, __Symbol::NtRule_2b(__nt), __end));
+        Ok(5)
+    }
+
+    fn __reduce_Rules_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Rules =  => ActionFn(14);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action14::<>(input, &__start, &__end);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 0);
+        __symbols.push((__start, __Symbol::NtRules(__nt), __end));
+        Ok(6)
+    }
+
+    fn __reduce_Rules_1<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // Rules = Rule+ => ActionFn(15);
+        let __sym0 = __pop_NtRule_2b(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action15::<>(input, __sym0);
+        let __states_len = __states.len();
+        __states.truncate(__states_len - 1);
+        __symbols.push((__start, __Symbol::NtRules(__nt), __end));
+        Ok(6)
+    }
+
+    fn __reduce_____Rules_0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        // __Rules = Rules => ActionFn(0);
+        let __sym0 = __pop_NtRules(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action0::<>(input, __sym0);
+        Err(Ok(__Symbol::Nt____Rules(__nt)))
+    }
+    fn __pop_Term_22_3a_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3a_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_5ba_2dz_5d_2b_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_5ba_2dz_5d_2b_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtName<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtName(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtName_2a<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<&'input str>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtName_2a(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtName_2b<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<&'input str>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtName_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtRule<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (&'input str, Vec<&'input str>), usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtRule(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtRule_2a<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<(&'input str, Vec<&'input str>)>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtRule_2a(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtRule_2b<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<(&'input str, Vec<&'input str>)>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtRule_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtRules<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<(&'input str, Vec<&'input str>)>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtRules(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____Rules<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<(&'input str, Vec<&'input str>)>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Rules(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+}
+mod __intern_token {
+    #![allow(unused_imports)]
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    extern crate regex as __regex;
+    use std::fmt as __fmt;
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Token<'input>(pub usize, pub &'input str);
+    impl<'a> __fmt::Display for Token<'a> {
+        fn fmt(&self, formatter: &mut __fmt::Formatter) -> Result<(), __fmt::Error> {
+            __fmt::Display::fmt(self.1, formatter)
+        }
+    }
+
+    pub struct __Matcher<'input> {
+        text: &'input str,
+        consumed: usize,
+        regex_set: __regex::RegexSet,
+        regex_vec: Vec<__regex::Regex>,
+    }
+
+    impl<'input> __Matcher<'input> {
+        pub fn new(s: &'input str) -> __Matcher<'input> {
+            let __strs: &[&str] = &[
+                "^(?u:[a-z])+",
+                "^(?u::)",
+            ];
+            let __regex_set = __regex::RegexSet::new(__strs).unwrap();
+            let __regex_vec = vec![
+                __regex::Regex::new("^(?u:[a-z])+").unwrap(),
+                __regex::Regex::new("^(?u::)").unwrap(),
+            ];
+            __Matcher {
+                text: s,
+                consumed: 0,
+                regex_set: __regex_set,
+                regex_vec: __regex_vec,
+            }
+        }
+    }
+
+    impl<'input> Iterator for __Matcher<'input> {
+        type Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize,Token<'input>,&'static str>>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let __text = self.text.trim_left();
+            let __whitespace = self.text.len() - __text.len();
+            let __start_offset = self.consumed + __whitespace;
+            if __text.is_empty() {
+                self.text = __text;
+                self.consumed = __start_offset;
+                None
+            } else {
+                let __matches = self.regex_set.matches(__text);
+                if !__matches.matched_any() {
+                    Some(Err(__lalrpop_util::ParseError::InvalidToken {
+                        location: __start_offset,
+                    }))
+                } else {
+                    let mut __longest_match = 0;
+                    let mut __index = 0;
+                    for __i in 0 .. 2 {
+                        if __matches.matched(__i) {
+                            let __match = self.regex_vec[__i].find(__text).unwrap();
+                            let __len = __match.end();
+                            if __len >= __longest_match {
+                                __longest_match = __len;
+                                __index = __i;
+                            }
+                        }
+                    }
+                    let __result = &__text[..__longest_match];
+                    let __remaining = &__text[__longest_match..];
+                    let __end_offset = __start_offset + __longest_match;
+                    self.text = __remaining;
+                    self.consumed = __end_offset;
+                    Some(Ok((__start_offset, Token(__index, __result), __end_offset)))
+                }
+            }
+        }
+    }
+}
+pub use self::__intern_token::Token;
+
+#[allow(unused_variables)]
+fn __action0<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, ::std::vec::Vec<(&'input str, Vec<&'input str>)>, usize),
+) -> ::std::vec::Vec<(&'input str, Vec<&'input str>)>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, ::std::vec::Vec<(&'input str, Vec<&'input str>)>, usize),
+) -> ::std::vec::Vec<(&'input str, Vec<&'input str>)>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, __1, _): (usize, ::std::vec::Vec<&'input str>, usize),
+) -> (&'input str, Vec<&'input str>)
+{
+    (__0, __1)
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> &'input str
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::vec::Vec<&'input str>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<&'input str>, usize),
+) -> ::std::vec::Vec<&'input str>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::vec::Vec<(&'input str, Vec<&'input str>)>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<(&'input str, Vec<&'input str>)>, usize),
+) -> ::std::vec::Vec<(&'input str, Vec<&'input str>)>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, (&'input str, Vec<&'input str>), usize),
+) -> ::std::vec::Vec<(&'input str, Vec<&'input str>)>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action9<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<(&'input str, Vec<&'input str>)>, usize),
+    (_, e, _): (usize, (&'input str, Vec<&'input str>), usize),
+) -> ::std::vec::Vec<(&'input str, Vec<&'input str>)>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action10<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> ::std::vec::Vec<&'input str>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action11<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<&'input str>, usize),
+    (_, e, _): (usize, &'input str, usize),
+) -> ::std::vec::Vec<&'input str>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action12<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+) -> (&'input str, Vec<&'input str>)
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action4(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action2(
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action13<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, ::std::vec::Vec<&'input str>, usize),
+) -> (&'input str, Vec<&'input str>)
+{
+    let __start0 = __2.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action5(
+        input,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action2(
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action14<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::vec::Vec<(&'input str, Vec<&'input str>)>
+{
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action6(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action1(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action15<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::vec::Vec<(&'input str, Vec<&'input str>)>, usize),
+) -> ::std::vec::Vec<(&'input str, Vec<&'input str>)>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action7(
+        input,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action1(
+        input,
+        __temp0,
+    )
+}
+
+pub trait __ToTriple<'input, > {
+    type Error;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),Self::Error>;
+}
+
+impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize) {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        Ok(value)
+    }
+}
+impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        value
+    }
+}
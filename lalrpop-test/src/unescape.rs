@@ -0,0 +1,2439 @@
+// auto-generated: "lalrpop 0.15.0"
+use std::borrow::Cow;
+use super::backquoted_text;
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+
+mod __parse__Entries {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    use std::borrow::Cow;
+    use super::super::backquoted_text;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__intern_token::Token;
+    #[allow(dead_code)]
+    pub fn parse_Entries<
+        'input,
+    >(
+        input: &'input str,
+    ) -> Result<Vec<(&'input str, Cow<'input, str>)>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let __ascent = __ascent::parse_Entries(
+            input,
+        );
+        let __parse_table = __parse_table::parse_Entries(
+            input,
+        );
+        assert_eq!(__ascent, __parse_table);
+        return __ascent;
+    }
+    mod __ascent {
+
+        mod __parse__Entries {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use std::borrow::Cow;
+            use super::super::super::super::backquoted_text;
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__intern_token::Token;
+            #[allow(dead_code)]
+            pub fn parse_Entries<
+                'input,
+            >(
+                input: &'input str,
+            ) -> Result<Vec<(&'input str, Cow<'input, str>)>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let __last_location = ::std::cell::RefCell::new(Default::default());
+                let mut __tokens = __tokens.inspect(|t| if let Ok(ref t) = *t { *__last_location.borrow_mut() = t.2.clone(); });
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                let mut __extra = None;
+                let __result = __state0(input, &mut __tokens, __lookahead, &mut __extra, ::std::marker::PhantomData::<()>);
+                if let Some((__token, __expected)) = __extra {
+                    return Err(match __result {
+                        Ok(_) => __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected },
+                        Err(_) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                    });
+                }
+                match __result {
+                    Ok((Some(__lookahead), _)) => {
+                        Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] })
+                    }
+                    Ok((None, __Nonterminal::____Entries((_, __nt, _)))) => {
+                        Ok(__nt)
+                    }
+                    Ok(_) => unreachable!(),
+                    Err(__lalrpop_util::ParseError::UnrecognizedEof { expected: __expected, .. }) => {
+                        Err(__lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.borrow().clone(), expected: __expected })
+                    }
+                    Err(__error) => Err(__error),
+                }
+            }
+
+            #[allow(dead_code)]
+            pub enum __Nonterminal<'input>
+             {
+                _28_3cEntry_3e_20_22_2c_22_29((usize, (&'input str, Cow<'input, str>), usize)),
+                _28_3cEntry_3e_20_22_2c_22_29_2a((usize, ::std::vec::Vec<(&'input str, Cow<'input, str>)>, usize)),
+                _28_3cEntry_3e_20_22_2c_22_29_2b((usize, ::std::vec::Vec<(&'input str, Cow<'input, str>)>, usize)),
+                Entries((usize, Vec<(&'input str, Cow<'input, str>)>, usize)),
+                Entry((usize, (&'input str, Cow<'input, str>), usize)),
+                Value((usize, Cow<'input, str>, usize)),
+                ____Entries((usize, Vec<(&'input str, Cow<'input, str>)>, usize)),
+            }
+            impl<'input> ::std::fmt::Debug for __Nonterminal<'input>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Nonterminal::_28_3cEntry_3e_20_22_2c_22_29(..) => __f.write_str("(<Entry> \",\")"),
+                        __Nonterminal::_28_3cEntry_3e_20_22_2c_22_29_2a(..) => __f.write_str("(<Entry> \",\")*"),
+                        __Nonterminal::_28_3cEntry_3e_20_22_2c_22_29_2b(..) => __f.write_str("(<Entry> \",\")+"),
+                        __Nonterminal::Entries(..) => __f.write_str("Entries"),
+                        __Nonterminal::Entry(..) => __f.write_str("Entry"),
+                        __Nonterminal::Value(..) => __f.write_str("Value"),
+                        __Nonterminal::____Entries(..) => __f.write_str("__Entries"),
+                    }
+                }
+            }
+
+            // State 0
+            //     AllInputs = []
+            //     OptionalInputs = []
+            //     FixedInputs = []
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     (<Entry> ",")+ = (*) (<Entry> ",")+ Entry "," [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+            //     (<Entry> ",")+ = (*) Entry "," [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+            //     Entries = (*) (<Entry> ",")+ Entry [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+            //     Entries = (*) Entry [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+            //     Entry = (*) NAME "=" Value [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+            //     __Entries = (*) Entries [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+            //
+            //   NAME -> S4
+            //
+            //     (<Entry> ",")+ -> S1
+            //     Entries -> S2
+            //     Entry -> S3
+            fn __state0<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state4(input, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"NAME"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::_28_3cEntry_3e_20_22_2c_22_29_2b(__sym0) => {
+                            __result = try!(__state1(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Entries(__sym0) => {
+                            __result = try!(__state2(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Entry(__sym0) => {
+                            __result = try!(__state3(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 1
+            //     AllInputs = [(<Entry> ",")+]
+            //     OptionalInputs = []
+            //     FixedInputs = [(<Entry> ",")+]
+            //     WillPushLen = 1
+            //     WillPush = [Entry]
+            //     WillProduce = None
+            //
+            //     (<Entry> ",")+ = (<Entry> ",")+ (*) Entry "," [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+            //     Entries = (<Entry> ",")+ (*) Entry [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+            //     Entry = (*) NAME "=" Value [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+            //
+            //   NAME -> S4
+            //
+            //     Entry -> S5
+            fn __state1<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, ::std::vec::Vec<(&'input str, Cow<'input, str>)>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state4(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"NAME"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Entry(__sym1) => {
+                            __result = try!(__state5(input, __tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 2
+            //     AllInputs = [Entries]
+            //     OptionalInputs = []
+            //     FixedInputs = [Entries]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(__Entries)
+            //
+            //     __Entries = Entries (*) [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+            //
+            //   [EOF] -> __Entries = Entries => ActionFn(0);
+            //
+            fn __state2<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Vec<(&'input str, Cow<'input, str>)>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(input, __sym0);
+                        let __nt = __Nonterminal::____Entries((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(input, __sym0);
+                        let __nt = __Nonterminal::____Entries((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 3
+            //     AllInputs = [Entry]
+            //     OptionalInputs = []
+            //     FixedInputs = [Entry]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     (<Entry> ",")+ = Entry (*) "," [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+            //     Entries = Entry (*) [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+            //
+            //   "," -> S6
+            //   [EOF] -> Entries = Entry => ActionFn(13);
+            //
+            fn __state3<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, (&'input str, Cow<'input, str>), usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action13::<>(input, __sym0);
+                        let __nt = __Nonterminal::Entries((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"",""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action13::<>(input, __sym0);
+                        let __nt = __Nonterminal::Entries((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 4
+            //     AllInputs = [NAME]
+            //     OptionalInputs = []
+            //     FixedInputs = [NAME]
+            //     WillPushLen = 2
+            //     WillPush = ["=", Value]
+            //     WillProduce = Some(Entry)
+            //
+            //     Entry = NAME (*) "=" Value [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+            //
+            //   "=" -> S7
+            //
+            fn __state4<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""=""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 5
+            //     AllInputs = [(<Entry> ",")+, Entry]
+            //     OptionalInputs = []
+            //     FixedInputs = [(<Entry> ",")+, Entry]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     (<Entry> ",")+ = (<Entry> ",")+ Entry (*) "," [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+            //     Entries = (<Entry> ",")+ Entry (*) [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+            //
+            //   "," -> S8
+            //   [EOF] -> Entries = (<Entry> ",")+, Entry => ActionFn(14);
+            //
+            fn __state5<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, ::std::vec::Vec<(&'input str, Cow<'input, str>)>, usize),
+                __sym1: (usize, (&'input str, Cow<'input, str>), usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state8(input, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action14::<>(input, __sym0, __sym1);
+                        let __nt = __Nonterminal::Entries((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"",""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action14::<>(input, __sym0, __sym1);
+                        let __nt = __Nonterminal::Entries((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 6
+            //     AllInputs = [Entry, ","]
+            //     OptionalInputs = []
+            //     FixedInputs = [Entry, ","]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some((<Entry> ",")+)
+            //
+            //     (<Entry> ",")+ = Entry "," (*) [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+            //
+            //   [NAME] -> (<Entry> ",")+ = Entry, "," => ActionFn(11);
+            //
+            fn __state6<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, (&'input str, Cow<'input, str>), usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(2, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action11::<>(input, __sym0, __sym1);
+                        let __nt = __Nonterminal::_28_3cEntry_3e_20_22_2c_22_29_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"NAME"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 7
+            //     AllInputs = [NAME, "="]
+            //     OptionalInputs = []
+            //     FixedInputs = [NAME, "="]
+            //     WillPushLen = 1
+            //     WillPush = [Value]
+            //     WillProduce = Some(Entry)
+            //
+            //     Entry = NAME "=" (*) Value [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+            //     Value = (*) r#"`[^`]*`"# [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+            //     Value = (*) CHAR [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+            //     Value = (*) STRING [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+            //
+            //   r#"`[^`]*`"# -> S10
+            //   CHAR -> S11
+            //   STRING -> S12
+            //
+            //     Value -> S9
+            fn __state7<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(5, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, backquoted_text(__tok0), __loc2);
+                        __result = try!(__state10(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(4, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, __lalrpop_util::unescape::unescape(__tok0), __loc2);
+                        __result = try!(__state11(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(3, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, __lalrpop_util::unescape::unescape(__tok0), __loc2);
+                        __result = try!(__state12(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"r#"`[^`]*`"#"###.to_string(),
+                            r###"CHAR"###.to_string(),
+                            r###"STRING"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Value(__sym2) => {
+                            __result = try!(__state9(input, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 8
+            //     AllInputs = [(<Entry> ",")+, Entry, ","]
+            //     OptionalInputs = []
+            //     FixedInputs = [(<Entry> ",")+, Entry, ","]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some((<Entry> ",")+)
+            //
+            //     (<Entry> ",")+ = (<Entry> ",")+ Entry "," (*) [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+            //
+            //   [NAME] -> (<Entry> ",")+ = (<Entry> ",")+, Entry, "," => ActionFn(12);
+            //
+            fn __state8<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, ::std::vec::Vec<(&'input str, Cow<'input, str>)>, usize),
+                __sym1: (usize, (&'input str, Cow<'input, str>), usize),
+                __sym2: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(2, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action12::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::_28_3cEntry_3e_20_22_2c_22_29_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"NAME"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 9
+            //     AllInputs = [NAME, "=", Value]
+            //     OptionalInputs = []
+            //     FixedInputs = [NAME, "=", Value]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Entry)
+            //
+            //     Entry = NAME "=" Value (*) [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+            //
+            //   [",", EOF] -> Entry = NAME, "=", Value => ActionFn(2);
+            //
+            fn __state9<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, &'input str, usize),
+                __sym2: (usize, Cow<'input, str>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                match __lookahead {
+                    Some((_, Token(0, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action2::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Entry((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"",""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action2::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Entry((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 10
+            //     AllInputs = [r#"`[^`]*`"#]
+            //     OptionalInputs = []
+            //     FixedInputs = [r#"`[^`]*`"#]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Value)
+            //
+            //     Value = r#"`[^`]*`"# (*) [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+            //
+            //   [",", EOF] -> Value = r#"`[^`]*`"# => ActionFn(5);
+            //
+            fn __state10<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, ::std::borrow::Cow<'input, str>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(0, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action5::<>(input, __sym0);
+                        let __nt = __Nonterminal::Value((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"",""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action5::<>(input, __sym0);
+                        let __nt = __Nonterminal::Value((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 11
+            //     AllInputs = [CHAR]
+            //     OptionalInputs = []
+            //     FixedInputs = [CHAR]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Value)
+            //
+            //     Value = CHAR (*) [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+            //
+            //   [",", EOF] -> Value = CHAR => ActionFn(4);
+            //
+            fn __state11<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, ::std::borrow::Cow<'input, str>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(0, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action4::<>(input, __sym0);
+                        let __nt = __Nonterminal::Value((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"",""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action4::<>(input, __sym0);
+                        let __nt = __Nonterminal::Value((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 12
+            //     AllInputs = [STRING]
+            //     OptionalInputs = []
+            //     FixedInputs = [STRING]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Value)
+            //
+            //     Value = STRING (*) [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+            //
+            //   [",", EOF] -> Value = STRING => ActionFn(3);
+            //
+            fn __state12<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, ::std::borrow::Cow<'input, str>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<'input>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(0, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action3::<>(input, __sym0);
+                        let __nt = __Nonterminal::Value((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"",""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action3::<>(input, __sym0);
+                        let __nt = __Nonterminal::Value((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+        }
+        pub use self::__parse__Entries::parse_Entries;
+    }
+    mod __parse_table {
+
+        mod __parse_table {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use std::borrow::Cow;
+            use super::super::super::super::backquoted_text;
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__intern_token::Token;
+            #[allow(dead_code)]
+            pub enum __Symbol<'input>
+             {
+                Term_22_2c_22(&'input str),
+                Term_22_3d_22(&'input str),
+                Termr_23_22_60_5b_5e_60_5d_2a_60_22_23(::std::borrow::Cow<'input, str>),
+                TermCHAR(::std::borrow::Cow<'input, str>),
+                TermNAME(&'input str),
+                TermSTRING(::std::borrow::Cow<'input, str>),
+                Nt_28_3cEntry_3e_20_22_2c_22_29((&'input str, Cow<'input, str>)),
+                Nt_28_3cEntry_3e_20_22_2c_22_29_2a(::std::vec::Vec<(&'input str, Cow<'input, str>)>),
+                Nt_28_3cEntry_3e_20_22_2c_22_29_2b(::std::vec::Vec<(&'input str, Cow<'input, str>)>),
+                NtEntries(Vec<(&'input str, Cow<'input, str>)>),
+                NtEntry((&'input str, Cow<'input, str>)),
+                NtValue(Cow<'input, str>),
+                Nt____Entries(Vec<(&'input str, Cow<'input, str>)>),
+            }
+            impl<'input> ::std::fmt::Debug for __Symbol<'input>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Symbol::Term_22_2c_22(..) => __f.write_str("\",\""),
+                        __Symbol::Term_22_3d_22(..) => __f.write_str("\"=\""),
+                        __Symbol::Termr_23_22_60_5b_5e_60_5d_2a_60_22_23(..) => __f.write_str("r#\"`[^`]*`\"#"),
+                        __Symbol::TermCHAR(..) => __f.write_str("CHAR"),
+                        __Symbol::TermNAME(..) => __f.write_str("NAME"),
+                        __Symbol::TermSTRING(..) => __f.write_str("STRING"),
+                        __Symbol::Nt_28_3cEntry_3e_20_22_2c_22_29(..) => __f.write_str("(<Entry> \",\")"),
+                        __Symbol::Nt_28_3cEntry_3e_20_22_2c_22_29_2a(..) => __f.write_str("(<Entry> \",\")*"),
+                        __Symbol::Nt_28_3cEntry_3e_20_22_2c_22_29_2b(..) => __f.write_str("(<Entry> \",\")+"),
+                        __Symbol::NtEntries(..) => __f.write_str("Entries"),
+                        __Symbol::NtEntry(..) => __f.write_str("Entry"),
+                        __Symbol::NtValue(..) => __f.write_str("Value"),
+                        __Symbol::Nt____Entries(..) => __f.write_str("__Entries"),
+                    }
+                }
+            }
+            const __ACTION: &'static [i8] = &[
+                // State 0
+                //     (<Entry> ",")+ = (*) (<Entry> ",")+ Entry "," [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+                //     (<Entry> ",")+ = (*) Entry "," [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+                //     Entries = (*) (<Entry> ",")+ Entry [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+                //     Entries = (*) Entry [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+                //     Entry = (*) NAME "=" Value [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+                //     __Entries = (*) Entries [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+                0,  // on ",", error
+                0,  // on "=", error
+                0,  // on r#"`[^`]*`"#, error
+                0,  // on CHAR, error
+                5,  // on NAME, goto 4
+                0,  // on STRING, error
+
+                // State 1
+                //     (<Entry> ",")+ = (<Entry> ",")+ (*) Entry "," [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+                //     Entries = (<Entry> ",")+ (*) Entry [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+                //     Entry = (*) NAME "=" Value [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+                0,  // on ",", error
+                0,  // on "=", error
+                0,  // on r#"`[^`]*`"#, error
+                0,  // on CHAR, error
+                5,  // on NAME, goto 4
+                0,  // on STRING, error
+
+                // State 2
+                //     __Entries = Entries (*) [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+                0,  // on ",", error
+                0,  // on "=", error
+                0,  // on r#"`[^`]*`"#, error
+                0,  // on CHAR, error
+                0,  // on NAME, error
+                0,  // on STRING, error
+
+                // State 3
+                //     (<Entry> ",")+ = Entry (*) "," [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+                //     Entries = Entry (*) [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+                7,  // on ",", goto 6
+                0,  // on "=", error
+                0,  // on r#"`[^`]*`"#, error
+                0,  // on CHAR, error
+                0,  // on NAME, error
+                0,  // on STRING, error
+
+                // State 4
+                //     Entry = NAME (*) "=" Value [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+                0,  // on ",", error
+                8,  // on "=", goto 7
+                0,  // on r#"`[^`]*`"#, error
+                0,  // on CHAR, error
+                0,  // on NAME, error
+                0,  // on STRING, error
+
+                // State 5
+                //     (<Entry> ",")+ = (<Entry> ",")+ Entry (*) "," [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+                //     Entries = (<Entry> ",")+ Entry (*) [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+                9,  // on ",", goto 8
+                0,  // on "=", error
+                0,  // on r#"`[^`]*`"#, error
+                0,  // on CHAR, error
+                0,  // on NAME, error
+                0,  // on STRING, error
+
+                // State 6
+                //     (<Entry> ",")+ = Entry "," (*) [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+                0,  // on ",", error
+                0,  // on "=", error
+                0,  // on r#"`[^`]*`"#, error
+                0,  // on CHAR, error
+                -4,  // on NAME, reduce `(<Entry> ",")+ = Entry, "," => ActionFn(11);`
+                0,  // on STRING, error
+
+                // State 7
+                //     Entry = NAME "=" (*) Value [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+                //     Value = (*) r#"`[^`]*`"# [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+                //     Value = (*) CHAR [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+                //     Value = (*) STRING [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+                0,  // on ",", error
+                0,  // on "=", error
+                11,  // on r#"`[^`]*`"#, goto 10
+                12,  // on CHAR, goto 11
+                0,  // on NAME, error
+                13,  // on STRING, goto 12
+
+                // State 8
+                //     (<Entry> ",")+ = (<Entry> ",")+ Entry "," (*) [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+                0,  // on ",", error
+                0,  // on "=", error
+                0,  // on r#"`[^`]*`"#, error
+                0,  // on CHAR, error
+                -5,  // on NAME, reduce `(<Entry> ",")+ = (<Entry> ",")+, Entry, "," => ActionFn(12);`
+                0,  // on STRING, error
+
+                // State 9
+                //     Entry = NAME "=" Value (*) [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+                -8,  // on ",", reduce `Entry = NAME, "=", Value => ActionFn(2);`
+                0,  // on "=", error
+                0,  // on r#"`[^`]*`"#, error
+                0,  // on CHAR, error
+                0,  // on NAME, error
+                0,  // on STRING, error
+
+                // State 10
+                //     Value = r#"`[^`]*`"# (*) [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+                -11,  // on ",", reduce `Value = r#"`[^`]*`"# => ActionFn(5);`
+                0,  // on "=", error
+                0,  // on r#"`[^`]*`"#, error
+                0,  // on CHAR, error
+                0,  // on NAME, error
+                0,  // on STRING, error
+
+                // State 11
+                //     Value = CHAR (*) [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+                -10,  // on ",", reduce `Value = CHAR => ActionFn(4);`
+                0,  // on "=", error
+                0,  // on r#"`[^`]*`"#, error
+                0,  // on CHAR, error
+                0,  // on NAME, error
+                0,  // on STRING, error
+
+                // State 12
+                //     Value = STRING (*) [",", "=", r#"`[^`]*`"#, CHAR, NAME, STRING, EOF]
+                -9,  // on ",", reduce `Value = STRING => ActionFn(3);`
+                0,  // on "=", error
+                0,  // on r#"`[^`]*`"#, error
+                0,  // on CHAR, error
+                0,  // on NAME, error
+                0,  // on STRING, error
+
+            ];
+            const __EOF_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // on EOF, error
+
+                // State 1
+                0,  // on EOF, error
+
+                // State 2
+                -12,  // on EOF, reduce `__Entries = Entries => ActionFn(0);`
+
+                // State 3
+                -6,  // on EOF, reduce `Entries = Entry => ActionFn(13);`
+
+                // State 4
+                0,  // on EOF, error
+
+                // State 5
+                -7,  // on EOF, reduce `Entries = (<Entry> ",")+, Entry => ActionFn(14);`
+
+                // State 6
+                0,  // on EOF, error
+
+                // State 7
+                0,  // on EOF, error
+
+                // State 8
+                0,  // on EOF, error
+
+                // State 9
+                -8,  // on EOF, reduce `Entry = NAME, "=", Value => ActionFn(2);`
+
+                // State 10
+                -11,  // on EOF, reduce `Value = r#"`[^`]*`"# => ActionFn(5);`
+
+                // State 11
+                -10,  // on EOF, reduce `Value = CHAR => ActionFn(4);`
+
+                // State 12
+                -9,  // on EOF, reduce `Value = STRING => ActionFn(3);`
+
+            ];
+            const __GOTO: &'static [u8] = &[
+                // State 0
+                0,  // on (<Entry> ","), error
+                0,  // on (<Entry> ",")*, error
+                2,  // on (<Entry> ",")+, goto 1
+                3,  // on Entries, goto 2
+                4,  // on Entry, goto 3
+                0,  // on Value, error
+                0,  // on __Entries, error
+
+                // State 1
+                0,  // on (<Entry> ","), error
+                0,  // on (<Entry> ",")*, error
+                0,  // on (<Entry> ",")+, error
+                0,  // on Entries, error
+                6,  // on Entry, goto 5
+                0,  // on Value, error
+                0,  // on __Entries, error
+
+                // State 2
+                0,  // on (<Entry> ","), error
+                0,  // on (<Entry> ",")*, error
+                0,  // on (<Entry> ",")+, error
+                0,  // on Entries, error
+                0,  // on Entry, error
+                0,  // on Value, error
+                0,  // on __Entries, error
+
+                // State 3
+                0,  // on (<Entry> ","), error
+                0,  // on (<Entry> ",")*, error
+                0,  // on (<Entry> ",")+, error
+                0,  // on Entries, error
+                0,  // on Entry, error
+                0,  // on Value, error
+                0,  // on __Entries, error
+
+                // State 4
+                0,  // on (<Entry> ","), error
+                0,  // on (<Entry> ",")*, error
+                0,  // on (<Entry> ",")+, error
+                0,  // on Entries, error
+                0,  // on Entry, error
+                0,  // on Value, error
+                0,  // on __Entries, error
+
+                // State 5
+                0,  // on (<Entry> ","), error
+                0,  // on (<Entry> ",")*, error
+                0,  // on (<Entry> ",")+, error
+                0,  // on Entries, error
+                0,  // on Entry, error
+                0,  // on Value, error
+                0,  // on __Entries, error
+
+                // State 6
+                0,  // on (<Entry> ","), error
+                0,  // on (<Entry> ",")*, error
+                0,  // on (<Entry> ",")+, error
+                0,  // on Entries, error
+                0,  // on Entry, error
+                0,  // on Value, error
+                0,  // on __Entries, error
+
+                // State 7
+                0,  // on (<Entry> ","), error
+                0,  // on (<Entry> ",")*, error
+                0,  // on (<Entry> ",")+, error
+                0,  // on Entries, error
+                0,  // on Entry, error
+                10,  // on Value, goto 9
+                0,  // on __Entries, error
+
+                // State 8
+                0,  // on (<Entry> ","), error
+                0,  // on (<Entry> ",")*, error
+                0,  // on (<Entry> ",")+, error
+                0,  // on Entries, error
+                0,  // on Entry, error
+                0,  // on Value, error
+                0,  // on __Entries, error
+
+                // State 9
+                0,  // on (<Entry> ","), error
+                0,  // on (<Entry> ",")*, error
+                0,  // on (<Entry> ",")+, error
+                0,  // on Entries, error
+                0,  // on Entry, error
+                0,  // on Value, error
+                0,  // on __Entries, error
+
+                // State 10
+                0,  // on (<Entry> ","), error
+                0,  // on (<Entry> ",")*, error
+                0,  // on (<Entry> ",")+, error
+                0,  // on Entries, error
+                0,  // on Entry, error
+                0,  // on Value, error
+                0,  // on __Entries, error
+
+                // State 11
+                0,  // on (<Entry> ","), error
+                0,  // on (<Entry> ",")*, error
+                0,  // on (<Entry> ",")+, error
+                0,  // on Entries, error
+                0,  // on Entry, error
+                0,  // on Value, error
+                0,  // on __Entries, error
+
+                // State 12
+                0,  // on (<Entry> ","), error
+                0,  // on (<Entry> ",")*, error
+                0,  // on (<Entry> ",")+, error
+                0,  // on Entries, error
+                0,  // on Entry, error
+                0,  // on Value, error
+                0,  // on __Entries, error
+
+            ];
+            const __DEFAULT_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // no default reduction
+
+                // State 1
+                0,  // no default reduction
+
+                // State 2
+                0,  // no default reduction
+
+                // State 3
+                0,  // no default reduction
+
+                // State 4
+                0,  // no default reduction
+
+                // State 5
+                0,  // no default reduction
+
+                // State 6
+                -4,  // on any token, reduce `(<Entry> ",")+ = Entry, "," => ActionFn(11);`
+
+                // State 7
+                0,  // no default reduction
+
+                // State 8
+                -5,  // on any token, reduce `(<Entry> ",")+ = (<Entry> ",")+, Entry, "," => ActionFn(12);`
+
+                // State 9
+                -8,  // on any token, reduce `Entry = NAME, "=", Value => ActionFn(2);`
+
+                // State 10
+                -11,  // on any token, reduce `Value = r#"`[^`]*`"# => ActionFn(5);`
+
+                // State 11
+                -10,  // on any token, reduce `Value = CHAR => ActionFn(4);`
+
+                // State 12
+                -9,  // on any token, reduce `Value = STRING => ActionFn(3);`
+
+            ];
+            fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+                const __TERMINAL: &'static [&'static str] = &[
+                    r###"",""###,
+                    r###""=""###,
+                    r###"r#"`[^`]*`"#"###,
+                    r###"CHAR"###,
+                    r###"NAME"###,
+                    r###"STRING"###,
+                ];
+                __ACTION[(__state * 6)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+                    if state == 0 {
+                        None
+                    } else {
+                        Some(terminal.to_string())
+                    }
+                }).collect()
+            }
+            #[allow(dead_code)]
+            pub fn parse_Entries<
+                'input,
+            >(
+                input: &'input str,
+            ) -> Result<Vec<(&'input str, Cow<'input, str>)>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Entries(__v) => Ok(__v), _ => unreachable!() })
+            }
+            #[allow(dead_code)]
+            pub fn parse_Entries_cancelable<
+                'input,
+            >(
+                input: &'input str,
+                __cancel: &::std::sync::atomic::AtomicBool,
+            ) -> Result<Vec<(&'input str, Cow<'input, str>)>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Entries(__v) => Ok(__v), _ => unreachable!() })
+            }
+
+            #[allow(dead_code)]
+            pub struct EntriesParser {
+                __states: ::std::vec::Vec<i32>,
+                __symbols: __lalrpop_util::stack::Recycled,
+                __cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+            }
+
+            #[allow(dead_code)]
+            impl EntriesParser {
+                pub fn new() -> EntriesParser {
+                    EntriesParser { __states: vec![], __symbols: __lalrpop_util::stack::Recycled::new(), __cancel: None }
+                }
+
+                pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+                    self.__cancel = Some(flag);
+                }
+
+                pub fn parse_in_place<'input>(
+                    &mut self,
+                    input: &'input str,
+                ) -> Result<Vec<(&'input str, Cow<'input, str>)>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+                {
+                    let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                    let mut __symbols = self.__symbols.take();
+                    let __result = __parse::<>(input, 0, &mut __tokens, &mut self.__states, &mut __symbols, self.__cancel.as_ref().map(|c| &**c)).and_then(|__v| match __v { __Symbol::Nt____Entries(__v) => Ok(__v), _ => unreachable!() });
+                    self.__symbols.put(__symbols);
+                    __result
+                }
+            }
+
+            fn __parse<
+                'input,
+            >(
+                input: &'input str,
+                __start_state: i32,
+                mut __tokens: &mut Iterator<Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+                mut __states: &mut ::std::vec::Vec<i32>,
+                mut __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                __cancel: Option<&::std::sync::atomic::AtomicBool>,
+            ) -> Result<__Symbol<'input>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                __states.clear();
+                __states.push(__start_state);
+                __symbols.clear();
+                let mut __integer;
+                let mut __lookahead;
+                let __last_location: &mut usize = &mut Default::default();
+                let mut __default_reduced = [0_usize; 16];
+                let mut __default_reduced_len = 0;
+                '__shift: loop {
+                    loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __DEFAULT_ACTION[__state] as i32;
+                        if __action == 0 {
+                            break;
+                        }
+                        if __default_reduced_len < 16 {
+                            __default_reduced[__default_reduced_len] = __state;
+                            __default_reduced_len += 1;
+                        }
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    }
+                    __lookahead = match __tokens.next() {
+                        Some(Ok(v)) => v,
+                        None => break '__shift,
+                        Some(Err(e)) => return Err(e),
+                    };
+                    *__last_location = __lookahead.2.clone();
+                    __integer = match __lookahead.1 {
+                        Token(0, _) if true => 0,
+                        Token(1, _) if true => 1,
+                        Token(5, _) if true => 2,
+                        Token(4, _) if true => 3,
+                        Token(2, _) if true => 4,
+                        Token(3, _) if true => 5,
+                        _ => {
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| true).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match Some(__lookahead) {
+                                Some(__token) => {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error);
+                        }
+                    };
+                    '__inner: loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __ACTION[__state * 6 + __integer] as i32;
+                        if __action > 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                                }
+                            }
+                            let __symbol = match __integer {
+                                0 => match __lookahead.1 {
+                                    Token(0, __tok0) => __Symbol::Term_22_2c_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                1 => match __lookahead.1 {
+                                    Token(1, __tok0) => __Symbol::Term_22_3d_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                2 => match __lookahead.1 {
+                                    Token(5, __tok0) => __Symbol::Termr_23_22_60_5b_5e_60_5d_2a_60_22_23(backquoted_text(__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                3 => match __lookahead.1 {
+                                    Token(4, __tok0) => __Symbol::TermCHAR(__lalrpop_util::unescape::unescape(__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                4 => match __lookahead.1 {
+                                    Token(2, __tok0) => __Symbol::TermNAME((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                5 => match __lookahead.1 {
+                                    Token(3, __tok0) => __Symbol::TermSTRING(__lalrpop_util::unescape::unescape(__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                _ => unreachable!(),
+                            };
+                            __states.push(__action - 1);
+                            __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                            __default_reduced_len = 0;
+                            continue '__shift;
+                        } else if __action < 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                                }
+                            }
+                            if let Some(r) = __reduce(input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                                if r.is_err() {
+                                    return r;
+                                }
+                                return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] });
+                            }
+                        } else {
+                            let mut __err_lookahead = Some(__lookahead);
+                            let mut __err_integer: Option<usize> = Some(__integer);
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 6 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match __err_lookahead {
+                                Some(__token) => {
+                                    if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                        __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                    } else {
+                                        __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                    }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error)
+                        }
+                    }
+                }
+                loop {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __action = __EOF_ACTION[__state] as i32;
+                    if __action < 0 {
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    } else {
+                        let mut __err_lookahead = None;
+                        let mut __err_integer: Option<usize> = None;
+                        let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 6 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                        let __expected = __expected_tokens(__state);
+                        let __error = match __err_lookahead {
+                            Some(__token) => {
+                                if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                    __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                } else {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                            }
+                            None => {
+                                __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                            }
+                        };
+                        return Err(__error)
+                    }
+                }
+            }
+            fn __accepts<
+                'input,
+            >(
+                input: &'input str,
+                __error_state: i32,
+                __states: & [i32],
+                __opt_integer: Option<usize>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> bool
+            {
+                let mut __states = __states.to_vec();
+                __states.push(__error_state);
+                loop {
+                    let mut __states_len = __states.len();
+                    let __top = __states[__states_len - 1];
+                    let __action = match __opt_integer {
+                        None => __EOF_ACTION[__top as usize] as i32,
+                        Some(__integer) => __ACTION[(__top * 6) as usize + __integer] as i32,
+                    };
+                    if __action == 0 { return false; }
+                    if __action > 0 { return true; }
+                    let (__to_pop, __nt) = match -__action {
+                        // simulate (<Entry> ",") = Entry, "," => ActionFn(8);
+                        1 => {
+                            (2, 0)
+                        }
+                        // simulate (<Entry> ",")* =  => ActionFn(6);
+                        2 => {
+                            (0, 1)
+                        }
+                        // simulate (<Entry> ",")* = (<Entry> ",")+ => ActionFn(7);
+                        3 => {
+                            (1, 1)
+                        }
+                        // simulate (<Entry> ",")+ = Entry, "," => ActionFn(11);
+                        4 => {
+                            (2, 2)
+                        }
+                        // simulate (<Entry> ",")+ = (<Entry> ",")+, Entry, "," => ActionFn(12);
+                        5 => {
+                            (3, 2)
+                        }
+                        // simulate Entries = Entry => ActionFn(13);
+                        6 => {
+                            (1, 3)
+                        }
+                        // simulate Entries = (<Entry> ",")+, Entry => ActionFn(14);
+                        7 => {
+                            (2, 3)
+                        }
+                        // simulate Entry = NAME, "=", Value => ActionFn(2);
+                        8 => {
+                            (3, 4)
+                        }
+                        // simulate Value = STRING => ActionFn(3);
+                        9 => {
+                            (1, 5)
+                        }
+                        // simulate Value = CHAR => ActionFn(4);
+                        10 => {
+                            (1, 5)
+                        }
+                        // simulate Value = r#"`[^`]*`"# => ActionFn(5);
+                        11 => {
+                            (1, 5)
+                        }
+                        // simulate __Entries = Entries => ActionFn(0);
+                        12 => return true,
+                        _ => panic!("invalid action code {}", __action)
+                    };
+                    __states_len -= __to_pop;
+                    __states.truncate(__states_len);
+                    let __top = __states[__states_len - 1];
+                    let __next_state = __GOTO[(__top * 7 + __nt) as usize] as i32 - 1;
+                    __states.push(__next_state);
+                }
+            }
+            pub fn __reduce<
+                'input,
+            >(
+                input: &'input str,
+                __action: i32,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Option<Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                let __nonterminal = match -__action {
+                    1 => match __reduce__28_3cEntry_3e_20_22_2c_22_29_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    2 => match __reduce__28_3cEntry_3e_20_22_2c_22_29_2a_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    3 => match __reduce__28_3cEntry_3e_20_22_2c_22_29_2a_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    4 => match __reduce__28_3cEntry_3e_20_22_2c_22_29_2b_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    5 => match __reduce__28_3cEntry_3e_20_22_2c_22_29_2b_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    6 => match __reduce_Entries_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    7 => match __reduce_Entries_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    8 => match __reduce_Entry_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    9 => match __reduce_Value_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    10 => match __reduce_Value_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    11 => match __reduce_Value_2(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    12 => match __reduce_____Entries_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    _ => panic!("invalid action code {}", __action)
+                };
+                let __state = *__states.last().unwrap() as usize;
+                let __next_state = __GOTO[__state * 7 + __nonterminal] as i32 - 1;
+                __states.push(__next_state);
+                None
+            }
+
+            fn __reduce__28_3cEntry_3e_20_22_2c_22_29_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<Entry> ",") = Entry, "," => ActionFn(8);
+                let __sym1 = __pop_Term_22_2c_22(__symbols);
+                let __sym0 = __pop_NtEntry(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action8::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_3cEntry_3e_20_22_2c_22_29(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce__28_3cEntry_3e_20_22_2c_22_29_2a_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<Entry> ",")* =  => ActionFn(6);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action6::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_28_3cEntry_3e_20_22_2c_22_29_2a(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce__28_3cEntry_3e_20_22_2c_22_29_2a_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<Entry> ",")* = (<Entry> ",")+ => ActionFn(7);
+                let __sym0 = __pop_Nt_28_3cEntry_3e_20_22_2c_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action7::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::Nt_28_3cEntry_3e_20_22_2c_22_29_2a(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce__28_3cEntry_3e_20_22_2c_22_29_2b_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<Entry> ",")+ = Entry, "," => ActionFn(11);
+                let __sym1 = __pop_Term_22_2c_22(__symbols);
+                let __sym0 = __pop_NtEntry(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action11::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_3cEntry_3e_20_22_2c_22_29_2b(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce__28_3cEntry_3e_20_22_2c_22_29_2b_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<Entry> ",")+ = (<Entry> ",")+, Entry, "," => ActionFn(12);
+                let __sym2 = __pop_Term_22_2c_22(__symbols);
+                let __sym1 = __pop_NtEntry(__symbols);
+                let __sym0 = __pop_Nt_28_3cEntry_3e_20_22_2c_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action12::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::Nt_28_3cEntry_3e_20_22_2c_22_29_2b(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce_Entries_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Entries = Entry => ActionFn(13);
+                let __sym0 = __pop_NtEntry(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action13::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtEntries(__nt), __end));
+                Ok(3)
+            }
+
+            fn __reduce_Entries_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Entries = (<Entry> ",")+, Entry => ActionFn(14);
+                let __sym1 = __pop_NtEntry(__symbols);
+                let __sym0 = __pop_Nt_28_3cEntry_3e_20_22_2c_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action14::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtEntries(__nt), __end));
+                Ok(3)
+            }
+
+            fn __reduce_Entry_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Entry = NAME, "=", Value => ActionFn(2);
+                let __sym2 = __pop_NtValue(__symbols);
+                let __sym1 = __pop_Term_22_3d_22(__symbols);
+                let __sym0 = __pop_TermNAME(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action2::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtEntry(__nt), __end));
+                Ok(4)
+            }
+
+            fn __reduce_Value_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Value = STRING => ActionFn(3);
+                let __sym0 = __pop_TermSTRING(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action3::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtValue(__nt), __end));
+                Ok(5)
+            }
+
+            fn __reduce_Value_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Value = CHAR => ActionFn(4);
+                let __sym0 = __pop_TermCHAR(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action4::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtValue(__nt), __end));
+                Ok(5)
+            }
+
+            fn __reduce_Value_2<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Value = r#"`[^`]*`"# => ActionFn(5);
+                let __sym0 = __pop_Termr_23_22_60_5b_5e_60_5d_2a_60_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action5::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtValue(__nt), __end));
+                Ok(5)
+            }
+
+            fn __reduce_____Entries_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // __Entries = Entries => ActionFn(0);
+                let __sym0 = __pop_NtEntries(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action0::<>(input, __sym0);
+                Err(Ok(__Symbol::Nt____Entries(__nt)))
+            }
+            fn __pop_Term_22_2c_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2c_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_3d_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_3d_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Termr_23_22_60_5b_5e_60_5d_2a_60_22_23<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, ::std::borrow::Cow<'input, str>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Termr_23_22_60_5b_5e_60_5d_2a_60_22_23(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_TermCHAR<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, ::std::borrow::Cow<'input, str>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::TermCHAR(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_TermNAME<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::TermNAME(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_TermSTRING<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, ::std::borrow::Cow<'input, str>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::TermSTRING(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_28_3cEntry_3e_20_22_2c_22_29<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, (&'input str, Cow<'input, str>), usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_28_3cEntry_3e_20_22_2c_22_29(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_28_3cEntry_3e_20_22_2c_22_29_2a<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, ::std::vec::Vec<(&'input str, Cow<'input, str>)>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_28_3cEntry_3e_20_22_2c_22_29_2a(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_28_3cEntry_3e_20_22_2c_22_29_2b<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, ::std::vec::Vec<(&'input str, Cow<'input, str>)>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_28_3cEntry_3e_20_22_2c_22_29_2b(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtEntries<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Vec<(&'input str, Cow<'input, str>)>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtEntries(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtEntry<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, (&'input str, Cow<'input, str>), usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtEntry(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtValue<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Cow<'input, str>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtValue(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt____Entries<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Vec<(&'input str, Cow<'input, str>)>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt____Entries(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+        }
+        pub use self::__parse_table::parse_Entries;
+        pub use self::__parse_table::parse_Entries_cancelable;
+        pub use self::__parse_table::EntriesParser;
+    }
+    pub use self::__parse_table::parse_Entries_cancelable;
+    pub use self::__parse_table::EntriesParser;
+}
+pub use self::__parse__Entries::parse_Entries;
+pub use self::__parse__Entries::parse_Entries_cancelable;
+pub use self::__parse__Entries::EntriesParser;
+mod __intern_token {
+    #![allow(unused_imports)]
+    use std::borrow::Cow;
+    use super::backquoted_text;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    extern crate regex as __regex;
+    use std::fmt as __fmt;
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Token<'input>(pub usize, pub &'input str);
+    impl<'a> __fmt::Display for Token<'a> {
+        fn fmt(&self, formatter: &mut __fmt::Formatter) -> Result<(), __fmt::Error> {
+            __fmt::Display::fmt(self.1, formatter)
+        }
+    }
+
+    pub struct __Matcher<'input> {
+        text: &'input str,
+        consumed: usize,
+        regex_set: __regex::RegexSet,
+        regex_vec: Vec<__regex::Regex>,
+    }
+
+    impl<'input> __Matcher<'input> {
+        pub fn new(s: &'input str) -> __Matcher<'input> {
+            let __strs: &[&str] = &[
+                "^(?u:,)",
+                "^(?u:=)",
+                "^(?u:[a-z])+",
+                "^(?u:\")((?u:\\\\)(?u:.)|(?u:[\0-!\\#-\\[\\]-\u{10ffff}]))*(?u:\")",
+                "^(?u:')((?u:\\\\)(?u:.)|(?u:[\0-\\&\\(-\\[\\]-\u{10ffff}]))(?u:')",
+                "^(?u:`)(?u:[\0-_a-\u{10ffff}])*(?u:`)",
+            ];
+            let __regex_set = __regex::RegexSet::new(__strs).unwrap();
+            let __regex_vec = vec![
+                __regex::Regex::new("^(?u:,)").unwrap(),
+                __regex::Regex::new("^(?u:=)").unwrap(),
+                __regex::Regex::new("^(?u:[a-z])+").unwrap(),
+                __regex::Regex::new("^(?u:\")((?u:\\\\)(?u:.)|(?u:[\0-!\\#-\\[\\]-\u{10ffff}]))*(?u:\")").unwrap(),
+                __regex::Regex::new("^(?u:')((?u:\\\\)(?u:.)|(?u:[\0-\\&\\(-\\[\\]-\u{10ffff}]))(?u:')").unwrap(),
+                __regex::Regex::new("^(?u:`)(?u:[\0-_a-\u{10ffff}])*(?u:`)").unwrap(),
+            ];
+            __Matcher {
+                text: s,
+                consumed: 0,
+                regex_set: __regex_set,
+                regex_vec: __regex_vec,
+            }
+        }
+    }
+
+    impl<'input> Iterator for __Matcher<'input> {
+        type Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize,Token<'input>,&'static str>>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let __text = self.text.trim_left();
+            let __whitespace = self.text.len() - __text.len();
+            let __start_offset = self.consumed + __whitespace;
+            if __text.is_empty() {
+                self.text = __text;
+                self.consumed = __start_offset;
+                None
+            } else {
+                let __matches = self.regex_set.matches(__text);
+                if !__matches.matched_any() {
+                    Some(Err(__lalrpop_util::ParseError::InvalidToken {
+                        location: __start_offset,
+                    }))
+                } else {
+                    let mut __longest_match = 0;
+                    let mut __index = 0;
+                    for __i in 0 .. 6 {
+                        if __matches.matched(__i) {
+                            let __match = self.regex_vec[__i].find(__text).unwrap();
+                            let __len = __match.end();
+                            if __len >= __longest_match {
+                                __longest_match = __len;
+                                __index = __i;
+                            }
+                        }
+                    }
+                    let __result = &__text[..__longest_match];
+                    match __index {
+                        3 | 4 => {
+                            if let Err(__offset) = __lalrpop_util::unescape::check(__result) {
+                                return Some(Err(__lalrpop_util::ParseError::InvalidToken {
+                                    location: __start_offset + __offset,
+                                }));
+                            }
+                        }
+                        _ => { }
+                    }
+                    let __remaining = &__text[__longest_match..];
+                    let __end_offset = __start_offset + __longest_match;
+                    self.text = __remaining;
+                    self.consumed = __end_offset;
+                    Some(Ok((__start_offset, Token(__index, __result), __end_offset)))
+                }
+            }
+        }
+    }
+}
+pub use self::__intern_token::Token;
+
+#[allow(unused_variables)]
+fn __action0<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Vec<(&'input str, Cow<'input, str>)>, usize),
+) -> Vec<(&'input str, Cow<'input, str>)>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<(&'input str, Cow<'input, str>)>, usize),
+    (_, e, _): (usize, (&'input str, Cow<'input, str>), usize),
+) -> Vec<(&'input str, Cow<'input, str>)>
+{
+    //@ src/unescape.lalrpop:20:37 (5 lines)
+    {
+        let mut v = v;
+        v.push(e);
+        v
+    }
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, __1, _): (usize, Cow<'input, str>, usize),
+) -> (&'input str, Cow<'input, str>)
+{
+    (__0, __1)
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, ::std::borrow::Cow<'input, str>, usize),
+) -> Cow<'input, str>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, ::std::borrow::Cow<'input, str>, usize),
+) -> Cow<'input, str>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, ::std::borrow::Cow<'input, str>, usize),
+) -> Cow<'input, str>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::vec::Vec<(&'input str, Cow<'input, str>)>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<(&'input str, Cow<'input, str>)>, usize),
+) -> ::std::vec::Vec<(&'input str, Cow<'input, str>)>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, (&'input str, Cow<'input, str>), usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> (&'input str, Cow<'input, str>)
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action9<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, (&'input str, Cow<'input, str>), usize),
+) -> ::std::vec::Vec<(&'input str, Cow<'input, str>)>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action10<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<(&'input str, Cow<'input, str>)>, usize),
+    (_, e, _): (usize, (&'input str, Cow<'input, str>), usize),
+) -> ::std::vec::Vec<(&'input str, Cow<'input, str>)>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action11<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, (&'input str, Cow<'input, str>), usize),
+    __1: (usize, &'input str, usize),
+) -> ::std::vec::Vec<(&'input str, Cow<'input, str>)>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action8(
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action9(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action12<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::vec::Vec<(&'input str, Cow<'input, str>)>, usize),
+    __1: (usize, (&'input str, Cow<'input, str>), usize),
+    __2: (usize, &'input str, usize),
+) -> ::std::vec::Vec<(&'input str, Cow<'input, str>)>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action8(
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action10(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action13<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, (&'input str, Cow<'input, str>), usize),
+) -> Vec<(&'input str, Cow<'input, str>)>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action6(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action1(
+        input,
+        __temp0,
+        __0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action14<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::vec::Vec<(&'input str, Cow<'input, str>)>, usize),
+    __1: (usize, (&'input str, Cow<'input, str>), usize),
+) -> Vec<(&'input str, Cow<'input, str>)>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action7(
+        input,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action1(
+        input,
+        __temp0,
+        __1,
+    )
+}
+
+pub trait __ToTriple<'input, > {
+    type Error;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),Self::Error>;
+}
+
+impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize) {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        Ok(value)
+    }
+}
+impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        value
+    }
+}
@@ -0,0 +1,7316 @@
+// auto-generated: "lalrpop 0.15.0"
+use std::str::FromStr;
+use unparse_ast::{Expr, Op};
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+
+mod __parse__Expr {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    use std::str::FromStr;
+    use unparse_ast::{Expr, Op};
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__intern_token::Token;
+    #[allow(dead_code)]
+    pub fn parse_Expr<
+        'input,
+    >(
+        input: &'input str,
+    ) -> Result<Box<Expr>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+    {
+        let __ascent = __ascent::parse_Expr(
+            input,
+        );
+        let __parse_table = __parse_table::parse_Expr(
+            input,
+        );
+        assert_eq!(__ascent, __parse_table);
+        return __ascent;
+    }
+    mod __ascent {
+
+        mod __parse__Expr {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use std::str::FromStr;
+            use unparse_ast::{Expr, Op};
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__intern_token::Token;
+            #[allow(dead_code)]
+            pub fn parse_Expr<
+                'input,
+            >(
+                input: &'input str,
+            ) -> Result<Box<Expr>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let __last_location = ::std::cell::RefCell::new(Default::default());
+                let mut __tokens = __tokens.inspect(|t| if let Ok(ref t) = *t { *__last_location.borrow_mut() = t.2.clone(); });
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                let mut __extra = None;
+                let __result = __state0(input, &mut __tokens, __lookahead, &mut __extra, ::std::marker::PhantomData::<()>);
+                if let Some((__token, __expected)) = __extra {
+                    return Err(match __result {
+                        Ok(_) => __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected },
+                        Err(_) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                    });
+                }
+                match __result {
+                    Ok((Some(__lookahead), _)) => {
+                        Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] })
+                    }
+                    Ok((None, __Nonterminal::____Expr((_, __nt, _)))) => {
+                        Ok(__nt)
+                    }
+                    Ok(_) => unreachable!(),
+                    Err(__lalrpop_util::ParseError::UnrecognizedEof { expected: __expected, .. }) => {
+                        Err(__lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.borrow().clone(), expected: __expected })
+                    }
+                    Err(__error) => Err(__error),
+                }
+            }
+
+            #[allow(dead_code)]
+            pub enum __Nonterminal<>
+             {
+                _28_22_3a_22_20_3cIdent_3e_29((usize, String, usize)),
+                _28_22_3a_22_20_3cIdent_3e_29_3f((usize, ::std::option::Option<String>, usize)),
+                _28_3cExpr_3e_20_22_3b_22_29((usize, Box<Expr>, usize)),
+                _28_3cExpr_3e_20_22_3b_22_29_2a((usize, ::std::vec::Vec<Box<Expr>>, usize)),
+                _28_3cExpr_3e_20_22_3b_22_29_2b((usize, ::std::vec::Vec<Box<Expr>>, usize)),
+                AddOp((usize, Op, usize)),
+                Expr((usize, Box<Expr>, usize)),
+                Factor((usize, Box<Expr>, usize)),
+                Ident((usize, String, usize)),
+                MulOp((usize, Op, usize)),
+                Num((usize, i32, usize)),
+                Term((usize, Box<Expr>, usize)),
+                ____Expr((usize, Box<Expr>, usize)),
+            }
+            impl<> ::std::fmt::Debug for __Nonterminal<>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Nonterminal::_28_22_3a_22_20_3cIdent_3e_29(..) => __f.write_str("(\":\" <Ident>)"),
+                        __Nonterminal::_28_22_3a_22_20_3cIdent_3e_29_3f(..) => __f.write_str("(\":\" <Ident>)?"),
+                        __Nonterminal::_28_3cExpr_3e_20_22_3b_22_29(..) => __f.write_str("(<Expr> \";\")"),
+                        __Nonterminal::_28_3cExpr_3e_20_22_3b_22_29_2a(..) => __f.write_str("(<Expr> \";\")*"),
+                        __Nonterminal::_28_3cExpr_3e_20_22_3b_22_29_2b(..) => __f.write_str("(<Expr> \";\")+"),
+                        __Nonterminal::AddOp(..) => __f.write_str("AddOp"),
+                        __Nonterminal::Expr(..) => __f.write_str("Expr"),
+                        __Nonterminal::Factor(..) => __f.write_str("Factor"),
+                        __Nonterminal::Ident(..) => __f.write_str("Ident"),
+                        __Nonterminal::MulOp(..) => __f.write_str("MulOp"),
+                        __Nonterminal::Num(..) => __f.write_str("Num"),
+                        __Nonterminal::Term(..) => __f.write_str("Term"),
+                        __Nonterminal::____Expr(..) => __f.write_str("__Expr"),
+                    }
+                }
+            }
+
+            // State 0
+            //     AllInputs = []
+            //     OptionalInputs = []
+            //     FixedInputs = []
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Expr = (*) Expr AddOp Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = (*) Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = (*) "let" Ident ":" Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = (*) "let" Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Factor = (*) Factor MulOp Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Factor = (*) Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Ident = (*) r#"[a-z]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Num = (*) r#"[0-9]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Ident ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Ident "[" (<Expr> ";")+ "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Ident "[" "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Num ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) "-" Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     __Expr = (*) Expr ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "(" -> S6
+            //   "-" -> S7
+            //   "let" -> S8
+            //   r#"[0-9]+"# -> S9
+            //   r#"[a-z]+"# -> S10
+            //
+            //     Expr -> S1
+            //     Factor -> S2
+            //     Ident -> S3
+            //     Num -> S4
+            //     Term -> S5
+            fn __state0<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(input, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(13, __tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state8(input, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state9(input, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym0 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state10(input, __tokens, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""let""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                            r###"r#"[a-z]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Expr(__sym0) => {
+                            __result = try!(__state1(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Factor(__sym0) => {
+                            __result = try!(__state2(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Ident(__sym0) => {
+                            __result = try!(__state3(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Num(__sym0) => {
+                            __result = try!(__state4(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Term(__sym0) => {
+                            __result = try!(__state5(input, __tokens, __lookahead, __extra, __sym0, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 1
+            //     AllInputs = [Expr]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     AddOp = (*) "+" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     AddOp = (*) "-" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = Expr (*) AddOp Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     __Expr = Expr (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "+" -> S12
+            //   "-" -> S13
+            //   [EOF] -> __Expr = Expr => ActionFn(0);
+            //
+            //     AddOp -> S11
+            fn __state1<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Box<Expr>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, Token(5, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state12(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state13(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(input, __sym0);
+                        let __nt = __Nonterminal::____Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action0::<>(input, __sym0);
+                        let __nt = __Nonterminal::____Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::AddOp(__sym1) => {
+                            __result = try!(__state11(input, __tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 2
+            //     AllInputs = [Factor]
+            //     OptionalInputs = []
+            //     FixedInputs = [Factor]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Expr = Factor (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Factor = Factor (*) MulOp Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     MulOp = (*) "*" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     MulOp = (*) "/" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "*" -> S15
+            //   "/" -> S16
+            //   [")", "+", "-", ";", EOF] -> Expr = Factor => ActionFn(3);
+            //
+            //     MulOp -> S14
+            fn __state2<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Box<Expr>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, Token(4, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state15(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(7, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state16(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(9, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action3::<>(input, __sym0);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                            r###"";""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action3::<>(input, __sym0);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::MulOp(__sym1) => {
+                            __result = try!(__state14(input, __tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 3
+            //     AllInputs = [Ident]
+            //     OptionalInputs = []
+            //     FixedInputs = [Ident]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Term)
+            //
+            //     Term = Ident (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = Ident (*) "[" (<Expr> ";")+ "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = Ident (*) "[" "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "[" -> S17
+            //   [")", "*", "+", "-", "/", ";", EOF] -> Term = Ident => ActionFn(11);
+            //
+            fn __state3<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, String, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, Token(11, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state17(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(7, _), _)) |
+                    Some((_, Token(9, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action11::<>(input, __sym0);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                            r###"";""###.to_string(),
+                            r###""[""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action11::<>(input, __sym0);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 4
+            //     AllInputs = [Num]
+            //     OptionalInputs = []
+            //     FixedInputs = [Num]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Term)
+            //
+            //     Term = Num (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   [")", "*", "+", "-", "/", ";", EOF] -> Term = Num => ActionFn(10);
+            //
+            fn __state4<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, i32, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(7, _), _)) |
+                    Some((_, Token(9, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action10::<>(input, __sym0);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                            r###"";""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action10::<>(input, __sym0);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 5
+            //     AllInputs = [Term]
+            //     OptionalInputs = []
+            //     FixedInputs = [Term]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Factor)
+            //
+            //     Factor = Term (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   [")", "*", "+", "-", "/", ";", EOF] -> Factor = Term => ActionFn(7);
+            //
+            fn __state5<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Box<Expr>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(7, _), _)) |
+                    Some((_, Token(9, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action7::<>(input, __sym0);
+                        let __nt = __Nonterminal::Factor((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                            r###"";""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action7::<>(input, __sym0);
+                        let __nt = __Nonterminal::Factor((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 6
+            //     AllInputs = ["("]
+            //     OptionalInputs = []
+            //     FixedInputs = ["("]
+            //     WillPushLen = 2
+            //     WillPush = [Expr, ")"]
+            //     WillProduce = Some(Term)
+            //
+            //     Expr = (*) Expr AddOp Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = (*) Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = (*) "let" Ident ":" Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = (*) "let" Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Factor = (*) Factor MulOp Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Factor = (*) Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Ident = (*) r#"[a-z]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Num = (*) r#"[0-9]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Ident ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Ident "[" (<Expr> ";")+ "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Ident "[" "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Num ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = "(" (*) Expr ")" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) "-" Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "(" -> S6
+            //   "-" -> S7
+            //   "let" -> S8
+            //   r#"[0-9]+"# -> S9
+            //   r#"[a-z]+"# -> S10
+            //
+            //     Expr -> S18
+            //     Factor -> S2
+            //     Ident -> S3
+            //     Num -> S4
+            //     Term -> S5
+            fn __state6<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                let __sym0 = &mut Some(__sym0);
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(13, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state8(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state9(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state10(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""let""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                            r###"r#"[a-z]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    if __sym0.is_none() {
+                        return Ok(__result);
+                    }
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Expr(__sym1) => {
+                            __result = try!(__state18(input, __tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Factor(__sym1) => {
+                            __result = try!(__state2(input, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Ident(__sym1) => {
+                            __result = try!(__state3(input, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Num(__sym1) => {
+                            __result = try!(__state4(input, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Term(__sym1) => {
+                            __result = try!(__state5(input, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 7
+            //     AllInputs = ["-"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["-"]
+            //     WillPushLen = 1
+            //     WillPush = [Term]
+            //     WillProduce = Some(Term)
+            //
+            //     Ident = (*) r#"[a-z]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Num = (*) r#"[0-9]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Ident ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Ident "[" (<Expr> ";")+ "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Ident "[" "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Num ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) "-" Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = "-" (*) Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "(" -> S6
+            //   "-" -> S7
+            //   r#"[0-9]+"# -> S9
+            //   r#"[a-z]+"# -> S10
+            //
+            //     Ident -> S3
+            //     Num -> S4
+            //     Term -> S19
+            fn __state7<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state9(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state10(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                            r###"r#"[a-z]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Ident(__sym1) => {
+                            __result = try!(__state3(input, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Num(__sym1) => {
+                            __result = try!(__state4(input, __tokens, __lookahead, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Term(__sym1) => {
+                            __result = try!(__state19(input, __tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 8
+            //     AllInputs = ["let"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["let"]
+            //     WillPushLen = 4
+            //     WillPush = [Ident, "=", Expr, ";"]
+            //     WillProduce = Some(Expr)
+            //
+            //     Expr = "let" (*) Ident ":" Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = "let" (*) Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Ident = (*) r#"[a-z]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   r#"[a-z]+"# -> S10
+            //
+            //     Ident -> S20
+            fn __state8<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state10(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"r#"[a-z]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Ident(__sym1) => {
+                            __result = try!(__state20(input, __tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 9
+            //     AllInputs = [r#"[0-9]+"#]
+            //     OptionalInputs = []
+            //     FixedInputs = [r#"[0-9]+"#]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Num)
+            //
+            //     Num = r#"[0-9]+"# (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   [")", "*", "+", "-", "/", ";", EOF] -> Num = r#"[0-9]+"# => ActionFn(15);
+            //
+            fn __state9<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(7, _), _)) |
+                    Some((_, Token(9, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action15::<>(input, __sym0);
+                        let __nt = __Nonterminal::Num((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                            r###"";""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action15::<>(input, __sym0);
+                        let __nt = __Nonterminal::Num((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 10
+            //     AllInputs = [r#"[a-z]+"#]
+            //     OptionalInputs = []
+            //     FixedInputs = [r#"[a-z]+"#]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Ident)
+            //
+            //     Ident = r#"[a-z]+"# (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   [")", "*", "+", "-", "/", ":", ";", "=", "[", EOF] -> Ident = r#"[a-z]+"# => ActionFn(16);
+            //
+            fn __state10<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(7, _), _)) |
+                    Some((_, Token(8, _), _)) |
+                    Some((_, Token(9, _), _)) |
+                    Some((_, Token(10, _), _)) |
+                    Some((_, Token(11, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action16::<>(input, __sym0);
+                        let __nt = __Nonterminal::Ident((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                            r###"":""###.to_string(),
+                            r###"";""###.to_string(),
+                            r###""=""###.to_string(),
+                            r###""[""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action16::<>(input, __sym0);
+                        let __nt = __Nonterminal::Ident((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 11
+            //     AllInputs = [Expr, AddOp]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr, AddOp]
+            //     WillPushLen = 1
+            //     WillPush = [Factor]
+            //     WillProduce = Some(Expr)
+            //
+            //     Expr = Expr AddOp (*) Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Factor = (*) Factor MulOp Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Factor = (*) Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Ident = (*) r#"[a-z]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Num = (*) r#"[0-9]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Ident ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Ident "[" (<Expr> ";")+ "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Ident "[" "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Num ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) "-" Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "(" -> S6
+            //   "-" -> S7
+            //   r#"[0-9]+"# -> S9
+            //   r#"[a-z]+"# -> S10
+            //
+            //     Factor -> S21
+            //     Ident -> S3
+            //     Num -> S4
+            //     Term -> S5
+            fn __state11<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Box<Expr>, usize),
+                __sym1: (usize, Op, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __sym0 = &mut Some(__sym0);
+                let __sym1 = &mut Some(__sym1);
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state9(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state10(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                            r###"r#"[a-z]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    if __sym1.is_none() {
+                        return Ok(__result);
+                    }
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Factor(__sym2) => {
+                            __result = try!(__state21(input, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Ident(__sym2) => {
+                            __result = try!(__state3(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Num(__sym2) => {
+                            __result = try!(__state4(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Term(__sym2) => {
+                            __result = try!(__state5(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 12
+            //     AllInputs = ["+"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["+"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(AddOp)
+            //
+            //     AddOp = "+" (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   ["(", "-", r#"[0-9]+"#, r#"[a-z]+"#] -> AddOp = "+" => ActionFn(4);
+            //
+            fn __state12<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(2, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(0, _), _)) |
+                    Some((_, Token(1, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action4::<>(input, __sym0);
+                        let __nt = __Nonterminal::AddOp((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                            r###"r#"[a-z]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 13
+            //     AllInputs = ["-"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["-"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(AddOp)
+            //
+            //     AddOp = "-" (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   ["(", "-", r#"[0-9]+"#, r#"[a-z]+"#] -> AddOp = "-" => ActionFn(5);
+            //
+            fn __state13<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(2, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(0, _), _)) |
+                    Some((_, Token(1, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action5::<>(input, __sym0);
+                        let __nt = __Nonterminal::AddOp((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                            r###"r#"[a-z]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 14
+            //     AllInputs = [Factor, MulOp]
+            //     OptionalInputs = []
+            //     FixedInputs = [Factor, MulOp]
+            //     WillPushLen = 1
+            //     WillPush = [Term]
+            //     WillProduce = Some(Factor)
+            //
+            //     Factor = Factor MulOp (*) Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Ident = (*) r#"[a-z]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Num = (*) r#"[0-9]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Ident ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Ident "[" (<Expr> ";")+ "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Ident "[" "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Num ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) "-" Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "(" -> S6
+            //   "-" -> S7
+            //   r#"[0-9]+"# -> S9
+            //   r#"[a-z]+"# -> S10
+            //
+            //     Ident -> S3
+            //     Num -> S4
+            //     Term -> S22
+            fn __state14<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Box<Expr>, usize),
+                __sym1: (usize, Op, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state9(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state10(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                            r###"r#"[a-z]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Ident(__sym2) => {
+                            __result = try!(__state3(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Num(__sym2) => {
+                            __result = try!(__state4(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Term(__sym2) => {
+                            __result = try!(__state22(input, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 15
+            //     AllInputs = ["*"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["*"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(MulOp)
+            //
+            //     MulOp = "*" (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   ["(", "-", r#"[0-9]+"#, r#"[a-z]+"#] -> MulOp = "*" => ActionFn(8);
+            //
+            fn __state15<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(2, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(0, _), _)) |
+                    Some((_, Token(1, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action8::<>(input, __sym0);
+                        let __nt = __Nonterminal::MulOp((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                            r###"r#"[a-z]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 16
+            //     AllInputs = ["/"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["/"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(MulOp)
+            //
+            //     MulOp = "/" (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   ["(", "-", r#"[0-9]+"#, r#"[a-z]+"#] -> MulOp = "/" => ActionFn(9);
+            //
+            fn __state16<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(2, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(0, _), _)) |
+                    Some((_, Token(1, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym0.2.clone();
+                        let __nt = super::super::super::__action9::<>(input, __sym0);
+                        let __nt = __Nonterminal::MulOp((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                            r###"r#"[a-z]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 17
+            //     AllInputs = [Ident, "["]
+            //     OptionalInputs = []
+            //     FixedInputs = [Ident, "["]
+            //     WillPushLen = 1
+            //     WillPush = ["]"]
+            //     WillProduce = Some(Term)
+            //
+            //     (<Expr> ";")+ = (*) (<Expr> ";")+ Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     (<Expr> ";")+ = (*) Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = (*) Expr AddOp Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = (*) Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = (*) "let" Ident ":" Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = (*) "let" Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Factor = (*) Factor MulOp Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Factor = (*) Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Ident = (*) r#"[a-z]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Num = (*) r#"[0-9]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Ident ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Ident "[" (<Expr> ";")+ "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = Ident "[" (*) (<Expr> ";")+ "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Ident "[" "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = Ident "[" (*) "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Num ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) "-" Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "(" -> S6
+            //   "-" -> S7
+            //   "]" -> S25
+            //   "let" -> S8
+            //   r#"[0-9]+"# -> S9
+            //   r#"[a-z]+"# -> S10
+            //
+            //     (<Expr> ";")+ -> S23
+            //     Expr -> S24
+            //     Factor -> S2
+            //     Ident -> S3
+            //     Num -> S4
+            //     Term -> S5
+            fn __state17<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, String, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                let __sym0 = &mut Some(__sym0);
+                let __sym1 = &mut Some(__sym1);
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(12, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        let __sym0 = __sym0.take().unwrap();
+                        let __sym1 = __sym1.take().unwrap();
+                        __result = try!(__state25(input, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, Token(13, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state8(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state9(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state10(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""]""###.to_string(),
+                            r###""let""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                            r###"r#"[a-z]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    if __sym1.is_none() {
+                        return Ok(__result);
+                    }
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::_28_3cExpr_3e_20_22_3b_22_29_2b(__sym2) => {
+                            __result = try!(__state23(input, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Expr(__sym2) => {
+                            __result = try!(__state24(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Factor(__sym2) => {
+                            __result = try!(__state2(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Ident(__sym2) => {
+                            __result = try!(__state3(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Num(__sym2) => {
+                            __result = try!(__state4(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Term(__sym2) => {
+                            __result = try!(__state5(input, __tokens, __lookahead, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 18
+            //     AllInputs = ["(", Expr]
+            //     OptionalInputs = ["("]
+            //     FixedInputs = [Expr]
+            //     WillPushLen = 1
+            //     WillPush = [")"]
+            //     WillProduce = None
+            //
+            //     AddOp = (*) "+" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     AddOp = (*) "-" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = Expr (*) AddOp Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = "(" Expr (*) ")" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   ")" -> S26
+            //   "+" -> S12
+            //   "-" -> S13
+            //
+            //     AddOp -> S11
+            fn __state18<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: &mut Option<(usize, &'input str, usize)>,
+                __sym1: (usize, Box<Expr>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, Token(3, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        let __sym0 = __sym0.take().unwrap();
+                        __result = try!(__state26(input, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, Token(5, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state12(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state13(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::AddOp(__sym2) => {
+                            __result = try!(__state11(input, __tokens, __lookahead, __extra, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 19
+            //     AllInputs = ["-", Term]
+            //     OptionalInputs = []
+            //     FixedInputs = ["-", Term]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Term)
+            //
+            //     Term = "-" Term (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   [")", "*", "+", "-", "/", ";", EOF] -> Term = "-", Term => ActionFn(12);
+            //
+            fn __state19<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, Box<Expr>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(7, _), _)) |
+                    Some((_, Token(9, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action12::<>(input, __sym0, __sym1);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                            r###"";""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action12::<>(input, __sym0, __sym1);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 20
+            //     AllInputs = ["let", Ident]
+            //     OptionalInputs = []
+            //     FixedInputs = ["let", Ident]
+            //     WillPushLen = 3
+            //     WillPush = ["=", Expr, ";"]
+            //     WillProduce = Some(Expr)
+            //
+            //     Expr = "let" Ident (*) ":" Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = "let" Ident (*) "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   ":" -> S27
+            //   "=" -> S28
+            //
+            fn __state20<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, String, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, Token(8, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state27(input, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, Token(10, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state28(input, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"":""###.to_string(),
+                            r###""=""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 21
+            //     AllInputs = [Expr, AddOp, Factor]
+            //     OptionalInputs = [Expr, AddOp]
+            //     FixedInputs = [Factor]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = None
+            //
+            //     Expr = Expr AddOp Factor (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Factor = Factor (*) MulOp Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     MulOp = (*) "*" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     MulOp = (*) "/" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "*" -> S15
+            //   "/" -> S16
+            //   [")", "+", "-", ";", EOF] -> Expr = Expr, AddOp, Factor => ActionFn(1);
+            //
+            //     MulOp -> S14
+            fn __state21<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: &mut Option<(usize, Box<Expr>, usize)>,
+                __sym1: &mut Option<(usize, Op, usize)>,
+                __sym2: (usize, Box<Expr>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, Token(4, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state15(input, __tokens, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(7, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state16(input, __tokens, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(9, _), _)) |
+                    None => {
+                        let __sym0 = __sym0.take().unwrap();
+                        let __sym1 = __sym1.take().unwrap();
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action1::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                            r###"";""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __sym0 = __sym0.take().unwrap();
+                        let __sym1 = __sym1.take().unwrap();
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action1::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::MulOp(__sym3) => {
+                            __result = try!(__state14(input, __tokens, __lookahead, __extra, __sym2, __sym3, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 22
+            //     AllInputs = [Factor, MulOp, Term]
+            //     OptionalInputs = []
+            //     FixedInputs = [Factor, MulOp, Term]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Factor)
+            //
+            //     Factor = Factor MulOp Term (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   [")", "*", "+", "-", "/", ";", EOF] -> Factor = Factor, MulOp, Term => ActionFn(6);
+            //
+            fn __state22<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Box<Expr>, usize),
+                __sym1: (usize, Op, usize),
+                __sym2: (usize, Box<Expr>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(7, _), _)) |
+                    Some((_, Token(9, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action6::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Factor((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                            r###"";""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action6::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Factor((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 23
+            //     AllInputs = [Ident, "[", (<Expr> ";")+]
+            //     OptionalInputs = [Ident, "["]
+            //     FixedInputs = [(<Expr> ";")+]
+            //     WillPushLen = 1
+            //     WillPush = ["]"]
+            //     WillProduce = None
+            //
+            //     (<Expr> ";")+ = (<Expr> ";")+ (*) Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = (*) Expr AddOp Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = (*) Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = (*) "let" Ident ":" Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = (*) "let" Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Factor = (*) Factor MulOp Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Factor = (*) Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Ident = (*) r#"[a-z]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Num = (*) r#"[0-9]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Ident ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Ident "[" (<Expr> ";")+ "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = Ident "[" (<Expr> ";")+ (*) "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Ident "[" "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Num ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) "-" Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "(" -> S6
+            //   "-" -> S7
+            //   "]" -> S30
+            //   "let" -> S8
+            //   r#"[0-9]+"# -> S9
+            //   r#"[a-z]+"# -> S10
+            //
+            //     Expr -> S29
+            //     Factor -> S2
+            //     Ident -> S3
+            //     Num -> S4
+            //     Term -> S5
+            fn __state23<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: &mut Option<(usize, String, usize)>,
+                __sym1: &mut Option<(usize, &'input str, usize)>,
+                __sym2: (usize, ::std::vec::Vec<Box<Expr>>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __sym2 = &mut Some(__sym2);
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(input, __tokens, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(12, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        let __sym0 = __sym0.take().unwrap();
+                        let __sym1 = __sym1.take().unwrap();
+                        let __sym2 = __sym2.take().unwrap();
+                        __result = try!(__state30(input, __tokens, __extra, __sym0, __sym1, __sym2, __sym3, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    Some((__loc1, Token(13, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state8(input, __tokens, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state9(input, __tokens, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state10(input, __tokens, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""]""###.to_string(),
+                            r###""let""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                            r###"r#"[a-z]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    if __sym2.is_none() {
+                        return Ok(__result);
+                    }
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Expr(__sym3) => {
+                            __result = try!(__state29(input, __tokens, __lookahead, __extra, __sym2, __sym3, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Factor(__sym3) => {
+                            __result = try!(__state2(input, __tokens, __lookahead, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Ident(__sym3) => {
+                            __result = try!(__state3(input, __tokens, __lookahead, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Num(__sym3) => {
+                            __result = try!(__state4(input, __tokens, __lookahead, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Term(__sym3) => {
+                            __result = try!(__state5(input, __tokens, __lookahead, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 24
+            //     AllInputs = [Expr]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr]
+            //     WillPushLen = 1
+            //     WillPush = [";"]
+            //     WillProduce = None
+            //
+            //     (<Expr> ";")+ = Expr (*) ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     AddOp = (*) "+" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     AddOp = (*) "-" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = Expr (*) AddOp Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "+" -> S12
+            //   "-" -> S13
+            //   ";" -> S31
+            //
+            //     AddOp -> S11
+            fn __state24<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Box<Expr>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, Token(5, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state12(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state13(input, __tokens, __extra, __sym1, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(9, __tok0), __loc2)) => {
+                        let __sym1 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state31(input, __tokens, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###"";""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::AddOp(__sym1) => {
+                            __result = try!(__state11(input, __tokens, __lookahead, __extra, __sym0, __sym1, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 25
+            //     AllInputs = [Ident, "[", "]"]
+            //     OptionalInputs = []
+            //     FixedInputs = [Ident, "[", "]"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Term)
+            //
+            //     Term = Ident "[" "]" (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   [")", "*", "+", "-", "/", ";", EOF] -> Term = Ident, "[", "]" => ActionFn(30);
+            //
+            fn __state25<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, String, usize),
+                __sym1: (usize, &'input str, usize),
+                __sym2: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(7, _), _)) |
+                    Some((_, Token(9, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action30::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                            r###"";""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action30::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 26
+            //     AllInputs = ["(", Expr, ")"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["(", Expr, ")"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Term)
+            //
+            //     Term = "(" Expr ")" (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   [")", "*", "+", "-", "/", ";", EOF] -> Term = "(", Expr, ")" => ActionFn(14);
+            //
+            fn __state26<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, Box<Expr>, usize),
+                __sym2: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(7, _), _)) |
+                    Some((_, Token(9, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action14::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                            r###"";""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action14::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 27
+            //     AllInputs = ["let", Ident, ":"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["let", Ident, ":"]
+            //     WillPushLen = 4
+            //     WillPush = [Ident, "=", Expr, ";"]
+            //     WillProduce = Some(Expr)
+            //
+            //     Expr = "let" Ident ":" (*) Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Ident = (*) r#"[a-z]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   r#"[a-z]+"# -> S10
+            //
+            //     Ident -> S32
+            fn __state27<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, String, usize),
+                __sym2: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state10(input, __tokens, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"r#"[a-z]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Ident(__sym3) => {
+                            __result = try!(__state32(input, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, __sym3, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 28
+            //     AllInputs = ["let", Ident, "="]
+            //     OptionalInputs = []
+            //     FixedInputs = ["let", Ident, "="]
+            //     WillPushLen = 2
+            //     WillPush = [Expr, ";"]
+            //     WillProduce = Some(Expr)
+            //
+            //     Expr = (*) Expr AddOp Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = (*) Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = (*) "let" Ident ":" Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = (*) "let" Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = "let" Ident "=" (*) Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Factor = (*) Factor MulOp Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Factor = (*) Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Ident = (*) r#"[a-z]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Num = (*) r#"[0-9]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Ident ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Ident "[" (<Expr> ";")+ "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Ident "[" "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Num ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) "-" Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "(" -> S6
+            //   "-" -> S7
+            //   "let" -> S8
+            //   r#"[0-9]+"# -> S9
+            //   r#"[a-z]+"# -> S10
+            //
+            //     Expr -> S33
+            //     Factor -> S2
+            //     Ident -> S3
+            //     Num -> S4
+            //     Term -> S5
+            fn __state28<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, String, usize),
+                __sym2: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                let __sym0 = &mut Some(__sym0);
+                let __sym1 = &mut Some(__sym1);
+                let __sym2 = &mut Some(__sym2);
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(input, __tokens, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(13, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state8(input, __tokens, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state9(input, __tokens, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym3 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state10(input, __tokens, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""let""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                            r###"r#"[a-z]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    if __sym2.is_none() {
+                        return Ok(__result);
+                    }
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Expr(__sym3) => {
+                            __result = try!(__state33(input, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, __sym3, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Factor(__sym3) => {
+                            __result = try!(__state2(input, __tokens, __lookahead, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Ident(__sym3) => {
+                            __result = try!(__state3(input, __tokens, __lookahead, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Num(__sym3) => {
+                            __result = try!(__state4(input, __tokens, __lookahead, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Term(__sym3) => {
+                            __result = try!(__state5(input, __tokens, __lookahead, __extra, __sym3, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 29
+            //     AllInputs = [(<Expr> ";")+, Expr]
+            //     OptionalInputs = [(<Expr> ";")+]
+            //     FixedInputs = [Expr]
+            //     WillPushLen = 1
+            //     WillPush = [";"]
+            //     WillProduce = None
+            //
+            //     (<Expr> ";")+ = (<Expr> ";")+ Expr (*) ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     AddOp = (*) "+" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     AddOp = (*) "-" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = Expr (*) AddOp Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "+" -> S12
+            //   "-" -> S13
+            //   ";" -> S34
+            //
+            //     AddOp -> S11
+            fn __state29<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: &mut Option<(usize, ::std::vec::Vec<Box<Expr>>, usize)>,
+                __sym1: (usize, Box<Expr>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, Token(5, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state12(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state13(input, __tokens, __extra, __sym2, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(9, __tok0), __loc2)) => {
+                        let __sym2 = (__loc1, (__tok0), __loc2);
+                        let __sym0 = __sym0.take().unwrap();
+                        __result = try!(__state34(input, __tokens, __extra, __sym0, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###"";""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::AddOp(__sym2) => {
+                            __result = try!(__state11(input, __tokens, __lookahead, __extra, __sym1, __sym2, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 30
+            //     AllInputs = [Ident, "[", (<Expr> ";")+, "]"]
+            //     OptionalInputs = []
+            //     FixedInputs = [Ident, "[", (<Expr> ";")+, "]"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Term)
+            //
+            //     Term = Ident "[" (<Expr> ";")+ "]" (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   [")", "*", "+", "-", "/", ";", EOF] -> Term = Ident, "[", (<Expr> ";")+, "]" => ActionFn(31);
+            //
+            fn __state30<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, String, usize),
+                __sym1: (usize, &'input str, usize),
+                __sym2: (usize, ::std::vec::Vec<Box<Expr>>, usize),
+                __sym3: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(4, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(7, _), _)) |
+                    Some((_, Token(9, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym3.2.clone();
+                        let __nt = super::super::super::__action31::<>(input, __sym0, __sym1, __sym2, __sym3);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""*""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""/""###.to_string(),
+                            r###"";""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym3.2.clone();
+                        let __nt = super::super::super::__action31::<>(input, __sym0, __sym1, __sym2, __sym3);
+                        let __nt = __Nonterminal::Term((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 31
+            //     AllInputs = [Expr, ";"]
+            //     OptionalInputs = []
+            //     FixedInputs = [Expr, ";"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some((<Expr> ";")+)
+            //
+            //     (<Expr> ";")+ = Expr ";" (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   ["(", "-", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#] -> (<Expr> ";")+ = Expr, ";" => ActionFn(28);
+            //
+            fn __state31<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, Box<Expr>, usize),
+                __sym1: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(2, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(12, _), _)) |
+                    Some((_, Token(13, _), _)) |
+                    Some((_, Token(0, _), _)) |
+                    Some((_, Token(1, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym1.2.clone();
+                        let __nt = super::super::super::__action28::<>(input, __sym0, __sym1);
+                        let __nt = __Nonterminal::_28_3cExpr_3e_20_22_3b_22_29_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""]""###.to_string(),
+                            r###""let""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                            r###"r#"[a-z]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 32
+            //     AllInputs = ["let", Ident, ":", Ident]
+            //     OptionalInputs = []
+            //     FixedInputs = ["let", Ident, ":", Ident]
+            //     WillPushLen = 3
+            //     WillPush = ["=", Expr, ";"]
+            //     WillProduce = Some(Expr)
+            //
+            //     Expr = "let" Ident ":" Ident (*) "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "=" -> S35
+            //
+            fn __state32<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, String, usize),
+                __sym2: (usize, &'input str, usize),
+                __sym3: (usize, String, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, Token(10, __tok0), __loc2)) => {
+                        let __sym4 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state35(input, __tokens, __extra, __sym0, __sym1, __sym2, __sym3, __sym4, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""=""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 33
+            //     AllInputs = ["let", Ident, "=", Expr]
+            //     OptionalInputs = ["let", Ident, "="]
+            //     FixedInputs = [Expr]
+            //     WillPushLen = 1
+            //     WillPush = [";"]
+            //     WillProduce = Some(Expr)
+            //
+            //     AddOp = (*) "+" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     AddOp = (*) "-" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = Expr (*) AddOp Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = "let" Ident "=" Expr (*) ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "+" -> S12
+            //   "-" -> S13
+            //   ";" -> S36
+            //
+            //     AddOp -> S11
+            fn __state33<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: &mut Option<(usize, &'input str, usize)>,
+                __sym1: &mut Option<(usize, String, usize)>,
+                __sym2: &mut Option<(usize, &'input str, usize)>,
+                __sym3: (usize, Box<Expr>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, Token(5, __tok0), __loc2)) => {
+                        let __sym4 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state12(input, __tokens, __extra, __sym4, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym4 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state13(input, __tokens, __extra, __sym4, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(9, __tok0), __loc2)) => {
+                        let __sym4 = (__loc1, (__tok0), __loc2);
+                        let __sym0 = __sym0.take().unwrap();
+                        let __sym1 = __sym1.take().unwrap();
+                        let __sym2 = __sym2.take().unwrap();
+                        __result = try!(__state36(input, __tokens, __extra, __sym0, __sym1, __sym2, __sym3, __sym4, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###"";""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::AddOp(__sym4) => {
+                            __result = try!(__state11(input, __tokens, __lookahead, __extra, __sym3, __sym4, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 34
+            //     AllInputs = [(<Expr> ";")+, Expr, ";"]
+            //     OptionalInputs = []
+            //     FixedInputs = [(<Expr> ";")+, Expr, ";"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some((<Expr> ";")+)
+            //
+            //     (<Expr> ";")+ = (<Expr> ";")+ Expr ";" (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   ["(", "-", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#] -> (<Expr> ";")+ = (<Expr> ";")+, Expr, ";" => ActionFn(29);
+            //
+            fn __state34<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, ::std::vec::Vec<Box<Expr>>, usize),
+                __sym1: (usize, Box<Expr>, usize),
+                __sym2: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(2, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(12, _), _)) |
+                    Some((_, Token(13, _), _)) |
+                    Some((_, Token(0, _), _)) |
+                    Some((_, Token(1, _), _)) => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym2.2.clone();
+                        let __nt = super::super::super::__action29::<>(input, __sym0, __sym1, __sym2);
+                        let __nt = __Nonterminal::_28_3cExpr_3e_20_22_3b_22_29_2b((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""]""###.to_string(),
+                            r###""let""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                            r###"r#"[a-z]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+            }
+
+            // State 35
+            //     AllInputs = ["let", Ident, ":", Ident, "="]
+            //     OptionalInputs = []
+            //     FixedInputs = ["let", Ident, ":", Ident, "="]
+            //     WillPushLen = 2
+            //     WillPush = [Expr, ";"]
+            //     WillProduce = Some(Expr)
+            //
+            //     Expr = (*) Expr AddOp Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = (*) Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = (*) "let" Ident ":" Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = "let" Ident ":" Ident "=" (*) Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = (*) "let" Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Factor = (*) Factor MulOp Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Factor = (*) Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Ident = (*) r#"[a-z]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Num = (*) r#"[0-9]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Ident ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Ident "[" (<Expr> ";")+ "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Ident "[" "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) Num ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Term = (*) "-" Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "(" -> S6
+            //   "-" -> S7
+            //   "let" -> S8
+            //   r#"[0-9]+"# -> S9
+            //   r#"[a-z]+"# -> S10
+            //
+            //     Expr -> S37
+            //     Factor -> S2
+            //     Ident -> S3
+            //     Num -> S4
+            //     Term -> S5
+            fn __state35<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, String, usize),
+                __sym2: (usize, &'input str, usize),
+                __sym3: (usize, String, usize),
+                __sym4: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                let __sym0 = &mut Some(__sym0);
+                let __sym1 = &mut Some(__sym1);
+                let __sym2 = &mut Some(__sym2);
+                let __sym3 = &mut Some(__sym3);
+                let __sym4 = &mut Some(__sym4);
+                match __lookahead {
+                    Some((__loc1, Token(2, __tok0), __loc2)) => {
+                        let __sym5 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state6(input, __tokens, __extra, __sym5, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym5 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state7(input, __tokens, __extra, __sym5, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(13, __tok0), __loc2)) => {
+                        let __sym5 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state8(input, __tokens, __extra, __sym5, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(0, __tok0), __loc2)) => {
+                        let __sym5 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state9(input, __tokens, __extra, __sym5, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(1, __tok0), __loc2)) => {
+                        let __sym5 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state10(input, __tokens, __extra, __sym5, ::std::marker::PhantomData::<()>));
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""(""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###""let""###.to_string(),
+                            r###"r#"[0-9]+"#"###.to_string(),
+                            r###"r#"[a-z]+"#"###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    if __sym4.is_none() {
+                        return Ok(__result);
+                    }
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::Expr(__sym5) => {
+                            __result = try!(__state37(input, __tokens, __lookahead, __extra, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Factor(__sym5) => {
+                            __result = try!(__state2(input, __tokens, __lookahead, __extra, __sym5, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Ident(__sym5) => {
+                            __result = try!(__state3(input, __tokens, __lookahead, __extra, __sym5, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Num(__sym5) => {
+                            __result = try!(__state4(input, __tokens, __lookahead, __extra, __sym5, ::std::marker::PhantomData::<()>));
+                        }
+                        __Nonterminal::Term(__sym5) => {
+                            __result = try!(__state5(input, __tokens, __lookahead, __extra, __sym5, ::std::marker::PhantomData::<()>));
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 36
+            //     AllInputs = ["let", Ident, "=", Expr, ";"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["let", Ident, "=", Expr, ";"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Expr)
+            //
+            //     Expr = "let" Ident "=" Expr ";" (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   [")", "+", "-", ";", EOF] -> Expr = "let", Ident, "=", Expr, ";" => ActionFn(27);
+            //
+            fn __state36<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, String, usize),
+                __sym2: (usize, &'input str, usize),
+                __sym3: (usize, Box<Expr>, usize),
+                __sym4: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(9, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym4.2.clone();
+                        let __nt = super::super::super::__action27::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###"";""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym4.2.clone();
+                        let __nt = super::super::super::__action27::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+
+            // State 37
+            //     AllInputs = ["let", Ident, ":", Ident, "=", Expr]
+            //     OptionalInputs = ["let", Ident, ":", Ident, "="]
+            //     FixedInputs = [Expr]
+            //     WillPushLen = 1
+            //     WillPush = [";"]
+            //     WillProduce = Some(Expr)
+            //
+            //     AddOp = (*) "+" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     AddOp = (*) "-" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = Expr (*) AddOp Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //     Expr = "let" Ident ":" Ident "=" Expr (*) ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   "+" -> S12
+            //   "-" -> S13
+            //   ";" -> S38
+            //
+            //     AddOp -> S11
+            fn __state37<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __lookahead: Option<(usize, Token<'input>, usize)>,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: &mut Option<(usize, &'input str, usize)>,
+                __sym1: &mut Option<(usize, String, usize)>,
+                __sym2: &mut Option<(usize, &'input str, usize)>,
+                __sym3: &mut Option<(usize, String, usize)>,
+                __sym4: &mut Option<(usize, &'input str, usize)>,
+                __sym5: (usize, Box<Expr>, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                match __lookahead {
+                    Some((__loc1, Token(5, __tok0), __loc2)) => {
+                        let __sym6 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state12(input, __tokens, __extra, __sym6, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(6, __tok0), __loc2)) => {
+                        let __sym6 = (__loc1, (__tok0), __loc2);
+                        __result = try!(__state13(input, __tokens, __extra, __sym6, ::std::marker::PhantomData::<()>));
+                    }
+                    Some((__loc1, Token(9, __tok0), __loc2)) => {
+                        let __sym6 = (__loc1, (__tok0), __loc2);
+                        let __sym0 = __sym0.take().unwrap();
+                        let __sym1 = __sym1.take().unwrap();
+                        let __sym2 = __sym2.take().unwrap();
+                        let __sym3 = __sym3.take().unwrap();
+                        let __sym4 = __sym4.take().unwrap();
+                        __result = try!(__state38(input, __tokens, __extra, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, ::std::marker::PhantomData::<()>));
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###"";""###.to_string(),
+                        ];
+                        return Err(match __lookahead {
+                            Some(__token) => __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected },
+                            None => __lalrpop_util::ParseError::UnrecognizedEof { location: Default::default(), expected: __expected },
+                        });
+                    }
+                }
+                loop {
+                    let (__lookahead, __nt) = __result;
+                    match __nt {
+                        __Nonterminal::AddOp(__sym6) => {
+                            __result = try!(__state11(input, __tokens, __lookahead, __extra, __sym5, __sym6, ::std::marker::PhantomData::<()>));
+                            return Ok(__result);
+                        }
+                        _ => {
+                            return Ok((__lookahead, __nt));
+                        }
+                    }
+                }
+            }
+
+            // State 38
+            //     AllInputs = ["let", Ident, ":", Ident, "=", Expr, ";"]
+            //     OptionalInputs = []
+            //     FixedInputs = ["let", Ident, ":", Ident, "=", Expr, ";"]
+            //     WillPushLen = 0
+            //     WillPush = []
+            //     WillProduce = Some(Expr)
+            //
+            //     Expr = "let" Ident ":" Ident "=" Expr ";" (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+            //
+            //   [")", "+", "-", ";", EOF] -> Expr = "let", Ident, ":", Ident, "=", Expr, ";" => ActionFn(26);
+            //
+            fn __state38<
+                'input,
+                __TOKENS: Iterator<Item=Result<(usize, Token<'input>, usize),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+            >(
+                input: &'input str,
+                __tokens: &mut __TOKENS,
+                __extra: &mut Option<((usize, Token<'input>, usize), ::std::vec::Vec<::std::string::String>)>,
+                __sym0: (usize, &'input str, usize),
+                __sym1: (usize, String, usize),
+                __sym2: (usize, &'input str, usize),
+                __sym3: (usize, String, usize),
+                __sym4: (usize, &'input str, usize),
+                __sym5: (usize, Box<Expr>, usize),
+                __sym6: (usize, &'input str, usize),
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<(Option<(usize, Token<'input>, usize)>, __Nonterminal<>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __result: (Option<(usize, Token<'input>, usize)>, __Nonterminal<>);
+                let __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => Some(v),
+                    None => None,
+                    Some(Err(e)) => return Err(e),
+                };
+                match __lookahead {
+                    Some((_, Token(3, _), _)) |
+                    Some((_, Token(5, _), _)) |
+                    Some((_, Token(6, _), _)) |
+                    Some((_, Token(9, _), _)) |
+                    None => {
+                        let __start = __sym0.0.clone();
+                        let __end = __sym6.2.clone();
+                        let __nt = super::super::super::__action26::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                    _ => {
+                        let __expected = vec![
+                            r###"")""###.to_string(),
+                            r###""+""###.to_string(),
+                            r###""-""###.to_string(),
+                            r###"";""###.to_string(),
+                        ];
+                        if let Some(__token) = __lookahead {
+                            *__extra = Some((__token, __expected));
+                        }
+                        let __lookahead: Option<(usize, Token<'input>, usize)> = None;
+                        let __start = __sym0.0.clone();
+                        let __end = __sym6.2.clone();
+                        let __nt = super::super::super::__action26::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+                        let __nt = __Nonterminal::Expr((
+                            __start,
+                            __nt,
+                            __end,
+                        ));
+                        __result = (__lookahead, __nt);
+                        return Ok(__result);
+                    }
+                }
+            }
+        }
+        pub use self::__parse__Expr::parse_Expr;
+    }
+    mod __parse_table {
+
+        mod __parse_table {
+            #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+            use std::str::FromStr;
+            use unparse_ast::{Expr, Op};
+            #[allow(unused_extern_crates)]
+            extern crate lalrpop_util as __lalrpop_util;
+            use super::super::super::__intern_token::Token;
+            #[allow(dead_code)]
+            pub enum __Symbol<'input>
+             {
+                Term_22_28_22(&'input str),
+                Term_22_29_22(&'input str),
+                Term_22_2a_22(&'input str),
+                Term_22_2b_22(&'input str),
+                Term_22_2d_22(&'input str),
+                Term_22_2f_22(&'input str),
+                Term_22_3a_22(&'input str),
+                Term_22_3b_22(&'input str),
+                Term_22_3d_22(&'input str),
+                Term_22_5b_22(&'input str),
+                Term_22_5d_22(&'input str),
+                Term_22let_22(&'input str),
+                Termr_23_22_5b0_2d9_5d_2b_22_23(&'input str),
+                Termr_23_22_5ba_2dz_5d_2b_22_23(&'input str),
+                Nt_28_22_3a_22_20_3cIdent_3e_29(String),
+                Nt_28_22_3a_22_20_3cIdent_3e_29_3f(::std::option::Option<String>),
+                Nt_28_3cExpr_3e_20_22_3b_22_29(Box<Expr>),
+                Nt_28_3cExpr_3e_20_22_3b_22_29_2a(::std::vec::Vec<Box<Expr>>),
+                Nt_28_3cExpr_3e_20_22_3b_22_29_2b(::std::vec::Vec<Box<Expr>>),
+                NtAddOp(Op),
+                NtExpr(Box<Expr>),
+                NtFactor(Box<Expr>),
+                NtIdent(String),
+                NtMulOp(Op),
+                NtNum(i32),
+                NtTerm(Box<Expr>),
+                Nt____Expr(Box<Expr>),
+            }
+            impl<'input> ::std::fmt::Debug for __Symbol<'input>
+            {
+                fn fmt(&self, __f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    match *self {
+                        __Symbol::Term_22_28_22(..) => __f.write_str("\"(\""),
+                        __Symbol::Term_22_29_22(..) => __f.write_str("\")\""),
+                        __Symbol::Term_22_2a_22(..) => __f.write_str("\"*\""),
+                        __Symbol::Term_22_2b_22(..) => __f.write_str("\"+\""),
+                        __Symbol::Term_22_2d_22(..) => __f.write_str("\"-\""),
+                        __Symbol::Term_22_2f_22(..) => __f.write_str("\"/\""),
+                        __Symbol::Term_22_3a_22(..) => __f.write_str("\":\""),
+                        __Symbol::Term_22_3b_22(..) => __f.write_str("\";\""),
+                        __Symbol::Term_22_3d_22(..) => __f.write_str("\"=\""),
+                        __Symbol::Term_22_5b_22(..) => __f.write_str("\"[\""),
+                        __Symbol::Term_22_5d_22(..) => __f.write_str("\"]\""),
+                        __Symbol::Term_22let_22(..) => __f.write_str("\"let\""),
+                        __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(..) => __f.write_str("r#\"[0-9]+\"#"),
+                        __Symbol::Termr_23_22_5ba_2dz_5d_2b_22_23(..) => __f.write_str("r#\"[a-z]+\"#"),
+                        __Symbol::Nt_28_22_3a_22_20_3cIdent_3e_29(..) => __f.write_str("(\":\" <Ident>)"),
+                        __Symbol::Nt_28_22_3a_22_20_3cIdent_3e_29_3f(..) => __f.write_str("(\":\" <Ident>)?"),
+                        __Symbol::Nt_28_3cExpr_3e_20_22_3b_22_29(..) => __f.write_str("(<Expr> \";\")"),
+                        __Symbol::Nt_28_3cExpr_3e_20_22_3b_22_29_2a(..) => __f.write_str("(<Expr> \";\")*"),
+                        __Symbol::Nt_28_3cExpr_3e_20_22_3b_22_29_2b(..) => __f.write_str("(<Expr> \";\")+"),
+                        __Symbol::NtAddOp(..) => __f.write_str("AddOp"),
+                        __Symbol::NtExpr(..) => __f.write_str("Expr"),
+                        __Symbol::NtFactor(..) => __f.write_str("Factor"),
+                        __Symbol::NtIdent(..) => __f.write_str("Ident"),
+                        __Symbol::NtMulOp(..) => __f.write_str("MulOp"),
+                        __Symbol::NtNum(..) => __f.write_str("Num"),
+                        __Symbol::NtTerm(..) => __f.write_str("Term"),
+                        __Symbol::Nt____Expr(..) => __f.write_str("__Expr"),
+                    }
+                }
+            }
+            const __ACTION: &'static [i8] = &[
+                // State 0
+                //     Expr = (*) Expr AddOp Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = (*) Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = (*) "let" Ident ":" Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = (*) "let" Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Factor = (*) Factor MulOp Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Factor = (*) Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Ident = (*) r#"[a-z]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Num = (*) r#"[0-9]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Ident ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Ident "[" (<Expr> ";")+ "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Ident "[" "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Num ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) "-" Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     __Expr = (*) Expr ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                7,  // on "(", goto 6
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                8,  // on "-", goto 7
+                0,  // on "/", error
+                0,  // on ":", error
+                0,  // on ";", error
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                9,  // on "let", goto 8
+                10,  // on r#"[0-9]+"#, goto 9
+                11,  // on r#"[a-z]+"#, goto 10
+
+                // State 1
+                //     AddOp = (*) "+" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     AddOp = (*) "-" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = Expr (*) AddOp Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     __Expr = Expr (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                0,  // on "*", error
+                13,  // on "+", goto 12
+                14,  // on "-", goto 13
+                0,  // on "/", error
+                0,  // on ":", error
+                0,  // on ";", error
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "let", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 2
+                //     Expr = Factor (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Factor = Factor (*) MulOp Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     MulOp = (*) "*" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     MulOp = (*) "/" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                -14,  // on ")", reduce `Expr = Factor => ActionFn(3);`
+                16,  // on "*", goto 15
+                -14,  // on "+", reduce `Expr = Factor => ActionFn(3);`
+                -14,  // on "-", reduce `Expr = Factor => ActionFn(3);`
+                17,  // on "/", goto 16
+                0,  // on ":", error
+                -14,  // on ";", reduce `Expr = Factor => ActionFn(3);`
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "let", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 3
+                //     Term = Ident (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = Ident (*) "[" (<Expr> ";")+ "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = Ident (*) "[" "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                -22,  // on ")", reduce `Term = Ident => ActionFn(11);`
+                -22,  // on "*", reduce `Term = Ident => ActionFn(11);`
+                -22,  // on "+", reduce `Term = Ident => ActionFn(11);`
+                -22,  // on "-", reduce `Term = Ident => ActionFn(11);`
+                -22,  // on "/", reduce `Term = Ident => ActionFn(11);`
+                0,  // on ":", error
+                -22,  // on ";", reduce `Term = Ident => ActionFn(11);`
+                0,  // on "=", error
+                18,  // on "[", goto 17
+                0,  // on "]", error
+                0,  // on "let", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 4
+                //     Term = Num (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                -21,  // on ")", reduce `Term = Num => ActionFn(10);`
+                -21,  // on "*", reduce `Term = Num => ActionFn(10);`
+                -21,  // on "+", reduce `Term = Num => ActionFn(10);`
+                -21,  // on "-", reduce `Term = Num => ActionFn(10);`
+                -21,  // on "/", reduce `Term = Num => ActionFn(10);`
+                0,  // on ":", error
+                -21,  // on ";", reduce `Term = Num => ActionFn(10);`
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "let", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 5
+                //     Factor = Term (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                -16,  // on ")", reduce `Factor = Term => ActionFn(7);`
+                -16,  // on "*", reduce `Factor = Term => ActionFn(7);`
+                -16,  // on "+", reduce `Factor = Term => ActionFn(7);`
+                -16,  // on "-", reduce `Factor = Term => ActionFn(7);`
+                -16,  // on "/", reduce `Factor = Term => ActionFn(7);`
+                0,  // on ":", error
+                -16,  // on ";", reduce `Factor = Term => ActionFn(7);`
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "let", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 6
+                //     Expr = (*) Expr AddOp Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = (*) Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = (*) "let" Ident ":" Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = (*) "let" Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Factor = (*) Factor MulOp Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Factor = (*) Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Ident = (*) r#"[a-z]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Num = (*) r#"[0-9]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Ident ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Ident "[" (<Expr> ";")+ "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Ident "[" "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Num ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = "(" (*) Expr ")" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) "-" Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                7,  // on "(", goto 6
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                8,  // on "-", goto 7
+                0,  // on "/", error
+                0,  // on ":", error
+                0,  // on ";", error
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                9,  // on "let", goto 8
+                10,  // on r#"[0-9]+"#, goto 9
+                11,  // on r#"[a-z]+"#, goto 10
+
+                // State 7
+                //     Ident = (*) r#"[a-z]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Num = (*) r#"[0-9]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Ident ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Ident "[" (<Expr> ";")+ "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Ident "[" "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Num ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) "-" Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = "-" (*) Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                7,  // on "(", goto 6
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                8,  // on "-", goto 7
+                0,  // on "/", error
+                0,  // on ":", error
+                0,  // on ";", error
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "let", error
+                10,  // on r#"[0-9]+"#, goto 9
+                11,  // on r#"[a-z]+"#, goto 10
+
+                // State 8
+                //     Expr = "let" (*) Ident ":" Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = "let" (*) Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Ident = (*) r#"[a-z]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                0,  // on "-", error
+                0,  // on "/", error
+                0,  // on ":", error
+                0,  // on ";", error
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "let", error
+                0,  // on r#"[0-9]+"#, error
+                11,  // on r#"[a-z]+"#, goto 10
+
+                // State 9
+                //     Num = r#"[0-9]+"# (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                -20,  // on ")", reduce `Num = r#"[0-9]+"# => ActionFn(15);`
+                -20,  // on "*", reduce `Num = r#"[0-9]+"# => ActionFn(15);`
+                -20,  // on "+", reduce `Num = r#"[0-9]+"# => ActionFn(15);`
+                -20,  // on "-", reduce `Num = r#"[0-9]+"# => ActionFn(15);`
+                -20,  // on "/", reduce `Num = r#"[0-9]+"# => ActionFn(15);`
+                0,  // on ":", error
+                -20,  // on ";", reduce `Num = r#"[0-9]+"# => ActionFn(15);`
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "let", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 10
+                //     Ident = r#"[a-z]+"# (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                -17,  // on ")", reduce `Ident = r#"[a-z]+"# => ActionFn(16);`
+                -17,  // on "*", reduce `Ident = r#"[a-z]+"# => ActionFn(16);`
+                -17,  // on "+", reduce `Ident = r#"[a-z]+"# => ActionFn(16);`
+                -17,  // on "-", reduce `Ident = r#"[a-z]+"# => ActionFn(16);`
+                -17,  // on "/", reduce `Ident = r#"[a-z]+"# => ActionFn(16);`
+                -17,  // on ":", reduce `Ident = r#"[a-z]+"# => ActionFn(16);`
+                -17,  // on ";", reduce `Ident = r#"[a-z]+"# => ActionFn(16);`
+                -17,  // on "=", reduce `Ident = r#"[a-z]+"# => ActionFn(16);`
+                -17,  // on "[", reduce `Ident = r#"[a-z]+"# => ActionFn(16);`
+                0,  // on "]", error
+                0,  // on "let", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 11
+                //     Expr = Expr AddOp (*) Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Factor = (*) Factor MulOp Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Factor = (*) Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Ident = (*) r#"[a-z]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Num = (*) r#"[0-9]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Ident ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Ident "[" (<Expr> ";")+ "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Ident "[" "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Num ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) "-" Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                7,  // on "(", goto 6
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                8,  // on "-", goto 7
+                0,  // on "/", error
+                0,  // on ":", error
+                0,  // on ";", error
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "let", error
+                10,  // on r#"[0-9]+"#, goto 9
+                11,  // on r#"[a-z]+"#, goto 10
+
+                // State 12
+                //     AddOp = "+" (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                -9,  // on "(", reduce `AddOp = "+" => ActionFn(4);`
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                -9,  // on "-", reduce `AddOp = "+" => ActionFn(4);`
+                0,  // on "/", error
+                0,  // on ":", error
+                0,  // on ";", error
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "let", error
+                -9,  // on r#"[0-9]+"#, reduce `AddOp = "+" => ActionFn(4);`
+                -9,  // on r#"[a-z]+"#, reduce `AddOp = "+" => ActionFn(4);`
+
+                // State 13
+                //     AddOp = "-" (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                -10,  // on "(", reduce `AddOp = "-" => ActionFn(5);`
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                -10,  // on "-", reduce `AddOp = "-" => ActionFn(5);`
+                0,  // on "/", error
+                0,  // on ":", error
+                0,  // on ";", error
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "let", error
+                -10,  // on r#"[0-9]+"#, reduce `AddOp = "-" => ActionFn(5);`
+                -10,  // on r#"[a-z]+"#, reduce `AddOp = "-" => ActionFn(5);`
+
+                // State 14
+                //     Factor = Factor MulOp (*) Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Ident = (*) r#"[a-z]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Num = (*) r#"[0-9]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Ident ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Ident "[" (<Expr> ";")+ "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Ident "[" "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Num ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) "-" Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                7,  // on "(", goto 6
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                8,  // on "-", goto 7
+                0,  // on "/", error
+                0,  // on ":", error
+                0,  // on ";", error
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "let", error
+                10,  // on r#"[0-9]+"#, goto 9
+                11,  // on r#"[a-z]+"#, goto 10
+
+                // State 15
+                //     MulOp = "*" (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                -18,  // on "(", reduce `MulOp = "*" => ActionFn(8);`
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                -18,  // on "-", reduce `MulOp = "*" => ActionFn(8);`
+                0,  // on "/", error
+                0,  // on ":", error
+                0,  // on ";", error
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "let", error
+                -18,  // on r#"[0-9]+"#, reduce `MulOp = "*" => ActionFn(8);`
+                -18,  // on r#"[a-z]+"#, reduce `MulOp = "*" => ActionFn(8);`
+
+                // State 16
+                //     MulOp = "/" (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                -19,  // on "(", reduce `MulOp = "/" => ActionFn(9);`
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                -19,  // on "-", reduce `MulOp = "/" => ActionFn(9);`
+                0,  // on "/", error
+                0,  // on ":", error
+                0,  // on ";", error
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "let", error
+                -19,  // on r#"[0-9]+"#, reduce `MulOp = "/" => ActionFn(9);`
+                -19,  // on r#"[a-z]+"#, reduce `MulOp = "/" => ActionFn(9);`
+
+                // State 17
+                //     (<Expr> ";")+ = (*) (<Expr> ";")+ Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     (<Expr> ";")+ = (*) Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = (*) Expr AddOp Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = (*) Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = (*) "let" Ident ":" Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = (*) "let" Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Factor = (*) Factor MulOp Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Factor = (*) Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Ident = (*) r#"[a-z]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Num = (*) r#"[0-9]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Ident ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Ident "[" (<Expr> ";")+ "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = Ident "[" (*) (<Expr> ";")+ "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Ident "[" "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = Ident "[" (*) "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Num ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) "-" Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                7,  // on "(", goto 6
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                8,  // on "-", goto 7
+                0,  // on "/", error
+                0,  // on ":", error
+                0,  // on ";", error
+                0,  // on "=", error
+                0,  // on "[", error
+                26,  // on "]", goto 25
+                9,  // on "let", goto 8
+                10,  // on r#"[0-9]+"#, goto 9
+                11,  // on r#"[a-z]+"#, goto 10
+
+                // State 18
+                //     AddOp = (*) "+" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     AddOp = (*) "-" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = Expr (*) AddOp Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = "(" Expr (*) ")" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                27,  // on ")", goto 26
+                0,  // on "*", error
+                13,  // on "+", goto 12
+                14,  // on "-", goto 13
+                0,  // on "/", error
+                0,  // on ":", error
+                0,  // on ";", error
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "let", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 19
+                //     Term = "-" Term (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                -23,  // on ")", reduce `Term = "-", Term => ActionFn(12);`
+                -23,  // on "*", reduce `Term = "-", Term => ActionFn(12);`
+                -23,  // on "+", reduce `Term = "-", Term => ActionFn(12);`
+                -23,  // on "-", reduce `Term = "-", Term => ActionFn(12);`
+                -23,  // on "/", reduce `Term = "-", Term => ActionFn(12);`
+                0,  // on ":", error
+                -23,  // on ";", reduce `Term = "-", Term => ActionFn(12);`
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "let", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 20
+                //     Expr = "let" Ident (*) ":" Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = "let" Ident (*) "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                0,  // on "-", error
+                0,  // on "/", error
+                28,  // on ":", goto 27
+                0,  // on ";", error
+                29,  // on "=", goto 28
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "let", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 21
+                //     Expr = Expr AddOp Factor (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Factor = Factor (*) MulOp Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     MulOp = (*) "*" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     MulOp = (*) "/" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                -11,  // on ")", reduce `Expr = Expr, AddOp, Factor => ActionFn(1);`
+                16,  // on "*", goto 15
+                -11,  // on "+", reduce `Expr = Expr, AddOp, Factor => ActionFn(1);`
+                -11,  // on "-", reduce `Expr = Expr, AddOp, Factor => ActionFn(1);`
+                17,  // on "/", goto 16
+                0,  // on ":", error
+                -11,  // on ";", reduce `Expr = Expr, AddOp, Factor => ActionFn(1);`
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "let", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 22
+                //     Factor = Factor MulOp Term (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                -15,  // on ")", reduce `Factor = Factor, MulOp, Term => ActionFn(6);`
+                -15,  // on "*", reduce `Factor = Factor, MulOp, Term => ActionFn(6);`
+                -15,  // on "+", reduce `Factor = Factor, MulOp, Term => ActionFn(6);`
+                -15,  // on "-", reduce `Factor = Factor, MulOp, Term => ActionFn(6);`
+                -15,  // on "/", reduce `Factor = Factor, MulOp, Term => ActionFn(6);`
+                0,  // on ":", error
+                -15,  // on ";", reduce `Factor = Factor, MulOp, Term => ActionFn(6);`
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "let", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 23
+                //     (<Expr> ";")+ = (<Expr> ";")+ (*) Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = (*) Expr AddOp Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = (*) Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = (*) "let" Ident ":" Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = (*) "let" Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Factor = (*) Factor MulOp Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Factor = (*) Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Ident = (*) r#"[a-z]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Num = (*) r#"[0-9]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Ident ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Ident "[" (<Expr> ";")+ "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = Ident "[" (<Expr> ";")+ (*) "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Ident "[" "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Num ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) "-" Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                7,  // on "(", goto 6
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                8,  // on "-", goto 7
+                0,  // on "/", error
+                0,  // on ":", error
+                0,  // on ";", error
+                0,  // on "=", error
+                0,  // on "[", error
+                31,  // on "]", goto 30
+                9,  // on "let", goto 8
+                10,  // on r#"[0-9]+"#, goto 9
+                11,  // on r#"[a-z]+"#, goto 10
+
+                // State 24
+                //     (<Expr> ";")+ = Expr (*) ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     AddOp = (*) "+" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     AddOp = (*) "-" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = Expr (*) AddOp Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                0,  // on "*", error
+                13,  // on "+", goto 12
+                14,  // on "-", goto 13
+                0,  // on "/", error
+                0,  // on ":", error
+                32,  // on ";", goto 31
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "let", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 25
+                //     Term = Ident "[" "]" (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                -24,  // on ")", reduce `Term = Ident, "[", "]" => ActionFn(30);`
+                -24,  // on "*", reduce `Term = Ident, "[", "]" => ActionFn(30);`
+                -24,  // on "+", reduce `Term = Ident, "[", "]" => ActionFn(30);`
+                -24,  // on "-", reduce `Term = Ident, "[", "]" => ActionFn(30);`
+                -24,  // on "/", reduce `Term = Ident, "[", "]" => ActionFn(30);`
+                0,  // on ":", error
+                -24,  // on ";", reduce `Term = Ident, "[", "]" => ActionFn(30);`
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "let", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 26
+                //     Term = "(" Expr ")" (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                -26,  // on ")", reduce `Term = "(", Expr, ")" => ActionFn(14);`
+                -26,  // on "*", reduce `Term = "(", Expr, ")" => ActionFn(14);`
+                -26,  // on "+", reduce `Term = "(", Expr, ")" => ActionFn(14);`
+                -26,  // on "-", reduce `Term = "(", Expr, ")" => ActionFn(14);`
+                -26,  // on "/", reduce `Term = "(", Expr, ")" => ActionFn(14);`
+                0,  // on ":", error
+                -26,  // on ";", reduce `Term = "(", Expr, ")" => ActionFn(14);`
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "let", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 27
+                //     Expr = "let" Ident ":" (*) Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Ident = (*) r#"[a-z]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                0,  // on "-", error
+                0,  // on "/", error
+                0,  // on ":", error
+                0,  // on ";", error
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "let", error
+                0,  // on r#"[0-9]+"#, error
+                11,  // on r#"[a-z]+"#, goto 10
+
+                // State 28
+                //     Expr = (*) Expr AddOp Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = (*) Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = (*) "let" Ident ":" Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = (*) "let" Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = "let" Ident "=" (*) Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Factor = (*) Factor MulOp Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Factor = (*) Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Ident = (*) r#"[a-z]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Num = (*) r#"[0-9]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Ident ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Ident "[" (<Expr> ";")+ "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Ident "[" "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Num ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) "-" Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                7,  // on "(", goto 6
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                8,  // on "-", goto 7
+                0,  // on "/", error
+                0,  // on ":", error
+                0,  // on ";", error
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                9,  // on "let", goto 8
+                10,  // on r#"[0-9]+"#, goto 9
+                11,  // on r#"[a-z]+"#, goto 10
+
+                // State 29
+                //     (<Expr> ";")+ = (<Expr> ";")+ Expr (*) ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     AddOp = (*) "+" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     AddOp = (*) "-" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = Expr (*) AddOp Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                0,  // on "*", error
+                13,  // on "+", goto 12
+                14,  // on "-", goto 13
+                0,  // on "/", error
+                0,  // on ":", error
+                35,  // on ";", goto 34
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "let", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 30
+                //     Term = Ident "[" (<Expr> ";")+ "]" (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                -25,  // on ")", reduce `Term = Ident, "[", (<Expr> ";")+, "]" => ActionFn(31);`
+                -25,  // on "*", reduce `Term = Ident, "[", (<Expr> ";")+, "]" => ActionFn(31);`
+                -25,  // on "+", reduce `Term = Ident, "[", (<Expr> ";")+, "]" => ActionFn(31);`
+                -25,  // on "-", reduce `Term = Ident, "[", (<Expr> ";")+, "]" => ActionFn(31);`
+                -25,  // on "/", reduce `Term = Ident, "[", (<Expr> ";")+, "]" => ActionFn(31);`
+                0,  // on ":", error
+                -25,  // on ";", reduce `Term = Ident, "[", (<Expr> ";")+, "]" => ActionFn(31);`
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "let", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 31
+                //     (<Expr> ";")+ = Expr ";" (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                -7,  // on "(", reduce `(<Expr> ";")+ = Expr, ";" => ActionFn(28);`
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                -7,  // on "-", reduce `(<Expr> ";")+ = Expr, ";" => ActionFn(28);`
+                0,  // on "/", error
+                0,  // on ":", error
+                0,  // on ";", error
+                0,  // on "=", error
+                0,  // on "[", error
+                -7,  // on "]", reduce `(<Expr> ";")+ = Expr, ";" => ActionFn(28);`
+                -7,  // on "let", reduce `(<Expr> ";")+ = Expr, ";" => ActionFn(28);`
+                -7,  // on r#"[0-9]+"#, reduce `(<Expr> ";")+ = Expr, ";" => ActionFn(28);`
+                -7,  // on r#"[a-z]+"#, reduce `(<Expr> ";")+ = Expr, ";" => ActionFn(28);`
+
+                // State 32
+                //     Expr = "let" Ident ":" Ident (*) "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                0,  // on "-", error
+                0,  // on "/", error
+                0,  // on ":", error
+                0,  // on ";", error
+                36,  // on "=", goto 35
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "let", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 33
+                //     AddOp = (*) "+" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     AddOp = (*) "-" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = Expr (*) AddOp Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = "let" Ident "=" Expr (*) ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                0,  // on "*", error
+                13,  // on "+", goto 12
+                14,  // on "-", goto 13
+                0,  // on "/", error
+                0,  // on ":", error
+                37,  // on ";", goto 36
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "let", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 34
+                //     (<Expr> ";")+ = (<Expr> ";")+ Expr ";" (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                -8,  // on "(", reduce `(<Expr> ";")+ = (<Expr> ";")+, Expr, ";" => ActionFn(29);`
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                -8,  // on "-", reduce `(<Expr> ";")+ = (<Expr> ";")+, Expr, ";" => ActionFn(29);`
+                0,  // on "/", error
+                0,  // on ":", error
+                0,  // on ";", error
+                0,  // on "=", error
+                0,  // on "[", error
+                -8,  // on "]", reduce `(<Expr> ";")+ = (<Expr> ";")+, Expr, ";" => ActionFn(29);`
+                -8,  // on "let", reduce `(<Expr> ";")+ = (<Expr> ";")+, Expr, ";" => ActionFn(29);`
+                -8,  // on r#"[0-9]+"#, reduce `(<Expr> ";")+ = (<Expr> ";")+, Expr, ";" => ActionFn(29);`
+                -8,  // on r#"[a-z]+"#, reduce `(<Expr> ";")+ = (<Expr> ";")+, Expr, ";" => ActionFn(29);`
+
+                // State 35
+                //     Expr = (*) Expr AddOp Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = (*) Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = (*) "let" Ident ":" Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = "let" Ident ":" Ident "=" (*) Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = (*) "let" Ident "=" Expr ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Factor = (*) Factor MulOp Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Factor = (*) Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Ident = (*) r#"[a-z]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Num = (*) r#"[0-9]+"# ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Ident ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Ident "[" (<Expr> ";")+ "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Ident "[" "]" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) Num ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) "(" Expr ")" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Term = (*) "-" Term ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                7,  // on "(", goto 6
+                0,  // on ")", error
+                0,  // on "*", error
+                0,  // on "+", error
+                8,  // on "-", goto 7
+                0,  // on "/", error
+                0,  // on ":", error
+                0,  // on ";", error
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                9,  // on "let", goto 8
+                10,  // on r#"[0-9]+"#, goto 9
+                11,  // on r#"[a-z]+"#, goto 10
+
+                // State 36
+                //     Expr = "let" Ident "=" Expr ";" (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                -13,  // on ")", reduce `Expr = "let", Ident, "=", Expr, ";" => ActionFn(27);`
+                0,  // on "*", error
+                -13,  // on "+", reduce `Expr = "let", Ident, "=", Expr, ";" => ActionFn(27);`
+                -13,  // on "-", reduce `Expr = "let", Ident, "=", Expr, ";" => ActionFn(27);`
+                0,  // on "/", error
+                0,  // on ":", error
+                -13,  // on ";", reduce `Expr = "let", Ident, "=", Expr, ";" => ActionFn(27);`
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "let", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 37
+                //     AddOp = (*) "+" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     AddOp = (*) "-" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = Expr (*) AddOp Factor ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                //     Expr = "let" Ident ":" Ident "=" Expr (*) ";" ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                0,  // on ")", error
+                0,  // on "*", error
+                13,  // on "+", goto 12
+                14,  // on "-", goto 13
+                0,  // on "/", error
+                0,  // on ":", error
+                39,  // on ";", goto 38
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "let", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+                // State 38
+                //     Expr = "let" Ident ":" Ident "=" Expr ";" (*) ["(", ")", "*", "+", "-", "/", ":", ";", "=", "[", "]", "let", r#"[0-9]+"#, r#"[a-z]+"#, EOF]
+                0,  // on "(", error
+                -12,  // on ")", reduce `Expr = "let", Ident, ":", Ident, "=", Expr, ";" => ActionFn(26);`
+                0,  // on "*", error
+                -12,  // on "+", reduce `Expr = "let", Ident, ":", Ident, "=", Expr, ";" => ActionFn(26);`
+                -12,  // on "-", reduce `Expr = "let", Ident, ":", Ident, "=", Expr, ";" => ActionFn(26);`
+                0,  // on "/", error
+                0,  // on ":", error
+                -12,  // on ";", reduce `Expr = "let", Ident, ":", Ident, "=", Expr, ";" => ActionFn(26);`
+                0,  // on "=", error
+                0,  // on "[", error
+                0,  // on "]", error
+                0,  // on "let", error
+                0,  // on r#"[0-9]+"#, error
+                0,  // on r#"[a-z]+"#, error
+
+            ];
+            const __EOF_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // on EOF, error
+
+                // State 1
+                -27,  // on EOF, reduce `__Expr = Expr => ActionFn(0);`
+
+                // State 2
+                -14,  // on EOF, reduce `Expr = Factor => ActionFn(3);`
+
+                // State 3
+                -22,  // on EOF, reduce `Term = Ident => ActionFn(11);`
+
+                // State 4
+                -21,  // on EOF, reduce `Term = Num => ActionFn(10);`
+
+                // State 5
+                -16,  // on EOF, reduce `Factor = Term => ActionFn(7);`
+
+                // State 6
+                0,  // on EOF, error
+
+                // State 7
+                0,  // on EOF, error
+
+                // State 8
+                0,  // on EOF, error
+
+                // State 9
+                -20,  // on EOF, reduce `Num = r#"[0-9]+"# => ActionFn(15);`
+
+                // State 10
+                -17,  // on EOF, reduce `Ident = r#"[a-z]+"# => ActionFn(16);`
+
+                // State 11
+                0,  // on EOF, error
+
+                // State 12
+                0,  // on EOF, error
+
+                // State 13
+                0,  // on EOF, error
+
+                // State 14
+                0,  // on EOF, error
+
+                // State 15
+                0,  // on EOF, error
+
+                // State 16
+                0,  // on EOF, error
+
+                // State 17
+                0,  // on EOF, error
+
+                // State 18
+                0,  // on EOF, error
+
+                // State 19
+                -23,  // on EOF, reduce `Term = "-", Term => ActionFn(12);`
+
+                // State 20
+                0,  // on EOF, error
+
+                // State 21
+                -11,  // on EOF, reduce `Expr = Expr, AddOp, Factor => ActionFn(1);`
+
+                // State 22
+                -15,  // on EOF, reduce `Factor = Factor, MulOp, Term => ActionFn(6);`
+
+                // State 23
+                0,  // on EOF, error
+
+                // State 24
+                0,  // on EOF, error
+
+                // State 25
+                -24,  // on EOF, reduce `Term = Ident, "[", "]" => ActionFn(30);`
+
+                // State 26
+                -26,  // on EOF, reduce `Term = "(", Expr, ")" => ActionFn(14);`
+
+                // State 27
+                0,  // on EOF, error
+
+                // State 28
+                0,  // on EOF, error
+
+                // State 29
+                0,  // on EOF, error
+
+                // State 30
+                -25,  // on EOF, reduce `Term = Ident, "[", (<Expr> ";")+, "]" => ActionFn(31);`
+
+                // State 31
+                0,  // on EOF, error
+
+                // State 32
+                0,  // on EOF, error
+
+                // State 33
+                0,  // on EOF, error
+
+                // State 34
+                0,  // on EOF, error
+
+                // State 35
+                0,  // on EOF, error
+
+                // State 36
+                -13,  // on EOF, reduce `Expr = "let", Ident, "=", Expr, ";" => ActionFn(27);`
+
+                // State 37
+                0,  // on EOF, error
+
+                // State 38
+                -12,  // on EOF, reduce `Expr = "let", Ident, ":", Ident, "=", Expr, ";" => ActionFn(26);`
+
+            ];
+            const __GOTO: &'static [u8] = &[
+                // State 0
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                2,  // on Expr, goto 1
+                3,  // on Factor, goto 2
+                4,  // on Ident, goto 3
+                0,  // on MulOp, error
+                5,  // on Num, goto 4
+                6,  // on Term, goto 5
+                0,  // on __Expr, error
+
+                // State 1
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                12,  // on AddOp, goto 11
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Ident, error
+                0,  // on MulOp, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 2
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Ident, error
+                15,  // on MulOp, goto 14
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 3
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Ident, error
+                0,  // on MulOp, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 4
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Ident, error
+                0,  // on MulOp, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 5
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Ident, error
+                0,  // on MulOp, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 6
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                19,  // on Expr, goto 18
+                3,  // on Factor, goto 2
+                4,  // on Ident, goto 3
+                0,  // on MulOp, error
+                5,  // on Num, goto 4
+                6,  // on Term, goto 5
+                0,  // on __Expr, error
+
+                // State 7
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                0,  // on Expr, error
+                0,  // on Factor, error
+                4,  // on Ident, goto 3
+                0,  // on MulOp, error
+                5,  // on Num, goto 4
+                20,  // on Term, goto 19
+                0,  // on __Expr, error
+
+                // State 8
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                0,  // on Expr, error
+                0,  // on Factor, error
+                21,  // on Ident, goto 20
+                0,  // on MulOp, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 9
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Ident, error
+                0,  // on MulOp, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 10
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Ident, error
+                0,  // on MulOp, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 11
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                0,  // on Expr, error
+                22,  // on Factor, goto 21
+                4,  // on Ident, goto 3
+                0,  // on MulOp, error
+                5,  // on Num, goto 4
+                6,  // on Term, goto 5
+                0,  // on __Expr, error
+
+                // State 12
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Ident, error
+                0,  // on MulOp, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 13
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Ident, error
+                0,  // on MulOp, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 14
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                0,  // on Expr, error
+                0,  // on Factor, error
+                4,  // on Ident, goto 3
+                0,  // on MulOp, error
+                5,  // on Num, goto 4
+                23,  // on Term, goto 22
+                0,  // on __Expr, error
+
+                // State 15
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Ident, error
+                0,  // on MulOp, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 16
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Ident, error
+                0,  // on MulOp, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 17
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                24,  // on (<Expr> ";")+, goto 23
+                0,  // on AddOp, error
+                25,  // on Expr, goto 24
+                3,  // on Factor, goto 2
+                4,  // on Ident, goto 3
+                0,  // on MulOp, error
+                5,  // on Num, goto 4
+                6,  // on Term, goto 5
+                0,  // on __Expr, error
+
+                // State 18
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                12,  // on AddOp, goto 11
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Ident, error
+                0,  // on MulOp, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 19
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Ident, error
+                0,  // on MulOp, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 20
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Ident, error
+                0,  // on MulOp, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 21
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Ident, error
+                15,  // on MulOp, goto 14
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 22
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Ident, error
+                0,  // on MulOp, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 23
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                30,  // on Expr, goto 29
+                3,  // on Factor, goto 2
+                4,  // on Ident, goto 3
+                0,  // on MulOp, error
+                5,  // on Num, goto 4
+                6,  // on Term, goto 5
+                0,  // on __Expr, error
+
+                // State 24
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                12,  // on AddOp, goto 11
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Ident, error
+                0,  // on MulOp, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 25
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Ident, error
+                0,  // on MulOp, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 26
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Ident, error
+                0,  // on MulOp, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 27
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                0,  // on Expr, error
+                0,  // on Factor, error
+                33,  // on Ident, goto 32
+                0,  // on MulOp, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 28
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                34,  // on Expr, goto 33
+                3,  // on Factor, goto 2
+                4,  // on Ident, goto 3
+                0,  // on MulOp, error
+                5,  // on Num, goto 4
+                6,  // on Term, goto 5
+                0,  // on __Expr, error
+
+                // State 29
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                12,  // on AddOp, goto 11
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Ident, error
+                0,  // on MulOp, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 30
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Ident, error
+                0,  // on MulOp, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 31
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Ident, error
+                0,  // on MulOp, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 32
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Ident, error
+                0,  // on MulOp, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 33
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                12,  // on AddOp, goto 11
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Ident, error
+                0,  // on MulOp, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 34
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Ident, error
+                0,  // on MulOp, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 35
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                38,  // on Expr, goto 37
+                3,  // on Factor, goto 2
+                4,  // on Ident, goto 3
+                0,  // on MulOp, error
+                5,  // on Num, goto 4
+                6,  // on Term, goto 5
+                0,  // on __Expr, error
+
+                // State 36
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Ident, error
+                0,  // on MulOp, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 37
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                12,  // on AddOp, goto 11
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Ident, error
+                0,  // on MulOp, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+                // State 38
+                0,  // on (":" <Ident>), error
+                0,  // on (":" <Ident>)?, error
+                0,  // on (<Expr> ";"), error
+                0,  // on (<Expr> ";")*, error
+                0,  // on (<Expr> ";")+, error
+                0,  // on AddOp, error
+                0,  // on Expr, error
+                0,  // on Factor, error
+                0,  // on Ident, error
+                0,  // on MulOp, error
+                0,  // on Num, error
+                0,  // on Term, error
+                0,  // on __Expr, error
+
+            ];
+            const __DEFAULT_ACTION: &'static [i8] = &[
+                // State 0
+                0,  // no default reduction
+
+                // State 1
+                0,  // no default reduction
+
+                // State 2
+                0,  // no default reduction
+
+                // State 3
+                0,  // no default reduction
+
+                // State 4
+                -21,  // on any token, reduce `Term = Num => ActionFn(10);`
+
+                // State 5
+                -16,  // on any token, reduce `Factor = Term => ActionFn(7);`
+
+                // State 6
+                0,  // no default reduction
+
+                // State 7
+                0,  // no default reduction
+
+                // State 8
+                0,  // no default reduction
+
+                // State 9
+                -20,  // on any token, reduce `Num = r#"[0-9]+"# => ActionFn(15);`
+
+                // State 10
+                -17,  // on any token, reduce `Ident = r#"[a-z]+"# => ActionFn(16);`
+
+                // State 11
+                0,  // no default reduction
+
+                // State 12
+                -9,  // on any token, reduce `AddOp = "+" => ActionFn(4);`
+
+                // State 13
+                -10,  // on any token, reduce `AddOp = "-" => ActionFn(5);`
+
+                // State 14
+                0,  // no default reduction
+
+                // State 15
+                -18,  // on any token, reduce `MulOp = "*" => ActionFn(8);`
+
+                // State 16
+                -19,  // on any token, reduce `MulOp = "/" => ActionFn(9);`
+
+                // State 17
+                0,  // no default reduction
+
+                // State 18
+                0,  // no default reduction
+
+                // State 19
+                -23,  // on any token, reduce `Term = "-", Term => ActionFn(12);`
+
+                // State 20
+                0,  // no default reduction
+
+                // State 21
+                0,  // no default reduction
+
+                // State 22
+                -15,  // on any token, reduce `Factor = Factor, MulOp, Term => ActionFn(6);`
+
+                // State 23
+                0,  // no default reduction
+
+                // State 24
+                0,  // no default reduction
+
+                // State 25
+                -24,  // on any token, reduce `Term = Ident, "[", "]" => ActionFn(30);`
+
+                // State 26
+                -26,  // on any token, reduce `Term = "(", Expr, ")" => ActionFn(14);`
+
+                // State 27
+                0,  // no default reduction
+
+                // State 28
+                0,  // no default reduction
+
+                // State 29
+                0,  // no default reduction
+
+                // State 30
+                -25,  // on any token, reduce `Term = Ident, "[", (<Expr> ";")+, "]" => ActionFn(31);`
+
+                // State 31
+                -7,  // on any token, reduce `(<Expr> ";")+ = Expr, ";" => ActionFn(28);`
+
+                // State 32
+                0,  // no default reduction
+
+                // State 33
+                0,  // no default reduction
+
+                // State 34
+                -8,  // on any token, reduce `(<Expr> ";")+ = (<Expr> ";")+, Expr, ";" => ActionFn(29);`
+
+                // State 35
+                0,  // no default reduction
+
+                // State 36
+                -13,  // on any token, reduce `Expr = "let", Ident, "=", Expr, ";" => ActionFn(27);`
+
+                // State 37
+                0,  // no default reduction
+
+                // State 38
+                -12,  // on any token, reduce `Expr = "let", Ident, ":", Ident, "=", Expr, ";" => ActionFn(26);`
+
+            ];
+            fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+                const __TERMINAL: &'static [&'static str] = &[
+                    r###""(""###,
+                    r###"")""###,
+                    r###""*""###,
+                    r###""+""###,
+                    r###""-""###,
+                    r###""/""###,
+                    r###"":""###,
+                    r###"";""###,
+                    r###""=""###,
+                    r###""[""###,
+                    r###""]""###,
+                    r###""let""###,
+                    r###"r#"[0-9]+"#"###,
+                    r###"r#"[a-z]+"#"###,
+                ];
+                __ACTION[(__state * 14)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+                    if state == 0 {
+                        None
+                    } else {
+                        Some(terminal.to_string())
+                    }
+                }).collect()
+            }
+            #[allow(dead_code)]
+            pub fn parse_Expr<
+                'input,
+            >(
+                input: &'input str,
+            ) -> Result<Box<Expr>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, None).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() })
+            }
+            #[allow(dead_code)]
+            pub fn parse_Expr_cancelable<
+                'input,
+            >(
+                input: &'input str,
+                __cancel: &::std::sync::atomic::AtomicBool,
+            ) -> Result<Box<Expr>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                let mut __states = vec![];
+                let mut __symbols = vec![];
+                __parse::<>(input, 0, &mut __tokens, &mut __states, &mut __symbols, Some(__cancel)).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() })
+            }
+
+            #[allow(dead_code)]
+            pub struct ExprParser {
+                __states: ::std::vec::Vec<i32>,
+                __symbols: __lalrpop_util::stack::Recycled,
+                __cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,
+            }
+
+            #[allow(dead_code)]
+            impl ExprParser {
+                pub fn new() -> ExprParser {
+                    ExprParser { __states: vec![], __symbols: __lalrpop_util::stack::Recycled::new(), __cancel: None }
+                }
+
+                pub fn set_cancel_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+                    self.__cancel = Some(flag);
+                }
+
+                pub fn parse_in_place<'input>(
+                    &mut self,
+                    input: &'input str,
+                ) -> Result<Box<Expr>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+                {
+                    let mut __tokens = super::super::super::__intern_token::__Matcher::new(input);
+                    let mut __symbols = self.__symbols.take();
+                    let __result = __parse::<>(input, 0, &mut __tokens, &mut self.__states, &mut __symbols, self.__cancel.as_ref().map(|c| &**c)).and_then(|__v| match __v { __Symbol::Nt____Expr(__v) => Ok(__v), _ => unreachable!() });
+                    self.__symbols.put(__symbols);
+                    __result
+                }
+            }
+
+            fn __parse<
+                'input,
+            >(
+                input: &'input str,
+                __start_state: i32,
+                mut __tokens: &mut Iterator<Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>,
+                mut __states: &mut ::std::vec::Vec<i32>,
+                mut __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                __cancel: Option<&::std::sync::atomic::AtomicBool>,
+            ) -> Result<__Symbol<'input>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            {
+                __states.clear();
+                __states.push(__start_state);
+                __symbols.clear();
+                let mut __integer;
+                let mut __lookahead;
+                let __last_location: &mut usize = &mut Default::default();
+                let mut __default_reduced = [0_usize; 16];
+                let mut __default_reduced_len = 0;
+                '__shift: loop {
+                    loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __DEFAULT_ACTION[__state] as i32;
+                        if __action == 0 {
+                            break;
+                        }
+                        if __default_reduced_len < 16 {
+                            __default_reduced[__default_reduced_len] = __state;
+                            __default_reduced_len += 1;
+                        }
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    }
+                    __lookahead = match __tokens.next() {
+                        Some(Ok(v)) => v,
+                        None => break '__shift,
+                        Some(Err(e)) => return Err(e),
+                    };
+                    *__last_location = __lookahead.2.clone();
+                    __integer = match __lookahead.1 {
+                        Token(2, _) if true => 0,
+                        Token(3, _) if true => 1,
+                        Token(4, _) if true => 2,
+                        Token(5, _) if true => 3,
+                        Token(6, _) if true => 4,
+                        Token(7, _) if true => 5,
+                        Token(8, _) if true => 6,
+                        Token(9, _) if true => 7,
+                        Token(10, _) if true => 8,
+                        Token(11, _) if true => 9,
+                        Token(12, _) if true => 10,
+                        Token(13, _) if true => 11,
+                        Token(0, _) if true => 12,
+                        Token(1, _) if true => 13,
+                        _ => {
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| true).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match Some(__lookahead) {
+                                Some(__token) => {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error);
+                        }
+                    };
+                    '__inner: loop {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __action = __ACTION[__state * 14 + __integer] as i32;
+                        if __action > 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0 });
+                                }
+                            }
+                            let __symbol = match __integer {
+                                0 => match __lookahead.1 {
+                                    Token(2, __tok0) => __Symbol::Term_22_28_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                1 => match __lookahead.1 {
+                                    Token(3, __tok0) => __Symbol::Term_22_29_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                2 => match __lookahead.1 {
+                                    Token(4, __tok0) => __Symbol::Term_22_2a_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                3 => match __lookahead.1 {
+                                    Token(5, __tok0) => __Symbol::Term_22_2b_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                4 => match __lookahead.1 {
+                                    Token(6, __tok0) => __Symbol::Term_22_2d_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                5 => match __lookahead.1 {
+                                    Token(7, __tok0) => __Symbol::Term_22_2f_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                6 => match __lookahead.1 {
+                                    Token(8, __tok0) => __Symbol::Term_22_3a_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                7 => match __lookahead.1 {
+                                    Token(9, __tok0) => __Symbol::Term_22_3b_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                8 => match __lookahead.1 {
+                                    Token(10, __tok0) => __Symbol::Term_22_3d_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                9 => match __lookahead.1 {
+                                    Token(11, __tok0) => __Symbol::Term_22_5b_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                10 => match __lookahead.1 {
+                                    Token(12, __tok0) => __Symbol::Term_22_5d_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                11 => match __lookahead.1 {
+                                    Token(13, __tok0) => __Symbol::Term_22let_22((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                12 => match __lookahead.1 {
+                                    Token(0, __tok0) => __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                13 => match __lookahead.1 {
+                                    Token(1, __tok0) => __Symbol::Termr_23_22_5ba_2dz_5d_2b_22_23((__tok0)),
+                                    _ => unreachable!(),
+                                },
+                                _ => unreachable!(),
+                            };
+                            __states.push(__action - 1);
+                            __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                            __default_reduced_len = 0;
+                            continue '__shift;
+                        } else if __action < 0 {
+                            if let Some(__flag) = __cancel {
+                                if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                    return Err(__lalrpop_util::ParseError::Canceled { location: __lookahead.0.clone() });
+                                }
+                            }
+                            if let Some(r) = __reduce(input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                                if r.is_err() {
+                                    return r;
+                                }
+                                return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead, expected: vec![] });
+                            }
+                        } else {
+                            let mut __err_lookahead = Some(__lookahead);
+                            let mut __err_integer: Option<usize> = Some(__integer);
+                            let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 14 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                            let __expected = __expected_tokens(__state);
+                            let __error = match __err_lookahead {
+                                Some(__token) => {
+                                    if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                        __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                    } else {
+                                        __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                    }
+                                }
+                                None => {
+                                    __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                                }
+                            };
+                            return Err(__error)
+                        }
+                    }
+                }
+                loop {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __action = __EOF_ACTION[__state] as i32;
+                    if __action < 0 {
+                        if let Some(__flag) = __cancel {
+                            if __flag.load(::std::sync::atomic::Ordering::Relaxed) {
+                                return Err(__lalrpop_util::ParseError::Canceled { location: __last_location.clone() });
+                            }
+                        }
+                        if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            return r;
+                        }
+                    } else {
+                        let mut __err_lookahead = None;
+                        let mut __err_integer: Option<usize> = None;
+                        let __state = __default_reduced[..__default_reduced_len].iter().cloned().find(|&__s| match __err_integer { Some(__i) => __ACTION[__s * 14 + __i] == 0, None => __EOF_ACTION[__s] == 0 }).unwrap_or(*__states.last().unwrap() as usize);
+                        let __expected = __expected_tokens(__state);
+                        let __error = match __err_lookahead {
+                            Some(__token) => {
+                                if __accepts(input,  *__states.last().unwrap(), &__states[..__states.len() - 1], None, ::std::marker::PhantomData::<()>) {
+                                    __lalrpop_util::ParseError::ExtraToken { token: __token, expected: __expected }
+                                } else {
+                                    __lalrpop_util::ParseError::UnrecognizedToken { token: Some(__token), expected: __expected }
+                                }
+                            }
+                            None => {
+                                __lalrpop_util::ParseError::UnrecognizedEof { location: __last_location.clone(), expected: __expected }
+                            }
+                        };
+                        return Err(__error)
+                    }
+                }
+            }
+            fn __accepts<
+                'input,
+            >(
+                input: &'input str,
+                __error_state: i32,
+                __states: & [i32],
+                __opt_integer: Option<usize>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> bool
+            {
+                let mut __states = __states.to_vec();
+                __states.push(__error_state);
+                loop {
+                    let mut __states_len = __states.len();
+                    let __top = __states[__states_len - 1];
+                    let __action = match __opt_integer {
+                        None => __EOF_ACTION[__top as usize] as i32,
+                        Some(__integer) => __ACTION[(__top * 14) as usize + __integer] as i32,
+                    };
+                    if __action == 0 { return false; }
+                    if __action > 0 { return true; }
+                    let (__to_pop, __nt) = match -__action {
+                        // simulate (":" <Ident>) = ":", Ident => ActionFn(22);
+                        1 => {
+                            (2, 0)
+                        }
+                        // simulate (":" <Ident>)? = ":", Ident => ActionFn(25);
+                        2 => {
+                            (2, 1)
+                        }
+                        // simulate (":" <Ident>)? =  => ActionFn(21);
+                        3 => {
+                            (0, 1)
+                        }
+                        // simulate (<Expr> ";") = Expr, ";" => ActionFn(19);
+                        4 => {
+                            (2, 2)
+                        }
+                        // simulate (<Expr> ";")* =  => ActionFn(17);
+                        5 => {
+                            (0, 3)
+                        }
+                        // simulate (<Expr> ";")* = (<Expr> ";")+ => ActionFn(18);
+                        6 => {
+                            (1, 3)
+                        }
+                        // simulate (<Expr> ";")+ = Expr, ";" => ActionFn(28);
+                        7 => {
+                            (2, 4)
+                        }
+                        // simulate (<Expr> ";")+ = (<Expr> ";")+, Expr, ";" => ActionFn(29);
+                        8 => {
+                            (3, 4)
+                        }
+                        // simulate AddOp = "+" => ActionFn(4);
+                        9 => {
+                            (1, 5)
+                        }
+                        // simulate AddOp = "-" => ActionFn(5);
+                        10 => {
+                            (1, 5)
+                        }
+                        // simulate Expr = Expr, AddOp, Factor => ActionFn(1);
+                        11 => {
+                            (3, 6)
+                        }
+                        // simulate Expr = "let", Ident, ":", Ident, "=", Expr, ";" => ActionFn(26);
+                        12 => {
+                            (7, 6)
+                        }
+                        // simulate Expr = "let", Ident, "=", Expr, ";" => ActionFn(27);
+                        13 => {
+                            (5, 6)
+                        }
+                        // simulate Expr = Factor => ActionFn(3);
+                        14 => {
+                            (1, 6)
+                        }
+                        // simulate Factor = Factor, MulOp, Term => ActionFn(6);
+                        15 => {
+                            (3, 7)
+                        }
+                        // simulate Factor = Term => ActionFn(7);
+                        16 => {
+                            (1, 7)
+                        }
+                        // simulate Ident = r#"[a-z]+"# => ActionFn(16);
+                        17 => {
+                            (1, 8)
+                        }
+                        // simulate MulOp = "*" => ActionFn(8);
+                        18 => {
+                            (1, 9)
+                        }
+                        // simulate MulOp = "/" => ActionFn(9);
+                        19 => {
+                            (1, 9)
+                        }
+                        // simulate Num = r#"[0-9]+"# => ActionFn(15);
+                        20 => {
+                            (1, 10)
+                        }
+                        // simulate Term = Num => ActionFn(10);
+                        21 => {
+                            (1, 11)
+                        }
+                        // simulate Term = Ident => ActionFn(11);
+                        22 => {
+                            (1, 11)
+                        }
+                        // simulate Term = "-", Term => ActionFn(12);
+                        23 => {
+                            (2, 11)
+                        }
+                        // simulate Term = Ident, "[", "]" => ActionFn(30);
+                        24 => {
+                            (3, 11)
+                        }
+                        // simulate Term = Ident, "[", (<Expr> ";")+, "]" => ActionFn(31);
+                        25 => {
+                            (4, 11)
+                        }
+                        // simulate Term = "(", Expr, ")" => ActionFn(14);
+                        26 => {
+                            (3, 11)
+                        }
+                        // simulate __Expr = Expr => ActionFn(0);
+                        27 => return true,
+                        _ => panic!("invalid action code {}", __action)
+                    };
+                    __states_len -= __to_pop;
+                    __states.truncate(__states_len);
+                    let __top = __states[__states_len - 1];
+                    let __next_state = __GOTO[(__top * 13 + __nt) as usize] as i32 - 1;
+                    __states.push(__next_state);
+                }
+            }
+            pub fn __reduce<
+                'input,
+            >(
+                input: &'input str,
+                __action: i32,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Option<Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                let __nonterminal = match -__action {
+                    1 => match __reduce__28_22_3a_22_20_3cIdent_3e_29_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    2 => match __reduce__28_22_3a_22_20_3cIdent_3e_29_3f_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    3 => match __reduce__28_22_3a_22_20_3cIdent_3e_29_3f_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    4 => match __reduce__28_3cExpr_3e_20_22_3b_22_29_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    5 => match __reduce__28_3cExpr_3e_20_22_3b_22_29_2a_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    6 => match __reduce__28_3cExpr_3e_20_22_3b_22_29_2a_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    7 => match __reduce__28_3cExpr_3e_20_22_3b_22_29_2b_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    8 => match __reduce__28_3cExpr_3e_20_22_3b_22_29_2b_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    9 => match __reduce_AddOp_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    10 => match __reduce_AddOp_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    11 => match __reduce_Expr_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    12 => match __reduce_Expr_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    13 => match __reduce_Expr_2(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    14 => match __reduce_Expr_3(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    15 => match __reduce_Factor_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    16 => match __reduce_Factor_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    17 => match __reduce_Ident_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    18 => match __reduce_MulOp_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    19 => match __reduce_MulOp_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    20 => match __reduce_Num_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    21 => match __reduce_Term_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    22 => match __reduce_Term_1(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    23 => match __reduce_Term_2(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    24 => match __reduce_Term_3(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    25 => match __reduce_Term_4(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    26 => match __reduce_Term_5(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    27 => match __reduce_____Expr_0(input, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>) {
+                        Ok(v) => v,
+                        Err(r) => return Some(r),
+                    },
+                    _ => panic!("invalid action code {}", __action)
+                };
+                let __state = *__states.last().unwrap() as usize;
+                let __next_state = __GOTO[__state * 13 + __nonterminal] as i32 - 1;
+                __states.push(__next_state);
+                None
+            }
+
+            fn __reduce__28_22_3a_22_20_3cIdent_3e_29_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (":" <Ident>) = ":", Ident => ActionFn(22);
+                let __sym1 = __pop_NtIdent(__symbols);
+                let __sym0 = __pop_Term_22_3a_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action22::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_3a_22_20_3cIdent_3e_29(__nt), __end));
+                Ok(0)
+            }
+
+            fn __reduce__28_22_3a_22_20_3cIdent_3e_29_3f_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (":" <Ident>)? = ":", Ident => ActionFn(25);
+                let __sym1 = __pop_NtIdent(__symbols);
+                let __sym0 = __pop_Term_22_3a_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action25::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_3a_22_20_3cIdent_3e_29_3f(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce__28_22_3a_22_20_3cIdent_3e_29_3f_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (":" <Ident>)? =  => ActionFn(21);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action21::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_28_22_3a_22_20_3cIdent_3e_29_3f(__nt), __end));
+                Ok(1)
+            }
+
+            fn __reduce__28_3cExpr_3e_20_22_3b_22_29_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<Expr> ";") = Expr, ";" => ActionFn(19);
+                let __sym1 = __pop_Term_22_3b_22(__symbols);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action19::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_3cExpr_3e_20_22_3b_22_29(__nt), __end));
+                Ok(2)
+            }
+
+            fn __reduce__28_3cExpr_3e_20_22_3b_22_29_2a_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<Expr> ";")* =  => ActionFn(17);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::super::super::__action17::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_28_3cExpr_3e_20_22_3b_22_29_2a(__nt), __end));
+                Ok(3)
+            }
+
+            fn __reduce__28_3cExpr_3e_20_22_3b_22_29_2a_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<Expr> ";")* = (<Expr> ";")+ => ActionFn(18);
+                let __sym0 = __pop_Nt_28_3cExpr_3e_20_22_3b_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action18::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::Nt_28_3cExpr_3e_20_22_3b_22_29_2a(__nt), __end));
+                Ok(3)
+            }
+
+            fn __reduce__28_3cExpr_3e_20_22_3b_22_29_2b_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<Expr> ";")+ = Expr, ";" => ActionFn(28);
+                let __sym1 = __pop_Term_22_3b_22(__symbols);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action28::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_3cExpr_3e_20_22_3b_22_29_2b(__nt), __end));
+                Ok(4)
+            }
+
+            fn __reduce__28_3cExpr_3e_20_22_3b_22_29_2b_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // (<Expr> ";")+ = (<Expr> ";")+, Expr, ";" => ActionFn(29);
+                let __sym2 = __pop_Term_22_3b_22(__symbols);
+                let __sym1 = __pop_NtExpr(__symbols);
+                let __sym0 = __pop_Nt_28_3cExpr_3e_20_22_3b_22_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action29::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::Nt_28_3cExpr_3e_20_22_3b_22_29_2b(__nt), __end));
+                Ok(4)
+            }
+
+            fn __reduce_AddOp_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // AddOp = "+" => ActionFn(4);
+                let __sym0 = __pop_Term_22_2b_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action4::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAddOp(__nt), __end));
+                Ok(5)
+            }
+
+            fn __reduce_AddOp_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // AddOp = "-" => ActionFn(5);
+                let __sym0 = __pop_Term_22_2d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action5::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAddOp(__nt), __end));
+                Ok(5)
+            }
+
+            fn __reduce_Expr_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expr = Expr, AddOp, Factor => ActionFn(1);
+                let __sym2 = __pop_NtFactor(__symbols);
+                let __sym1 = __pop_NtAddOp(__symbols);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action1::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+                Ok(6)
+            }
+
+            fn __reduce_Expr_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expr = "let", Ident, ":", Ident, "=", Expr, ";" => ActionFn(26);
+                let __sym6 = __pop_Term_22_3b_22(__symbols);
+                let __sym5 = __pop_NtExpr(__symbols);
+                let __sym4 = __pop_Term_22_3d_22(__symbols);
+                let __sym3 = __pop_NtIdent(__symbols);
+                let __sym2 = __pop_Term_22_3a_22(__symbols);
+                let __sym1 = __pop_NtIdent(__symbols);
+                let __sym0 = __pop_Term_22let_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym6.2.clone();
+                let __nt = super::super::super::__action26::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 7);
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+                Ok(6)
+            }
+
+            fn __reduce_Expr_2<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expr = "let", Ident, "=", Expr, ";" => ActionFn(27);
+                let __sym4 = __pop_Term_22_3b_22(__symbols);
+                let __sym3 = __pop_NtExpr(__symbols);
+                let __sym2 = __pop_Term_22_3d_22(__symbols);
+                let __sym1 = __pop_NtIdent(__symbols);
+                let __sym0 = __pop_Term_22let_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = super::super::super::__action27::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 5);
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+                Ok(6)
+            }
+
+            fn __reduce_Expr_3<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Expr = Factor => ActionFn(3);
+                let __sym0 = __pop_NtFactor(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action3::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+                Ok(6)
+            }
+
+            fn __reduce_Factor_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Factor = Factor, MulOp, Term => ActionFn(6);
+                let __sym2 = __pop_NtTerm(__symbols);
+                let __sym1 = __pop_NtMulOp(__symbols);
+                let __sym0 = __pop_NtFactor(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action6::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtFactor(__nt), __end));
+                Ok(7)
+            }
+
+            fn __reduce_Factor_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Factor = Term => ActionFn(7);
+                let __sym0 = __pop_NtTerm(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action7::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtFactor(__nt), __end));
+                Ok(7)
+            }
+
+            fn __reduce_Ident_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Ident = r#"[a-z]+"# => ActionFn(16);
+                let __sym0 = __pop_Termr_23_22_5ba_2dz_5d_2b_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action16::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtIdent(__nt), __end));
+                Ok(8)
+            }
+
+            fn __reduce_MulOp_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // MulOp = "*" => ActionFn(8);
+                let __sym0 = __pop_Term_22_2a_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action8::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtMulOp(__nt), __end));
+                Ok(9)
+            }
+
+            fn __reduce_MulOp_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // MulOp = "/" => ActionFn(9);
+                let __sym0 = __pop_Term_22_2f_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action9::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtMulOp(__nt), __end));
+                Ok(9)
+            }
+
+            fn __reduce_Num_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Num = r#"[0-9]+"# => ActionFn(15);
+                let __sym0 = __pop_Termr_23_22_5b0_2d9_5d_2b_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action15::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtNum(__nt), __end));
+                Ok(10)
+            }
+
+            fn __reduce_Term_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Term = Num => ActionFn(10);
+                let __sym0 = __pop_NtNum(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action10::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+                Ok(11)
+            }
+
+            fn __reduce_Term_1<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Term = Ident => ActionFn(11);
+                let __sym0 = __pop_NtIdent(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action11::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+                Ok(11)
+            }
+
+            fn __reduce_Term_2<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Term = "-", Term => ActionFn(12);
+                let __sym1 = __pop_NtTerm(__symbols);
+                let __sym0 = __pop_Term_22_2d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::super::super::__action12::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+                Ok(11)
+            }
+
+            fn __reduce_Term_3<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Term = Ident, "[", "]" => ActionFn(30);
+                let __sym2 = __pop_Term_22_5d_22(__symbols);
+                let __sym1 = __pop_Term_22_5b_22(__symbols);
+                let __sym0 = __pop_NtIdent(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action30::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+                Ok(11)
+            }
+
+            fn __reduce_Term_4<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Term = Ident, "[", (<Expr> ";")+, "]" => ActionFn(31);
+                let __sym3 = __pop_Term_22_5d_22(__symbols);
+                let __sym2 = __pop_Nt_28_3cExpr_3e_20_22_3b_22_29_2b(__symbols);
+                let __sym1 = __pop_Term_22_5b_22(__symbols);
+                let __sym0 = __pop_NtIdent(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::super::super::__action31::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+                Ok(11)
+            }
+
+            fn __reduce_Term_5<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // Term = "(", Expr, ")" => ActionFn(14);
+                let __sym2 = __pop_Term_22_29_22(__symbols);
+                let __sym1 = __pop_NtExpr(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::super::super::__action14::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+                Ok(11)
+            }
+
+            fn __reduce_____Expr_0<
+                'input,
+            >(
+                input: &'input str,
+                __lookahead_start: Option<&usize>,
+                __states: &mut ::std::vec::Vec<i32>,
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+                _: ::std::marker::PhantomData<()>,
+            ) -> Result<usize, Result<__Symbol<'input>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+            {
+                // __Expr = Expr => ActionFn(0);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::super::super::__action0::<>(input, __sym0);
+                Err(Ok(__Symbol::Nt____Expr(__nt)))
+            }
+            fn __pop_Term_22_28_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_28_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_29_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_29_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_2a_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2a_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_2b_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2b_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_2d_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2d_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_2f_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_2f_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_3a_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_3a_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_3b_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_3b_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_3d_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_3d_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_5b_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_5b_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22_5d_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22_5d_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Term_22let_22<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Term_22let_22(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Termr_23_22_5b0_2d9_5d_2b_22_23<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Termr_23_22_5ba_2dz_5d_2b_22_23<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, &'input str, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Termr_23_22_5ba_2dz_5d_2b_22_23(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_28_22_3a_22_20_3cIdent_3e_29<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, String, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_28_22_3a_22_20_3cIdent_3e_29(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_28_22_3a_22_20_3cIdent_3e_29_3f<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, ::std::option::Option<String>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_28_22_3a_22_20_3cIdent_3e_29_3f(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_28_3cExpr_3e_20_22_3b_22_29<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Box<Expr>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_28_3cExpr_3e_20_22_3b_22_29(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_28_3cExpr_3e_20_22_3b_22_29_2a<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, ::std::vec::Vec<Box<Expr>>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_28_3cExpr_3e_20_22_3b_22_29_2a(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt_28_3cExpr_3e_20_22_3b_22_29_2b<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, ::std::vec::Vec<Box<Expr>>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt_28_3cExpr_3e_20_22_3b_22_29_2b(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtAddOp<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Op, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtAddOp(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtExpr<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Box<Expr>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtExpr(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtFactor<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Box<Expr>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtFactor(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtIdent<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, String, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtIdent(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtMulOp<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Op, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtMulOp(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtNum<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, i32, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtNum(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_NtTerm<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Box<Expr>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::NtTerm(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+            fn __pop_Nt____Expr<
+              'input,
+            >(
+                __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+            ) -> (usize, Box<Expr>, usize)
+             {
+                match __symbols.pop().unwrap() {
+                    (__l, __Symbol::Nt____Expr(__v), __r) => (__l, __v, __r),
+                    _ => panic!("symbol type mismatch")
+                }
+            }
+        }
+        pub use self::__parse_table::parse_Expr;
+        pub use self::__parse_table::parse_Expr_cancelable;
+        pub use self::__parse_table::ExprParser;
+    }
+    pub use self::__parse_table::parse_Expr_cancelable;
+    pub use self::__parse_table::ExprParser;
+}
+pub use self::__parse__Expr::parse_Expr;
+pub use self::__parse__Expr::parse_Expr_cancelable;
+pub use self::__parse__Expr::ExprParser;
+mod __intern_token {
+    #![allow(unused_imports)]
+    use std::str::FromStr;
+    use unparse_ast::{Expr, Op};
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    extern crate regex as __regex;
+    use std::fmt as __fmt;
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Token<'input>(pub usize, pub &'input str);
+    impl<'a> __fmt::Display for Token<'a> {
+        fn fmt(&self, formatter: &mut __fmt::Formatter) -> Result<(), __fmt::Error> {
+            __fmt::Display::fmt(self.1, formatter)
+        }
+    }
+
+    pub struct __Matcher<'input> {
+        text: &'input str,
+        consumed: usize,
+        regex_set: __regex::RegexSet,
+        regex_vec: Vec<__regex::Regex>,
+    }
+
+    impl<'input> __Matcher<'input> {
+        pub fn new(s: &'input str) -> __Matcher<'input> {
+            let __strs: &[&str] = &[
+                "^(?u:[0-9])+",
+                "^(?u:[a-z])+",
+                "^(?u:\\()",
+                "^(?u:\\))",
+                "^(?u:\\*)",
+                "^(?u:\\+)",
+                "^(?u:\\-)",
+                "^(?u:/)",
+                "^(?u::)",
+                "^(?u:;)",
+                "^(?u:=)",
+                "^(?u:\\[)",
+                "^(?u:\\])",
+                "^(?u:let)",
+            ];
+            let __regex_set = __regex::RegexSet::new(__strs).unwrap();
+            let __regex_vec = vec![
+                __regex::Regex::new("^(?u:[0-9])+").unwrap(),
+                __regex::Regex::new("^(?u:[a-z])+").unwrap(),
+                __regex::Regex::new("^(?u:\\()").unwrap(),
+                __regex::Regex::new("^(?u:\\))").unwrap(),
+                __regex::Regex::new("^(?u:\\*)").unwrap(),
+                __regex::Regex::new("^(?u:\\+)").unwrap(),
+                __regex::Regex::new("^(?u:\\-)").unwrap(),
+                __regex::Regex::new("^(?u:/)").unwrap(),
+                __regex::Regex::new("^(?u::)").unwrap(),
+                __regex::Regex::new("^(?u:;)").unwrap(),
+                __regex::Regex::new("^(?u:=)").unwrap(),
+                __regex::Regex::new("^(?u:\\[)").unwrap(),
+                __regex::Regex::new("^(?u:\\])").unwrap(),
+                __regex::Regex::new("^(?u:let)").unwrap(),
+            ];
+            __Matcher {
+                text: s,
+                consumed: 0,
+                regex_set: __regex_set,
+                regex_vec: __regex_vec,
+            }
+        }
+    }
+
+    impl<'input> Iterator for __Matcher<'input> {
+        type Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize,Token<'input>,&'static str>>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let __text = self.text.trim_left();
+            let __whitespace = self.text.len() - __text.len();
+            let __start_offset = self.consumed + __whitespace;
+            if __text.is_empty() {
+                self.text = __text;
+                self.consumed = __start_offset;
+                None
+            } else {
+                let __matches = self.regex_set.matches(__text);
+                if !__matches.matched_any() {
+                    Some(Err(__lalrpop_util::ParseError::InvalidToken {
+                        location: __start_offset,
+                    }))
+                } else {
+                    let mut __longest_match = 0;
+                    let mut __index = 0;
+                    for __i in 0 .. 14 {
+                        if __matches.matched(__i) {
+                            let __match = self.regex_vec[__i].find(__text).unwrap();
+                            let __len = __match.end();
+                            if __len >= __longest_match {
+                                __longest_match = __len;
+                                __index = __i;
+                            }
+                        }
+                    }
+                    let __result = &__text[..__longest_match];
+                    let __remaining = &__text[__longest_match..];
+                    let __end_offset = __start_offset + __longest_match;
+                    self.text = __remaining;
+                    self.consumed = __end_offset;
+                    Some(Ok((__start_offset, Token(__index, __result), __end_offset)))
+                }
+            }
+        }
+    }
+}
+pub use self::__intern_token::Token;
+
+#[allow(unused_variables)]
+fn __action0<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, Box<Expr>, usize),
+    (_, op, _): (usize, Op, usize),
+    (_, r, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    //@ src/unparse.lalrpop:11:39 (1 lines)
+    Box::new(Expr::Op(l, op, r))
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, name, _): (usize, String, usize),
+    (_, ty, _): (usize, ::std::option::Option<String>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, value, _): (usize, Box<Expr>, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> Box<Expr>
+{
+    //@ src/unparse.lalrpop:13:9 (1 lines)
+    Box::new(Expr::Let { name: name, ty: ty, value: value })
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Op
+{
+    //@ src/unparse.lalrpop:18:12 (1 lines)
+    Op::Add
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Op
+{
+    //@ src/unparse.lalrpop:19:12 (1 lines)
+    Op::Sub
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, Box<Expr>, usize),
+    (_, op, _): (usize, Op, usize),
+    (_, r, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    //@ src/unparse.lalrpop:23:39 (1 lines)
+    Box::new(Expr::Op(l, op, r))
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Op
+{
+    //@ src/unparse.lalrpop:28:12 (1 lines)
+    Op::Mul
+}
+
+#[allow(unused_variables)]
+fn __action9<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Op
+{
+    //@ src/unparse.lalrpop:29:12 (1 lines)
+    Op::Div
+}
+
+#[allow(unused_variables)]
+fn __action10<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, i32, usize),
+) -> Box<Expr>
+{
+    //@ src/unparse.lalrpop:33:12 (1 lines)
+    Box::new(Expr::Num(__0))
+}
+
+#[allow(unused_variables)]
+fn __action11<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, String, usize),
+) -> Box<Expr>
+{
+    //@ src/unparse.lalrpop:34:14 (1 lines)
+    Box::new(Expr::Var(__0))
+}
+
+#[allow(unused_variables)]
+fn __action12<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    //@ src/unparse.lalrpop:35:19 (1 lines)
+    Box::new(Expr::Neg(__0))
+}
+
+#[allow(unused_variables)]
+fn __action13<
+    'input,
+>(
+    input: &'input str,
+    (_, f, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, args, _): (usize, ::std::vec::Vec<Box<Expr>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> Box<Expr>
+{
+    //@ src/unparse.lalrpop:36:47 (1 lines)
+    Box::new(Expr::Call(f, args))
+}
+
+#[allow(unused_variables)]
+fn __action14<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, Box<Expr>, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> Box<Expr>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action15<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> i32
+{
+    //@ src/unparse.lalrpop:41:25 (1 lines)
+    i32::from_str(__0).unwrap()
+}
+
+#[allow(unused_variables)]
+fn __action16<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> String
+{
+    //@ src/unparse.lalrpop:44:30 (1 lines)
+    __0.to_string()
+}
+
+#[allow(unused_variables)]
+fn __action17<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::vec::Vec<Box<Expr>>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+fn __action18<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<Box<Expr>>, usize),
+) -> ::std::vec::Vec<Box<Expr>>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action19<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Box<Expr>, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> Box<Expr>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action20<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, String, usize),
+) -> ::std::option::Option<String>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action21<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::option::Option<String>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action22<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, String, usize),
+) -> String
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action23<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> ::std::vec::Vec<Box<Expr>>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action24<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<Box<Expr>>, usize),
+    (_, e, _): (usize, Box<Expr>, usize),
+) -> ::std::vec::Vec<Box<Expr>>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action25<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+) -> ::std::option::Option<String>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action22(
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action20(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action26<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, String, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Box<Expr>, usize),
+    __6: (usize, &'input str, usize),
+) -> Box<Expr>
+{
+    let __start0 = __2.0.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action25(
+        input,
+        __2,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action2(
+        input,
+        __0,
+        __1,
+        __temp0,
+        __4,
+        __5,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action27<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Expr>, usize),
+    __4: (usize, &'input str, usize),
+) -> Box<Expr>
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action21(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action2(
+        input,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action28<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Box<Expr>, usize),
+    __1: (usize, &'input str, usize),
+) -> ::std::vec::Vec<Box<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action19(
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action23(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action29<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::vec::Vec<Box<Expr>>, usize),
+    __1: (usize, Box<Expr>, usize),
+    __2: (usize, &'input str, usize),
+) -> ::std::vec::Vec<Box<Expr>>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action19(
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action24(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action30<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, String, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, &'input str, usize),
+) -> Box<Expr>
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action17(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action13(
+        input,
+        __0,
+        __1,
+        __temp0,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action31<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, String, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, ::std::vec::Vec<Box<Expr>>, usize),
+    __3: (usize, &'input str, usize),
+) -> Box<Expr>
+{
+    let __start0 = __2.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action18(
+        input,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action13(
+        input,
+        __0,
+        __1,
+        __temp0,
+        __3,
+    )
+}
+
+#[allow(non_snake_case)]
+pub fn unparse_Expr<'input>(value: &Box<Expr>) -> Option<String> {
+    let mut __out = String::new();
+    if __unparse(&mut __out, value, __unparse0, 11) {
+        Some(__out)
+    } else {
+        None
+    }
+}
+
+fn __unparse<T: ?Sized>(__out: &mut String, __value: &T, __unparser: fn(&mut String, &T, usize) -> bool, __max_hops: usize) -> bool {
+    for __hops in 0..__max_hops + 1 {
+        let __start = __out.len();
+        if __unparser(__out, __value, __hops) {
+            return true;
+        }
+        __out.truncate(__start);
+    }
+    false
+}
+
+fn __unparse_text(__out: &mut String, __text: &str) -> bool {
+    let __open = __out.ends_with(|c| c == '(' || c == '[' || c == '{');
+    let __close = __text.starts_with(|c| c == ')' || c == ']' || c == '}' || c == ',' || c == ';');
+    if !__out.is_empty() && !__text.is_empty() && !__open && !__close {
+        __out.push(' ');
+    }
+    __out.push_str(__text);
+    true
+}
+
+// Expr
+fn __unparse0<'input>(__out: &mut String, __value: &Box<Expr>, __hops: usize) -> bool {
+    let __start = __out.len();
+    match **__value {
+        Expr::Op(ref l, ref op, ref r) if true => {
+            if __unparse(__out, l, __unparse0, 11) && __unparse(__out, op, __unparse1, 11) && __unparse(__out, r, __unparse2, 11) {
+                return true;
+            }
+            __out.truncate(__start);
+        }
+        _ => {}
+    }
+    match **__value {
+        Expr::Let { name: ref name, ty: ref ty, value: ref value } if true => {
+            if __unparse_text(__out, "let") && __unparse(__out, name, __unparse3, 11) && __unparse(__out, ty, __unparse4, 11) && __unparse_text(__out, "=") && __unparse(__out, value, __unparse0, 11) && __unparse_text(__out, ";") {
+                return true;
+            }
+            __out.truncate(__start);
+        }
+        _ => {}
+    }
+    if __hops > 0 {
+        if __unparse2(__out, __value, __hops - 1) {
+            return true;
+        }
+        __out.truncate(__start);
+    }
+    false
+}
+
+// AddOp
+fn __unparse1<'input>(__out: &mut String, __value: &Op, _: usize) -> bool {
+    let __start = __out.len();
+    match *__value {
+        Op::Add if true => {
+            if __unparse_text(__out, "+") {
+                return true;
+            }
+            __out.truncate(__start);
+        }
+        _ => {}
+    }
+    match *__value {
+        Op::Sub if true => {
+            if __unparse_text(__out, "-") {
+                return true;
+            }
+            __out.truncate(__start);
+        }
+        _ => {}
+    }
+    false
+}
+
+// Factor
+fn __unparse2<'input>(__out: &mut String, __value: &Box<Expr>, __hops: usize) -> bool {
+    let __start = __out.len();
+    match **__value {
+        Expr::Op(ref l, ref op, ref r) if true => {
+            if __unparse(__out, l, __unparse2, 11) && __unparse(__out, op, __unparse5, 11) && __unparse(__out, r, __unparse6, 11) {
+                return true;
+            }
+            __out.truncate(__start);
+        }
+        _ => {}
+    }
+    if __hops > 0 {
+        if __unparse6(__out, __value, __hops - 1) {
+            return true;
+        }
+        __out.truncate(__start);
+    }
+    false
+}
+
+// Ident
+fn __unparse3<'input>(__out: &mut String, __value: &String, _: usize) -> bool {
+    __unparse_text(__out, &format!("{}", __value))
+}
+
+// (":" <Ident>)?
+fn __unparse4<'input>(__out: &mut String, __value: &::std::option::Option<String>, _: usize) -> bool {
+    let __start = __out.len();
+    match *__value {
+        Some(ref __0) if true => {
+            if __unparse(__out, __0, __unparse7, 11) {
+                return true;
+            }
+            __out.truncate(__start);
+        }
+        _ => {}
+    }
+    match *__value {
+        None if true => {
+            return true;
+        }
+        _ => {}
+    }
+    false
+}
+
+// MulOp
+fn __unparse5<'input>(__out: &mut String, __value: &Op, _: usize) -> bool {
+    let __start = __out.len();
+    match *__value {
+        Op::Mul if true => {
+            if __unparse_text(__out, "*") {
+                return true;
+            }
+            __out.truncate(__start);
+        }
+        _ => {}
+    }
+    match *__value {
+        Op::Div if true => {
+            if __unparse_text(__out, "/") {
+                return true;
+            }
+            __out.truncate(__start);
+        }
+        _ => {}
+    }
+    false
+}
+
+// Term
+fn __unparse6<'input>(__out: &mut String, __value: &Box<Expr>, __hops: usize) -> bool {
+    let __start = __out.len();
+    match **__value {
+        Expr::Num(ref __0) if true => {
+            if __unparse(__out, __0, __unparse8, 11) {
+                return true;
+            }
+            __out.truncate(__start);
+        }
+        _ => {}
+    }
+    match **__value {
+        Expr::Var(ref __0) if true => {
+            if __unparse(__out, __0, __unparse3, 11) {
+                return true;
+            }
+            __out.truncate(__start);
+        }
+        _ => {}
+    }
+    match **__value {
+        Expr::Neg(ref __0) if true => {
+            if __unparse_text(__out, "-") && __unparse(__out, __0, __unparse6, 11) {
+                return true;
+            }
+            __out.truncate(__start);
+        }
+        _ => {}
+    }
+    match **__value {
+        Expr::Call(ref f, ref args) if true => {
+            if __unparse(__out, f, __unparse3, 11) && __unparse_text(__out, "[") && __unparse(__out, args, __unparse9, 11) && __unparse_text(__out, "]") {
+                return true;
+            }
+            __out.truncate(__start);
+        }
+        _ => {}
+    }
+    if __hops > 0 {
+        if __unparse_text(__out, "(") && __unparse0(__out, __value, __hops - 1) && __unparse_text(__out, ")") {
+            return true;
+        }
+        __out.truncate(__start);
+    }
+    false
+}
+
+// (":" <Ident>)
+fn __unparse7<'input>(__out: &mut String, __value: &String, __hops: usize) -> bool {
+    let __start = __out.len();
+    if __hops > 0 {
+        if __unparse_text(__out, ":") && __unparse3(__out, __value, __hops - 1) {
+            return true;
+        }
+        __out.truncate(__start);
+    }
+    false
+}
+
+// Num
+fn __unparse8<'input>(__out: &mut String, __value: &i32, _: usize) -> bool {
+    __unparse_text(__out, &format!("{}", __value))
+}
+
+// (<Expr> ";")*
+fn __unparse9<'input>(__out: &mut String, __value: &::std::vec::Vec<Box<Expr>>, _: usize) -> bool {
+    for __e in __value {
+        if !__unparse(__out, __e, __unparse10, 11) {
+            return false;
+        }
+    }
+    true
+}
+
+// (<Expr> ";")
+fn __unparse10<'input>(__out: &mut String, __value: &Box<Expr>, __hops: usize) -> bool {
+    let __start = __out.len();
+    if __hops > 0 {
+        if __unparse0(__out, __value, __hops - 1) && __unparse_text(__out, ";") {
+            return true;
+        }
+        __out.truncate(__start);
+    }
+    false
+}
+
+pub trait __ToTriple<'input, > {
+    type Error;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),Self::Error>;
+}
+
+impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize) {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        Ok(value)
+    }
+}
+impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize),&'static str> {
+    type Error = &'static str;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+        value
+    }
+}
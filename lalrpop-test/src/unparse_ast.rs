@@ -0,0 +1,19 @@
+//! The AST that the `unparse` parser builds and writes back as text.
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expr {
+    Num(i32),
+    Var(String),
+    Neg(Box<Expr>),
+    Op(Box<Expr>, Op, Box<Expr>),
+    Call(String, Vec<Box<Expr>>),
+    Let { name: String, ty: Option<String>, value: Box<Expr> },
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
@@ -0,0 +1,28 @@
+//! A global allocator that counts the allocations of each thread, so
+//! that tests can check that a parser does without them.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+pub struct CountingAllocator;
+
+thread_local! {
+    // (a `const` initializer, which counting cannot allocate for)
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+/// The number of allocations the current thread has made so far.
+pub fn allocations() -> usize {
+    ALLOCATIONS.with(|count| count.get())
+}
@@ -8,6 +8,8 @@ use util::tok::Tok;
 // a simple tokenizer
 pub mod tok;
 
+pub mod count_alloc;
+
 pub fn test<R: Debug + Eq, F>(parse_fn: F, input: &str, expected: R)
     where F: FnOnce(Vec<Tok>) -> Result<R, ParseError<(), Tok, &'static str>>
 {
@@ -0,0 +1,1023 @@
+//! The runtime of the Earley backend, which LALRPOP uses for grammars
+//! marked `#![earley]`. Rather than compiling the grammar into parse
+//! tables, the generated parser describes its productions with the
+//! types below and hands them, along with the terminals of the input,
+//! to `Chart::parse`. That works for any context-free grammar,
+//! ambiguous or not, at the cost of speed; it is meant for trying
+//! out a grammar before making it LR(1).
+//!
+//! The result of a successful parse is a sequence of `Step`s: the
+//! shifts and reductions an LR parser would have performed for the
+//! same derivation, which the generated code replays to run the
+//! action code. When the input is ambiguous, `Chart::forest` gives
+//! all of its derivations, as a shared packed parse forest.
+//!
+//! Where a derivation uses the same node of the forest more than once,
+//! or the derivations of an ambiguous input share one, replaying them
+//! runs the same action code again. `Forest::resolve_memoized` and
+//! `Forest::derivations_memoized` mark those nodes with `Step::Keep`
+//! and `Step::Recall`, so that a replay can keep the value built for
+//! such a node and reuse it instead.
+
+use std::collections::{HashMap, HashSet};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Symbol {
+    Terminal(usize),
+    Nonterminal(usize),
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct Production {
+    pub nonterminal: usize,
+    pub symbols: &'static [Symbol],
+
+    /// When a nonterminal can be derived in several ways,
+    /// `Forest::resolve` only considers the productions with the
+    /// highest `dprec`.
+    pub dprec: u32,
+
+    /// If all the productions `Forest::resolve` considers have the
+    /// same `merge`, their results are merged with it instead of
+    /// keeping just the first.
+    pub merge: Option<usize>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Step {
+    /// Consume the next token.
+    Shift,
+
+    /// Reduce the production with the given index.
+    Reduce(usize),
+
+    /// Go back to the token with the given index, to derive the same
+    /// tokens again.
+    Rewind(usize),
+
+    /// Merge the two symbols on top of the stack with the merge
+    /// function with the given index.
+    Merge(usize),
+
+    /// If the replay has kept the value of the forest node with index
+    /// `node` (see `Keep`), push that value, go on from the token with
+    /// index `end`, and skip the `skip` steps that follow, which derive
+    /// the node again; otherwise carry on with them.
+    Recall { node: usize, end: usize, skip: usize },
+
+    /// Keep the symbol on top of the stack as the value of the forest
+    /// node with the given index.
+    Keep(usize),
+}
+
+/// An Earley item: a production, how much of it has been matched,
+/// and the index of the token where the match started.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+struct Item {
+    production: usize,
+    dot: usize,
+    origin: usize,
+}
+
+#[derive(Default)]
+struct ItemSet {
+    items: Vec<Item>,
+    seen: HashSet<Item>,
+}
+
+impl ItemSet {
+    fn insert(&mut self, item: Item) {
+        if self.seen.insert(item) {
+            self.items.push(item);
+        }
+    }
+
+    fn contains(&self, item: &Item) -> bool {
+        self.seen.contains(item)
+    }
+}
+
+pub struct Chart<'g> {
+    productions: &'g [Production],
+    start: usize,
+    tokens: Vec<usize>,
+    nullable: Vec<bool>,
+
+    /// `sets[i]` holds the items that are valid after the first `i`
+    /// tokens. If the parse fails, there are no sets past the token
+    /// that could not be consumed.
+    sets: Vec<ItemSet>,
+}
+
+impl<'g> Chart<'g> {
+    /// Parses `tokens` (each the index of a terminal) as the
+    /// nonterminal `start`.
+    pub fn parse(productions: &'g [Production], start: usize, tokens: &[usize]) -> Chart<'g> {
+        match Chart::parse_within(productions, start, tokens, None) {
+            Ok(chart) => chart,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Like `parse`, but gives up once more than `limit` items have
+    /// been completed, the Earley counterpart of reductions, which
+    /// ambiguous grammars can make superlinear in the length of the
+    /// input. Returns the index of the token the parser had reached.
+    pub fn parse_with_limit(productions: &'g [Production],
+                            start: usize,
+                            tokens: &[usize],
+                            limit: usize)
+                            -> Result<Chart<'g>, usize> {
+        Chart::parse_within(productions, start, tokens, Some(limit))
+    }
+
+    fn parse_within(productions: &'g [Production],
+                    start: usize,
+                    tokens: &[usize],
+                    limit: Option<usize>)
+                    -> Result<Chart<'g>, usize> {
+        let mut budget = limit;
+        let mut chart = Chart {
+            productions: productions,
+            start: start,
+            tokens: tokens.to_vec(),
+            nullable: nullable(productions),
+            sets: vec![ItemSet::default()],
+        };
+
+        for (index, production) in productions.iter().enumerate() {
+            if production.nonterminal == start {
+                chart.sets[0].insert(Item { production: index, dot: 0, origin: 0 });
+            }
+        }
+
+        for index in 0..tokens.len() + 1 {
+            if !chart.close(index, &mut budget) {
+                return Err(index);
+            }
+            if index == tokens.len() {
+                break;
+            }
+
+            let mut next = ItemSet::default();
+            for item in &chart.sets[index].items {
+                if chart.next_symbol(item) == Some(Symbol::Terminal(tokens[index])) {
+                    next.insert(Item { dot: item.dot + 1, ..*item });
+                }
+            }
+            if next.items.is_empty() {
+                break;
+            }
+            chart.sets.push(next);
+        }
+
+        Ok(chart)
+    }
+
+    /// If the parse failed, the index of the token that could not be
+    /// consumed, or the number of tokens if the input ended too soon.
+    pub fn error(&self) -> Option<usize> {
+        let last = self.sets.len() - 1;
+        if last < self.tokens.len() || self.completed(self.start, 0, last).is_empty() {
+            Some(last)
+        } else {
+            None
+        }
+    }
+
+    /// True if the first `index` tokens, which must have been
+    /// consumed, are a complete parse of the start nonterminal.
+    pub fn is_complete(&self, index: usize) -> bool {
+        !self.completed(self.start, 0, index).is_empty()
+    }
+
+    /// The terminals that could have come after the first `index`
+    /// tokens.
+    pub fn expected(&self, index: usize) -> Vec<usize> {
+        let mut terminals: Vec<usize> =
+            self.sets[index].items
+                            .iter()
+                            .filter_map(|item| match self.next_symbol(item) {
+                                Some(Symbol::Terminal(terminal)) => Some(terminal),
+                                _ => None,
+                            })
+                            .collect();
+        terminals.sort();
+        terminals.dedup();
+        terminals
+    }
+
+    /// The steps that build a parse tree of the input, which must have
+    /// been parsed successfully. If the input is ambiguous, earlier
+    /// productions are preferred.
+    pub fn derivation(&self) -> Vec<Step> {
+        let mut steps = vec![];
+        let mut active = HashSet::new();
+        let end = self.tokens.len();
+        assert!(self.derive(self.start, 0, end, &mut active, &mut steps),
+                "no derivation of a successful parse");
+        steps
+    }
+
+    /// All the derivations of the input, which must have been parsed
+    /// successfully.
+    pub fn forest(&self) -> Forest {
+        let root = Node { nonterminal: self.start, start: 0, end: self.tokens.len() };
+        let mut nodes = HashMap::new();
+        let mut indices = HashMap::new();
+        let mut pending = vec![root];
+        while let Some(node) = pending.pop() {
+            if nodes.contains_key(&node) {
+                continue;
+            }
+            let mut alternatives = vec![];
+            for production in self.completed(node.nonterminal, node.start, node.end) {
+                let symbols = self.productions[production].symbols;
+                let mut splits = vec![];
+                self.splits(production, symbols.len(), node.start, node.end, &mut vec![],
+                            &mut splits);
+                for children in splits {
+                    for child in &children {
+                        if let Child::Node(child) = *child {
+                            pending.push(child);
+                        }
+                    }
+                    alternatives.push(Packed { production: production, children: children });
+                }
+            }
+            assert!(!alternatives.is_empty(), "no derivation of a successful parse");
+            indices.insert(node, indices.len());
+            nodes.insert(node, alternatives);
+        }
+        Forest { root: root, nodes: nodes, indices: indices }
+    }
+
+    fn next_symbol(&self, item: &Item) -> Option<Symbol> {
+        self.productions[item.production].symbols.get(item.dot).cloned()
+    }
+
+    /// Adds the items predicted and completed by those in
+    /// `sets[index]`, until there are no more. Each completion uses up
+    /// one unit of `budget`, if there is one; returns false if it runs
+    /// out.
+    fn close(&mut self, index: usize, budget: &mut Option<usize>) -> bool {
+        let mut next = 0;
+        while next < self.sets[index].items.len() {
+            let item = self.sets[index].items[next];
+            next += 1;
+
+            match self.next_symbol(&item) {
+                Some(Symbol::Nonterminal(nonterminal)) => {
+                    for (production, data) in self.productions.iter().enumerate() {
+                        if data.nonterminal == nonterminal {
+                            self.sets[index].insert(Item {
+                                production: production,
+                                dot: 0,
+                                origin: index,
+                            });
+                        }
+                    }
+                    // A nullable nonterminal may be completed right
+                    // away; since it might already have been, we
+                    // can't wait for the completion to advance past
+                    // it (see Aycock and Horspool, "Practical Earley
+                    // Parsing").
+                    if self.nullable[nonterminal] {
+                        self.sets[index].insert(Item { dot: item.dot + 1, ..item });
+                    }
+                }
+                Some(Symbol::Terminal(_)) => {}
+                None => {
+                    if let Some(ref mut budget) = *budget {
+                        if *budget == 0 {
+                            return false;
+                        }
+                        *budget -= 1;
+                    }
+                    let nonterminal = self.productions[item.production].nonterminal;
+                    let waiting: Vec<Item> =
+                        self.sets[item.origin]
+                            .items
+                            .iter()
+                            .filter(|waiting| {
+                                self.next_symbol(waiting) == Some(Symbol::Nonterminal(nonterminal))
+                            })
+                            .map(|waiting| Item { dot: waiting.dot + 1, ..*waiting })
+                            .collect();
+                    for waiting in waiting {
+                        self.sets[index].insert(waiting);
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    /// The productions of `nonterminal` that match the tokens from
+    /// `start` to `end`.
+    fn completed(&self, nonterminal: usize, start: usize, end: usize) -> Vec<usize> {
+        let mut productions: Vec<usize> =
+            self.sets[end].items
+                          .iter()
+                          .filter(|item| {
+                              item.origin == start &&
+                                  self.next_symbol(item).is_none() &&
+                                  self.productions[item.production].nonterminal == nonterminal
+                          })
+                          .map(|item| item.production)
+                          .collect();
+        productions.sort();
+        productions
+    }
+
+    /// Pushes the steps deriving the tokens from `start` to `end`
+    /// from `nonterminal`. `active` holds the nonterminals (and
+    /// spans) being derived further up the tree, which we must not
+    /// derive again lest we loop forever in grammars with cycles.
+    fn derive(&self,
+              nonterminal: usize,
+              start: usize,
+              end: usize,
+              active: &mut HashSet<(usize, usize, usize)>,
+              steps: &mut Vec<Step>)
+              -> bool {
+        if !active.insert((nonterminal, start, end)) {
+            return false;
+        }
+        let mut derived = false;
+        for production in self.completed(nonterminal, start, end) {
+            let len = steps.len();
+            if let Some(children) = self.split(production, start, end, active) {
+                derived = children.iter().all(|&(symbol, start, end)| match symbol {
+                    Symbol::Terminal(_) => {
+                        steps.push(Step::Shift);
+                        true
+                    }
+                    Symbol::Nonterminal(nonterminal) => {
+                        self.derive(nonterminal, start, end, active, steps)
+                    }
+                });
+                if derived {
+                    steps.push(Step::Reduce(production));
+                    break;
+                }
+            }
+            steps.truncate(len);
+        }
+        active.remove(&(nonterminal, start, end));
+        derived
+    }
+
+    /// Divides the tokens from `start` to `end`, which `production`
+    /// matches, among its symbols.
+    fn split(&self,
+             production: usize,
+             start: usize,
+             end: usize,
+             active: &HashSet<(usize, usize, usize)>)
+             -> Option<Vec<(Symbol, usize, usize)>> {
+        let symbols = self.productions[production].symbols;
+        let mut children = vec![];
+        let mut position = end;
+        for dot in (0..symbols.len()).rev() {
+            // The symbols before `dot` must match the tokens from
+            // `start` to where this symbol starts, which is exactly
+            // when the item with the dot before this symbol is in the
+            // set there.
+            let item = Item { production: production, dot: dot, origin: start };
+            let symbol_start = match symbols[dot] {
+                Symbol::Terminal(terminal) => {
+                    if position > start && self.tokens[position - 1] == terminal &&
+                       self.sets[position - 1].contains(&item) {
+                        Some(position - 1)
+                    } else {
+                        None
+                    }
+                }
+                Symbol::Nonterminal(nonterminal) => {
+                    (start..position + 1).rev().find(|&k| {
+                        self.sets[k].contains(&item) &&
+                            !active.contains(&(nonterminal, k, position)) &&
+                            !self.completed(nonterminal, k, position).is_empty()
+                    })
+                }
+            };
+            match symbol_start {
+                Some(symbol_start) => {
+                    children.push((symbols[dot], symbol_start, position));
+                    position = symbol_start;
+                }
+                None => return None,
+            }
+        }
+        children.reverse();
+        Some(children)
+    }
+
+    /// Pushes onto `splits` every way of dividing the tokens from
+    /// `start` to `end` among the first `dot` symbols of
+    /// `production`, given that the symbols after `dot` match the
+    /// `children` (in reverse order). As in `split`, later symbols
+    /// get the shortest matches first.
+    fn splits(&self,
+              production: usize,
+              dot: usize,
+              start: usize,
+              end: usize,
+              children: &mut Vec<Child>,
+              splits: &mut Vec<Vec<Child>>) {
+        if dot == 0 {
+            if end == start {
+                splits.push(children.iter().rev().cloned().collect());
+            }
+            return;
+        }
+        let item = Item { production: production, dot: dot - 1, origin: start };
+        match self.productions[production].symbols[dot - 1] {
+            Symbol::Terminal(terminal) => {
+                if end > start && self.tokens[end - 1] == terminal &&
+                   self.sets[end - 1].contains(&item) {
+                    children.push(Child::Token(end - 1));
+                    self.splits(production, dot - 1, start, end - 1, children, splits);
+                    children.pop();
+                }
+            }
+            Symbol::Nonterminal(nonterminal) => {
+                for k in (start..end + 1).rev() {
+                    if self.sets[k].contains(&item) &&
+                       !self.completed(nonterminal, k, end).is_empty() {
+                        children.push(Child::Node(Node {
+                            nonterminal: nonterminal,
+                            start: k,
+                            end: end,
+                        }));
+                        self.splits(production, dot - 1, start, k, children, splits);
+                        children.pop();
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A nonterminal matching the tokens from `start` to `end` in a
+/// `Forest`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Node {
+    pub nonterminal: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// One way of deriving a `Node`: a production, and what each of its
+/// symbols matches.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Packed {
+    pub production: usize,
+    pub children: Vec<Child>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Child {
+    /// The token with the given index.
+    Token(usize),
+    Node(Node),
+}
+
+/// A shared packed parse forest: every way of deriving the input,
+/// with the derivations of each nonterminal and span stored once.
+/// Grammars with cycles (like `A = A`) have infinitely many
+/// derivations, so the forest may contain cycles, but derivations
+/// built from it never use a node inside itself.
+pub struct Forest {
+    root: Node,
+    nodes: HashMap<Node, Vec<Packed>>,
+
+    /// the index of each node, by which `Step::Keep` and
+    /// `Step::Recall` refer to it
+    indices: HashMap<Node, usize>,
+}
+
+/// The nodes whose values a derivation being built keeps, in the order
+/// it keeps them, so that those kept by a part of it that is given up
+/// can be forgotten again.
+#[derive(Default)]
+struct Kept {
+    nodes: HashSet<Node>,
+    order: Vec<Node>,
+}
+
+impl Kept {
+    fn insert(&mut self, node: Node) {
+        self.nodes.insert(node);
+        self.order.push(node);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        while self.order.len() > len {
+            let node = self.order.pop().unwrap();
+            self.nodes.remove(&node);
+        }
+    }
+}
+
+impl Forest {
+    /// The start symbol, matching the whole input.
+    pub fn root(&self) -> Node {
+        self.root
+    }
+
+    /// The ways of deriving `node`, in the order that `derivations`
+    /// tries them.
+    pub fn alternatives(&self, node: Node) -> &[Packed] {
+        &self.nodes[&node]
+    }
+
+    pub fn is_ambiguous(&self) -> bool {
+        self.nodes.values().any(|alternatives| alternatives.len() > 1)
+    }
+
+    /// Iterates over the distinct derivations of the input, starting
+    /// with the one `Chart::derivation` picks.
+    pub fn derivations(&self) -> Derivations {
+        Derivations { forest: self, script: Some(vec![]), stable: None }
+    }
+
+    /// Like `derivations`, but the nodes that are derived the same way
+    /// in every derivation, as nothing below them is ambiguous, are
+    /// kept, and recalled by the derivations after the one that first
+    /// builds them. A replay that keeps the values of the nodes across
+    /// derivations thus runs their actions only once, at the cost of
+    /// cloning the value of every such node.
+    pub fn derivations_memoized(&self) -> Derivations {
+        let mut stable = HashMap::new();
+        self.is_stable(self.root, &mut stable);
+        let stable = stable.into_iter().filter(|&(_, stable)| stable).map(|(node, _)| node);
+        Derivations { forest: self, script: Some(vec![]), stable: Some(stable.collect()) }
+    }
+
+    /// Whether `node` has a single derivation, recording the answer
+    /// for it and the nodes below it in `stable`. Nodes that are being
+    /// looked at further up, and so lie on a cycle, are not.
+    fn is_stable(&self, node: Node, stable: &mut HashMap<Node, bool>) -> bool {
+        if let Some(&answer) = stable.get(&node) {
+            return answer;
+        }
+        stable.insert(node, false);
+        let alternatives = self.alternatives(node);
+        let mut answer = alternatives.len() == 1;
+        for child in &alternatives[0].children {
+            if let Child::Node(child) = *child {
+                answer &= self.is_stable(child, stable);
+            }
+        }
+        stable.insert(node, answer);
+        answer
+    }
+
+    /// Builds a single derivation, letting `choose` pick the index of
+    /// the alternative to use wherever a node has more than one.
+    /// Returns `None` if the choices lead to a node being used inside
+    /// itself.
+    pub fn derivation_by<F>(&self, mut choose: F) -> Option<Vec<Step>>
+        where F: FnMut(Node, &[Packed]) -> usize
+    {
+        let mut steps = vec![];
+        if self.walk(self.root, None, &mut HashSet::new(), &mut steps, &mut choose) {
+            Some(steps)
+        } else {
+            None
+        }
+    }
+
+    /// Builds the derivation that the `dprec` and `merge` settings of
+    /// `productions` (which must be the ones the chart was built
+    /// with) pick. Wherever a node has more than one alternative,
+    /// only those whose production has the highest `dprec` are used.
+    /// If more than one is left and they all have the same `merge`,
+    /// they are all derived, separated by `Rewind`s and followed by
+    /// `Merge`s; otherwise the first is used.
+    pub fn resolve(&self, productions: &[Production]) -> Vec<Step> {
+        let mut steps = vec![];
+        assert!(self.walk_resolved(self.root,
+                                   productions,
+                                   &mut HashSet::new(),
+                                   None,
+                                   &mut steps),
+                "no derivation of a successful parse");
+        steps
+    }
+
+    /// Like `resolve`, but where merging results makes the derivation
+    /// use a node again, the value built for it the first time is kept
+    /// and recalled, rather than derived anew. Without this, nested
+    /// ambiguities derive the innermost nodes exponentially often.
+    pub fn resolve_memoized(&self, productions: &[Production]) -> Vec<Step> {
+        let mut steps = vec![];
+        assert!(self.walk_resolved(self.root,
+                                   productions,
+                                   &mut HashSet::new(),
+                                   Some(&mut Kept::default()),
+                                   &mut steps),
+                "no derivation of a successful parse");
+
+        // only the values that are recalled need to be kept
+        let recalled: HashSet<usize> = steps.iter()
+                                            .filter_map(|step| match *step {
+                                                Step::Recall { node, .. } => Some(node),
+                                                _ => None,
+                                            })
+                                            .collect();
+        steps.retain(|step| match *step {
+            Step::Keep(node) => recalled.contains(&node),
+            _ => true,
+        });
+        steps
+    }
+
+    fn walk_resolved(&self,
+                     node: Node,
+                     productions: &[Production],
+                     active: &mut HashSet<Node>,
+                     mut kept: Option<&mut Kept>,
+                     steps: &mut Vec<Step>)
+                     -> bool {
+        if kept.as_ref().map_or(false, |kept| kept.nodes.contains(&node)) {
+            steps.push(Step::Recall { node: self.indices[&node], end: node.end, skip: 0 });
+            return true;
+        }
+        if !active.insert(node) {
+            return false;
+        }
+        let alternatives = self.alternatives(node);
+        let dprec = alternatives.iter()
+                                .map(|packed| productions[packed.production].dprec)
+                                .max()
+                                .unwrap();
+        let candidates: Vec<&Packed> =
+            alternatives.iter()
+                        .filter(|packed| productions[packed.production].dprec == dprec)
+                        .collect();
+        let merge = productions[candidates[0].production].merge;
+        let merging = candidates.len() > 1 && merge.is_some() &&
+                      candidates.iter().all(|packed| productions[packed.production].merge == merge);
+
+        let mut derived = 0;
+        for packed in candidates {
+            let len = steps.len();
+            let kept_len = kept.as_ref().map_or(0, |kept| kept.order.len());
+            if derived > 0 {
+                steps.push(Step::Rewind(node.start));
+            }
+            let ok = packed.children.iter().all(|child| match *child {
+                Child::Token(_) => {
+                    steps.push(Step::Shift);
+                    true
+                }
+                Child::Node(child) => {
+                    self.walk_resolved(child,
+                                       productions,
+                                       active,
+                                       kept.as_mut().map(|kept| &mut **kept),
+                                       steps)
+                }
+            });
+            if !ok {
+                steps.truncate(len);
+                if let Some(ref mut kept) = kept {
+                    kept.truncate(kept_len);
+                }
+                continue;
+            }
+            steps.push(Step::Reduce(packed.production));
+            if derived > 0 {
+                steps.push(Step::Merge(merge.unwrap()));
+            }
+            derived += 1;
+            if !merging {
+                break;
+            }
+        }
+        active.remove(&node);
+        if derived > 0 {
+            if let Some(kept) = kept {
+                kept.insert(node);
+                steps.push(Step::Keep(self.indices[&node]));
+            }
+        }
+        derived > 0
+    }
+
+    /// Pushes the steps of a derivation of `node`. If `stable` is
+    /// given, the steps of the nodes in it are wrapped in a
+    /// `Step::Recall` and a `Step::Keep`.
+    fn walk<F>(&self,
+               node: Node,
+               stable: Option<&HashSet<Node>>,
+               active: &mut HashSet<Node>,
+               steps: &mut Vec<Step>,
+               choose: &mut F)
+               -> bool
+        where F: FnMut(Node, &[Packed]) -> usize
+    {
+        if !active.insert(node) {
+            return false;
+        }
+        let recall = steps.len();
+        let keep = stable.map_or(false, |stable| stable.contains(&node));
+        if keep {
+            // (`skip` is filled in below)
+            steps.push(Step::Recall { node: self.indices[&node], end: node.end, skip: 0 });
+        }
+        let alternatives = self.alternatives(node);
+        let packed = if alternatives.len() == 1 {
+            &alternatives[0]
+        } else {
+            &alternatives[choose(node, alternatives)]
+        };
+        let derived = packed.children.iter().all(|child| match *child {
+            Child::Token(_) => {
+                steps.push(Step::Shift);
+                true
+            }
+            Child::Node(child) => self.walk(child, stable, active, steps, choose),
+        });
+        if derived {
+            steps.push(Step::Reduce(packed.production));
+            if keep {
+                steps.push(Step::Keep(self.indices[&node]));
+                let skip = steps.len() - recall - 1;
+                steps[recall] = Step::Recall {
+                    node: self.indices[&node],
+                    end: node.end,
+                    skip: skip,
+                };
+            }
+        }
+        active.remove(&node);
+        derived
+    }
+}
+
+/// The iterator returned by `Forest::derivations`. Each derivation is
+/// identified by the choices made at the ambiguous nodes it visits;
+/// we go through them like an odometer, always bumping the last
+/// choice that has alternatives left.
+pub struct Derivations<'f> {
+    forest: &'f Forest,
+    script: Option<Vec<usize>>,
+
+    /// the nodes to keep, for `Forest::derivations_memoized`
+    stable: Option<HashSet<Node>>,
+}
+
+impl<'f> Iterator for Derivations<'f> {
+    type Item = Vec<Step>;
+
+    fn next(&mut self) -> Option<Vec<Step>> {
+        while let Some(script) = self.script.take() {
+            let mut trail: Vec<(usize, usize)> = vec![];
+            let mut steps = vec![];
+            let derived = {
+                let mut choose = |_, alternatives: &[Packed]| {
+                    let choice = script.get(trail.len()).cloned().unwrap_or(0);
+                    trail.push((choice, alternatives.len()));
+                    choice
+                };
+                self.forest.walk(self.forest.root,
+                                 self.stable.as_ref(),
+                                 &mut HashSet::new(),
+                                 &mut steps,
+                                 &mut choose)
+            };
+            let derivation = if derived { Some(steps) } else { None };
+
+            while let Some((choice, count)) = trail.pop() {
+                if choice + 1 < count {
+                    let mut script: Vec<usize> = trail.iter().map(|&(c, _)| c).collect();
+                    script.push(choice + 1);
+                    self.script = Some(script);
+                    break;
+                }
+            }
+
+            if derivation.is_some() {
+                return derivation;
+            }
+        }
+        None
+    }
+}
+
+/// Which nonterminals can match the empty string.
+fn nullable(productions: &[Production]) -> Vec<bool> {
+    let num_nonterminals = productions.iter().map(|p| p.nonterminal + 1).max().unwrap_or(0);
+    let mut nullable = vec![false; num_nonterminals];
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for production in productions {
+            if !nullable[production.nonterminal] &&
+               production.symbols.iter().all(|symbol| match *symbol {
+                   Symbol::Terminal(_) => false,
+                   Symbol::Nonterminal(nt) => nullable[nt],
+               }) {
+                nullable[production.nonterminal] = true;
+                changed = true;
+            }
+        }
+    }
+    nullable
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::Symbol::{Nonterminal as N, Terminal as T};
+
+    // E = E "+" E | "x" | Empty "(" Empty ")";    (nonterminal 0)
+    // Empty = ;                                    (nonterminal 1)
+    const PLUS: usize = 0;
+    const X: usize = 1;
+    const LPAREN: usize = 2;
+    const RPAREN: usize = 3;
+
+    const GRAMMAR: &'static [Production] = &[
+        Production { nonterminal: 0, symbols: &[N(0), T(PLUS), N(0)], dprec: 0, merge: None },
+        Production { nonterminal: 0, symbols: &[T(X)], dprec: 0, merge: None },
+        Production { nonterminal: 0, symbols: &[N(1), T(LPAREN), N(1), T(RPAREN)],
+                     dprec: 0, merge: None },
+        Production { nonterminal: 1, symbols: &[], dprec: 0, merge: None },
+    ];
+
+    #[test]
+    fn ambiguous() {
+        let chart = Chart::parse(GRAMMAR, 0, &[X, PLUS, X, PLUS, X]);
+        assert_eq!(chart.error(), None);
+        // earlier productions are preferred, so the first `E` is the
+        // longest possible one
+        assert_eq!(chart.derivation(),
+                   vec![Step::Shift, Step::Reduce(1), Step::Shift, Step::Shift, Step::Reduce(1),
+                        Step::Reduce(0), Step::Shift, Step::Shift, Step::Reduce(1),
+                        Step::Reduce(0)]);
+    }
+
+    #[test]
+    fn nullable_nonterminals() {
+        let chart = Chart::parse(GRAMMAR, 0, &[LPAREN, RPAREN]);
+        assert_eq!(chart.error(), None);
+        assert_eq!(chart.derivation(),
+                   vec![Step::Reduce(3), Step::Shift, Step::Reduce(3), Step::Shift,
+                        Step::Reduce(2)]);
+    }
+
+    #[test]
+    fn unexpected_token() {
+        let chart = Chart::parse(GRAMMAR, 0, &[X, X]);
+        assert_eq!(chart.error(), Some(1));
+        assert_eq!(chart.expected(1), vec![PLUS]);
+        // `x` on its own is complete, so the second one is extra
+        assert!(chart.is_complete(1));
+    }
+
+    #[test]
+    fn unexpected_end() {
+        let chart = Chart::parse(GRAMMAR, 0, &[X, PLUS]);
+        assert_eq!(chart.error(), Some(2));
+        assert_eq!(chart.expected(2), vec![X, LPAREN]);
+        assert!(!chart.is_complete(2));
+    }
+
+    #[test]
+    fn reduction_limit() {
+        let tokens = &[X, PLUS, X, PLUS, X];
+        assert!(Chart::parse_with_limit(GRAMMAR, 0, tokens, 100).is_ok());
+        // `x +` takes three completions: `Empty` at the start and
+        // after `+`, and `E` after `x`
+        assert_eq!(Chart::parse_with_limit(GRAMMAR, 0, tokens, 2).err(), Some(2));
+    }
+
+    #[test]
+    fn forest() {
+        let chart = Chart::parse(GRAMMAR, 0, &[X, PLUS, X, PLUS, X]);
+        let forest = chart.forest();
+        assert!(forest.is_ambiguous());
+        assert_eq!(forest.alternatives(forest.root()).len(), 2);
+
+        let derivations: Vec<Vec<Step>> = forest.derivations().collect();
+        assert_eq!(derivations.len(), 2);
+        assert_eq!(derivations[0], chart.derivation());
+        assert_eq!(derivations[1],
+                   vec![Step::Shift, Step::Reduce(1), Step::Shift, Step::Shift, Step::Reduce(1),
+                        Step::Shift, Step::Shift, Step::Reduce(1), Step::Reduce(0),
+                        Step::Reduce(0)]);
+
+        // picking the alternative whose first operand is shortest
+        // makes `+` right-associative
+        let right = forest.derivation_by(|_, alternatives| {
+            (0..alternatives.len())
+                .min_by_key(|&i| match alternatives[i].children[0] {
+                    Child::Node(node) => node.end - node.start,
+                    Child::Token(_) => 1,
+                })
+                .unwrap()
+        });
+        assert_eq!(right.as_ref(), Some(&derivations[1]));
+    }
+
+    #[test]
+    fn cycle() {
+        // A = A | "x"
+        const CYCLIC: &'static [Production] = &[
+            Production { nonterminal: 0, symbols: &[N(0)], dprec: 0, merge: None },
+            Production { nonterminal: 0, symbols: &[T(X)], dprec: 0, merge: None },
+        ];
+        let chart = Chart::parse(CYCLIC, 0, &[X]);
+        assert_eq!(chart.error(), None);
+        assert_eq!(chart.derivation(), vec![Step::Shift, Step::Reduce(1)]);
+        assert_eq!(chart.forest().derivations().collect::<Vec<_>>(),
+                   vec![vec![Step::Shift, Step::Reduce(1)]]);
+    }
+
+    #[test]
+    fn resolve() {
+        // S = A | B; A = "x"; B = "x";
+        const A: &'static [Symbol] = &[N(1)];
+        const B: &'static [Symbol] = &[N(2)];
+        const TERMINAL: &'static [Symbol] = &[T(X)];
+        fn grammar(dprec: u32, merge: Option<usize>) -> Vec<Production> {
+            vec![Production { nonterminal: 0, symbols: A, dprec: 0, merge: merge },
+                 Production { nonterminal: 0, symbols: B, dprec: dprec, merge: merge },
+                 Production { nonterminal: 1, symbols: TERMINAL, dprec: 0, merge: None },
+                 Production { nonterminal: 2, symbols: TERMINAL, dprec: 0, merge: None }]
+        }
+
+        // without preferences the first alternative is used
+        let productions = grammar(0, None);
+        let forest = Chart::parse(&productions, 0, &[X]).forest();
+        assert_eq!(forest.resolve(&productions),
+                   vec![Step::Shift, Step::Reduce(2), Step::Reduce(0)]);
+
+        // a higher `dprec` wins, even when merging
+        let productions = grammar(1, Some(0));
+        let forest = Chart::parse(&productions, 0, &[X]).forest();
+        assert_eq!(forest.resolve(&productions),
+                   vec![Step::Shift, Step::Reduce(3), Step::Reduce(1)]);
+
+        // equal ones with the same `merge` are all derived
+        let productions = grammar(0, Some(0));
+        let forest = Chart::parse(&productions, 0, &[X]).forest();
+        assert_eq!(forest.resolve(&productions),
+                   vec![Step::Shift, Step::Reduce(2), Step::Reduce(0), Step::Rewind(0),
+                        Step::Shift, Step::Reduce(3), Step::Reduce(1), Step::Merge(0)]);
+    }
+
+    #[test]
+    fn memoized() {
+        // S = A | B, merged; A = C; B = C; C = "x";
+        const C: &'static [Symbol] = &[N(3)];
+        const TERMINAL: &'static [Symbol] = &[T(X)];
+        const PRODUCTIONS: &'static [Production] = &[
+            Production { nonterminal: 0, symbols: &[N(1)], dprec: 0, merge: Some(0) },
+            Production { nonterminal: 0, symbols: &[N(2)], dprec: 0, merge: Some(0) },
+            Production { nonterminal: 1, symbols: C, dprec: 0, merge: None },
+            Production { nonterminal: 2, symbols: C, dprec: 0, merge: None },
+            Production { nonterminal: 3, symbols: TERMINAL, dprec: 0, merge: None },
+        ];
+        let forest = Chart::parse(PRODUCTIONS, 0, &[X]).forest();
+        let c = forest.indices[&Node { nonterminal: 3, start: 0, end: 1 }];
+
+        // both alternatives of `S` use the same `C`, which is derived
+        // once, and only its value is kept
+        assert_eq!(forest.resolve_memoized(PRODUCTIONS),
+                   vec![Step::Shift, Step::Reduce(4), Step::Keep(c), Step::Reduce(2),
+                        Step::Reduce(0), Step::Rewind(0), Step::Recall { node: c, end: 1, skip: 0 },
+                        Step::Reduce(3), Step::Reduce(1), Step::Merge(0)]);
+
+        // the derivations of `x + x + x` share the last `x`
+        let chart = Chart::parse(GRAMMAR, 0, &[X, PLUS, X, PLUS, X]);
+        let forest = chart.forest();
+        let memoized: Vec<Vec<Step>> = forest.derivations_memoized().collect();
+        let plain: Vec<Vec<Step>> = memoized.iter()
+                                            .map(|steps| {
+                                                steps.iter()
+                                                     .cloned()
+                                                     .filter(|step| match *step {
+                                                         Step::Recall { .. } |
+                                                         Step::Keep(_) => false,
+                                                         _ => true,
+                                                     })
+                                                     .collect()
+                                            })
+                                            .collect();
+        assert_eq!(plain, forest.derivations().collect::<Vec<_>>());
+        let x = forest.indices[&Node { nonterminal: 0, start: 4, end: 5 }];
+        for steps in &memoized {
+            let recall = steps.iter()
+                              .position(|step| *step == Step::Recall { node: x, end: 5, skip: 3 })
+                              .unwrap();
+            assert_eq!(&steps[recall + 1..recall + 4],
+                       &[Step::Shift, Step::Reduce(1), Step::Keep(x)]);
+        }
+    }
+}
@@ -0,0 +1,265 @@
+//! Support for the C entry points that LALRPOP generates when asked
+//! to (`Configuration::emit_c_abi`). For each public nonterminal
+//! `Expr` of `calc.lalrpop`, the parser module gets
+//!
+//! ```c
+//! calc_Expr *calc_parse_Expr(const uint8_t *input, size_t len, lalrpop_error *error);
+//! void calc_free_Expr(calc_Expr *value);
+//! ```
+//!
+//! where `calc_Expr` is an opaque handle to the boxed value of the
+//! nonterminal. On failure, the parse function returns `NULL` and
+//! fills in `*error`, whose message must then be freed with
+//! `calc_free_error`. This module holds the code the entry points
+//! share.
+
+use std::ffi::CString;
+use std::fmt::Display;
+use std::os::raw::{c_char, c_void};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::slice;
+use std::str;
+
+use ParseError;
+
+/// The input was parsed successfully.
+pub const OK: u32 = 0;
+
+/// The input is not valid UTF-8; `start` and `end` are the offset of
+/// the first invalid byte.
+pub const INVALID_UTF8: u32 = 1;
+
+/// `ParseError::InvalidToken`.
+pub const INVALID_TOKEN: u32 = 2;
+
+/// `ParseError::UnrecognizedToken` and `ParseError::ReservedWord`.
+pub const UNRECOGNIZED_TOKEN: u32 = 3;
+
+/// `ParseError::UnrecognizedEof`.
+pub const UNEXPECTED_EOF: u32 = 4;
+
+/// `ParseError::ExtraToken`.
+pub const EXTRA_TOKEN: u32 = 5;
+
+/// `ParseError::User`.
+pub const USER: u32 = 6;
+
+/// Any other `ParseError`, such as `DepthLimit` or `StackCapacity`.
+pub const OTHER: u32 = 7;
+
+/// The parser (or the action code) panicked.
+pub const PANIC: u32 = 8;
+
+/// Why a C entry point failed, laid out as `lalrpop_error` in the
+/// generated header.
+#[repr(C)]
+#[derive(Debug)]
+pub struct Error {
+    /// One of the constants of this module.
+    pub kind: u32,
+
+    /// The byte offsets of the start and end of the input the error
+    /// is about; both are the length of the input for errors at its
+    /// end or errors without a location.
+    pub start: usize,
+    pub end: usize,
+
+    /// The error as the parser would display it, as a NUL-terminated
+    /// string owned by Rust; `NULL` if there is no error.
+    pub message: *mut c_char,
+}
+
+/// Runs `parse` over the `len` bytes at `input` and returns the
+/// boxed value it produces, or `NULL` after filling in `*error` (if
+/// `error` is not `NULL`). `*error` is overwritten either way, so it
+/// need not be initialized, but a message left in it is leaked.
+///
+/// The caller must ensure that `input` points to `len` readable
+/// bytes (it may be `NULL` if `len` is 0), and that the value does
+/// not borrow from the input, which C code may free as soon as the
+/// call returns.
+pub unsafe fn parse<'input, T, F, TOK, E>(input: *const u8,
+                                          len: usize,
+                                          error: *mut Error,
+                                          parse: F)
+                                          -> *mut c_void
+    where F: FnOnce(&'input str) -> Result<T, ParseError<usize, TOK, E>>,
+          TOK: Display,
+          E: Display
+{
+    set_error(error, OK, 0, 0, None);
+
+    let bytes: &'input [u8] = if len == 0 { &[] } else { slice::from_raw_parts(input, len) };
+    let text = match str::from_utf8(bytes) {
+        Ok(text) => text,
+        Err(utf8_error) => {
+            let offset = utf8_error.valid_up_to();
+            set_error(error, INVALID_UTF8, offset, offset, Some(utf8_error.to_string()));
+            return ptr::null_mut();
+        }
+    };
+
+    // unwinding into C code is undefined behavior
+    match panic::catch_unwind(AssertUnwindSafe(|| parse(text))) {
+        Ok(Ok(value)) => Box::into_raw(Box::new(value)) as *mut c_void,
+        Ok(Err(parse_error)) => {
+            let kind = match parse_error {
+                ParseError::InvalidToken { .. } => INVALID_TOKEN,
+                ParseError::UnrecognizedToken { token: Some(_), .. } |
+                ParseError::ReservedWord { .. } => UNRECOGNIZED_TOKEN,
+                ParseError::UnrecognizedToken { token: None, .. } |
+                ParseError::UnrecognizedEof { .. } => UNEXPECTED_EOF,
+                ParseError::ExtraToken { .. } => EXTRA_TOKEN,
+                ParseError::User { .. } => USER,
+                _ => OTHER,
+            };
+            let (start, end) = match parse_error {
+                ParseError::UnrecognizedToken { token: Some((start, _, end)), .. } |
+                ParseError::ExtraToken { token: (start, _, end), .. } |
+                ParseError::ReservedWord { token: (start, _, end), .. } |
+                ParseError::GuardFailed { start, end } |
+                ParseError::VersionTooOld { start, end, .. } => (start, end),
+                _ => {
+                    let location = parse_error.location().cloned().unwrap_or(len);
+                    (location, location)
+                }
+            };
+            set_error(error, kind, start, end, Some(parse_error.to_string()));
+            ptr::null_mut()
+        }
+        Err(_) => {
+            set_error(error, PANIC, len, len, Some(String::from("the parser panicked")));
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Drops a value returned by `parse`, which must have type `T`.
+/// `value` may be `NULL`.
+pub unsafe fn free<T>(value: *mut c_void) {
+    if !value.is_null() {
+        drop(Box::from_raw(value as *mut T));
+    }
+}
+
+/// Frees the message of `*error`, which must have been filled in by
+/// `parse`, and resets it to `OK`. `error` may be `NULL`.
+pub unsafe fn free_error(error: *mut Error) {
+    if !error.is_null() && !(*error).message.is_null() {
+        drop(CString::from_raw((*error).message));
+    }
+    set_error(error, OK, 0, 0, None);
+}
+
+unsafe fn set_error(error: *mut Error,
+                    kind: u32,
+                    start: usize,
+                    end: usize,
+                    message: Option<String>) {
+    if error.is_null() {
+        return;
+    }
+    let error = &mut *error;
+    error.kind = kind;
+    error.start = start;
+    error.end = end;
+    error.message = match message {
+        Some(message) => CString::new(message.replace('\0', "\\0")).unwrap().into_raw(),
+        None => ptr::null_mut(),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+    use std::ptr;
+
+    fn parse_digits(text: &str) -> Result<u32, ParseError<usize, char, &'static str>> {
+        match text.char_indices().find(|&(_, c)| !c.is_digit(10)) {
+            Some((offset, c)) => {
+                Err(ParseError::UnrecognizedToken {
+                    token: Some((offset, c, offset + c.len_utf8())),
+                    expected: vec![String::from("digit")],
+                })
+            }
+            None if text.is_empty() => Err(ParseError::User { error: "no digits" }),
+            None => Ok(text.parse().unwrap()),
+        }
+    }
+
+    fn stale_error() -> Error {
+        Error { kind: PANIC, start: 1, end: 1, message: ptr::null_mut() }
+    }
+
+    fn message(error: &Error) -> String {
+        unsafe { CStr::from_ptr(error.message).to_string_lossy().into_owned() }
+    }
+
+    #[test]
+    fn success() {
+        let input = b"42";
+        let mut error = stale_error();
+        unsafe {
+            let value = parse(input.as_ptr(), input.len(), &mut error, parse_digits);
+            assert_eq!(*(value as *mut u32), 42);
+            free::<u32>(value);
+        }
+        assert_eq!(error.kind, OK);
+        assert!(error.message.is_null());
+    }
+
+    #[test]
+    fn parse_errors() {
+        let input = b"12x4";
+        let mut error = stale_error();
+        unsafe {
+            assert!(parse(input.as_ptr(), input.len(), &mut error, parse_digits).is_null());
+            assert_eq!((error.kind, error.start, error.end), (UNRECOGNIZED_TOKEN, 2, 3));
+            assert!(message(&error).contains("`x`"));
+            free_error(&mut error);
+
+            assert!(parse(ptr::null(), 0, &mut error, parse_digits).is_null());
+            assert_eq!((error.kind, error.start, error.end), (USER, 0, 0));
+            assert_eq!(message(&error), "no digits");
+
+            free_error(&mut error);
+        }
+        assert_eq!(error.kind, OK);
+        assert!(error.message.is_null());
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let input = b"1\xff";
+        let mut error = stale_error();
+        unsafe {
+            assert!(parse(input.as_ptr(), input.len(), &mut error, parse_digits).is_null());
+            assert_eq!((error.kind, error.start, error.end), (INVALID_UTF8, 1, 1));
+            free_error(&mut error);
+        }
+    }
+
+    #[test]
+    fn panic() {
+        let input = b"1";
+        let mut error = stale_error();
+        unsafe {
+            let value = parse(input.as_ptr(), input.len(), &mut error, |_| -> Result<u32, ParseError<usize, char, &'static str>> {
+                panic!("oops")
+            });
+            assert!(value.is_null());
+            assert_eq!(error.kind, PANIC);
+            free_error(&mut error);
+        }
+    }
+
+    #[test]
+    fn null_error() {
+        let input = b"x";
+        unsafe {
+            assert!(parse(input.as_ptr(), input.len(), ptr::null_mut(), parse_digits).is_null());
+        }
+    }
+}
@@ -1,39 +1,254 @@
+//! Without its default `std` feature, this crate is `no_std`, and
+//! does without an allocator too. It then only has what parsers built
+//! with `Configuration::fixed_stack` need (see the `stack` module).
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "std")]
+extern crate core;
+
+#[cfg(feature = "serde")]
+extern crate serde;
+
+#[cfg(feature = "proc-macro2")]
+extern crate proc_macro2;
+
+#[cfg(feature = "unicode-segmentation")]
+extern crate unicode_segmentation;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "std")]
+use std::cmp::Ordering;
+#[cfg(feature = "std")]
 use std::error::Error;
-use std::fmt;
+use core::fmt;
+
+#[cfg(feature = "std")]
+pub mod earley;
+#[cfg(feature = "std")]
+pub mod ffi;
+mod location;
+#[cfg(feature = "std")]
+pub mod lsp;
+#[cfg(feature = "std")]
+mod render;
+#[cfg(feature = "std")]
+pub mod repair;
+pub mod scan;
+mod spanned;
+pub mod stack;
+#[cfg(all(feature = "std", feature = "proc-macro2"))]
+pub mod token_stream;
+#[cfg(feature = "std")]
+pub mod tokens;
+#[cfg(feature = "std")]
+pub mod unescape;
+mod version;
+
+pub use location::{ColumnUnit, Columns, LineCol, Location, Locations};
+#[cfg(feature = "std")]
+pub use render::{error_span, render_error};
+pub use spanned::Spanned;
+pub use version::Version;
+
+/// The tokens that the parser expected where it found a syntax error:
+/// their names, with the `std` feature, and otherwise the state of the
+/// parser, which has no allocator to collect the names with (or
+/// `None`, if it expected nothing but the end of the input).
+#[cfg(feature = "std")]
+pub type Expected = Vec<String>;
+
+/// See the `std` version.
+#[cfg(not(feature = "std"))]
+pub type Expected = Option<ExpectedState>;
+
+/// The state in which a parser found a syntax error, if it was built
+/// without the `std` feature of this crate. The `expected_tokens`
+/// function of a parser with a fixed-size stack (see the `stack`
+/// module) lists the names of the tokens it expected in the state.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ExpectedState {
+    pub state: usize,
+}
+
+/// Collects the names of the tokens that a parser expected in `state`
+/// into its `Expected` value, if it can; see `Expected`.
+#[doc(hidden)]
+#[cfg(feature = "std")]
+pub fn expected<I>(_state: usize, names: I) -> Expected
+    where I: Iterator<Item = &'static str>
+{
+    names.map(String::from).collect()
+}
+
+#[doc(hidden)]
+#[cfg(not(feature = "std"))]
+pub fn expected<I>(state: usize, _names: I) -> Expected
+    where I: Iterator<Item = &'static str>
+{
+    Some(ExpectedState { state: state })
+}
+
+/// The text of a `ParseError`, which is a `&'static str` if this crate
+/// is built without the `std` feature.
+#[cfg(feature = "std")]
+pub type Text = String;
+
+/// See the `std` version.
+#[cfg(not(feature = "std"))]
+pub type Text = &'static str;
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ParseError<L,T,E> {
-    /// Generated by the parser when it encounters a token (or EOF) it did not
-    /// expect.
+    /// Generated by the tokenizer when the input at `location` does
+    /// not start with any token of the grammar.
     InvalidToken {
         location: L
     },
 
-    /// Generated by the parser when it encounters a token (or EOF) it did not
-    /// expect.
+    /// Generated by the parser when it encounters a token that is
+    /// valid on its own, but not at this point of the input (which
+    /// is not yet a complete parse).
     UnrecognizedToken {
-        /// If this is `Some`, then an unexpected token of type `T`
-        /// was observed, with a span given by the two `L` values. If
-        /// this is `None`, then EOF was observed when it was not
-        /// expected.
+        /// The unexpected token of type `T`, with a span given by the
+        /// two `L` values. Parsers generated by older versions of
+        /// LALRPOP use `None` where current ones report
+        /// `UnrecognizedEof`.
         token: Option<(L, T, L)>,
 
         /// The set of expected tokens: these names are taken from the
         /// grammar and hence may not necessarily be suitable for
         /// presenting to the user.
-        expected: Vec<String>
+        expected: Expected
+    },
+
+    /// Generated by the parser when the input ends in the middle of
+    /// a rule.
+    UnrecognizedEof {
+        /// The end of the last token, or the default location if the
+        /// input is empty.
+        location: L,
+
+        /// The set of expected tokens, as for `UnrecognizedToken`.
+        expected: Expected,
     },
 
     /// Generated by the parser when it encounters additional,
-    /// unexpected tokens.
+    /// unexpected tokens after a complete parse.
     ExtraToken {
         token: (L, T, L),
+
+        /// The tokens that could have continued the parse instead
+        /// (in addition to the end of the input), as for
+        /// `UnrecognizedToken`.
+        expected: Expected,
+    },
+
+    /// Generated by the parser when it encounters a keyword marked
+    /// `#[reserved]` where it expected a terminal that would match
+    /// the keyword's text if it were not a keyword, like an
+    /// identifier.
+    ReservedWord {
+        token: (L, T, L),
+
+        /// What the keyword is reserved from, as given in the
+        /// grammar, like `identifier`.
+        description: Text,
+
+        /// The set of expected tokens, as for `UnrecognizedToken`.
+        expected: Expected,
     },
 
     /// Custom error type.
     User {
         error: E,
     },
+
+    /// Generated by the parser when the input is nested more deeply
+    /// than the maximum depth it was configured with.
+    DepthLimit {
+        /// The location of the token that would have exceeded the
+        /// limit.
+        location: L,
+
+        /// The maximum depth.
+        depth: usize,
+    },
+
+    /// Generated by a parser that keeps its stacks in a buffer that
+    /// the caller supplies (see the `stack` module) when the input
+    /// does not fit in the buffer.
+    StackCapacity {
+        /// The location of the token that did not fit.
+        location: L,
+
+        /// The number of symbols that fit in the buffer.
+        capacity: usize,
+    },
+
+    /// Generated by the parser when it needed more reductions than
+    /// the maximum it was configured with, which guards against
+    /// input that makes it do a disproportionate amount of work.
+    ReductionLimit {
+        /// The location of the token the parser was looking at when
+        /// it hit the limit.
+        location: L,
+
+        /// The maximum number of reductions.
+        limit: usize,
+    },
+
+    /// Generated by the parser when its cancellation flag (see
+    /// `FooParser::set_cancel_flag` and `parse_Foo_cancelable`) was
+    /// set while it was parsing.
+    Canceled {
+        /// The location of the next token at the point the parser
+        /// noticed the cancellation.
+        location: L,
+    },
+
+    /// Generated by the parser when the `if { ... }` guard of an
+    /// alternative rejected the symbols it matched.
+    GuardFailed {
+        /// The start of the first symbol.
+        start: L,
+
+        /// The end of the last symbol.
+        end: L,
+    },
+
+    /// Generated by the parser when an alternative marked
+    /// `#[since = "..."]` matched, but the parser was given an
+    /// older version of the language.
+    VersionTooOld {
+        /// The start of the first symbol.
+        start: L,
+
+        /// The end of the last symbol.
+        end: L,
+
+        /// The version that introduced the alternative.
+        since: Version,
+    },
+
+    /// Generated by parsers built with `Configuration::panic_free`
+    /// where other parsers would panic, when they find that one of
+    /// their own invariants does not hold. This indicates a bug in
+    /// LALRPOP rather than a problem with the input.
+    Internal {
+        /// What went wrong.
+        message: Text,
+    },
+
+    /// Never generated. It keeps code outside of this crate from
+    /// matching the variants exhaustively, so that adding one is not
+    /// a breaking change.
+    #[doc(hidden)]
+    __Nonexhaustive,
 }
 
 impl<L, T, E> ParseError<L, T, E> {
@@ -46,8 +261,18 @@ impl<L, T, E> ParseError<L, T, E> {
         match self {
             ParseError::InvalidToken { location } => ParseError::InvalidToken { location: loc_op(location) },
             ParseError::UnrecognizedToken { token, expected } => ParseError::UnrecognizedToken { token: token.map(maptok), expected: expected },
-            ParseError::ExtraToken { token } => ParseError::ExtraToken { token: maptok(token) },
-            ParseError::User { error } => ParseError::User { error: err_op(error) }
+            ParseError::UnrecognizedEof { location, expected } => ParseError::UnrecognizedEof { location: loc_op(location), expected: expected },
+            ParseError::ExtraToken { token, expected } => ParseError::ExtraToken { token: maptok(token), expected: expected },
+            ParseError::ReservedWord { token, description, expected } => ParseError::ReservedWord { token: maptok(token), description: description, expected: expected },
+            ParseError::User { error } => ParseError::User { error: err_op(error) },
+            ParseError::DepthLimit { location, depth } => ParseError::DepthLimit { location: loc_op(location), depth: depth },
+            ParseError::StackCapacity { location, capacity } => ParseError::StackCapacity { location: loc_op(location), capacity: capacity },
+            ParseError::ReductionLimit { location, limit } => ParseError::ReductionLimit { location: loc_op(location), limit: limit },
+            ParseError::Canceled { location } => ParseError::Canceled { location: loc_op(location) },
+            ParseError::GuardFailed { start, end } => ParseError::GuardFailed { start: loc_op(start), end: loc_op(end) },
+            ParseError::VersionTooOld { start, end, since } => ParseError::VersionTooOld { start: loc_op(start), end: loc_op(end), since: since },
+            ParseError::Internal { message } => ParseError::Internal { message: message },
+            ParseError::__Nonexhaustive => ParseError::__Nonexhaustive,
         }
     }
 
@@ -62,48 +287,280 @@ impl<L, T, E> ParseError<L, T, E> {
     pub fn map_error<F,EE>(self, op: F) -> ParseError<L, T, EE> where F: Fn(E) -> EE {
         self.map_intern(|x|x, |x|x, op)
     }
+
+    /// Returns the location at which this error starts, if it has
+    /// one. User errors, internal errors, and unexpected EOF from
+    /// parsers generated by older versions of LALRPOP, have no
+    /// location.
+    pub fn location(&self) -> Option<&L> {
+        match *self {
+            ParseError::InvalidToken { ref location } |
+            ParseError::UnrecognizedEof { ref location, .. } |
+            ParseError::DepthLimit { ref location, .. } |
+            ParseError::StackCapacity { ref location, .. } |
+            ParseError::ReductionLimit { ref location, .. } |
+            ParseError::Canceled { ref location } |
+            ParseError::GuardFailed { start: ref location, .. } |
+            ParseError::VersionTooOld { start: ref location, .. } => Some(location),
+            ParseError::UnrecognizedToken { token: Some((ref location, _, _)), .. } |
+            ParseError::ExtraToken { token: (ref location, _, _), .. } |
+            ParseError::ReservedWord { token: (ref location, _, _), .. } => Some(location),
+            ParseError::UnrecognizedToken { token: None, .. } |
+            ParseError::User { .. } |
+            ParseError::Internal { .. } |
+            ParseError::__Nonexhaustive => None,
+        }
+    }
 }
 
-impl<L, T, E> fmt::Display for ParseError<L, T, E>
+/// Merges several lists of errors (e.g., those recovered from by
+/// different parsers run over the same input) into one list, ordered
+/// by location. Errors without a location come last; otherwise, the
+/// relative order of the errors is preserved.
+#[cfg(feature = "std")]
+pub fn merge_errors<L, T, E, I>(lists: I) -> Vec<ParseError<L, T, E>>
+    where L: Ord, I: IntoIterator<Item=Vec<ParseError<L, T, E>>>
+{
+    let mut errors: Vec<_> = lists.into_iter().flat_map(|list| list).collect();
+    errors.sort_by(|a, b| {
+        match (a.location(), b.location()) {
+            (Some(a), Some(b)) => a.cmp(b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    });
+    errors
+}
+
+impl<L, T, E> ParseError<L, T, E> {
+    /// The names of the tokens that the parser expected where the
+    /// error occurred, for the errors that have them.
+    #[cfg(feature = "std")]
+    pub fn expected(&self) -> &[String] {
+        match *self {
+            ParseError::UnrecognizedToken { ref expected, .. } |
+            ParseError::UnrecognizedEof { ref expected, .. } |
+            ParseError::ExtraToken { ref expected, .. } |
+            ParseError::ReservedWord { ref expected, .. } => expected,
+            _ => &[],
+        }
+    }
+
+    /// Returns a value that displays this error as configured by
+    /// `options`. Displaying the error itself is equivalent to
+    /// displaying it with the default options.
+    pub fn display_with(&self, options: DisplayOptions) -> DisplayWith<L, T, E> {
+        DisplayWith { error: self, options: options }
+    }
+
+    /// Wraps this error so that, if it is a `User` error, the user
+    /// error is reported as its cause.
+    pub fn chained(self) -> ChainedError<L, T, E> {
+        ChainedError(self)
+    }
+}
+
+/// Controls how `ParseError::display_with` renders an error.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DisplayOptions {
+    /// Include the location of the error. Default is true.
+    pub location: bool,
+
+    /// Include the list of tokens the parser expected. Default is
+    /// true.
+    pub expected: bool,
+
+    /// If non-zero, list at most this many expected tokens. Default
+    /// is zero.
+    pub max_expected: usize,
+}
+
+impl Default for DisplayOptions {
+    fn default() -> Self {
+        DisplayOptions {
+            location: true,
+            expected: true,
+            max_expected: 0,
+        }
+    }
+}
+
+/// A `ParseError` paired with the `DisplayOptions` to render it with;
+/// see `ParseError::display_with`.
+pub struct DisplayWith<'e, L: 'e, T: 'e, E: 'e> {
+    error: &'e ParseError<L, T, E>,
+    options: DisplayOptions,
+}
+
+impl<'e, L, T, E> DisplayWith<'e, L, T, E>
+where L: fmt::Display
+{
+    fn write_location(&self, f: &mut fmt::Formatter, text: &str, location: &L) -> fmt::Result {
+        if self.options.location {
+            write!(f, " {} {}", text, location)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn write_span(&self, f: &mut fmt::Formatter, start: &L, end: &L) -> fmt::Result {
+        if self.options.location {
+            write!(f, " found at {}:{}", start, end)
+        } else {
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn write_expected(&self, f: &mut fmt::Formatter, expected: &[String]) -> fmt::Result {
+        if expected.is_empty() {
+            return Ok(());
+        }
+        self.write_expected_list(f, "Expected one of", expected)
+    }
+
+    /// Like `write_expected`, for a token after a complete parse,
+    /// where the end of the input is expected too.
+    #[cfg(feature = "std")]
+    fn write_expected_after_complete_parse(&self,
+                                           f: &mut fmt::Formatter,
+                                           expected: &[String])
+                                           -> fmt::Result {
+        if expected.is_empty() {
+            return Ok(());
+        }
+        self.write_expected_list(f, "Expected end of input or one of", expected)
+    }
+
+    #[cfg(feature = "std")]
+    fn write_expected_list(&self,
+                           f: &mut fmt::Formatter,
+                           heading: &str,
+                           expected: &[String])
+                           -> fmt::Result {
+        if !self.options.expected {
+            return Ok(());
+        }
+
+        let shown = if self.options.max_expected != 0 && self.options.max_expected < expected.len() {
+            self.options.max_expected
+        } else {
+            expected.len()
+        };
+
+        try!(writeln!(f, ""));
+        for (i, e) in expected[..shown].iter().enumerate() {
+            let sep = match i {
+                0 => heading,
+                _ if i < expected.len() - 1 => ",",
+                // Last expected message to be written
+                _ => " or",
+            };
+            try!(write!(f, "{} {}", sep, e));
+        }
+        if shown < expected.len() {
+            try!(write!(f, " or {} more", expected.len() - shown));
+        }
+        Ok(())
+    }
+
+    // (without the `std` feature there are no names to write)
+    #[cfg(not(feature = "std"))]
+    fn write_expected(&self, _: &mut fmt::Formatter, _: &Expected) -> fmt::Result {
+        Ok(())
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn write_expected_after_complete_parse(&self,
+                                           _: &mut fmt::Formatter,
+                                           _: &Expected)
+                                           -> fmt::Result {
+        Ok(())
+    }
+}
+
+impl<'e, L, T, E> fmt::Display for DisplayWith<'e, L, T, E>
 where L: fmt::Display,
       T: fmt::Display,
       E: fmt::Display
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use self::ParseError::*;
-        match *self {
-            InvalidToken { ref location } =>
-                write!(f, "Invalid token at {}", location),
+        match *self.error {
+            InvalidToken { ref location } => {
+                try!(write!(f, "Invalid token"));
+                self.write_location(f, "at", location)
+            }
             UnrecognizedToken { ref token, ref expected } => {
                 match *token {
-                    Some((ref start, ref token, ref end)) =>
-                        try!(write!(f, "Unrecognized token `{}` found at {}:{}", token, start, end)),
+                    Some((ref start, ref token, ref end)) => {
+                        try!(write!(f, "Unrecognized token `{}`", token));
+                        try!(self.write_span(f, start, end));
+                    }
                     None =>
                         try!(write!(f, "Unrecognized EOF")),
                 }
-                if !expected.is_empty() {
-                    try!(writeln!(f, ""));
-                    for (i, e) in expected.iter().enumerate() {
-                        let sep = match i {
-                            0 => "Expected one of",
-                            _ if i < expected.len() - 1 => ",",
-                            // Last expected message to be written
-                            _ => " or",
-                        };
-                        try!(write!(f, "{} {}", sep, e));
-                    }
-                }
-                Ok(())
+                self.write_expected(f, expected)
+            }
+            UnrecognizedEof { ref location, ref expected } => {
+                try!(write!(f, "Unrecognized EOF"));
+                try!(self.write_location(f, "found at", location));
+                self.write_expected(f, expected)
             }
-            ExtraToken { token: (ref start, ref token, ref end) } => {
-                write!(f, "Extra token {} found at {}:{}", token, start, end)
+            ExtraToken { token: (ref start, ref token, ref end), ref expected } => {
+                try!(write!(f, "Extra token {}", token));
+                try!(self.write_span(f, start, end));
+                self.write_expected_after_complete_parse(f, expected)
+            }
+            ReservedWord { token: (ref start, ref token, _), ref description, .. } => {
+                try!(write!(f, "Expected {}, found keyword `{}`", description, token));
+                self.write_location(f, "at", start)
             }
             User { ref error } =>
-                write!(f, "{}", error)
+                write!(f, "{}", error),
+            DepthLimit { ref location, depth } => {
+                try!(write!(f, "Maximum nesting depth of {} exceeded", depth));
+                self.write_location(f, "at", location)
+            }
+            StackCapacity { ref location, capacity } => {
+                try!(write!(f, "Stack capacity of {} symbols exceeded", capacity));
+                self.write_location(f, "at", location)
+            }
+            ReductionLimit { ref location, limit } => {
+                try!(write!(f, "Maximum number of {} reductions exceeded", limit));
+                self.write_location(f, "at", location)
+            }
+            Canceled { ref location } => {
+                try!(write!(f, "Parse canceled"));
+                self.write_location(f, "at", location)
+            }
+            GuardFailed { ref start, ref end } => {
+                try!(write!(f, "Rejected by guard"));
+                self.write_span(f, start, end)
+            }
+            VersionTooOld { ref start, ref end, since } => {
+                try!(write!(f, "Requires version {} or later", since));
+                self.write_span(f, start, end)
+            }
+            Internal { ref message } =>
+                write!(f, "Internal parser error: {}", message),
+            __Nonexhaustive => unreachable!(),
         }
     }
 }
 
+impl<L, T, E> fmt::Display for ParseError<L, T, E>
+where L: fmt::Display,
+      T: fmt::Display,
+      E: fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.display_with(DisplayOptions::default()), f)
+    }
+}
+
+#[cfg(feature = "std")]
 impl<L, T, E> Error for ParseError<L, T, E>
 where L: fmt::Debug + fmt::Display,
       T: fmt::Debug + fmt::Display,
@@ -114,12 +571,104 @@ where L: fmt::Debug + fmt::Display,
     }
 }
 
+/// A `ParseError` whose user error type implements `Error`, so that
+/// the user error can be reported as the source of the parse error.
+/// (`ParseError` itself cannot do this, since user errors are often
+/// plain strings.) See `ParseError::chained`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChainedError<L, T, E>(pub ParseError<L, T, E>);
+
+impl<L, T, E> From<ParseError<L, T, E>> for ChainedError<L, T, E> {
+    fn from(error: ParseError<L, T, E>) -> Self {
+        ChainedError(error)
+    }
+}
+
+impl<L, T, E> fmt::Display for ChainedError<L, T, E>
+where L: fmt::Display,
+      T: fmt::Display,
+      E: fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<L, T, E> Error for ChainedError<L, T, E>
+where L: fmt::Debug + fmt::Display,
+      T: fmt::Debug + fmt::Display,
+      E: Error + 'static
+{
+    fn description(&self) -> &str {
+        "parse error"
+    }
+
+    fn source(&self) -> Option<&(Error + 'static)> {
+        match self.0 {
+            ParseError::User { ref error } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// The value of the `!` token, which a parser passes to the action
+/// of the rule it used to recover from a syntax error.
+#[cfg(feature = "std")]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ErrorRecovery<L, T, E> {
     pub error: ParseError<L, T, E>,
+
+    /// The tokens that were skipped to resume parsing, with their
+    /// locations, starting with the one that caused the error (if it
+    /// was skipped).
     pub dropped_tokens: Vec<(L, T, L)>,
+
+    /// The parser state that recovered, by shifting the `!` token.
+    /// States are numbered as in the comments of the parse tables in
+    /// the generated code, so this tells which `!` rules could apply;
+    /// the numbers change whenever the grammar does.
+    pub state: usize,
 }
 
+#[cfg(feature = "std")]
+impl<L, T, E> ErrorRecovery<L, T, E> {
+    pub fn map_location<F,LL>(self, op: F) -> ErrorRecovery<LL, T, E> where F: Fn(L) -> LL {
+        ErrorRecovery {
+            dropped_tokens: self.dropped_tokens
+                                .into_iter()
+                                .map(|(l, t, r)| (op(l), t, op(r)))
+                                .collect(),
+            error: self.error.map_location(op),
+            state: self.state,
+        }
+    }
+
+    pub fn map_token<F,TT>(self, op: F) -> ErrorRecovery<L, TT, E> where F: Fn(T) -> TT {
+        ErrorRecovery {
+            dropped_tokens: self.dropped_tokens
+                                .into_iter()
+                                .map(|(l, t, r)| (l, op(t), r))
+                                .collect(),
+            error: self.error.map_token(op),
+            state: self.state,
+        }
+    }
+
+    pub fn map_error<F,EE>(self, op: F) -> ErrorRecovery<L, T, EE> where F: Fn(E) -> EE {
+        ErrorRecovery {
+            error: self.error.map_error(op),
+            dropped_tokens: self.dropped_tokens,
+            state: self.state,
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+extern crate serde_json;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -136,4 +685,148 @@ mod tests {
         assert_eq!(format!("{}", err), "Unrecognized token `t0` found at 1:2\n\
                                         Expected one of t1, t2 or t3");
     }
+
+    #[test]
+    fn test_unrecognized_eof() {
+        let err = ParseError::UnrecognizedEof::<i32, &str, &str> {
+            location: 4,
+            expected: vec!["t1".to_string()],
+        };
+        assert_eq!(format!("{}", err), "Unrecognized EOF found at 4\n\
+                                        Expected one of t1");
+        assert_eq!(err.location(), Some(&4));
+        assert_eq!(err.expected(), &["t1".to_string()]);
+    }
+
+    #[test]
+    fn test_extra_token() {
+        let err = ParseError::ExtraToken::<i32, &str, &str> {
+            token: (1, "t0", 2),
+            expected: vec!["t1".to_string(), "t2".to_string()],
+        };
+        assert_eq!(format!("{}", err), "Extra token t0 found at 1:2\n\
+                                        Expected end of input or one of t1 or t2");
+        let err = ParseError::ExtraToken::<i32, &str, &str> { token: (1, "t0", 2), expected: vec![] };
+        assert_eq!(format!("{}", err), "Extra token t0 found at 1:2");
+    }
+
+    #[test]
+    fn test_depth_limit() {
+        let err = ParseError::DepthLimit::<i32, &str, &str> { location: 7, depth: 64 };
+        assert_eq!(format!("{}", err), "Maximum nesting depth of 64 exceeded at 7");
+        assert_eq!(err.map_location(|l| l * 2),
+                   ParseError::DepthLimit { location: 14, depth: 64 });
+    }
+
+    #[test]
+    fn test_stack_capacity() {
+        let err = ParseError::StackCapacity::<i32, &str, &str> { location: 7, capacity: 12 };
+        assert_eq!(format!("{}", err), "Stack capacity of 12 symbols exceeded at 7");
+        assert_eq!(err.location(), Some(&7));
+    }
+
+    #[test]
+    fn test_reduction_limit() {
+        let err = ParseError::ReductionLimit::<i32, &str, &str> { location: 7, limit: 1000 };
+        assert_eq!(format!("{}", err), "Maximum number of 1000 reductions exceeded at 7");
+        assert_eq!(err.location(), Some(&7));
+    }
+
+    #[test]
+    fn test_reserved_word() {
+        let err = ParseError::ReservedWord::<i32, &str, &str> {
+            token: (3, "while", 8),
+            description: "identifier".to_string(),
+            expected: vec!["ID".to_string()],
+        };
+        assert_eq!(format!("{}", err), "Expected identifier, found keyword `while` at 3");
+        assert_eq!(err.location(), Some(&3));
+        assert_eq!(err.expected(), &["ID".to_string()]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let err = ParseError::UnrecognizedToken::<usize, String, String> {
+            token: Some((1, "t0".to_string(), 2)),
+            expected: vec!["t1".to_string()],
+        };
+        let recovery = ErrorRecovery {
+            error: err,
+            dropped_tokens: vec![(3, "t4".to_string(), 4)],
+            state: 5,
+        };
+        let json = serde_json::to_string(&recovery).unwrap();
+        let back: ErrorRecovery<usize, String, String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, recovery);
+    }
+
+    #[test]
+    fn test_display_with() {
+        let err = ParseError::UnrecognizedToken::<i32, &str, &str> {
+            token: Some((1, "t0", 2)),
+            expected: vec!["t1", "t2", "t3"]
+                .into_iter()
+                .map(|s| s.to_string())
+                .collect()
+        };
+        let options = DisplayOptions { location: false, max_expected: 2, ..DisplayOptions::default() };
+        assert_eq!(format!("{}", err.display_with(options)), "Unrecognized token `t0`\n\
+                                                              Expected one of t1, t2 or 1 more");
+        let options = DisplayOptions { expected: false, ..DisplayOptions::default() };
+        assert_eq!(format!("{}", err.display_with(options)), "Unrecognized token `t0` found at 1:2");
+    }
+
+    #[test]
+    fn test_chained_source() {
+        use std::fmt;
+
+        #[derive(Debug)]
+        struct MyError;
+
+        impl fmt::Display for MyError {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "my error")
+            }
+        }
+
+        impl Error for MyError {
+            fn description(&self) -> &str {
+                "my error"
+            }
+        }
+
+        let err = ParseError::User::<i32, &str, MyError> { error: MyError }.chained();
+        assert_eq!(err.source().map(|e| e.to_string()), Some("my error".to_string()));
+
+        let err = ParseError::InvalidToken::<i32, &str, MyError> { location: 0 }.chained();
+        assert!(err.source().is_none());
+    }
+
+    #[test]
+    fn test_merge_errors() {
+        let first = vec![ParseError::User::<i32, &str, &str> { error: "user" },
+                         ParseError::InvalidToken { location: 5 }];
+        let second = vec![ParseError::ExtraToken { token: (3, "t", 4), expected: vec![] },
+                          ParseError::UnrecognizedToken { token: None, expected: vec![] }];
+        let merged = merge_errors(vec![first, second]);
+        assert_eq!(merged.iter().map(|e| e.location().cloned()).collect::<Vec<_>>(),
+                   vec![Some(3), Some(5), None, None]);
+        assert_eq!(merged[2], ParseError::User { error: "user" });
+    }
+
+    #[test]
+    fn test_error_recovery_map() {
+        let recovery = ErrorRecovery {
+            error: ParseError::ExtraToken::<i32, &str, &str> { token: (1, "t", 2), expected: vec![] },
+            dropped_tokens: vec![(3, "u", 4)],
+            state: 3,
+        };
+        let mapped = recovery.map_location(|l| l * 10).map_token(|t| t.len());
+        assert_eq!(mapped, ErrorRecovery {
+            error: ParseError::ExtraToken { token: (10, 1, 20), expected: vec![] },
+            dropped_tokens: vec![(30, 1, 40)],
+            state: 3,
+        });
+    }
 }
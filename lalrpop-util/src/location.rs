@@ -0,0 +1,328 @@
+//! Location types that a lexer can attach to the tokens it produces
+//! and that a grammar can then bind with `@L` and `@R`.
+
+use core::cmp;
+use core::fmt;
+use ParseError;
+
+#[cfg(feature = "unicode-segmentation")]
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A position in the input that a lexer can compute as it goes: it
+/// uses `advance` to find the location that follows a piece of text.
+/// The built-in tokenizer produces byte offsets, but a grammar that
+/// uses it can declare another `Location` type, which then has to
+/// implement this trait:
+///
+/// ```ignore
+/// extern {
+///     type Location = lalrpop_util::LineCol;
+/// }
+/// ```
+///
+/// The generated parsers do not otherwise require it of a grammar's
+/// `Location` type; they only ever clone locations and create default
+/// ones (for empty inputs).
+pub trait Location: Clone + Default {
+    /// Returns the location reached by consuming `text` starting at
+    /// `self`.
+    fn advance(&self, text: &str) -> Self;
+}
+
+/// Byte offsets, the convention used by the built-in tokenizer.
+impl Location for usize {
+    fn advance(&self, text: &str) -> usize {
+        *self + text.len()
+    }
+}
+
+/// A location carrying both the byte offset and the (1-based) line
+/// and column, counted in characters. Use `Columns` to count columns
+/// differently.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LineCol {
+    pub offset: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl LineCol {
+    /// Computes the location of the byte `offset` within `source`,
+    /// clamped as described for `Columns::from_offset`.
+    pub fn from_offset(source: &str, offset: usize) -> LineCol {
+        Columns::new().from_offset(source, offset)
+    }
+}
+
+impl Default for LineCol {
+    fn default() -> LineCol {
+        LineCol { offset: 0, line: 1, column: 1 }
+    }
+}
+
+impl Location for LineCol {
+    fn advance(&self, text: &str) -> LineCol {
+        Columns::new().advance(*self, text)
+    }
+}
+
+/// What the columns of a `LineCol` count.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ColumnUnit {
+    /// Unicode scalar values (`char`s), the default.
+    Chars,
+
+    /// UTF-16 code units, as the Language Server Protocol counts
+    /// them.
+    Utf16,
+
+    /// Extended grapheme clusters, which is what a user sees as one
+    /// character (e.g., `e` followed by a combining accent). Telling
+    /// them apart requires the `unicode-segmentation` feature; without
+    /// it, columns count `char`s, as for `Chars`.
+    Graphemes,
+}
+
+/// Computes `LineCol` locations whose columns count `ColumnUnit`s,
+/// and where tabs advance to the next tab stop, for reporting
+/// locations the way an editor shows them:
+///
+/// ```
+/// use lalrpop_util::{ColumnUnit, Columns, LineCol};
+///
+/// let columns = Columns::new().unit(ColumnUnit::Utf16).tab_width(4);
+/// let location = columns.from_offset("\tx = \"\u{1F600}\";", 10);
+/// assert_eq!((location.line, location.column), (1, 12));
+/// ```
+///
+/// The default, `Columns::new()`, counts `char`s and treats a tab as
+/// one column, like `LineCol::advance`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Columns {
+    unit: ColumnUnit,
+    tab_width: usize,
+}
+
+impl Columns {
+    pub fn new() -> Columns {
+        Columns { unit: ColumnUnit::Chars, tab_width: 1 }
+    }
+
+    /// Sets what the columns count.
+    pub fn unit(mut self, unit: ColumnUnit) -> Columns {
+        self.unit = unit;
+        self
+    }
+
+    /// Sets the distance between tab stops. A width of 0 is taken to
+    /// be 1, so that a tab is always one column at least.
+    pub fn tab_width(mut self, tab_width: usize) -> Columns {
+        self.tab_width = cmp::max(tab_width, 1);
+        self
+    }
+
+    /// Returns the location reached by consuming `text` starting at
+    /// `location`. A grapheme cluster that `text` splits is counted
+    /// as if it ended at the end of `text`.
+    pub fn advance(&self, location: LineCol, text: &str) -> LineCol {
+        let mut result = location;
+        match self.unit {
+            #[cfg(not(feature = "unicode-segmentation"))]
+            ColumnUnit::Chars | ColumnUnit::Graphemes => {
+                for c in text.chars() {
+                    self.step(&mut result, c, 1);
+                }
+            }
+            #[cfg(feature = "unicode-segmentation")]
+            ColumnUnit::Chars => {
+                for c in text.chars() {
+                    self.step(&mut result, c, 1);
+                }
+            }
+            ColumnUnit::Utf16 => {
+                for c in text.chars() {
+                    self.step(&mut result, c, c.len_utf16());
+                }
+            }
+            #[cfg(feature = "unicode-segmentation")]
+            ColumnUnit::Graphemes => {
+                for grapheme in text.graphemes(true) {
+                    // `\r\n` is a single grapheme cluster
+                    let c = if grapheme.ends_with('\n') {
+                        '\n'
+                    } else {
+                        grapheme.chars().next().unwrap()
+                    };
+                    self.step(&mut result, c, 1);
+                }
+            }
+        }
+        result.offset += text.len();
+        result
+    }
+
+    /// Computes the location of the byte `offset` within `source`. An
+    /// offset past the end of `source` is taken to be its end, and one
+    /// in the middle of a character the start of that character.
+    pub fn from_offset(&self, source: &str, offset: usize) -> LineCol {
+        let mut offset = cmp::min(offset, source.len());
+        while !source.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        self.advance(LineCol::default(), &source[..offset])
+    }
+
+    fn step(&self, location: &mut LineCol, c: char, width: usize) {
+        match c {
+            '\n' => {
+                location.line += 1;
+                location.column = 1;
+            }
+            '\t' => {
+                let stop = (location.column - 1) / self.tab_width + 1;
+                location.column = stop * self.tab_width + 1;
+            }
+            _ => location.column += width,
+        }
+    }
+}
+
+impl Default for Columns {
+    fn default() -> Columns {
+        Columns::new()
+    }
+}
+
+/// Converts the byte offsets of the tokens that a lexer produces from
+/// `text`, and of its errors, into locations of type `L`, advancing
+/// from each token to the next. The parsers of a grammar that uses
+/// the built-in tokenizer wrap it in this if the grammar declares a
+/// `Location` type.
+pub struct Locations<'input, L, I> {
+    text: &'input str,
+    offset: usize,
+    location: L,
+    tokens: I,
+}
+
+impl<'input, L: Location, I> Locations<'input, L, I> {
+    pub fn new(text: &'input str, tokens: I) -> Locations<'input, L, I> {
+        Locations { text: text, offset: 0, location: L::default(), tokens: tokens }
+    }
+}
+
+/// The location of the byte `offset` of `text`, given that `from` is
+/// the location of the byte `from_offset`. Offsets are normally met in
+/// order, but one before `from_offset` is located from the start.
+fn locate<L: Location>(text: &str, from_offset: usize, from: &L, offset: usize) -> L {
+    if offset < from_offset {
+        L::default().advance(&text[..offset])
+    } else {
+        from.advance(&text[from_offset..offset])
+    }
+}
+
+impl<'input, L, T, E, I> Iterator for Locations<'input, L, I>
+    where L: Location, I: Iterator<Item = Result<(usize, T, usize), ParseError<usize, T, E>>>
+{
+    type Item = Result<(L, T, L), ParseError<L, T, E>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.tokens.next() {
+            None => None,
+            Some(Ok((start, token, end))) => {
+                let start_location = locate(self.text, self.offset, &self.location, start);
+                let end_location = start_location.advance(&self.text[start..end]);
+                self.offset = end;
+                self.location = end_location.clone();
+                Some(Ok((start_location, token, end_location)))
+            }
+            Some(Err(error)) => {
+                let (text, offset, location) = (self.text, self.offset, &self.location);
+                Some(Err(error.map_location(|o| locate(text, offset, location, o))))
+            }
+        }
+    }
+}
+
+impl fmt::Display for LineCol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn usize_advance() {
+        assert_eq!(3.advance("é!"), 6);
+    }
+
+    #[test]
+    fn line_col_advance() {
+        let start = LineCol::default();
+        let end = start.advance("ab\ncdé");
+        assert_eq!(end, LineCol { offset: 7, line: 2, column: 4 });
+        assert_eq!(end.to_string(), "2:4");
+    }
+
+    #[test]
+    fn columns() {
+        let text = "\ta\u{1F600}b\n  \tc";
+        let end = |columns: Columns| {
+            let end = columns.advance(LineCol::default(), text);
+            (end.line, end.column)
+        };
+        assert_eq!(end(Columns::new()), (2, 5));
+        assert_eq!(end(Columns::new().tab_width(4)), (2, 6));
+        assert_eq!(end(Columns::new().tab_width(0)), (2, 5));
+        assert_eq!(Columns::new().tab_width(4).from_offset(text, 7).column, 8);
+        assert_eq!(Columns::new().unit(ColumnUnit::Utf16).from_offset(text, 6).column, 5);
+    }
+
+    #[cfg(not(feature = "unicode-segmentation"))]
+    #[test]
+    fn grapheme_columns_without_segmentation() {
+        let columns = Columns::new().unit(ColumnUnit::Graphemes);
+        assert_eq!(columns.from_offset("e\u{301}x", 4).column, 4);
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[test]
+    fn grapheme_columns() {
+        let columns = Columns::new().unit(ColumnUnit::Graphemes);
+        let end = columns.advance(LineCol::default(), "e\u{301}x\r\ny");
+        assert_eq!((end.line, end.column), (2, 2));
+        assert_eq!(columns.from_offset("e\u{301}x", 3).column, 2);
+    }
+
+    #[test]
+    fn locations() {
+        let text = "ab\n  cd!";
+        let tokens = vec![Ok((0, "ab", 2)), Ok((5, "cd", 7)),
+                          Err(ParseError::InvalidToken { location: 7 })];
+        let locations: Vec<Result<(LineCol, &str, LineCol), ParseError<LineCol, &str, ()>>> =
+            Locations::new(text, tokens.into_iter()).collect();
+        let at = |offset, line, column| LineCol { offset: offset, line: line, column: column };
+        assert_eq!(locations,
+                   vec![Ok((at(0, 1, 1), "ab", at(2, 1, 3))),
+                        Ok((at(5, 2, 3), "cd", at(7, 2, 5))),
+                        Err(ParseError::InvalidToken { location: at(7, 2, 5) })]);
+    }
+
+    #[test]
+    fn line_col_from_offset() {
+        assert_eq!(LineCol::from_offset("x\n\ny", 3),
+                   LineCol { offset: 3, line: 3, column: 1 });
+    }
+
+    #[test]
+    fn line_col_from_bad_offset() {
+        assert_eq!(LineCol::from_offset("x\ny", 10),
+                   LineCol { offset: 3, line: 2, column: 2 });
+        assert_eq!(LineCol::from_offset("xé", 2),
+                   LineCol { offset: 1, line: 1, column: 2 });
+    }
+}
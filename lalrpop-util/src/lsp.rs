@@ -0,0 +1,171 @@
+//! Conversion of the byte offsets of the built-in tokenizer into the
+//! positions of the Language Server Protocol, whose lines count from
+//! 0 and whose characters are UTF-16 code units:
+//!
+//! ```ignore
+//! let index = LineIndex::new(text);
+//! match parser::parse_Program(text) {
+//!     Ok(program) => ...,
+//!     Err(error) => {
+//!         let range = index.range(&error);         // for the diagnostic
+//!         let error = index.map_error(error);      // for its message
+//!         ...
+//!     }
+//! }
+//! ```
+
+use std::fmt;
+
+use ParseError;
+use render::error_span;
+
+/// A position in a text document, as the Language Server Protocol
+/// defines it.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position {
+    /// The line, starting at 0.
+    pub line: usize,
+
+    /// The offset in the line, in UTF-16 code units.
+    pub character: usize,
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.character)
+    }
+}
+
+/// The starts of the lines of a source text, which make converting a
+/// byte offset into a `Position` take time logarithmic in the number
+/// of lines (plus the length of its line). Lines end with `\n`, `\r\n`
+/// or `\r`, as in the Language Server Protocol.
+#[derive(Clone, Debug)]
+pub struct LineIndex<'source> {
+    source: &'source str,
+    line_starts: Vec<usize>,
+}
+
+impl<'source> LineIndex<'source> {
+    pub fn new(source: &'source str) -> LineIndex<'source> {
+        let bytes = source.as_bytes();
+        let mut line_starts = vec![0];
+        for (i, &b) in bytes.iter().enumerate() {
+            if b == b'\n' || (b == b'\r' && bytes.get(i + 1) != Some(&b'\n')) {
+                line_starts.push(i + 1);
+            }
+        }
+        LineIndex { source: source, line_starts: line_starts }
+    }
+
+    /// The position of the byte `offset`. Offsets past the end of the
+    /// source are treated as its end, and offsets inside a character
+    /// as its start.
+    pub fn position(&self, offset: usize) -> Position {
+        let mut offset = if offset > self.source.len() { self.source.len() } else { offset };
+        while !self.source.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let line = match self.line_starts.binary_search(&offset) {
+            Ok(line) => line,
+            Err(next_line) => next_line - 1,
+        };
+        let line_start = self.line_starts[line];
+        let character = self.source[line_start..offset].chars().map(|c| c.len_utf16()).sum();
+        Position { line: line, character: character }
+    }
+
+    /// The byte offset of `position`, the inverse of `position`. As
+    /// the protocol requires, characters past the end of the line
+    /// stand for its end; `None` if the line does not exist or if the
+    /// character is inside a surrogate pair.
+    pub fn offset(&self, position: Position) -> Option<usize> {
+        let line_start = match self.line_starts.get(position.line) {
+            Some(&line_start) => line_start,
+            None => return None,
+        };
+        let line = &self.source[line_start..];
+        let line = match line.find(|c| c == '\n' || c == '\r') {
+            Some(end) => &line[..end],
+            None => line,
+        };
+        let mut character = 0;
+        for (i, c) in line.char_indices() {
+            if character == position.character {
+                return Some(line_start + i);
+            } else if character > position.character {
+                return None;
+            }
+            character += c.len_utf16();
+        }
+        if character <= position.character {
+            Some(line_start + line.len())
+        } else {
+            None
+        }
+    }
+
+    /// The range of the source that `error` is about, if it is about
+    /// one, as for `render_error`.
+    pub fn range<T, E>(&self, error: &ParseError<usize, T, E>) -> Option<(Position, Position)> {
+        error_span(error, self.source).map(|(lo, hi)| (self.position(lo), self.position(hi)))
+    }
+
+    /// Converts the byte offsets of `error` into `Position`s.
+    pub fn map_error<T, E>(&self, error: ParseError<usize, T, E>) -> ParseError<Position, T, E> {
+        error.map_location(|offset| self.position(offset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ParseError;
+
+    fn position(line: usize, character: usize) -> Position {
+        Position { line: line, character: character }
+    }
+
+    #[test]
+    fn positions() {
+        let source = "ab\r\n\u{1F600}é!\rx\n";
+        let index = LineIndex::new(source);
+        assert_eq!(index.position(1), position(0, 1));
+        assert_eq!(index.position(4), position(1, 0));
+        assert_eq!(index.position(10), position(1, 3));
+        assert_eq!(index.position(11), position(1, 4));
+        assert_eq!(index.position(12), position(2, 0));
+        assert_eq!(index.position(99), position(3, 0));
+        // inside the emoji
+        assert_eq!(index.position(6), position(1, 0));
+    }
+
+    #[test]
+    fn offsets() {
+        let source = "ab\r\n\u{1F600}é!\rx\n";
+        let index = LineIndex::new(source);
+        for offset in vec![0, 1, 2, 4, 8, 10, 11, 12, 14] {
+            assert_eq!(index.offset(index.position(offset)), Some(offset));
+        }
+        assert_eq!(index.offset(position(0, 9)), Some(2));
+        assert_eq!(index.offset(position(1, 1)), None);
+        assert_eq!(index.offset(position(4, 0)), None);
+    }
+
+    #[test]
+    fn errors() {
+        let index = LineIndex::new("x\n\u{1F600}+");
+        let error = ParseError::UnrecognizedToken::<usize, &str, &str> {
+            token: Some((6, "+", 7)),
+            expected: vec![],
+        };
+        assert_eq!(index.range(&error), Some((position(1, 2), position(1, 3))));
+        match index.map_error(error) {
+            ParseError::UnrecognizedToken { token: Some((start, _, end)), .. } => {
+                assert_eq!((start, end), (position(1, 2), position(1, 3)));
+            }
+            error => panic!("unexpected error {:?}", error),
+        }
+        assert_eq!(index.range(&ParseError::User::<usize, &str, &str> { error: "e" }), None);
+    }
+}
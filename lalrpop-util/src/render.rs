@@ -0,0 +1,166 @@
+//! Renders parse errors against the source text they came from, in
+//! the style of rustc:
+//!
+//! ```text
+//! error: Unrecognized token `+` found at 3:4
+//!  --> input.txt:1:4
+//!   |
+//! 1 | (1++2)
+//!   |    ^
+//! ```
+
+use std::cmp;
+use std::fmt::{self, Write};
+
+use ParseError;
+
+/// Renders `error`, whose locations are byte offsets into `source`,
+/// as an annotated snippet of `source` with the offending span
+/// underlined. `filename` is used only to label the snippet.
+pub fn render_error<T, E>(error: &ParseError<usize, T, E>, source: &str, filename: &str) -> String
+where T: fmt::Display,
+      E: fmt::Display
+{
+    let mut out = String::new();
+    let _ = write!(out, "error: {}", error);
+
+    let (lo, hi) = match error_span(error, source) {
+        Some(span) => span,
+        None => return out,
+    };
+
+    let line_start = source[..lo].rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let line_end = source[lo..].find('\n').map(|i| lo + i).unwrap_or(source.len());
+    let line_text = &source[line_start..line_end];
+    let line_num = source[..line_start].matches('\n').count() + 1;
+    let col = source[line_start..lo].chars().count() + 1;
+
+    // underline the span, but no further than the end of its first
+    // line; an empty span (e.g., EOF) gets a single caret
+    let hi = if hi > line_end { line_end } else { hi };
+    let width = source[lo..hi].chars().count();
+
+    // reproduce any tabs in the indentation so the carets line up
+    let padding: String = source[line_start..lo]
+        .chars()
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+
+    let gutter = line_num.to_string().len();
+    let _ = writeln!(out, "");
+    let _ = writeln!(out, "{0:1$}--> {2}:{3}:{4}", "", gutter, filename, line_num, col);
+    let _ = writeln!(out, "{0:1$} |", "", gutter);
+    let _ = writeln!(out, "{} | {}", line_num, line_text);
+    let _ = write!(out, "{0:1$} | {2}", "", gutter, padding);
+    for _ in 0..cmp::max(width, 1) {
+        out.push('^');
+    }
+
+    out
+}
+
+/// Returns the byte span of `source` that `error` refers to, the one
+/// `render_error` underlines, if any. An error without a location, or
+/// whose location does not fall on a character boundary of `source`,
+/// has none.
+pub fn error_span<T, E>(error: &ParseError<usize, T, E>, source: &str) -> Option<(usize, usize)> {
+    let span = match *error {
+        ParseError::InvalidToken { location } |
+        ParseError::UnrecognizedEof { location, .. } |
+        ParseError::DepthLimit { location, .. } |
+        ParseError::StackCapacity { location, .. } |
+        ParseError::ReductionLimit { location, .. } |
+        ParseError::Canceled { location } => (location, location),
+        ParseError::UnrecognizedToken { token: Some((lo, _, hi)), .. } |
+        ParseError::ExtraToken { token: (lo, _, hi), .. } |
+        ParseError::ReservedWord { token: (lo, _, hi), .. } |
+        ParseError::GuardFailed { start: lo, end: hi } |
+        ParseError::VersionTooOld { start: lo, end: hi, .. } => (lo, hi),
+        ParseError::UnrecognizedToken { token: None, .. } => (source.len(), source.len()),
+        ParseError::User { .. } |
+        ParseError::Internal { .. } |
+        ParseError::__Nonexhaustive => return None,
+    };
+
+    // ignore locations that do not make sense for `source`
+    if span.0 <= span.1 && span.1 <= source.len() &&
+       source.is_char_boundary(span.0) && source.is_char_boundary(span.1) {
+        Some(span)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ParseError;
+    use super::{error_span, render_error};
+
+    #[test]
+    fn unrecognized_token() {
+        let err = ParseError::UnrecognizedToken::<usize, &str, &str> {
+            token: Some((7, "+", 8)),
+            expected: vec![],
+        };
+        assert_eq!(render_error(&err, "(1+2)\n(1++2)\n", "input.txt"),
+                   "error: Unrecognized token `+` found at 7:8\n\
+                    \x20--> input.txt:2:2\n\
+                    \x20 |\n\
+                    2 | (1++2)\n\
+                    \x20 |  ^");
+    }
+
+    #[test]
+    fn multi_char_span_with_tabs() {
+        let err = ParseError::ExtraToken::<usize, &str, &str> { token: (2, "foo", 5), expected: vec![] };
+        assert_eq!(render_error(&err, "\t\tfoo", "input.txt"),
+                   "error: Extra token foo found at 2:5\n\
+                    \x20--> input.txt:1:3\n\
+                    \x20 |\n\
+                    1 | \t\tfoo\n\
+                    \x20 | \t\t^^^");
+    }
+
+    #[test]
+    fn eof() {
+        let err = ParseError::UnrecognizedToken::<usize, &str, &str> {
+            token: None,
+            expected: vec![],
+        };
+        assert_eq!(render_error(&err, "(1", "input.txt"),
+                   "error: Unrecognized EOF\n\
+                    \x20--> input.txt:1:3\n\
+                    \x20 |\n\
+                    1 | (1\n\
+                    \x20 |   ^");
+    }
+
+    #[test]
+    fn eof_with_location() {
+        let err = ParseError::UnrecognizedEof::<usize, &str, &str> {
+            location: 2,
+            expected: vec![String::from("\")\"")],
+        };
+        assert_eq!(render_error(&err, "(1  ", "input.txt"),
+                   "error: Unrecognized EOF found at 2\n\
+                    Expected one of \")\"\n\
+                    \x20--> input.txt:1:3\n\
+                    \x20 |\n\
+                    1 | (1  \n\
+                    \x20 |   ^");
+    }
+
+    #[test]
+    fn user_error_has_no_snippet() {
+        let err = ParseError::User::<usize, &str, &str> { error: "oops" };
+        assert_eq!(render_error(&err, "(1", "input.txt"), "error: oops");
+    }
+
+    #[test]
+    fn spans() {
+        let err = ParseError::ExtraToken::<usize, &str, &str> { token: (2, "é", 4), expected: vec![] };
+        assert_eq!(error_span(&err, "(1é"), Some((2, 4)));
+        assert_eq!(error_span(&err, "(1"), None);
+        assert_eq!(error_span(&err, "(1\u{2122}"), None);
+    }
+}
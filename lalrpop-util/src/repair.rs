@@ -0,0 +1,447 @@
+//! Error repair for table-driven parsers, used by grammars marked
+//! `#![repair]`. When the parser hits a token it cannot shift, the
+//! search below looks for the cheapest ways to edit the input so that
+//! parsing can go on: inserting terminals before the token and
+//! deleting tokens each cost one, and a repair counts as a success
+//! once the parser can shift a few more tokens of the original input
+//! after it (or accept). This is the CPCT+ algorithm of grmtools, minus
+//! its ranking of the repairs.
+//!
+//! The search only needs the parse tables, which the generated parser
+//! exposes through the `Automaton` trait. `report` and `apply` turn
+//! the `Step`s it finds into something to show the user and into an
+//! edited token stream to parse again.
+
+use std::fmt;
+use ParseError;
+
+/// The parse tables of a generated parser.
+pub trait Automaton {
+    /// The number of terminals.
+    fn terminals(&self) -> usize;
+
+    /// The action of `state` on `terminal` (`None` for EOF), encoded
+    /// as in the generated tables: `s + 1` shifts to state `s`,
+    /// `-(p + 1)` reduces production `p`, and `0` is an error.
+    fn action(&self, state: i32, terminal: Option<usize>) -> i32;
+
+    /// How many symbols production `p` pops, and the index of its
+    /// nonterminal; `None` for the production of the start symbol,
+    /// reducing which accepts the input.
+    fn production(&self, production: usize) -> Option<(usize, usize)>;
+
+    /// The state to go to after reducing `nonterminal` in `state`.
+    fn goto(&self, state: i32, nonterminal: usize) -> i32;
+
+    /// Whether a repair may insert `terminal`. Terminals whose tokens
+    /// carry data the parser cannot make up should not be inserted.
+    fn insertable(&self, _terminal: usize) -> bool {
+        true
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Step {
+    /// Insert the terminal with the given index before the next token.
+    Insert(usize),
+
+    /// Delete the next token.
+    Delete,
+
+    /// Keep the next token.
+    Shift,
+}
+
+/// How much searching for repairs may cost.
+#[derive(Copy, Clone, Debug)]
+pub struct Budget {
+    /// The most insertions and deletions a repair may make.
+    pub cost: usize,
+
+    /// The most configurations (parser states along with a position
+    /// in the input) to look at before giving up.
+    pub configurations: usize,
+}
+
+impl Default for Budget {
+    fn default() -> Budget {
+        Budget {
+            cost: 3,
+            configurations: 10_000,
+        }
+    }
+}
+
+/// A repair is successful once this many tokens of the input after
+/// it have been shifted.
+const SHIFTS: usize = 3;
+
+struct Configuration {
+    states: Vec<i32>,
+    position: usize,
+    steps: Vec<Step>,
+    shifted: usize,
+}
+
+enum Outcome {
+    Shift(Vec<i32>),
+    Accept,
+    Error,
+}
+
+/// Finds the cheapest repairs for a parser with the state stack
+/// `states` that could not shift the first of `tokens` (the
+/// terminals of the rest of the input, or nothing at EOF). The
+/// repairs are sorted, and do not end with `Shift`s; there are none
+/// if no repair fits in `budget`.
+pub fn repairs<A: Automaton>(automaton: &A,
+                             states: &[i32],
+                             tokens: &[usize],
+                             budget: Budget)
+                             -> Vec<Vec<Step>> {
+    let mut explored = 0;
+    let mut level = vec![Configuration {
+                             states: states.to_vec(),
+                             position: 0,
+                             steps: vec![],
+                             shifted: 0,
+                         }];
+    for _ in 0..budget.cost + 1 {
+        let mut found = vec![];
+        let mut next = vec![];
+
+        // shifting is free, so the configurations it leads to are
+        // explored along with the ones of the same cost
+        while let Some(configuration) = level.pop() {
+            explored += 1;
+            if explored > budget.configurations {
+                break;
+            }
+
+            let token = tokens.get(configuration.position).cloned();
+            match advance(automaton, &configuration.states, token) {
+                Outcome::Accept => {
+                    found.push(configuration.steps.clone());
+                }
+                Outcome::Shift(states) => {
+                    let mut steps = configuration.steps.clone();
+                    steps.push(Step::Shift);
+                    if configuration.shifted + 1 == SHIFTS {
+                        found.push(steps);
+                    } else {
+                        level.push(Configuration {
+                            states: states,
+                            position: configuration.position + 1,
+                            steps: steps,
+                            shifted: configuration.shifted + 1,
+                        });
+                    }
+                }
+                Outcome::Error => {}
+            }
+
+            for terminal in (0..automaton.terminals()).filter(|&t| automaton.insertable(t)) {
+                if let Outcome::Shift(states) = advance(automaton,
+                                                        &configuration.states,
+                                                        Some(terminal)) {
+                    let mut steps = configuration.steps.clone();
+                    steps.push(Step::Insert(terminal));
+                    next.push(Configuration {
+                        states: states,
+                        position: configuration.position,
+                        steps: steps,
+                        shifted: 0,
+                    });
+                }
+            }
+
+            // deleting a token right after an insertion gives the
+            // same result as deleting it first, so only do the latter
+            let after_insert = match configuration.steps.last() {
+                Some(&Step::Insert(_)) => true,
+                _ => false,
+            };
+            if token.is_some() && !after_insert {
+                let mut steps = configuration.steps;
+                steps.push(Step::Delete);
+                next.push(Configuration {
+                    states: configuration.states,
+                    position: configuration.position + 1,
+                    steps: steps,
+                    shifted: 0,
+                });
+            }
+        }
+
+        if !found.is_empty() || explored > budget.configurations {
+            for steps in &mut found {
+                while steps.last() == Some(&Step::Shift) {
+                    steps.pop();
+                }
+            }
+            found.sort();
+            found.dedup();
+            return found;
+        }
+        level = next;
+    }
+    vec![]
+}
+
+/// Performs the reductions for `terminal` and then shifts it, if the
+/// automaton lets us.
+fn advance<A: Automaton>(automaton: &A, states: &[i32], terminal: Option<usize>) -> Outcome {
+    let mut states = states.to_vec();
+    loop {
+        let top = *states.last().unwrap();
+        let action = automaton.action(top, terminal);
+        if action > 0 {
+            states.push(action - 1);
+            return Outcome::Shift(states);
+        } else if action == 0 {
+            return Outcome::Error;
+        }
+        match automaton.production((-action - 1) as usize) {
+            None => return Outcome::Accept,
+            Some((to_pop, nonterminal)) => {
+                let len = states.len() - to_pop;
+                states.truncate(len);
+                let top = *states.last().unwrap();
+                states.push(automaton.goto(top, nonterminal));
+            }
+        }
+    }
+}
+
+/// A step of a repair, as reported to users.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Repair<T> {
+    /// Insert the terminal with the given name.
+    Insert(String),
+
+    /// Delete the given token.
+    Delete(T),
+
+    /// Keep the given token.
+    Shift(T),
+}
+
+/// A syntax error, along with the cheapest repairs for it. If there
+/// were any, the parser went on as if the first had been made.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Repaired<L, T, E> {
+    pub error: ParseError<L, T, E>,
+    pub repairs: Vec<Vec<Repair<T>>>,
+}
+
+impl<L, T, E> fmt::Display for Repaired<L, T, E>
+    where L: fmt::Display, T: fmt::Display, E: fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(write!(f, "{}", self.error));
+        for repair in &self.repairs {
+            try!(write!(f, "\nPossible repair: {}", describe(repair)));
+        }
+        Ok(())
+    }
+}
+
+/// Describes a repair like "insert `)` before `;`".
+pub fn describe<T: fmt::Display>(repair: &[Repair<T>]) -> String {
+    let mut descriptions = vec![];
+    for (index, step) in repair.iter().enumerate() {
+        match *step {
+            Repair::Insert(ref terminal) => {
+                let next = repair[index..].iter().filter_map(|step| match *step {
+                    Repair::Insert(_) => None,
+                    Repair::Delete(ref token) | Repair::Shift(ref token) => Some(token),
+                }).next();
+                descriptions.push(match next {
+                    Some(token) => format!("insert {} before `{}`", terminal, token),
+                    None => format!("insert {} at the end", terminal),
+                });
+            }
+            Repair::Delete(ref token) => descriptions.push(format!("delete `{}`", token)),
+            Repair::Shift(_) => {}
+        }
+    }
+    descriptions.join(", ")
+}
+
+/// Describes `steps`, which repair the input starting at the first of
+/// `tokens`, in terms of the names of the `terminals` and the tokens.
+/// If the steps end with an insertion, the token it goes before (if
+/// any) is added as a `Shift`.
+pub fn report<L, T: Clone>(steps: &[Step],
+                           terminals: &[&str],
+                           tokens: &[(L, T, L)])
+                           -> Vec<Repair<T>> {
+    let mut tokens = tokens.iter();
+    let mut repair: Vec<Repair<T>> =
+        steps.iter()
+             .map(|&step| match step {
+                 Step::Insert(terminal) => Repair::Insert(terminals[terminal].to_string()),
+                 Step::Delete => Repair::Delete(tokens.next().unwrap().1.clone()),
+                 Step::Shift => Repair::Shift(tokens.next().unwrap().1.clone()),
+             })
+             .collect();
+    if let Some(&Step::Insert(_)) = steps.last() {
+        if let Some(token) = tokens.next() {
+            repair.push(Repair::Shift(token.1.clone()));
+        }
+    }
+    repair
+}
+
+/// Applies `steps` to the tokens from `index` on, along with their
+/// terminals. The tokens to insert are made by `insert`, given a
+/// terminal; they are placed where the next token starts, or where
+/// the previous one ends at EOF. Returns false, leaving the tokens
+/// alone, if `insert` cannot make one of them.
+pub fn apply<L, T, F>(steps: &[Step],
+                      tokens: &mut Vec<(L, T, L)>,
+                      terminals: &mut Vec<usize>,
+                      index: usize,
+                      mut insert: F)
+                      -> bool
+    where L: Clone + Default, F: FnMut(usize) -> Option<T>
+{
+    let mut inserted = vec![];
+    for &step in steps {
+        if let Step::Insert(terminal) = step {
+            match insert(terminal) {
+                Some(token) => inserted.push(token),
+                None => return false,
+            }
+        }
+    }
+
+    let rest: Vec<_> = tokens.drain(index..).zip(terminals.drain(index..)).collect();
+    let mut rest = rest.into_iter().peekable();
+    let mut inserted = inserted.into_iter();
+    for &step in steps {
+        match step {
+            Step::Insert(terminal) => {
+                let location = match rest.peek() {
+                    Some(&(ref token, _)) => token.0.clone(),
+                    None => tokens.last().map(|t| t.2.clone()).unwrap_or_default(),
+                };
+                tokens.push((location.clone(), inserted.next().unwrap(), location));
+                terminals.push(terminal);
+            }
+            Step::Delete => {
+                rest.next();
+            }
+            Step::Shift => {
+                let (token, terminal) = rest.next().unwrap();
+                tokens.push(token);
+                terminals.push(terminal);
+            }
+        }
+    }
+    for (token, terminal) in rest {
+        tokens.push(token);
+        terminals.push(terminal);
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // S = E (accepts);                 (production 0)
+    // E = "(" E ")";                   (production 1)
+    // E = "x";                         (production 2)
+    const LPAREN: usize = 0;
+    const RPAREN: usize = 1;
+    const X: usize = 2;
+
+    struct Parens;
+
+    impl Automaton for Parens {
+        fn terminals(&self) -> usize {
+            3
+        }
+
+        fn action(&self, state: i32, terminal: Option<usize>) -> i32 {
+            match (state, terminal) {
+                // S = . E
+                (0, Some(LPAREN)) | (2, Some(LPAREN)) => 2 + 1,
+                (0, Some(X)) | (2, Some(X)) => 3 + 1,
+                // S = E .
+                (1, None) => -1,
+                // E = "x" .
+                (3, Some(RPAREN)) | (3, None) => -3,
+                // E = "(" E . ")"
+                (4, Some(RPAREN)) => 5 + 1,
+                // E = "(" E ")" .
+                (5, Some(RPAREN)) | (5, None) => -2,
+                _ => 0,
+            }
+        }
+
+        fn production(&self, production: usize) -> Option<(usize, usize)> {
+            match production {
+                0 => None,
+                1 => Some((3, 0)),
+                2 => Some((1, 0)),
+                _ => unreachable!(),
+            }
+        }
+
+        fn goto(&self, state: i32, _nonterminal: usize) -> i32 {
+            match state {
+                0 => 1,
+                2 => 4,
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn insert_at_eof() {
+        // `( x` stops in state 4, at EOF
+        assert_eq!(repairs(&Parens, &[0, 2, 4], &[], Budget::default()),
+                   vec![vec![Step::Insert(RPAREN)]]);
+    }
+
+    #[test]
+    fn delete() {
+        // `( x x )` stops at the second `x`
+        assert_eq!(repairs(&Parens, &[0, 2, 4], &[X, RPAREN], Budget::default()),
+                   vec![vec![Step::Delete]]);
+    }
+
+    #[test]
+    fn all_cheapest_repairs() {
+        // `)` stops right away; no single edit fixes it
+        assert_eq!(repairs(&Parens, &[0], &[RPAREN], Budget::default()),
+                   vec![vec![Step::Insert(LPAREN), Step::Insert(X)],
+                        vec![Step::Delete, Step::Insert(X)]]);
+    }
+
+    #[test]
+    fn budget() {
+        let budget = Budget { cost: 1, ..Budget::default() };
+        assert_eq!(repairs(&Parens, &[0], &[RPAREN], budget), Vec::<Vec<Step>>::new());
+    }
+
+    #[test]
+    fn report_and_apply() {
+        let steps = [Step::Delete, Step::Insert(X)];
+        let mut tokens = vec![(0, "y", 1), (2, ")", 3)];
+        let mut terminals = vec![X, RPAREN];
+        let repair = report(&steps, &["\"(\"", "\")\"", "\"x\""], &tokens);
+        assert_eq!(repair,
+                   vec![Repair::Delete("y"), Repair::Insert("\"x\"".to_string()),
+                        Repair::Shift(")")]);
+        assert_eq!(describe(&repair), "delete `y`, insert \"x\" before `)`");
+
+        assert!(!apply(&steps, &mut tokens, &mut terminals, 0, |_| None));
+        assert_eq!(tokens.len(), 2);
+        assert!(apply(&steps, &mut tokens, &mut terminals, 0, |_| Some("x")));
+        assert_eq!(tokens, vec![(2, "x", 2), (2, ")", 3)]);
+        assert_eq!(terminals, vec![X, RPAREN]);
+    }
+}
@@ -0,0 +1,104 @@
+//! Scanning routines used by the lexers that LALRPOP generates when
+//! fast scanning is enabled (see `Configuration::fast_scanning`).
+//! They find the end of a run of bytes belonging to some character
+//! class without going through the regex engine.
+
+/// The ASCII whitespace characters, as a bitmap suitable for
+/// `class_end`.
+const ASCII_WHITESPACE: [u32; 4] = [0x0000_3e00, 0x0000_0001, 0, 0];
+
+const ONES: u64 = 0x0101_0101_0101_0101;
+const HIGHS: u64 = 0x8080_8080_8080_8080;
+
+/// Returns the number of bytes of whitespace at the start of
+/// `text`, i.e. `text.len() - text.trim_left().len()`, skipping ASCII
+/// whitespace without decoding it.
+pub fn skip_whitespace(text: &str) -> usize {
+    let ascii = class_end(text.as_bytes(), 0, &ASCII_WHITESPACE, false);
+    let rest = &text[ascii..];
+    ascii + (rest.len() - rest.trim_left().len())
+}
+
+/// Returns the index of the first byte at or after `start` that does
+/// not belong to a character class, or `bytes.len()` if there is
+/// none. The class contains the ASCII characters whose bits are set
+/// in the bitmap `ascii` (bit `c % 32` of word `c / 32`) and, if
+/// `non_ascii` is true, every non-ASCII character.
+pub fn class_end(bytes: &[u8], start: usize, ascii: &[u32; 4], non_ascii: bool) -> usize {
+    let mut index = start;
+    while index < bytes.len() {
+        let byte = bytes[index];
+        let in_class = if byte < 0x80 {
+            ascii[(byte >> 5) as usize] & (1 << (byte & 31)) != 0
+        } else {
+            non_ascii
+        };
+        if !in_class {
+            break;
+        }
+        index += 1;
+    }
+    index
+}
+
+/// Returns the index of the first byte at or after `start` that is
+/// one of `stops`, or `bytes.len()` if there is none. This examines
+/// eight bytes at a time, which makes it much faster than `class_end`
+/// on long runs such as the bodies of string literals.
+pub fn find_any(bytes: &[u8], start: usize, stops: &[u8]) -> usize {
+    let mut index = start;
+    while index + 8 <= bytes.len() {
+        let word = bytes[index..index + 8]
+            .iter()
+            .fold(0u64, |word, &byte| (word << 8) | byte as u64);
+        // A byte of `word ^ (ONES * stop)` is zero iff that byte of
+        // `word` is `stop`; the usual trick detects zero bytes.
+        let found = stops.iter().fold(0, |found, &stop| {
+            let x = word ^ (ONES * stop as u64);
+            found | (x.wrapping_sub(ONES) & !x & HIGHS)
+        });
+        if found != 0 {
+            break;
+        }
+        index += 8;
+    }
+    while index < bytes.len() && !stops.contains(&bytes[index]) {
+        index += 1;
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIGITS: [u32; 4] = [0, 0x03ff_0000, 0, 0];
+
+    #[test]
+    fn skip_whitespace_ascii_and_unicode() {
+        assert_eq!(skip_whitespace(" \t\n\r x"), 5);
+        assert_eq!(skip_whitespace(" \u{2003} x"), 5);
+        assert_eq!(skip_whitespace("x "), 0);
+        assert_eq!(skip_whitespace("   "), 3);
+    }
+
+    #[test]
+    fn class_end_stops_at_first_non_member() {
+        assert_eq!(class_end(b"x123+4", 1, &DIGITS, false), 4);
+        assert_eq!(class_end(b"123", 0, &DIGITS, false), 3);
+        assert_eq!(class_end("1é".as_bytes(), 0, &DIGITS, false), 1);
+        assert_eq!(class_end("1é2+".as_bytes(), 0, &DIGITS, true), 4);
+    }
+
+    #[test]
+    fn find_any_in_long_and_short_input() {
+        let text = "\"a string body that is longer than a word, é\\\" and more\"";
+        assert_eq!(find_any(text.as_bytes(), 1, b"\"\\"), text.find('\\').unwrap());
+        assert_eq!(find_any(b"abc", 0, b"\""), 3);
+        for len in 0..20 {
+            let mut bytes = vec![b'a'; len];
+            bytes.push(b'"');
+            assert_eq!(find_any(&bytes, 0, b"\""), len);
+        }
+    }
+}
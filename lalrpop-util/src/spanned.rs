@@ -0,0 +1,36 @@
+/// A value parsed from a span of the input, which knows where it
+/// starts and ends. The types that LALRPOP declares for `#[ast]`
+/// nonterminals implement it, so that diagnostics about the nodes of a
+/// tree can point at their text.
+pub trait Spanned<L> {
+    /// The location where the value starts.
+    fn start(&self) -> L;
+
+    /// The location just past the end of the value.
+    fn end(&self) -> L;
+
+    fn span(&self) -> (L, L) {
+        (self.start(), self.end())
+    }
+}
+
+impl<'a, L, T: Spanned<L> + ?Sized> Spanned<L> for &'a T {
+    fn start(&self) -> L {
+        (**self).start()
+    }
+
+    fn end(&self) -> L {
+        (**self).end()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<L, T: Spanned<L> + ?Sized> Spanned<L> for Box<T> {
+    fn start(&self) -> L {
+        (**self).start()
+    }
+
+    fn end(&self) -> L {
+        (**self).end()
+    }
+}
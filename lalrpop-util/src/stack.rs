@@ -0,0 +1,378 @@
+//! Fixed-capacity stacks, which the parsers that LALRPOP generates
+//! with `Configuration::fixed_stack` keep in a buffer supplied by
+//! their caller rather than on the heap. For each public nonterminal
+//! `Expr`, such a parser has
+//!
+//! ```ignore
+//! pub fn parse_Expr_with_stack<'input>(input: &'input str, stack: &mut [u8])
+//!     -> Result<Expr, ParseError<usize, Token<'input>, &'static str>>
+//! ```
+//!
+//! instead of `parse_Expr`. The buffer need not be initialized or
+//! aligned:
+//!
+//! ```ignore
+//! let mut stack = [0u8; 4096];
+//! let expr = calc::parse_Expr_with_stack("22 * 44 + 66", &mut stack);
+//! ```
+//!
+//! Input that is nested too deeply for the buffer results in a
+//! `ParseError::StackCapacity` error, whose `capacity` is the number
+//! of symbols that fit in it.
+//!
+//! Such a parser does not allocate, and only uses `core`, so that it
+//! works in a `no_std` crate that has no allocator, with this crate
+//! built without its `std` feature. Its syntax errors then have the
+//! state of the parser rather than the names of the tokens it
+//! expected (see `Expected`), which the parser module's
+//! `expected_tokens` function lists:
+//!
+//! ```ignore
+//! if let Err(ParseError::UnrecognizedToken { expected: Some(expected), .. }) = result {
+//!     for name in calc::expected_tokens(expected.state) {
+//!         ...
+//!     }
+//! }
+//! ```
+//!
+//! The grammar has to supply its own tokenizer, since the built-in
+//! one needs `std`, and its actions must do without `Vec`, `String`
+//! and the like, and hence without the `*`, `+` and `?` macros.
+//!
+//! With the `std` feature, this module also has `Recycled`, with
+//! which other parsers reuse the buffers of their stacks.
+
+use core::marker::PhantomData;
+use core::mem;
+use core::ops::{Deref, DerefMut};
+use core::ptr;
+use core::slice;
+#[cfg(feature = "std")]
+use std::alloc::{dealloc, Layout};
+
+/// A stack of at most `capacity()` values of type `T`, stored in a
+/// borrowed buffer. It dereferences to a slice of its values, from
+/// the bottom of the stack to the top.
+pub struct FixedStack<'buf, T: 'buf> {
+    ptr: *mut T,
+    len: usize,
+    capacity: usize,
+    marker: PhantomData<&'buf mut [T]>,
+}
+
+/// Splits `buffer` into a stack of values of type `S` and a stack of
+/// values of type `T`, where the first can hold one more value than
+/// the second, as the state stack of a parser holds one more state
+/// than its symbol stack holds symbols. Both have capacity zero if
+/// `buffer` is too small to hold a single `S`. `S` and `T` must not
+/// both be zero-sized, since a buffer could hold any number of them.
+pub fn split_buffer<'buf, S, T>(buffer: &'buf mut [u8])
+                                -> (FixedStack<'buf, S>, FixedStack<'buf, T>) {
+    let sizes = mem::size_of::<S>() + mem::size_of::<T>();
+    assert!(sizes > 0, "cannot split a buffer into stacks of zero-sized values");
+
+    // the stacks are placed at byte offsets from the start of `buffer`
+    let base = buffer.as_mut_ptr();
+    let first = align_up(base, 0, mem::align_of::<S>());
+
+    // aligning the second stack wastes less than its alignment
+    let needed = first + mem::size_of::<S>() + mem::align_of::<T>() - 1;
+    if needed > buffer.len() {
+        let second = align_up(base, 0, mem::align_of::<T>());
+        return (FixedStack::empty(base, first), FixedStack::empty(base, second));
+    }
+    let capacity = (buffer.len() - needed) / sizes;
+    let second = align_up(base,
+                          first + (capacity + 1) * mem::size_of::<S>(),
+                          mem::align_of::<T>());
+    unsafe { (FixedStack::new(base, first, capacity + 1), FixedStack::new(base, second, capacity)) }
+}
+
+/// Rounds `offset` up to the next offset from `base` that is aligned
+/// to `align`.
+fn align_up(base: *mut u8, offset: usize, align: usize) -> usize {
+    let misalignment = (base as usize + offset) % align;
+    if misalignment == 0 {
+        offset
+    } else {
+        offset + align - misalignment
+    }
+}
+
+impl<'buf, T> FixedStack<'buf, T> {
+    /// A stack of `capacity` values starting `offset` bytes into the
+    /// buffer at `base`, which must have room for them.
+    unsafe fn new(base: *mut u8, offset: usize, capacity: usize) -> FixedStack<'buf, T> {
+        FixedStack {
+            ptr: base.offset(offset as isize) as *mut T,
+            len: 0,
+            capacity: capacity,
+            marker: PhantomData,
+        }
+    }
+
+    /// A stack that cannot hold any values. It is still aligned, for
+    /// the sake of `Deref`, but it may lie past the end of the buffer.
+    fn empty(base: *mut u8, offset: usize) -> FixedStack<'buf, T> {
+        FixedStack {
+            ptr: base.wrapping_offset(offset as isize) as *mut T,
+            len: 0,
+            capacity: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// The maximum number of values on the stack.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Pushes `value` on the stack, which must not be full.
+    pub fn push(&mut self, value: T) {
+        assert!(self.len < self.capacity, "fixed-size stack overflow");
+        unsafe {
+            ptr::write(self.ptr.offset(self.len as isize), value);
+        }
+        self.len += 1;
+    }
+
+    /// Removes the value on top of the stack and returns it, or
+    /// `None` if it is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        unsafe { Some(ptr::read(self.ptr.offset(self.len as isize))) }
+    }
+
+    /// Drops the values above the first `len`, if any.
+    pub fn truncate(&mut self, len: usize) {
+        while self.len > len {
+            self.pop();
+        }
+    }
+
+    /// Drops all of the values.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+}
+
+impl<'buf, T> Deref for FixedStack<'buf, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+impl<'buf, T> DerefMut for FixedStack<'buf, T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+}
+
+impl<'buf, T> Drop for FixedStack<'buf, T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+/// The buffer of an emptied vector, kept for a later vector of values
+/// of the same size and alignment. The `FooParser` structs of generated
+/// parsers keep their symbol stack this way from one parse to the
+/// next, since the symbols of each parse borrow from its input: the
+/// buffer holds no values, so it need not know their lifetimes.
+#[cfg(feature = "std")]
+pub struct Recycled {
+    ptr: *mut u8,
+    capacity: usize,
+    size: usize,
+    align: usize,
+}
+
+// there are no values in the buffer, only memory
+#[cfg(feature = "std")]
+unsafe impl Send for Recycled {}
+#[cfg(feature = "std")]
+unsafe impl Sync for Recycled {}
+
+#[cfg(feature = "std")]
+impl Recycled {
+    /// No buffer at all.
+    pub fn new() -> Recycled {
+        Recycled {
+            ptr: ptr::null_mut(),
+            capacity: 0,
+            size: 0,
+            align: 0,
+        }
+    }
+
+    /// Returns an empty vector with the buffer, if it was kept for
+    /// values like `T`, and with no buffer otherwise. Either way, the
+    /// buffer is no longer kept.
+    pub fn take<T>(&mut self) -> Vec<T> {
+        let recycled = mem::replace(self, Recycled::new());
+        if recycled.ptr.is_null() || recycled.size != mem::size_of::<T>() ||
+           recycled.align != mem::align_of::<T>() {
+            return vec![];
+        }
+        let vec = unsafe { Vec::from_raw_parts(recycled.ptr as *mut T, 0, recycled.capacity) };
+        mem::forget(recycled);
+        vec
+    }
+
+    /// Empties `vec` and keeps its buffer, instead of the one kept so
+    /// far, if any.
+    pub fn put<T>(&mut self, mut vec: Vec<T>) {
+        vec.clear();
+        if mem::size_of::<T>() == 0 || vec.capacity() == 0 {
+            *self = Recycled::new();
+            return;
+        }
+        *self = Recycled {
+            ptr: vec.as_mut_ptr() as *mut u8,
+            capacity: vec.capacity(),
+            size: mem::size_of::<T>(),
+            align: mem::align_of::<T>(),
+        };
+        mem::forget(vec);
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for Recycled {
+    fn default() -> Recycled {
+        Recycled::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for Recycled {
+    fn drop(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe {
+                dealloc(self.ptr,
+                        Layout::from_size_align_unchecked(self.size * self.capacity, self.align));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::mem;
+    use std::rc::Rc;
+
+    #[test]
+    fn capacities() {
+        let mut buffer = [0u8; 100];
+        {
+            let (states, symbols) = split_buffer::<i32, (usize, u8, usize)>(&mut buffer[1..]);
+            assert_eq!(symbols.capacity() + 1, states.capacity());
+            let symbol_size = mem::size_of::<(usize, u8, usize)>();
+            assert!(states.capacity() * 4 + symbols.capacity() * symbol_size <= 99);
+            assert!(symbols.capacity() >= 2);
+            assert_eq!(states.as_ptr() as usize % mem::align_of::<i32>(), 0);
+            assert_eq!(symbols.as_ptr() as usize % mem::align_of::<usize>(), 0);
+            assert!(states.as_ptr() as usize + states.capacity() * 4 <=
+                    symbols.as_ptr() as usize);
+        }
+
+        let (states, symbols) = split_buffer::<i32, u64>(&mut buffer[..3]);
+        assert_eq!((states.capacity(), symbols.capacity()), (0, 0));
+        let (states, symbols) = split_buffer::<i32, u64>(&mut [][..]);
+        assert_eq!((states.capacity(), symbols.capacity()), (0, 0));
+        let mut buffer = [0u8; 100];
+        let (states, symbols) = split_buffer::<i32, ()>(&mut buffer[1..]);
+        assert_eq!((states.capacity(), symbols.capacity()), (24, 23));
+    }
+
+    #[test]
+    #[should_panic(expected = "zero-sized values")]
+    fn zero_sized() {
+        let mut buffer = [0u8; 16];
+        split_buffer::<(), ()>(&mut buffer);
+    }
+
+    #[test]
+    fn push_and_pop() {
+        let mut buffer = [0u8; 256];
+        let (mut states, mut symbols) = split_buffer::<i32, (usize, char, usize)>(&mut buffer);
+        states.push(0);
+        for (i, c) in "abc".chars().enumerate() {
+            states.push(i as i32 + 1);
+            symbols.push((i, c, i + 1));
+        }
+        assert_eq!(&states[..], &[0, 1, 2, 3]);
+        assert_eq!(symbols.last(), Some(&(2, 'c', 3)));
+        assert_eq!(symbols.pop(), Some((2, 'c', 3)));
+        states.truncate(1);
+        assert_eq!(states.len(), 1);
+        symbols.clear();
+        assert_eq!(symbols.pop(), None);
+    }
+
+    #[test]
+    fn drops_values() {
+        let value = Rc::new(());
+        {
+            let mut buffer = [0u8; 64];
+            let (_, mut symbols) = split_buffer::<i32, Rc<()>>(&mut buffer);
+            symbols.push(value.clone());
+            symbols.push(value.clone());
+            assert_eq!(Rc::strong_count(&value), 3);
+        }
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn recycle_buffer() {
+        let value = Rc::new(());
+        let mut recycled = Recycled::new();
+        {
+            let text = String::from("abc");
+            let mut symbols: Vec<(usize, &str, Rc<()>)> = Vec::with_capacity(8);
+            symbols.push((0, &text[..], value.clone()));
+            recycled.put(symbols);
+        }
+        assert_eq!(Rc::strong_count(&value), 1);
+
+        let text = String::from("def");
+        let mut symbols: Vec<(usize, &str, Rc<()>)> = recycled.take();
+        assert!(symbols.is_empty());
+        assert_eq!(symbols.capacity(), 8);
+        symbols.push((0, &text[..], value.clone()));
+
+        // the buffer was taken
+        assert_eq!(recycled.take::<(usize, &str, Rc<()>)>().capacity(), 0);
+    }
+
+    #[test]
+    fn recycle_other_layout() {
+        let mut recycled = Recycled::new();
+        recycled.put(Vec::<u64>::with_capacity(4));
+        assert_eq!(recycled.take::<u8>().capacity(), 0);
+
+        recycled.put(Vec::<u64>::with_capacity(4));
+        recycled.put(Vec::<u8>::with_capacity(16));
+        assert_eq!(recycled.take::<u8>().capacity(), 16);
+
+        // zero-sized values have no buffer to keep
+        recycled.put(vec![(), ()]);
+        assert_eq!(recycled.take::<()>().len(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "fixed-size stack overflow")]
+    fn overflow() {
+        let mut buffer = [0u8; 16];
+        let (mut states, _) = split_buffer::<i32, u32>(&mut buffer);
+        for state in 0..states.capacity() + 1 {
+            states.push(state as i32);
+        }
+    }
+}
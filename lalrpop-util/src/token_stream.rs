@@ -0,0 +1,199 @@
+//! Tokens for the parsers of `#![token_stream]` grammars, which read
+//! the token trees that a procedural macro receives. The trees are
+//! flattened into a sequence of tokens: the delimiters of a group
+//! become tokens of their own around its contents, and runs of joint
+//! punctuation are joined into the longest operator of the grammar
+//! they spell (so that `>>` stays two `>` tokens if the grammar has
+//! no `>>` operator). The parser module of such a grammar has a
+//! `tokens` function that does this with the keywords and operators
+//! of the grammar:
+//!
+//! ```ignore
+//! let input: TokenStream = ...;
+//! let ast = parser::parse_Item(parser::tokens(input));
+//! ```
+
+pub use proc_macro2::{Delimiter, Ident, Literal, Span, TokenStream};
+
+use proc_macro2::{Spacing, TokenTree};
+use std::collections::HashMap;
+use std::vec;
+
+/// A token of a `#![token_stream]` grammar.
+#[derive(Clone, Debug)]
+pub enum Token {
+    /// One of the keywords, operators and delimiters that the grammar
+    /// uses as terminals, given by its index in the list passed to
+    /// `Tokens::new`.
+    Fixed(usize),
+
+    /// An identifier that is not a keyword of the grammar (the
+    /// terminal `Ident`).
+    Ident(Ident),
+
+    /// A literal (the terminal `Literal`).
+    Literal(Literal),
+
+    /// A punctuation character or delimiter that the grammar does not
+    /// use; the parser rejects these.
+    Punct(char),
+}
+
+/// The location of a token: the span of the token tree it comes
+/// from, or of the first or last tree of an operator. Delimiters have
+/// the span of their whole group. The default, used for empty input,
+/// is `Span::call_site()`.
+#[derive(Copy, Clone, Debug)]
+pub struct SpanLocation(pub Span);
+
+impl Default for SpanLocation {
+    fn default() -> SpanLocation {
+        SpanLocation(Span::call_site())
+    }
+}
+
+/// The tokens of a `TokenStream`, as the triples the parser expects.
+#[derive(Clone)]
+pub struct Tokens {
+    tokens: vec::IntoIter<(SpanLocation, Token, SpanLocation)>,
+}
+
+impl Tokens {
+    /// Flattens `stream` into tokens, recognizing `fixed`, the
+    /// keywords, operators and delimiters of the grammar.
+    pub fn new(stream: TokenStream, fixed: &[&str]) -> Tokens {
+        let fixed: HashMap<&str, usize> = fixed.iter().cloned().zip(0..).collect();
+        let mut tokens = vec![];
+        push_stream(&mut tokens, stream, &fixed);
+        Tokens { tokens: tokens.into_iter() }
+    }
+}
+
+impl Iterator for Tokens {
+    type Item = (SpanLocation, Token, SpanLocation);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.tokens.next()
+    }
+}
+
+fn push_stream(tokens: &mut Vec<(SpanLocation, Token, SpanLocation)>,
+               stream: TokenStream,
+               fixed: &HashMap<&str, usize>) {
+    let trees: Vec<TokenTree> = stream.into_iter().collect();
+    let mut index = 0;
+    while index < trees.len() {
+        let span = trees[index].span();
+        match trees[index] {
+            TokenTree::Group(ref group) => {
+                let delimiters = match group.delimiter() {
+                    Delimiter::Parenthesis => Some(('(', ')')),
+                    Delimiter::Brace => Some(('{', '}')),
+                    Delimiter::Bracket => Some(('[', ']')),
+                    Delimiter::None => None,
+                };
+                if let Some((open, _)) = delimiters {
+                    push_delimiter(tokens, open, span, fixed);
+                }
+                push_stream(tokens, group.stream(), fixed);
+                if let Some((_, close)) = delimiters {
+                    push_delimiter(tokens, close, span, fixed);
+                }
+            }
+            TokenTree::Ident(ref ident) => {
+                let token = match fixed.get(&ident.to_string()[..]) {
+                    Some(&index) => Token::Fixed(index),
+                    None => Token::Ident(ident.clone()),
+                };
+                tokens.push((SpanLocation(span), token, SpanLocation(span)));
+            }
+            TokenTree::Literal(ref literal) => {
+                tokens.push((SpanLocation(span), Token::Literal(literal.clone()), SpanLocation(span)));
+            }
+            TokenTree::Punct(ref punct) => {
+                // find the longest operator spelled by the run of
+                // joint punctuation starting here
+                let mut text = String::new();
+                let mut longest = None;
+                let mut end = index;
+                while let Some(&TokenTree::Punct(ref punct)) = trees.get(end) {
+                    text.push(punct.as_char());
+                    end += 1;
+                    if let Some(&fixed_index) = fixed.get(&text[..]) {
+                        longest = Some((fixed_index, end));
+                    }
+                    if punct.spacing() == Spacing::Alone {
+                        break;
+                    }
+                }
+                match longest {
+                    Some((fixed_index, end)) => {
+                        let last_span = trees[end - 1].span();
+                        tokens.push((SpanLocation(span),
+                                     Token::Fixed(fixed_index),
+                                     SpanLocation(last_span)));
+                        index = end;
+                        continue;
+                    }
+                    None => {
+                        tokens.push((SpanLocation(span),
+                                     Token::Punct(punct.as_char()),
+                                     SpanLocation(span)));
+                    }
+                }
+            }
+        }
+        index += 1;
+    }
+}
+
+fn push_delimiter(tokens: &mut Vec<(SpanLocation, Token, SpanLocation)>,
+                  delimiter: char,
+                  span: Span,
+                  fixed: &HashMap<&str, usize>) {
+    let token = match fixed.get(&delimiter.to_string()[..]) {
+        Some(&index) => Token::Fixed(index),
+        None => Token::Punct(delimiter),
+    };
+    tokens.push((SpanLocation(span), token, SpanLocation(span)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(input: &str, fixed: &[&str]) -> Vec<String> {
+        Tokens::new(input.parse().unwrap(), fixed)
+            .map(|(_, token, _)| match token {
+                Token::Fixed(index) => format!("`{}`", fixed[index]),
+                Token::Ident(ident) => format!("Ident({})", ident),
+                Token::Literal(literal) => format!("Literal({})", literal),
+                Token::Punct(c) => format!("Punct({})", c),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn keywords_and_delimiters() {
+        assert_eq!(tokens("fn f(x) { 1 }", &["fn", "(", ")", "{", "}"]),
+                   vec!["`fn`", "Ident(f)", "`(`", "Ident(x)", "`)`",
+                        "`{`", "Literal(1)", "`}`"]);
+    }
+
+    #[test]
+    fn unused_delimiters() {
+        assert_eq!(tokens("[x]", &[]), vec!["Punct([)", "Ident(x)", "Punct(])"]);
+    }
+
+    #[test]
+    fn operators() {
+        assert_eq!(tokens("a => b >> c", &["=>", ">>", ">"]),
+                   vec!["Ident(a)", "`=>`", "Ident(b)", "`>>`", "Ident(c)"]);
+        assert_eq!(tokens("Vec<Vec<u8>>", &["<", ">"]),
+                   vec!["Ident(Vec)", "`<`", "Ident(Vec)", "`<`", "Ident(u8)", "`>`", "`>`"]);
+        // `=` and `>` are not joint here
+        assert_eq!(tokens("a = > b", &["=>", "=", ">"]),
+                   vec!["Ident(a)", "`=`", "`>`", "Ident(b)"]);
+        assert_eq!(tokens("a ? b", &["=>"]), vec!["Ident(a)", "Punct(?)", "Ident(b)"]);
+    }
+}
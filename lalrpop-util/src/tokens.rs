@@ -0,0 +1,111 @@
+//! Token arrays, which separate lexing from parsing. The parsers that
+//! LALRPOP generates with `Configuration::token_arrays` for grammars
+//! with the built-in tokenizer have
+//!
+//! ```ignore
+//! pub fn tokenize<'input>(input: &'input str)
+//!     -> Result<TokenArray, ParseError<usize, Token<'input>, &'static str>>
+//! ```
+//!
+//! which lexes all of `input` in one pass, and, for each public
+//! nonterminal `Expr`,
+//!
+//! ```ignore
+//! pub fn parse_Expr_tokens<'input>(input: &'input str, tokens: &TokenArray)
+//!     -> Result<Expr, ParseError<usize, Token<'input>, &'static str>>
+//! ```
+//!
+//! which parses the tokens of `input` from the array:
+//!
+//! ```ignore
+//! let input = "22 * 44 + 66";
+//! let tokens = calc::tokenize(input).unwrap();
+//! let expr = calc::parse_Expr_tokens(input, &tokens);
+//! ```
+//!
+//! The array can be parsed as often as needed, and used for other
+//! analyses, like highlighting, in between. The kind of a token is the
+//! index in `Token` of its terminal.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The kinds and spans of the tokens of an input, kept in separate
+/// vectors so that scanning the kinds touches as little memory as
+/// possible.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TokenArray {
+    kinds: Vec<u32>,
+    spans: Vec<(usize, usize)>,
+}
+
+impl TokenArray {
+    pub fn new() -> TokenArray {
+        TokenArray::default()
+    }
+
+    /// Adds a token of kind `kind` that spans `start..end`.
+    pub fn push(&mut self, kind: usize, start: usize, end: usize) {
+        self.kinds.push(kind as u32);
+        self.spans.push((start, end));
+    }
+
+    /// The number of tokens.
+    pub fn len(&self) -> usize {
+        self.kinds.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.kinds.is_empty()
+    }
+
+    /// The kind of the `index`th token.
+    pub fn kind(&self, index: usize) -> usize {
+        self.kinds[index] as usize
+    }
+
+    /// The start and end of the `index`th token.
+    pub fn span(&self, index: usize) -> (usize, usize) {
+        self.spans[index]
+    }
+
+    /// The text of the `index`th token, in the `input` that was
+    /// tokenized.
+    pub fn text<'input>(&self, input: &'input str, index: usize) -> &'input str {
+        let (start, end) = self.spans[index];
+        &input[start..end]
+    }
+
+    /// The kinds of all of the tokens, in order.
+    pub fn kinds(&self) -> &[u32] {
+        &self.kinds
+    }
+
+    /// The spans of all of the tokens, in order.
+    pub fn spans(&self) -> &[(usize, usize)] {
+        &self.spans
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_and_read() {
+        let input = "let x = 1";
+        let mut tokens = TokenArray::new();
+        assert!(tokens.is_empty());
+        tokens.push(3, 0, 3);
+        tokens.push(0, 4, 5);
+        tokens.push(1, 6, 7);
+        tokens.push(2, 8, 9);
+        assert_eq!(tokens.len(), 4);
+        assert_eq!(tokens.kind(1), 0);
+        assert_eq!(tokens.span(2), (6, 7));
+        assert_eq!(tokens.text(input, 0), "let");
+        assert_eq!(tokens.kinds(), &[3, 0, 1, 2]);
+        assert_eq!(tokens.spans()[3], (8, 9));
+    }
+}
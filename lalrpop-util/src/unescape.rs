@@ -0,0 +1,200 @@
+//! Escape decoding for the terminals that a `match` section marks
+//! `#[unescape]`, such as string literals:
+//!
+//! ```ignore
+//! match {
+//!     #[unescape] r#""(\\.|[^"\\])*""# => STRING,
+//! } else {
+//!     _
+//! }
+//! ```
+//!
+//! The value of such a terminal is a `Cow<'input, str>` rather than
+//! a `&'input str`: the text of the token without its quotes, with
+//! its escape sequences decoded. Only tokens that contain escapes
+//! are copied. The generated lexer rejects tokens with an invalid
+//! escape sequence (with `ParseError::InvalidToken` at the offending
+//! backslash), so the parser never sees them.
+//!
+//! The escapes are those of Rust string literals: `\n`, `\r`, `\t`,
+//! `\\`, `\0`, `\'`, `\"`, `\x` followed by two hex digits (at most
+//! `7F`), and `\u{...}` with one to six hex digits.
+//!
+//! The functions that take a `&str` and return a `Cow<str>` can also
+//! be used as the hooks of `match` entries, which post-process the
+//! text of their tokens:
+//!
+//! ```ignore
+//! use lalrpop_util::unescape::{decode_escapes, unquote};
+//!
+//! match {
+//!     r#""[^"]*""# => { unquote },
+//!     r"[a-z\\]+" => IDENT { decode_escapes },
+//! }
+//! ```
+
+use std::borrow::Cow;
+
+/// Decodes the escape sequences of `text`, after removing the quotes
+/// around it, if it is enclosed in a matching pair of `"` or `'`.
+/// Invalid escape sequences are left as they are.
+pub fn unescape(text: &str) -> Cow<str> {
+    decode_escapes(strip_quotes(text))
+}
+
+/// Decodes the escape sequences of `text`, which keeps its quotes, if
+/// any. Invalid escape sequences are left as they are.
+pub fn decode_escapes(text: &str) -> Cow<str> {
+    if !text.contains('\\') {
+        return Cow::Borrowed(text);
+    }
+    let mut out = String::with_capacity(text.len());
+    let _ = decode(text, Some(&mut out));
+    Cow::Owned(out)
+}
+
+/// Removes the quotes around `text`, if it is enclosed in a matching
+/// pair of `"` or `'`, without decoding its escape sequences.
+pub fn unquote(text: &str) -> Cow<str> {
+    Cow::Borrowed(strip_quotes(text))
+}
+
+/// Checks the escape sequences of `text` (as for `unescape`), and
+/// returns the byte offset in `text` of the first invalid one, if
+/// any.
+pub fn check(text: &str) -> Result<(), usize> {
+    let inner = strip_quotes(text);
+    let quote_len = if inner.len() < text.len() { 1 } else { 0 };
+    decode(inner, None).map_err(|offset| offset + quote_len)
+}
+
+fn strip_quotes(text: &str) -> &str {
+    let bytes = text.as_bytes();
+    if bytes.len() >= 2 && (bytes[0] == b'"' || bytes[0] == b'\'') &&
+       bytes[bytes.len() - 1] == bytes[0] {
+        &text[1..text.len() - 1]
+    } else {
+        text
+    }
+}
+
+/// Appends `text`, decoded, to `out` (if given), copying invalid
+/// escape sequences verbatim; returns the offset of the first of
+/// those.
+fn decode(text: &str, mut out: Option<&mut String>) -> Result<(), usize> {
+    let mut result = Ok(());
+    let mut rest = text;
+    while let Some(backslash) = rest.find('\\') {
+        let escape = &rest[backslash..];
+        let (decoded, len) = match decode_escape(escape) {
+            Some((c, len)) => (c, len),
+            None => {
+                if result.is_ok() {
+                    result = Err(text.len() - escape.len());
+                }
+                ('\\', 1)
+            }
+        };
+        if let Some(ref mut out) = out {
+            out.push_str(&rest[..backslash]);
+            out.push(decoded);
+        }
+        rest = &escape[len..];
+    }
+    if let Some(out) = out {
+        out.push_str(rest);
+    }
+    result
+}
+
+/// Decodes the escape sequence at the start of `escape`, which starts
+/// with a backslash, returning the character and the length of the
+/// sequence.
+fn decode_escape(escape: &str) -> Option<(char, usize)> {
+    let c = match escape[1..].chars().next() {
+        Some(c) => c,
+        None => return None,
+    };
+    match c {
+        'n' => Some(('\n', 2)),
+        'r' => Some(('\r', 2)),
+        't' => Some(('\t', 2)),
+        '\\' => Some(('\\', 2)),
+        '0' => Some(('\0', 2)),
+        '\'' => Some(('\'', 2)),
+        '"' => Some(('"', 2)),
+        'x' => {
+            if escape.len() < 4 || !escape.is_char_boundary(4) {
+                return None;
+            }
+            let digits = &escape[2..4];
+            match u8::from_str_radix(digits, 16) {
+                Ok(byte) if byte < 0x80 && !digits.starts_with('+') => Some((byte as char, 4)),
+                _ => None,
+            }
+        }
+        'u' => {
+            if !escape[2..].starts_with('{') {
+                return None;
+            }
+            let close = match escape.find('}') {
+                Some(close) => close,
+                None => return None,
+            };
+            let digits = &escape[3..close];
+            if digits.is_empty() || digits.len() > 6 || digits.starts_with('+') {
+                return None;
+            }
+            u32::from_str_radix(digits, 16)
+                .ok()
+                .and_then(::std::char::from_u32)
+                .map(|c| (c, close + 1))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+
+    #[test]
+    fn borrowed_without_escapes() {
+        match unescape("\"hello\"") {
+            Cow::Borrowed(text) => assert_eq!(text, "hello"),
+            Cow::Owned(_) => panic!("copied a string without escapes"),
+        }
+        assert_eq!(unescape("'x'"), "x");
+        assert_eq!(unescape("\"x'"), "\"x'");
+        assert_eq!(unescape("\""), "\"");
+    }
+
+    #[test]
+    fn escapes() {
+        assert_eq!(unescape(r#""a\tb\n\\\"\x41\u{e9}\u{1F600}\0""#),
+                   "a\tb\n\\\"A\u{e9}\u{1F600}\0");
+        assert_eq!(unescape(r"'\''"), "'");
+        assert_eq!(check(r#""a\tb\u{10FFFF}""#), Ok(()));
+    }
+
+    #[test]
+    fn hooks() {
+        assert_eq!(decode_escapes(r#""a\tb""#), "\"a\tb\"");
+        assert_eq!(unquote(r#""a\tb""#), r"a\tb");
+        assert_eq!(unquote("abc"), "abc");
+    }
+
+    #[test]
+    fn invalid_escapes() {
+        assert_eq!(check(r#""ab\q""#), Err(3));
+        assert_eq!(check(r"\x80"), Err(0));
+        assert_eq!(check(r"a\x4"), Err(1));
+        assert_eq!(check(r"\u{110000}"), Err(0));
+        assert_eq!(check(r"\u{}"), Err(0));
+        assert_eq!(check(r"\u41"), Err(0));
+        assert_eq!(check(r"x\"), Err(1));
+        // left as they are by `unescape`
+        assert_eq!(unescape(r#""a\qb\n""#), "a\\qb\n");
+    }
+}
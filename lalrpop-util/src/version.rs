@@ -0,0 +1,77 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use core::fmt;
+#[cfg(feature = "std")]
+use std::str::FromStr;
+
+/// A version of the language being parsed, like `2.1`. Parsers
+/// generated from grammars with `#[since = "..."]` alternatives take
+/// one, and reject the alternatives introduced after it with
+/// `ParseError::VersionTooOld`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl Version {
+    pub fn new(major: u32, minor: u32, patch: u32) -> Version {
+        Version { major: major, minor: minor, patch: patch }
+    }
+}
+
+#[cfg(feature = "std")]
+impl FromStr for Version {
+    type Err = String;
+
+    /// Parses `1`, `1.2` or `1.2.3`; missing components are zero.
+    fn from_str(s: &str) -> Result<Version, String> {
+        let mut components = [0; 3];
+        let mut count = 0;
+        for part in s.split('.') {
+            if count == components.len() {
+                return Err(format!("version `{}` has more than three components", s));
+            }
+            components[count] = match part.parse() {
+                Ok(n) => n,
+                Err(_) => return Err(format!("invalid version `{}`", s)),
+            };
+            count += 1;
+        }
+        Ok(Version::new(components[0], components[1], components[2]))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(write!(f, "{}.{}", self.major, self.minor));
+        if self.patch != 0 {
+            try!(write!(f, ".{}", self.patch));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Version;
+
+    #[test]
+    fn parse() {
+        assert_eq!("2".parse(), Ok(Version::new(2, 0, 0)));
+        assert_eq!("2.1".parse(), Ok(Version::new(2, 1, 0)));
+        assert_eq!("2.1.3".parse(), Ok(Version::new(2, 1, 3)));
+        assert!("2.x".parse::<Version>().is_err());
+        assert!("1.2.3.4".parse::<Version>().is_err());
+    }
+
+    #[test]
+    fn order_and_display() {
+        assert!(Version::new(1, 10, 0) > Version::new(1, 9, 5));
+        assert_eq!(Version::new(2, 0, 0).to_string(), "2.0");
+        assert_eq!(Version::new(2, 1, 3).to_string(), "2.1.3");
+    }
+}
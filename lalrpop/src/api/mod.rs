@@ -1,10 +1,13 @@
 use build;
+use diagnostics::Diagnostics;
+use lr1::GrammarAnalysis;
 use log::Level;
-use session::{ColorConfig, Session};
+use session::{ColorConfig, ConflictBaseline, ExampleRendering, Session};
 use std::default::Default;
 use std::env;
 use std::env::current_dir;
 use std::error::Error;
+use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::rc::Rc;
 
@@ -41,6 +44,21 @@ impl Configuration {
         self
     }
 
+    /// Draw the examples in conflict reports as derivation trees,
+    /// with each symbol indented below the nonterminal it is reduced
+    /// to, rather than as brackets under the symbols; this is easier
+    /// to read when there are many nested reductions. If
+    /// `box_drawing` is true, the tree is drawn with Unicode
+    /// box-drawing characters.
+    pub fn draw_examples_as_trees(&mut self, box_drawing: bool) -> &mut Configuration {
+        self.session.example_rendering = if box_drawing {
+            ExampleRendering::BoxTree
+        } else {
+            ExampleRendering::Tree
+        };
+        self
+    }
+
     /// Specify a custom directory to search for input files.  This
     /// directory is recursively searched for `.lalrpop` files to be
     /// considered as input files.  This configuration setting also
@@ -61,6 +79,28 @@ impl Configuration {
         self
     }
 
+    /// When processing a directory, also write a Rust file at `path`
+    /// that declares a module for the parsers generated from each of
+    /// its grammars, named after the path of the generated file
+    /// relative to the directory of `path`: with the default
+    /// directories, the parsers of `src/lang/expr.lalrpop` end up in
+    /// `parsers::lang::expr` if `path` is `src/parsers.rs`. This
+    /// saves declaring each of them by hand: the crate just needs
+    /// `mod parsers;`, or, when the parsers are written to
+    /// `$OUT_DIR`,
+    ///
+    /// ```ignore
+    /// mod parsers {
+    ///     include!(concat!(env!("OUT_DIR"), "/parsers.rs"));
+    /// }
+    /// ```
+    ///
+    /// The file is only rewritten when its contents change.
+    pub fn emit_parsers_module<P>(&mut self, path: P) -> &mut Self where P: Into<PathBuf> {
+        self.session.parsers_module = Some(path.into());
+        self
+    }
+
     /// Apply `cargo` directory location conventions, by setting the
     /// input directory to `src` and the output directory to
     /// `$OUT_DIR`.
@@ -83,12 +123,500 @@ impl Configuration {
         self
     }
 
+    /// If true, emit comments recording where generated code comes
+    /// from in the grammar: the production that each reduction
+    /// function reduces, and a marker before each action's code,
+    /// which `rewrite_rustc_output` needs to point rustc's errors in
+    /// action code at the grammar. Default is true.
+    pub fn emit_grammar_comments(&mut self, val: bool) -> &mut Configuration {
+        self.session.emit_grammar_comments = val;
+        self
+    }
+
+    /// If true, table-driven parsers check the consistency of their
+    /// stacks as they parse, with `debug_assert!`s, so a bug in
+    /// LALRPOP shows up where it happens rather than as a confusing
+    /// panic or wrong result later on. The checks are only compiled
+    /// into debug builds anyway, but leaving them out of the generated
+    /// code keeps it smaller. Default is false.
+    pub fn emit_debug_assertions(&mut self, val: bool) -> &mut Configuration {
+        self.session.emit_debug_assertions = val;
+        self
+    }
+
+    /// If true, table-driven parsers print each token they read, and
+    /// each shift, reduction and goto they perform, to stdout. The
+    /// token and location types must implement `Debug`. Default is
+    /// false.
+    ///
+    /// These options, along with `emit_comments`, let a build script
+    /// make the parsers of debug builds easier to look into, while
+    /// keeping those of release builds lean:
+    ///
+    /// ```ignore
+    /// let debug = env::var("PROFILE").map(|p| p == "debug").unwrap_or(false);
+    /// lalrpop::Configuration::new()
+    ///     .emit_comments(debug)
+    ///     .emit_grammar_comments(debug)
+    ///     .emit_debug_assertions(debug)
+    ///     .process_current_dir()
+    ///     .unwrap();
+    /// ```
+    pub fn emit_trace(&mut self, val: bool) -> &mut Configuration {
+        self.session.emit_trace = val;
+        self
+    }
+
+    /// If true, instrument the table-driven parsers to count how often
+    /// they enter each state and run each reduction, on each thread.
+    /// For a public nonterminal `Foo`, the generated module then has a
+    /// `write_profile_Foo` function that writes these counts out; save
+    /// them, after parsing representative input, to `foo.profile` next
+    /// to the grammar `foo.lalrpop` for `use_profile`. Several profiles
+    /// can simply be concatenated. Default is false.
+    pub fn emit_profile(&mut self, val: bool) -> &mut Configuration {
+        self.session.emit_profile = val;
+        self
+    }
+
+    /// If true, lay out the tables of the table-driven parsers of a
+    /// grammar `foo.lalrpop` according to the profile `foo.profile`
+    /// next to it (see `emit_profile`): the states entered most often
+    /// come first, so that their rows share cache lines, and so do the
+    /// arms for the reductions run most often. This renumbers the
+    /// states, including those in `ErrorRecovery::state`. Grammars
+    /// without a profile, and instrumented parsers, are laid out as
+    /// usual. The profile is not a dependency that LALRPOP tracks, so
+    /// use `force_build` to pick up a new one. Default is false.
+    pub fn use_profile(&mut self, val: bool) -> &mut Configuration {
+        self.session.use_profile = val;
+        self
+    }
+
     /// If true, emit report file about generated code.
     pub fn emit_report(&mut self, val: bool) -> &mut Configuration {
         self.session.emit_report = val;
         self
     }
 
+    /// If true, emit a `.sets` file listing the FIRST, FOLLOW and
+    /// nullable sets of each nonterminal in the expanded grammar.
+    pub fn emit_sets(&mut self, val: bool) -> &mut Configuration {
+        self.session.emit_sets = val;
+        self
+    }
+
+    /// If true, emit a `.classes` file stating whether each public
+    /// nonterminal is LL(1), SLR(1), LALR(1) and LR(1), along with
+    /// the first construct that rules out each class it is not.
+    pub fn emit_classes(&mut self, val: bool) -> &mut Configuration {
+        self.session.emit_classes = val;
+        self
+    }
+
+    /// If true, emit a `.conflicts` file describing, as JSON, each
+    /// conflict found while building the parse tables: its state and
+    /// items, the lookahead, the competing actions and an example of
+    /// each. Commit it alongside the grammar to see how changes to
+    /// the grammar affect its conflicts in code review. The file is
+    /// written (with an empty array) even if there are no conflicts.
+    pub fn emit_conflicts(&mut self, val: bool) -> &mut Configuration {
+        self.session.emit_conflicts = val;
+        self
+    }
+
+    /// If true, emit a `.md` file documenting the grammar: each
+    /// nonterminal with its type, its `///` comments and its
+    /// alternatives (without the action code), linked to the
+    /// nonterminals they refer to, followed by a table of the
+    /// terminals.
+    pub fn emit_doc(&mut self, val: bool) -> &mut Configuration {
+        self.session.emit_doc = val;
+        self
+    }
+
+    /// If true, emit a `.stats` file counting the nonterminals,
+    /// terminals and productions of the grammar, and the states,
+    /// conflicts and lines of generated code of each public parser.
+    /// Keeping this file under version control shows how the
+    /// complexity of the grammar changes over time.
+    pub fn emit_stats(&mut self, val: bool) -> &mut Configuration {
+        self.session.emit_stats = val;
+        self
+    }
+
+    /// If true, `process_dir` (and `process_current_dir`) goes on with
+    /// the other grammars when one has errors or conflicts, rather
+    /// than stopping at it, and ends by printing a table of each
+    /// grammar, sorted by path, with what became of it (generated, up
+    /// to date, or failed, and why), the number of conflicts reported
+    /// for it, and how long it took, followed by totals. If any
+    /// grammar failed, the build then fails as it otherwise would have
+    /// at the first. `process_file` returns an error for a grammar
+    /// that fails, rather than exiting. Default is false.
+    pub fn summarize(&mut self, val: bool) -> &mut Configuration {
+        self.session.summarize = val;
+        self
+    }
+
+    /// If true, emit a `.operators.md` file with a table of the
+    /// operators of each chain of expression tiers in the grammar
+    /// (like those that `#[pratt]` expands to), with their
+    /// precedences and associativities, followed by the operators
+    /// whose placement contradicts their precedence.
+    pub fn emit_operators(&mut self, val: bool) -> &mut Configuration {
+        self.session.emit_operators = val;
+        self
+    }
+
+    /// If true, emit a `.bench.rs` file containing a criterion
+    /// benchmark that runs each public parser over the files in
+    /// `benches/corpus/<grammar>/<Nonterminal>`. Copy it into the
+    /// crate's `benches` directory to track parsing performance.
+    /// This is only possible for grammars that use the built-in
+    /// tokenizer and take no parameters.
+    pub fn emit_bench(&mut self, val: bool) -> &mut Configuration {
+        self.session.emit_bench = val;
+        self
+    }
+
+    /// If true, add `extern "C"` entry points to the generated parser
+    /// module and write a matching `.h` file, so that the parsers can
+    /// be called from C, C++ or any language with a C FFI. For each
+    /// public nonterminal `Expr` of `calc.lalrpop`, `calc_parse_Expr`
+    /// parses a UTF-8 buffer and returns an opaque handle to the
+    /// value, or `NULL` and a `lalrpop_error` describing what went
+    /// wrong; see `lalrpop_util::ffi`. Build the crate as a `cdylib`
+    /// or `staticlib` to link against it. This is only possible for
+    /// grammars that use the built-in tokenizer and take no
+    /// parameters, and for nonterminals whose values do not borrow
+    /// from the input; the error type, if any, must implement
+    /// `Display`.
+    pub fn emit_c_abi(&mut self, val: bool) -> &mut Configuration {
+        self.session.emit_c_abi = val;
+        self
+    }
+
+    /// If true, derive serde's `Serialize` and `Deserialize` for the
+    /// `Token` type generated for grammars that do not declare an
+    /// external tokenizer. The derives are referred to as
+    /// `::serde::Serialize` and `::serde::Deserialize`, so the crate
+    /// using the generated code must depend on serde with its `derive`
+    /// feature, and have `extern crate serde;` at its root. Default is
+    /// false.
+    pub fn derive_serde(&mut self, val: bool) -> &mut Configuration {
+        self.session.derive_serde = val;
+        self
+    }
+
+    /// If true, the lexers generated for grammars without an external
+    /// tokenizer find the end of tokens such as identifiers, numbers,
+    /// keywords and string literals (and skip whitespace) with loops
+    /// over the input bytes, rather than running their regexes a
+    /// second time. String bodies are searched several bytes at a
+    /// time. This speeds up lexing of large inputs noticeably.
+    /// Default is false.
+    pub fn fast_scanning(&mut self, val: bool) -> &mut Configuration {
+        self.session.fast_scanning = val;
+        self
+    }
+
+    /// Start the names of the items generated in the parser modules
+    /// (the parse functions' helper modules, action functions and so
+    /// forth) with `prefix` instead of `__`. As with the default, the
+    /// prefix is lengthened with underscores until it appears nowhere
+    /// in the grammar (or the files it extends or imports), so that
+    /// it cannot collide with the names used there; a distinctive
+    /// prefix also keeps the generated names apart from those that
+    /// `use` declarations bring in with a glob. The prefix must be a
+    /// valid start of a Rust identifier.
+    pub fn prefix(&mut self, prefix: &str) -> &mut Configuration {
+        assert!(prefix.chars().next().map_or(false, |c| c == '_' || c.is_alphabetic()) &&
+                prefix.chars().all(|c| c == '_' || c.is_alphanumeric()),
+                "invalid prefix `{}`", prefix);
+        self.session.prefix = Some(prefix.to_string());
+        self
+    }
+
+    /// Gate the generated code on the cfg predicate `predicate`,
+    /// e.g. `feature = "parser"`. This emits a `#![cfg(...)]`
+    /// attribute at the top of each generated module, so that the
+    /// parsers are compiled only when the predicate holds.
+    pub fn cfg(&mut self, predicate: &str) -> &mut Configuration {
+        self.session.cfg = Some(predicate.to_string());
+        self
+    }
+
+    /// Gate the parser for the public nonterminal `nonterminal` on
+    /// the cfg predicate `predicate`. Unlike `cfg`, this leaves the
+    /// rest of the generated module (and any other parsers) alone,
+    /// which is useful for excluding particularly large parsers from
+    /// minimal builds.
+    pub fn parser_cfg(&mut self, nonterminal: &str, predicate: &str) -> &mut Configuration {
+        self.session.parser_cfgs.insert(nonterminal.to_string(), predicate.to_string());
+        self
+    }
+
+    /// Enable the setting `key = "value"`, e.g. `feature =
+    /// "ext-syntax"`, for `#[cfg(...)]` annotations in the grammar.
+    /// Nonterminals and alternatives annotated with `#[cfg(key =
+    /// "value", ...)]` are left out of the grammar unless all of the
+    /// settings they list are enabled, so that one grammar file can
+    /// describe several editions or variants of a language.
+    pub fn grammar_cfg(&mut self, key: &str, value: &str) -> &mut Configuration {
+        self.session.grammar_cfgs.insert((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Limit the depth of the stack of generated parsers to `depth`
+    /// symbols. Input that is nested more deeply than that results
+    /// in a `ParseError::DepthLimit` error, rather than unbounded
+    /// memory use. This is only supported by table-driven parsers
+    /// (the default). By default, there is no limit.
+    pub fn max_depth(&mut self, depth: usize) -> &mut Configuration {
+        self.session.max_depth = Some(depth);
+        self
+    }
+
+    /// Limit generated parsers to `limit` reductions per parse.
+    /// Input that needs more than that, such as input that makes an
+    /// ambiguous Earley parser or error recovery do a lot of work,
+    /// results in a `ParseError::ReductionLimit` error, so that
+    /// hostile input cannot tie up a service. The reductions done
+    /// while recovering from errors count towards the limit. This is
+    /// only supported by table-driven and Earley parsers. By default,
+    /// there is no limit.
+    pub fn max_reductions(&mut self, limit: usize) -> &mut Configuration {
+        self.session.max_reductions = Some(limit);
+        self
+    }
+
+    /// If true, Earley parsers (see `#![earley]`) keep the value
+    /// built for a nonterminal over a span of tokens, and reuse it
+    /// wherever the same span is derived as the same nonterminal
+    /// again, rather than running the action code again: where the
+    /// results of `#[merge]` alternatives are merged, which otherwise
+    /// takes time exponential in how deeply ambiguities nest, and
+    /// across the derivations returned by `parse_X_all`. The types of
+    /// all terminals and nonterminals must then implement `Clone`, and
+    /// actions should not have side effects. Default is false.
+    pub fn memoize_actions(&mut self, val: bool) -> &mut Configuration {
+        self.session.memoize_actions = val;
+        self
+    }
+
+    /// When the grammar has conflicts, search for a sentence of at
+    /// most `max_length` tokens that can be parsed in two different
+    /// ways, and report it along with both parse trees. Conflicts do
+    /// not necessarily mean the grammar is ambiguous; this finds
+    /// actual ambiguity, if there is some within the bound. The
+    /// search is exponential in `max_length`, so keep it small (say,
+    /// 10 or less). By default, no search is done.
+    pub fn search_ambiguity(&mut self, max_length: usize) -> &mut Configuration {
+        self.session.ambiguity_search = Some(max_length);
+        self
+    }
+
+    /// Give up on building canonical LR(1) states (for
+    /// `LALRPOP_LANE_TABLE=disabled` and for `#[lookahead = "2"]`) once
+    /// they come to take more than roughly `bytes` bytes, and fall back
+    /// on something cheaper. This does not cap the memory of the build:
+    /// the states are built up to the budget before being dropped. A
+    /// parser built with `LALRPOP_LANE_TABLE=disabled` falls back on
+    /// the lane table algorithm, which merges states that only differ
+    /// in their lookaheads; a second token needs the exact lookaheads,
+    /// so conflicts are then not resolved with one. `--emit stats`
+    /// reports how much each pass took. By default, there is no budget.
+    pub fn lr1_fallback_budget(&mut self, bytes: usize) -> &mut Configuration {
+        self.session.lr1_fallback_budget = Some(bytes);
+        self
+    }
+
+    /// Write checkpoints of the canonical LR(1) construction (see
+    /// `lr1_fallback_budget`) to the directory `dir`, so that a build
+    /// of a huge grammar that is interrupted, or killed for using too
+    /// much memory, resumes from its last checkpoint the next time it
+    /// runs, rather than starting over. Each checkpoint is named after
+    /// a fingerprint of the grammar and of the public nonterminal, and
+    /// removed once the states are built. The directory must exist.
+    /// The fingerprint may change with the version of Rust LALRPOP is
+    /// built with, which merely starts the construction afresh. By
+    /// default, no checkpoints are written.
+    pub fn checkpoint_dir<P>(&mut self, dir: P) -> &mut Self where P: Into<PathBuf> {
+        self.session.checkpoint_dir = Some(dir.into());
+        self
+    }
+
+    /// Write a checkpoint (see `checkpoint_dir`) every `states`
+    /// states. Each checkpoint holds all of the states built so far,
+    /// so writing them too often slows the construction down. Default
+    /// is 5000.
+    pub fn checkpoint_interval(&mut self, states: usize) -> &mut Configuration {
+        self.session.checkpoint_interval = states;
+        self
+    }
+
+    /// If true, treat warnings about the grammar (such as
+    /// `lalrpop::unused_terminal`) as errors, which is useful in CI.
+    /// Lints that a grammar explicitly allows with
+    /// `#![allow(lalrpop::...)]` are still not reported. Default is
+    /// false.
+    pub fn deny_warnings(&mut self, val: bool) -> &mut Configuration {
+        self.session.deny_warnings = val;
+        self
+    }
+
+    /// If true, don't stop at the first conflict found while building
+    /// the parse tables: report every conflicting state, for every
+    /// public nonterminal, so that they can all be fixed in one go.
+    /// Default is false.
+    pub fn report_all_conflicts(&mut self, val: bool) -> &mut Configuration {
+        self.session.report_all_conflicts = val;
+        self
+    }
+
+    /// If true, report the conflicts in the grammar as warnings, and
+    /// generate the parsers anyway, resolving each conflict the way
+    /// yacc does: a shift wins over a reduction, and of two
+    /// reductions, the one whose production comes first in the grammar
+    /// wins. The states are built with the algorithm the grammar asks
+    /// for, so an `#[LALR]` grammar keeps its LALR(1) tables. From a
+    /// build script, the warnings are passed on to Cargo. This lets an
+    /// unfinished grammar be tried out end-to-end; the resolutions are
+    /// rarely the ones wanted, so fix the conflicts before relying on
+    /// the parser. Default is false.
+    pub fn conflicts_as_warnings(&mut self, val: bool) -> &mut Configuration {
+        self.session.conflicts_as_warnings = val;
+        self
+    }
+
+    /// Only treat the conflicts of a grammar `foo.lalrpop` as errors
+    /// if they are missing from its baseline file,
+    /// `foo.conflict-baseline`, next to it; a missing file allows no
+    /// conflicts. The conflicts are those of the states the parsers
+    /// are generated from, built with the algorithm the grammar asks
+    /// for, and they are resolved as with `conflicts_as_warnings`, but
+    /// without the warnings. This lets a
+    /// grammar with many known conflicts be cleaned up gradually,
+    /// while keeping new ones out; see `update_conflict_baseline`.
+    pub fn check_conflict_baseline(&mut self) -> &mut Configuration {
+        self.session.conflict_baseline = ConflictBaseline::Check;
+        self
+    }
+
+    /// Record all of the conflicts of each grammar in its baseline
+    /// file (see `check_conflict_baseline`), replacing what was there,
+    /// and generate the parsers as if the baseline were checked.
+    pub fn update_conflict_baseline(&mut self) -> &mut Configuration {
+        self.session.conflict_baseline = ConflictBaseline::Update;
+        self
+    }
+
+    /// If true, try inlining each small, non-recursive, private
+    /// nonterminal, as if it were marked `#[inline]`, and keep the
+    /// change wherever it leaves the parsers with fewer conflicts or
+    /// fewer states. Each nonterminal that gets inlined is logged.
+    /// This makes building the grammar slower. Default is false.
+    pub fn auto_inline(&mut self, val: bool) -> &mut Configuration {
+        self.session.auto_inline = val;
+        self
+    }
+
+    /// If true, when a conflict report suggests inlining a
+    /// nonterminal, rebuild the parse tables with it inlined to check
+    /// that this resolves the conflict, and if no inlining was
+    /// suggested, try inlining a few of the nonterminals involved in
+    /// the conflict. This makes reporting conflicts slower. Default
+    /// is false.
+    pub fn verify_inlining(&mut self, val: bool) -> &mut Configuration {
+        self.session.verify_inlining = val;
+        self
+    }
+
+    /// If true, mark the function that the generated parsers use to
+    /// reduce each production, like `__reduce_Expr_2` for the third
+    /// alternative of `Expr`, as `#[inline(never)]`, so that CPU
+    /// profiles (and flamegraphs) of the application attribute the
+    /// time spent parsing to specific productions, including the time
+    /// spent in their action code. This makes the parsers somewhat
+    /// slower. This is only supported by table-driven parsers (the
+    /// default). Default is false.
+    pub fn profile_reductions(&mut self, val: bool) -> &mut Configuration {
+        self.session.profile_reductions = val;
+        self
+    }
+
+    /// If true, generate parsers that do not panic on any input: the
+    /// generated code contains no `unwrap`, `panic!` or indexing that
+    /// can fail, and reports violations of the parser's own
+    /// invariants (which would be bugs in LALRPOP) as
+    /// `ParseError::Internal` errors, like any other error. This is
+    /// meant for parsers embedded in services that must not go down.
+    /// Action code, and the functions that `match` sections refer
+    /// to, are of course not covered. This is only supported by
+    /// table-driven parsers (the default) without error recovery or
+    /// error repair, and cannot be combined with fast scanning. The
+    /// parsers are slightly slower. Default is false.
+    pub fn panic_free(&mut self, val: bool) -> &mut Configuration {
+        self.session.panic_free = val;
+        self
+    }
+
+    /// Place the parse tables of generated parsers in the link
+    /// section `section` (e.g. `".rodata.parser"`, or
+    /// `"__TEXT,__parser"` on macOS), so that a linker script can put
+    /// them in flash rather than RAM. The tables are then `static`
+    /// arrays rather than `const` slices, since only statics can be
+    /// placed in a section. Either way, they are plain data, which
+    /// needs no initialization or allocation at run time. This is
+    /// only supported by table-driven parsers (the default);
+    /// recursive ascent parsers have no tables. By default, the
+    /// tables go wherever the compiler puts them.
+    pub fn table_link_section(&mut self, section: &str) -> &mut Configuration {
+        self.session.table_section = Some(section.to_string());
+        self
+    }
+
+    /// If true, generate parsers that keep their state and symbol
+    /// stacks in a buffer supplied by the caller, rather than on the
+    /// heap, for targets without an allocator: instead of
+    /// `parse_Foo`, the parser module has `parse_Foo_with_stack`,
+    /// which takes an extra `&mut [u8]` (see
+    /// `lalrpop_util::stack`). Input that is nested too deeply for
+    /// the buffer results in a `ParseError::StackCapacity` error. The
+    /// parsers only use `core`, so that they build in `no_std`
+    /// crates, where they work with `lalrpop_util` built without its
+    /// `std` feature. This is only supported by table-driven parsers
+    /// (the default) without error recovery or error repair, and
+    /// there is no reusable `FooParser`. Default is false.
+    pub fn fixed_stack(&mut self, val: bool) -> &mut Configuration {
+        self.session.fixed_stack = val;
+        self
+    }
+
+    /// If true, the generated module of a grammar also has a
+    /// `tokenize` function, which lexes all of the input into a
+    /// `lalrpop_util::tokens::TokenArray` of the kinds and spans of its
+    /// tokens, and for each public nonterminal `Foo`, a
+    /// `parse_Foo_tokens` function that parses the input from such an
+    /// array. This is only supported by table-driven parsers (the
+    /// default) with the built-in tokenizer. Default is false.
+    pub fn token_arrays(&mut self, val: bool) -> &mut Configuration {
+        self.session.token_arrays = val;
+        self
+    }
+
+    /// If true, table-driven parsers skip the states that only reduce
+    /// a unit production `A = B` whose action is the identity: the
+    /// `B` is left on the stack where the `A` is expected, which
+    /// saves a reduction (and often a state) per level of a grammar
+    /// with many tiers of expressions. The parsers accept the same
+    /// input and report the same errors either way. Default is false.
+    pub fn eliminate_unit_rules(&mut self, val: bool) -> &mut Configuration {
+        self.session.eliminate_unit_rules = val;
+        self
+    }
+
     /// Minimal logs: only for errors that halt progress.
     pub fn log_quiet(&mut self) -> &mut Configuration {
         self.session.log.set_level(Level::Taciturn);
@@ -153,6 +681,87 @@ impl Configuration {
         try!(build::process_file(session, path));
         Ok(())
     }
+
+    /// Compare the grammars in the `.lalrpop` files `old` and `new`,
+    /// printing the productions that were added, removed or changed,
+    /// as well as short inputs that only one of them accepts.
+    pub fn diff_files<P:AsRef<Path>, Q:AsRef<Path>>(&self, old: P, new: Q) -> Result<(), Box<Error>> {
+        let session = Rc::new(self.session.clone());
+        try!(build::diff_files(session, old.as_ref(), new.as_ref()));
+        Ok(())
+    }
+
+    /// Step through the conflicts of the grammar in the `.lalrpop`
+    /// file `path` interactively, reading commands from stdin: move
+    /// between the conflicts, expand every example that leads to one,
+    /// or show the lines of the grammar involved.
+    pub fn explain_conflicts<P:AsRef<Path>>(&self, path: P) -> Result<(), Box<Error>> {
+        let session = Rc::new(self.session.clone());
+        try!(build::explain_conflicts(session, path.as_ref()));
+        Ok(())
+    }
+
+    /// Build the parser for the public nonterminal `nonterminal` of
+    /// the grammar in the `.lalrpop` file `path` in memory, and use it
+    /// to parse each line read from stdin, printing the derivation of
+    /// the line or the error. If `nonterminal` is `None`, the grammar
+    /// must have a single public nonterminal. Lines are tokenized as
+    /// for `animate_parse`. This makes it quick to try out changes to
+    /// a grammar, since there is no code to compile.
+    pub fn repl<P:AsRef<Path>>(&self, path: P, nonterminal: Option<&str>) -> Result<(), Box<Error>> {
+        let session = Rc::new(self.session.clone());
+        try!(build::repl(session, path.as_ref(), nonterminal));
+        Ok(())
+    }
+
+    /// Write a self-contained HTML page to `out` that animates, step
+    /// by step, how the parser for the public nonterminal
+    /// `nonterminal` of the grammar in the `.lalrpop` file `path`
+    /// parses `input`, showing the stack, the remaining input and the
+    /// action taken at each step. For grammars with an `extern` token
+    /// declaration, `input` is a list of terminals separated by
+    /// whitespace (like `Num "+" Num`), since the tokenizer is not
+    /// available. The grammar must not have conflicts.
+    pub fn animate_parse<P:AsRef<Path>, W:Write>(&self,
+                                                 path: P,
+                                                 nonterminal: &str,
+                                                 input: &str,
+                                                 out: &mut W)
+                                                 -> Result<(), Box<Error>> {
+        let session = Rc::new(self.session.clone());
+        try!(build::animate_parse(session, path.as_ref(), nonterminal, input, out));
+        Ok(())
+    }
+
+    /// Write a static web page to `out` where users can try out the
+    /// parser for the public nonterminal `nonterminal` (or the only
+    /// public nonterminal) of the grammar in the `.lalrpop` file
+    /// `path`: as they type into it, the page shows the derivation of
+    /// their input or the place where it goes wrong. The page carries
+    /// the parse table and the tokenizer along with a small
+    /// interpreter, so it can be served as is; action code is not
+    /// run. Input is tokenized as for `animate_parse`. The grammar
+    /// must not have conflicts.
+    pub fn playground<P:AsRef<Path>, W:Write>(&self,
+                                              path: P,
+                                              nonterminal: Option<&str>,
+                                              out: &mut W)
+                                              -> Result<(), Box<Error>> {
+        let session = Rc::new(self.session.clone());
+        try!(build::playground(session, path.as_ref(), nonterminal, out));
+        Ok(())
+    }
+
+    /// Analyze the grammar in the `.lalrpop` file `path` without
+    /// generating a parser, returning its FIRST and FOLLOW sets and
+    /// the states and conflicts of each of its parsers as data, for
+    /// tools that want to inspect a grammar. All conflicts are
+    /// gathered, whatever `report_all_conflicts` says. An error in the
+    /// grammar is returned rather than printed.
+    pub fn analyze_file<P:AsRef<Path>>(&self, path: P) -> Result<GrammarAnalysis, Box<Error>> {
+        let session = Rc::new(self.session.clone());
+        Ok(try!(build::analyze_file(session, path.as_ref())))
+    }
 }
 
 /// Process all files in the current directory, which -- unless you
@@ -173,3 +782,34 @@ pub fn process_root() -> Result<(), Box<Error>> {
 pub fn process_root_unconditionally() -> Result<(), Box<Error>> {
     Configuration::new().force_build(true).process_current_dir()
 }
+
+/// Generate the parser for the grammar `grammar_text`, configured by
+/// `config`, returning the Rust source that would be written to the
+/// `.rs` file. Nothing is read from or written to the filesystem, so
+/// the grammar cannot import a token module or extend another
+/// grammar, and the settings that emit other files are ignored. The
+/// errors in the grammar, and the warnings along with them, are
+/// returned rather than printed.
+pub fn process_str(grammar_text: &str, config: &Configuration) -> Result<String, Diagnostics> {
+    let session = Rc::new(config.session.clone());
+    build::process_str(session, grammar_text)
+}
+
+/// Check the grammar `grammar_text`, configured by `config`, without
+/// generating a parser, returning its errors and warnings, including
+/// the conflicts of all of its parsers. Like `process_str`, this
+/// neither reads nor writes files; it is meant for tools such as an
+/// online playground, which can compile lalrpop to WebAssembly and
+/// pass `Diagnostics::to_json` to JavaScript.
+pub fn check_str(grammar_text: &str, config: &Configuration) -> Diagnostics {
+    let session = Rc::new(config.session.clone());
+    build::check_str(session, grammar_text)
+}
+
+/// Analyze the grammar in the `.lalrpop` file `path` without
+/// generating a parser; see `Configuration::analyze_file`.
+///
+/// Equivalent to `Configuration::new().analyze_file(path)`.
+pub fn process_grammar_for_analysis<P:AsRef<Path>>(path: P) -> Result<GrammarAnalysis, Box<Error>> {
+    Configuration::new().analyze_file(path)
+}
@@ -0,0 +1,130 @@
+//! Generates a criterion benchmark for the public parsers of a
+//! grammar (`--emit bench`). Each parser is run over every file in a
+//! corpus directory of its own, so that changes to the grammar or to
+//! LALRPOP that make parsing slower show up as regressions in
+//! `cargo bench`.
+//!
+//! The parsers are fed the text of the files, so only grammars that
+//! use the built-in tokenizer and take no parameters can be
+//! benchmarked; for other grammars we just write a note explaining
+//! why there is no benchmark.
+
+use grammar::consts::INPUT_PARAMETER;
+use grammar::repr::*;
+use intern::intern;
+use rust::RustWrite;
+use std::io::{self, Write};
+
+#[cfg(test)]
+mod test;
+
+/// Where the generated parsers live, as seen from the benchmark.
+pub struct BenchTarget<'a> {
+    /// Name of the crate that contains the generated module.
+    pub crate_name: &'a str,
+
+    /// Path of the generated module within that crate, e.g.
+    /// `parser::calculator`.
+    pub module_path: &'a str,
+
+    /// Directory (relative to the crate root) containing one
+    /// subdirectory of corpus files per public nonterminal.
+    pub corpus_dir: &'a str,
+}
+
+pub fn generate_bench<W: Write>(out: &mut RustWrite<W>,
+                                grammar: &Grammar,
+                                target: &BenchTarget)
+                                -> io::Result<()> {
+    rust!(out, "// Benchmarks for the parsers in `{}::{}`, generated by LALRPOP.",
+          target.crate_name, target.module_path);
+
+    if let Some(reason) = unsupported_reason(grammar) {
+        rust!(out, "//");
+        rust!(out, "// No benchmark was generated because {}.", reason);
+        return Ok(());
+    }
+
+    let nonterminals: Vec<NonterminalString> =
+        grammar.start_nonterminals.keys().cloned().collect();
+
+    rust!(out, "//");
+    rust!(out, "// To use it, copy this file into the `benches` directory, add `criterion`");
+    rust!(out, "// as a dev-dependency, and declare the benchmark with `harness = false`.");
+    rust!(out, "// Each parser is run over every file in `{}/<Nonterminal>`,", target.corpus_dir);
+    rust!(out, "// e.g. `{}/{}`.", target.corpus_dir, nonterminals[0]);
+    rust!(out, "");
+    rust!(out, "#![allow(non_snake_case)]");
+    rust!(out, "");
+    rust!(out, "#[macro_use]");
+    rust!(out, "extern crate criterion;");
+    rust!(out, "extern crate {};", target.crate_name);
+    rust!(out, "");
+    rust!(out, "use criterion::Criterion;");
+    rust!(out, "use std::fs;");
+    rust!(out, "use std::io::Read;");
+    rust!(out, "use std::path::Path;");
+    rust!(out, "use {}::{} as parser;", target.crate_name, target.module_path);
+    rust!(out, "");
+
+    // `corpus` returns the name and contents of each corpus file of a
+    // nonterminal, in a stable order.
+    rust!(out, "fn corpus(nonterminal: &str) -> Vec<(String, String)> {{");
+    rust!(out, "let dir = Path::new(env!(\"CARGO_MANIFEST_DIR\"))");
+    rust!(out, ".join({:?})", target.corpus_dir);
+    rust!(out, ".join(nonterminal);");
+    rust!(out, "let entries = fs::read_dir(&dir)");
+    rust!(out, ".unwrap_or_else(|e| panic!(\"cannot read `{{}}`: {{}}\", dir.display(), e));");
+    rust!(out, "let mut paths: Vec<_> = entries.map(|entry| entry.unwrap().path()).collect();");
+    rust!(out, "paths.sort();");
+    rust!(out, "paths.into_iter()");
+    rust!(out, ".map(|path| {{");
+    rust!(out, "let mut text = String::new();");
+    rust!(out, "fs::File::open(&path).and_then(|mut f| f.read_to_string(&mut text))");
+    rust!(out, ".unwrap_or_else(|e| panic!(\"cannot read `{{}}`: {{}}\", path.display(), e));");
+    rust!(out, "let name = path.file_name().unwrap().to_string_lossy().into_owned();");
+    rust!(out, "(name, text)");
+    rust!(out, "}})");
+    rust!(out, ".collect()");
+    rust!(out, "}}");
+
+    for &nt in &nonterminals {
+        rust!(out, "");
+        rust!(out, "fn bench_{}(c: &mut Criterion) {{", nt);
+        rust!(out, "for (name, text) in corpus(\"{}\") {{", nt);
+        rust!(out, "c.bench_function(&format!(\"{}/{{}}\", name), move |b| {{", nt);
+        rust!(out, "b.iter(|| parser::parse_{}(&text).is_ok())", nt);
+        rust!(out, "}});");
+        rust!(out, "}}");
+        rust!(out, "}}");
+    }
+
+    rust!(out, "");
+    rust!(out, "criterion_group!(benches, {});",
+          nonterminals.iter()
+                      .map(|nt| format!("bench_{}", nt))
+                      .collect::<Vec<_>>()
+                      .join(", "));
+    rust!(out, "criterion_main!(benches);");
+    Ok(())
+}
+
+/// Why the parsers of `grammar` can't be called with just the text
+/// of a corpus file, if they can't. (Grammars that use the built-in
+/// tokenizer always have the implicit `input` parameter.)
+pub fn unsupported_reason(grammar: &Grammar) -> Option<&'static str> {
+    if grammar.intern_token.is_none() {
+        Some("the grammar uses an external tokenizer")
+    } else if grammar.parameters.iter().any(|p| p.name != intern(INPUT_PARAMETER)) {
+        Some("the grammar takes parameters")
+    } else if grammar.type_parameters.iter().any(|p| match *p {
+        TypeParameter::Lifetime(_) => false,
+        TypeParameter::Id(_) => true,
+    }) {
+        Some("the grammar has type parameters")
+    } else if grammar.start_nonterminals.is_empty() {
+        Some("the grammar has no public nonterminals")
+    } else {
+        None
+    }
+}
@@ -0,0 +1,52 @@
+use rust::RustWrite;
+use test_util::normalized_grammar;
+
+use super::{generate_bench, BenchTarget};
+
+fn bench(grammar: &str) -> String {
+    let grammar = normalized_grammar(grammar);
+    let target = BenchTarget {
+        crate_name: "calc",
+        module_path: "parser::expr",
+        corpus_dir: "benches/corpus/parser/expr",
+    };
+    let mut out = RustWrite::new(vec![]);
+    generate_bench(&mut out, &grammar, &target).unwrap();
+    String::from_utf8(out.into_inner()).unwrap()
+}
+
+#[test]
+fn one_bench_per_public_nonterminal() {
+    let out = bench(r#"
+grammar;
+    pub Expr: () = { Expr "+" Num => (), Num => () };
+    pub Num: () = r"[0-9]+" => ();
+"#);
+    assert!(out.contains("use calc::parser::expr as parser;"));
+    assert!(out.contains("fn bench_Expr(c: &mut Criterion) {"));
+    assert!(out.contains("for (name, text) in corpus(\"Num\") {"));
+    assert!(out.contains("parser::parse_Num(&text)"));
+    assert!(out.contains("criterion_group!(benches, bench_Expr, bench_Num);"));
+    assert!(out.contains(".join(\"benches/corpus/parser/expr\")"));
+}
+
+#[test]
+fn external_tokenizer() {
+    let out = bench(r#"
+grammar;
+    extern { enum Tok { Num => Tok::Num } }
+    pub Expr: () = Num => ();
+"#);
+    assert!(out.contains("No benchmark was generated because the grammar uses an external \
+                          tokenizer."));
+    assert!(!out.contains("criterion_main!"));
+}
+
+#[test]
+fn grammar_parameters() {
+    let out = bench(r#"
+grammar(scale: u32);
+    pub Num: u32 = r"[0-9]+" => scale;
+"#);
+    assert!(out.contains("because the grammar takes parameters"));
+}
@@ -29,12 +29,27 @@
 //!
 //! * -- in this case, those "user-declared" parameters are inserted by
 //! the "internal tokenizer".
+//!
+//! The body of a user action fn is preceded by a source marker like
+//! `//@ src/foo.lalrpop:22:17 (1 lines)`, giving the location of the
+//! action code in the grammar and how many lines it spans. These
+//! markers are used by `rewrite_rustc_output` to report errors in
+//! action code against the `.lalrpop` file.
 
+use file_text::FileText;
+use grammar::parse_tree as pt;
 use grammar::repr as r;
 use rust::RustWrite;
+use session::Session;
+use source_map;
 use std::io::{self, Write};
+use tok::{Tok, Tokenizer};
 
-pub fn emit_action_code<W: Write>(grammar: &r::Grammar, rust: &mut RustWrite<W>) -> io::Result<()> {
+pub fn emit_action_code<W: Write>(session: &Session,
+                                  file_text: &FileText,
+                                  grammar: &r::Grammar,
+                                  rust: &mut RustWrite<W>)
+                                  -> io::Result<()> {
     for (i, defn) in grammar.action_fn_defns.iter().enumerate() {
         rust!(rust, "");
 
@@ -47,7 +62,7 @@ pub fn emit_action_code<W: Write>(grammar: &r::Grammar, rust: &mut RustWrite<W>)
 
         match defn.kind {
             r::ActionFnDefnKind::User(ref data) => {
-                try!(emit_user_action_code(grammar, rust, i, defn, data))
+                try!(emit_user_action_code(session, file_text, grammar, rust, i, defn, data))
             }
             r::ActionFnDefnKind::Lookaround(ref variant) => {
                 try!(emit_lookaround_action_code(grammar, rust, i, defn, variant))
@@ -74,7 +89,9 @@ fn ret_type_string(grammar: &r::Grammar, defn: &r::ActionFnDefn) -> String {
     }
 }
 
-fn emit_user_action_code<W: Write>(grammar: &r::Grammar,
+fn emit_user_action_code<W: Write>(session: &Session,
+                                   file_text: &FileText,
+                                   grammar: &r::Grammar,
                                    rust: &mut RustWrite<W>,
                                    index: usize,
                                    defn: &r::ActionFnDefn,
@@ -85,14 +102,31 @@ fn emit_user_action_code<W: Write>(grammar: &r::Grammar,
     // For each symbol to be reduced, we will receive
     // a (L, T, L) triple where the Ls are locations and
     // the T is the data. Ignore the locations and bind
-    // the data to the name the user gave.
+    // the data to the name the user gave. (If there is a guard or a
+    // version check, we keep the start of the first symbol and the
+    // end of the last, to report where the rejected symbols are.)
+    let last = data.arg_patterns.len().wrapping_sub(1);
+    let guarded = data.guard.is_some() || data.since.is_some();
     let mut arguments: Vec<String> = data.arg_patterns
                                          .iter()
                                          .zip(data.arg_types
                                                   .iter()
                                                   .cloned()
                                                   .map(|t| grammar.types.spanned_type(t)))
-                                         .map(|(p, t)| format!("(_, {}, _): {}", p, t))
+                                         .enumerate()
+                                         .map(|(i, (p, t))| {
+                                             let start = if guarded && i == 0 {
+                                                 format!("{}start", grammar.prefix)
+                                             } else {
+                                                 format!("_")
+                                             };
+                                             let end = if guarded && i == last {
+                                                 format!("{}end", grammar.prefix)
+                                             } else {
+                                                 format!("_")
+                                             };
+                                             format!("({}, {}, {}): {}", start, p, end, t)
+                                         })
                                          .collect();
 
     // If this is a reduce of an empty production, we will
@@ -115,11 +149,73 @@ fn emit_user_action_code<W: Write>(grammar: &r::Grammar,
                               ret_type,
                               vec![]));
     rust!(rust, "{{");
+    if guarded && data.arg_patterns.is_empty() {
+        rust!(rust, "let {p}start = {p}lookbehind.clone();", p = grammar.prefix);
+        rust!(rust, "let {p}end = {p}lookahead.clone();", p = grammar.prefix);
+    }
+    if let Some(since) = data.since {
+        rust!(rust,
+              "let {p}since = {p}lalrpop_util::Version::new({}, {}, {});",
+              since.major,
+              since.minor,
+              since.patch,
+              p = grammar.prefix);
+        rust!(rust, "if {p}version < {p}since {{", p = grammar.prefix);
+        rust!(rust,
+              "return Err({p}lalrpop_util::ParseError::VersionTooOld {{ \
+               start: {p}start, end: {p}end, since: {p}since }});",
+              p = grammar.prefix);
+        rust!(rust, "}}");
+    }
+    if let Some(ref guard) = data.guard {
+        rust!(rust, "if !({}) {{", guard);
+        rust!(rust,
+              "return Err({p}lalrpop_util::ParseError::GuardFailed {{ \
+               start: {p}start, end: {p}end }});",
+              p = grammar.prefix);
+        rust!(rust, "}}");
+    }
+    let marker_offset = if session.emit_grammar_comments {
+        action_code_offset(file_text, data.span)
+    } else {
+        None
+    };
+    if let Some(offset) = marker_offset {
+        let (path, line, col) = file_text.location(offset);
+        rust!(rust, "{}", source_map::marker(path,
+                                             line + 1,
+                                             col + 1,
+                                             data.code.lines().count()));
+    }
     rust!(rust, "{}", data.code);
     rust!(rust, "}}");
     Ok(())
 }
 
+/// Finds the offset in the grammar file where the code of the action
+/// written in the alternative `span` begins. Returns `None` if there
+/// is no code written there (e.g., for actions synthesized by macro
+/// expansion).
+fn action_code_offset(file_text: &FileText, span: pt::Span) -> Option<usize> {
+    if span.1 > file_text.text().len() {
+        return None;
+    }
+    let text = &file_text.text()[span.0..span.1];
+    for token in Tokenizer::new(text, span.0) {
+        match token {
+            Ok((_, Tok::EqualsGreaterThanCode(code), hi)) |
+            Ok((_, Tok::EqualsGreaterThanQuestionCode(code), hi)) => {
+                // `code` runs right up to `hi`, but may begin with
+                // some whitespace that the parser strips
+                return Some(hi - code.trim_left().len());
+            }
+            Ok(_) => { }
+            Err(_) => return None,
+        }
+    }
+    None
+}
+
 fn emit_lookaround_action_code<W: Write>(grammar: &r::Grammar,
                                          rust: &mut RustWrite<W>,
                                          index: usize,
@@ -0,0 +1,84 @@
+//! The types of `#[ast]` nonterminals (see `repr::AstType`), with their
+//! implementations of `Spanned`.
+
+use grammar::repr as r;
+use rust::RustWrite;
+use std::io::{self, Write};
+
+pub fn emit_ast_types<W: Write>(grammar: &r::Grammar, rust: &mut RustWrite<W>) -> io::Result<()> {
+    for ast_type in &grammar.ast_types {
+        try!(emit_ast_type(grammar, ast_type, rust));
+    }
+    Ok(())
+}
+
+fn emit_ast_type<W: Write>(grammar: &r::Grammar,
+                           ast_type: &r::AstType,
+                           rust: &mut RustWrite<W>)
+                           -> io::Result<()> {
+    let p = &grammar.prefix;
+    let is_enum = ast_type.variants.iter().any(|variant| variant.name.is_some());
+
+    rust!(rust, "");
+    if !ast_type.derives.is_empty() {
+        rust!(rust, "#[derive({})]", ast_type.derives.join(", "));
+    }
+    // (after the derives, which may define them)
+    for attribute in &ast_type.attributes {
+        rust!(rust, "#[{}]", attribute);
+    }
+    if is_enum {
+        rust!(rust, "pub enum {} {{", ast_type.ty);
+        for variant in &ast_type.variants {
+            rust!(rust, "{} {{", variant.name.unwrap());
+            for &(name, ref ty) in &variant.fields {
+                rust!(rust, "{}: {},", name, ty);
+            }
+            rust!(rust, "}},");
+        }
+        rust!(rust, "}}");
+    } else {
+        rust!(rust, "pub struct {} {{", ast_type.ty);
+        for &(name, ref ty) in &ast_type.variants[0].fields {
+            rust!(rust, "pub {}: {},", name, ty);
+        }
+        rust!(rust, "}}");
+    }
+
+    let type_parameters: Vec<String> = ast_type.type_parameters
+                                               .iter()
+                                               .map(|parameter| parameter.to_string())
+                                               .collect();
+    let type_parameters = if type_parameters.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", type_parameters.join(", "))
+    };
+    rust!(rust, "");
+    rust!(rust,
+          "impl{} {}lalrpop_util::Spanned<{}> for {} {{",
+          type_parameters,
+          p,
+          ast_type.loc_type,
+          ast_type.ty);
+    for field in &["start", "end"] {
+        rust!(rust, "fn {}(&self) -> {} {{", field, ast_type.loc_type);
+        if is_enum {
+            rust!(rust, "match *self {{");
+            for variant in &ast_type.variants {
+                rust!(rust,
+                      "{}::{} {{ ref {}, .. }} => {}.clone(),",
+                      ast_type.nonterminal,
+                      variant.name.unwrap(),
+                      field,
+                      field);
+            }
+            rust!(rust, "}}");
+        } else {
+            rust!(rust, "self.{}.clone()", field);
+        }
+        rust!(rust, "}}");
+    }
+    rust!(rust, "}}");
+    Ok(())
+}
@@ -1,51 +1,423 @@
 //! Utilies for running in a build script.
 
-use atty;
+use bench;
+use c_abi;
+use collections::{set, Set};
+use diagnostics::{Diagnostic, Diagnostics, Severity};
 use file_text::FileText;
 use grammar::parse_tree as pt;
 use grammar::repr as r;
+use grammar_diff;
+use grammar_doc;
+use intern::{intern, InternedString};
 use lalrpop_util::ParseError;
 use lexer::intern_token;
+use lint::{self, LintLevel};
 use lr1;
 use message::{Content, Message};
 use message::builder::InlineBuilder;
 use normalize;
+use operator_table;
 use parser;
 use rust::RustWrite;
-use session::{ColorConfig, Session};
+use session::{ColorConfig, ConflictBaseline, Session};
 use term;
 use tls::Tls;
 use tok;
 
+use std::env;
 use std::fs;
-use std::io::{self, Write, BufRead};
+use std::io::{self, Write, BufRead, Read};
 use std::path::{Path, PathBuf};
 use std::process::exit;
 use std::rc::Rc;
 
 mod action;
+mod ast;
 mod fake_term;
+mod parsers_module;
+mod summary;
+mod unparse;
+
+#[cfg(test)]
+mod test;
 
 use self::fake_term::FakeTerminal;
+use self::summary::{GrammarFailed, GrammarSummary, Status};
 
 const LALRPOP_VERSION_HEADER: &'static str =
     concat!("// auto-generated: \"", env!("CARGO_PKG_NAME"), " ", env!("CARGO_PKG_VERSION"), "\"");
 
 pub fn process_dir<P: AsRef<Path>>(session: Rc<Session>, root_dir: P) -> io::Result<()> {
-    let lalrpop_files = try!(lalrpop_files(root_dir));
+    let mut lalrpop_files = try!(lalrpop_files(root_dir));
+    lalrpop_files.sort();
+    if session.summarize {
+        let (summaries, error) = process_files_summarized(&session, &lalrpop_files);
+        println!("");
+        let stdout = io::stdout();
+        try!(summary::write_summary(&mut stdout.lock(), &summaries));
+        if let Some(error) = error {
+            return Err(error);
+        }
+        if summaries.iter().any(|summary| summary.status.failed()) {
+            exit(1);
+        }
+    } else {
+        for lalrpop_file in &lalrpop_files {
+            try!(process_file(session.clone(), lalrpop_file));
+        }
+    }
+    if let Some(ref parsers_file) = session.parsers_module {
+        try!(emit_parsers_module(&session, &lalrpop_files, parsers_file));
+    }
+    Ok(())
+}
+
+/// Writes the module declaring the parsers generated from
+/// `lalrpop_files` (but not token modules, which generate none) to
+/// `parsers_file`, unless it is already there.
+fn emit_parsers_module(session: &Session,
+                       lalrpop_files: &[PathBuf],
+                       parsers_file: &Path)
+                       -> io::Result<()> {
+    // the paths of the generated files are relative to that of the
+    // module, whether they were given relative to the current
+    // directory or not
+    let current_dir = try!(env::current_dir());
+    let mut rs_files = vec![];
     for lalrpop_file in lalrpop_files {
-        try!(process_file(session.clone(), lalrpop_file));
+        if !is_token_module(&try!(read_file_text(lalrpop_file))) {
+            rs_files.push(current_dir.join(try!(resolve_rs_file(session, lalrpop_file))));
+        }
+    }
+    rs_files.sort();
+
+    let dir = current_dir.join(parsers_file.parent().unwrap_or(Path::new("")));
+    let text = match parsers_module::parsers_module(&dir, &rs_files) {
+        Ok(module) => format!("{}\n{}", LALRPOP_VERSION_HEADER, module),
+        Err(message) => return Err(io::Error::new(io::ErrorKind::InvalidInput, message)),
+    };
+
+    let mut old_text = String::new();
+    if let Ok(mut file) = fs::File::open(parsers_file) {
+        try!(file.read_to_string(&mut old_text));
+    }
+    if text != old_text {
+        log!(session,
+             Informative,
+             "writing parsers module `{}`",
+             parsers_file.display());
+        if let Some(parent) = parsers_file.parent() {
+            try!(fs::create_dir_all(parent));
+        }
+        let mut file = try!(fs::File::create(parsers_file));
+        try!(file.write_all(text.as_bytes()));
     }
     Ok(())
 }
 
+/// Processes each of `lalrpop_files`, whether or not the ones before
+/// fail, and sums up what became of them; see
+/// `Configuration::summarize`. Along with the summaries comes the
+/// first error that was not in a grammar, like a file that could not
+/// be read.
+fn process_files_summarized(session: &Rc<Session>,
+                            lalrpop_files: &[PathBuf])
+                            -> (Vec<GrammarSummary>, Option<io::Error>) {
+    let mut summaries = vec![];
+    let mut first_error = None;
+    for lalrpop_file in lalrpop_files {
+        let stopwatch = ::log::Stopwatch::start();
+        let (status, conflicts) = match process_file_status(session.clone(), lalrpop_file) {
+            Ok(status) => (status, 0),
+            Err(error) => {
+                let failed = error.get_ref()
+                                  .and_then(|e| e.downcast_ref::<GrammarFailed>())
+                                  .map(|failed| (failed.reason.clone(), failed.conflicts));
+                match failed {
+                    Some((reason, conflicts)) => (Status::Failed(reason), conflicts),
+                    None => {
+                        // errors in grammars have been reported already
+                        println!("{}: error: {}", lalrpop_file.display(), error);
+                        let reason = error.to_string();
+                        if first_error.is_none() {
+                            first_error = Some(error);
+                        }
+                        (Status::Failed(reason), 0)
+                    }
+                }
+            }
+        };
+        summaries.push(GrammarSummary {
+            file: lalrpop_file.clone(),
+            status: status,
+            conflicts: conflicts,
+            seconds: stopwatch.seconds(),
+        });
+    }
+    (summaries, first_error)
+}
+
+/// Ends the processing of a grammar whose errors have been reported:
+/// by exiting, unless the build is summarized, in which case the error
+/// returned lets the other grammars be processed first.
+fn grammar_failed(session: &Session, reason: &str, conflicts: usize) -> io::Error {
+    if !session.summarize {
+        exit(1);
+    }
+    grammar_error(reason, conflicts)
+}
+
+/// The error that processing a grammar whose errors have been
+/// reported ends with, without exiting.
+fn grammar_error(reason: &str, conflicts: usize) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData,
+                   GrammarFailed { reason: reason.to_string(), conflicts: conflicts })
+}
+
 pub fn process_file<P: AsRef<Path>>(session: Rc<Session>, lalrpop_file: P) -> io::Result<()> {
-    let lalrpop_file = lalrpop_file.as_ref();
+    try!(process_file_status(session, lalrpop_file.as_ref()));
+    Ok(())
+}
+
+fn process_file_status(session: Rc<Session>, lalrpop_file: &Path) -> io::Result<Status> {
     let rs_file = try!(resolve_rs_file(&session, lalrpop_file));
     let report_file = try!(resolve_report_file(&session, lalrpop_file));
     process_file_into(session, lalrpop_file, &rs_file, &report_file)
 }
 
+/// Compares the grammars in `old_file` and `new_file`, printing the
+/// differences to stdout.
+pub fn diff_files(session: Rc<Session>, old_file: &Path, new_file: &Path) -> io::Result<()> {
+    let old = try!(load_grammar(&session, old_file));
+    let new = try!(load_grammar(&session, new_file));
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+    grammar_diff::diff_grammars(&mut stdout, &old, &new, grammar_diff::MAX_SENTENCE_LENGTH)
+}
+
+/// Steps through the conflicts of the grammar in `lalrpop_file`,
+/// reading commands from stdin.
+pub fn explain_conflicts(session: Rc<Session>, lalrpop_file: &Path) -> io::Result<()> {
+    let file_text = Rc::new(try!(read_file_text(lalrpop_file)));
+    let grammar = try!(parse_and_normalize_grammar(&session, &file_text));
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+    lr1::explain_conflicts(&session, stdin.lock(), &mut stdout, &grammar, &file_text)
+}
+
+/// Parses and normalizes the grammar in `lalrpop_file` and gathers
+/// its sets, states and conflicts, without generating any code. An
+/// error in the grammar is returned (as `InvalidData`) rather than
+/// reported.
+pub fn analyze_file(session: Rc<Session>, lalrpop_file: &Path) -> io::Result<lr1::GrammarAnalysis> {
+    // stopping at the first conflict would leave the others out
+    let mut session = (*session).clone();
+    session.report_all_conflicts = true;
+    let session = Rc::new(session);
+
+    let file_text = Rc::new(try!(read_file_text(lalrpop_file)));
+    match try_parse_and_normalize_grammar(&session, &file_text) {
+        Ok(grammar) => Ok(lr1::analyze_grammar(&session, &file_text, &grammar)),
+        Err((span, message)) => {
+            Err(io::Error::new(io::ErrorKind::InvalidData,
+                               format!("{} error: {}", file_text.span_str(span), message)))
+        }
+    }
+}
+
+/// Writes an HTML page to `out` that animates how the parser for
+/// `nonterminal` of the grammar in `lalrpop_file` parses `input`.
+/// Generates the parser for the grammar `text` in memory; see
+/// `lalrpop::process_str`.
+pub fn process_str(session: Rc<Session>, text: &str) -> Result<String, Diagnostics> {
+    let file_text = Rc::new(FileText::new(PathBuf::from("grammar.lalrpop"), text.to_string()));
+    let mut diagnostics = vec![];
+
+    if let Some(grammar) = check_grammar(&session, &file_text, &mut diagnostics) {
+        match generate_parser(&session, &file_text, &grammar, None, None, None, None) {
+            Ok(Ok((buffer, warnings))) => {
+                let warnings = with_denial(&session, &grammar, warnings);
+                if !warnings.iter().any(|&(_, denied)| denied) {
+                    let code = String::from_utf8(buffer).unwrap();
+                    return Ok(format!("{}\n{}", LALRPOP_VERSION_HEADER, code));
+                }
+                push_lint_diagnostics(&file_text, warnings, &mut diagnostics);
+            }
+            Ok(Err(conflict_messages)) => {
+                for message in conflict_messages {
+                    diagnostics.push(conflict_diagnostic(&file_text, message));
+                }
+            }
+            Err(error) => {
+                diagnostics.push(diagnostic(&file_text, Severity::Error, None, error.to_string()));
+            }
+        }
+    }
+
+    Err(Diagnostics { diagnostics: diagnostics })
+}
+
+/// Checks the grammar `text` in memory, including the conflicts of
+/// all of its parsers; see `lalrpop::check_str`.
+pub fn check_str(session: Rc<Session>, text: &str) -> Diagnostics {
+    let file_text = Rc::new(FileText::new(PathBuf::from("grammar.lalrpop"), text.to_string()));
+    let mut diagnostics = vec![];
+
+    if let Some(grammar) = check_grammar(&session, &file_text, &mut diagnostics) {
+        // Earley parsers have no states, and so no conflicts
+        if grammar.algorithm.codegen != r::LrCodeGeneration::Earley {
+            let _lr1_tls = lr1::Lr1Tls::install(grammar.terminals.clone());
+            let mut reduced = set();
+            let mut conflicts = false;
+            for &start_nt in grammar.start_nonterminals.values() {
+                match lr1::build_states(&session, &grammar, start_nt) {
+                    Ok(states) => {
+                        for state in &states {
+                            reduced.extend(state.reductions.iter().map(|&(_, p)| p));
+                            reduced.extend(state.two_token_reductions.iter().map(|r| r.production));
+                        }
+                    }
+                    Err(error) => {
+                        conflicts = true;
+                        for message in lr1::report_error(&session, &file_text, &grammar, &error) {
+                            diagnostics.push(conflict_diagnostic(&file_text, message));
+                        }
+                    }
+                }
+            }
+
+            // (the tables of a parser with conflicts are not built)
+            if !conflicts {
+                let warnings = lint::check_reductions(&grammar, &reduced);
+                push_lint_diagnostics(&file_text, with_denial(&session, &grammar, warnings),
+                                      &mut diagnostics);
+            }
+        }
+    }
+
+    Diagnostics { diagnostics: diagnostics }
+}
+
+fn push_lint_diagnostics(file_text: &FileText,
+                         warnings: Vec<(lint::Warning, bool)>,
+                         diagnostics: &mut Vec<Diagnostic>) {
+    for (warning, denied) in warnings {
+        let severity = if denied { Severity::Error } else { Severity::Warning };
+        let message = format!("{} [{}]", warning.message, warning.lint);
+        diagnostics.push(diagnostic(file_text, severity, warning.span, message));
+    }
+}
+
+/// Parses, normalizes and lints the grammar in `file_text`, adding
+/// the problems found to `diagnostics`. Returns the grammar, unless
+/// one of them is an error.
+fn check_grammar(session: &Session,
+                 file_text: &FileText,
+                 diagnostics: &mut Vec<Diagnostic>)
+                 -> Option<r::Grammar> {
+    let grammar = match try_parse_and_normalize_grammar(session, file_text) {
+        Ok(grammar) => grammar,
+        Err((span, message)) => {
+            diagnostics.push(diagnostic(file_text, Severity::Error, Some(span), message));
+            return None;
+        }
+    };
+
+    let warnings = check_lints(session, &grammar);
+    let mut failed = warnings.iter().any(|&(_, denied)| denied);
+    push_lint_diagnostics(file_text, warnings, diagnostics);
+    if grammar.start_nonterminals.is_empty() {
+        failed = true;
+        diagnostics.push(diagnostic(file_text,
+                                    Severity::Error,
+                                    None,
+                                    "no public symbols declared in grammar".to_string()));
+    }
+
+    if failed {
+        None
+    } else {
+        Some(grammar)
+    }
+}
+
+fn diagnostic(file_text: &FileText,
+              severity: Severity,
+              span: Option<pt::Span>,
+              message: String)
+              -> Diagnostic {
+    Diagnostic {
+        severity: severity,
+        span: span.map(|span| (span.0, span.1)),
+        location: span.map(|span| {
+            let (line, column) = file_text.line_col(span.0);
+            (line + 1, column + 1)
+        }),
+        message: message,
+    }
+}
+
+/// Renders the message about a conflict as text, without the citation
+/// of its span, which the `Diagnostic` records instead.
+fn conflict_diagnostic(file_text: &FileText, message: Message) -> Diagnostic {
+    let span = message.span();
+    let (heading, body) = message.into_heading_and_body();
+    let mut lines: Vec<String> = heading.emit_to_canvas(80)
+                                        .to_strings()
+                                        .iter()
+                                        .map(|row| row.to_string())
+                                        .collect();
+    lines.push(String::new());
+    lines.extend(body.emit_to_canvas(80).to_strings().iter().map(|row| row.to_string()));
+    let text: Vec<&str> = lines.iter().map(|line| line.trim_right()).collect();
+    diagnostic(file_text, Severity::Error, Some(span), text.join("\n"))
+}
+
+pub fn animate_parse<W: Write>(session: Rc<Session>,
+                               lalrpop_file: &Path,
+                               nonterminal: &str,
+                               input: &str,
+                               out: &mut W)
+                               -> io::Result<()> {
+    let grammar = try!(load_grammar(&session, lalrpop_file));
+    lr1::animate_parse(&session, out, &grammar, r::NonterminalString(intern(nonterminal)), input)
+}
+
+/// Parses the lines of stdin with the parser for `nonterminal` (or
+/// the only public nonterminal) of the grammar in `lalrpop_file`.
+pub fn repl(session: Rc<Session>, lalrpop_file: &Path, nonterminal: Option<&str>) -> io::Result<()> {
+    let grammar = try!(load_grammar(&session, lalrpop_file));
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+    lr1::repl(&session,
+              stdin.lock(),
+              &mut stdout,
+              &grammar,
+              nonterminal.map(|nt| r::NonterminalString(intern(nt))))
+}
+
+/// Writes a web page to `out` where the grammar in `lalrpop_file` can
+/// be tried out; see `lr1::write_playground`.
+pub fn playground<W: Write>(session: Rc<Session>,
+                            lalrpop_file: &Path,
+                            nonterminal: Option<&str>,
+                            out: &mut W)
+                            -> io::Result<()> {
+    let grammar = try!(load_grammar(&session, lalrpop_file));
+    lr1::write_playground(&session,
+                          out,
+                          &grammar,
+                          nonterminal.map(|nt| r::NonterminalString(intern(nt))))
+}
+
+fn load_grammar(session: &Session, lalrpop_file: &Path) -> io::Result<r::Grammar> {
+    let file_text = try!(read_file_text(lalrpop_file));
+    parse_and_normalize_grammar(session, &file_text)
+}
+
 fn resolve_rs_file(session: &Session, lalrpop_file: &Path) -> io::Result<PathBuf> {
     gen_resolve_file(session, lalrpop_file, "rs")
 }
@@ -54,6 +426,50 @@ fn resolve_report_file(session: &Session, lalrpop_file: &Path) -> io::Result<Pat
     gen_resolve_file(session, lalrpop_file, "report")
 }
 
+fn resolve_sets_file(session: &Session, lalrpop_file: &Path) -> io::Result<PathBuf> {
+    gen_resolve_file(session, lalrpop_file, "sets")
+}
+
+fn resolve_classes_file(session: &Session, lalrpop_file: &Path) -> io::Result<PathBuf> {
+    gen_resolve_file(session, lalrpop_file, "classes")
+}
+
+fn resolve_conflicts_file(session: &Session, lalrpop_file: &Path) -> io::Result<PathBuf> {
+    gen_resolve_file(session, lalrpop_file, "conflicts")
+}
+
+/// The baseline file is checked in along with the grammar, and so
+/// lives next to it rather than in the output directory.
+fn resolve_baseline_file(lalrpop_file: &Path) -> PathBuf {
+    lalrpop_file.with_extension("conflict-baseline")
+}
+
+/// Like the baseline file, the profile is checked in along with the
+/// grammar.
+fn resolve_profile_file(lalrpop_file: &Path) -> PathBuf {
+    lalrpop_file.with_extension("profile")
+}
+
+fn resolve_doc_file(session: &Session, lalrpop_file: &Path) -> io::Result<PathBuf> {
+    gen_resolve_file(session, lalrpop_file, "md")
+}
+
+fn resolve_stats_file(session: &Session, lalrpop_file: &Path) -> io::Result<PathBuf> {
+    gen_resolve_file(session, lalrpop_file, "stats")
+}
+
+fn resolve_operators_file(session: &Session, lalrpop_file: &Path) -> io::Result<PathBuf> {
+    gen_resolve_file(session, lalrpop_file, "operators.md")
+}
+
+fn resolve_bench_file(session: &Session, lalrpop_file: &Path) -> io::Result<PathBuf> {
+    gen_resolve_file(session, lalrpop_file, "bench.rs")
+}
+
+fn resolve_header_file(session: &Session, lalrpop_file: &Path) -> io::Result<PathBuf> {
+    gen_resolve_file(session, lalrpop_file, "h")
+}
+
 fn gen_resolve_file(session: &Session, lalrpop_file: &Path, ext: &str) -> io::Result<PathBuf> {
     let in_dir = if let Some(ref d) = session.in_dir {
         d.as_path()
@@ -73,7 +489,7 @@ fn gen_resolve_file(session: &Session, lalrpop_file: &Path, ext: &str) -> io::Re
               .with_extension(ext))
 }
 
-fn process_file_into(session: Rc<Session>, lalrpop_file: &Path, rs_file: &Path, report_file: &Path) -> io::Result<()> {
+fn process_file_into(session: Rc<Session>, lalrpop_file: &Path, rs_file: &Path, report_file: &Path) -> io::Result<Status> {
     if session.force_build || try!(needs_rebuild(&lalrpop_file, &rs_file)) {
         log!(session,
              Informative,
@@ -86,14 +502,22 @@ fn process_file_into(session: Rc<Session>, lalrpop_file: &Path, rs_file: &Path,
         try!(remove_old_file(&rs_file));
 
         // Load the LALRPOP source text for this file:
-        let file_text = Rc::new(try!(FileText::from_path(lalrpop_file.to_path_buf())));
+        let file_text = Rc::new(try!(read_file_text(lalrpop_file)));
+
+        // Token modules are only there to be imported by other
+        // grammars.
+        if is_token_module(&file_text) {
+            log!(session,
+                 Informative,
+                 "skipping token module `{}`",
+                 lalrpop_file.to_string_lossy());
+            return Ok(Status::TokenModule);
+        }
 
-        // Store the session and file-text in TLS -- this is not
-        // intended to be used in this high-level code, but it gives
-        // easy access to this information pervasively in the
-        // low-level LR(1) and grammar normalization code. This is
-        // particularly useful for error-reporting.
-        let _tls = Tls::install(session.clone(), file_text.clone());
+        // Everything below takes the session explicitly; it is also
+        // stored in TLS only for the debug output of the message
+        // rendering code, which has no session of its own.
+        let _tls = Tls::install(session.clone());
 
         // Do the LALRPOP processing itself and write the resulting
         // buffer into a file. We use a buffer so that if LR(1)
@@ -101,15 +525,99 @@ fn process_file_into(session: Rc<Session>, lalrpop_file: &Path, rs_file: &Path,
         // file behind.
         {
             let grammar = try!(parse_and_normalize_grammar(&session, &file_text));
-            let buffer = try!(emit_recursive_ascent(&session, &grammar, &report_file));
+            if session.emit_sets {
+                let sets_file = try!(resolve_sets_file(&session, lalrpop_file));
+                try!(emit_sets(&grammar, &sets_file));
+            }
+            if session.emit_classes {
+                let classes_file = try!(resolve_classes_file(&session, lalrpop_file));
+                try!(emit_classes(&session, &grammar, &classes_file));
+            }
+            if session.emit_doc {
+                let doc_file = try!(resolve_doc_file(&session, lalrpop_file));
+                try!(emit_doc(&file_text, &grammar, lalrpop_file, &doc_file));
+            }
+            if session.emit_stats {
+                let stats_file = try!(resolve_stats_file(&session, lalrpop_file));
+                try!(emit_stats(&session, &grammar, &stats_file));
+            }
+            if session.emit_operators {
+                let operators_file = try!(resolve_operators_file(&session, lalrpop_file));
+                try!(emit_operators(&grammar, lalrpop_file, &operators_file));
+            }
+            if session.emit_bench {
+                let bench_file = try!(resolve_bench_file(&session, lalrpop_file));
+                try!(emit_bench(&session, &grammar, lalrpop_file, &bench_file));
+            }
+            let conflicts_file = if session.emit_conflicts {
+                Some(try!(resolve_conflicts_file(&session, lalrpop_file)))
+            } else {
+                None
+            };
+            let baseline_file = if session.conflict_baseline != ConflictBaseline::Ignore {
+                Some(resolve_baseline_file(lalrpop_file))
+            } else {
+                None
+            };
+            // an instrumented parser is laid out as usual, so that
+            // its profile refers to the usual states
+            let profile_file = if session.use_profile && !session.emit_profile {
+                Some(resolve_profile_file(lalrpop_file))
+            } else {
+                None
+            };
+            let (mut buffer, warnings) =
+                try!(emit_recursive_ascent(&session,
+                                           &file_text,
+                                           &grammar,
+                                           report_file,
+                                           conflicts_file.as_ref().map(|f| f.as_path()),
+                                           baseline_file.as_ref().map(|f| f.as_path()),
+                                           profile_file.as_ref().map(|f| f.as_path())));
+            try!(report_lint_warnings(&session, &file_text, &grammar, warnings));
+            if session.emit_c_abi {
+                let header_file = try!(resolve_header_file(&session, lalrpop_file));
+                try!(emit_c_abi(&session, &grammar, lalrpop_file, &header_file, &mut buffer));
+            }
             let mut output_file = try!(fs::File::create(&rs_file));
             try!(writeln!(output_file, "{}", LALRPOP_VERSION_HEADER));
             try!(output_file.write_all(&buffer));
         }
 
         try!(make_read_only(&rs_file, true));
+        Ok(Status::Generated)
+    } else {
+        Ok(Status::UpToDate)
+    }
+}
+
+/// Reads the fingerprints in the baseline file `path`, which allows
+/// no conflicts if it does not exist.
+fn read_baseline(path: &Path) -> io::Result<Set<String>> {
+    let mut fingerprints = set();
+    let mut text = String::new();
+    match fs::File::open(path) {
+        Ok(mut file) => {
+            try!(file.read_to_string(&mut text));
+        }
+        Err(ref error) if error.kind() == io::ErrorKind::NotFound => {}
+        Err(error) => return Err(error),
+    }
+    lr1::parse_baseline(&text, &mut fingerprints);
+    Ok(fingerprints)
+}
+
+/// Reads the profile `path`, if it exists.
+fn read_profile(path: &Path) -> io::Result<Option<String>> {
+    let mut text = String::new();
+    match fs::File::open(path) {
+        Ok(mut file) => {
+            try!(file.read_to_string(&mut text));
+            Ok(Some(text))
+        }
+        Err(ref error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error),
     }
-    Ok(())
 }
 
 fn remove_old_file(rs_file: &Path) -> io::Result<()> {
@@ -128,9 +636,13 @@ fn remove_old_file(rs_file: &Path) -> io::Result<()> {
 fn needs_rebuild(lalrpop_file: &Path, rs_file: &Path) -> io::Result<bool> {
     return match fs::metadata(&rs_file) {
         Ok(rs_metadata) => {
-            let lalrpop_metadata = try!(fs::metadata(&lalrpop_file));
-            if compare_modification_times(&lalrpop_metadata, &rs_metadata) {
-                return Ok(true);
+            // changes to the grammars that this one extends count too
+            let file_text = try!(read_file_text(lalrpop_file));
+            for (path, _, _) in file_text.files() {
+                let lalrpop_metadata = try!(fs::metadata(path));
+                if compare_modification_times(&lalrpop_metadata, &rs_metadata) {
+                    return Ok(true);
+                }
             }
 
             compare_lalrpop_version(rs_file)
@@ -207,36 +719,211 @@ fn lalrpop_files<P: AsRef<Path>>(root_dir: P) -> io::Result<Vec<PathBuf>> {
 }
 
 fn parse_and_normalize_grammar(session: &Session, file_text: &FileText) -> io::Result<r::Grammar> {
-    let grammar = match parser::parse_grammar(file_text.text()) {
-        Ok(grammar) => grammar,
+    match try_parse_and_normalize_grammar(session, file_text) {
+        Ok(grammar) => {
+            try!(report_warnings(session, file_text, &grammar));
+            Ok(grammar)
+        }
+        Err((span, message)) => {
+            report_error(&file_text, span, &message);
+            Err(grammar_failed(session, "grammar error", 0))
+        }
+    }
+}
+
+/// Like `parse_and_normalize_grammar`, but returns the span and
+/// message of an error in the grammar rather than reporting it and
+/// exiting, and doesn't report warnings.
+fn try_parse_and_normalize_grammar(session: &Session,
+                                   file_text: &FileText)
+                                   -> Result<r::Grammar, (pt::Span, String)> {
+    // parse the grammar and the files it refers to, which
+    // `read_file_text` appended to it, and combine them
+    let files = file_text.files();
+    let mut grammars = vec![];
+    for &(_, text, offset) in &files {
+        grammars.push(try!(parse_grammar(file_text, text, offset)));
+    }
+    let mut grammar = try!(combine_grammar(&files, &grammars, 0, &mut vec![]));
+    if let Some(ref prefix) = session.prefix {
+        grammar.prefix = unique_prefix(prefix, &files);
+    }
+
+    normalize::normalize(session, grammar).map_err(|error| (error.span, error.message))
+}
+
+/// Lengthens `prefix` with underscores until it appears in none of
+/// `files`, as `parser::parse_grammar` does with the default prefix.
+fn unique_prefix(prefix: &str, files: &[(&Path, &str, usize)]) -> String {
+    let mut prefix = prefix.to_string();
+    while files.iter().any(|&(_, text, _)| text.contains(&prefix)) {
+        prefix.push('_');
+    }
+    prefix
+}
+
+/// Combines the grammar in `files[index]` with the token module it
+/// imports and the grammar it extends, if any. `extending` holds the
+/// indices of the grammars that extend this one, to catch cycles.
+fn combine_grammar(files: &[(&Path, &str, usize)],
+                   grammars: &[pt::Grammar],
+                   index: usize,
+                   extending: &mut Vec<usize>)
+                   -> Result<pt::Grammar, (pt::Span, String)> {
+    let path = files[index].0;
+    let mut grammar = grammars[index].clone();
+
+    for item in &mut grammar.items {
+        if let pt::GrammarItem::MatchToken(ref mut match_token) = *item {
+            if let Some(module) = match_token.module.clone() {
+                if let Some(annotation) = match_token.annotations.first() {
+                    return Err((annotation.id_span,
+                                format!("annotations of an imported `match` section go on \
+                                         the section, in `{}`",
+                                        module)));
+                }
+                let module_index = try!(file_index(files, path, &module, match_token.span));
+                let module_grammar = &grammars[module_index];
+                if !module_grammar.is_token_module() {
+                    return Err((match_token.span,
+                                format!("`{}` is not a token module, which has only a \
+                                         `match` section",
+                                        files[module_index].0.display())));
+                }
+                *match_token = module_grammar.match_token().unwrap().clone();
+            }
+        }
+    }
+
+    if let Some(extends) = grammar.extends.clone() {
+        let base_index = try!(file_index(files, path, &extends.path, extends.span));
+        if base_index == index || extending.contains(&base_index) {
+            return Err((extends.span,
+                        format!("`{}` extends itself", files[base_index].0.display())));
+        }
+        extending.push(index);
+        let base = try!(combine_grammar(files, grammars, base_index, extending));
+        extending.pop();
+        grammar = try!(normalize::stitch(base, grammar)
+                           .map_err(|error| (error.span, error.message)));
+    }
+
+    Ok(grammar)
+}
+
+/// The index in `files` of the file at `relative_path` from the
+/// grammar in `path`, which `read_file_text` appended unless it could
+/// not read it.
+fn file_index(files: &[(&Path, &str, usize)],
+              path: &Path,
+              relative_path: &str,
+              span: pt::Span)
+              -> Result<usize, (pt::Span, String)> {
+    let file = referenced_file(path, relative_path);
+    match files.iter().position(|&(path, _, _)| path == file) {
+        Some(index) => Ok(index),
+        None => {
+            let error = fs::File::open(&file).err()
+                                             .map(|e| e.to_string())
+                                             .unwrap_or(String::new());
+            Err((span, format!("cannot read `{}`: {}", file.display(), error)))
+        }
+    }
+}
+
+/// Reads `lalrpop_file`, appending the text of the files that it
+/// refers to: the grammar that it extends and the token module that
+/// it imports, then the files that those refer to, and so on. Skips
+/// the files that cannot be read or parsed, leaving it to
+/// `try_parse_and_normalize_grammar` to report that.
+fn read_file_text(lalrpop_file: &Path) -> io::Result<FileText> {
+    let mut file_text = try!(FileText::from_path(lalrpop_file.to_path_buf()));
+    let mut index = 0;
+    while index < file_text.files().len() {
+        let referenced_files: Vec<PathBuf> = {
+            let files = file_text.files();
+            let (path, text, offset) = files[index];
+            match parser::parse_grammar_at(text, offset) {
+                Ok(grammar) => {
+                    grammar.extends
+                           .iter()
+                           .map(|extends| &extends.path)
+                           .chain(grammar.items
+                                         .iter()
+                                         .filter_map(|item| item.as_match_token())
+                                         .filter_map(|match_token| match_token.module.as_ref()))
+                           .map(|relative_path| referenced_file(path, relative_path))
+                           .collect()
+                }
+                Err(_) => vec![],
+            }
+        };
+        for file in referenced_files {
+            if file_text.files().iter().any(|&(path, _, _)| path == file) {
+                continue;
+            }
+            let mut text = String::new();
+            if fs::File::open(&file).and_then(|mut f| f.read_to_string(&mut text)).is_ok() {
+                file_text.append(file, &text);
+            }
+        }
+        index += 1;
+    }
+    Ok(file_text)
+}
+
+/// True if the file read into `file_text` is a token module (see
+/// `pt::Grammar::is_token_module`).
+fn is_token_module(file_text: &FileText) -> bool {
+    let (_, text, offset) = file_text.files()[0];
+    parser::parse_grammar_at(text, offset).map(|grammar| grammar.is_token_module())
+                                           .unwrap_or(false)
+}
+
+/// The path of the file at `relative_path` from the grammar in `path`.
+fn referenced_file(path: &Path, relative_path: &str) -> PathBuf {
+    path.parent().unwrap_or(Path::new("")).join(relative_path)
+}
+
+/// Parses `text`, one of the files in `file_text` (starting at
+/// `offset`).
+fn parse_grammar(file_text: &FileText,
+                 text: &str,
+                 offset: usize)
+                 -> Result<pt::Grammar, (pt::Span, String)> {
+    match parser::parse_grammar_at(text, offset) {
+        Ok(grammar) => Ok(grammar),
 
         Err(ParseError::InvalidToken { location }) => {
             let ch = file_text.text()[location..].chars().next().unwrap();
-            report_error(&file_text,
-                         pt::Span(location, location),
-                         &format!("invalid character `{}`", ch));
+            return Err((pt::Span(location, location),
+                        format!("invalid character `{}`", ch)));
         }
 
-        Err(ParseError::UnrecognizedToken { token: None, expected: _ }) => {
-            let len = file_text.text().len();
-            report_error(&file_text,
-                         pt::Span(len, len),
-                         &format!("unexpected end of file"));
+        Err(ParseError::UnrecognizedToken { token: None, expected: _ }) |
+        Err(ParseError::UnrecognizedEof { .. }) => {
+            let end = offset + text.len();
+            return Err((pt::Span(end, end),
+                        format!("unexpected end of file")));
         }
 
         Err(ParseError::UnrecognizedToken { token: Some((lo, _, hi)), expected }) => {
             let _ = expected; // didn't implement this yet :)
             let text = &file_text.text()[lo..hi];
-            report_error(&file_text,
-                         pt::Span(lo, hi),
-                         &format!("unexpected token: `{}`", text));
+            return Err((pt::Span(lo, hi),
+                        format!("unexpected token: `{}`", text)));
         }
 
-        Err(ParseError::ExtraToken { token: (lo, _, hi) }) => {
+        Err(ParseError::ExtraToken { token: (lo, _, hi), .. }) => {
             let text = &file_text.text()[lo..hi];
-            report_error(&file_text,
-                         pt::Span(lo, hi),
-                         &format!("extra token at end of input: `{}`", text));
+            return Err((pt::Span(lo, hi),
+                        format!("extra token at end of input: `{}`", text)));
+        }
+
+        Err(ParseError::ReservedWord { token: (lo, _, hi), description, .. }) => {
+            let text = &file_text.text()[lo..hi];
+            return Err((pt::Span(lo, hi),
+                        format!("expected {}, found keyword `{}`", description, text)));
         }
 
         Err(ParseError::User { error }) => {
@@ -258,43 +945,142 @@ fn parse_and_normalize_grammar(session: &Session, file_text: &FileText) -> io::R
                 }
             };
 
-            report_error(&file_text,
-                         pt::Span(error.location, error.location + 1),
-                         string)
+            return Err((pt::Span(error.location, error.location + 1),
+                        string.to_string()));
         }
-    };
 
-    match normalize::normalize(session, grammar) {
-        Ok(grammar) => Ok(grammar),
-        Err(error) => report_error(&file_text, error.span, &error.message),
+        Err(ParseError::DepthLimit { location, depth: _ }) => {
+            return Err((pt::Span(location, location),
+                        format!("grammar is nested too deeply")));
+        }
+
+        Err(ParseError::StackCapacity { location, capacity: _ }) => {
+            return Err((pt::Span(location, location),
+                        format!("grammar does not fit on the parse stack")));
+        }
+
+        Err(ParseError::ReductionLimit { location, limit: _ }) => {
+            return Err((pt::Span(location, location),
+                        format!("grammar needs too many reductions")));
+        }
+
+        Err(ParseError::Canceled { location }) => {
+            return Err((pt::Span(location, location),
+                        format!("parse canceled")));
+        }
+
+        Err(ParseError::GuardFailed { start, end }) => {
+            return Err((pt::Span(start, end),
+                        format!("rejected by guard")));
+        }
+
+        Err(ParseError::VersionTooOld { start, end, since }) => {
+            return Err((pt::Span(start, end),
+                        format!("requires version {} or later", since)));
+        }
+
+        Err(ParseError::Internal { message }) => {
+            return Err((pt::Span(0, 0),
+                        format!("internal parser error: {}", message)));
+        }
+
+        Err(ParseError::__Nonexhaustive) => unreachable!(),
+    }
+}
+
+/// Runs the lints on `grammar`, pairing each warning with whether it
+/// is denied, either by the grammar or by `session.deny_warnings`.
+fn check_lints(session: &Session, grammar: &r::Grammar) -> Vec<(lint::Warning, bool)> {
+    with_denial(session, grammar, lint::check(grammar))
+}
+
+fn with_denial(session: &Session,
+               grammar: &r::Grammar,
+               warnings: Vec<lint::Warning>)
+               -> Vec<(lint::Warning, bool)> {
+    warnings.into_iter()
+            .map(|warning| {
+                let denied = session.deny_warnings ||
+                             lint::level(grammar, warning.lint) == LintLevel::Deny;
+                (warning, denied)
+            })
+            .collect()
+}
+
+/// Reports the lints that fire for `grammar`. Returns an error if any
+/// of them is denied.
+fn report_warnings(session: &Session,
+                   file_text: &FileText,
+                   grammar: &r::Grammar)
+                   -> io::Result<()> {
+    report_lint_warnings(session, file_text, grammar, lint::check(grammar))
+}
+
+/// Reports `warnings`, of the lints on `grammar`. Returns an error if
+/// any of them is denied, as `check_str` does, rather than exiting: a
+/// build script can then decide what to do about it.
+fn report_lint_warnings(session: &Session,
+                        file_text: &FileText,
+                        grammar: &r::Grammar,
+                        warnings: Vec<lint::Warning>)
+                        -> io::Result<()> {
+    let mut denied = false;
+    for (warning, deny) in with_denial(session, grammar, warnings) {
+        denied |= deny;
+        let kind = if deny { "error" } else { "warning" };
+
+        let location = match warning.span {
+            Some(span) => file_text.span_str(span),
+            None => format!("{}:", file_text.path().display()),
+        };
+        println!("{} {}: {} [{}]", location, kind, warning.message, warning.lint);
+
+        if let Some(span) = warning.span {
+            let out = io::stderr();
+            let mut out = out.lock();
+            file_text.highlight(span, &mut out).unwrap();
+        }
     }
+
+    if denied {
+        return Err(grammar_error("denied warnings", 0));
+    }
+    Ok(())
 }
 
-fn report_error(file_text: &FileText, span: pt::Span, message: &str) -> ! {
+fn report_error(file_text: &FileText, span: pt::Span, message: &str) {
     println!("{} error: {}", file_text.span_str(span), message);
 
     let out = io::stderr();
     let mut out = out.lock();
     file_text.highlight(span, &mut out).unwrap();
+}
 
-    exit(1);
+/// Reports a warning about the build as a whole. From a build script,
+/// it goes to Cargo, which shows it even when the build succeeds.
+fn report_build_warning(session: &Session, message: &str) {
+    if env::var_os("OUT_DIR").is_some() && env::var_os("TARGET").is_some() {
+        println!("cargo:warning={}", message);
+    } else {
+        log!(session, Informative, "warning: {}", message);
+    }
 }
 
-fn report_messages(messages: Vec<Message>) -> term::Result<()> {
+fn report_messages(session: &Session, messages: Vec<Message>) -> term::Result<()> {
     let builder = InlineBuilder::new().begin_paragraphs();
     let builder = messages.into_iter().fold(builder, |b, m| b.push(Box::new(m)));
     let content = builder.end().end();
-    report_content(&*content)
+    report_content(session, &*content)
 }
 
-fn report_content(content: &Content) -> term::Result<()> {
+fn report_content(session: &Session, content: &Content) -> term::Result<()> {
     // FIXME -- can we query the size of the terminal somehow?
     let canvas = content.emit_to_canvas(80);
 
-    let try_colors = match Tls::session().color_config {
+    let try_colors = match session.color_config {
         ColorConfig::Yes => true,
         ColorConfig::No => false,
-        ColorConfig::IfTty => atty::is(),
+        ColorConfig::IfTty => stdout_is_tty(),
     };
 
     if try_colors {
@@ -308,16 +1094,190 @@ fn report_content(content: &Content) -> term::Result<()> {
     canvas.write_to(&mut stdout)
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+fn stdout_is_tty() -> bool {
+    ::atty::is()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn stdout_is_tty() -> bool {
+    false
+}
+
 fn emit_module_attributes<W: Write>(grammar: &r::Grammar, rust: &mut RustWrite<W>) -> io::Result<()> {
     rust.write_module_attributes(grammar)
 }
 
+fn emit_cfg_attributes<W: Write>(session: &Session,
+                                 grammar: &r::Grammar,
+                                 rust: &mut RustWrite<W>)
+                                 -> io::Result<()> {
+    if let Some(ref predicate) = session.cfg {
+        rust!(rust, "#![cfg({})]", predicate);
+    }
+
+    // if some parsers may be compiled out, the action code they use
+    // will go unused
+    let gated = grammar.start_nonterminals
+                       .keys()
+                       .any(|nt| parser_cfg(session, *nt).is_some());
+    if gated {
+        rust!(rust, "#![allow(dead_code)]");
+    }
+
+    Ok(())
+}
+
+fn parser_cfg(session: &Session, user_nt: r::NonterminalString) -> Option<&String> {
+    session.parser_cfgs.get(&user_nt.to_string())
+}
+
 fn emit_uses<W: Write>(grammar: &r::Grammar, rust: &mut RustWrite<W>) -> io::Result<()> {
     rust.write_uses("", grammar)
 }
 
 
-fn emit_recursive_ascent(session: &Session, grammar: &r::Grammar, report_file : &Path) -> io::Result<Vec<u8>> {
+fn emit_sets(grammar: &r::Grammar, sets_file: &Path) -> io::Result<()> {
+    let _lr1_tls = lr1::Lr1Tls::install(grammar.terminals.clone());
+    let mut output_sets_file = try!(fs::File::create(sets_file));
+    lr1::generate_sets(&mut output_sets_file, grammar)
+}
+
+fn emit_classes(session: &Session, grammar: &r::Grammar, classes_file: &Path) -> io::Result<()> {
+    let _lr1_tls = lr1::Lr1Tls::install(grammar.terminals.clone());
+    let mut output_classes_file = try!(fs::File::create(classes_file));
+    lr1::generate_classes(session, &mut output_classes_file, grammar)
+}
+
+fn emit_doc(file_text: &FileText,
+            grammar: &r::Grammar,
+            lalrpop_file: &Path,
+            doc_file: &Path)
+            -> io::Result<()> {
+    // the reference shows the grammar as written, which we already
+    // parsed successfully once
+    let parse_tree = parser::parse_grammar(file_text.text()).unwrap();
+    let title = lalrpop_file.file_stem().unwrap().to_string_lossy();
+    let mut output_doc_file = try!(fs::File::create(doc_file));
+    grammar_doc::generate_doc(&mut output_doc_file, &title, file_text.text(), &parse_tree, grammar)
+}
+
+fn emit_stats(session: &Session, grammar: &r::Grammar, stats_file: &Path) -> io::Result<()> {
+    let _lr1_tls = lr1::Lr1Tls::install(grammar.terminals.clone());
+    let mut output_stats_file = try!(fs::File::create(stats_file));
+    lr1::generate_stats(session, &mut output_stats_file, grammar)
+}
+
+fn emit_operators(grammar: &r::Grammar,
+                  lalrpop_file: &Path,
+                  operators_file: &Path)
+                  -> io::Result<()> {
+    let title = lalrpop_file.file_stem().unwrap().to_string_lossy();
+    let mut output_operators_file = try!(fs::File::create(operators_file));
+    operator_table::generate_operators(&mut output_operators_file, &title, grammar)
+}
+
+fn emit_bench(session: &Session,
+              grammar: &r::Grammar,
+              lalrpop_file: &Path,
+              bench_file: &Path)
+              -> io::Result<()> {
+    // When run from a build script, cargo tells us the crate name;
+    // otherwise, guess that we are being run from the crate root.
+    let crate_name = env::var("CARGO_PKG_NAME")
+        .ok()
+        .or_else(|| {
+            env::current_dir()
+                .ok()
+                .and_then(|dir| dir.file_name().map(|name| name.to_string_lossy().into_owned()))
+        })
+        .unwrap_or_else(|| String::from("parser"))
+        .replace("-", "_");
+
+    // The module path mirrors the path of the grammar within the
+    // input directory, as is the convention for generated parsers.
+    let in_dir = session.in_dir.as_ref().map(|d| d.as_path()).unwrap_or(Path::new("."));
+    let relative = lalrpop_file.strip_prefix(in_dir).unwrap_or(lalrpop_file).with_extension("");
+    let mut module_path: Vec<String> = relative.components()
+                                               .map(|c| c.as_os_str().to_string_lossy().into_owned())
+                                               .filter(|c| c != ".")
+                                               .collect();
+    if module_path.len() > 1 && module_path[0] == "src" {
+        module_path.remove(0);
+    }
+    let module_path = module_path.join("::");
+    let corpus_dir = format!("benches/corpus/{}", module_path.replace("::", "/"));
+
+    let target = bench::BenchTarget {
+        crate_name: &crate_name,
+        module_path: &module_path,
+        corpus_dir: &corpus_dir,
+    };
+    let mut rust = RustWrite::new(try!(fs::File::create(bench_file)));
+    bench::generate_bench(&mut rust, grammar, &target)
+}
+
+/// Appends the C entry points of the parsers to `buffer`, the
+/// generated module, and writes the header declaring them.
+fn emit_c_abi(session: &Session,
+              grammar: &r::Grammar,
+              lalrpop_file: &Path,
+              header_file: &Path,
+              buffer: &mut Vec<u8>)
+              -> io::Result<()> {
+    let prefix = c_abi::symbol_prefix(&lalrpop_file.file_stem().unwrap().to_string_lossy());
+    let file_name = lalrpop_file.file_name().unwrap().to_string_lossy();
+    {
+        let mut rust = RustWrite::new(&mut *buffer);
+        try!(c_abi::generate_entry_points(&mut rust, session, grammar, &prefix));
+    }
+    let mut output_header_file = try!(fs::File::create(header_file));
+    c_abi::generate_header(&mut output_header_file, session, grammar, &prefix, &file_name)
+}
+
+fn emit_recursive_ascent(session: &Session,
+                         file_text: &FileText,
+                         grammar: &r::Grammar,
+                         report_file: &Path,
+                         conflicts_file: Option<&Path>,
+                         baseline_file: Option<&Path>,
+                         profile_file: Option<&Path>)
+                         -> io::Result<(Vec<u8>, Vec<lint::Warning>)> {
+    if grammar.start_nonterminals.is_empty() {
+        println!("Error: no public symbols declared in grammar");
+        return Err(grammar_failed(session, "no public symbols", 0));
+    }
+
+    match try!(generate_parser(session,
+                               file_text,
+                               grammar,
+                               Some(report_file),
+                               conflicts_file,
+                               baseline_file,
+                               profile_file)) {
+        Ok(generated) => Ok(generated),
+        Err(conflict_messages) => {
+            let conflicts = conflict_messages.len();
+            let _ = report_messages(session, conflict_messages);
+            Err(grammar_failed(session, "conflicts", conflicts))
+        }
+    }
+}
+
+/// Generates the parsers for the public nonterminals of `grammar`,
+/// along with the warnings of the lints that look at their tables, or
+/// returns the messages about the conflicts that prevent it.
+/// Conflicts listed in `baseline_file` don't, if the session checks
+/// it. The table-driven parsers are laid out by `profile_file`, if it
+/// exists.
+fn generate_parser(session: &Session,
+                   file_text: &FileText,
+                   grammar: &r::Grammar,
+                   report_file: Option<&Path>,
+                   conflicts_file: Option<&Path>,
+                   baseline_file: Option<&Path>,
+                   profile_file: Option<&Path>)
+                   -> io::Result<Result<(Vec<u8>, Vec<lint::Warning>), Vec<Message>>> {
     let mut rust = RustWrite::new(vec![]);
 
     // We generate a module structure like this:
@@ -325,8 +1285,12 @@ fn emit_recursive_ascent(session: &Session, grammar: &r::Grammar, report_file :
     // ```
     // mod <output-file> {
     //     // For each public symbol:
-    //     pub fn parse_XYZ();
-    //     mod __XYZ { ... }
+    //     pub use self::__parse_table::parse_XYZ;
+    //
+    //     // The tables and parse loop of all the public symbols,
+    //     // and their `parse_XYZ` fns (for the other kinds of parser,
+    //     // there is a module `__parse__XYZ` for each public symbol):
+    //     mod __parse_table { ... }
     //
     //     // For each bit of action code:
     //     <action-code>
@@ -340,12 +1304,39 @@ fn emit_recursive_ascent(session: &Session, grammar: &r::Grammar, report_file :
     // way.
 
     try!(emit_module_attributes(grammar, &mut rust));
+    try!(emit_cfg_attributes(session, grammar, &mut rust));
     try!(emit_uses(grammar, &mut rust));
 
-    if grammar.start_nonterminals.is_empty() {
-        println!("Error: no public symbols declared in grammar");
-        exit(1);
-    }
+    // messages about the conflicts found so far; unless the session
+    // asks for all of them, we stop at the first nonterminal that has
+    // any
+    let mut conflict_messages = vec![];
+
+    // the entries of the `.conflicts` file, if we are writing one
+    let mut conflict_entries = vec![];
+
+    // the productions that the states of the parsers reduce
+    let mut reduced = set();
+
+    // the fingerprints of the conflicts that the baseline allows, if
+    // we are checking it, and of those found so far
+    let baseline = match baseline_file {
+        Some(path) if session.conflict_baseline == ConflictBaseline::Check =>
+            Some(try!(read_baseline(path))),
+        _ => None,
+    };
+    let updating_baseline = baseline_file.is_some() &&
+                            session.conflict_baseline == ConflictBaseline::Update;
+    let mut fingerprints = set();
+
+    // the text of the profile that the tables are laid out by, if any
+    let profile_text = match profile_file {
+        Some(path) => try!(read_profile(path)),
+        None => None,
+    };
+
+    // the states of the table-driven parsers, which share one module
+    let mut table_parsers = vec![];
 
     for (&user_nt, &start_nt) in &grammar.start_nonterminals {
         // We generate these, so there should always be exactly 1
@@ -353,6 +1344,41 @@ fn emit_recursive_ascent(session: &Session, grammar: &r::Grammar, report_file :
         // where to stop!
         assert_eq!(grammar.productions_for(start_nt).len(), 1);
 
+        let cfg = parser_cfg(session, user_nt);
+
+        // Earley parsers interpret the grammar itself, so there are
+        // no states to build
+        if grammar.algorithm.codegen == r::LrCodeGeneration::Earley {
+            if let Some(predicate) = cfg {
+                rust!(rust, "#[cfg({})]", predicate);
+            }
+            try!(lr1::codegen::earley::compile(session,
+                                               &grammar,
+                                               user_nt,
+                                               start_nt,
+                                               "super",
+                                               &mut rust));
+            if let Some(predicate) = cfg {
+                rust!(rust, "#[cfg({})]", predicate);
+            }
+            rust!(rust,
+                  "pub use self::{}parse{}::parse_{};",
+                  grammar.prefix,
+                  start_nt,
+                  user_nt);
+            if grammar.intern_token.is_some() {
+                if let Some(predicate) = cfg {
+                    rust!(rust, "#[cfg({})]", predicate);
+                }
+                rust!(rust,
+                      "pub use self::{}parse{}::parse_{}_all;",
+                      grammar.prefix,
+                      start_nt,
+                      user_nt);
+            }
+            continue;
+        }
+
         log!(session,
              Verbose,
              "Building states for public nonterminal `{}`",
@@ -360,58 +1386,336 @@ fn emit_recursive_ascent(session: &Session, grammar: &r::Grammar, report_file :
 
         let _lr1_tls = lr1::Lr1Tls::install(grammar.terminals.clone());
 
-        let lr1result = lr1::build_states(&grammar, start_nt);
-        if session.emit_report {
-            let mut output_report_file = try!(fs::File::create(&report_file));
-            try!(lr1::generate_report(&mut output_report_file, &lr1result));
+        let lr1result = lr1::build_states(session, &grammar, start_nt);
+        if let Some(report_file) = report_file {
+            if session.emit_report {
+                let mut output_report_file = try!(fs::File::create(report_file));
+                try!(lr1::generate_report(&mut output_report_file, &lr1result));
+            }
         }
 
         let states = match lr1result {
             Ok(states) => states,
             Err(error) => {
-                let messages = lr1::report_error(&grammar, &error);
-                let _ = report_messages(messages);
-                exit(1) // FIXME -- propagate up instead of calling `exit`
+                if conflicts_file.is_some() {
+                    lr1::dump_conflicts(session, file_text, &grammar, user_nt, &error,
+                                        &mut conflict_entries);
+                }
+
+                // leave out the conflicts that the baseline allows,
+                // taking them from all of the states, as the parser is
+                // generated from those
+                let error = if session.conflict_baseline == ConflictBaseline::Ignore {
+                    error
+                } else {
+                    let all = match lr1::build_all_states(session, &grammar, start_nt) {
+                        Ok(states) => lr1::LR1TableConstructionError {
+                            states: states,
+                            conflicts: vec![],
+                        },
+                        Err(all) => all,
+                    };
+                    let mut new_conflicts = vec![];
+                    for conflict in &all.conflicts {
+                        let conflict_fingerprints = lr1::conflict_fingerprints(user_nt, conflict);
+                        let known = match baseline {
+                            Some(ref baseline) =>
+                                conflict_fingerprints.iter().all(|f| baseline.contains(f)),
+                            None => updating_baseline,
+                        };
+                        if !known {
+                            new_conflicts.push(conflict.clone());
+                        }
+                        fingerprints.extend(conflict_fingerprints);
+                    }
+                    lr1::LR1TableConstructionError {
+                        states: all.states,
+                        conflicts: new_conflicts,
+                    }
+                };
+
+                if error.conflicts.is_empty() {
+                    log!(session,
+                         Informative,
+                         "Resolving the known conflicts of `{}` by default",
+                         user_nt);
+                    let mut states = error.states;
+                    lr1::resolve_conflicts(&grammar, &mut states);
+                    states
+                } else {
+                    let mut messages = vec![];
+                    if let Some(max_length) = session.ambiguity_search {
+                        if let Some(ambiguity) = lr1::search_ambiguity(&grammar,
+                                                                       user_nt,
+                                                                       max_length) {
+                            messages.push(lr1::report_ambiguity(session, file_text, &ambiguity));
+                        }
+                    }
+                    messages.extend(lr1::report_error(session, file_text, &grammar, &error));
+                    if session.conflicts_as_warnings {
+                        report_build_warning(session,
+                                             &format!("the parser for `{}` has conflicts, \
+                                                       which are resolved by default: shifts \
+                                                       win over reductions, and earlier \
+                                                       productions over later ones",
+                                                      user_nt));
+                        try!(report_messages(session, messages));
+                        lr1::build_states_resolving_conflicts(session, &grammar, start_nt)
+                    } else {
+                        conflict_messages.extend(messages);
+                        if session.report_all_conflicts {
+                            continue;
+                        } else {
+                            break;
+                        }
+                    }
+                }
             }
         };
 
+        // once there are conflicts, we only keep looking for more
+        if !conflict_messages.is_empty() {
+            continue;
+        }
+
+        for state in &states {
+            reduced.extend(state.reductions.iter().map(|&(_, production)| production));
+            reduced.extend(state.two_token_reductions.iter().map(|r| r.production));
+        }
+
+        // the table-driven parsers share one module, whose parser fns
+        // are gated one by one; the others each get a module of their
+        // own, which may be compiled out as a whole
+        if grammar.algorithm.codegen != r::LrCodeGeneration::TableDriven {
+            if let Some(predicate) = cfg {
+                rust!(rust, "#[cfg({})]", predicate);
+            }
+        }
+
         match grammar.algorithm.codegen {
             r::LrCodeGeneration::RecursiveAscent =>
-                try!(lr1::codegen::ascent::compile(&grammar,
+                try!(lr1::codegen::ascent::compile(session,
+                                                   &grammar,
                                                    user_nt,
                                                    start_nt,
                                                    &states,
                                                    "super",
                                                    &mut rust)),
-            r::LrCodeGeneration::TableDriven =>
-                try!(lr1::codegen::parse_table::compile(&grammar,
-                                                        user_nt,
-                                                        start_nt,
-                                                        &states,
-                                                        "super",
-                                                        &mut rust)),
+            r::LrCodeGeneration::TableDriven => {
+                let states = if session.eliminate_unit_rules {
+                    let num_states = states.len();
+                    let states = lr1::eliminate_unit_reductions(&grammar, states);
+                    log!(session,
+                         Verbose,
+                         "Eliminated {} states that only reduced unit productions",
+                         num_states - states.len());
+                    states
+                } else {
+                    states
+                };
+                let profile = match profile_text {
+                    Some(ref text) => lr1::parse_profile(text, &user_nt.to_string()),
+                    None => lr1::Profile::default(),
+                };
+                let states = if profile_text.is_some() {
+                    log!(session, Verbose, "Laying out the states of `{}` by its profile", user_nt);
+                    lr1::reorder_states(states, &profile)
+                } else {
+                    states
+                };
+                table_parsers.push(lr1::codegen::parse_table::ParserStates {
+                    user_start_symbol: user_nt,
+                    start_symbol: start_nt,
+                    states: states,
+                    profile: profile,
+                });
+            }
 
             r::LrCodeGeneration::TestAll =>
-                try!(lr1::codegen::test_all::compile(&grammar, user_nt, start_nt, &states, &mut rust)),
+                try!(lr1::codegen::test_all::compile(session,
+                                                     &grammar,
+                                                     user_nt,
+                                                     start_nt,
+                                                     &states,
+                                                     &mut rust)),
+
+            r::LrCodeGeneration::Earley => unreachable!(),
         }
 
-        rust!(rust,
-              "pub use self::{}parse{}::parse_{};",
-              grammar.prefix,
-              start_nt,
-              user_nt);
+        let module = if grammar.algorithm.codegen == r::LrCodeGeneration::TableDriven {
+            format!("{}parse_table", grammar.prefix)
+        } else {
+            format!("{}parse{}", grammar.prefix, start_nt)
+        };
+
+        // the table-driven parser fns each come with a variant that
+        // takes a cancellation flag (which the recursive ascent parser
+        // has no use for, as it never checks one)
+        let variants: &[&str] = if grammar.algorithm.codegen != r::LrCodeGeneration::RecursiveAscent {
+            &["", "_cancelable"]
+        } else {
+            &[""]
+        };
+
+        for variant in variants {
+            if let Some(predicate) = cfg {
+                rust!(rust, "#[cfg({})]", predicate);
+            }
+            rust!(rust,
+                  "pub use self::{}::parse_{}{}{};",
+                  module,
+                  user_nt,
+                  if session.fixed_stack { "_with_stack" } else { "" },
+                  variant);
+        }
+
+        if session.token_arrays {
+            for variant in variants {
+                if let Some(predicate) = cfg {
+                    rust!(rust, "#[cfg({})]", predicate);
+                }
+                rust!(rust,
+                      "pub use self::{}::parse_{}_tokens{}{};",
+                      module,
+                      user_nt,
+                      if session.fixed_stack { "_with_stack" } else { "" },
+                      variant);
+            }
+        }
+
+        if session.emit_profile && grammar.algorithm.codegen == r::LrCodeGeneration::TableDriven {
+            if let Some(predicate) = cfg {
+                rust!(rust, "#[cfg({})]", predicate);
+            }
+            rust!(rust,
+                  "pub use self::{}::write_profile_{};",
+                  module,
+                  user_nt);
+        }
+
+        if grammar.repair {
+            for variant in variants {
+                if let Some(predicate) = cfg {
+                    rust!(rust, "#[cfg({})]", predicate);
+                }
+                rust!(rust,
+                      "pub use self::{}::parse_{}_repaired{};",
+                      module,
+                      user_nt,
+                      variant);
+            }
+        }
+
+        // the recursive ascent parser keeps its state on the Rust
+        // stack, and so has no reusable parser struct, and neither
+        // does a parser that keeps it in a buffer
+        if grammar.algorithm.codegen != r::LrCodeGeneration::RecursiveAscent &&
+           !session.fixed_stack {
+            if let Some(predicate) = cfg {
+                rust!(rust, "#[cfg({})]", predicate);
+            }
+            rust!(rust,
+                  "pub use self::{}::{}Parser;",
+                  module,
+                  user_nt);
+        }
+    }
+
+    // a parser with a fixed-size stack reports the state it found a
+    // syntax error in, if it has no allocator to list the tokens it
+    // expected with (see `lalrpop_util::Expected`)
+    if session.fixed_stack {
+        rust!(rust, "pub use self::{}parse_table::expected_tokens;", grammar.prefix);
+    }
+
+    if let Some(conflicts_file) = conflicts_file {
+        let mut output_conflicts_file = try!(fs::File::create(conflicts_file));
+        try!(lr1::write_conflicts(&mut output_conflicts_file, &conflict_entries));
+    }
+
+    if !conflict_messages.is_empty() {
+        return Ok(Err(conflict_messages));
+    }
+
+    if !table_parsers.is_empty() {
+        let _lr1_tls = lr1::Lr1Tls::install(grammar.terminals.clone());
+        try!(lr1::codegen::parse_table::compile_all(session,
+                                                    &grammar,
+                                                    table_parsers,
+                                                    "super",
+                                                    &mut rust));
+    }
+
+    if let Some(baseline_file) = baseline_file {
+        if updating_baseline {
+            let mut output_baseline_file = try!(fs::File::create(baseline_file));
+            try!(lr1::write_baseline(&mut output_baseline_file, &fingerprints));
+            log!(session,
+                 Informative,
+                 "Recorded {} conflicts in `{}`",
+                 fingerprints.len(),
+                 baseline_file.display());
+        } else if let Some(ref baseline) = baseline {
+            let fixed = baseline.difference(&fingerprints).count();
+            if fixed > 0 {
+                log!(session,
+                     Informative,
+                     "{} of the conflicts in `{}` no longer arise; it can be updated",
+                     fixed,
+                     baseline_file.display());
+            }
+        }
     }
 
     if let Some(ref intern_token) = grammar.intern_token {
-        try!(intern_token::compile(&grammar, intern_token, &mut rust));
+        try!(intern_token::compile(session, &grammar, intern_token, &mut rust));
         rust!(rust, "pub use self::{}intern_token::Token;", grammar.prefix);
+        if session.token_arrays {
+            rust!(rust, "pub use self::{}intern_token::tokenize;", grammar.prefix);
+        }
     }
 
-    try!(action::emit_action_code(grammar, &mut rust));
+    if let Some(ref fixed) = grammar.token_stream {
+        try!(emit_token_stream_fn(grammar, fixed, &mut rust));
+    }
+
+    try!(action::emit_action_code(session, file_text, grammar, &mut rust));
+
+    try!(ast::emit_ast_types(grammar, &mut rust));
+
+    try!(unparse::emit_unparsers(grammar, &mut rust));
 
     try!(emit_to_triple_trait(grammar, &mut rust));
 
-    Ok(rust.into_inner())
+    // Earley parsers have no tables to leave alternatives out of
+    let warnings = if grammar.algorithm.codegen == r::LrCodeGeneration::Earley {
+        vec![]
+    } else {
+        lint::check_reductions(grammar, &reduced)
+    };
+
+    Ok(Ok((rust.into_inner(), warnings)))
+}
+
+/// Emits `tokens`, which cuts a `TokenStream` into the tokens of a
+/// `#![token_stream]` grammar, numbering its keywords, operators and
+/// delimiters as its conversions do.
+fn emit_token_stream_fn<W: Write>(grammar: &r::Grammar,
+                                  fixed: &[InternedString],
+                                  rust: &mut RustWrite<W>)
+                                  -> io::Result<()> {
+    let fixed: Vec<String> = fixed.iter().map(|text| format!("{:?}", text.to_string())).collect();
+    rust!(rust, "");
+    rust!(rust,
+          "pub fn tokens(stream: {p}lalrpop_util::token_stream::TokenStream) \
+           -> {p}lalrpop_util::token_stream::Tokens {{",
+          p = grammar.prefix);
+    rust!(rust,
+          "{}lalrpop_util::token_stream::Tokens::new(stream, &[{}])",
+          grammar.prefix,
+          fixed.join(", "));
+    rust!(rust, "}}");
+    Ok(())
 }
 
 fn emit_to_triple_trait<W: Write>(grammar: &r::Grammar, rust: &mut RustWrite<W>) -> io::Result<()> {
@@ -0,0 +1,172 @@
+//! The parsers module that `Configuration::emit_parsers_module` asks
+//! for: a Rust file that declares a module for the parsers generated
+//! from each grammar in a directory, named after the grammar's path,
+//! so that a crate with many grammars needs a single `mod`:
+//!
+//! ```ignore
+//! #[path = "calc.rs"]
+//! pub mod calc;
+//!
+//! #[doc(hidden)]
+//! #[path = "lang/expr.rs"]
+//! pub mod __lang__expr;
+//!
+//! pub mod lang {
+//!     pub use super::__lang__expr as expr;
+//! }
+//! ```
+//!
+//! The modules of grammars in subdirectories are declared at the top
+//! and re-exported, as `#[path]` attributes inside inline modules
+//! would be resolved relative to a directory named after them.
+
+use collections::Map;
+use std::path::{Component, Path, PathBuf};
+
+#[derive(Default)]
+struct Module {
+    /// The path of the generated file, if this module is generated
+    /// from a grammar.
+    file: Option<String>,
+
+    /// The modules for the grammars in the directory of this name.
+    children: Map<String, Module>,
+}
+
+/// The text of a parsers module to be written in `dir`, for the
+/// parsers in `rs_files`. Files outside of `dir` are referred to by
+/// their absolute path, with a module named after the file.
+pub fn parsers_module(dir: &Path, rs_files: &[PathBuf]) -> Result<String, String> {
+    let mut root = Module::default();
+    for rs_file in rs_files {
+        let (names, path) = match rs_file.strip_prefix(dir) {
+            Ok(relative) => (module_names(relative), path_string(relative)),
+            Err(_) => {
+                let stem = rs_file.file_stem().map_or(String::new(), |stem| {
+                    stem.to_string_lossy().into_owned()
+                });
+                (vec![module_name(&stem)], rs_file.display().to_string())
+            }
+        };
+        try!(root.insert(&names, path));
+    }
+
+    let mut items = vec![];
+    for (name, module) in &root.children {
+        try!(declare(&mut items, &[name.clone()], module));
+    }
+    for (name, module) in &root.children {
+        if module.file.is_none() {
+            let mut text = String::new();
+            write_nested(&mut text, &mut vec![name.clone()], module, "");
+            items.push(text);
+        }
+    }
+    Ok(items.join("\n"))
+}
+
+impl Module {
+    fn insert(&mut self, names: &[String], file: String) -> Result<(), String> {
+        let module = names.iter().fold(self, |module, name| {
+            module.children.entry(name.clone()).or_insert_with(Module::default)
+        });
+        if module.file.is_some() {
+            return Err(format!("the parsers of `{}` and `{}` would both be in module `{}`",
+                               module.file.as_ref().unwrap(),
+                               file,
+                               names.join("::")));
+        }
+        module.file = Some(file);
+        Ok(())
+    }
+}
+
+/// Declares the modules generated from grammars among `module`, at
+/// `path`, and its children.
+fn declare(items: &mut Vec<String>, path: &[String], module: &Module) -> Result<(), String> {
+    if let Some(ref file) = module.file {
+        if !module.children.is_empty() {
+            return Err(format!("module `{}` holds the parsers of `{}`, and so cannot also \
+                                hold those of the grammars in directory `{}`",
+                               path.join("::"),
+                               file,
+                               path.join("/")));
+        }
+        let mut text = String::new();
+        if path.len() > 1 {
+            text.push_str("#[doc(hidden)]\n");
+        }
+        text.push_str(&format!("#[path = {:?}]\n", file));
+        text.push_str(&format!("pub mod {};\n", hidden_name(path)));
+        items.push(text);
+    }
+    for (name, child) in &module.children {
+        let mut child_path = path.to_vec();
+        child_path.push(name.clone());
+        try!(declare(items, &child_path, child));
+    }
+    Ok(())
+}
+
+/// Writes the inline modules for the directories among `module`, at
+/// `path`, which re-export the modules declared at the top.
+fn write_nested(text: &mut String, path: &mut Vec<String>, module: &Module, indent: &str) {
+    text.push_str(&format!("{}pub mod {} {{\n", indent, path.last().unwrap()));
+    let inner = format!("{}    ", indent);
+    for (name, child) in &module.children {
+        path.push(name.clone());
+        if child.file.is_some() {
+            text.push_str(&format!("{}pub use {}{} as {};\n",
+                                   inner,
+                                   "super::".repeat(path.len() - 1),
+                                   hidden_name(path),
+                                   name));
+        } else {
+            write_nested(text, path, child, &inner);
+        }
+        path.pop();
+    }
+    text.push_str(&format!("{}}}\n", indent));
+}
+
+/// The name a module is declared under at the top: its own for those
+/// at the top, and one made of all of the names on its path for the
+/// rest.
+fn hidden_name(path: &[String]) -> String {
+    if path.len() == 1 {
+        path[0].clone()
+    } else {
+        format!("__{}", path.join("__"))
+    }
+}
+
+fn module_names(relative: &Path) -> Vec<String> {
+    let relative = relative.with_extension("");
+    relative.components()
+            .filter_map(|component| match component {
+                Component::Normal(name) => Some(module_name(&name.to_string_lossy())),
+                _ => None,
+            })
+            .collect()
+}
+
+/// `name`, with the characters that can't appear in an identifier
+/// replaced by `_`.
+fn module_name(name: &str) -> String {
+    let mut module_name: String =
+        name.chars()
+            .map(|c| if c.is_alphanumeric() || c == '_' { c } else { '_' })
+            .collect();
+    if module_name.chars().next().map_or(true, |c| c.is_numeric()) {
+        module_name.insert(0, '_');
+    }
+    module_name
+}
+
+/// `path` with `/` separators, as `#[path]` accepts on all platforms.
+fn path_string(path: &Path) -> String {
+    let components: Vec<String> = path.components()
+                                      .map(|c| c.as_os_str().to_string_lossy().into_owned())
+                                      .collect();
+    components.join("/")
+}
@@ -0,0 +1,122 @@
+//! The summary that `Configuration::summarize` asks for at the end of
+//! a build of several grammars: a table of what became of each
+//! grammar, how many conflicts were reported for it and how long it
+//! took, sorted by path, so that the outcome of a workspace with many
+//! grammars can be taken in at a glance, rather than pieced together
+//! from the diagnostics of each.
+//!
+//! ```text
+//! grammar            status              conflicts  time
+//! src/calc.lalrpop   generated                   0  0.42s
+//! src/expr.lalrpop   failed (conflicts)          3  1.20s
+//! src/tok.lalrpop    token module                0  0.00s
+//!
+//! 3 grammars: 1 generated, 1 token module, 1 failed
+//! ```
+
+use std::cmp::max;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+/// What became of a grammar.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Status {
+    Generated,
+
+    /// The generated file was newer than the grammar.
+    UpToDate,
+
+    TokenModule,
+
+    /// The grammar has errors, which were reported as it was
+    /// processed; this says what kind.
+    Failed(String),
+}
+
+impl Status {
+    pub fn failed(&self) -> bool {
+        match *self {
+            Status::Failed(_) => true,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GrammarSummary {
+    pub file: PathBuf,
+    pub status: Status,
+
+    /// The conflicts that were reported, and made the grammar fail.
+    pub conflicts: usize,
+
+    pub seconds: f64,
+}
+
+/// The error that processing a grammar ends with, once its errors
+/// have been reported, when the build is summarized; the other
+/// grammars are processed before the build fails.
+#[derive(Debug)]
+pub struct GrammarFailed {
+    pub reason: String,
+    pub conflicts: usize,
+}
+
+impl fmt::Display for GrammarFailed {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{}", self.reason)
+    }
+}
+
+impl Error for GrammarFailed {
+    fn description(&self) -> &str {
+        &self.reason
+    }
+}
+
+fn status_str(status: &Status) -> String {
+    match *status {
+        Status::Generated => String::from("generated"),
+        Status::UpToDate => String::from("up to date"),
+        Status::TokenModule => String::from("token module"),
+        Status::Failed(ref reason) => format!("failed ({})", reason),
+    }
+}
+
+pub fn write_summary<W: Write>(out: &mut W, grammars: &[GrammarSummary]) -> io::Result<()> {
+    let mut grammars: Vec<&GrammarSummary> = grammars.iter().collect();
+    grammars.sort_by(|a, b| a.file.cmp(&b.file));
+
+    let rows: Vec<(String, String)> =
+        grammars.iter()
+                .map(|grammar| (grammar.file.display().to_string(), status_str(&grammar.status)))
+                .collect();
+    let file_width = rows.iter().map(|row| row.0.len()).fold("grammar".len(), max);
+    let status_width = rows.iter().map(|row| row.1.len()).fold("status".len(), max);
+
+    try!(writeln!(out, "{:2$}  {:3$}  conflicts  time", "grammar", "status",
+                  file_width, status_width));
+    for (grammar, &(ref file, ref status)) in grammars.iter().zip(&rows) {
+        try!(writeln!(out, "{:4$}  {:5$}  {:>9}  {:.2}s", file, status, grammar.conflicts,
+                      grammar.seconds, file_width, status_width));
+    }
+
+    let count = |wanted: &Fn(&Status) -> bool| {
+        grammars.iter().filter(|grammar| wanted(&grammar.status)).count()
+    };
+    let failed = count(&|s| s.failed());
+    let modules = count(&|s| *s == Status::TokenModule);
+    let counts = [(count(&|s| *s == Status::Generated), String::from("generated")),
+                  (count(&|s| *s == Status::UpToDate), String::from("up to date")),
+                  (modules, format!("token module{}", if modules == 1 { "" } else { "s" })),
+                  (failed, String::from("failed"))];
+    let counts: Vec<String> = counts.iter()
+                                    .filter(|&&(count, _)| count > 0)
+                                    .map(|&(count, ref what)| format!("{} {}", count, what))
+                                    .collect();
+    try!(writeln!(out, ""));
+    writeln!(out, "{} grammar{}: {}", grammars.len(), if grammars.len() == 1 { "" } else { "s" },
+             counts.join(", "))
+}
@@ -0,0 +1,540 @@
+use diagnostics::Severity;
+use file_text::FileText;
+use session::{ConflictBaseline, Session};
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use super::{check_grammar, check_str, generate_parser, process_file, process_files_summarized,
+            process_str, unique_prefix};
+use super::parsers_module::parsers_module;
+use super::summary::{write_summary, GrammarSummary, Status};
+
+#[test]
+fn prefix_absent_from_all_files() {
+    let files = [(Path::new("dialect.lalrpop"), "grammar; X = __lp;", 0),
+                 (Path::new("base.lalrpop"), "grammar; Y = __lp_;", 18)];
+    assert_eq!(unique_prefix("__lp", &files[..1]), "__lp_");
+    assert_eq!(unique_prefix("__lp", &files), "__lp__");
+    assert_eq!(unique_prefix("__parser", &files), "__parser");
+}
+
+#[test]
+fn process_str_generates_parser() {
+    let code = process_str(Rc::new(Session::test()), r#"
+grammar;
+pub Num: i32 = r"[0-9]+" => <>.parse().unwrap();
+"#).unwrap();
+    assert!(code.contains("pub fn parse_Num"));
+    assert!(code.contains("mod __intern_token"));
+}
+
+#[test]
+fn parsers_share_parse_loop() {
+    let mut session = Session::test();
+    session.unit_test = false;
+    let code = process_str(Rc::new(session), r#"
+grammar;
+pub Sum: i32 = {
+    <l:Sum> "+" <r:Num> => l + r,
+    Num,
+};
+pub Num: i32 = r"[0-9]+" => <>.parse().unwrap_or(0);
+"#).unwrap();
+    // one module, with one parse loop, serves both parsers
+    assert_eq!(code.matches("\nmod __parse").count(), 1);
+    assert_eq!(code.matches("fn __parse<").count(), 1);
+    assert!(code.contains("pub use self::__parse_table::parse_Sum;"));
+    assert!(code.contains("pub use self::__parse_table::parse_Num;"));
+
+    // each starting from the start state of its own parser
+    assert!(code.contains("__parse::<>(input, 0, "));
+    assert!(!code.contains("__parse::<>(input, 1, "));
+    assert!(code.contains("__Symbol::Nt____Num(__v) => Ok(__v)"));
+    assert!(code.contains("__Symbol::Nt____Sum(__v) => Ok(__v)"));
+}
+
+#[test]
+fn process_str_reports_error() {
+    let text = "grammar;\npub A = B;\n";
+    let diagnostics = process_str(Rc::new(Session::test()), text).unwrap_err();
+    assert_eq!(diagnostics.diagnostics.len(), 1);
+    let diagnostic = &diagnostics.diagnostics[0];
+    assert_eq!(diagnostic.severity, Severity::Error);
+    assert_eq!(diagnostic.location, Some((2, 9)));
+    assert_eq!(diagnostic.span.map(|(start, end)| &text[start..end]), Some("B"));
+}
+
+#[test]
+fn process_str_reports_conflicts() {
+    let diagnostics = process_str(Rc::new(Session::test()), r#"
+grammar;
+pub E: () = {
+    E "+" E => (),
+    "N" => (),
+};
+"#).unwrap_err();
+    let diagnostic = &diagnostics.diagnostics[0];
+    assert_eq!(diagnostic.severity, Severity::Error);
+    assert_eq!(diagnostic.location.map(|(line, _)| line), Some(4));
+    assert!(diagnostic.message.starts_with("Ambiguous grammar detected"),
+            "{}", diagnostic.message);
+}
+
+#[test]
+fn process_str_reports_dead_alternatives() {
+    let mut session = Session::test();
+    session.conflicts_as_warnings = true;
+    let text = r#"
+#![deny(lalrpop::dead_alternative)]
+grammar;
+pub E: () = {
+    "N" => (),
+    "N" => (),
+};
+"#;
+    let diagnostics = process_str(Rc::new(session), text).unwrap_err();
+    let errors: Vec<_> = diagnostics.diagnostics
+                                    .iter()
+                                    .filter(|d| d.severity == Severity::Error)
+                                    .collect();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].location, Some((6, 5)));
+    assert!(errors[0].message.ends_with("[lalrpop::dead_alternative]"),
+            "{}", errors[0].message);
+
+    // the duplicate is also reported, as a warning
+    let warnings: Vec<_> = diagnostics.diagnostics
+                                      .iter()
+                                      .filter(|d| d.severity == Severity::Warning)
+                                      .collect();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].location, Some((6, 5)));
+    assert!(warnings[0].message.ends_with("[lalrpop::duplicate_production]"),
+            "{}", warnings[0].message);
+
+    // the first of the two alternatives wins, so it is not dead
+    let text = text.replace("#![deny(lalrpop::dead_alternative)]", "");
+    let mut session = Session::test();
+    session.conflicts_as_warnings = true;
+    assert!(process_str(Rc::new(session), &text).is_ok());
+}
+
+#[test]
+fn check_str_reports_all_conflicts() {
+    let diagnostics = check_str(Rc::new(Session::test()), r#"
+grammar;
+pub E: () = {
+    E "+" E => (),
+    "N" => (),
+};
+pub F: () = {
+    F "*" F => (),
+    "N" => (),
+};
+"#);
+    assert!(diagnostics.has_errors());
+    let lines: Vec<_> = diagnostics.diagnostics
+                                   .iter()
+                                   .map(|d| d.location.unwrap().0)
+                                   .collect();
+    assert!(lines.contains(&4) && lines.contains(&8), "{:?}", lines);
+}
+
+#[test]
+fn check_str_diagnostics_as_json() {
+    let text = "grammar;\npub A = \"a\" B;\n";
+    let diagnostics = check_str(Rc::new(Session::test()), text);
+    assert_eq!(diagnostics.to_json(),
+               "[{\"severity\": \"error\", \"span\": [21, 22], \"line\": 2, \"column\": 13, \
+                \"message\": \"no definition found for `B`\"}]");
+    assert!(check_str(Rc::new(Session::test()), "grammar;\npub A = \"a\";\n").diagnostics.is_empty());
+}
+
+fn panic_free_session() -> Rc<Session> {
+    let mut session = Session::test();
+    session.panic_free = true;
+    Rc::new(session)
+}
+
+/// Panics if `code` contains anything that could panic, other than
+/// slices that the generated code checks beforehand.
+fn assert_panic_free(code: &str) {
+    let forbidden = [".unwrap()", ".expect(", "panic!", "unreachable!", "unimplemented!",
+                     "ACTION[", "GOTO[", "states[", "symbols[", "regex_vec["];
+    for line in code.lines() {
+        for pattern in &forbidden {
+            assert!(!line.contains(pattern), "`{}` in: {}", pattern, line);
+        }
+    }
+}
+
+const CALC: &'static str = r#"
+grammar;
+pub Expr: i32 = {
+    <l:Expr> "+" <r:Factor> => l + r,
+    Factor,
+};
+Factor: i32 = {
+    <l:Factor> "*" <r:Term> => l * r,
+    Term,
+};
+Term: i32 = {
+    Num,
+    "(" <Expr> ")",
+    "-" <Term> => -<>,
+};
+Num: i32 = r"[0-9]+" => <>.parse().unwrap_or(0);
+"#;
+
+#[test]
+fn panic_free_parsers_do_not_panic() {
+    let code = process_str(panic_free_session(), CALC).unwrap();
+    assert_panic_free(&code);
+    assert!(code.contains("ParseError::Internal"));
+
+    // for comparison
+    let code = process_str(Rc::new(Session::test()), CALC).unwrap();
+    assert!(code.contains(".unwrap()"));
+}
+
+#[test]
+fn panic_free_parsers_not_supported() {
+    let unsupported = ["#[recursive_ascent] grammar; pub A: () = \"a\" => ();",
+                       "grammar; pub A: () = { \"a\" => (), ! => () };"];
+    for grammar in &unsupported {
+        let diagnostics = check_str(panic_free_session(), grammar);
+        assert!(diagnostics.diagnostics[0].message.starts_with("panic-free parsers"),
+                "{}", diagnostics.diagnostics[0].message);
+    }
+
+    let mut session = Session::test();
+    session.panic_free = true;
+    session.fast_scanning = true;
+    let diagnostics = check_str(Rc::new(session), "grammar; pub A: () = \"a\" => ();");
+    assert_eq!(diagnostics.diagnostics[0].message,
+               "panic-free parsers cannot be combined with fast scanning");
+}
+
+/// Generates random grammars (from a fixed seed) and checks that the
+/// code generated for each of them in panic-free mode is panic-free.
+#[test]
+fn panic_free_generator_fuzz() {
+    let mut seed: u32 = 0x2545_f491;
+    let mut random = |n: usize| {
+        // xorshift
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        seed as usize % n
+    };
+
+    let terminals = ["\"a\"", "\"b\"", "\"(\"", "\")\"", "r\"[0-9]+\"", "\"if\"", "r\"[a-z]+\""];
+    let nonterminals = ["A", "B", "C"];
+    let mut generated = 0;
+    for _ in 0..40 {
+        let mut grammar = String::from("grammar;\n");
+        for (index, nt) in nonterminals.iter().enumerate() {
+            let visibility = if index == 0 { "pub " } else { "" };
+            grammar.push_str(&format!("{}{}: () = {{\n", visibility, nt));
+            for _ in 0..random(3) + 1 {
+                let mut symbols = vec![];
+                for _ in 0..random(4) {
+                    if random(3) == 0 {
+                        symbols.push(nonterminals[random(nonterminals.len())]);
+                    } else {
+                        symbols.push(terminals[random(terminals.len())]);
+                    }
+                }
+                grammar.push_str(&format!("    {} => (),\n", symbols.join(" ")));
+            }
+            grammar.push_str("};\n");
+        }
+
+        // grammars with conflicts (or other errors) are rejected
+        // before any code is generated
+        if let Ok(code) = process_str(panic_free_session(), &grammar) {
+            assert_panic_free(&code);
+            generated += 1;
+        }
+    }
+    assert!(generated >= 10, "only {} grammars were accepted", generated);
+}
+
+#[test]
+fn tables_in_link_section() {
+    let mut session = Session::test();
+    session.table_section = Some(String::from(".rodata.calc"));
+    let code = process_str(Rc::new(session), CALC).unwrap();
+    assert_eq!(code.matches("#[link_section = \".rodata.calc\"]").count(), 4);
+    // 15 states, 6 terminals, 5 nonterminals
+    assert!(code.contains("static __ACTION: [i8; 90] = ["));
+    assert!(code.contains("static __EOF_ACTION: [i8; 15] = ["));
+    assert!(code.contains("static __GOTO: [u8; 75] = ["));
+    assert!(code.contains("static __DEFAULT_ACTION: [i8; 15] = ["));
+    assert!(!code.contains("const __ACTION"));
+
+    let code = process_str(Rc::new(Session::test()), CALC).unwrap();
+    assert!(code.contains("const __ACTION: &'static ["));
+    assert!(!code.contains("link_section"));
+
+    let mut session = Session::test();
+    session.table_section = Some(String::from(".rodata.calc"));
+    let diagnostics = check_str(Rc::new(session), "#![earley] grammar; pub A: () = \"a\" => ();");
+    assert!(diagnostics.diagnostics[0].message.contains("link section"));
+}
+
+#[test]
+fn fixed_stack_parsers_not_supported() {
+    let unsupported = ["#[recursive_ascent] grammar; pub A: () = \"a\" => ();",
+                       "#![earley] grammar; pub A: () = \"a\" => ();",
+                       "grammar; pub A: () = { \"a\" => (), ! => () };"];
+    for grammar in &unsupported {
+        let mut session = Session::test();
+        session.fixed_stack = true;
+        let diagnostics = check_str(Rc::new(session), grammar);
+        assert!(diagnostics.diagnostics[0].message.starts_with("fixed-size parse stacks"),
+                "{}", diagnostics.diagnostics[0].message);
+    }
+}
+
+#[test]
+fn token_arrays_not_supported() {
+    let unsupported = [("#[recursive_ascent] grammar; pub A: () = \"a\" => ();",
+                        "token arrays are only supported by table-driven parsers"),
+                       ("grammar; extern { enum Tok { \"a\" => Tok::A } } pub A: () = \"a\" => ();",
+                        "token arrays require the built-in tokenizer")];
+    for &(grammar, message) in &unsupported {
+        let mut session = Session::test();
+        session.token_arrays = true;
+        let diagnostics = check_str(Rc::new(session), grammar);
+        assert_eq!(diagnostics.diagnostics[0].message, message);
+    }
+}
+
+#[test]
+fn max_nesting_not_supported() {
+    let unsupported = ["#[recursive_ascent] grammar; \
+                        #[max_nesting(depth = \"2\")] pub A: () = \"a\" => ();",
+                       "grammar; #[max_nesting(depth = \"2\")] pub A: () = { \"a\" => (), ! => () };"];
+    for grammar in &unsupported {
+        let diagnostics = check_str(Rc::new(Session::test()), grammar);
+        assert!(diagnostics.diagnostics[0].message.starts_with("#[max_nesting]"),
+                "{}", diagnostics.diagnostics[0].message);
+    }
+}
+
+#[test]
+fn conflicts_as_warnings() {
+    let mut session = Session::test();
+    session.conflicts_as_warnings = true;
+    let grammar = r#"
+grammar;
+pub E: () = {
+    E "+" E => (),
+    "N" => (),
+};
+"#;
+    let code = process_str(Rc::new(session), grammar).unwrap();
+    assert!(code.contains("pub fn parse_E"));
+
+    let mut session = Session::test();
+    session.conflicts_as_warnings = true;
+    let code = process_str(Rc::new(session), &format!("#[recursive_ascent]{}", grammar)).unwrap();
+    assert!(code.contains("pub fn parse_E"));
+}
+
+#[test]
+fn parsers_module_declares_each_parser() {
+    let dir = Path::new("/crate/src");
+    let rs_files: Vec<PathBuf> = ["/crate/src/calc.rs",
+                                  "/crate/src/lang/expr.rs",
+                                  "/crate/src/lang/v2/my-stmt.rs",
+                                  "/elsewhere/2d.rs"]
+        .iter()
+        .map(PathBuf::from)
+        .collect();
+    assert_eq!(parsers_module(dir, &rs_files).unwrap(), r#"#[path = "/elsewhere/2d.rs"]
+pub mod _2d;
+
+#[path = "calc.rs"]
+pub mod calc;
+
+#[doc(hidden)]
+#[path = "lang/expr.rs"]
+pub mod __lang__expr;
+
+#[doc(hidden)]
+#[path = "lang/v2/my-stmt.rs"]
+pub mod __lang__v2__my_stmt;
+
+pub mod lang {
+    pub use super::__lang__expr as expr;
+    pub mod v2 {
+        pub use super::super::__lang__v2__my_stmt as my_stmt;
+    }
+}
+"#);
+}
+
+#[test]
+fn summary_table() {
+    let summary = |file: &str, status: Status, conflicts: usize, seconds: f64| GrammarSummary {
+        file: PathBuf::from(file),
+        status: status,
+        conflicts: conflicts,
+        seconds: seconds,
+    };
+    let mut out = vec![];
+    write_summary(&mut out,
+                  &[summary("src/tok.lalrpop", Status::TokenModule, 0, 0.0),
+                    summary("src/expr.lalrpop", Status::Failed(String::from("conflicts")), 3, 1.2),
+                    summary("src/calc.lalrpop", Status::Generated, 0, 0.421)])
+        .unwrap();
+    let out = String::from_utf8(out).unwrap();
+    assert_eq!(out, r#"grammar           status              conflicts  time
+src/calc.lalrpop  generated                   0  0.42s
+src/expr.lalrpop  failed (conflicts)          3  1.20s
+src/tok.lalrpop   token module                0  0.00s
+
+3 grammars: 1 generated, 1 token module, 1 failed
+"#);
+}
+
+#[test]
+fn summarized_build() {
+    let dir = env::temp_dir().join("lalrpop-summarized-build");
+    fs::create_dir_all(&dir).unwrap();
+    let grammars = [("good.lalrpop", r#"grammar; pub E: () = { "N" => () };"#),
+                    ("ambiguous.lalrpop", r#"grammar; pub E: () = { E "+" E => (), "N" => () };"#)];
+    let mut files = vec![];
+    for &(name, text) in &grammars {
+        let file = dir.join(name);
+        let _ = fs::remove_file(file.with_extension("rs"));
+        fs::File::create(&file).unwrap().write_all(text.as_bytes()).unwrap();
+        files.push(file);
+    }
+
+    let mut session = Session::test();
+    session.summarize = true;
+    session.force_build = true;
+    let (summaries, error) = process_files_summarized(&Rc::new(session), &files);
+    assert!(error.is_none());
+    assert_eq!(summaries[0].status, Status::Generated);
+    assert_eq!(summaries[0].conflicts, 0);
+    assert_eq!(summaries[1].status, Status::Failed(String::from("conflicts")));
+    assert!(summaries[1].conflicts > 0);
+    assert!(dir.join("good.rs").exists());
+    assert!(!dir.join("ambiguous.rs").exists());
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn denied_warnings_are_errors() {
+    let dir = env::temp_dir().join("lalrpop-denied-warnings");
+    fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("unreachable.lalrpop");
+    let text = r#"#![deny(lalrpop::never_reduced)]
+                  grammar; pub S: () = "a" => (); T: () = "b" => ();"#;
+    fs::File::create(&file).unwrap().write_all(text.as_bytes()).unwrap();
+
+    let mut session = Session::test();
+    session.force_build = true;
+    let error = process_file(Rc::new(session), &file).unwrap_err();
+    assert_eq!(error.to_string(), "denied warnings");
+    assert!(!dir.join("unreachable.rs").exists());
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn baseline_allows_known_conflicts() {
+    let dir = env::temp_dir().join("lalrpop-conflict-baseline");
+    fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("ambiguous.lalrpop");
+    let text = r#"grammar; pub E: () = { E "+" E => (), "N" => () };"#;
+    fs::File::create(&file).unwrap().write_all(text.as_bytes()).unwrap();
+    let _ = fs::remove_file(file.with_extension("conflict-baseline"));
+
+    // a missing baseline allows no conflicts (and as with `--summarize`,
+    // the build fails with an error rather than exiting)...
+    let mut session = Session::test();
+    session.force_build = true;
+    session.summarize = true;
+    session.conflict_baseline = ConflictBaseline::Check;
+    assert!(process_file(Rc::new(session.clone()), &file).is_err());
+
+    // ...but once they are recorded, the parser is generated
+    session.conflict_baseline = ConflictBaseline::Update;
+    process_file(Rc::new(session.clone()), &file).unwrap();
+    session.conflict_baseline = ConflictBaseline::Check;
+    process_file(Rc::new(session), &file).unwrap();
+    assert!(dir.join("ambiguous.rs").exists());
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn parsers_module_conflicts() {
+    let dir = Path::new("/crate/src");
+    let rs_files = [PathBuf::from("/crate/src/lang.rs"), PathBuf::from("/crate/src/lang/expr.rs")];
+    assert!(parsers_module(dir, &rs_files).is_err());
+    let rs_files = [PathBuf::from("/crate/src/calc.rs"), PathBuf::from("/elsewhere/calc.rs")];
+    assert!(parsers_module(dir, &rs_files).is_err());
+}
+
+#[test]
+fn debug_options() {
+    let code = process_str(Rc::new(Session::test()), CALC).unwrap();
+    assert!(code.contains("//@ grammar.lalrpop:"));
+    assert!(!code.contains("debug_assert"));
+    assert!(!code.contains("println!"));
+
+    let mut session = Session::test();
+    session.emit_grammar_comments = false;
+    session.emit_debug_assertions = true;
+    session.emit_trace = true;
+    let code = process_str(Rc::new(session), CALC).unwrap();
+    assert!(!code.contains("//@"));
+    assert!(!code.contains("// Expr = "));
+    assert!(code.contains("debug_assert_eq!(__states.len(), __symbols.len() + 1"));
+    assert!(code.contains("println!(\"--> shift"));
+}
+
+#[test]
+fn profiled_parsers() {
+    let mut session = Session::test();
+    session.emit_profile = true;
+    let code = process_str(Rc::new(session), CALC).unwrap();
+    assert!(code.contains("pub fn write_profile_Expr"));
+    assert!(code.contains("pub use self::__parse_table::write_profile_Expr;"));
+    assert!(code.contains("__STATE_VISITS.with("));
+
+    let code = process_str(Rc::new(Session::test()), CALC).unwrap();
+    assert!(!code.contains("write_profile"));
+}
+
+#[test]
+fn profile_layout() {
+    let mut session = Session::test();
+    session.use_profile = true;
+    let session = Rc::new(session);
+    let file_text = Rc::new(FileText::new(PathBuf::from("grammar.lalrpop"), CALC.to_string()));
+    let grammar = check_grammar(&session, &file_text, &mut vec![]).unwrap();
+
+    let profile_file = env::temp_dir().join("lalrpop-profile-layout.profile");
+    {
+        let mut file = fs::File::create(&profile_file).unwrap();
+        writeln!(file, "# hot\nExpr reduce 2 100\nExpr state 3 100").unwrap();
+    }
+    let generated = generate_parser(&session, &file_text, &grammar,
+                                    None, None, None, Some(&profile_file));
+    fs::remove_file(&profile_file).unwrap();
+    let code = String::from_utf8(generated.unwrap().unwrap().0).unwrap();
+    let hot = code.find(" 2 => match __reduce_").unwrap();
+    let cold = code.find(" 1 => match __reduce_").unwrap();
+    assert!(hot < cold);
+}
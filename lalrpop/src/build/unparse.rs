@@ -0,0 +1,213 @@
+//! The `unparse_X` functions that `#[unparse]` asks for, which write
+//! values back as text that parses to them (see `repr::UnparseDefn`).
+//!
+//! Each unparser tries the alternatives of its nonterminal in turn and
+//! returns whether one could write the value; those that pass the
+//! value on to another nonterminal take a *hop*. The unparsers are
+//! first tried with no hops, then with more, up to the number of
+//! nonterminals, so that the shortest way of writing each value is
+//! found and parentheses are only added where the grammar needs them.
+
+use grammar::repr as r;
+use rust::RustWrite;
+use std::io::{self, Write};
+
+pub fn emit_unparsers<W: Write>(grammar: &r::Grammar, rust: &mut RustWrite<W>) -> io::Result<()> {
+    if grammar.unparsers.is_empty() {
+        return Ok(());
+    }
+
+    let p = &grammar.prefix;
+    let lifetimes: Vec<String> = grammar.type_parameters
+                                        .iter()
+                                        .map(|parameter| parameter.to_string())
+                                        .collect();
+    let lifetimes = if lifetimes.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", lifetimes.join(", "))
+    };
+    let hops = grammar.unparsers.len();
+
+    for (index, defn) in grammar.unparsers.iter().enumerate() {
+        if !defn.public {
+            continue;
+        }
+        rust!(rust, "");
+        rust!(rust, "#[allow(non_snake_case)]");
+        rust!(rust,
+              "pub fn unparse_{}{}(value: &{}) -> Option<String> {{",
+              defn.nonterminal,
+              lifetimes,
+              defn.ty);
+        rust!(rust, "let mut {}out = String::new();", p);
+        rust!(rust,
+              "if {p}unparse(&mut {p}out, value, {p}unparse{}, {}) {{",
+              index,
+              hops,
+              p = p);
+        rust!(rust, "Some({}out)", p);
+        rust!(rust, "}} else {{");
+        rust!(rust, "None");
+        rust!(rust, "}}");
+        rust!(rust, "}}");
+    }
+
+    rust!(rust, "");
+    rust!(rust,
+          "fn {p}unparse<T: ?Sized>({p}out: &mut String, {p}value: &T, \
+           {p}unparser: fn(&mut String, &T, usize) -> bool, {p}max_hops: usize) -> bool {{",
+          p = p);
+    rust!(rust, "for {p}hops in 0..{p}max_hops + 1 {{", p = p);
+    rust!(rust, "let {p}start = {p}out.len();", p = p);
+    rust!(rust, "if {p}unparser({p}out, {p}value, {p}hops) {{", p = p);
+    rust!(rust, "return true;");
+    rust!(rust, "}}");
+    rust!(rust, "{p}out.truncate({p}start);", p = p);
+    rust!(rust, "}}");
+    rust!(rust, "false");
+    rust!(rust, "}}");
+
+    // tokens are separated by a space, except after opening
+    // delimiters and before closing ones and separators
+    rust!(rust, "");
+    rust!(rust,
+          "fn {p}unparse_text({p}out: &mut String, {p}text: &str) -> bool {{",
+          p = p);
+    rust!(rust,
+          "let {p}open = {p}out.ends_with(|c| c == '(' || c == '[' || c == '{{');",
+          p = p);
+    rust!(rust,
+          "let {p}close = {p}text.starts_with(|c| \
+           c == ')' || c == ']' || c == '}}' || c == ',' || c == ';');",
+          p = p);
+    rust!(rust,
+          "if !{p}out.is_empty() && !{p}text.is_empty() && !{p}open && !{p}close {{",
+          p = p);
+    rust!(rust, "{}out.push(' ');", p);
+    rust!(rust, "}}");
+    rust!(rust, "{p}out.push_str({p}text);", p = p);
+    rust!(rust, "true");
+    rust!(rust, "}}");
+
+    for (index, defn) in grammar.unparsers.iter().enumerate() {
+        try!(emit_unparser(grammar, index, defn, &lifetimes, hops, rust));
+    }
+
+    Ok(())
+}
+
+fn emit_unparser<W: Write>(grammar: &r::Grammar,
+                           index: usize,
+                           defn: &r::UnparseDefn,
+                           lifetimes: &str,
+                           hops: usize,
+                           rust: &mut RustWrite<W>)
+                           -> io::Result<()> {
+    let p = &grammar.prefix;
+    let passes_on = |alternative: &r::UnparseAlternative| {
+        alternative.items.iter().any(|item| match *item {
+            r::UnparseItem::Passthrough(..) => true,
+            _ => false,
+        })
+    };
+    let hops_parameter = match defn.kind {
+        r::UnparseKind::Alternatives(ref alternatives) if alternatives.iter().any(&passes_on) => {
+            format!("{}hops", p)
+        }
+        _ => format!("_"),
+    };
+    rust!(rust, "");
+    rust!(rust, "// {}", defn.nonterminal);
+    rust!(rust,
+          "fn {p}unparse{}{}({p}out: &mut String, {p}value: &{}, {}: usize) -> bool {{",
+          index,
+          lifetimes,
+          defn.ty,
+          hops_parameter,
+          p = p);
+    match defn.kind {
+        r::UnparseKind::Format(ref format) => {
+            rust!(rust,
+                  "{p}unparse_text({p}out, &format!({:?}, {p}value))",
+                  format,
+                  p = p);
+        }
+
+        r::UnparseKind::Repeat { min, ref element } => {
+            if min > 0 {
+                rust!(rust, "if {}value.len() < {} {{", p, min);
+                rust!(rust, "return false;");
+                rust!(rust, "}}");
+            }
+            rust!(rust, "for {p}e in {p}value {{", p = p);
+            rust!(rust, "if !{} {{", item(grammar, element, hops));
+            rust!(rust, "return false;");
+            rust!(rust, "}}");
+            rust!(rust, "}}");
+            rust!(rust, "true");
+        }
+
+        r::UnparseKind::Alternatives(ref alternatives) => {
+            if alternatives.iter().any(|alternative| !alternative.items.is_empty()) {
+                rust!(rust, "let {p}start = {p}out.len();", p = p);
+            }
+            for alternative in alternatives {
+                let items: Vec<String> = alternative.items
+                                                    .iter()
+                                                    .map(|i| item(grammar, i, hops))
+                                                    .collect();
+                match alternative.pattern {
+                    Some(ref pattern) => {
+                        // the guard keeps the `_` arm reachable when
+                        // the pattern matches every value
+                        rust!(rust,
+                              "match {}{}value {{",
+                              "*".repeat(alternative.derefs + 1),
+                              p);
+                        rust!(rust, "{} if true => {{", pattern);
+                    }
+                    None => {
+                        if passes_on(alternative) {
+                            rust!(rust, "if {}hops > 0 {{", p);
+                        } else {
+                            rust!(rust, "{{");
+                        }
+                    }
+                }
+                if items.is_empty() {
+                    rust!(rust, "return true;");
+                } else {
+                    rust!(rust, "if {} {{", items.join(" && "));
+                    rust!(rust, "return true;");
+                    rust!(rust, "}}");
+                    rust!(rust, "{p}out.truncate({p}start);", p = p);
+                }
+                if alternative.pattern.is_some() {
+                    rust!(rust, "}}");
+                    rust!(rust, "_ => {{}}");
+                }
+                rust!(rust, "}}");
+            }
+            rust!(rust, "false");
+        }
+    }
+    rust!(rust, "}}");
+    Ok(())
+}
+
+fn item(grammar: &r::Grammar, item: &r::UnparseItem, hops: usize) -> String {
+    let p = &grammar.prefix;
+    match *item {
+        r::UnparseItem::Text(ref text) => format!("{p}unparse_text({p}out, {:?})", text, p = p),
+        r::UnparseItem::Display(ref value) => {
+            format!("{p}unparse_text({p}out, &format!(\"{{}}\", {}))", value, p = p)
+        }
+        r::UnparseItem::Nonterminal(index, ref value) => {
+            format!("{p}unparse({p}out, {}, {p}unparse{}, {})", value, index, hops, p = p)
+        }
+        r::UnparseItem::Passthrough(index, ref value) => {
+            format!("{p}unparse{}({p}out, {}, {p}hops - 1)", index, value, p = p)
+        }
+    }
+}
@@ -0,0 +1,243 @@
+//! Generates `extern "C"` entry points for the public parsers of a
+//! grammar, and a C header declaring them (`--emit c`), so that the
+//! parsers can be used from C, C++ or anything else with a C FFI.
+//! For each public nonterminal `Expr` of `calc.lalrpop` we add to the
+//! parser module:
+//!
+//!     #[no_mangle]
+//!     pub unsafe extern "C" fn calc_parse_Expr(input: *const u8, len: usize,
+//!                                              error: *mut lalrpop_util::ffi::Error)
+//!                                              -> *mut c_void { ... }
+//!
+//!     #[no_mangle]
+//!     pub unsafe extern "C" fn calc_free_Expr(value: *mut c_void) { ... }
+//!
+//! plus a `calc_free_error` function for the messages of errors. The
+//! value is returned boxed, as an opaque handle, so that the C code
+//! can hand it back to other Rust functions of the crate. The work is
+//! done by `lalrpop_util::ffi`.
+//!
+//! As for benchmarks, only grammars that use the built-in tokenizer
+//! and take no parameters get entry points, and only if they keep its
+//! byte offsets as locations; the header of other grammars just
+//! explains why it is empty.
+
+use bench::unsupported_reason;
+use grammar::repr::*;
+use intern::intern;
+use lalrpop_util::ffi;
+use rust::RustWrite;
+use session::Session;
+use std::io::{self, Write};
+
+#[cfg(test)]
+mod test;
+
+/// The prefix of the C functions generated for the grammar in the
+/// file named `file_stem`: the stem, with anything that can't appear
+/// in a C identifier replaced by `_`.
+pub fn symbol_prefix(file_stem: &str) -> String {
+    let mut prefix: String =
+        file_stem.chars()
+                 .map(|c| if (c as u32) < 128 && c.is_alphanumeric() { c } else { '_' })
+                 .collect();
+    if prefix.starts_with(|c: char| c.is_digit(10)) {
+        prefix.insert(0, '_');
+    }
+    prefix
+}
+
+/// Appends the entry points for the parsers of `grammar`, whose C
+/// functions are named `<prefix>_parse_<Nonterminal>` etc., to the
+/// parser module written by `out`.
+pub fn generate_entry_points<W: Write>(out: &mut RustWrite<W>,
+                                       session: &Session,
+                                       grammar: &Grammar,
+                                       prefix: &str)
+                                       -> io::Result<()> {
+    let nonterminals = entry_point_nonterminals(grammar);
+    if nonterminals.is_empty() {
+        return Ok(());
+    }
+
+    let p = &grammar.prefix;
+    rust!(out, "");
+    rust!(out, "// C entry points; see `lalrpop_util::ffi`.");
+    for &nt in &nonterminals {
+        let cfg = session.parser_cfgs.get(&nt.to_string());
+
+        rust!(out, "");
+        if let Some(predicate) = cfg {
+            rust!(out, "#[cfg({})]", predicate);
+        }
+        rust!(out, "#[no_mangle]");
+        rust!(out, "#[allow(non_snake_case)]");
+        rust!(out, "pub unsafe extern \"C\" fn {}_parse_{}(input: *const u8, len: usize, \
+                    error: *mut {}lalrpop_util::ffi::Error) -> *mut ::std::os::raw::c_void {{",
+              prefix, nt, p);
+        rust!(out, "{}lalrpop_util::ffi::parse(input, len, error, |text| parse_{}(text))", p, nt);
+        rust!(out, "}}");
+
+        rust!(out, "");
+        if let Some(predicate) = cfg {
+            rust!(out, "#[cfg({})]", predicate);
+        }
+        rust!(out, "#[no_mangle]");
+        rust!(out, "#[allow(non_snake_case)]");
+        rust!(out, "pub unsafe extern \"C\" fn {}_free_{}(value: *mut ::std::os::raw::c_void) {{",
+              prefix, nt);
+        rust!(out, "{}lalrpop_util::ffi::free::<{}>(value)",
+              p, grammar.types.nonterminal_type(nt));
+        rust!(out, "}}");
+    }
+
+    rust!(out, "");
+    rust!(out, "#[no_mangle]");
+    rust!(out, "pub unsafe extern \"C\" fn {}_free_error(error: *mut {}lalrpop_util::ffi::Error) {{",
+          prefix, p);
+    rust!(out, "{}lalrpop_util::ffi::free_error(error)", p);
+    rust!(out, "}}");
+    Ok(())
+}
+
+/// Writes the C header declaring the entry points that
+/// `generate_entry_points` adds for `grammar`, which was read from
+/// `file_name`.
+pub fn generate_header<W: Write>(out: &mut W,
+                                 session: &Session,
+                                 grammar: &Grammar,
+                                 prefix: &str,
+                                 file_name: &str)
+                                 -> io::Result<()> {
+    try!(writeln!(out, "/* C interface to the parsers of `{}`, generated by LALRPOP. */",
+                  file_name));
+
+    if let Some(reason) = unsupported(grammar) {
+        try!(writeln!(out, ""));
+        try!(writeln!(out, "/* No entry points were generated because {}. */", reason));
+        return Ok(());
+    }
+
+    let guard = format!("{}_H", prefix.to_uppercase());
+    try!(writeln!(out, ""));
+    try!(writeln!(out, "#ifndef {}", guard));
+    try!(writeln!(out, "#define {}", guard));
+    try!(writeln!(out, ""));
+    try!(writeln!(out, "#include <stddef.h>"));
+    try!(writeln!(out, "#include <stdint.h>"));
+    try!(writeln!(out, ""));
+    try!(writeln!(out, "#ifdef __cplusplus"));
+    try!(writeln!(out, "extern \"C\" {{"));
+    try!(writeln!(out, "#endif"));
+
+    // shared by the headers of all grammars
+    try!(writeln!(out, ""));
+    try!(writeln!(out, "#ifndef LALRPOP_ERROR_DEFINED"));
+    try!(writeln!(out, "#define LALRPOP_ERROR_DEFINED"));
+    try!(writeln!(out, ""));
+    try!(writeln!(out, "/* The values of `lalrpop_error.kind`. */"));
+    try!(writeln!(out, "enum {{"));
+    for &(name, value) in ERROR_KINDS {
+        try!(writeln!(out, "    LALRPOP_{} = {},", name, value));
+    }
+    try!(writeln!(out, "}};"));
+    try!(writeln!(out, ""));
+    try!(writeln!(out, "/* Why a parse failed: `start` and `end` are the byte offsets of the"));
+    try!(writeln!(out, "   offending input. `message` belongs to Rust; free it with the"));
+    try!(writeln!(out, "   `_free_error` function of the grammar. */"));
+    try!(writeln!(out, "typedef struct lalrpop_error {{"));
+    try!(writeln!(out, "    uint32_t kind;"));
+    try!(writeln!(out, "    size_t start;"));
+    try!(writeln!(out, "    size_t end;"));
+    try!(writeln!(out, "    char *message;"));
+    try!(writeln!(out, "}} lalrpop_error;"));
+    try!(writeln!(out, ""));
+    try!(writeln!(out, "#endif"));
+
+    for &nt in grammar.start_nonterminals.keys() {
+        try!(writeln!(out, ""));
+        if borrows_input(grammar, nt) {
+            try!(writeln!(out, "/* No entry point for `{}`, whose values borrow from the input. */",
+                          nt));
+            continue;
+        }
+        let handle = format!("{}_{}", prefix, nt);
+        try!(writeln!(out, "/* Parses the `len` bytes of UTF-8 text at `input` as the nonterminal `{}`.", nt));
+        try!(writeln!(out, "   Returns NULL on failure, after filling in `*error` (if `error` is"));
+        try!(writeln!(out, "   not NULL)."));
+        if let Some(predicate) = session.parser_cfgs.get(&nt.to_string()) {
+            try!(writeln!(out, "   Only available if the crate is compiled with `cfg({})`.", predicate));
+        }
+        try!(writeln!(out, "   The result must be freed with `{}_free_{}`. */", prefix, nt));
+        try!(writeln!(out, "typedef struct {} {};", handle, handle));
+        try!(writeln!(out,
+                      "{} *{}_parse_{}(const uint8_t *input, size_t len, lalrpop_error *error);",
+                      handle, prefix, nt));
+        try!(writeln!(out, "void {}_free_{}({} *value);", prefix, nt, handle));
+    }
+
+    if !entry_point_nonterminals(grammar).is_empty() {
+        try!(writeln!(out, ""));
+        try!(writeln!(out, "void {}_free_error(lalrpop_error *error);", prefix));
+    }
+
+    try!(writeln!(out, ""));
+    try!(writeln!(out, "#ifdef __cplusplus"));
+    try!(writeln!(out, "}}"));
+    try!(writeln!(out, "#endif"));
+    try!(writeln!(out, ""));
+    try!(writeln!(out, "#endif"));
+    Ok(())
+}
+
+const ERROR_KINDS: &'static [(&'static str, u32)] = &[
+    ("OK", ffi::OK),
+    ("INVALID_UTF8", ffi::INVALID_UTF8),
+    ("INVALID_TOKEN", ffi::INVALID_TOKEN),
+    ("UNRECOGNIZED_TOKEN", ffi::UNRECOGNIZED_TOKEN),
+    ("UNEXPECTED_EOF", ffi::UNEXPECTED_EOF),
+    ("EXTRA_TOKEN", ffi::EXTRA_TOKEN),
+    ("USER", ffi::USER),
+    ("OTHER", ffi::OTHER),
+    ("PANIC", ffi::PANIC),
+];
+
+/// Why `grammar` gets no entry points, if it doesn't: besides the
+/// reasons it could not be benchmarked, `lalrpop_util::ffi` reports
+/// errors at byte offsets.
+fn unsupported(grammar: &Grammar) -> Option<&'static str> {
+    unsupported_reason(grammar).or_else(|| {
+        if grammar.custom_locations() {
+            Some("the grammar declares its own `Location` type")
+        } else {
+            None
+        }
+    })
+}
+
+/// The public nonterminals that get entry points.
+fn entry_point_nonterminals(grammar: &Grammar) -> Vec<NonterminalString> {
+    if unsupported(grammar).is_some() {
+        return vec![];
+    }
+    grammar.start_nonterminals
+           .keys()
+           .cloned()
+           .filter(|&nt| !borrows_input(grammar, nt))
+           .collect()
+}
+
+/// True if the values of `nt` may borrow from the input (or from
+/// anything else that is not `'static`), which C code could free
+/// while they are in use.
+fn borrows_input(grammar: &Grammar, nt: NonterminalString) -> bool {
+    let static_lifetime = intern("'static");
+    grammar.types
+           .nonterminal_type(nt)
+           .referenced()
+           .iter()
+           .any(|p| match *p {
+               TypeParameter::Lifetime(lifetime) => lifetime != static_lifetime,
+               TypeParameter::Id(_) => false,
+           })
+}
@@ -0,0 +1,101 @@
+use rust::RustWrite;
+use session::Session;
+use test_util::normalized_grammar;
+
+use super::{generate_entry_points, generate_header, symbol_prefix};
+
+fn entry_points(session: &Session, grammar: &str) -> String {
+    let grammar = normalized_grammar(grammar);
+    let mut out = RustWrite::new(vec![]);
+    generate_entry_points(&mut out, session, &grammar, "calc").unwrap();
+    String::from_utf8(out.into_inner()).unwrap()
+}
+
+fn header(session: &Session, grammar: &str) -> String {
+    let grammar = normalized_grammar(grammar);
+    let mut out = vec![];
+    generate_header(&mut out, session, &grammar, "calc", "calc.lalrpop").unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+const GRAMMAR: &'static str = r#"
+grammar;
+    pub Expr: Box<u32> = { <e:Expr> "+" <n:Num> => Box::new(*e + n), Num => Box::new(<>) };
+    pub Num: u32 = r"[0-9]+" => u32::from_str_radix(<>, 10).unwrap();
+    pub Word: &'input str = r"[a-z]+";
+    pub Keyword: &'static str = "let" => "let";
+"#;
+
+#[test]
+fn one_entry_point_per_public_nonterminal() {
+    let out = entry_points(&Session::test(), GRAMMAR);
+    assert!(out.contains("pub unsafe extern \"C\" fn calc_parse_Expr(input: *const u8, \
+                          len: usize, error: *mut __lalrpop_util::ffi::Error) \
+                          -> *mut ::std::os::raw::c_void {"));
+    assert!(out.contains("__lalrpop_util::ffi::parse(input, len, error, |text| parse_Num(text))"));
+    assert!(out.contains("__lalrpop_util::ffi::free::<Box<u32>>(value)"));
+    assert!(out.contains("__lalrpop_util::ffi::free::<&'static str>(value)"));
+    assert!(out.contains("pub unsafe extern \"C\" fn calc_free_Num("));
+    assert!(out.contains("pub unsafe extern \"C\" fn calc_free_error("));
+
+    // `Word` borrows from the input, which the caller may free
+    assert!(!out.contains("Word"));
+}
+
+#[test]
+fn header_declarations() {
+    let out = header(&Session::test(), GRAMMAR);
+    assert!(out.contains("#ifndef CALC_H"));
+    assert!(out.contains("    LALRPOP_UNRECOGNIZED_TOKEN = 3,"));
+    assert!(out.contains("typedef struct calc_Expr calc_Expr;"));
+    assert!(out.contains("calc_Expr *calc_parse_Expr(const uint8_t *input, size_t len, \
+                          lalrpop_error *error);"));
+    assert!(out.contains("void calc_free_Num(calc_Num *value);"));
+    assert!(out.contains("/* No entry point for `Word`, whose values borrow from the input. */"));
+    assert!(out.contains("void calc_free_error(lalrpop_error *error);"));
+}
+
+#[test]
+fn parser_cfgs() {
+    let mut session = Session::test();
+    session.parser_cfgs.insert(String::from("Num"), String::from("feature = \"num\""));
+    let out = entry_points(&session, GRAMMAR);
+    assert!(out.contains("#[cfg(feature = \"num\")]\n#[no_mangle]\n#[allow(non_snake_case)]\n\
+                          pub unsafe extern \"C\" fn calc_parse_Num("));
+    let out = header(&session, GRAMMAR);
+    assert!(out.contains("Only available if the crate is compiled with `cfg(feature = \"num\")`."));
+}
+
+#[test]
+fn external_tokenizer() {
+    let grammar = r#"
+grammar;
+    extern { enum Tok { Num => Tok::Num } }
+    pub Expr: () = Num => ();
+"#;
+    assert_eq!(entry_points(&Session::test(), grammar), "");
+    assert_eq!(header(&Session::test(), grammar),
+               "/* C interface to the parsers of `calc.lalrpop`, generated by LALRPOP. */\n\
+                \n\
+                /* No entry points were generated because the grammar uses an external \
+                tokenizer. */\n");
+}
+
+#[test]
+fn custom_locations() {
+    let grammar = r#"
+grammar;
+    extern { type Location = lalrpop_util::LineCol; }
+    pub Expr: () = "x" => ();
+"#;
+    assert_eq!(entry_points(&Session::test(), grammar), "");
+    assert!(header(&Session::test(), grammar)
+                .contains("because the grammar declares its own `Location` type."));
+}
+
+#[test]
+fn prefixes() {
+    assert_eq!(symbol_prefix("calc"), "calc");
+    assert_eq!(symbol_prefix("my-grammar.v2"), "my_grammar_v2");
+    assert_eq!(symbol_prefix("2d"), "_2d");
+}
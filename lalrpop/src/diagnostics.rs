@@ -0,0 +1,115 @@
+//! The errors and warnings that `process_str` and `check_str`
+//! return, rather than printing them as the processing of files does.
+
+use lr1::json_string;
+use std::error::Error;
+use std::fmt;
+
+/// How serious a `Diagnostic` is.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// A problem found in a grammar.
+#[derive(Clone, Debug)]
+pub struct Diagnostic {
+    pub severity: Severity,
+
+    /// The byte offsets in the grammar text of the start and end of
+    /// what the diagnostic is about, if it can be pinned down.
+    pub span: Option<(usize, usize)>,
+
+    /// The line and column, both counted from 1, where `span` starts.
+    pub location: Option<(usize, usize)>,
+
+    /// The description of the problem. For a conflict, this spans
+    /// several lines, with the examples that lead to it.
+    pub message: String,
+}
+
+/// The diagnostics for a grammar. When `process_str` fails, there
+/// is at least one error among them.
+#[derive(Clone, Debug)]
+pub struct Diagnostics {
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl Diagnostics {
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics.iter().any(|d| d.severity == Severity::Error)
+    }
+
+    /// Serializes the diagnostics as a JSON array, for use from
+    /// JavaScript when lalrpop is compiled to WebAssembly. Each
+    /// element looks like
+    ///
+    /// ```json
+    /// { "severity": "error", "span": [17, 18], "line": 2, "column": 9,
+    ///   "message": "no definition found for `B`" }
+    /// ```
+    ///
+    /// where `span`, `line` and `column` are `null` if the diagnostic
+    /// is not about a particular place in the grammar.
+    pub fn to_json(&self) -> String {
+        let entries: Vec<String> =
+            self.diagnostics
+                .iter()
+                .map(|d| {
+                    let span = match d.span {
+                        Some((start, end)) => format!("[{}, {}]", start, end),
+                        None => String::from("null"),
+                    };
+                    let (line, column) = match d.location {
+                        Some((line, column)) => (line.to_string(), column.to_string()),
+                        None => (String::from("null"), String::from("null")),
+                    };
+                    format!("{{\"severity\": {}, \"span\": {}, \"line\": {}, \"column\": {}, \
+                             \"message\": {}}}",
+                            json_string(&d.severity.to_string()),
+                            span,
+                            line,
+                            column,
+                            json_string(&d.message))
+                })
+                .collect();
+        format!("[{}]", entries.join(", "))
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Severity::Error => write!(fmt, "error"),
+            Severity::Warning => write!(fmt, "warning"),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        if let Some((line, column)) = self.location {
+            try!(write!(fmt, "{}:{}: ", line, column));
+        }
+        write!(fmt, "{}: {}", self.severity, self.message)
+    }
+}
+
+impl fmt::Display for Diagnostics {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        for (index, diagnostic) in self.diagnostics.iter().enumerate() {
+            if index > 0 {
+                try!(writeln!(fmt, ""));
+            }
+            try!(write!(fmt, "{}", diagnostic));
+        }
+        Ok(())
+    }
+}
+
+impl Error for Diagnostics {
+    fn description(&self) -> &str {
+        "the grammar could not be processed"
+    }
+}
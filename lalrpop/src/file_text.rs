@@ -1,13 +1,17 @@
 use grammar::parse_tree as pt;
 use std::fmt::{Display, Formatter, Error};
 use std::fs::File;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::io::{self, Read, Write};
 
 pub struct FileText {
     path: PathBuf,
     input_str: String,
     newlines: Vec<usize>,
+
+    // the files added by `append`: the offset of each one in
+    // `input_str`, and its path
+    appended: Vec<(usize, PathBuf)>,
 }
 
 impl FileText {
@@ -30,7 +34,46 @@ impl FileText {
                    .collect()
         };
 
-        FileText { path: path, input_str: input_str, newlines: newline_indices }
+        FileText {
+            path: path,
+            input_str: input_str,
+            newlines: newline_indices,
+            appended: vec![],
+        }
+    }
+
+    /// Appends the text of the file `path` (like the grammar that this
+    /// one extends), returning the offset where it starts. Spans past
+    /// that offset are reported relative to `path`.
+    pub fn append(&mut self, path: PathBuf, text: &str) -> usize {
+        if !self.input_str.is_empty() && !self.input_str.ends_with('\n') {
+            self.input_str.push('\n');
+            self.newlines.push(self.input_str.len());
+        }
+        let offset = self.input_str.len();
+        self.input_str.push_str(text);
+        self.newlines.extend(text.as_bytes()
+                                 .iter()
+                                 .enumerate()
+                                 .filter(|&(_, &b)| b == ('\n' as u8))
+                                 .map(|(i, _)| offset + i + 1));
+        self.appended.push((offset, path));
+        offset
+    }
+
+    /// The files that make up the text: this file and then the ones
+    /// appended to it, each with its path, text and offset.
+    pub fn files(&self) -> Vec<(&Path, &str, usize)> {
+        let mut files = vec![];
+        let mut path = &self.path;
+        let mut start = 0;
+        for &(offset, ref next_path) in &self.appended {
+            files.push((path.as_path(), &self.input_str[start..offset], start));
+            path = next_path;
+            start = offset;
+        }
+        files.push((path.as_path(), &self.input_str[start..], start));
+        files
     }
 
     #[cfg(test)]
@@ -38,19 +81,33 @@ impl FileText {
         Self::new(PathBuf::from("test.lalrpop"), String::from(""))
     }
 
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
     pub fn text(&self) -> &String {
         &self.input_str
     }
 
     pub fn span_str(&self, span: pt::Span) -> String {
-        let (start_line, start_col) = self.line_col(span.0);
-        let (end_line, end_col) = self.line_col(span.1);
+        let (path, start_line, start_col) = self.location(span.0);
+        let (_, end_line, end_col) = self.location(span.1);
         format!("{}:{}:{}: {}:{}",
-                self.path.display(),
+                path.display(),
                 start_line+1, start_col+1, end_line+1, end_col)
     }
 
-    fn line_col(&self, pos: usize) -> (usize, usize) {
+    /// Like `line_col`, but relative to the file that `pos` is in, in
+    /// case others were appended to this one.
+    pub fn location(&self, pos: usize) -> (&Path, usize, usize) {
+        let (line, col) = self.line_col(pos);
+        match self.appended.iter().rev().find(|&&(offset, _)| offset <= pos) {
+            Some(&(offset, ref path)) => (path.as_path(), line - self.line_col(offset).0, col),
+            None => (self.path.as_path(), line, col),
+        }
+    }
+
+    pub fn line_col(&self, pos: usize) -> (usize, usize) {
         let num_lines = self.newlines.len();
         let line =
             (0..num_lines)
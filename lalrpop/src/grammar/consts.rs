@@ -25,3 +25,136 @@ pub const RECURSIVE_ASCENT: &'static str = "recursive_ascent";
 /// Annotation to request test-all-style code generation.
 pub const TEST_ALL: &'static str = "test_all";
 
+/// Module attribute, `#![token_stream]`, for grammars whose parsers
+/// read the token trees of a procedural macro.
+pub const TOKEN_STREAM: &'static str = "token_stream";
+
+
+/// Annotation to request that a nonterminal be expanded into
+/// precedence tiers from the operator annotations on its alternatives.
+pub const PRATT: &'static str = "pratt";
+
+/// Operator annotations for the alternatives of a `#[pratt]` nonterminal.
+pub const LEFT: &'static str = "left";
+pub const RIGHT: &'static str = "right";
+pub const PREFIX: &'static str = "prefix";
+pub const POSTFIX: &'static str = "postfix";
+
+/// Annotations that tell an Earley parser which alternative to prefer
+/// when the input is ambiguous, and how to merge alternatives when
+/// there is no preference.
+pub const DPREC: &'static str = "dprec";
+pub const MERGE: &'static str = "merge";
+
+/// Annotation that declares how a nonterminal recovers from errors,
+/// like `#[recover(skip_until = [";"], value = "Stmt::Error")]`, and
+/// its parameters.
+pub const RECOVER: &'static str = "recover";
+pub const SKIP_UNTIL: &'static str = "skip_until";
+pub const VALUE: &'static str = "value";
+
+/// Annotation on the grammar, like `#[recover_delimiters(open = ["(",
+/// "{"], close = [")", "}"])]`, declaring the brackets that error
+/// recovery skips over as a unit: when it drops an `open` token, it
+/// drops everything up to the matching `close` token with it. The
+/// parameters are `OPEN` and `CLOSE`.
+pub const RECOVER_DELIMITERS: &'static str = "recover_delimiters";
+
+/// Annotation that leaves a nonterminal or alternative out of the
+/// grammar unless the settings it lists, like `#[cfg(feature =
+/// "ext-syntax")]`, are all enabled (see `Configuration::grammar_cfg`).
+pub const CFG: &'static str = "cfg";
+
+/// Annotation that wraps the values of a nonterminal in a `Box`, so
+/// that `#[boxed] Expr: Expr = ...` has the type `Box<Expr>`, as
+/// needed by the recursive variants of an AST enum.
+pub const BOXED: &'static str = "boxed";
+
+/// Annotation that marks an alternative as introduced in a version of
+/// the language, like `#[since = "2.0"]`; parsers are then given the
+/// version to parse and reject the alternative in older ones.
+pub const SINCE: &'static str = "since";
+
+/// Annotation on an entry of a `match` section, like `#[unescape]
+/// r#""(\\.|[^"\\])*""# => STRING`, that makes the value of the
+/// terminal a `Cow<'input, str>` with its quotes removed and its
+/// escape sequences decoded.
+pub const UNESCAPE: &'static str = "unescape";
+
+/// Annotation on a keyword in a `match` section, like `#[reserved =
+/// "identifier"] "while"`, so that the parser reports the keyword as
+/// `ParseError::ReservedWord` where it expected a terminal that would
+/// otherwise match its text. The description defaults to
+/// `identifier`.
+pub const RESERVED: &'static str = "reserved";
+pub const DEFAULT_RESERVED_DESCRIPTION: &'static str = "identifier";
+
+/// Annotation on a keyword in a `match` section, like `#[contextual]
+/// "union"`, so that the keyword is only a keyword where the parser
+/// can accept it; elsewhere it is the terminal that would otherwise
+/// match its text, like an identifier.
+pub const CONTEXTUAL: &'static str = "contextual";
+
+/// Annotation on a `match` or `extern` section, like `#[normalize =
+/// "fold_keywords"]`, naming a function that every token goes through
+/// before it reaches the parser.
+pub const NORMALIZE: &'static str = "normalize";
+
+/// Annotation on a `match` section, like `#[newlines(insert = [";"],
+/// after = [ID, ")"], open = ["("], close = [")"])]`, that makes the
+/// tokenizer insert a terminal at the line breaks (and the end of the
+/// input) that follow certain terminals, outside of brackets, as
+/// automatic semicolon insertion does; and its parameters.
+pub const NEWLINES: &'static str = "newlines";
+pub const INSERT: &'static str = "insert";
+pub const AFTER: &'static str = "after";
+pub const OPEN: &'static str = "open";
+pub const CLOSE: &'static str = "close";
+
+/// Annotation that limits how deeply a nonterminal may be nested in
+/// the input, like `#[max_nesting(depth = "256")] Array = "[" ...`;
+/// deeper input is rejected with `ParseError::DepthLimit`.
+pub const MAX_NESTING: &'static str = "max_nesting";
+pub const DEPTH: &'static str = "depth";
+
+/// Annotation that lets the parser look at the token after the next
+/// one to decide conflicts in parsing a nonterminal, like `#[lookahead
+/// = "2"] Label = Id;`, where one token of lookahead is not enough.
+pub const LOOKAHEAD: &'static str = "lookahead";
+
+/// Annotation that asks for an `unparse_X` function, like
+/// `#[unparse] pub Expr: Box<Expr> = ...`, which writes the values of
+/// a nonterminal back as text, from the actions of its alternatives.
+pub const UNPARSE: &'static str = "unparse";
+
+/// Annotation that tells `unparse_X` functions to write the values of
+/// a nonterminal with a format string, like `#[format = "{}"] Num:
+/// i32 = ...`, rather than from its alternatives.
+pub const FORMAT: &'static str = "format";
+
+/// Annotation that asks LALRPOP to declare the type of a nonterminal,
+/// like `#[ast] Assign = <name:Id> "=" <value:Expr>;`, with a field for
+/// each named symbol plus the locations where the value starts and
+/// ends, and to build its values without any action code.
+pub const AST: &'static str = "ast";
+
+/// Annotation that names the enum variant an alternative of an `#[ast]`
+/// nonterminal builds, like `#[variant = "Add"]`.
+pub const VARIANT: &'static str = "variant";
+
+/// Module attribute (`#![ast_text]`) that gives the types of `#[ast]`
+/// nonterminals a `text` field too, with the input they were parsed
+/// from.
+pub const AST_TEXT: &'static str = "ast_text";
+
+/// Module attribute, like `#![ast_derive(Clone, Debug)]`, that lists
+/// the traits to derive for the types of `#[ast]` nonterminals, rather
+/// than `Clone`, `Debug` and `PartialEq`; as an annotation, like
+/// `#[ast_derive = "Clone, Copy"]`, it overrides the list for one.
+pub const AST_DERIVE: &'static str = "ast_derive";
+
+/// Module attribute, like `#![ast_attr(serde(tag = "kind"))]`, that
+/// puts an attribute on the types of all `#[ast]` nonterminals; as an
+/// annotation, like `#[ast_attr = "serde(rename = \"expr\")"]`, it
+/// puts one on the type of one of them.
+pub const AST_ATTR: &'static str = "ast_attr";
@@ -5,15 +5,16 @@ some pre-expansion and so forth before creating the proper AST.
 
 */
 
+use file_text::FileText;
 use intern::{intern, InternedString};
 use lexer::dfa::DFA;
-use grammar::consts::{LALR, RECURSIVE_ASCENT, TABLE_DRIVEN, TEST_ALL};
+use grammar::consts::{LALR, RECOVER_DELIMITERS, RECURSIVE_ASCENT, TABLE_DRIVEN, TEST_ALL};
 use grammar::repr::{self as r, NominalTypeRepr, TypeRepr};
 use grammar::pattern::Pattern;
 use message::Content;
 use message::builder::InlineBuilder;
+use session::Session;
 use std::fmt::{Debug, Display, Formatter, Error};
-use tls::Tls;
 use util::Sep;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -27,14 +28,47 @@ pub struct Grammar {
     pub items: Vec<GrammarItem>,
     pub annotations: Vec<Annotation>,
     pub module_attributes: Vec<String>,
+
+    // the `extend "base.lalrpop";` declaration, if any; see
+    // `normalize::stitch`
+    pub extends: Option<Extends>,
+
+    // for `#![token_stream]` grammars, the keywords, operators and
+    // delimiters; see `normalize::token_stream`
+    pub token_stream: Option<Vec<InternedString>>,
+}
+
+/// The declaration `extend "base.lalrpop";`, which makes a grammar a
+/// dialect of the grammar in `base.lalrpop`: it inherits the items of
+/// that grammar, overriding the nonterminals it defines again. It may
+/// name the base grammar, as in `extend "base.lalrpop" as base;`, so
+/// that `base::Expr` refers to the `Expr` of the base grammar, and
+/// rename its nonterminals, as in `extend "base.lalrpop" { Expr as
+/// BaseExpr };`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Extends {
+    pub span: Span,
+    // the path of the base grammar, relative to the directory of
+    // the extending grammar
+    pub path: String,
+    pub namespace: Option<InternedString>,
+    pub renames: Vec<Rename>,
+}
+
+/// `Expr as BaseExpr` in `extend "base.lalrpop" { Expr as BaseExpr };`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rename {
+    pub span: Span,
+    pub from: NonterminalString,
+    pub to: NonterminalString,
 }
 
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Span(pub usize, pub usize);
 
-impl Into<Box<Content>> for Span {
-    fn into(self) -> Box<Content> {
-        let file_text = Tls::file_text();
+impl Span {
+    /// The location of this span in `file_text`, for use in a message.
+    pub fn into_content(self, file_text: &FileText) -> Box<Content> {
         let string = file_text.span_str(self);
 
         // Insert an Adjacent block to prevent wrapping inside this
@@ -56,13 +90,32 @@ pub enum GrammarItem {
 pub struct MatchToken {
     pub contents: Vec<MatchContents>,
     pub span: Span,
+
+    // like `#[normalize = "fold_keywords"]`
+    pub annotations: Vec<Annotation>,
+
+    // for `match "tokens.lalrpop";`, the path of the token module
+    // (see `Grammar::is_token_module`) whose `match` section this
+    // stands for; the build replaces it with that section
+    pub module: Option<String>,
 }
 
 impl MatchToken {
     pub fn new(contents: MatchContents, span: Span) -> MatchToken {
         MatchToken {
             contents: vec![contents],
-            span: span
+            span: span,
+            annotations: vec![],
+            module: None,
+        }
+    }
+
+    pub fn import(module: String, span: Span) -> MatchToken {
+        MatchToken {
+            contents: vec![],
+            span: span,
+            annotations: vec![],
+            module: Some(module),
         }
     }
 
@@ -72,7 +125,9 @@ impl MatchToken {
         new_contents.push(contents);
         MatchToken {
             contents: new_contents,
-            span: self.span
+            span: self.span,
+            annotations: self.annotations,
+            module: self.module,
         }
     }
 }
@@ -84,11 +139,16 @@ pub struct MatchContents {
 
 // FIXME: Validate that MatchSymbol is actually a TerminalString::Literal
 //          and that MatchMapping is an Id or String
+//
+// The annotations of an entry, like `#[unescape]`, follow its span,
+// and a mapped entry ends with its hook, if any: the function that
+// post-processes the text of the token, as in `=> STRING { f }`.
+// Entries like `r"..." => { f }` are mapped to themselves.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum MatchItem {
     CatchAll(Span),
-    Unmapped(MatchSymbol, Span),
-    Mapped(MatchSymbol, MatchMapping, Span)
+    Unmapped(MatchSymbol, Span, Vec<Annotation>),
+    Mapped(MatchSymbol, MatchMapping, Span, Vec<Annotation>, Option<Path>)
 }
 
 impl MatchItem {
@@ -101,9 +161,17 @@ impl MatchItem {
 
     pub fn span(&self) -> Span {
         match *self {
-            MatchItem::CatchAll(span)     => span,
-            MatchItem::Unmapped(_, span)  => span,
-            MatchItem::Mapped(_, _, span) => span
+            MatchItem::CatchAll(span)        => span,
+            MatchItem::Unmapped(_, span, _)  => span,
+            MatchItem::Mapped(_, _, span, _, _) => span
+        }
+    }
+
+    pub fn annotations(&self) -> &[Annotation] {
+        match *self {
+            MatchItem::CatchAll(_) => &[],
+            MatchItem::Unmapped(_, _, ref annotations) |
+            MatchItem::Mapped(_, _, _, ref annotations, _) => annotations,
         }
     }
 }
@@ -119,9 +187,59 @@ pub struct InternToken {
     /// Set of `r"foo"` and `"foo"` literals extracted from the
     /// grammar. Sorted by order of increasing precedence.
     pub match_entries: Vec<MatchEntry>,
+
+    /// The keywords marked `#[reserved]`.
+    pub reserved_words: Vec<ReservedWord>,
+
+    /// The keywords marked `#[contextual]`.
+    pub contextual_keywords: Vec<ContextualKeyword>,
+
+    /// The terminal to insert at line breaks, if the `match` section
+    /// has a `#[newlines(...)]` annotation.
+    pub newlines: Option<Newlines>,
+
     pub dfa: DFA
 }
 
+/// A keyword marked `#[reserved = "..."]`, and the terminals whose
+/// patterns match its text, so that the keyword would be one of them
+/// if it were not a keyword.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReservedWord {
+    /// The index of the keyword in `match_entries`.
+    pub index: usize,
+
+    /// What the keyword is reserved from, like `identifier`.
+    pub description: InternedString,
+
+    pub shadowed: Vec<TerminalString>,
+}
+
+/// A keyword marked `#[contextual]`, and the terminal that its text
+/// is lexed as where the parser cannot accept the keyword.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContextualKeyword {
+    /// The index of the keyword in `match_entries`.
+    pub index: usize,
+
+    /// The index in `match_entries` of the highest-precedence other
+    /// terminal whose pattern matches the keyword's text.
+    pub fallback: usize,
+}
+
+/// The rules of `#[newlines(...)]`, with the terminals as indices in
+/// `match_entries`: at a line break, or at the end of the input, the
+/// tokenizer produces `insert` if the previous token was one of
+/// `after` and every `open` token since the start has been matched
+/// by a `close` token.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Newlines {
+    pub insert: usize,
+    pub after: Vec<usize>,
+    pub open: Vec<usize>,
+    pub close: Vec<usize>,
+}
+
 /// In `token_check`, as we prepare to generate a tokenizer, we
 /// combine any `match` declaration the user may have given with the
 /// set of literals (e.g. `"foo"` or `r"[a-z]"`) that appear elsewhere
@@ -159,11 +277,32 @@ pub struct MatchEntry {
     pub precedence: usize,
     pub match_literal: TerminalLiteral,
     pub user_name: TerminalString,
+
+    /// If true (`#[unescape]`), the value of the token is its text
+    /// with its quotes removed and its escape sequences decoded; see
+    /// `lalrpop_util::unescape`.
+    pub unescape: bool,
+
+    /// The function that turns the text of the token into its value,
+    /// a `Cow<'input, str>`, if any (`=> NAME { hook }`).
+    pub hook: Option<Path>,
+
+    /// What the keyword is reserved from, if it is marked
+    /// `#[reserved]`; see `ReservedWord`.
+    pub reserved: Option<InternedString>,
+
+    /// If true (`#[contextual]`), the keyword is only a keyword where
+    /// the parser can accept it; see `ContextualKeyword`.
+    pub contextual: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ExternToken {
     pub span: Span,
+
+    // like `#[normalize = "fold_keywords"]`
+    pub annotations: Vec<Annotation>,
+
     pub associated_types: Vec<AssociatedType>,
     pub enum_token: Option<EnumToken>,
 }
@@ -180,6 +319,20 @@ pub struct EnumToken {
     pub type_name: TypeRef,
     pub type_span: Span,
     pub conversions: Vec<Conversion>,
+
+    // `enum PpTok for Tok::Pp { ... }` blocks, which are folded into
+    // `conversions` by the `token_adapter` pass
+    pub adapters: Vec<TokenAdapter>,
+}
+
+/// Conversions for the tokens of another enum, `PpTok`, that reach
+/// the parser wrapped in a variant of the token type, `Tok::Pp`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TokenAdapter {
+    pub type_name: TypeRef,
+    pub type_span: Span,
+    pub variant: Path,
+    pub conversions: Vec<Conversion>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -187,6 +340,9 @@ pub struct Conversion {
     pub span: Span,
     pub from: TerminalString,
     pub to: Pattern<TypeRef>,
+
+    // if <guard>, checked once `to` matches; may use its bindings
+    pub guard: Option<String>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -358,17 +514,45 @@ pub struct NonterminalData {
 pub struct Annotation {
     pub id_span: Span,
     pub id: InternedString,
+
+    // the string in `#[id = "..."]`, if any
+    pub arg: Option<(Span, InternedString)>,
+
+    // the `key = value` pairs in `#[id(key = value, ...)]`
+    pub params: Vec<AnnotationParam>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnnotationParam {
+    pub key_span: Span,
+    pub key: InternedString,
+    pub value: AnnotationValue,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AnnotationValue {
+    // key = "..."
+    String(InternedString),
+
+    // key = ["a", r"b"]
+    Terminals(Vec<TerminalString>),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Alternative {
     pub span: Span,
 
+    // operator annotations like `#[left = "1"]`; only legal in `#[pratt]` nonterminals
+    pub annotations: Vec<Annotation>,
+
     pub expr: ExprSymbol,
 
     // if C, only legal in macros
     pub condition: Option<Condition>,
 
+    // if { code }, checked before the action runs
+    pub guard: Option<String>,
+
     // => { code }
     pub action: Option<ActionKind>,
 }
@@ -502,12 +686,9 @@ impl NonterminalString
     pub fn len(&self) -> usize {
         self.0.len()
     }
-}
-
-impl Into<Box<Content>> for NonterminalString {
-    fn into(self) -> Box<Content> {
-        let session = Tls::session();
 
+    /// This nonterminal, styled as `session` asks, for use in a message.
+    pub fn into_content(self, session: &Session) -> Box<Content> {
         InlineBuilder::new().text(self).styled(session.nonterminal_symbol).end()
     }
 }
@@ -542,11 +723,9 @@ impl TerminalString {
     pub fn regex(i: InternedString) -> TerminalString {
         TerminalString::Literal(TerminalLiteral::Regex(i))
     }
-}
 
-impl Into<Box<Content>> for TerminalString {
-    fn into(self) -> Box<Content> {
-        let session = Tls::session();
+    /// This terminal, styled as `session` asks, for use in a message.
+    pub fn into_content(self, session: &Session) -> Box<Content> {
         InlineBuilder::new()
             .text(self)
             .styled(session.terminal_symbol)
@@ -579,6 +758,15 @@ impl Grammar {
                   .flat_map(|i| i.as_match_token())
                   .next()
     }
+
+    /// True for a token module: a file with a `match` section and
+    /// no nonterminals, which grammars share by importing it with
+    /// `match "tokens.lalrpop";`. No parser is generated for it.
+    pub fn is_token_module(&self) -> bool {
+        self.extends.is_none() &&
+        self.match_token().map_or(false, |m| m.module.is_none()) &&
+        self.items.iter().all(|item| item.as_nonterminal().is_none())
+    }
 }
 
 impl GrammarItem {
@@ -1003,6 +1191,13 @@ impl Path {
         }
     }
 
+    pub fn boxed() -> Path {
+        Path {
+            absolute: true,
+            ids: vec![intern("std"), intern("boxed"), intern("Box")]
+        }
+    }
+
     pub fn option() -> Path {
         Path {
             absolute: true,
@@ -1017,6 +1212,14 @@ impl Path {
             None
         }
     }
+
+    /// True if, used as a pattern, this path binds a variable (like
+    /// `c` in `Tok::Op(c)`) rather than naming a constant or variant.
+    pub fn is_binding(&self) -> bool {
+        self.as_id().map_or(false, |id| {
+            id.to_string().starts_with(|c: char| c.is_lowercase() || c == '_')
+        })
+    }
 }
 
 pub fn read_algorithm(annotations: &[Annotation], algorithm: &mut r::Algorithm) {
@@ -1029,6 +1232,8 @@ pub fn read_algorithm(annotations: &[Annotation], algorithm: &mut r::Algorithm)
             algorithm.codegen = r::LrCodeGeneration::RecursiveAscent;
         } else if annotation.id == intern(TEST_ALL) {
             algorithm.codegen = r::LrCodeGeneration::TestAll;
+        } else if annotation.id == intern(RECOVER_DELIMITERS) {
+            // not an algorithm; see `recover_delimiters`
         } else {
             panic!("validation permitted unknown annotation: {:?}",
                     annotation.id);
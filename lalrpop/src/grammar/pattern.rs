@@ -85,6 +85,52 @@ impl<T> PatternKind<T> {
     }
 }
 
+impl<T> Pattern<T> {
+    /// Whether the pattern, read as an expression, is the one value
+    /// that it matches, like `Tok::Plus` (but not `Tok::Num(_)`).
+    pub fn is_expression(&self) -> bool {
+        match self.kind {
+            PatternKind::Path(ref path) => !path.is_binding(),
+            PatternKind::Enum(_, ref pats) |
+            PatternKind::Tuple(ref pats) |
+            PatternKind::TupleStruct(_, ref pats) => pats.iter().all(|p| p.is_expression()),
+            PatternKind::Struct(_, ref fields, dotdot) =>
+                !dotdot && fields.iter().all(|f| f.pattern.is_expression()),
+            PatternKind::Usize(_) | PatternKind::CharLiteral(_) => true,
+            PatternKind::Underscore | PatternKind::DotDot | PatternKind::Choose(_) => false,
+        }
+    }
+}
+
+impl<T: Clone> Pattern<T> {
+    /// Replaces the variables that this pattern binds, like the `c`
+    /// in `Tok::Op(c)`, with `_`.
+    pub fn without_bindings(&self) -> Pattern<T> {
+        let kind = match self.kind {
+            PatternKind::Path(ref path) if path.is_binding() =>
+                PatternKind::Underscore,
+            PatternKind::Enum(ref path, ref pats) =>
+                PatternKind::Enum(path.clone(), pats.iter().map(|p| p.without_bindings()).collect()),
+            PatternKind::Struct(ref path, ref fields, dotdot) =>
+                PatternKind::Struct(
+                    path.clone(),
+                    fields.iter()
+                          .map(|f| FieldPattern { field_span: f.field_span,
+                                                  field_name: f.field_name,
+                                                  pattern: f.pattern.without_bindings() })
+                          .collect(),
+                    dotdot),
+            PatternKind::Tuple(ref pats) =>
+                PatternKind::Tuple(pats.iter().map(|p| p.without_bindings()).collect()),
+            PatternKind::TupleStruct(ref path, ref pats) =>
+                PatternKind::TupleStruct(path.clone(),
+                                         pats.iter().map(|p| p.without_bindings()).collect()),
+            ref kind => kind.clone(),
+        };
+        Pattern { span: self.span, kind: kind }
+    }
+}
+
 impl<T> FieldPattern<T> {
     pub fn map<U>(&self, map_fn: &mut FnMut(&T) -> U) -> FieldPattern<U> {
         FieldPattern {
@@ -4,11 +4,16 @@
  * representation incrementally.
  */
 
-use intern::{intern, InternedString};
+use intern::{intern, read, InternedString};
+use lalrpop_util::Version;
+use grammar::consts::{DEPTH, LOOKAHEAD, MAX_NESTING};
+use grammar::parse_tree::AnnotationValue;
 use grammar::pattern::{Pattern};
 use message::Content;
 use std::fmt::{Debug, Display, Formatter, Error};
 use collections::{map, Map};
+use lint::{Lint, LintLevel};
+use session::Session;
 use util::Sep;
 
 // These concepts we re-use wholesale
@@ -31,6 +36,10 @@ pub struct Grammar {
     // true if the grammar mentions the `!` terminal anywhere
     pub uses_error_recovery: bool,
 
+    // true if the grammar is marked `#![repair]`, which generates a
+    // `parse_X_repaired` function that repairs syntax errors
+    pub repair: bool,
+
     // these are the nonterminals that were declared to be public; the
     // key is the user's name for the symbol, the value is the
     // artificial symbol we introduce, which will always have a single
@@ -53,6 +62,10 @@ pub struct Grammar {
     // an extern token declaration
     pub intern_token: Option<InternToken>,
 
+    // for `#![token_stream]` grammars, the keywords, operators and
+    // delimiters, in the order of the indices of `Token::Fixed`
+    pub token_stream: Option<Vec<InternedString>>,
+
     // the grammar proper:
 
     pub action_fn_defns: Vec<ActionFnDefn>,
@@ -60,8 +73,34 @@ pub struct Grammar {
     pub nonterminals: Map<NonterminalString, NonterminalData>,
     pub token_span: Span,
     pub conversions: Map<TerminalString, Pattern<TypeRepr>>,
+    pub conversion_guards: Map<TerminalString, String>,
+
+    // functions applied to the values that `conversions` extract, like
+    // the one decoding the escapes of `#[unescape]` terminals
+    pub conversion_fns: Map<TerminalString, String>,
+
+    // `#[normalize = "..."]`: the function that the tokens of an
+    // `extern` section go through, or that the built-in lexer applies
+    // to the text of its tokens to recognize fixed strings in them
+    pub token_normalizer: Option<String>,
+
+    // `#[recover_delimiters(...)]`: the pairs of brackets that error
+    // recovery drops as a unit
+    pub recover_delimiters: Vec<(TerminalString, TerminalString)>,
+
     pub types: Types,
     pub module_attributes: Vec<String>,
+
+    // levels of the lints changed by `#![allow(lalrpop::...)]` and
+    // similar attributes; these are not part of `module_attributes`
+    pub lint_levels: Map<Lint, LintLevel>,
+
+    // how to write the values of the nonterminals that `#[unparse]`
+    // nonterminals are made of back as text, if there are any
+    pub unparsers: Vec<UnparseDefn>,
+
+    // the types that the parser declares for `#[ast]` nonterminals
+    pub ast_types: Vec<AstType>,
 }
 
 /// For each terminal, we map it to a small integer from 0 to N.
@@ -80,6 +119,31 @@ pub struct NonterminalData {
     pub productions: Vec<Production>,
 }
 
+impl NonterminalData {
+    /// The depth of the `#[max_nesting(depth = "N")]` annotation of
+    /// this nonterminal, if it has one.
+    pub fn max_nesting(&self) -> Option<usize> {
+        self.annotations
+            .iter()
+            .filter(|a| a.id == intern(MAX_NESTING))
+            .flat_map(|a| &a.params)
+            .filter_map(|param| match param.value {
+                AnnotationValue::String(s) if param.key == intern(DEPTH) => {
+                    read(|interner| interner.data(s).parse().ok())
+                }
+                _ => None,
+            })
+            .next()
+    }
+
+    /// Whether this nonterminal is marked `#[lookahead = "2"]`, so that
+    /// conflicts in reducing it may be resolved by looking at the token
+    /// after the next one.
+    pub fn two_token_lookahead(&self) -> bool {
+        self.annotations.iter().any(|a| a.id == intern(LOOKAHEAD))
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Algorithm {
     pub lalr: bool,
@@ -91,6 +155,10 @@ pub enum LrCodeGeneration {
     TableDriven,
     RecursiveAscent,
     TestAll,
+
+    /// Not LR at all: an Earley parser that interprets the grammar at
+    /// runtime (see `lr1::codegen::earley`), selected by `#![earley]`.
+    Earley,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -99,6 +167,101 @@ pub struct Parameter {
     pub ty: TypeRepr,
 }
 
+/// The type that the parser declares for an `#[ast]` nonterminal: a
+/// struct, or an enum with a variant for each alternative.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AstType {
+    pub nonterminal: NonterminalString,
+
+    /// the type, with the type parameters of the grammar that its
+    /// fields use, like `Expr<'input>`
+    pub ty: TypeRepr,
+    pub type_parameters: Vec<TypeParameter>,
+
+    /// the locations, `start` and `end`, that every node has
+    pub loc_type: TypeRepr,
+
+    /// the traits to derive, from `#![ast_derive(...)]` or the
+    /// nonterminal's `#[ast_derive = "..."]`, and the attributes to put
+    /// on the type, from `#![ast_attr(...)]` and `#[ast_attr = "..."]`
+    pub derives: Vec<String>,
+    pub attributes: Vec<String>,
+
+    /// one variant without a name for a struct
+    pub variants: Vec<AstVariant>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AstVariant {
+    pub name: Option<InternedString>,
+
+    /// all fields, including `start`, `end` and `text`
+    pub fields: Vec<(InternedString, TypeRepr)>,
+}
+
+/// How the `unparse_X` functions, which `#[unparse]` asks for, write
+/// the values of a nonterminal back as text that parses to them. The
+/// unparsers refer to each other by their index in
+/// `Grammar::unparsers`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnparseDefn {
+    pub nonterminal: NonterminalString,
+    pub ty: TypeRepr,
+
+    /// true for the nonterminals marked `#[unparse]`, which get a
+    /// public `unparse_X` function
+    pub public: bool,
+
+    pub kind: UnparseKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UnparseKind {
+    /// `#[format = "{}"]`: the value is formatted with this string
+    Format(String),
+
+    /// `X*` and `X+`: the elements of the `Vec`, of which there must
+    /// be at least `min`, in turn, with `element` reading each one
+    /// from the variable `e` (with the grammar's prefix)
+    Repeat { min: usize, element: UnparseItem },
+
+    /// the first of these alternatives that can write the value
+    Alternatives(Vec<UnparseAlternative>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnparseAlternative {
+    /// the pattern that the values built by the alternative's action
+    /// match, with `ref` bindings for the symbols; none if the action
+    /// passes the value of one of its symbols on as it is
+    pub pattern: Option<String>,
+
+    /// the number of boxes around the values, which are dereferenced
+    /// before matching them against `pattern`
+    pub derefs: usize,
+
+    /// the symbols of the alternative, in order
+    pub items: Vec<UnparseItem>,
+}
+
+/// How to write a symbol of an alternative; the strings are the Rust
+/// expressions for the references to its value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UnparseItem {
+    /// a terminal whose value is dropped, written as it is quoted
+    Text(String),
+
+    /// a terminal whose value is kept, written with `Display`
+    Display(String),
+
+    /// a nonterminal, written by the unparser with this index
+    Nonterminal(usize, String),
+
+    /// the nonterminal whose value the alternative passes on, which
+    /// is the whole value being written
+    Passthrough(usize, String),
+}
+
 #[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Production {
     // this overlaps with the key in the hashmap, obviously, but it's
@@ -107,6 +270,13 @@ pub struct Production {
     pub symbols: Vec<Symbol>,
     pub action: ActionFn,
     pub span: Span,
+
+    // only used by Earley parsers: when the input is ambiguous, the
+    // alternatives with the highest `dprec` win, and if there are
+    // several of them with the same `merge` function, their results
+    // are combined with it
+    pub dprec: u32,
+    pub merge: Option<InternedString>,
 }
 
 #[derive(Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
@@ -135,6 +305,20 @@ pub struct UserActionFnDefn {
     pub arg_patterns: Vec<InternedString>,
     pub arg_types: Vec<TypeRepr>,
     pub code: String,
+
+    /// code of the `if { ... }` guard, if any; the action fn returns
+    /// `ParseError::GuardFailed` when it is false, so an action with
+    /// a guard is always fallible
+    pub guard: Option<String>,
+
+    /// the version given by `#[since = "..."]`, if any; like a
+    /// guard, this makes the action fn fallible, returning
+    /// `ParseError::VersionTooOld` for older versions
+    pub since: Option<Version>,
+
+    /// span of the alternative the action was written in; used to
+    /// map the generated action fn back to the grammar source
+    pub span: Span,
 }
 
 /// An action fn generated by the inlining pass.  If we were
@@ -205,6 +389,15 @@ impl TypeRepr {
         })
     }
 
+    /// `::std::boxed::Box<ty>`, the type of a `#[boxed]` nonterminal
+    /// whose alternatives produce `ty`.
+    pub fn boxed(ty: TypeRepr) -> TypeRepr {
+        TypeRepr::Nominal(NominalTypeRepr {
+            path: Path::boxed(),
+            types: vec![ty]
+        })
+    }
+
     /// Returns the type parameters (or potential type parameters)
     /// referenced by this type. e.g., for the type `&'x X`, would
     /// return `[TypeParameter::Lifetime('x), TypeParameter::Id(X)]`.
@@ -296,6 +489,10 @@ impl Types {
         assert!(self.nonterminal_types.insert(nt_id, ty).is_none());
     }
 
+    pub fn remove_type(&mut self, nt_id: NonterminalString) -> Option<TypeRepr> {
+        self.nonterminal_types.remove(&nt_id)
+    }
+
     pub fn add_term_type(&mut self, term: TerminalString, ty: TypeRepr) {
         assert!(self.terminal_types.insert(term, ty).is_none());
     }
@@ -444,6 +641,14 @@ impl Symbol {
             Symbol::Nonterminal(id) => t.nonterminal_type(id),
         }
     }
+
+    /// This symbol, styled as `session` asks, for use in a message.
+    pub fn into_content(self, session: &Session) -> Box<Content> {
+        match self {
+            Symbol::Nonterminal(nt) => nt.into_content(session),
+            Symbol::Terminal(term) => term.into_content(session),
+        }
+    }
 }
 
 impl Display for Symbol {
@@ -461,11 +666,14 @@ impl Debug for Symbol {
     }
 }
 
-impl Into<Box<Content>> for Symbol {
-    fn into(self) -> Box<Content> {
-        match self {
-            Symbol::Nonterminal(nt) => nt.into(),
-            Symbol::Terminal(term) => term.into(),
+/// `Foo = Bar "baz"`, or `Foo = ()` for an empty production, leaving
+/// out the action.
+impl Display for Production {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        if self.symbols.is_empty() {
+            write!(fmt, "{} = ()", self.nonterminal)
+        } else {
+            write!(fmt, "{} = {}", self.nonterminal, Sep(" ", &self.symbols))
         }
     }
 }
@@ -503,8 +711,13 @@ impl UserActionFnDefn {
                    .map(|(p, t)| format!("{}: {}", p, t))
                    .collect();
 
-        format!("fn {}({}) -> {} {{ {} }}",
-                name, Sep(", ", &arg_strings), defn.ret_type, self.code)
+        match self.guard {
+            Some(ref guard) => format!("fn {}({}) -> {} if {{ {} }} {{ {} }}",
+                                       name, Sep(", ", &arg_strings), defn.ret_type, guard,
+                                       self.code),
+            None => format!("fn {}({}) -> {} {{ {} }}",
+                            name, Sep(", ", &arg_strings), defn.ret_type, self.code),
+        }
     }
 }
 
@@ -530,6 +743,21 @@ impl Grammar {
         &self.conversions[&t]
     }
 
+    /// The guard that the token has to satisfy, besides matching
+    /// `pattern(t)`, if any.
+    pub fn conversion_guard(&self, t: TerminalString) -> Option<&str> {
+        self.conversion_guards.get(&t).map(|g| &g[..])
+    }
+
+    /// The expression for the value of the token `t`, given the names
+    /// bound by the placeholders of `pattern(t)`.
+    pub fn terminal_value(&self, t: TerminalString, pattern_names: &[String]) -> String {
+        match self.conversion_fns.get(&t) {
+            Some(f) => format!("{}({})", f, pattern_names.join(", ")),
+            None => format!("({})", pattern_names.join(", ")),
+        }
+    }
+
     pub fn productions_for(&self, nonterminal: NonterminalString) -> &[Production] {
         match self.nonterminals.get(&nonterminal) {
             Some(v) => &v.productions[..],
@@ -537,6 +765,14 @@ impl Grammar {
         }
     }
 
+    /// True if the grammar uses the built-in tokenizer but declares
+    /// its own `Location` type in an `extern` section, so that the
+    /// byte offsets of the tokenizer have to be converted (see
+    /// `lalrpop_util::Locations`).
+    pub fn custom_locations(&self) -> bool {
+        self.intern_token.is_some() && self.types.terminal_loc_type() != TypeRepr::usize()
+    }
+
     pub fn user_parameter_refs(&self) -> String {
         let mut result = String::new();
         for parameter in &self.parameters {
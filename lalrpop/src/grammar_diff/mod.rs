@@ -0,0 +1,238 @@
+//! Compares two versions of a grammar, to help review changes to it.
+//! We report the productions that were added, removed or whose action
+//! code changed, and then look for sentences (up to some length) that
+//! one version accepts but the other does not.
+
+use collections::{set, Set};
+use grammar::repr::*;
+use lr1;
+use std::io::{self, Write};
+use util::Sep;
+
+#[cfg(test)]
+mod test;
+
+/// The longest sentences we enumerate when comparing the languages
+/// of the two grammars.
+pub const MAX_SENTENCE_LENGTH: usize = 6;
+
+/// How many differing sentences to list for each public nonterminal
+/// and direction.
+const MAX_EXAMPLES: usize = 5;
+
+pub fn diff_grammars<W: Write>(out: &mut W,
+                               old: &Grammar,
+                               new: &Grammar,
+                               max_length: usize)
+                               -> io::Result<()> {
+    let mut differences = false;
+
+    let nonterminals: Set<NonterminalString> =
+        user_nonterminals(old).into_iter().chain(user_nonterminals(new)).collect();
+    for &nt in &nonterminals {
+        let old_productions = old.productions_for(nt);
+        let new_productions = new.productions_for(nt);
+
+        for production in old_productions {
+            match find_production(new_productions, production) {
+                None => {
+                    try!(writeln!(out, "- {}", production));
+                    differences = true;
+                }
+                Some(other) => {
+                    if action_code(old, production) != action_code(new, other) {
+                        try!(writeln!(out, "~ {} (action code changed)",
+                                      production));
+                        differences = true;
+                    }
+                }
+            }
+        }
+
+        for production in new_productions {
+            if find_production(old_productions, production).is_none() {
+                try!(writeln!(out, "+ {}", production));
+                differences = true;
+            }
+        }
+    }
+
+    for (&user_nt, _) in &old.start_nonterminals {
+        if !new.start_nonterminals.contains_key(&user_nt) {
+            continue;
+        }
+
+        let old_only = rejected(new, user_nt, lr1::sentences(old, user_nt, max_length));
+        let new_only = rejected(old, user_nt, lr1::sentences(new, user_nt, max_length));
+
+        for &(which, other, ref sentences) in &[("old", "new", old_only),
+                                                 ("new", "old", new_only)] {
+            if sentences.is_empty() {
+                continue;
+            }
+            try!(writeln!(out, ""));
+            try!(writeln!(out, "Inputs that `{}` accepts in the {} grammar but not the {} one:",
+                          user_nt, which, other));
+            for sentence in sentences.iter().take(MAX_EXAMPLES) {
+                if sentence.is_empty() {
+                    try!(writeln!(out, "    (empty input)"));
+                } else {
+                    try!(writeln!(out, "    {}", Sep(" ", sentence)));
+                }
+            }
+            differences = true;
+        }
+    }
+
+    if !differences {
+        try!(writeln!(out, "No differences found (compared inputs of up to {} tokens).",
+                      max_length));
+    }
+
+    Ok(())
+}
+
+/// The nonterminals of `grammar`, leaving out those we add for each
+/// public nonterminal.
+fn user_nonterminals(grammar: &Grammar) -> Set<NonterminalString> {
+    let start_nonterminals: Set<NonterminalString> =
+        grammar.start_nonterminals.values().cloned().collect();
+    grammar.nonterminals
+           .keys()
+           .cloned()
+           .filter(|nt| !start_nonterminals.contains(nt))
+           .collect()
+}
+
+fn find_production<'grammar>(productions: &'grammar [Production],
+                             production: &Production)
+                             -> Option<&'grammar Production> {
+    productions.iter().find(|p| p.symbols == production.symbols)
+}
+
+fn action_code<'grammar>(grammar: &'grammar Grammar, production: &Production) -> Option<&'grammar str> {
+    match grammar.action_fn_defns[production.action.index()].kind {
+        ActionFnDefnKind::User(ref defn) => Some(&defn.code),
+        ActionFnDefnKind::Inline(_) | ActionFnDefnKind::Lookaround(_) => None,
+    }
+}
+
+/// Filters `sentences` down to those that `nonterminal` does not
+/// accept in `grammar`.
+fn rejected(grammar: &Grammar,
+            nonterminal: NonterminalString,
+            sentences: Set<Vec<TerminalString>>)
+            -> Vec<Vec<TerminalString>> {
+    let mut rejected: Vec<_> =
+        sentences.into_iter()
+                 .filter(|sentence| !accepts(grammar, nonterminal, sentence))
+                 .collect();
+    rejected.sort_by_key(|sentence| sentence.len());
+    rejected
+}
+
+#[derive(Copy, Clone)]
+struct EarleyItem<'grammar> {
+    production: &'grammar Production,
+    dot: usize,
+    origin: usize,
+}
+
+impl<'grammar> PartialEq for EarleyItem<'grammar> {
+    fn eq(&self, other: &EarleyItem<'grammar>) -> bool {
+        self.production as *const Production == other.production as *const Production &&
+            self.dot == other.dot &&
+            self.origin == other.origin
+    }
+}
+
+impl<'grammar> EarleyItem<'grammar> {
+    fn advance(self) -> EarleyItem<'grammar> {
+        EarleyItem { dot: self.dot + 1, ..self }
+    }
+
+    fn next_symbol(&self) -> Option<Symbol> {
+        self.production.symbols.get(self.dot).cloned()
+    }
+}
+
+/// Tests whether `nonterminal` derives `sentence`, using Earley's
+/// algorithm (which, unlike the LR(1) construction, copes with any
+/// grammar, so it works even if `grammar` has conflicts).
+fn accepts(grammar: &Grammar, nonterminal: NonterminalString, sentence: &[TerminalString]) -> bool {
+    let nullable = nullable_nonterminals(grammar);
+
+    fn add<'grammar>(set: &mut Vec<EarleyItem<'grammar>>, item: EarleyItem<'grammar>) {
+        if !set.contains(&item) {
+            set.push(item);
+        }
+    }
+
+    let mut chart: Vec<Vec<EarleyItem>> = (0..sentence.len() + 1).map(|_| vec![]).collect();
+    for production in grammar.productions_for(nonterminal) {
+        add(&mut chart[0], EarleyItem { production: production, dot: 0, origin: 0 });
+    }
+
+    for i in 0..sentence.len() + 1 {
+        let mut j = 0;
+        while j < chart[i].len() {
+            let item = chart[i][j];
+            j += 1;
+            match item.next_symbol() {
+                Some(Symbol::Nonterminal(nt)) => {
+                    for production in grammar.productions_for(nt) {
+                        add(&mut chart[i], EarleyItem { production: production, dot: 0, origin: i });
+                    }
+                    if nullable.contains(&nt) {
+                        add(&mut chart[i], item.advance());
+                    }
+                }
+                Some(Symbol::Terminal(term)) => {
+                    if i < sentence.len() && sentence[i] == term {
+                        add(&mut chart[i + 1], item.advance());
+                    }
+                }
+                None => {
+                    let mut k = 0;
+                    while k < chart[item.origin].len() {
+                        let parent = chart[item.origin][k];
+                        k += 1;
+                        if parent.next_symbol() == Some(Symbol::Nonterminal(item.production.nonterminal)) {
+                            add(&mut chart[i], parent.advance());
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    chart[sentence.len()].iter().any(|item| {
+        item.origin == 0 &&
+            item.production.nonterminal == nonterminal &&
+            item.next_symbol().is_none()
+    })
+}
+
+fn nullable_nonterminals(grammar: &Grammar) -> Set<NonterminalString> {
+    let mut nullable = set();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (&nt, data) in &grammar.nonterminals {
+            if nullable.contains(&nt) {
+                continue;
+            }
+            let empty = data.productions.iter().any(|production| {
+                production.symbols.iter().all(|symbol| match *symbol {
+                    Symbol::Terminal(_) => false,
+                    Symbol::Nonterminal(other) => nullable.contains(&other),
+                })
+            });
+            if empty {
+                nullable.insert(nt);
+                changed = true;
+            }
+        }
+    }
+    nullable
+}
@@ -0,0 +1,67 @@
+use intern::intern;
+use grammar::repr::*;
+use test_util::normalized_grammar;
+
+use super::{accepts, diff_grammars};
+
+fn nt(t: &str) -> NonterminalString {
+    NonterminalString(intern(t))
+}
+
+fn term(t: &str) -> TerminalString {
+    TerminalString::quoted(intern(t))
+}
+
+fn diff(old: &str, new: &str) -> String {
+    let old = normalized_grammar(old);
+    let new = normalized_grammar(new);
+    let mut out = vec![];
+    diff_grammars(&mut out, &old, &new, 4).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn identical() {
+    let grammar = r#"
+grammar;
+    pub E: () = { E "+" T => (), T => () };
+    T: () = { "(" E ")" => (), "x" => () };
+"#;
+    assert_eq!(diff(grammar, grammar).trim(),
+               "No differences found (compared inputs of up to 4 tokens).");
+}
+
+#[test]
+fn changed_operator() {
+    let out = diff(r#"
+grammar;
+    pub E: () = { E "+" T => (), T => () };
+    T: () = { "x" => () };
+"#, r#"
+grammar;
+    pub E: () = { E "-" T => (), T => () };
+    T: () = { "x" => { () } };
+"#);
+    assert_eq!(out.trim(), r#"- E = E "+" T
++ E = E "-" T
+~ T = "x" (action code changed)
+
+Inputs that `E` accepts in the old grammar but not the new one:
+    "x" "+" "x"
+
+Inputs that `E` accepts in the new grammar but not the old one:
+    "x" "-" "x""#);
+}
+
+#[test]
+fn earley_handles_empty_and_ambiguous_productions() {
+    let grammar = normalized_grammar(r#"
+grammar;
+    pub S: () = { S S => (), A "x" => () };
+    A: () = { () => (), "a" => () };
+"#);
+    assert!(accepts(&grammar, nt("S"), &[term("x")]));
+    assert!(accepts(&grammar, nt("S"), &[term("a"), term("x"), term("x")]));
+    assert!(!accepts(&grammar, nt("S"), &[]));
+    assert!(!accepts(&grammar, nt("S"), &[term("a")]));
+}
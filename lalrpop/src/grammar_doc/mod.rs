@@ -0,0 +1,323 @@
+//! Writes a Markdown reference for a grammar (`--emit doc`). Each
+//! nonterminal gets a section with its type, the `///` comments
+//! written above it and its alternatives, with the action code left
+//! out and every nonterminal linked to its own section. A table at
+//! the end lists the terminals, what they match and where they are
+//! used.
+//!
+//! The alternatives are taken from the grammar as written, before
+//! macros and the like are expanded, since that is what a reader of
+//! the grammar knows; the types and terminals come from the
+//! normalized grammar, since only there have they been worked out.
+
+use collections::{map, Map};
+use grammar::parse_tree as pt;
+use grammar::repr as r;
+use intern::InternedString;
+use std::io::{self, Write};
+
+#[cfg(test)]
+mod test;
+
+pub fn generate_doc<W: Write>(out: &mut W,
+                              title: &str,
+                              text: &str,
+                              parse_tree: &pt::Grammar,
+                              grammar: &r::Grammar)
+                              -> io::Result<()> {
+    let nonterminals: Vec<&pt::NonterminalData> =
+        parse_tree.items
+                  .iter()
+                  .filter_map(|item| match *item {
+                      pt::GrammarItem::Nonterminal(ref data) => Some(data),
+                      _ => None,
+                  })
+                  .collect();
+
+    let cx = DocCx::new(&nonterminals);
+
+    try!(writeln!(out, "# Grammar `{}`", title));
+    try!(writeln!(out, ""));
+    try!(writeln!(out, "## Nonterminals"));
+    try!(writeln!(out, ""));
+    for data in &nonterminals {
+        try!(writeln!(out, "- {}", cx.link(data.name)));
+    }
+
+    for data in &nonterminals {
+        try!(writeln!(out, ""));
+        try!(write_nonterminal(out, &cx, text, grammar, data));
+    }
+
+    try!(writeln!(out, ""));
+    write_terminals(out, &cx, grammar)
+}
+
+struct DocCx<'doc> {
+    nonterminals: Map<pt::NonterminalString, &'doc pt::NonterminalData>,
+
+    // for each nonterminal (or terminal), the nonterminals whose
+    // alternatives refer to it, in the order they are declared
+    nonterminal_uses: Map<pt::NonterminalString, Vec<pt::NonterminalString>>,
+    terminal_uses: Map<pt::TerminalString, Vec<pt::NonterminalString>>,
+}
+
+impl<'doc> DocCx<'doc> {
+    fn new(nonterminals: &[&'doc pt::NonterminalData]) -> DocCx<'doc> {
+        let mut cx = DocCx {
+            nonterminals: nonterminals.iter().map(|data| (data.name, *data)).collect(),
+            nonterminal_uses: map(),
+            terminal_uses: map(),
+        };
+        for data in nonterminals {
+            for alternative in &data.alternatives {
+                for symbol in &alternative.expr.symbols {
+                    cx.record_uses(data, symbol);
+                }
+            }
+        }
+        cx
+    }
+
+    fn record_uses(&mut self, user: &pt::NonterminalData, symbol: &pt::Symbol) {
+        match symbol.kind {
+            pt::SymbolKind::Expr(ref expr) => {
+                for symbol in &expr.symbols {
+                    self.record_uses(user, symbol);
+                }
+            }
+            pt::SymbolKind::AmbiguousId(id) => {
+                match self.resolve(user, id) {
+                    Resolution::Nonterminal(nt) => push_use(&mut self.nonterminal_uses, nt, user.name),
+                    Resolution::Terminal(t) => push_use(&mut self.terminal_uses, t, user.name),
+                    Resolution::Argument => {}
+                }
+            }
+            pt::SymbolKind::Terminal(t) => push_use(&mut self.terminal_uses, t, user.name),
+            pt::SymbolKind::Nonterminal(nt) => push_use(&mut self.nonterminal_uses, nt, user.name),
+            pt::SymbolKind::Macro(ref m) => {
+                push_use(&mut self.nonterminal_uses, m.name, user.name);
+                for arg in &m.args {
+                    self.record_uses(user, arg);
+                }
+            }
+            pt::SymbolKind::Repeat(ref repeat) => self.record_uses(user, &repeat.symbol),
+            pt::SymbolKind::Choose(ref symbol) |
+            pt::SymbolKind::Name(_, ref symbol) => self.record_uses(user, symbol),
+            pt::SymbolKind::Lookahead |
+            pt::SymbolKind::Lookbehind |
+            pt::SymbolKind::Error => {}
+        }
+    }
+
+    /// Identifiers have not been resolved in the grammar as written:
+    /// `X` may be a nonterminal, a terminal declared in an `extern`
+    /// block, or an argument of the macro `user`.
+    fn resolve(&self, user: &pt::NonterminalData, id: InternedString) -> Resolution {
+        let nt = pt::NonterminalString(id);
+        if user.args.contains(&nt) {
+            Resolution::Argument
+        } else if self.nonterminals.contains_key(&nt) {
+            Resolution::Nonterminal(nt)
+        } else {
+            Resolution::Terminal(pt::TerminalString::Bare(id))
+        }
+    }
+
+    fn link(&self, nt: pt::NonterminalString) -> String {
+        format!("[{}](#{})", code(&nt.to_string()), nt)
+    }
+
+    fn links(&self, nts: Option<&Vec<pt::NonterminalString>>) -> String {
+        match nts {
+            Some(nts) => nts.iter().map(|&nt| self.link(nt)).collect::<Vec<_>>().join(", "),
+            None => String::from("-"),
+        }
+    }
+
+    /// The symbols of `alternative` of `user`, without the names
+    /// given to them (since the action code is left out).
+    fn alternative(&self, user: &pt::NonterminalData, alternative: &pt::Alternative) -> String {
+        let symbols = self.symbols(user, &alternative.expr.symbols);
+        if symbols.is_empty() {
+            String::from("*(empty)*")
+        } else {
+            symbols
+        }
+    }
+
+    fn symbols(&self, user: &pt::NonterminalData, symbols: &[pt::Symbol]) -> String {
+        symbols.iter()
+               .map(|symbol| self.symbol(user, symbol))
+               .filter(|symbol| !symbol.is_empty())
+               .collect::<Vec<_>>()
+               .join(" ")
+    }
+
+    fn symbol(&self, user: &pt::NonterminalData, symbol: &pt::Symbol) -> String {
+        match symbol.kind {
+            pt::SymbolKind::Expr(ref expr) => {
+                format!("\\( {} \\)", self.symbols(user, &expr.symbols))
+            }
+            pt::SymbolKind::AmbiguousId(id) => {
+                match self.resolve(user, id) {
+                    Resolution::Nonterminal(nt) => self.link(nt),
+                    Resolution::Terminal(t) => code(&t.to_string()),
+                    Resolution::Argument => code(&id.to_string()),
+                }
+            }
+            pt::SymbolKind::Terminal(t) => code(&t.to_string()),
+            pt::SymbolKind::Nonterminal(nt) => self.link(nt),
+            pt::SymbolKind::Macro(ref m) => {
+                let args: Vec<String> = m.args.iter().map(|arg| self.symbol(user, arg)).collect();
+                format!("{}\\<{}\\>", self.link(m.name), args.join(", "))
+            }
+            pt::SymbolKind::Repeat(ref repeat) => {
+                format!("{}\\{}", self.symbol(user, &repeat.symbol), repeat.op)
+            }
+            pt::SymbolKind::Choose(ref symbol) |
+            pt::SymbolKind::Name(_, ref symbol) => self.symbol(user, symbol),
+            pt::SymbolKind::Lookahead |
+            pt::SymbolKind::Lookbehind => String::new(),
+            pt::SymbolKind::Error => code("!"),
+        }
+    }
+}
+
+enum Resolution {
+    Nonterminal(pt::NonterminalString),
+    Terminal(pt::TerminalString),
+    Argument,
+}
+
+fn push_use<K: Ord>(uses: &mut Map<K, Vec<pt::NonterminalString>>,
+                    used: K,
+                    user: pt::NonterminalString) {
+    let users = uses.entry(used).or_insert(vec![]);
+    if !users.contains(&user) {
+        users.push(user);
+    }
+}
+
+fn write_nonterminal<W: Write>(out: &mut W,
+                               cx: &DocCx,
+                               text: &str,
+                               grammar: &r::Grammar,
+                               data: &pt::NonterminalData)
+                               -> io::Result<()> {
+    let heading = if data.args.is_empty() {
+        data.name.to_string()
+    } else {
+        let args: Vec<String> = data.args.iter().map(|a| a.to_string()).collect();
+        format!("{}<{}>", data.name, args.join(", "))
+    };
+    try!(writeln!(out, "## <a name=\"{}\"></a>{}", data.name, code(&heading)));
+    try!(writeln!(out, ""));
+
+    let doc = doc_comment(text, data.span);
+    if !doc.is_empty() {
+        for line in doc {
+            try!(writeln!(out, "{}", line));
+        }
+        try!(writeln!(out, ""));
+    }
+
+    // macros are only typed once they are expanded, so for those we
+    // can only show what the user declared
+    let ty = match grammar.types.lookup_nonterminal_type(data.name) {
+        Some(ty) if data.args.is_empty() => Some(ty.to_string()),
+        _ => data.type_decl.as_ref().map(|ty| ty.to_string()),
+    };
+    if let Some(ty) = ty {
+        try!(writeln!(out, "Type: {}", code(&ty)));
+        try!(writeln!(out, ""));
+    }
+    if data.public {
+        try!(writeln!(out, "Public: the parser `parse_{}` parses this nonterminal.", data.name));
+        try!(writeln!(out, ""));
+    }
+
+    for alternative in &data.alternatives {
+        try!(writeln!(out, "- {}", cx.alternative(data, alternative)));
+    }
+    try!(writeln!(out, ""));
+
+    writeln!(out, "Used by: {}", cx.links(cx.nonterminal_uses.get(&data.name)))
+}
+
+fn write_terminals<W: Write>(out: &mut W, cx: &DocCx, grammar: &r::Grammar) -> io::Result<()> {
+    try!(writeln!(out, "## Terminals"));
+    try!(writeln!(out, ""));
+    try!(writeln!(out, "| Terminal | Matches | Used by |"));
+    try!(writeln!(out, "| --- | --- | --- |"));
+
+    // the text each terminal matches, or the pattern of the token
+    // it stands for
+    let matches: Map<pt::TerminalString, String> = match grammar.intern_token {
+        Some(ref intern_token) => {
+            intern_token.match_entries
+                        .iter()
+                        .map(|entry| (entry.user_name, entry.match_literal.to_string()))
+                        .collect()
+        }
+        None => {
+            grammar.conversions
+                   .iter()
+                   .map(|(&terminal, pattern)| {
+                       let pattern = match grammar.conversion_guard(terminal) {
+                           Some(guard) => format!("{} if {}", pattern, guard),
+                           None => pattern.to_string(),
+                       };
+                       (terminal, pattern)
+                   })
+                   .collect()
+        }
+    };
+
+    for &terminal in &grammar.terminals.all {
+        if terminal == pt::TerminalString::Error {
+            continue;
+        }
+        let matches = matches.get(&terminal).map(|m| code(m)).unwrap_or(String::from("-"));
+        try!(writeln!(out, "| {} | {} | {} |",
+                      cell(&code(&terminal.to_string())),
+                      cell(&matches),
+                      cx.links(cx.terminal_uses.get(&terminal))));
+    }
+    Ok(())
+}
+
+/// The `///` comments on the lines above the nonterminal named at
+/// `span`, skipping over any annotations in between. The lexer drops
+/// comments, so we have to go back to the text for these.
+fn doc_comment(text: &str, span: pt::Span) -> Vec<String> {
+    let line_start = text[..span.0].rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let mut lines = vec![];
+    for line in text[..line_start].lines().rev() {
+        let line = line.trim();
+        if line.starts_with("///") {
+            let line = &line[3..];
+            lines.push(if line.starts_with(" ") { &line[1..] } else { line });
+        } else if !line.starts_with("#[") {
+            break;
+        }
+    }
+    lines.into_iter().rev().map(|line| line.to_string()).collect()
+}
+
+/// `s` as a Markdown code span. Punctuation between symbols is
+/// written as escaped text instead, since code spans cannot be put
+/// next to one another.
+pub fn code(s: &str) -> String {
+    if s.contains('`') {
+        format!("`` {} ``", s)
+    } else {
+        format!("`{}`", s)
+    }
+}
+
+/// `s` escaped for use in a table cell, where `|` ends the cell (even
+/// in a code span).
+pub fn cell(s: &str) -> String {
+    s.replace("|", "\\|")
+}
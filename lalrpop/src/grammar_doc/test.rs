@@ -0,0 +1,101 @@
+use parser;
+use test_util::normalized_grammar;
+
+use super::generate_doc;
+
+fn doc(text: &str) -> String {
+    let parse_tree = parser::parse_grammar(text).unwrap();
+    let grammar = normalized_grammar(text);
+    let mut out = vec![];
+    generate_doc(&mut out, "calc", text, &parse_tree, &grammar).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn calc() {
+    let out = doc(r#"
+grammar;
+
+/// A sum of terms.
+///
+/// Addition associates to the left.
+pub Expr: i32 = {
+    <l:Expr> "+" <r:Term> => l + r,
+    Term,
+};
+
+#[inline]
+Term: i32 = {
+    Num,
+    "(" <Expr> ")",
+    "[" <Comma<Num>> "]" => <>.len() as i32,
+};
+
+// not documentation
+Comma<T>: Vec<T> = <v:(<T> ",")*> <e:T?> => v;
+
+Num: i32 = r"[0-9]+|0x[0-9a-f]+" => i32::from_str(<>).unwrap();
+"#);
+    assert_eq!(out, r#####"# Grammar `calc`
+
+## Nonterminals
+
+- [`Expr`](#Expr)
+- [`Term`](#Term)
+- [`Comma`](#Comma)
+- [`Num`](#Num)
+
+## <a name="Expr"></a>`Expr`
+
+A sum of terms.
+
+Addition associates to the left.
+
+Type: `i32`
+
+Public: the parser `parse_Expr` parses this nonterminal.
+
+- [`Expr`](#Expr) `"+"` [`Term`](#Term)
+- [`Term`](#Term)
+
+Used by: [`Expr`](#Expr), [`Term`](#Term)
+
+## <a name="Term"></a>`Term`
+
+Type: `i32`
+
+- [`Num`](#Num)
+- `"("` [`Expr`](#Expr) `")"`
+- `"["` [`Comma`](#Comma)\<[`Num`](#Num)\> `"]"`
+
+Used by: [`Expr`](#Expr)
+
+## <a name="Comma"></a>`Comma<T>`
+
+Type: `Vec<T>`
+
+- \( `T` `","` \)\* `T`\?
+
+Used by: [`Term`](#Term)
+
+## <a name="Num"></a>`Num`
+
+Type: `i32`
+
+- `r#"[0-9]+|0x[0-9a-f]+"#`
+
+Used by: [`Term`](#Term)
+
+## Terminals
+
+| Terminal | Matches | Used by |
+| --- | --- | --- |
+| `"("` | `"("` | [`Term`](#Term) |
+| `")"` | `")"` | [`Term`](#Term) |
+| `"+"` | `"+"` | [`Expr`](#Expr) |
+| `","` | `","` | [`Comma`](#Comma) |
+| `"["` | `"["` | [`Term`](#Term) |
+| `"]"` | `"]"` | [`Term`](#Term) |
+| `r#"[0-9]+\|0x[0-9a-f]+"#` | `r#"[0-9]+\|0x[0-9a-f]+"#` | [`Num`](#Num) |
+"#####);
+}
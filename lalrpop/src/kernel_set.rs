@@ -1,4 +1,5 @@
 use std::collections::VecDeque;
+use std::collections::vec_deque;
 use std::fmt::Debug;
 use std::hash::Hash;
 use collections::{map, Map};
@@ -34,4 +35,9 @@ impl<K:Kernel> KernelSet<K> {
     pub fn next(&mut self) -> Option<K> {
         self.kernels.pop_front()
     }
+
+    /// The kernels that were added but not yet taken by `next`.
+    pub fn pending(&self) -> vec_deque::Iter<K> {
+        self.kernels.iter()
+    }
 }
@@ -11,7 +11,6 @@ use lexer::nfa::{self, NFA, NFAConstructionError, NFAStateIndex, Test};
 #[cfg(test)]
 mod test;
 
-#[cfg(test)]
 pub mod interpret;
 
 mod overlap;
@@ -26,11 +26,22 @@ mod __intern_token {
         fn __tokenize(&self, text: &str) -> Option<(usize, usize)> { ... }
     }
 
+    // with fast scanning, for each token that has a scan plan:
+    fn __scan0(__text: &str) -> usize { ... }
+
     impl<'input> Iterator for __Matcher<'input> {
         type Item = Result<(usize, Token<'input>, usize), ParseError>;
         //                  ~~~~~  ~~~~~~~~~~~~~  ~~~~~
         //                  start  token          end
     }
+
+    // with `#[reserved]` keywords:
+    pub fn __reserved_word<'input, L, E>(error: ParseError<L, Token<'input>, E>)
+                                         -> ParseError<L, Token<'input>, E> { ... }
+
+    // with token arrays:
+    pub fn tokenize<'input>(input: &'input str)
+                            -> Result<TokenArray, ParseError<usize, Token<'input>, E>> { ... }
 }
 ```
 
@@ -38,18 +49,33 @@ mod __intern_token {
 
 use intern;
 use lexer::re;
-use grammar::parse_tree::InternToken;
+use lexer::scan::{self, ByteClass, ScanPlan, ScanStep};
+use grammar::parse_tree::{InternToken, Newlines};
 use grammar::repr::{Grammar, TerminalLiteral};
 use rust::RustWrite;
+use session::Session;
 use std::io::{self, Write};
 
 pub fn compile<W: Write>(
+    session: &Session,
     grammar: &Grammar,
     intern_token: &InternToken,
     out: &mut RustWrite<W>)
     -> io::Result<()>
 {
     let prefix = &grammar.prefix;
+    let fast_scanning = session.fast_scanning;
+
+    // Panic-free lexers keep the regexes as `Option`s, rather than
+    // unwrapping them when the lexer is created, and report anything
+    // that goes wrong with them as `ParseError::Internal`.
+    let panic_free = session.panic_free;
+    let internal_error = |message: &str| {
+        format!("{}lalrpop_util::ParseError::Internal {{ \
+                 message: ::std::string::String::from({:?}) }}",
+                prefix,
+                message)
+    };
 
     rust!(out, "mod {}intern_token {{", prefix);
     rust!(out, "#![allow(unused_imports)]");
@@ -57,7 +83,11 @@ pub fn compile<W: Write>(
     rust!(out, "extern crate regex as {}regex;", prefix);
     rust!(out, "use std::fmt as {}fmt;", prefix);
     rust!(out, "");
-    rust!(out, "#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]");
+    if session.derive_serde {
+        rust!(out, "#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, ::serde::Serialize, ::serde::Deserialize)]");
+    } else {
+        rust!(out, "#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]");
+    }
     rust!(out, "pub struct Token<'input>(pub usize, pub &'input str);");
     rust!(out, "impl<'a> {}fmt::Display for Token<'a> {{", prefix);
     rust!(out, "fn fmt(&self, formatter: &mut {}fmt::Formatter) -> Result<(), {}fmt::Error> {{", prefix, prefix);
@@ -68,45 +98,67 @@ pub fn compile<W: Write>(
     rust!(out, "pub struct {}Matcher<'input> {{", prefix);
     rust!(out, "text: &'input str,"); // remaining input
     rust!(out, "consumed: usize,"); // number of chars consumed thus far
-    rust!(out, "regex_set: {}regex::RegexSet,", prefix);
-    rust!(out, "regex_vec: Vec<{}regex::Regex>,", prefix);
+    if panic_free {
+        rust!(out, "regex_set: Option<{}regex::RegexSet>,", prefix);
+        rust!(out, "regex_vec: Vec<Option<{}regex::Regex>>,", prefix);
+    } else {
+        rust!(out, "regex_set: {}regex::RegexSet,", prefix);
+        rust!(out, "regex_vec: Vec<{}regex::Regex>,", prefix);
+    }
+    if intern_token.newlines.is_some() {
+        // the last token produced, and how many `open` tokens are
+        // still unmatched, for `#[newlines]`
+        rust!(out, "previous: Option<usize>,");
+        rust!(out, "depth: usize,");
+    }
     rust!(out, "}}");
     rust!(out, "");
     rust!(out, "impl<'input> {}Matcher<'input> {{", prefix);
     rust!(out, "pub fn new(s: &'input str) -> {}Matcher<'input> {{", prefix);
 
-    // create a vector of rust string literals with the text of each
-    // regular expression
-    let regex_strings: Vec<String> = intern::read(|interner| {
+    let regexes: Vec<re::Regex> = intern::read(|interner| {
         intern_token.match_entries
                     .iter()
                     .map(|match_entry| match match_entry.match_literal {
                         TerminalLiteral::Quoted(s) => re::parse_literal(interner.data(s)),
                         TerminalLiteral::Regex(s) => re::parse_regex(interner.data(s)).unwrap(),
                     })
-                    .map(|regex| {
-                        // make sure all regex are anchored at the beginning of the input
-                        format!("^{}", regex)
-                    })
-                    .map(|regex_str| {
-                        // create a rust string with text of the regex; the Debug impl
-                        // will add quotes and escape
-                        format!("{:?}", regex_str)
-                    })
                     .collect()
     });
 
+    // create a vector of rust string literals with the text of each
+    // regular expression
+    let regex_strings: Vec<String> =
+        regexes.iter()
+               .map(|regex| {
+                   // make sure all regex are anchored at the beginning of the input
+                   format!("^{}", regex)
+               })
+               .map(|regex_str| {
+                   // create a rust string with text of the regex; the Debug impl
+                   // will add quotes and escape
+                   format!("{:?}", regex_str)
+               })
+               .collect();
+
+    let scan_plans: Vec<Option<ScanPlan>> = if fast_scanning {
+        regexes.iter().map(scan::scan_plan).collect()
+    } else {
+        regexes.iter().map(|_| None).collect()
+    };
+
     rust!(out, "let {}strs: &[&str] = &[", prefix);
     for literal in &regex_strings {
         rust!(out, "{},", literal);
     }
     rust!(out, "];");
-    rust!(out, "let {}regex_set = {}regex::RegexSet::new({}strs).unwrap();",
-          prefix, prefix, prefix);
+    let unwrap = if panic_free { "ok()" } else { "unwrap()" };
+    rust!(out, "let {}regex_set = {}regex::RegexSet::new({}strs).{};",
+          prefix, prefix, prefix, unwrap);
 
     rust!(out, "let {}regex_vec = vec![", prefix);
     for literal in &regex_strings {
-        rust!(out, "{}regex::Regex::new({}).unwrap(),", prefix, literal);
+        rust!(out, "{}regex::Regex::new({}).{},", prefix, literal, unwrap);
     }
     rust!(out, "];");
 
@@ -115,10 +167,21 @@ pub fn compile<W: Write>(
     rust!(out, "consumed: 0,");
     rust!(out, "regex_set: {}regex_set,", prefix);
     rust!(out, "regex_vec: {}regex_vec,", prefix);
+    if intern_token.newlines.is_some() {
+        rust!(out, "previous: None,");
+        rust!(out, "depth: 0,");
+    }
     rust!(out, "}}"); // struct literal
     rust!(out, "}}"); // fn new()
     rust!(out, "}}"); // impl Matcher<'input>
     rust!(out, "");
+    for (index, plan) in scan_plans.iter().enumerate() {
+        if let Some(ref plan) = *plan {
+            if scan::fixed_length(plan).is_none() {
+                try!(write_scan_fn(out, prefix, index, plan));
+            }
+        }
+    }
     rust!(out, "impl<'input> Iterator for {}Matcher<'input> {{", prefix);
     rust!(out, "type Item = Result<(usize, Token<'input>, usize), \
                 {}lalrpop_util::ParseError<usize,Token<'input>,{}>>;",
@@ -128,10 +191,22 @@ pub fn compile<W: Write>(
     rust!(out, "fn next(&mut self) -> Option<Self::Item> {{");
 
     // start by trimming whitespace from left
-    rust!(out, "let {}text = self.text.trim_left();", prefix);
-    rust!(out, "let {}whitespace = self.text.len() - {}text.len();", prefix, prefix);
+    if fast_scanning {
+        rust!(out, "let {}whitespace = {}lalrpop_util::scan::skip_whitespace(self.text);",
+              prefix, prefix);
+        rust!(out, "let {}text = &self.text[{}whitespace..];", prefix, prefix);
+    } else {
+        rust!(out, "let {}text = self.text.trim_left();", prefix);
+        rust!(out, "let {}whitespace = self.text.len() - {}text.len();", prefix, prefix);
+    }
     rust!(out, "let {}start_offset = self.consumed + {}whitespace;", prefix, prefix);
 
+    // with `#[newlines]`, a line break in the whitespace (or the end
+    // of the input) may be a token itself
+    if let Some(ref newlines) = intern_token.newlines {
+        try!(write_newline_insertion(out, prefix, newlines));
+    }
+
     // if nothing left, return None
     rust!(out, "if {}text.is_empty() {{", prefix);
     rust!(out, "self.text = {}text;", prefix);
@@ -140,7 +215,14 @@ pub fn compile<W: Write>(
     rust!(out, "}} else {{");
 
     // otherwise, use regex-set to find list of matching tokens
-    rust!(out, "let {}matches = self.regex_set.matches({}text);", prefix, prefix);
+    if panic_free {
+        rust!(out, "let {}matches = match self.regex_set {{", prefix);
+        rust!(out, "Some(ref {}regex_set) => {}regex_set.matches({}text),", prefix, prefix, prefix);
+        rust!(out, "None => return Some(Err({})),", internal_error("invalid regex"));
+        rust!(out, "}};");
+    } else {
+        rust!(out, "let {}matches = self.regex_set.matches({}text);", prefix, prefix);
+    }
 
     // if nothing matched, return an error
     rust!(out, "if !{}matches.matched_any() {{", prefix);
@@ -165,8 +247,34 @@ pub fn compile<W: Write>(
     // are sorted in order of increasing priority, and because we know
     // that indices of equal priority cannot both match (because of
     // the DFA check).
-    rust!(out, "let {}match = self.regex_vec[{}i].find({}text).unwrap();", prefix, prefix, prefix);
-    rust!(out, "let {}len = {}match.end();", prefix, prefix);
+    if scan_plans.iter().any(|plan| plan.is_some()) {
+        // tokens with a scan plan don't need the regex to be re-run
+        rust!(out, "let {}len = match {}i {{", prefix, prefix);
+        for (index, plan) in scan_plans.iter().enumerate() {
+            if let Some(ref plan) = *plan {
+                match scan::fixed_length(plan) {
+                    Some(len) => rust!(out, "{} => {},", index, len),
+                    None => rust!(out, "{} => {}scan{}({}text),", index, prefix, index, prefix),
+                }
+            }
+        }
+        rust!(out, "_ => self.regex_vec[{}i].find({}text).unwrap().end(),", prefix, prefix);
+        rust!(out, "}};");
+    } else if panic_free {
+        rust!(out, "let {}len = match self.regex_vec.get({}i) {{", prefix, prefix);
+        rust!(out, "Some(&Some(ref {}regex)) => match {}regex.find({}text) {{",
+              prefix, prefix, prefix);
+        rust!(out, "Some({}match) => {}match.end(),", prefix, prefix);
+        rust!(out, "None => return Some(Err({})),",
+              internal_error("regex set and regex disagree"));
+        rust!(out, "}},");
+        rust!(out, "_ => return Some(Err({})),", internal_error("invalid regex"));
+        rust!(out, "}};");
+    } else {
+        rust!(out, "let {}match = self.regex_vec[{}i].find({}text).unwrap();",
+              prefix, prefix, prefix);
+        rust!(out, "let {}len = {}match.end();", prefix, prefix);
+    }
     rust!(out, "if {}len >= {}longest_match {{", prefix, prefix);
     rust!(out, "{}longest_match = {}len;", prefix, prefix);
     rust!(out, "{}index = {}i;", prefix, prefix);
@@ -175,7 +283,64 @@ pub fn compile<W: Write>(
     rust!(out, "}}"); // for loop
 
     // transform the result into the expected return value
+    if panic_free {
+        // regex matches end on character boundaries, but check
+        // rather than risk a panic when slicing
+        rust!(out, "if !{}text.is_char_boundary({}longest_match) {{", prefix, prefix);
+        rust!(out, "return Some(Err({}));", internal_error("match ends inside a character"));
+        rust!(out, "}}");
+    }
     rust!(out, "let {}result = &{}text[..{}longest_match];", prefix, prefix, prefix);
+
+    // with `#[normalize]`, a token whose normalized text is one of the
+    // fixed strings of the grammar is that string, like a keyword
+    if let Some(ref normalizer) = grammar.token_normalizer {
+        rust!(out, "match &*{}({}result) {{", normalizer, prefix);
+        try!(intern::read(|interner| -> io::Result<()> {
+            for (index, match_entry) in intern_token.match_entries.iter().enumerate() {
+                if let TerminalLiteral::Quoted(s) = match_entry.match_literal {
+                    rust!(out, "{:?} => {}index = {},", interner.data(s), prefix, index);
+                }
+            }
+            Ok(())
+        }));
+        rust!(out, "_ => {{ }}");
+        rust!(out, "}}");
+    }
+
+    // the parser can't handle tokens whose escapes it can't decode,
+    // so `#[unescape]` tokens with an invalid escape are invalid
+    let unescaped: Vec<String> =
+        intern_token.match_entries
+                    .iter()
+                    .enumerate()
+                    .filter(|&(_, match_entry)| match_entry.unescape)
+                    .map(|(index, _)| index.to_string())
+                    .collect();
+    if !unescaped.is_empty() {
+        rust!(out, "match {}index {{", prefix);
+        rust!(out, "{} => {{", unescaped.join(" | "));
+        rust!(out, "if let Err({}offset) = {}lalrpop_util::unescape::check({}result) {{",
+              prefix, prefix, prefix);
+        rust!(out, "return Some(Err({}lalrpop_util::ParseError::InvalidToken {{", prefix);
+        rust!(out, "location: {}start_offset + {}offset,", prefix, prefix);
+        rust!(out, "}}));");
+        rust!(out, "}}");
+        rust!(out, "}}");
+        rust!(out, "_ => {{ }}");
+        rust!(out, "}}");
+    }
+    if let Some(ref newlines) = intern_token.newlines {
+        if !newlines.open.is_empty() {
+            rust!(out, "match {}index {{", prefix);
+            rust!(out, "{} => self.depth += 1,", join_indices(&newlines.open));
+            rust!(out, "{} => self.depth = self.depth.saturating_sub(1),",
+                  join_indices(&newlines.close));
+            rust!(out, "_ => {{ }}");
+            rust!(out, "}}");
+        }
+        rust!(out, "self.previous = Some({}index);", prefix);
+    }
     rust!(out, "let {}remaining = &{}text[{}longest_match..];", prefix, prefix, prefix);
     rust!(out, "let {}end_offset = {}start_offset + {}longest_match;", prefix, prefix, prefix);
     rust!(out, "self.text = {}remaining;", prefix);
@@ -187,7 +352,178 @@ pub fn compile<W: Write>(
     rust!(out, "}}"); // else
     rust!(out, "}}"); // fn
     rust!(out, "}}"); // impl
+
+    if !intern_token.reserved_words.is_empty() {
+        try!(write_reserved_word_fn(grammar, intern_token, out));
+    }
+    if session.token_arrays {
+        try!(write_tokenize_fn(grammar, out));
+    }
     rust!(out, "}}"); // mod
     Ok(())
 }
 
+/// Writes the part of `next` that, for `#[newlines]`, produces the
+/// `insert` token at the first line break in the whitespace before
+/// the next token, or at the end of the input, if the previous token
+/// was one of the `after` tokens and no `open` token is unmatched.
+/// The inserted token spans the line break.
+fn write_newline_insertion<W: Write>(out: &mut RustWrite<W>,
+                                     prefix: &str,
+                                     newlines: &Newlines)
+                                     -> io::Result<()> {
+    let after: Vec<String> = newlines.after
+                                     .iter()
+                                     .map(|index| format!("Some({})", index))
+                                     .collect();
+    rust!(out, "if self.depth == 0 && match self.previous {{ {} => true, _ => false }} {{",
+          after.join(" | "));
+    rust!(out, "let {}input = self.text;", prefix);
+    rust!(out, "if let Some({}newline) = {}input[..{}whitespace].find('\\n') {{",
+          prefix, prefix, prefix);
+    rust!(out, "let {}line_break = &{}input[{}newline..{}newline + 1];",
+          prefix, prefix, prefix, prefix);
+    rust!(out, "let {}offset = self.consumed + {}newline;", prefix, prefix);
+    rust!(out, "self.text = &{}input[{}newline + 1..];", prefix, prefix);
+    rust!(out, "self.consumed = {}offset + 1;", prefix);
+    rust!(out, "self.previous = Some({});", newlines.insert);
+    rust!(out, "return Some(Ok(({p}offset, Token({}, {p}line_break), {p}offset + 1)));",
+          newlines.insert,
+          p = prefix);
+    rust!(out, "}}");
+    rust!(out, "if {}text.is_empty() {{", prefix);
+    rust!(out, "self.previous = Some({});", newlines.insert);
+    rust!(out, "return Some(Ok(({p}start_offset, Token({}, \"\"), {p}start_offset)));",
+          newlines.insert,
+          p = prefix);
+    rust!(out, "}}");
+    rust!(out, "}}");
+    Ok(())
+}
+
+/// Writes `tokenize`, which lexes all of the input into a token array
+/// (see `lalrpop_util::tokens`), stopping at the first invalid token.
+fn write_tokenize_fn<W: Write>(grammar: &Grammar, out: &mut RustWrite<W>) -> io::Result<()> {
+    let prefix = &grammar.prefix;
+    rust!(out, "");
+    rust!(out,
+          "pub fn tokenize<'input>({p}input: &'input str) \
+           -> Result<{p}lalrpop_util::tokens::TokenArray, \
+           {p}lalrpop_util::ParseError<usize, Token<'input>, {}>> {{",
+          grammar.types.error_type(),
+          p = prefix);
+    rust!(out, "let mut {}tokens = {}lalrpop_util::tokens::TokenArray::new();", prefix, prefix);
+    rust!(out, "for {p}token in {p}Matcher::new({p}input) {{", p = prefix);
+    rust!(out, "let ({p}start, Token({p}kind, _), {p}end) = {p}token?;", p = prefix);
+    rust!(out, "{p}tokens.push({p}kind, {p}start, {p}end);", p = prefix);
+    rust!(out, "}}");
+    rust!(out, "Ok({}tokens)", prefix);
+    rust!(out, "}}");
+    Ok(())
+}
+
+fn join_indices(indices: &[usize]) -> String {
+    indices.iter().map(|index| index.to_string()).collect::<Vec<_>>().join(" | ")
+}
+
+/// Writes `__reserved_word`, which the parse functions pass their
+/// errors through: it turns an unrecognized `#[reserved]` keyword into
+/// a `ParseError::ReservedWord` if the parser expected one of the
+/// terminals the keyword would otherwise be.
+fn write_reserved_word_fn<W: Write>(grammar: &Grammar,
+                                    intern_token: &InternToken,
+                                    out: &mut RustWrite<W>)
+                                    -> io::Result<()> {
+    let prefix = &grammar.prefix;
+    let error_type = format!("{}lalrpop_util::ParseError<L, Token<'input>, E>", prefix);
+    rust!(out, "");
+    rust!(out, "pub fn {}reserved_word<'input, L, E>({}error: {}) -> {} {{",
+          prefix, prefix, error_type, error_type);
+    rust!(out, "match {}error {{", prefix);
+    rust!(out, "{p}lalrpop_util::ParseError::UnrecognizedToken {{ \
+                token: Some({p}token), expected: {p}expected }} => {{",
+          p = prefix);
+    rust!(out, "let {p}description = match ({p}token.1).0 {{", p = prefix);
+    try!(intern::read(|interner| -> io::Result<()> {
+        for reserved_word in &intern_token.reserved_words {
+            // the names in `expected` are the terminals as written
+            // in the grammar (see `__expected_tokens`)
+            let shadowed: Vec<String> =
+                reserved_word.shadowed
+                             .iter()
+                             .map(|terminal| format!("{}e == r###\"{}\"###", prefix, terminal))
+                             .collect();
+            rust!(out, "{} if {}expected.iter().any(|{}e| {}) => {:?},",
+                  reserved_word.index,
+                  prefix,
+                  prefix,
+                  shadowed.join(" || "),
+                  interner.data(reserved_word.description));
+        }
+        Ok(())
+    }));
+    rust!(out, "_ => {{");
+    rust!(out, "return {p}lalrpop_util::ParseError::UnrecognizedToken {{ \
+                token: Some({p}token), expected: {p}expected }};",
+          p = prefix);
+    rust!(out, "}}");
+    rust!(out, "}};");
+    rust!(out, "{p}lalrpop_util::ParseError::ReservedWord {{ token: {p}token, \
+                description: ::std::string::String::from({p}description), \
+                expected: {p}expected }}",
+          p = prefix);
+    rust!(out, "}}");
+    rust!(out, "{}error => {}error,", prefix, prefix);
+    rust!(out, "}}");
+    rust!(out, "}}");
+    Ok(())
+}
+
+
+/// Runs of classes that exclude at most this many ASCII characters
+/// (and no others) are scanned with `find_any`.
+const MAX_STOP_BYTES: usize = 3;
+
+fn write_scan_fn<W: Write>(out: &mut RustWrite<W>,
+                           prefix: &str,
+                           index: usize,
+                           plan: &ScanPlan)
+                           -> io::Result<()> {
+    rust!(out, "fn {}scan{}({}text: &str) -> usize {{", prefix, index, prefix);
+    rust!(out, "let {}bytes = {}text.as_bytes();", prefix, prefix);
+    rust!(out, "let mut {}end = 0;", prefix);
+    for step in plan {
+        match *step {
+            ScanStep::Char(_) => {
+                rust!(out, "{}end += 1;", prefix);
+            }
+            ScanStep::Run(ref class) => {
+                rust!(out, "{}end = {};", prefix, scan_run(prefix, class));
+            }
+        }
+    }
+    rust!(out, "{}end", prefix);
+    rust!(out, "}}");
+    rust!(out, "");
+    Ok(())
+}
+
+fn scan_run(prefix: &str, class: &ByteClass) -> String {
+    match class.excluded_bytes(MAX_STOP_BYTES) {
+        Some(stops) => {
+            format!("{p}lalrpop_util::scan::find_any({p}bytes, {p}end, &{:?})",
+                    stops,
+                    p = prefix)
+        }
+        None => {
+            format!("{p}lalrpop_util::scan::class_end({p}bytes, {p}end, \
+                     &[{:#x}, {:#x}, {:#x}, {:#x}], {})",
+                    class.ascii[0],
+                    class.ascii[1],
+                    class.ascii[2],
+                    class.ascii[3],
+                    class.non_ascii,
+                    p = prefix)
+        }
+    }
+}
@@ -6,3 +6,4 @@ pub mod dfa;
 pub mod intern_token;
 pub mod re;
 pub mod nfa;
+pub mod scan;
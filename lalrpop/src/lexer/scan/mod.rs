@@ -0,0 +1,213 @@
+//! Fast scanning for the generated lexer. Many tokens (identifiers,
+//! numbers, string literals, keywords) are described by regular
+//! expressions that can be matched by a simple left-to-right walk
+//! over the bytes of the input, with each character class that
+//! repeats scanned in a tight loop. For such a regex, we compute a
+//! `ScanPlan` here, and the lexer uses it to find out how long a
+//! match is instead of running the regex a second time.
+//!
+//! The plan is only ever executed on input that the regex is already
+//! known to match at the start, so it never needs to check anything
+//! except where each repetition ends.
+
+use lexer::re::Regex;
+use regex_syntax::{CharClass, Expr, Repeater};
+
+#[cfg(test)]
+mod test;
+
+/// A set of characters, represented in terms of bytes: a bitmap of
+/// the ASCII characters it contains, and whether it contains every
+/// non-ASCII character (classes that contain only some of them can't
+/// be scanned bytewise).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ByteClass {
+    pub ascii: [u32; 4],
+    pub non_ascii: bool,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ScanStep {
+    /// Consume one (ASCII) character from the class.
+    Char(ByteClass),
+
+    /// Consume as many characters from the class as possible.
+    Run(ByteClass),
+}
+
+pub type ScanPlan = Vec<ScanStep>;
+
+/// Number of valid non-ASCII `char` values.
+const NUM_NON_ASCII: u32 = 0x11_0000 - 0x80 - 0x800;
+
+/// Computes a scan plan for `regex`, if it has one. This is the case
+/// when the regex is a sequence of single ASCII characters (or
+/// classes thereof) and of greedy `*` or `+` repetitions of classes,
+/// where each repetition is followed by something that cannot
+/// continue it; so stopping at the end of every repetition is the
+/// only way to match.
+pub fn scan_plan(regex: &Regex) -> Option<ScanPlan> {
+    let mut plan = vec![];
+    if !push_steps(regex, &mut plan) || plan.is_empty() {
+        return None;
+    }
+
+    for (step, next) in plan.iter().zip(plan.iter().skip(1)) {
+        if let ScanStep::Run(ref class) = *step {
+            if !class.is_disjoint(next.class()) {
+                return None;
+            }
+        }
+    }
+
+    Some(plan)
+}
+
+/// The length of the match of any plan without repetitions.
+pub fn fixed_length(plan: &ScanPlan) -> Option<usize> {
+    if plan.iter().all(|step| match *step {
+        ScanStep::Char(_) => true,
+        ScanStep::Run(_) => false,
+    }) {
+        Some(plan.len())
+    } else {
+        None
+    }
+}
+
+fn push_steps(expr: &Expr, plan: &mut ScanPlan) -> bool {
+    match *expr {
+        Expr::Literal { ref chars, casei: false } => {
+            chars.iter().all(|&ch| match ByteClass::from_char(ch) {
+                Some(class) => {
+                    plan.push(ScanStep::Char(class));
+                    true
+                }
+                None => false,
+            })
+        }
+
+        Expr::Class(ref class) => {
+            match ByteClass::from_class(class) {
+                Some(class) if !class.non_ascii => {
+                    plan.push(ScanStep::Char(class));
+                    true
+                }
+                _ => false,
+            }
+        }
+
+        Expr::Group { ref e, name: None, .. } => push_steps(e, plan),
+
+        Expr::Concat(ref exprs) => exprs.iter().all(|e| push_steps(e, plan)),
+
+        // Since the regex is known to match, `x+` can be scanned just
+        // like `x*`.
+        Expr::Repeat { ref e, r: Repeater::ZeroOrMore, greedy: true } |
+        Expr::Repeat { ref e, r: Repeater::OneOrMore, greedy: true } => {
+            match repeated_class(e) {
+                Some(class) => {
+                    plan.push(ScanStep::Run(class));
+                    true
+                }
+                None => false,
+            }
+        }
+
+        _ => false,
+    }
+}
+
+fn repeated_class(expr: &Expr) -> Option<ByteClass> {
+    match *expr {
+        Expr::Literal { ref chars, casei: false } if chars.len() == 1 => {
+            ByteClass::from_char(chars[0])
+        }
+        Expr::Class(ref class) => ByteClass::from_class(class),
+        Expr::Group { ref e, name: None, .. } => repeated_class(e),
+        _ => None,
+    }
+}
+
+impl ByteClass {
+    fn empty() -> ByteClass {
+        ByteClass {
+            ascii: [0; 4],
+            non_ascii: false,
+        }
+    }
+
+    fn from_char(ch: char) -> Option<ByteClass> {
+        if ch as u32 >= 0x80 {
+            return None;
+        }
+        let mut class = ByteClass::empty();
+        class.insert(ch as u8);
+        Some(class)
+    }
+
+    fn from_class(char_class: &CharClass) -> Option<ByteClass> {
+        let mut class = ByteClass::empty();
+        let mut non_ascii = 0;
+        for range in char_class.iter() {
+            let (start, end) = (range.start as u32, range.end as u32);
+            for byte in start..::std::cmp::min(end + 1, 0x80) {
+                class.insert(byte as u8);
+            }
+            if end >= 0x80 {
+                let start = ::std::cmp::max(start, 0x80);
+                let surrogates = overlap(start, end, 0xD800, 0xDFFF);
+                non_ascii += end - start + 1 - surrogates;
+            }
+        }
+        if non_ascii == NUM_NON_ASCII {
+            class.non_ascii = true;
+        } else if non_ascii != 0 {
+            return None;
+        }
+        Some(class)
+    }
+
+    fn insert(&mut self, byte: u8) {
+        self.ascii[(byte >> 5) as usize] |= 1 << (byte & 31);
+    }
+
+    fn contains(&self, byte: u8) -> bool {
+        self.ascii[(byte >> 5) as usize] & (1 << (byte & 31)) != 0
+    }
+
+    fn is_disjoint(&self, other: &ByteClass) -> bool {
+        !(self.non_ascii && other.non_ascii) &&
+            self.ascii.iter().zip(&other.ascii).all(|(a, b)| a & b == 0)
+    }
+
+    /// If this class contains everything except a few ASCII
+    /// characters, those characters. Runs of such classes are best
+    /// scanned by searching for the characters that end them.
+    pub fn excluded_bytes(&self, max: usize) -> Option<Vec<u8>> {
+        if !self.non_ascii {
+            return None;
+        }
+        let excluded: Vec<u8> = (0..0x80u8).filter(|&b| !self.contains(b)).collect();
+        if excluded.len() <= max {
+            Some(excluded)
+        } else {
+            None
+        }
+    }
+}
+
+impl ScanStep {
+    fn class(&self) -> &ByteClass {
+        match *self {
+            ScanStep::Char(ref class) | ScanStep::Run(ref class) => class,
+        }
+    }
+}
+
+/// Number of values in both `start..=end` and `lo..=hi`.
+fn overlap(start: u32, end: u32, lo: u32, hi: u32) -> u32 {
+    let start = ::std::cmp::max(start, lo);
+    let end = ::std::cmp::min(end, hi);
+    if start <= end { end - start + 1 } else { 0 }
+}
@@ -0,0 +1,65 @@
+use lexer::re;
+use super::*;
+
+fn plan(regex: &str) -> Option<ScanPlan> {
+    scan_plan(&re::parse_regex(regex).unwrap())
+}
+
+fn steps(regex: &str) -> Vec<&'static str> {
+    plan(regex).unwrap()
+               .iter()
+               .map(|step| match *step {
+                   ScanStep::Char(_) => "char",
+                   ScanStep::Run(_) => "run",
+               })
+               .collect()
+}
+
+#[test]
+fn identifier() {
+    assert_eq!(steps(r"[a-zA-Z_][a-zA-Z0-9_]*"), vec!["char", "run"]);
+}
+
+#[test]
+fn number() {
+    assert_eq!(steps(r"[0-9]+"), vec!["run"]);
+    assert_eq!(steps(r"[0-9]+\.[0-9]+"), vec!["run", "char", "run"]);
+}
+
+#[test]
+fn string_literal() {
+    let plan = plan(r#""[^"]*""#).unwrap();
+    assert_eq!(plan.len(), 3);
+    match plan[1] {
+        ScanStep::Run(class) => {
+            assert!(class.non_ascii);
+            assert_eq!(class.excluded_bytes(3), Some(vec![b'"']));
+        }
+        ScanStep::Char(_) => panic!("expected a run"),
+    }
+}
+
+#[test]
+fn keyword() {
+    let plan = plan(r"class").unwrap();
+    assert_eq!(fixed_length(&plan), Some(5));
+}
+
+#[test]
+fn ambiguous_end_of_run() {
+    // the run could end before any `0`, so the regex would have to
+    // backtrack
+    assert!(plan(r"[0-9]*0").is_none());
+    assert!(plan(r"a*a*").is_none());
+}
+
+#[test]
+fn unsupported() {
+    assert!(plan(r"a|b").is_none());
+    assert!(plan(r"a?").is_none());
+    assert!(plan(r"[0-9]+?").is_none());
+    assert!(plan(r"(?i)class").is_none());
+    assert!(plan(r"é").is_none());
+    // only some non-ASCII characters
+    assert!(plan(r"[a-zé]+").is_none());
+}
@@ -9,6 +9,7 @@
 #![cfg_attr(test, allow(dead_code))]
 
 extern crate ascii_canvas;
+#[cfg(not(target_arch = "wasm32"))]
 extern crate atty;
 extern crate bit_set;
 extern crate diff;
@@ -32,17 +33,25 @@ mod rust;
 mod log;
 
 mod api;
+mod bench;
 mod build;
+mod c_abi;
 mod collections;
+mod diagnostics;
 mod file_text;
 mod grammar;
+mod grammar_diff;
+mod grammar_doc;
 mod lexer;
+mod lint;
 mod lr1;
 mod message;
 mod normalize;
+mod operator_table;
 mod parser;
 mod kernel_set;
 mod session;
+mod source_map;
 mod tls;
 mod tok;
 mod util;
@@ -51,6 +60,13 @@ mod util;
 #[cfg(test)] mod test_util;
 
 pub use api::Configuration;
+pub use api::check_str;
+pub use api::process_str;
+pub use api::process_grammar_for_analysis;
 pub use api::process_root;
 pub use api::process_root_unconditionally;
+pub use source_map::rewrite_rustc_output;
+pub use diagnostics::{Diagnostic, Diagnostics, Severity};
 pub use ascii_canvas::style;
+pub use lr1::{ConflictAction, ConflictInfo, ExampleInfo, GrammarAnalysis, NonterminalInfo,
+              ParserInfo, StateInfo};
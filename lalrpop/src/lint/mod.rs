@@ -0,0 +1,365 @@
+//! Warnings about grammars that are accepted but are probably not
+//! what the user meant. Each kind of warning is a `Lint`, whose level
+//! the grammar can change with a module attribute like
+//! `#![allow(lalrpop::unused_terminal)]` or
+//! `#![deny(lalrpop::never_reduced)]`.
+
+use collections::{map, set, Map, Set};
+use grammar::consts::INLINE;
+use grammar::repr::*;
+use intern::intern;
+use lexer::dfa::Kind;
+use std::fmt;
+use util::near_misses;
+
+#[cfg(test)]
+mod test;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Lint {
+    /// A declared terminal that no production uses.
+    UnusedTerminal,
+
+    /// A nonterminal that cannot be reached from any public
+    /// nonterminal, so none of its productions is ever reduced.
+    NeverReduced,
+
+    /// A terminal of the internal tokenizer that is never produced,
+    /// because terminals with higher precedence match the same text.
+    ShadowedToken,
+
+    /// The generated symbol enum has so many variants that it slows
+    /// down compilation of the parser considerably.
+    OversizedSymbolEnum,
+
+    /// An alternative that the parse tables never reduce, because
+    /// another action always wins where it would apply (like an
+    /// identical alternative before it, once conflicts are resolved
+    /// by default).
+    DeadAlternative,
+
+    /// An alternative with the same symbols as an earlier alternative
+    /// of the same nonterminal (after macro expansion), which is
+    /// usually a copy-paste or merge error.
+    DuplicateProduction,
+}
+
+const ALL_LINTS: &'static [Lint] = &[Lint::UnusedTerminal,
+                                     Lint::NeverReduced,
+                                     Lint::ShadowedToken,
+                                     Lint::OversizedSymbolEnum,
+                                     Lint::DeadAlternative,
+                                     Lint::DuplicateProduction];
+
+/// Symbol enums with more variants than this trigger
+/// `lalrpop::oversized_symbol_enum`.
+pub const MAX_SYMBOL_VARIANTS: usize = 500;
+
+impl Lint {
+    pub fn name(self) -> &'static str {
+        match self {
+            Lint::UnusedTerminal => "unused_terminal",
+            Lint::NeverReduced => "never_reduced",
+            Lint::ShadowedToken => "shadowed_token",
+            Lint::OversizedSymbolEnum => "oversized_symbol_enum",
+            Lint::DeadAlternative => "dead_alternative",
+            Lint::DuplicateProduction => "duplicate_production",
+        }
+    }
+
+    /// The level at which the lint is reported unless the grammar
+    /// says otherwise. `oversized_symbol_enum` is about the build
+    /// time of the generated code rather than about the grammar, and
+    /// fires on every large grammar, so it must be asked for.
+    pub fn default_level(self) -> LintLevel {
+        match self {
+            Lint::OversizedSymbolEnum => LintLevel::Allow,
+            _ => LintLevel::Warn,
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Lint> {
+        ALL_LINTS.iter().cloned().find(|lint| lint.name() == name)
+    }
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "lalrpop::{}", self.name())
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LintLevel {
+    Allow,
+    Warn,
+    Deny,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Warning {
+    pub lint: Lint,
+    /// Where in the grammar the problem is, if it can be pinned down.
+    pub span: Option<Span>,
+    pub message: String,
+}
+
+/// Separates the lint attributes (`#![allow(lalrpop::...)]`,
+/// `#![warn(...)]`, `#![deny(...)]`) from the attributes that should
+/// be copied into the generated module.
+pub fn extract_lint_levels(attributes: Vec<String>)
+                           -> Result<(Vec<String>, Map<Lint, LintLevel>), String> {
+    let mut others = vec![];
+    let mut levels = map();
+    for attribute in attributes {
+        match try!(parse_lint_attribute(&attribute)) {
+            Some((level, lints)) => {
+                for lint in lints {
+                    levels.insert(lint, level);
+                }
+            }
+            None => others.push(attribute),
+        }
+    }
+    Ok((others, levels))
+}
+
+fn parse_lint_attribute(attribute: &str) -> Result<Option<(LintLevel, Vec<Lint>)>, String> {
+    let body: String = attribute.chars().filter(|c| !c.is_whitespace()).collect();
+    if !body.starts_with("#![") || !body.ends_with("]") {
+        return Ok(None);
+    }
+    let body = &body[3..body.len() - 1];
+
+    let (level, list) = if body.starts_with("allow(") {
+        (LintLevel::Allow, &body[6..])
+    } else if body.starts_with("warn(") {
+        (LintLevel::Warn, &body[5..])
+    } else if body.starts_with("deny(") {
+        (LintLevel::Deny, &body[5..])
+    } else {
+        return Ok(None);
+    };
+    if !list.ends_with(")") {
+        return Ok(None);
+    }
+
+    let names: Vec<&str> = list[..list.len() - 1].split(',').filter(|s| !s.is_empty()).collect();
+    if !names.iter().any(|name| name.starts_with("lalrpop::")) {
+        return Ok(None);
+    }
+
+    let mut lints = vec![];
+    for name in names {
+        if !name.starts_with("lalrpop::") {
+            return Err(format!("lint `{}` cannot be mixed with LALRPOP lints in `{}`; \
+                                use a separate attribute",
+                               name, attribute));
+        }
+        match Lint::from_name(&name["lalrpop::".len()..]) {
+            Some(lint) => lints.push(lint),
+            None => return Err(format!("unknown lint `{}`", name)),
+        }
+    }
+    Ok(Some((level, lints)))
+}
+
+/// The level at which `lint` is reported for `grammar`.
+pub fn level(grammar: &Grammar, lint: Lint) -> LintLevel {
+    grammar.lint_levels.get(&lint).cloned().unwrap_or(lint.default_level())
+}
+
+/// Runs all lints on `grammar`, omitting those that are allowed.
+pub fn check(grammar: &Grammar) -> Vec<Warning> {
+    let mut warnings = vec![];
+    unused_terminals(grammar, &mut warnings);
+    never_reduced(grammar, &mut warnings);
+    shadowed_tokens(grammar, &mut warnings);
+    oversized_symbol_enum(grammar, &mut warnings);
+    duplicate_productions(grammar, &mut warnings);
+    warnings.retain(|w| level(grammar, w.lint) != LintLevel::Allow);
+    warnings
+}
+
+fn unused_terminals(grammar: &Grammar, warnings: &mut Vec<Warning>) {
+    let used: Set<TerminalString> =
+        grammar.nonterminals
+               .values()
+               .flat_map(|data| &data.productions)
+               .flat_map(|production| &production.symbols)
+               .filter_map(|symbol| match *symbol {
+                   Symbol::Terminal(term) => Some(term),
+                   Symbol::Nonterminal(_) => None,
+               })
+               .collect();
+
+    for &term in &grammar.terminals.all {
+        if term == TerminalString::Error || used.contains(&term) {
+            continue;
+        }
+
+        // the patterns of the internal tokenizer all carry the span
+        // of the whole grammar, which is not worth highlighting
+        let span = match grammar.intern_token {
+            Some(_) => None,
+            None => grammar.conversions.get(&term).map(|pattern| pattern.span),
+        };
+        // a terminal that the grammar uses instead, with a `match`
+        // section that lets unknown literals through or an `extern`
+        // section that declares both, may be a typo of this one
+        let mut message = format!("terminal `{}` is declared but never used", term);
+        let typos = near_misses(&term.to_string(), &used);
+        if !typos.is_empty() {
+            let typos: Vec<String> = typos.iter().map(|t| format!("`{}`", t)).collect();
+            message.push_str(&format!("; the grammar uses {}, which may be a typo of it",
+                                      typos.join(" and ")));
+        }
+        warnings.push(Warning {
+            lint: Lint::UnusedTerminal,
+            span: span,
+            message: message,
+        });
+    }
+}
+
+/// The nonterminals that the public nonterminals refer to, directly
+/// or indirectly.
+pub fn reachable(grammar: &Grammar) -> Set<NonterminalString> {
+    reachable_from(grammar, grammar.start_nonterminals.values().cloned().collect())
+}
+
+/// The nonterminals that `roots` refer to, directly or indirectly,
+/// including `roots` themselves.
+pub fn reachable_from(grammar: &Grammar, roots: Vec<NonterminalString>) -> Set<NonterminalString> {
+    let mut reachable = set();
+    let mut stack = roots;
+    while let Some(nt) = stack.pop() {
+        if !reachable.insert(nt) {
+            continue;
+        }
+        for production in grammar.productions_for(nt) {
+            for symbol in &production.symbols {
+                if let Symbol::Nonterminal(referenced) = *symbol {
+                    stack.push(referenced);
+                }
+            }
+        }
+    }
+    reachable
+}
+
+fn never_reduced(grammar: &Grammar, warnings: &mut Vec<Warning>) {
+    let reachable = reachable(grammar);
+
+    // inlined nonterminals are no longer referenced from anywhere,
+    // having been substituted into their uses
+    let inline = intern(INLINE);
+    for data in grammar.nonterminals.values() {
+        if reachable.contains(&data.name) ||
+            data.annotations.iter().any(|a| a.id == inline)
+        {
+            continue;
+        }
+        warnings.push(Warning {
+            lint: Lint::NeverReduced,
+            span: Some(data.span),
+            message: format!("nonterminal `{}` is not reachable from any public \
+                              nonterminal, so it is never reduced",
+                             data.name),
+        });
+    }
+}
+
+fn shadowed_tokens(grammar: &Grammar, warnings: &mut Vec<Warning>) {
+    let intern_token = match grammar.intern_token {
+        Some(ref t) => t,
+        None => return,
+    };
+
+    let accepted: Set<usize> =
+        intern_token.dfa
+                    .states
+                    .iter()
+                    .filter_map(|state| match state.kind {
+                        Kind::Accepts(index) => Some(index.index()),
+                        _ => None,
+                    })
+                    .collect();
+
+    for (index, entry) in intern_token.match_entries.iter().enumerate() {
+        if accepted.contains(&index) {
+            continue;
+        }
+        warnings.push(Warning {
+            lint: Lint::ShadowedToken,
+            span: None,
+            message: format!("terminal `{}` is never produced by the tokenizer, \
+                              because terminals with higher precedence match \
+                              the same input",
+                             entry.match_literal),
+        });
+    }
+}
+
+fn oversized_symbol_enum(grammar: &Grammar, warnings: &mut Vec<Warning>) {
+    let variants = grammar.terminals.all.len() + grammar.nonterminals.len();
+    if variants <= MAX_SYMBOL_VARIANTS {
+        return;
+    }
+    warnings.push(Warning {
+        lint: Lint::OversizedSymbolEnum,
+        span: None,
+        message: format!("the generated symbol enum has {} variants (more than {}), \
+                          which will slow down compilation; consider splitting \
+                          the grammar into several files",
+                         variants, MAX_SYMBOL_VARIANTS),
+    });
+}
+
+fn duplicate_productions(grammar: &Grammar, warnings: &mut Vec<Warning>) {
+    for data in grammar.nonterminals.values() {
+        let mut seen: Set<&[Symbol]> = set();
+        for production in &data.productions {
+            // with a `merge` function, identical alternatives are the
+            // way to combine the interpretations of the same input
+            if production.merge.is_some() || seen.insert(&production.symbols[..]) {
+                continue;
+            }
+            warnings.push(Warning {
+                lint: Lint::DuplicateProduction,
+                span: Some(production.span),
+                message: format!("this alternative of `{}` has the same symbols as an earlier \
+                                  one, so the two differ at most in their action code",
+                                 data.name),
+            });
+        }
+    }
+}
+
+/// Runs `lalrpop::dead_alternative` on `grammar`, whose parse tables
+/// reduce the productions in `reduced` (in any of their states),
+/// omitting the warnings if it is allowed. Unlike the other lints,
+/// this one can only run once the tables are built.
+pub fn check_reductions(grammar: &Grammar, reduced: &Set<&Production>) -> Vec<Warning> {
+    if level(grammar, Lint::DeadAlternative) == LintLevel::Allow {
+        return vec![];
+    }
+
+    // unreachable nonterminals are `lalrpop::never_reduced`
+    let mut warnings = vec![];
+    for nt in reachable(grammar) {
+        for production in grammar.productions_for(nt) {
+            if reduced.contains(production) {
+                continue;
+            }
+            warnings.push(Warning {
+                lint: Lint::DeadAlternative,
+                span: Some(production.span),
+                message: format!("this alternative of `{}` is never reduced, because \
+                                  another action always wins where it would apply",
+                                 nt),
+            });
+        }
+    }
+    warnings
+}
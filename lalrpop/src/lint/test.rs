@@ -0,0 +1,175 @@
+use collections::set;
+use grammar::repr::Grammar;
+use lr1::{build_states, build_states_resolving_conflicts, Lr1Tls};
+use normalize::normalize_without_validating;
+use session::Session;
+use parser;
+use test_util::normalized_grammar;
+
+use super::{check, check_reductions, extract_lint_levels, Lint, LintLevel, MAX_SYMBOL_VARIANTS};
+
+fn lints(grammar: &str) -> Vec<Lint> {
+    check(&normalized_grammar(grammar)).into_iter().map(|w| w.lint).collect()
+}
+
+#[test]
+fn unused_terminal() {
+    let warnings = check(&normalized_grammar(r#"
+grammar;
+extern { enum Tok { "a" => Tok::A, "b" => Tok::B } }
+pub S: () = "a" => ();
+"#));
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].lint, Lint::UnusedTerminal);
+    assert_eq!(warnings[0].message, r#"terminal `"b"` is declared but never used"#);
+    assert!(warnings[0].span.is_some());
+}
+
+#[test]
+fn unused_terminal_typo() {
+    let warnings = check(&normalized_grammar(r#"
+grammar;
+match { "while", "do", _ }
+pub S: () = "whlie" "do" => ();
+"#));
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].message,
+               "terminal `\"while\"` is declared but never used; the grammar uses `\"whlie\"`, \
+                which may be a typo of it");
+}
+
+#[test]
+fn never_reduced() {
+    assert_eq!(lints(r#"
+grammar;
+pub S: () = "a" => ();
+T: () = "b" => ();
+#[inline] U: () = "c" => ();
+pub V: () = U => ();
+"#),
+               vec![Lint::NeverReduced]);
+}
+
+#[test]
+fn shadowed_token() {
+    assert_eq!(lints(r#"
+grammar;
+match {
+    r"[a-z]+"
+} else {
+    "if"
+}
+pub S: () = { "if" => (), r"[a-z]+" => () };
+"#),
+               vec![Lint::ShadowedToken]);
+}
+
+#[test]
+fn duplicate_production() {
+    let warnings = check(&normalized_grammar(r#"
+grammar;
+pub S: () = { "a" T => (), "b" T? => (), "a" T => () };
+T: () = { "c" => (), Pair<"c"> => () };
+Pair<X>: () = { X X => (), X "c" => () };
+"#));
+    let warnings: Vec<&str> = warnings.iter()
+                                      .filter(|w| w.lint == Lint::DuplicateProduction)
+                                      .map(|w| &w.message[..])
+                                      .collect();
+    assert_eq!(warnings,
+               vec!["this alternative of `Pair<\"c\">` has the same symbols as an earlier one, \
+                     so the two differ at most in their action code",
+                    "this alternative of `S` has the same symbols as an earlier one, so the \
+                     two differ at most in their action code"]);
+}
+
+#[test]
+fn oversized_symbol_enum() {
+    let alternatives: Vec<String> = (0..MAX_SYMBOL_VARIANTS).map(|i| format!("\"t{}\" => ()", i))
+                                                            .collect();
+    let grammar = format!("grammar; pub S: () = {{ {} }};", alternatives.join(", "));
+    assert_eq!(lints(&grammar), vec![]);
+    let grammar = format!("#![warn(lalrpop::oversized_symbol_enum)] {}", grammar);
+    assert_eq!(lints(&grammar), vec![Lint::OversizedSymbolEnum]);
+}
+
+#[test]
+fn allow_attribute() {
+    assert_eq!(lints(r#"
+#![allow(lalrpop::never_reduced)]
+grammar;
+pub S: () = "a" => ();
+T: () = "b" => ();
+"#),
+               vec![]);
+}
+
+#[test]
+fn lint_attributes_are_not_emitted() {
+    let grammar = normalized_grammar(r#"
+#![deny(lalrpop::unused_terminal, lalrpop::shadowed_token)]
+#![allow(dead_code)]
+grammar;
+pub S: () = "a" => ();
+"#);
+    assert_eq!(grammar.module_attributes, vec!["#![allow(dead_code)]".to_string()]);
+    assert_eq!(grammar.lint_levels.get(&Lint::UnusedTerminal), Some(&LintLevel::Deny));
+    assert_eq!(grammar.lint_levels.get(&Lint::ShadowedToken), Some(&LintLevel::Deny));
+}
+
+#[test]
+fn unknown_lint() {
+    let grammar = parser::parse_grammar(r#"
+#![warn(lalrpop::unused_everything)]
+grammar;
+pub S: () = "a" => ();
+"#).unwrap();
+    let err = normalize_without_validating(grammar).unwrap_err();
+    assert_eq!(err.message, "unknown lint `lalrpop::unused_everything`");
+}
+
+#[test]
+fn mixed_attribute() {
+    let attributes = vec!["#![allow(dead_code, lalrpop::never_reduced)]".to_string()];
+    assert!(extract_lint_levels(attributes).is_err());
+}
+
+/// The warnings of `lalrpop::dead_alternative` for the tables built
+/// for `grammar`, resolving any conflicts.
+fn dead_alternatives(grammar: &Grammar) -> Vec<String> {
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let mut reduced = set();
+    for &start in grammar.start_nonterminals.values() {
+        let states = match build_states(&Session::test(), grammar, start) {
+            Ok(states) => states,
+            Err(_) => build_states_resolving_conflicts(&Session::test(), grammar, start),
+        };
+        for state in &states {
+            reduced.extend(state.reductions.iter().map(|&(_, production)| production));
+        }
+    }
+    check_reductions(grammar, &reduced).into_iter().map(|w| w.message).collect()
+}
+
+#[test]
+fn dead_alternative() {
+    let grammar = normalized_grammar(r#"
+grammar;
+pub S: () = { "a" T => (), "b" => () };
+T: () = { "c" => (), "c" => (), "d" => () };
+"#);
+    assert_eq!(dead_alternatives(&grammar),
+               vec!["this alternative of `T` is never reduced, because another action always \
+                     wins where it would apply"]);
+}
+
+#[test]
+fn no_dead_alternatives() {
+    let grammar = normalized_grammar(r#"
+grammar;
+pub E: () = { E "+" T => (), T => () };
+pub T: () = { T "*" F => (), F => () };
+F: () = { "(" E ")" => (), "x" => (), => () };
+"#);
+    assert!(dead_alternatives(&grammar).is_empty());
+}
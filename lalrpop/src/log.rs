@@ -42,9 +42,13 @@ macro_rules! log {
     }
 }
 
+/// Debug output for code that is not handed a session; it goes
+/// through the session in TLS, if one is installed (see `tls`).
 macro_rules! debug {
     ($($args:expr),*) => {
-        log!(::tls::Tls::session(), Debug, $($args),*)
+        if let Some(session) = ::tls::Tls::session() {
+            log!(session, Debug, $($args),*)
+        }
     }
 }
 
@@ -52,13 +56,42 @@ macro_rules! profile {
     ($session:expr, $phase_name:expr, $action:expr) => {
         {
             log!($session, Verbose, "Phase `{}` begun", $phase_name);
-            let time_stamp = ::std::time::Instant::now();
+            let stopwatch = ::log::Stopwatch::start();
             let result = $action;
-            let elapsed = time_stamp.elapsed();
             log!($session, Verbose, "Phase `{}` completed in {} seconds",
-                 $phase_name, elapsed.as_secs() as f64 + elapsed.subsec_nanos() as f64 / 1000_000_000.0);
+                 $phase_name, stopwatch.seconds());
             result
         }
     }
 }
 
+
+/// Measures how long a phase takes, for `profile!`. There is no clock
+/// on wasm32, so there every phase takes no time.
+pub struct Stopwatch {
+    #[cfg(not(target_arch = "wasm32"))]
+    start: ::std::time::Instant,
+}
+
+impl Stopwatch {
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn start() -> Stopwatch {
+        Stopwatch { start: ::std::time::Instant::now() }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn start() -> Stopwatch {
+        Stopwatch {}
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn seconds(&self) -> f64 {
+        let elapsed = self.start.elapsed();
+        elapsed.as_secs() as f64 + elapsed.subsec_nanos() as f64 / 1000_000_000.0
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn seconds(&self) -> f64 {
+        0.0
+    }
+}
@@ -0,0 +1,281 @@
+//! A bounded search for ambiguity. A conflict only means that the
+//! LR(1) construction could not decide what to do with one token of
+//! lookahead; the grammar may or may not actually be ambiguous. This
+//! module looks for a concrete sentence that has two distinct parse
+//! trees by exploring the leftmost derivations of a nonterminal, in
+//! breadth-first order, up to a maximum sentence length. Since two
+//! distinct leftmost derivations always correspond to two distinct
+//! parse trees, anything it reports is a genuine ambiguity; but not
+//! finding one proves nothing beyond the bound.
+
+use collections::{map, set, Map, Set};
+use file_text::FileText;
+use grammar::repr::*;
+use lr1::example::{Example, ExampleStyles, ExampleSymbol, Reduction};
+use message::{Message};
+use message::builder::MessageBuilder;
+use session::Session;
+use std::collections::VecDeque;
+use std::usize;
+
+#[cfg(test)]
+mod test;
+
+/// Upper limit on the number of partial derivations we examine, so
+/// that the search terminates in reasonable time no matter how large
+/// the grammar is.
+const MAX_DERIVATIONS: usize = 100_000;
+
+/// A sentence derivable from `nonterminal` in two different ways.
+#[derive(Debug)]
+pub struct Ambiguity<'grammar> {
+    pub nonterminal: NonterminalString,
+    pub first: Vec<&'grammar Production>,
+    pub second: Vec<&'grammar Production>,
+}
+
+/// A leftmost derivation in progress: `prefix` has been derived
+/// entirely, `remaining` is what is left of the sentential form, and
+/// `productions` is the sequence of productions applied so far.
+struct Derivation<'grammar> {
+    prefix: Vec<TerminalString>,
+    remaining: Vec<Symbol>,
+    productions: Vec<&'grammar Production>,
+}
+
+/// Searches for a sentence of at most `max_length` terminals that
+/// can be derived from `nonterminal` in two different ways.
+pub fn search_ambiguity<'grammar>(grammar: &'grammar Grammar,
+                                  nonterminal: NonterminalString,
+                                  max_length: usize)
+                                  -> Option<Ambiguity<'grammar>> {
+    let mut sentences: Map<Vec<TerminalString>, Vec<&'grammar Production>> = map();
+    let mut result = None;
+    explore(grammar, nonterminal, max_length, |sentence, productions| {
+        if let Some(previous) = sentences.get(&sentence) {
+            result = Some(Ambiguity {
+                nonterminal: nonterminal,
+                first: previous.clone(),
+                second: productions,
+            });
+            return true;
+        }
+        sentences.insert(sentence, productions);
+        false
+    });
+    result
+}
+
+/// Returns the sentences of at most `max_length` terminals that can
+/// be derived from `nonterminal`. For large grammars, this may not be
+/// all of them, as the search is bounded.
+pub fn sentences(grammar: &Grammar,
+                 nonterminal: NonterminalString,
+                 max_length: usize)
+                 -> Set<Vec<TerminalString>> {
+    let mut sentences = set();
+    explore(grammar, nonterminal, max_length, |sentence, _| {
+        sentences.insert(sentence);
+        false
+    });
+    sentences
+}
+
+/// Explores the leftmost derivations of `nonterminal` in
+/// breadth-first order, invoking `complete` with each sentence of at
+/// most `max_length` terminals that is found, along with the
+/// productions used to derive it, until `complete` returns true.
+fn explore<'grammar, F>(grammar: &'grammar Grammar,
+                        nonterminal: NonterminalString,
+                        max_length: usize,
+                        mut complete: F)
+    where F: FnMut(Vec<TerminalString>, Vec<&'grammar Production>) -> bool
+{
+    let min_lengths = min_lengths(grammar);
+    let min_length = |symbols: &[Symbol]| {
+        symbols.iter()
+               .map(|symbol| match *symbol {
+                   Symbol::Terminal(_) => 1,
+                   Symbol::Nonterminal(nt) => min_lengths[&nt],
+               })
+               .fold(0, |sum: usize, len| sum.saturating_add(len))
+    };
+
+    let mut queue = VecDeque::new();
+    queue.push_back(Derivation {
+        prefix: vec![],
+        remaining: vec![Symbol::Nonterminal(nonterminal)],
+        productions: vec![],
+    });
+
+    let mut derivations = 0;
+    while let Some(mut derivation) = queue.pop_front() {
+        derivations += 1;
+        if derivations > MAX_DERIVATIONS {
+            return;
+        }
+
+        // move the leading terminals over to the prefix
+        let leading = derivation.remaining
+                                .iter()
+                                .take_while(|symbol| match **symbol {
+                                    Symbol::Terminal(_) => true,
+                                    Symbol::Nonterminal(_) => false,
+                                })
+                                .count();
+        for symbol in derivation.remaining.drain(..leading) {
+            if let Symbol::Terminal(term) = symbol {
+                derivation.prefix.push(term);
+            }
+        }
+
+        let nt = match derivation.remaining.first() {
+            Some(&Symbol::Nonterminal(nt)) => nt,
+            Some(&Symbol::Terminal(_)) => unreachable!(),
+            None => {
+                if complete(derivation.prefix, derivation.productions) {
+                    return;
+                }
+                continue;
+            }
+        };
+
+        for production in grammar.productions_for(nt) {
+            let remaining: Vec<Symbol> =
+                production.symbols.iter()
+                                  .chain(&derivation.remaining[1..])
+                                  .cloned()
+                                  .collect();
+            if derivation.prefix.len().saturating_add(min_length(&remaining)) > max_length {
+                continue;
+            }
+            let mut productions = derivation.productions.clone();
+            productions.push(production);
+            queue.push_back(Derivation {
+                prefix: derivation.prefix.clone(),
+                remaining: remaining,
+                productions: productions,
+            });
+        }
+    }
+}
+
+/// The length of the shortest sentence each nonterminal can derive
+/// (`usize::MAX` if it derives none).
+fn min_lengths(grammar: &Grammar) -> Map<NonterminalString, usize> {
+    let mut lengths: Map<NonterminalString, usize> =
+        grammar.nonterminals.keys().map(|&nt| (nt, usize::MAX)).collect();
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (&nt, data) in &grammar.nonterminals {
+            for production in &data.productions {
+                let len = production.symbols
+                                    .iter()
+                                    .map(|symbol| match *symbol {
+                                        Symbol::Terminal(_) => 1,
+                                        Symbol::Nonterminal(other) => lengths[&other],
+                                    })
+                                    .fold(0, |sum: usize, len| sum.saturating_add(len));
+                if len < lengths[&nt] {
+                    lengths.insert(nt, len);
+                    changed = true;
+                }
+            }
+        }
+    }
+
+    lengths
+}
+
+impl<'grammar> Ambiguity<'grammar> {
+    /// Renders one of the two derivations as an `Example`.
+    pub fn example(&self, productions: &[&'grammar Production]) -> Example {
+        let mut example = Example {
+            symbols: vec![],
+            cursor: 0,
+            reductions: vec![],
+        };
+        let mut productions = productions.iter();
+        push_tree(&mut example, self.nonterminal, &mut productions);
+        example
+    }
+}
+
+/// Appends the subtree for `nonterminal` to `example`, consuming its
+/// productions from `productions` (which are in leftmost, i.e.
+/// pre-order, order).
+fn push_tree(example: &mut Example,
+             nonterminal: NonterminalString,
+             productions: &mut ::std::slice::Iter<&Production>) {
+    let production = productions.next().unwrap();
+    assert_eq!(production.nonterminal, nonterminal);
+
+    let start = example.symbols.len();
+    if production.symbols.is_empty() {
+        example.symbols.push(ExampleSymbol::Epsilon);
+    }
+    for &symbol in &production.symbols {
+        match symbol {
+            Symbol::Terminal(_) => example.symbols.push(ExampleSymbol::Symbol(symbol)),
+            Symbol::Nonterminal(nt) => push_tree(example, nt, productions),
+        }
+    }
+    example.reductions.push(Reduction {
+        start: start,
+        end: example.symbols.len(),
+        nonterminal: nonterminal,
+    });
+}
+
+/// Builds an error message showing the ambiguous sentence and both
+/// of its parse trees. The message points at the first production
+/// where the two derivations differ.
+pub fn report_ambiguity(session: &Session,
+                        file_text: &FileText,
+                        ambiguity: &Ambiguity)
+                        -> Message {
+    let span = ambiguity.first
+                        .iter()
+                        .zip(&ambiguity.second)
+                        .find(|&(p, q)| p != q)
+                        .map(|(p, _)| p.span)
+                        .unwrap_or(ambiguity.first[0].span);
+
+    let first = ambiguity.example(&ambiguity.first);
+    let second = ambiguity.example(&ambiguity.second);
+    let styles = ExampleStyles::ambig(session);
+
+    MessageBuilder::new(session, file_text, span)
+        .heading()
+        .text("Ambiguous grammar detected")
+        .end()
+        .body()
+
+        .begin_lines()
+        .begin_wrap()
+        .text("The following sentence can be parsed as")
+        .push(ambiguity.nonterminal.into_content(session))
+        .verbatimed()
+        .text("in two ways:")
+        .end()
+        .push(first.to_symbol_list(first.symbols.len(), styles))
+        .end()
+
+        .begin_lines()
+        .wrap_text("It could be parsed like so:")
+        .push(first.into_picture(session, styles))
+        .end()
+
+        .begin_lines()
+        .wrap_text("Alternatively, it could be parsed like so:")
+        .push(second.into_picture(session, styles))
+        .end()
+
+        .wrap_text("LALRPOP does not yet support ambiguous grammars. \
+                    See the LALRPOP manual for advice on \
+                    making your grammar unambiguous.")
+        .end()
+        .end()
+}
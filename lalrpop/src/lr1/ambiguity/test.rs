@@ -0,0 +1,53 @@
+use intern::intern;
+use grammar::repr::*;
+use test_util::normalized_grammar;
+
+use super::search_ambiguity;
+
+fn nt(t: &str) -> NonterminalString {
+    NonterminalString(intern(t))
+}
+
+#[test]
+fn ambiguous_sum() {
+    let grammar = normalized_grammar(r#"
+grammar;
+    pub E: () = { E "+" E => (), "x" => () };
+"#);
+    let ambiguity = search_ambiguity(&grammar, nt("E"), 8).unwrap();
+    let spans = |productions: &[&Production]| {
+        ambiguity.example(productions)
+                 .reductions
+                 .iter()
+                 .map(|r| (r.start, r.end))
+                 .collect::<Vec<_>>()
+    };
+
+    // x + x + x, reduced as (x + x) + x or as x + (x + x)
+    assert_eq!(ambiguity.example(&ambiguity.first).symbols.len(), 5);
+    assert_eq!(spans(&ambiguity.first), vec![(0, 1), (2, 3), (0, 3), (4, 5), (0, 5)]);
+    assert_eq!(spans(&ambiguity.second), vec![(0, 1), (2, 3), (4, 5), (2, 5), (0, 5)]);
+}
+
+#[test]
+fn ambiguous_empty_sequence() {
+    let grammar = normalized_grammar(r#"
+grammar;
+    pub S: () = { S A => (), () => () };
+    A: () = { "x" => (), () => () };
+"#);
+    let ambiguity = search_ambiguity(&grammar, nt("S"), 2).unwrap();
+    assert!(ambiguity.first != ambiguity.second);
+}
+
+#[test]
+fn unambiguous_conflict() {
+    // needs two tokens of lookahead, but is not ambiguous
+    let grammar = normalized_grammar(r#"
+grammar;
+    pub S: () = { A "x" "y" => (), B "x" "z" => () };
+    A: () = "a" => ();
+    B: () = "a" => ();
+"#);
+    assert!(search_ambiguity(&grammar, nt("S"), 6).is_none());
+}
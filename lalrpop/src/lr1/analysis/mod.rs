@@ -0,0 +1,237 @@
+//! Gathers what LALRPOP knows about a grammar -- its FIRST and FOLLOW
+//! sets, the states of each parser and the conflicts among them --
+//! into plain data (`lalrpop::process_grammar_for_analysis`), so that
+//! tools such as grammar debuggers or CI bots can be built on top of
+//! LALRPOP rather than scraping its reports. Symbols, tokens, items
+//! and productions are given as strings in the same notation that the
+//! reports use (e.g., `Expr = Expr (*) "+" Expr ["+", EOF]`).
+
+use file_text::FileText;
+use grammar::repr::*;
+use lr1::build_states;
+use lr1::conflicts::example_symbols;
+use lr1::core::*;
+use lr1::error::{conflict_examples, report_conflict};
+use lr1::example::Example;
+use lr1::first::FirstSets;
+use lr1::follow::FollowSets;
+use lr1::lookahead::TokenSet;
+use lr1::sets::reported_nonterminals;
+use lr1::tls::Lr1Tls;
+use message::Content;
+use session::Session;
+
+#[cfg(test)]
+mod test;
+
+/// Everything found while analyzing a grammar.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GrammarAnalysis {
+    /// The nullable, FIRST and FOLLOW sets of each nonterminal of the
+    /// expanded grammar (leaving out those that are inlined).
+    pub nonterminals: Vec<NonterminalInfo>,
+
+    /// The parser for each public nonterminal.
+    pub parsers: Vec<ParserInfo>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NonterminalInfo {
+    pub name: String,
+
+    /// True if the nonterminal can match the empty string.
+    pub nullable: bool,
+
+    /// The terminals that can begin the nonterminal.
+    pub first: Vec<String>,
+
+    /// The terminals (and `EOF`) that can follow the nonterminal.
+    pub follow: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParserInfo {
+    /// The public nonterminal that this parser parses.
+    pub nonterminal: String,
+
+    /// The states of the parser. If there are conflicts, these are
+    /// the states in which they were found, which are not
+    /// necessarily all the states the parser would have.
+    pub states: Vec<StateInfo>,
+
+    /// The conflicts, one per state and lookahead token. Empty if
+    /// the parser could be built.
+    pub conflicts: Vec<ConflictInfo>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StateInfo {
+    pub index: usize,
+
+    /// The LR(1) items of the state, with their lookahead.
+    pub items: Vec<String>,
+
+    /// For each terminal that can be shifted, the state to go to.
+    pub shifts: Vec<(String, usize)>,
+
+    /// The productions that can be reduced, each with the tokens on
+    /// which it is reduced.
+    pub reductions: Vec<(String, Vec<String>)>,
+
+    /// For each nonterminal, the state to go to after reducing it.
+    pub gotos: Vec<(String, usize)>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConflictInfo {
+    /// The state in which the conflict arises.
+    pub state: usize,
+
+    /// The lookahead token on which the actions conflict.
+    pub lookahead: String,
+
+    /// The production that could be reduced.
+    pub reduce: String,
+
+    /// What else could be done instead.
+    pub action: ConflictAction,
+
+    /// An example input that leads to the conflict if we take
+    /// `action`, if one could be found.
+    pub action_example: Option<ExampleInfo>,
+
+    /// An example input that leads to the conflict if we reduce, if
+    /// one could be found.
+    pub reduce_example: Option<ExampleInfo>,
+
+    /// The lines of the message that LALRPOP would print about the
+    /// conflict.
+    pub explanation: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConflictAction {
+    Shift,
+
+    /// Reduce the given production.
+    Reduce(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExampleInfo {
+    pub symbols: Vec<String>,
+
+    /// The number of symbols seen before the conflict arises.
+    pub cursor: usize,
+}
+
+pub fn analyze_grammar(session: &Session,
+                       file_text: &FileText,
+                       grammar: &Grammar)
+                       -> GrammarAnalysis {
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+
+    let first_sets = FirstSets::new(grammar);
+    let follow_sets = FollowSets::new(grammar, &first_sets);
+    let nonterminals =
+        reported_nonterminals(grammar)
+            .into_iter()
+            .map(|nt| {
+                let mut first = first_sets.first0(&[Symbol::Nonterminal(nt)]);
+                let nullable = first.take_eof();
+                NonterminalInfo {
+                    name: nt.to_string(),
+                    nullable: nullable,
+                    first: token_strings(&first),
+                    follow: token_strings(&follow_sets.follow(nt)),
+                }
+            })
+            .collect();
+
+    let parsers =
+        grammar.start_nonterminals
+               .iter()
+               .map(|(&user_nt, &start_nt)| match build_states(session, grammar, start_nt) {
+                   Ok(states) => ParserInfo {
+                       nonterminal: user_nt.to_string(),
+                       states: states.iter().map(state_info).collect(),
+                       conflicts: vec![],
+                   },
+                   Err(error) => ParserInfo {
+                       nonterminal: user_nt.to_string(),
+                       states: error.states.iter().map(state_info).collect(),
+                       conflicts: conflict_infos(session, file_text, grammar, &error),
+                   },
+               })
+               .collect();
+
+    GrammarAnalysis {
+        nonterminals: nonterminals,
+        parsers: parsers,
+    }
+}
+
+fn state_info(state: &LR1State) -> StateInfo {
+    StateInfo {
+        index: state.index.0,
+        items: state.items.vec.iter().map(|item| format!("{:?}", item)).collect(),
+        shifts: state.shifts
+                     .iter()
+                     .map(|(terminal, next)| (terminal.to_string(), next.0))
+                     .collect(),
+        reductions: state.reductions
+                         .iter()
+                         .map(|&(ref lookahead, production)| {
+                             (production.to_string(), token_strings(lookahead))
+                         })
+                         .collect(),
+        gotos: state.gotos
+                    .iter()
+                    .map(|(nonterminal, next)| (nonterminal.to_string(), next.0))
+                    .collect(),
+    }
+}
+
+fn conflict_infos<'grammar>(session: &Session,
+                            file_text: &FileText,
+                            grammar: &'grammar Grammar,
+                            error: &LR1TableConstructionError<'grammar>)
+                            -> Vec<ConflictInfo> {
+    conflict_examples(session, file_text, grammar, error)
+        .into_iter()
+        .map(|(conflict, action_example, reduce_example)| {
+            let action = match conflict.action {
+                Action::Shift(..) => ConflictAction::Shift,
+                Action::Reduce(production) => ConflictAction::Reduce(production.to_string()),
+            };
+            let explanation =
+                report_conflict(session, file_text, grammar, error, &conflict)
+                    .emit_to_canvas(80)
+                    .to_strings()
+                    .into_iter()
+                    .map(|row| row.to_string().trim_right().to_string())
+                    .collect();
+            ConflictInfo {
+                state: conflict.state.0,
+                lookahead: conflict.lookahead.to_string(),
+                reduce: conflict.production.to_string(),
+                action: action,
+                action_example: action_example.as_ref().map(example_info),
+                reduce_example: reduce_example.as_ref().map(example_info),
+                explanation: explanation,
+            }
+        })
+        .collect()
+}
+
+fn example_info(example: &Example) -> ExampleInfo {
+    let (symbols, cursor) = example_symbols(example);
+    ExampleInfo {
+        symbols: symbols,
+        cursor: cursor,
+    }
+}
+
+fn token_strings(set: &TokenSet) -> Vec<String> {
+    set.iter().map(|token| token.to_string()).collect()
+}
@@ -0,0 +1,64 @@
+use file_text::FileText;
+use session::Session;
+use test_util::normalized_grammar;
+use super::{analyze_grammar, ConflictAction, ExampleInfo};
+
+fn strings(strs: &[&str]) -> Vec<String> {
+    strs.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn expr() {
+    let grammar = normalized_grammar(r#"
+grammar;
+    pub E: () = { E "+" E => (), "x" => () };
+"#);
+    let analysis = analyze_grammar(&Session::test(), &FileText::test(), &grammar);
+
+    assert_eq!(analysis.nonterminals.len(), 1);
+    let e = &analysis.nonterminals[0];
+    assert_eq!(e.name, "E");
+    assert!(!e.nullable);
+    assert_eq!(e.first, strings(&[r#""x""#]));
+    assert_eq!(e.follow, strings(&[r#""+""#, "EOF"]));
+
+    assert_eq!(analysis.parsers.len(), 1);
+    let parser = &analysis.parsers[0];
+    assert_eq!(parser.nonterminal, "E");
+
+    assert_eq!(parser.conflicts.len(), 1);
+    let conflict = &parser.conflicts[0];
+    assert_eq!(conflict.lookahead, r#""+""#);
+    assert_eq!(conflict.reduce, r#"E = E "+" E"#);
+    assert_eq!(conflict.action, ConflictAction::Shift);
+    let example = ExampleInfo {
+        symbols: strings(&["E", r#""+""#, "E", r#""+""#, "E"]),
+        cursor: 3,
+    };
+    assert_eq!(conflict.action_example.as_ref(), Some(&example));
+    assert_eq!(conflict.reduce_example.as_ref(), Some(&example));
+    assert!(conflict.explanation.iter().any(|line| line.contains("Ambiguous grammar detected")));
+
+    // the conflict is in one of the states that were built
+    let state = &parser.states[conflict.state];
+    assert_eq!(state.index, conflict.state);
+    assert_eq!(state.items,
+               strings(&[r#"E = E (*) "+" E ["+", "x", EOF]"#,
+                         r#"E = E "+" E (*) ["+", "x", EOF]"#]));
+}
+
+#[test]
+fn no_conflicts() {
+    let grammar = normalized_grammar(r#"
+grammar;
+    pub E: () = { E "+" "x" => (), "x" => () };
+"#);
+    let analysis = analyze_grammar(&Session::test(), &FileText::test(), &grammar);
+    let parser = &analysis.parsers[0];
+    assert!(parser.conflicts.is_empty());
+
+    // the start state shifts "x" and goes to some state on `E`
+    let start = &parser.states[0];
+    assert_eq!(start.shifts.iter().map(|s| &s.0[..]).collect::<Vec<_>>(), vec![r#""x""#]);
+    assert!(start.gotos.iter().any(|g| g.0 == "E"));
+}
@@ -0,0 +1,354 @@
+//! Runs the LR(1) parser for a nonterminal over some input and writes
+//! the steps it takes as a self-contained HTML page that animates the
+//! parse (`lalrpop animate`): at each step, the page shows the stack
+//! of states and symbols, the input that remains and the action the
+//! parser takes next. This is meant for teaching how LR parsers work.
+//!
+//! Grammars with the built-in tokenizer are given the input text
+//! itself. For grammars with an `extern` token declaration, we cannot
+//! run the user's tokenizer, so the input is a list of terminals
+//! separated by whitespace, like `Num "+" Num`.
+
+use grammar::repr::*;
+use lexer::dfa::interpret::interpret;
+use lr1::build_states;
+use lr1::conflicts::json_string;
+use lr1::core::*;
+use lr1::lookahead::Token;
+use lr1::tls::Lr1Tls;
+use session::Session;
+use std::io::{self, Write};
+
+#[cfg(test)]
+mod test;
+
+/// One configuration of the parser, and what it does next.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Step<'grammar> {
+    pub states: Vec<StateIndex>,
+
+    /// The symbols that took us from each state on the stack to the
+    /// next; one fewer than `states`.
+    pub symbols: Vec<String>,
+
+    /// The text of the tokens still to be read.
+    pub input: Vec<String>,
+
+    pub kind: StepKind<'grammar>,
+
+    /// `kind`, described for people.
+    pub action: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StepKind<'grammar> {
+    Shift(TerminalString),
+    Reduce(&'grammar Production),
+    Accept,
+    Error,
+}
+
+/// The input, cut into tokens as far as that was possible.
+pub struct Tokens {
+    pub tokens: Vec<(TerminalString, String)>,
+
+    /// The input from the first place where no token matched, if any.
+    pub rest: String,
+}
+
+pub fn animate_parse<W: Write>(session: &Session,
+                               out: &mut W,
+                               grammar: &Grammar,
+                               nonterminal: NonterminalString,
+                               input: &str)
+                               -> io::Result<()> {
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let states = try!(build_parser(session, grammar, nonterminal));
+    let steps = parse_steps(&states, &tokenize(grammar, input));
+    write_page(out, &format!("Parsing `{}` as `{}`", input, nonterminal), &steps)
+}
+
+/// `nonterminal`, or else the only public nonterminal of `grammar`.
+pub fn choose_nonterminal(grammar: &Grammar,
+                          nonterminal: Option<NonterminalString>)
+                          -> io::Result<NonterminalString> {
+    match nonterminal {
+        Some(nonterminal) => Ok(nonterminal),
+        None => {
+            let public: Vec<String> =
+                grammar.start_nonterminals.keys().map(|nt| format!("`{}`", nt)).collect();
+            if public.len() != 1 {
+                return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                                          format!("the grammar has several public nonterminals \
+                                                   ({}); choose one with `--start`",
+                                                  public.join(", "))));
+            }
+            Ok(*grammar.start_nonterminals.keys().next().unwrap())
+        }
+    }
+}
+
+/// Builds the states of the parser for the public nonterminal
+/// `nonterminal`. The `Lr1Tls` must be installed.
+pub fn build_parser<'grammar>(session: &Session,
+                              grammar: &'grammar Grammar,
+                              nonterminal: NonterminalString)
+                              -> io::Result<Vec<LR1State<'grammar>>> {
+    let start_nt = match grammar.start_nonterminals.get(&nonterminal) {
+        Some(&start_nt) => start_nt,
+        None => {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                                      format!("`{}` is not a public nonterminal", nonterminal)));
+        }
+    };
+    build_states(session, grammar, start_nt).map_err(|_| {
+        io::Error::new(io::ErrorKind::InvalidData,
+                       "the grammar has conflicts, so there is no parser to run")
+    })
+}
+
+pub fn tokenize(grammar: &Grammar, input: &str) -> Tokens {
+    let mut tokens = vec![];
+    let mut text = input.trim_left();
+    match grammar.intern_token {
+        Some(ref intern_token) => {
+            while !text.is_empty() {
+                match interpret(&intern_token.dfa, text) {
+                    Some((index, matched)) if !matched.is_empty() => {
+                        let terminal = intern_token.match_entries[index.index()].user_name;
+                        tokens.push((terminal, matched.to_string()));
+                        text = text[matched.len()..].trim_left();
+                    }
+                    _ => break,
+                }
+            }
+        }
+        None => {
+            while !text.is_empty() {
+                let word = text.split_whitespace().next().unwrap();
+                match grammar.terminals.all.iter().find(|t| t.to_string() == word) {
+                    Some(&terminal) => tokens.push((terminal, word.to_string())),
+                    None => break,
+                }
+                text = text[word.len()..].trim_left();
+            }
+        }
+    }
+    Tokens {
+        tokens: tokens,
+        rest: text.to_string(),
+    }
+}
+
+/// Runs the parser with the given `states` over `tokens`. The last
+/// step is always an `Accept` or an `Error`.
+pub fn parse_steps<'grammar>(states: &[LR1State<'grammar>], tokens: &Tokens) -> Vec<Step<'grammar>> {
+    let mut steps = vec![];
+    let mut stack = vec![StateIndex(0)];
+    let mut symbols: Vec<String> = vec![];
+    let mut position = 0;
+
+    loop {
+        let mut input: Vec<String> =
+            tokens.tokens[position..].iter().map(|&(_, ref text)| text.clone()).collect();
+        if !tokens.rest.is_empty() {
+            input.push(tokens.rest.clone());
+        }
+        let mut step = Step {
+            states: stack.clone(),
+            symbols: symbols.clone(),
+            input: input,
+            kind: StepKind::Error,
+            action: String::new(),
+        };
+
+        let state = &states[stack.last().unwrap().0];
+        let lookahead = match tokens.tokens.get(position) {
+            Some(&(terminal, _)) => Token::Terminal(terminal),
+            None if !tokens.rest.is_empty() => {
+                step.action = format!("error: no token matches the input at `{}`", tokens.rest);
+                steps.push(step);
+                return steps;
+            }
+            None => Token::EOF,
+        };
+
+        if let Token::Terminal(terminal) = lookahead {
+            if let Some(&next) = state.shifts.get(&terminal) {
+                step.kind = StepKind::Shift(terminal);
+                step.action = format!("shift {} and go to state {}", terminal, next.0);
+                steps.push(step);
+                stack.push(next);
+                symbols.push(terminal.to_string());
+                position += 1;
+                continue;
+            }
+        }
+
+        let production = state.reductions
+                              .iter()
+                              .find(|&&(ref tokens, _)| tokens.contains(lookahead))
+                              .map(|&(_, production)| production);
+        let production = match production {
+            Some(production) => production,
+            None => {
+                let mut expected: Vec<String> =
+                    state.shifts.keys().map(|terminal| terminal.to_string()).collect();
+                for &(ref tokens, _) in &state.reductions {
+                    expected.extend(tokens.iter().map(|token| token.to_string()));
+                }
+                expected.sort();
+                expected.dedup();
+                step.action = format!("error: unexpected {}; expected one of {}",
+                                      lookahead,
+                                      expected.join(", "));
+                steps.push(step);
+                return steps;
+            }
+        };
+
+        let remaining = symbols.len() - production.symbols.len();
+        stack.truncate(remaining + 1);
+        symbols.truncate(remaining);
+
+        // the reduction of the artificial start nonterminal is the
+        // one nothing goes to
+        match states[stack.last().unwrap().0].gotos.get(&production.nonterminal) {
+            Some(&next) => {
+                step.kind = StepKind::Reduce(production);
+                step.action = format!("reduce {} and go to state {}",
+                                      production,
+                                      next.0);
+                steps.push(step);
+                stack.push(next);
+                symbols.push(production.nonterminal.to_string());
+            }
+            None => {
+                step.kind = StepKind::Accept;
+                step.action = String::from("accept");
+                steps.push(step);
+                return steps;
+            }
+        }
+    }
+}
+
+fn write_page<W: Write>(out: &mut W, title: &str, steps: &[Step]) -> io::Result<()> {
+    let steps: Vec<String> =
+        steps.iter()
+             .map(|step| {
+                 let states: Vec<String> = step.states.iter().map(|s| s.0.to_string()).collect();
+                 let symbols: Vec<String> = step.symbols.iter().map(|s| script_string(s)).collect();
+                 let input: Vec<String> = step.input.iter().map(|s| script_string(s)).collect();
+                 format!("  {{ states: [{}], symbols: [{}], input: [{}], action: {} }}",
+                         states.join(", "),
+                         symbols.join(", "),
+                         input.join(", "),
+                         script_string(&step.action))
+             })
+             .collect();
+    let page = PAGE.replace("$TITLE", &html_escape(title))
+                   .replace("$STEPS", &steps.join(",\n"));
+    out.write_all(page.as_bytes())
+}
+
+/// `s` as a string literal that can go in a `<script>` element.
+pub fn script_string(s: &str) -> String {
+    json_string(s).replace("<", "\\u003c")
+}
+
+/// `s` escaped for HTML. This also escapes `$`, so that the title
+/// cannot contain the `$STEPS` placeholder.
+fn html_escape(s: &str) -> String {
+    s.replace("&", "&amp;")
+     .replace("<", "&lt;")
+     .replace(">", "&gt;")
+     .replace("\"", "&quot;")
+     .replace("$", "&#36;")
+}
+
+const PAGE: &'static str = r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>$TITLE</title>
+<style>
+  body { font-family: sans-serif; margin: 2em; }
+  table { border-collapse: collapse; margin: 1em 0; }
+  td { border: 1px solid #999; padding: 0.3em 0.6em; font-family: monospace; text-align: center; }
+  th { padding: 0.3em 0.6em; text-align: right; font-weight: normal; color: #666; }
+  #action { font-size: 120%; font-family: monospace; }
+  .top { background: #ffd; }
+</style>
+</head>
+<body>
+<h1>$TITLE</h1>
+<p>
+  <button onclick="show(0)">&laquo; First</button>
+  <button onclick="show(current - 1)">&lsaquo; Back</button>
+  <button onclick="show(current + 1)">Next &rsaquo;</button>
+  <button onclick="show(steps.length - 1)">Last &raquo;</button>
+  <button onclick="play()" id="play">Play</button>
+  <span id="counter"></span>
+</p>
+<h2>Stack</h2>
+<table id="stack"></table>
+<h2>Remaining input</h2>
+<table id="input"></table>
+<h2>Next action</h2>
+<p id="action"></p>
+<script>
+var steps = [
+$STEPS
+];
+var current = 0;
+var timer = null;
+
+function row(label, cells, top) {
+  var tr = document.createElement("tr");
+  var th = document.createElement("th");
+  th.textContent = label;
+  tr.appendChild(th);
+  cells.forEach(function (cell, index) {
+    var td = document.createElement("td");
+    td.textContent = cell;
+    if (index == top) { td.className = "top"; }
+    tr.appendChild(td);
+  });
+  return tr;
+}
+
+function show(index) {
+  current = Math.max(0, Math.min(index, steps.length - 1));
+  var step = steps[current];
+  var stack = document.getElementById("stack");
+  stack.innerHTML = "";
+  stack.appendChild(row("state", step.states, step.states.length - 1));
+  stack.appendChild(row("symbol", [""].concat(step.symbols), step.states.length - 1));
+  var input = document.getElementById("input");
+  input.innerHTML = "";
+  input.appendChild(row("token", step.input.length ? step.input : ["(end of input)"], 0));
+  document.getElementById("action").textContent = step.action;
+  document.getElementById("counter").textContent =
+    "Step " + (current + 1) + " of " + steps.length;
+  if (current == steps.length - 1) { stop(); }
+}
+
+function play() {
+  if (timer) { stop(); return; }
+  if (current == steps.length - 1) { show(0); }
+  document.getElementById("play").textContent = "Pause";
+  timer = setInterval(function () { show(current + 1); }, 1000);
+}
+
+function stop() {
+  clearInterval(timer);
+  timer = null;
+  document.getElementById("play").textContent = "Play";
+}
+
+show(0);
+</script>
+</body>
+</html>
+"#;
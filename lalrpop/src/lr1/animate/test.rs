@@ -0,0 +1,96 @@
+use grammar::repr::*;
+use intern::intern;
+use lr1::build_states;
+use lr1::tls::Lr1Tls;
+use session::Session;
+use test_util::normalized_grammar;
+
+use super::{animate_parse, parse_steps, tokenize};
+
+const GRAMMAR: &'static str = r#"
+grammar;
+    pub E: () = { E "+" T => (), T => () };
+    T: () = { "x" => (), "(" E ")" => () };
+"#;
+
+fn actions(grammar: &str, input: &str) -> Vec<String> {
+    let grammar = normalized_grammar(grammar);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let start_nt = grammar.start_nonterminals[&NonterminalString(intern("E"))];
+    let states = build_states(&Session::test(), &grammar, start_nt).unwrap();
+    parse_steps(&states, &tokenize(&grammar, input))
+        .into_iter()
+        .map(|step| {
+            // leave out the state numbers, which depend on how the
+            // states happen to be built
+            let action = step.action;
+            match action.find(" and go to state") {
+                Some(index) => action[..index].to_string(),
+                None => action,
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn accept() {
+    assert_eq!(actions(GRAMMAR, "x + (x)"),
+               vec![r#"shift "x""#,
+                    r#"reduce T = "x""#,
+                    r#"reduce E = T"#,
+                    r#"shift "+""#,
+                    r#"shift "(""#,
+                    r#"shift "x""#,
+                    r#"reduce T = "x""#,
+                    r#"reduce E = T"#,
+                    r#"shift ")""#,
+                    r#"reduce T = "(" E ")""#,
+                    r#"reduce E = E "+" T"#,
+                    r#"accept"#]);
+}
+
+#[test]
+fn unexpected_token() {
+    assert_eq!(actions(GRAMMAR, "x x"),
+               vec![r#"shift "x""#,
+                    r#"error: unexpected "x"; expected one of ")", "+", EOF"#]);
+}
+
+#[test]
+fn invalid_token() {
+    assert_eq!(actions(GRAMMAR, "x + y"),
+               vec![r#"shift "x""#,
+                    r#"reduce T = "x""#,
+                    r#"reduce E = T"#,
+                    r#"shift "+""#,
+                    r#"error: no token matches the input at `y`"#]);
+}
+
+#[test]
+fn extern_tokens() {
+    let grammar = r#"
+grammar;
+    extern { enum Tok { Num => Tok::Num, "+" => Tok::Plus } }
+    pub E: () = { E "+" Num => (), Num => () };
+"#;
+    assert_eq!(actions(grammar, r#"Num "+" Num"#),
+               vec![r#"shift Num"#,
+                    r#"reduce E = Num"#,
+                    r#"shift "+""#,
+                    r#"shift Num"#,
+                    r#"reduce E = E "+" Num"#,
+                    r#"accept"#]);
+}
+
+#[test]
+fn page() {
+    let grammar = normalized_grammar(GRAMMAR);
+    let mut out = vec![];
+    let nonterminal = NonterminalString(intern("E"));
+    animate_parse(&Session::test(), &mut out, &grammar, nonterminal, "x</script>").unwrap();
+    let out = String::from_utf8(out).unwrap();
+    assert!(out.contains("<title>Parsing `x&lt;/script&gt;` as `E`</title>"));
+    assert!(out.contains(r#"action: "shift \"x\" and go to state "#));
+    // the input cannot end the script early
+    assert!(out.contains(r#"action: "error: no token matches the input at `\u003c/script>`" }"#));
+}
@@ -3,26 +3,128 @@
 use collections::{map, Multimap};
 use kernel_set;
 use grammar::repr::*;
+use lr1::checkpoint::{Checkpoints, CheckpointLookahead, Progress};
 use lr1::core::*;
 use lr1::first;
 use lr1::lane_table::*;
 use lr1::lookahead::*;
+use lr1::memory;
+use session::Session;
+use std::fmt;
 use std::rc::Rc;
 use std::env;
-use tls::Tls;
 
 #[cfg(test)]
 mod test;
 
-fn build_lr1_states_legacy<'grammar>(grammar: &'grammar Grammar, start: NonterminalString) -> LR1Result<'grammar>
+pub fn build_lr1_states_legacy<'grammar>(session: &Session,
+                                         grammar: &'grammar Grammar,
+                                         start: NonterminalString)
+                                         -> LR1Result<'grammar>
 {
     let eof = TokenSet::eof();
     let mut lr1: LR<'grammar, TokenSet> = LR::new(grammar, start, eof);
-    lr1.set_permit_early_stop(true);
-    lr1.build_states()
+    lr1.set_permit_early_stop(!session.report_all_conflicts);
+    lr1.set_fallback_budget(session.lr1_fallback_budget);
+    lr1.set_checkpoints(Checkpoints::new::<TokenSet>(session, grammar, start));
+    match lr1.build_states_within_budget(session) {
+        Some(result) => result,
+        None => {
+            // the states the parser is generated from may as well
+            // merge those that only differ in their lookaheads
+            log!(session, Informative,
+                 "The LR(1) states for `{}` exceed the fallback budget; \
+                  building them with the lane table algorithm instead.",
+                 start);
+            build_lane_table_states(session, grammar, start)
+        }
+    }
+}
+
+/// The canonical LR(1) states for `start` came to take more than the
+/// `budget` of `Configuration::lr1_fallback_budget`, in bytes, and were
+/// given up on.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OverBudget {
+    pub start: NonterminalString,
+    pub budget: usize,
+}
+
+impl fmt::Display for OverBudget {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt,
+               "the canonical LR(1) states for `{}` exceed the fallback budget of {} bytes",
+               self.start,
+               self.budget)
+    }
+}
+
+/// Builds the canonical LR(1) states, all of them even if there are
+/// conflicts. Unlike the lane table algorithm, which gives up on
+/// exact lookaheads when it finds conflicts, this reports each
+/// conflict with just the tokens it arises on. The callers need those
+/// exact lookaheads, so if the states exceed the fallback budget, this
+/// gives up with `OverBudget` rather than build other states.
+pub fn build_canonical_lr1_states<'grammar>(session: &Session,
+                                            grammar: &'grammar Grammar,
+                                            start: NonterminalString)
+                                            -> Result<LR1Result<'grammar>, OverBudget>
+{
+    let mut lr1: LR<'grammar, TokenSet> = LR::new(grammar, start, TokenSet::eof());
+    lr1.set_fallback_budget(session.lr1_fallback_budget);
+    lr1.set_checkpoints(Checkpoints::new::<TokenSet>(session, grammar, start));
+    let result = memory::pass("canonical LR(1) state construction",
+                              || lr1.build_states_within_budget(session));
+    result.ok_or_else(|| OverBudget {
+        start: start,
+        budget: session.lr1_fallback_budget.unwrap(),
+    })
+}
+
+/// Resolves the conflicts of `states` the way yacc does by default: a
+/// shift wins over a reduction, and between two reductions, the
+/// production that comes first in the grammar wins.
+pub fn resolve_conflicts(grammar: &Grammar, states: &mut [LR1State]) {
+    for state in states {
+        resolve_state_conflicts(grammar, state);
+    }
 }
 
-type ConstructionFunction<'grammar> = fn(&'grammar Grammar, NonterminalString) -> LR1Result<'grammar> ;
+fn resolve_state_conflicts(grammar: &Grammar, state: &mut LR1State) {
+    let mut taken = TokenSet::new();
+    for &terminal in state.shifts.keys() {
+        taken.insert(Token::Terminal(terminal));
+    }
+
+    state.reductions.sort_by_key(|&(_, production)| grammar_order(grammar, production));
+    for &mut (ref mut lookahead, _) in &mut state.reductions {
+        let mut remaining = TokenSet::new();
+        for token in lookahead.iter() {
+            if !taken.contains(token) {
+                remaining.insert(token);
+            }
+        }
+        taken.union_with(lookahead);
+        *lookahead = remaining;
+    }
+    state.reductions.retain(|&(ref lookahead, _)| lookahead.len() > 0);
+}
+
+/// Where `production` comes in the grammar: after the productions of
+/// the nonterminals defined before its own, and after the ones listed
+/// before it in its nonterminal. The span of the production itself
+/// won't do, as the productions of macros share the span of the macro.
+fn grammar_order(grammar: &Grammar, production: &Production) -> (usize, NonterminalString, usize) {
+    let nt = &grammar.nonterminals[&production.nonterminal];
+    let index = nt.productions
+                  .iter()
+                  .position(|p| p as *const Production == production as *const Production)
+                  .unwrap();
+    (nt.span.0, production.nonterminal, index)
+}
+
+type ConstructionFunction<'grammar> =
+    fn(&Session, &'grammar Grammar, NonterminalString) -> LR1Result<'grammar>;
 
 pub fn use_lane_table() -> bool {
     match env::var("LALRPOP_LANE_TABLE") {
@@ -31,7 +133,8 @@ pub fn use_lane_table() -> bool {
     }
 }
 
-pub fn build_lr1_states<'grammar>(grammar: &'grammar Grammar,
+pub fn build_lr1_states<'grammar>(session: &Session,
+                                  grammar: &'grammar Grammar,
                                   start: NonterminalString)
                                   -> LR1Result<'grammar>
 {
@@ -41,22 +144,24 @@ pub fn build_lr1_states<'grammar>(grammar: &'grammar Grammar,
         ("legacy", build_lr1_states_legacy as ConstructionFunction)
     };
 
+    let phase_name = format!("LR(1) state construction ({})", method_name);
     profile! {
-        &Tls::session(),
-        format!("LR(1) state construction ({})", method_name),
+        session,
+        phase_name,
         {
-            method_fn(grammar, start)
+            memory::pass(&phase_name, || method_fn(session, grammar, start))
         }
     }
 }
 
-pub fn build_lr0_states<'grammar>(grammar: &'grammar Grammar,
+pub fn build_lr0_states<'grammar>(session: &Session,
+                                  grammar: &'grammar Grammar,
                                   start: NonterminalString)
                                   -> Result<Vec<LR0State<'grammar>>,
                                             LR0TableConstructionError<'grammar>>
 {
     let lr1 = LR::new(grammar, start, Nil);
-    lr1.build_states()
+    memory::pass("LR(0) state construction", || lr1.build_states(session))
 }
 
 pub struct LR<'grammar, L: LookaheadBuild> {
@@ -65,6 +170,8 @@ pub struct LR<'grammar, L: LookaheadBuild> {
     start_nt: NonterminalString,
     start_lookahead: L,
     permit_early_stop: bool,
+    fallback_budget: Option<usize>,
+    checkpoints: Option<Checkpoints<'grammar>>,
 }
 
 impl<'grammar, L: LookaheadBuild> LR<'grammar, L> {
@@ -78,6 +185,8 @@ impl<'grammar, L: LookaheadBuild> LR<'grammar, L> {
             start_nt: start_nt,
             start_lookahead: start_lookahead,
             permit_early_stop: false,
+            fallback_budget: None,
+            checkpoints: None,
         }
     }
 
@@ -85,19 +194,55 @@ impl<'grammar, L: LookaheadBuild> LR<'grammar, L> {
         self.permit_early_stop = v;
     }
 
-    fn build_states(&self)
+    fn set_fallback_budget(&mut self, budget: Option<usize>) {
+        self.fallback_budget = budget;
+    }
+
+    fn set_checkpoints(&mut self, checkpoints: Option<Checkpoints<'grammar>>) {
+        self.checkpoints = checkpoints;
+    }
+
+    fn build_states(&self, session: &Session)
                     -> Result<Vec<State<'grammar, L>>,
                               TableConstructionError<'grammar, L>>
     {
-        let session = Tls::session();
+        self.build_states_within_budget(session)
+            .expect("states built without a fallback budget")
+    }
+
+    /// Builds the states, unless the ones built so far come to take
+    /// more than the fallback budget, in which case they are dropped and
+    /// `None` is returned. With checkpoints, the states are built from
+    /// where the last checkpoint left off, if there is one.
+    fn build_states_within_budget(&self, session: &Session)
+                                  -> Option<Result<Vec<State<'grammar, L>>,
+                                                   TableConstructionError<'grammar, L>>>
+    {
         let mut kernel_set = kernel_set::KernelSet::new();
         let mut states = vec![];
         let mut conflicts = vec![];
-
-        // create the starting state
-        kernel_set.add_state(Kernel::start(self.items(self.start_nt,
-                                                      0,
-                                                      &self.start_lookahead)));
+        let mut bytes = 0;
+
+        let resumed = match self.checkpoints {
+            Some(ref checkpoints) => checkpoints.load().and_then(|progress| self.resume(progress)),
+            None => None,
+        };
+        match resumed {
+            Some((resumed_kernel_set, resumed_states)) => {
+                log!(session, Informative,
+                     "Resuming from a checkpoint with {} states built.", resumed_states.len());
+                kernel_set = resumed_kernel_set;
+                for state in &resumed_states {
+                    conflicts.extend(L::conflicts(state));
+                    bytes += memory::state_bytes(state);
+                }
+                states = resumed_states;
+            }
+            None => {
+                // create the starting state
+                kernel_set.add_state(self.start_kernel());
+            }
+        }
 
         while let Some(Kernel { items: seed_items }) = kernel_set.next() {
             let items = self.transitive_closure(seed_items);
@@ -111,7 +256,8 @@ impl<'grammar, L: LookaheadBuild> LR<'grammar, L> {
                                          items: items.clone(),
                                          shifts: map(),
                                          reductions: vec!(),
-                                         gotos: map() };
+                                         gotos: map(),
+                                         two_token_reductions: vec![] };
 
             // group the items that we can transition into by shifting
             // over a term or nonterm
@@ -160,9 +306,24 @@ impl<'grammar, L: LookaheadBuild> LR<'grammar, L> {
             // check for shift-reduce conflicts (reduce-reduce detected above)
             conflicts.extend(L::conflicts(&this_state));
 
+            bytes += memory::state_bytes(&this_state);
+            memory::note(bytes);
+
             // extract a new state
             states.push(this_state);
 
+            if self.fallback_budget.map_or(false, |budget| bytes > budget) {
+                log!(session, Verbose,
+                     "{} states take about {} bytes, giving up on them.", states.len(), bytes);
+                // a build with a larger budget can take it from here
+                self.save_checkpoint(session, &states, &kernel_set);
+                return None;
+            }
+
+            if self.checkpoints.as_ref().map_or(false, |c| c.due(states.len())) {
+                self.save_checkpoint(session, &states, &kernel_set);
+            }
+
             if self.permit_early_stop && session.stop_after(conflicts.len()) {
                 log!(session, Verbose,
                      "{} conflicts encountered, stopping.", conflicts.len());
@@ -170,11 +331,74 @@ impl<'grammar, L: LookaheadBuild> LR<'grammar, L> {
             }
         }
 
+        if let Some(ref checkpoints) = self.checkpoints {
+            checkpoints.remove();
+        }
+
         if !conflicts.is_empty() {
-            Err(TableConstructionError { states: states, conflicts: conflicts })
+            Some(Err(TableConstructionError { states: states, conflicts: conflicts }))
         } else {
-            Ok(states)
+            Some(Ok(states))
+        }
+    }
+
+    fn start_kernel(&self) -> Kernel<'grammar, L> {
+        Kernel::start(self.items(self.start_nt, 0, &self.start_lookahead))
+    }
+
+    fn save_checkpoint(&self,
+                       session: &Session,
+                       states: &[State<'grammar, L>],
+                       kernel_set: &kernel_set::KernelSet<Kernel<'grammar, L>>) {
+        if let Some(ref checkpoints) = self.checkpoints {
+            let kernels: Vec<_> = kernel_set.pending().map(|kernel| &kernel.items).collect();
+            match checkpoints.save(states, &kernels) {
+                Ok(()) => {
+                    log!(session, Verbose, "Checkpoint of {} states written.", states.len());
+                }
+                Err(err) => {
+                    log!(session, Informative,
+                         "Warning: could not write the checkpoint `{}`: {}",
+                         checkpoints.path().display(), err);
+                }
+            }
+        }
+    }
+
+    /// Picks up the construction from a checkpoint: the kernels of the
+    /// states that were built are added again, so that they keep their
+    /// indices, then those still to build. `None` if the checkpoint
+    /// does not fit the states this would build.
+    fn resume(&self, progress: Progress<'grammar, L>)
+              -> Option<(kernel_set::KernelSet<Kernel<'grammar, L>>, Vec<State<'grammar, L>>)>
+    {
+        let mut kernel_set = kernel_set::KernelSet::new();
+        let Progress { states, kernels } = progress;
+        if kernels.iter().any(|kernel| kernel.iter().any(|item| item.index == 0)) {
+            return None;
+        }
+        {
+            // the kernel of a state other than the starting one is
+            // made of the items with `index > 0`; see `Kernel`
+            let built = states.iter().map(|state| {
+                if state.index.0 == 0 {
+                    self.start_kernel()
+                } else {
+                    let items = state.items.vec.iter().filter(|i| i.index > 0);
+                    Kernel::shifted(items.cloned().collect())
+                }
+            });
+            let pending = kernels.into_iter().map(Kernel::shifted);
+            for (index, kernel) in built.chain(pending).enumerate() {
+                if kernel_set.add_state(kernel) != StateIndex(index) {
+                    return None;
+                }
+            }
+        }
+        for _ in &states {
+            kernel_set.next();
         }
+        Some((kernel_set, states))
     }
 
     fn items(&self,
@@ -302,7 +526,7 @@ impl<'grammar, L: LookaheadBuild> kernel_set::Kernel for Kernel<'grammar, L> {
     }
 }
 
-pub trait LookaheadBuild: Lookahead {
+pub trait LookaheadBuild: CheckpointLookahead {
     // Given that there exists an item
     //
     //     X = ... (*) Y ...s [L]
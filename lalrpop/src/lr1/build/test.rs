@@ -8,9 +8,10 @@ use lr1::lookahead::Token;
 use lr1::lookahead::Token::EOF;
 use lr1::lookahead::TokenSet;
 use lr1::tls::Lr1Tls;
-use tls::Tls;
+use lr1::build_states_resolving_conflicts;
+use session::Session;
 
-use super::{LR, use_lane_table, build_lr0_states, build_lr1_states};
+use super::{LR, use_lane_table, build_lr0_states, build_lr1_states, build_lr1_states_legacy};
 
 fn nt(t: &str) -> NonterminalString {
     NonterminalString(intern(t))
@@ -103,7 +104,6 @@ C: Option<u32> = {
 
 #[test]
 fn expr_grammar1() {
-    let _tls = Tls::test();
 
     let grammar = normalized_grammar(r#"
 grammar;
@@ -127,7 +127,7 @@ grammar;
 
     // for now, just test that process does not result in an error
     // and yields expected number of states.
-    let states = build_lr1_states(&grammar, nt("S")).unwrap();
+    let states = build_lr1_states(&Session::test(), &grammar, nt("S")).unwrap();
     println!("{:#?}", states);
     assert_eq!(states.len(), if use_lane_table() { 9 } else { 16 });
 
@@ -159,7 +159,6 @@ grammar;
 
 #[test]
 fn shift_reduce_conflict1() {
-    let _tls = Tls::test();
 
     // This grammar gets a shift-reduce conflict because if the input
     // is "&" (*) "L", then we see two possibilities, and we must decide
@@ -200,13 +199,12 @@ fn shift_reduce_conflict1() {
 
     let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
 
-    assert!(build_lr1_states(&grammar, nt("E")).is_err());
+    assert!(build_lr1_states(&Session::test(), &grammar, nt("E")).is_err());
 }
 
 /// One of the few grammars that IS LR(0).
 #[test]
 fn lr0_expr_grammar_with_explicit_eof() {
-    let _tls = Tls::test();
 
     let grammar = normalized_grammar(r#"
 grammar;
@@ -228,7 +226,7 @@ T: () = {
 
     // for now, just test that process does not result in an error
     // and yields expected number of states.
-    let states = build_lr0_states(&grammar, nt("S")).unwrap();
+    let states = build_lr0_states(&Session::test(), &grammar, nt("S")).unwrap();
     assert_eq!(states.len(), 10);
 }
 
@@ -236,7 +234,6 @@ T: () = {
 /// Without the artifical '$', grammar is not LR(0).
 #[test]
 fn lr0_expr_grammar_with_implicit_eof() {
-    let _tls = Tls::test();
 
     let grammar = normalized_grammar(r#"
 grammar;
@@ -256,7 +253,7 @@ T: () = {
 
     let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
 
-    build_lr0_states(&grammar, nt("S")).unwrap_err();
+    build_lr0_states(&Session::test(), &grammar, nt("S")).unwrap_err();
 }
 
 /// When we moved to storing items as (lr0 -> TokenSet) pairs, a bug
@@ -264,7 +261,6 @@ T: () = {
 /// S0)` and `(Foo, S1)` as distinct items instead of `(Foo, S0|S1)`.
 #[test]
 fn issue_144() {
-    let _tls = Tls::test();
 
     let grammar = normalized_grammar(r##"
 grammar;
@@ -307,13 +303,12 @@ ty: () = {
 "##);
 
     let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
-    build_lr1_states(&grammar, nt("ForeignItem")).unwrap();
+    build_lr1_states(&Session::test(), &grammar, nt("ForeignItem")).unwrap();
 }
 
 // Not sure if this is the right spot
 #[test]
 fn match_grammar() {
-    let _tls = Tls::test();
 
     let grammar = normalized_grammar(r#"
 grammar;
@@ -329,6 +324,74 @@ pub Query = SELECT r"[a-z]+";
 
     let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
 
-    let states = build_lr0_states(&grammar, nt("Query")).expect("build states");
+    let states = build_lr0_states(&Session::test(), &grammar, nt("Query")).expect("build states");
     println!("states: {:?}", states);
 }
+
+fn count_conflicts(report_all_conflicts: bool) -> usize {
+    let mut session = Session::test();
+    session.report_all_conflicts = report_all_conflicts;
+
+    let grammar = normalized_grammar(r#"
+grammar;
+    extern { enum Tok { "+" => .., "*" => .., "N" => .. } }
+
+    E: () = {
+        E "+" E => (),
+        E "*" E => (),
+        "N" => (),
+    };
+"#);
+
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    build_lr1_states_legacy(&session, &grammar, nt("E")).unwrap_err().conflicts.len()
+}
+
+#[test]
+fn report_all_conflicts() {
+    // by default, we stop at the first state with conflicts (on
+    // either operator as the lookahead)...
+    assert_eq!(count_conflicts(false), 2);
+
+    // ...but can keep going to find both states: after `E + E` and
+    // after `E * E`
+    assert_eq!(count_conflicts(true), 4);
+}
+
+#[test]
+fn resolve_conflicts_by_default() {
+    use lr1::lookahead::Lookahead;
+
+    let grammar = normalized_grammar(r#"
+grammar;
+    extern { enum Tok { "+" => .., "(" => .., "N" => .., "x" => .. } }
+
+    S: () = {
+        E => (),
+        A "x" => (),
+        B "x" => (),
+    };
+
+    E: () = {
+        E "+" E => (),
+        "(" => (),
+    };
+
+    B: () = "N" => ();
+    A: () = "N" => ();
+"#);
+
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let states = build_states_resolving_conflicts(&Session::test(), &grammar, nt("S"));
+    assert!(states.iter().all(|state| TokenSet::conflicts(state).is_empty()));
+
+    // shifting `+` makes the addition right-associative...
+    let tree = interpret(&states, tokens!["(", "+", "(", "+", "("]).unwrap();
+    assert_eq!(
+        &format!("{}", tree)[..],
+        r#"[S: [E: [E: "("], "+", [E: [E: "("], "+", [E: "("]]]]"#);
+
+    // ...and `B: "N"` wins over `A: "N"`, as it comes first
+    let tree = interpret(&states, tokens!["N", "x"]).unwrap();
+    assert_eq!(&format!("{}", tree)[..], r#"[S: [B: "N"], "x"]"#);
+}
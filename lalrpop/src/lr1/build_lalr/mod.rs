@@ -5,10 +5,11 @@ use itertools::Itertools;
 use lr1::build;
 use lr1::core::*;
 use lr1::lookahead::*;
+use lr1::memory;
 use grammar::repr::*;
 use std::rc::Rc;
 use std::mem;
-use tls::Tls;
+use session::Session;
 
 #[cfg(test)]
 mod test;
@@ -24,24 +25,34 @@ struct LALR1State<'grammar> {
     pub gotos: Map<NonterminalString, StateIndex>,
 }
 
-pub fn build_lalr_states<'grammar>(grammar: &'grammar Grammar,
+pub fn build_lalr_states<'grammar>(session: &Session,
+                                   grammar: &'grammar Grammar,
                                    start: NonterminalString)
                                    -> LR1Result<'grammar> {
     // First build the LR(1) states
-    let lr_states = try!(build::build_lr1_states(grammar, start));
+    let lr_states = try!(build::build_lr1_states(session, grammar, start));
 
     // With lane table, there is no reason to do state collapse
     // for LALR. In fact, LALR is pointless!
     if build::use_lane_table() {
-        println!("Warning: Now that the new lane-table algorithm is the default,");
-        println!("         #[lalr] mode has no effect and can be removed.");
+        log!(session, Informative,
+             "Warning: Now that the new lane-table algorithm is the default,\n         \
+              #[lalr] mode has no effect and can be removed.");
         return Ok(lr_states);
     }
 
     profile! {
-        &Tls::session(),
+        session,
         "LALR(1) state collapse",
-        collapse_to_lalr_states(&lr_states)
+        memory::pass("LALR(1) state collapse", || {
+            let result = collapse_to_lalr_states(&lr_states);
+            let collapsed = match result {
+                Ok(ref states) => memory::states_bytes(states),
+                Err(ref error) => memory::states_bytes(&error.states),
+            };
+            memory::note(memory::states_bytes(&lr_states) + collapsed);
+            result
+        })
     }
 }
 
@@ -138,6 +149,7 @@ pub fn collapse_to_lalr_states<'grammar>(lr_states: &[LR1State<'grammar>])
                                                                .map(|(p, ts)| (ts, p))
                                                                .collect(),
                                                  gotos: lr.gotos,
+                                                 two_token_reductions: vec![],
                                              }
                                          })
                                          .collect();
@@ -1,8 +1,8 @@
 use intern::intern;
 use grammar::repr::*;
 use lr1::tls::Lr1Tls;
+use session::Session;
 use test_util::{normalized_grammar};
-use tls::Tls;
 use super::build_lalr_states;
 use super::super::interpret::interpret;
 
@@ -18,7 +18,6 @@ macro_rules! tokens {
 
 #[test]
 fn figure9_23() {
-    let _tls = Tls::test();
 
     let grammar = normalized_grammar(r#"
         grammar;
@@ -36,7 +35,7 @@ fn figure9_23() {
 
     let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
 
-    let states = build_lalr_states(&grammar, nt("S")).unwrap();
+    let states = build_lalr_states(&Session::test(), &grammar, nt("S")).unwrap();
     println!("{:#?}", states);
 
     let tree = interpret(&states, tokens!["N", "-", "(", "N", "-", "N", ")"])
@@ -0,0 +1,356 @@
+//! Checkpoints of the canonical LR(1) construction, so that a build
+//! that was interrupted (or that ran out of memory, see `lr1::memory`)
+//! can pick up where it stopped rather than start over. With
+//! `Configuration::checkpoint_dir`, every so many states the states
+//! built so far, and the kernels of the states still to build, are
+//! written to a file in that directory; the next build of the same
+//! grammar reads them back and goes on from there. The file is removed
+//! once all of the states have been built.
+//!
+//! A checkpoint is named after a fingerprint of the productions and
+//! terminals of the grammar and of the start nonterminal, so a grammar
+//! that has changed in the meantime starts afresh. It is a text file
+//! that refers to terminals, nonterminals and productions by their
+//! index in the grammar:
+//!
+//! ```text
+//! lalrpop checkpoint LR(1)
+//! state
+//! item 4 1 $,0,3
+//! shift 3 5
+//! goto 2 6
+//! reduce 4 $,0
+//! kernel
+//! item 7 2 1
+//! ```
+
+use collections::{map, Map};
+use grammar::repr::*;
+use lr1::core::*;
+use lr1::lookahead::{Lookahead, Nil, Token, TokenSet};
+use session::Session;
+use std::collections::hash_map::DefaultHasher;
+use std::fs::{self, File};
+use std::hash::{Hash, Hasher};
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+use std::rc::Rc;
+
+#[cfg(test)]
+mod test;
+
+macro_rules! try_some {
+    ($e:expr) => {
+        match $e {
+            Some(value) => value,
+            None => return None,
+        }
+    }
+}
+
+/// Lookaheads that can be written to a checkpoint.
+pub trait CheckpointLookahead: Lookahead {
+    /// The kind of states these lookaheads make, like `LR(1)`.
+    fn kind() -> &'static str;
+
+    fn write(&self, grammar: &Grammar) -> String;
+
+    fn read(text: &str, grammar: &Grammar) -> Option<Self>;
+}
+
+impl CheckpointLookahead for Nil {
+    fn kind() -> &'static str {
+        "LR(0)"
+    }
+
+    fn write(&self, _grammar: &Grammar) -> String {
+        String::from("-")
+    }
+
+    fn read(text: &str, _grammar: &Grammar) -> Option<Nil> {
+        if text == "-" { Some(Nil) } else { None }
+    }
+}
+
+/// EOF is written as `$`, the error token as `!`, and a terminal as
+/// its index in the grammar, separated by commas.
+impl CheckpointLookahead for TokenSet {
+    fn kind() -> &'static str {
+        "LR(1)"
+    }
+
+    fn write(&self, grammar: &Grammar) -> String {
+        let tokens: Vec<String> = self.iter()
+                                       .map(|token| match token {
+                                           Token::EOF => String::from("$"),
+                                           Token::Error => String::from("!"),
+                                           Token::Terminal(t) => {
+                                               grammar.terminals.bits[&t].to_string()
+                                           }
+                                       })
+                                       .collect();
+        if tokens.is_empty() { String::from("-") } else { tokens.join(",") }
+    }
+
+    fn read(text: &str, grammar: &Grammar) -> Option<TokenSet> {
+        let mut set = TokenSet::new();
+        if text == "-" {
+            return Some(set);
+        }
+        for token in text.split(',') {
+            match token {
+                "$" => { set.insert(Token::EOF); }
+                "!" => { set.insert(Token::Error); }
+                _ => {
+                    let index = match token.parse::<usize>() {
+                        Ok(index) if index < grammar.terminals.all.len() => index,
+                        _ => return None,
+                    };
+                    set.insert(Token::Terminal(grammar.terminals.all[index]));
+                }
+            }
+        }
+        Some(set)
+    }
+}
+
+/// The states built so far, and the kernels of the states still to
+/// build, in the order of their indices.
+pub struct Progress<'grammar, L: Lookahead> {
+    pub states: Vec<State<'grammar, L>>,
+    pub kernels: Vec<Vec<Item<'grammar, L>>>,
+}
+
+/// Where and how often the construction of the states for one start
+/// nonterminal writes checkpoints.
+pub struct Checkpoints<'grammar> {
+    grammar: &'grammar Grammar,
+    path: PathBuf,
+    interval: usize,
+    productions: Vec<&'grammar Production>,
+
+    /// the indices in `productions`, by the address of the production
+    production_indices: Map<usize, usize>,
+
+    nonterminals: Vec<NonterminalString>,
+    nonterminal_indices: Map<NonterminalString, usize>,
+}
+
+fn address(production: &Production) -> usize {
+    production as *const Production as usize
+}
+
+impl<'grammar> Checkpoints<'grammar> {
+    /// The checkpoints for building the states of `L` for `start`, if
+    /// the session asks for them.
+    pub fn new<L: CheckpointLookahead>(session: &Session,
+                                       grammar: &'grammar Grammar,
+                                       start: NonterminalString)
+                                       -> Option<Checkpoints<'grammar>> {
+        let dir = match session.checkpoint_dir {
+            Some(ref dir) => dir,
+            None => return None,
+        };
+
+        let nonterminals: Vec<NonterminalString> = grammar.nonterminals.keys().cloned().collect();
+        let productions: Vec<&'grammar Production> =
+            nonterminals.iter()
+                        .flat_map(|&nt| grammar.productions_for(nt))
+                        .collect();
+
+        let mut hasher = DefaultHasher::new();
+        L::kind().hash(&mut hasher);
+        start.to_string().hash(&mut hasher);
+        for terminal in &grammar.terminals.all {
+            terminal.to_string().hash(&mut hasher);
+        }
+        for production in &productions {
+            format!("{:?}", production).hash(&mut hasher);
+        }
+
+        Some(Checkpoints {
+            grammar: grammar,
+            path: dir.join(format!("{:016x}.checkpoint", hasher.finish())),
+            interval: session.checkpoint_interval,
+            production_indices: productions.iter()
+                                           .enumerate()
+                                           .map(|(index, &production)| {
+                                               (address(production), index)
+                                           })
+                                           .collect(),
+            productions: productions,
+            nonterminal_indices: nonterminals.iter()
+                                             .enumerate()
+                                             .map(|(index, &nt)| (nt, index))
+                                             .collect(),
+            nonterminals: nonterminals,
+        })
+    }
+
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// True if a checkpoint is due once `built` states have been
+    /// built.
+    pub fn due(&self, built: usize) -> bool {
+        self.interval > 0 && built % self.interval == 0
+    }
+
+    /// Writes a checkpoint of `states` and of the `kernels` still to
+    /// build. The checkpoint is written to another file first, so
+    /// that a build interrupted while writing it leaves the previous
+    /// one intact.
+    pub fn save<L: CheckpointLookahead>(&self,
+                                        states: &[State<'grammar, L>],
+                                        kernels: &[&Vec<Item<'grammar, L>>])
+                                        -> io::Result<()> {
+        let mut text = format!("lalrpop checkpoint {}\n", L::kind());
+        for state in states {
+            text.push_str("state\n");
+            self.write_items(&mut text, &state.items.vec);
+            for (terminal, target) in &state.shifts {
+                text.push_str(&format!("shift {} {}\n",
+                                       self.grammar.terminals.bits[terminal], target.0));
+            }
+            for (nonterminal, target) in &state.gotos {
+                text.push_str(&format!("goto {} {}\n",
+                                       self.nonterminal_indices[nonterminal], target.0));
+            }
+            for &(ref lookahead, production) in &state.reductions {
+                text.push_str(&format!("reduce {} {}\n",
+                                       self.production_indices[&address(production)],
+                                       lookahead.write(self.grammar)));
+            }
+        }
+        for kernel in kernels {
+            text.push_str("kernel\n");
+            self.write_items(&mut text, kernel);
+        }
+
+        let partial = self.path.with_extension("partial");
+        {
+            let mut file = try!(File::create(&partial));
+            try!(file.write_all(text.as_bytes()));
+        }
+        fs::rename(&partial, &self.path)
+    }
+
+    fn write_items<L: CheckpointLookahead>(&self, text: &mut String, items: &[Item<'grammar, L>]) {
+        for item in items {
+            text.push_str(&format!("item {} {} {}\n",
+                                   self.production_indices[&address(item.production)],
+                                   item.index,
+                                   item.lookahead.write(self.grammar)));
+        }
+    }
+
+    /// Reads the checkpoint back, if there is one; `None` if there is
+    /// none, or if it cannot be made sense of.
+    pub fn load<L: CheckpointLookahead>(&self) -> Option<Progress<'grammar, L>> {
+        let mut text = String::new();
+        match File::open(&self.path).and_then(|mut file| file.read_to_string(&mut text)) {
+            Ok(_) => self.parse(&text),
+            Err(_) => None,
+        }
+    }
+
+    fn parse<L: CheckpointLookahead>(&self, text: &str) -> Option<Progress<'grammar, L>> {
+        let mut lines = text.lines();
+        if lines.next() != Some(&format!("lalrpop checkpoint {}", L::kind())[..]) {
+            return None;
+        }
+
+        let mut progress = Progress { states: vec![], kernels: vec![] };
+        let mut items = vec![];
+        let mut in_kernel = false;
+        for line in lines {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match (fields.get(0).cloned(), fields.len()) {
+                (Some("state"), 1) | (Some("kernel"), 1) => {
+                    self.finish_items(&mut progress, &mut items, in_kernel);
+                    in_kernel = fields[0] == "kernel";
+                    if !in_kernel {
+                        if !progress.kernels.is_empty() {
+                            return None;
+                        }
+                        let index = StateIndex(progress.states.len());
+                        progress.states.push(State { index: index,
+                                                     items: Items { vec: Rc::new(vec![]) },
+                                                     shifts: map(),
+                                                     reductions: vec![],
+                                                     gotos: map(),
+                                                     two_token_reductions: vec![] });
+                    }
+                }
+                (Some("item"), 4) => {
+                    if !in_kernel && progress.states.is_empty() {
+                        return None;
+                    }
+                    items.push(Item { production: try_some!(self.production(fields[1])),
+                                      index: try_some!(fields[2].parse::<usize>().ok()),
+                                      lookahead: try_some!(L::read(fields[3], self.grammar)) });
+                }
+                (Some(action), 3) if !in_kernel && !progress.states.is_empty() => {
+                    let state = progress.states.last_mut().unwrap();
+                    match action {
+                        "shift" => {
+                            let terminal = try_some!(index(fields[1], &self.grammar.terminals.all));
+                            let target = StateIndex(try_some!(fields[2].parse::<usize>().ok()));
+                            state.shifts.insert(terminal, target);
+                        }
+                        "goto" => {
+                            let nonterminal = try_some!(index(fields[1], &self.nonterminals));
+                            let target = StateIndex(try_some!(fields[2].parse::<usize>().ok()));
+                            state.gotos.insert(nonterminal, target);
+                        }
+                        "reduce" => {
+                            let production = try_some!(self.production(fields[1]));
+                            let lookahead = try_some!(L::read(fields[2], self.grammar));
+                            state.reductions.push((lookahead, production));
+                        }
+                        _ => return None,
+                    }
+                }
+                _ => return None,
+            }
+        }
+        self.finish_items(&mut progress, &mut items, in_kernel);
+
+        // every state must be one that has been built, or whose
+        // kernel is still to build
+        let num_states = progress.states.len() + progress.kernels.len();
+        let targets_exist = progress.states.iter().all(|state| {
+            state.shifts.values().chain(state.gotos.values()).all(|target| target.0 < num_states)
+        });
+        if progress.states.is_empty() || !targets_exist {
+            return None;
+        }
+        Some(progress)
+    }
+
+    fn finish_items<L: Lookahead>(&self,
+                                  progress: &mut Progress<'grammar, L>,
+                                  items: &mut Vec<Item<'grammar, L>>,
+                                  in_kernel: bool) {
+        let items = ::std::mem::replace(items, vec![]);
+        if in_kernel {
+            progress.kernels.push(items);
+        } else if let Some(state) = progress.states.last_mut() {
+            state.items = Items { vec: Rc::new(items) };
+        }
+    }
+
+    fn production(&self, text: &str) -> Option<&'grammar Production> {
+        index(text, &self.productions)
+    }
+
+    /// Removes the checkpoint, once the states have all been built.
+    pub fn remove(&self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+fn index<T: Copy>(text: &str, values: &[T]) -> Option<T> {
+    text.parse::<usize>().ok().and_then(|index| values.get(index).cloned())
+}
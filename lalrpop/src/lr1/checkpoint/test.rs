@@ -0,0 +1,94 @@
+use intern::intern;
+use grammar::repr::*;
+use lr1::build::build_canonical_lr1_states;
+use lr1::lookahead::TokenSet;
+use lr1::tls::Lr1Tls;
+use session::Session;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use test_util::normalized_grammar;
+
+use super::{Checkpoints, Progress};
+
+fn nt(t: &str) -> NonterminalString {
+    NonterminalString(intern(t))
+}
+
+fn grammar() -> Grammar {
+    normalized_grammar(r#"
+grammar;
+    pub Expr: i32 = { <l:Expr> "+" <r:Term> => l + r, Term };
+    Term: i32 = { <l:Term> "*" <r:Atom> => l * r, Atom };
+    Atom: i32 = { "N" => 1, "(" <Expr> ")" };
+"#)
+}
+
+fn checkpoint_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(name);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn round_trip() {
+    let grammar = grammar();
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let start = grammar.start_nonterminals[&nt("Expr")];
+    let states = build_canonical_lr1_states(&Session::test(), &grammar, start).unwrap().unwrap();
+
+    let mut session = Session::test();
+    session.checkpoint_dir = Some(checkpoint_dir("lalrpop-checkpoint-round-trip"));
+    let checkpoints = Checkpoints::new::<TokenSet>(&session, &grammar, start).unwrap();
+    let kernel = states[2].items.vec[..1].to_vec();
+    checkpoints.save(&states, &[&kernel]).unwrap();
+    let progress: Progress<TokenSet> = checkpoints.load().unwrap();
+    checkpoints.remove();
+
+    assert_eq!(format!("{:?}", progress.states), format!("{:?}", states));
+    assert_eq!(progress.kernels, vec![kernel]);
+}
+
+#[test]
+fn resume() {
+    let grammar = grammar();
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let start = grammar.start_nonterminals[&nt("Expr")];
+    let states = build_canonical_lr1_states(&Session::test(), &grammar, start).unwrap().unwrap();
+
+    // running out of memory leaves a checkpoint behind...
+    let mut session = Session::test();
+    session.checkpoint_dir = Some(checkpoint_dir("lalrpop-checkpoint-resume"));
+    session.lr1_fallback_budget = Some(1);
+    build_canonical_lr1_states(&session, &grammar, start).unwrap_err();
+    let checkpoints = Checkpoints::new::<TokenSet>(&session, &grammar, start).unwrap();
+    let progress: Progress<TokenSet> = checkpoints.load().unwrap();
+    assert_eq!(progress.states.len(), 1);
+    assert!(!progress.kernels.is_empty());
+
+    // ...which the next build resumes from, and removes
+    session.lr1_fallback_budget = None;
+    let resumed = build_canonical_lr1_states(&session, &grammar, start).unwrap().unwrap();
+    assert_eq!(format!("{:?}", resumed), format!("{:?}", states));
+    assert!(!checkpoints.path().exists());
+}
+
+#[test]
+fn changed_grammar() {
+    let grammar = grammar();
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let start = grammar.start_nonterminals[&nt("Expr")];
+    let mut session = Session::test();
+    session.checkpoint_dir = Some(checkpoint_dir("lalrpop-checkpoint-changed"));
+    let checkpoints = Checkpoints::new::<TokenSet>(&session, &grammar, start).unwrap();
+
+    let other = normalized_grammar(r#"
+grammar;
+    pub Expr: i32 = { <l:Expr> "-" <r:Term> => l - r, Term };
+    Term: i32 = { <l:Term> "*" <r:Atom> => l * r, Atom };
+    Atom: i32 = { "N" => 1, "(" <Expr> ")" };
+"#);
+    let other_start = other.start_nonterminals[&nt("Expr")];
+    let other_checkpoints = Checkpoints::new::<TokenSet>(&session, &other, other_start).unwrap();
+    assert!(checkpoints.path() != other_checkpoints.path());
+}
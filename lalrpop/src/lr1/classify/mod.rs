@@ -0,0 +1,210 @@
+//! Classifies a grammar as LL(1), SLR(1), LALR(1) and/or LR(1), and
+//! for each class it does not belong to, points at the first
+//! construct that rules it out. Useful to decide whether a grammar
+//! could be handled by simpler tooling, or needs more than LR(1).
+
+use collections::Set;
+use grammar::repr::*;
+use lint;
+use lr1::build::{build_lr0_states, build_lr1_states};
+use lr1::build_lalr::collapse_to_lalr_states;
+use lr1::core::*;
+use lr1::first::FirstSets;
+use lr1::follow::FollowSets;
+use lr1::lookahead::{Token, TokenSet};
+use session::Session;
+use std::io::{self, Write};
+
+#[cfg(test)]
+mod test;
+
+/// For each class, either `Ok(())` or a description of the first
+/// violation found.
+#[derive(Debug)]
+pub struct Classification {
+    pub ll1: Result<(), String>,
+    pub slr1: Result<(), String>,
+    pub lalr1: Result<(), String>,
+    pub lr1: Result<(), String>,
+}
+
+impl Classification {
+    /// The simplest class the grammar belongs to, if any.
+    pub fn simplest(&self) -> Option<&'static str> {
+        let classes = [("LL(1)", &self.ll1),
+                       ("SLR(1)", &self.slr1),
+                       ("LALR(1)", &self.lalr1),
+                       ("LR(1)", &self.lr1)];
+        classes.iter().find(|&&(_, result)| result.is_ok()).map(|&(name, _)| name)
+    }
+}
+
+pub fn generate_classes<W: Write>(session: &Session, out: &mut W, grammar: &Grammar) -> io::Result<()> {
+    try!(writeln!(out, "Lalrpop Grammar Classes"));
+    try!(writeln!(out, "======================="));
+
+    for (&user_nt, &start_nt) in &grammar.start_nonterminals {
+        let classification = classify(session, grammar, start_nt);
+
+        try!(writeln!(out, ""));
+        try!(writeln!(out, "{}", user_nt));
+        for &(name, result) in &[("LL(1)", &classification.ll1),
+                                 ("SLR(1)", &classification.slr1),
+                                 ("LALR(1)", &classification.lalr1),
+                                 ("LR(1)", &classification.lr1)] {
+            match *result {
+                Ok(()) => try!(writeln!(out, "    {}: yes", name)),
+                Err(ref reason) => try!(writeln!(out, "    {}: no, {}", name, reason)),
+            }
+        }
+        match classification.simplest() {
+            Some(name) => try!(writeln!(out, "    simplest class: {}", name)),
+            None => try!(writeln!(out, "    simplest class: none (the grammar is not LR(1))")),
+        }
+    }
+
+    Ok(())
+}
+
+pub fn classify(session: &Session, grammar: &Grammar, start: NonterminalString) -> Classification {
+    let first_sets = FirstSets::new(grammar);
+    let follow_sets = FollowSets::new(grammar, &first_sets);
+
+    let (lr1, lalr1) = match build_lr1_states(session, grammar, start) {
+        Ok(states) => {
+            let lalr1 = match collapse_to_lalr_states(&states) {
+                Ok(_) => Ok(()),
+                Err(error) => Err(describe_conflict(&error.conflicts[0])),
+            };
+            (Ok(()), lalr1)
+        }
+        Err(error) => {
+            let reason = describe_conflict(&error.conflicts[0]);
+            (Err(reason.clone()), Err(reason))
+        }
+    };
+
+    Classification {
+        ll1: check_ll1(grammar, start, &first_sets, &follow_sets),
+        slr1: check_slr1(session, grammar, start, &follow_sets),
+        lalr1: lalr1,
+        lr1: lr1,
+    }
+}
+
+fn check_ll1(grammar: &Grammar,
+             start: NonterminalString,
+             first_sets: &FirstSets,
+             follow_sets: &FollowSets)
+             -> Result<(), String> {
+    // visit the nonterminals in the order they appear in the source
+    let reachable = lint::reachable_from(grammar, vec![start]);
+    let mut nonterminals: Vec<&NonterminalData> =
+        grammar.nonterminals.values()
+                            .filter(|data| reachable.contains(&data.name))
+                            .collect();
+    nonterminals.sort_by_key(|data| data.span.0);
+
+    for data in nonterminals {
+        let productions = &data.productions;
+        for (i, p) in productions.iter().enumerate() {
+            let mut p_first = first_sets.first0(&p.symbols);
+            let p_nullable = p_first.take_eof();
+            for q in &productions[i + 1..] {
+                let mut q_first = first_sets.first0(&q.symbols);
+                let q_nullable = q_first.take_eof();
+
+                if let Some(token) = first_token(&p_first.intersection(&q_first)) {
+                    return Err(format!("alternatives `{}` and `{}` can both start with `{}`",
+                                       p, q, token));
+                }
+
+                if p_nullable && q_nullable {
+                    return Err(format!("alternatives `{}` and `{}` can both be empty",
+                                       p, q));
+                }
+
+                let follow = follow_sets.follow(data.name);
+                for &(empty, other, other_first) in &[(p_nullable, q, &q_first),
+                                                      (q_nullable, p, &p_first)] {
+                    if !empty {
+                        continue;
+                    }
+                    if let Some(token) = first_token(&other_first.intersection(&follow)) {
+                        return Err(format!("`{}` can be empty and be followed by `{}`, \
+                                            but alternative `{}` can also start with `{}`",
+                                           data.name, token, other, token));
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn check_slr1(session: &Session,
+              grammar: &Grammar,
+              start: NonterminalString,
+              follow_sets: &FollowSets)
+              -> Result<(), String> {
+    let states = match build_lr0_states(session, grammar, start) {
+        Ok(states) => states,
+        Err(error) => error.states,
+    };
+
+    for state in &states {
+        let shifts: Set<TerminalString> =
+            state.items.vec.iter()
+                           .filter_map(|item| match item.shift_symbol() {
+                               Some((Symbol::Terminal(term), _)) => Some(term),
+                               _ => None,
+                           })
+                           .collect();
+        let reductions: Vec<&Production> =
+            state.items.vec.iter()
+                           .filter(|item| item.can_reduce())
+                           .map(|item| item.production)
+                           .collect();
+
+        for (i, &p) in reductions.iter().enumerate() {
+            let follow = follow_sets.follow(p.nonterminal);
+            if let Some(token) = follow.iter().find(|token| match *token {
+                Token::Terminal(term) => shifts.contains(&term),
+                Token::EOF | Token::Error => false,
+            }) {
+                return Err(format!("reducing `{}` conflicts with shifting `{}`, \
+                                    which may follow `{}`",
+                                   p, token, p.nonterminal));
+            }
+            for &q in &reductions[i + 1..] {
+                let both = follow.intersection(&follow_sets.follow(q.nonterminal));
+                if let Some(token) = first_token(&both) {
+                    return Err(format!("reducing `{}` and reducing `{}` conflict on `{}`, \
+                                        which may follow both",
+                                       p, q, token));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn describe_conflict(conflict: &LR1Conflict) -> String {
+    let token = conflict.lookahead.iter().next().unwrap();
+    match conflict.action {
+        Action::Shift(term, _) => {
+            format!("reducing `{}` conflicts with shifting `{}`",
+                    conflict.production, term)
+        }
+        Action::Reduce(other) => {
+            format!("reducing `{}` and reducing `{}` conflict on `{}`",
+                    conflict.production, other, token)
+        }
+    }
+}
+
+fn first_token(set: &TokenSet) -> Option<Token> {
+    set.iter().next()
+}
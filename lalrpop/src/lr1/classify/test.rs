@@ -0,0 +1,80 @@
+use intern::intern;
+use grammar::repr::*;
+use lr1::tls::Lr1Tls;
+use session::Session;
+use test_util::normalized_grammar;
+use super::{classify, generate_classes};
+
+fn nt(t: &str) -> NonterminalString {
+    NonterminalString(intern(t))
+}
+
+#[test]
+fn ll1_grammar() {
+    let grammar = normalized_grammar(r#"
+grammar;
+    pub S: () = { "(" S ")" S => (), () => () };
+"#);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let classification = classify(&Session::test(), &grammar, nt("S"));
+    assert!(classification.ll1.is_ok());
+    assert!(classification.slr1.is_ok());
+    assert!(classification.lalr1.is_ok());
+    assert!(classification.lr1.is_ok());
+    assert_eq!(classification.simplest(), Some("LL(1)"));
+}
+
+#[test]
+fn left_recursion_is_not_ll1() {
+    let grammar = normalized_grammar(r#"
+grammar;
+    pub E: () = { E "+" T => (), T => () };
+    T: () = { "(" E ")" => (), "x" => () };
+"#);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let mut out = vec![];
+    generate_classes(&Session::test(), &mut out, &grammar).unwrap();
+    let out = String::from_utf8(out).unwrap();
+    assert_eq!(out.trim(), r#"Lalrpop Grammar Classes
+=======================
+
+E
+    LL(1): no, alternatives `E = E "+" T` and `E = T` can both start with `"("`
+    SLR(1): yes
+    LALR(1): yes
+    LR(1): yes
+    simplest class: SLR(1)"#);
+}
+
+#[test]
+fn lalr_but_not_slr() {
+    // the classic example: `"="` is in FOLLOW(R) because of `"*" R`,
+    // but not in the state where `S = R` is reduced
+    let grammar = normalized_grammar(r#"
+grammar;
+    pub S: () = { L "=" R => (), R => () };
+    L: () = { "*" R => (), "id" => () };
+    R: () = { L => () };
+"#);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let classification = classify(&Session::test(), &grammar, nt("S"));
+    assert_eq!(classification.simplest(), Some("LALR(1)"));
+    assert!(classification.ll1.is_err());
+    assert!(classification.lalr1.is_ok());
+    assert_eq!(classification.slr1.unwrap_err(),
+               r#"reducing `R = L` conflicts with shifting `"="`, which may follow `R`"#);
+}
+
+#[test]
+fn ambiguous_grammar() {
+    let grammar = normalized_grammar(r#"
+grammar;
+    pub E: () = { E "+" E => (), "x" => () };
+"#);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let classification = classify(&Session::test(), &grammar, nt("E"));
+    assert_eq!(classification.simplest(), None);
+    assert!(classification.lalr1.is_err());
+    assert_eq!(classification.lr1.unwrap_err(),
+               r#"reducing `E = E "+" E` conflicts with shifting `"+"`"#);
+}
@@ -10,13 +10,14 @@ use lr1::core::*;
 use lr1::lookahead::Token;
 use lr1::state_graph::StateGraph;
 use rust::RustWrite;
+use session::Session;
 use std::io::{self, Write};
-use tls::Tls;
 use util::{Escape, Sep};
 
 use super::base::CodeGenerator;
 
-pub fn compile<'grammar, W: Write>(grammar: &'grammar Grammar,
+pub fn compile<'grammar, W: Write>(session: &Session,
+                                   grammar: &'grammar Grammar,
                                    user_start_symbol: NonterminalString,
                                    start_symbol: NonterminalString,
                                    states: &[LR1State<'grammar>],
@@ -24,7 +25,8 @@ pub fn compile<'grammar, W: Write>(grammar: &'grammar Grammar,
                                    out: &mut RustWrite<W>)
                                    -> io::Result<()> {
     let graph = StateGraph::new(&states);
-    let mut ascent = CodeGenerator::new_ascent(grammar,
+    let mut ascent = CodeGenerator::new_ascent(session,
+                                               grammar,
                                                user_start_symbol,
                                                start_symbol,
                                                &graph,
@@ -112,7 +114,8 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent,
                                                 'grammar,
                                                 W,
                                                 RecursiveAscent<'ascent, 'grammar>> {
-    fn new_ascent(grammar: &'grammar Grammar,
+    fn new_ascent(session: &'ascent Session,
+                  grammar: &'grammar Grammar,
                   user_start_symbol: NonterminalString,
                   start_symbol: NonterminalString,
                   graph: &'ascent StateGraph,
@@ -156,7 +159,8 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent,
                                  .map(|state| Self::state_input_for(state))
                                  .collect();
 
-        CodeGenerator::new(grammar,
+        CodeGenerator::new(session,
+                           grammar,
                            user_start_symbol,
                            start_symbol,
                            states,
@@ -217,6 +221,32 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent,
             rust!(self.out, "{}({}),", Escape(nt), ty);
         }
 
+        rust!(self.out, "}}");
+
+        // implement `Debug` by showing the nonterminal (the values
+        // need not be `Debug`)
+        rust!(self.out,
+              "impl<{params}> ::std::fmt::Debug for {}Nonterminal<{params}>",
+              self.prefix,
+              params = Sep(", ", &self.custom.nonterminal_type_params));
+        if !self.custom.nonterminal_where_clauses.is_empty() {
+            rust!(self.out, " where {}", Sep(", ", &self.custom.nonterminal_where_clauses));
+        }
+        rust!(self.out, "{{");
+        rust!(self.out,
+              "fn fmt(&self, {}f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {{",
+              self.prefix);
+        rust!(self.out, "match *self {{");
+        for &nt in self.grammar.nonterminals.keys() {
+            rust!(self.out,
+                  "{}Nonterminal::{}(..) => {}f.write_str({:?}),",
+                  self.prefix,
+                  Escape(nt),
+                  self.prefix,
+                  nt.to_string());
+        }
+        rust!(self.out, "}}");
+        rust!(self.out, "}}");
         rust!(self.out, "}}");
         Ok(())
     }
@@ -229,24 +259,54 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent,
         try!(self.start_parser_fn());
         try!(self.define_tokens());
 
+        // the state functions report `UnrecognizedEof` without a
+        // location; remember the end of the last token to fill it in
+        rust!(self.out,
+              "let {}last_location = ::std::cell::RefCell::new(Default::default());",
+              self.prefix);
+        rust!(self.out,
+              "let mut {p}tokens = {p}tokens.inspect(|t| if let Ok(ref t) = *t {{ \
+               *{p}last_location.borrow_mut() = t.2.clone(); }});",
+              p = self.prefix);
+
         try!(self.next_token("lookahead", "tokens"));
+        rust!(self.out, "let mut {}extra = None;", self.prefix);
         rust!(self.out,
-              "match try!({}state0({}&mut {}tokens, {}lookahead, {})) {{",
-              self.prefix,
-              self.grammar.user_parameter_refs(),
-              self.prefix,
-              self.prefix,
-              phantom_data);
+              "let {p}result = {p}state0({upr}&mut {p}tokens, {p}lookahead, &mut {p}extra, {phantom});",
+              upr = self.grammar.user_parameter_refs(),
+              phantom = phantom_data,
+              p = self.prefix);
+
+        // if a state function found a token after what may have been
+        // a complete parse, it finished the parse as if at EOF to see
+        // (see `write_state_fn`)
+        rust!(self.out, "if let Some(({p}token, {p}expected)) = {p}extra {{", p = self.prefix);
+        rust!(self.out, "return Err(match {}result {{", self.prefix);
+        rust!(self.out,
+              "Ok(_) => {p}lalrpop_util::ParseError::ExtraToken {{ \
+               token: {p}token, expected: {p}expected }},",
+              p = self.prefix);
+        rust!(self.out,
+              "Err(_) => {p}lalrpop_util::ParseError::UnrecognizedToken {{ \
+               token: Some({p}token), expected: {p}expected }},",
+              p = self.prefix);
+        rust!(self.out, "}});");
+        rust!(self.out, "}}");
+
+        rust!(self.out, "match {}result {{", self.prefix);
 
-        // extra tokens?
-        rust!(self.out, "(Some({}lookahead), _) => {{", self.prefix);
-        rust!(self.out, "Err({}lalrpop_util::ParseError::ExtraToken {{ token: {}lookahead }})",
-              self.prefix, self.prefix);
+        // extra tokens? (the parse is complete, so nothing else
+        // could have come)
+        rust!(self.out, "Ok((Some({}lookahead), _)) => {{", self.prefix);
+        rust!(self.out,
+              "Err({p}lalrpop_util::ParseError::ExtraToken {{ token: {p}lookahead, \
+               expected: vec![] }})",
+              p = self.prefix);
         rust!(self.out, "}}");
 
         // otherwise, we expect to see only the goal terminal
         rust!(self.out,
-              "(None, {}Nonterminal::{}((_, {}nt, _))) => {{",
+              "Ok((None, {}Nonterminal::{}((_, {}nt, _)))) => {{",
               self.prefix,
               Escape(self.start_symbol),
               self.prefix);
@@ -254,7 +314,17 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent,
         rust!(self.out, "}}");
 
         // nothing else should be possible
-        rust!(self.out, "_ => unreachable!(),");
+        rust!(self.out, "Ok(_) => unreachable!(),");
+
+        rust!(self.out,
+              "Err({p}lalrpop_util::ParseError::UnrecognizedEof {{ expected: {p}expected, .. }}) => {{",
+              p = self.prefix);
+        rust!(self.out,
+              "Err({p}lalrpop_util::ParseError::UnrecognizedEof {{ \
+               location: {p}last_location.borrow().clone(), expected: {p}expected }})",
+              p = self.prefix);
+        rust!(self.out, "}}");
+        rust!(self.out, "Err({p}error) => Err({p}error),", p = self.prefix);
         rust!(self.out, "}}");
 
         self.end_parser_fn()
@@ -276,7 +346,7 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent,
         rust!(self.out, "");
 
         // Leave a comment explaining what this state is.
-        if Tls::session().emit_comments {
+        if self.session.emit_comments {
             rust!(self.out, "// State {}", this_index.0);
             rust!(self.out, "//     AllInputs = {:?}", inputs.all);
             rust!(self.out, "//     OptionalInputs = {:?}", inputs.optional());
@@ -321,7 +391,7 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent,
             try!(self.consume_terminal(terminal, sym_name));
 
             // transition to the new state
-            if try!(self.transition("result", stack_suffix, next_index, &["tokens"])) {
+            if try!(self.transition("result", stack_suffix, next_index, &["tokens", "extra"])) {
                 fallthrough = true;
             }
 
@@ -373,14 +443,48 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent,
                         .iter()
                         .any(|&(ref t, _)| t.contains(Token::Terminal(*terminal)))
             });
-        rust!(self.out, "return Err({}lalrpop_util::ParseError::UnrecognizedToken {{", self.prefix);
-        rust!(self.out, "token: {}lookahead,", self.prefix);
-        rust!(self.out, "expected: vec![");
+        rust!(self.out, "let {}expected = vec![", self.prefix);
         for terminal in successful_terminals {
             rust!(self.out, "r###\"{}\"###.to_string(),", terminal);
         }
-        rust!(self.out, "]");
-        rust!(self.out, "}});");
+        rust!(self.out, "];");
+
+        // If we would reduce at EOF, the input so far may be a
+        // complete parse (the states of the stack may have been
+        // merged with ones that accept EOF, though). To find out,
+        // stash the lookahead in `extra` and carry on as if at EOF;
+        // the start function then reports an `ExtraToken` if the
+        // parse succeeds and an `UnrecognizedToken` if it fails.
+        let eof_production = this_state.reductions
+                                       .iter()
+                                       .find(|&&(ref t, _)| t.contains(Token::EOF))
+                                       .map(|&(_, production)| production);
+        if let Some(production) = eof_production {
+            rust!(self.out, "if let Some({p}token) = {p}lookahead {{", p = self.prefix);
+            rust!(self.out, "*{p}extra = Some(({p}token, {p}expected));", p = self.prefix);
+            rust!(self.out, "}}");
+            rust!(self.out,
+                  "let {}lookahead: Option<{}> = None;",
+                  self.prefix,
+                  self.triple_type());
+            try!(self.emit_reduce_action("result", stack_suffix, production));
+            if production.symbols.len() > 0 {
+                rust!(self.out, "return Ok({}result);", self.prefix);
+            } else {
+                fallthrough = true;
+            }
+        } else {
+            rust!(self.out, "return Err(match {}lookahead {{", self.prefix);
+            rust!(self.out,
+                  "Some({p}token) => {p}lalrpop_util::ParseError::UnrecognizedToken {{ \
+                   token: Some({p}token), expected: {p}expected }},",
+                  p = self.prefix);
+            rust!(self.out,
+                  "None => {p}lalrpop_util::ParseError::UnrecognizedEof {{ \
+                   location: Default::default(), expected: {p}expected }},",
+                  p = self.prefix);
+            rust!(self.out, "}});");
+        }
         rust!(self.out, "}}");
 
         rust!(self.out, "}}"); // match
@@ -444,7 +548,10 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent,
                       Escape(nt),
                       self.prefix,
                       stack_suffix.len());
-                try!(self.transition("result", stack_suffix, next_index, &["tokens", "lookahead"]));
+                try!(self.transition("result",
+                                     stack_suffix,
+                                     next_index,
+                                     &["tokens", "lookahead", "extra"]));
                 rust!(self.out, "}}");
             }
 
@@ -557,6 +664,9 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent,
         if !starts_with_terminal {
             base_args.push(format!("{}lookahead: Option<{}>", self.prefix, triple_type));
         }
+        base_args.push(format!("{}extra: &mut Option<({}, ::std::vec::Vec<::std::string::String>)>",
+                               self.prefix,
+                               triple_type));
 
         // "Optional symbols" may or may not be consumed, so take an
         // `&mut Option`
@@ -888,10 +998,10 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent,
         rust!(self.out, "Some({}) => {{", pattern);
 
         rust!(self.out,
-              "let {} = ({}loc1, ({}), {}loc2);",
+              "let {} = ({}loc1, {}, {}loc2);",
               let_name,
               self.prefix,
-              pattern_names.join(", "),
+              self.grammar.terminal_value(id, &pattern_names),
               self.prefix);
 
         Ok(())
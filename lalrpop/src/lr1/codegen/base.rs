@@ -3,6 +3,7 @@
 use grammar::repr::*;
 use lr1::core::*;
 use rust::RustWrite;
+use session::Session;
 use std::io::{self, Write};
 use util::Sep;
 
@@ -10,6 +11,9 @@ use util::Sep;
 /// code generator is customized by supplying distinct types for `C`
 /// (e.g., `self::ascent::RecursiveAscent`).
 pub struct CodeGenerator<'codegen, 'grammar: 'codegen, W: Write + 'codegen, C> {
+    /// the settings of the build
+    pub session: &'codegen Session,
+
     /// the complete grammar
     pub grammar: &'grammar Grammar,
 
@@ -42,7 +46,8 @@ pub struct CodeGenerator<'codegen, 'grammar: 'codegen, W: Write + 'codegen, C> {
 }
 
 impl<'codegen, 'grammar, W: Write, C> CodeGenerator<'codegen, 'grammar, W, C> {
-    pub fn new(grammar: &'grammar Grammar,
+    pub fn new(session: &'codegen Session,
+               grammar: &'grammar Grammar,
                user_start_symbol: NonterminalString,
                start_symbol: NonterminalString,
                states: &'codegen [LR1State<'grammar>],
@@ -52,6 +57,7 @@ impl<'codegen, 'grammar, W: Write, C> CodeGenerator<'codegen, 'grammar, W, C> {
                custom: C)
                -> Self {
         CodeGenerator {
+            session: session,
             grammar: grammar,
             prefix: &grammar.prefix,
             types: &grammar.types,
@@ -67,9 +73,18 @@ impl<'codegen, 'grammar, W: Write, C> CodeGenerator<'codegen, 'grammar, W, C> {
 
     pub fn write_parse_mod<F>(&mut self, body: F) -> io::Result<()>
         where F: FnOnce(&mut Self) -> io::Result<()>
+    {
+        let name = format!("{}parse{}", self.prefix, self.start_symbol);
+        self.write_mod(&name, body)
+    }
+
+    /// Writes the module `name`, with the lints and `use`s that the
+    /// generated code needs, around `body`.
+    pub fn write_mod<F>(&mut self, name: &str, body: F) -> io::Result<()>
+        where F: FnOnce(&mut Self) -> io::Result<()>
     {
         rust!(self.out, "");
-        rust!(self.out, "mod {}parse{} {{", self.prefix, self.start_symbol);
+        rust!(self.out, "mod {} {{", name);
 
         // these stylistic lints are annoying for the generated code,
         // which doesn't follow conventions:
@@ -88,6 +103,9 @@ impl<'codegen, 'grammar, W: Write, C> CodeGenerator<'codegen, 'grammar, W, C> {
 
     pub fn write_uses(&mut self) -> io::Result<()> {
         try!(self.out.write_uses(&format!("{}::", self.action_module), &self.grammar));
+        if self.session.fixed_stack {
+            rust!(self.out, "extern crate core as {}core;", self.prefix);
+        }
 
         if self.grammar.intern_token.is_some() {
             rust!(self.out, "use {}::{}intern_token::Token;", self.action_module, self.prefix);
@@ -95,29 +113,35 @@ impl<'codegen, 'grammar, W: Write, C> CodeGenerator<'codegen, 'grammar, W, C> {
             rust!(self.out, "use {}::{}ToTriple;", self.action_module, self.prefix);
         }
 
+        // the types of `#[ast]` nonterminals are declared next to the
+        // action code
+        for ast_type in &self.grammar.ast_types {
+            rust!(self.out, "use {}::{};", self.action_module, ast_type.nonterminal);
+        }
+
         Ok(())
     }
 
-    pub fn start_parser_fn(&mut self) -> io::Result<()> {
+    /// Returns the extra type parameters, parameters, and where
+    /// clauses (beyond those the user declared) that the public
+    /// parse functions take: for an external tokenizer, these
+    /// describe the `TOKENS` iterator.
+    pub fn parser_fn_inputs(&self) -> (Vec<String>, Vec<String>, Vec<String>) {
         let error_type = self.types.error_type();
-        let parse_error_type = self.types.parse_error_type();
-
-        let (type_parameters, parameters, mut where_clauses);
 
         if self.grammar.intern_token.is_some() {
             // if we are generating the tokenizer, we just need the
             // input, and that has already been added as one of the
             // user parameters
-            type_parameters = vec![];
-            parameters = vec![];
-            where_clauses = vec![];
-        } else {
-            // otherwise, we need an iterator of type `TOKENS`
-            let mut user_type_parameters = String::new();
-            for type_parameter in &self.grammar.type_parameters {
-                user_type_parameters.push_str(&format!("{}, ", type_parameter));
-            }
-            type_parameters = vec![format!("{}TOKEN: {}ToTriple<{}Error={}>",
+            return (vec![], vec![], vec![]);
+        }
+
+        // otherwise, we need an iterator of type `TOKENS`
+        let mut user_type_parameters = String::new();
+        for type_parameter in &self.grammar.type_parameters {
+            user_type_parameters.push_str(&format!("{}, ", type_parameter));
+        }
+        let type_parameters = vec![format!("{}TOKEN: {}ToTriple<{}Error={}>",
                                            self.prefix,
                                            self.prefix,
                                            user_type_parameters,
@@ -125,28 +149,87 @@ impl<'codegen, 'grammar, W: Write, C> CodeGenerator<'codegen, 'grammar, W, C> {
                                    format!("{}TOKENS: IntoIterator<Item={}TOKEN>",
                                            self.prefix,
                                            self.prefix)];
-            parameters = vec![format!("{}tokens0: {}TOKENS", self.prefix, self.prefix)];
-            where_clauses = vec![];
+        let parameters = vec![format!("{}tokens0: {}TOKENS", self.prefix, self.prefix)];
+        let mut where_clauses = vec![];
 
-            if self.repeatable {
-                where_clauses.push(format!("{}TOKENS: Clone", self.prefix));
-            }
+        if self.repeatable {
+            where_clauses.push(format!("{}TOKENS: Clone", self.prefix));
+        }
+
+        (type_parameters, parameters, where_clauses)
+    }
+
+    pub fn start_parser_fn(&mut self) -> io::Result<()> {
+        self.start_cancelable_parser_fn(false)
+    }
+
+    /// Starts the public `parse_Foo` function or, if `cancelable` is
+    /// true, `parse_Foo_cancelable`, which also takes a cancellation
+    /// flag: once the flag is set, the parse fails with
+    /// `ParseError::Canceled`.
+    pub fn start_cancelable_parser_fn(&mut self, cancelable: bool) -> io::Result<()> {
+        let parse_error_type = self.types.parse_error_type();
+        let (type_parameters, mut parameters, where_clauses) = self.parser_fn_inputs();
+
+        // a parser with a fixed-size stack takes the buffer for it
+        // (see `lalrpop_util::stack`)
+        let mut suffix = if self.session.fixed_stack {
+            parameters.push(format!("{}stack: &mut [u8]", self.prefix));
+            String::from("_with_stack")
+        } else {
+            String::new()
+        };
+        if cancelable {
+            parameters.push(self.cancel_parameter());
+            suffix.push_str("_cancelable");
         }
 
+        let result_type = format!("Result<{}, {}>",
+                                  self.types.nonterminal_type(self.start_symbol),
+                                  parse_error_type);
         rust!(self.out, "#[allow(dead_code)]");
         try!(self.out.write_pub_fn_header(self.grammar,
-                                          format!("parse_{}", self.user_start_symbol),
+                                          format!("parse_{}{}", self.user_start_symbol, suffix),
                                           type_parameters,
                                           parameters,
-                                          format!("Result<{}, {}>",
-                                                  self.types.nonterminal_type(self.start_symbol),
-                                                  parse_error_type),
+                                          result_type.clone(),
                                           where_clauses));
         rust!(self.out, "{{");
+        self.start_reserved_words(&result_type)
+    }
+
+    /// The cancellation flag parameter of `parse_Foo_cancelable`.
+    pub fn cancel_parameter(&self) -> String {
+        format!("{}cancel: &{}::sync::atomic::AtomicBool", self.prefix, self.core_crate())
+    }
 
+    /// The crate to take `marker`, `fmt` and the like from: `core`
+    /// (as declared by `write_uses`) for a parser with a fixed-size
+    /// stack, which then builds in `no_std` crates, and `::std`
+    /// otherwise.
+    pub fn core_crate(&self) -> String {
+        if self.session.fixed_stack {
+            format!("{}core", self.prefix)
+        } else {
+            String::from("::std")
+        }
+    }
+
+    /// For grammars with `#[reserved]` keywords, starts a closure
+    /// around the body of a parser fn that returns `result_type`, so
+    /// that `end_parser_fn` can pass the error it returns, if any,
+    /// through `__reserved_word` (see `lexer::intern_token`).
+    pub fn start_reserved_words(&mut self, result_type: &str) -> io::Result<()> {
+        if self.has_reserved_words() {
+            rust!(self.out, "let {}result: {} = (|| {{", self.prefix, result_type);
+        }
         Ok(())
     }
 
+    fn has_reserved_words(&self) -> bool {
+        self.grammar.intern_token.as_ref().map_or(false, |t| !t.reserved_words.is_empty())
+    }
+
     pub fn define_tokens(&mut self) -> io::Result<()> {
         if self.grammar.intern_token.is_some() {
             // if we are generating the tokenizer, create a matcher as our input iterator
@@ -156,6 +239,7 @@ impl<'codegen, 'grammar, W: Write, C> CodeGenerator<'codegen, 'grammar, W, C> {
                   self.action_module,
                   self.prefix,
                   self.prefix);
+            try!(self.convert_locations());
         } else {
             // otherwise, convert one from the `IntoIterator`
             // supplied, using the `ToTriple` trait which inserts
@@ -167,17 +251,62 @@ impl<'codegen, 'grammar, W: Write, C> CodeGenerator<'codegen, 'grammar, W, C> {
                   self.prefix,
                   clone_call);
 
+            match self.grammar.token_normalizer {
+                Some(ref normalizer) => {
+                    rust!(self.out,
+                          "let mut {}tokens = {}tokens.map(|t| {}ToTriple::to_triple(t)\
+                           .map(|(l, t, r)| (l, {}(t), r)));",
+                          self.prefix,
+                          self.prefix,
+                          self.prefix,
+                          normalizer);
+                }
+                None => {
+                    rust!(self.out,
+                          "let mut {}tokens = {}tokens.map(|t| {}ToTriple::to_triple(t));",
+                          self.prefix,
+                          self.prefix,
+                          self.prefix);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// If the grammar declares a `Location` type for the built-in
+    /// tokenizer, converts the byte offsets of `{p}tokens`.
+    pub fn convert_locations(&mut self) -> io::Result<()> {
+        if self.grammar.custom_locations() {
             rust!(self.out,
-                  "let mut {}tokens = {}tokens.map(|t| {}ToTriple::to_triple(t));",
-                  self.prefix,
-                  self.prefix,
-                  self.prefix);
+                  "let mut {p}tokens = {p}lalrpop_util::Locations::<{}, _>::new(input, {p}tokens);",
+                  self.types.terminal_loc_type(),
+                  p = self.prefix);
         }
+        Ok(())
+    }
 
+    /// Writes the arm of a `match` on a token that maps `terminal` to
+    /// `index`. Terminals without a guard keep the `if true` of old,
+    /// which stops rustc from flagging the fallback arm as
+    /// unreachable when every variant of the token type is covered.
+    pub fn write_terminal_arm(&mut self, terminal: TerminalString, index: usize) -> io::Result<()> {
+        let pattern = self.grammar.pattern(terminal).map(&mut |_| "_");
+        match self.grammar.conversion_guard(terminal) {
+            Some(guard) => rust!(self.out, "{} if {} => {},", pattern, guard, index),
+            None => rust!(self.out, "{} if true => {},", pattern, index),
+        }
         Ok(())
     }
 
     pub fn end_parser_fn(&mut self) -> io::Result<()> {
+        if self.has_reserved_words() {
+            rust!(self.out, "}})();");
+            rust!(self.out,
+                  "{p}result.map_err({}::{p}intern_token::{p}reserved_word)",
+                  self.action_module,
+                  p = self.prefix);
+        }
         rust!(self.out, "}}");
         Ok(())
     }
@@ -187,7 +316,8 @@ impl<'codegen, 'grammar, W: Write, C> CodeGenerator<'codegen, 'grammar, W, C> {
     /// all type parameters are constrained, even if they are not
     /// used.
     pub fn phantom_data_type(&self) -> String {
-        format!("::std::marker::PhantomData<({})>",
+        format!("{}::marker::PhantomData<({})>",
+                self.core_crate(),
                 Sep(", ", &self.grammar.non_lifetime_type_parameters()))
     }
 
@@ -196,7 +326,8 @@ impl<'codegen, 'grammar, W: Write, C> CodeGenerator<'codegen, 'grammar, W, C> {
     /// all type parameters are constrained, even if they are not
     /// used.
     pub fn phantom_data_expr(&self) -> String {
-        format!("::std::marker::PhantomData::<({})>",
+        format!("{}::marker::PhantomData::<({})>",
+                self.core_crate(),
                 Sep(", ", &self.grammar.non_lifetime_type_parameters()))
     }
 }
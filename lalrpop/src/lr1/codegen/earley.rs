@@ -0,0 +1,824 @@
+//! A compiler from a grammar to an [Earley] parser, used for grammars
+//! marked `#![earley]`. No LR(1) states are built; instead, the
+//! productions are written out as data, and the generated parser
+//! collects the tokens, runs the Earley algorithm in
+//! `lalrpop_util::earley` over them and then replays the resulting
+//! derivation as a sequence of shifts and reductions, running the
+//! action code much like the table-driven parser does. This accepts
+//! any context-free grammar (picking the first derivation if the
+//! input is ambiguous, unless `#[dprec]` and `#[merge]` say
+//! otherwise), which makes it handy while a grammar is still taking
+//! shape. For grammars with the built-in tokenizer, `parse_X_all`
+//! also returns the results of all the derivations. With
+//! `Configuration::memoize_actions`, the replay keeps the values of
+//! forest nodes that the derivations use more than once (see
+//! `Forest::resolve_memoized`), and recalls them.
+//!
+//! [Earley]: https://en.wikipedia.org/wiki/Earley_parser
+
+use collections::Set;
+use grammar::parse_tree::WhereClause;
+use grammar::repr::*;
+use intern::InternedString;
+use rust::RustWrite;
+use session::Session;
+use std::io::{self, Write};
+use util::{Escape, Sep};
+
+use super::base::CodeGenerator;
+
+pub fn compile<'grammar, W: Write>(session: &Session,
+                                   grammar: &'grammar Grammar,
+                                   user_start_symbol: NonterminalString,
+                                   start_symbol: NonterminalString,
+                                   action_module: &str,
+                                   out: &mut RustWrite<W>)
+                                   -> io::Result<()> {
+    let mut earley = CodeGenerator::new_earley(session,
+                                               grammar,
+                                               user_start_symbol,
+                                               start_symbol,
+                                               action_module,
+                                               out);
+    earley.write()
+}
+
+struct Earley {
+    /// type parameters for the `Symbol` type
+    symbol_type_params: Vec<TypeParameter>,
+
+    symbol_where_clauses: Vec<WhereClause<TypeRepr>>,
+
+    /// the `#[merge]` functions, each with the nonterminal whose
+    /// results it merges; `Step::Merge` refers to them by index
+    merges: Vec<(NonterminalString, InternedString)>,
+
+    /// whether any production has a `#[dprec]` or `#[merge]`
+    disambiguates: bool,
+}
+
+impl<'codegen, 'grammar, W: Write> CodeGenerator<'codegen, 'grammar, W, Earley> {
+    fn new_earley(session: &'codegen Session,
+                  grammar: &'grammar Grammar,
+                  user_start_symbol: NonterminalString,
+                  start_symbol: NonterminalString,
+                  action_module: &str,
+                  out: &'codegen mut RustWrite<W>)
+                  -> Self {
+        // As for the table-driven parser, the `Symbol` type is
+        // parameterized only by the type parameters that appear in
+        // the types of terminals and nonterminals.
+        let referenced_ty_params: Set<TypeParameter> = grammar.types
+                                                              .nonterminal_types()
+                                                              .into_iter()
+                                                              .chain(grammar.types
+                                                                            .terminal_types())
+                                                              .flat_map(|t| t.referenced())
+                                                              .collect();
+
+        let symbol_type_params: Vec<_> = grammar.type_parameters
+                                                .iter()
+                                                .filter(|t| referenced_ty_params.contains(t))
+                                                .cloned()
+                                                .collect();
+
+        let mut referenced_where_clauses = Set::new();
+        for wc in &grammar.where_clauses {
+            wc.map(|ty| {
+                if ty.referenced().iter().any(|p| symbol_type_params.contains(p)) {
+                    referenced_where_clauses.insert(wc.clone());
+                }
+            });
+        }
+
+        let symbol_where_clauses: Vec<_> = grammar.where_clauses
+                                                  .iter()
+                                                  .filter(|wc| referenced_where_clauses.contains(wc))
+                                                  .cloned()
+                                                  .collect();
+
+        let mut merges = vec![];
+        let mut disambiguates = false;
+        for production in grammar.nonterminals.values().flat_map(|nt| &nt.productions) {
+            if let Some(merge) = production.merge {
+                let merge = (production.nonterminal, merge);
+                if !merges.contains(&merge) {
+                    merges.push(merge);
+                }
+            }
+            disambiguates |= production.dprec > 0 || production.merge.is_some();
+        }
+
+        CodeGenerator::new(session,
+                           grammar,
+                           user_start_symbol,
+                           start_symbol,
+                           &[],
+                           out,
+                           false,
+                           action_module,
+                           Earley {
+                               symbol_type_params: symbol_type_params,
+                               symbol_where_clauses: symbol_where_clauses,
+                               merges: merges,
+                               disambiguates: disambiguates,
+                           })
+    }
+
+    fn write(&mut self) -> io::Result<()> {
+        self.write_parse_mod(|this| {
+            try!(this.write_value_type_defn());
+            try!(this.write_grammar_tables());
+            try!(this.write_parser_fn());
+            if this.grammar.intern_token.is_some() {
+                try!(this.write_all_parser_fn());
+            }
+            try!(this.emit_replay_fn());
+            try!(this.emit_reduce_fn());
+            if !this.custom.merges.is_empty() {
+                try!(this.emit_merge_fn());
+            }
+            try!(this.emit_downcast_fns());
+            Ok(())
+        })
+    }
+
+    fn write_value_type_defn(&mut self) -> io::Result<()> {
+        rust!(self.out, "#[allow(dead_code)]");
+        if self.session.memoize_actions {
+            rust!(self.out, "#[derive(Clone)]");
+        }
+        rust!(self.out,
+              "pub enum {}Symbol<{}>",
+              self.prefix,
+              Sep(", ", &self.custom.symbol_type_params));
+
+        if !self.custom.symbol_where_clauses.is_empty() {
+            rust!(self.out, " where {}", Sep(", ", &self.custom.symbol_where_clauses));
+        }
+
+        rust!(self.out, " {{");
+        for &term in &self.grammar.terminals.all {
+            let name = self.variant_name_for_symbol(Symbol::Terminal(term));
+            let ty = self.types.terminal_type(term).clone();
+            rust!(self.out, "{}({}),", name, ty);
+        }
+        for &nt in self.grammar.nonterminals.keys() {
+            let name = self.variant_name_for_symbol(Symbol::Nonterminal(nt));
+            let ty = self.types.nonterminal_type(nt).clone();
+            rust!(self.out, "{}({}),", name, ty);
+        }
+        rust!(self.out, "}}");
+        Ok(())
+    }
+
+    /// Writes the names of the terminals, for the expected tokens of
+    /// errors, and the productions, which are numbered in the same
+    /// order as the table-driven parser numbers its reductions.
+    fn write_grammar_tables(&mut self) -> io::Result<()> {
+        rust!(self.out, "const {}TERMINALS: &'static [&'static str] = &[", self.prefix);
+        for &terminal in &self.grammar.terminals.all {
+            rust!(self.out, "r###\"{}\"###,", terminal);
+        }
+        rust!(self.out, "];");
+
+        let nonterminals: Vec<NonterminalString> =
+            self.grammar.nonterminals.keys().cloned().collect();
+        rust!(self.out,
+              "const {}PRODUCTIONS: &'static [{}lalrpop_util::earley::Production] = &[",
+              self.prefix,
+              self.prefix);
+        for production in self.grammar.nonterminals.values().flat_map(|nt| &nt.productions) {
+            if self.session.emit_grammar_comments {
+                rust!(self.out, "// {:?}", production);
+            }
+            let symbols: Vec<String> =
+                production.symbols
+                          .iter()
+                          .map(|&symbol| match symbol {
+                              Symbol::Terminal(t) => {
+                                  format!("{}lalrpop_util::earley::Symbol::Terminal({})",
+                                          self.prefix,
+                                          self.grammar.terminals.bits[&t])
+                              }
+                              Symbol::Nonterminal(nt) => {
+                                  format!("{}lalrpop_util::earley::Symbol::Nonterminal({})",
+                                          self.prefix,
+                                          nonterminals.iter().position(|&x| x == nt).unwrap())
+                              }
+                          })
+                          .collect();
+            let merge = match production.merge {
+                Some(merge) => {
+                    let merge = (production.nonterminal, merge);
+                    format!("Some({})", self.custom.merges.iter().position(|&m| m == merge).unwrap())
+                }
+                None => format!("None"),
+            };
+            rust!(self.out,
+                  "{}lalrpop_util::earley::Production {{ nonterminal: {}, symbols: &[{}], \
+                   dprec: {}, merge: {} }},",
+                  self.prefix,
+                  nonterminals.iter().position(|&x| x == production.nonterminal).unwrap(),
+                  Sep(", ", &symbols),
+                  production.dprec,
+                  merge);
+        }
+        rust!(self.out, "];");
+        Ok(())
+    }
+
+    fn write_parser_fn(&mut self) -> io::Result<()> {
+        try!(self.start_parser_fn());
+        try!(self.parse_tokens());
+        let derivation = if !self.custom.disambiguates {
+            format!("{}chart.derivation()", self.prefix)
+        } else if self.session.memoize_actions {
+            format!("{p}chart.forest().resolve_memoized({p}PRODUCTIONS)", p = self.prefix)
+        } else {
+            format!("{p}chart.forest().resolve({p}PRODUCTIONS)", p = self.prefix)
+        };
+        let memo = if self.session.memoize_actions {
+            "&mut ::std::collections::HashMap::new(), "
+        } else {
+            ""
+        };
+        rust!(self.out,
+              "{p}replay({}{}, {p}triples, &{p}integers, {}{})",
+              self.grammar.user_parameter_refs(),
+              derivation,
+              memo,
+              self.phantom_data_expr(),
+              p = self.prefix);
+        self.end_parser_fn()
+    }
+
+    /// Writes `parse_X_all`, which runs the action code of every
+    /// derivation of the input, skipping those where a fallible
+    /// action fails (unless they all do). Each derivation consumes
+    /// its own copy of the tokens, so this is only written for
+    /// grammars with the built-in tokenizer, whose tokens are `Copy`.
+    fn write_all_parser_fn(&mut self) -> io::Result<()> {
+        let parse_error_type = self.types.parse_error_type();
+        let (type_parameters, parameters, where_clauses) = self.parser_fn_inputs();
+
+        let result_type = format!("Result<::std::vec::Vec<{}>, {}>",
+                                  self.types.nonterminal_type(self.start_symbol),
+                                  parse_error_type);
+        rust!(self.out, "#[allow(dead_code)]");
+        try!(self.out.write_pub_fn_header(self.grammar,
+                                          format!("parse_{}_all", self.user_start_symbol),
+                                          type_parameters,
+                                          parameters,
+                                          result_type.clone(),
+                                          where_clauses));
+        rust!(self.out, "{{");
+        try!(self.start_reserved_words(&result_type));
+        try!(self.parse_tokens());
+        rust!(self.out, "let mut {}results = vec![];", self.prefix);
+        rust!(self.out, "let mut {}failure = None;", self.prefix);
+        let (derivations, memo) = if self.session.memoize_actions {
+            // one memo for all the derivations, which share the values
+            // of their unambiguous parts
+            rust!(self.out,
+                  "let mut {}memo = ::std::collections::HashMap::new();",
+                  self.prefix);
+            ("derivations_memoized", format!("&mut {}memo, ", self.prefix))
+        } else {
+            ("derivations", String::new())
+        };
+        rust!(self.out,
+              "for {p}steps in {p}chart.forest().{}() {{",
+              derivations,
+              p = self.prefix);
+        rust!(self.out,
+              "match {p}replay({}{p}steps, {p}triples.clone(), &{p}integers, {}{}) {{",
+              self.grammar.user_parameter_refs(),
+              memo,
+              self.phantom_data_expr(),
+              p = self.prefix);
+        rust!(self.out, "Ok(v) => {}results.push(v),", self.prefix);
+        rust!(self.out,
+              "Err(e) => if {p}failure.is_none() {{ {p}failure = Some(e); }},",
+              p = self.prefix);
+        rust!(self.out, "}}");
+        rust!(self.out, "}}");
+        rust!(self.out, "match {}failure {{", self.prefix);
+        rust!(self.out,
+              "Some(e) if {}results.is_empty() => Err(e),",
+              self.prefix);
+        rust!(self.out, "_ => Ok({}results),", self.prefix);
+        rust!(self.out, "}}");
+        self.end_parser_fn()
+    }
+
+    /// Writes the start of a parser fn body: collects the tokens,
+    /// parses them into `__chart`, and returns if that fails.
+    fn parse_tokens(&mut self) -> io::Result<()> {
+        try!(self.define_tokens());
+
+        // Collect all the tokens up front, stopping at the first one
+        // that is not a terminal of the grammar.
+        rust!(self.out, "let mut {}triples = vec![];", self.prefix);
+        rust!(self.out, "let mut {}integers = vec![];", self.prefix);
+        rust!(self.out, "let mut {}unrecognized = None;", self.prefix);
+        rust!(self.out, "for {p}token in {p}tokens {{", p = self.prefix);
+        rust!(self.out, "let {}lookahead = match {}token {{", self.prefix, self.prefix);
+        rust!(self.out, "Ok(v) => v,");
+        if self.grammar.intern_token.is_some() {
+            rust!(self.out, "Err(e) => return Err(e),");
+        } else {
+            rust!(self.out,
+                  "Err(e) => return Err({}lalrpop_util::ParseError::User {{ error: e }}),",
+                  self.prefix);
+        }
+        rust!(self.out, "}};");
+        rust!(self.out, "let {}integer = match {}lookahead.1 {{", self.prefix, self.prefix);
+        for (&terminal, index) in self.grammar.terminals.all.iter().zip(0..) {
+            try!(self.write_terminal_arm(terminal, index));
+        }
+        rust!(self.out, "_ => {{");
+        rust!(self.out, "{p}unrecognized = Some({p}lookahead);", p = self.prefix);
+        rust!(self.out, "break;");
+        rust!(self.out, "}}");
+        rust!(self.out, "}};");
+        rust!(self.out, "{p}triples.push({p}lookahead);", p = self.prefix);
+        rust!(self.out, "{p}integers.push({p}integer);", p = self.prefix);
+        rust!(self.out, "}}");
+
+        let start_index = self.grammar
+                              .nonterminals
+                              .keys()
+                              .position(|&nt| nt == self.start_symbol)
+                              .unwrap();
+        if let Some(max_reductions) = self.session.max_reductions {
+            rust!(self.out,
+                  "let {p}chart = match {p}lalrpop_util::earley::Chart::parse_with_limit(\
+                   {p}PRODUCTIONS, {}, &{p}integers, {}) {{",
+                  start_index,
+                  max_reductions,
+                  p = self.prefix);
+            rust!(self.out, "Ok({p}chart) => {p}chart,", p = self.prefix);
+            rust!(self.out, "Err({p}index) => {{", p = self.prefix);
+            rust!(self.out,
+                  "let {p}location = {p}triples.get({p}index).map(|{p}t| {p}t.0.clone())\
+                   .or_else(|| {p}triples.last().map(|{p}t| {p}t.2.clone()))\
+                   .unwrap_or_default();",
+                  p = self.prefix);
+            rust!(self.out,
+                  "return Err({p}lalrpop_util::ParseError::ReductionLimit {{ \
+                   location: {p}location, limit: {} }});",
+                  max_reductions,
+                  p = self.prefix);
+            rust!(self.out, "}}");
+            rust!(self.out, "}};");
+        } else {
+            rust!(self.out,
+                  "let {p}chart = {p}lalrpop_util::earley::Chart::parse({p}PRODUCTIONS, {}, \
+                   &{p}integers);",
+                  start_index,
+                  p = self.prefix);
+        }
+
+        // If the tokens before an unrecognized one parse fine, the
+        // error is the unrecognized token.
+        rust!(self.out, "let {}error = match {}chart.error() {{", self.prefix, self.prefix);
+        rust!(self.out, "Some({p}index) => Some({p}index),", p = self.prefix);
+        rust!(self.out,
+              "None if {p}unrecognized.is_some() => Some({p}integers.len()),",
+              p = self.prefix);
+        rust!(self.out, "None => None,");
+        rust!(self.out, "}};");
+        rust!(self.out, "if let Some({p}index) = {p}error {{", p = self.prefix);
+        rust!(self.out,
+              "let {p}expected = {p}chart.expected({p}index).into_iter()\
+               .map(|{p}t| {p}TERMINALS[{p}t].to_string()).collect();",
+              p = self.prefix);
+        // A token is extra if the tokens before it are a complete
+        // parse; a token that matched no terminal never is.
+        rust!(self.out, "if {p}index < {p}triples.len() {{", p = self.prefix);
+        rust!(self.out, "let {p}token = {p}triples.swap_remove({p}index);", p = self.prefix);
+        rust!(self.out, "if {p}chart.is_complete({p}index) {{", p = self.prefix);
+        rust!(self.out,
+              "return Err({p}lalrpop_util::ParseError::ExtraToken {{ \
+               token: {p}token, expected: {p}expected }});",
+              p = self.prefix);
+        rust!(self.out, "}}");
+        rust!(self.out,
+              "return Err({p}lalrpop_util::ParseError::UnrecognizedToken {{ \
+               token: Some({p}token), expected: {p}expected }});",
+              p = self.prefix);
+        rust!(self.out, "}}");
+        rust!(self.out, "if {p}unrecognized.is_some() {{", p = self.prefix);
+        rust!(self.out,
+              "return Err({p}lalrpop_util::ParseError::UnrecognizedToken {{ \
+               token: {p}unrecognized, expected: {p}expected }});",
+              p = self.prefix);
+        rust!(self.out, "}}");
+        rust!(self.out,
+              "return Err({p}lalrpop_util::ParseError::UnrecognizedEof {{ \
+               location: {p}triples.last().map(|{p}t| {p}t.2.clone()).unwrap_or_default(), \
+               expected: {p}expected }});",
+              p = self.prefix);
+        rust!(self.out, "}}");
+        Ok(())
+    }
+
+    /// Writes the `__replay` function, which performs the shifts and
+    /// reductions of a derivation. If the grammar has `#[merge]`
+    /// functions, the derivation may rewind to derive some tokens
+    /// again, so they are cloned rather than consumed; the same goes
+    /// for recalling memoized values, which skips to the end of the
+    /// tokens they were derived from.
+    fn emit_replay_fn(&mut self) -> io::Result<()> {
+        let success_type = self.types.nonterminal_type(self.start_symbol);
+        let parse_error_type = self.types.parse_error_type();
+        let memoize = self.session.memoize_actions;
+        let mut parameters = vec![format!("{}steps: ::std::vec::Vec<{}lalrpop_util::earley::Step>",
+                                          self.prefix,
+                                          self.prefix),
+                                  format!("{}triples: ::std::vec::Vec<{}>",
+                                          self.prefix,
+                                          self.types.triple_type()),
+                                  format!("{}integers: &[usize]", self.prefix)];
+        if memoize {
+            parameters.push(format!("{}memo: &mut ::std::collections::HashMap<usize, {}>",
+                                    self.prefix,
+                                    self.spanned_symbol_type()));
+        }
+        parameters.push(format!("_: {}", self.phantom_data_type()));
+
+        try!(self.out.write_fn_header(self.grammar,
+                                      format!("{}replay", self.prefix),
+                                      vec![],
+                                      parameters,
+                                      format!("Result<{}, {}>", success_type, parse_error_type),
+                                      vec![]));
+        rust!(self.out, "{{");
+        let merges = !self.custom.merges.is_empty();
+        let indexed = merges || memoize;
+        rust!(self.out, "let mut {}symbols = vec![];", self.prefix);
+        if indexed {
+            rust!(self.out, "let mut {}next = 0;", self.prefix);
+        } else {
+            rust!(self.out,
+                  "let mut {p}tokens = {p}triples.into_iter()\
+                   .zip({p}integers.iter().cloned()).peekable();",
+                  p = self.prefix);
+        }
+        if memoize {
+            // recalling a value skips the steps that derive it
+            rust!(self.out, "let mut {p}steps = {p}steps.into_iter();", p = self.prefix);
+            rust!(self.out, "while let Some({p}step) = {p}steps.next() {{", p = self.prefix);
+        } else {
+            rust!(self.out, "for {p}step in {p}steps {{", p = self.prefix);
+        }
+        rust!(self.out, "match {}step {{", self.prefix);
+        rust!(self.out, "{}lalrpop_util::earley::Step::Shift => {{", self.prefix);
+        if indexed {
+            rust!(self.out,
+                  "let ({p}lookahead, {p}integer) = \
+                   ({p}triples[{p}next].clone(), {p}integers[{p}next]);",
+                  p = self.prefix);
+            rust!(self.out, "{}next += 1;", self.prefix);
+        } else {
+            rust!(self.out,
+                  "let ({p}lookahead, {p}integer) = {p}tokens.next().unwrap();",
+                  p = self.prefix);
+        }
+        try!(self.token_to_symbol());
+        rust!(self.out,
+              "{p}symbols.push(({p}lookahead.0, {p}symbol, {p}lookahead.2));",
+              p = self.prefix);
+        rust!(self.out, "}}");
+        rust!(self.out, "{p}lalrpop_util::earley::Step::Reduce({p}production) => {{",
+              p = self.prefix);
+        if indexed {
+            rust!(self.out,
+                  "let {p}lookahead_start = {p}triples.get({p}next).map(|{p}t| {p}t.0.clone());",
+                  p = self.prefix);
+        } else {
+            rust!(self.out,
+                  "let {p}lookahead_start = \
+                   {p}tokens.peek().map(|&(ref {p}t, _)| {p}t.0.clone());",
+                  p = self.prefix);
+        }
+        rust!(self.out,
+              "if let Some(r) = {p}reduce({}{p}production, {p}lookahead_start.as_ref(), \
+               &mut {p}symbols, {}) {{",
+              self.grammar.user_parameter_refs(),
+              self.phantom_data_expr(),
+              p = self.prefix);
+        rust!(self.out, "return r;");
+        rust!(self.out, "}}");
+        rust!(self.out, "}}");
+        let mut unreachable = vec![];
+        if merges {
+            rust!(self.out,
+                  "{p}lalrpop_util::earley::Step::Rewind({p}index) => {p}next = {p}index,",
+                  p = self.prefix);
+            rust!(self.out,
+                  "{p}lalrpop_util::earley::Step::Merge({p}index) => \
+                   {p}merge({}{p}index, &mut {p}symbols, {}),",
+                  self.grammar.user_parameter_refs(),
+                  self.phantom_data_expr(),
+                  p = self.prefix);
+        } else {
+            unreachable.push("Rewind(_)");
+            unreachable.push("Merge(_)");
+        }
+        if memoize {
+            rust!(self.out,
+                  "{p}lalrpop_util::earley::Step::Recall {{ node: {p}node, end: {p}end, \
+                   skip: {p}skip }} => {{",
+                  p = self.prefix);
+            rust!(self.out,
+                  "if let Some({p}symbol) = {p}memo.get(&{p}node).cloned() {{",
+                  p = self.prefix);
+            rust!(self.out, "{p}symbols.push({p}symbol);", p = self.prefix);
+            rust!(self.out, "{p}next = {p}end;", p = self.prefix);
+            rust!(self.out, "for _ in 0..{p}skip {{ {p}steps.next(); }}", p = self.prefix);
+            rust!(self.out, "}}");
+            rust!(self.out, "}}");
+            rust!(self.out,
+                  "{p}lalrpop_util::earley::Step::Keep({p}node) => {{ \
+                   {p}memo.insert({p}node, {p}symbols.last().unwrap().clone()); }}",
+                  p = self.prefix);
+        } else {
+            unreachable.push("Recall { .. }");
+            unreachable.push("Keep(_)");
+        }
+        if !unreachable.is_empty() {
+            let patterns: Vec<String> = unreachable.iter()
+                                                   .map(|pattern| {
+                                                       format!("{}lalrpop_util::earley::Step::{}",
+                                                               self.prefix,
+                                                               pattern)
+                                                   })
+                                                   .collect();
+            rust!(self.out, "{} => unreachable!(),", patterns.join(" | "));
+        }
+        rust!(self.out, "}}"); // match
+        rust!(self.out, "}}"); // for
+
+        // the last step always reduces the start symbol
+        rust!(self.out, "unreachable!()");
+        rust!(self.out, "}}");
+        Ok(())
+    }
+
+    fn token_to_symbol(&mut self) -> io::Result<()> {
+        rust!(self.out,
+              "let {}symbol = match {}integer {{",
+              self.prefix,
+              self.prefix);
+        for (&terminal, index) in self.grammar.terminals.all.iter().zip(0..) {
+            rust!(self.out, "{} => match {}lookahead.1 {{", index, self.prefix);
+
+            // the guard has already been checked to get `integer`,
+            // so its bindings are not needed here
+            let mut pattern_names = vec![];
+            let pattern = self.grammar.pattern(terminal).without_bindings().map(&mut |_| {
+                let index = pattern_names.len();
+                pattern_names.push(format!("{}tok{}", self.prefix, index));
+                pattern_names.last().cloned().unwrap()
+            });
+
+            let mut pattern = format!("{}", pattern);
+            if pattern_names.is_empty() {
+                pattern_names.push(format!("{}tok", self.prefix));
+                pattern = format!("{}tok @ {}", self.prefix, pattern);
+            }
+
+            let variant_name = self.variant_name_for_symbol(Symbol::Terminal(terminal));
+            rust!(self.out,
+                  "{} => {}Symbol::{}({}),",
+                  pattern,
+                  self.prefix,
+                  variant_name,
+                  self.grammar.terminal_value(terminal, &pattern_names));
+            rust!(self.out, "_ => unreachable!(),");
+            rust!(self.out, "}},");
+        }
+        rust!(self.out, "_ => unreachable!(),");
+        rust!(self.out, "}};");
+        Ok(())
+    }
+
+    /// Writes the `__reduce` function, which runs the action code of
+    /// a production on the symbols at the top of the stack. It
+    /// returns the result of the parse after reducing the start
+    /// symbol.
+    fn emit_reduce_fn(&mut self) -> io::Result<()> {
+        let success_type = self.types.nonterminal_type(self.start_symbol);
+        let parse_error_type = self.types.parse_error_type();
+        let loc_type = self.types.terminal_loc_type();
+        let spanned_symbol_type = self.spanned_symbol_type();
+
+        let parameters = vec![format!("{}production: usize", self.prefix),
+                              format!("{}lookahead_start: Option<&{}>", self.prefix, loc_type),
+                              format!("{}symbols: &mut ::std::vec::Vec<{}>",
+                                      self.prefix,
+                                      spanned_symbol_type),
+                              format!("_: {}", self.phantom_data_type())];
+
+        try!(self.out.write_fn_header(self.grammar,
+                                      format!("{}reduce", self.prefix),
+                                      vec![],
+                                      parameters,
+                                      format!("Option<Result<{},{}>>",
+                                              success_type,
+                                              parse_error_type),
+                                      vec![]));
+        rust!(self.out, "{{");
+        rust!(self.out, "match {}production {{", self.prefix);
+        let productions: Vec<&Production> =
+            self.grammar.nonterminals.values().flat_map(|nt| &nt.productions).collect();
+        for (index, production) in productions.into_iter().enumerate() {
+            rust!(self.out, "{} => {{", index);
+            try!(self.emit_reduce_action(production));
+            rust!(self.out, "}}");
+        }
+        rust!(self.out,
+              "_ => panic!(\"invalid production {{}}\", {}production)",
+              self.prefix);
+        rust!(self.out, "}}");
+        rust!(self.out, "None");
+        rust!(self.out, "}}");
+        Ok(())
+    }
+
+    fn emit_reduce_action(&mut self, production: &Production) -> io::Result<()> {
+        if self.session.emit_grammar_comments {
+            rust!(self.out, "// {:?}", production);
+        }
+
+        for (index, &symbol) in production.symbols.iter().enumerate().rev() {
+            let name = self.variant_name_for_symbol(symbol);
+            rust!(self.out,
+                  "let {p}sym{} = {p}pop_{}({p}symbols);",
+                  index,
+                  name,
+                  p = self.prefix);
+        }
+        let transfer_syms: Vec<_> = (0..production.symbols.len())
+                                        .map(|i| format!("{}sym{}", self.prefix, i))
+                                        .collect();
+
+        // As in the table-driven parser, an empty production starts
+        // where the previous symbol ended and ends where the next
+        // token starts.
+        if let Some(first_sym) = transfer_syms.first() {
+            rust!(self.out, "let {}start = {}.0.clone();", self.prefix, first_sym);
+        } else {
+            rust!(self.out,
+                  "let {p}start = {p}symbols.last().map(|s| s.2.clone()).unwrap_or_default();",
+                  p = self.prefix);
+        }
+        if let Some(last_sym) = transfer_syms.last() {
+            rust!(self.out, "let {}end = {}.2.clone();", self.prefix, last_sym);
+        } else {
+            rust!(self.out,
+                  "let {p}end = {p}lookahead_start.cloned().unwrap_or_else(|| {p}start.clone());",
+                  p = self.prefix);
+        }
+
+        let mut args = transfer_syms;
+        if args.is_empty() {
+            args.push(format!("&{}start", self.prefix));
+            args.push(format!("&{}end", self.prefix));
+        }
+
+        let call = format!("{}::{}action{}::<{}>({}{})",
+                           self.action_module,
+                           self.prefix,
+                           production.action.index(),
+                           Sep(", ", &self.grammar.non_lifetime_type_parameters()),
+                           self.grammar.user_parameter_refs(),
+                           Sep(", ", &args));
+        if self.grammar.action_is_fallible(production.action) {
+            rust!(self.out, "let {}nt = match {} {{", self.prefix, call);
+            rust!(self.out, "Ok(v) => v,");
+            rust!(self.out, "Err(e) => return Some(Err(e)),");
+            rust!(self.out, "}};");
+        } else {
+            rust!(self.out, "let {}nt = {};", self.prefix, call);
+        }
+
+        if production.nonterminal == self.start_symbol {
+            rust!(self.out, "return Some(Ok({}nt));", self.prefix);
+        } else {
+            let name = self.variant_name_for_symbol(Symbol::Nonterminal(production.nonterminal));
+            rust!(self.out,
+                  "{p}symbols.push(({p}start, {p}Symbol::{}({p}nt), {p}end));",
+                  name,
+                  p = self.prefix);
+        }
+        Ok(())
+    }
+
+    /// Writes the `__merge` function, which replaces the two results
+    /// on top of the stack, derived from the same tokens, with the
+    /// result of the given `#[merge]` function.
+    fn emit_merge_fn(&mut self) -> io::Result<()> {
+        let spanned_symbol_type = self.spanned_symbol_type();
+        let parameters = vec![format!("{}merge: usize", self.prefix),
+                              format!("{}symbols: &mut ::std::vec::Vec<{}>",
+                                      self.prefix,
+                                      spanned_symbol_type),
+                              format!("_: {}", self.phantom_data_type())];
+
+        try!(self.out.write_fn_header(self.grammar,
+                                      format!("{}merge", self.prefix),
+                                      vec![],
+                                      parameters,
+                                      format!("()"),
+                                      vec![]));
+        rust!(self.out, "{{");
+        rust!(self.out, "match {}merge {{", self.prefix);
+        for (index, &(nonterminal, function)) in self.custom.merges.clone().iter().enumerate() {
+            let name = self.variant_name_for_symbol(Symbol::Nonterminal(nonterminal));
+            rust!(self.out, "{} => {{", index);
+            rust!(self.out,
+                  "let (_, {p}second, {p}end) = {p}pop_{}({p}symbols);",
+                  name,
+                  p = self.prefix);
+            rust!(self.out,
+                  "let ({p}start, {p}first, _) = {p}pop_{}({p}symbols);",
+                  name,
+                  p = self.prefix);
+            rust!(self.out,
+                  "{p}symbols.push(({p}start, {p}Symbol::{}({}({p}first, {p}second)), {p}end));",
+                  name,
+                  function,
+                  p = self.prefix);
+            rust!(self.out, "}}");
+        }
+        rust!(self.out,
+              "_ => panic!(\"invalid merge {{}}\", {}merge)",
+              self.prefix);
+        rust!(self.out, "}}");
+        rust!(self.out, "}}");
+        Ok(())
+    }
+
+    fn variant_name_for_symbol(&self, s: Symbol) -> String {
+        match s {
+            Symbol::Nonterminal(nt) => format!("Nt{}", Escape(nt)),
+            Symbol::Terminal(t) => format!("Term{}", Escape(t)),
+        }
+    }
+
+    fn emit_downcast_fns(&mut self) -> io::Result<()> {
+        for &term in &self.grammar.terminals.all {
+            let name = self.variant_name_for_symbol(Symbol::Terminal(term));
+            let ty = self.types.terminal_type(term).clone();
+            try!(self.emit_downcast_fn(&name, ty));
+        }
+        for &nt in self.grammar.nonterminals.keys() {
+            let name = self.variant_name_for_symbol(Symbol::Nonterminal(nt));
+            let ty = self.types.nonterminal_type(nt).clone();
+            try!(self.emit_downcast_fn(&name, ty));
+        }
+        Ok(())
+    }
+
+    fn emit_downcast_fn(&mut self, variant_name: &str, variant_ty: TypeRepr) -> io::Result<()> {
+        let spanned_symbol_type = self.spanned_symbol_type();
+
+        rust!(self.out, "fn {}pop_{}<", self.prefix, variant_name);
+        for type_parameter in &self.custom.symbol_type_params {
+            rust!(self.out, "  {},", type_parameter);
+        }
+        rust!(self.out, ">(");
+        rust!(self.out,
+              "{}symbols: &mut ::std::vec::Vec<{}>",
+              self.prefix,
+              spanned_symbol_type);
+        rust!(self.out, ") -> {}", self.types.spanned_type(variant_ty));
+        if !self.custom.symbol_where_clauses.is_empty() {
+            rust!(self.out, " where {}", Sep(", ", &self.custom.symbol_where_clauses));
+        }
+        rust!(self.out, " {{");
+        rust!(self.out, "match {}symbols.pop().unwrap() {{", self.prefix);
+        rust!(self.out,
+              "({p}l, {p}Symbol::{}({p}v), {p}r) => ({p}l, {p}v, {p}r),",
+              variant_name,
+              p = self.prefix);
+        rust!(self.out, "_ => panic!(\"symbol type mismatch\")");
+        rust!(self.out, "}}");
+        rust!(self.out, "}}");
+        Ok(())
+    }
+
+    fn symbol_type(&self) -> String {
+        format!("{}Symbol<{}>",
+                self.prefix,
+                Sep(", ", &self.custom.symbol_type_params))
+    }
+
+    fn spanned_symbol_type(&self) -> String {
+        let loc_type = self.types.terminal_loc_type();
+        format!("({},{},{})", loc_type, self.symbol_type(), loc_type)
+    }
+}
@@ -1,4 +1,5 @@
 pub mod ascent;
 mod base;
+pub mod earley;
 pub mod parse_table;
 pub mod test_all;
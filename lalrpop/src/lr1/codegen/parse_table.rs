@@ -5,27 +5,91 @@ use grammar::parse_tree::WhereClause;
 use grammar::repr::*;
 use lr1::core::*;
 use lr1::lookahead::Token;
+use lr1::profile::Profile;
+use lr1::unit_rules;
 use rust::RustWrite;
+use session::Session;
 use std::fmt;
 use std::io::{self, Write};
-use tls::Tls;
+use std::ops::Range;
 use util::{Escape, Sep};
 
 use super::base::CodeGenerator;
 
-const DEBUG_PRINT: bool = false;
+/// How many of the states in which default reductions were performed
+/// since the last shift the parse loop remembers; see
+/// `let_unrecognized_token_error`.
+const DEFAULT_REDUCED_LIMIT: usize = 16;
 
-pub fn compile<'grammar, W: Write>(grammar: &'grammar Grammar,
+pub fn compile<'grammar, W: Write>(session: &Session,
+                                   grammar: &'grammar Grammar,
                                    user_start_symbol: NonterminalString,
                                    start_symbol: NonterminalString,
                                    states: &[LR1State<'grammar>],
                                    action_module: &str,
                                    out: &mut RustWrite<W>)
                                    -> io::Result<()> {
-    let mut table_driven = CodeGenerator::new_table_driven(grammar,
-                                                           user_start_symbol,
-                                                           start_symbol,
-                                                           states,
+    let parser = ParserStates {
+        user_start_symbol: user_start_symbol,
+        start_symbol: start_symbol,
+        states: states.to_vec(),
+        profile: Profile::default(),
+    };
+    compile_all(session, grammar, vec![parser], action_module, out)
+}
+
+/// The states of the parser for one public nonterminal, along with
+/// the profile that they are laid out by.
+pub struct ParserStates<'grammar> {
+    pub user_start_symbol: NonterminalString,
+    pub start_symbol: NonterminalString,
+    pub states: Vec<LR1State<'grammar>>,
+    pub profile: Profile,
+}
+
+/// Like `compile`, but for the parsers of several public nonterminals
+/// at once: their states are put in the same tables, one after the
+/// other, and a single parse loop (along with the reduce actions and
+/// error recovery) serves all of them, starting from the start state
+/// of whichever parser is called. The arms of the reduce actions that
+/// the profiles count most often come first.
+pub fn compile_all<'grammar, W: Write>(session: &Session,
+                                       grammar: &'grammar Grammar,
+                                       parsers: Vec<ParserStates<'grammar>>,
+                                       action_module: &str,
+                                       out: &mut RustWrite<W>)
+                                       -> io::Result<()> {
+    let mut states = vec![];
+    let mut starts = vec![];
+    let mut reduction_counts = Map::new();
+    for parser in parsers {
+        let offset = states.len();
+        starts.push(Start {
+            user_start_symbol: parser.user_start_symbol,
+            start_symbol: parser.start_symbol,
+            states: offset..offset + parser.states.len(),
+        });
+        states.extend(parser.states.into_iter().map(|mut state| {
+            state.index = StateIndex(state.index.0 + offset);
+            for next_state in state.shifts.values_mut().chain(state.gotos.values_mut()) {
+                next_state.0 += offset;
+            }
+            state
+        }));
+
+        // the reduce actions are shared, so each profile counts all of
+        // them (see `write_profile_fn`)
+        for (&action, &count) in &parser.profile.reductions {
+            let total = reduction_counts.entry(action).or_insert(0);
+            *total = ::std::cmp::max(*total, count);
+        }
+    }
+
+    let mut table_driven = CodeGenerator::new_table_driven(session,
+                                                           grammar,
+                                                           starts,
+                                                           &states,
+                                                           reduction_counts,
                                                            action_module,
                                                            out);
     table_driven.write()
@@ -33,14 +97,25 @@ pub fn compile<'grammar, W: Write>(grammar: &'grammar Grammar,
 
 // We create three parse tables:
 //
-// - `ACTION[state * num_states + terminal]: i32`: given a state and next token,
+// - `ACTION[state * num_states + terminal]: A`: given a state and next token,
 //   yields an integer indicating whether to shift/reduce (see below)
-// - `EOF_ACTION[state]: i32`: as above, but for the EOF token
-// - `GOTO[state * num_states + nonterminal]: i32`: index + 1 of state to jump to when given
+// - `EOF_ACTION[state]: A`: as above, but for the EOF token
+// - `GOTO[state * num_states + nonterminal]: G`: index + 1 of state to jump to when given
 //   nonterminal is pushed (no error is possible)
+// - `DEFAULT_ACTION[state]: A`: for states that reduce the same
+//   production no matter what the next token is, that reduction (see
+//   below); otherwise zero. This lets the parser reduce without
+//   pulling the next token from the input. Grammars that use error
+//   recovery or error repair do not get this table.
 //
-// For the `ACTION` and `EOF_ACTION` tables, the value is an `i32` and
-// its interpretation varies depending on whether it is positive or
+// `A` is the smallest of `i8`, `i16` and `i32` that can hold every
+// state and reduction index of the grammar, and `G` the smallest of
+// `u8`, `u16` and `u32` that can hold every state; small grammars thus
+// get much smaller tables. Entries are widened to `i32` as they are
+// read.
+//
+// For the `ACTION` and `EOF_ACTION` tables, the value is a signed
+// integer and its interpretation varies depending on whether it is positive or
 // negative:
 //
 // - if zero, parse error.
@@ -68,15 +143,63 @@ pub fn compile<'grammar, W: Write>(grammar: &'grammar Grammar,
 // }
 // ```
 //
-// The action parser function looks like this (pseudo-code):
+// The states of the parsers of all the public nonterminals go in the
+// same tables, and the parse loop is shared by all of them: it starts
+// from the start state of the parser that is called, and returns the
+// value of its start symbol as a `Value`. The public parser function
+// is just a thin wrapper that passes it that start state and takes
+// the value back out; it also erases the type of the token iterator,
+// so that the parse loop itself is not instantiated anew for every
+// kind of iterator the user parses from:
+//
+// ```
+// pub fn parse_fn<TOKENS>(tokens: TOKENS) -> Result<T, Error>
+//    where TOKENS: IntoIterator<Item=...>
+// {
+//    let mut tokens = tokens.into_iter().map(|t| ToTriple::to_triple(t));
+//    parse(START_STATE, &mut tokens, &mut vec![], &mut vec![], None)
+//        .map(|value| match value { Value::VariantForStartNonterminal(v) => v, ... })
+// }
+// ```
+//
+// The `parse_in_place` method of the generated `FooParser` struct is
+// the same, except that it supplies stacks that it keeps around from
+// one parse to the next, along with its cancellation flag (if any).
+//
+// The parse loop function looks like this (pseudo-code):
 //
 // ```
-// fn parse_fn<TOKENS>(tokens: TOKENS) -> Result<T, Error>
-//    where TOKENS: Iterator<Item=Result<(Location, Token, Location), Error>>
+// fn parse(start_state: i32,
+//          tokens: &mut Iterator<Item=Result<(Location, Token, Location), Error>>,
+//          states: &mut Vec<i32>,
+//          symbols: &mut Vec<(Location, Value, Location)>,
+//          cancel: Option<&AtomicBool>)
+//    -> Result<Value, Error>
 // {
-//    let mut states = vec![0]; // initial state is zero
-//    let mut symbols = vec![];
+//    states.clear();
+//    states.push(start_state);
+//    symbols.clear();
+//    let mut default_reduced = [0; 16];
+//    let mut default_reduced_len = 0;
 //    'shift: loop {
+//        // Perform default reductions, remembering the (first 16)
+//        // states we did them in: if the next token turns out to be
+//        // an error, the first of those states that has no action for
+//        // it is where the error would have been detected without
+//        // them, and so whose expected tokens we report.
+//        loop {
+//            let state = *states.last().unwrap() as usize;
+//            let action = DEFAULT_ACTION[state];
+//            if action == 0 { break; }
+//            if default_reduced_len < 16 {
+//                default_reduced[default_reduced_len] = state;
+//                default_reduced_len += 1;
+//            }
+//            if let Some(r) = reduce(action, None, &mut states, &mut symbols) {
+//                return r;
+//            }
+//        }
+//
 //        // Code to shift the next symbol and determine which terminal
 //        // it is; emitted by `shift_symbol()`.
 //        let lookahead = match tokens.next() {
@@ -102,6 +225,7 @@ pub fn compile<'grammar, W: Write>(grammar: &'grammar Grammar,
 //            if action > 0 { // shift
 //                states.push(action - 1);
 //                symbols.push(symbol);
+//                default_reduced_len = 0;
 //                continue 'shift;
 //            } else if action < 0 { // reduce
 //                if let Some(r) = reduce(action, Some(&lookahead.0), &mut states, &mut symbols) {
@@ -109,7 +233,8 @@ pub fn compile<'grammar, W: Write>(grammar: &'grammar Grammar,
 //                    if r.is_err() {
 //                        return r;
 //                    }
-//                    return Err(lalrpop_util::ParseError::ExtraToken { token: lookahead });
+//                    return Err(lalrpop_util::ParseError::ExtraToken { token: lookahead,
+//                                                                     expected: vec![] });
 //                }
 //            } else {
 //                // Error recovery code: emitted by `try_error_recovery`
@@ -156,17 +281,10 @@ pub fn compile<'grammar, W: Write>(grammar: &'grammar Grammar,
 //           states: &mut Vec<i32>, symbols: &mut Vec<(L, Symbol, L))
 //           -> Option<Result<..>> {
 //     let nonterminal = match -action {
-//        0 => {
-//            // Execute reduce action 0 to produce nonterminal N, popping from stacks etc
-//            // (generated by `emit_reduce_action()`). If this is a fallible action,
-//            // it may return `Some(Err)`, and if this is a reduce of the start NT,
-//            // it may return `Some(Ok)`.
-//            states.pop(); // however many times
-//            symbols.pop(); // however many times
-//            let data = action_fn0(...);
-//            symbols.push((l, Value::VariantForNonterminalN(data), r));
-//            N
-//        }
+//        0 => match reduce_N_0(lookahead_start, states, symbols) {
+//            Ok(nonterminal) => nonterminal,
+//            Err(r) => return Some(r),
+//        },
 //        ...
 //     };
 //     let state = *states.last().unwrap();
@@ -175,6 +293,23 @@ pub fn compile<'grammar, W: Write>(grammar: &'grammar Grammar,
 //     None
 // }
 //
+// // generated by `emit_reduce_action()`, one per production, named
+// // after the nonterminal and the index of the production among its
+// // alternatives, so that they can be told apart in stack traces
+// fn reduce_N_0(lookahead_start: Option<&L>,
+//               states: &mut Vec<i32>, symbols: &mut Vec<(L, Symbol, L))
+//               -> Result<usize, Result<..>> {
+//     // Execute reduce action 0 to produce nonterminal N, popping from
+//     // stacks etc. If this is a fallible action, it may return
+//     // `Err(Err)`, and if this is a reduce of a start NT, it may
+//     // return `Err(Ok)`.
+//     states.pop(); // however many times
+//     symbols.pop(); // however many times
+//     let data = action_fn0(...);
+//     symbols.push((l, Value::VariantForNonterminalN(data), r));
+//     Ok(N)
+// }
+//
 // generated by `write_error_recovery_fn`
 // fn error_recovery(...) {
 //     let mut dropped_tokens = vec![];
@@ -241,7 +376,7 @@ pub fn compile<'grammar, W: Write>(grammar: &'grammar Grammar,
 //     let recover_state = states[top];
 //     let error_state = ACTION[recover_state * ACTIONS_PER_STATE + 1] - 1;
 //     states.push(error_state);
-//     let recovery = ErrorRecovery { dropped_tokens, ... };
+//     let recovery = ErrorRecovery { dropped_tokens, state: recover_state, ... };
 //     symbols.push((start, Symbol::Termerror(recovery), end));
 //     Ok(None)
 // }
@@ -252,6 +387,7 @@ enum Comment<'a, T> {
     Goto(T, usize),
     Error(T),
     Reduce(T, &'a Production),
+    NoDefault,
 }
 
 impl<'a, T: fmt::Display> fmt::Display for Comment<'a, T> {
@@ -262,12 +398,27 @@ impl<'a, T: fmt::Display> fmt::Display for Comment<'a, T> {
             Comment::Error(ref token) =>
                 write!(f, " // on {}, error", token),
             Comment::Reduce(ref token, production) =>
-                write!(f, " // on {}, reduce `{:?}`", token, production)
+                write!(f, " // on {}, reduce `{:?}`", token, production),
+            Comment::NoDefault =>
+                write!(f, " // no default reduction"),
         }
     }
 }
 
+/// A public nonterminal whose parser is in the tables.
+struct Start {
+    user_start_symbol: NonterminalString,
+    start_symbol: NonterminalString,
+
+    /// the indices of the states of its parser, the first of which
+    /// is its start state
+    states: Range<usize>,
+}
+
 struct TableDriven<'grammar> {
+    /// the public nonterminals, in the order of their states
+    starts: Vec<Start>,
+
     /// type parameters for the `Nonterminal` type
     symbol_type_params: Vec<TypeParameter>,
 
@@ -277,13 +428,91 @@ struct TableDriven<'grammar> {
     all_nonterminals: Vec<NonterminalString>,
 
     reduce_indices: Map<&'grammar Production, usize>,
+
+    /// for each state, the production it reduces regardless of the
+    /// lookahead, if any (see `default_reduction`)
+    default_reductions: Vec<Option<&'grammar Production>>,
+
+    /// element type of the `ACTION`, `EOF_ACTION` and `DEFAULT_ACTION`
+    /// tables
+    action_type: &'static str,
+
+    /// element type of the `GOTO` table
+    goto_type: &'static str,
+
+    /// how often each reduce action ran, by its action code, in the
+    /// profile that the parser is laid out by
+    reduction_counts: Map<usize, u64>,
+}
+
+impl<'grammar> TableDriven<'grammar> {
+    fn uses_default_reductions(&self) -> bool {
+        self.default_reductions.iter().any(|r| r.is_some())
+    }
+}
+
+/// The smallest signed integer type that can hold `-max..=max`.
+fn signed_table_type(max: usize) -> &'static str {
+    if max <= i8::max_value() as usize {
+        "i8"
+    } else if max <= i16::max_value() as usize {
+        "i16"
+    } else {
+        "i32"
+    }
+}
+
+/// The smallest unsigned integer type that can hold `0..=max`.
+fn unsigned_table_type(max: usize) -> &'static str {
+    if max <= u8::max_value() as usize {
+        "u8"
+    } else if max <= u16::max_value() as usize {
+        "u16"
+    } else {
+        "u32"
+    }
+}
+
+/// The production that `state` reduces no matter what the next token
+/// is, if the parser can safely do so without looking at the next
+/// token. We leave out empty productions (whose location is taken
+/// from the next token), fallible actions (whose errors would then
+/// preempt a syntax error in the next token) and the final reduction
+/// of a start symbol (which is only valid at EOF).
+fn default_reduction<'grammar>(grammar: &'grammar Grammar,
+                               state: &LR1State<'grammar>)
+                               -> Option<&'grammar Production> {
+    if !state.shifts.is_empty() || !state.two_token_reductions.is_empty() {
+        return None;
+    }
+
+    let production = match state.reductions.first() {
+        Some(&(_, production)) => production,
+        None => return None,
+    };
+
+    if state.reductions.iter().any(|&(_, p)| p != production) ||
+        production.symbols.is_empty() ||
+        is_start_symbol(grammar, production.nonterminal) ||
+        grammar.action_is_fallible(production.action) {
+        return None;
+    }
+
+    Some(production)
+}
+
+/// True if `nonterminal` is the start symbol of one of the parsers,
+/// whose reduction completes the parse.
+fn is_start_symbol(grammar: &Grammar, nonterminal: NonterminalString) -> bool {
+    grammar.start_nonterminals.values().any(|&nt| nt == nonterminal)
 }
 
 impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDriven<'grammar>> {
-    fn new_table_driven(grammar: &'grammar Grammar,
-                        user_start_symbol: NonterminalString,
-                        start_symbol: NonterminalString,
+    fn new_table_driven(session: &'ascent Session,
+                        grammar: &'grammar Grammar,
+                        starts: Vec<Start>,
                         states: &'ascent [LR1State<'grammar>],
+                        reduction_counts: Map<usize, u64>,
                         action_module: &str,
                         out: &'ascent mut RustWrite<W>)
                         -> Self {
@@ -331,7 +560,32 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
                                                                       .zip(0..)
                                                                       .collect();
 
-        CodeGenerator::new(grammar,
+        // Default reductions move the point where a syntax error is
+        // detected past some reductions, which would change which
+        // states error recovery can resume from (and error repair can
+        // insert tokens in); so don't use them together. Parsers with
+        // a fixed-size stack do without them too, as reporting errors
+        // after them takes a list of the states they were done in.
+        let default_reductions = states.iter()
+                                       .map(|state| if grammar.uses_error_recovery ||
+                                                       grammar.repair || session.fixed_stack {
+                                           None
+                                       } else {
+                                           default_reduction(grammar, state)
+                                       })
+                                       .collect();
+
+        // Shifts are encoded as `state + 1` and reductions as
+        // `-(index + 1)`, so pick table types wide enough for both.
+        let action_type = signed_table_type(::std::cmp::max(states.len(), reduce_indices.len()));
+        let goto_type = unsigned_table_type(states.len());
+
+        // (the parser fns of each start symbol are written with it
+        // as the current one; see `write`)
+        let (user_start_symbol, start_symbol) = (starts[0].user_start_symbol,
+                                                 starts[0].start_symbol);
+        CodeGenerator::new(session,
+                           grammar,
                            user_start_symbol,
                            start_symbol,
                            states,
@@ -339,6 +593,7 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
                            false,
                            action_module,
                            TableDriven {
+                               starts: starts,
                                symbol_type_params: symbol_type_params,
                                symbol_where_clauses: symbol_where_clauses,
                                all_nonterminals: grammar.nonterminals
@@ -346,16 +601,31 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
                                                         .cloned()
                                                         .collect(),
                                reduce_indices: reduce_indices,
+                               default_reductions: default_reductions,
+                               action_type: action_type,
+                               goto_type: goto_type,
+                               reduction_counts: reduction_counts,
                            })
     }
 
     fn write(&mut self) -> io::Result<()> {
-        self.write_parse_mod(|this| {
+        let name = format!("{}parse_table", self.prefix);
+        self.write_mod(&name, |this| {
             try!(this.write_value_type_defn());
             try!(this.write_parse_table());
-            try!(this.write_parser_fn());
+            try!(this.write_profile_counters());
+            for index in 0..this.custom.starts.len() {
+                this.user_start_symbol = this.custom.starts[index].user_start_symbol;
+                this.start_symbol = this.custom.starts[index].start_symbol;
+                try!(this.write_start_fns());
+            }
+            try!(this.write_parse_loop_fn());
+            if this.grammar.repair {
+                try!(this.write_repair_automaton());
+            }
             try!(this.write_error_recovery_fn());
             try!(this.write_accepts_fn());
+            try!(this.write_nesting_fn());
             try!(this.emit_reduce_actions());
             try!(this.emit_downcast_fns());
             Ok(())
@@ -391,18 +661,67 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
             rust!(self.out, "{}({}),", name, ty);
         }
         rust!(self.out, "}}");
+
+        try!(self.write_value_type_debug_impl());
+        Ok(())
+    }
+
+    /// Implements `Debug` for the symbols by showing the terminal or
+    /// nonterminal they are for (their values need not be `Debug`).
+    fn write_value_type_debug_impl(&mut self) -> io::Result<()> {
+        rust!(self.out,
+              "impl<{params}> {c}::fmt::Debug for {}Symbol<{params}>",
+              self.prefix,
+              c = self.core_crate(),
+              params = Sep(", ", &self.custom.symbol_type_params));
+        if !self.custom.symbol_where_clauses.is_empty() {
+            rust!(self.out, " where {}", Sep(", ", &self.custom.symbol_where_clauses));
+        }
+        rust!(self.out, "{{");
+        rust!(self.out,
+              "fn fmt(&self, {}f: &mut {c}::fmt::Formatter) -> {c}::fmt::Result {{",
+              self.prefix,
+              c = self.core_crate());
+        rust!(self.out, "match *self {{");
+        let symbols: Vec<Symbol> = self.grammar
+                                       .terminals
+                                       .all
+                                       .iter()
+                                       .map(|&term| Symbol::Terminal(term))
+                                       .chain(self.grammar
+                                                  .nonterminals
+                                                  .keys()
+                                                  .map(|&nt| Symbol::Nonterminal(nt)))
+                                       .collect();
+        for symbol in symbols {
+            let name = self.variant_name_for_symbol(symbol);
+            rust!(self.out,
+                  "{}Symbol::{}(..) => {}f.write_str({:?}),",
+                  self.prefix,
+                  name,
+                  self.prefix,
+                  symbol.to_string());
+        }
+        rust!(self.out, "}}");
+        rust!(self.out, "}}");
+        rust!(self.out, "}}");
         Ok(())
     }
 
     fn write_parse_table(&mut self) -> io::Result<()> {
+        let num_states = self.states.len();
+        let action_type = self.custom.action_type;
+
         // The table is a two-dimensional matrix indexed first by state
         // and then by the terminal index. The value is described above.
-        rust!(self.out, "const {}ACTION: &'static [i32] = &[", self.prefix);
+        try!(self.start_table("ACTION",
+                              action_type,
+                              num_states * self.grammar.terminals.all.len()));
 
         for (index, state) in self.states.iter().enumerate() {
             rust!(self.out, "// State {}", index);
 
-            if Tls::session().emit_comments {
+            if self.session.emit_comments {
                 for item in state.items.vec.iter() {
                     rust!(self.out, "//     {:?}", item);
                 }
@@ -417,24 +736,23 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
                     Self::write_reduction(custom, state, Token::Terminal(*terminal))
                 }
             });
-            try!(self.out.write_table_row(iterator))
+            try!(self.out.write_table_row(self.session.emit_comments, iterator))
         }
 
         rust!(self.out, "];");
 
         // Actions on EOF. Indexed just by state.
-        rust!(self.out,
-              "const {}EOF_ACTION: &'static [i32] = &[",
-              self.prefix);
+        try!(self.start_table("EOF_ACTION", action_type, num_states));
         for (index, state) in self.states.iter().enumerate() {
             rust!(self.out, "// State {}", index);
             let reduction = Self::write_reduction(&self.custom, state, Token::EOF);
-            try!(self.out.write_table_row(Some(reduction)));
+            try!(self.out.write_table_row(self.session.emit_comments, Some(reduction)));
         }
         rust!(self.out, "];");
 
         // The goto table is indexed by state and *nonterminal*.
-        rust!(self.out, "const {}GOTO: &'static [i32] = &[", self.prefix);
+        let goto_type = self.custom.goto_type;
+        try!(self.start_table("GOTO", goto_type, num_states * self.grammar.nonterminals.len()));
         for (index, state) in self.states.iter().enumerate() {
             rust!(self.out, "// State {}", index);
             let iterator = self.grammar.nonterminals.keys().map(|nonterminal| {
@@ -444,20 +762,59 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
                     (0, Comment::Error(*nonterminal))
                 }
             });
-            try!(self.out.write_table_row(iterator));
+            try!(self.out.write_table_row(self.session.emit_comments, iterator));
         }
         rust!(self.out, "];");
 
+        if self.custom.uses_default_reductions() {
+            try!(self.start_table("DEFAULT_ACTION", action_type, num_states));
+            for (index, reduction) in self.custom.default_reductions.iter().enumerate() {
+                rust!(self.out, "// State {}", index);
+                let entry = match *reduction {
+                    Some(production) => {
+                        let action = self.custom.reduce_indices[production];
+                        (-(action as i32 + 1), Comment::Reduce("any token", production))
+                    }
+                    None => (0, Comment::NoDefault),
+                };
+                try!(self.out.write_table_row(self.session.emit_comments, Some(entry)));
+            }
+            rust!(self.out, "];");
+        }
+
         try!(self.emit_expected_tokens_fn());
 
         Ok(())
     }
 
+    /// Starts the definition of the table `{p}name`, which has `len`
+    /// entries of type `ty`: a `const` slice or, if the tables go in
+    /// a link section, a `static` array, since only statics can be
+    /// placed in a section.
+    fn start_table(&mut self, name: &str, ty: &str, len: usize) -> io::Result<()> {
+        match self.session.table_section {
+            Some(ref section) => {
+                rust!(self.out, "#[link_section = {:?}]", section);
+                rust!(self.out, "static {}{}: [{}; {}] = [", self.prefix, name, ty, len);
+            }
+            None => {
+                rust!(self.out, "const {}{}: &'static [{}] = &[", self.prefix, name, ty);
+            }
+        }
+        Ok(())
+    }
+
     fn write_reduction<'s>(custom: &TableDriven<'grammar>, state: &'s LR1State, token: Token) -> (i32, Comment<'s, Token>) {
+        // (where the token after `token` decides, the first reduction
+        // is the default; see `TwoTokenReduction`)
         let reduction = state.reductions
                              .iter()
                              .filter(|&&(ref t, _)| t.contains(token))
                              .map(|&(_, p)| p)
+                             .chain(state.two_token_reductions
+                                         .iter()
+                                         .filter(|r| Token::Terminal(r.terminal) == token)
+                                         .map(|r| r.production))
                              .next();
         if let Some(production) = reduction {
             let action = custom.reduce_indices[production];
@@ -468,22 +825,707 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
         }
     }
 
+    /// Writes the public parser fns of the current start symbol, and
+    /// its parser struct, which hand its start state to the shared
+    /// parse loop.
+    fn write_start_fns(&mut self) -> io::Result<()> {
+        try!(self.write_profile_fn());
+        try!(self.write_parser_fn());
+        if self.session.token_arrays {
+            try!(self.write_token_array_parser_fn(false));
+            try!(self.write_token_array_parser_fn(true));
+        }
+        if self.grammar.repair {
+            try!(self.write_repaired_parser_fn(false));
+            try!(self.write_repaired_parser_fn(true));
+        }
+        if !self.session.fixed_stack {
+            try!(self.write_parser_struct());
+        }
+        Ok(())
+    }
+
+    /// The public nonterminal whose parser fns are being written.
+    fn current_start(&self) -> &Start {
+        self.custom
+            .starts
+            .iter()
+            .find(|start| start.start_symbol == self.start_symbol)
+            .unwrap()
+    }
+
+    /// Gates the item that follows on the `cfg` predicate of the
+    /// parser of the current start symbol, if it has one (see
+    /// `Configuration::parser_cfg`); the rest of the module serves the
+    /// other parsers as well.
+    fn write_parser_cfg(&mut self) -> io::Result<()> {
+        let session = self.session;
+        if let Some(predicate) = session.parser_cfgs.get(&self.user_start_symbol.to_string()) {
+            rust!(self.out, "#[cfg({})]", predicate);
+        }
+        Ok(())
+    }
+
+    /// Writes the public `parse_Foo` function. This is only a thin
+    /// shim: it converts whatever token iterator the user supplied
+    /// into a `&mut Iterator` trait object and hands it off to the
+    /// `__parse` function, which contains the actual parse loop. This
+    /// way the (large) parse loop is instantiated once per grammar,
+    /// rather than once per token iterator type that the user happens
+    /// to parse from, or per public nonterminal. It is followed by
+    /// `parse_Foo_cancelable`, which hands its cancellation flag to
+    /// `__parse` as well.
     fn write_parser_fn(&mut self) -> io::Result<()> {
-        let phantom_data_expr = self.phantom_data_expr();
+        for &cancelable in &[false, true] {
+            try!(self.write_parser_cfg());
+            try!(self.start_cancelable_parser_fn(cancelable));
+
+            try!(self.define_tokens());
+            try!(self.define_stacks());
+            try!(self.call_parse_loop_fn("&mut {p}states",
+                                         "&mut {p}symbols",
+                                         Self::cancel_arg(cancelable)));
+
+            try!(self.end_parser_fn());
+        }
+        Ok(())
+    }
+
+    /// The cancellation flag that a public parser fn passes to
+    /// `__parse`: its own, if it is `cancelable`.
+    fn cancel_arg(cancelable: bool) -> &'static str {
+        if cancelable {
+            "Some({p}cancel)"
+        } else {
+            "None"
+        }
+    }
+
+    /// Defines the state and symbol stacks of a parser fn: in the
+    /// buffer a parser with a fixed-size stack is given, or else on
+    /// the heap.
+    fn define_stacks(&mut self) -> io::Result<()> {
+        if self.session.fixed_stack {
+            rust!(self.out,
+                  "let (mut {p}states, mut {p}symbols) = \
+                   {p}lalrpop_util::stack::split_buffer({p}stack);",
+                  p = self.prefix);
+        } else {
+            rust!(self.out, "let mut {}states = vec![];", self.prefix);
+            rust!(self.out, "let mut {}symbols = vec![];", self.prefix);
+        }
+        Ok(())
+    }
+
+    /// With token arrays, writes `parse_Foo_tokens`, which parses the
+    /// tokens of the input from an array that `tokenize` filled in
+    /// (see `lalrpop_util::tokens`):
+    ///
+    /// ```ignore
+    /// pub fn parse_Foo_tokens<'input>(input: &'input str, tokens: &TokenArray)
+    ///     -> Result<Foo, ParseError<usize, Token<'input>, &'static str>>
+    /// ```
+    ///
+    /// or, if `cancelable` is true, `parse_Foo_tokens_cancelable`, which
+    /// also takes a cancellation flag.
+    fn write_token_array_parser_fn(&mut self, cancelable: bool) -> io::Result<()> {
+        let parse_error_type = self.types.parse_error_type();
+        let result_type = format!("Result<{}, {}>",
+                                  self.types.nonterminal_type(self.start_symbol),
+                                  parse_error_type);
+        let mut parameters = vec![format!("{}token_array: &{}lalrpop_util::tokens::TokenArray",
+                                          self.prefix,
+                                          self.prefix)];
+        let mut suffix = if self.session.fixed_stack {
+            parameters.push(format!("{}stack: &mut [u8]", self.prefix));
+            String::from("_with_stack")
+        } else {
+            String::new()
+        };
+        if cancelable {
+            parameters.push(self.cancel_parameter());
+            suffix.push_str("_cancelable");
+        }
+        try!(self.write_parser_cfg());
+        rust!(self.out, "#[allow(dead_code)]");
+        try!(self.out.write_pub_fn_header(self.grammar,
+                                          format!("parse_{}_tokens{}",
+                                                  self.user_start_symbol,
+                                                  suffix),
+                                          vec![],
+                                          parameters,
+                                          result_type.clone(),
+                                          vec![]));
+        rust!(self.out, "{{");
+        try!(self.start_reserved_words(&result_type));
+        rust!(self.out,
+              "let mut {p}tokens = (0..{p}token_array.len()).map(|{p}i| {{",
+              p = self.prefix);
+        rust!(self.out,
+              "let ({p}start, {p}end) = {p}token_array.span({p}i);",
+              p = self.prefix);
+        if self.session.panic_free {
+            // an array of the tokens of some other input may not fit
+            rust!(self.out,
+                  "if {p}start > {p}end || !input.is_char_boundary({p}start) || \
+                   !input.is_char_boundary({p}end) {{",
+                  p = self.prefix);
+            rust!(self.out,
+                  "return Err({});",
+                  self.internal_error("token array does not fit the input"));
+            rust!(self.out, "}}");
+        }
+        // the array holds byte offsets, converted like those of the
+        // tokenizer if the grammar declares a `Location` type
+        let token_error_type = if self.grammar.custom_locations() {
+            format!("{}lalrpop_util::ParseError<usize, {}, {}>",
+                    self.prefix,
+                    self.types.terminal_token_type(),
+                    self.types.error_type())
+        } else {
+            parse_error_type.to_string()
+        };
+        rust!(self.out,
+              "Ok::<_, {}>(({p}start, Token({p}token_array.kind({p}i), \
+               &input[{p}start..{p}end]), {p}end))",
+              token_error_type,
+              p = self.prefix);
+        rust!(self.out, "}});");
+        try!(self.convert_locations());
+        try!(self.define_stacks());
+        try!(self.call_parse_loop_fn("&mut {p}states",
+                                     "&mut {p}symbols",
+                                     Self::cancel_arg(cancelable)));
+        self.end_parser_fn()
+    }
+
+    /// The type of a state or symbol stack of `element`s: a `Vec`,
+    /// or a `FixedStack` in the buffer given to a parser with a
+    /// fixed-size stack.
+    fn stack_type(&self, element: &str) -> String {
+        if self.session.fixed_stack {
+            format!("{}lalrpop_util::stack::FixedStack<{}>", self.prefix, element)
+        } else {
+            format!("::std::vec::Vec<{}>", element)
+        }
+    }
+
+    /// For a parser with a fixed-size stack, emits code that runs
+    /// `on_full` (with `{}` standing in for a
+    /// `ParseError::StackCapacity` at `location`) if the symbol stack
+    /// is full, before another symbol is pushed on it.
+    fn check_stack_capacity(&mut self, location: &str, on_full: &str) -> io::Result<()> {
+        if !self.session.fixed_stack {
+            return Ok(());
+        }
+        let error = format!("{p}lalrpop_util::ParseError::StackCapacity {{ \
+                             location: {}, capacity: {p}symbols.capacity() }}",
+                            location,
+                            p = self.prefix);
+        rust!(self.out,
+              "if {p}symbols.len() >= {p}symbols.capacity() {{",
+              p = self.prefix);
+        rust!(self.out, "{};", on_full.replace("{}", &error));
+        rust!(self.out, "}}");
+        Ok(())
+    }
+
+    /// Emits a call to the `__parse` function, given expressions for
+    /// the state and symbol stacks it should use and its cancellation
+    /// flag (with `{p}` standing in for the prefix).
+    fn call_parse_loop_fn(&mut self, states: &str, symbols: &str, cancel: &str) -> io::Result<()> {
+        let call = self.parse_loop_call(states, symbols, cancel);
+        rust!(self.out, "{}", call);
+        Ok(())
+    }
+
+    /// The call emitted by `call_parse_loop_fn`, as an expression: it
+    /// starts from the start state of the current start symbol, and
+    /// takes its value out of the symbol that `__parse` returns.
+    fn parse_loop_call(&self, states: &str, symbols: &str, cancel: &str) -> String {
+        let start = self.current_start();
+        let mismatch = if self.session.panic_free {
+            format!("Err({})", self.internal_error("symbol type mismatch"))
+        } else {
+            format!("unreachable!()")
+        };
+        format!("{p}parse::<{tps}>({upr}{start_state}, &mut {p}tokens, {states}, {symbols}, \
+                 {cancel}).and_then(|{p}v| match {p}v {{ \
+                 {p}Symbol::{variant}({p}v) => Ok({p}v), _ => {mismatch} }})",
+                tps = Sep(", ", &self.grammar.non_lifetime_type_parameters()),
+                upr = self.grammar.user_parameter_refs(),
+                start_state = start.states.start,
+                states = states.replace("{p}", self.prefix),
+                symbols = symbols.replace("{p}", self.prefix),
+                cancel = cancel.replace("{p}", self.prefix),
+                variant = self.variant_name_for_symbol(Symbol::Nonterminal(self.start_symbol)),
+                mismatch = mismatch,
+                p = self.prefix)
+    }
 
-        try!(self.start_parser_fn());
+    /// Writes `parse_Foo_repaired`, for grammars marked `#![repair]`.
+    /// It collects the tokens and parses them; whenever that fails
+    /// on an unrecognized token, it searches for repairs with
+    /// `lalrpop_util::repair`, records the error along with them,
+    /// and parses the tokens again with the first repair applied.
+    /// (Tokens are only made up for the terminals that
+    /// `write_repair_automaton` knows how to insert.) It returns the
+    /// result of the last parse, if it succeeded, and the errors:
+    ///
+    /// ```ignore
+    /// pub fn parse_Foo_repaired<'input>(input: &'input str)
+    ///     -> (Option<Foo>, Vec<Repaired<usize, Token<'input>, &'static str>>)
+    /// ```
+    ///
+    /// or, if `cancelable` is true, `parse_Foo_repaired_cancelable`,
+    /// which also takes a cancellation flag.
+    fn write_repaired_parser_fn(&mut self, cancelable: bool) -> io::Result<()> {
+        let repaired_type = format!("{}lalrpop_util::repair::Repaired<{}, {}, {}>",
+                                    self.prefix,
+                                    self.types.terminal_loc_type(),
+                                    self.types.terminal_token_type(),
+                                    self.types.error_type());
+        let (type_parameters, mut parameters, where_clauses) = self.parser_fn_inputs();
+        let suffix = if cancelable {
+            parameters.push(self.cancel_parameter());
+            "_cancelable"
+        } else {
+            ""
+        };
 
+        try!(self.write_parser_cfg());
+        rust!(self.out, "#[allow(dead_code)]");
+        try!(self.out.write_pub_fn_header(self.grammar,
+                                          format!("parse_{}_repaired{}",
+                                                  self.user_start_symbol,
+                                                  suffix),
+                                          type_parameters,
+                                          parameters,
+                                          format!("(Option<{}>, ::std::vec::Vec<{}>)",
+                                                  self.types.nonterminal_type(self.start_symbol),
+                                                  repaired_type),
+                                          where_clauses));
+        rust!(self.out, "{{");
         try!(self.define_tokens());
+        rust!(self.out, "let mut {}triples = vec![];", self.prefix);
+        rust!(self.out, "let mut {}integers = vec![];", self.prefix);
+        rust!(self.out, "let mut {}errors = vec![];", self.prefix);
+        rust!(self.out, "for {p}token in {p}tokens {{", p = self.prefix);
+        rust!(self.out, "let {}lookahead = match {}token {{", self.prefix, self.prefix);
+        rust!(self.out, "Ok(v) => v,");
+        rust!(self.out, "Err(e) => {{");
+        let error = if self.grammar.intern_token.is_some() {
+            "e".to_string()
+        } else {
+            format!("{}lalrpop_util::ParseError::User {{ error: e }}", self.prefix)
+        };
+        rust!(self.out,
+              "{p}errors.push({p}lalrpop_util::repair::Repaired {{ error: {}, repairs: vec![] }});",
+              error,
+              p = self.prefix);
+        rust!(self.out, "return (None, {}errors);", self.prefix);
+        rust!(self.out, "}}");
+        rust!(self.out, "}};");
+        rust!(self.out, "let {}integer = match {}lookahead.1 {{", self.prefix, self.prefix);
+        for (&terminal, index) in self.grammar.terminals.all.iter().zip(0..) {
+            if terminal == TerminalString::Error {
+                continue;
+            }
+            try!(self.write_terminal_arm(terminal, index));
+        }
 
-        // State and data stack.
-        rust!(self.out, "let mut {}states = vec![0_i32];", self.prefix);
+        // an extern token that no terminal matches, which there is no
+        // repairing (the tokens that the parser expected there are
+        // unknown, since it has not got that far yet)
+        rust!(self.out, "_ => {{");
+        rust!(self.out,
+              "{p}errors.push({p}lalrpop_util::repair::Repaired {{ \
+               error: {p}lalrpop_util::ParseError::UnrecognizedToken {{ \
+               token: Some({p}lookahead), expected: vec![] }}, repairs: vec![] }});",
+              p = self.prefix);
+        rust!(self.out, "return (None, {}errors);", self.prefix);
+        rust!(self.out, "}}");
+        rust!(self.out, "}};");
+        rust!(self.out, "{p}triples.push({p}lookahead);", p = self.prefix);
+        rust!(self.out, "{p}integers.push({p}integer);", p = self.prefix);
+        rust!(self.out, "}}");
+
+        rust!(self.out, "loop {{");
+        rust!(self.out, "let {}consumed = ::std::cell::Cell::new(0);", self.prefix);
+        rust!(self.out, "let mut {}states = vec![];", self.prefix);
         rust!(self.out, "let mut {}symbols = vec![];", self.prefix);
+        rust!(self.out, "let {}result = {{", self.prefix);
+        rust!(self.out,
+              "let mut {p}tokens = {p}triples.iter().cloned()\
+               .inspect(|_| {p}consumed.set({p}consumed.get() + 1))\
+               .map(Ok::<_, {}>);",
+              self.tokens_error_type(),
+              p = self.prefix);
+        try!(self.call_parse_loop_fn("&mut {p}states",
+                                     "&mut {p}symbols",
+                                     Self::cancel_arg(cancelable)));
+        rust!(self.out, "}};");
+        rust!(self.out, "let {p}error = match {p}result {{", p = self.prefix);
+        rust!(self.out, "Ok(v) => return (Some(v), {}errors),", self.prefix);
+        rust!(self.out, "Err(e) => e,");
+        rust!(self.out, "}};");
+
+        // the index of the token the parser stopped at
+        rust!(self.out, "let {p}index = match {p}error {{", p = self.prefix);
+        rust!(self.out,
+              "{p}lalrpop_util::ParseError::UnrecognizedToken {{ token: Some(_), .. }} | \
+               {p}lalrpop_util::ParseError::ExtraToken {{ .. }} => \
+               {p}consumed.get() - 1,",
+              p = self.prefix);
+        rust!(self.out,
+              "{p}lalrpop_util::ParseError::UnrecognizedToken {{ token: None, .. }} | \
+               {p}lalrpop_util::ParseError::UnrecognizedEof {{ .. }} => \
+               {p}triples.len(),",
+              p = self.prefix);
+        rust!(self.out, "_ => {{");
+        rust!(self.out,
+              "{p}errors.push({p}lalrpop_util::repair::Repaired {{ error: {p}error, \
+               repairs: vec![] }});",
+              p = self.prefix);
+        rust!(self.out, "return (None, {}errors);", self.prefix);
+        rust!(self.out, "}}");
+        rust!(self.out, "}};");
+
+        rust!(self.out,
+              "let {p}steps = {p}lalrpop_util::repair::repairs(&{p}Automaton, &{p}states, \
+               &{p}integers[{p}index..], Default::default());",
+              p = self.prefix);
+        rust!(self.out,
+              "let {p}repairs = {p}steps.iter().map(|{p}s| {p}lalrpop_util::repair::report(\
+               {p}s, {p}TERMINALS, &{p}triples[{p}index..])).collect();",
+              p = self.prefix);
+        rust!(self.out, "let {p}applied = match {p}steps.first() {{", p = self.prefix);
+        rust!(self.out,
+              "Some({p}s) => {p}lalrpop_util::repair::apply({p}s, &mut {p}triples, \
+               &mut {p}integers, {p}index, {p}insert_token),",
+              p = self.prefix);
+        rust!(self.out, "None => false,");
+        rust!(self.out, "}};");
+        rust!(self.out,
+              "{p}errors.push({p}lalrpop_util::repair::Repaired {{ error: {p}error, \
+               repairs: {p}repairs }});",
+              p = self.prefix);
+        rust!(self.out, "if !{}applied {{", self.prefix);
+        rust!(self.out, "return (None, {}errors);", self.prefix);
+        rust!(self.out, "}}");
+        rust!(self.out, "}}"); // loop
+        rust!(self.out, "}}");
+        Ok(())
+    }
+
+    /// Writes what the repair search needs to know about the grammar:
+    /// the names of the terminals, the `Automaton` that wraps the
+    /// parse tables, and `__insert_token`, which makes up the token
+    /// for a literal terminal of the built-in tokenizer, or for a
+    /// terminal of an extern token enum whose pattern matches a single
+    /// value, like `Tok::Plus` (but not `Tok::Num(<i32>)`).
+    fn write_repair_automaton(&mut self) -> io::Result<()> {
+        rust!(self.out, "const {}TERMINALS: &'static [&'static str] = &[", self.prefix);
+        for &terminal in &self.grammar.terminals.all {
+            rust!(self.out, "r###\"{}\"###,", terminal);
+        }
+        rust!(self.out, "];");
+
+        let num_terminals = self.grammar.terminals.all.len();
+        rust!(self.out, "struct {}Automaton;", self.prefix);
+        rust!(self.out,
+              "impl {p}lalrpop_util::repair::Automaton for {p}Automaton {{",
+              p = self.prefix);
+        rust!(self.out, "fn terminals(&self) -> usize {{ {} }}", num_terminals);
+        rust!(self.out,
+              "fn action(&self, {p}state: i32, {p}terminal: Option<usize>) -> i32 {{",
+              p = self.prefix);
+        rust!(self.out, "match {}terminal {{", self.prefix);
+        rust!(self.out,
+              "Some({p}t) => {p}ACTION[{p}state as usize * {} + {p}t] as i32,",
+              num_terminals,
+              p = self.prefix);
+        rust!(self.out,
+              "None => {p}EOF_ACTION[{p}state as usize] as i32,",
+              p = self.prefix);
+        rust!(self.out, "}}");
+        rust!(self.out, "}}");
+        rust!(self.out,
+              "fn production(&self, {}production: usize) -> Option<(usize, usize)> {{",
+              self.prefix);
+        rust!(self.out, "match {}production {{", self.prefix);
+        for (production, index) in self.grammar
+                                       .nonterminals
+                                       .values()
+                                       .flat_map(|nt| &nt.productions)
+                                       .zip(0..) {
+            if is_start_symbol(self.grammar, production.nonterminal) {
+                rust!(self.out, "{} => None,", index);
+            } else {
+                let nt = self.custom
+                             .all_nonterminals
+                             .iter()
+                             .position(|&x| x == production.nonterminal)
+                             .unwrap();
+                rust!(self.out, "{} => Some(({}, {})),", index, production.symbols.len(), nt);
+            }
+        }
+        rust!(self.out,
+              "_ => panic!(\"invalid production {{}}\", {}production),",
+              self.prefix);
+        rust!(self.out, "}}");
+        rust!(self.out, "}}");
+        rust!(self.out,
+              "fn goto(&self, {p}state: i32, {p}nonterminal: usize) -> i32 {{",
+              p = self.prefix);
+        rust!(self.out,
+              "{p}GOTO[{p}state as usize * {} + {p}nonterminal] as i32 - 1",
+              self.grammar.nonterminals.len(),
+              p = self.prefix);
+        rust!(self.out, "}}");
+        rust!(self.out, "fn insertable(&self, {}terminal: usize) -> bool {{", self.prefix);
+        rust!(self.out, "{}insert_token({}terminal).is_some()", self.prefix, self.prefix);
+        rust!(self.out, "}}");
+        rust!(self.out, "}}"); // impl
+
+        rust!(self.out,
+              "fn {p}insert_token<'input>({p}terminal: usize) -> Option<{}> {{",
+              self.types.terminal_token_type(),
+              p = self.prefix);
+        rust!(self.out, "match {}terminal {{", self.prefix);
+        for (&terminal, index) in self.grammar.terminals.all.iter().zip(0..) {
+            if self.grammar.intern_token.is_none() {
+                let pattern = self.grammar.pattern(terminal);
+                if pattern.is_expression() && self.grammar.conversion_guard(terminal).is_none() {
+                    rust!(self.out, "{} => Some({}),", index, pattern);
+                }
+            } else if let TerminalString::Literal(TerminalLiteral::Quoted(text)) = terminal {
+                let text = ::intern::read(|interner| format!("{:?}", interner.data(text)));
+                let token = self.grammar.pattern(terminal).map(&mut |_| text.clone());
+                rust!(self.out, "{} => Some({}),", index, token);
+            }
+        }
+        rust!(self.out, "_ => None,");
+        rust!(self.out, "}}");
+        rust!(self.out, "}}");
+        Ok(())
+    }
+
+    /// Writes the `FooParser` struct, which owns stacks that are
+    /// reused from one call to `parse_in_place` to the next, rather
+    /// than being allocated afresh on every parse:
+    ///
+    /// ```
+    /// pub struct FooParser {
+    ///     __states: Vec<i32>,
+    ///     __symbols: Recycled,
+    ///     __cancel: Option<Arc<AtomicBool>>,
+    /// }
+    ///
+    /// impl FooParser {
+    ///     pub fn new() -> FooParser { ... }
+    ///     pub fn set_cancel_flag(&mut self, flag: Arc<AtomicBool>) { ... }
+    ///     pub fn parse_in_place<'input, TOKENS>(&mut self, tokens: TOKENS) -> Result<...> { ... }
+    /// }
+    /// ```
+    ///
+    /// The symbols of a parse borrow from its input, so in between
+    /// parses the buffer of the (empty) symbol stack is kept as a
+    /// `lalrpop_util::stack::Recycled`, which does not know the type of
+    /// the symbols. This way the struct has no type or lifetime
+    /// parameters, and can be used to parse inputs that do not outlive
+    /// it; those are all declared on `parse_in_place`.
+    fn write_parser_struct(&mut self) -> io::Result<()> {
+        let struct_name = format!("{}Parser", self.user_start_symbol);
+
+        rust!(self.out, "");
+        try!(self.write_parser_cfg());
+        rust!(self.out, "#[allow(dead_code)]");
+        rust!(self.out, "pub struct {} {{", struct_name);
+        rust!(self.out, "{}states: ::std::vec::Vec<i32>,", self.prefix);
+        rust!(self.out, "{p}symbols: {p}lalrpop_util::stack::Recycled,", p = self.prefix);
+        rust!(self.out,
+              "{}cancel: Option<::std::sync::Arc<::std::sync::atomic::AtomicBool>>,",
+              self.prefix);
+        rust!(self.out, "}}");
+
+        rust!(self.out, "");
+        try!(self.write_parser_cfg());
+        rust!(self.out, "#[allow(dead_code)]");
+        rust!(self.out, "impl {} {{", struct_name);
+
+        rust!(self.out, "pub fn new() -> {} {{", struct_name);
+        rust!(self.out,
+              "{} {{ {p}states: vec![], {p}symbols: {p}lalrpop_util::stack::Recycled::new(), \
+               {p}cancel: None }}",
+              struct_name,
+              p = self.prefix);
+        rust!(self.out, "}}");
+
+        // Once `flag` is set, the parse under way (or the next one)
+        // fails with `ParseError::Canceled` as soon as it shifts or
+        // drops a token or reduces a production.
+        rust!(self.out, "");
+        rust!(self.out,
+              "pub fn set_cancel_flag(&mut self, flag: \
+               ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {{");
+        rust!(self.out, "self.{}cancel = Some(flag);", self.prefix);
+        rust!(self.out, "}}");
+
+        let (tokens_type_parameters, tokens_parameters, tokens_where_clauses) =
+            self.parser_fn_inputs();
+        let type_parameters: Vec<String> =
+            self.grammar.type_parameters
+                        .iter()
+                        .map(|tp| tp.to_string())
+                        .chain(tokens_type_parameters)
+                        .collect();
+        let parameters: Vec<String> =
+            self.grammar.parameters
+                        .iter()
+                        .map(|p| format!("{}: {}", p.name, p.ty))
+                        .chain(tokens_parameters)
+                        .collect();
+        let where_clauses: Vec<String> =
+            self.grammar.where_clauses
+                        .iter()
+                        .map(|wc| wc.to_string())
+                        .chain(tokens_where_clauses)
+                        .collect();
+
+        rust!(self.out, "");
+        rust!(self.out, "pub fn parse_in_place<{}>(", Sep(", ", &type_parameters));
+        rust!(self.out, "&mut self,");
+        for parameter in &parameters {
+            rust!(self.out, "{},", parameter);
+        }
+        rust!(self.out,
+              ") -> Result<{}, {}>",
+              self.types.nonterminal_type(self.start_symbol),
+              self.types.parse_error_type());
+        if !where_clauses.is_empty() {
+            rust!(self.out, "where {}", Sep(", ", &where_clauses));
+        }
+        rust!(self.out, "{{");
+        try!(self.define_tokens());
+        rust!(self.out, "let mut {p}symbols = self.{p}symbols.take();", p = self.prefix);
+        let call = self.parse_loop_call("&mut self.{p}states",
+                                        "&mut {p}symbols",
+                                        "self.{p}cancel.as_ref().map(|c| &**c)");
+        rust!(self.out, "let {}result = {};", self.prefix, call);
+        rust!(self.out, "self.{p}symbols.put({p}symbols);", p = self.prefix);
+        rust!(self.out, "{}result", self.prefix);
+        rust!(self.out, "}}");
+
+        rust!(self.out, "}}"); // impl
+        Ok(())
+    }
+
+    /// Returns the type of the token iterator trait object that the
+    /// parse loop consumes.
+    fn tokens_trait_object_type(&self) -> String {
+        format!("&mut Iterator<Item = Result<{}, {}>>",
+                self.types.triple_type(),
+                self.tokens_error_type())
+    }
+
+    /// The type of the errors in the token stream of the parser.
+    fn tokens_error_type(&self) -> TypeRepr {
+        // The tokenizer, when we supply it, returns parse
+        // errors. Otherwise, it returns custom user errors.
+        if self.grammar.intern_token.is_some() {
+            self.types.parse_error_type().clone()
+        } else {
+            self.types.error_type()
+        }
+    }
+
+    fn write_parse_loop_fn(&mut self) -> io::Result<()> {
+        let phantom_data_expr = self.phantom_data_expr();
+        let empty_stack = format!("return Err({})", self.internal_error("empty state stack"));
+        let invalid_state = format!("return Err({})", self.internal_error("invalid state"));
+
+        let parameters = vec![format!("{}start_state: i32", self.prefix),
+                              format!("mut {}tokens: {}",
+                                      self.prefix,
+                                      self.tokens_trait_object_type()),
+                              format!("mut {}states: &mut {}",
+                                      self.prefix,
+                                      self.stack_type("i32")),
+                              format!("mut {}symbols: &mut {}",
+                                      self.prefix,
+                                      self.stack_type(&self.spanned_symbol_type())),
+                              format!("{}cancel: Option<&{}::sync::atomic::AtomicBool>",
+                                      self.prefix,
+                                      self.core_crate())];
+        rust!(self.out, "");
+        try!(self.out.write_fn_header(self.grammar,
+                                      format!("{}parse", self.prefix),
+                                      vec![],
+                                      parameters,
+                                      format!("Result<{}, {}>",
+                                              self.symbol_type(),
+                                              self.types.parse_error_type()),
+                                      vec![]));
+        rust!(self.out, "{{");
+
+        // State and data stack. These may be left over from a
+        // previous parse, so reset them.
+        rust!(self.out, "{}states.clear();", self.prefix);
+        if self.session.fixed_stack {
+            // (a buffer too small for the start state)
+            rust!(self.out, "if {}states.capacity() == 0 {{", self.prefix);
+            rust!(self.out,
+                  "return Err({}lalrpop_util::ParseError::StackCapacity {{ \
+                   location: Default::default(), capacity: 0 }});",
+                  self.prefix);
+            rust!(self.out, "}}");
+        }
+        rust!(self.out, "{p}states.push({p}start_state);", p = self.prefix);
+        rust!(self.out, "{}symbols.clear();", self.prefix);
 
         rust!(self.out, "let mut {}integer;", self.prefix);
         rust!(self.out, "let mut {}lookahead;", self.prefix);
+        // The token after the lookahead, if the parser has had to peek
+        // at it; see `two_token_action`.
+        let two_tokens = self.states.iter().any(|state| !state.two_token_reductions.is_empty());
+        if two_tokens {
+            rust!(self.out, "let mut {}peeked = None;", self.prefix);
+        }
         // The location of the last token is necessary for for error recovery at EOF (or they would not have
         // a location)
-        rust!(self.out, "let {}last_location = &mut Default::default();", self.prefix);
+        rust!(self.out,
+              "let {}last_location: &mut {} = &mut Default::default();",
+              self.prefix,
+              self.types.terminal_loc_type());
+
+        // The stack slots whose states may have begun a nonterminal
+        // with a `#[max_nesting]`, with its index; see
+        // `write_nesting_fn`.
+        if !self.nesting_nonterminals().is_empty() {
+            rust!(self.out,
+                  "let mut {}nesting: ::std::vec::Vec<(usize, usize)> = ::std::vec::Vec::new();",
+                  self.prefix);
+        }
+
+        // The number of reductions so far, if they are limited; see
+        // `count_reduction`.
+        if self.session.max_reductions.is_some() {
+            rust!(self.out, "let {}reductions = &mut 0_usize;", self.prefix);
+        }
+
+        // The states in which we performed default reductions since
+        // the last shift; see `let_unrecognized_token_error`. Long
+        // chains of them are rare, so rather than allocate, we only
+        // remember the first few.
+        let default_reductions = self.custom.uses_default_reductions();
+        if default_reductions {
+            rust!(self.out,
+                  "let mut {}default_reduced = [0_usize; {}];",
+                  self.prefix,
+                  DEFAULT_REDUCED_LIMIT);
+            rust!(self.out, "let mut {}default_reduced_len = 0;", self.prefix);
+        }
 
         // Outer loop: each time we continue around this loop, we
         // shift a new token from the input. We break from the loop
@@ -491,11 +1533,52 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
         // error occurs).
         rust!(self.out, "'{}shift: loop {{", self.prefix);
 
+        if default_reductions {
+            rust!(self.out, "loop {{");
+            rust!(self.out, "let {}state = {};", self.prefix, self.top_state(&empty_stack));
+            rust!(self.out,
+                  "let {}action = {} as i32;",
+                  self.prefix,
+                  self.table_entry("DEFAULT_ACTION",
+                                   &format!("{}state", self.prefix),
+                                   &invalid_state));
+            rust!(self.out, "if {}action == 0 {{", self.prefix);
+            rust!(self.out, "break;");
+            rust!(self.out, "}}");
+            if self.session.emit_trace {
+                rust!(self.out, "println!(\"--> default reduce in state {{}}\", {}state);",
+                      self.prefix);
+            }
+            rust!(self.out,
+                  "if {}default_reduced_len < {} {{",
+                  self.prefix,
+                  DEFAULT_REDUCED_LIMIT);
+            rust!(self.out,
+                  "{p}default_reduced[{p}default_reduced_len] = {p}state;",
+                  p = self.prefix);
+            rust!(self.out, "{}default_reduced_len += 1;", self.prefix);
+            rust!(self.out, "}}");
+            try!(self.check_cancel(&format!("{}last_location.clone()", self.prefix)));
+            try!(self.count_reduction(&format!("{}last_location.clone()", self.prefix)));
+            rust!(self.out,
+                  "if let Some(r) = {}reduce({}{}action, None, &mut {}states, &mut {}symbols, {}) {{",
+                  self.prefix,
+                  self.grammar.user_parameter_refs(),
+                  self.prefix,
+                  self.prefix,
+                  self.prefix,
+                  phantom_data_expr);
+            rust!(self.out, "return r;");
+            rust!(self.out, "}}");
+            try!(self.check_nesting(&format!("{}last_location.clone()", self.prefix)));
+            rust!(self.out, "}}");
+        }
+
         // Read next token from input.
-        try!(self.next_token("lookahead", "tokens", "last_location", "shift"));
+        try!(self.next_token("lookahead", "tokens", "last_location", "shift", two_tokens));
         try!(self.token_to_integer("integer", "lookahead"));
 
-        if DEBUG_PRINT {
+        if self.session.emit_trace {
             rust!(self.out, "println!(\"pulled next token from input: {{:?}}\", \
                              {p}lookahead);",
                   p = self.prefix);
@@ -506,21 +1589,23 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
 
         // Loop.
         rust!(self.out, "'{}inner: loop {{", self.prefix);
-        rust!(self.out,
-              "let {}state = *{}states.last().unwrap() as usize;",
-              self.prefix,
-              self.prefix);
+        try!(self.assert_stacks_consistent());
+        rust!(self.out, "let {}state = {};", self.prefix, self.top_state(&empty_stack));
+        try!(self.count_visit("STATE_VISITS", &format!("{}state as usize", self.prefix)));
 
         // Load the next action to take.
         rust!(self.out,
-              "let {}action = {}ACTION[{}state * {} + {}integer];",
-              self.prefix,
-              self.prefix,
+              "let {}action = {} as i32;",
               self.prefix,
-              self.grammar.terminals.all.len(),
-              self.prefix);
-
-        if DEBUG_PRINT {
+              self.table_entry("ACTION",
+                               &format!("{p}state * {} + {p}integer",
+                                        self.grammar.terminals.all.len(),
+                                        p = self.prefix),
+                               &invalid_state));
+        try!(self.two_token_action());
+        try!(self.contextual_action(&invalid_state));
+
+        if self.session.emit_trace {
             rust!(self.out,
                   "println!(\"state: {{}} lookahead: {{:?}}/{{}} action: {{}} stack-depth: {{}}\", \
                    {p}state, {p}lookahead, {p}integer, {p}action, {p}symbols.len());",
@@ -529,27 +1614,44 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
 
         // Shift.
         rust!(self.out, "if {}action > 0 {{", self.prefix);
-        if DEBUG_PRINT {
+        if self.session.emit_trace {
             rust!(self.out, "println!(\"--> shift `{{:?}}`\", {p}lookahead);", p = self.prefix);
         }
+        try!(self.check_cancel(&format!("{}lookahead.0", self.prefix)));
+        if let Some(max_depth) = self.session.max_depth {
+            rust!(self.out, "if {}symbols.len() >= {} {{", self.prefix, max_depth);
+            rust!(self.out,
+                  "return Err({p}lalrpop_util::ParseError::DepthLimit {{ \
+                   location: {p}lookahead.0, depth: {} }});",
+                  max_depth,
+                  p = self.prefix);
+            rust!(self.out, "}}");
+        }
+        try!(self.check_stack_capacity(&format!("{}lookahead.0", self.prefix), "return Err({})"));
         try!(self.token_to_symbol());
         rust!(self.out,
               "{}states.push({}action - 1);",
               self.prefix,
               self.prefix);
+        try!(self.check_nesting(&format!("{}lookahead.0.clone()", self.prefix)));
         rust!(self.out,
               "{}symbols.push(({}lookahead.0, {}symbol, {}lookahead.2));",
               self.prefix,
               self.prefix,
               self.prefix,
               self.prefix);
+        if default_reductions {
+            rust!(self.out, "{}default_reduced_len = 0;", self.prefix);
+        }
         rust!(self.out, "continue '{}shift;", self.prefix);
 
         // Reduce.
         rust!(self.out, "}} else if {}action < 0 {{", self.prefix);
-        if DEBUG_PRINT {
+        if self.session.emit_trace {
             rust!(self.out, "println!(\"--> reduce\");");
         }
+        try!(self.check_cancel(&format!("{}lookahead.0.clone()", self.prefix)));
+        try!(self.count_reduction(&format!("{}lookahead.0.clone()", self.prefix)));
         rust!(self.out,
               "if let Some(r) = {}reduce({}{}action, Some(&{}lookahead.0), &mut {}states, &mut \
                {}symbols, {}) {{",
@@ -563,11 +1665,14 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
         rust!(self.out, "if r.is_err() {{");
         rust!(self.out, "return r;");
         rust!(self.out, "}}");
+        // (the parse is complete, so nothing else could have come)
         rust!(self.out,
-              "return Err({}lalrpop_util::ParseError::ExtraToken {{ token: {}lookahead }});",
-              self.prefix,
-              self.prefix);
+              "return Err({p}lalrpop_util::ParseError::ExtraToken {{ \
+               token: {p}lookahead, expected: {} }});",
+              if self.session.fixed_stack { "Default::default()" } else { "vec![]" },
+              p = self.prefix);
         rust!(self.out, "}}");
+        try!(self.check_nesting(&format!("{}lookahead.0.clone()", self.prefix)));
 
         // Error.
         rust!(self.out, "}} else {{");
@@ -588,27 +1693,27 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
 
         // EOF loop
         rust!(self.out, "loop {{");
-        rust!(self.out,
-              "let {}state = *{}states.last().unwrap() as usize;",
-              self.prefix,
-              self.prefix);
-        if DEBUG_PRINT {
+        try!(self.assert_stacks_consistent());
+        rust!(self.out, "let {}state = {};", self.prefix, self.top_state(&empty_stack));
+        try!(self.count_visit("STATE_VISITS", &format!("{}state as usize", self.prefix)));
+        if self.session.emit_trace {
             rust!(self.out,
                   "println!(\"EOF loop state: {{}}\", {}state);",
                   self.prefix);
         }
         rust!(self.out,
-              "let {}action = {}EOF_ACTION[{}state];",
+              "let {}action = {} as i32;",
               self.prefix,
-              self.prefix,
-              self.prefix);
-        if DEBUG_PRINT {
+              self.table_entry("EOF_ACTION", &format!("{}state", self.prefix), &invalid_state));
+        if self.session.emit_trace {
             rust!(self.out,
                   "println!(\"EOF in state {{}} takes action {{}}\", {}state, {}action);",
                   self.prefix,
                   self.prefix);
         }
         rust!(self.out, "if {}action < 0 {{", self.prefix);
+        try!(self.check_cancel(&format!("{}last_location.clone()", self.prefix)));
+        try!(self.count_reduction(&format!("{}last_location.clone()", self.prefix)));
         rust!(self.out,
               "if let Some(r) = {}reduce({}{}action, None, &mut {}states, &mut {}symbols, {}) {{",
               self.prefix,
@@ -619,6 +1724,7 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
               phantom_data_expr);
         rust!(self.out, "return r;");
         rust!(self.out, "}}");
+        try!(self.check_nesting(&format!("{}last_location.clone()", self.prefix)));
         rust!(self.out, "}} else {{");
 
         self.try_error_recovery(
@@ -633,19 +1739,33 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
 
         rust!(self.out, "}}"); // while let
 
-        self.end_parser_fn()
+        rust!(self.out, "}}");
+        Ok(())
     }
 
+    /// Emits code to read the next token into `lookahead`. If
+    /// `peekable`, that is the token peeked at by `two_token_action`,
+    /// if there is one.
     fn next_token(&mut self,
                   lookahead: &str,
                   tokens: &str,
                   last_location: &str,
-                  break_on_eof: &str) -> io::Result<()> {
-        rust!(self.out,
-              "{p}{lookahead} = match {p}{tokens}.next() {{",
-              lookahead = lookahead,
-              tokens = tokens,
-              p = self.prefix);
+                  break_on_eof: &str,
+                  peekable: bool) -> io::Result<()> {
+        if peekable {
+            rust!(self.out,
+                  "{p}{lookahead} = \
+                   match {p}peeked.take().unwrap_or_else(|| {p}{tokens}.next()) {{",
+                  lookahead = lookahead,
+                  tokens = tokens,
+                  p = self.prefix);
+        } else {
+            rust!(self.out,
+                  "{p}{lookahead} = match {p}{tokens}.next() {{",
+                  lookahead = lookahead,
+                  tokens = tokens,
+                  p = self.prefix);
+        }
         rust!(self.out, "Some(Ok(v)) => v,");
         rust!(self.out, "None => break '{}{},", self.prefix, break_on_eof); // EOF: break out
         if self.grammar.intern_token.is_some() {
@@ -665,6 +1785,133 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
         Ok(())
     }
 
+    /// Emits code that redefines `action` where the lookahead is a
+    /// `#[contextual]` keyword that the state cannot accept: if the
+    /// state accepts the terminal that the keyword's text would
+    /// otherwise be (see `ContextualKeyword`), the lookahead becomes
+    /// that terminal.
+    fn contextual_action(&mut self, invalid_state: &str) -> io::Result<()> {
+        let remaps: Vec<(usize, usize, usize)> = match self.grammar.intern_token {
+            Some(ref data) => {
+                data.contextual_keywords
+                    .iter()
+                    .map(|keyword| {
+                        let fallback = data.match_entries[keyword.fallback].user_name;
+                        (keyword.index, keyword.fallback, self.grammar.terminals.bits[&fallback])
+                    })
+                    .collect()
+            }
+            None => vec![],
+        };
+        if remaps.is_empty() {
+            return Ok(());
+        }
+
+        rust!(self.out,
+              "let {p}action = if {p}action != 0 {{ {p}action }} else {{",
+              p = self.prefix);
+        rust!(self.out, "match ({}lookahead.1).0 {{", self.prefix);
+        for (index, fallback, integer) in remaps {
+            rust!(self.out, "{} => {{", index);
+            rust!(self.out,
+                  "let {}fallback = {} as i32;",
+                  self.prefix,
+                  self.table_entry("ACTION",
+                                   &format!("{p}state * {} + {}",
+                                            self.grammar.terminals.all.len(),
+                                            integer,
+                                            p = self.prefix),
+                                   invalid_state));
+            rust!(self.out, "if {}fallback != 0 {{", self.prefix);
+            rust!(self.out,
+                  "{p}lookahead.1 = Token({}, ({p}lookahead.1).1);",
+                  fallback,
+                  p = self.prefix);
+            rust!(self.out, "{}integer = {};", self.prefix, integer);
+            rust!(self.out, "}}");
+            rust!(self.out, "{}fallback", self.prefix);
+            rust!(self.out, "}}");
+        }
+        rust!(self.out, "_ => 0,");
+        rust!(self.out, "}}");
+        rust!(self.out, "}};");
+        Ok(())
+    }
+
+    /// Emits code that redefines `action` where the state has
+    /// two-token reductions (see `TwoTokenReduction`) on the lookahead:
+    /// it peeks at the token after the lookahead, and takes the
+    /// reduction whose following tokens include it, if any.
+    fn two_token_action(&mut self) -> io::Result<()> {
+        let num_terminals = self.grammar.terminals.all.len();
+        let mut decided = vec![];
+        let mut reductions = vec![];
+        for (index, state) in self.states.iter().enumerate() {
+            for reduction in &state.two_token_reductions {
+                let integer = self.grammar.terminals.bits[&reduction.terminal];
+                let key = format!("({}, {})", index, integer);
+                if !decided.contains(&key) {
+                    decided.push(key);
+                }
+                let patterns: Vec<String> =
+                    reduction.following
+                             .iter()
+                             .map(|token| {
+                                 let following = match token {
+                                     Token::Terminal(t) => self.grammar.terminals.bits[&t],
+                                     Token::EOF => num_terminals,
+                                     Token::Error => num_terminals + 1,
+                                 };
+                                 format!("({}, {}, {})", index, integer, following)
+                             })
+                             .collect();
+                let action = self.custom.reduce_indices[reduction.production];
+                reductions.push((patterns.join(" | "), -(action as i32 + 1)));
+            }
+        }
+        if decided.is_empty() {
+            return Ok(());
+        }
+
+        rust!(self.out,
+              "let {p}action = match ({p}state, {p}integer) {{",
+              p = self.prefix);
+        rust!(self.out, "{} => {{", decided.join(" | "));
+        rust!(self.out, "if {}peeked.is_none() {{", self.prefix);
+        rust!(self.out, "{p}peeked = Some({p}tokens.next());", p = self.prefix);
+        rust!(self.out, "}}");
+
+        // the integer of the peeked token, as for the lookahead, or
+        // one past the terminals at EOF, and two past them for
+        // anything else
+        rust!(self.out, "let {}following = match {}peeked {{", self.prefix, self.prefix);
+        rust!(self.out, "Some(Some(Ok(ref {p}next))) => match {p}next.1 {{", p = self.prefix);
+        for (&terminal, index) in self.grammar.terminals.all.iter().zip(0..) {
+            if terminal == TerminalString::Error {
+                continue;
+            }
+            try!(self.write_terminal_arm(terminal, index));
+        }
+        rust!(self.out, "_ => {},", num_terminals + 1);
+        rust!(self.out, "}},");
+        rust!(self.out, "Some(None) => {},", num_terminals);
+        rust!(self.out, "_ => {},", num_terminals + 1);
+        rust!(self.out, "}};");
+
+        rust!(self.out,
+              "match ({p}state, {p}integer, {p}following) {{",
+              p = self.prefix);
+        for (patterns, action) in reductions {
+            rust!(self.out, "{} => {},", patterns, action);
+        }
+        rust!(self.out, "_ => {}action,", self.prefix);
+        rust!(self.out, "}}");
+        rust!(self.out, "}}");
+        rust!(self.out, "_ => {}action,", self.prefix);
+        rust!(self.out, "}};");
+        Ok(())
+    }
+
     fn token_to_integer(&mut self, integer: &str, lookahead: &str) -> io::Result<()> {
         rust!(self.out,
               "{p}{integer} = match {p}{lookahead}.1 {{",
@@ -675,17 +1922,17 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
             if terminal == TerminalString::Error {
                 continue;
             }
-            let pattern = self.grammar.pattern(terminal).map(&mut |_| "_");
-            rust!(self.out, "{pattern} if true => {index},",
-                  pattern = pattern,
-                  index = index);
+            try!(self.write_terminal_arm(terminal, index));
         }
 
         rust!(self.out, "_ => {{");
         let prefix = self.prefix;
-        try!(self.let_unrecognized_token_error("error", &format!("Some({p}{lookahead})",
-                                                                 lookahead = lookahead,
-                                                                 p = prefix)));
+        try!(self.let_unrecognized_token_error("error",
+                                               &format!("Some({p}{lookahead})",
+                                                        lookahead = lookahead,
+                                                        p = prefix),
+                                               "true",
+                                               false));
         rust!(self.out, "return Err({p}error);", p = self.prefix);
         rust!(self.out, "}}");
 
@@ -694,6 +1941,13 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
     }
 
     fn token_to_symbol(&mut self) -> io::Result<()> {
+        // (the integer was computed from the token, so these
+        // mismatches cannot happen)
+        let mismatch = if self.session.panic_free {
+            format!("return Err({})", self.internal_error("token does not match its terminal"))
+        } else {
+            format!("unreachable!()")
+        };
         rust!(self.out,
               "let {}symbol = match {}integer {{",
               self.prefix,
@@ -704,8 +1958,10 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
             }
             rust!(self.out, "{} => match {}lookahead.1 {{", index, self.prefix);
 
+            // the guard has already been checked to get `integer`,
+            // so its bindings are not needed here
             let mut pattern_names = vec![];
-            let pattern = self.grammar.pattern(terminal).map(&mut |_| {
+            let pattern = self.grammar.pattern(terminal).without_bindings().map(&mut |_| {
                 let index = pattern_names.len();
                 pattern_names.push(format!("{}tok{}", self.prefix, index));
                 pattern_names.last().cloned().unwrap()
@@ -719,33 +1975,33 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
 
             let variant_name = self.variant_name_for_symbol(Symbol::Terminal(terminal));
             rust!(self.out,
-                  "{} => {}Symbol::{}(({})),",
+                  "{} => {}Symbol::{}({}),",
                   pattern,
                   self.prefix,
                   variant_name,
-                  pattern_names.join(", "));
-            rust!(self.out, "_ => unreachable!(),");
+                  self.grammar.terminal_value(terminal, &pattern_names));
+            rust!(self.out, "_ => {},", mismatch);
             rust!(self.out, "}},");
         }
 
-        rust!(self.out, "_ => unreachable!(),");
+        rust!(self.out, "_ => {},", mismatch);
 
         rust!(self.out, "}};");
         Ok(())
     }
 
     fn emit_reduce_actions(&mut self) -> io::Result<()> {
-        let success_type = self.types.nonterminal_type(self.start_symbol);
+        let success_type = self.symbol_type();
         let parse_error_type = self.types.parse_error_type();
         let loc_type = self.types.terminal_loc_type();
         let spanned_symbol_type = self.spanned_symbol_type();
 
         let parameters = vec![format!("{}action: i32", self.prefix),
                               format!("{}lookahead_start: Option<&{}>", self.prefix, loc_type),
-                              format!("{}states: &mut ::std::vec::Vec<i32>", self.prefix),
-                              format!("{}symbols: &mut ::std::vec::Vec<{}>",
+                              format!("{}states: &mut {}", self.prefix, self.stack_type("i32")),
+                              format!("{}symbols: &mut {}",
                                       self.prefix,
-                                      spanned_symbol_type),
+                                      self.stack_type(&spanned_symbol_type)),
                               format!("_: {}", self.phantom_data_type())];
 
         try!(self.out.write_pub_fn_header(self.grammar,
@@ -758,36 +2014,69 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
                                           vec![]));
         rust!(self.out, "{{");
 
+        try!(self.count_visit("REDUCTIONS", &format!("(-{}action) as usize", self.prefix)));
         rust!(self.out,
               "let {}nonterminal = match -{}action {{",
               self.prefix,
               self.prefix);
-        for (production, index) in self.grammar
-                                       .nonterminals
-                                       .values()
-                                       .flat_map(|nt| &nt.productions)
-                                       .zip(1..) {
-
-            rust!(self.out, "{} => {{", index);
-            try!(self.emit_reduce_action(production));
-            rust!(self.out, "}}");
+        let phantom_data_expr = self.phantom_data_expr();
+        // the reductions that the profile counts most often first
+        let mut arms: Vec<_> = self.reduce_fns().into_iter().zip(1..).collect();
+        {
+            let counts = &self.custom.reduction_counts;
+            let count = |index: &usize| counts.get(index).cloned().unwrap_or(0);
+            arms.sort_by(|&(_, ref a), &(_, ref b)| count(b).cmp(&count(a)));
+        }
+        for ((production, name), index) in arms {
+            rust!(self.out,
+                  "{} => match {}({}{p}lookahead_start, {p}states, {p}symbols, {}) {{",
+                  index,
+                  name,
+                  self.grammar.user_parameter_refs(),
+                  phantom_data_expr,
+                  p = self.prefix);
+            rust!(self.out, "Ok(v) => v,");
+            rust!(self.out, "Err(r) => return Some(r),");
+            rust!(self.out, "}},");
+        }
+        if self.session.panic_free {
+            rust!(self.out,
+                  "_ => return Some(Err({})),",
+                  self.internal_error("invalid action code"));
+        } else {
+            rust!(self.out,
+                  "_ => panic!(\"invalid action code {{}}\", {}action)",
+                  self.prefix);
         }
-        rust!(self.out,
-              "_ => panic!(\"invalid action code {{}}\", {}action)",
-              self.prefix);
         rust!(self.out, "}};");
-        rust!(self.out,
-              "let {}state = *{}states.last().unwrap() as usize;",
-              self.prefix,
-              self.prefix);
-        rust!(self.out,
-              "let {}next_state = {}GOTO[{}state * {} + {}nonterminal] - 1;",
-              self.prefix,
-              self.prefix,
-              self.prefix,
-              self.grammar.nonterminals.len(),
-              self.prefix);
-        if DEBUG_PRINT {
+        let empty_stack = format!("return Some(Err({}))", self.internal_error("empty state stack"));
+        rust!(self.out, "let {}state = {};", self.prefix, self.top_state(&empty_stack));
+        let goto_index = format!("{p}state * {} + {p}nonterminal",
+                                 self.grammar.nonterminals.len(),
+                                 p = self.prefix);
+        if self.session.panic_free {
+            // a zero entry means there is no transition, which the
+            // state that reduced should have ruled out
+            rust!(self.out,
+                  "let {p}next_state = match {p}GOTO.get({}) {{ \
+                   Some(&{p}g) if {p}g > 0 => {p}g as i32 - 1, \
+                   _ => return Some(Err({})) }};",
+                  goto_index,
+                  self.internal_error("invalid goto"),
+                  p = self.prefix);
+        } else {
+            rust!(self.out,
+                  "let {p}next_state = {p}GOTO[{}] as i32 - 1;",
+                  goto_index,
+                  p = self.prefix);
+            if self.session.emit_debug_assertions {
+                rust!(self.out,
+                      "debug_assert!({p}next_state >= 0, \
+                       \"no goto from state {{}} on nonterminal {{}}\", {p}state, {p}nonterminal);",
+                      p = self.prefix);
+            }
+        }
+        if self.session.emit_trace {
             rust!(self.out,
                   "println!(\"goto state {{}} from {{}} due to nonterminal {{}}\", {}next_state, \
                    {}state, {}nonterminal);",
@@ -801,22 +2090,97 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
               self.prefix);
         rust!(self.out, "None");
         rust!(self.out, "}}");
+
+        for (production, name) in self.reduce_fns() {
+            try!(self.emit_reduce_action(production, name));
+        }
         Ok(())
     }
 
-    fn emit_reduce_action(&mut self, production: &Production) -> io::Result<()> {
-        rust!(self.out, "// {:?}", production);
+    /// With `emit_debug_assertions`, checks that there is a state on
+    /// the state stack for each symbol on the symbol stack, along with
+    /// the start state.
+    fn assert_stacks_consistent(&mut self) -> io::Result<()> {
+        if self.session.emit_debug_assertions {
+            rust!(self.out,
+                  "debug_assert_eq!({p}states.len(), {p}symbols.len() + 1, \
+                   \"inconsistent parse stacks\");",
+                  p = self.prefix);
+        }
+        Ok(())
+    }
+
+    /// The productions, in the order of their reduce actions, with
+    /// the names of the functions that reduce them, like
+    /// `__reduce_Expr_2` for the third alternative of `Expr`.
+    fn reduce_fns(&self) -> Vec<(&'grammar Production, String)> {
+        self.grammar
+            .nonterminals
+            .values()
+            .flat_map(|nt| nt.productions.iter().enumerate())
+            .map(|(index, production)| {
+                (production,
+                 format!("{}reduce_{}_{}", self.prefix, Escape(production.nonterminal), index))
+            })
+            .collect()
+    }
+
+    fn emit_reduce_action(&mut self, production: &Production, name: String) -> io::Result<()> {
+        let success_type = self.symbol_type();
+        let parse_error_type = self.types.parse_error_type();
+        let loc_type = self.types.terminal_loc_type();
+        let spanned_symbol_type = self.spanned_symbol_type();
+
+        let parameters = vec![format!("{}lookahead_start: Option<&{}>", self.prefix, loc_type),
+                              format!("{}states: &mut {}", self.prefix, self.stack_type("i32")),
+                              format!("{}symbols: &mut {}",
+                                      self.prefix,
+                                      self.stack_type(&spanned_symbol_type)),
+                              format!("_: {}", self.phantom_data_type())];
+
+        rust!(self.out, "");
+        if self.session.profile_reductions {
+            rust!(self.out, "#[inline(never)]");
+        }
+        try!(self.out.write_fn_header(self.grammar,
+                                      name,
+                                      vec![],
+                                      parameters,
+                                      format!("Result<usize, Result<{},{}>>",
+                                              success_type,
+                                              parse_error_type),
+                                      vec![]));
+        rust!(self.out, "{{");
+        if self.session.emit_grammar_comments {
+            rust!(self.out, "// {:?}", production);
+        }
 
         // Pop each of the symbols and their associated states.
+        if self.session.emit_debug_assertions && !production.symbols.is_empty() {
+            rust!(self.out,
+                  "debug_assert!({p}symbols.len() >= {}, \"too few symbols to reduce\");",
+                  production.symbols.len(),
+                  p = self.prefix);
+        }
         for (index, &symbol) in production.symbols.iter().enumerate().rev() {
             let name = self.variant_name_for_symbol(symbol);
-            rust!(self.out,
-                "let {}sym{} = {}pop_{}({}symbols);",
-                self.prefix,
-                index,
-                self.prefix,
-                name,
-                self.prefix);
+            if self.session.panic_free {
+                rust!(self.out,
+                      "let {p}sym{} = match {p}pop_{}({p}symbols) {{ \
+                       Some({p}v) => {p}v, None => return Err(Err({})) }};",
+                      index,
+                      name,
+                      self.internal_error("symbol type mismatch"),
+                      p = self.prefix);
+            } else {
+                rust!(self.out,
+                    "let {}sym{} = {}pop_{}({}symbols);",
+                    self.prefix,
+                    index,
+                    self.prefix,
+                    name,
+                    self.prefix);
+            }
         }
         let transfer_syms: Vec<_> = (0..production.symbols.len())
                                         .map(|i| format!("{}sym{}", self.prefix, i))
@@ -859,6 +2223,13 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
 
         let transfered_syms = transfer_syms.len();
 
+        // reducing an empty production (other than a start symbol)
+        // pushes a symbol without popping any
+        if transfered_syms == 0 && !is_start_symbol(self.grammar, production.nonterminal) {
+            try!(self.check_stack_capacity(&format!("{}end.clone()", self.prefix),
+                                           "return Err(Err({}))"));
+        }
+
         let mut args = transfer_syms;
         if transfered_syms == 0 {
             args.push(format!("&{}start", self.prefix));
@@ -878,7 +2249,7 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
                   self.grammar.user_parameter_refs(),
                   Sep(", ", &args));
             rust!(self.out, "Ok(v) => v,");
-            rust!(self.out, "Err(e) => return Some(Err(e)),");
+            rust!(self.out, "Err(e) => return Err(Err(e)),");
             rust!(self.out, "}};");
         } else {
             rust!(self.out,
@@ -893,8 +2264,10 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
         }
 
         // if this is the final state, return it
-        if production.nonterminal == self.start_symbol {
-            rust!(self.out, "return Some(Ok({}nt));", self.prefix);
+        if is_start_symbol(self.grammar, production.nonterminal) {
+            let name = self.variant_name_for_symbol(Symbol::Nonterminal(production.nonterminal));
+            rust!(self.out, "Err(Ok({p}Symbol::{}({p}nt)))", name, p = self.prefix);
+            rust!(self.out, "}}");
             return Ok(());
         }
 
@@ -902,6 +2275,13 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
         rust!(self.out,
               "let {p}states_len = {p}states.len();",
               p = self.prefix);
+        if self.session.panic_free && !production.symbols.is_empty() {
+            rust!(self.out,
+                  "if {p}states_len < {len} {{ return Err(Err({})); }}",
+                  self.internal_error("state stack underflow"),
+                  p = self.prefix,
+                  len = production.symbols.len());
+        }
         rust!(self.out,
               "{p}states.truncate({p}states_len - {len});",
               p = self.prefix,
@@ -925,12 +2305,13 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
                         .iter()
                         .position(|&x| x == production.nonterminal)
                         .unwrap();
-        rust!(self.out, "{}", index);
+        rust!(self.out, "Ok({})", index);
+        rust!(self.out, "}}");
 
         Ok(())
     }
 
-    fn variant_name_for_symbol(&mut self, s: Symbol) -> String {
+    fn variant_name_for_symbol(&self, s: Symbol) -> String {
         match s {
             Symbol::Nonterminal(nt) => format!("Nt{}", Escape(nt)),
             Symbol::Terminal(t) => format!("Term{}", Escape(t)),
@@ -941,19 +2322,36 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
         for &term in &self.grammar.terminals.all {
             let name = self.variant_name_for_symbol(Symbol::Terminal(term));
             let ty = self.types.terminal_type(term).clone();
-            try!(self.emit_downcast_fn(&name, ty));
+            try!(self.emit_downcast_fn(&name, &[], ty));
         }
 
+        // If unit reductions were eliminated, a `B` may be on the
+        // stack where an `A` is expected (see `lr1::unit_rules`).
+        let unit_aliases = if self.session.eliminate_unit_rules {
+            unit_rules::unit_aliases(self.grammar)
+        } else {
+            Map::new()
+        };
         for &nt in self.grammar.nonterminals.keys() {
             let name = self.variant_name_for_symbol(Symbol::Nonterminal(nt));
+            let aliases: Vec<String> =
+                unit_aliases.get(&nt)
+                            .into_iter()
+                            .flat_map(|aliases| aliases)
+                            .map(|&alias| self.variant_name_for_symbol(Symbol::Nonterminal(alias)))
+                            .collect();
             let ty = self.types.nonterminal_type(nt).clone();
-            try!(self.emit_downcast_fn(&name, ty));
+            try!(self.emit_downcast_fn(&name, &aliases, ty));
         }
 
         Ok(())
     }
 
-    fn emit_downcast_fn(&mut self, variant_name: &str, variant_ty: TypeRepr) -> io::Result<()> {
+    fn emit_downcast_fn(&mut self,
+                        variant_name: &str,
+                        alias_names: &[String],
+                        variant_ty: TypeRepr)
+                        -> io::Result<()> {
         let spanned_symbol_type = self.spanned_symbol_type();
 
         rust!(self.out, "fn {}pop_{}<", self.prefix, variant_name);
@@ -962,10 +2360,16 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
         }
         rust!(self.out, ">(");
         rust!(self.out,
-              "{}symbols: &mut ::std::vec::Vec<{}>",
+              "{}symbols: &mut {}",
               self.prefix,
-              spanned_symbol_type);
-        rust!(self.out, ") -> {}", self.types.spanned_type(variant_ty));
+              self.stack_type(&spanned_symbol_type));
+        // panic-free parsers leave it to the caller to report a
+        // mismatch
+        if self.session.panic_free {
+            rust!(self.out, ") -> Option<{}>", self.types.spanned_type(variant_ty));
+        } else {
+            rust!(self.out, ") -> {}", self.types.spanned_type(variant_ty));
+        }
 
         if !self.custom.symbol_where_clauses.is_empty() {
             rust!(self.out, " where {}", Sep(", ", &self.custom.symbol_where_clauses));
@@ -973,21 +2377,38 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
 
         rust!(self.out, " {{");
 
-        if DEBUG_PRINT {
+        if self.session.emit_trace {
             rust!(self.out, "println!(\"pop_{}\");", variant_name);
         }
-        rust!(self.out, "match {}symbols.pop().unwrap() {{", self.prefix);
-        rust!(self.out,
-              "({}l, {}Symbol::{}({}v), {}r) => ({}l, {}v, {}r),",
-              self.prefix,
-              self.prefix,
-              variant_name,
-              self.prefix,
-              self.prefix,
-              self.prefix,
-              self.prefix,
-              self.prefix);
-        rust!(self.out, "_ => panic!(\"symbol type mismatch\")");
+        if self.session.panic_free {
+            rust!(self.out, "match {}symbols.pop() {{", self.prefix);
+            for name in Some(variant_name).into_iter().chain(alias_names.iter().map(|a| &a[..])) {
+                rust!(self.out,
+                      "Some(({p}l, {p}Symbol::{}({p}v), {p}r)) => Some(({p}l, {p}v, {p}r)),",
+                      name,
+                      p = self.prefix);
+            }
+            rust!(self.out, "_ => None,");
+        } else {
+            rust!(self.out, "match {}symbols.pop().unwrap() {{", self.prefix);
+            rust!(self.out,
+                  "({}l, {}Symbol::{}({}v), {}r) => ({}l, {}v, {}r),",
+                  self.prefix,
+                  self.prefix,
+                  variant_name,
+                  self.prefix,
+                  self.prefix,
+                  self.prefix,
+                  self.prefix,
+                  self.prefix);
+            for alias_name in alias_names {
+                rust!(self.out,
+                      "({p}l, {p}Symbol::{}({p}v), {p}r) => ({p}l, {p}v, {p}r),",
+                      alias_name,
+                      p = self.prefix);
+            }
+            rust!(self.out, "_ => panic!(\"symbol type mismatch\")");
+        }
         rust!(self.out, "}}");
 
         rust!(self.out, "}}");
@@ -1050,9 +2471,24 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
         // Easy case: error recovery is disabled. Just error out.
         if !self.grammar.uses_error_recovery {
             let prefix = self.prefix;
+            let is_error = if self.session.panic_free {
+                format!("match {p}err_integer {{ \
+                         Some({p}i) => {p}ACTION.get({p}s * {n} + {p}i).map_or(true, |&{p}a| {p}a == 0), \
+                         None => {p}EOF_ACTION.get({p}s).map_or(true, |&{p}a| {p}a == 0) }}",
+                        n = self.grammar.terminals.all.len(),
+                        p = prefix)
+            } else {
+                format!("match {p}err_integer {{ \
+                         Some({p}i) => {p}ACTION[{p}s * {n} + {p}i] == 0, \
+                         None => {p}EOF_ACTION[{p}s] == 0 }}",
+                        n = self.grammar.terminals.all.len(),
+                        p = prefix)
+            };
             self.let_unrecognized_token_error(
                 "error",
                 &format!("{p}err_lookahead", p = prefix),
+                &is_error,
+                true,
             )?;
             rust!(self.out, "return Err({p}error)", p = prefix);
             return Ok(());
@@ -1069,8 +2505,15 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
                {p}{last_location}, \
                &mut {p}err_lookahead, \
                &mut {p}err_integer, \
+               {reductions}\
+               {p}cancel, \
                {phantom_data_expr}) {{",
               upr = self.grammar.user_parameter_refs(),
+              reductions = if self.session.max_reductions.is_some() {
+                  format!("{}reductions, ", self.prefix)
+              } else {
+                  String::new()
+              },
               tokens = tokens,
               states = states,
               symbols = symbols,
@@ -1096,6 +2539,325 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
         Ok(())
     }
 
+    /// Emits code that returns `ParseError::Canceled` at `location`
+    /// (an expression) if the cancellation flag has been set. Expects
+    /// a `cancel` variable of type `Option<&AtomicBool>`.
+    fn check_cancel(&mut self, location: &str) -> io::Result<()> {
+        rust!(self.out, "if let Some({p}flag) = {p}cancel {{", p = self.prefix);
+        rust!(self.out,
+              "if {}flag.load({}::sync::atomic::Ordering::Relaxed) {{",
+              self.prefix,
+              self.core_crate());
+        rust!(self.out,
+              "return Err({p}lalrpop_util::ParseError::Canceled {{ location: {} }});",
+              location,
+              p = self.prefix);
+        rust!(self.out, "}}");
+        rust!(self.out, "}}");
+        Ok(())
+    }
+
+    /// If the number of reductions is limited, counts one more and
+    /// returns `ParseError::ReductionLimit` at `location` (an
+    /// expression) if that is one too many. Expects a `reductions`
+    /// variable of type `&mut usize`.
+    fn count_reduction(&mut self, location: &str) -> io::Result<()> {
+        if let Some(max_reductions) = self.session.max_reductions {
+            rust!(self.out, "*{}reductions += 1;", self.prefix);
+            rust!(self.out, "if *{}reductions > {} {{", self.prefix, max_reductions);
+            rust!(self.out,
+                  "return Err({p}lalrpop_util::ParseError::ReductionLimit {{ \
+                   location: {}, limit: {} }});",
+                  location,
+                  max_reductions,
+                  p = self.prefix);
+            rust!(self.out, "}}");
+        }
+        Ok(())
+    }
+
+    /// True if the parser counts the states it enters and the
+    /// reductions it runs; see `write_profile_fn`.
+    fn profiled(&self) -> bool {
+        self.session.emit_profile &&
+        self.grammar.algorithm.codegen == LrCodeGeneration::TableDriven
+    }
+
+    /// If the parser is profiled, counts one more entry `index` (an
+    /// expression) of the counters `{p}name`.
+    fn count_visit(&mut self, name: &str, index: &str) -> io::Result<()> {
+        if self.profiled() {
+            rust!(self.out,
+                  "{p}{}.with(|{p}counts| {{ \
+                   if let Some({p}count) = {p}counts.borrow_mut().get_mut({}) {{ \
+                   *{p}count += 1; }} }});",
+                  name,
+                  index,
+                  p = self.prefix);
+        }
+        Ok(())
+    }
+
+    /// If the parser is profiled, writes the counters of the states
+    /// it enters and the reductions it runs, on each thread.
+    fn write_profile_counters(&mut self) -> io::Result<()> {
+        if !self.profiled() {
+            return Ok(());
+        }
+        let counters = [("STATE_VISITS", self.states.len()),
+                        ("REDUCTIONS", self.custom.reduce_indices.len() + 1)];
+        rust!(self.out, "thread_local! {{");
+        for &(name, len) in &counters {
+            rust!(self.out,
+                  "static {}{}: ::std::cell::RefCell<::std::vec::Vec<u64>> = \
+                   ::std::cell::RefCell::new(vec![0; {}]);",
+                  self.prefix,
+                  name,
+                  len);
+        }
+        rust!(self.out, "}}");
+        Ok(())
+    }
+
+    /// If the parser is profiled, writes the function that writes out
+    /// the counters of the current start symbol, in the format that
+    /// `lr1::parse_profile` reads:
+    ///
+    /// ```ignore
+    /// pub fn write_profile_Foo<W: ::std::io::Write>(out: &mut W)
+    ///     -> ::std::io::Result<()>
+    /// ```
+    ///
+    /// The states are numbered from the start state of its parser, as
+    /// `lr1::reorder_states` expects them to be. The reductions are
+    /// shared by all of the parsers, so each of these functions writes
+    /// out all of them.
+    fn write_profile_fn(&mut self) -> io::Result<()> {
+        if !self.profiled() {
+            return Ok(());
+        }
+        let states = self.current_start().states.clone();
+        let counters = [("STATE_VISITS", "state", states),
+                        ("REDUCTIONS", "reduce", 0..self.custom.reduce_indices.len() + 1)];
+        rust!(self.out, "");
+        try!(self.write_parser_cfg());
+        rust!(self.out,
+              "pub fn write_profile_{}<{p}W: ::std::io::Write>({p}out: &mut {p}W) \
+               -> ::std::io::Result<()> {{",
+              self.user_start_symbol,
+              p = self.prefix);
+        for &(name, kind, ref range) in &counters {
+            rust!(self.out,
+                  "try!({p}{}.with(|{p}counts| -> ::std::io::Result<()> {{",
+                  name,
+                  p = self.prefix);
+            rust!(self.out,
+                  "for ({p}index, &{p}count) in {p}counts.borrow()[{}..{}].iter().enumerate() {{",
+                  range.start,
+                  range.end,
+                  p = self.prefix);
+            rust!(self.out, "if {}count > 0 {{", self.prefix);
+            rust!(self.out,
+                  "try!(writeln!({p}out, \"{} {} {{}} {{}}\", {p}index, {p}count));",
+                  self.user_start_symbol,
+                  kind,
+                  p = self.prefix);
+            rust!(self.out, "}}");
+            rust!(self.out, "}}");
+            rust!(self.out, "Ok(())");
+            rust!(self.out, "}}));");
+        }
+        rust!(self.out, "Ok(())");
+        rust!(self.out, "}}");
+        Ok(())
+    }
+
+    /// The nonterminals with a `#[max_nesting]`, with their depths.
+    fn nesting_nonterminals(&self) -> Vec<(NonterminalString, usize)> {
+        self.grammar
+            .nonterminals
+            .values()
+            .filter_map(|nt| nt.max_nesting().map(|depth| (nt.name, depth)))
+            .collect()
+    }
+
+    /// Emits code to run after a state is pushed, which returns
+    /// `ParseError::DepthLimit` at `location` if that made a
+    /// nonterminal with a `#[max_nesting]` too deeply nested.
+    fn check_nesting(&mut self, location: &str) -> io::Result<()> {
+        if self.nesting_nonterminals().is_empty() {
+            return Ok(());
+        }
+        rust!(self.out,
+              "if let Some({p}depth) = {p}enter_nesting(&mut {p}nesting, &{p}states) {{",
+              p = self.prefix);
+        rust!(self.out,
+              "return Err({p}lalrpop_util::ParseError::DepthLimit {{ \
+               location: {}, depth: {p}depth }});",
+              location,
+              p = self.prefix);
+        rust!(self.out, "}}");
+        Ok(())
+    }
+
+    /// Writes the `enter_nesting` function, for grammars with
+    /// `#[max_nesting]` nonterminals, which the parse loop calls
+    /// whenever it pushes a state:
+    ///
+    /// ```ignore
+    /// fn __enter_nesting(__nesting: &mut Vec<(usize, usize)>, __states: &[i32])
+    ///     -> Option<usize>
+    /// ```
+    ///
+    /// `nesting` lists the slots of the state stack whose states may
+    /// have begun one of those nonterminals (having shifted the
+    /// first symbol of one of its alternatives), along with the index
+    /// of the nonterminal; an `X` is nested as deeply as the number
+    /// of its entries. The function drops the entries of the slots
+    /// that were popped (or replaced by the new state) and adds those
+    /// of the new state, unless that exceeds the maximum depth of a
+    /// nonterminal, which it returns.
+    fn write_nesting_fn(&mut self) -> io::Result<()> {
+        let nesting = self.nesting_nonterminals();
+        if nesting.is_empty() {
+            return Ok(());
+        }
+
+        rust!(self.out, "");
+        rust!(self.out,
+              "fn {p}enter_nesting({p}nesting: &mut ::std::vec::Vec<(usize, usize)>, \
+               {p}states: &[i32]) -> Option<usize> {{",
+              p = self.prefix);
+        rust!(self.out,
+              "let {p}state = match {p}states.last() {{ Some(&{p}s) => {p}s, None => return None }};",
+              p = self.prefix);
+        rust!(self.out, "let {p}top = {p}states.len() - 1;", p = self.prefix);
+        rust!(self.out,
+              "while {p}nesting.last().map_or(false, |&({p}slot, _)| {p}slot >= {p}top) {{",
+              p = self.prefix);
+        rust!(self.out, "{}nesting.pop();", self.prefix);
+        rust!(self.out, "}}");
+
+        // the nonterminals each state may begin, with their depths
+        rust!(self.out,
+              "let {p}begun: &'static [(usize, usize)] = match {p}state {{",
+              p = self.prefix);
+        for state in self.states {
+            let begun: Vec<String> =
+                nesting.iter()
+                       .enumerate()
+                       .filter(|&(_, &(nt, _))| {
+                           state.items.vec.iter().any(|item| {
+                               item.production.nonterminal == nt && item.index == 1 &&
+                               item.production.symbols.len() > 1
+                           })
+                       })
+                       .map(|(index, &(_, depth))| format!("({}, {})", index, depth))
+                       .collect();
+            if !begun.is_empty() {
+                rust!(self.out, "{} => &[{}],", state.index.0, Sep(", ", &begun));
+            }
+        }
+        rust!(self.out, "_ => &[],");
+        rust!(self.out, "}};");
+
+        rust!(self.out, "for &({p}nt, {p}depth) in {p}begun {{", p = self.prefix);
+        rust!(self.out,
+              "if {p}nesting.iter().filter(|&&(_, {p}n)| {p}n == {p}nt).count() >= {p}depth {{",
+              p = self.prefix);
+        rust!(self.out, "return Some({}depth);", self.prefix);
+        rust!(self.out, "}}");
+        rust!(self.out, "{p}nesting.push(({p}top, {p}nt));", p = self.prefix);
+        rust!(self.out, "}}");
+        rust!(self.out, "None");
+        rust!(self.out, "}}");
+        Ok(())
+    }
+
+    /// An expression for a `ParseError::Internal` with `message`,
+    /// which panic-free parsers return where others would panic.
+    fn internal_error(&self, message: &str) -> String {
+        format!("{p}lalrpop_util::ParseError::Internal {{ \
+                 message: {}::convert::From::from({:?}) }}",
+                self.core_crate(),
+                message,
+                p = self.prefix)
+    }
+
+    /// An expression for the state on top of `{p}states`, as a
+    /// `usize`. Panic-free parsers evaluate `on_empty` (which should
+    /// diverge) if the stack is empty.
+    fn top_state(&self, on_empty: &str) -> String {
+        if self.session.panic_free {
+            format!("match {p}states.last() {{ Some(&{p}s) => {p}s as usize, None => {} }}",
+                    on_empty,
+                    p = self.prefix)
+        } else {
+            format!("*{p}states.last().unwrap() as usize", p = self.prefix)
+        }
+    }
+
+    /// An expression for the entry of `table` (e.g. `ACTION`) at
+    /// `index`. Panic-free parsers evaluate `out_of_bounds` (which
+    /// should diverge) if there is no such entry.
+    fn table_entry(&self, table: &str, index: &str, out_of_bounds: &str) -> String {
+        if self.session.panic_free {
+            format!("(match {p}{}.get({}) {{ Some(&{p}a) => {p}a, None => {} }})",
+                    table,
+                    index,
+                    out_of_bounds,
+                    p = self.prefix)
+        } else {
+            format!("{p}{}[{}]", table, index, p = self.prefix)
+        }
+    }
+
+    /// Emits code that, if the token that error recovery just dropped
+    /// opens one of the `#[recover_delimiters]`, drops the tokens up to
+    /// and including the matching close as well. Any open delimiter
+    /// nests and any close delimiter unnests, so mismatched brackets
+    /// are not told apart. At EOF, this breaks out of the `'eof` loop
+    /// like `next_token`.
+    fn skip_delimited_group(&mut self) -> io::Result<()> {
+        if self.grammar.recover_delimiters.is_empty() {
+            return Ok(());
+        }
+        let (open, close): (Vec<_>, Vec<_>) =
+            self.grammar
+                .recover_delimiters
+                .iter()
+                .map(|&(open, close)| (self.grammar.terminals.bits[&open],
+                                       self.grammar.terminals.bits[&close]))
+                .unzip();
+        let some_open: Vec<String> = open.iter().map(|i| format!("Some({})", i)).collect();
+
+        rust!(self.out,
+              "if match *{}opt_integer {{ {} => true, _ => false }} {{",
+              self.prefix,
+              some_open.join(" | "));
+        rust!(self.out, "let mut {}depth = 1_usize;", self.prefix);
+        rust!(self.out, "while {}depth > 0 {{", self.prefix);
+        self.next_token("lookahead", "tokens", "last_location", "eof", false)?;
+        rust!(self.out, "let {p}integer;", p = self.prefix);
+        try!(self.token_to_integer("integer", "lookahead"));
+        rust!(self.out, "match {}integer {{", self.prefix);
+        rust!(self.out, "{} => {}depth += 1,", Sep(" | ", &open), self.prefix);
+        rust!(self.out, "{} => {}depth -= 1,", Sep(" | ", &close), self.prefix);
+        rust!(self.out, "_ => {{ }}");
+        rust!(self.out, "}}");
+        if self.session.emit_trace {
+            rust!(
+                self.out,
+                r#"println!("Error recovery: dropping token `{{:?}}`", {p}lookahead);"#,
+                p = self.prefix,
+            );
+        }
+        rust!(self.out, "{p}dropped_tokens.push({p}lookahead);", p = self.prefix);
+        rust!(self.out, "}}");
+        rust!(self.out, "}}");
+        Ok(())
+    }
+
     fn write_error_recovery_fn(&mut self) -> io::Result<()> {
         // Easy case: error recovery is disabled. Just error out.
         if !self.grammar.uses_error_recovery {
@@ -1109,7 +2871,7 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
         let loc_type = self.types.terminal_loc_type();
         let prefix = self.prefix;
         let actions_per_state = self.grammar.terminals.all.len();
-        let start_type = self.types.nonterminal_type(self.start_symbol);
+        let start_type = self.symbol_type();
 
         // The tokenizr, when we supply it, returns parse
         // errors. Otherwise, it returns custom user errors.
@@ -1119,7 +2881,7 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
             &error_type
         };
 
-        let parameters = vec![format!("{p}tokens: &mut {p}I",
+        let mut parameters = vec![format!("{p}tokens: &mut {p}I",
                                       p = self.prefix),
                               format!("{p}states: &mut ::std::vec::Vec<i32>",
                                       p = self.prefix),
@@ -1133,9 +2895,13 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
                                       triple_type = triple_type,
                                       p = self.prefix),
                               format!("{p}opt_integer: &mut Option<usize>",
-                                      p = self.prefix),
-                              format!("_: {}",
-                                      self.phantom_data_type())];
+                                      p = self.prefix)];
+        if self.session.max_reductions.is_some() {
+            parameters.push(format!("{p}reductions: &mut usize", p = self.prefix));
+        }
+        parameters.push(format!("{p}cancel: Option<&::std::sync::atomic::AtomicBool>",
+                                p = self.prefix));
+        parameters.push(format!("_: {}", self.phantom_data_type()));
 
         try!(self.out.write_fn_header(self.grammar,
                                       format!("{p}error_recovery", p = self.prefix),
@@ -1155,14 +2921,16 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
 
         self.let_unrecognized_token_error(
             "error",
-            &format!("{p}opt_lookahead.clone()", p = prefix)
+            &format!("{p}opt_lookahead.clone()", p = prefix),
+            "true",
+            true,
         )?;
 
         rust!(self.out, "let mut {}dropped_tokens = vec![];", prefix);
 
         let phantom_data_expr = self.phantom_data_expr();
 
-        if DEBUG_PRINT {
+        if self.session.emit_trace {
             rust!(self.out, "println!(\"Initiating error recovery in state: {{}}\", \
                              {p}states.last().unwrap());",
                   p = self.prefix);
@@ -1189,7 +2957,7 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
 
         // Access the action with `error` as the lookahead; it is always final
         // column in the row for this state
-        rust!(self.out, "let {p}action = {p}ACTION[{p}state * {} + {}];",
+        rust!(self.out, "let {p}action = {p}ACTION[{p}state * {} + {}] as i32;",
               actions_per_state,
               actions_per_state - 1,
               p = self.prefix);
@@ -1197,11 +2965,16 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
         rust!(self.out, "break;");
         rust!(self.out, "}}");
 
-        if DEBUG_PRINT {
+        if self.session.emit_trace {
             rust!(self.out, r#"println!("Error recovery reduces on action: {{}}", {}action);"#,
                   self.prefix);
         }
 
+        let location = format!("{p}opt_lookahead.as_ref().map(|l| l.0.clone())\
+                                .unwrap_or_else(|| {p}last_location.clone())",
+                               p = self.prefix);
+        try!(self.check_cancel(&location));
+        try!(self.count_reduction(&location));
         rust!(self.out,
               "let {p}lookahead_start = {p}opt_lookahead.as_ref().map(|l| &l.0);",
               p = self.prefix);
@@ -1242,7 +3015,7 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
         rust!(self.out,
               "let {p}state = {p}states[{p}top];",
               p = self.prefix);
-        if DEBUG_PRINT {
+        if self.session.emit_trace {
             rust!(
                 self.out,
                 "println!(\"Probing recovery from state {{}} (top = {{}}).\", {p}state, {p}top);",
@@ -1250,7 +3023,7 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
             );
         }
         // ...fetch action for error token...
-        rust!(self.out, "let {p}action = {p}ACTION[({p}state * {} + {}) as usize];",
+        rust!(self.out, "let {p}action = {p}ACTION[({p}state * {} + {}) as usize] as i32;",
               actions_per_state,
               actions_per_state - 1,
               p = self.prefix);
@@ -1292,7 +3065,7 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
         // first token that we dropped (if any) and use that as the
         // point of error.
         rust!(self.out, "None => {{");
-        if DEBUG_PRINT {
+        if self.session.emit_trace {
             rust!(self.out, r#"println!("Error recovery: cannot drop EOF; aborting");"#);
         }
         rust!(self.out, "return Err({}error)", prefix);
@@ -1301,15 +3074,17 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
         // Else, drop the current token and shift to the next. If there is a next
         // token, we will `continue` to the start of the `'find_state` loop.
         rust!(self.out, "Some(mut {p}lookahead) => {{", p = self.prefix);
-        if DEBUG_PRINT {
+        if self.session.emit_trace {
             rust!(
                 self.out,
                 r#"println!("Error recovery: dropping token `{{:?}}`", {p}lookahead);"#,
                 p = self.prefix,
             );
         }
+        try!(self.check_cancel(&format!("{}lookahead.0.clone()", self.prefix)));
         rust!(self.out, "{p}dropped_tokens.push({p}lookahead);", p = self.prefix);
-        self.next_token("lookahead", "tokens", "last_location", "eof")?;
+        try!(self.skip_delimited_group());
+        self.next_token("lookahead", "tokens", "last_location", "eof", false)?;
         rust!(self.out, "let {p}integer;", p = self.prefix);
         try!(self.token_to_integer("integer", "lookahead"));
         rust!(self.out, "*{p}opt_lookahead = Some({p}lookahead);", p = self.prefix);
@@ -1323,7 +3098,7 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
         // `'eof` loop) when we encounter EOF (i.e., there is no
         // `next_token`). Just set `opt_lookahead` to `None` in that
         // case.
-        if DEBUG_PRINT {
+        if self.session.emit_trace {
             rust!(self.out, "println!(\"Encountered EOF during error recovery\");");
         }
         rust!(self.out, "*{p}opt_lookahead = None;", p = self.prefix);
@@ -1360,7 +3135,7 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
         rust!(self.out, "let {p}top = {p}top0;", p = self.prefix);
         rust!(self.out, "let {p}start = if let Some({p}popped_sym) = {p}symbols.get({p}top) {{",
               p = self.prefix);
-        if DEBUG_PRINT {
+        if self.session.emit_trace {
             rust!(
                 self.out,
                 "println!(\"Span starts from popped symbol {{:?}}\", \
@@ -1372,7 +3147,7 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
               p = self.prefix);
         rust!(self.out, "}} else if let Some({p}dropped_token) = {p}dropped_tokens.first() {{",
               p = self.prefix);
-        if DEBUG_PRINT {
+        if self.session.emit_trace {
             rust!(
                 self.out,
                 "println!(\"Span starts from dropped token {{:?}}\", \
@@ -1383,12 +3158,12 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
         rust!(self.out, "{p}dropped_token.0.clone()",
               p = self.prefix);
         rust!(self.out, "}} else if {p}top > 0 {{", p = self.prefix);
-        if DEBUG_PRINT {
+        if self.session.emit_trace {
             rust!(self.out, "println!(\"Span starts from end of last retained symbol\");");
         }
         rust!(self.out, "{p}symbols[{p}top - 1].2.clone()", p = self.prefix);
         rust!(self.out, "}} else {{");
-        if DEBUG_PRINT {
+        if self.session.emit_trace {
             rust!(self.out, "println!(\"Span starts from default\");");
         }
         rust!(self.out, "Default::default()");
@@ -1422,7 +3197,7 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
         //             -
         rust!(self.out, "let {p}end = if let Some({p}dropped_token) = {p}dropped_tokens.last() {{",
               p = self.prefix);
-        if DEBUG_PRINT {
+        if self.session.emit_trace {
             rust!(
                 self.out,
                 "println!(\"Span ends at end of last dropped token {{:?}}\", \
@@ -1434,7 +3209,7 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
               p = self.prefix);
         rust!(self.out, "}} else if {p}states_len - 1 > {p}top {{",
               p = self.prefix);
-        if DEBUG_PRINT {
+        if self.session.emit_trace {
             rust!(
                 self.out,
                 "println!(\"Span ends at end of last popped symbol {{:?}}\", \
@@ -1446,13 +3221,13 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
               p = self.prefix);
         rust!(self.out, "}} else if let Some({p}lookahead) = {p}opt_lookahead.as_ref() {{", p =
               self.prefix);
-        if DEBUG_PRINT {
+        if self.session.emit_trace {
             rust!(self.out, "println!(\"Span ends at start of lookahead\");");
         }
         rust!(self.out, "{p}lookahead.0.clone()",
               p = self.prefix);
         rust!(self.out, "}} else {{");
-        if DEBUG_PRINT {
+        if self.session.emit_trace {
             rust!(self.out, "println!(\"Span ends at start\");");
         }
         rust!(self.out, "{p}start.clone()",
@@ -1476,13 +3251,13 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
               p = self.prefix);
 
         // Load the error action, which must be a shift.
-        rust!(self.out, "let {p}error_action = {p}ACTION[({p}recover_state * {} + {}) as usize];",
+        rust!(self.out, "let {p}error_action = {p}ACTION[({p}recover_state * {} + {}) as usize] as i32;",
               actions_per_state,
               actions_per_state - 1,
               p = self.prefix);
         rust!(self.out, "let {p}error_state = {p}error_action - 1;", p = self.prefix);
 
-        if DEBUG_PRINT {
+        if self.session.emit_trace {
             rust!(self.out,
                   "println!(\"Recovering from error:\");");
             rust!(self.out,
@@ -1512,6 +3287,7 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
               p = self.prefix);
         rust!(self.out, "error: {p}error,", p = self.prefix);
         rust!(self.out, "dropped_tokens: {p}dropped_tokens,", p = self.prefix);
+        rust!(self.out, "state: {p}recover_state as usize,", p = self.prefix);
         rust!(self.out, "}};");
         rust!(
             self.out,
@@ -1551,15 +3327,27 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
     /// take this into account can lead error recovery into an
     /// infinite loop (see the `error_recovery_lalr_loop` test) or
     /// produce crappy results (see `error_recovery_lock_in`).
+    ///
+    /// It is also how we tell whether an unexpected token comes after
+    /// a complete parse (see `let_unrecognized_token_error`), so it
+    /// is emitted even if the grammar doesn't use error recovery.
+    ///
+    /// A parser with a fixed-size stack has no error recovery, so it
+    /// only asks before it returns an error, when it no longer needs
+    /// its state stack. Rather than simulating the reductions on a
+    /// copy of the stack, which would allocate, it passes the stack
+    /// itself (without `error_state`, which it popped off).
     fn write_accepts_fn(&mut self) -> io::Result<()> {
-        if !self.grammar.uses_error_recovery {
-            return Ok(());
-        }
-
         let actions_per_state = self.grammar.terminals.all.len();
+        let states_type = if self.session.fixed_stack {
+            format!("&mut {}", self.stack_type("i32"))
+        } else {
+            format!("& [i32]")
+        };
         let parameters = vec![format!("{p}error_state: i32",
                                       p = self.prefix),
-                              format!("{p}states: & [i32]",
+                              format!("{p}states: {}",
+                                      states_type,
                                       p = self.prefix),
                               format!("{p}opt_integer: Option<usize>",
                                       p = self.prefix),
@@ -1573,7 +3361,7 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
                                       vec![]));
         rust!(self.out, "{{");
 
-        if DEBUG_PRINT {
+        if self.session.emit_trace {
             rust!(self.out,
                   "println!(\"Testing whether state {{}} accepts token {{:?}}\", \
                    {p}error_state, {p}opt_integer);",
@@ -1581,7 +3369,9 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
         }
 
         // Create our own copy of the state stack to play with.
-        rust!(self.out, "let mut {p}states = {p}states.to_vec();", p = self.prefix);
+        if !self.session.fixed_stack {
+            rust!(self.out, "let mut {p}states = {p}states.to_vec();", p = self.prefix);
+        }
         rust!(self.out, "{p}states.push({p}error_state);", p = self.prefix);
 
         rust!(self.out, "loop {{",);
@@ -1591,22 +3381,34 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
               self.prefix,
               self.prefix);
 
-        rust!(self.out, "let {p}top = {p}states[{p}states_len - 1];", p = self.prefix);
+        // (panic-free parsers do not accept if the simulation goes
+        // wrong)
+        let top = if self.session.panic_free {
+            format!("match {p}states.last() {{ Some(&{p}s) => {p}s, None => return false }}",
+                    p = self.prefix)
+        } else {
+            format!("{p}states[{p}states_len - 1]", p = self.prefix)
+        };
+        rust!(self.out, "let {}top = {};", self.prefix, top);
 
-        if DEBUG_PRINT {
+        if self.session.emit_trace {
             rust!(self.out,
                   "println!(\"accepts: top-state={{}} num-states={{}}\", {p}top, {p}states_len);",
                   p = self.prefix);
         }
 
         rust!(self.out, "let {p}action = match {p}opt_integer {{", p = self.prefix);
-        rust!(self.out, "None => {p}EOF_ACTION[{p}top as usize],", p = self.prefix);
-        rust!(
-            self.out,
-            "Some({p}integer) => {p}ACTION[({p}top * {actions_per_state}) as usize + {p}integer],",
-            p = self.prefix,
-            actions_per_state = actions_per_state,
-        );
+        rust!(self.out,
+              "None => {} as i32,",
+              self.table_entry("EOF_ACTION", &format!("{}top as usize", self.prefix), "return false"));
+        rust!(self.out,
+              "Some({p}integer) => {} as i32,",
+              self.table_entry("ACTION",
+                               &format!("({p}top * {}) as usize + {p}integer",
+                                        actions_per_state,
+                                        p = self.prefix),
+                               "return false"),
+              p = self.prefix);
         rust!(self.out, "}};"); // end `match`
 
         // If we encounter an error action, we do **not** accept.
@@ -1624,12 +3426,12 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
                                        .flat_map(|nt| &nt.productions)
                                        .zip(1..)
         {
-            if Tls::session().emit_comments {
+            if self.session.emit_comments {
                 rust!(self.out, "// simulate {:?}", production);
             }
 
-            // if we just reduced the start symbol, that is also an accept criteria
-            if production.nonterminal == self.start_symbol {
+            // if we just reduced a start symbol, that is also an accept criteria
+            if is_start_symbol(self.grammar, production.nonterminal) {
                 rust!(self.out, "{} => return true,", index);
             } else {
                 let num_symbols = production.symbols.len();
@@ -1639,7 +3441,7 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
                              .position(|&x| x == production.nonterminal)
                              .unwrap();
                 rust!(self.out, "{} => {{", index);
-                if DEBUG_PRINT {
+                if self.session.emit_trace {
                     rust!(self.out,
                           "println!(r##\"accepts: simulating {:?}\"##);",
                           production);
@@ -1653,16 +3455,23 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
                 rust!(self.out, "}}");
             }
         };
-        rust!(self.out,
-              "_ => panic!(\"invalid action code {{}}\", {}action)",
-              self.prefix);
+        if self.session.panic_free {
+            rust!(self.out, "_ => return false,");
+        } else {
+            rust!(self.out,
+                  "_ => panic!(\"invalid action code {{}}\", {}action)",
+                  self.prefix);
+        }
         rust!(self.out, "}};"); // end match
 
+        if self.session.panic_free {
+            rust!(self.out, "if {p}to_pop > {p}states_len {{ return false; }}", p = self.prefix);
+        }
         rust!(self.out, "{p}states_len -= {p}to_pop;", p = self.prefix);
         rust!(self.out, "{p}states.truncate({p}states_len);", p = self.prefix);
-        rust!(self.out, "let {p}top = {p}states[{p}states_len - 1];", p = self.prefix);
+        rust!(self.out, "let {}top = {};", self.prefix, top);
 
-        if DEBUG_PRINT {
+        if self.session.emit_trace {
             rust!(self.out,
                   "println!(\"accepts: popped {{}} symbols, new top is {{}}, nt is {{}}\", \
                    {p}to_pop, \
@@ -1674,11 +3483,22 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
 
         rust!(
             self.out,
-            "let {p}next_state = {p}GOTO[({p}top * {num_non_terminals} + {p}nt) as usize] - 1;",
+            "let {p}next_state = {} as i32 - 1;",
+            self.table_entry("GOTO",
+                             &format!("({p}top * {} + {p}nt) as usize",
+                                      self.grammar.nonterminals.len(),
+                                      p = self.prefix),
+                             "return false"),
             p = self.prefix,
-            num_non_terminals = self.grammar.nonterminals.len(),
         );
 
+        // (a reduction that does not fit in a fixed-size stack would
+        // end the parse with `StackCapacity` rather than accept)
+        if self.session.fixed_stack {
+            rust!(self.out,
+                  "if {p}states.len() >= {p}states.capacity() {{ return false; }}",
+                  p = self.prefix);
+        }
         rust!(self.out, "{p}states.push({p}next_state);", p = self.prefix);
 
         rust!(self.out, "}}"); // end loop
@@ -1698,27 +3518,114 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
         format!("({},{},{})", loc_type, self.symbol_type(), loc_type)
     }
 
-    fn let_unrecognized_token_error(&mut self, error_var: &str, token: &str) -> io::Result<()> {
-        rust!(self.out, "let {}state = *{}states.last().unwrap() as usize;",
-            self.prefix,
-            self.prefix);
+    /// Emits code to build the error for `token`, an
+    /// `Option<(L, T, L)>` expression that is `None` at EOF: an
+    /// `UnrecognizedEof` at `{p}last_location`, an `ExtraToken` if
+    /// the states on the stack would accept EOF instead (unless
+    /// `known_terminal` is false, meaning that the token is not a
+    /// terminal of the grammar at all), or an `UnrecognizedToken`.
+    /// `is_error` is an expression telling whether state `{p}s` has
+    /// no action for the token; if we performed default reductions
+    /// before finding out the token is an error, we use it to find
+    /// the state in which a parser without default reductions would
+    /// have reported the error, so that the expected tokens are the
+    /// same either way (unless there were more default reductions than
+    /// the parse loop remembers).
+    fn let_unrecognized_token_error(&mut self,
+                                    error_var: &str,
+                                    token: &str,
+                                    is_error: &str,
+                                    known_terminal: bool)
+                                    -> io::Result<()> {
+        let top_state = self.top_state(&format!("return Err({})",
+                                                 self.internal_error("empty state stack")));
+        if self.custom.uses_default_reductions() {
+            rust!(self.out,
+                  "let {p}state = {p}default_reduced[..{p}default_reduced_len].iter().cloned()\
+                   .find(|&{p}s| {is_error})\
+                   .unwrap_or({top_state});",
+                  is_error = is_error,
+                  top_state = top_state,
+                  p = self.prefix);
+        } else {
+            rust!(self.out, "let {}state = {};", self.prefix, top_state);
+        }
         rust!(self.out,
-              "let {}{} = {}lalrpop_util::ParseError::UnrecognizedToken {{",
-              self.prefix,
-              error_var,
-              self.prefix);
-        rust!(self.out, "token: {},", token);
-        rust!(self.out, "expected: {}expected_tokens({}state),",
-            self.prefix,
-            self.prefix);
+              "let {p}expected = {p}expected_tokens({p}state);",
+              p = self.prefix);
+        rust!(self.out, "let {}{} = match {} {{", self.prefix, error_var, token);
+        rust!(self.out, "Some({}token) => {{", self.prefix);
+        if known_terminal {
+            // the top state may have been merged with one that
+            // accepts EOF, so simulate the reductions
+            if self.session.fixed_stack {
+                rust!(self.out,
+                      "if {p}states.pop().map_or(false, |{p}top| \
+                       {p}accepts({upr} {p}top, {p}states, None, {phantom})) {{",
+                      upr = self.grammar.user_parameter_refs(),
+                      phantom = self.phantom_data_expr(),
+                      p = self.prefix);
+            } else if self.session.panic_free {
+                rust!(self.out,
+                      "if {p}states.split_last().map_or(false, |(&{p}top, {p}rest)| \
+                       {p}accepts({upr} {p}top, {p}rest, None, {phantom})) {{",
+                      upr = self.grammar.user_parameter_refs(),
+                      phantom = self.phantom_data_expr(),
+                      p = self.prefix);
+            } else {
+                rust!(self.out,
+                      "if {p}accepts({upr} *{p}states.last().unwrap(), \
+                       &{p}states[..{p}states.len() - 1], None, {phantom}) {{",
+                      upr = self.grammar.user_parameter_refs(),
+                      phantom = self.phantom_data_expr(),
+                      p = self.prefix);
+            }
+            rust!(self.out,
+                  "{p}lalrpop_util::ParseError::ExtraToken {{ \
+                   token: {p}token, expected: {p}expected }}",
+                  p = self.prefix);
+            rust!(self.out, "}} else {{");
+        }
+        rust!(self.out,
+              "{p}lalrpop_util::ParseError::UnrecognizedToken {{ \
+               token: Some({p}token), expected: {p}expected }}",
+              p = self.prefix);
+        if known_terminal {
+            rust!(self.out, "}}");
+        }
+        rust!(self.out, "}}");
+        rust!(self.out, "None => {{");
+        rust!(self.out,
+              "{p}lalrpop_util::ParseError::UnrecognizedEof {{ \
+               location: {p}last_location.clone(), expected: {p}expected }}",
+              p = self.prefix);
+        rust!(self.out, "}}");
         rust!(self.out, "}};");
         Ok(())
     }
 
+    /// Emits `{p}expected_tokens`, which lists the tokens that a state
+    /// has actions for. A parser with a fixed-size stack does not
+    /// allocate the list; it has a public `expected_tokens` function
+    /// that iterates over the names of the tokens instead, and reports
+    /// errors with `lalrpop_util::expected`, which only collects them
+    /// if `lalrpop_util` was built with its `std` feature.
     fn emit_expected_tokens_fn(&mut self) -> io::Result<()> {
-        rust!(self.out, "fn {}expected_tokens({}state: usize) -> Vec<::std::string::String> {{",
-            self.prefix,
-            self.prefix);
+        if self.session.fixed_stack {
+            rust!(self.out, "fn {p}expected_tokens({p}state: usize) -> {p}lalrpop_util::Expected {{",
+                  p = self.prefix);
+            rust!(self.out,
+                  "{p}lalrpop_util::expected({p}state, expected_tokens({p}state))",
+                  p = self.prefix);
+            rust!(self.out, "}}");
+            rust!(self.out,
+                  "pub fn expected_tokens({}state: usize) -> impl Iterator<Item = &'static str> {{",
+                  self.prefix);
+        } else {
+            rust!(self.out, "fn {}expected_tokens({}state: usize) -> Vec<::std::string::String> {{",
+                self.prefix,
+                self.prefix);
+        }
 
         rust!(self.out, "const {}TERMINAL: &'static [&'static str] = &[", self.prefix);
         let all_terminals = if self.grammar.uses_error_recovery {
@@ -1735,17 +3642,30 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
         rust!(self.out, "];");
 
         // Grab any terminals in the current state which would have resulted in a successful parse
-        rust!(self.out, "{}ACTION[({}state * {})..].iter().zip({}TERMINAL).filter_map(|(&state, terminal)| {{",
-            self.prefix,
-            self.prefix,
-            self.grammar.terminals.all.len(),
-            self.prefix);
+        if self.session.panic_free {
+            rust!(self.out,
+                  "{p}ACTION.iter().skip({p}state * {}).zip({p}TERMINAL).filter_map(|(&state, terminal)| {{",
+                  self.grammar.terminals.all.len(),
+                  p = self.prefix);
+        } else {
+            rust!(self.out, "{}ACTION[({}state * {})..].iter().zip({}TERMINAL).filter_map(|(&state, terminal)| {{",
+                self.prefix,
+                self.prefix,
+                self.grammar.terminals.all.len(),
+                self.prefix);
+        }
         rust!(self.out, "if state == 0 {{");
         rust!(self.out, "None");
         rust!(self.out, "}} else {{");
-        rust!(self.out, "Some(terminal.to_string())");
-        rust!(self.out, "}}");
-        rust!(self.out, "}}).collect()");
+        if self.session.fixed_stack {
+            rust!(self.out, "Some(*terminal)");
+            rust!(self.out, "}}");
+            rust!(self.out, "}})");
+        } else {
+            rust!(self.out, "Some(terminal.to_string())");
+            rust!(self.out, "}}");
+            rust!(self.out, "}}).collect()");
+        }
         rust!(self.out, "}}");
         Ok(())
     }
@@ -5,18 +5,21 @@
 use grammar::repr::{Grammar, NonterminalString, TypeParameter};
 use lr1::core::*;
 use rust::RustWrite;
+use session::Session;
 use std::io::{self, Write};
 use util::Sep;
 
 use super::base::CodeGenerator;
 
-pub fn compile<'grammar, W: Write>(grammar: &'grammar Grammar,
+pub fn compile<'grammar, W: Write>(session: &Session,
+                                   grammar: &'grammar Grammar,
                                    user_start_symbol: NonterminalString,
                                    start_symbol: NonterminalString,
                                    states: &[LR1State<'grammar>],
                                    out: &mut RustWrite<W>)
                                    -> io::Result<()> {
-    let mut ascent = CodeGenerator::new_test_all(grammar,
+    let mut ascent = CodeGenerator::new_test_all(session,
+                                                 grammar,
                                                  user_start_symbol,
                                                  start_symbol,
                                                  states,
@@ -27,13 +30,15 @@ pub fn compile<'grammar, W: Write>(grammar: &'grammar Grammar,
 struct TestAll;
 
 impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TestAll> {
-    fn new_test_all(grammar: &'grammar Grammar,
+    fn new_test_all(session: &'ascent Session,
+                    grammar: &'grammar Grammar,
                     user_start_symbol: NonterminalString,
                     start_symbol: NonterminalString,
                     states: &'ascent [LR1State<'grammar>],
                     out: &'ascent mut RustWrite<W>)
                     -> Self {
-        CodeGenerator::new(grammar,
+        CodeGenerator::new(session,
+                           grammar,
                            user_start_symbol,
                            start_symbol,
                            states,
@@ -48,7 +53,8 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TestAll> {
             try!(this.write_parser_fn());
 
             rust!(this.out, "mod {}ascent {{", this.prefix);
-            try!(super::ascent::compile(this.grammar,
+            try!(super::ascent::compile(this.session,
+                                        this.grammar,
                                         this.user_start_symbol,
                                         this.start_symbol,
                                         this.states,
@@ -62,19 +68,38 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TestAll> {
             rust!(this.out, "}}");
 
             rust!(this.out, "mod {}parse_table {{", this.prefix);
-            try!(super::parse_table::compile(this.grammar,
+            try!(super::parse_table::compile(this.session,
+                                             this.grammar,
                                              this.user_start_symbol,
                                              this.start_symbol,
                                              this.states,
                                              "super::super::super",
                                              this.out));
             rust!(this.out,
-                  "pub use self::{}parse{}::parse_{};",
+                  "pub use self::{}parse_table::parse_{};",
+                  this.prefix,
+                  this.user_start_symbol);
+            rust!(this.out,
+                  "pub use self::{}parse_table::parse_{}_cancelable;",
+                  this.prefix,
+                  this.user_start_symbol);
+            rust!(this.out,
+                  "pub use self::{}parse_table::{}Parser;",
                   this.prefix,
-                  this.start_symbol,
                   this.user_start_symbol);
             rust!(this.out, "}}");
 
+            // only the table-driven parser has a reusable parser
+            // struct, and can be canceled, so that is what we export
+            rust!(this.out,
+                  "pub use self::{}parse_table::parse_{}_cancelable;",
+                  this.prefix,
+                  this.user_start_symbol);
+            rust!(this.out,
+                  "pub use self::{}parse_table::{}Parser;",
+                  this.prefix,
+                  this.user_start_symbol);
+
             Ok(())
         })
     }
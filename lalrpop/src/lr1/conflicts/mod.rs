@@ -0,0 +1,190 @@
+//! Writes the conflicts found while building the LR(1) states as
+//! JSON (`--emit conflicts`), so that the set of conflicts of a
+//! grammar can be diffed from one revision to the next. The output
+//! is an array with one object per conflict (and lookahead token):
+//!
+//! ```text
+//! [
+//!   {
+//!     "nonterminal": "Expr",
+//!     "state": 5,
+//!     "lookahead": "\"+\"",
+//!     "reduce": "Expr = Expr \"+\" Expr",
+//!     "action": "shift",
+//!     "items": [
+//!       "Expr = Expr \"+\" Expr (*) [\"+\", EOF]",
+//!       "Expr = Expr (*) \"+\" Expr [\"+\", EOF]"
+//!     ],
+//!     "action_example": { "symbols": ["Expr", "\"+\"", "Expr", "\"+\"", "Expr"], "cursor": 3 },
+//!     "reduce_example": { "symbols": ["Expr", "\"+\"", "Expr", "\"+\"", "Expr"], "cursor": 3 }
+//!   }
+//! ]
+//! ```
+//!
+//! `action` is either `"shift"` or the production of the competing
+//! reduction. The examples are sequences of symbols, with `cursor`
+//! being the number of symbols seen before the conflict arises; they
+//! are `null` if none could be found.
+//!
+//! This module also reads and writes conflict baselines (see
+//! `Configuration::check_conflict_baseline`): the fingerprints of the
+//! conflicts a grammar is known to have, one per line, like
+//!
+//! ```text
+//! Expr | "+" | reduce Expr = Expr "+" Expr | shift
+//! ```
+//!
+//! with the public nonterminal, the lookahead token and the two
+//! actions that conflict. Lines starting with `#` are comments.
+
+use collections::Set;
+use file_text::FileText;
+use grammar::repr::*;
+use lr1::core::*;
+use lr1::error::conflict_examples;
+use lr1::example::{Example, ExampleSymbol};
+use session::Session;
+use std::io::{self, Write};
+
+#[cfg(test)]
+mod test;
+
+/// Appends a JSON object for each conflict in `error`, which was
+/// found while building the states for `nonterminal`, to `entries`.
+/// (The entries refer to terminals, so the `Lr1Tls` must be
+/// installed when calling this, but not when writing them out.)
+pub fn dump_conflicts<'grammar>(session: &Session,
+                                file_text: &FileText,
+                                grammar: &'grammar Grammar,
+                                nonterminal: NonterminalString,
+                                error: &LR1TableConstructionError<'grammar>,
+                                entries: &mut Vec<String>) {
+    let examples = conflict_examples(session, file_text, grammar, error);
+    for (conflict, action_example, reduce_example) in examples {
+        let items: Vec<String> =
+            error.states[conflict.state.0]
+                .items
+                .vec
+                .iter()
+                .map(|item| format!("      {}", json_string(&format!("{:?}", item))))
+                .collect();
+        let action = match conflict.action {
+            Action::Shift(..) => String::from("shift"),
+            Action::Reduce(production) => format!("reduce {}", production),
+        };
+
+        let mut entry = String::new();
+        entry.push_str("  {\n");
+        entry.push_str(&format!("    \"nonterminal\": {},\n",
+                                json_string(&nonterminal.to_string())));
+        entry.push_str(&format!("    \"state\": {},\n", conflict.state.0));
+        entry.push_str(&format!("    \"lookahead\": {},\n",
+                                json_string(&conflict.lookahead.to_string())));
+        entry.push_str(&format!("    \"reduce\": {},\n",
+                                json_string(&conflict.production.to_string())));
+        entry.push_str(&format!("    \"action\": {},\n", json_string(&action)));
+        entry.push_str(&format!("    \"items\": [\n{}\n    ],\n", items.join(",\n")));
+        entry.push_str(&format!("    \"action_example\": {},\n",
+                                example_json(action_example.as_ref())));
+        entry.push_str(&format!("    \"reduce_example\": {}\n",
+                                example_json(reduce_example.as_ref())));
+        entry.push_str("  }");
+        entries.push(entry);
+    }
+}
+
+/// Writes the array of the `entries` collected by `dump_conflicts`.
+/// If there are none, this is just `[]`.
+pub fn write_conflicts<W: Write>(out: &mut W, entries: &[String]) -> io::Result<()> {
+    if entries.is_empty() {
+        return writeln!(out, "[]");
+    }
+    try!(writeln!(out, "["));
+    try!(writeln!(out, "{}", entries.join(",\n")));
+    writeln!(out, "]")
+}
+
+/// The fingerprints of `conflict`, which was found while building the
+/// states for `nonterminal`: one for each of its lookahead tokens.
+/// They leave out the state, so that they stay the same when other
+/// parts of the grammar change.
+pub fn conflict_fingerprints(nonterminal: NonterminalString,
+                             conflict: &LR1Conflict)
+                             -> Vec<String> {
+    let mut actions = [format!("reduce {}", conflict.production),
+                       match conflict.action {
+                           Action::Shift(..) => String::from("shift"),
+                           Action::Reduce(production) =>
+                               format!("reduce {}", production),
+                       }];
+    actions.sort();
+    conflict.lookahead
+            .iter()
+            .map(|token| format!("{} | {} | {} | {}", nonterminal, token, actions[0], actions[1]))
+            .collect()
+}
+
+/// Adds the fingerprints in the baseline `text` to `fingerprints`.
+pub fn parse_baseline(text: &str, fingerprints: &mut Set<String>) {
+    for line in text.lines() {
+        let line = line.trim();
+        if !line.is_empty() && !line.starts_with('#') {
+            fingerprints.insert(line.to_string());
+        }
+    }
+}
+
+/// Writes a baseline of the `fingerprints`.
+pub fn write_baseline<W: Write>(out: &mut W, fingerprints: &Set<String>) -> io::Result<()> {
+    try!(writeln!(out, "# The conflicts that LALRPOP tolerates in this grammar; new ones"));
+    try!(writeln!(out, "# are still errors. Remove lines as the conflicts are fixed."));
+    for fingerprint in fingerprints {
+        try!(writeln!(out, "{}", fingerprint));
+    }
+    Ok(())
+}
+
+/// The symbols of `example`, along with the number of them that come
+/// before the cursor. The empty space that examples use to show where
+/// nullable nonterminals were reduced is left out.
+pub fn example_symbols(example: &Example) -> (Vec<String>, usize) {
+    let mut symbols = vec![];
+    let mut cursor = 0;
+    for (index, symbol) in example.symbols.iter().enumerate() {
+        if let ExampleSymbol::Symbol(symbol) = *symbol {
+            symbols.push(symbol.to_string());
+            if index < example.cursor {
+                cursor += 1;
+            }
+        }
+    }
+    (symbols, cursor)
+}
+
+fn example_json(example: Option<&Example>) -> String {
+    let example = match example {
+        Some(example) => example,
+        None => return String::from("null"),
+    };
+
+    let (symbols, cursor) = example_symbols(example);
+    let symbols: Vec<String> = symbols.iter().map(|s| json_string(s)).collect();
+    format!("{{ \"symbols\": [{}], \"cursor\": {} }}", symbols.join(", "), cursor)
+}
+
+pub fn json_string(s: &str) -> String {
+    let mut json = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
@@ -0,0 +1,86 @@
+use collections::set;
+use file_text::FileText;
+use intern::intern;
+use grammar::repr::*;
+use lr1::{build_canonical_states, build_states};
+use lr1::tls::Lr1Tls;
+use session::Session;
+use test_util::normalized_grammar;
+use super::{conflict_fingerprints, dump_conflicts, parse_baseline, write_baseline,
+            write_conflicts};
+
+#[test]
+fn expr() {
+    let grammar = normalized_grammar(r#"
+grammar;
+    pub E: () = { E "+" E => (), "x" => () };
+"#);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let nt = NonterminalString(intern("E"));
+    let error = build_states(&Session::test(), &grammar, grammar.start_nonterminals[&nt]).unwrap_err();
+    let mut entries = vec![];
+    dump_conflicts(&Session::test(), &FileText::test(), &grammar, nt, &error, &mut entries);
+    let mut out = vec![];
+    write_conflicts(&mut out, &entries).unwrap();
+    let out = String::from_utf8(out).unwrap();
+    assert_eq!(out.trim(), r#"[
+  {
+    "nonterminal": "E",
+    "state": 4,
+    "lookahead": "\"+\"",
+    "reduce": "E = E \"+\" E",
+    "action": "shift",
+    "items": [
+      "E = E (*) \"+\" E [\"+\", \"x\", EOF]",
+      "E = E \"+\" E (*) [\"+\", \"x\", EOF]"
+    ],
+    "action_example": { "symbols": ["E", "\"+\"", "E", "\"+\"", "E"], "cursor": 3 },
+    "reduce_example": { "symbols": ["E", "\"+\"", "E", "\"+\"", "E"], "cursor": 3 }
+  }
+]"#);
+}
+
+#[test]
+fn no_conflicts() {
+    let mut out = vec![];
+    write_conflicts(&mut out, &[]).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "[]\n");
+}
+
+#[test]
+fn fingerprints() {
+    let grammar = normalized_grammar(r#"
+grammar;
+    pub S: () = { A "x" => (), B "x" => (), S "+" S => () };
+    A: () = "N" => ();
+    B: () = "N" => ();
+"#);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let nt = NonterminalString(intern("S"));
+    let start = grammar.start_nonterminals[&nt];
+    let error = build_canonical_states(&Session::test(), &grammar, start).unwrap().unwrap_err();
+    let mut fingerprints = set();
+    for conflict in &error.conflicts {
+        fingerprints.extend(conflict_fingerprints(nt, conflict));
+    }
+    let fingerprints: Vec<_> = fingerprints.into_iter().collect();
+    assert_eq!(fingerprints, vec![
+        r#"S | "+" | reduce S = S "+" S | shift"#,
+        r#"S | "x" | reduce A = "N" | reduce B = "N""#,
+    ]);
+}
+
+#[test]
+fn baseline_round_trip() {
+    let mut fingerprints = set();
+    fingerprints.insert(String::from(r#"S | "+" | reduce S = S "+" S | shift"#));
+    fingerprints.insert(String::from(r#"S | "x" | reduce A = "N" | reduce B = "N""#));
+    let mut out = vec![];
+    write_baseline(&mut out, &fingerprints).unwrap();
+    let out = String::from_utf8(out).unwrap();
+    assert!(out.starts_with("# "));
+
+    let mut parsed = set();
+    parse_baseline(&format!("{}\n  # fixed:\n", out), &mut parsed);
+    assert_eq!(parsed, fingerprints);
+}
@@ -125,6 +125,21 @@ pub struct State<'grammar, L: Lookahead> {
     pub shifts: Map<TerminalString, StateIndex>,
     pub reductions: Vec<(L, &'grammar Production)>,
     pub gotos: Map<NonterminalString, StateIndex>,
+
+    /// reductions that depend on the token after the next one, where
+    /// the next one alone leaves a conflict; see `two_tokens`
+    pub two_token_reductions: Vec<TwoTokenReduction<'grammar>>,
+}
+
+/// On `terminal`, reduce `production` if the token after it is one of
+/// `following`. If the token after it is none of those of the state's
+/// two-token reductions on `terminal`, the state shifts `terminal` if
+/// it can, and otherwise takes the first of those reductions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TwoTokenReduction<'grammar> {
+    pub terminal: TerminalString,
+    pub following: TokenSet,
+    pub production: &'grammar Production,
 }
 
 pub type LR0State<'grammar> = State<'grammar, Nil>;
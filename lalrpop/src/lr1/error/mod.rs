@@ -1,6 +1,8 @@
 //! Error reporting. For now very stupid and simplistic.
 
 use collections::{set, Set};
+use file_text::FileText;
+use lr1::build_states;
 use lr1::trace::Tracer;
 use lr1::core::*;
 use lr1::example::{Example, ExampleStyles, ExampleSymbol};
@@ -10,19 +12,91 @@ use itertools::Itertools;
 use grammar::repr::*;
 use message::{Message};
 use message::builder::{Builder, BodyCharacter, Character, MessageBuilder};
-use tls::Tls;
+use intern::intern;
+use normalize::inline_nt;
+use session::Session;
+use util::Sep;
 
 #[cfg(test)] mod test;
 
-pub fn report_error(grammar: &Grammar,
+pub fn report_error(session: &Session,
+                    file_text: &FileText,
+                    grammar: &Grammar,
                     error: &LR1TableConstructionError)
                     -> Vec<Message>
 {
-    let mut cx = ErrorReportingCx::new(grammar, &error.states, &error.conflicts);
+    let mut cx = ErrorReportingCx::new(session, file_text, grammar,
+                                       &error.states, &error.conflicts);
     cx.report_errors()
 }
 
+/// Splits the conflicts in `error` up by lookahead token, dropping
+/// duplicates.
+pub fn distinct_token_conflicts<'grammar>(error: &LR1TableConstructionError<'grammar>)
+                                          -> Vec<TokenConflict<'grammar>>
+{
+    let mut conflicts: Vec<TokenConflict<'grammar>> = vec![];
+    for conflict in token_conflicts(&error.conflicts) {
+        if !conflicts.contains(&conflict) {
+            conflicts.push(conflict);
+        }
+    }
+    conflicts
+}
+
+/// Pairs each of the `distinct_token_conflicts` of `error` with the
+/// shortest example of the conflicting action and of the reduction,
+/// if any can be found.
+pub fn conflict_examples<'grammar>(session: &Session,
+                                   file_text: &FileText,
+                                   grammar: &'grammar Grammar,
+                                   error: &LR1TableConstructionError<'grammar>)
+                                   -> Vec<(TokenConflict<'grammar>, Option<Example>, Option<Example>)>
+{
+    let cx = ErrorReportingCx::new(session, file_text, grammar,
+                                   &error.states, &error.conflicts);
+    distinct_token_conflicts(error)
+        .into_iter()
+        .map(|conflict| {
+            let (action_examples, reduce_examples) = cx.examples(&conflict);
+            let action_example = action_examples.into_iter().next();
+            let reduce_example = reduce_examples.into_iter().next();
+            (conflict, action_example, reduce_example)
+        })
+        .collect()
+}
+
+/// All the examples of the conflicting action and of the reduction
+/// of `conflict` (one of the conflicts in `error`), shortest first.
+pub fn conflict_traces<'grammar>(session: &Session,
+                                 file_text: &FileText,
+                                 grammar: &'grammar Grammar,
+                                 error: &LR1TableConstructionError<'grammar>,
+                                 conflict: &TokenConflict<'grammar>)
+                                 -> (Vec<Example>, Vec<Example>)
+{
+    let cx = ErrorReportingCx::new(session, file_text, grammar,
+                                   &error.states, &error.conflicts);
+    cx.examples(conflict)
+}
+
+/// The usual message explaining `conflict`, one of the conflicts in
+/// `error`.
+pub fn report_conflict<'grammar>(session: &Session,
+                                 file_text: &FileText,
+                                 grammar: &'grammar Grammar,
+                                 error: &LR1TableConstructionError<'grammar>,
+                                 conflict: &TokenConflict<'grammar>)
+                                 -> Message
+{
+    let mut cx = ErrorReportingCx::new(session, file_text, grammar,
+                                       &error.states, &error.conflicts);
+    cx.report_error(conflict)
+}
+
 struct ErrorReportingCx<'cx, 'grammar: 'cx> {
+    session: &'cx Session,
+    file_text: &'cx FileText,
     grammar: &'grammar Grammar,
     first_sets: FirstSets,
     states: &'cx [LR1State<'grammar>],
@@ -42,11 +116,20 @@ enum ConflictClassification {
     /// between two instances of `T`.
     Precedence { shift: Example, reduce: Example, nonterminal: NonterminalString },
 
+    /// The grammar is ambiguous in the manner of the classic
+    /// "dangling else": the reduction completes an alternative of
+    /// `nonterminal` that is a prefix of another alternative, which
+    /// continues with `terminal`.
+    DanglingElse { shift: Example, reduce: Example,
+                   nonterminal: NonterminalString, terminal: TerminalString },
+
     /// Suggest inlining `nonterminal`. Makes sense if there are two
     /// levels in the reduction tree in both examples, and the suffix
-    /// after the inner reduction is the same in all cases.
+    /// after the inner reduction is the same in all cases. If
+    /// `verified` is true, we actually tried inlining `nonterminal`
+    /// and the conflict went away.
     SuggestInline { shift: Example, reduce: Example,
-                    nonterminal: NonterminalString },
+                    nonterminal: NonterminalString, verified: bool },
 
     /// Like the previous, but suggest replacing `nonterminal` with
     /// `symbol?`. Makes sense if the thing to be inlined consists of
@@ -54,6 +137,24 @@ enum ConflictClassification {
     SuggestQuestion { shift: Example, reduce: Example,
                       nonterminal: NonterminalString, symbol: Symbol },
 
+    /// `nonterminal` is a right-recursive list separated by
+    /// `terminal`, and the list may also be followed by a trailing
+    /// `terminal`. Makes sense if reducing `X = ...` competes with
+    /// shifting `terminal` in `X = ... terminal X`.
+    TrailingSeparator { shift: Example, reduce: Example,
+                        nonterminal: NonterminalString, terminal: TerminalString },
+
+    /// Two alternatives of a nonterminal begin with the same symbols
+    /// once the nonterminals where they part ways are expanded, and
+    /// factoring that common prefix out removes the conflict.
+    LeftFactor { action: Example, reduce: Example, factoring: LeftFactoring },
+
+    /// Two distinct nonterminals can be reduced from exactly the same
+    /// symbols, and the lookahead does not tell them apart. Makes
+    /// sense for reduce/reduce conflicts only.
+    OverlappingPrefix { action: Example, reduce: Example,
+                        nonterminals: (NonterminalString, NonterminalString) },
+
     /// Can't say much beyond that a conflict occurred.
     InsufficientLookahead { action: Example, reduce: Example },
 
@@ -61,14 +162,38 @@ enum ConflictClassification {
     Naive,
 }
 
-type TokenConflict<'grammar> = Conflict<'grammar, Token>;
+/// Alternatives `i` and `j` of `nonterminal`, rewritten as the single
+/// alternative `prefix tail`, where `tail` is a new nonterminal with
+/// the alternatives `rests.0` and `rests.1`.
+#[derive(Debug)]
+struct LeftFactoring {
+    nonterminal: NonterminalString,
+    alternatives: (usize, usize),
+    prefix: Vec<Symbol>,
+    tail: NonterminalString,
+    rests: (Vec<Symbol>, Vec<Symbol>),
+}
+
+/// The most nonterminals we expand while looking for the common
+/// prefix of two alternatives.
+const MAX_EXPANSIONS: usize = 8;
+
+/// The most nonterminals we try inlining, rebuilding the states each
+/// time, to find one that resolves a conflict.
+const MAX_INLINING_CHECKS: usize = 4;
+
+pub type TokenConflict<'grammar> = Conflict<'grammar, Token>;
 
 impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
-    fn new(grammar: &'grammar Grammar,
+    fn new(session: &'cx Session,
+           file_text: &'cx FileText,
+           grammar: &'grammar Grammar,
            states: &'cx [LR1State<'grammar>],
            conflicts: &'cx [LR1Conflict<'grammar>])
            -> Self {
         ErrorReportingCx {
+            session: session,
+            file_text: file_text,
             grammar: grammar,
             first_sets: FirstSets::new(grammar),
             states: states,
@@ -91,10 +216,13 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
             ConflictClassification::Precedence { shift, reduce, nonterminal } => {
                 self.report_error_precedence(conflict, shift, reduce, nonterminal)
             }
-            ConflictClassification::SuggestInline { shift, reduce, nonterminal } => {
+            ConflictClassification::DanglingElse { shift, reduce, nonterminal, terminal } => {
+                self.report_error_dangling_else(conflict, shift, reduce, nonterminal, terminal)
+            }
+            ConflictClassification::SuggestInline { shift, reduce, nonterminal, verified } => {
                 self.report_error_suggest_inline(conflict,
                                                  shift, reduce,
-                                                 nonterminal)
+                                                 nonterminal, verified)
             }
             ConflictClassification::SuggestQuestion { shift, reduce,
                                                       nonterminal, symbol } => {
@@ -102,6 +230,18 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
                                                    shift, reduce,
                                                    nonterminal, symbol)
             }
+            ConflictClassification::TrailingSeparator { shift, reduce,
+                                                        nonterminal, terminal } => {
+                self.report_error_trailing_separator(conflict,
+                                                     shift, reduce,
+                                                     nonterminal, terminal)
+            }
+            ConflictClassification::LeftFactor { action, reduce, factoring } => {
+                self.report_error_left_factor(conflict, action, reduce, factoring)
+            }
+            ConflictClassification::OverlappingPrefix { action, reduce, nonterminals } => {
+                self.report_error_overlapping_prefix(conflict, action, reduce, nonterminals)
+            }
             ConflictClassification::InsufficientLookahead { action, reduce } => {
                 self.report_error_insufficient_lookahead(conflict, action, reduce)
             }
@@ -116,8 +256,8 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
                                    shift: Example,
                                    reduce: Example)
                                    -> Builder<BodyCharacter> {
-        let styles = ExampleStyles::ambig();
-        MessageBuilder::new(conflict.production.span)
+        let styles = ExampleStyles::ambig(self.session);
+        MessageBuilder::new(self.session, self.file_text, conflict.production.span)
             .heading()
             .text("Ambiguous grammar detected")
             .end()
@@ -130,12 +270,12 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
 
             .begin_lines()
             .wrap_text("They could be reduced like so:")
-            .push(reduce.into_picture(styles))
+            .push(reduce.into_picture(self.session, styles))
             .end()
 
             .begin_lines()
             .wrap_text("Alternatively, they could be reduced like so:")
-            .push(shift.into_picture(styles))
+            .push(shift.into_picture(self.session, styles))
             .end()
     }
 
@@ -161,9 +301,9 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
         self.report_error_ambiguity_core(conflict, shift, reduce)
             .begin_wrap()
             .text("Hint:")
-            .styled(Tls::session().hint_text)
+            .styled(self.session.hint_text)
             .text("This looks like a precedence error related to")
-            .push(nonterminal)
+            .push(nonterminal.into_content(self.session))
             .verbatimed()
             .punctuated(".")
             .text("See the LALRPOP manual for advice on encoding precedence.")
@@ -172,14 +312,46 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
             .end()
     }
 
+    fn report_error_dangling_else(&self,
+                                  conflict: &TokenConflict<'grammar>,
+                                  shift: Example,
+                                  reduce: Example,
+                                  nonterminal: NonterminalString,
+                                  terminal: TerminalString)
+                                  -> Message {
+        self.report_error_ambiguity_core(conflict, shift, reduce)
+            .begin_wrap()
+            .text("Hint:")
+            .styled(self.session.hint_text)
+            .text("This looks like the \"dangling else\" problem: when")
+            .push(nonterminal.into_content(self.session))
+            .verbatimed()
+            .text("is nested inside itself, a following")
+            .push(terminal.into_content(self.session))
+            .verbatimed()
+            .text("could belong to either the inner or the outer one.")
+            .text("Consider splitting")
+            .push(nonterminal.into_content(self.session))
+            .verbatimed()
+            .text("into a variant that may end in the shorter alternative")
+            .text("and a variant that may not, and using the latter")
+            .text("before")
+            .push(terminal.into_content(self.session))
+            .verbatimed()
+            .punctuated(".")
+            .end()
+            .end()
+            .end()
+    }
+
     fn report_error_not_lr1_core(&self,
                                  conflict: &TokenConflict<'grammar>,
                                  action: Example,
                                  reduce: Example)
                                  -> Builder<BodyCharacter> {
-        let styles = ExampleStyles::new();
+        let styles = ExampleStyles::new(self.session);
         let builder =
-            MessageBuilder::new(conflict.production.span)
+            MessageBuilder::new(self.session, self.file_text, conflict.production.span)
             .heading()
             .text("Local ambiguity detected")
             .end()
@@ -203,9 +375,9 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
             Token::Terminal(term) => {
                 builder
                     .text("At that point, if the next token is a")
-                    .push(term)
+                    .push(term.into_content(self.session))
                     .verbatimed()
-                    .styled(Tls::session().cursor_symbol)
+                    .styled(self.session.cursor_symbol)
                     .punctuated(",")
             }
             Token::Error =>
@@ -259,15 +431,15 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
             .text(intro_word)
             .punctuated(",")
             .text("the parser could shift the")
-            .push(lookahead)
+            .push(lookahead.into_content(self.session))
             .verbatimed()
             .text("token and later use it to construct a")
-            .push(nt1)
+            .push(nt1.into_content(self.session))
             .verbatimed()
             .punctuated(".")
             .text("This might then yield a parse tree like")
             .end()
-            .push(example.into_picture(styles))
+            .push(example.into_picture(self.session, styles))
             .end()
     }
 
@@ -285,18 +457,18 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
             .text(intro_word)
             .punctuated(",")
             .text("the parser could execute the production at")
-            .push(production.span)
+            .push(production.span.into_content(self.file_text))
             .punctuated(",")
             .text("which would consume the top")
             .text(production.symbols.len())
             .text("token(s) from the stack")
             .text("and produce a")
-            .push(production.nonterminal)
+            .push(production.nonterminal.into_content(self.session))
             .verbatimed()
             .punctuated(".")
             .text("This might then yield a parse tree like")
             .end()
-            .push(example.into_picture(styles))
+            .push(example.into_picture(self.session, styles))
             .end()
     }
 
@@ -304,20 +476,34 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
                                    conflict: &TokenConflict<'grammar>,
                                    shift: Example,
                                    reduce: Example,
-                                   nonterminal: NonterminalString)
+                                   nonterminal: NonterminalString,
+                                   verified: bool)
                                    -> Message
     {
         let builder = self.report_error_not_lr1_core(conflict, shift, reduce);
 
-        builder
+        let builder = builder
             .begin_wrap()
             .text("Hint:")
-            .styled(Tls::session().hint_text)
-            .text("It appears you could resolve this problem by adding")
-            .text("the annotation `#[inline]` to the definition of")
-            .push(nonterminal)
-            .verbatimed()
-            .punctuated(".")
+            .styled(self.session.hint_text);
+
+        let builder = if verified {
+            builder
+                .text("Inlining")
+                .push(nonterminal.into_content(self.session))
+                .verbatimed()
+                .text("removes this conflict, so you can resolve this problem")
+                .text("by adding the annotation `#[inline]` to its definition.")
+        } else {
+            builder
+                .text("It appears you could resolve this problem by adding")
+                .text("the annotation `#[inline]` to the definition of")
+                .push(nonterminal.into_content(self.session))
+                .verbatimed()
+                .punctuated(".")
+        };
+
+        builder
             .text("For more information, see the section on inlining")
             .text("in the LALRPOP manual.")
             .end()
@@ -338,17 +524,17 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
         builder
             .begin_wrap()
             .text("Hint:")
-            .styled(Tls::session().hint_text)
+            .styled(self.session.hint_text)
             .text("It appears you could resolve this problem by replacing")
             .text("uses of")
-            .push(nonterminal)
+            .push(nonterminal.into_content(self.session))
             .verbatimed()
             .text("with")
             .text(symbol) // intentionally disable coloring here, looks better
             .adjacent_text("`", "?`")
             .text("(or, alternatively, by adding the annotation `#[inline]` \
                    to the definition of")
-            .push(nonterminal)
+            .push(nonterminal.into_content(self.session))
             .punctuated(").")
             .text("For more information, see the section on inlining")
             .text("in the LALROP manual.")
@@ -357,6 +543,130 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
             .end()
     }
 
+    fn report_error_trailing_separator(&self,
+                                       conflict: &TokenConflict<'grammar>,
+                                       shift: Example,
+                                       reduce: Example,
+                                       nonterminal: NonterminalString,
+                                       terminal: TerminalString)
+                                       -> Message
+    {
+        let builder = self.report_error_not_lr1_core(conflict, shift, reduce);
+
+        builder
+            .begin_wrap()
+            .text("Hint:")
+            .styled(self.session.hint_text)
+            .text("It appears that")
+            .push(nonterminal.into_content(self.session))
+            .verbatimed()
+            .text("is a list separated by")
+            .push(terminal.into_content(self.session))
+            .verbatimed()
+            .text("that may also be followed by a trailing")
+            .push(terminal.into_content(self.session))
+            .verbatimed()
+            .punctuated(".")
+            .text("Because the list is right-recursive, the parser must decide")
+            .text("whether another element follows before it can see past the")
+            .push(terminal.into_content(self.session))
+            .verbatimed()
+            .punctuated(".")
+            .text("Consider making")
+            .push(nonterminal.into_content(self.session))
+            .verbatimed()
+            .text("left-recursive, or using a macro like `Comma<T>`")
+            .text("as described in the LALRPOP manual.")
+            .end()
+            .end()
+            .end()
+    }
+
+    fn report_error_left_factor(&self,
+                                conflict: &TokenConflict<'grammar>,
+                                action: Example,
+                                reduce: Example,
+                                factoring: LeftFactoring)
+                                -> Message
+    {
+        let builder = self.report_error_not_lr1_core(conflict, action, reduce);
+
+        let productions = self.grammar.productions_for(factoring.nonterminal);
+        let (i, j) = factoring.alternatives;
+        // if the nonterminal has other alternatives, these stay as
+        // they are and only the new one is shown
+        let alternative = format!("{} {}", Sep(" ", &factoring.prefix), factoring.tail);
+        let rewrite = vec![
+            if productions.len() == 2 {
+                format!("{} = {};", factoring.nonterminal, alternative)
+            } else {
+                format!("{},", alternative)
+            },
+            format!("{} = {{", factoring.tail),
+            format!("    {},", symbols_str(&factoring.rests.0)),
+            format!("    {},", symbols_str(&factoring.rests.1)),
+            String::from("};"),
+        ];
+
+        let builder = builder
+            .begin_wrap()
+            .text("Hint:")
+            .styled(self.session.hint_text)
+            .text("The alternatives of")
+            .push(factoring.nonterminal.into_content(self.session))
+            .verbatimed()
+            .text("at")
+            .push(productions[i].span.into_content(self.file_text))
+            .text("and")
+            .push(productions[j].span.into_content(self.file_text))
+            .text("begin with the same symbols, and factoring these out")
+            .text("removes this conflict. Replace the two alternatives with:")
+            .end()
+            .begin_lines();
+
+        let builder = rewrite.into_iter().fold(builder, |builder, line| builder.text(line));
+
+        builder
+            .end()
+            .indented()
+            .begin_wrap()
+            .text("and move their action code into")
+            .push(factoring.tail.into_content(self.session))
+            .verbatimed()
+            .punctuated(".")
+            .end()
+            .end()
+            .end()
+    }
+
+    fn report_error_overlapping_prefix(&self,
+                                       conflict: &TokenConflict<'grammar>,
+                                       action: Example,
+                                       reduce: Example,
+                                       nonterminals: (NonterminalString, NonterminalString))
+                                       -> Message
+    {
+        let builder = self.report_error_not_lr1_core(conflict, action, reduce);
+
+        builder
+            .begin_wrap()
+            .text("Hint:")
+            .styled(self.session.hint_text)
+            .text("Both")
+            .push(nonterminals.0.into_content(self.session))
+            .verbatimed()
+            .text("and")
+            .push(nonterminals.1.into_content(self.session))
+            .verbatimed()
+            .text("can be produced from the same symbols here, and the parser")
+            .text("only finds out which one was meant later on.")
+            .text("Consider factoring them into a single nonterminal")
+            .text("and telling the cases apart in the rule that uses it.")
+            .end()
+            .end()
+            .end()
+    }
+
     fn report_error_insufficient_lookahead(&self,
                                            conflict: &TokenConflict<'grammar>,
                                            action: Example,
@@ -394,7 +704,7 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
                           conflict: &TokenConflict<'grammar>)
                           -> Message {
         let mut builder =
-            MessageBuilder::new(conflict.production.span)
+            MessageBuilder::new(self.session, self.file_text, conflict.production.span)
             .heading()
             .text("Conflict detected")
             .end()
@@ -410,7 +720,7 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
                    .begin_wrap()
                    .text(format!("and looking at a token `{:?}`", conflict.lookahead))
                    .text("we can reduce to a")
-                   .push(conflict.production.nonterminal)
+                   .push(conflict.production.nonterminal.into_content(self.session))
                    .verbatimed();
         builder = match conflict.action {
             Action::Shift(..) =>
@@ -426,23 +736,7 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
     }
 
     fn classify(&mut self, conflict: &TokenConflict<'grammar>) -> ConflictClassification {
-        // Find examples from the conflicting action (either a shift
-        // or a reduce).
-        let mut action_examples = match conflict.action {
-            Action::Shift(..) => self.shift_examples(conflict),
-            Action::Reduce(production) => self.reduce_examples(conflict.state,
-                                                               production,
-                                                               conflict.lookahead)
-        };
-
-        // Find examples from the conflicting reduce.
-        let mut reduce_examples = self.reduce_examples(conflict.state,
-                                                       conflict.production,
-                                                       conflict.lookahead);
-
-        // Prefer shorter examples to longer ones.
-        action_examples.sort_by(|e, f| e.symbols.len().cmp(&f.symbols.len()));
-        reduce_examples.sort_by(|e, f| e.symbols.len().cmp(&f.symbols.len()));
+        let (action_examples, reduce_examples) = self.examples(conflict);
 
         // This really shouldn't happen, but if we've failed to come
         // up with examples, then report a "naive" error.
@@ -462,6 +756,24 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
             return classification;
         }
 
+        if let Some(classification) = self.try_classify_trailing_separator(conflict,
+                                                                           &action_examples,
+                                                                           &reduce_examples) {
+            return classification;
+        }
+
+        if let Some(classification) = self.try_classify_overlapping_prefix(conflict,
+                                                                           &action_examples,
+                                                                           &reduce_examples) {
+            return classification;
+        }
+
+        if let Some(classification) = self.try_classify_left_factor(conflict,
+                                                                    &action_examples,
+                                                                    &reduce_examples) {
+            return classification;
+        }
+
         if let Some(classification) = self.try_classify_inline(conflict,
                                                                &action_examples,
                                                                &reduce_examples) {
@@ -511,6 +823,17 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
                             nonterminal: conflict.production.nonterminal,
                         };
                     }
+
+                    // Or a dangling else, if we are stuck between
+                    // reducing `T = X` and shifting `S` in `T = X S Y`.
+                    if self.extending_production(conflict.production, term).is_some() {
+                        return ConflictClassification::DanglingElse {
+                            shift: action.clone(),
+                            reduce: reduce.clone(),
+                            nonterminal: nt,
+                            terminal: term,
+                        };
+                    }
                 }
                 ConflictClassification::Ambiguity {
                     action: action.clone(),
@@ -538,8 +861,10 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
             return None;
         }
 
-        debug!("try_classify_question: action_examples={:?}", action_examples);
-        debug!("try_classify_question: reduce_examples={:?}", reduce_examples);
+        log!(self.session, Debug, "try_classify_question: action_examples={:?}",
+             action_examples);
+        log!(self.session, Debug, "try_classify_question: reduce_examples={:?}",
+             reduce_examples);
 
         let nt = conflict.production.nonterminal;
         let nt_productions = self.grammar.productions_for(nt);
@@ -562,6 +887,252 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
         None
     }
 
+    fn try_classify_trailing_separator(&self,
+                                       conflict: &TokenConflict<'grammar>,
+                                       action_examples: &[Example],
+                                       reduce_examples: &[Example])
+                                       -> Option<ConflictClassification> {
+        // If we get a shift/reduce conflict between reducing
+        //
+        //     T = X
+        //
+        // and shifting `S` in
+        //
+        //     T = X S T
+        //
+        // then `T` is a right-recursive list separated by `S`, and
+        // the only way for `S` to also follow `T` is as a trailing
+        // separator.
+        let term = match conflict.action {
+            Action::Shift(term, _) => term,
+            Action::Reduce(_) => return None,
+        };
+
+        let nt = conflict.production.nonterminal;
+        let longer = match self.extending_production(conflict.production, term) {
+            Some(p) => p,
+            None => return None,
+        };
+        if longer.symbols.len() != conflict.production.symbols.len() + 2 ||
+            longer.symbols.last() != Some(&Symbol::Nonterminal(nt))
+        {
+            return None;
+        }
+
+        Some(ConflictClassification::TrailingSeparator {
+            shift: action_examples[0].clone(),
+            reduce: reduce_examples[0].clone(),
+            nonterminal: nt,
+            terminal: term,
+        })
+    }
+
+    fn try_classify_overlapping_prefix(&self,
+                                       conflict: &TokenConflict<'grammar>,
+                                       action_examples: &[Example],
+                                       reduce_examples: &[Example])
+                                       -> Option<ConflictClassification> {
+        // If we get a reduce/reduce conflict between `A = X` and
+        // `B = X`, the two alternatives using `A` and `B` share a
+        // prefix that the parser cannot tell apart yet.
+        let other = match conflict.action {
+            Action::Shift(..) => return None,
+            Action::Reduce(production) => production,
+        };
+
+        let nt = conflict.production.nonterminal;
+        if other.nonterminal == nt || other.symbols != conflict.production.symbols {
+            return None;
+        }
+
+        Some(ConflictClassification::OverlappingPrefix {
+            action: action_examples[0].clone(),
+            reduce: reduce_examples[0].clone(),
+            nonterminals: (nt, other.nonterminal),
+        })
+    }
+
+    /// Finds another production for the same nonterminal as
+    /// `production` that begins with all of its symbols followed by
+    /// `term`.
+    fn extending_production(&self,
+                            production: &Production,
+                            term: TerminalString)
+                            -> Option<&'grammar Production> {
+        let len = production.symbols.len();
+        self.grammar.productions_for(production.nonterminal)
+                    .iter()
+                    .find(|p| {
+                        p.symbols.len() > len &&
+                            p.symbols[..len] == production.symbols[..] &&
+                            p.symbols[len] == Symbol::Terminal(term)
+                    })
+    }
+
+    fn try_classify_left_factor(&self,
+                                conflict: &TokenConflict<'grammar>,
+                                action_examples: &[Example],
+                                reduce_examples: &[Example])
+                                -> Option<ConflictClassification> {
+        // If two alternatives of a nonterminal that is being reduced
+        // in the examples, like
+        //
+        //     S = A "x" "y"
+        //     S = "id" "x" "z"
+        //
+        // have the same prefix once we expand the nonterminals where
+        // they differ (here, `A = "id"`), suggest factoring out the
+        // prefix:
+        //
+        //     S = "id" "x" STail
+        //     STail = { "y", "z" }
+        //
+        // We only suggest this if we have checked that it removes
+        // the conflict.
+        let mut candidates = vec![];
+        for example in &[&action_examples[0], &reduce_examples[0]] {
+            for reduction in &example.reductions {
+                if !candidates.contains(&reduction.nonterminal) {
+                    candidates.push(reduction.nonterminal);
+                }
+            }
+        }
+
+        for nt in candidates {
+            let count = self.grammar.productions_for(nt).len();
+            for i in 0..count {
+                for j in i + 1..count {
+                    if let Some(factoring) = self.left_factor(nt, i, j) {
+                        if self.factoring_resolves(conflict, &factoring) {
+                            return Some(ConflictClassification::LeftFactor {
+                                action: action_examples[0].clone(),
+                                reduce: reduce_examples[0].clone(),
+                                factoring: factoring,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Factors out the common prefix of alternatives `i` and `j` of
+    /// `nonterminal`, if expanding the nonterminals where they differ
+    /// makes it longer (and at least two symbols long).
+    fn left_factor(&self, nonterminal: NonterminalString, i: usize, j: usize)
+                   -> Option<LeftFactoring> {
+        // the tail is named after the nonterminal, which must be a
+        // plain name for that to make sense
+        if !nonterminal.to_string().chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return None;
+        }
+
+        let productions = self.grammar.productions_for(nonterminal);
+        let mut symbols = (productions[i].symbols.clone(), productions[j].symbols.clone());
+        let original = common_prefix_len(&symbols.0, &symbols.1);
+        for _ in 0..MAX_EXPANSIONS {
+            let len = common_prefix_len(&symbols.0, &symbols.1);
+            if len == symbols.0.len() || len == symbols.1.len() {
+                break;
+            }
+            let expanded_0 = self.expand(nonterminal, &mut symbols.0, len);
+            let expanded_1 = self.expand(nonterminal, &mut symbols.1, len);
+            if !expanded_0 && !expanded_1 {
+                break;
+            }
+        }
+
+        let len = common_prefix_len(&symbols.0, &symbols.1);
+        if len <= original || len < 2 {
+            return None;
+        }
+
+        let mut tail = NonterminalString(intern(&format!("{}Tail", nonterminal)));
+        let mut n = 1;
+        while self.grammar.nonterminals.contains_key(&tail) {
+            n += 1;
+            tail = NonterminalString(intern(&format!("{}Tail{}", nonterminal, n)));
+        }
+
+        Some(LeftFactoring {
+            nonterminal: nonterminal,
+            alternatives: (i, j),
+            prefix: symbols.0[..len].to_vec(),
+            tail: tail,
+            rests: (symbols.0[len..].to_vec(), symbols.1[len..].to_vec()),
+        })
+    }
+
+    /// Replaces `symbols[index]` with the symbols of its production,
+    /// if it is a nonterminal (other than `nonterminal`) with just
+    /// one production that does not refer to itself.
+    fn expand(&self, nonterminal: NonterminalString, symbols: &mut Vec<Symbol>, index: usize)
+              -> bool {
+        let nt = match symbols[index] {
+            Symbol::Nonterminal(nt) if nt != nonterminal => nt,
+            _ => return false,
+        };
+        let productions = self.grammar.productions_for(nt);
+        if productions.len() != 1 || productions[0].symbols.contains(&Symbol::Nonterminal(nt)) {
+            return false;
+        }
+        let rest = symbols.split_off(index + 1);
+        symbols.pop();
+        symbols.extend(productions[0].symbols.iter().cloned());
+        symbols.extend(rest);
+        true
+    }
+
+    /// Applies `factoring` to a copy of the grammar and rebuilds the
+    /// states: true if no conflict on the same lookahead remains.
+    fn factoring_resolves(&self,
+                          conflict: &TokenConflict<'grammar>,
+                          factoring: &LeftFactoring)
+                          -> bool {
+        let start = match self.start_nonterminal() {
+            Some(nt) => nt,
+            None => return false,
+        };
+
+        let mut grammar = self.grammar.clone();
+        let (i, j) = factoring.alternatives;
+        let (first, second) = {
+            let productions = grammar.productions_for(factoring.nonterminal);
+            (productions[i].clone(), productions[j].clone())
+        };
+        let mut prefix = factoring.prefix.clone();
+        prefix.push(Symbol::Nonterminal(factoring.tail));
+        {
+            let data = grammar.nonterminals.get_mut(&factoring.nonterminal).unwrap();
+            data.productions.remove(j);
+            data.productions.remove(i);
+            data.productions.push(Production { symbols: prefix, ..first.clone() });
+        }
+        grammar.nonterminals.insert(factoring.tail, NonterminalData {
+            name: factoring.tail,
+            span: first.span,
+            annotations: vec![],
+            productions: vec![
+                Production {
+                    nonterminal: factoring.tail,
+                    symbols: factoring.rests.0.clone(),
+                    ..first
+                },
+                Production {
+                    nonterminal: factoring.tail,
+                    symbols: factoring.rests.1.clone(),
+                    ..second
+                },
+            ],
+        });
+
+        log!(self.session, Verbose, "Checking whether factoring `{}` resolves the conflict",
+             factoring.nonterminal);
+        resolves(self.session, &grammar, start, conflict)
+    }
+
     fn try_classify_inline(&self,
                            conflict: &TokenConflict<'grammar>,
                            action_examples: &[Example],
@@ -583,7 +1154,7 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
         // would help; but maybe we want to restrict it to cases
         // where inlining would help *all* the examples...?
 
-        action_examples
+        let suggestion = action_examples
             .iter()
             .cartesian_product(reduce_examples)
             .filter_map(|(shift, reduce)| {
@@ -593,20 +1164,133 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
                         shift: shift.clone(),
                         reduce: reduce.clone(),
                         nonterminal: nt,
+                        verified: self.inlining_resolves(conflict, nt),
                     })
                 } else {
                     None
                 }
             })
-            .next()
+            .next();
+        if suggestion.is_some() {
+            return suggestion;
+        }
+
+        // The examples did not suggest anything, but the conflict
+        // may still go away if one of the intermediate nonterminals
+        // being reduced is inlined; try out (a few of) those that
+        // take part in the conflict one by one.
+        if !self.session.verify_inlining {
+            return None;
+        }
+        let involved = self.conflicting_nonterminals(conflict);
+        let mut candidates = vec![];
+        for reduction in &reduce_examples[0].reductions {
+            let nt = reduction.nonterminal;
+            if involved.contains(&nt) && self.can_try_inlining(nt) && !candidates.contains(&nt) {
+                candidates.push(nt);
+            }
+        }
+        candidates
+            .into_iter()
+            .take(MAX_INLINING_CHECKS)
+            .find(|&nt| self.inlining_resolves(conflict, nt))
+            .map(|nt| ConflictClassification::SuggestInline {
+                shift: action_examples[0].clone(),
+                reduce: reduce_examples[0].clone(),
+                nonterminal: nt,
+                verified: true,
+            })
+    }
+
+    /// True if `nonterminal` is an intermediate nonterminal that we
+    /// could inline: not public and not recursive.
+    fn can_try_inlining(&self, nonterminal: NonterminalString) -> bool {
+        if self.grammar.start_nonterminals.contains_key(&nonterminal) ||
+            self.grammar.start_nonterminals.values().any(|&nt| nt == nonterminal)
+        {
+            return false;
+        }
+
+        let symbol = Symbol::Nonterminal(nonterminal);
+        self.grammar.productions_for(nonterminal)
+                    .iter()
+                    .all(|p| !p.symbols.contains(&symbol))
+    }
+
+    /// The nonterminals of the items in the state of `conflict` that
+    /// take part in it, by reducing its production or by shifting its
+    /// lookahead, and those that these items refer to.
+    fn conflicting_nonterminals(&self,
+                                conflict: &TokenConflict<'grammar>)
+                                -> Set<NonterminalString> {
+        let mut nonterminals = set();
+        for item in self.states[conflict.state.0].items.vec.iter() {
+            let involved = match conflict.lookahead {
+                Token::Terminal(term) => item.can_shift_terminal(term),
+                _ => false,
+            } || (item.production == conflict.production && item.can_reduce());
+            if !involved {
+                continue;
+            }
+            nonterminals.insert(item.production.nonterminal);
+            for symbol in &item.production.symbols {
+                if let Symbol::Nonterminal(nt) = *symbol {
+                    nonterminals.insert(nt);
+                }
+            }
+        }
+        nonterminals
+    }
+
+    /// Tentatively inlines `nonterminal` into a copy of the grammar
+    /// and rebuilds the states: if no conflict on the same lookahead
+    /// remains, inlining provably resolves `conflict`. Since this is
+    /// slow for large grammars, it is only done if the session asks
+    /// for it with `verify_inlining`.
+    fn inlining_resolves(&self,
+                         conflict: &TokenConflict<'grammar>,
+                         nonterminal: NonterminalString)
+                         -> bool {
+        if !self.session.verify_inlining || !self.can_try_inlining(nonterminal) {
+            return false;
+        }
+
+        let start = match self.start_nonterminal() {
+            Some(nt) => nt,
+            None => return false,
+        };
+
+        let mut grammar = self.grammar.clone();
+        inline_nt(&mut grammar, nonterminal);
+        log!(self.session, Verbose, "Checking whether inlining `{}` resolves the conflict",
+             nonterminal);
+        resolves(self.session, &grammar, start, conflict)
+    }
+
+    /// The nonterminal the states being reported on were built for.
+    /// This is the nonterminal in the initial state that no other
+    /// nonterminal there refers to.
+    fn start_nonterminal(&self) -> Option<NonterminalString> {
+        let items = match self.states.first() {
+            Some(state) => &state.items.vec,
+            None => return None,
+        };
+        items.iter()
+             .map(|item| item.production.nonterminal)
+             .find(|&nt| {
+                 items.iter().all(|item| {
+                     item.production.nonterminal == nt ||
+                         item.production.symbols.first() != Some(&Symbol::Nonterminal(nt))
+                 })
+             })
     }
 
     fn try_classify_inline_example<'ex>(&self,
                                         shift: &Example,
                                         reduce: &Example)
                                         -> bool {
-        debug!("try_classify_inline_example({:?}, {:?})",
-               shift, reduce);
+        log!(self.session, Debug, "try_classify_inline_example({:?}, {:?})",
+             shift, reduce);
 
         // In the case of shift, the example will look like
         //
@@ -623,8 +1307,8 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
         // make our next hard decision (to reduce R0 or not).
         let shift_upcoming =
             &shift.symbols[shift.cursor+1 .. shift.reductions[0].end];
-        debug!("try_classify_inline_example: shift_upcoming={:?}",
-               shift_upcoming);
+        log!(self.session, Debug, "try_classify_inline_example: shift_upcoming={:?}",
+             shift_upcoming);
 
         // For the reduce, the example might look like
         //
@@ -646,8 +1330,8 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
         let i = match i { Some(v) => v, None => return false };
         let ri = &reduce.reductions[i];
         let reduce_upcoming = &reduce.symbols[r0_end..ri.end];
-        debug!("try_classify_inline_example: reduce_upcoming={:?} i={:?}",
-               reduce_upcoming, i);
+        log!(self.session, Debug, "try_classify_inline_example: reduce_upcoming={:?} i={:?}",
+             reduce_upcoming, i);
 
         // For now, we only suggest inlining a single nonterminal,
         // mostly because I am too lazy to weak the suggestion struct
@@ -705,10 +1389,29 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
             .unwrap_or(false)
     }
 
+    /// Finds examples from the conflicting action (either a shift or
+    /// a reduce) and from the conflicting reduce, shortest first.
+    fn examples(&self, conflict: &TokenConflict<'grammar>) -> (Vec<Example>, Vec<Example>) {
+        let mut action_examples = match conflict.action {
+            Action::Shift(..) => self.shift_examples(conflict),
+            Action::Reduce(production) => self.reduce_examples(conflict.state,
+                                                               production,
+                                                               conflict.lookahead)
+        };
+        let mut reduce_examples = self.reduce_examples(conflict.state,
+                                                       conflict.production,
+                                                       conflict.lookahead);
+
+        // Prefer shorter examples to longer ones.
+        action_examples.sort_by(|e, f| e.symbols.len().cmp(&f.symbols.len()));
+        reduce_examples.sort_by(|e, f| e.symbols.len().cmp(&f.symbols.len()));
+        (action_examples, reduce_examples)
+    }
+
     fn shift_examples(&self,
                       conflict: &TokenConflict<'grammar>)
                       -> Vec<Example> {
-        log!(Tls::session(), Verbose, "Gathering shift examples");
+        log!(self.session, Verbose, "Gathering shift examples");
         let state = &self.states[conflict.state.0];
         let conflicting_items = self.conflicting_shift_items(state, conflict);
         conflicting_items
@@ -729,7 +1432,7 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
                        production: &'grammar Production,
                        lookahead: Token)
                        -> Vec<Example> {
-        log!(Tls::session(), Verbose, "Gathering reduce examples");
+        log!(self.session, Verbose, "Gathering reduce examples");
         let item = Item {
             production: production,
             index: production.symbols.len(),
@@ -756,6 +1459,36 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
     }
 }
 
+/// True if building the states of `grammar` (a changed copy of the
+/// grammar in which `conflict` was found) for `start` leaves no
+/// conflict on the lookahead of `conflict`.
+fn resolves(session: &Session,
+            grammar: &Grammar,
+            start: NonterminalString,
+            conflict: &TokenConflict)
+            -> bool {
+    match build_states(session, grammar, start) {
+        Ok(_) => true,
+        Err(error) => {
+            token_conflicts(&error.conflicts)
+                .iter()
+                .all(|c| c.lookahead != conflict.lookahead)
+        }
+    }
+}
+
+fn common_prefix_len(a: &[Symbol], b: &[Symbol]) -> usize {
+    a.iter().zip(b).take_while(|&(a, b)| a == b).count()
+}
+
+fn symbols_str(symbols: &[Symbol]) -> String {
+    if symbols.is_empty() {
+        String::from("()")
+    } else {
+        symbols.iter().map(|s| s.to_string()).collect::<Vec<_>>().join(" ")
+    }
+}
+
 fn token_conflicts<'grammar>(conflicts: &[Conflict<'grammar, TokenSet>])
                              -> Vec<TokenConflict<'grammar>> {
     conflicts
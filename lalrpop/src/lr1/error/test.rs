@@ -1,9 +1,12 @@
+use file_text::FileText;
 use intern::intern;
 use grammar::repr::*;
 use lr1::build_states;
+use lr1::example::Example;
+use lr1::lookahead::Token;
 use lr1::tls::Lr1Tls;
+use session::Session;
 use test_util::normalized_grammar;
-use tls::Tls;
 
 use super::{ConflictClassification, ErrorReportingCx};
 
@@ -13,7 +16,6 @@ fn nt(t: &str) -> NonterminalString {
 
 #[test]
 fn priority_conflict() {
-    let _tls = Tls::test();
     let grammar = normalized_grammar(r#"
 grammar;
 pub Ty: () = {
@@ -23,8 +25,10 @@ pub Ty: () = {
 };
 "#);
     let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
-    let err = build_states(&grammar, nt("Ty")).unwrap_err();
-    let mut cx = ErrorReportingCx::new(&grammar, &err.states, &err.conflicts);
+    let session = Session::test();
+    let file_text = FileText::test();
+    let err = build_states(&session, &grammar, nt("Ty")).unwrap_err();
+    let mut cx = ErrorReportingCx::new(&session, &file_text, &grammar, &err.states, &err.conflicts);
     let conflicts = super::token_conflicts(&err.conflicts);
     let conflict = &conflicts[0];
 
@@ -50,7 +54,6 @@ pub Ty: () = {
 
 #[test]
 fn expr_braced_conflict() {
-    let _tls = Tls::test();
     let grammar = normalized_grammar(r#"
 grammar;
 pub Expr: () = {
@@ -61,8 +64,10 @@ pub Expr: () = {
 };
 "#);
     let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
-    let err = build_states(&grammar, nt("Expr")).unwrap_err();
-    let mut cx = ErrorReportingCx::new(&grammar, &err.states, &err.conflicts);
+    let session = Session::test();
+    let file_text = FileText::test();
+    let err = build_states(&session, &grammar, nt("Expr")).unwrap_err();
+    let mut cx = ErrorReportingCx::new(&session, &file_text, &grammar, &err.states, &err.conflicts);
     let conflicts = super::token_conflicts(&err.conflicts);
     let conflict = &conflicts[0];
 
@@ -76,7 +81,6 @@ pub Expr: () = {
 
 #[test]
 fn suggest_question_conflict() {
-    let _tls = Tls::test();
     let grammar = normalized_grammar(r#"
         grammar;
 
@@ -91,8 +95,10 @@ fn suggest_question_conflict() {
         };
 "#);
     let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
-    let err = build_states(&grammar, nt("E")).unwrap_err();
-    let mut cx = ErrorReportingCx::new(&grammar, &err.states, &err.conflicts);
+    let session = Session::test();
+    let file_text = FileText::test();
+    let err = build_states(&session, &grammar, nt("E")).unwrap_err();
+    let mut cx = ErrorReportingCx::new(&session, &file_text, &grammar, &err.states, &err.conflicts);
     let conflicts = super::token_conflicts(&err.conflicts);
     let conflict = &conflicts[0];
 
@@ -114,7 +120,6 @@ fn suggest_question_conflict() {
 
 #[test]
 fn suggest_inline_conflict() {
-    let _tls = Tls::test();
     let grammar = normalized_grammar(r##"
 grammar;
 
@@ -130,29 +135,36 @@ Path: () = {
 Ident = r#"[a-zA-Z][a-zA-Z0-9]*"#;
 "##);
     let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
-    let err = build_states(&grammar, nt("ImportDecl")).unwrap_err();
-    let mut cx = ErrorReportingCx::new(&grammar, &err.states, &err.conflicts);
-    let conflicts = super::token_conflicts(&err.conflicts);
-    let conflict = &conflicts[0];
+    for &verify in &[false, true] {
+        let mut session = Session::test();
+        session.verify_inlining = verify;
+        let file_text = FileText::test();
+        let err = build_states(&session, &grammar, nt("ImportDecl")).unwrap_err();
+        let mut cx = ErrorReportingCx::new(&session, &file_text, &grammar,
+                                           &err.states, &err.conflicts);
+        let conflicts = super::token_conflicts(&err.conflicts);
+        let conflict = &conflicts[0];
 
-    println!("conflict={:?}", conflict);
+        println!("conflict={:?}", conflict);
 
-    match cx.classify(conflict) {
-        ConflictClassification::SuggestInline {
-            shift: _,
-            reduce: _,
-            nonterminal,
-        } => {
-            assert_eq!(nonterminal, nt("Path"));
+        match cx.classify(conflict) {
+            ConflictClassification::SuggestInline {
+                shift: _,
+                reduce: _,
+                nonterminal,
+                verified,
+            } => {
+                assert_eq!(nonterminal, nt("Path"));
+                assert_eq!(verified, verify);
+            }
+            r => panic!("wrong classification {:#?}", r)
         }
-        r => panic!("wrong classification {:#?}", r)
     }
 }
 
 /// This example used to cause an out-of-bounds error.
 #[test]
 fn issue_249() {
-    let _tls = Tls::test();
     let grammar = normalized_grammar(r##"
 grammar;
 
@@ -162,11 +174,189 @@ StructParameter = "may_dangle"?;
 VarDecl = "let";
 "##);
     let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
-    let err = build_states(&grammar, nt("Func")).unwrap_err();
-    let mut cx = ErrorReportingCx::new(&grammar, &err.states, &err.conflicts);
+    let session = Session::test();
+    let file_text = FileText::test();
+    let err = build_states(&session, &grammar, nt("Func")).unwrap_err();
+    let mut cx = ErrorReportingCx::new(&session, &file_text, &grammar, &err.states, &err.conflicts);
     let conflicts = super::token_conflicts(&err.conflicts);
     for conflict in &conflicts {
         println!("conflict={:?}", conflict);
         cx.classify(conflict);
     }
 }
+
+#[test]
+fn dangling_else_conflict() {
+    let grammar = normalized_grammar(r#"
+grammar;
+pub S: () = {
+    "if" "E" "then" S => (),
+    "if" "E" "then" S "else" S => (),
+    "x" => (),
+};
+"#);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let session = Session::test();
+    let file_text = FileText::test();
+    let err = build_states(&session, &grammar, nt("S")).unwrap_err();
+    let mut cx = ErrorReportingCx::new(&session, &file_text, &grammar, &err.states, &err.conflicts);
+    let conflicts = super::token_conflicts(&err.conflicts);
+    let conflict = &conflicts[0];
+
+    println!("conflict={:?}", conflict);
+
+    match cx.classify(conflict) {
+        ConflictClassification::DanglingElse {
+            shift: _,
+            reduce: _,
+            nonterminal,
+            terminal,
+        } => {
+            assert_eq!(nonterminal, nt("S"));
+            assert_eq!(terminal, TerminalString::quoted(intern("else")));
+        }
+        r => panic!("wrong classification {:#?}", r)
+    }
+}
+
+#[test]
+fn trailing_separator_conflict() {
+    let grammar = normalized_grammar(r#"
+grammar;
+pub Tuple: () = "(" Items ","? ")" => ();
+Items: () = {
+    Item "," Items => (),
+    Item => (),
+};
+Item = "x";
+"#);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let session = Session::test();
+    let file_text = FileText::test();
+    let err = build_states(&session, &grammar, nt("Tuple")).unwrap_err();
+    let mut cx = ErrorReportingCx::new(&session, &file_text, &grammar, &err.states, &err.conflicts);
+    let conflicts = super::token_conflicts(&err.conflicts);
+    let conflict = &conflicts[0];
+
+    println!("conflict={:?}", conflict);
+
+    match cx.classify(conflict) {
+        ConflictClassification::TrailingSeparator {
+            shift: _,
+            reduce: _,
+            nonterminal,
+            terminal,
+        } => {
+            assert_eq!(nonterminal, nt("Items"));
+            assert_eq!(terminal, TerminalString::quoted(intern(",")));
+        }
+        r => panic!("wrong classification {:#?}", r)
+    }
+}
+
+#[test]
+fn overlapping_prefix_conflict() {
+    let grammar = normalized_grammar(r#"
+grammar;
+pub S: () = {
+    A "x" "y" => (),
+    B "x" "z" => (),
+};
+A: () = "id" => ();
+B: () = "id" => ();
+"#);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let session = Session::test();
+    let file_text = FileText::test();
+    let err = build_states(&session, &grammar, nt("S")).unwrap_err();
+    let mut cx = ErrorReportingCx::new(&session, &file_text, &grammar, &err.states, &err.conflicts);
+    let conflicts = super::token_conflicts(&err.conflicts);
+    let x = Token::Terminal(TerminalString::quoted(intern("x")));
+    let conflict = conflicts.iter().find(|c| c.lookahead == x).unwrap();
+
+    println!("conflict={:?}", conflict);
+
+    match cx.classify(conflict) {
+        ConflictClassification::OverlappingPrefix {
+            action: _,
+            reduce: _,
+            nonterminals,
+        } => {
+            assert_eq!(nonterminals, (nt("A"), nt("B")));
+        }
+        r => panic!("wrong classification {:#?}", r)
+    }
+}
+
+#[test]
+fn reduce_chain_to_end_of_input() {
+    let grammar = normalized_grammar(r#"
+grammar;
+pub S: () = {
+    A => (),
+    B => (),
+};
+A: () = X => ();
+B: () = Y => ();
+X: () = "x" => ();
+Y: () = "x" => ();
+"#);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let session = Session::test();
+    let file_text = FileText::test();
+    let err = build_states(&session, &grammar, nt("S")).unwrap_err();
+    let mut cx = ErrorReportingCx::new(&session, &file_text, &grammar, &err.states, &err.conflicts);
+    let conflicts = super::token_conflicts(&err.conflicts);
+
+    // The conflict on EOF can only be explained by tracing the
+    // reductions `X -> A -> S` all the way up to the start symbol.
+    let conflict = conflicts.iter().find(|c| c.lookahead == Token::EOF).unwrap();
+    println!("conflict={:?}", conflict);
+
+    match cx.classify(conflict) {
+        ConflictClassification::Ambiguity { action, reduce } => {
+            let nonterminals = |example: &Example| -> Vec<NonterminalString> {
+                example.reductions.iter().map(|r| r.nonterminal).collect()
+            };
+            assert_eq!(nonterminals(&reduce), vec![nt("X"), nt("A")]);
+            assert_eq!(nonterminals(&action), vec![nt("Y"), nt("B")]);
+        }
+        r => panic!("wrong classification {:#?}", r)
+    }
+}
+
+#[test]
+fn left_factor_conflict() {
+    let grammar = normalized_grammar(r#"
+grammar;
+pub Top: () = "begin" S "end" => ();
+S: () = {
+    "(" Id "x" "y" ")" => (),
+    "(" "id" "x" "z" ")" => (),
+};
+Id: () = "id" => ();
+"#);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let session = Session::test();
+    let file_text = FileText::test();
+    let err = build_states(&session, &grammar, nt("Top")).unwrap_err();
+    let mut cx = ErrorReportingCx::new(&session, &file_text, &grammar, &err.states, &err.conflicts);
+    let conflicts = super::token_conflicts(&err.conflicts);
+    let conflict = &conflicts[0];
+
+    println!("conflict={:?}", conflict);
+
+    match cx.classify(conflict) {
+        ConflictClassification::LeftFactor {
+            action: _,
+            reduce: _,
+            factoring,
+        } => {
+            let terminal = |t| Symbol::Terminal(TerminalString::quoted(intern(t)));
+            assert_eq!(factoring.nonterminal, nt("S"));
+            assert_eq!(factoring.prefix, vec![terminal("("), terminal("id"), terminal("x")]);
+            assert_eq!(factoring.tail, nt("STail"));
+        }
+        r => panic!("wrong classification {:#?}", r)
+    }
+}
@@ -4,9 +4,9 @@ use ascii_canvas::{AsciiView};
 use message::Content;
 use message::builder::InlineBuilder;
 use grammar::repr::*;
+use session::{ExampleRendering, Session};
 use std::fmt::{Debug, Formatter, Error};
 use style::Style;
-use tls::Tls;
 
 #[cfg(test)] mod test;
 
@@ -59,6 +59,8 @@ pub struct ExampleStyles {
     pub before_cursor: Style,
     pub on_cursor: Style,
     pub after_cursor: Style,
+    pub terminal_symbol: Style,
+    pub nonterminal_symbol: Style,
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -104,7 +106,11 @@ impl Example {
             };
 
             if let &ExampleSymbol::Symbol(s) = symbol {
-                builder = builder.push(s).styled(style);
+                let symbol_style = match s {
+                    Symbol::Terminal(_) => styles.terminal_symbol,
+                    Symbol::Nonterminal(_) => styles.nonterminal_symbol,
+                };
+                builder = builder.text(s).styled(symbol_style).styled(style);
             }
         }
 
@@ -112,8 +118,19 @@ impl Example {
     }
 
     /// Render the example into a styled diagram suitable for
-    /// embedding in an error message.
-    pub fn into_picture(self, styles: ExampleStyles) -> Box<Content> {
+    /// embedding in an error message, drawn the way `session` asks
+    /// for.
+    pub fn into_picture(self, session: &Session, styles: ExampleStyles) -> Box<Content> {
+        match session.example_rendering {
+            ExampleRendering::Brackets => self.into_brackets(styles),
+            ExampleRendering::Tree => self.into_tree(styles, false),
+            ExampleRendering::BoxTree => self.into_tree(styles, true),
+        }
+    }
+
+    /// Render the example with the reductions as brackets under the
+    /// symbols, as in the diagram at the top of this file.
+    fn into_brackets(self, styles: ExampleStyles) -> Box<Content> {
         let lengths = self.lengths();
         let positions = self.positions(&lengths);
         InlineBuilder::new()
@@ -126,6 +143,162 @@ impl Example {
             .end()
     }
 
+    /// Render the example as a derivation tree, like:
+    ///
+    /// ```
+    /// Ty
+    ///   Ty
+    ///     Ty
+    ///     "->"
+    ///     Ty
+    ///   "->"
+    ///   Ty
+    /// ```
+    ///
+    /// or, if `box_drawing` is true:
+    ///
+    /// ```
+    /// Ty
+    /// ├─ Ty
+    /// │  ├─ Ty
+    /// │  ├─ "->"
+    /// │  └─ Ty
+    /// ├─ "->"
+    /// └─ Ty
+    /// ```
+    ///
+    /// Symbols that are not part of any reduction are roots of their
+    /// own. Empty reductions are drawn without children.
+    pub fn into_tree(self, styles: ExampleStyles, box_drawing: bool) -> Box<Content> {
+        let roots = self.tree_nodes(0, self.symbols.len(), self.reductions.len());
+        let mut lines = vec![];
+        for root in &roots {
+            self.tree_lines(root, &mut String::new(), None, box_drawing, &styles, &mut lines);
+        }
+        InlineBuilder::new()
+            .push(Box::new(ExampleTree { example: self, lines: lines }))
+            .indented()
+            .end()
+    }
+
+    /// The trees for the symbols in `start..end`, made of the
+    /// reductions with an index below `limit` (since reductions are
+    /// ordered from smallest to largest, only those can be nested
+    /// inside the reduction `limit`).
+    fn tree_nodes(&self, start: usize, end: usize, limit: usize) -> Vec<TreeNode> {
+        let inside: Vec<usize> =
+            (0..limit)
+            .filter(|&i| start <= self.reductions[i].start && self.reductions[i].end <= end)
+            .collect();
+
+        // the outermost reductions are those that no later one contains
+        let outermost: Vec<usize> =
+            inside.iter()
+                  .cloned()
+                  .filter(|&i| {
+                      let r = &self.reductions[i];
+                      !inside.iter().any(|&j| {
+                          j > i &&
+                              self.reductions[j].start <= r.start &&
+                              r.end <= self.reductions[j].end
+                      })
+                  })
+                  .collect();
+
+        let mut nodes = vec![];
+        let mut position = start;
+        while position < end {
+            match outermost.iter().find(|&&i| self.reductions[i].start == position) {
+                Some(&i) => {
+                    let r = &self.reductions[i];
+                    nodes.push(TreeNode::Reduction(r.nonterminal,
+                                                   self.tree_nodes(r.start, r.end, i)));
+                    position = r.end;
+                }
+                None => {
+                    if let ExampleSymbol::Symbol(_) = self.symbols[position] {
+                        nodes.push(TreeNode::Symbol(position));
+                    }
+                    position += 1;
+                }
+            }
+        }
+        nodes
+    }
+
+    /// Appends the lines for `node` and its children to `lines`.
+    /// `indent` is the prefix for the lines of the children of the
+    /// node's parent; `last` is whether the node is the last child of
+    /// its parent (`None` for roots).
+    fn tree_lines(&self,
+                  node: &TreeNode,
+                  indent: &mut String,
+                  last: Option<bool>,
+                  box_drawing: bool,
+                  styles: &ExampleStyles,
+                  lines: &mut Vec<TreeLine>) {
+        let connector = match (last, box_drawing) {
+            (None, _) => "",
+            (Some(_), false) => "  ",
+            (Some(false), true) => "├─ ",
+            (Some(true), true) => "└─ ",
+        };
+        let prefix = format!("{}{}", indent, connector);
+
+        match *node {
+            TreeNode::Symbol(index) => {
+                let symbol = match self.symbols[index] {
+                    ExampleSymbol::Symbol(symbol) => symbol,
+                    ExampleSymbol::Epsilon => unreachable!(),
+                };
+                let (style, symbol_style) = match symbol {
+                    Symbol::Terminal(_) => (self.cursor_style(index, styles, true),
+                                            styles.terminal_symbol),
+                    Symbol::Nonterminal(_) => (self.cursor_style(index, styles, false),
+                                               styles.nonterminal_symbol),
+                };
+                lines.push(TreeLine {
+                    prefix: prefix,
+                    label: symbol.to_string(),
+                    style: style.with(symbol_style),
+                });
+            }
+            TreeNode::Reduction(nonterminal, ref children) => {
+                lines.push(TreeLine {
+                    prefix: prefix,
+                    label: nonterminal.to_string(),
+                    style: styles.nonterminal_symbol,
+                });
+                let len = indent.len();
+                indent.push_str(match (last, box_drawing) {
+                    (None, _) => "",
+                    (Some(_), false) => "  ",
+                    (Some(false), true) => "│  ",
+                    (Some(true), true) => "   ",
+                });
+                for (i, child) in children.iter().enumerate() {
+                    let last = i + 1 == children.len();
+                    self.tree_lines(child, indent, Some(last), box_drawing, styles, lines);
+                }
+                indent.truncate(len);
+            }
+        }
+    }
+
+    /// The style for the symbol at `index`, depending on where it is
+    /// relative to the cursor. (Only terminals are shown in the
+    /// "on-cursor" style, since it'd be misleading to show a
+    /// nonterminal that way.)
+    fn cursor_style(&self, index: usize, styles: &ExampleStyles, terminal: bool) -> Style {
+        if index < self.cursor {
+            styles.before_cursor
+        } else if index == self.cursor && terminal {
+            styles.on_cursor
+        } else {
+            styles.after_cursor
+        }
+    }
+
     fn starting_positions(&self, lengths: &[usize]) -> Vec<usize> {
         lengths.iter()
                .scan(0, |counter, &len| {
@@ -259,11 +432,20 @@ impl Example {
         positions
     }
 
+    #[cfg(test)]
+    pub fn paint_tree_unstyled(&self, box_drawing: bool) -> Vec<::ascii_canvas::Row> {
+        use std::default::Default;
+        let content = self.clone().into_tree(ExampleStyles::default(), box_drawing);
+        let min_width = content.min_width();
+        let canvas = content.emit_to_canvas(min_width);
+        canvas.to_strings()
+    }
+
     #[cfg(test)]
     pub fn paint_unstyled(&self) -> Vec<::ascii_canvas::Row> {
         use std::default::Default;
         let this = self.clone();
-        let content = this.into_picture(ExampleStyles::default());
+        let content = this.into_brackets(ExampleStyles::default());
         let min_width = content.min_width();
         let canvas = content.emit_to_canvas(min_width);
         canvas.to_strings()
@@ -286,14 +468,13 @@ impl Example {
         // Write the labels for each reduction. Do this after the
         // brackets so that ascii canvas can convert `|` to `+`
         // without interfering with the text (in case of weird overlap).
-        let session = Tls::session();
         for (index, reduction) in self.reductions.iter().enumerate() {
             let column = positions[reduction.start] + 2;
             let row = 1 + index;
             view.write_chars(row,
                              column,
                              reduction.nonterminal.to_string().chars(),
-                             session.nonterminal_symbol);
+                             styles.nonterminal_symbol);
         }
 
         // Write the labels on top:
@@ -306,7 +487,6 @@ impl Example {
                         positions: &[usize],
                         styles: &ExampleStyles,
                         view: &mut AsciiView) {
-        let session = Tls::session();
         for (index, ex_symbol) in symbols.iter().enumerate() {
             let style = if index < self.cursor {
                 styles.before_cursor
@@ -329,13 +509,13 @@ impl Example {
                     view.write_chars(0,
                                      column,
                                      term.to_string().chars(),
-                                     style.with(session.terminal_symbol));
+                                     style.with(styles.terminal_symbol));
                 }
                 ExampleSymbol::Symbol(Symbol::Nonterminal(nt)) => {
                     view.write_chars(0,
                                      column,
                                      nt.to_string().chars(),
-                                     style.with(session.nonterminal_symbol));
+                                     style.with(styles.nonterminal_symbol));
                 }
                 ExampleSymbol::Epsilon => {
                 }
@@ -370,6 +550,52 @@ impl Debug for ExamplePicture {
     }
 }
 
+enum TreeNode {
+    /// The symbol at this index.
+    Symbol(usize),
+
+    /// A reduction to this nonterminal, of these nodes.
+    Reduction(NonterminalString, Vec<TreeNode>),
+}
+
+struct TreeLine {
+    prefix: String,
+    label: String,
+    style: Style,
+}
+
+struct ExampleTree {
+    example: Example,
+    lines: Vec<TreeLine>,
+}
+
+impl Content for ExampleTree {
+    fn min_width(&self) -> usize {
+        self.lines.iter()
+                  .map(|line| line.prefix.chars().count() + line.label.chars().count())
+                  .max()
+                  .unwrap_or(0)
+    }
+
+    fn emit(&self, view: &mut AsciiView) {
+        for (row, line) in self.lines.iter().enumerate() {
+            let column = line.prefix.chars().count();
+            view.write_chars(row, 0, line.prefix.chars(), Style::new());
+            view.write_chars(row, column, line.label.chars(), line.style);
+        }
+    }
+
+    fn into_wrap_items(self: Box<Self>, wrap_items: &mut Vec<Box<Content>>) {
+        wrap_items.push(self);
+    }
+}
+
+impl Debug for ExampleTree {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        Debug::fmt(&self.example, fmt)
+    }
+}
+
 fn shift(positions: &mut [usize], amount: usize) {
     for position in positions {
         *position += amount;
@@ -377,21 +603,23 @@ fn shift(positions: &mut [usize], amount: usize) {
 }
 
 impl ExampleStyles {
-    pub fn ambig() -> Self {
-        let session = Tls::session();
+    pub fn ambig(session: &Session) -> Self {
         ExampleStyles {
             before_cursor: session.ambig_symbols,
             on_cursor: session.ambig_symbols,
             after_cursor: session.ambig_symbols,
+            terminal_symbol: session.terminal_symbol,
+            nonterminal_symbol: session.nonterminal_symbol,
         }
     }
 
-    pub fn new() -> Self {
-        let session = Tls::session();
+    pub fn new(session: &Session) -> Self {
         ExampleStyles {
             before_cursor: session.observed_symbols,
             on_cursor: session.cursor_symbol,
             after_cursor: session.unobserved_symbols,
+            terminal_symbol: session.terminal_symbol,
+            nonterminal_symbol: session.nonterminal_symbol,
         }
     }
 }
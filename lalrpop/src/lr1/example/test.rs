@@ -1,7 +1,6 @@
 use intern::intern;
 use grammar::repr::*;
 use test_util::expect_debug;
-use tls::Tls;
 
 use super::{Example, ExampleSymbol, Reduction};
 
@@ -42,7 +41,6 @@ fn long_label_1_example() -> Example {
 
 #[test]
 fn long_label_1_positions() {
-    let _tls = Tls::test();
     let example = long_label_1_example();
     let lengths = example.lengths();
     let positions = example.positions(&lengths);
@@ -51,7 +49,6 @@ fn long_label_1_positions() {
 
 #[test]
 fn long_label_1_strings() {
-    let _tls = Tls::test();
     let strings = long_label_1_example().paint_unstyled();
     expect_debug(strings, r#"
 [
@@ -90,7 +87,6 @@ fn empty_labels_example() -> Example {
 
 #[test]
 fn empty_labels_positions() {
-    let _tls = Tls::test();
     let example = empty_labels_example();
     let lengths = example.lengths();
     let positions = example.positions(&lengths);
@@ -100,7 +96,6 @@ fn empty_labels_positions() {
 
 #[test]
 fn empty_labels_strings() {
-    let _tls = Tls::test();
     let strings = empty_labels_example().paint_unstyled();
     expect_debug(strings, r#"
 [
@@ -135,7 +130,6 @@ fn single_token_example() -> Example {
 
 #[test]
 fn single_token_strings() {
-    let _tls = Tls::test();
     let strings = single_token_example().paint_unstyled();
     expect_debug(strings, r#"
 [
@@ -146,3 +140,72 @@ fn single_token_strings() {
 ]
 "#.trim());
 }
+
+#[test]
+fn long_label_1_tree() {
+    let strings = long_label_1_example().paint_tree_unstyled(false);
+    expect_debug(strings, r#"
+[
+    "  Label",
+    "    LongLabel22",
+    "      A1",
+    "      B2",
+    "      C3",
+    "      D4",
+    "    E5",
+    "    F6"
+]
+"#.trim());
+}
+
+#[test]
+fn long_label_1_box_tree() {
+    let strings = long_label_1_example().paint_tree_unstyled(true);
+    expect_debug(strings, r#"
+[
+    "  Label",
+    "  ├─ LongLabel22",
+    "  │  ├─ A1",
+    "  │  ├─ B2",
+    "  │  ├─ C3",
+    "  │  └─ D4",
+    "  ├─ E5",
+    "  └─ F6"
+]
+"#.trim());
+}
+
+#[test]
+fn empty_labels_box_tree() {
+    let strings = empty_labels_example().paint_tree_unstyled(true);
+    expect_debug(strings, r#"
+[
+    "  MegaLongLabel",
+    "  ├─ X",
+    "  ├─ A1",
+    "  ├─ B2",
+    "  └─ C3",
+    "  D4",
+    "  E5",
+    "  Y",
+    "  Z",
+    "  └─ F6"
+]
+"#.trim());
+}
+
+#[test]
+fn single_token_box_tree() {
+    let strings = single_token_example().paint_tree_unstyled(true);
+    expect_debug(strings, r#"
+[
+    "  ExprSuffix",
+    "  ├─ ExprSuffix",
+    "  │  └─ ExprAtom",
+    "  │     └─ _return_",
+    "  ├─ _A_",
+    "  ├─ Expression",
+    "  └─ _B_"
+]
+"#.trim());
+}
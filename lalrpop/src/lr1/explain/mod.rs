@@ -0,0 +1,233 @@
+//! An interactive explorer for the conflicts of a grammar
+//! (`lalrpop explain-conflicts`). Rather than printing every conflict
+//! at once, it shows one at a time and reads commands to move between
+//! them, expand all the example derivations that lead to a conflict,
+//! or show the lines of the grammar that are involved.
+
+use file_text::FileText;
+use grammar::repr::*;
+use lr1::build_states;
+use lr1::core::*;
+use lr1::error::{conflict_traces, distinct_token_conflicts, report_conflict, TokenConflict};
+use lr1::example::{Example, ExampleStyles};
+use lr1::tls::Lr1Tls;
+use message::Content;
+use session::Session;
+use std::io::{self, BufRead, Write};
+
+#[cfg(test)]
+mod test;
+
+const HELP: &'static str = "\
+Commands:
+  n, <enter>   go to the next conflict
+  p            go to the previous conflict
+  <number>     go to the conflict with that number
+  l            list all conflicts
+  e            explain the current conflict
+  t            show every example that leads to the current conflict
+  s            show the grammar lines involved in the current conflict
+  h, ?         show this help
+  q            quit";
+
+/// The conflicts found while building the states for one public
+/// nonterminal.
+struct Conflicts<'grammar> {
+    nonterminal: NonterminalString,
+    error: LR1TableConstructionError<'grammar>,
+    conflicts: Vec<TokenConflict<'grammar>>,
+}
+
+/// Reads commands from `input` and writes the responses to `out`
+/// until the user quits or `input` runs out.
+pub fn explain_conflicts<R: BufRead, W: Write>(session: &Session,
+                                               mut input: R,
+                                               out: &mut W,
+                                               grammar: &Grammar,
+                                               file_text: &FileText)
+                                               -> io::Result<()> {
+    if grammar.algorithm.codegen == LrCodeGeneration::Earley {
+        return writeln!(out, "Earley parsers accept any grammar, so there are no conflicts.");
+    }
+
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+
+    let mut all = vec![];
+    for (&user_nt, &start_nt) in &grammar.start_nonterminals {
+        if let Err(error) = build_states(session, grammar, start_nt) {
+            let conflicts = distinct_token_conflicts(&error);
+            all.push(Conflicts { nonterminal: user_nt, error: error, conflicts: conflicts });
+        }
+    }
+
+    // every conflict, as the index of its nonterminal's `Conflicts`
+    // and its index in there
+    let conflicts: Vec<(usize, usize)> =
+        all.iter()
+           .enumerate()
+           .flat_map(|(i, c)| (0..c.conflicts.len()).map(move |j| (i, j)))
+           .collect();
+    if conflicts.is_empty() {
+        return writeln!(out, "No conflicts found.");
+    }
+
+    try!(writeln!(out, "Found {} conflict{}. Type `h` for help.",
+                  conflicts.len(),
+                  if conflicts.len() == 1 { "" } else { "s" }));
+    let mut current = 0;
+    let mut show = true;
+    loop {
+        let (i, j) = conflicts[current];
+        let (group, conflict) = (&all[i], &all[i].conflicts[j]);
+        if show {
+            try!(writeln!(out, ""));
+            try!(write_summary(out, current, conflicts.len(), group, conflict));
+        }
+        show = false;
+
+        try!(write!(out, "> "));
+        try!(out.flush());
+        let mut line = String::new();
+        if try!(input.read_line(&mut line)) == 0 {
+            return Ok(());
+        }
+
+        match line.trim() {
+            "" | "n" => {
+                if current + 1 < conflicts.len() {
+                    current += 1;
+                    show = true;
+                } else {
+                    try!(writeln!(out, "This is the last conflict."));
+                }
+            }
+            "p" => {
+                if current > 0 {
+                    current -= 1;
+                    show = true;
+                } else {
+                    try!(writeln!(out, "This is the first conflict."));
+                }
+            }
+            "l" => {
+                for (index, &(i, j)) in conflicts.iter().enumerate() {
+                    let conflict = &all[i].conflicts[j];
+                    try!(writeln!(out, "{:>4}. {} in state {}, on {}",
+                                  index + 1,
+                                  all[i].nonterminal,
+                                  conflict.state.0,
+                                  conflict.lookahead));
+                }
+            }
+            "e" => {
+                let message =
+                    report_conflict(session, file_text, grammar, &group.error, conflict);
+                try!(write_content(out, &message));
+            }
+            "t" => try!(write_traces(session, out, file_text, grammar, group, conflict)),
+            "s" => try!(write_source(out, file_text, group, conflict)),
+            "h" | "?" => try!(writeln!(out, "{}", HELP)),
+            "q" => return Ok(()),
+            command => match command.parse::<usize>() {
+                Ok(n) if n >= 1 && n <= conflicts.len() => {
+                    current = n - 1;
+                    show = true;
+                }
+                Ok(n) => try!(writeln!(out, "There is no conflict number {}.", n)),
+                Err(_) => try!(writeln!(out, "Unknown command `{}`. Type `h` for help.", command)),
+            },
+        }
+    }
+}
+
+fn write_summary<W: Write>(out: &mut W,
+                           index: usize,
+                           count: usize,
+                           group: &Conflicts,
+                           conflict: &TokenConflict)
+                           -> io::Result<()> {
+    try!(writeln!(out, "Conflict {} of {}: in state {} of the parser for `{}`, on {}, either",
+                  index + 1,
+                  count,
+                  conflict.state.0,
+                  group.nonterminal,
+                  conflict.lookahead));
+    try!(writeln!(out, "    reduce {}", conflict.production));
+    match conflict.action {
+        Action::Shift(terminal, _) => writeln!(out, "or  shift {}", terminal),
+        Action::Reduce(production) => writeln!(out, "or  reduce {}", production),
+    }
+}
+
+fn write_traces<W: Write>(session: &Session,
+                          out: &mut W,
+                          file_text: &FileText,
+                          grammar: &Grammar,
+                          group: &Conflicts,
+                          conflict: &TokenConflict)
+                          -> io::Result<()> {
+    let (action_examples, reduce_examples) =
+        conflict_traces(session, file_text, grammar, &group.error, conflict);
+    let action = match conflict.action {
+        Action::Shift(..) => "shifting",
+        Action::Reduce(..) => "the other reduction",
+    };
+    try!(write_examples(session, out, &format!("Examples of {}:", action), &action_examples));
+    write_examples(session, out, "Examples of the reduction:", &reduce_examples)
+}
+
+fn write_examples<W: Write>(session: &Session,
+                            out: &mut W,
+                            heading: &str,
+                            examples: &[Example])
+                            -> io::Result<()> {
+    try!(writeln!(out, "{}", heading));
+    if examples.is_empty() {
+        return writeln!(out, "  (none found)");
+    }
+    for (index, example) in examples.iter().enumerate() {
+        try!(writeln!(out, "  {}.", index + 1));
+        let picture = example.clone().into_picture(session, ExampleStyles::new(session));
+        try!(write_content(out, &*picture));
+    }
+    Ok(())
+}
+
+fn write_source<W: Write>(out: &mut W,
+                          file_text: &FileText,
+                          group: &Conflicts,
+                          conflict: &TokenConflict)
+                          -> io::Result<()> {
+    let mut productions = vec![conflict.production];
+    match conflict.action {
+        Action::Shift(terminal, _) => {
+            // the productions that would go on with the shift
+            let state = &group.error.states[conflict.state.0];
+            for item in state.items.vec.iter() {
+                if item.can_shift() &&
+                    item.production.symbols[item.index] == Symbol::Terminal(terminal) &&
+                    !productions.contains(&item.production)
+                {
+                    productions.push(item.production);
+                }
+            }
+        }
+        Action::Reduce(production) => productions.push(production),
+    }
+
+    for production in productions {
+        try!(writeln!(out, "{}: {}",
+                      file_text.span_str(production.span),
+                      production));
+        try!(file_text.highlight(production.span, out));
+    }
+    Ok(())
+}
+
+fn write_content<W: Write>(out: &mut W, content: &Content) -> io::Result<()> {
+    let canvas = content.emit_to_canvas(80);
+    for row in canvas.to_strings() {
+        try!(writeln!(out, "{}", row));
+    }
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use file_text::FileText;
+use grammar::repr::Grammar;
+use session::Session;
+use std::path::PathBuf;
+use test_util::normalized_grammar;
+use super::explain_conflicts;
+
+const GRAMMAR: &'static str = r#"
+grammar;
+    pub E: () = { E "+" E => (), "x" => () };
+"#;
+
+fn explain(grammar: &Grammar, commands: &str) -> String {
+    let file_text = FileText::new(PathBuf::from("tmp.lalrpop"), String::from(GRAMMAR));
+    let mut out = vec![];
+    explain_conflicts(&Session::test(), commands.as_bytes(), &mut out, grammar, &file_text)
+        .unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn summary_and_navigation() {
+    let grammar = normalized_grammar(GRAMMAR);
+    let out = explain(&grammar, "p\nn\n7\nl\nq\n");
+    assert_eq!(out, r#"Found 1 conflict. Type `h` for help.
+
+Conflict 1 of 1: in state 4 of the parser for `E`, on "+", either
+    reduce E = E "+" E
+or  shift "+"
+> This is the first conflict.
+> This is the last conflict.
+> There is no conflict number 7.
+>    1. E in state 4, on "+"
+> "#);
+}
+
+#[test]
+fn source() {
+    let grammar = normalized_grammar(GRAMMAR);
+    let out = explain(&grammar, "s\n");
+    assert!(out.contains(r#"tmp.lalrpop:3:19: 3:31: E = E "+" E"#), "{}", out);
+    assert!(out.contains("  ~~~~~~~~~~~~"), "{}", out);
+}
+
+#[test]
+fn traces() {
+    let grammar = normalized_grammar(GRAMMAR);
+    let out = explain(&grammar, "t\n");
+    assert!(out.contains("Examples of shifting:\n  1.\n"), "{}", out);
+    assert!(out.contains("Examples of the reduction:\n  1.\n"), "{}", out);
+}
+
+#[test]
+fn no_conflicts() {
+    let grammar = normalized_grammar(r#"
+grammar;
+    pub E: () = { E "+" "x" => (), "x" => () };
+"#);
+    assert_eq!(explain(&grammar, ""), "No conflicts found.\n");
+}
@@ -0,0 +1,56 @@
+//! Follow set construction and computation.
+
+use collections::{Map, map};
+use grammar::repr::*;
+use lr1::first::FirstSets;
+use lr1::lookahead::TokenSet;
+
+#[cfg(test)]
+mod test;
+
+/// For each nonterminal `X`, the set of tokens that may appear right
+/// after `X` in some sentence. EOF is included if `X` may end the
+/// input.
+#[derive(Clone)]
+pub struct FollowSets {
+    map: Map<NonterminalString, TokenSet>
+}
+
+impl FollowSets {
+    pub fn new(grammar: &Grammar, first_sets: &FirstSets) -> FollowSets {
+        let mut this = FollowSets { map: map() };
+        for &start_nt in grammar.start_nonterminals.values() {
+            this.map.insert(start_nt, TokenSet::eof());
+        }
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for production in grammar.nonterminals.values()
+                                                  .flat_map(|p| &p.productions) {
+                for (index, symbol) in production.symbols.iter().enumerate() {
+                    let nt = match *symbol {
+                        Symbol::Nonterminal(nt) => nt,
+                        Symbol::Terminal(_) => continue,
+                    };
+
+                    // FOLLOW(nt) includes FIRST(suffix), and if the
+                    // suffix may be empty, FOLLOW of the production's
+                    // nonterminal as well
+                    let follow_lhs = this.follow(production.nonterminal);
+                    let set = first_sets.first1(&production.symbols[index + 1..],
+                                                &follow_lhs);
+                    let follow_set =
+                        this.map.entry(nt).or_insert_with(|| TokenSet::new());
+                    changed |= follow_set.union_with(&set);
+                }
+            }
+        }
+        this
+    }
+
+    /// Returns `FOLLOW(nt)`.
+    pub fn follow(&self, nt: NonterminalString) -> TokenSet {
+        self.map.get(&nt).cloned().unwrap_or_else(TokenSet::new)
+    }
+}
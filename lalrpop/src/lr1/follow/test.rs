@@ -0,0 +1,52 @@
+use intern::intern;
+use grammar::repr::*;
+use lr1::first::FirstSets;
+use lr1::lookahead::Token;
+use lr1::lookahead::Token::EOF;
+use lr1::tls::Lr1Tls;
+use test_util::normalized_grammar;
+use super::FollowSets;
+
+fn nt(t: &str) -> NonterminalString {
+    NonterminalString(intern(t))
+}
+
+fn la(t: &str) -> Token {
+    Token::Terminal(TerminalString::quoted(intern(t)))
+}
+
+fn follow(follow: &FollowSets, t: &str) -> Vec<Token> {
+    follow.follow(nt(t)).iter().collect()
+}
+
+#[test]
+fn basic() {
+    let grammar = normalized_grammar(r#"
+grammar;
+    pub S: () = A B "c" => ();
+    A: () = { "a" => (), () => () };
+    B: () = { "b" => (), () => () };
+"#);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let first_sets = FirstSets::new(&grammar);
+    let follow_sets = FollowSets::new(&grammar, &first_sets);
+
+    assert_eq!(follow(&follow_sets, "A"), vec![la("b"), la("c")]);
+    assert_eq!(follow(&follow_sets, "B"), vec![la("c")]);
+    assert_eq!(follow(&follow_sets, "S"), vec![EOF]);
+}
+
+#[test]
+fn inherits_from_lhs() {
+    let grammar = normalized_grammar(r#"
+grammar;
+    pub S: () = { "(" E ")" => (), E => () };
+    E: () = { "x" T => () };
+    T: () = { "y" => (), () => () };
+"#);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let first_sets = FirstSets::new(&grammar);
+    let follow_sets = FollowSets::new(&grammar, &first_sets);
+
+    assert_eq!(follow(&follow_sets, "T"), vec![la(")"), EOF]);
+}
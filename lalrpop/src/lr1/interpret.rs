@@ -67,6 +67,7 @@ impl<'grammar, L> Machine<'grammar, L>
 
         self.state_stack.push(StateIndex(0));
 
+        let mut tokens = tokens.peekable();
         let mut token = tokens.next();
         while let Some(terminal) = token {
             let state = self.top_state();
@@ -74,14 +75,30 @@ impl<'grammar, L> Machine<'grammar, L>
             println!("state={:?}", state);
             println!("terminal={:?}", terminal);
 
-            // check whether we can shift this token
-            if let Some(&next_index) = state.shifts.get(&terminal) {
+            // check whether the token after this one decides
+            let following = match tokens.peek() {
+                Some(&next) => Token::Terminal(next),
+                None => Token::EOF,
+            };
+            let two_token_reduction =
+                state.two_token_reductions
+                     .iter()
+                     .find(|r| r.terminal == terminal && r.following.contains(following));
+            if let Some(reduction) = two_token_reduction {
+                let more = self.reduce(reduction.production);
+                assert!(more);
+            } else if let Some(&next_index) = state.shifts.get(&terminal) {
                 self.data_stack.push(ParseTree::Terminal(terminal));
                 self.state_stack.push(next_index);
                 token = tokens.next();
             } else if let Some(production) = L::reduction(state, Token::Terminal(terminal)) {
                 let more = self.reduce(production);
                 assert!(more);
+            } else if let Some(reduction) = state.two_token_reductions
+                                                 .iter()
+                                                 .find(|r| r.terminal == terminal) {
+                let more = self.reduce(reduction.production);
+                assert!(more);
             } else {
                 return Err((state, Token::Terminal(terminal)));
             }
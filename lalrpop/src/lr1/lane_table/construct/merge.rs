@@ -4,6 +4,7 @@ use lr1::core::{Action, LR1State, StateIndex};
 use lr1::lane_table::construct::state_set::StateSet;
 use lr1::lane_table::table::LaneTable;
 use lr1::lane_table::table::context_set::ContextSet;
+use session::Session;
 
 /// The "merge" phase of the algorithm is described in "Step 3c" of
 /// [the README][r].  It consists of walking through the various
@@ -15,6 +16,7 @@ use lr1::lane_table::table::context_set::ContextSet;
 ///
 /// [r]: ../README.md
 pub struct Merge<'m, 'grammar: 'm> {
+    session: &'m Session,
     table: &'m LaneTable<'grammar>,
     states: &'m mut Vec<LR1State<'grammar>>,
     visited: Set<StateIndex>,
@@ -25,13 +27,15 @@ pub struct Merge<'m, 'grammar: 'm> {
 }
 
 impl<'m, 'grammar> Merge<'m, 'grammar> {
-    pub fn new(table: &'m LaneTable<'grammar>,
+    pub fn new(session: &'m Session,
+               table: &'m LaneTable<'grammar>,
                unify: &'m mut UnificationTable<StateSet>,
                states: &'m mut Vec<LR1State<'grammar>>,
                state_sets: &'m mut Map<StateIndex, StateSet>,
                inconsistent_state: StateIndex)
                -> Self {
         Merge {
+            session: session,
             table: table,
             states: states,
             visited: Set::new(),
@@ -39,6 +43,7 @@ impl<'m, 'grammar> Merge<'m, 'grammar> {
             clones: Multimap::new(),
             target_states: vec![inconsistent_state],
             context_sets: ContextSets {
+                session: session,
                 unify: unify,
                 state_sets: state_sets,
             }
@@ -46,7 +51,7 @@ impl<'m, 'grammar> Merge<'m, 'grammar> {
     }
 
     pub fn start(&mut self, beachhead_state: StateIndex) -> Result<(), (StateIndex, StateIndex)> {
-        debug!("Merge::start(beachhead_state={:?})", beachhead_state);
+        log!(self.session, Debug, "Merge::start(beachhead_state={:?})", beachhead_state);
 
         // Since we always start walks from beachhead states, and they
         // are not reachable from anyone else, this state should not
@@ -55,12 +60,13 @@ impl<'m, 'grammar> Merge<'m, 'grammar> {
     }
 
     pub fn patch_target_starts(mut self, actions: &Set<Action<'grammar>>) {
-        debug!("Merge::patch_target_starts(actions={:?})", actions);
+        log!(self.session, Debug, "Merge::patch_target_starts(actions={:?})", actions);
 
         for &target_state in &self.target_states {
-            debug!("Merge::patch_target_starts: target_state={:?}", target_state);
+            log!(self.session, Debug, "Merge::patch_target_starts: target_state={:?}",
+                 target_state);
             let context_set = self.context_sets.context_set(target_state);
-            debug!("Merge::patch_target_starts: context_set={:?}", context_set);
+            log!(self.session, Debug, "Merge::patch_target_starts: context_set={:?}", context_set);
             context_set.apply(&mut self.states[target_state.0], actions);
         }
     }
@@ -76,24 +82,24 @@ impl<'m, 'grammar> Merge<'m, 'grammar> {
     }
 
     fn walk(&mut self, state: StateIndex) -> Result<(), (StateIndex, StateIndex)> {
-        debug!("Merge::walk(state={:?})", state);
+        log!(self.session, Debug, "Merge::walk(state={:?})", state);
 
         if !self.visited.insert(state) {
-            debug!("Merge::walk: visited already");
+            log!(self.session, Debug, "Merge::walk: visited already");
             return Ok(());
         }
 
         for &successor in self.successors(state).iter().flat_map(|&s| s) {
-            debug!("Merge::walk: state={:?} successor={:?}",
-                   state, successor);
+            log!(self.session, Debug, "Merge::walk: state={:?} successor={:?}",
+                 state, successor);
 
             if self.context_sets.union(state, successor) {
-                debug!("Merge::walk: successful union, context-set = {:?}",
-                       self.context_sets.context_set(state));
+                log!(self.session, Debug, "Merge::walk: successful union, context-set = {:?}",
+                     self.context_sets.context_set(state));
                 self.walk(successor)?;
             } else {
                 // search for an existing clone with which we can merge
-                debug!("Merge::walk: union failed, seek existing clone");
+                log!(self.session, Debug, "Merge::walk: union failed, seek existing clone");
                 let existing_clone = {
                     let context_sets = &mut self.context_sets;
                     self.clones.get(&successor)
@@ -105,23 +111,23 @@ impl<'m, 'grammar> Merge<'m, 'grammar> {
                 };
 
                 if let Some(successor1) = existing_clone {
-                    debug!("Merge::walk: found existing clone {:?}", successor1);
+                    log!(self.session, Debug, "Merge::walk: found existing clone {:?}", successor1);
                     self.patch_links(state, successor, successor1);
                     self.walk(successor1)?;
                 } else {
                     // if we don't find one, we have to make a new clone
-                    debug!("Merge::walk: creating new clone of {:?}", successor);
+                    log!(self.session, Debug, "Merge::walk: creating new clone of {:?}", successor);
                     let successor1 = self.clone(successor);
                     if self.context_sets.union(state, successor1) {
                         self.patch_links(state, successor, successor1);
                         self.walk(successor1)?;
                     } else {
-                        debug!("Merge::walk: failed to union {:?} with {:?}",
-                               state, successor1);
-                        debug!("Merge::walk: state context = {:?}",
-                               self.context_sets.context_set(state));
-                        debug!("Merge::walk: successor context = {:?}",
-                               self.context_sets.context_set(successor1));
+                        log!(self.session, Debug, "Merge::walk: failed to union {:?} with {:?}",
+                             state, successor1);
+                        log!(self.session, Debug, "Merge::walk: state context = {:?}",
+                             self.context_sets.context_set(state));
+                        log!(self.session, Debug, "Merge::walk: successor context = {:?}",
+                             self.context_sets.context_set(successor1));
 
                         return Err((self.original_index(state),
                                     self.original_index(successor1)));
@@ -153,7 +159,7 @@ impl<'m, 'grammar> Merge<'m, 'grammar> {
             self.target_states.push(new_index);
         }
 
-        debug!("Merge::clone: cloned {:?} to {:?}", state, new_index);
+        log!(self.session, Debug, "Merge::clone: cloned {:?} to {:?}", state, new_index);
         new_index
     }
 
@@ -179,6 +185,7 @@ impl<'m, 'grammar> Merge<'m, 'grammar> {
 }
 
 struct ContextSets<'m> {
+    session: &'m Session,
     state_sets: &'m mut Map<StateIndex, StateSet>,
     unify: &'m mut UnificationTable<StateSet>,
 }
@@ -193,8 +200,8 @@ impl<'m> ContextSets<'m> {
         let set1 = self.state_sets[&source];
         let set2 = self.state_sets[&target];
         let result = self.unify.unify_var_var(set1, set2).is_ok();
-        debug!("ContextSets::union: source={:?} target={:?} result={:?}",
-               source, target, result);
+        log!(self.session, Debug, "ContextSets::union: source={:?} target={:?} result={:?}",
+             source, target, result);
         result
     }
 
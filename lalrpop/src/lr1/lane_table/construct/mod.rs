@@ -10,7 +10,9 @@ use lr1::lookahead::{Lookahead, TokenSet};
 use lr1::lane_table::lane::LaneTracer;
 use lr1::lane_table::table::{ConflictIndex, LaneTable};
 use lr1::lane_table::table::context_set::OverlappingLookahead;
+use lr1::memory;
 use lr1::state_graph::StateGraph;
+use session::Session;
 use std::rc::Rc;
 
 mod merge;
@@ -35,9 +37,10 @@ impl<'grammar> LaneTableConstruct<'grammar> {
         }
     }
 
-    pub fn construct(self) -> Result<Vec<LR1State<'grammar>>, LR1TableConstructionError<'grammar>> {
+    pub fn construct(self, session: &Session)
+                     -> Result<Vec<LR1State<'grammar>>, LR1TableConstructionError<'grammar>> {
         let states = {
-            match build::build_lr0_states(self.grammar, self.start_nt) {
+            match build::build_lr0_states(session, self.grammar, self.start_nt) {
                 Ok(states) => {
                     // In this case, the grammar is actually
                     // LR(0). This is very rare -- it means that the
@@ -64,7 +67,8 @@ impl<'grammar> LaneTableConstruct<'grammar> {
         };
 
         // Convert the LR(0) states into LR(0-1) states.
-        let mut states = self.promote_lr0_states(states);
+        let mut states = self.promote_lr0_states(session, states);
+        memory::note(memory::states_bytes(&states));
 
         // For each inconsistent state, apply the lane-table algorithm to
         // resolve it.
@@ -73,33 +77,39 @@ impl<'grammar> LaneTableConstruct<'grammar> {
                 break;
             }
 
-            match self.resolve_inconsistencies(&mut states, StateIndex(i)) {
+            match self.resolve_inconsistencies(session, &mut states, StateIndex(i)) {
                 Ok(()) => { }
                 Err(_) => {
                     // We failed because of irreconcilable conflicts
                     // somewhere. Just compute the conflicts from the final set of
                     // states.
-                    debug!("construct: failed to resolve inconsistencies in state {:#?}",
-                           states[i]);
+                    log!(session, Debug,
+                         "construct: failed to resolve inconsistencies in state {:#?}",
+                         states[i]);
                     let conflicts: Vec<Conflict<'grammar, TokenSet>> =
                         states.iter()
                               .flat_map(|s| Lookahead::conflicts(&s))
                               .collect();
+                    memory::note(memory::states_bytes(&states));
                     return Err(TableConstructionError { states: states,
                                                         conflicts: conflicts });
                 }
             }
         }
 
+        memory::note(memory::states_bytes(&states));
         Ok(states)
     }
 
     /// Given a set of LR0 states, returns LR1 states where the lookahead
     /// is always `TokenSet::all()`. We refer to these states as LR(0-1)
     /// states in the README.
-    fn promote_lr0_states(&self, lr0: Vec<LR0State<'grammar>>) -> Vec<LR1State<'grammar>> {
+    fn promote_lr0_states(&self,
+                          session: &Session,
+                          lr0: Vec<LR0State<'grammar>>)
+                          -> Vec<LR1State<'grammar>> {
         let all = TokenSet::all();
-        debug!("promote_lr0_states: all={:?}", all);
+        log!(session, Debug, "promote_lr0_states: all={:?}", all);
         lr0.into_iter()
             .map(|s| {
                 let items = s.items
@@ -123,17 +133,19 @@ impl<'grammar> LaneTableConstruct<'grammar> {
                     shifts: s.shifts,
                     reductions: reductions,
                     gotos: s.gotos,
+                    two_token_reductions: vec![],
                 }
             })
             .collect()
     }
 
     fn resolve_inconsistencies(&self,
+                               session: &Session,
                                states: &mut Vec<LR1State<'grammar>>,
                                inconsistent_state: StateIndex)
                                -> Result<(), StateIndex> {
-        debug!("resolve_inconsistencies(inconsistent_state={:?}/{:#?}",
-               inconsistent_state, states[inconsistent_state.0]);
+        log!(session, Debug, "resolve_inconsistencies(inconsistent_state={:?}/{:#?}",
+             inconsistent_state, states[inconsistent_state.0]);
 
         let mut actions = super::conflicting_actions(&states[inconsistent_state.0]);
         if actions.is_empty() {
@@ -156,13 +168,13 @@ impl<'grammar> LaneTableConstruct<'grammar> {
             }
         }
 
-        debug!("resolve_inconsistencies: conflicting_actions={:?}", actions);
+        log!(session, Debug, "resolve_inconsistencies: conflicting_actions={:?}", actions);
 
         let table = self.build_lane_table(states, inconsistent_state, &actions);
 
         // Consider first the "LALR" case, where the lookaheads for each
         // action are completely disjoint.
-        if self.attempt_lalr(&mut states[inconsistent_state.0], &table, &actions) {
+        if self.attempt_lalr(session, &mut states[inconsistent_state.0], &table, &actions) {
             return Ok(());
         }
 
@@ -172,24 +184,30 @@ impl<'grammar> LaneTableConstruct<'grammar> {
         //
         // (To handle unification, we also map each state to a
         // `StateSet` that is its entry in the `ena` table.)
-        let rows = table.rows()?;
+        let rows = table.rows(session)?;
         let mut unify = UnificationTable::<StateSet>::new();
         let mut state_sets = Map::new();
         for (&state_index, context_set) in &rows {
             let state_set = unify.new_key(context_set.clone());
             state_sets.insert(state_index, state_set);
-            debug!("resolve_inconsistencies: state_index={:?}, state_set={:?}",
-                   state_index, state_set);
+            log!(session, Debug, "resolve_inconsistencies: state_index={:?}, state_set={:?}",
+                 state_index, state_set);
         }
 
         // Now merge state-sets, cloning states where needed.
-        let mut merge = Merge::new(&table, &mut unify, states, &mut state_sets, inconsistent_state);
+        let mut merge = Merge::new(session,
+                                   &table,
+                                   &mut unify,
+                                   states,
+                                   &mut state_sets,
+                                   inconsistent_state);
         let beachhead_states = table.beachhead_states();
         for beachhead_state in beachhead_states {
             match merge.start(beachhead_state) {
                 Ok(()) => { }
                 Err((source, _)) => {
-                    debug!("resolve_inconsistencies: failed to merge, source={:?}", source);
+                    log!(session, Debug, "resolve_inconsistencies: failed to merge, source={:?}",
+                         source);
                     return Err(source);
                 }
             }
@@ -200,19 +218,20 @@ impl<'grammar> LaneTableConstruct<'grammar> {
     }
 
     fn attempt_lalr(&self,
+                    session: &Session,
                     state: &mut LR1State<'grammar>,
                     table: &LaneTable<'grammar>,
                     actions: &Set<Action<'grammar>>)
                     -> bool {
         match table.columns() {
             Ok(columns) => {
-                debug!("attempt_lalr, columns={:#?}", columns);
+                log!(session, Debug, "attempt_lalr, columns={:#?}", columns);
                 columns.apply(state, actions);
-                debug!("attempt_lalr, state={:#?}", state);
+                log!(session, Debug, "attempt_lalr, state={:#?}", state);
                 true
             }
             Err(OverlappingLookahead) => {
-                debug!("attempt_lalr, OverlappingLookahead");
+                log!(session, Debug, "attempt_lalr, OverlappingLookahead");
                 false
             }
         }
@@ -2,6 +2,7 @@ use collections::Set;
 use lr1::core::*;
 use lr1::lookahead::Lookahead;
 use grammar::repr::*;
+use session::Session;
 
 mod construct;
 mod lane;
@@ -10,10 +11,11 @@ mod table;
 #[cfg(test)]
 mod test;
 
-pub fn build_lane_table_states<'grammar>(grammar: &'grammar Grammar,
+pub fn build_lane_table_states<'grammar>(session: &Session,
+                                         grammar: &'grammar Grammar,
                                          start: NonterminalString)
                                          -> LR1Result<'grammar> {
-    construct::LaneTableConstruct::new(grammar, start).construct()
+    construct::LaneTableConstruct::new(grammar, start).construct(session)
 }
 
 fn conflicting_actions<'grammar, L: Lookahead>(state: &State<'grammar, L>)
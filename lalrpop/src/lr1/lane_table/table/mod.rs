@@ -17,6 +17,7 @@ use collections::{Map, Multimap, Set};
 use grammar::repr::*;
 use lr1::core::*;
 use lr1::lookahead::*;
+use session::Session;
 use std::default::Default;
 use std::fmt::{Debug, Error, Formatter};
 use std::iter;
@@ -115,11 +116,12 @@ impl<'grammar> LaneTable<'grammar> {
     /// along with the context set for each state (i.e., each row in
     /// the table, basically). Returns Err if any state has a conflict
     /// between the context sets even within its own row.
-    pub fn rows(&self) -> Result<Map<StateIndex, ContextSet>, StateIndex> {
+    pub fn rows(&self, session: &Session) -> Result<Map<StateIndex, ContextSet>, StateIndex> {
         let mut map = Map::new();
         for (&(state_index, conflict_index), token_set) in &self.lookaheads {
-            debug!("rows: inserting state_index={:?} conflict_index={:?} token_set={:?}",
-                   state_index, conflict_index, token_set);
+            log!(session, Debug,
+                 "rows: inserting state_index={:?} conflict_index={:?} token_set={:?}",
+                 state_index, conflict_index, token_set);
             match {
                 map.entry(state_index)
                    .or_insert_with(|| ContextSet::new(self.conflicts))
@@ -127,8 +129,9 @@ impl<'grammar> LaneTable<'grammar> {
             } {
                 Ok(_changed) => { }
                 Err(OverlappingLookahead) => {
-                    debug!("rows: intra-row conflict inserting state_index={:?} conflict_index={:?} token_set={:?}",
-                           state_index, conflict_index, token_set);
+                    log!(session, Debug,
+                         "rows: intra-row conflict inserting state_index={:?} conflict_index={:?} token_set={:?}",
+                         state_index, conflict_index, token_set);
                     return Err(state_index);
                 }
             }
@@ -1,5 +1,6 @@
 use intern::intern;
 use grammar::repr::*;
+use session::Session;
 use test_util::{expect_debug, normalized_grammar};
 use lr1::build;
 use lr1::core::*;
@@ -7,7 +8,6 @@ use lr1::first::FirstSets;
 use lr1::interpret;
 use lr1::state_graph::StateGraph;
 use lr1::tls::Lr1Tls;
-use tls::Tls;
 
 use super::construct::*;
 use super::lane::*;
@@ -103,7 +103,7 @@ fn build_table<'grammar>(grammar: &'grammar Grammar,
                          goal: &str,
                          tokens: &[&str])
                          -> LaneTable<'grammar> {
-    let lr0_err = build::build_lr0_states(&grammar, nt(goal)).unwrap_err();
+    let lr0_err = build::build_lr0_states(&Session::test(), &grammar, nt(goal)).unwrap_err();
 
     // Push the `tokens` to find the index of the inconsistent state
     let inconsistent_state_index = traverse(&lr0_err.states, tokens);
@@ -133,7 +133,6 @@ fn build_table<'grammar>(grammar: &'grammar Grammar,
 
 #[test]
 fn g0_conflict_1() {
-    let _tls = Tls::test();
     let grammar = paper_example_g0();
     let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
     let table = build_table(&grammar, "G", &["e"]);
@@ -154,7 +153,6 @@ fn g0_conflict_1() {
 
 #[test]
 fn paper_example_g1_conflict_1() {
-    let _tls = Tls::test();
     let grammar = paper_example_g1();
     let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
     let table = build_table(&grammar, "G", &["a", "e"]);
@@ -176,11 +174,10 @@ fn paper_example_g1_conflict_1() {
 
 #[test]
 fn paper_example_g0_build() {
-    let _tls = Tls::test();
     let grammar = paper_example_g0();
     let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
-    let lr0_err = build::build_lr0_states(&grammar, nt("G")).unwrap_err();
-    let states = LaneTableConstruct::new(&grammar, nt("G")).construct()
+    let lr0_err = build::build_lr0_states(&Session::test(), &grammar, nt("G")).unwrap_err();
+    let states = LaneTableConstruct::new(&grammar, nt("G")).construct(&Session::test())
         .expect("failed to build lane table states");
 
     // we do not require more *states* than LR(0), just different lookahead
@@ -200,11 +197,10 @@ fn paper_example_g0_build() {
 
 #[test]
 fn paper_example_g1_build() {
-    let _tls = Tls::test();
     let grammar = paper_example_g1();
     let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
-    let lr0_err = build::build_lr0_states(&grammar, nt("G")).unwrap_err();
-    let states = LaneTableConstruct::new(&grammar, nt("G")).construct()
+    let lr0_err = build::build_lr0_states(&Session::test(), &grammar, nt("G")).unwrap_err();
+    let states = LaneTableConstruct::new(&grammar, nt("G")).construct(&Session::test())
         .expect("failed to build lane table states");
 
     // we require more *states* than LR(0), not just different lookahead
@@ -277,7 +273,6 @@ P: () = {
 
 #[test]
 fn large_conflict_1() {
-    let _tls = Tls::test();
     let grammar = paper_example_large();
     let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
     let table = build_table(&grammar, "G", &["x", "s", "k", "t"]);
@@ -340,10 +335,9 @@ fn large_conflict_1() {
 
 #[test]
 fn paper_example_large_build() {
-    let _tls = Tls::test();
     let grammar = paper_example_large();
     let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
-    let states = LaneTableConstruct::new(&grammar, nt("G")).construct()
+    let states = LaneTableConstruct::new(&grammar, nt("G")).construct(&Session::test())
         .expect("failed to build lane table states");
 
     let tree = interpret::interpret(&states, tokens!["y", "s", "k", "t", "c", "b"]).unwrap();
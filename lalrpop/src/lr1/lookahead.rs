@@ -11,6 +11,10 @@ pub trait Lookahead: Clone + Debug + Eq + Ord + Hash + Collection<Item=Self> {
 
     fn conflicts<'grammar>(this_state: &State<'grammar, Self>)
                            -> Vec<Conflict<'grammar, Self>>;
+
+    /// Roughly how many bytes the lookahead holds on the heap; see
+    /// `lr1::memory`.
+    fn heap_bytes(&self) -> usize;
 }
 
 #[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
@@ -29,6 +33,10 @@ impl Lookahead for Nil {
         Ok(())
     }
 
+    fn heap_bytes(&self) -> usize {
+        0
+    }
+
     fn conflicts<'grammar>(this_state: &State<'grammar, Self>)
                            -> Vec<Conflict<'grammar, Self>> {
         let index = this_state.index;
@@ -78,6 +86,10 @@ impl Lookahead for TokenSet {
         write!(fmt, " {:?}", self)
     }
 
+    fn heap_bytes(&self) -> usize {
+        self.bit_set.capacity() / 8
+    }
+
     fn conflicts<'grammar>(this_state: &State<'grammar, Self>)
                            -> Vec<Conflict<'grammar, Self>> {
         let mut conflicts = vec![];
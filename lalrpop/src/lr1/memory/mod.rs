@@ -0,0 +1,104 @@
+//! Accounting of the memory that building the LR tables takes. There
+//! is no stable way to observe the allocator, so the passes that build
+//! states add up what the states they hold keep on the heap (see
+//! `state_bytes`) as they go, and `record` collects the peak that each
+//! pass reached. The numbers are estimates: they are meant for
+//! comparing passes and grammars, not for predicting the footprint of
+//! the process.
+//!
+//! The canonical LR(1) construction can take far more memory than the
+//! lane table construction, as it keeps apart states that only differ
+//! in their lookaheads. With `Configuration::lr1_fallback_budget`, it
+//! gives up once the states it has built exceed the budget, and drops
+//! them all: it does not evict some of them to stay within it. The
+//! legacy construction then falls back on the lane table construction;
+//! the passes that need the exact lookaheads of the canonical states
+//! get an `OverBudget` error.
+
+use grammar::repr::*;
+use lr1::core::*;
+use lr1::lookahead::Lookahead;
+use std::cell::RefCell;
+use std::cmp::max;
+use std::mem;
+
+#[cfg(test)]
+mod test;
+
+/// The peak memory of the passes that ran while `record` did, in the
+/// order they began.
+struct Recording {
+    passes: Vec<(String, usize)>,
+
+    /// the indices in `passes` of those still running
+    open: Vec<usize>,
+}
+
+thread_local! {
+    static RECORDING: RefCell<Option<Recording>> = RefCell::new(None)
+}
+
+/// Runs `op`, and returns its result along with the peak memory, in
+/// bytes, of each of the passes that ran during it.
+pub fn record<R, F: FnOnce() -> R>(op: F) -> (R, Vec<(String, usize)>) {
+    let previous = RECORDING.with(|recording| {
+        mem::replace(&mut *recording.borrow_mut(),
+                     Some(Recording { passes: vec![], open: vec![] }))
+    });
+    let result = op();
+    let recording = RECORDING.with(|recording| {
+        mem::replace(&mut *recording.borrow_mut(), previous)
+    });
+    (result, recording.map_or(vec![], |recording| recording.passes))
+}
+
+/// Runs the pass `name`, whose peak memory is that of the largest
+/// `note` while it runs.
+pub fn pass<R, F: FnOnce() -> R>(name: &str, op: F) -> R {
+    RECORDING.with(|recording| {
+        if let Some(ref mut recording) = *recording.borrow_mut() {
+            recording.open.push(recording.passes.len());
+            recording.passes.push((name.to_string(), 0));
+        }
+    });
+    let result = op();
+    RECORDING.with(|recording| {
+        if let Some(ref mut recording) = *recording.borrow_mut() {
+            recording.open.pop();
+        }
+    });
+    result
+}
+
+/// Notes that the running passes hold `bytes` bytes.
+pub fn note(bytes: usize) {
+    RECORDING.with(|recording| {
+        if let Some(ref mut recording) = *recording.borrow_mut() {
+            for &index in &recording.open {
+                let peak = &mut recording.passes[index].1;
+                *peak = max(*peak, bytes);
+            }
+        }
+    });
+}
+
+/// Roughly how many bytes `items` take, with their lookaheads.
+pub fn items_bytes<'grammar, L: Lookahead>(items: &[Item<'grammar, L>]) -> usize {
+    items.len() * mem::size_of::<Item<'grammar, L>>() +
+    items.iter().map(|item| item.lookahead.heap_bytes()).sum::<usize>()
+}
+
+/// Roughly how many bytes `state` takes. An entry of its maps of
+/// transitions counts twice, for the nodes of the tree it is in.
+pub fn state_bytes<'grammar, L: Lookahead>(state: &State<'grammar, L>) -> usize {
+    let entry = mem::size_of::<(TerminalString, StateIndex)>();
+    mem::size_of::<State<'grammar, L>>() + items_bytes(&state.items.vec) +
+    state.reductions.len() * mem::size_of::<(L, &'grammar Production)>() +
+    state.reductions.iter().map(|&(ref lookahead, _)| lookahead.heap_bytes()).sum::<usize>() +
+    (state.shifts.len() + state.gotos.len()) * 2 * entry
+}
+
+/// Roughly how many bytes `states` take.
+pub fn states_bytes<'grammar, L: Lookahead>(states: &[State<'grammar, L>]) -> usize {
+    states.iter().map(state_bytes).sum()
+}
@@ -0,0 +1,82 @@
+use intern::intern;
+use grammar::repr::*;
+use lr1::build::{build_canonical_lr1_states, build_lr1_states, build_lr1_states_legacy, OverBudget};
+use lr1::interpret::interpret;
+use lr1::tls::Lr1Tls;
+use session::Session;
+use test_util::normalized_grammar;
+
+use super::{note, pass, record, state_bytes, states_bytes};
+
+fn nt(t: &str) -> NonterminalString {
+    NonterminalString(intern(t))
+}
+
+macro_rules! tokens {
+    ($($x:expr),*) => {
+        vec![$(TerminalString::quoted(intern($x))),*]
+    }
+}
+
+fn grammar() -> Grammar {
+    normalized_grammar(r#"
+grammar;
+    pub Expr: i32 = { <l:Expr> "+" <r:Atom> => l + r, Atom };
+    Atom: i32 = { "N" => 1, "(" <Expr> ")" };
+"#)
+}
+
+#[test]
+fn passes() {
+    let ((), passes) = record(|| {
+        note(1000);
+        pass("outer", || {
+            note(10);
+            pass("inner", || note(30));
+            note(20);
+        });
+        pass("last", || ());
+    });
+    assert_eq!(passes,
+               vec![("outer".to_string(), 30), ("inner".to_string(), 30),
+                    ("last".to_string(), 0)]);
+
+    // nothing is recorded outside of `record`
+    pass("unrecorded", || note(10));
+    let ((), passes) = record(|| ());
+    assert!(passes.is_empty());
+}
+
+#[test]
+fn construction() {
+    let grammar = grammar();
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let start = grammar.start_nonterminals[&nt("Expr")];
+
+    let (states, passes) = record(|| build_canonical_lr1_states(&Session::test(), &grammar, start));
+    let states = states.unwrap().unwrap();
+    assert!(states.iter().all(|state| state_bytes(state) > 0));
+    assert_eq!(passes, vec![("canonical LR(1) state construction".to_string(),
+                             states_bytes(&states))]);
+}
+
+#[test]
+fn over_budget() {
+    let grammar = grammar();
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let start = grammar.start_nonterminals[&nt("Expr")];
+
+    let mut session = Session::test();
+    session.lr1_fallback_budget = Some(1);
+    assert_eq!(build_canonical_lr1_states(&session, &grammar, start).unwrap_err(),
+               OverBudget { start: start, budget: 1 });
+
+    // the legacy construction gives up on the canonical states for
+    // those of the lane table construction
+    let states = build_lr1_states_legacy(&session, &grammar, start).unwrap();
+    let lane_states = build_lr1_states(&Session::test(), &grammar, start).unwrap();
+    assert_eq!(states.len(), lane_states.len());
+
+    assert!(interpret(&states, tokens!["N", "+", "(", "N", "+", "N", ")"]).is_ok());
+    assert!(interpret(&states, tokens!["N", "+"]).is_err());
+}
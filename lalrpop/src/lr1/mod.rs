@@ -1,38 +1,124 @@
 //! Naive LR(1) generation algorithm.
 
 use grammar::repr::*;
+use session::Session;
 
 pub mod codegen;
+mod ambiguity;
+mod analysis;
+mod animate;
+mod repl;
 mod build;
 mod build_lalr;
+mod checkpoint;
+mod classify;
+mod conflicts;
 mod core;
 mod error;
 mod example;
+mod explain;
+mod playground;
+mod profile;
 mod first;
+mod follow;
 mod lane_table;
 mod lookahead;
+mod memory;
 mod state_graph;
 mod tls;
 mod trace;
+mod two_tokens;
+mod unit_rules;
 mod report;
+mod sets;
+mod stats;
 use std::io::{self, Write};
 
 #[cfg(test)] mod interpret;
 
-pub use self::core::{LR1Result, LR1TableConstructionError};
+pub use self::animate::animate_parse;
+pub use self::build::{resolve_conflicts, OverBudget};
+pub use self::ambiguity::{report_ambiguity, search_ambiguity, sentences};
+pub use self::analysis::{analyze_grammar, ConflictAction, ConflictInfo, ExampleInfo,
+                         GrammarAnalysis, NonterminalInfo, ParserInfo, StateInfo};
+pub use self::conflicts::{conflict_fingerprints, dump_conflicts, json_string, parse_baseline,
+                          write_baseline, write_conflicts};
+pub use self::core::{LR1Result, LR1State, LR1TableConstructionError};
 pub use self::error::report_error;
+pub use self::explain::explain_conflicts;
+pub use self::playground::write_playground;
+pub use self::profile::{parse_profile, reorder_states, Profile};
+pub use self::repl::repl;
 pub use self::tls::Lr1Tls;
+pub use self::unit_rules::eliminate_unit_reductions;
 
-pub fn build_states<'grammar>(grammar: &'grammar Grammar,
+pub fn build_states<'grammar>(session: &Session,
+                              grammar: &'grammar Grammar,
                               start: NonterminalString)
                               -> LR1Result<'grammar> {
-    if !grammar.algorithm.lalr {
-        build::build_lr1_states(grammar, start)
+    let result = if !grammar.algorithm.lalr {
+        build::build_lr1_states(session, grammar, start)
     } else {
-        build_lalr::build_lalr_states(grammar, start)
+        build_lalr::build_lalr_states(session, grammar, start)
+    };
+
+    // conflicts in reducing `#[lookahead = "2"]` nonterminals may yet
+    // be resolved with another token of lookahead, which needs the
+    // exact lookaheads of the canonical states
+    match result {
+        Err(error) if grammar.nonterminals.values().any(|nt| nt.two_token_lookahead()) => {
+            match build_canonical_states(session, grammar, start) {
+                Ok(Ok(states)) => Ok(states),
+                Ok(Err(error)) => two_tokens::resolve_with_two_tokens(grammar, error),
+                Err(over_budget) => {
+                    log!(session, Informative,
+                         "Not looking at two tokens to resolve conflicts: {}.",
+                         over_budget);
+                    Err(error)
+                }
+            }
+        }
+        result => result,
     }
 }
 
+/// Builds all of the states for `start` with the algorithm the
+/// grammar asks for, whatever conflicts they have.
+pub fn build_all_states<'grammar>(session: &Session,
+                                  grammar: &'grammar Grammar,
+                                  start: NonterminalString)
+                                  -> LR1Result<'grammar> {
+    // stopping at the first conflict would leave states out
+    let mut session = session.clone();
+    session.report_all_conflicts = true;
+    build_states(&session, grammar, start)
+}
+
+/// Builds the states for `start` whatever conflicts they have,
+/// resolving those by default; see
+/// `Configuration::conflicts_as_warnings`.
+pub fn build_states_resolving_conflicts<'grammar>(session: &Session,
+                                                  grammar: &'grammar Grammar,
+                                                  start: NonterminalString)
+                                                  -> Vec<LR1State<'grammar>> {
+    let mut states = match build_all_states(session, grammar, start) {
+        Ok(states) => states,
+        Err(error) => error.states,
+    };
+    resolve_conflicts(grammar, &mut states);
+    states
+}
+
+/// Builds all of the canonical LR(1) states for `start`, whatever
+/// conflicts they have, which are reported with exact lookaheads,
+/// unless they exceed the fallback budget.
+pub fn build_canonical_states<'grammar>(session: &Session,
+                                        grammar: &'grammar Grammar,
+                                        start: NonterminalString)
+                                        -> Result<LR1Result<'grammar>, OverBudget> {
+    build::build_canonical_lr1_states(session, grammar, start)
+}
+
 pub fn generate_report<'grammar, W : Write + 'grammar>
     ( out:          &'grammar mut W
     , lr1result:    &LR1Result<'grammar>
@@ -40,3 +126,21 @@ pub fn generate_report<'grammar, W : Write + 'grammar>
 {
     report::generate_report(out, lr1result)
 }
+
+pub fn generate_sets<W: Write>(out: &mut W, grammar: &Grammar) -> io::Result<()> {
+    sets::generate_sets(out, grammar)
+}
+
+pub fn generate_classes<W: Write>(session: &Session,
+                                  out: &mut W,
+                                  grammar: &Grammar)
+                                  -> io::Result<()> {
+    classify::generate_classes(session, out, grammar)
+}
+
+pub fn generate_stats<W: Write>(session: &Session,
+                                out: &mut W,
+                                grammar: &Grammar)
+                                -> io::Result<()> {
+    stats::generate_stats(session, out, grammar)
+}
@@ -0,0 +1,385 @@
+//! Writes a static HTML page for trying out a grammar in the browser
+//! (`lalrpop playground`): the user types some input and sees, as
+//! they type, its derivation as a tree or the point where it is
+//! rejected. The page embeds the parse table, and the DFA of the
+//! built-in tokenizer if the grammar has one, along with a small
+//! interpreter for both written in JavaScript, so it needs no server
+//! and can be put on the documentation site of a language.
+//!
+//! As for `lalrpop animate`, the input of a grammar with an `extern`
+//! token declaration is a list of terminals separated by whitespace.
+//! The action code is not run, so the page shows derivations rather
+//! than values.
+
+use collections::Map;
+use grammar::repr::*;
+use lexer::dfa::Kind;
+use lr1::animate::{build_parser, choose_nonterminal, script_string};
+use lr1::core::*;
+use lr1::lookahead::Token;
+use lr1::tls::Lr1Tls;
+use session::Session;
+use std::io::{self, Write};
+
+#[cfg(test)]
+mod test;
+
+/// Writes the playground for the parser for `nonterminal` (or the
+/// only public nonterminal) of `grammar` to `out`. The grammar must
+/// not have conflicts.
+pub fn write_playground<W: Write>(session: &Session,
+                                  out: &mut W,
+                                  grammar: &Grammar,
+                                  nonterminal: Option<NonterminalString>)
+                                  -> io::Result<()> {
+    let nonterminal = try!(choose_nonterminal(grammar, nonterminal));
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let states = try!(build_parser(session, grammar, nonterminal));
+
+    let terminals: Map<TerminalString, usize> =
+        grammar.terminals.all.iter().cloned().zip(0..).collect();
+
+    let mut productions: Vec<&Production> = vec![];
+    let mut production_indices: Map<&Production, usize> = Map::new();
+    for state in &states {
+        for &(_, production) in &state.reductions {
+            if !production_indices.contains_key(production) {
+                production_indices.insert(production, productions.len());
+                productions.push(production);
+            }
+        }
+    }
+
+    let page = fill_template(PAGE,
+                             &[("TITLE", format!("{} playground", nonterminal)),
+                               ("TERMINALS", terminals_json(grammar)),
+                               ("PRODUCTIONS", productions_json(&productions)),
+                               ("STATES", states_json(&states, &terminals, &production_indices)),
+                               ("DFA", dfa_json(grammar, &terminals))]);
+    out.write_all(page.as_bytes())
+}
+
+/// Replaces each `$NAME` in `template` with the value given for
+/// `NAME`. This is done in a single pass over the template, so a value
+/// that happens to contain `$NAME` (say, the text of a terminal) is
+/// copied as it is.
+fn fill_template(template: &str, values: &[(&str, String)]) -> String {
+    let mut page = String::new();
+    let mut rest = template;
+    while let Some(dollar) = rest.find('$') {
+        page.push_str(&rest[..dollar]);
+        rest = &rest[dollar + 1..];
+        match values.iter().find(|&&(name, _)| rest.starts_with(name)) {
+            Some(&(name, ref value)) => {
+                page.push_str(value);
+                rest = &rest[name.len()..];
+            }
+            None => page.push('$'),
+        }
+    }
+    page.push_str(rest);
+    page
+}
+
+/// For each terminal, its name and whether its text is worth showing
+/// (it is not for literals like `"+"`).
+fn terminals_json(grammar: &Grammar) -> String {
+    let terminals: Vec<String> =
+        grammar.terminals
+               .all
+               .iter()
+               .map(|terminal| {
+                   let quoted = match terminal.as_literal() {
+                       Some(TerminalLiteral::Quoted(_)) => true,
+                       _ => false,
+                   };
+                   format!("{{ name: {}, quoted: {} }}",
+                           script_string(&terminal.to_string()),
+                           quoted)
+               })
+               .collect();
+    terminals.join(",\n")
+}
+
+fn productions_json(productions: &[&Production]) -> String {
+    let productions: Vec<String> =
+        productions.iter()
+                   .map(|production| {
+                       format!("{{ nonterminal: {}, length: {}, text: {} }}",
+                               script_string(&production.nonterminal.to_string()),
+                               production.symbols.len(),
+                               script_string(&production.to_string()))
+                   })
+                   .collect();
+    productions.join(",\n")
+}
+
+/// For each state, its shifts and gotos, and its reductions with the
+/// terminals they apply to; the end of the input is terminal `-1`.
+fn states_json(states: &[LR1State],
+               terminals: &Map<TerminalString, usize>,
+               production_indices: &Map<&Production, usize>)
+               -> String {
+    let states: Vec<String> =
+        states.iter()
+              .map(|state| {
+                  let shifts: Vec<String> =
+                      state.shifts
+                           .iter()
+                           .map(|(terminal, next)| format!("{}: {}", terminals[terminal], next.0))
+                           .collect();
+                  let reductions: Vec<String> =
+                      state.reductions
+                           .iter()
+                           .map(|&(ref tokens, production)| {
+                               let lookahead: Vec<String> =
+                                   tokens.iter()
+                                         .filter_map(|token| match token {
+                                             Token::Terminal(terminal) => {
+                                                 Some(terminals[&terminal].to_string())
+                                             }
+                                             Token::EOF => Some(String::from("-1")),
+                                             Token::Error => None,
+                                         })
+                                         .collect();
+                               format!("[[{}], {}]",
+                                       lookahead.join(", "),
+                                       production_indices[production])
+                           })
+                           .collect();
+                  let gotos: Vec<String> =
+                      state.gotos
+                           .iter()
+                           .map(|(nonterminal, next)| {
+                               format!("{}: {}", script_string(&nonterminal.to_string()), next.0)
+                           })
+                           .collect();
+                  format!("{{ shifts: {{{}}}, reductions: [{}], gotos: {{{}}} }}",
+                          shifts.join(", "),
+                          reductions.join(", "),
+                          gotos.join(", "))
+              })
+              .collect();
+    states.join(",\n")
+}
+
+/// The states of the DFA of the built-in tokenizer, or `null` if
+/// there is none. Each state has the terminal it accepts (or `-1`),
+/// whether it rejects, its edges as `[start, end, next]` character
+/// ranges, with `end` exclusive, and the state to go to otherwise.
+fn dfa_json(grammar: &Grammar, terminals: &Map<TerminalString, usize>) -> String {
+    let intern_token = match grammar.intern_token {
+        Some(ref intern_token) => intern_token,
+        None => return String::from("null"),
+    };
+    let states: Vec<String> =
+        intern_token.dfa
+                    .states
+                    .iter()
+                    .map(|state| {
+                        let (accepts, reject) = match state.kind {
+                            Kind::Accepts(nfa) => {
+                                let terminal = intern_token.match_entries[nfa.index()].user_name;
+                                (terminals[&terminal] as isize, false)
+                            }
+                            Kind::Reject => (-1, true),
+                            Kind::Neither => (-1, false),
+                        };
+                        let edges: Vec<String> =
+                            state.test_edges
+                                 .iter()
+                                 .map(|&(test, next)| {
+                                     format!("[{}, {}, {}]", test.start, test.end, next.index())
+                                 })
+                                 .collect();
+                        format!("{{ accepts: {}, reject: {}, edges: [{}], other: {} }}",
+                                accepts,
+                                reject,
+                                edges.join(", "),
+                                state.other_edge.index())
+                    })
+                    .collect();
+    format!("[\n{}\n]", states.join(",\n"))
+}
+
+const PAGE: &'static str = r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>$TITLE</title>
+<style>
+  body { font-family: sans-serif; margin: 2em; }
+  textarea { width: 100%; font-family: monospace; font-size: 110%; }
+  #result { font-family: monospace; }
+  #result ul { list-style: none; padding-left: 1.5em; margin: 0; }
+  .terminal { color: #06c; }
+  .text { color: #666; }
+  .error { color: #c00; }
+</style>
+</head>
+<body>
+<h1>$TITLE</h1>
+<textarea id="input" rows="4" autofocus></textarea>
+<div id="result"></div>
+<script>
+var terminals = [
+$TERMINALS
+];
+var productions = [
+$PRODUCTIONS
+];
+var states = [
+$STATES
+];
+var dfa = $DFA;
+
+// Cuts `input` into tokens; returns them along with the offset of
+// the first place where no token matches, or -1.
+function tokenize(input) {
+  var tokens = [];
+  var offset = 0;
+  var skip = function () {
+    while (offset < input.length && /\s/.test(input[offset])) { offset++; }
+  };
+  skip();
+  while (offset < input.length) {
+    var token = dfa ? matchDfa(input, offset) : matchWord(input, offset);
+    if (!token) { return { tokens: tokens, rest: offset }; }
+    tokens.push(token);
+    offset = token.end;
+    skip();
+  }
+  return { tokens: tokens, rest: -1 };
+}
+
+// The longest match of the tokenizer at `offset`.
+function matchDfa(input, offset) {
+  var state = 0, longest = null, index = offset;
+  while (index < input.length) {
+    var code = input.codePointAt(index);
+    var next = dfa[state].other;
+    dfa[state].edges.forEach(function (edge) {
+      if (code >= edge[0] && code < edge[1]) { next = edge[2]; }
+    });
+    state = next;
+    index += code > 0xffff ? 2 : 1;
+    if (dfa[state].reject) { break; }
+    if (dfa[state].accepts >= 0) {
+      longest = { terminal: dfa[state].accepts, start: offset, end: index };
+    }
+  }
+  return longest && longest.end > offset ? longest : null;
+}
+
+// Without the built-in tokenizer, the input is a list of terminals.
+function matchWord(input, offset) {
+  var word = /^\S+/.exec(input.slice(offset))[0];
+  for (var i = 0; i < terminals.length; i++) {
+    if (terminals[i].name == word) {
+      return { terminal: i, start: offset, end: offset + word.length };
+    }
+  }
+  return null;
+}
+
+function lookaheadName(terminal) {
+  return terminal < 0 ? "end of input" : terminals[terminal].name;
+}
+
+// Runs the parser over `input`; returns the derivation or an error.
+function parse(input) {
+  var lexed = tokenize(input);
+  var tokens = lexed.tokens;
+  var stack = [0], trees = [], position = 0;
+  for (;;) {
+    var state = states[stack[stack.length - 1]];
+    if (position == tokens.length && lexed.rest >= 0) {
+      return { error: "no token matches the input", offset: lexed.rest };
+    }
+    var token = tokens[position];
+    var lookahead = token ? token.terminal : -1;
+    if (token && state.shifts.hasOwnProperty(lookahead)) {
+      stack.push(state.shifts[lookahead]);
+      trees.push({ terminal: lookahead, text: input.slice(token.start, token.end) });
+      position++;
+      continue;
+    }
+    var production = null;
+    state.reductions.forEach(function (reduction) {
+      if (production === null && reduction[0].indexOf(lookahead) >= 0) {
+        production = productions[reduction[1]];
+      }
+    });
+    if (production === null) {
+      var expected = Object.keys(state.shifts).map(Number);
+      state.reductions.forEach(function (reduction) {
+        expected = expected.concat(reduction[0]);
+      });
+      expected = expected.filter(function (t, i) { return expected.indexOf(t) == i; })
+                         .map(lookaheadName)
+                         .sort();
+      return {
+        error: "unexpected " + lookaheadName(lookahead) + "; expected one of " + expected.join(", "),
+        offset: token ? token.start : input.length
+      };
+    }
+    var start = trees.length - production.length;
+    var children = trees.splice(start, production.length);
+    stack.splice(start + 1, production.length);
+    var top = states[stack[stack.length - 1]];
+    // the reduction of the artificial start nonterminal is the one
+    // nothing goes to
+    if (!top.gotos.hasOwnProperty(production.nonterminal)) {
+      return { tree: children[0] };
+    }
+    stack.push(top.gotos[production.nonterminal]);
+    trees.push({ nonterminal: production.nonterminal, children: children });
+  }
+}
+
+function span(className, text) {
+  var element = document.createElement("span");
+  element.className = className;
+  element.textContent = text;
+  return element;
+}
+
+function treeElement(tree) {
+  var item = document.createElement("li");
+  if (tree.nonterminal !== undefined) {
+    item.appendChild(document.createTextNode(tree.nonterminal));
+    var list = document.createElement("ul");
+    tree.children.forEach(function (child) { list.appendChild(treeElement(child)); });
+    item.appendChild(list);
+  } else {
+    item.appendChild(span("terminal", terminals[tree.terminal].name));
+    if (!terminals[tree.terminal].quoted) {
+      item.appendChild(span("text", " `" + tree.text + "`"));
+    }
+  }
+  return item;
+}
+
+function update() {
+  var input = document.getElementById("input").value;
+  var result = document.getElementById("result");
+  var parsed = parse(input);
+  result.innerHTML = "";
+  if (parsed.error !== undefined) {
+    var before = input.slice(0, parsed.offset);
+    var line = before.split("\n").length;
+    var column = parsed.offset - before.lastIndexOf("\n");
+    result.appendChild(span("error", line + ":" + column + ": " + parsed.error));
+  } else {
+    var list = document.createElement("ul");
+    list.appendChild(treeElement(parsed.tree));
+    result.appendChild(list);
+  }
+}
+
+document.getElementById("input").addEventListener("input", update);
+update();
+</script>
+</body>
+</html>
+"#;
@@ -0,0 +1,72 @@
+use grammar::repr::*;
+use intern::intern;
+use session::Session;
+use test_util::normalized_grammar;
+
+use super::write_playground;
+
+fn page(grammar: &str, nonterminal: Option<&str>) -> String {
+    let grammar = normalized_grammar(grammar);
+    let mut out = vec![];
+    write_playground(&Session::test(),
+                     &mut out,
+                     &grammar,
+                     nonterminal.map(|nt| NonterminalString(intern(nt))))
+        .unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn built_in_tokenizer() {
+    let page = page(r#"
+grammar;
+    pub E: () = { E "+" T => (), T => () };
+    T: () = { Num => (), "(" E ")" => () };
+    Num: () = r"[0-9]+" => ();
+"#, None);
+    assert!(page.contains("<title>E playground</title>"));
+    assert!(page.contains(r#"{ name: "\"+\"", quoted: true }"#));
+    assert!(page.contains(r##"{ name: "r#\"[0-9]+\"#", quoted: false }"##));
+    assert!(page.contains(r#"{ nonterminal: "E", length: 3, text: "E = E \"+\" T" }"#));
+    assert!(!page.contains("var dfa = null;"));
+    assert!(!page.contains("$STATES"));
+}
+
+#[test]
+fn extern_tokens() {
+    let page = page(r#"
+grammar;
+extern {
+    enum Tok {
+        "Num" => Tok::Num,
+        "+" => Tok::Plus,
+    }
+}
+    pub E: () = { E "+" "Num" => (), "Num" => () };
+    pub F: () = "Num" => ();
+"#, Some("F"));
+    assert!(page.contains("<title>F playground</title>"));
+    assert!(page.contains("var dfa = null;"));
+}
+
+#[test]
+fn placeholder_in_terminal() {
+    // the text of the terminal is copied into the page as it is, not
+    // taken for the placeholder of the states
+    let page = page(r#"
+grammar;
+    pub E: () = "$STATES" => ();
+"#, None);
+    assert!(page.contains(r#"{ name: "\"$STATES\"", quoted: true }"#));
+}
+
+#[test]
+fn several_public_nonterminals() {
+    let grammar = normalized_grammar(r#"
+grammar;
+    pub E: () = "x" => ();
+    pub F: () = "y" => ();
+"#);
+    let err = write_playground(&Session::test(), &mut vec![], &grammar, None).unwrap_err();
+    assert!(err.to_string().contains("choose one with `--start`"));
+}
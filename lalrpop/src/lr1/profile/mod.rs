@@ -0,0 +1,91 @@
+//! Profile-guided layout of the parse tables. A parser generated with
+//! `emit_profile` counts how often it enters each state and runs each
+//! reduce action, and can write these counts to a profile like
+//!
+//! ```text
+//! Expr state 12 3401
+//! Expr reduce 3 1207
+//! ```
+//!
+//! Regenerating the parser with `use_profile` then numbers the states
+//! by how often they were entered, so that the rows of the hot states
+//! sit next to each other at the start of the tables, and puts the
+//! arms of the hot reduce actions first.
+//!
+//! The counts refer to the states of the build that recorded them; a
+//! profile that no longer matches the grammar makes for a worse
+//! layout, but never for a wrong parser.
+
+use collections::{map, Map};
+use lr1::core::*;
+
+#[cfg(test)]
+mod test;
+
+/// The counts recorded for the parser of one nonterminal.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Profile {
+    /// how often each state was entered
+    pub states: Map<usize, u64>,
+
+    /// how often each reduce action ran, by its action code (the
+    /// index of the production plus one)
+    pub reductions: Map<usize, u64>,
+}
+
+/// Reads the counts for the parser of `nonterminal` from the profile
+/// `text`, summing up repeated entries; comments and lines that are
+/// not understood are ignored.
+pub fn parse_profile(text: &str, nonterminal: &str) -> Profile {
+    let mut profile = Profile { states: map(), reductions: map() };
+    for line in text.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() != 4 || fields[0] != nonterminal {
+            continue;
+        }
+        let counts = match fields[1] {
+            "state" => &mut profile.states,
+            "reduce" => &mut profile.reductions,
+            _ => continue,
+        };
+        if let (Ok(index), Ok(count)) = (fields[2].parse::<usize>(), fields[3].parse::<u64>()) {
+            *counts.entry(index).or_insert(0) += count;
+        }
+    }
+    profile
+}
+
+/// Numbers the states by how often `profile` says they were entered,
+/// most often first. The start state stays state 0, as the parser
+/// begins there, and states that were entered equally often keep
+/// their relative order.
+pub fn reorder_states<'grammar>(states: Vec<LR1State<'grammar>>,
+                                profile: &Profile)
+                                -> Vec<LR1State<'grammar>> {
+    let mut order: Vec<usize> = (1..states.len()).collect();
+    let count = |index: &usize| profile.states.get(index).cloned().unwrap_or(0);
+    order.sort_by(|a, b| count(b).cmp(&count(a)));
+    order.insert(0, 0);
+
+    let mut renumbered = vec![StateIndex(0); states.len()];
+    for (new_index, &index) in order.iter().enumerate() {
+        renumbered[index] = StateIndex(new_index);
+    }
+    let renumber = |index: &mut StateIndex| *index = renumbered[index.0];
+
+    let mut states: Vec<Option<LR1State<'grammar>>> = states.into_iter().map(Some).collect();
+    order.iter()
+         .map(|&index| {
+             let mut state = states[index].take().unwrap();
+             renumber(&mut state.index);
+             for target in state.shifts.values_mut().chain(state.gotos.values_mut()) {
+                 renumber(target);
+             }
+             state
+         })
+         .collect()
+}
@@ -0,0 +1,64 @@
+use intern::intern;
+use grammar::repr::*;
+use lr1::build::build_lr1_states;
+use lr1::interpret::interpret;
+use lr1::tls::Lr1Tls;
+use session::Session;
+use test_util::normalized_grammar;
+
+use super::{parse_profile, reorder_states};
+
+fn nt(t: &str) -> NonterminalString {
+    NonterminalString(intern(t))
+}
+
+macro_rules! tokens {
+    ($($x:expr),*) => {
+        vec![$(TerminalString::quoted(intern($x))),*]
+    }
+}
+
+#[test]
+fn parse() {
+    let profile = parse_profile(r#"
+# recorded by hand
+Expr state 3 10
+Expr state 1 5
+Expr reduce 2 7
+Term state 3 100
+Expr state 3 1
+Expr state x 1
+Expr shift 3 1
+"#,
+                                "Expr");
+    assert_eq!(profile.states.into_iter().collect::<Vec<_>>(), vec![(1, 5), (3, 11)]);
+    assert_eq!(profile.reductions.into_iter().collect::<Vec<_>>(), vec![(2, 7)]);
+}
+
+#[test]
+fn reorder() {
+    let grammar = normalized_grammar(r#"
+grammar;
+    pub Expr: i32 = { <l:Expr> "+" <r:Atom> => l + r, Atom };
+    Atom: i32 = { "N" => 1, "(" <Expr> ")" };
+"#);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let start = grammar.start_nonterminals[&nt("Expr")];
+    let states = build_lr1_states(&Session::test(), &grammar, start).unwrap();
+    let num_states = states.len();
+    let hot = num_states - 1;
+    let hot_items = states[hot].items.clone();
+    let profile = parse_profile(&format!("Expr state {} 50\nExpr state 0 100\n", hot), "Expr");
+    let states = reorder_states(states, &profile);
+
+    assert_eq!(states.len(), num_states);
+    assert_eq!(states[1].items, hot_items);
+    for (index, state) in states.iter().enumerate() {
+        assert_eq!(state.index.0, index);
+    }
+
+    assert!(interpret(&states, tokens!["N"]).is_ok());
+    assert!(interpret(&states, tokens!["N", "+", "(", "N", "+", "N", ")"]).is_ok());
+    assert!(interpret(&states, tokens!["N", "+"]).is_err());
+    assert!(interpret(&states, tokens!["(", "N"]).is_err());
+}
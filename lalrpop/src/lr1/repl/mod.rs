@@ -0,0 +1,98 @@
+//! A read-eval-print loop for trying out a grammar (`lalrpop repl`):
+//! we build the parse table in memory and run it over each line that
+//! is read, printing the derivation of the line or the point where it
+//! was rejected. No code is generated, so a grammar can be changed and
+//! tried again without going through cargo.
+//!
+//! Lines are cut into tokens as for `lalrpop animate`: with the
+//! built-in tokenizer, if the grammar has one, or else as a list of
+//! terminals separated by whitespace.
+
+use grammar::repr::*;
+use lr1::animate::{build_parser, choose_nonterminal, parse_steps, tokenize, StepKind};
+use lr1::tls::Lr1Tls;
+use session::Session;
+use std::io::{self, BufRead, Write};
+
+#[cfg(test)]
+mod test;
+
+/// A derivation, as built from the steps of the parser.
+enum Tree {
+    Nonterminal(NonterminalString, Vec<Tree>),
+
+    /// A terminal and the text it matched.
+    Terminal(TerminalString, String),
+}
+
+/// Reads lines from `input` and writes the derivation of each one
+/// (as `nonterminal`) to `out`, until `input` runs out. If
+/// `nonterminal` is not given, the grammar must have just one public
+/// nonterminal.
+pub fn repl<R: BufRead, W: Write>(session: &Session,
+                                  mut input: R,
+                                  out: &mut W,
+                                  grammar: &Grammar,
+                                  nonterminal: Option<NonterminalString>)
+                                  -> io::Result<()> {
+    let nonterminal = try!(choose_nonterminal(grammar, nonterminal));
+
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let states = try!(build_parser(session, grammar, nonterminal));
+
+    try!(writeln!(out, "Parsing lines as `{}`; end the input to quit.", nonterminal));
+    loop {
+        try!(write!(out, "> "));
+        try!(out.flush());
+        let mut line = String::new();
+        if try!(input.read_line(&mut line)) == 0 {
+            return Ok(());
+        }
+
+        let tokens = tokenize(grammar, line.trim());
+        let steps = parse_steps(&states, &tokens);
+
+        // replay the shifts and reductions to build the derivation
+        let mut stack = vec![];
+        let mut texts = tokens.tokens.iter().map(|&(_, ref text)| text.clone());
+        for step in steps {
+            match step.kind {
+                StepKind::Shift(terminal) => {
+                    stack.push(Tree::Terminal(terminal, texts.next().unwrap()));
+                }
+                StepKind::Reduce(production) => {
+                    let len = stack.len() - production.symbols.len();
+                    let children = stack.split_off(len);
+                    stack.push(Tree::Nonterminal(production.nonterminal, children));
+                }
+                StepKind::Accept => {
+                    try!(write_tree(out, &stack.pop().unwrap(), 0));
+                }
+                StepKind::Error => {
+                    try!(writeln!(out, "{}", step.action));
+                }
+            }
+        }
+    }
+}
+
+fn write_tree<W: Write>(out: &mut W, tree: &Tree, depth: usize) -> io::Result<()> {
+    match *tree {
+        Tree::Nonterminal(nonterminal, ref children) => {
+            try!(writeln!(out, "{:2$}{}", "", nonterminal, depth * 2));
+            for child in children {
+                try!(write_tree(out, child, depth + 1));
+            }
+            Ok(())
+        }
+        Tree::Terminal(terminal, ref text) => {
+            // for terminals like `"+"`, the text adds nothing
+            match terminal.as_literal() {
+                Some(TerminalLiteral::Quoted(s)) if s.to_string() == *text => {
+                    writeln!(out, "{:2$}{}", "", terminal, depth * 2)
+                }
+                _ => writeln!(out, "{:3$}{} `{}`", "", terminal, text, depth * 2),
+            }
+        }
+    }
+}
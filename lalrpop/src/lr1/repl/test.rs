@@ -0,0 +1,63 @@
+use grammar::repr::*;
+use intern::intern;
+use session::Session;
+use test_util::normalized_grammar;
+
+use super::repl;
+
+fn run(grammar: &str, nonterminal: Option<&str>, input: &str) -> String {
+    let grammar = normalized_grammar(grammar);
+    let mut out = vec![];
+    repl(&Session::test(),
+         input.as_bytes(),
+         &mut out,
+         &grammar,
+         nonterminal.map(|nt| NonterminalString(intern(nt))))
+        .unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn derivations() {
+    let out = run(r#"
+grammar;
+    pub E: () = { E "+" T => (), T => () };
+    T: () = { Num => (), "(" E ")" => () };
+    Num: () = r"[0-9]+" => ();
+"#, None, "1 + (22)\n1 +\n1 ? 2\n");
+    assert_eq!(out, r##"Parsing lines as `E`; end the input to quit.
+> E
+  E
+    T
+      Num
+        r#"[0-9]+"# `1`
+  "+"
+  T
+    "("
+    E
+      T
+        Num
+          r#"[0-9]+"# `22`
+    ")"
+> error: unexpected EOF; expected one of "(", r#"[0-9]+"#
+> error: no token matches the input at `? 2`
+> "##);
+}
+
+#[test]
+fn choose_nonterminal() {
+    let grammar = r#"
+grammar;
+    pub A: () = "a" => ();
+    pub B: () = "b" => ();
+"#;
+    assert_eq!(run(grammar, Some("B"), "b\n"),
+               "Parsing lines as `B`; end the input to quit.\n> B\n  \"b\"\n> ");
+
+    let grammar = normalized_grammar(grammar);
+    let mut out = vec![];
+    let err = repl(&Session::test(), "b\n".as_bytes(), &mut out, &grammar, None).unwrap_err();
+    assert_eq!(err.to_string(),
+               "the grammar has several public nonterminals (`A`, `B`); \
+                choose one with `--start`");
+}
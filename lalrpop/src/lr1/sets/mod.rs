@@ -0,0 +1,58 @@
+//! Writes the FIRST, FOLLOW and nullable information for each
+//! nonterminal of the expanded grammar, to help explain where the
+//! lookahead in a conflict comes from.
+
+use grammar::consts::INLINE;
+use grammar::repr::*;
+use intern::intern;
+use lr1::first::FirstSets;
+use lr1::follow::FollowSets;
+use lr1::lookahead::TokenSet;
+use std::io::{self, Write};
+
+#[cfg(test)]
+mod test;
+
+pub fn generate_sets<W: Write>(out: &mut W, grammar: &Grammar) -> io::Result<()> {
+    let first_sets = FirstSets::new(grammar);
+    let follow_sets = FollowSets::new(grammar, &first_sets);
+
+    try!(writeln!(out, "Lalrpop FIRST/FOLLOW Sets"));
+    try!(writeln!(out, "========================="));
+
+    for nt in reported_nonterminals(grammar) {
+        let mut first = first_sets.first0(&[Symbol::Nonterminal(nt)]);
+        let nullable = first.take_eof();
+
+        try!(writeln!(out, ""));
+        try!(writeln!(out, "{}", nt));
+        try!(writeln!(out, "    nullable: {}", if nullable { "yes" } else { "no" }));
+        try!(write_set(out, "FIRST", &first));
+        try!(write_set(out, "FOLLOW", &follow_sets.follow(nt)));
+    }
+
+    Ok(())
+}
+
+/// The nonterminals of the expanded grammar whose sets are worth
+/// reporting: all but the artificial `__Foo = Foo` start nonterminals
+/// and the nonterminals that have been inlined into their uses.
+pub fn reported_nonterminals(grammar: &Grammar) -> Vec<NonterminalString> {
+    let inline = intern(INLINE);
+    grammar.nonterminals
+           .iter()
+           .filter(|&(nt, data)| {
+               !grammar.start_nonterminals.values().any(|s| s == nt) &&
+                   !data.annotations.iter().any(|a| a.id == inline)
+           })
+           .map(|(&nt, _)| nt)
+           .collect()
+}
+
+fn write_set<W: Write>(out: &mut W, label: &str, set: &TokenSet) -> io::Result<()> {
+    try!(write!(out, "    {}:", label));
+    for token in set.iter() {
+        try!(write!(out, " {}", token));
+    }
+    writeln!(out, "")
+}
@@ -0,0 +1,28 @@
+use lr1::tls::Lr1Tls;
+use test_util::normalized_grammar;
+use super::generate_sets;
+
+#[test]
+fn expr() {
+    let grammar = normalized_grammar(r#"
+grammar;
+    pub E: () = { E "+" T => (), T => () };
+    T: () = { "(" E ")" => (), "x" => (), () => () };
+"#);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let mut out = vec![];
+    generate_sets(&mut out, &grammar).unwrap();
+    let out = String::from_utf8(out).unwrap();
+    assert_eq!(out.trim(), r#"Lalrpop FIRST/FOLLOW Sets
+=========================
+
+E
+    nullable: yes
+    FIRST: "(" "+" "x"
+    FOLLOW: ")" "+" EOF
+
+T
+    nullable: yes
+    FIRST: "(" "x"
+    FOLLOW: ")" "+" EOF"#);
+}
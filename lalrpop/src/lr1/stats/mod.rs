@@ -0,0 +1,244 @@
+//! Writes a summary of how big and how tangled a grammar is (`--emit
+//! stats`): how many nonterminals, terminals and productions the
+//! expanded grammar has, how deeply its nonterminals nest, and, for
+//! each public nonterminal, how many states and conflicts its parser
+//! has, roughly how much memory building its states took, and roughly
+//! how much code it takes. The numbers are meant to be
+//! compared from one version of a grammar to the next, so that a
+//! change that makes the parser much bigger does not go unnoticed.
+
+use collections::{map, Map};
+use grammar::repr::*;
+use lr1::build_states;
+use lr1::codegen;
+use lr1::error::distinct_token_conflicts;
+use lr1::memory;
+use lr1::sets::reported_nonterminals;
+use lr1::unit_rules::eliminate_unit_reductions;
+use petgraph::Graph;
+use petgraph::algo::tarjan_scc;
+use rust::RustWrite;
+use session::Session;
+use std::io::{self, Write};
+
+#[cfg(test)]
+mod test;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GrammarStats {
+    /// These count the expanded grammar, leaving out the artificial
+    /// start nonterminals and the nonterminals that were inlined.
+    pub nonterminals: usize,
+    pub terminals: usize,
+    pub productions: usize,
+
+    /// The production with the most symbols, if there is one.
+    pub longest_production: Option<(String, usize)>,
+
+    /// The nonterminals that can (directly or not) refer to
+    /// themselves.
+    pub recursive_nonterminals: usize,
+
+    /// The length of the longest chain of nonterminals that refer to
+    /// one another, where each group of mutually recursive
+    /// nonterminals counts once.
+    pub depth: usize,
+
+    pub parsers: Vec<ParserStats>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParserStats {
+    pub nonterminal: NonterminalString,
+
+    /// `None` for Earley parsers, which have no states.
+    pub states: Option<usize>,
+
+    /// The conflicts, one per state and lookahead token.
+    pub conflicts: usize,
+
+    /// The lines and bytes of Rust generated for the parser, not
+    /// counting the action code and tokenizer it shares with the other
+    /// parsers; `None` if there are conflicts.
+    pub code_size: Option<(usize, usize)>,
+
+    /// The peak memory, in bytes, of each pass that built the states
+    /// (see `lr1::memory`); empty for Earley parsers.
+    pub memory: Vec<(String, usize)>,
+}
+
+pub fn generate_stats<W: Write>(session: &Session,
+                                out: &mut W,
+                                grammar: &Grammar)
+                                -> io::Result<()> {
+    let stats = try!(grammar_stats(session, grammar));
+
+    try!(writeln!(out, "Lalrpop Grammar Statistics"));
+    try!(writeln!(out, "=========================="));
+    try!(writeln!(out, ""));
+    try!(writeln!(out, "nonterminals: {}", stats.nonterminals));
+    try!(writeln!(out, "terminals: {}", stats.terminals));
+    try!(writeln!(out, "productions: {}", stats.productions));
+    match stats.longest_production {
+        Some((ref production, len)) => {
+            try!(writeln!(out, "longest production: {} symbols, `{}`", len, production))
+        }
+        None => try!(writeln!(out, "longest production: none")),
+    }
+    try!(writeln!(out, "recursive nonterminals: {}", stats.recursive_nonterminals));
+    try!(writeln!(out, "nesting depth: {}", stats.depth));
+
+    for parser in &stats.parsers {
+        try!(writeln!(out, ""));
+        try!(writeln!(out, "{}", parser.nonterminal));
+        match parser.states {
+            Some(states) => try!(writeln!(out, "    states: {}", states)),
+            None => try!(writeln!(out, "    states: none (Earley parser)")),
+        }
+        try!(writeln!(out, "    conflicts: {}", parser.conflicts));
+        for &(ref pass, bytes) in &parser.memory {
+            try!(writeln!(out, "    peak memory ({}): {} bytes", pass, bytes));
+        }
+        match parser.code_size {
+            Some((lines, bytes)) => {
+                try!(writeln!(out, "    generated code: {} lines, {} bytes", lines, bytes))
+            }
+            None => try!(writeln!(out, "    generated code: none (the grammar has conflicts)")),
+        }
+    }
+
+    Ok(())
+}
+
+/// Gathers the statistics of `grammar`. The `Lr1Tls` must be
+/// installed.
+pub fn grammar_stats(session: &Session, grammar: &Grammar) -> io::Result<GrammarStats> {
+    let nonterminals = reported_nonterminals(grammar);
+
+    let mut productions = 0;
+    let mut longest_production: Option<(String, usize)> = None;
+    for nt in &nonterminals {
+        for production in grammar.productions_for(*nt) {
+            productions += 1;
+            let len = production.symbols.len();
+            if longest_production.as_ref().map(|&(_, l)| len > l).unwrap_or(true) {
+                longest_production = Some((production.to_string(), len));
+            }
+        }
+    }
+
+    let (recursive_nonterminals, depth) = nesting(grammar, &nonterminals);
+
+    let mut parsers = vec![];
+    for (&user_nt, &start_nt) in &grammar.start_nonterminals {
+        parsers.push(try!(parser_stats(session, grammar, user_nt, start_nt)));
+    }
+
+    Ok(GrammarStats {
+        nonterminals: nonterminals.len(),
+        terminals: grammar.terminals.all.iter().filter(|&&t| t != TerminalString::Error).count(),
+        productions: productions,
+        longest_production: longest_production,
+        recursive_nonterminals: recursive_nonterminals,
+        depth: depth,
+        parsers: parsers,
+    })
+}
+
+/// The number of recursive nonterminals and the nesting depth, found
+/// from the strongly connected components of the graph in which each
+/// nonterminal points at the nonterminals its productions refer to.
+fn nesting(grammar: &Grammar, nonterminals: &[NonterminalString]) -> (usize, usize) {
+    let mut graph = Graph::new();
+    let nodes: Map<NonterminalString, _> =
+        nonterminals.iter().map(|&nt| (nt, graph.add_node(nt))).collect();
+    for (&nt, &node) in &nodes {
+        for production in grammar.productions_for(nt) {
+            for symbol in &production.symbols {
+                if let Symbol::Nonterminal(referenced) = *symbol {
+                    if let Some(&target) = nodes.get(&referenced) {
+                        graph.update_edge(node, target, ());
+                    }
+                }
+            }
+        }
+    }
+
+    // the components come out with the ones that are referred to
+    // before the ones that refer to them
+    let mut recursive = 0;
+    let mut depths = map();
+    let mut max_depth = 0;
+    for component in tarjan_scc(&graph) {
+        if component.len() > 1 || graph.contains_edge(component[0], component[0]) {
+            recursive += component.len();
+        }
+        let depth = 1 +
+                    component.iter()
+                             .flat_map(|&node| graph.neighbors(node))
+                             .filter(|target| !component.contains(target))
+                             .map(|target| depths[&target])
+                             .max()
+                             .unwrap_or(0);
+        for &node in &component {
+            depths.insert(node, depth);
+        }
+        max_depth = ::std::cmp::max(max_depth, depth);
+    }
+
+    (recursive, max_depth)
+}
+
+fn parser_stats(session: &Session,
+                grammar: &Grammar,
+                user_nt: NonterminalString,
+                start_nt: NonterminalString)
+                -> io::Result<ParserStats> {
+    let mut rust = RustWrite::new(vec![]);
+    let mut passes = vec![];
+
+    let states = if grammar.algorithm.codegen == LrCodeGeneration::Earley {
+        try!(codegen::earley::compile(session, grammar, user_nt, start_nt, "super", &mut rust));
+        None
+    } else {
+        let (result, memory) = memory::record(|| build_states(session, grammar, start_nt));
+        passes = memory;
+        match result {
+            Ok(states) => {
+                let num_states = states.len();
+                if grammar.algorithm.codegen == LrCodeGeneration::RecursiveAscent {
+                    try!(codegen::ascent::compile(session, grammar, user_nt, start_nt, &states,
+                                                  "super", &mut rust));
+                } else {
+                    let states = if session.eliminate_unit_rules {
+                        eliminate_unit_reductions(grammar, states)
+                    } else {
+                        states
+                    };
+                    try!(codegen::parse_table::compile(session, grammar, user_nt, start_nt, &states,
+                                                       "super", &mut rust));
+                }
+                Some(num_states)
+            }
+            Err(error) => {
+                return Ok(ParserStats {
+                    nonterminal: user_nt,
+                    states: Some(error.states.len()),
+                    conflicts: distinct_token_conflicts(&error).len(),
+                    code_size: None,
+                    memory: passes,
+                });
+            }
+        }
+    };
+
+    let code = rust.into_inner();
+    let lines = code.iter().filter(|&&b| b == b'\n').count();
+    Ok(ParserStats {
+        nonterminal: user_nt,
+        states: states,
+        conflicts: 0,
+        code_size: Some((lines, code.len())),
+        memory: passes,
+    })
+}
@@ -0,0 +1,55 @@
+use intern::intern;
+use grammar::repr::*;
+use lr1::tls::Lr1Tls;
+use session::Session;
+use test_util::normalized_grammar;
+use super::grammar_stats;
+
+fn nt(t: &str) -> NonterminalString {
+    NonterminalString(intern(t))
+}
+
+#[test]
+fn expr() {
+    let grammar = normalized_grammar(r#"
+grammar;
+    pub E: () = { E "+" T => (), T => () };
+    T: () = { "(" E ")" => (), Num => () };
+    Num: () = "x" => ();
+"#);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let stats = grammar_stats(&Session::test(), &grammar).unwrap();
+    assert_eq!(stats.nonterminals, 3);
+    assert_eq!(stats.terminals, 4);
+    assert_eq!(stats.productions, 5);
+    assert_eq!(stats.longest_production, Some((String::from(r#"E = E "+" T"#), 3)));
+
+    // `E` and `T` refer to each other, and then to `Num`
+    assert_eq!(stats.recursive_nonterminals, 2);
+    assert_eq!(stats.depth, 2);
+
+    assert_eq!(stats.parsers.len(), 1);
+    let parser = &stats.parsers[0];
+    assert_eq!(parser.nonterminal, nt("E"));
+    assert!(parser.states.unwrap() > 0);
+    assert_eq!(parser.conflicts, 0);
+    assert!(parser.code_size.unwrap().0 > 0);
+    assert!(!parser.memory.is_empty());
+    assert!(parser.memory.iter().all(|&(_, bytes)| bytes > 0));
+}
+
+#[test]
+fn conflicts() {
+    let grammar = normalized_grammar(r#"
+grammar;
+    pub E: () = { E "+" E => (), "x" => () };
+"#);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let stats = grammar_stats(&Session::test(), &grammar).unwrap();
+    assert_eq!(stats.recursive_nonterminals, 1);
+    assert_eq!(stats.depth, 1);
+
+    let parser = &stats.parsers[0];
+    assert_eq!(parser.conflicts, 1);
+    assert_eq!(parser.code_size, None);
+}
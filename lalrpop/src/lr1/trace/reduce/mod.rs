@@ -49,6 +49,14 @@ impl<'trace, 'grammar> Tracer<'trace, 'grammar> {
         }
     }
 
+    /// True if `item` (in `state`) is the item `__S = (*) S` that
+    /// starts the parse. Any other item `Z = (*) ...` is only in a
+    /// state because some item there can shift a `Z`, and so there is
+    /// a goto on `Z`.
+    fn is_start_item(&self, state: StateIndex, item: &LR1Item<'grammar>) -> bool {
+        item.index == 0 && !self.states[state.0].gotos.contains_key(&item.production.nonterminal)
+    }
+
     // We know that we can reduce the nonterminal `Y`. We want to find
     // at least one element of context, so we search back to find out
     // who will consume that reduced value. So search for those items
@@ -84,7 +92,20 @@ impl<'trace, 'grammar> Tracer<'trace, 'grammar> {
             let first_suffix = self.first_sets.first0(symbol_sets.suffix);
             let continue_tracing = first_suffix.contains_eof();
 
-            if !continue_tracing {
+            if self.is_start_item(item_state, pred_item) {
+                // Found the start item:
+                //
+                //    [__S = (*) Y]
+                //
+                // Nothing consumes `__S`, so this is as far as we can
+                // go; the context is the end of the input. Add an
+                // edge
+                //
+                //    [__S = (*) Y] -(,Y,)-> [Y]
+                //
+                // and stop.
+                self.trace_graph.add_accepting_edge(pred_item.to_lr0(), nonterminal, symbol_sets);
+            } else if !continue_tracing {
                 // Add an edge
                 //
                 //    [Z = ...p (*) Y ...s] -(...p,Y,...s)-> [Y]
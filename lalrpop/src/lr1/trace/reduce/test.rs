@@ -6,8 +6,8 @@ use lr1::first::FirstSets;
 use lr1::interpret::interpret_partial;
 use lr1::lookahead::{Token, TokenSet};
 use lr1::tls::Lr1Tls;
+use session::Session;
 use test_util::{expect_debug, normalized_grammar};
-use tls::Tls;
 
 use super::super::Tracer;
 
@@ -50,11 +50,10 @@ fn test_grammar1() -> Grammar {
 
 #[test]
 fn backtrace1() {
-    let _tls = Tls::test();
     let grammar = test_grammar1();
     let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
     let first_sets = FirstSets::new(&grammar);
-    let states = build_states(&grammar, nt("Start")).unwrap();
+    let states = build_states(&Session::test(), &grammar, nt("Start")).unwrap();
     let tracer = Tracer::new(&first_sets, &states);
     let state_stack = interpret_partial(&states, terms!["Int"]).unwrap();
     let top_state = *state_stack.last().unwrap();
@@ -119,7 +118,6 @@ fn backtrace1() {
 
 #[test]
 fn backtrace2() {
-    let _tls = Tls::test();
     // This grammar yields a S/R conflict. Is it (int -> int) -> int
     // or int -> (int -> int)?
     let grammar = normalized_grammar(r#"
@@ -132,7 +130,7 @@ pub Ty: () = {
 "#);
     let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
     let first_sets = FirstSets::new(&grammar);
-    let err = build_states(&grammar, nt("Ty")).unwrap_err();
+    let err = build_states(&Session::test(), &grammar, nt("Ty")).unwrap_err();
     let tracer = Tracer::new(&first_sets, &err.states);
     let conflict = err.conflicts[0].clone();
     println!("conflict={:?}", conflict);
@@ -171,7 +169,6 @@ pub Ty: () = {
 fn reduce_backtrace_3_graph() {
     // This grammar yields a S/R conflict. Is it `(int -> int) -> int`
     // or `int -> (int -> int)`?
-    let _tls = Tls::test();
     let grammar = normalized_grammar(r#"
 grammar;
 pub Ty: () = {
@@ -182,7 +179,7 @@ pub Ty: () = {
 "#);
     let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
     let first_sets = FirstSets::new(&grammar);
-    let err = build_states(&grammar, nt("Ty")).unwrap_err();
+    let err = build_states(&Session::test(), &grammar, nt("Ty")).unwrap_err();
     let conflict = err.conflicts[0].clone();
     println!("conflict={:?}", conflict);
     let item = Item { production: conflict.production,
@@ -217,7 +214,6 @@ pub Ty: () = {
 
 #[test]
 fn backtrace_filter() {
-    let _tls = Tls::test();
     let grammar = normalized_grammar(r#"
     grammar;
 
@@ -246,7 +242,7 @@ fn backtrace_filter() {
     };
 "#);
     let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
-    let states = build_states(&grammar, nt("Start")).unwrap();
+    let states = build_states(&Session::test(), &grammar, nt("Start")).unwrap();
     let first_sets = FirstSets::new(&grammar);
     let tracer = Tracer::new(&first_sets, &states);
     let state_stack = interpret_partial(&states, terms!["Int"]).unwrap();
@@ -4,8 +4,8 @@ use lr1::build_states;
 use lr1::core::*;
 use lr1::first::FirstSets;
 use lr1::tls::Lr1Tls;
+use session::Session;
 use test_util::{expect_debug, normalized_grammar};
-use tls::Tls;
 
 use super::super::Tracer;
 
@@ -18,7 +18,6 @@ fn shift_backtrace_1() {
     // This grammar yields a S/R conflict. Is it `(int -> int) -> int`
     // or `int -> (int -> int)`?
 
-    let _tls = Tls::test();
     let grammar = normalized_grammar(r#"
 grammar;
 pub Ty: () = {
@@ -29,7 +28,7 @@ pub Ty: () = {
 "#);
     let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
     let first_sets = FirstSets::new(&grammar);
-    let err = build_states(&grammar, nt("Ty")).unwrap_err();
+    let err = build_states(&Session::test(), &grammar, nt("Ty")).unwrap_err();
     let conflict = err.conflicts[0].clone();
     println!("conflict={:?}", conflict);
 
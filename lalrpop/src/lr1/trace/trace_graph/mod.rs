@@ -1,4 +1,4 @@
-use collections::{Map, map};
+use collections::{Map, map, Set, set};
 use lr1::core::*;
 use lr1::first::*;
 use lr1::lookahead::*;
@@ -51,6 +51,10 @@ pub struct TraceGraph<'grammar> {
 
     graph: Graph<TraceGraphNode<'grammar>, SymbolSets<'grammar>>,
     indices: Map<TraceGraphNode<'grammar>, NodeIndex>,
+
+    // Start items `__S = (*) S` that end a reduce trace because `S`
+    // is followed by the end of the input (see `add_accepting_edge`).
+    accepting: Set<NodeIndex>,
 }
 
 #[derive(Copy, Clone, Debug, PartialOrd, Ord, PartialEq, Eq)]
@@ -64,6 +68,7 @@ impl<'grammar> TraceGraph<'grammar> {
         TraceGraph {
             graph: Graph::new(),
             indices: map(),
+            accepting: set(),
         }
     }
 
@@ -92,6 +97,23 @@ impl<'grammar> TraceGraph<'grammar> {
         }
     }
 
+    /// Adds an edge from the start item `__S = (*) S` to `to`. When
+    /// tracing a reduce, we normally stop at an item that provides a
+    /// token of context; but after a chain of reductions all the way
+    /// up to `S`, the context is the end of the input, so we stop at
+    /// the start item instead. As it is not a reduction the user
+    /// wrote, examples leave it out.
+    pub fn add_accepting_edge<T>(&mut self,
+                                 start_item: LR0Item<'grammar>,
+                                 to: T,
+                                 labels: SymbolSets<'grammar>)
+        where T: Into<TraceGraphNode<'grammar>>
+    {
+        let from = self.add_node(start_item);
+        self.accepting.insert(from);
+        self.add_edge(start_item, to, labels);
+    }
+
     pub fn lr0_examples<'graph>(&'graph self,
                                 lr0_item: LR0Item<'grammar>)
                                 -> PathEnumerator<'graph, 'grammar>
@@ -368,7 +390,7 @@ impl<'graph, 'grammar> PathEnumerator<'graph, 'grammar> {
             self.stack[1..]
                 .iter()
                 .rev()
-                .map(|state| {
+                .filter_map(|state| {
                     let nonterminal = match self.graph.graph[state.index] {
                         TraceGraphNode::Nonterminal(nonterminal) => nonterminal,
                         TraceGraphNode::Item(item) => item.production.nonterminal,
@@ -380,7 +402,11 @@ impl<'graph, 'grammar> PathEnumerator<'graph, 'grammar> {
                     };
                     cursors.0 += state.symbol_sets.prefix.len();
                     cursors.1 -= state.symbol_sets.suffix.len();
-                    reduction
+                    if self.graph.accepting.contains(&state.index) {
+                        None
+                    } else {
+                        Some(reduction)
+                    }
                 })
                 .collect();
         reductions.reverse();
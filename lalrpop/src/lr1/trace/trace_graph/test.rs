@@ -2,7 +2,6 @@ use intern::intern;
 use grammar::repr::*;
 use lr1::core::*;
 use test_util::expect_debug;
-use tls::Tls;
 
 macro_rules! nt {
     ($x:ident) => {
@@ -22,7 +21,9 @@ macro_rules! production {
             nonterminal: nt!($x),
             symbols: syms![$($y),*],
             action: ActionFn::new(0),
-            span: Span(0, 0)
+            span: Span(0, 0),
+            dprec: 0,
+            merge: None
         }
     }
 }
@@ -31,7 +32,6 @@ use super::TraceGraph;
 
 #[test]
 fn enumerator() {
-    let _tls = Tls::test();
 
     // Build this graph:
     //
@@ -91,7 +91,6 @@ fn enumerator() {
 
 #[test]
 fn enumerator1() {
-    let _tls = Tls::test();
 
     // Build this graph:
     //
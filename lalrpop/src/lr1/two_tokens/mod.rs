@@ -0,0 +1,244 @@
+//! Resolution of LR(1) conflicts with a second token of lookahead,
+//! for nonterminals marked `#[lookahead = "2"]`.
+//!
+//! Take a grammar of rules like yacc's, without `;` between them:
+//!
+//!     Rules = Rule*;
+//!     Rule = Id ":" Symbols;
+//!     Symbols = Symbols Id | ();
+//!
+//! Having read `a: b` and seeing an `Id` next, the parser cannot tell
+//! whether it is one more symbol of the rule for `a`, or the start of
+//! the next rule; but the token after it would tell it, as only the
+//! start of a rule is followed by `:`. Rather than building LR(2)
+//! states, which multiplies the number of states by the number of
+//! pairs of tokens, we build the canonical LR(1) states, and for each
+//! conflict on a token `t` in parsing a marked nonterminal, work out
+//! which tokens may come after `t` if the parser takes each of the
+//! conflicting actions. If those sets do not overlap, the conflict is
+//! resolved by a `TwoTokenReduction` for each reduction, which the
+//! parser consults by peeking at the token after `t`.
+//!
+//! To find the tokens that may follow `t` after a reduction, we walk
+//! back from the state over the symbols of the production, to the
+//! states the parser may return to, follow their gotos, and look at
+//! what those do on `t`: shift it (and then the tokens the resulting
+//! state has actions for may follow) or reduce again. This considers
+//! every path back, and so may find more tokens than can actually
+//! follow, which only makes us give up on some conflicts we could
+//! have resolved.
+
+use collections::{set, Set};
+use grammar::repr::*;
+use lr1::core::*;
+use lr1::lookahead::{Lookahead, Token, TokenSet};
+
+#[cfg(test)]
+mod test;
+
+/// Resolves the conflicts of `error`, whose states should be the
+/// canonical LR(1) states (so that their lookaheads are exact), with
+/// two tokens of lookahead, where the nonterminals reduced allow it.
+/// Returns the states if that resolves every conflict, and otherwise
+/// the conflicts that remain.
+pub fn resolve_with_two_tokens<'grammar>(grammar: &'grammar Grammar,
+                                         error: LR1TableConstructionError<'grammar>)
+                                         -> LR1Result<'grammar> {
+    let mut states = error.states;
+
+    // (all decided on the states as built, before any are changed)
+    let mut resolutions = vec![];
+    {
+        let analysis = Analysis::new(grammar, &states);
+        let mut conflicting: Vec<StateIndex> = error.conflicts.iter().map(|c| c.state).collect();
+        conflicting.sort();
+        conflicting.dedup();
+        for index in conflicting {
+            let state = &states[index.0];
+            for terminal in conflicting_terminals(state) {
+                if let Some(reductions) = analysis.resolve(state, terminal) {
+                    resolutions.push((index, terminal, reductions));
+                }
+            }
+        }
+    }
+
+    for (index, terminal, reductions) in resolutions {
+        let state = &mut states[index.0];
+        for &mut (ref mut lookahead, _) in &mut state.reductions {
+            if lookahead.contains(Token::Terminal(terminal)) {
+                let mut remaining = TokenSet::new();
+                for token in lookahead.iter().filter(|&t| t != Token::Terminal(terminal)) {
+                    remaining.insert(token);
+                }
+                *lookahead = remaining;
+            }
+        }
+        state.two_token_reductions.extend(reductions);
+    }
+
+    let conflicts: Vec<_> = states.iter().flat_map(|state| TokenSet::conflicts(state)).collect();
+    if conflicts.is_empty() {
+        Ok(states)
+    } else {
+        Err(TableConstructionError { states: states, conflicts: conflicts })
+    }
+}
+
+/// The terminals on which `state` has more than one action.
+fn conflicting_terminals(state: &LR1State) -> Vec<TerminalString> {
+    let mut terminals: Vec<TerminalString> = vec![];
+    let mut seen = TokenSet::new();
+    for &(ref lookahead, _) in &state.reductions {
+        for token in lookahead.iter() {
+            if let Token::Terminal(terminal) = token {
+                if (state.shifts.contains_key(&terminal) || !seen.insert(token)) &&
+                   !terminals.contains(&terminal) {
+                    terminals.push(terminal);
+                }
+            }
+        }
+    }
+    terminals
+}
+
+struct Analysis<'s, 'grammar: 's> {
+    grammar: &'grammar Grammar,
+    states: &'s [LR1State<'grammar>],
+
+    /// for each state, the states with a transition to it, and the
+    /// symbol it is on
+    predecessors: Vec<Vec<(Symbol, StateIndex)>>,
+}
+
+impl<'s, 'grammar> Analysis<'s, 'grammar> {
+    fn new(grammar: &'grammar Grammar, states: &'s [LR1State<'grammar>]) -> Self {
+        let mut predecessors = vec![vec![]; states.len()];
+        for state in states {
+            for (&terminal, &target) in &state.shifts {
+                predecessors[target.0].push((Symbol::Terminal(terminal), state.index));
+            }
+            for (&nonterminal, &target) in &state.gotos {
+                predecessors[target.0].push((Symbol::Nonterminal(nonterminal), state.index));
+            }
+        }
+        Analysis { grammar: grammar, states: states, predecessors: predecessors }
+    }
+
+    fn is_marked(&self, nonterminal: NonterminalString) -> bool {
+        self.grammar.nonterminals[&nonterminal].two_token_lookahead()
+    }
+
+    /// The two-token reductions that resolve the conflict of `state` on
+    /// `terminal`, if it is a conflict in parsing a marked nonterminal
+    /// and the tokens that may follow `terminal` are different for each
+    /// action. The conflict is in parsing a marked nonterminal if
+    /// shifting `terminal` continues one of its productions, or if one
+    /// of the reductions, or those that it leads to on `terminal`,
+    /// reduces it (so that marking `Rule` above does for the reduction
+    /// of `Symbols`, too).
+    fn resolve(&self,
+               state: &LR1State<'grammar>,
+               terminal: TerminalString)
+               -> Option<Vec<TwoTokenReduction<'grammar>>> {
+        let mut marked = state.items
+                              .vec
+                              .iter()
+                              .any(|item| {
+                                  item.can_shift_terminal(terminal) &&
+                                  self.is_marked(item.production.nonterminal)
+                              });
+        let mut taken = match state.shifts.get(&terminal) {
+            Some(&target) => self.first_tokens(target),
+            None => TokenSet::new(),
+        };
+        let mut reductions = vec![];
+        for &(ref lookahead, production) in &state.reductions {
+            if !lookahead.contains(Token::Terminal(terminal)) {
+                continue;
+            }
+            let mut following = TokenSet::new();
+            let mut visited = set();
+            marked |= self.add_after_reduction(state.index,
+                                               production,
+                                               terminal,
+                                               &mut visited,
+                                               &mut following);
+            if following.len() == 0 || following.is_intersecting(&taken) {
+                return None;
+            }
+            taken.union_with(&following);
+            reductions.push(TwoTokenReduction {
+                terminal: terminal,
+                following: following,
+                production: production,
+            });
+        }
+        if marked {
+            Some(reductions)
+        } else {
+            None
+        }
+    }
+
+    /// The tokens that `state` has an action for.
+    fn first_tokens(&self, state: StateIndex) -> TokenSet {
+        let state = &self.states[state.0];
+        let mut tokens = TokenSet::new();
+        for &terminal in state.shifts.keys() {
+            tokens.insert(Token::Terminal(terminal));
+        }
+        for &(ref lookahead, _) in &state.reductions {
+            tokens.union_with(lookahead);
+        }
+        tokens
+    }
+
+    /// Adds the tokens that may come after `terminal` if `state`
+    /// reduces `production` on it to `tokens`, and returns whether that
+    /// reduces a marked nonterminal along the way.
+    fn add_after_reduction(&self,
+                           state: StateIndex,
+                           production: &'grammar Production,
+                           terminal: TerminalString,
+                           visited: &mut Set<(StateIndex, &'grammar Production)>,
+                           tokens: &mut TokenSet)
+                           -> bool {
+        if !visited.insert((state, production)) {
+            return false;
+        }
+        let mut marked = self.is_marked(production.nonterminal);
+
+        // the states the parser may be in once the symbols of
+        // `production` are popped
+        let mut bases = vec![state];
+        for &symbol in production.symbols.iter().rev() {
+            let mut previous: Vec<StateIndex> =
+                bases.iter()
+                     .flat_map(|base| &self.predecessors[base.0])
+                     .filter(|&&(s, _)| s == symbol)
+                     .map(|&(_, predecessor)| predecessor)
+                     .collect();
+            previous.sort();
+            previous.dedup();
+            bases = previous;
+        }
+
+        for base in bases {
+            let target = match self.states[base.0].gotos.get(&production.nonterminal) {
+                Some(&target) => target,
+                None => continue,
+            };
+            let target_state = &self.states[target.0];
+            if let Some(&shifted) = target_state.shifts.get(&terminal) {
+                tokens.union_with(&self.first_tokens(shifted));
+            }
+            for &(ref lookahead, next) in &target_state.reductions {
+                if lookahead.contains(Token::Terminal(terminal)) {
+                    marked |= self.add_after_reduction(target, next, terminal, visited, tokens);
+                }
+            }
+        }
+        marked
+    }
+}
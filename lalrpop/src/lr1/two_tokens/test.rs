@@ -0,0 +1,71 @@
+use intern::intern;
+use grammar::repr::*;
+use lr1::build_states;
+use lr1::interpret::interpret;
+use lr1::lookahead::Token;
+use lr1::tls::Lr1Tls;
+use session::Session;
+use test_util::normalized_grammar;
+
+macro_rules! tokens {
+    ($($x:expr),*) => {
+        vec![$(TerminalString::quoted(intern($x))),*]
+    }
+}
+
+fn start(grammar: &Grammar, name: &str) -> NonterminalString {
+    grammar.start_nonterminals[&NonterminalString(intern(name))]
+}
+
+#[test]
+fn rules_without_separators() {
+    let grammar = normalized_grammar(r#"
+grammar;
+    extern { enum Tok { "Id" => .., ":" => .. } }
+    pub Rules = Rule*;
+    #[lookahead = "2"]
+    Rule = "Id" ":" Symbols;
+    Symbols: () = { Symbols "Id" => (), () => () };
+"#);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+
+    let states = build_states(&Session::test(), &grammar, start(&grammar, "Rules")).unwrap();
+    let reductions: Vec<_> = states.iter().flat_map(|state| &state.two_token_reductions).collect();
+    assert_eq!(reductions.len(), 1);
+    assert_eq!(reductions[0].terminal, TerminalString::quoted(intern("Id")));
+    assert_eq!(reductions[0].following.iter().collect::<Vec<_>>(),
+               vec![Token::Terminal(TerminalString::quoted(intern(":")))]);
+    assert_eq!(reductions[0].production.nonterminal, NonterminalString(intern("Rule")));
+
+    let tree = interpret(&states, tokens!["Id", ":", "Id", "Id", ":", "Id"]).unwrap();
+    assert_eq!(format!("{:?}", tree).matches("[Rule: ").count(), 2);
+}
+
+#[test]
+fn unmarked() {
+    let grammar = normalized_grammar(r#"
+grammar;
+    extern { enum Tok { "Id" => .., ":" => .. } }
+    pub Rules = Rule*;
+    Rule = "Id" ":" Symbols;
+    Symbols: () = { Symbols "Id" => (), () => () };
+"#);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    assert!(build_states(&Session::test(), &grammar, start(&grammar, "Rules")).is_err());
+}
+
+#[test]
+fn three_tokens_needed() {
+    let grammar = normalized_grammar(r#"
+grammar;
+    extern { enum Tok { "a" => .., "x" => .., "y" => .., "z" => .., "w" => .. } }
+    pub S = { A "x" "y" "z", B "x" "y" "w" };
+    #[lookahead = "2"]
+    A = "a";
+    B = "a";
+"#);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let error = build_states(&Session::test(), &grammar, start(&grammar, "S")).unwrap_err();
+    assert_eq!(error.conflicts.len(), 1);
+    assert!(error.states.iter().all(|state| state.two_token_reductions.is_empty()));
+}
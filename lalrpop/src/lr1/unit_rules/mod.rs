@@ -0,0 +1,171 @@
+//! Elimination of unit reductions. Grammars with several tiers of
+//! precedence are full of productions like `Expr = Sum` or `Sum =
+//! Product`, whose only job is to pass a value up a level. Each such
+//! production gives rise to a state that does nothing but reduce it,
+//! so parsing a single number goes through one reduction per tier.
+//!
+//! If the action is the identity and both nonterminals have the same
+//! type, the reduction does not change the value, and we can bypass
+//! the state altogether: wherever the automaton would go to a state
+//! that only reduces `A = B`, it goes directly to where `A` would
+//! lead. The symbol on top of the stack is then still a `B`, which is
+//! why the table-driven code generator accepts `B` wherever it pops
+//! an `A` (see `unit_aliases`).
+
+use collections::{map, Map, Set};
+use grammar::repr::*;
+use lr1::core::*;
+
+#[cfg(test)]
+mod test;
+
+/// True if `production` has the form `A = B`, where the action just
+/// returns the `B` and `A` and `B` have the same type.
+pub fn is_unit_production(grammar: &Grammar, production: &Production) -> bool {
+    if production.symbols.len() != 1 {
+        return false;
+    }
+
+    let symbol = match production.symbols[0] {
+        Symbol::Nonterminal(nt) if nt != production.nonterminal => nt,
+        _ => return false,
+    };
+
+    if grammar.start_nonterminals.values().any(|&nt| nt == production.nonterminal) {
+        return false;
+    }
+
+    if grammar.types.nonterminal_type(production.nonterminal) !=
+        grammar.types.nonterminal_type(symbol) {
+        return false;
+    }
+
+    let defn = &grammar.action_fn_defns[production.action.index()];
+    match defn.kind {
+        ActionFnDefnKind::User(ref data) if !defn.fallible && data.arg_patterns.len() == 1 => {
+            let mut code = data.code.trim();
+            while (code.starts_with("(") && code.ends_with(")")) ||
+                  (code.starts_with("{") && code.ends_with("}")) {
+                code = code[1..code.len() - 1].trim();
+            }
+            code == data.arg_patterns[0].to_string()
+        }
+        _ => false,
+    }
+}
+
+/// For each nonterminal `A`, the nonterminals `B` that can stand in
+/// for it through a chain of unit productions `A = B`.
+pub fn unit_aliases(grammar: &Grammar) -> Map<NonterminalString, Set<NonterminalString>> {
+    let mut aliases: Map<NonterminalString, Set<NonterminalString>> = map();
+    for production in grammar.nonterminals.values().flat_map(|data| &data.productions) {
+        if is_unit_production(grammar, production) {
+            if let Symbol::Nonterminal(nt) = production.symbols[0] {
+                aliases.entry(production.nonterminal).or_insert_with(Set::new).insert(nt);
+            }
+        }
+    }
+
+    // close over chains like `A = B`, `B = C`
+    let mut changed = true;
+    while changed {
+        changed = false;
+        let snapshot = aliases.clone();
+        for (&nt, nt_aliases) in &mut aliases {
+            let transitive: Vec<NonterminalString> =
+                nt_aliases.iter()
+                          .flat_map(|alias| snapshot.get(alias).into_iter().flat_map(|s| s))
+                          .cloned()
+                          .filter(|&alias| alias != nt)
+                          .collect();
+            for alias in transitive {
+                changed |= nt_aliases.insert(alias);
+            }
+        }
+    }
+
+    aliases
+}
+
+/// Redirects the gotos that lead to states that only reduce a unit
+/// production, and then drops the states that are no longer
+/// reachable.
+pub fn eliminate_unit_reductions<'grammar>(grammar: &Grammar,
+                                           mut states: Vec<LR1State<'grammar>>)
+                                           -> Vec<LR1State<'grammar>> {
+    let unit_states: Map<StateIndex, NonterminalString> =
+        states.iter()
+              .filter(|state| {
+                  state.shifts.is_empty() && state.gotos.is_empty() &&
+                  state.two_token_reductions.is_empty()
+              })
+              .filter_map(|state| {
+                  let production = match state.reductions.first() {
+                      Some(&(_, production)) => production,
+                      None => return None,
+                  };
+                  if state.reductions.iter().all(|&(_, p)| p == production) &&
+                      is_unit_production(grammar, production) {
+                      Some((state.index, production.nonterminal))
+                  } else {
+                      None
+                  }
+              })
+              .collect();
+
+    if unit_states.is_empty() {
+        return states;
+    }
+
+    for state in &mut states {
+        let gotos: Vec<(NonterminalString, StateIndex)> =
+            state.gotos.iter().map(|(&nt, &target)| (nt, target)).collect();
+        for (nt, mut target) in gotos {
+            // follow chains of unit states; a cycle would mean that
+            // the grammar is ambiguous, but guard against it anyway
+            let mut steps = 0;
+            while let Some(&parent) = unit_states.get(&target) {
+                match state.gotos.get(&parent) {
+                    Some(&next) if steps < unit_states.len() => target = next,
+                    _ => break,
+                }
+                steps += 1;
+            }
+            state.gotos.insert(nt, target);
+        }
+    }
+
+    // find the states still reachable from the start state
+    let mut reachable = vec![false; states.len()];
+    let mut stack = vec![StateIndex(0)];
+    while let Some(index) = stack.pop() {
+        if reachable[index.0] {
+            continue;
+        }
+        reachable[index.0] = true;
+        let state = &states[index.0];
+        stack.extend(state.shifts.values().chain(state.gotos.values()).cloned());
+    }
+
+    // and renumber them
+    let mut renumbered = vec![None; states.len()];
+    let mut next = 0;
+    for (index, &keep) in reachable.iter().enumerate() {
+        if keep {
+            renumbered[index] = Some(StateIndex(next));
+            next += 1;
+        }
+    }
+    let renumber = |index: &mut StateIndex| *index = renumbered[index.0].unwrap();
+
+    states.into_iter()
+          .filter(|state| reachable[state.index.0])
+          .map(|mut state| {
+              renumber(&mut state.index);
+              for target in state.shifts.values_mut().chain(state.gotos.values_mut()) {
+                  renumber(target);
+              }
+              state
+          })
+          .collect()
+}
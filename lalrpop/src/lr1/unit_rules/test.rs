@@ -0,0 +1,67 @@
+use intern::intern;
+use grammar::repr::*;
+use lr1::build::build_lr1_states;
+use lr1::interpret::interpret;
+use lr1::tls::Lr1Tls;
+use session::Session;
+use test_util::normalized_grammar;
+
+use super::{eliminate_unit_reductions, is_unit_production, unit_aliases};
+
+fn nt(t: &str) -> NonterminalString {
+    NonterminalString(intern(t))
+}
+
+macro_rules! tokens {
+    ($($x:expr),*) => {
+        vec![$(TerminalString::quoted(intern($x))),*]
+    }
+}
+
+fn tiers() -> Grammar {
+    normalized_grammar(r#"
+grammar;
+    pub Expr: i32 = { <l:Expr> "+" <r:Term> => l + r, Term };
+    Term: i32 = { <l:Term> "*" <r:Atom> => l * r, <a:Atom> => a };
+    Atom: i32 = { "N" => 1, "(" <Expr> ")", "-" <Atom> => -<> };
+"#)
+}
+
+#[test]
+fn unit_productions() {
+    let grammar = tiers();
+    let units: Vec<String> =
+        grammar.nonterminals
+               .values()
+               .flat_map(|data| &data.productions)
+               .filter(|p| is_unit_production(&grammar, p))
+               .map(|p| format!("{} = {}", p.nonterminal, p.symbols[0]))
+               .collect();
+    assert_eq!(units, vec!["Expr = Term", "Term = Atom"]);
+
+    let aliases = unit_aliases(&grammar);
+    assert_eq!(aliases[&nt("Expr")].iter().map(|nt| nt.to_string()).collect::<Vec<_>>(),
+               vec!["Atom", "Term"]);
+    assert_eq!(aliases[&nt("Term")].iter().map(|nt| nt.to_string()).collect::<Vec<_>>(),
+               vec!["Atom"]);
+}
+
+#[test]
+fn eliminate_tiers() {
+    let grammar = tiers();
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let start = grammar.start_nonterminals[&nt("Expr")];
+    let states = build_lr1_states(&Session::test(), &grammar, start).unwrap();
+    let num_states = states.len();
+    let states = eliminate_unit_reductions(&grammar, states);
+    assert!(states.len() < num_states);
+
+    for (index, state) in states.iter().enumerate() {
+        assert_eq!(state.index.0, index);
+    }
+
+    assert!(interpret(&states, tokens!["N"]).is_ok());
+    assert!(interpret(&states, tokens!["N", "+", "N", "*", "(", "-", "N", ")"]).is_ok());
+    assert!(interpret(&states, tokens!["N", "+"]).is_err());
+    assert!(interpret(&states, tokens!["(", "N", "*", "N"]).is_err());
+}
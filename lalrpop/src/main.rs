@@ -28,6 +28,12 @@ fn main1() -> io::Result<()> {
         process::exit(0);
     }
 
+    if args.flag_map_errors {
+        let stdin = io::stdin();
+        try!(lalrpop::rewrite_rustc_output(stdin.lock(), &mut stdout));
+        process::exit(0);
+    }
+
     let mut config = Configuration::new();
 
     match args.flag_level.unwrap_or(LevelFlag::Info) {
@@ -49,10 +55,116 @@ fn main1() -> io::Result<()> {
         config.emit_comments(true);
     }
 
+    match args.flag_examples {
+        Some(ExamplesFlag::Tree) => { config.draw_examples_as_trees(false); }
+        Some(ExamplesFlag::Box) => { config.draw_examples_as_trees(true); }
+        Some(ExamplesFlag::Brackets) | None => { }
+    }
+
     if args.flag_report {
         config.emit_report(true);
     }
 
+    for emit in &args.flag_emit {
+        match *emit {
+            EmitFlag::Sets => { config.emit_sets(true); }
+            EmitFlag::Classes => { config.emit_classes(true); }
+            EmitFlag::Conflicts => { config.emit_conflicts(true); }
+            EmitFlag::Bench => { config.emit_bench(true); }
+            EmitFlag::C => { config.emit_c_abi(true); }
+            EmitFlag::Doc => { config.emit_doc(true); }
+            EmitFlag::Stats => { config.emit_stats(true); }
+            EmitFlag::Operators => { config.emit_operators(true); }
+        }
+    }
+
+    if let Some(max_length) = args.flag_search_ambiguity {
+        config.search_ambiguity(max_length);
+    }
+
+    if let Some(bytes) = args.flag_fallback_budget {
+        config.lr1_fallback_budget(bytes);
+    }
+
+    if let Some(ref dir) = args.flag_checkpoint_dir {
+        config.checkpoint_dir(dir);
+    }
+
+    if args.flag_all_conflicts {
+        config.report_all_conflicts(true);
+    }
+
+    if args.flag_conflicts_as_warnings {
+        config.conflicts_as_warnings(true);
+    }
+
+    match args.flag_conflict_baseline {
+        Some(BaselineFlag::Check) => { config.check_conflict_baseline(); }
+        Some(BaselineFlag::Update) => { config.update_conflict_baseline(); }
+        None => { }
+    }
+
+    match args.flag_profile {
+        Some(ProfileFlag::Emit) => { config.emit_profile(true); }
+        Some(ProfileFlag::Use) => { config.use_profile(true); }
+        None => { }
+    }
+
+    if args.cmd_diff {
+        if let Err(err) = config.diff_files(&args.arg_old, &args.arg_new) {
+            try!(writeln!(stderr, "Error encountered comparing `{}` and `{}`: {}",
+                          args.arg_old, args.arg_new, err));
+            process::exit(1);
+        }
+        process::exit(0);
+    }
+
+    if args.cmd_explain_conflicts {
+        if let Err(err) = config.explain_conflicts(&args.arg_grammar) {
+            try!(writeln!(stderr, "Error encountered explaining the conflicts of `{}`: {}",
+                          args.arg_grammar, err));
+            process::exit(1);
+        }
+        process::exit(0);
+    }
+
+    if args.cmd_repl {
+        if let Err(err) = config.repl(&args.arg_grammar,
+                                      args.flag_start.as_ref().map(|s| &s[..])) {
+            try!(writeln!(stderr, "Error encountered running the REPL for `{}`: {}",
+                          args.arg_grammar, err));
+            process::exit(1);
+        }
+        process::exit(0);
+    }
+
+    if args.cmd_playground {
+        let stdout = io::stdout();
+        let mut stdout = stdout.lock();
+        if let Err(err) = config.playground(&args.arg_grammar,
+                                            args.flag_start.as_ref().map(|s| &s[..]),
+                                            &mut stdout) {
+            try!(writeln!(stderr, "Error encountered writing the playground for `{}`: {}",
+                          args.arg_grammar, err));
+            process::exit(1);
+        }
+        process::exit(0);
+    }
+
+    if args.cmd_animate {
+        let stdout = io::stdout();
+        let mut stdout = stdout.lock();
+        if let Err(err) = config.animate_parse(&args.arg_grammar,
+                                               &args.arg_nonterminal,
+                                               &args.arg_input,
+                                               &mut stdout) {
+            try!(writeln!(stderr, "Error encountered animating the parse of `{}`: {}",
+                          args.arg_input, err));
+            process::exit(1);
+        }
+        process::exit(0);
+    }
+
     if args.arg_inputs.len() == 0 {
         try!(writeln!(stderr, "Error: no input files specified! Try --help for help."));
         process::exit(1);
@@ -73,9 +185,15 @@ fn main1() -> io::Result<()> {
 }
 
 const USAGE: &'static str = "
-Usage: lalrpop [options] <inputs>...
+Usage: lalrpop [options] [--emit KIND]... <inputs>...
+       lalrpop [options] diff <old> <new>
+       lalrpop [options] explain-conflicts <grammar>
+       lalrpop [options] animate <grammar> <nonterminal> <input>
+       lalrpop [options] repl <grammar> [--start NONTERMINAL]
+       lalrpop [options] playground <grammar> [--start NONTERMINAL]
        lalrpop --help
        lalrpop (-V | --version)
+       lalrpop --map-errors
 
 Options:
     -V, --version        Print version.
@@ -85,17 +203,74 @@ Options:
     -c, --color          Force colorful output, even if this is not a TTY.
     --comments           Enable comments in the generated code.
     --report             Generate report files.
+    --emit KIND          Generate additional files describing the grammar; may be
+                         given more than once.
+                         Valid values: sets (FIRST, FOLLOW and nullable sets),
+                         classes (LL(1), SLR(1), LALR(1) and LR(1) membership),
+                         conflicts (the LR(1) conflicts, as JSON),
+                         bench (a criterion benchmark of the public parsers),
+                         c (C entry points for the public parsers, and a header),
+                         doc (a Markdown reference of the grammar),
+                         stats (counts of symbols, states, conflicts and code),
+                         operators (a table of operators and their precedences).
+    --examples STYLE     How to draw example derivations in conflict reports.
+                         Valid values: brackets (default), tree, box.
+    --search-ambiguity LEN
+                         On conflicts, search for a sentence of at most LEN tokens
+                         that can be parsed in two ways.
+    --fallback-budget BYTES
+                         Give up on canonical LR(1) states, and fall back on the
+                         lane table algorithm, once they take about BYTES bytes.
+    --checkpoint-dir DIR Write checkpoints of the LR(1) states to DIR while building
+                         them, and resume from them if the build is interrupted.
+    --all-conflicts      Report every conflict, rather than stopping at the first.
+    --conflicts-as-warnings
+                         Report conflicts as warnings and generate the parsers
+                         anyway: shifts win over reductions, and earlier rules
+                         over later ones.
+    --conflict-baseline MODE
+                         Compare the conflicts of each grammar with the baseline
+                         file next to it, and only fail on new ones.
+                         Valid values: check, update (record them all).
+    --profile MODE       Instrument table-driven parsers to record a profile, or lay
+                         out their tables by the profile next to each grammar.
+                         Valid values: emit, use.
+    --start NONTERMINAL  The public nonterminal that `repl` and `playground` parse
+                         input as; only needed if the grammar has more than one.
+    --map-errors         Read rustc output from stdin and rewrite locations in
+                         generated action code to point at the grammar.
 ";
 
 #[derive(Debug, RustcDecodable)]
 struct Args {
     arg_inputs: Vec<String>,
+    cmd_diff: bool,
+    arg_old: String,
+    arg_new: String,
+    cmd_explain_conflicts: bool,
+    arg_grammar: String,
+    cmd_animate: bool,
+    arg_nonterminal: String,
+    arg_input: String,
+    cmd_repl: bool,
+    cmd_playground: bool,
+    flag_start: Option<String>,
     flag_level: Option<LevelFlag>,
     flag_force: bool,
     flag_color: bool,
     flag_comments: bool,
     flag_report: bool,
+    flag_emit: Vec<EmitFlag>,
+    flag_examples: Option<ExamplesFlag>,
+    flag_search_ambiguity: Option<usize>,
+    flag_fallback_budget: Option<usize>,
+    flag_checkpoint_dir: Option<String>,
+    flag_all_conflicts: bool,
+    flag_conflicts_as_warnings: bool,
+    flag_conflict_baseline: Option<BaselineFlag>,
+    flag_profile: Option<ProfileFlag>,
     flag_version: bool,
+    flag_map_errors: bool,
 }
 
 #[derive(Debug, RustcDecodable)]
@@ -103,11 +278,31 @@ enum LevelFlag {
     Quiet, Info, Verbose, Debug
 }
 
+#[derive(Debug, PartialEq, RustcDecodable)]
+enum EmitFlag {
+    Sets, Classes, Conflicts, Bench, C, Doc, Stats, Operators
+}
+
+#[derive(Debug, RustcDecodable)]
+enum ExamplesFlag {
+    Brackets, Tree, Box
+}
+
+#[derive(Debug, RustcDecodable)]
+enum BaselineFlag {
+    Check, Update
+}
+
+#[derive(Debug, RustcDecodable)]
+enum ProfileFlag {
+    Emit, Use
+}
+
 #[cfg(test)]
 mod test {
     use docopt::Docopt;
     use super::USAGE;
-    use super::Args;
+    use super::{Args, BaselineFlag, EmitFlag, ProfileFlag};
 
     #[test]
     fn test_usage_help() {
@@ -125,6 +320,174 @@ mod test {
             .unwrap();
     }
    
+    #[test]
+    fn test_usage_map_errors() {
+        let argv = || vec!["lalrpop", "--map-errors"];
+        let _: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).decode())
+            .unwrap();
+    }
+
+    #[test]
+    fn test_usage_emit() {
+        let cases = vec![
+            (vec![], vec![]),
+            (vec!["sets"], vec![EmitFlag::Sets]),
+            (vec!["classes"], vec![EmitFlag::Classes]),
+            (vec!["conflicts"], vec![EmitFlag::Conflicts]),
+            (vec!["bench"], vec![EmitFlag::Bench]),
+            (vec!["c"], vec![EmitFlag::C]),
+            (vec!["doc"], vec![EmitFlag::Doc]),
+            (vec!["stats"], vec![EmitFlag::Stats]),
+            (vec!["operators"], vec![EmitFlag::Operators]),
+            (vec!["sets", "doc"], vec![EmitFlag::Sets, EmitFlag::Doc]),
+        ];
+        for (kinds, expected) in cases {
+            let mut argv = vec!["lalrpop"];
+            for kind in kinds {
+                argv.push("--emit");
+                argv.push(kind);
+            }
+            argv.push("file.lalrpop");
+            let args: Args = Docopt::new(USAGE)
+                .and_then(|d| d.argv(argv.into_iter()).decode())
+                .unwrap();
+            assert_eq!(args.flag_emit, expected);
+        }
+    }
+
+    #[test]
+    fn test_usage_search_ambiguity() {
+        let argv = || vec!["lalrpop", "--search-ambiguity", "8", "file.lalrpop"];
+        let args: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).decode())
+            .unwrap();
+        assert_eq!(args.flag_search_ambiguity, Some(8));
+    }
+
+    #[test]
+    fn test_usage_fallback_budget() {
+        let argv = || vec!["lalrpop", "--fallback-budget", "1000000", "file.lalrpop"];
+        let args: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).decode())
+            .unwrap();
+        assert_eq!(args.flag_fallback_budget, Some(1000000));
+    }
+
+    #[test]
+    fn test_usage_checkpoint_dir() {
+        let argv = || vec!["lalrpop", "--checkpoint-dir", "target/lr1", "file.lalrpop"];
+        let args: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).decode())
+            .unwrap();
+        assert_eq!(args.flag_checkpoint_dir, Some(String::from("target/lr1")));
+    }
+
+    #[test]
+    fn test_usage_all_conflicts() {
+        let argv = || vec!["lalrpop", "--all-conflicts", "file.lalrpop"];
+        let args: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).decode())
+            .unwrap();
+        assert!(args.flag_all_conflicts);
+    }
+
+    #[test]
+    fn test_usage_conflicts_as_warnings() {
+        let argv = || vec!["lalrpop", "--conflicts-as-warnings", "file.lalrpop"];
+        let args: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).decode())
+            .unwrap();
+        assert!(args.flag_conflicts_as_warnings);
+    }
+
+    #[test]
+    fn test_usage_conflict_baseline() {
+        let argv = || vec!["lalrpop", "--conflict-baseline", "update", "file.lalrpop"];
+        let args: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).decode())
+            .unwrap();
+        match args.flag_conflict_baseline {
+            Some(BaselineFlag::Update) => { }
+            flag => panic!("unexpected flag: {:?}", flag),
+        }
+    }
+
+    #[test]
+    fn test_usage_profile() {
+        let argv = || vec!["lalrpop", "--profile", "use", "file.lalrpop"];
+        let args: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).decode())
+            .unwrap();
+        match args.flag_profile {
+            Some(ProfileFlag::Use) => { }
+            flag => panic!("unexpected flag: {:?}", flag),
+        }
+    }
+
+    #[test]
+    fn test_usage_examples() {
+        let argv = || vec!["lalrpop", "--examples", "box", "file.lalrpop"];
+        let _: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).decode())
+            .unwrap();
+    }
+
+    #[test]
+    fn test_usage_diff() {
+        let argv = || vec!["lalrpop", "diff", "old.lalrpop", "new.lalrpop"];
+        let args: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).decode())
+            .unwrap();
+        assert!(args.cmd_diff);
+        assert_eq!(args.arg_old, "old.lalrpop");
+        assert_eq!(args.arg_new, "new.lalrpop");
+    }
+
+    #[test]
+    fn test_usage_explain_conflicts() {
+        let argv = || vec!["lalrpop", "explain-conflicts", "grammar.lalrpop"];
+        let args: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).decode())
+            .unwrap();
+        assert!(args.cmd_explain_conflicts);
+        assert_eq!(args.arg_grammar, "grammar.lalrpop");
+    }
+
+    #[test]
+    fn test_usage_repl() {
+        let argv = || vec!["lalrpop", "repl", "grammar.lalrpop", "--start", "Expr"];
+        let args: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).decode())
+            .unwrap();
+        assert!(args.cmd_repl);
+        assert_eq!(args.arg_grammar, "grammar.lalrpop");
+        assert_eq!(args.flag_start, Some(String::from("Expr")));
+    }
+
+    #[test]
+    fn test_usage_playground() {
+        let argv = || vec!["lalrpop", "playground", "grammar.lalrpop"];
+        let args: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).decode())
+            .unwrap();
+        assert!(args.cmd_playground);
+        assert_eq!(args.arg_grammar, "grammar.lalrpop");
+        assert_eq!(args.flag_start, None);
+    }
+
+    #[test]
+    fn test_usage_animate() {
+        let argv = || vec!["lalrpop", "animate", "grammar.lalrpop", "Expr", "1 + 2"];
+        let args: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).decode())
+            .unwrap();
+        assert!(args.cmd_animate);
+        assert_eq!(args.arg_grammar, "grammar.lalrpop");
+        assert_eq!(args.arg_nonterminal, "Expr");
+        assert_eq!(args.arg_input, "1 + 2");
+    }
+
     #[test]
     fn test_usage_single_input() {
         let argv = || vec!["lalrpop", "file.lalrpop"];
@@ -1,3 +1,4 @@
+use file_text::FileText;
 use grammar::parse_tree::Span;
 use message::{Content, Message};
 use message::indent::Indent;
@@ -6,10 +7,13 @@ use message::styled::Styled;
 use message::text::Text;
 use message::vert::Vert;
 use message::wrap::Wrap;
+use session::Session;
 use style::Style;
 
 pub struct MessageBuilder {
     span: Span,
+    citation: String,
+    heading_style: Style,
     heading: Option<Box<Content>>,
     body: Option<Box<Content>>,
 }
@@ -23,8 +27,10 @@ pub struct BodyCharacter {
 }
 
 impl MessageBuilder {
-    pub fn new(span: Span) -> Self {
+    pub fn new(session: &Session, file_text: &FileText, span: Span) -> Self {
         MessageBuilder { span: span,
+                         citation: file_text.span_str(span),
+                         heading_style: session.heading,
                          heading: None, body: None }
     }
 
@@ -38,6 +44,8 @@ impl MessageBuilder {
 
     pub fn end(self) -> Message {
         Message::new(self.span,
+                     self.citation,
+                     self.heading_style,
                      self.heading.expect("never defined a heading"),
                      self.body.expect("never defined a body"))
     }
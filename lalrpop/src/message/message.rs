@@ -4,7 +4,6 @@ use message::Content;
 use std::cmp;
 use std::fmt::{Debug, Formatter, Error};
 use style::Style;
-use tls::Tls;
 
 /// The top-level message display like this:
 ///
@@ -28,43 +27,57 @@ use tls::Tls;
 ///     <body>
 /// }
 /// ```
+///
+/// The citation of the span (its text, like `foo.lalrpop:1:2: 1:5`)
+/// and the style of the heading are worked out when the message is
+/// built, so that the message can be drawn on its own.
 pub struct Message {
     span: Span,
+    citation: String,
+    heading_style: Style,
     heading: Box<Content>,
     body: Box<Content>,
 }
 
 impl Message {
     pub fn new(span: Span,
+               citation: String,
+               heading_style: Style,
                heading: Box<Content>,
                body: Box<Content>) -> Self {
         Message {
             span: span,
+            citation: citation,
+            heading_style: heading_style,
             heading: heading,
             body: body,
         }
     }
+
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// The heading and body, without the citation of the span.
+    pub fn into_heading_and_body(self) -> (Box<Content>, Box<Content>) {
+        (self.heading, self.body)
+    }
 }
 
 impl Content for Message {
     fn min_width(&self) -> usize {
-        let file_text = Tls::file_text();
-        let span = file_text.span_str(self.span).chars().count();
+        let span = self.citation.chars().count();
         let heading = self.heading.min_width();
         let body = self.body.min_width();
         cmp::max(span + heading + 2, body + 2)
     }
 
     fn emit(&self, view: &mut AsciiView) {
-        let session = Tls::session();
-        let file_text = Tls::file_text();
-
-        let span = file_text.span_str(self.span);
-        view.write_chars(0, 0, span.chars(), Style::new());
-        let count = span.chars().count();
+        view.write_chars(0, 0, self.citation.chars(), Style::new());
+        let count = self.citation.chars().count();
         view.write_chars(0, count, ":".chars(), Style::new());
 
-        let (row, _) = self.heading.emit_at(&mut view.styled(session.heading),
+        let (row, _) = self.heading.emit_at(&mut view.styled(self.heading_style),
                                             0,
                                             count + 2);
 
@@ -1,23 +1,24 @@
 use ascii_canvas::AsciiCanvas;
+use file_text::FileText;
 use grammar::parse_tree::Span;
 use message::builder::MessageBuilder;
+use session::Session;
+use std::path::PathBuf;
 use test_util::expect_debug;
-use tls::Tls;
 
 use super::*;
 
-fn install_tls() -> Tls {
-    Tls::test_string(r#"foo
+fn file_text() -> FileText {
+    FileText::new(PathBuf::from("tmp.txt"), String::from(r#"foo
 bar
 baz
-"#)
+"#))
 }
 
 #[test]
 fn hello_world() {
-    let _tls = install_tls();
     let msg =
-        MessageBuilder::new(Span(0, 2))
+        MessageBuilder::new(&Session::test(), &file_text(), Span(0, 2))
         .heading()
         .text("Hello, world!")
         .end()
@@ -47,9 +48,8 @@ fn hello_world() {
 /// header (which used to mess up the `min_width` computation).
 #[test]
 fn long_body() {
-    let _tls = install_tls();
     let msg =
-        MessageBuilder::new(Span(0, 2))
+        MessageBuilder::new(&Session::test(), &file_text(), Span(0, 2))
         .heading()
         .text("Hello, world!")
         .end()
@@ -72,9 +72,8 @@ fn long_body() {
 
 #[test]
 fn paragraphs() {
-    let _tls = install_tls();
     let msg =
-        MessageBuilder::new(Span(0, 2))
+        MessageBuilder::new(&Session::test(), &file_text(), Span(0, 2))
         .heading()
         .text("Hello, world!")
         .end() // heading
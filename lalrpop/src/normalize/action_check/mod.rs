@@ -0,0 +1,216 @@
+//! Checks that the action code and guards of the alternatives are
+//! well-formed enough to be pasted into the generated parser: their
+//! delimiters match, and their literals and comments are terminated.
+//! Catching these mistakes here lets us report them at the
+//! alternative, rather than as confusing rustc errors in the
+//! generated file.
+
+use super::{NormResult, NormError};
+
+use grammar::parse_tree::*;
+
+#[cfg(test)]
+mod test;
+
+pub fn validate(grammar: &Grammar) -> NormResult<()> {
+    for data in grammar.items.iter().filter_map(|item| item.as_nonterminal()) {
+        for alternative in &data.alternatives {
+            if let Some(ref guard) = alternative.guard {
+                if let Err(message) = check(guard) {
+                    return_err!(alternative.span, "malformed guard: {}", message);
+                }
+            }
+            match alternative.action {
+                Some(ActionKind::User(ref code)) |
+                Some(ActionKind::Fallible(ref code)) => {
+                    if let Err(message) = check(code) {
+                        return_err!(alternative.span, "malformed action code: {}", message);
+                    }
+                }
+                Some(ActionKind::Lookahead) |
+                Some(ActionKind::Lookbehind) |
+                None => {}
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Scans `code` as a sequence of Rust tokens, and describes the first
+/// problem found, if any.
+fn check(code: &str) -> Result<(), String> {
+    let mut scanner = Scanner {
+        chars: code.chars().collect(),
+        index: 0,
+    };
+    scanner.scan()
+}
+
+struct Scanner {
+    chars: Vec<char>,
+    index: usize,
+}
+
+impl Scanner {
+    fn peek(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.index + offset).cloned()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek(0);
+        if c.is_some() {
+            self.index += 1;
+        }
+        c
+    }
+
+    fn scan(&mut self) -> Result<(), String> {
+        // the delimiters that are still open, innermost last
+        let mut open = vec![];
+        while let Some(c) = self.next() {
+            match c {
+                '(' | '[' | '{' => open.push(c),
+                ')' | ']' | '}' => {
+                    match open.pop() {
+                        Some(o) if closing(o) == c => {}
+                        Some(o) => {
+                            return Err(format!("`{}` closed by `{}`", o, c));
+                        }
+                        None => {
+                            return Err(format!("unexpected `{}`", c));
+                        }
+                    }
+                }
+                '"' => try!(self.string_literal()),
+                '\'' => try!(self.lifetime_or_character_literal()),
+                '/' if self.peek(0) == Some('/') => {
+                    while let Some(c) = self.next() {
+                        if c == '\n' {
+                            break;
+                        }
+                    }
+                }
+                '/' if self.peek(0) == Some('*') => {
+                    self.index += 1;
+                    try!(self.block_comment());
+                }
+                c if is_identifier_char(c) => {
+                    let start = self.index - 1;
+                    self.identifier();
+                    let word: String = self.chars[start..self.index].iter().cloned().collect();
+                    if word == "r" || word == "br" {
+                        try!(self.raw_string_literal());
+                    }
+                }
+                _ => {}
+            }
+        }
+        match open.pop() {
+            Some(o) => Err(format!("unclosed `{}`", o)),
+            None => Ok(()),
+        }
+    }
+
+    fn identifier(&mut self) {
+        while self.peek(0).map_or(false, is_identifier_char) {
+            self.index += 1;
+        }
+    }
+
+    /// Skips the rest of a string literal, after its opening `"`.
+    fn string_literal(&mut self) -> Result<(), String> {
+        while let Some(c) = self.next() {
+            match c {
+                '\\' => {
+                    self.next();
+                }
+                '"' => return Ok(()),
+                _ => {}
+            }
+        }
+        Err(format!("unterminated string literal"))
+    }
+
+    /// Skips a raw string literal like `r#"..."#`, if one follows the
+    /// `r` (or `br`) that was just scanned.
+    fn raw_string_literal(&mut self) -> Result<(), String> {
+        let mut hashes = 0;
+        while self.peek(hashes) == Some('#') {
+            hashes += 1;
+        }
+        if self.peek(hashes) != Some('"') {
+            return Ok(());
+        }
+        self.index += hashes + 1;
+        while let Some(c) = self.next() {
+            if c == '"' && (0..hashes).all(|i| self.peek(i) == Some('#')) {
+                self.index += hashes;
+                return Ok(());
+            }
+        }
+        Err(format!("unterminated raw string literal"))
+    }
+
+    /// Skips a lifetime like `'a` or a character literal like `'a'`,
+    /// after the opening `'`.
+    fn lifetime_or_character_literal(&mut self) -> Result<(), String> {
+        match self.next() {
+            Some('\\') => {
+                self.next();
+                while let Some(c) = self.next() {
+                    if c == '\'' {
+                        return Ok(());
+                    }
+                }
+                Err(format!("unterminated character literal"))
+            }
+            Some(_) if self.peek(0) == Some('\'') => {
+                self.index += 1;
+                Ok(())
+            }
+            Some(c) if is_identifier_char(c) => {
+                let start = self.index - 1;
+                self.identifier();
+                if self.peek(0) == Some('\'') {
+                    let text: String = self.chars[start..self.index].iter().cloned().collect();
+                    return Err(format!("character literal `'{}'` has more than one character",
+                                       text));
+                }
+                Ok(())
+            }
+            _ => Err(format!("unterminated character literal")),
+        }
+    }
+
+    /// Skips the rest of a (possibly nested) block comment, after its
+    /// opening `/*`.
+    fn block_comment(&mut self) -> Result<(), String> {
+        let mut depth = 1;
+        while let Some(c) = self.next() {
+            if c == '/' && self.peek(0) == Some('*') {
+                self.index += 1;
+                depth += 1;
+            } else if c == '*' && self.peek(0) == Some('/') {
+                self.index += 1;
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(());
+                }
+            }
+        }
+        Err(format!("unterminated block comment"))
+    }
+}
+
+fn closing(delimiter: char) -> char {
+    match delimiter {
+        '(' => ')',
+        '[' => ']',
+        '{' => '}',
+        _ => panic!("not an opening delimiter: {:?}", delimiter),
+    }
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
@@ -0,0 +1,48 @@
+use parser;
+use test_util;
+
+fn check_err(expected_err: &str, grammar: &str, span: &str) {
+    let parsed_grammar = parser::parse_grammar(&grammar).unwrap();
+    let err = super::validate(&parsed_grammar).unwrap_err();
+    test_util::check_norm_err(expected_err, span, err);
+}
+
+fn check_ok(grammar: &str) {
+    let parsed_grammar = parser::parse_grammar(&grammar).unwrap();
+    super::validate(&parsed_grammar).unwrap();
+}
+
+#[test]
+fn mismatched_delimiters() {
+    check_err(
+        r#"malformed action code: `\(` closed by `\]`"#,
+        r#"grammar; Term = { "a" => f(1], "b" => () };"#,
+        r#"                  ~~~~~~~~~~~              "#);
+}
+
+#[test]
+fn long_character_literal() {
+    check_err(
+        r#"malformed action code: character literal `'ab'` has more than one character"#,
+        r#"grammar; Term: char = { "a" => 'ab', "b" => 'b' };"#,
+        r#"                        ~~~~~~~~~~~~~~~~~~~~~~~~  "#);
+}
+
+#[test]
+fn malformed_guard() {
+    check_err(
+        r#"malformed guard: `\[` closed by `\)`"#,
+        r#"grammar; Term = <a:"a"> if { a[0) } => ();"#,
+        r#"                ~~~~~~~~~~~~~~~~~~~~~~~~~ "#);
+}
+
+#[test]
+fn well_formed() {
+    check_ok(r##"grammar;
+        Term: Vec<&'input str> = {
+            <a:"a"> => vec![a], // a comment with a `(`
+            "b" => { let c = ')'; /* a comment */ vec![] },
+            "c" => vec![r#"raw string with a `]`"#, "string with a \"}\""],
+            "d" => { 'outer: loop { break 'outer vec![] } },
+        };"##);
+}
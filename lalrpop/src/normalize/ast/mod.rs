@@ -0,0 +1,431 @@
+//! Expands `#[ast]` nonterminals, whose types LALRPOP declares rather
+//! than the user. Given:
+//!
+//!     #[ast]
+//!     Expr = {
+//!         #[variant = "Add"] <left:Expr> "+" <right:Term>,
+//!         #[variant = "Term"] <term:Term>,
+//!     };
+//!
+//! where `Term` is an `#[ast]` nonterminal too, we generate:
+//!
+//!     Expr: Expr = {
+//!         <__start:@L> <left:Expr> "+" <right:Term> <__end:@R> =>
+//!             Expr::Add { start: __start, end: __end,
+//!                         left: Box::new(left), right: Box::new(right) },
+//!         <__start:@L> <term:Term> <__end:@R> =>
+//!             Expr::Term { start: __start, end: __end, term: Box::new(term) },
+//!     };
+//!
+//! and the parser declares `enum Expr` with these variants (a
+//! nonterminal with a single alternative without a `#[variant]` gets a
+//! struct instead). Every node thus knows where it starts and ends,
+//! and implements `lalrpop_util::Spanned`. Under `#![ast_text]`, it
+//! also gets a `text` field with the input it was parsed from.
+//!
+//! The named symbols become the fields, with the types of their
+//! values; those of `#[ast]` nonterminals are boxed, so that the types
+//! can be recursive. The types take the type parameters of the grammar
+//! that their fields use, like `'input` for the text of terminals,
+//! which are only known once the types have been inferred; so the
+//! types are first declared without parameters, and then given them
+//! by `add_type_parameters`.
+//!
+//! The types derive `Clone`, `Debug` and `PartialEq`, unless the
+//! grammar lists other traits with `#![ast_derive(...)]`, or the
+//! nonterminal with `#[ast_derive = "..."]`. Attributes for all types,
+//! like `#![ast_attr(serde(tag = "kind"))]`, or for one, like
+//! `#[ast_attr = "serde(rename = \"expr\")"]`, are put on them as well.
+
+use super::{NormResult, NormError};
+
+use collections::{map, set, Map, Set};
+use grammar::consts::{AST, AST_ATTR, AST_DERIVE, AST_TEXT, INPUT_LIFETIME, LOCATION, VARIANT};
+use grammar::parse_tree::*;
+use grammar::repr as r;
+use intern::{intern, read, InternedString};
+use normalize::lower::{attribute_arguments, is_attribute};
+use normalize::tyinfer;
+
+#[cfg(test)]
+mod test;
+
+pub fn expand(mut grammar: Grammar) -> NormResult<Grammar> {
+    let ast_names: Set<NonterminalString> =
+        grammar.items
+               .iter()
+               .filter_map(|item| item.as_nonterminal())
+               .filter(|data| is_ast(data))
+               .map(|data| data.name)
+               .collect();
+    if ast_names.is_empty() {
+        return Ok(grammar);
+    }
+
+    let text = has_text(&grammar);
+    if let Some(extern_token) = grammar.extern_token() {
+        if extern_token.enum_token.is_some() {
+            if text {
+                return_err!(extern_token.span,
+                            "`#![{}]` needs the built-in lexer, as the text of a node \
+                             is a slice of the input",
+                            AST_TEXT);
+            }
+            if !extern_token.associated_types.iter().any(|t| t.type_name == intern(LOCATION)) {
+                return_err!(extern_token.span,
+                            "the nodes of #[ast] nonterminals record where they start \
+                             and end, so the `extern` section must declare a `type {}`",
+                            LOCATION);
+            }
+        }
+    }
+
+    let prefix = grammar.prefix.clone();
+    for item in &mut grammar.items {
+        if let GrammarItem::Nonterminal(ref mut data) = *item {
+            if is_ast(data) {
+                try!(expand_nonterminal(&prefix, &ast_names, text, data));
+            }
+        }
+    }
+    Ok(grammar)
+}
+
+fn expand_nonterminal(prefix: &str,
+                      ast_names: &Set<NonterminalString>,
+                      text: bool,
+                      data: &mut NonterminalData)
+                      -> NormResult<()> {
+    if data.type_decl.is_some() {
+        return_err!(data.span,
+                    "#[ast] nonterminal `{}` cannot declare a type, as LALRPOP declares it",
+                    data.name);
+    }
+
+    let is_struct = data.alternatives.len() == 1 &&
+                    data.alternatives.iter().all(|alternative| variant(alternative).is_none());
+    let mut variants = set();
+    for alternative in &mut data.alternatives {
+        let path = if is_struct {
+            data.name.to_string()
+        } else {
+            match variant(alternative) {
+                Some((span, name)) => {
+                    if !read(|interner| is_identifier(interner.data(name))) {
+                        return_err!(span, "`{}` is not a valid variant name", name);
+                    }
+                    if !variants.insert(name) {
+                        return_err!(span, "duplicate variant `{}`", name);
+                    }
+                    format!("{}::{}", data.name, name)
+                }
+                None => {
+                    return_err!(alternative.span,
+                                "the alternatives of `{}` build the variants of an enum, \
+                                 so each one needs a name, like `#[variant = \"Name\"]`",
+                                data.name);
+                }
+            }
+        };
+
+        if alternative.action.is_some() {
+            return_err!(alternative.span,
+                        "the alternatives of #[ast] nonterminals cannot have actions, \
+                         as their values are built from their named symbols");
+        }
+
+        let mut fields = vec![format!("start: {}start", prefix), format!("end: {}end", prefix)];
+        if text {
+            fields.push(format!("text: &input[{p}start..{p}end]", p = prefix));
+        }
+        for symbol in &alternative.expr.symbols {
+            match symbol.kind {
+                SymbolKind::Name(name, ref named) => {
+                    if is_reserved(name, text) {
+                        return_err!(symbol.span,
+                                    "`{}` cannot name a field of `{}`, as every node \
+                                     has a field by that name",
+                                    name,
+                                    data.name);
+                    }
+                    let boxed = match named.kind {
+                        SymbolKind::Nonterminal(nt) => ast_names.contains(&nt),
+                        _ => false,
+                    };
+                    if boxed {
+                        fields.push(format!("{}: ::std::boxed::Box::new({})", name, name));
+                    } else {
+                        fields.push(format!("{}: {}", name, name));
+                    }
+                }
+                SymbolKind::Choose(_) => {
+                    return_err!(symbol.span,
+                                "the symbols of #[ast] alternatives become fields, so \
+                                 they need names, like `<value:X>`");
+                }
+                _ => { }
+            }
+        }
+
+        let span = alternative.span;
+        let located = |name: &str, kind: SymbolKind| {
+            Symbol {
+                span: span,
+                kind: SymbolKind::Name(intern(&format!("{}{}", prefix, name)),
+                                       Box::new(Symbol { span: span, kind: kind })),
+            }
+        };
+        alternative.expr.symbols.insert(0, located("start", SymbolKind::Lookahead));
+        alternative.expr.symbols.push(located("end", SymbolKind::Lookbehind));
+        alternative.action = Some(ActionKind::User(format!("{} {{ {} }}",
+                                                           path,
+                                                           fields.join(", "))));
+    }
+
+    data.type_decl = Some(TypeRef::Nominal {
+        path: Path::from_id(data.name.0),
+        types: vec![],
+    });
+    Ok(())
+}
+
+/// Gives the types of `#[ast]` nonterminals the type parameters of the
+/// grammar that their fields use (including through the types of
+/// other `#[ast]` nonterminals), and infers the types again if that
+/// changed any of them.
+pub fn add_type_parameters(mut grammar: Grammar,
+                           types: r::Types)
+                           -> NormResult<(Grammar, r::Types)> {
+    let text = has_text(&grammar);
+    let ast_names: Vec<NonterminalString> =
+        grammar.items
+               .iter()
+               .filter_map(|item| item.as_nonterminal())
+               .filter(|data| is_ast(data))
+               .map(|data| data.name)
+               .collect();
+
+    // the type parameters that the fields of each type use, and the
+    // other types they refer to
+    let mut used: Map<NonterminalString, Set<TypeParameter>> = map();
+    let mut references: Map<NonterminalString, Set<NonterminalString>> = map();
+    for data in grammar.items.iter().filter_map(|item| item.as_nonterminal()) {
+        if !is_ast(data) {
+            continue;
+        }
+        let mut parameters = set();
+        let mut names = set();
+        for variant in variants(&grammar.prefix, data, &ast_names, &types, text) {
+            for &(_, ref ty) in &variant.fields {
+                for parameter in ty.referenced() {
+                    if grammar.type_parameters.contains(&parameter) {
+                        parameters.insert(parameter);
+                    } else if let TypeParameter::Id(id) = parameter {
+                        if ast_names.contains(&NonterminalString(id)) {
+                            names.insert(NonterminalString(id));
+                        }
+                    }
+                }
+            }
+        }
+        used.insert(data.name, parameters);
+        references.insert(data.name, names);
+    }
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for name in &ast_names {
+            let inherited: Vec<TypeParameter> =
+                references[name].iter()
+                                .flat_map(|other| used[other].iter().cloned())
+                                .collect();
+            let parameters = used.get_mut(name).unwrap();
+            for parameter in inherited {
+                changed |= parameters.insert(parameter);
+            }
+        }
+    }
+
+    if used.values().all(|parameters| parameters.is_empty()) {
+        return Ok((grammar, types));
+    }
+
+    let type_parameters = grammar.type_parameters.clone();
+    for item in &mut grammar.items {
+        if let GrammarItem::Nonterminal(ref mut data) = *item {
+            if let Some(parameters) = used.get(&data.name) {
+                let types = type_parameters.iter()
+                                           .filter(|p| parameters.contains(p))
+                                           .map(|p| match *p {
+                                               TypeParameter::Lifetime(l) => TypeRef::Lifetime(l),
+                                               TypeParameter::Id(id) => TypeRef::Id(id),
+                                           })
+                                           .collect();
+                data.type_decl = Some(TypeRef::Nominal {
+                    path: Path::from_id(data.name.0),
+                    types: types,
+                });
+            }
+        }
+    }
+
+    let types = try!(tyinfer::infer_types(&grammar));
+    Ok((grammar, types))
+}
+
+/// The types to declare for the `#[ast]` nonterminals; part of
+/// lowering.
+pub fn ast_types(grammar: &Grammar, types: &r::Types) -> Vec<r::AstType> {
+    let text = has_text(grammar);
+    let derives = grammar.module_attributes
+                         .iter()
+                         .filter_map(|a| attribute_arguments(a, AST_DERIVE))
+                         .last()
+                         .map(|list| split_list(&list))
+                         .unwrap_or_else(|| split_list(DEFAULT_DERIVES));
+    let attributes: Vec<String> = grammar.module_attributes
+                                         .iter()
+                                         .filter_map(|a| attribute_arguments(a, AST_ATTR))
+                                         .collect();
+    let ast_names: Vec<NonterminalString> =
+        grammar.items
+               .iter()
+               .filter_map(|item| item.as_nonterminal())
+               .filter(|data| is_ast(data))
+               .map(|data| data.name)
+               .collect();
+    grammar.items
+           .iter()
+           .filter_map(|item| item.as_nonterminal())
+           .filter(|data| is_ast(data))
+           .map(|data| {
+               let ty = types.nonterminal_type(data.name).clone();
+               let referenced = ty.referenced();
+               let derives = match annotation_arg(data, AST_DERIVE) {
+                   Some(list) => split_list(&list),
+                   None => derives.clone(),
+               };
+               let mut attributes = attributes.clone();
+               attributes.extend(annotation_arg(data, AST_ATTR).map(|a| unescape(&a)));
+               r::AstType {
+                   nonterminal: data.name,
+                   type_parameters: grammar.type_parameters
+                                           .iter()
+                                           .filter(|p| referenced.contains(p))
+                                           .cloned()
+                                           .collect(),
+                   ty: ty,
+                   loc_type: types.terminal_loc_type(),
+                   derives: derives,
+                   attributes: attributes,
+                   variants: variants(&grammar.prefix, data, &ast_names, types, text),
+               }
+           })
+           .collect()
+}
+
+/// The fields of the values that the (expanded) alternatives of `data`
+/// build.
+fn variants(prefix: &str,
+            data: &NonterminalData,
+            ast_names: &[NonterminalString],
+            types: &r::Types,
+            text: bool)
+            -> Vec<r::AstVariant> {
+    let start = intern(&format!("{}start", prefix));
+    let end = intern(&format!("{}end", prefix));
+    data.alternatives
+        .iter()
+        .map(|alternative| {
+            let mut fields = vec![(intern("start"), types.terminal_loc_type()),
+                                  (intern("end"), types.terminal_loc_type())];
+            if text {
+                fields.push((intern("text"), r::TypeRepr::Ref {
+                    lifetime: Some(intern(INPUT_LIFETIME)),
+                    mutable: false,
+                    referent: Box::new(r::TypeRepr::str()),
+                }));
+            }
+            for symbol in &alternative.expr.symbols {
+                if let SymbolKind::Name(name, ref named) = symbol.kind {
+                    if name == start || name == end {
+                        continue;
+                    }
+                    let ty = match named.kind {
+                        SymbolKind::Terminal(id) => types.terminal_type(id).clone(),
+                        SymbolKind::Nonterminal(nt) if ast_names.contains(&nt) => {
+                            r::TypeRepr::boxed(types.nonterminal_type(nt).clone())
+                        }
+                        SymbolKind::Nonterminal(nt) => types.nonterminal_type(nt).clone(),
+                        SymbolKind::Error => types.parse_error_type().clone(),
+                        _ => panic!("symbol `{}` should have been expanded away", named),
+                    };
+                    fields.push((name, ty));
+                }
+            }
+            r::AstVariant {
+                name: variant(alternative).map(|(_, name)| name),
+                fields: fields,
+            }
+        })
+        .collect()
+}
+
+/// The traits that the types derive unless the grammar says otherwise.
+const DEFAULT_DERIVES: &'static str = "Clone, Debug, PartialEq";
+
+fn annotation_arg(data: &NonterminalData, id: &str) -> Option<String> {
+    data.annotations
+        .iter()
+        .find(|a| a.id == intern(id))
+        .and_then(|a| a.arg)
+        .map(|(_, arg)| arg.to_string())
+}
+
+/// The contents of a string literal, whose quotes and backslashes are
+/// escaped.
+fn unescape(literal: &str) -> String {
+    let mut text = String::new();
+    let mut chars = literal.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => text.extend(chars.next()),
+            c => text.push(c),
+        }
+    }
+    text
+}
+
+/// The traits of a list like `Clone, Debug`.
+fn split_list(list: &str) -> Vec<String> {
+    list.split(',')
+        .map(|item| item.trim())
+        .filter(|item| !item.is_empty())
+        .map(|item| item.to_string())
+        .collect()
+}
+
+fn is_ast(data: &NonterminalData) -> bool {
+    data.annotations.iter().any(|a| a.id == intern(AST))
+}
+
+fn has_text(grammar: &Grammar) -> bool {
+    grammar.module_attributes.iter().any(|a| is_attribute(a, AST_TEXT))
+}
+
+fn variant(alternative: &Alternative) -> Option<(Span, InternedString)> {
+    alternative.annotations
+               .iter()
+               .find(|a| a.id == intern(VARIANT))
+               .and_then(|a| a.arg)
+}
+
+fn is_reserved(name: InternedString, text: bool) -> bool {
+    name == intern("start") || name == intern("end") || (text && name == intern("text"))
+}
+
+fn is_identifier(name: &str) -> bool {
+    name.chars().next().map_or(false, |c| c.is_alphabetic() || c == '_') &&
+    name.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
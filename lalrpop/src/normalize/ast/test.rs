@@ -0,0 +1,131 @@
+use grammar::repr::AstType;
+use normalize::normalize_without_validating;
+use parser;
+use test_util::{check_norm_err, normalized_grammar};
+
+fn fields(ast_type: &AstType) -> Vec<(Option<String>, Vec<String>)> {
+    ast_type.variants
+            .iter()
+            .map(|variant| {
+                (variant.name.map(|name| name.to_string()),
+                 variant.fields
+                        .iter()
+                        .map(|&(name, ref ty)| format!("{}: {}", name, ty))
+                        .collect())
+            })
+            .collect()
+}
+
+#[test]
+fn types_and_fields() {
+    let grammar = normalized_grammar(r#"
+grammar;
+    #[ast]
+    pub Expr = {
+        #[variant = "Add"] <left:Expr> "+" <right:Term>,
+        #[variant = "Term"] <term:Term>,
+    };
+    #[ast]
+    Term = <value:Num>;
+    #[ast]
+    Call = <name:"Id"> "(" <args:Num*> ")";
+    Num: i32 = "Num" => 0;
+"#);
+
+    let types: Vec<_> = grammar.ast_types
+                               .iter()
+                               .map(|ast_type| (ast_type.ty.to_string(),
+                                                ast_type.type_parameters.len()))
+                               .collect();
+    assert_eq!(types,
+               vec![(format!("Expr"), 0), (format!("Term"), 0), (format!("Call<'input>"), 1)]);
+
+    assert_eq!(fields(&grammar.ast_types[0]),
+               vec![(Some(format!("Add")),
+                     vec![format!("start: usize"),
+                          format!("end: usize"),
+                          format!("left: ::std::boxed::Box<Expr>"),
+                          format!("right: ::std::boxed::Box<Term>")]),
+                    (Some(format!("Term")),
+                     vec![format!("start: usize"),
+                          format!("end: usize"),
+                          format!("term: ::std::boxed::Box<Term>")])]);
+    assert_eq!(fields(&grammar.ast_types[2]),
+               vec![(None,
+                     vec![format!("start: usize"),
+                          format!("end: usize"),
+                          format!("name: &'input str"),
+                          format!("args: ::std::vec::Vec<i32>")])]);
+}
+
+#[test]
+fn text_and_inherited_lifetimes() {
+    let grammar = normalized_grammar(r#"
+#![ast_text]
+grammar;
+    #[ast]
+    pub Pair = <left:Num> "," <right:Num>;
+    #[ast]
+    Num = "Num";
+"#);
+
+    assert!(grammar.module_attributes.is_empty());
+    assert_eq!(grammar.ast_types[0].ty.to_string(), "Pair<'input>");
+    assert_eq!(fields(&grammar.ast_types[1]),
+               vec![(None,
+                     vec![format!("start: usize"),
+                          format!("end: usize"),
+                          format!("text: &'input str")])]);
+}
+
+fn check_err(expected_err: &str, grammar: &str, span: &str) {
+    let parsed_grammar = parser::parse_grammar(&grammar).unwrap();
+    let err = normalize_without_validating(parsed_grammar).unwrap_err();
+    check_norm_err(expected_err, span, err);
+}
+
+#[test]
+fn unnamed_variant() {
+    check_err(
+        r#"the alternatives of `X` build the variants of an enum"#,
+        r#"grammar; #[ast] X = { #[variant = "A"] "a", "b" };"#,
+        r#"                                            ~~~   "#);
+}
+
+#[test]
+fn action() {
+    check_err(
+        r#"the alternatives of #\[ast\] nonterminals cannot have actions"#,
+        r#"grammar; #[ast] X = <a:"a"> => a;"#,
+        r#"                    ~~~~~~~~~~~~  "#);
+}
+
+#[test]
+fn reserved_field() {
+    check_err(
+        r#"`start` cannot name a field of `X`, as every node has a field by that name"#,
+        r#"grammar; #[ast] X = <start:"a">;"#,
+        r#"                    ~~~~~~~~~~~ "#);
+}
+
+#[test]
+fn derives_and_attributes() {
+    let grammar = normalized_grammar(r#"
+#![ast_derive(Clone, Debug, Serialize)]
+#![ast_attr(serde(tag = "kind"))]
+grammar;
+    #[ast]
+    pub Pair = <left:Num> "," <right:Num>;
+    #[ast]
+    #[ast_derive = "Clone, Copy"]
+    #[ast_attr = "serde(rename = \"number\")"]
+    Num = "Num";
+"#);
+
+    assert!(grammar.module_attributes.is_empty());
+    assert_eq!(grammar.ast_types[0].derives, vec!["Clone", "Debug", "Serialize"]);
+    assert_eq!(grammar.ast_types[0].attributes, vec!["serde(tag = \"kind\")"]);
+    assert_eq!(grammar.ast_types[1].derives, vec!["Clone", "Copy"]);
+    assert_eq!(grammar.ast_types[1].attributes,
+               vec!["serde(tag = \"kind\")", "serde(rename = \"number\")"]);
+}
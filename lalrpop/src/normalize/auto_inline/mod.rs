@@ -0,0 +1,145 @@
+/*!
+ * Automatic inlining of nonterminals (`Configuration::auto_inline`).
+ *
+ * Tries inlining each small, non-recursive, private nonterminal, as
+ * if it had been marked `#[inline]`, and keeps the change if the
+ * parsers then have fewer conflicts or, failing that, fewer states.
+ * Every nonterminal that gets inlined is logged, with the difference
+ * it made.
+ */
+
+use collections::{set, Set};
+use grammar::consts::INLINE;
+use grammar::repr::*;
+use intern::intern;
+use lr1::{build_states, Lr1Tls};
+use normalize::inline_nt;
+use session::Session;
+
+#[cfg(test)]
+mod test;
+
+/// Nonterminals with more alternatives than this, or with a longer
+/// alternative, are not worth trying: inlining them multiplies the
+/// productions of every nonterminal that uses them.
+const MAX_ALTERNATIVES: usize = 4;
+const MAX_SYMBOLS: usize = 3;
+
+/// The number of conflicts and states of all the parsers of a grammar.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Size {
+    conflicts: usize,
+    states: usize,
+}
+
+/// Inlines the nonterminals that make the parsers smaller, and
+/// returns their names in the order they were inlined.
+pub fn auto_inline(session: &Session, grammar: &mut Grammar) -> Vec<NonterminalString> {
+    // Earley parsers have no states to make smaller
+    if grammar.algorithm.codegen == LrCodeGeneration::Earley {
+        return vec![];
+    }
+
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+
+    let mut size = measure(session, grammar);
+    let mut inlined = vec![];
+    for nt in candidates(grammar) {
+        let mut inlined_grammar = grammar.clone();
+        inline_nt(&mut inlined_grammar, nt);
+        let inlined_size = measure(session, &inlined_grammar);
+        if inlined_size >= size {
+            continue;
+        }
+
+        log!(session,
+             Informative,
+             "automatically inlined `{}`: {} to {} states, {} to {} conflicts",
+             nt,
+             size.states,
+             inlined_size.states,
+             size.conflicts,
+             inlined_size.conflicts);
+
+        // the productions of `nt` are left in place, as for
+        // `#[inline]`, so mark it the same way
+        {
+            let data = inlined_grammar.nonterminals.get_mut(&nt).unwrap();
+            let span = data.span;
+            data.annotations.push(Annotation {
+                id_span: span,
+                id: intern(INLINE),
+                arg: None,
+                params: vec![],
+            });
+        }
+
+        *grammar = inlined_grammar;
+        size = inlined_size;
+        inlined.push(nt);
+    }
+    inlined
+}
+
+/// The nonterminals that could be inlined: those that are private,
+/// small, not recursive, and used somewhere.
+fn candidates(grammar: &Grammar) -> Vec<NonterminalString> {
+    let inline = intern(INLINE);
+    grammar.nonterminals
+           .iter()
+           .filter(|&(nt, data)| {
+               !grammar.start_nonterminals.contains_key(nt) &&
+                   !grammar.start_nonterminals.values().any(|s| s == nt) &&
+                   !data.annotations.iter().any(|a| a.id == inline) &&
+                   data.productions.len() <= MAX_ALTERNATIVES &&
+                   data.productions.iter().all(|p| p.symbols.len() <= MAX_SYMBOLS) &&
+                   is_used(grammar, *nt) &&
+                   !is_recursive(grammar, *nt)
+           })
+           .map(|(&nt, _)| nt)
+           .collect()
+}
+
+fn is_used(grammar: &Grammar, nt: NonterminalString) -> bool {
+    let symbol = Symbol::Nonterminal(nt);
+    grammar.nonterminals
+           .values()
+           .filter(|data| data.name != nt)
+           .flat_map(|data| &data.productions)
+           .any(|p| p.symbols.contains(&symbol))
+}
+
+/// True if `nt` can derive a string that contains `nt` again.
+fn is_recursive(grammar: &Grammar, nt: NonterminalString) -> bool {
+    let mut visited: Set<NonterminalString> = set();
+    let mut stack = vec![nt];
+    while let Some(next) = stack.pop() {
+        for production in grammar.productions_for(next) {
+            for symbol in &production.symbols {
+                if let Symbol::Nonterminal(referenced) = *symbol {
+                    if referenced == nt {
+                        return true;
+                    }
+                    if visited.insert(referenced) {
+                        stack.push(referenced);
+                    }
+                }
+            }
+        }
+    }
+    false
+}
+
+fn measure(session: &Session, grammar: &Grammar) -> Size {
+    let mut size = Size { conflicts: 0, states: 0 };
+    for &start_nt in grammar.start_nonterminals.values() {
+        match build_states(session, grammar, start_nt) {
+            Ok(states) => size.states += states.len(),
+            Err(error) => {
+                size.conflicts += error.conflicts.len();
+                size.states += error.states.len();
+            }
+        }
+    }
+    size
+}
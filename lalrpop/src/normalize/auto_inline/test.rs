@@ -0,0 +1,48 @@
+use grammar::consts::INLINE;
+use grammar::parse_tree::NonterminalString;
+use grammar::repr::{Grammar, Symbol};
+use intern::intern;
+use normalize::{self, inline};
+use parser;
+use session::Session;
+
+use super::auto_inline;
+
+fn lowered_grammar(text: &str) -> Grammar {
+    let g = parser::parse_grammar(text).unwrap();
+    let g = normalize::lower_helper(&Session::test(), g, true).unwrap();
+    inline::inline(g).unwrap()
+}
+
+fn nt(t: &str) -> NonterminalString {
+    NonterminalString(intern(t))
+}
+
+#[test]
+fn inlining_removes_conflict() {
+    // without inlining, after `"a"` has been seen we have to decide
+    // whether `Opt` was empty before seeing what follows it
+    let mut grammar = lowered_grammar(r#"
+grammar;
+    pub S: () = { Opt "a" "b" => (), "a" "c" => () };
+    Opt: () = { () => (), "x" => () };
+"#);
+    let inlined = auto_inline(&Session::test(), &mut grammar);
+    assert_eq!(inlined, vec![nt("Opt")]);
+
+    let inline = intern(INLINE);
+    assert!(grammar.nonterminals[&nt("Opt")].annotations.iter().any(|a| a.id == inline));
+    let opt = Symbol::Nonterminal(nt("Opt"));
+    assert!(grammar.productions_for(nt("S")).iter().all(|p| !p.symbols.contains(&opt)));
+}
+
+#[test]
+fn recursive_nonterminals_are_kept() {
+    let mut grammar = lowered_grammar(r#"
+grammar;
+    pub S: () = { "(" L ")" => () };
+    L: () = { L "," "x" => (), "x" => () };
+"#);
+    let inlined = auto_inline(&Session::test(), &mut grammar);
+    assert!(inlined.is_empty());
+}
@@ -0,0 +1,66 @@
+//! Evaluates `#[cfg(...)]` annotations against the settings enabled
+//! with `Configuration::grammar_cfg`. Given:
+//!
+//!     Stmt = {
+//!         Expr ";",
+//!         #[cfg(feature = "ext-syntax")]
+//!         "let" Id "=" Expr ";",
+//!     };
+//!
+//! the second alternative is kept only if `feature = "ext-syntax"` is
+//! enabled. A nonterminal annotated with `#[cfg(...)]` is removed
+//! altogether unless all of its settings are enabled. The annotations
+//! themselves are removed either way.
+
+use grammar::consts::CFG;
+use grammar::parse_tree::*;
+use intern::{intern, read};
+use session::Session;
+
+#[cfg(test)]
+mod test;
+
+pub fn strip(session: &Session, mut grammar: Grammar) -> Grammar {
+    grammar.items.retain(|item| match *item {
+        GrammarItem::Nonterminal(ref data) => enabled(session, &data.annotations),
+        _ => true,
+    });
+
+    for item in &mut grammar.items {
+        if let GrammarItem::Nonterminal(ref mut data) = *item {
+            remove_cfg(&mut data.annotations);
+            data.alternatives.retain(|alternative| enabled(session, &alternative.annotations));
+            for alternative in &mut data.alternatives {
+                remove_cfg(&mut alternative.annotations);
+            }
+        }
+    }
+
+    grammar
+}
+
+/// True if every setting listed by the `#[cfg(...)]` annotations in
+/// `annotations` is enabled.
+fn enabled(session: &Session, annotations: &[Annotation]) -> bool {
+    let cfg = intern(CFG);
+    annotations.iter()
+               .filter(|annotation| annotation.id == cfg)
+               .flat_map(|annotation| &annotation.params)
+               .all(|param| match param.value {
+                   AnnotationValue::String(value) => {
+                       let setting = read(|interner| {
+                           (interner.data(param.key).to_string(),
+                            interner.data(value).to_string())
+                       });
+                       session.grammar_cfgs.contains(&setting)
+                   }
+                   AnnotationValue::Terminals(_) => {
+                       panic!("validation permitted terminals in #[cfg]")
+                   }
+               })
+}
+
+fn remove_cfg(annotations: &mut Vec<Annotation>) {
+    let cfg = intern(CFG);
+    annotations.retain(|annotation| annotation.id != cfg);
+}
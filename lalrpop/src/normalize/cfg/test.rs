@@ -0,0 +1,69 @@
+use grammar::parse_tree::Grammar;
+use parser;
+use session::Session;
+use test_util::compare;
+
+use super::strip;
+
+fn stripped(grammar: &str, settings: &[(&str, &str)]) -> Grammar {
+    let mut session = Session::test();
+    for &(key, value) in settings {
+        session.grammar_cfgs.insert((key.to_string(), value.to_string()));
+    }
+    strip(&session, parser::parse_grammar(grammar).unwrap())
+}
+
+const GRAMMAR: &'static str = r#"
+grammar;
+    pub Stmt = {
+        Expr ";",
+        #[cfg(feature = "let")]
+        "let" "Id" "=" Expr ";",
+        #[cfg(feature = "let", edition = "2")]
+        "let" "mut" "Id" "=" Expr ";",
+    };
+    Expr = "Id";
+    #[cfg(feature = "let")]
+    Unused = "let";
+"#;
+
+#[test]
+fn disabled() {
+    compare(stripped(GRAMMAR, &[]),
+            parser::parse_grammar(r#"
+grammar;
+    pub Stmt = {
+        Expr ";",
+    };
+    Expr = "Id";
+"#).unwrap());
+}
+
+#[test]
+fn partially_enabled() {
+    compare(stripped(GRAMMAR, &[("feature", "let")]),
+            parser::parse_grammar(r#"
+grammar;
+    pub Stmt = {
+        Expr ";",
+        "let" "Id" "=" Expr ";",
+    };
+    Expr = "Id";
+    Unused = "let";
+"#).unwrap());
+}
+
+#[test]
+fn enabled() {
+    compare(stripped(GRAMMAR, &[("feature", "let"), ("edition", "2")]),
+            parser::parse_grammar(r#"
+grammar;
+    pub Stmt = {
+        Expr ";",
+        "let" "Id" "=" Expr ";",
+        "let" "mut" "Id" "=" Expr ";",
+    };
+    Expr = "Id";
+    Unused = "let";
+"#).unwrap());
+}
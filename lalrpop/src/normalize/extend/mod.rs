@@ -0,0 +1,324 @@
+//! Stitches a grammar declared with `extend "base.lalrpop";` together
+//! with the grammar it extends. Given the base grammar:
+//!
+//!     grammar;
+//!     pub Query = "select" Columns;
+//!     Columns = { "*", Id };
+//!
+//! and the dialect:
+//!
+//!     grammar;
+//!     extend "base.lalrpop";
+//!     Columns = { "*", "distinct" Id, Id };
+//!
+//! we generate:
+//!
+//!     grammar;
+//!     pub Query = "select" Columns;
+//!     Columns = { "*", "distinct" Id, Id };
+//!
+//! A nonterminal (or macro) of the dialect replaces the one of the
+//! base with the same name, and any other one is added. The token
+//! declarations (`extern`, `match`) of the dialect replace those of
+//! the base, if it has any. The `use` statements and module attributes
+//! of both are kept, while the grammar header (parameters, where
+//! clauses and annotations) is the one of the dialect.
+//!
+//! The nonterminals of the base can be renamed first, so that they do
+//! not collide with those of the dialect, as in `extend "base.lalrpop"
+//! { Columns as BaseColumns };`. And if the base is given a namespace,
+//! as in `extend "base.lalrpop" as base;`, the dialect can refer to
+//! the definition of the base even when it overrides it:
+//!
+//!     Columns = { base::Columns, "distinct" Id };
+//!
+//! The definition of the base is then kept as the private nonterminal
+//! `base::Columns`; its references to other nonterminals, including
+//! `Columns`, are to those of the dialect.
+
+use super::{NormResult, NormError};
+
+use collections::{map, Map, Set};
+use grammar::parse_tree::*;
+use intern::{intern, read, InternedString};
+
+#[cfg(test)]
+mod test;
+
+pub fn stitch(base: Grammar, derived: Grammar) -> NormResult<Grammar> {
+    let (namespace, renames) = match derived.extends {
+        Some(ref extends) => (extends.namespace, extends.renames.clone()),
+        None => (None, vec![]),
+    };
+    let mut base = try!(rename(base, &renames));
+    let mut derived = derived;
+
+    let overridden: Set<NonterminalString> = derived.items
+                                                    .iter()
+                                                    .filter_map(|item| item.as_nonterminal())
+                                                    .map(|data| data.name)
+                                                    .collect();
+
+    // the overridden nonterminals of the base that the dialect refers
+    // to with `base::X`, and so that have to be kept
+    let mut kept = Set::new();
+    if let Some(namespace) = namespace {
+        let defined: Set<NonterminalString> = base.items
+                                                  .iter()
+                                                  .filter_map(|item| item.as_nonterminal())
+                                                  .map(|data| data.name)
+                                                  .collect();
+        let renames: Map<NonterminalString, NonterminalString> = renames.iter()
+                                                                        .map(|r| (r.from, r.to))
+                                                                        .collect();
+        let prefix = read(|interner| format!("{}::", interner.data(namespace)));
+        for item in &mut derived.items {
+            if let GrammarItem::Nonterminal(ref mut data) = *item {
+                for alternative in &mut data.alternatives {
+                    for symbol in &mut alternative.expr.symbols {
+                        try!(qualify(symbol, &prefix, &defined, &overridden, &renames, &mut kept));
+                    }
+                }
+            }
+        }
+    }
+
+    let derived_has_tokens = derived.items.iter().any(is_token_item);
+
+    // each nonterminal of the dialect takes the place of the one it
+    // overrides, and its token declarations those of the base, so
+    // that the order of the base is kept
+    let mut derived_items: Vec<Option<GrammarItem>> = derived.items
+                                                             .into_iter()
+                                                             .map(Some)
+                                                             .collect();
+    let mut uses = vec![];
+    let mut items = vec![];
+    for item in base.items.drain(..) {
+        match item {
+            GrammarItem::Use(u) => add_unique(&mut uses, u),
+            GrammarItem::Nonterminal(ref data) if overridden.contains(&data.name) => {
+                let name = data.name;
+                let index = derived_items.iter()
+                                         .position(|item| match *item {
+                                             Some(GrammarItem::Nonterminal(ref d)) => d.name == name,
+                                             _ => false,
+                                         })
+                                         .unwrap();
+                items.push(derived_items[index].take().unwrap());
+                if kept.contains(&name) {
+                    let mut data = data.clone();
+                    data.public = false;
+                    data.name = qualified(namespace.unwrap(), name);
+                    items.push(GrammarItem::Nonterminal(data));
+                }
+            }
+            ref item if derived_has_tokens && is_token_item(item) => {
+                for derived_item in &mut derived_items {
+                    if derived_item.as_ref().map_or(false, is_token_item) {
+                        items.push(derived_item.take().unwrap());
+                    }
+                }
+            }
+            item => items.push(item),
+        }
+    }
+    for item in derived_items.into_iter().filter_map(|item| item) {
+        match item {
+            GrammarItem::Use(u) => add_unique(&mut uses, u),
+            item => items.push(item),
+        }
+    }
+
+    let mut module_attributes = base.module_attributes;
+    for attribute in derived.module_attributes {
+        add_unique(&mut module_attributes, attribute);
+    }
+
+    Ok(Grammar {
+        // the prefixes do not appear in their own grammars, so the
+        // longer one appears in neither
+        prefix: if base.prefix.len() > derived.prefix.len() {
+            base.prefix
+        } else {
+            derived.prefix
+        },
+        span: derived.span,
+        type_parameters: derived.type_parameters,
+        parameters: derived.parameters,
+        where_clauses: derived.where_clauses,
+        items: uses.into_iter().map(GrammarItem::Use).chain(items).collect(),
+        annotations: derived.annotations,
+        module_attributes: module_attributes,
+        extends: None,
+        token_stream: None,
+    })
+}
+
+/// Applies the renames of `extend "..." { X as Y }` to the base
+/// grammar, both to the definitions and the references.
+fn rename(mut base: Grammar, renames: &[Rename]) -> NormResult<Grammar> {
+    let defined: Set<NonterminalString> = base.items
+                                              .iter()
+                                              .filter_map(|item| item.as_nonterminal())
+                                              .map(|data| data.name)
+                                              .collect();
+    let mut map = map();
+    for rename in renames {
+        if !defined.contains(&rename.from) {
+            return_err!(rename.span,
+                        "the extended grammar does not define `{}`",
+                        rename.from);
+        }
+        if defined.contains(&rename.to) {
+            return_err!(rename.span,
+                        "the extended grammar already defines `{}`",
+                        rename.to);
+        }
+        map.insert(rename.from, rename.to);
+    }
+    if map.is_empty() {
+        return Ok(base);
+    }
+
+    for item in &mut base.items {
+        if let GrammarItem::Nonterminal(ref mut data) = *item {
+            if let Some(&to) = map.get(&data.name) {
+                data.name = to;
+            }
+            for alternative in &mut data.alternatives {
+                for symbol in &mut alternative.expr.symbols {
+                    rename_symbol(symbol, &map, &data.args);
+                }
+            }
+        }
+    }
+    Ok(base)
+}
+
+/// Renames the references in `symbol` according to `map`, except for
+/// those to the arguments `args` of the macro it is in.
+fn rename_symbol(symbol: &mut Symbol,
+                 map: &Map<NonterminalString, NonterminalString>,
+                 args: &[NonterminalString]) {
+    match symbol.kind {
+        SymbolKind::AmbiguousId(ref mut id) => {
+            let name = NonterminalString(*id);
+            if let Some(&to) = map.get(&name) {
+                if !args.contains(&name) {
+                    *id = to.0;
+                }
+            }
+        }
+        SymbolKind::Nonterminal(ref mut name) => {
+            if let Some(&to) = map.get(name) {
+                *name = to;
+            }
+        }
+        SymbolKind::Macro(ref mut msym) => {
+            if let Some(&to) = map.get(&msym.name) {
+                msym.name = to;
+            }
+            for arg in &mut msym.args {
+                rename_symbol(arg, map, args);
+            }
+        }
+        SymbolKind::Expr(ref mut expr) => {
+            for symbol in &mut expr.symbols {
+                rename_symbol(symbol, map, args);
+            }
+        }
+        SymbolKind::Repeat(ref mut repeat) => rename_symbol(&mut repeat.symbol, map, args),
+        SymbolKind::Choose(ref mut symbol) |
+        SymbolKind::Name(_, ref mut symbol) => rename_symbol(symbol, map, args),
+        SymbolKind::Terminal(_) |
+        SymbolKind::Lookahead |
+        SymbolKind::Lookbehind |
+        SymbolKind::Error => {}
+    }
+}
+
+/// Resolves the references like `base::X` in `symbol`, where `prefix`
+/// is `base::`: they become references to `X`, or to `base::X` if the
+/// dialect overrides `X`, in which case `X` is added to `kept`.
+fn qualify(symbol: &mut Symbol,
+           prefix: &str,
+           defined: &Set<NonterminalString>,
+           overridden: &Set<NonterminalString>,
+           renames: &Map<NonterminalString, NonterminalString>,
+           kept: &mut Set<NonterminalString>)
+           -> NormResult<()> {
+    match symbol.kind {
+        SymbolKind::AmbiguousId(ref mut id) => {
+            let unqualified = read(|interner| {
+                let id = interner.data(*id);
+                if id.starts_with(prefix) {
+                    Some(id[prefix.len()..].to_string())
+                } else {
+                    None
+                }
+            });
+            if let Some(unqualified) = unqualified {
+                let name = NonterminalString(intern(&unqualified));
+                let name = *renames.get(&name).unwrap_or(&name);
+                if !defined.contains(&name) {
+                    return_err!(symbol.span,
+                                "the extended grammar does not define `{}`",
+                                unqualified);
+                }
+                if overridden.contains(&name) {
+                    kept.insert(name);
+                    *id = intern(&format!("{}{}", prefix, name));
+                } else {
+                    *id = name.0;
+                }
+            }
+        }
+        SymbolKind::Macro(ref mut msym) => {
+            for arg in &mut msym.args {
+                try!(qualify(arg, prefix, defined, overridden, renames, kept));
+            }
+        }
+        SymbolKind::Expr(ref mut expr) => {
+            for symbol in &mut expr.symbols {
+                try!(qualify(symbol, prefix, defined, overridden, renames, kept));
+            }
+        }
+        SymbolKind::Repeat(ref mut repeat) => {
+            try!(qualify(&mut repeat.symbol, prefix, defined, overridden, renames, kept));
+        }
+        SymbolKind::Choose(ref mut symbol) |
+        SymbolKind::Name(_, ref mut symbol) => {
+            try!(qualify(symbol, prefix, defined, overridden, renames, kept));
+        }
+        SymbolKind::Nonterminal(_) |
+        SymbolKind::Terminal(_) |
+        SymbolKind::Lookahead |
+        SymbolKind::Lookbehind |
+        SymbolKind::Error => {}
+    }
+    Ok(())
+}
+
+fn qualified(namespace: InternedString, name: NonterminalString) -> NonterminalString {
+    let namespace = read(|interner| interner.data(namespace).to_string());
+    NonterminalString(intern(&format!("{}::{}", namespace, name)))
+}
+
+/// Adds `item` to `items` (the `use` statements or module attributes
+/// of the grammar) unless it is already there.
+fn add_unique(items: &mut Vec<String>, item: String) {
+    if !items.contains(&item) {
+        items.push(item);
+    }
+}
+
+fn is_token_item(item: &GrammarItem) -> bool {
+    match *item {
+        GrammarItem::MatchToken(..) |
+        GrammarItem::ExternToken(..) |
+        GrammarItem::InternToken(..) => true,
+        GrammarItem::Nonterminal(..) |
+        GrammarItem::Use(..) => false,
+    }
+}
@@ -0,0 +1,163 @@
+use grammar::parse_tree::Grammar;
+use normalize::NormResult;
+use parser;
+use test_util::{check_norm_err, compare};
+use util::Sep;
+
+use super::stitch;
+
+fn try_stitch(base: &str, derived: &str) -> NormResult<Grammar> {
+    let base = parser::parse_grammar(base).unwrap();
+    let derived = parser::parse_grammar(derived).unwrap();
+    stitch(base, derived)
+}
+
+/// The public flag, name and alternatives of each nonterminal.
+fn nonterminals(grammar: &Grammar) -> Vec<(bool, String, Vec<String>)> {
+    grammar.items
+           .iter()
+           .filter_map(|item| item.as_nonterminal())
+           .map(|data| {
+               let alternatives = data.alternatives
+                                      .iter()
+                                      .map(|a| format!("{}", Sep(" ", &a.expr.symbols)))
+                                      .collect();
+               (data.public, format!("{}", data.name), alternatives)
+           })
+           .collect()
+}
+
+fn stitched(base: &str, derived: &str) -> String {
+    format!("{:?}", try_stitch(base, derived).unwrap())
+}
+
+fn parsed(grammar: &str) -> String {
+    format!("{:?}", parser::parse_grammar(grammar).unwrap())
+}
+
+#[test]
+fn override_and_add_nonterminals() {
+    compare(stitched(r#"
+grammar;
+    pub Query = "select" Columns From?;
+    Columns = { "*", Id };
+    From = "from" Id;
+    Id = r"[a-z]+";
+"#, r#"
+grammar;
+    extend "base.lalrpop";
+    Columns = { "*", "distinct" Id, Id };
+    pub Statement = { Query, "show" Id };
+"#),
+            parsed(r#"
+grammar;
+    pub Query = "select" Columns From?;
+    Columns = { "*", "distinct" Id, Id };
+    From = "from" Id;
+    Id = r"[a-z]+";
+    pub Statement = { Query, "show" Id };
+"#));
+}
+
+#[test]
+fn token_declarations_are_replaced() {
+    compare(stitched(r#"
+use tok::Tok;
+grammar;
+    extern { enum Tok { "x" => Tok::X } }
+    pub X = "x";
+"#, r#"
+use tok::Tok;
+use std::str::FromStr;
+grammar(scale: i32);
+    extend "base.lalrpop";
+    extern { enum Tok { "x" => Tok::X, "y" => Tok::Y } }
+    pub Y = { X, "y" };
+"#),
+            parsed(r#"
+use tok::Tok;
+use std::str::FromStr;
+grammar(scale: i32);
+    extern { enum Tok { "x" => Tok::X, "y" => Tok::Y } }
+    pub X = "x";
+    pub Y = { X, "y" };
+"#));
+}
+
+#[test]
+fn base_tokens_are_inherited() {
+    compare(stitched(r#"
+grammar;
+    match { "x" } else { _ }
+    pub X = "x";
+"#, r#"
+grammar;
+    extend "base.lalrpop";
+    pub X = "x" "x";
+"#),
+            parsed(r#"
+grammar;
+    match { "x" } else { _ }
+    pub X = "x" "x";
+"#));
+}
+
+#[test]
+fn namespace_refers_to_overridden_definition() {
+    let grammar = try_stitch(r#"
+grammar;
+    pub Query = "select" Columns;
+    Columns = { "*", Id };
+    Id = r"[a-z]+";
+"#, r#"
+grammar;
+    extend "base.lalrpop" as base;
+    Columns = { base::Columns, "distinct" base::Id };
+"#).unwrap();
+
+    assert_eq!(nonterminals(&grammar),
+               vec![(true, format!("Query"), vec![format!("\"select\" Columns")]),
+                    (false, format!("Columns"), vec![format!("base::Columns"),
+                                                     format!("\"distinct\" Id")]),
+                    (false, format!("base::Columns"), vec![format!("\"*\""), format!("Id")]),
+                    (false, format!("Id"), vec![format!("r#\"[a-z]+\"#")])]);
+}
+
+#[test]
+fn rename_base_nonterminals() {
+    compare(stitched(r#"
+grammar;
+    pub Expr = { Term, Expr "+" Term };
+    Term = Comma<Term>;
+    Comma<Term> = Term ",";
+"#, r#"
+grammar;
+    extend "base.lalrpop" as base { Expr as BaseExpr, Term as Atom };
+    pub Expr = { base::Expr, "-" };
+"#),
+            parsed(r#"
+grammar;
+    pub BaseExpr = { Atom, BaseExpr "+" Atom };
+    Atom = Comma<Atom>;
+    Comma<Term> = Term ",";
+    pub Expr = { BaseExpr, "-" };
+"#));
+}
+
+#[test]
+fn rename_undefined() {
+    check_norm_err(
+        r#"the extended grammar does not define `Stmt`"#,
+        r#"                              ~~~~~~~~~                  "#,
+        try_stitch(r#"grammar; X = "x";"#,
+                   r#"grammar; extend "b.lalrpop" { Stmt as S }; Y = X;"#).unwrap_err());
+}
+
+#[test]
+fn namespace_undefined() {
+    check_norm_err(
+        r#"the extended grammar does not define `Y`"#,
+        r#"                                      ~~~~ "#,
+        try_stitch(r#"grammar; X = "x";"#,
+                   r#"grammar; extend "b.lalrpop" as b; X = b::Y;"#).unwrap_err());
+}
@@ -18,7 +18,9 @@ pub fn inline(mut grammar: Grammar) -> NormResult<Grammar> {
     Ok(grammar)
 }
 
-fn inline_nt(grammar: &mut Grammar, inline_nt: NonterminalString) {
+/// Inlines `inline_nt` into every production that references it.
+/// The productions of `inline_nt` itself are left in place.
+pub fn inline_nt(grammar: &mut Grammar, inline_nt: NonterminalString) {
     let inline_productions: Vec<_> = grammar.productions_for(inline_nt).iter().cloned().collect();
     for (_, data) in &mut grammar.nonterminals {
         let mut new_productions = vec![];
@@ -113,6 +115,8 @@ impl<'a> Inliner<'a> {
                 span: self.into_production.span,
                 symbols: prod_symbols,
                 action: action_fn,
+                dprec: self.into_production.dprec,
+                merge: self.into_production.merge,
             });
         } else {
             let next_symbol = into_symbols[0];
@@ -2,13 +2,16 @@
 //!
 
 use intern::{self, intern, InternedString};
-use normalize::NormResult;
+use normalize::{NormError, NormResult};
 use normalize::norm_util::{self, Symbols};
+use normalize::{ast, unparse};
 use grammar::consts::*;
 use grammar::pattern::{Pattern, PatternKind};
 use grammar::parse_tree as pt;
 use grammar::parse_tree::{InternToken, NonterminalString, TerminalString, Path, read_algorithm};
 use grammar::repr as r;
+use lalrpop_util::Version;
+use lint;
 use session::Session;
 use collections::{map, Map};
 
@@ -26,6 +29,7 @@ struct LowerState<'s> {
     intern_token: Option<InternToken>,
     types: r::Types,
     uses_error_recovery: bool,
+    uses_since: bool,
 }
 
 impl<'s> LowerState<'s> {
@@ -39,14 +43,21 @@ impl<'s> LowerState<'s> {
             types: types,
             intern_token: None,
             uses_error_recovery: false,
+            uses_since: false,
         }
     }
 
     fn lower(mut self, grammar: pt::Grammar) -> NormResult<r::Grammar> {
         let start_symbols = self.synthesize_start_symbols(&grammar);
+        let unparsers = try!(unparse::unparsers(&grammar, &self.types));
+        let ast_types = ast::ast_types(&grammar, &self.types);
 
         let mut uses = vec![];
         let mut token_span = None;
+        let mut conversion_guards = map();
+        let mut conversion_fns = map();
+        let mut token_normalizer = None;
+        let mut guard_span = None;
         let internal_token_path = Path {
             absolute: false,
             ids: vec![intern("Token")],
@@ -58,10 +69,12 @@ impl<'s> LowerState<'s> {
                     uses.push(data);
                 }
 
-                pt::GrammarItem::MatchToken(_) => {
+                pt::GrammarItem::MatchToken(data) => {
                     // The declarations in the match token are handled
                     // fully by the `token_check` when it constructs the
-                    //  `InternToken` -- there is nothing left to do here.
+                    //  `InternToken` -- there is nothing left to do here
+                    // but the annotations.
+                    token_normalizer = normalize(&data.annotations);
                 }
 
                 pt::GrammarItem::InternToken(data) => {
@@ -96,10 +109,22 @@ impl<'s> LowerState<'s> {
 
                                 (match_entry.user_name, pattern)
                             }));
+                    for match_entry in &data.match_entries {
+                        if match_entry.unescape {
+                            conversion_fns.insert(match_entry.user_name,
+                                                  format!("{}lalrpop_util::unescape::unescape",
+                                                          self.prefix));
+                        } else if let Some(ref hook) = match_entry.hook {
+                            conversion_fns.insert(match_entry.user_name, hook.to_string());
+                        }
+                    }
                     self.intern_token = Some(data);
                 }
 
                 pt::GrammarItem::ExternToken(data) => {
+                    if data.enum_token.is_some() {
+                        token_normalizer = normalize(&data.annotations);
+                    }
                     if let Some(enum_token) = data.enum_token {
                         token_span = Some(enum_token.type_span);
                         self.conversions.extend(enum_token.conversions
@@ -110,11 +135,18 @@ impl<'s> LowerState<'s> {
                                                                   t.type_repr()
                                                               }))
                                                           }));
+                        for conversion in &enum_token.conversions {
+                            if let Some(ref guard) = conversion.guard {
+                                conversion_guards.insert(conversion.from, guard.clone());
+                                guard_span = guard_span.or(Some(conversion.span));
+                            }
+                        }
                     }
                 }
 
                 pt::GrammarItem::Nonterminal(nt) => {
                     let nt_name = nt.name;
+                    let boxed = nt.annotations.iter().any(|a| a.id == intern(BOXED));
                     let productions: Vec<_> = nt.alternatives
                                                 .into_iter()
                                                 .map(|alt| {
@@ -122,15 +154,27 @@ impl<'s> LowerState<'s> {
                                                                       .nonterminal_type(nt_name)
                                                                       .clone();
                                                     let symbols = self.symbols(&alt.expr.symbols);
+                                                    let since = since(&alt.annotations);
                                                     let action = self.action_kind(nt_type,
                                                                                   &alt.expr,
                                                                                   &symbols,
-                                                                                  alt.action);
+                                                                                  alt.span,
+                                                                                  alt.guard,
+                                                                                  since,
+                                                                                  if boxed {
+                                                                                      boxed_action(alt.action)
+                                                                                  } else {
+                                                                                      alt.action
+                                                                                  });
+                                                    let (dprec, merge) =
+                                                        disambiguation(&alt.annotations);
                                                     r::Production {
                                                         nonterminal: nt_name,
                                                         span: alt.span,
                                                         symbols: symbols,
                                                         action: action,
+                                                        dprec: dprec,
+                                                        merge: merge,
                                                     }
                                                 })
                                                 .collect();
@@ -145,15 +189,31 @@ impl<'s> LowerState<'s> {
             }
         }
 
-        let parameters = grammar.parameters
-                                .iter()
-                                .map(|p| {
-                                    r::Parameter {
-                                        name: p.name,
-                                        ty: p.ty.type_repr(),
-                                    }
-                                })
-                                .collect();
+        let mut parameters: Vec<_> = grammar.parameters
+                                            .iter()
+                                            .map(|p| {
+                                                r::Parameter {
+                                                    name: p.name,
+                                                    ty: p.ty.type_repr(),
+                                                }
+                                            })
+                                            .collect();
+
+        // if any alternative is marked `#[since]`, the parser takes
+        // the version to parse as its first parameter
+        if self.uses_since {
+            parameters.insert(0, r::Parameter {
+                name: intern(&format!("{}version", self.prefix)),
+                ty: r::TypeRepr::Nominal(r::NominalTypeRepr {
+                    path: Path {
+                        absolute: false,
+                        ids: vec![intern(&format!("{}lalrpop_util", self.prefix)),
+                                  intern("Version")],
+                    },
+                    types: vec![],
+                }),
+            });
+        }
 
         let where_clauses = grammar.where_clauses
                                    .iter()
@@ -162,14 +222,208 @@ impl<'s> LowerState<'s> {
 
         let mut algorithm = r::Algorithm::default();
 
-        // FIXME Error recovery only works for parse tables so temporarily only generate parse tables for
-        // testing
-        if self.session.unit_test && !self.uses_error_recovery {
+        let mut module_attributes = grammar.module_attributes;
+        let repair = match module_attributes.iter().position(|a| is_attribute(a, "repair")) {
+            Some(index) => {
+                module_attributes.remove(index);
+                true
+            }
+            None => false,
+        };
+        if let Some(index) = module_attributes.iter().position(|a| is_attribute(a, AST_TEXT)) {
+            module_attributes.remove(index);
+        }
+        module_attributes.retain(|a| {
+            attribute_arguments(a, AST_DERIVE).is_none() && attribute_arguments(a, AST_ATTR).is_none()
+        });
+
+        let nesting_span = self.nonterminals
+                               .values()
+                               .filter(|nt| nt.max_nesting().is_some())
+                               .map(|nt| nt.span)
+                               .next();
+        let lookahead_span = self.nonterminals
+                                 .values()
+                                 .filter(|nt| nt.two_token_lookahead())
+                                 .map(|nt| nt.span)
+                                 .next();
+        let contextual = self.intern_token
+                             .as_ref()
+                             .map_or(false, |data| !data.contextual_keywords.is_empty());
+
+        // FIXME Error recovery, error repair, depth, nesting and
+        // reduction limits, panic-free parsers, fixed-size stacks,
+        // token guards, two-token lookahead, contextual keywords,
+        // profiles and token arrays only work for parse tables so
+        // temporarily only generate parse tables for testing
+        //
+        // The tokens of `#![token_stream]` grammars cannot be compared,
+        // as the test-all parsers do with their results.
+        if self.session.unit_test && !self.uses_error_recovery && !repair &&
+           self.session.max_depth.is_none() && self.session.max_reductions.is_none() &&
+           !self.session.panic_free && !self.session.fixed_stack && guard_span.is_none() &&
+           nesting_span.is_none() && lookahead_span.is_none() && !contextual &&
+           !self.session.emit_profile && !self.session.use_profile &&
+           !self.session.token_arrays &&
+           grammar.token_stream.is_none() {
             algorithm.codegen = r::LrCodeGeneration::TestAll;
         }
 
         read_algorithm(&grammar.annotations, &mut algorithm);
 
+        if let Some(index) = module_attributes.iter().position(|a| is_attribute(a, "earley")) {
+            module_attributes.remove(index);
+            algorithm.codegen = r::LrCodeGeneration::Earley;
+        }
+
+        if repair {
+            if algorithm.codegen != r::LrCodeGeneration::TableDriven {
+                return_err!(grammar.span,
+                            "error repair is only supported by table-driven parsers");
+            }
+            if self.uses_error_recovery {
+                return_err!(grammar.span,
+                            "error repair cannot be combined with `!` error recovery");
+            }
+        }
+
+        if self.session.max_depth.is_some() &&
+           algorithm.codegen == r::LrCodeGeneration::RecursiveAscent {
+            return_err!(grammar.span,
+                        "a maximum parse depth is not yet supported by recursive ascent parsers");
+        }
+
+        if self.session.max_reductions.is_some() &&
+           algorithm.codegen == r::LrCodeGeneration::RecursiveAscent {
+            return_err!(grammar.span,
+                        "a maximum number of reductions is not yet supported by \
+                         recursive ascent parsers");
+        }
+
+        if self.session.panic_free {
+            if algorithm.codegen != r::LrCodeGeneration::TableDriven {
+                return_err!(grammar.span,
+                            "panic-free parsers are only supported by table-driven parsers");
+            }
+            if self.uses_error_recovery || repair {
+                return_err!(grammar.span,
+                            "panic-free parsers cannot yet be combined with error recovery \
+                             or error repair");
+            }
+            if self.session.fast_scanning && self.intern_token.is_some() {
+                return_err!(grammar.span,
+                            "panic-free parsers cannot be combined with fast scanning");
+            }
+        }
+
+        if self.session.token_arrays {
+            if algorithm.codegen != r::LrCodeGeneration::TableDriven {
+                return_err!(grammar.span,
+                            "token arrays are only supported by table-driven parsers");
+            }
+            if self.intern_token.is_none() {
+                return_err!(grammar.span, "token arrays require the built-in tokenizer");
+            }
+        }
+
+        if self.session.fixed_stack {
+            if algorithm.codegen != r::LrCodeGeneration::TableDriven {
+                return_err!(grammar.span,
+                            "fixed-size parse stacks are only supported by table-driven parsers");
+            }
+            if self.uses_error_recovery || repair {
+                return_err!(grammar.span,
+                            "fixed-size parse stacks cannot yet be combined with error recovery \
+                             or error repair");
+            }
+            if self.session.emit_c_abi || self.session.emit_bench {
+                return_err!(grammar.span,
+                            "fixed-size parse stacks cannot be combined with C entry points \
+                             or benchmarks");
+            }
+        }
+
+        if let Some(span) = nesting_span {
+            if algorithm.codegen != r::LrCodeGeneration::TableDriven {
+                return_err!(span,
+                            "#[max_nesting] is only supported by table-driven parsers");
+            }
+            if self.uses_error_recovery || repair {
+                return_err!(span,
+                            "#[max_nesting] cannot yet be combined with error recovery \
+                             or error repair");
+            }
+            if self.session.fixed_stack {
+                return_err!(span,
+                            "#[max_nesting] cannot yet be combined with fixed-size parse stacks");
+            }
+        }
+
+        if let Some(span) = lookahead_span {
+            if algorithm.codegen != r::LrCodeGeneration::TableDriven {
+                return_err!(span,
+                            "#[lookahead] is only supported by table-driven parsers");
+            }
+            if self.uses_error_recovery || repair {
+                return_err!(span,
+                            "#[lookahead] cannot yet be combined with error recovery \
+                             or error repair");
+            }
+        }
+
+        if contextual {
+            if algorithm.codegen != r::LrCodeGeneration::TableDriven {
+                return_err!(grammar.span,
+                            "#[contextual] keywords are only supported by table-driven parsers");
+            }
+            if repair {
+                return_err!(grammar.span,
+                            "#[contextual] keywords cannot yet be combined with error repair");
+            }
+        }
+
+        if let Some(span) = guard_span {
+            if algorithm.codegen == r::LrCodeGeneration::RecursiveAscent {
+                return_err!(span,
+                            "guards on token patterns are not yet supported by recursive ascent parsers");
+            }
+        }
+
+        if algorithm.codegen == r::LrCodeGeneration::Earley {
+            if self.uses_error_recovery {
+                return_err!(grammar.span,
+                            "error recovery is not supported by Earley parsers");
+            }
+            if self.session.max_depth.is_some() {
+                return_err!(grammar.span,
+                            "a maximum parse depth is not supported by Earley parsers");
+            }
+            if self.session.table_section.is_some() {
+                return_err!(grammar.span,
+                            "placing the parse tables in a link section is not supported by \
+                             Earley parsers");
+            }
+        }
+
+        {
+            let productions = self.nonterminals.values().flat_map(|nt| &nt.productions);
+            for production in productions {
+                if production.dprec == 0 && production.merge.is_none() {
+                    continue;
+                }
+                if algorithm.codegen != r::LrCodeGeneration::Earley {
+                    return_err!(production.span,
+                                "`dprec` and `merge` are only supported by Earley parsers");
+                }
+                // merging replays the tokens of a node once per
+                // alternative, so they have to be cloned
+                if production.merge.is_some() && self.intern_token.is_none() {
+                    return_err!(production.span,
+                                "`merge` requires the built-in tokenizer");
+                }
+            }
+        }
+
         let mut all_terminals: Vec<_> = self.conversions
                                             .iter()
                                             .map(|c| c.0)
@@ -181,6 +435,39 @@ impl<'s> LowerState<'s> {
                                             .collect();
         all_terminals.sort();
 
+        let (module_attributes, lint_levels) =
+            match lint::extract_lint_levels(module_attributes) {
+                Ok(v) => v,
+                Err(message) => return_err!(grammar.span, "{}", message),
+            };
+
+        let mut recover_delimiters = vec![];
+        for annotation in &grammar.annotations {
+            if annotation.id != intern(RECOVER_DELIMITERS) {
+                continue;
+            }
+            let terminals = |key: &str| -> Vec<TerminalString> {
+                annotation.params
+                          .iter()
+                          .filter(|param| param.key == intern(key))
+                          .flat_map(|param| match param.value {
+                              pt::AnnotationValue::Terminals(ref terminals) => terminals.clone(),
+                              pt::AnnotationValue::String(_) => vec![],
+                          })
+                          .collect()
+            };
+            for (open, close) in terminals(OPEN).into_iter().zip(terminals(CLOSE)) {
+                for &terminal in &[open, close] {
+                    if all_terminals.binary_search(&terminal).is_err() {
+                        return_err!(annotation.id_span,
+                                    "`{}` is not a terminal of the grammar",
+                                    terminal);
+                    }
+                }
+                recover_delimiters.push((open, close));
+            }
+        }
+
         let terminal_bits: Map<_, _> = all_terminals.iter()
                                                     .cloned()
                                                     .zip(0..)
@@ -188,12 +475,17 @@ impl<'s> LowerState<'s> {
 
         Ok(r::Grammar {
             uses_error_recovery: self.uses_error_recovery,
+            repair: repair,
             prefix: self.prefix,
             start_nonterminals: start_symbols,
             uses: uses,
             action_fn_defns: self.action_fn_defns,
             nonterminals: self.nonterminals,
             conversions: self.conversions.into_iter().collect(),
+            conversion_guards: conversion_guards,
+            conversion_fns: conversion_fns,
+            token_normalizer: token_normalizer,
+            recover_delimiters: recover_delimiters,
             types: self.types,
             token_span: token_span.unwrap(),
             type_parameters: grammar.type_parameters,
@@ -201,11 +493,15 @@ impl<'s> LowerState<'s> {
             where_clauses: where_clauses,
             algorithm: algorithm,
             intern_token: self.intern_token,
+            token_stream: grammar.token_stream,
             terminals: r::TerminalSet {
                 all: all_terminals,
                 bits: terminal_bits,
             },
-            module_attributes: grammar.module_attributes,
+            module_attributes: module_attributes,
+            lint_levels: lint_levels,
+            unparsers: unparsers,
+            ast_types: ast_types,
         })
     }
 
@@ -231,12 +527,15 @@ impl<'s> LowerState<'s> {
                                                      pt::SymbolKind::Nonterminal(fake_name))],
                    };
                    let symbols = vec![r::Symbol::Nonterminal(nt.name)];
-                   let action_fn = self.action_fn(nt_type, false, &expr, &symbols, None);
+                   let action_fn = self.action_fn(nt_type, false, &expr, &symbols, nt.span,
+                                                  None, None, None);
                    let production = r::Production {
                        nonterminal: fake_name,
                        symbols: symbols,
                        action: action_fn,
                        span: nt.span,
+                       dprec: 0,
+                       merge: None,
                    };
                    self.nonterminals.insert(fake_name,
                                             r::NonterminalData {
@@ -254,18 +553,21 @@ impl<'s> LowerState<'s> {
                    nt_type: r::TypeRepr,
                    expr: &pt::ExprSymbol,
                    symbols: &[r::Symbol],
+                   span: pt::Span,
+                   guard: Option<String>,
+                   since: Option<Version>,
                    action: Option<pt::ActionKind>)
                    -> r::ActionFn {
         match action {
             Some(pt::ActionKind::Lookahead) => self.lookahead_action_fn(),
             Some(pt::ActionKind::Lookbehind) => self.lookbehind_action_fn(),
             Some(pt::ActionKind::User(string)) => {
-                self.action_fn(nt_type, false, &expr, &symbols, Some(string))
+                self.action_fn(nt_type, false, &expr, &symbols, span, guard, since, Some(string))
             }
             Some(pt::ActionKind::Fallible(string)) => {
-                self.action_fn(nt_type, true, &expr, &symbols, Some(string))
+                self.action_fn(nt_type, true, &expr, &symbols, span, guard, since, Some(string))
             }
-            None => self.action_fn(nt_type, false, &expr, &symbols, None),
+            None => self.action_fn(nt_type, false, &expr, &symbols, span, guard, since, None),
         }
     }
 
@@ -294,6 +596,9 @@ impl<'s> LowerState<'s> {
                  fallible: bool,
                  expr: &pt::ExprSymbol,
                  symbols: &[r::Symbol],
+                 span: pt::Span,
+                 guard: Option<String>,
+                 since: Option<Version>,
                  action: Option<String>)
                  -> r::ActionFn {
         let action = match action {
@@ -311,6 +616,17 @@ impl<'s> LowerState<'s> {
             }
         };
 
+        // A guard or a version check can reject the symbols, so the
+        // action becomes fallible.
+        if since.is_some() {
+            self.uses_since = true;
+        }
+        let (fallible, action) = if (guard.is_some() || since.is_some()) && !fallible {
+            (true, format!("Ok({})", action))
+        } else {
+            (fallible, action)
+        };
+
         // Note that the action fn takes ALL of the symbols in `expr`
         // as arguments, and some of them are simply dropped based on
         // the user's selections.
@@ -359,6 +675,9 @@ impl<'s> LowerState<'s> {
                         arg_patterns: arg_patterns,
                         arg_types: arg_types,
                         code: action,
+                        guard: guard,
+                        since: since,
+                        span: span,
                     }),
                 }
             }
@@ -380,6 +699,9 @@ impl<'s> LowerState<'s> {
                         arg_patterns: arg_patterns,
                         arg_types: arg_types,
                         code: action,
+                        guard: guard,
+                        since: since,
+                        span: span,
                     }),
                 }
             }
@@ -448,3 +770,79 @@ fn patterns<I>(mut chosen: I, num_args: usize) -> Vec<InternedString>
 
     result
 }
+
+/// The `#[dprec]` and `#[merge]` annotations of an alternative, which
+/// prevalidation has checked.
+fn disambiguation(annotations: &[pt::Annotation]) -> (u32, Option<InternedString>) {
+    let arg = |name: &str| {
+        annotations.iter()
+                   .find(|a| a.id == intern(name))
+                   .map(|a| a.arg.unwrap().1)
+    };
+    let dprec = arg(DPREC).map_or(0, |dprec| {
+        intern::read(|interner| interner.data(dprec).parse().unwrap())
+    });
+    (dprec, arg(MERGE))
+}
+
+/// The action of an alternative of a `#[boxed]` nonterminal, which
+/// puts the value of `action` (by default, the selected symbols) in a
+/// `Box`.
+fn boxed_action(action: Option<pt::ActionKind>) -> Option<pt::ActionKind> {
+    match action {
+        Some(pt::ActionKind::User(code)) => {
+            Some(pt::ActionKind::User(format!("::std::boxed::Box::new({})", code)))
+        }
+        Some(pt::ActionKind::Fallible(code)) => {
+            Some(pt::ActionKind::Fallible(format!("({}).map(::std::boxed::Box::new)", code)))
+        }
+        None => Some(pt::ActionKind::User(format!("::std::boxed::Box::new((<>))"))),
+        action => action,
+    }
+}
+
+/// The version given by the `#[since]` annotation of an alternative,
+/// which prevalidation has checked.
+fn since(annotations: &[pt::Annotation]) -> Option<Version> {
+    annotations.iter()
+               .find(|a| a.id == intern(SINCE))
+               .map(|a| {
+                   let arg = a.arg.unwrap().1;
+                   intern::read(|interner| interner.data(arg).parse().unwrap())
+               })
+}
+
+/// The function named by `#[normalize = "..."]`, if any.
+fn normalize(annotations: &[pt::Annotation]) -> Option<String> {
+    annotations.iter()
+               .find(|a| a.id == intern(NORMALIZE))
+               .map(|a| a.arg.unwrap().1.to_string())
+}
+
+/// True for `#![earley]` and `#![repair]` (given `name`), which
+/// configure LALRPOP rather than being copied into the generated
+/// module.
+pub fn is_attribute(attribute: &str, name: &str) -> bool {
+    let body: String = attribute.chars().filter(|c| !c.is_whitespace()).collect();
+    body == format!("#![{}]", name)
+}
+
+/// The text between the parentheses of `#![name(...)]`, for module
+/// attributes like `#![ast_derive(Clone, Debug)]` that configure
+/// LALRPOP.
+pub fn attribute_arguments(attribute: &str, name: &str) -> Option<String> {
+    let attribute = attribute.trim();
+    if !attribute.starts_with("#![") || !attribute.ends_with(']') {
+        return None;
+    }
+    let body = attribute[3..attribute.len() - 1].trim();
+    if !body.starts_with(name) {
+        return None;
+    }
+    let arguments = body[name.len()..].trim();
+    if arguments.starts_with('(') && arguments.ends_with(')') {
+        Some(arguments[1..arguments.len() - 1].trim().to_string())
+    } else {
+        None
+    }
+}
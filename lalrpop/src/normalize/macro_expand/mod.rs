@@ -191,8 +191,10 @@ impl MacroExpander {
             }
             alternatives.push(Alternative {
                 span: span,
+                annotations: alternative.annotations.clone(),
                 expr: self.macro_expand_expr_symbol(&args, &alternative.expr),
                 condition: None,
+                guard: alternative.guard.clone(),
                 action: alternative.action.clone(),
             });
         }
@@ -370,8 +372,10 @@ impl MacroExpander {
             args: vec![],
             type_decl: Some(ty_ref),
             alternatives: vec![Alternative { span: span,
+                                             annotations: vec![],
                                              expr: expr,
                                              condition: None,
+                                             guard: None,
                                              action: action("(<>)") }]
         }))
     }
@@ -407,14 +411,17 @@ impl MacroExpander {
                         // X* =
                         Alternative {
                             span: span,
+                            annotations: vec![],
                             expr: ExprSymbol { symbols: vec![] },
                             condition: None,
+                            guard: None,
                             action: action("vec![]")
                         },
 
                         // X* = <v:X+>
                         Alternative {
                             span: span,
+                            annotations: vec![],
                             expr: ExprSymbol {
                                 symbols: vec![
                                     Symbol::new(
@@ -426,6 +433,7 @@ impl MacroExpander {
                                                             SymbolKind::Repeat(plus_repeat)))))],
                             },
                             condition: None,
+                            guard: None,
                             action: action("v"),
                         }],
                 }))
@@ -446,16 +454,19 @@ impl MacroExpander {
                         // X+ = X
                         Alternative {
                             span: span,
+                            annotations: vec![],
                             expr: ExprSymbol {
                                 symbols: vec![repeat.symbol.clone()]
                             },
                             condition: None,
+                            guard: None,
                             action: action("vec![<>]"),
                         },
 
                         // X+ = <v:X+> <e:X>
                         Alternative {
                             span: span,
+                            annotations: vec![],
                             expr: ExprSymbol {
                                 symbols: vec![
                                     Symbol::new(span, SymbolKind::Name(
@@ -465,6 +476,7 @@ impl MacroExpander {
                                         e, Box::new(repeat.symbol.clone())))]
                             },
                             condition: None,
+                            guard: None,
                             action: action("{ let mut v = v; v.push(e); v }"),
                         }],
                 }))
@@ -484,18 +496,22 @@ impl MacroExpander {
                     alternatives: vec![
                         // X? = X => Some(<>)
                         Alternative { span: span,
+                                      annotations: vec![],
                                       expr: ExprSymbol {
                                           symbols: vec![repeat.symbol.clone()]
                                       },
                                       condition: None,
+                                      guard: None,
                                       action: action("Some(<>)") },
 
                         // X? = { => None; }
                         Alternative { span: span,
+                                      annotations: vec![],
                                       expr: ExprSymbol {
                                           symbols: vec![]
                                       },
                                       condition: None,
+                                      guard: None,
                                       action: action("None") }]
                 }))
             }
@@ -514,8 +530,10 @@ impl MacroExpander {
             type_decl: None,
             alternatives: vec![
                 Alternative { span: span,
+                              annotations: vec![],
                               expr: ExprSymbol { symbols: vec![] },
                               condition: None,
+                              guard: None,
                               action: Some(action) }]
         }))
     }
@@ -537,5 +555,7 @@ fn inline(span: Span) -> Vec<Annotation> {
     vec![Annotation {
                 id_span: span,
                 id: intern(INLINE),
+                arg: None,
+                params: vec![],
     }]
 }
@@ -25,6 +25,12 @@ macro_rules! return_err {
     }
 }
 
+/// Combines `derived`, a grammar declared with `extend "..."`, with
+/// `base`, the grammar it extends, into one to be normalized.
+pub fn stitch(base: pt::Grammar, derived: pt::Grammar) -> NormResult<pt::Grammar> {
+    extend::stitch(base, derived)
+}
+
 pub fn normalize(session: &Session, grammar: pt::Grammar) -> NormResult<r::Grammar> {
     normalize_helper(session, grammar, true)
 }
@@ -40,28 +46,80 @@ fn normalize_helper(session: &Session,
                     validate: bool)
                     -> NormResult<r::Grammar> {
     let grammar = try!(lower_helper(session, grammar, validate));
-    let grammar = profile!(session, "Inlining", try!(inline::inline(grammar)));
+    profile!(session, "Productivity check", try!(productivity::check(&grammar)));
+    let mut grammar = profile!(session, "Inlining", try!(inline::inline(grammar)));
+    if session.auto_inline {
+        profile!(session, "Automatic inlining", auto_inline::auto_inline(session, &mut grammar));
+    }
     Ok(grammar)
 }
 
 fn lower_helper(session: &Session, grammar: pt::Grammar, validate: bool) -> NormResult<r::Grammar> {
     profile!(session, "Grammar validation", if validate { try!(prevalidate::validate(&grammar)); });
+    profile!(session, "Action code check", if validate { try!(action_check::validate(&grammar)); });
+    let grammar = profile!(session, "Cfg evaluation", cfg::strip(session, grammar));
+    let grammar = profile!(session, "Token adapter expansion", try!(token_adapter::expand(grammar)));
+    let grammar = profile!(session, "Token stream expansion", try!(token_stream::expand(grammar)));
+    let grammar = profile!(session, "Built-in macros", try!(prelude::expand(grammar)));
     let grammar = profile!(session, "Grammar resolution", try!(resolve::resolve(grammar)));
+    let grammar = profile!(session, "Recovery expansion", try!(recover::expand(grammar)));
+    let grammar = profile!(session, "Operator expansion", try!(pratt::expand(grammar)));
+    let grammar = profile!(session, "AST expansion", try!(ast::expand(grammar)));
     let grammar = profile!(session, "Macro expansion", try!(macro_expand::expand_macros(grammar)));
+    let grammar = profile!(session, "Token stream tokens", try!(token_stream::add_fixed_tokens(grammar)));
     let grammar = profile!(session, "Token check", try!(token_check::validate(grammar)));
     let types = profile!(session, "Infer types", try!(tyinfer::infer_types(&grammar)));
+    let (grammar, types) = profile!(session, "AST type parameters",
+                                    try!(ast::add_type_parameters(grammar, types)));
     let grammar = profile!(session, "Lowering", try!(lower::lower(session, grammar, types)));
     Ok(grammar)
 }
 
+// Combines a grammar with the one it extends (see `stitch`); the
+// build does this before running the passes below.
+mod extend;
+
 // These are executed *IN ORDER*:
 
 // Check most safety conditions.
 mod prevalidate;
 
+// Check that the action code is well-formed Rust, as far as its
+// delimiters and literals go.
+mod action_check;
+
+// Removes the nonterminals and alternatives whose `#[cfg(...)]`
+// settings are not enabled.
+mod cfg;
+
+// Folds `enum PpTok for Tok::Pp { ... }` blocks into the
+// conversions of the extern token enum.
+mod token_adapter;
+
+// Declares the tokens of `#![token_stream]` grammars; the quoted
+// terminals are added after macro expansion, before the token check.
+mod token_stream;
+
+// Adds the built-in macros, like `CommaTrailing<T>`, that the grammar
+// uses without defining them.
+mod prelude;
+
 // Resolve identifiers into terminals/nonterminals etc.
 mod resolve;
 
+// Adds the error-recovery alternatives declared by `#[recover]`
+// annotations.
+mod recover;
+
+// Expands `#[pratt]` nonterminals into one nonterminal per
+// precedence level.
+mod pratt;
+
+// Declares the types of `#[ast]` nonterminals and builds their values
+// from their named symbols, with where each value starts and ends; the
+// types are given the type parameters they need after type inference.
+mod ast;
+
 // Expands macros and expressions
 //
 //     X = ...1 Comma<X> (X Y Z) ...2
@@ -91,9 +149,22 @@ mod tyinfer;
 // Lowers the parse tree to the repr notation.
 mod lower;
 
+// Works out how the `unparse_X` functions asked for by `#[unparse]`
+// write values back as text; part of lowering.
+mod unparse;
+
+// Check that every nonterminal can derive some input.
+mod productivity;
+
 // Inline nonterminals that have requested it.
 mod inline;
 
+// Inline the nonterminals that make the parsers smaller, if the
+// session asks for it.
+mod auto_inline;
+
+pub use self::inline::inline_nt;
+
 ///////////////////////////////////////////////////////////////////////////
 // Shared routines
 
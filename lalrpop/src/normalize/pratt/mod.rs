@@ -0,0 +1,249 @@
+//! Expands `#[pratt]` nonterminals into a chain of nonterminals, one
+//! per precedence level, so that operator expressions can be written
+//! as a single table instead of a hand-written tier per level. Given:
+//!
+//!     #[pratt]
+//!     Expr: i32 = {
+//!         #[left = "1"] <l:Expr> "+" <r:Expr> => l + r,
+//!         #[left = "2"] <l:Expr> "*" <r:Expr> => l * r,
+//!         #[prefix = "3"] "-" <e:Expr> => -e,
+//!         Num,
+//!         "(" <Expr> ")",
+//!     };
+//!
+//! we generate:
+//!
+//!     Expr: i32 = { <l:Expr> "+" <r:`Expr#2`> => l + r, `Expr#2` };
+//!     `Expr#2`: i32 = { <l:`Expr#2`> "*" <r:`Expr#3`> => l * r, `Expr#3` };
+//!     `Expr#3`: i32 = { "-" <e:`Expr#3`> => -e, `Expr#atom` };
+//!     `Expr#atom`: i32 = { Num, "(" <Expr> ")" };
+//!
+//! Higher levels bind more tightly. The operands of an operator are
+//! rewritten to refer to its own level or to the next one, depending
+//! on which side associates; any other reference to the nonterminal
+//! (like the one between the parentheses above) still refers to the
+//! whole expression. The result is an ordinary LR grammar, so the
+//! operators are parsed by the same state machine as everything else.
+
+use super::{NormResult, NormError};
+
+use grammar::consts::{PRATT, LEFT, RIGHT, PREFIX, POSTFIX};
+use grammar::parse_tree::*;
+use intern::{intern, read, InternedString};
+use std::collections::BTreeMap;
+use std::mem;
+
+#[cfg(test)]
+mod test;
+
+pub fn expand(mut grammar: Grammar) -> NormResult<Grammar> {
+    let items = mem::replace(&mut grammar.items, vec![]);
+    for item in items {
+        match item {
+            GrammarItem::Nonterminal(ref data) if is_pratt(data) => {
+                for data in try!(expand_nonterminal(data)) {
+                    grammar.items.push(GrammarItem::Nonterminal(data));
+                }
+            }
+            item => grammar.items.push(item),
+        }
+    }
+    Ok(grammar)
+}
+
+fn is_pratt(data: &NonterminalData) -> bool {
+    data.annotations.iter().any(|a| a.id == intern(PRATT))
+}
+
+fn is_operator(id: InternedString) -> bool {
+    [LEFT, RIGHT, PREFIX, POSTFIX].iter().any(|&name| id == intern(name))
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Kind {
+    Left,
+    Right,
+    Prefix,
+    Postfix,
+}
+
+impl Kind {
+    fn from_annotation(id: InternedString) -> Kind {
+        if id == intern(LEFT) {
+            Kind::Left
+        } else if id == intern(RIGHT) {
+            Kind::Right
+        } else if id == intern(PREFIX) {
+            Kind::Prefix
+        } else if id == intern(POSTFIX) {
+            Kind::Postfix
+        } else {
+            panic!("validation permitted unknown operator annotation: {:?}", id)
+        }
+    }
+}
+
+/// The operators declared at one precedence level.
+struct Level {
+    kind: Kind,
+    annotation: InternedString,
+    alternatives: Vec<Alternative>,
+}
+
+fn expand_nonterminal(data: &NonterminalData) -> NormResult<Vec<NonterminalData>> {
+    let mut levels: BTreeMap<u32, Level> = BTreeMap::new();
+    let mut atoms = vec![];
+
+    for alternative in &data.alternatives {
+        let annotation = match alternative.annotations.iter().find(|a| is_operator(a.id)) {
+            Some(annotation) => annotation,
+            None => {
+                atoms.push(alternative.clone());
+                continue;
+            }
+        };
+
+        let level = match annotation.arg {
+            Some((span, arg)) => match read(|interner| interner.data(arg).parse::<u32>()) {
+                Ok(level) => level,
+                Err(_) => return_err!(span,
+                                      "precedence level must be a number, not `{}`",
+                                      arg),
+            },
+            None => return_err!(annotation.id_span,
+                                "annotation `{}` requires a precedence level",
+                                annotation.id),
+        };
+
+        let kind = Kind::from_annotation(annotation.id);
+        try!(check_shape(data.name, kind, annotation.id, alternative));
+
+        let level = levels.entry(level).or_insert_with(|| Level {
+            kind: kind,
+            annotation: annotation.id,
+            alternatives: vec![],
+        });
+        if level.kind != kind {
+            return_err!(annotation.id_span,
+                        "`{}` operators cannot share a precedence level with `{}` operators",
+                        annotation.id,
+                        level.annotation);
+        }
+        level.alternatives.push(alternative.clone());
+    }
+
+    if atoms.is_empty() {
+        return_err!(data.span,
+                    "#[pratt] item `{}` needs an alternative without an operator annotation",
+                    data.name);
+    }
+
+    // the lowest level keeps the original name, so that the rest of
+    // the grammar can go on referring to it
+    let mut names = vec![data.name];
+    for &level in levels.keys().skip(1) {
+        names.push(NonterminalString(intern(&format!("{}#{}", data.name, level))));
+    }
+    if !levels.is_empty() {
+        names.push(NonterminalString(intern(&format!("{}#atom", data.name))));
+    }
+
+    let mut tiers = vec![];
+    for (index, level) in levels.into_iter().map(|(_, level)| level).enumerate() {
+        let (this, next) = (names[index], names[index + 1]);
+        let kind = level.kind;
+        let mut alternatives: Vec<Alternative> =
+            level.alternatives
+                 .into_iter()
+                 .map(|mut alternative| {
+                     {
+                         let symbols = &mut alternative.expr.symbols;
+                         let last = symbols.len() - 1;
+                         match kind {
+                             Kind::Left => {
+                                 rename(&mut symbols[0], this);
+                                 rename(&mut symbols[last], next);
+                             }
+                             Kind::Right => {
+                                 rename(&mut symbols[0], next);
+                                 rename(&mut symbols[last], this);
+                             }
+                             Kind::Prefix => rename(&mut symbols[last], this),
+                             Kind::Postfix => rename(&mut symbols[0], this),
+                         }
+                     }
+                     alternative.annotations.retain(|a| !is_operator(a.id));
+                     alternative
+                 })
+                 .collect();
+        alternatives.push(fallthrough(data.span, next));
+        tiers.push((this, alternatives));
+    }
+    tiers.push((*names.last().unwrap(), atoms));
+
+    Ok(tiers.into_iter()
+            .enumerate()
+            .map(|(index, (name, alternatives))| NonterminalData {
+                public: index == 0 && data.public,
+                name: name,
+                annotations: vec![],
+                span: data.span,
+                args: vec![],
+                type_decl: data.type_decl.clone(),
+                alternatives: alternatives,
+            })
+            .collect())
+}
+
+/// Checks that the operands of an operator are where its kind says
+/// they are: both ends for infix operators, the end for prefix
+/// operators and the start for postfix ones.
+fn check_shape(name: NonterminalString,
+               kind: Kind,
+               annotation: InternedString,
+               alternative: &Alternative)
+               -> NormResult<()> {
+    let symbols = &alternative.expr.symbols;
+    let starts = symbols.len() >= 2 && is_operand(&symbols[0], name);
+    let ends = symbols.len() >= 2 && is_operand(&symbols[symbols.len() - 1], name);
+    let (ok, expected) = match kind {
+        Kind::Left | Kind::Right => (starts && ends, "start and end"),
+        Kind::Prefix => (ends, "end"),
+        Kind::Postfix => (starts, "start"),
+    };
+    if !ok {
+        return_err!(alternative.span,
+                    "a `{}` operator must {} with `{}`",
+                    annotation,
+                    expected,
+                    name);
+    }
+    Ok(())
+}
+
+fn is_operand(symbol: &Symbol, name: NonterminalString) -> bool {
+    match symbol.kind {
+        SymbolKind::Nonterminal(n) => n == name,
+        SymbolKind::Choose(ref s) | SymbolKind::Name(_, ref s) => is_operand(s, name),
+        _ => false,
+    }
+}
+
+fn rename(symbol: &mut Symbol, name: NonterminalString) {
+    match symbol.kind {
+        SymbolKind::Nonterminal(ref mut n) => *n = name,
+        SymbolKind::Choose(ref mut s) | SymbolKind::Name(_, ref mut s) => rename(s, name),
+        _ => unreachable!(),
+    }
+}
+
+fn fallthrough(span: Span, name: NonterminalString) -> Alternative {
+    Alternative {
+        span: span,
+        annotations: vec![],
+        expr: ExprSymbol { symbols: vec![Symbol::new(span, SymbolKind::Nonterminal(name))] },
+        condition: None,
+        guard: None,
+        action: None,
+    }
+}
@@ -0,0 +1,91 @@
+use grammar::parse_tree::Grammar;
+use normalize::resolve::resolve;
+use parser;
+use test_util::{check_norm_err, compare};
+
+use super::expand;
+
+fn resolved(grammar: &str) -> Grammar {
+    resolve(parser::parse_grammar(grammar).unwrap()).unwrap()
+}
+
+fn check_err(expected_err: &str, grammar: &str, span: &str) {
+    let err = expand(resolved(grammar)).unwrap_err();
+    check_norm_err(expected_err, span, err);
+}
+
+#[test]
+fn one_nonterminal_per_level() {
+    let actual = expand(resolved(r#"
+grammar;
+    #[pratt]
+    pub Expr: i32 = {
+        #[left = "1"] <l:Expr> "+" <r:Expr> => l + r,
+        #[prefix = "3"] "-" <e:Expr> => -e,
+        #[right = "2"] <l:Expr> "^" <r:Expr> => l ^ r,
+        #[postfix = "4"] <e:Expr> "!" => e,
+        #[left = "1"] <l:Expr> "-" <r:Expr> => l - r,
+        "Num" => 0,
+        "(" <Expr> ")",
+    };
+"#)).unwrap();
+
+    let expected = resolved(r#"
+grammar;
+    pub Expr: i32 = {
+        <l:Expr> "+" <r:`Expr#2`> => l + r,
+        <l:Expr> "-" <r:`Expr#2`> => l - r,
+        `Expr#2`,
+    };
+    `Expr#2`: i32 = {
+        <l:`Expr#3`> "^" <r:`Expr#2`> => l ^ r,
+        `Expr#3`,
+    };
+    `Expr#3`: i32 = {
+        "-" <e:`Expr#3`> => -e,
+        `Expr#4`,
+    };
+    `Expr#4`: i32 = {
+        <e:`Expr#4`> "!" => e,
+        `Expr#atom`,
+    };
+    `Expr#atom`: i32 = {
+        "Num" => 0,
+        "(" <Expr> ")",
+    };
+"#);
+
+    compare(actual, expected);
+}
+
+#[test]
+fn bad_level() {
+    check_err(
+        r#"precedence level must be a number, not `high`"#,
+        r#"grammar; #[pratt] E = { #[left = "high"] E "+" E, "x" };"#,
+        r#"                                 ~~~~~~               "#);
+}
+
+#[test]
+fn bad_shape() {
+    check_err(
+        r#"a `postfix` operator must start with `E`"#,
+        r#"grammar; #[pratt] E = { #[postfix = "1"] "!" E, "x" };"#,
+        r#"                                         ~~~~~       "#);
+}
+
+#[test]
+fn mixed_level() {
+    check_err(
+        r#"`right` operators cannot share a precedence level with `left` operators"#,
+        r#"grammar; #[pratt] E = { #[left = "1"] E "+" E, #[right = "1"] E "^" E, "x" };"#,
+        r#"                                                 ~~~~~                       "#);
+}
+
+#[test]
+fn no_atoms() {
+    check_err(
+        r#"#\[pratt\] item `E` needs an alternative without an operator annotation"#,
+        r#"grammar; #[pratt] E = { #[prefix = "1"] "-" E };"#,
+        r#"                  ~                             "#);
+}
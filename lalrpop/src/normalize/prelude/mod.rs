@@ -0,0 +1,131 @@
+//! Adds the built-in macros that the grammar uses but does not define
+//! itself. There is one so far, for comma-separated lists with an
+//! optional trailing comma, which is easy to get wrong by hand (most
+//! attempts are ambiguous). Given a use of `CommaTrailing<Expr>`, we
+//! add:
+//!
+//!     CommaTrailing<T>: ::std::vec::Vec<T> = {
+//!         <v:(<T> ",")*> <e:T?> => match e { ... },
+//!     };
+//!
+//! which matches `a`, `a, b` and `a, b,` as well as nothing at all,
+//! without conflicts. A grammar can still define a `CommaTrailing` of
+//! its own, which then replaces the built-in one.
+
+use super::NormResult;
+
+use grammar::parse_tree::*;
+use intern::intern;
+
+#[cfg(test)]
+mod test;
+
+pub const COMMA_TRAILING: &'static str = "CommaTrailing";
+
+pub fn expand(mut grammar: Grammar) -> NormResult<Grammar> {
+    let name = NonterminalString(intern(COMMA_TRAILING));
+    let defined = grammar.items.iter().any(|item| match *item {
+        GrammarItem::Nonterminal(ref data) => data.name == name,
+        _ => false,
+    });
+    if defined {
+        return Ok(grammar);
+    }
+
+    let mut span = None;
+    for item in &grammar.items {
+        if let GrammarItem::Nonterminal(ref data) = *item {
+            for alternative in &data.alternatives {
+                for symbol in &alternative.expr.symbols {
+                    if span.is_none() {
+                        span = macro_use(symbol, name);
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some(span) = span {
+        grammar.items.push(GrammarItem::Nonterminal(comma_trailing(span, name)));
+    }
+    Ok(grammar)
+}
+
+/// The span of the first use of the macro `name` in `symbol`, if any.
+fn macro_use(symbol: &Symbol, name: NonterminalString) -> Option<Span> {
+    match symbol.kind {
+        SymbolKind::Macro(ref msym) => {
+            if msym.name == name {
+                Some(symbol.span)
+            } else {
+                msym.args.iter().filter_map(|arg| macro_use(arg, name)).next()
+            }
+        }
+        SymbolKind::Expr(ref expr) => {
+            expr.symbols.iter().filter_map(|sym| macro_use(sym, name)).next()
+        }
+        SymbolKind::Repeat(ref repeat) => macro_use(&repeat.symbol, name),
+        SymbolKind::Choose(ref sym) | SymbolKind::Name(_, ref sym) => macro_use(sym, name),
+        SymbolKind::AmbiguousId(_) |
+        SymbolKind::Terminal(_) |
+        SymbolKind::Nonterminal(_) |
+        SymbolKind::Lookahead |
+        SymbolKind::Lookbehind |
+        SymbolKind::Error => None,
+    }
+}
+
+/// The definition of `CommaTrailing<T>`, attributed to its first use.
+fn comma_trailing(span: Span, name: NonterminalString) -> NonterminalData {
+    let t = intern("T");
+    let arg = || Symbol::new(span, SymbolKind::AmbiguousId(t));
+    let comma = Symbol::new(span, SymbolKind::Terminal(TerminalString::quoted(intern(","))));
+
+    // <T> ","
+    let element = Symbol::new(span,
+                              SymbolKind::Expr(ExprSymbol {
+                                  symbols: vec![Symbol::new(span,
+                                                            SymbolKind::Choose(Box::new(arg()))),
+                                                comma],
+                              }));
+    // v:(<T> ",")*
+    let elements = Symbol::new(span,
+                               SymbolKind::Repeat(Box::new(RepeatSymbol {
+                                   op: RepeatOp::Star,
+                                   symbol: element,
+                               })));
+    // e:T?
+    let last = Symbol::new(span,
+                           SymbolKind::Repeat(Box::new(RepeatSymbol {
+                               op: RepeatOp::Question,
+                               symbol: arg(),
+                           })));
+
+    NonterminalData {
+        public: false,
+        name: name,
+        annotations: vec![],
+        span: span,
+        args: vec![NonterminalString(t)],
+        type_decl: Some(TypeRef::Nominal {
+            path: Path {
+                absolute: true,
+                ids: vec![intern("std"), intern("vec"), intern("Vec")],
+            },
+            types: vec![TypeRef::Id(t)],
+        }),
+        alternatives: vec![Alternative {
+            span: span,
+            annotations: vec![],
+            expr: ExprSymbol {
+                symbols: vec![Symbol::new(span, SymbolKind::Name(intern("v"), Box::new(elements))),
+                              Symbol::new(span, SymbolKind::Name(intern("e"), Box::new(last)))],
+            },
+            condition: None,
+            guard: None,
+            action: Some(ActionKind::User("match e { None => v, Some(e) => { let mut v = v; \
+                                           v.push(e); v } }"
+                                              .to_string())),
+        }],
+    }
+}
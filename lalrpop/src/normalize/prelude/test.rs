@@ -0,0 +1,47 @@
+use grammar::parse_tree::Grammar;
+use parser;
+use test_util::compare;
+
+use super::expand;
+
+fn parsed(grammar: &str) -> Grammar {
+    parser::parse_grammar(grammar).unwrap()
+}
+
+#[test]
+fn comma_trailing_is_added() {
+    let actual = expand(parsed(r#"
+grammar;
+    Args = "(" <Box<CommaTrailing<"x">>> ")";
+"#)).unwrap();
+
+    let expected = parsed(r#"
+grammar;
+    Args = "(" <Box<CommaTrailing<"x">>> ")";
+    CommaTrailing<T>: ::std::vec::Vec<T> =
+        <v:(<T> ",")*> <e:T?> => match e { None => v, Some(e) => { let mut v = v; v.push(e); v } };
+"#);
+
+    compare(actual, expected);
+}
+
+#[test]
+fn comma_trailing_can_be_redefined() {
+    let grammar = r#"
+grammar;
+    Args = "(" <CommaTrailing<"x">> ")";
+    CommaTrailing<T> = T*;
+"#;
+
+    compare(expand(parsed(grammar)).unwrap(), parsed(grammar));
+}
+
+#[test]
+fn comma_trailing_is_not_added_unless_used() {
+    let grammar = r#"
+grammar;
+    Args = "(" <"x"*> ")";
+"#;
+
+    compare(expand(parsed(grammar)).unwrap(), parsed(grammar));
+}
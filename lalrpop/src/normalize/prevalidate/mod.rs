@@ -6,8 +6,9 @@ use super::norm_util::{self, Symbols};
 use grammar::consts::*;
 use grammar::parse_tree::*;
 use grammar::repr as r;
-use intern::{intern, InternedString};
-use collections::{Multimap, set};
+use intern::{intern, read, InternedString};
+use collections::{Multimap, map, set};
+use lalrpop_util::Version;
 use util::Sep;
 
 #[cfg(test)]
@@ -46,13 +47,20 @@ impl<'grammar> Validator<'grammar> {
         let allowed_names = vec![intern(LALR),
                                  intern(TABLE_DRIVEN),
                                  intern(RECURSIVE_ASCENT),
-                                 intern(TEST_ALL)];
+                                 intern(TEST_ALL),
+                                 intern(RECOVER_DELIMITERS)];
         for annotation in &self.grammar.annotations {
             if !allowed_names.contains(&annotation.id) {
                 return_err!(annotation.id_span,
                             "unrecognized annotation `{}`",
                             annotation.id);
             }
+            try!(self.validate_no_arg(annotation));
+            if annotation.id == intern(RECOVER_DELIMITERS) {
+                try!(self.validate_recover_delimiters(annotation));
+            } else {
+                try!(self.validate_no_params(annotation));
+            }
         }
 
         for item in &self.grammar.items {
@@ -75,6 +83,8 @@ impl<'grammar> Validator<'grammar> {
                         }
                     }
 
+                    try!(self.validate_section_annotations(&data.annotations, true));
+
                     // Ensure that the catch all is final item of final block
                     for (contents_idx, match_contents) in data.contents.iter().enumerate() {
                         for (item_idx, item) in match_contents.items.iter().enumerate() {
@@ -82,9 +92,8 @@ impl<'grammar> Validator<'grammar> {
                                 return_err!(
                                     item.span(),
                                     "Catch all must be final item");
-                            } else {
-                                println!("ok");
                             }
+                            try!(self.validate_match_item_annotations(item));
                         }
                     }
                 }
@@ -105,6 +114,13 @@ impl<'grammar> Validator<'grammar> {
                         }
                     }
 
+                    try!(self.validate_section_annotations(&data.annotations, false));
+                    if let (Some(annotation), None) = (data.annotations.first(), data.enum_token.as_ref()) {
+                        return_err!(annotation.id_span,
+                                    "#[normalize] goes on the `match` section when the \
+                                     tokens are not declared in `extern`");
+                    }
+
                     let allowed_names = vec![intern(LOCATION), intern(ERROR)];
                     let mut new_names = set();
                     for associated_type in &data.associated_types {
@@ -125,7 +141,29 @@ impl<'grammar> Validator<'grammar> {
                 }
                 GrammarItem::Nonterminal(ref data) => {
                     let inline_annotation = intern(INLINE);
-                    let known_annotations = vec![inline_annotation];
+                    let pratt_annotation = intern(PRATT);
+                    let recover_annotation = intern(RECOVER);
+                    let cfg_annotation = intern(CFG);
+                    let boxed_annotation = intern(BOXED);
+                    let max_nesting_annotation = intern(MAX_NESTING);
+                    let unparse_annotation = intern(UNPARSE);
+                    let format_annotation = intern(FORMAT);
+                    let ast_annotation = intern(AST);
+                    let ast_derive_annotation = intern(AST_DERIVE);
+                    let ast_attr_annotation = intern(AST_ATTR);
+                    let lookahead_annotation = intern(LOOKAHEAD);
+                    let known_annotations = vec![inline_annotation,
+                                                 pratt_annotation,
+                                                 recover_annotation,
+                                                 cfg_annotation,
+                                                 boxed_annotation,
+                                                 max_nesting_annotation,
+                                                 unparse_annotation,
+                                                 format_annotation,
+                                                 ast_annotation,
+                                                 ast_derive_annotation,
+                                                 ast_attr_annotation,
+                                                 lookahead_annotation];
                     let mut found_annotations = set();
                     for annotation in &data.annotations {
                         if !known_annotations.contains(&annotation.id) {
@@ -139,11 +177,124 @@ impl<'grammar> Validator<'grammar> {
                         } else if annotation.id == inline_annotation && data.public {
                             return_err!(annotation.id_span,
                                         "public items cannot be marked #[inline]");
+                        } else if annotation.id == pratt_annotation && data.is_macro_def() {
+                            return_err!(annotation.id_span,
+                                        "macros cannot be marked #[pratt]");
+                        } else if (annotation.id == unparse_annotation ||
+                                   annotation.id == format_annotation ||
+                                   annotation.id == ast_annotation) &&
+                                  data.is_macro_def() {
+                            return_err!(annotation.id_span,
+                                        "macros cannot be marked #[{}]",
+                                        annotation.id);
+                        }
+                        if annotation.id == format_annotation {
+                            try!(self.validate_no_params(annotation));
+                            if annotation.arg.is_none() {
+                                return_err!(annotation.id_span,
+                                            "annotation `{}` requires a format string, \
+                                             like `#[{} = \"{{}}\"]`",
+                                            annotation.id,
+                                            annotation.id);
+                            }
+                            continue;
+                        }
+                        if annotation.id == ast_derive_annotation ||
+                           annotation.id == ast_attr_annotation {
+                            try!(self.validate_no_params(annotation));
+                            if annotation.arg.is_none() {
+                                return_err!(annotation.id_span,
+                                            "annotation `{}` requires a value, \
+                                             like `#[{} = \"{}\"]`",
+                                            annotation.id,
+                                            annotation.id,
+                                            if annotation.id == ast_derive_annotation {
+                                                "Clone, Debug"
+                                            } else {
+                                                "allow(dead_code)"
+                                            });
+                            }
+                            continue;
+                        }
+                        if annotation.id == lookahead_annotation {
+                            try!(self.validate_no_params(annotation));
+                            match annotation.arg {
+                                Some((_, value)) if value == intern("2") => {}
+                                Some((span, _)) => {
+                                    return_err!(span,
+                                                "only two tokens of lookahead are supported, \
+                                                 as in `#[{} = \"2\"]`",
+                                                annotation.id);
+                                }
+                                None => {
+                                    return_err!(annotation.id_span,
+                                                "annotation `{}` requires a number of tokens, \
+                                                 like `#[{} = \"2\"]`",
+                                                annotation.id,
+                                                annotation.id);
+                                }
+                            }
+                            continue;
+                        }
+                        try!(self.validate_no_arg(annotation));
+                        if annotation.id == recover_annotation {
+                            try!(self.validate_recover(annotation));
+                        } else if annotation.id == cfg_annotation {
+                            try!(self.validate_cfg(annotation));
+                        } else if annotation.id == max_nesting_annotation {
+                            try!(self.validate_max_nesting(annotation));
+                        } else {
+                            try!(self.validate_no_params(annotation));
+                        }
+                    }
+
+                    let pratt = found_annotations.contains(&pratt_annotation);
+                    if pratt && found_annotations.contains(&inline_annotation) {
+                        return_err!(data.span,
+                                    "#[pratt] items cannot be marked #[inline]");
+                    }
+                    // (the nesting of a nonterminal is tracked in the
+                    // parser's states, which inlining does away with)
+                    if found_annotations.contains(&max_nesting_annotation) &&
+                       found_annotations.contains(&inline_annotation) {
+                        return_err!(data.span,
+                                    "#[max_nesting] items cannot be marked #[inline]");
+                    }
+                    // (inlined items are never reduced)
+                    if found_annotations.contains(&lookahead_annotation) &&
+                       found_annotations.contains(&inline_annotation) {
+                        return_err!(data.span,
+                                    "#[lookahead] items cannot be marked #[inline]");
+                    }
+
+                    // (the alternatives of `#[ast]` items build their values
+                    // without actions, and the types they build are
+                    // boxed where they are fields)
+                    let ast = found_annotations.contains(&ast_annotation);
+                    let misplaced = if ast {
+                        data.annotations
+                            .iter()
+                            .find(|a| a.id == pratt_annotation || a.id == boxed_annotation)
+                    } else {
+                        data.annotations
+                            .iter()
+                            .find(|a| a.id == ast_derive_annotation || a.id == ast_attr_annotation)
+                    };
+                    if let Some(annotation) = misplaced {
+                        if ast {
+                            return_err!(annotation.id_span,
+                                        "#[ast] items cannot be marked #[{}]",
+                                        annotation.id);
+                        } else {
+                            return_err!(annotation.id_span,
+                                        "annotation `{}` is only allowed in #[ast] items",
+                                        annotation.id);
                         }
                     }
 
                     for alternative in &data.alternatives {
-                        try!(self.validate_alternative(alternative));
+                        try!(self.validate_alternative_annotations(alternative, pratt, ast));
+                        try!(self.validate_alternative(alternative, ast));
                     }
                 }
                 GrammarItem::InternToken(..) => { }
@@ -152,14 +303,436 @@ impl<'grammar> Validator<'grammar> {
         Ok(())
     }
 
+    /// The annotations of `match` and `extern` sections are
+    /// `#[normalize = "path::to::function"]` and, on `match` sections,
+    /// `#[newlines(...)]`.
+    fn validate_section_annotations(&self,
+                                    annotations: &[Annotation],
+                                    match_section: bool)
+                                    -> NormResult<()> {
+        let normalize_annotation = intern(NORMALIZE);
+        let newlines_annotation = intern(NEWLINES);
+        let mut found_annotations = set();
+        for annotation in annotations {
+            if annotation.id != normalize_annotation && annotation.id != newlines_annotation {
+                return_err!(annotation.id_span,
+                            "unrecognized annotation `{}`",
+                            annotation.id);
+            } else if !found_annotations.insert(annotation.id) {
+                return_err!(annotation.id_span,
+                            "duplicate annotation `{}`",
+                            annotation.id);
+            }
+            if annotation.id == newlines_annotation {
+                if !match_section {
+                    return_err!(annotation.id_span,
+                                "#[newlines] goes on the `match` section, as it requires \
+                                 the built-in tokenizer");
+                }
+                try!(self.validate_newlines(annotation));
+                continue;
+            }
+            try!(self.validate_no_params(annotation));
+            match annotation.arg {
+                None => {
+                    return_err!(annotation.id_span,
+                                "annotation `normalize` requires a function, \
+                                 like `#[normalize = \"fold_keywords\"]`");
+                }
+                Some((span, path)) => {
+                    if !read(|interner| is_path(interner.data(path))) {
+                        return_err!(span, "`{}` is not a path", path);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// `#[newlines(insert = [";"], after = [...], open = [...], close =
+    /// [...])]` needs exactly one terminal to insert and at least one
+    /// to insert it after; the brackets are optional, but must come in
+    /// pairs.
+    fn validate_newlines(&self, annotation: &Annotation) -> NormResult<()> {
+        try!(self.validate_no_arg(annotation));
+        let keys = [INSERT, AFTER, OPEN, CLOSE];
+        let mut lengths = map();
+        for param in &annotation.params {
+            let terminals = match param.value {
+                AnnotationValue::Terminals(ref terminals) => terminals,
+                AnnotationValue::String(_) => {
+                    return_err!(param.key_span,
+                                "`{}` must be a list of terminals, like `{} = [\";\"]`",
+                                param.key,
+                                param.key);
+                }
+            };
+            if !keys.iter().any(|&key| param.key == intern(key)) {
+                return_err!(param.key_span,
+                            "unrecognized parameter `{}`, try one of the following: {}",
+                            param.key,
+                            keys.join(", "));
+            }
+            if lengths.insert(param.key, terminals.len()).is_some() {
+                return_err!(param.key_span,
+                            "duplicate parameter `{}`",
+                            param.key);
+            }
+            if param.key == intern(INSERT) && terminals.len() != 1 {
+                return_err!(param.key_span,
+                            "`{}` takes exactly one terminal",
+                            param.key);
+            }
+            if param.key == intern(AFTER) && terminals.is_empty() {
+                return_err!(param.key_span,
+                            "`{}` needs at least one terminal",
+                            param.key);
+            }
+        }
+        for &key in &[INSERT, AFTER] {
+            if !lengths.contains_key(&intern(key)) {
+                return_err!(annotation.id_span,
+                            "annotation `{}` requires the parameter `{}`",
+                            annotation.id,
+                            key);
+            }
+        }
+        if lengths.get(&intern(OPEN)) != lengths.get(&intern(CLOSE)) {
+            return_err!(annotation.id_span,
+                        "the `{}` and `{}` brackets of annotation `{}` must come in pairs",
+                        OPEN,
+                        CLOSE,
+                        annotation.id);
+        }
+        Ok(())
+    }
+
+    /// The annotations of match entries are `#[unescape]` and, on
+    /// quoted literals, `#[reserved]`, `#[reserved = "..."]` or
+    /// `#[contextual]`.
+    fn validate_match_item_annotations(&self, item: &MatchItem) -> NormResult<()> {
+        let unescape_annotation = intern(UNESCAPE);
+        let reserved_annotation = intern(RESERVED);
+        let contextual_annotation = intern(CONTEXTUAL);
+        let mut found_annotations = set();
+        for annotation in item.annotations() {
+            if annotation.id != unescape_annotation &&
+               annotation.id != reserved_annotation &&
+               annotation.id != contextual_annotation {
+                return_err!(annotation.id_span,
+                            "unrecognized annotation `{}`",
+                            annotation.id);
+            } else if !found_annotations.insert(annotation.id) {
+                return_err!(annotation.id_span,
+                            "duplicate annotation `{}`",
+                            annotation.id);
+            }
+            try!(self.validate_no_params(annotation));
+            if annotation.id == reserved_annotation || annotation.id == contextual_annotation {
+                let symbol = match *item {
+                    MatchItem::Unmapped(symbol, ..) | MatchItem::Mapped(symbol, ..) => symbol,
+                    MatchItem::CatchAll(_) => unreachable!(),
+                };
+                if let TerminalLiteral::Regex(_) = symbol {
+                    return_err!(annotation.id_span,
+                                "only quoted literals can be marked #[{}]",
+                                annotation.id);
+                }
+                if found_annotations.contains(&reserved_annotation) &&
+                   found_annotations.contains(&contextual_annotation) {
+                    return_err!(annotation.id_span,
+                                "a keyword cannot be both #[reserved] and #[contextual]");
+                }
+                if annotation.id == reserved_annotation {
+                    continue;
+                }
+            }
+            try!(self.validate_no_arg(annotation));
+            if annotation.id == unescape_annotation {
+                if let MatchItem::Mapped(_, _, _, _, Some(_)) = *item {
+                    return_err!(annotation.id_span,
+                                "match entries with a hook cannot be marked #[unescape]");
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_no_arg(&self, annotation: &Annotation) -> NormResult<()> {
+        if let Some((span, _)) = annotation.arg {
+            return_err!(span,
+                        "annotation `{}` does not take a value",
+                        annotation.id);
+        }
+        Ok(())
+    }
+
+    fn validate_no_params(&self, annotation: &Annotation) -> NormResult<()> {
+        if let Some(param) = annotation.params.first() {
+            return_err!(param.key_span,
+                        "annotation `{}` does not take parameters",
+                        annotation.id);
+        }
+        Ok(())
+    }
+
+    /// `#[recover(skip_until = ["a", ...], value = "...")]` needs both
+    /// parameters, with a non-empty list of terminals.
+    fn validate_recover(&self, annotation: &Annotation) -> NormResult<()> {
+        let mut found_keys = set();
+        for param in &annotation.params {
+            if !found_keys.insert(param.key) {
+                return_err!(param.key_span,
+                            "duplicate parameter `{}`",
+                            param.key);
+            }
+            match param.value {
+                AnnotationValue::Terminals(ref terminals) if param.key == intern(SKIP_UNTIL) => {
+                    if terminals.is_empty() {
+                        return_err!(param.key_span,
+                                    "`{}` needs at least one terminal",
+                                    param.key);
+                    }
+                    if terminals.iter().any(|t| t.as_literal().is_none()) {
+                        return_err!(param.key_span,
+                                    "the terminals of `{}` must be quoted",
+                                    param.key);
+                    }
+                }
+                AnnotationValue::String(_) if param.key == intern(VALUE) => { }
+                _ if param.key == intern(SKIP_UNTIL) => {
+                    return_err!(param.key_span,
+                                "`{}` must be a list of terminals, like `{} = [\";\"]`",
+                                param.key,
+                                param.key);
+                }
+                _ if param.key == intern(VALUE) => {
+                    return_err!(param.key_span,
+                                "`{}` must be a string of Rust code",
+                                param.key);
+                }
+                _ => {
+                    return_err!(param.key_span,
+                                "unrecognized parameter `{}`, try one of the following: {}, {}",
+                                param.key,
+                                SKIP_UNTIL,
+                                VALUE);
+                }
+            }
+        }
+        for &key in &[SKIP_UNTIL, VALUE] {
+            if !found_keys.contains(&intern(key)) {
+                return_err!(annotation.id_span,
+                            "annotation `{}` requires the parameter `{}`",
+                            annotation.id,
+                            key);
+            }
+        }
+        Ok(())
+    }
+
+    /// `#[recover_delimiters(open = [...], close = [...])]` needs both
+    /// lists of quoted terminals, pairing them up by position.
+    fn validate_recover_delimiters(&self, annotation: &Annotation) -> NormResult<()> {
+        let mut lengths = map();
+        for param in &annotation.params {
+            if param.key != intern(OPEN) && param.key != intern(CLOSE) {
+                return_err!(param.key_span,
+                            "unrecognized parameter `{}`, try one of the following: {}, {}",
+                            param.key,
+                            OPEN,
+                            CLOSE);
+            }
+            let terminals = match param.value {
+                AnnotationValue::Terminals(ref terminals) if !terminals.is_empty() => terminals,
+                _ => {
+                    return_err!(param.key_span,
+                                "`{}` must be a list of terminals, like `{} = [\"(\"]`",
+                                param.key,
+                                param.key);
+                }
+            };
+            if terminals.iter().any(|t| t.as_literal().is_none()) {
+                return_err!(param.key_span,
+                            "the terminals of `{}` must be quoted",
+                            param.key);
+            }
+            if lengths.insert(param.key, terminals.len()).is_some() {
+                return_err!(param.key_span,
+                            "duplicate parameter `{}`",
+                            param.key);
+            }
+        }
+        match (lengths.get(&intern(OPEN)), lengths.get(&intern(CLOSE))) {
+            (Some(open), Some(close)) if open == close => Ok(()),
+            _ => {
+                return_err!(annotation.id_span,
+                            "annotation `{}` requires `{}` and `{}` lists of the same length",
+                            annotation.id,
+                            OPEN,
+                            CLOSE);
+            }
+        }
+    }
+
+    /// `#[max_nesting(depth = "N")]` needs a positive depth.
+    fn validate_max_nesting(&self, annotation: &Annotation) -> NormResult<()> {
+        let depth = annotation.params.iter().find(|param| param.key == intern(DEPTH));
+        for param in &annotation.params {
+            if param.key != intern(DEPTH) {
+                return_err!(param.key_span,
+                            "unrecognized parameter `{}`, try `{}`",
+                            param.key,
+                            DEPTH);
+            }
+        }
+        let valid = match depth {
+            Some(&AnnotationParam { value: AnnotationValue::String(s), .. }) => {
+                read(|interner| interner.data(s).parse::<usize>().map(|d| d > 0).unwrap_or(false))
+            }
+            _ => false,
+        };
+        if !valid {
+            return_err!(annotation.id_span,
+                        "annotation `{}` requires a positive depth, like \
+                         `#[{}({} = \"256\")]`",
+                        annotation.id,
+                        annotation.id,
+                        DEPTH);
+        }
+        Ok(())
+    }
+
+    /// `#[cfg(key = "value", ...)]` needs at least one setting, and
+    /// each setting's value must be a string.
+    fn validate_cfg(&self, annotation: &Annotation) -> NormResult<()> {
+        try!(self.validate_no_arg(annotation));
+        if annotation.params.is_empty() {
+            return_err!(annotation.id_span,
+                        "annotation `{}` requires a setting, like `#[{}(feature = \"x\")]`",
+                        annotation.id,
+                        annotation.id);
+        }
+        for param in &annotation.params {
+            if let AnnotationValue::Terminals(_) = param.value {
+                return_err!(param.key_span,
+                            "`{}` must be a string, like `{} = \"x\"`",
+                            param.key,
+                            param.key);
+            }
+        }
+        Ok(())
+    }
+
+    /// Alternatives of a `#[pratt]` nonterminal may carry a single
+    /// operator annotation with a precedence level, like `#[left = "1"]`.
+    /// Any alternative may carry `#[dprec = "N"]`, `#[merge = "f"]`,
+    /// `#[cfg(...)]` and `#[since = "version"]`, and those of an `#[ast]`
+    /// nonterminal `#[variant = "Name"]`.
+    fn validate_alternative_annotations(&self,
+                                        alternative: &Alternative,
+                                        pratt: bool,
+                                        ast: bool)
+                                        -> NormResult<()> {
+        let operator_annotations = vec![intern(LEFT),
+                                        intern(RIGHT),
+                                        intern(PREFIX),
+                                        intern(POSTFIX)];
+        let mut operator = false;
+        for (index, annotation) in alternative.annotations.iter().enumerate() {
+            if annotation.id != intern(CFG) {
+                try!(self.validate_no_params(annotation));
+            }
+            if alternative.annotations[..index].iter().any(|a| a.id == annotation.id) {
+                return_err!(annotation.id_span,
+                            "duplicate annotation `{}`",
+                            annotation.id);
+            } else if annotation.id == intern(CFG) {
+                try!(self.validate_cfg(annotation));
+            } else if annotation.id == intern(SINCE) {
+                match annotation.arg {
+                    Some((span, arg)) => {
+                        if let Err(message) = read(|interner| interner.data(arg).parse::<Version>()) {
+                            return_err!(span, "{}", message);
+                        }
+                    }
+                    None => return_err!(annotation.id_span,
+                                        "annotation `since` requires a version, \
+                                         like `#[since = \"2.0\"]`"),
+                }
+            } else if annotation.id == intern(DPREC) {
+                match annotation.arg {
+                    Some((span, arg)) => {
+                        if read(|interner| interner.data(arg).parse::<u32>()).is_err() {
+                            return_err!(span,
+                                        "`dprec` must be a number, not `{}`",
+                                        arg);
+                        }
+                    }
+                    None => return_err!(annotation.id_span,
+                                        "annotation `dprec` requires a value, \
+                                         like `#[dprec = \"1\"]`"),
+                }
+            } else if annotation.id == intern(MERGE) {
+                if annotation.arg.is_none() {
+                    return_err!(annotation.id_span,
+                                "annotation `merge` requires the path of a function, \
+                                 like `#[merge = \"merge_exprs\"]`");
+                }
+            } else if annotation.id == intern(VARIANT) {
+                if !ast {
+                    return_err!(annotation.id_span,
+                                "annotation `variant` is only allowed in #[ast] items");
+                }
+                if annotation.arg.is_none() {
+                    return_err!(annotation.id_span,
+                                "annotation `variant` requires the name of a variant, \
+                                 like `#[variant = \"Add\"]`");
+                }
+            } else if !operator_annotations.contains(&annotation.id) {
+                return_err!(annotation.id_span,
+                            "unrecognized annotation `{}`",
+                            annotation.id);
+            } else if !pratt {
+                return_err!(annotation.id_span,
+                            "operator annotations like `{}` are only allowed \
+                             in #[pratt] items",
+                            annotation.id);
+            } else if operator {
+                return_err!(annotation.id_span,
+                            "an alternative can have only one operator annotation");
+            } else if annotation.arg.is_none() {
+                return_err!(annotation.id_span,
+                            "annotation `{}` requires a precedence level, \
+                             like `#[{} = \"1\"]`",
+                            annotation.id,
+                            annotation.id);
+            } else {
+                operator = true;
+            }
+        }
+        Ok(())
+    }
+
     fn validate_alternative(&self,
-                            alternative: &Alternative)
+                            alternative: &Alternative,
+                            ast: bool)
                             -> NormResult<()> {
         try!(self.validate_expr(&alternative.expr));
 
+        if alternative.guard.is_some() {
+            match alternative.action {
+                Some(ActionKind::Lookahead) | Some(ActionKind::Lookbehind) => {
+                    return_err!(alternative.span,
+                                "guards cannot be used with `=>@L` or `=>@R`");
+                }
+                _ => { }
+            }
+        }
+
         match norm_util::analyze_expr(&alternative.expr) {
             Symbols::Named(syms) => {
-                if alternative.action.is_none() {
+                if alternative.action.is_none() && !ast {
                     let sym =
                         syms.iter()
                             .map(|&(_, _, sym)| sym)
@@ -289,3 +862,12 @@ impl<'grammar> Validator<'grammar> {
         Ok(())
     }
 }
+
+/// True for paths like `fold_keywords` and `::util::nfc`.
+fn is_path(text: &str) -> bool {
+    let text = if text.starts_with("::") { &text[2..] } else { text };
+    text.split("::").all(|id| {
+        !id.is_empty() && !id.starts_with(|c: char| c.is_digit(10)) &&
+        id.chars().all(|c| c == '_' || c.is_alphanumeric())
+    })
+}
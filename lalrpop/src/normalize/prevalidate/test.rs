@@ -125,4 +125,267 @@ fn mixing_names_and_anonymous_values() {
         r#"anonymous symbols like this one cannot be combined with named symbols like `b:B`"#,
         r#"grammar; Term = { <A> <b:B> => Alien: Eighth passanger of Nostromo};"#,
         r#"                  ~~~                                               "#);
-}
\ No newline at end of file
+}
+#[test]
+fn annotation_value() {
+    check_err(
+        r#"annotation `inline` does not take a value"#,
+        r#"grammar; #[inline = "1"] Term = ();"#,
+        r#"                    ~~~            "#);
+}
+
+#[test]
+fn operator_annotation_without_pratt() {
+    check_err(
+        r#"operator annotations like `left` are only allowed in #\[pratt\] items"#,
+        r#"grammar; E = { #[left = "1"] E "+" E, "x" };"#,
+        r#"                 ~~~~                       "#);
+}
+
+#[test]
+fn operator_annotation_without_level() {
+    check_err(
+        r#"annotation `prefix` requires a precedence level"#,
+        r#"grammar; #[pratt] E = { #[prefix] "-" E, "x" };"#,
+        r#"                          ~~~~~~               "#);
+}
+
+#[test]
+fn inline_pratt() {
+    check_err(
+        r#"#\[pratt\] items cannot be marked #\[inline\]"#,
+        r#"grammar; #[pratt] #[inline] E = "x";"#,
+        r#"                            ~       "#);
+}
+
+#[test]
+fn guard_with_lookaround() {
+    check_err(
+        r#"guards cannot be used with `=>@L` or `=>@R`"#,
+        r#"grammar; extern { type Location = usize; enum Tok { } } Foo = if { true } =>@L;"#,
+        r#"                                                              ~~~~~~~~~~~~~~~~  "#);
+}
+
+#[test]
+fn dprec_not_a_number() {
+    check_err(
+        r#"`dprec` must be a number, not `high`"#,
+        r#"grammar; E = { #[dprec = "high"] "x", "y" };"#,
+        r#"                         ~~~~~~             "#);
+}
+
+#[test]
+fn duplicate_merge() {
+    check_err(
+        r#"duplicate annotation `merge`"#,
+        r#"grammar; E = { #[merge = "f"] #[merge = "g"] "x" };"#,
+        r#"                                ~~~~~               "#);
+}
+
+#[test]
+fn recover_without_value() {
+    check_err(
+        r#"annotation `recover` requires the parameter `value`"#,
+        r#"grammar; #[recover(skip_until = [";"])] Stmt = "x" ";";"#,
+        r#"           ~~~~~~~                                     "#);
+}
+
+#[test]
+fn recover_unknown_parameter() {
+    check_err(
+        r#"unrecognized parameter `until`, try one of the following: skip_until, value"#,
+        r#"grammar; #[recover(until = [";"], value = "()")] Stmt = "x" ";";"#,
+        r#"                   ~~~~~                                        "#);
+}
+
+#[test]
+fn recover_empty_skip_until() {
+    check_err(
+        r#"`skip_until` needs at least one terminal"#,
+        r#"grammar; #[recover(skip_until = [], value = "()")] Stmt = "x";"#,
+        r#"                   ~~~~~~~~~~                                "#);
+}
+
+#[test]
+fn params_on_inline() {
+    check_err(
+        r#"annotation `inline` does not take parameters"#,
+        r#"grammar; #[inline(value = "()")] Stmt = "x";"#,
+        r#"                  ~~~~~                     "#);
+}
+
+#[test]
+fn cfg_without_settings() {
+    check_err(
+        r#"annotation `cfg` requires a setting, like `#\[cfg\(feature = "x"\)\]`"#,
+        r#"grammar; #[cfg()] Stmt = "x";"#,
+        r#"           ~~~                "#);
+}
+
+#[test]
+fn cfg_terminals() {
+    check_err(
+        r#"`feature` must be a string, like `feature = "x"`"#,
+        r#"grammar; Stmt = { #[cfg(feature = ["x"])] "x" };"#,
+        r#"                        ~~~~~~~                  "#);
+}
+
+#[test]
+fn since_invalid_version() {
+    check_err(
+        r#"invalid version `2.x`"#,
+        r#"grammar; E = { "x", #[since = "2.x"] "y" };"#,
+        r#"                              ~~~~~       "#);
+}
+
+#[test]
+fn unknown_match_entry_annotation() {
+    check_err(
+        r#"unrecognized annotation `inline`"#,
+        r#"grammar; match { #[inline] "x" } X = "x";"#,
+        r#"                   ~~~~~~                "#);
+}
+
+#[test]
+fn unescape_with_hook() {
+    check_err(
+        r#"match entries with a hook cannot be marked #\[unescape\]"#,
+        r#"grammar; match { #[unescape] "x" => X { f } } Y = X;"#,
+        r#"                   ~~~~~~~~                         "#);
+}
+
+#[test]
+fn reserved_regex() {
+    check_err(
+        r#"only quoted literals can be marked #\[reserved\]"#,
+        r#"grammar; match { #[reserved] r"[a-z]+" } X = r"[a-z]+";"#,
+        r#"                   ~~~~~~~~                            "#);
+}
+
+#[test]
+fn reserved_and_contextual() {
+    check_err(
+        r#"a keyword cannot be both #\[reserved\] and #\[contextual\]"#,
+        r#"grammar; match { #[reserved] #[contextual] "x" } else { _ } X = "x";"#,
+        r#"                               ~~~~~~~~~~                          "#);
+}
+
+#[test]
+fn normalize_without_function() {
+    check_err(
+        r#"annotation `normalize` requires a function, like `#\[normalize = "fold_keywords"\]`"#,
+        r#"grammar; #[normalize] match { _ } X = "x";"#,
+        r#"           ~~~~~~~~~                      "#);
+}
+
+#[test]
+fn normalize_not_a_path() {
+    check_err(
+        r#"`a-b` is not a path"#,
+        r#"grammar; #[normalize = "a-b"] match { _ } X = "x";"#,
+        r#"                       ~~~~~                      "#);
+}
+
+#[test]
+fn newlines_without_insert() {
+    check_err(
+        r#"annotation `newlines` requires the parameter `insert`"#,
+        r#"grammar; #[newlines(after = [ID])] match { _ } X = "x";"#,
+        r#"           ~~~~~~~~                                    "#);
+}
+
+#[test]
+fn newlines_insert_two() {
+    check_err(
+        r#"`insert` takes exactly one terminal"#,
+        r#"grammar; #[newlines(insert = [";", ","], after = [ID])] match { _ } X = "x";"#,
+        r#"                    ~~~~~~                                                  "#);
+}
+
+#[test]
+fn newlines_unpaired_brackets() {
+    check_err(
+        r#"the `open` and `close` brackets of annotation `newlines` must come in pairs"#,
+        r#"grammar; #[newlines(insert = [";"], after = [ID], open = ["("])] match { _ } X = "x";"#,
+        r#"           ~~~~~~~~                                                                 "#);
+}
+
+#[test]
+fn newlines_on_extern() {
+    check_err(
+        r#"#\[newlines\] goes on the `match` section, as it requires the built-in tokenizer"#,
+        r#"grammar; #[newlines(insert = [";"], after = [ID])] extern { } X = "x";"#,
+        r#"           ~~~~~~~~                                                   "#);
+}
+
+#[test]
+fn recover_bare_terminal() {
+    check_err(
+        r#"the terminals of `skip_until` must be quoted"#,
+        r#"grammar; #[recover(skip_until = [X], value = "()")] X = "x";"#,
+        r#"                   ~~~~~~~~~~                               "#);
+}
+
+#[test]
+fn recover_delimiters_unpaired() {
+    check_err(
+        r#"annotation `recover_delimiters` requires `open` and `close` lists of the same length"#,
+        r#"#[recover_delimiters(open = ["(", "{"], close = [")"])] grammar; X = "x";"#,
+        r#"  ~~~~~~~~~~~~~~~~~~                                                   "#);
+}
+
+#[test]
+fn max_nesting_without_depth() {
+    check_err(
+        r#"annotation `max_nesting` requires a positive depth, like `#\[max_nesting\(depth = "256"\)\]`"#,
+        r#"grammar; #[max_nesting(depth = "0")] X = "x";"#,
+        r#"           ~~~~~~~~~~~                       "#);
+}
+
+#[test]
+fn max_nesting_unknown_parameter() {
+    check_err(
+        r#"unrecognized parameter `levels`, try `depth`"#,
+        r#"grammar; #[max_nesting(levels = "2")] X = "x";"#,
+        r#"                       ~~~~~~                 "#);
+}
+
+#[test]
+fn max_nesting_inline() {
+    check_err(
+        r#"#\[max_nesting\] items cannot be marked #\[inline\]"#,
+        r#"grammar; #[inline] #[max_nesting(depth = "2")] X = "x";"#,
+        r#"                                               ~      "#);
+}
+
+#[test]
+fn lookahead_three_tokens() {
+    check_err(
+        r#"only two tokens of lookahead are supported, as in `#\[lookahead = "2"\]`"#,
+        r#"grammar; #[lookahead = "3"] X = "x";"#,
+        r#"                       ~~~          "#);
+}
+
+#[test]
+fn format_without_string() {
+    check_err(
+        r#"annotation `format` requires a format string, like `#\[format = "\{\}"\]`"#,
+        r#"grammar; #[format] X: i32 = "x" => 1;"#,
+        r#"           ~~~~~~                     "#);
+}
+
+#[test]
+fn variant_outside_ast() {
+    check_err(
+        r#"annotation `variant` is only allowed in #\[ast\] items"#,
+        r#"grammar; X: i32 = { #[variant = "One"] "x" => 1 };"#,
+        r#"                      ~~~~~~~                     "#);
+}
+
+#[test]
+fn ast_derive_outside_ast() {
+    check_err(
+        r#"annotation `ast_derive` is only allowed in #\[ast\] items"#,
+        r#"grammar; #[ast_derive = "Clone"] X: i32 = "x" => 1;"#,
+        r#"           ~~~~~~~~~~                              "#);
+}
@@ -0,0 +1,116 @@
+//! Checks that every nonterminal can derive at least one string of
+//! terminals. A nonterminal whose every alternative depends (directly
+//! or indirectly) on itself can never be reduced; left alone, this
+//! shows up as a confusing failure in the table builder. Only the
+//! nonterminals reachable from a public one are checked: the others
+//! never make it into the tables, and are already reported by the
+//! `lalrpop::never_reduced` lint.
+
+use super::{NormResult, NormError};
+
+use collections::{set, Set};
+use grammar::repr::*;
+use lint;
+
+#[cfg(test)]
+mod test;
+
+pub fn check(grammar: &Grammar) -> NormResult<()> {
+    let productive = productive_nonterminals(grammar);
+    let reachable = lint::reachable(grammar);
+
+    // Report the first offending nonterminal the user wrote, skipping
+    // the artificial start symbols that we introduce.
+    let offender =
+        grammar.nonterminals
+               .values()
+               .filter(|data| !productive.contains(&data.name))
+               .filter(|data| reachable.contains(&data.name))
+               .filter(|data| !grammar.start_nonterminals.values().any(|&nt| nt == data.name))
+               .min_by_key(|data| data.span.0);
+
+    let data = match offender {
+        Some(data) => data,
+        None => return Ok(()),
+    };
+
+    // Productions with no alternatives are reported during type
+    // inference, with a better message.
+    if data.productions.is_empty() {
+        return Ok(());
+    }
+
+    let cycle = dependency_cycle(grammar, &productive, data.name);
+    return_err!(
+        data.span,
+        "nonterminal `{}` can never match any input, because each of its \
+         alternatives depends on itself again: {}",
+        data.name,
+        cycle.iter()
+             .map(|nt| format!("`{}`", nt))
+             .collect::<Vec<_>>()
+             .join(" -> "));
+}
+
+/// Computes the set of nonterminals that derive at least one string
+/// of terminals, by iterating to a fixed point.
+fn productive_nonterminals(grammar: &Grammar) -> Set<NonterminalString> {
+    let mut productive = set();
+    loop {
+        let mut changed = false;
+        for (&nt, data) in &grammar.nonterminals {
+            if productive.contains(&nt) {
+                continue;
+            }
+            let derives = data.productions.iter().any(|production| {
+                production.symbols.iter().all(|symbol| match *symbol {
+                    Symbol::Terminal(_) => true,
+                    Symbol::Nonterminal(nt) => productive.contains(&nt),
+                })
+            });
+            if derives {
+                productive.insert(nt);
+                changed = true;
+            }
+        }
+        if !changed {
+            return productive;
+        }
+    }
+}
+
+/// Starting from the unproductive nonterminal `start`, follows the
+/// first unproductive nonterminal referenced by its first alternative
+/// until some nonterminal repeats, and returns the path taken. (Every
+/// alternative of an unproductive nonterminal references one, or it
+/// would be productive; the path only ends early at a nonterminal
+/// without alternatives.)
+fn dependency_cycle(grammar: &Grammar,
+                    productive: &Set<NonterminalString>,
+                    start: NonterminalString)
+                    -> Vec<NonterminalString> {
+    let mut path = vec![start];
+    let mut current = start;
+    loop {
+        let next =
+            grammar.productions_for(current)
+                   .first()
+                   .into_iter()
+                   .flat_map(|production| &production.symbols)
+                   .filter_map(|symbol| match *symbol {
+                       Symbol::Nonterminal(nt) if !productive.contains(&nt) => Some(nt),
+                       _ => None,
+                   })
+                   .next();
+        let next = match next {
+            Some(nt) => nt,
+            None => return path,
+        };
+        let repeated = path.contains(&next);
+        path.push(next);
+        if repeated {
+            return path;
+        }
+        current = next;
+    }
+}
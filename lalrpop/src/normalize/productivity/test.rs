@@ -0,0 +1,43 @@
+use grammar::repr::Grammar;
+use normalize::{self, NormResult};
+use parser;
+use session::Session;
+use test_util;
+
+use super::check;
+
+fn lowered_grammar(text: &str) -> Grammar {
+    let g = parser::parse_grammar(text).unwrap();
+    normalize::lower_helper(&Session::test(), g, true).unwrap()
+}
+
+fn check_err(expected_err: &str, grammar: &str, span: &str) {
+    let err: NormResult<()> = check(&lowered_grammar(grammar));
+    test_util::check_norm_err(expected_err, span, err.unwrap_err());
+}
+
+#[test]
+fn self_recursive() {
+    check_err(
+        r#"nonterminal `A` can never match any input, because each of its alternatives depends on itself again: `A` -> `A`"#,
+        r#"grammar; pub A: () = { "x" A => () };"#,
+        r#"             ~                        "#);
+}
+
+#[test]
+fn mutually_recursive() {
+    check_err(
+        r#"`A` -> `B` -> `A`"#,
+        r#"grammar; pub A: () = { B "x" => (), "(" A => () }; B: () = "y" A => ();"#,
+        r#"             ~                                                           "#);
+}
+
+#[test]
+fn productive() {
+    check(&lowered_grammar(r#"grammar; pub A: () = { "x" A => (), "y" => () };"#)).unwrap();
+}
+
+#[test]
+fn unreachable() {
+    check(&lowered_grammar(r#"grammar; pub A: () = "x" => (); B: () = "y" B => ();"#)).unwrap();
+}
@@ -0,0 +1,78 @@
+//! Expands `#[recover]` annotations into error-recovery alternatives,
+//! so that the recovery behavior of a nonterminal can be declared
+//! next to it rather than by placing `!` by hand. Given:
+//!
+//!     #[recover(skip_until = [";", "}"], value = "Stmt::Error")]
+//!     Stmt: Stmt = {
+//!         <e:Expr> ";" => Stmt::Expr(e),
+//!     };
+//!
+//! we generate:
+//!
+//!     Stmt: Stmt = {
+//!         <e:Expr> ";" => Stmt::Expr(e),
+//!         <error:!> ";" => Stmt::Error,
+//!         <error:!> "}" => Stmt::Error,
+//!     };
+//!
+//! When a `Stmt` cannot be parsed, the parser drops tokens until one
+//! of the synchronization tokens, which ends the `Stmt`, and uses the
+//! fallback value for it. The fallback can refer to the
+//! `ErrorRecovery` as `error`.
+
+use super::NormResult;
+
+use grammar::consts::{RECOVER, SKIP_UNTIL, VALUE};
+use grammar::parse_tree::*;
+use intern::{intern, read};
+
+#[cfg(test)]
+mod test;
+
+pub fn expand(mut grammar: Grammar) -> NormResult<Grammar> {
+    for item in &mut grammar.items {
+        if let GrammarItem::Nonterminal(ref mut data) = *item {
+            let recover = intern(RECOVER);
+            if let Some(position) = data.annotations.iter().position(|a| a.id == recover) {
+                let annotation = data.annotations.remove(position);
+                expand_nonterminal(data, annotation);
+            }
+        }
+    }
+    Ok(grammar)
+}
+
+fn expand_nonterminal(data: &mut NonterminalData, annotation: Annotation) {
+    let mut terminals = vec![];
+    let mut value = None;
+    for param in annotation.params {
+        match param.value {
+            AnnotationValue::Terminals(t) => {
+                assert_eq!(param.key, intern(SKIP_UNTIL));
+                terminals = t;
+            }
+            AnnotationValue::String(s) => {
+                assert_eq!(param.key, intern(VALUE));
+                value = Some(read(|interner| interner.data(s).to_string()));
+            }
+        }
+    }
+    let value = value.expect("validation permitted #[recover] without a value");
+
+    let span = annotation.id_span;
+    for terminal in terminals {
+        let error = Symbol::new(span, SymbolKind::Error);
+        data.alternatives.push(Alternative {
+            span: span,
+            annotations: vec![],
+            expr: ExprSymbol {
+                symbols: vec![Symbol::new(span,
+                                          SymbolKind::Name(intern("error"), Box::new(error))),
+                              Symbol::new(span, SymbolKind::Terminal(terminal))],
+            },
+            condition: None,
+            guard: None,
+            action: Some(ActionKind::User(value.clone())),
+        });
+    }
+}
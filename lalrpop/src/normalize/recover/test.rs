@@ -0,0 +1,53 @@
+use grammar::parse_tree::Grammar;
+use normalize::resolve::resolve;
+use parser;
+use test_util::compare;
+
+use super::expand;
+
+fn resolved(grammar: &str) -> Grammar {
+    resolve(parser::parse_grammar(grammar).unwrap()).unwrap()
+}
+
+#[test]
+fn one_alternative_per_terminal() {
+    let actual = expand(resolved(r#"
+grammar;
+    #[recover(skip_until = [";", "}"], value = "Stmt::Error")]
+    Stmt: Stmt = {
+        <e:"Expr"> ";" => Stmt::Expr(e),
+    };
+"#)).unwrap();
+
+    let expected = resolved(r#"
+grammar;
+    Stmt: Stmt = {
+        <e:"Expr"> ";" => Stmt::Expr(e),
+        <error:!> ";" => Stmt::Error,
+        <error:!> "}" => Stmt::Error,
+    };
+"#);
+
+    compare(actual, expected);
+}
+
+#[test]
+fn other_annotations_are_kept() {
+    let actual = expand(resolved(r#"
+grammar;
+    #[inline]
+    #[recover(skip_until = [";"], value = "()")]
+    Stmt: () = "x" ";";
+"#)).unwrap();
+
+    let expected = resolved(r#"
+grammar;
+    #[inline]
+    Stmt: () = {
+        "x" ";",
+        <error:!> ";" => (),
+    };
+"#);
+
+    compare(actual, expected);
+}
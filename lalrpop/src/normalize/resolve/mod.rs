@@ -6,6 +6,7 @@ use super::{NormResult, NormError};
 use grammar::parse_tree::*;
 use intern::{InternedString};
 use collections::{map, Map};
+use util::{did_you_mean, near_misses};
 
 #[cfg(test)]
 mod test;
@@ -49,7 +50,7 @@ fn resolve_in_place(grammar: &mut Grammar) -> NormResult<()> {
                    .flat_map(|match_token| &match_token.contents)
                    .flat_map(|match_contents| &match_contents.items)
                    .filter_map(|item| match *item {
-                       MatchItem::Mapped(_, TerminalString::Bare(id), _) => Some((item.span(), id, Def::Terminal)),
+                       MatchItem::Mapped(_, TerminalString::Bare(id), _, _, _) => Some((item.span(), id, Def::Terminal)),
                        _ => None
                    });
 
@@ -103,6 +104,28 @@ struct ScopeChain<'scope> {
 }
 
 impl Def {
+    /// Whether the name can stand for a symbol on its own.
+    fn is_symbol(self) -> bool {
+        match self {
+            Def::Terminal | Def::Nonterminal(0) | Def::MacroArg => true,
+            Def::Nonterminal(_) => false,
+        }
+    }
+
+    fn is_macro(self) -> bool {
+        match self {
+            Def::Nonterminal(arity) => arity > 0,
+            _ => false,
+        }
+    }
+
+    fn is_macro_arg(self) -> bool {
+        match self {
+            Def::MacroArg => true,
+            _ => false,
+        }
+    }
+
     fn description(&self) -> &'static str {
         match *self {
             Def::Terminal => "terminal",
@@ -153,7 +176,10 @@ impl Validator {
                             alternative: &mut Alternative)
                             -> NormResult<()> {
         if let Some(ref condition) = alternative.condition {
-            let def = try!(self.validate_id(scope, condition.span, condition.lhs.0));
+            let def = try!(self.validate_id(scope,
+                                            condition.span,
+                                            condition.lhs.0,
+                                            Def::is_macro_arg));
             match def {
                 Def::MacroArg => { /* OK */ }
                 _ => {
@@ -198,7 +224,7 @@ impl Validator {
             SymbolKind::Nonterminal(id) => {
                 // in normal operation, the parser never produces Nonterminal(_) entries,
                 // but during testing we do produce nonterminal entries
-                let def = try!(self.validate_id(scope, symbol.span, id.0));
+                let def = try!(self.validate_id(scope, symbol.span, id.0, Def::is_symbol));
                 match def {
                     Def::Nonterminal(0) |
                     Def::MacroArg => {
@@ -213,7 +239,7 @@ impl Validator {
             }
             SymbolKind::Macro(ref mut msym) => {
                 debug_assert!(msym.args.len() > 0);
-                let def = try!(self.validate_id(scope, symbol.span, msym.name.0));
+                let def = try!(self.validate_id(scope, symbol.span, msym.name.0, Def::is_macro));
                 match def {
                     Def::Nonterminal(0) |
                     Def::Terminal |
@@ -253,7 +279,7 @@ impl Validator {
                             id: InternedString,
                             symbol: &mut Symbol)
                             -> NormResult<()> {
-        symbol.kind = match try!(self.validate_id(scope, symbol.span, id)) {
+        symbol.kind = match try!(self.validate_id(scope, symbol.span, id, Def::is_symbol)) {
             Def::MacroArg |
             Def::Nonterminal(0) => SymbolKind::Nonterminal(NonterminalString(id)),
             Def::Terminal => SymbolKind::Terminal(TerminalString::Bare(id)),
@@ -262,14 +288,27 @@ impl Validator {
         Ok(())
     }
 
+    /// Looks up `id`; if it is not defined, the error suggests the
+    /// names close to it that are of the kind `expected` accepts,
+    /// like rustc does.
     fn validate_id(&self,
                    scope: &ScopeChain,
                    span: Span,
-                   id: InternedString)
+                   id: InternedString,
+                   expected: fn(Def) -> bool)
                    -> NormResult<Def> {
         match scope.def(id) {
             Some(def) => Ok(def),
-            None => return_err!(span, "no definition found for `{}`", id)
+            None => {
+                let candidates = scope.names()
+                                      .into_iter()
+                                      .filter(|&(_, def)| expected(def))
+                                      .map(|(name, _)| name);
+                return_err!(span,
+                            "no definition found for `{}`{}",
+                            id,
+                            did_you_mean(&near_misses(&id.to_string(), candidates)))
+            }
         }
     }
 }
@@ -280,4 +319,17 @@ impl<'scope> ScopeChain<'scope> {
                         .cloned()
                         .or_else(|| self.previous.and_then(|s| s.def(id)))
     }
+
+    /// The identifiers defined in this scope or the enclosing ones
+    /// (other than those this scope shadows).
+    fn names(&self) -> Vec<(InternedString, Def)> {
+        let mut names: Vec<(InternedString, Def)> =
+            self.identifiers.iter().map(|(&id, &def)| (id, def)).collect();
+        if let Some(previous) = self.previous {
+            names.extend(previous.names()
+                                 .into_iter()
+                                 .filter(|&(id, _)| !self.identifiers.contains_key(&id)));
+        }
+        names
+    }
 }
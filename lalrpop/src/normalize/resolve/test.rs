@@ -34,6 +34,45 @@ fn unknown_nonterminal() {
         r#"grammar; X = X >>>Y<<<;"#);
 }
 
+#[test]
+fn unknown_terminal_near_miss() {
+    check_err(
+        r"no definition found for `NUMBR`; did you mean `NUMBER`\?$",
+        r#"grammar; extern { enum T { NUMBER => .. } } X = X >>>NUMBR<<<;"#);
+}
+
+#[test]
+fn unknown_nonterminal_near_miss() {
+    check_err(
+        r"no definition found for `Exp`; did you mean `Expr`\?$",
+        r#"grammar; Expr = Expr "+" >>>Exp<<<; Atom = "a";"#);
+
+    // macros cannot stand on their own, so they are not suggested
+    check_err(
+        r"no definition found for `Comm`$",
+        r#"grammar; X = >>>Comm<<<; Comma<T> = T;"#);
+
+    // short names have no near misses
+    check_err(
+        r"no definition found for `Y`$",
+        r#"grammar; X = X >>>Y<<<;"#);
+}
+
+#[test]
+fn unknown_macro_near_miss() {
+    check_err(
+        r"no definition found for `Comm`; did you mean `Comma`\?$",
+        r#"grammar; X = >>>Comm<"a"><<<; Comma<T> = T; Commas = "b";"#);
+}
+
+#[test]
+fn unknown_macro_arg_near_miss() {
+    // only macro arguments can be used in conditions
+    check_err(
+        r"no definition found for `Mod`; did you mean `Mode`\?$",
+        r#"grammar; X<Mode> = "a" if >>>Mod == "x"<<<; Mods = "b";"#);
+}
+
 #[test]
 fn unknown_nonterminal_in_macro_arg() {
     check_err(
@@ -0,0 +1,77 @@
+//! Folds the token adapters of an `extern` section into the
+//! conversions of its token enum, so that a parser can be fed from
+//! two token sources merged into one enum. Given:
+//!
+//!     extern {
+//!         enum Tok {
+//!             ";" => Tok::Semi,
+//!         }
+//!         enum PpTok for Tok::Pp {
+//!             "#define" => PpTok::Define,
+//!         }
+//!     }
+//!
+//! we generate:
+//!
+//!     extern {
+//!         enum Tok {
+//!             ";" => Tok::Semi,
+//!             "#define" => Tok::Pp(PpTok::Define),
+//!         }
+//!     }
+
+use super::{NormResult, NormError};
+
+use collections::{map, Map};
+use grammar::parse_tree::*;
+use grammar::pattern::{Pattern, PatternKind};
+
+#[cfg(test)]
+mod test;
+
+pub fn expand(mut grammar: Grammar) -> NormResult<Grammar> {
+    for item in &mut grammar.items {
+        if let GrammarItem::ExternToken(ref mut data) = *item {
+            if let Some(ref mut enum_token) = data.enum_token {
+                try!(expand_enum_token(enum_token));
+            }
+        }
+    }
+    Ok(grammar)
+}
+
+fn expand_enum_token(enum_token: &mut EnumToken) -> NormResult<()> {
+    // the enum that each terminal was converted by so far
+    let mut converted_by: Map<TerminalString, String> = map();
+    for conversion in &enum_token.conversions {
+        converted_by.insert(conversion.from, enum_token.type_name.to_string());
+    }
+
+    let mut conversions = vec![];
+    for adapter in &enum_token.adapters {
+        for conversion in &adapter.conversions {
+            if let Some(other) = converted_by.insert(conversion.from, adapter.type_name.to_string()) {
+                return_err!(conversion.span,
+                            "terminal `{}` is converted by both `{}` and `{}`",
+                            conversion.from,
+                            other,
+                            adapter.type_name);
+            }
+
+            let span = conversion.to.span;
+            conversions.push(Conversion {
+                span: conversion.span,
+                from: conversion.from,
+                to: Pattern {
+                    span: span,
+                    kind: PatternKind::Enum(adapter.variant.clone(), vec![conversion.to.clone()]),
+                },
+                guard: conversion.guard.clone(),
+            });
+        }
+    }
+
+    enum_token.conversions.extend(conversions);
+    enum_token.adapters.clear();
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use grammar::parse_tree::Grammar;
+use normalize::NormResult;
+use parser;
+use test_util::{check_norm_err, compare};
+
+use super::expand;
+
+fn expanded(grammar: &str) -> NormResult<Grammar> {
+    expand(parser::parse_grammar(grammar).unwrap())
+}
+
+#[test]
+fn adapters_wrap_patterns() {
+    let actual = expanded(r##"
+grammar;
+    extern {
+        enum Tok {
+            ";" => Tok::Semi,
+        }
+        enum PpTok for Tok::Pp {
+            "#define" => PpTok::Define,
+        }
+        enum CoreTok for Tok::Core {
+            Num => CoreTok::Num(<i32>),
+        }
+    }
+"##).unwrap();
+
+    let expected = parser::parse_grammar(r##"
+grammar;
+    extern {
+        enum Tok {
+            ";" => Tok::Semi,
+            "#define" => Tok::Pp(PpTok::Define),
+            Num => Tok::Core(CoreTok::Num(<i32>)),
+        }
+    }
+"##).unwrap();
+
+    compare(actual, expected);
+}
+
+#[test]
+fn terminal_converted_twice() {
+    check_norm_err(
+        r#"terminal `"\+"` is converted by both `Tok` and `CoreTok`"#,
+        r#"                                                                             ~~~~~~~~~~~~~~~~~~~~~"#,
+        expanded(r#"grammar; extern { enum Tok { "+" => Tok::Plus } enum CoreTok for Tok::Core { "+" => CoreTok::Plus } }"#)
+            .unwrap_err());
+}
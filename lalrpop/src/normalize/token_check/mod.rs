@@ -7,13 +7,16 @@
 
 use super::{NormResult, NormError};
 
-use intern::{self, intern};
+use intern::{self, intern, InternedString};
 use lexer::re;
 use lexer::dfa::{self, DFAConstructionError, Precedence};
+use lexer::dfa::interpret::interpret;
 use lexer::nfa::NFAConstructionError::*;
 use grammar::consts::*;
 use grammar::parse_tree::*;
+use grammar::pattern::Pattern;
 use collections::{Map, Set};
+use util::{did_you_mean, near_misses};
 
 #[cfg(test)]
 mod test;
@@ -48,7 +51,9 @@ pub fn validate(mut grammar: Grammar) -> NormResult<Grammar> {
 
     match mode {
         TokenMode::Extern { .. } => {
-            // If using an external tokenizer, we're all done at this point.
+            // If using an external tokenizer, we're all done at this
+            // point, except for the lifetimes its tokens may borrow.
+            declare_token_lifetimes(&mut grammar);
         }
         TokenMode::Internal { match_block } => {
             // Otherwise, construct the `InternToken` item.
@@ -59,6 +64,42 @@ pub fn validate(mut grammar: Grammar) -> NormResult<Grammar> {
     Ok(grammar)
 }
 
+/// The tokens of an external tokenizer may borrow from the input,
+/// as in `enum Tok<'input> { ... }`, so that a lexer can hand out
+/// slices of the input rather than copying them into `String`s. The
+/// grammar declares any lifetime that the token, location and error
+/// types use but the user did not declare, just as it declares
+/// `'input` for the built-in tokenizer.
+fn declare_token_lifetimes(grammar: &mut Grammar) {
+    let lifetimes = {
+        let extern_token = grammar.extern_token().unwrap();
+        let static_lifetime = intern("'static");
+        let types = extern_token.associated_types
+                                .iter()
+                                .map(|associated_type| &associated_type.type_ref)
+                                .chain(extern_token.enum_token.iter().map(|e| &e.type_name));
+        let mut lifetimes = vec![];
+        for ty in types {
+            for parameter in ty.type_repr().referenced() {
+                match parameter {
+                    TypeParameter::Lifetime(l) if l != static_lifetime => {
+                        if !grammar.type_parameters.contains(&parameter) &&
+                           !lifetimes.contains(&parameter) {
+                            lifetimes.push(parameter);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        lifetimes
+    };
+
+    for (index, lifetime) in lifetimes.into_iter().enumerate() {
+        grammar.type_parameters.insert(index, lifetime);
+    }
+}
+
 ///////////////////////////////////////////////////////////////////////////
 // Validation phase -- this phase walks the grammar and visits all
 // terminals. If using an external set of tokens, it checks that all
@@ -108,6 +149,9 @@ struct MatchBlock {
 
     /// True if we should permit unrecognized literals to be used.
     catch_all: bool,
+
+    /// The `#[newlines(...)]` annotation of the `match` section, if any.
+    newlines: Option<Annotation>,
 }
 
 impl MatchBlock {
@@ -116,18 +160,34 @@ impl MatchBlock {
     fn new(opt_match_token: Option<&MatchToken>) -> NormResult<Self> {
         let mut match_block = Self::default();
         if let Some(match_token) = opt_match_token {
+            let newlines_annotation = intern(NEWLINES);
+            match_block.newlines = match_token.annotations
+                                              .iter()
+                                              .find(|a| a.id == newlines_annotation)
+                                              .cloned();
             for (idx, mc) in match_token.contents.iter().enumerate() {
                 let precedence = &match_token.contents.len() - idx;
                 for item in &mc.items {
                     match *item {
-                        MatchItem::Unmapped(sym, span) => {
+                        MatchItem::Unmapped(sym, span, ref annotations) => {
                             match_block.add_match_entry(precedence,
                                                         sym,
                                                         TerminalString::Literal(sym),
-                                                        span)?;
+                                                        span,
+                                                        is_unescaped(annotations),
+                                                        reserved(annotations),
+                                                        is_contextual(annotations),
+                                                        None)?;
                         }
-                        MatchItem::Mapped(sym, user, span) => {
-                            match_block.add_match_entry(precedence, sym, user, span)?;
+                        MatchItem::Mapped(sym, user, span, ref annotations, ref hook) => {
+                            match_block.add_match_entry(precedence,
+                                                        sym,
+                                                        user,
+                                                        span,
+                                                        is_unescaped(annotations),
+                                                        reserved(annotations),
+                                                        is_contextual(annotations),
+                                                        hook.clone())?;
                         }
                         MatchItem::CatchAll(_) => {
                             match_block.catch_all = true;
@@ -146,12 +206,30 @@ impl MatchBlock {
                        match_group_precedence: usize,
                        sym: TerminalLiteral,
                        user_name: TerminalString,
-                       span: Span)
+                       span: Span,
+                       unescape: bool,
+                       reserved: Option<InternedString>,
+                       contextual: bool,
+                       hook: Option<Path>)
                        -> NormResult<()> {
         if let Some(_old_span) = self.spans.insert(sym, span) {
             return_err!(span, "multiple match entries for `{}`", sym);
         }
 
+        // the entries for a terminal must agree on its value
+        for entry in self.match_entries.iter().filter(|entry| entry.user_name == user_name) {
+            if entry.unescape != unescape {
+                return_err!(span,
+                            "all match entries for `{}` must be marked #[unescape], or none",
+                            user_name);
+            }
+            if entry.hook != hook {
+                return_err!(span,
+                            "all match entries for `{}` must have the same hook",
+                            user_name);
+            }
+        }
+
         // NB: It's legal for multiple regex to produce same terminal.
         self.match_user_names.insert(user_name);
 
@@ -160,6 +238,10 @@ impl MatchBlock {
                       precedence: match_group_precedence * 2 + sym.base_precedence(),
                       match_literal: sym,
                       user_name: user_name,
+                      unescape: unescape,
+                      hook: hook,
+                      reserved: reserved,
+                      contextual: contextual,
                   });
         Ok(())
     }
@@ -173,8 +255,9 @@ impl MatchBlock {
 
         if !self.catch_all {
             return_err!(span,
-                        "terminal `{}` does not have a match mapping defined for it",
-                        sym);
+                        "terminal `{}` does not have a match mapping defined for it{}",
+                        sym,
+                        did_you_mean(&near_misses(&sym.to_string(), &self.match_user_names)));
         }
 
         self.match_user_names
@@ -185,6 +268,10 @@ impl MatchBlock {
                       precedence: sym.base_precedence(),
                       match_literal: sym,
                       user_name: TerminalString::Literal(sym),
+                      unescape: false,
+                      hook: None,
+                      reserved: None,
+                      contextual: false,
                   });
 
         self.spans.insert(sym, span);
@@ -199,7 +286,11 @@ impl<'grammar> Validator<'grammar> {
             match *item {
                 GrammarItem::Use(..) => {}
                 GrammarItem::MatchToken(..) => {}
-                GrammarItem::ExternToken(_) => {}
+                GrammarItem::ExternToken(ref data) => {
+                    if let Some(ref enum_token) = data.enum_token {
+                        try!(self.validate_guards(&enum_token.conversions));
+                    }
+                }
                 GrammarItem::InternToken(_) => {}
                 GrammarItem::Nonterminal(ref data) => {
                     for alternative in &data.alternatives {
@@ -211,6 +302,43 @@ impl<'grammar> Validator<'grammar> {
         Ok(())
     }
 
+    /// Checks that the guards of conversions like `"+" => Tok::Op(c)
+    /// if c == '+'` are not empty, and that a token matching the
+    /// pattern of a guarded conversion could not be claimed by
+    /// another conversion with the same pattern regardless.
+    fn validate_guards(&self, conversions: &[Conversion]) -> NormResult<()> {
+        for (index, conversion) in conversions.iter().enumerate() {
+            if conversion.guard.as_ref().map_or(false, |g| g.is_empty()) {
+                return_err!(conversion.span,
+                            "the guard on the pattern for `{}` is empty",
+                            conversion.from);
+            }
+
+            for other in &conversions[..index] {
+                if shape(&other.to) != shape(&conversion.to) {
+                    continue;
+                }
+                match (&other.guard, &conversion.guard) {
+                    (&None, &None) => {}
+                    (&Some(ref g1), &Some(ref g2)) if g1 != g2 => {}
+                    (&Some(_), &Some(_)) => {
+                        return_err!(conversion.span,
+                                    "`{}` and `{}` have the same pattern and guard",
+                                    other.from,
+                                    conversion.from);
+                    }
+                    (&Some(_), &None) | (&None, &Some(_)) => {
+                        return_err!(conversion.span,
+                                    "`{}` and `{}` have the same pattern, so both need a guard",
+                                    other.from,
+                                    conversion.from);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn validate_alternative(&mut self, alternative: &Alternative) -> NormResult<()> {
         assert!(alternative.condition.is_none()); // macro expansion should have removed these
         try!(self.validate_expr(&alternative.expr));
@@ -259,8 +387,9 @@ impl<'grammar> Validator<'grammar> {
             TokenMode::Extern { ref conversions } => {
                 if !conversions.contains(&term) {
                     return_err!(span,
-                                "terminal `{}` does not have a pattern defined for it",
-                                term);
+                                "terminal `{}` does not have a pattern defined for it{}",
+                                term,
+                                did_you_mean(&near_misses(&term.to_string(), conversions)));
                 }
             }
 
@@ -288,6 +417,33 @@ impl<'grammar> Validator<'grammar> {
     }
 }
 
+fn is_unescaped(annotations: &[Annotation]) -> bool {
+    let unescape_annotation = intern(UNESCAPE);
+    annotations.iter().any(|a| a.id == unescape_annotation)
+}
+
+/// What the entry is reserved from, if it is marked `#[reserved]`.
+fn reserved(annotations: &[Annotation]) -> Option<InternedString> {
+    let reserved_annotation = intern(RESERVED);
+    annotations.iter()
+               .find(|a| a.id == reserved_annotation)
+               .map(|a| match a.arg {
+                   Some((_, description)) => description,
+                   None => intern(DEFAULT_RESERVED_DESCRIPTION),
+               })
+}
+
+fn is_contextual(annotations: &[Annotation]) -> bool {
+    let contextual_annotation = intern(CONTEXTUAL);
+    annotations.iter().any(|a| a.id == contextual_annotation)
+}
+
+/// The pattern with its bindings and `<T>` values replaced by `_`,
+/// so that `Tok::Num(n)` and `Tok::Num(<i32>)` compare equal.
+fn shape(pattern: &Pattern<TypeRef>) -> String {
+    pattern.without_bindings().map(&mut |_| "_").to_string()
+}
+
 ///////////////////////////////////////////////////////////////////////////
 // Construction phase -- if we are constructing a tokenizer, this
 // phase builds up an internal token DFA.
@@ -296,6 +452,7 @@ fn construct(grammar: &mut Grammar, match_block: MatchBlock) -> NormResult<()> {
     let MatchBlock {
         mut match_entries,
         spans,
+        newlines,
         ..
     } = match_block;
 
@@ -357,10 +514,20 @@ fn construct(grammar: &mut Grammar, match_block: MatchBlock) -> NormResult<()> {
         }
     };
 
+    let reserved_words = try!(reserved_words(&match_entries, &regexs, &spans));
+    let contextual_keywords = try!(contextual_keywords(&match_entries, &regexs, &spans));
+    let newlines = match newlines {
+        Some(ref annotation) => Some(try!(newline_rules(annotation, &match_entries))),
+        None => None,
+    };
+
     grammar
         .items
         .push(GrammarItem::InternToken(InternToken {
                                            match_entries: match_entries,
+                                           reserved_words: reserved_words,
+                                           contextual_keywords: contextual_keywords,
+                                           newlines: newlines,
                                            dfa: dfa,
                                        }));
 
@@ -403,3 +570,146 @@ fn construct(grammar: &mut Grammar, match_block: MatchBlock) -> NormResult<()> {
 
     Ok(())
 }
+
+/// Finds, for each keyword marked `#[reserved]`, the terminals whose
+/// patterns (`regexs`, parallel with `match_entries`) match all of its
+/// text. A reserved keyword that no other terminal matches is an
+/// error, since it would never be reported as reserved.
+fn reserved_words(match_entries: &[MatchEntry],
+                  regexs: &[re::Regex],
+                  spans: &Map<TerminalLiteral, Span>)
+                  -> NormResult<Vec<ReservedWord>> {
+    let mut reserved_words = vec![];
+    for (index, match_entry) in match_entries.iter().enumerate() {
+        let description = match match_entry.reserved {
+            Some(description) => description,
+            None => continue,
+        };
+
+        let mut shadowed = vec![];
+        for other in shadowing_entries(index, match_entries, regexs) {
+            if !shadowed.contains(&match_entries[other].user_name) {
+                shadowed.push(match_entries[other].user_name);
+            }
+        }
+
+        if shadowed.is_empty() {
+            return_err!(spans[&match_entry.match_literal],
+                        "terminal `{}` is marked #[reserved], but no other terminal \
+                         matches its text",
+                        match_entry.match_literal);
+        }
+        reserved_words.push(ReservedWord {
+            index: index,
+            description: description,
+            shadowed: shadowed,
+        });
+    }
+    Ok(reserved_words)
+}
+
+/// Finds, for each keyword marked `#[contextual]`, the terminal its
+/// text is lexed as where the parser cannot accept the keyword: the
+/// highest-precedence other terminal whose pattern matches all of
+/// it. A contextual keyword that no other terminal matches is an
+/// error, since it could never be anything but the keyword.
+fn contextual_keywords(match_entries: &[MatchEntry],
+                       regexs: &[re::Regex],
+                       spans: &Map<TerminalLiteral, Span>)
+                       -> NormResult<Vec<ContextualKeyword>> {
+    let mut contextual_keywords = vec![];
+    for (index, match_entry) in match_entries.iter().enumerate() {
+        if !match_entry.contextual {
+            continue;
+        }
+
+        // `match_entries` is sorted by increasing precedence
+        let fallback = match shadowing_entries(index, match_entries, regexs).pop() {
+            Some(fallback) => fallback,
+            None => {
+                return_err!(spans[&match_entry.match_literal],
+                            "terminal `{}` is marked #[contextual], but no other terminal \
+                             matches its text",
+                            match_entry.match_literal);
+            }
+        };
+        contextual_keywords.push(ContextualKeyword {
+            index: index,
+            fallback: fallback,
+        });
+    }
+    Ok(contextual_keywords)
+}
+
+/// Finds the entries for the terminals named by `#[newlines(...)]`.
+/// Every terminal must be one that the tokenizer produces; where
+/// several entries produce the same terminal, `insert` is the first.
+fn newline_rules(annotation: &Annotation, match_entries: &[MatchEntry]) -> NormResult<Newlines> {
+    let mut rules = Newlines {
+        insert: 0,
+        after: vec![],
+        open: vec![],
+        close: vec![],
+    };
+    for param in &annotation.params {
+        let terminals = match param.value {
+            AnnotationValue::Terminals(ref terminals) => terminals,
+            AnnotationValue::String(_) => unreachable!("prevalidate permitted a string"),
+        };
+        let mut entries = vec![];
+        for &terminal in terminals {
+            let len = entries.len();
+            entries.extend(match_entries.iter()
+                                        .enumerate()
+                                        .filter(|&(_, entry)| entry.user_name == terminal)
+                                        .map(|(index, _)| index));
+            if entries.len() == len {
+                return_err!(param.key_span,
+                            "`{}` is not a terminal of the `match` section",
+                            terminal);
+            }
+        }
+        if param.key == intern(INSERT) {
+            rules.insert = entries[0];
+        } else if param.key == intern(AFTER) {
+            rules.after = entries;
+        } else if param.key == intern(OPEN) {
+            rules.open = entries;
+        } else {
+            rules.close = entries;
+        }
+    }
+    Ok(rules)
+}
+
+/// The indices of the entries for other terminals whose patterns
+/// match all of the text of the keyword at `index`, in the order of
+/// `match_entries`.
+fn shadowing_entries(index: usize,
+                     match_entries: &[MatchEntry],
+                     regexs: &[re::Regex])
+                     -> Vec<usize> {
+    let match_entry = &match_entries[index];
+    let text = match match_entry.match_literal {
+        TerminalLiteral::Quoted(s) => s,
+        TerminalLiteral::Regex(_) => unreachable!("prevalidate permitted a keyword regex"),
+    };
+
+    let mut entries = vec![];
+    for (other, (other_entry, regex)) in match_entries.iter().zip(regexs).enumerate() {
+        if other_entry.user_name == match_entry.user_name {
+            continue;
+        }
+        let matches = match dfa::build_dfa(&[regex.clone()], &[Precedence(0)]) {
+            Ok(dfa) => intern::read(|interner| {
+                let text = interner.data(text);
+                interpret(&dfa, text).map_or(false, |(_, matched)| matched == text)
+            }),
+            Err(_) => false,
+        };
+        if matches {
+            entries.push(other);
+        }
+    }
+    entries
+}
@@ -57,6 +57,14 @@ fn unknown_id_terminal() {
         r#"                                        ~~~~~ "#);
 }
 
+#[test]
+fn unknown_terminal_near_misses() {
+    check_err(
+        r#"pattern defined for it; did you mean `"while"` or `"wide"`\?$"#,
+        r#"grammar; extern { enum T { "while" => .., "wide" => .., "do" => .. } } X = "wile";"#,
+        r#"                                                                           ~~~~~~ "#);
+}
+
 #[test]
 fn tick_input_lifetime_already_declared() {
     check_err(
@@ -65,6 +73,21 @@ fn tick_input_lifetime_already_declared() {
         r#"~~~~~~~                      "#);
 }
 
+#[test]
+fn token_lifetimes_are_implicit() {
+    let grammar = validate_grammar(r#"
+grammar<'err, T>;
+extern {
+    type Location = Loc<'loc>;
+    enum Tok<'input, 'err> { "x" => Tok::X(<&'input str>) }
+}
+X = "x";
+"#).unwrap();
+    let type_parameters: Vec<String> =
+        grammar.type_parameters.iter().map(|p| p.to_string()).collect();
+    assert_eq!(type_parameters, vec!["'loc", "'input", "'err", "T"]);
+}
+
 #[test]
 fn input_parameter_already_declared() {
     check_err(
@@ -130,6 +153,14 @@ fn match_precedence() {
                 ]);
 }
 
+#[test]
+fn invalid_match_literal_near_miss() {
+    check_err(
+        r#"terminal `"BEGN"` does not have a match mapping defined for it; did you mean `"BEGIN"`"#,
+        r#"grammar; match { r"(?i)begin" => "BEGIN" } X = "BEGN";"#,
+        r#"                                               ~~~~~~ "#);
+}
+
 /// Test that, without a `catch-all`, using unrecognized literals is an error.
 #[test]
 fn invalid_match_literal() {
@@ -191,3 +222,105 @@ fn same_literal_twice() {
         r#"grammar; match { r"(?i)b" => "B" } else { r"(?i)b" => "b" }"#,
         r#"                                          ~~~~~~~~~~~~~~~~ "#);
 }
+
+#[test]
+fn guard_needed_for_same_pattern() {
+    check_err(
+        r#"`"\+"` and `"-"` have the same pattern, so both need a guard"#,
+        r#"grammar; extern { enum Tok { "+" => Tok::Op(c) if c == '+', "-" => Tok::Op(c), } }"#,
+        r#"                                                            ~~~~~~~~~~~~~~~~~        "#);
+}
+
+#[test]
+fn same_pattern_and_guard() {
+    check_err(
+        r#"`"\+"` and `"-"` have the same pattern and guard"#,
+        r#"grammar; extern { enum Tok { "+" => Tok::Op(c) if c == '+', "-" => Tok::Op(c) if c == '+', } }"#,
+        r#"                                                            ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~       "#);
+}
+
+#[test]
+fn unescape_disagreement() {
+    check_err(
+        r#"all match entries for `"s"` must be marked #\[unescape\], or none"#,
+        r##"grammar; match { #[unescape] r#""[a-z]*""# => "s", r#"'[a-z]*'"# => "s" } X = "s";"##,
+        r##"                                                   ~~~~~~~~~~~~~~~~~~~~~          "##);
+}
+
+#[test]
+fn reserved_without_shadowed_terminal() {
+    check_err(
+        r#"terminal `"while"` is marked #\[reserved\], but no other terminal matches its text"#,
+        r#"grammar; match { #[reserved] "while" } else { r"[0-9]+" => NUM } X = "while" NUM;"#,
+        r#"                             ~~~~~~~                                             "#);
+}
+
+#[test]
+fn reserved_words() {
+    let grammar = validate_grammar(r#"
+grammar;
+match { #[reserved] "while", #[reserved = "name"] "do" }
+else { r"[a-z]+" => ID }
+else { r"[a-v]+" => NAME }
+X = "while" "do" ID NAME;
+"#).unwrap();
+    let intern_token = grammar.intern_token().unwrap();
+    let reserved: Vec<(String, String, Vec<String>)> =
+        intern_token.reserved_words
+                    .iter()
+                    .map(|r| (intern_token.match_entries[r.index].user_name.to_string(),
+                              r.description.to_string(),
+                              r.shadowed.iter().map(|t| t.to_string()).collect()))
+                    .collect();
+    assert_eq!(reserved,
+               vec![(r#""do""#.to_string(), "name".to_string(),
+                     vec!["NAME".to_string(), "ID".to_string()]),
+                    (r#""while""#.to_string(), "identifier".to_string(),
+                     vec!["ID".to_string()])]);
+}
+
+#[test]
+fn contextual_keywords() {
+    let grammar = validate_grammar(r#"
+grammar;
+match { #[contextual] "do", "x" }
+else { r"[a-z]+" => ID }
+else { r"[a-v]+" => NAME }
+X = "do" "x" ID NAME;
+"#).unwrap();
+    let intern_token = grammar.intern_token().unwrap();
+    let contextual: Vec<(String, String)> =
+        intern_token.contextual_keywords
+                    .iter()
+                    .map(|k| (intern_token.match_entries[k.index].user_name.to_string(),
+                              intern_token.match_entries[k.fallback].user_name.to_string()))
+                    .collect();
+    assert_eq!(contextual, vec![(r#""do""#.to_string(), "ID".to_string())]);
+}
+
+#[test]
+fn newlines_unknown_terminal() {
+    check_err(
+        r#"`";"` is not a terminal of the `match` section"#,
+        r#"grammar; #[newlines(insert = [";"], after = [ID])] match { r"[a-z]+" => ID } X = ID;"#,
+        r#"                    ~~~~~~                                                         "#);
+}
+
+#[test]
+fn newlines() {
+    let grammar = validate_grammar(r#"
+grammar;
+#[newlines(insert = [";"], after = [ID, ")"], open = ["("], close = [")"])]
+match { ";", "(", ")" } else { r"[a-z]+" => ID }
+X = ID ";" "(" ")";
+"#).unwrap();
+    let intern_token = grammar.intern_token().unwrap();
+    let names = |indices: &[usize]| -> Vec<String> {
+        indices.iter().map(|&i| intern_token.match_entries[i].user_name.to_string()).collect()
+    };
+    let newlines = intern_token.newlines.as_ref().unwrap();
+    assert_eq!(names(&[newlines.insert]), vec![r#"";""#]);
+    assert_eq!(names(&newlines.after), vec!["ID", r#"")""#]);
+    assert_eq!(names(&newlines.open), vec![r#""(""#]);
+    assert_eq!(names(&newlines.close), vec![r#"")""#]);
+}
@@ -0,0 +1,237 @@
+//! Declares the tokens of `#![token_stream]` grammars, whose parsers
+//! read the token trees of a procedural macro (see
+//! `lalrpop_util::token_stream`). Given:
+//!
+//!     #![token_stream]
+//!     grammar;
+//!
+//!     pub Item = "fn" Ident "(" ")" "=>" Literal;
+//!
+//! we generate:
+//!
+//!     grammar;
+//!
+//!     extern {
+//!         type Location = __lalrpop_util::token_stream::SpanLocation;
+//!         enum __lalrpop_util::token_stream::Token {
+//!             Ident => __lalrpop_util::token_stream::Token::Ident(<__lalrpop_util::token_stream::Ident>),
+//!             Literal => __lalrpop_util::token_stream::Token::Literal(<__lalrpop_util::token_stream::Literal>),
+//!             "(" => __lalrpop_util::token_stream::Token::Fixed(0),
+//!             ")" => __lalrpop_util::token_stream::Token::Fixed(1),
+//!             "=>" => __lalrpop_util::token_stream::Token::Fixed(2),
+//!             "fn" => __lalrpop_util::token_stream::Token::Fixed(3),
+//!         }
+//!     }
+//!
+//! and record the keywords, operators and delimiters in
+//! `Grammar::token_stream`, so that the generated `tokens` function
+//! can give them the same indices. This happens in two steps: `Ident`
+//! and `Literal` must be declared before name resolution, but the
+//! quoted terminals are only all known after macro expansion, which
+//! is when `add_fixed_tokens` runs.
+
+use super::{NormResult, NormError};
+
+use collections::Set;
+use grammar::consts::{LOCATION, TOKEN_STREAM};
+use grammar::parse_tree::*;
+use grammar::pattern::{Pattern, PatternKind};
+use intern::{intern, read, InternedString};
+use normalize::lower::is_attribute;
+
+#[cfg(test)]
+mod test;
+
+pub fn expand(mut grammar: Grammar) -> NormResult<Grammar> {
+    match grammar.module_attributes.iter().position(|a| is_attribute(a, TOKEN_STREAM)) {
+        Some(index) => {
+            grammar.module_attributes.remove(index);
+        }
+        None => return Ok(grammar),
+    }
+
+    if let Some(match_token) = grammar.match_token() {
+        return_err!(match_token.span,
+                    "a `#![token_stream]` grammar cannot have a `match` section");
+    }
+    if let Some(enum_token) = grammar.enum_token() {
+        return_err!(enum_token.type_span,
+                    "a `#![token_stream]` grammar cannot declare its own tokens");
+    }
+
+    let span = grammar.span;
+    let prefix = grammar.prefix.clone();
+    let path = |name: &str| token_stream_path(&prefix, &[name]);
+    let type_ref = |name: &str| TypeRef::Nominal { path: path(name), types: vec![] };
+
+    // `Ident` and `Literal`, unless the grammar has nonterminals by
+    // those names
+    let nonterminals: Set<NonterminalString> =
+        grammar.items
+               .iter()
+               .filter_map(|item| item.as_nonterminal())
+               .map(|data| data.name)
+               .collect();
+    let conversions =
+        ["Ident", "Literal"]
+            .iter()
+            .filter(|name| !nonterminals.contains(&NonterminalString(intern(name))))
+            .map(|name| {
+                let mut variant = path("Token");
+                variant.ids.push(intern(name));
+                Conversion {
+                    span: span,
+                    from: TerminalString::Bare(intern(name)),
+                    to: Pattern {
+                        span: span,
+                        kind: PatternKind::Enum(variant,
+                                                vec![Pattern {
+                                                    span: span,
+                                                    kind: PatternKind::Choose(type_ref(name)),
+                                                }]),
+                    },
+                    guard: None,
+                }
+            })
+            .collect();
+
+    let enum_token = EnumToken {
+        type_name: type_ref("Token"),
+        type_span: span,
+        conversions: conversions,
+        adapters: vec![],
+    };
+    let location = AssociatedType {
+        type_span: span,
+        type_name: intern(LOCATION),
+        type_ref: type_ref("SpanLocation"),
+    };
+
+    // an `extern` section may still declare the other associated
+    // types, like `Error`
+    match grammar.items.iter().position(|item| item.as_extern_token().is_some()) {
+        Some(index) => {
+            if let GrammarItem::ExternToken(ref mut data) = grammar.items[index] {
+                if !data.associated_types.iter().any(|a| a.type_name == location.type_name) {
+                    data.associated_types.push(location);
+                }
+                data.enum_token = Some(enum_token);
+            }
+        }
+        None => {
+            grammar.items.push(GrammarItem::ExternToken(ExternToken {
+                span: span,
+                annotations: vec![],
+                associated_types: vec![location],
+                enum_token: Some(enum_token),
+            }));
+        }
+    }
+
+    grammar.token_stream = Some(vec![]);
+    Ok(grammar)
+}
+
+/// Adds a conversion for each quoted terminal of a `#![token_stream]`
+/// grammar, which must be a keyword, an operator or a delimiter.
+pub fn add_fixed_tokens(mut grammar: Grammar) -> NormResult<Grammar> {
+    if grammar.token_stream.is_none() {
+        return Ok(grammar);
+    }
+
+    let mut fixed = Set::new();
+    for item in &grammar.items {
+        if let GrammarItem::Nonterminal(ref data) = *item {
+            for alternative in &data.alternatives {
+                try!(collect_expr(&alternative.expr, &mut fixed));
+            }
+        }
+    }
+    let fixed: Vec<InternedString> = fixed.into_iter().collect();
+
+    let variant = token_stream_path(&grammar.prefix, &["Token", "Fixed"]);
+    for item in &mut grammar.items {
+        if let GrammarItem::ExternToken(ref mut data) = *item {
+            let enum_token = data.enum_token.as_mut().unwrap();
+            let span = enum_token.type_span;
+            for (&text, index) in fixed.iter().zip(0..) {
+                enum_token.conversions.push(Conversion {
+                    span: span,
+                    from: TerminalString::Literal(TerminalLiteral::Quoted(text)),
+                    to: Pattern {
+                        span: span,
+                        kind: PatternKind::Enum(variant.clone(),
+                                                vec![Pattern {
+                                                    span: span,
+                                                    kind: PatternKind::Usize(index),
+                                                }]),
+                    },
+                    guard: None,
+                });
+            }
+        }
+    }
+
+    grammar.token_stream = Some(fixed);
+    Ok(grammar)
+}
+
+/// `__lalrpop_util::token_stream::<names>`, relative to the generated
+/// module, which imports `lalrpop_util` under the grammar's prefix.
+fn token_stream_path(prefix: &str, names: &[&str]) -> Path {
+    let mut ids = vec![intern(&format!("{}lalrpop_util", prefix)), intern("token_stream")];
+    ids.extend(names.iter().map(|name| intern(name)));
+    Path {
+        absolute: false,
+        ids: ids,
+    }
+}
+
+fn collect_expr(expr: &ExprSymbol, fixed: &mut Set<InternedString>) -> NormResult<()> {
+    for symbol in &expr.symbols {
+        try!(collect_symbol(symbol, fixed));
+    }
+    Ok(())
+}
+
+fn collect_symbol(symbol: &Symbol, fixed: &mut Set<InternedString>) -> NormResult<()> {
+    match symbol.kind {
+        SymbolKind::Expr(ref expr) => try!(collect_expr(expr, fixed)),
+        SymbolKind::Terminal(TerminalString::Literal(TerminalLiteral::Quoted(text))) => {
+            if !read(|interner| is_fixed_token(interner.data(text))) {
+                return_err!(symbol.span,
+                            "`\"{}\"` is not a keyword, operator or delimiter, so it \
+                             cannot be a terminal of a `#![token_stream]` grammar",
+                            text);
+            }
+            fixed.insert(text);
+        }
+        SymbolKind::Terminal(TerminalString::Literal(TerminalLiteral::Regex(_))) => {
+            return_err!(symbol.span,
+                        "regular expressions cannot be terminals of a `#![token_stream]` \
+                         grammar");
+        }
+        SymbolKind::Repeat(ref repeat) => try!(collect_symbol(&repeat.symbol, fixed)),
+        SymbolKind::Choose(ref symbol) |
+        SymbolKind::Name(_, ref symbol) => try!(collect_symbol(symbol, fixed)),
+        _ => {}
+    }
+    Ok(())
+}
+
+/// True for the text of a token tree, or a run of joint punctuation,
+/// that `lalrpop_util::token_stream` can turn into a `Token::Fixed`.
+fn is_fixed_token(text: &str) -> bool {
+    let is_punct = |c: char| "=<>!~+-*/%^&|@.,;:#$?'".contains(c);
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(c) if c.is_alphabetic() || c == '_' => {
+            chars.all(|c| c.is_alphanumeric() || c == '_')
+        }
+        Some('(') | Some(')') | Some('[') | Some(']') | Some('{') | Some('}') => {
+            chars.next().is_none()
+        }
+        Some(c) => is_punct(c) && chars.all(is_punct),
+        None => false,
+    }
+}
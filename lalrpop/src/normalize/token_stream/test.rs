@@ -0,0 +1,85 @@
+use grammar::parse_tree::Grammar;
+use normalize::NormResult;
+use parser;
+use test_util::check_norm_err;
+
+use super::{add_fixed_tokens, expand};
+
+fn expanded(grammar: &str) -> NormResult<Grammar> {
+    expand(parser::parse_grammar(grammar).unwrap()).and_then(add_fixed_tokens)
+}
+
+#[test]
+fn declares_tokens() {
+    let actual = expanded(r#"
+#![token_stream]
+grammar;
+    extern {
+        type Error = String;
+    }
+    pub Item = "fn" Ident "(" ")" "=>" Literal;
+"#).unwrap();
+    assert!(actual.module_attributes.is_empty());
+
+    let extern_token = actual.extern_token().unwrap();
+    let associated_types: Vec<String> =
+        extern_token.associated_types
+                    .iter()
+                    .map(|a| format!("{} = {}", a.type_name, a.type_ref))
+                    .collect();
+    assert_eq!(associated_types,
+               vec!["Error = String",
+                    "Location = __lalrpop_util::token_stream::SpanLocation"]);
+
+    let enum_token = extern_token.enum_token.as_ref().unwrap();
+    assert_eq!(enum_token.type_name.to_string(), "__lalrpop_util::token_stream::Token");
+    let conversions: Vec<String> =
+        enum_token.conversions
+                  .iter()
+                  .map(|c| format!("{} => {}", c.from, c.to))
+                  .collect();
+    assert_eq!(conversions,
+               vec!["Ident => __lalrpop_util::token_stream::Token::Ident(\
+                     __lalrpop_util::token_stream::Ident)",
+                    "Literal => __lalrpop_util::token_stream::Token::Literal(\
+                     __lalrpop_util::token_stream::Literal)",
+                    r#""(" => __lalrpop_util::token_stream::Token::Fixed(0)"#,
+                    r#"")" => __lalrpop_util::token_stream::Token::Fixed(1)"#,
+                    r#""=>" => __lalrpop_util::token_stream::Token::Fixed(2)"#,
+                    r#""fn" => __lalrpop_util::token_stream::Token::Fixed(3)"#]);
+
+    let fixed: Vec<String> = actual.token_stream.unwrap().iter().map(|t| t.to_string()).collect();
+    assert_eq!(fixed, vec!["(", ")", "=>", "fn"]);
+}
+
+#[test]
+fn nonterminal_named_ident() {
+    let actual = expanded(r#"
+#![token_stream]
+grammar;
+    pub Ident = "x";
+"#).unwrap();
+    let conversions: Vec<String> = actual.enum_token()
+                                         .unwrap()
+                                         .conversions
+                                         .iter()
+                                         .map(|c| c.from.to_string())
+                                         .collect();
+    assert_eq!(conversions, vec!["Literal", r#""x""#]);
+}
+
+#[test]
+fn not_a_token() {
+    check_norm_err(
+        r#"`"1 \+ 1"` is not a keyword, operator or delimiter"#,
+        r#"                                      ~~~~~~~  "#,
+        expanded(r#"#![token_stream] grammar; pub X = "x" "1 + 1";"#).unwrap_err());
+}
+
+#[test]
+fn own_tokens() {
+    check_norm_err(
+        r#"a `#!\[token_stream\]` grammar cannot declare its own tokens"#,
+        r#"                                        ~~~                      "#,
+        expanded(r#"#![token_stream] grammar; extern { enum Tok { "x" => Tok::X } }"#).unwrap_err());
+}
@@ -2,7 +2,8 @@ use super::{NormResult, NormError};
 use super::norm_util::{self, AlternativeAction, Symbols};
 
 use std::collections::{HashMap, HashSet};
-use grammar::consts::{ERROR, INPUT_LIFETIME, LOCATION};
+use std::mem;
+use grammar::consts::{BOXED, ERROR, INPUT_LIFETIME, LOCATION};
 use grammar::parse_tree::{ActionKind, Alternative,
                           Grammar,
                           NonterminalData, NonterminalString,
@@ -24,6 +25,12 @@ pub fn infer_types(grammar: &Grammar) -> NormResult<Types> {
 
 struct TypeInferencer<'grammar> {
     stack: Vec<NonterminalString>,
+    // the number of times a nonterminal was found to reference itself
+    cycles: usize,
+    // the alternatives to check once all types are known: the
+    // nonterminal, the index of the alternative, and the index and
+    // type of an alternative it must agree with
+    unchecked: Vec<(NonterminalString, usize, usize, TypeRepr)>,
     nonterminals: HashMap<NonterminalString, NT<'grammar>>,
     types: Types,
     type_parameters: HashSet<InternedString>,
@@ -34,6 +41,7 @@ struct NT<'grammar> {
     span: Span,
     type_decl: &'grammar Option<TypeRef>,
     alternatives: &'grammar Vec<Alternative>,
+    boxed: bool,
 }
 
 impl<'grammar> TypeInferencer<'grammar> {
@@ -59,6 +67,8 @@ impl<'grammar> TypeInferencer<'grammar> {
             .collect();
 
         Ok(TypeInferencer { stack: vec![],
+                            cycles: 0,
+                            unchecked: vec![],
                             nonterminals: nonterminals,
                             types: types,
                             type_parameters: type_parameters })
@@ -75,10 +85,15 @@ impl<'grammar> TypeInferencer<'grammar> {
             });
 
         // Determine location type and enum type. If using an internal
-        // token, that's specified by us, not user.
+        // token, that's specified by us, not user, except that the
+        // byte offsets can be converted to a `Location` type declared
+        // in `extern`.
         if let Some(intern_token) = grammar.intern_token() {
-            let loc_type = // usize
-                TypeRepr::usize();
+            let loc_type = // usize, unless declared
+                opt_extern_token.and_then(|extern_token| {
+                    extern_token.associated_type(intern(LOCATION))
+                                .map(|tr| tr.type_ref.type_repr())
+                }).unwrap_or_else(TypeRepr::usize);
             let input_str = // &'input str
                 TypeRepr::Ref {
                     lifetime: Some(intern(INPUT_LIFETIME)),
@@ -96,8 +111,21 @@ impl<'grammar> TypeInferencer<'grammar> {
 
             let mut types = Types::new(&grammar.prefix, Some(loc_type), error_type, enum_type);
 
+            let input_cow = // ::std::borrow::Cow<'input, str>
+                TypeRepr::Nominal(NominalTypeRepr {
+                    path: Path {
+                        absolute: true,
+                        ids: vec![intern("std"), intern("borrow"), intern("Cow")],
+                    },
+                    types: vec![TypeRepr::Lifetime(intern(INPUT_LIFETIME)), TypeRepr::str()],
+                });
+
             for match_entry in &intern_token.match_entries {
-                types.add_term_type(match_entry.user_name, input_str.clone());
+                if match_entry.unescape || match_entry.hook.is_some() {
+                    types.add_term_type(match_entry.user_name, input_cow.clone());
+                } else {
+                    types.add_term_type(match_entry.user_name, input_str.clone());
+                }
             }
 
             types
@@ -146,6 +174,14 @@ impl<'grammar> TypeInferencer<'grammar> {
             debug_assert!(self.types.lookup_nonterminal_type(id).is_some());
         }
 
+        for (id, index, first, ty) in mem::replace(&mut self.unchecked, vec![]) {
+            let nt = self.nonterminals[&id];
+            let alt_ty = try!(self.alternative_type(&nt.alternatives[index]));
+            if alt_ty != ty {
+                try!(self.mismatch(nt, index, &alt_ty, first, &ty));
+            }
+        }
+
         Ok(self.types)
     }
 
@@ -155,8 +191,16 @@ impl<'grammar> TypeInferencer<'grammar> {
         }
 
         let nt = self.nonterminals[&id];
-        if self.stack.contains(&id) {
-            return_err!(nt.span, "cannot infer type of `{}` because it references itself", id);
+        if let Some(index) = self.stack.iter().position(|&s| s == id) {
+            let cycle: Vec<String> =
+                self.stack[index..].iter()
+                                   .chain(Some(&id))
+                                   .map(|nt| format!("`{}`", nt))
+                                   .collect();
+            self.cycles += 1;
+            return_err!(nt.span,
+                        "cannot infer type of `{}` because it references itself ({})",
+                        id, cycle.join(" -> "));
         }
 
         let ty = try!(self.push(id, |this| {
@@ -166,13 +210,23 @@ impl<'grammar> TypeInferencer<'grammar> {
 
             // Try to compute the types of all alternatives; note that
             // some may result in an error. Don't report these errors
-            // (yet).
+            // (yet). The alternatives that failed because they lead
+            // back to a nonterminal whose type is being computed
+            // (like `Expr = { Term, "(" <Expr> ")" }`) are deferred
+            // until the type of the others is known.
             let mut alternative_types = vec![];
             let mut alternative_errors = vec![];
-            for alt in nt.alternatives.iter() {
+            let mut deferred = vec![];
+            for (i, alt) in nt.alternatives.iter().enumerate() {
+                let cycles = this.cycles;
                 match this.alternative_type(alt) {
-                    Ok(t) => alternative_types.push(t),
-                    Err(e) => alternative_errors.push(e),
+                    Ok(t) => alternative_types.push((i, t)),
+                    Err(e) => {
+                        if this.cycles > cycles {
+                            deferred.push(i);
+                        }
+                        alternative_errors.push(e);
+                    }
                 }
             }
 
@@ -192,25 +246,63 @@ impl<'grammar> TypeInferencer<'grammar> {
             }
 
             // otherwise, check that all the cases where we had success agree
-            for ((ty, alt), i) in
-                alternative_types[1..].iter().zip(&nt.alternatives[1..]).zip(1..)
-            {
-                if &alternative_types[0] != ty {
-                    return_err!(alt.span,
-                                "type of alternative #{} is `{}`, \
-                                 but type of first alternative is `{}`",
-                                i+1, ty, alternative_types[0]);
+            let (first, ty) = alternative_types.remove(0);
+            for &(i, ref alt_ty) in &alternative_types {
+                if *alt_ty != ty {
+                    return this.mismatch(nt, i, alt_ty, first, &ty);
+                }
+            }
+
+            // and that the deferred alternatives agree, assuming that
+            // type for this nonterminal while computing theirs. If one
+            // still leads back to a nonterminal whose type is being
+            // computed further up the stack, it is checked once all
+            // types are known.
+            this.types.add_type(id, nt.ty(ty.clone()));
+            let mut result = Ok(());
+            for i in deferred {
+                let cycles = this.cycles;
+                match this.alternative_type(&nt.alternatives[i]) {
+                    Ok(alt_ty) => {
+                        if alt_ty != ty {
+                            result = this.mismatch(nt, i, &alt_ty, first, &ty).map(|_| ());
+                            break;
+                        }
+                    }
+                    Err(_) if this.cycles > cycles => {
+                        this.unchecked.push((id, i, first, ty.clone()));
+                    }
+                    Err(e) => {
+                        result = Err(e);
+                        break;
+                    }
                 }
             }
+            this.types.remove_type(id);
+            try!(result);
 
             // and use that type
-            Ok(alternative_types.pop().unwrap())
+            Ok(ty)
         }));
 
+        let ty = nt.ty(ty);
         self.types.add_type(id, ty.clone());
         Ok(ty)
     }
 
+    fn mismatch(&self,
+                nt: NT,
+                index: usize,
+                ty: &TypeRepr,
+                first: usize,
+                first_ty: &TypeRepr)
+                -> NormResult<TypeRepr> {
+        return_err!(nt.alternatives[index].span,
+                    "type of alternative #{} is `{}`, \
+                     but type of alternative #{} is `{}`",
+                    index + 1, ty, first + 1, first_ty);
+    }
+
     fn push<F,R>(&mut self, id: NonterminalString, f: F) -> NormResult<R>
         where F: FnOnce(&mut TypeInferencer) -> NormResult<R>
     {
@@ -307,7 +399,22 @@ impl<'grammar> TypeInferencer<'grammar> {
 
 impl<'grammar> NT<'grammar> {
     fn new(data: &'grammar NonterminalData) -> NT<'grammar> {
-        NT { span: data.span, type_decl: &data.type_decl, alternatives: &data.alternatives }
+        NT {
+            span: data.span,
+            type_decl: &data.type_decl,
+            alternatives: &data.alternatives,
+            boxed: data.annotations.iter().any(|a| a.id == intern(BOXED)),
+        }
+    }
+
+    /// The type of the nonterminal, given the type `ty` of its
+    /// alternatives (or the declared one).
+    fn ty(&self, ty: TypeRepr) -> TypeRepr {
+        if self.boxed {
+            TypeRepr::boxed(ty)
+        } else {
+            ty
+        }
     }
 }
 
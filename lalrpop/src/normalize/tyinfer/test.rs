@@ -70,7 +70,9 @@ grammar;
 "#).unwrap();
 
     let actual = expand_macros(grammar).unwrap();
-    assert!(infer_types(&actual).is_err());
+    let err = infer_types(&actual).unwrap_err();
+    // the cycle is reported starting from whichever nonterminal was visited first
+    assert_eq!(err.message.matches(" -> ").count(), 4, "{}", err.message);
 }
 
 #[test]
@@ -214,6 +216,17 @@ grammar;
         ])
 }
 
+#[test]
+fn intern_token_location() {
+    compare(r#"
+grammar;
+    extern { type Location = LineCol; }
+    Z = @L "Ho" @R;
+"#, vec![
+    ("Z", "(LineCol, &'input str, LineCol)")
+        ])
+}
+
 #[test]
 fn error() {
     compare(r#"
@@ -223,3 +236,98 @@ grammar;
     ("Z", "__lalrpop_util::ParseError<usize, Token<'input>, &'static str>")
         ])
 }
+
+#[test]
+fn category() {
+    compare(r#"
+grammar;
+    extern { enum Tok { "+=" => PlusEq(..), "-=" => MinusEq(..) } }
+    category AssignOp = "+=" | "-=";
+    X = "+=" AssignOp;
+"#, vec![
+    ("AssignOp", "Tok"),
+    ("X", "(Tok, Tok)"),
+        ])
+}
+
+#[test]
+fn test_cycle_through_parens() {
+    compare(r#"
+grammar;
+    extern { enum Tok { "(" => .., ")" => .., "foo" => .. } }
+    Expr = {
+        "(" <Expr> ")",
+        Term,
+    };
+    Term: i32 = "foo" => 22;
+"#, vec![
+    ("Expr", "i32"),
+        ])
+}
+
+#[test]
+fn test_cycle_mutual() {
+    compare(r#"
+grammar;
+    extern { enum Tok { "(" => .., ")" => .., "foo" => .. } }
+    A = {
+        B,
+        Term,
+    };
+    B = "(" <A> ")";
+    Term: i32 = "foo" => 22;
+"#, vec![
+    ("A", "i32"),
+    ("B", "i32"),
+        ])
+}
+
+#[test]
+fn test_cycle_through_macros() {
+    compare(r#"
+grammar;
+    extern { enum Tok { "[" => .., "]" => .., "," => .., "foo" => .. } }
+    Value = {
+        "[" <Comma<Value>> "]" => Value::List(<>),
+        Leaf,
+    };
+    Comma<T>: Vec<T> = <v:(<T> ",")*> <e:T?> => v.into_iter().chain(e).collect();
+    Leaf: Value = "foo" => Value::Leaf;
+"#, vec![
+    ("Value", "Value"),
+    ("Comma<Value>", "Vec<Value>"),
+        ])
+}
+
+#[test]
+fn test_cycle_inconsistent() {
+    let grammar = parser::parse_grammar(r#"
+grammar;
+    extern { enum Tok { "(" => .., ")" => .., "foo" => .. } }
+    Expr = {
+        "(" <Expr?> ")",
+        Term,
+    };
+    Term: i32 = "foo" => 22;
+"#).unwrap();
+
+    let actual = expand_macros(grammar).unwrap();
+    assert!(infer_types(&actual).is_err());
+}
+
+#[test]
+fn test_boxed() {
+    compare(r#"
+grammar;
+    extern { enum Tok { "(" => .., ")" => .., "foo" => .. } }
+    #[boxed]
+    A: i32 = "foo" => 22;
+    #[boxed]
+    B = "(" <A> ")";
+    C = { B, "(" <C> ")" };
+"#, vec![
+    ("A", "::std::boxed::Box<i32>"),
+    ("B", "::std::boxed::Box<::std::boxed::Box<i32>>"),
+    ("C", "::std::boxed::Box<::std::boxed::Box<i32>>"),
+        ])
+}
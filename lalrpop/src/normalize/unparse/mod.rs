@@ -0,0 +1,660 @@
+//! Works out how the `unparse_X` functions asked for by `#[unparse]`
+//! write values back as text (see `repr::UnparseDefn`).
+//!
+//! An alternative can be unparsed when its action only puts the values
+//! of its symbols together again, so that the value it builds can be
+//! taken apart with a pattern. That is the case for the default
+//! action, which makes a tuple of them (or passes one on), and for
+//! actions that build a value of an enum or struct from them, like
+//!
+//! ```ignore
+//! <l:Expr> "+" <r:Factor> => Box::new(Expr::Add(l, Op::Plus, r))
+//! ```
+//!
+//! whose values match `Expr::Add(ref l, Op::Plus, ref r)` once they
+//! are unboxed. The actions may call the constructors of variants and
+//! tuple structs, use struct literals, tuples, paths and literals, and
+//! box the values of symbols with `Box::new`. Terminals whose values
+//! are dropped are written as they are quoted, and those whose values
+//! are kept with `Display`. The values of other nonterminals, like
+//! numbers, can be written with a format string given by `#[format =
+//! "..."]` instead.
+
+use intern::{intern, InternedString};
+use grammar::consts::{BOXED, FORMAT, UNPARSE};
+use grammar::parse_tree::{ActionKind, Alternative, Grammar, NonterminalData, NonterminalString,
+                          Symbol, SymbolKind, TerminalLiteral, TerminalString,
+                          TypeParameter};
+use grammar::repr as r;
+use normalize::{NormError, NormResult};
+use normalize::norm_util::{self, Presence, Symbols};
+use collections::{map, Map};
+
+#[cfg(test)]
+mod test;
+
+macro_rules! try_some {
+    ($e:expr) => {
+        match $e {
+            Some(value) => value,
+            None => return None,
+        }
+    }
+}
+
+pub fn unparsers(grammar: &Grammar, types: &r::Types) -> NormResult<Vec<r::UnparseDefn>> {
+    let nonterminals: Map<NonterminalString, &NonterminalData> =
+        grammar.items
+               .iter()
+               .filter_map(|item| item.as_nonterminal())
+               .map(|nt| (nt.name, nt))
+               .collect();
+
+    let roots: Vec<&NonterminalData> = nonterminals.values()
+                                                   .cloned()
+                                                   .filter(|nt| annotation(nt, UNPARSE).is_some())
+                                                   .collect();
+    if roots.is_empty() {
+        return Ok(vec![]);
+    }
+
+    // the unparsers only declare the lifetimes of the grammar, as
+    // type parameters could not be inferred where they are called
+    for type_parameter in &grammar.type_parameters {
+        if let TypeParameter::Id(id) = *type_parameter {
+            return_err!(roots[0].span,
+                        "#[unparse] cannot be used in grammars with type parameters, like `{}`",
+                        id);
+        }
+    }
+
+    let mut state = UnparseState {
+        prefix: &grammar.prefix,
+        types: types,
+        nonterminals: nonterminals.clone(),
+        indices: map(),
+        queue: vec![],
+    };
+    for root in &roots {
+        state.index(root.name);
+    }
+
+    let mut defns = vec![];
+    while defns.len() < state.queue.len() {
+        let nt = state.queue[defns.len()];
+        let defn = try!(state.defn(nt));
+        defns.push(defn);
+    }
+    for defn in &mut defns {
+        defn.public = roots.iter().any(|root| root.name == defn.nonterminal);
+    }
+    Ok(defns)
+}
+
+struct UnparseState<'grammar> {
+    prefix: &'grammar str,
+    types: &'grammar r::Types,
+    nonterminals: Map<NonterminalString, &'grammar NonterminalData>,
+
+    // the index of the unparser of each nonterminal, and the
+    // nonterminals in the order of their indices
+    indices: Map<NonterminalString, usize>,
+    queue: Vec<NonterminalString>,
+}
+
+impl<'grammar> UnparseState<'grammar> {
+    fn index(&mut self, nt: NonterminalString) -> usize {
+        if let Some(&index) = self.indices.get(&nt) {
+            return index;
+        }
+        let index = self.queue.len();
+        self.indices.insert(nt, index);
+        self.queue.push(nt);
+        index
+    }
+
+    fn defn(&mut self, nt: NonterminalString) -> NormResult<r::UnparseDefn> {
+        let data = self.nonterminals[&nt];
+        let kind = if let Some(format) = annotation(data, FORMAT).and_then(|a| a.arg) {
+            r::UnparseKind::Format(format.1.to_string())
+        } else {
+            // the nonterminals that `X*` and `X+` expand to build
+            // their `Vec` with code of their own
+            let name = nt.to_string();
+            let element = if name.ends_with('*') {
+                let plus = strip(&data.alternatives[1].expr.symbols[0]);
+                match plus.kind {
+                    SymbolKind::Nonterminal(plus) => {
+                        Some((0, &self.nonterminals[&plus].alternatives[0].expr.symbols[0]))
+                    }
+                    _ => panic!("unexpected expansion of `{}`", nt),
+                }
+            } else if name.ends_with('+') {
+                Some((1, &data.alternatives[0].expr.symbols[0]))
+            } else {
+                None
+            };
+
+            match element {
+                Some((min, symbol)) => {
+                    let value = format!("{}e", self.prefix);
+                    r::UnparseKind::Repeat {
+                        min: min,
+                        element: self.item(strip(symbol), value),
+                    }
+                }
+                None => {
+                    let boxed = annotation(data, BOXED).is_some();
+                    let mut alternatives = vec![];
+                    for alternative in &data.alternatives {
+                        if let Some(alternative) = try!(self.alternative(nt, alternative, boxed)) {
+                            alternatives.push(alternative);
+                        }
+                    }
+
+                    // the alternatives that pass values on are only
+                    // tried once no pattern matches, and those that
+                    // wrap them in terminals, like parentheses, last
+                    alternatives.sort_by_key(|alternative| {
+                        (alternative.pattern.is_none(), alternative.items.len() > 1)
+                    });
+                    r::UnparseKind::Alternatives(alternatives)
+                }
+            }
+        };
+
+        Ok(r::UnparseDefn {
+            nonterminal: nt,
+            ty: self.types.nonterminal_type(nt).clone(),
+            public: false,
+            kind: kind,
+        })
+    }
+
+    /// How to unparse `alternative` of `nt`, or `None` for those that
+    /// recover from errors, which can't be.
+    fn alternative(&mut self,
+                   nt: NonterminalString,
+                   alternative: &Alternative,
+                   boxed: bool)
+                   -> NormResult<Option<r::UnparseAlternative>> {
+        let symbols = &alternative.expr.symbols;
+        if symbols.iter().any(|symbol| is_error(strip(symbol))) {
+            return Ok(None);
+        }
+
+        let cannot = |reason: String| -> NormError {
+            NormError {
+                message: format!("cannot unparse this alternative of `{}`: {}; \
+                                  a `#[format = \"...\"]` annotation on `{}` can say \
+                                  how to write its values instead",
+                                 nt,
+                                 reason,
+                                 nt),
+                span: alternative.span,
+            }
+        };
+
+        // the action, with `<>` replaced by the names of the symbols,
+        // as lowering does, and the symbols named in it
+        let unit = self.types.nonterminal_type(nt).is_unit();
+        let code = match alternative.action {
+            None if unit => format!("()"),
+            None => format!("(<>)"),
+            Some(ActionKind::User(ref code)) => code.clone(),
+            Some(_) => return Err(cannot(format!("its action is fallible"))),
+        };
+        let mut names: Vec<Option<InternedString>> = vec![None; symbols.len()];
+        let selected: Vec<String> = match norm_util::analyze_expr(&alternative.expr) {
+            Symbols::Named(named) => {
+                named.iter()
+                     .map(|&(index, name, _)| {
+                         names[index] = Some(name);
+                         name.to_string()
+                     })
+                     .collect()
+            }
+            Symbols::Anon(indices) => {
+                indices.iter()
+                       .enumerate()
+                       .map(|(i, &(index, _))| {
+                           let name = format!("{}{}", self.prefix, i);
+                           names[index] = Some(intern(&name));
+                           name
+                       })
+                       .collect()
+            }
+        };
+        let code = match norm_util::check_between_braces(&code) {
+            Presence::None => code,
+            Presence::Normal => code.replace("<>", &selected.join(", ")),
+            Presence::InCurlyBrackets => {
+                let fields: Vec<String> =
+                    selected.iter().map(|name| format!("{0}:{0}", name)).collect();
+                code.replace("<>", &fields.join(", "))
+            }
+        };
+
+        let mut expr = match ActionParser::new(&code).parse() {
+            Some(expr) => expr,
+            None => {
+                return Err(cannot(format!("its action `{}` does more than build a value \
+                                           from its symbols",
+                                          code)))
+            }
+        };
+        // `Box::new(x)` puts a box around the value, and `*x` takes one
+        // off of it, which `#[boxed]` puts back on
+        let mut derefs = if boxed { 1 } else { 0 };
+        loop {
+            if let Some(inner) = unbox(&expr) {
+                derefs += 1;
+                expr = inner;
+                continue;
+            }
+            let inner = match expr {
+                Expr::Deref(ref inner) if derefs > 0 => (**inner).clone(),
+                _ => break,
+            };
+            derefs -= 1;
+            expr = inner;
+        }
+
+        // the expressions for the values of the symbols, by index
+        let mut values = map();
+        let bindings: Map<String, usize> =
+            names.iter()
+                 .enumerate()
+                 .filter_map(|(index, name)| name.map(|name| (name.to_string(), index)))
+                 .collect();
+        let pattern = match expr {
+            Expr::Path(ref name) if bindings.contains_key(name) => {
+                let value = if derefs == 0 {
+                    format!("{}value", self.prefix)
+                } else {
+                    format!("&{}{}value", "*".repeat(derefs + 1), self.prefix)
+                };
+                values.insert(bindings[name], value);
+                None
+            }
+            _ => Some(try!(self.pattern(&expr, symbols, &bindings, &mut values).map_err(&cannot))),
+        };
+
+        let mut items = vec![];
+        for (index, symbol) in symbols.iter().enumerate() {
+            let symbol = strip(symbol);
+            if self.is_location(symbol) {
+                continue;
+            }
+            let item = match values.remove(&index) {
+                Some(value) => {
+                    match (self.item(symbol, value), pattern.is_none()) {
+                        (r::UnparseItem::Nonterminal(index, value), true) => {
+                            r::UnparseItem::Passthrough(index, value)
+                        }
+                        (item, _) => item,
+                    }
+                }
+                None => try!(self.dropped_item(symbol).map_err(&cannot)),
+            };
+            items.push(item);
+        }
+
+        Ok(Some(r::UnparseAlternative {
+            pattern: pattern,
+            derefs: derefs,
+            items: items,
+        }))
+    }
+
+    /// The pattern that matches the values built by `expr`, recording
+    /// the expressions for the values of the symbols it binds.
+    fn pattern(&self,
+               expr: &Expr,
+               symbols: &[Symbol],
+               bindings: &Map<String, usize>,
+               values: &mut Map<usize, String>)
+               -> Result<String, String> {
+        match *expr {
+            Expr::Path(ref name) if bindings.contains_key(name) => {
+                self.bind(name, name.clone(), symbols, bindings, values)
+            }
+            Expr::Path(ref path) => {
+                // a lone identifier in a pattern would bind anything
+                if is_identifier(path) && !is_constant(path) {
+                    return Err(format!("`{}` is not a symbol of the alternative", path));
+                }
+                Ok(path.clone())
+            }
+            Expr::Literal(ref literal) => Ok(literal.clone()),
+            Expr::Deref(_) => Err(format!("its action takes a value out of a box")),
+            Expr::Call(ref path, ref args) if is_box_new(path) => {
+                match unbox(expr) {
+                    Some(Expr::Path(ref name)) if bindings.contains_key(name) => {
+                        self.bind(name, format!("&**{}", name), symbols, bindings, values)
+                    }
+                    _ => Err(format!("its action boxes something other than a symbol")),
+                }
+            }
+            Expr::Call(ref path, ref args) => {
+                let mut patterns = vec![];
+                for arg in args {
+                    patterns.push(try!(self.pattern(arg, symbols, bindings, values)));
+                }
+                Ok(format!("{}({})", path, patterns.join(", ")))
+            }
+            Expr::Struct(ref path, ref fields) => {
+                let mut patterns = vec![];
+                for &(ref field, ref value) in fields {
+                    let pattern = try!(self.pattern(value, symbols, bindings, values));
+                    patterns.push(format!("{}: {}", field, pattern));
+                }
+                Ok(format!("{} {{ {} }}", path, patterns.join(", ")))
+            }
+            Expr::Tuple(ref elements) => {
+                let mut patterns = vec![];
+                for element in elements {
+                    patterns.push(try!(self.pattern(element, symbols, bindings, values)));
+                }
+                if patterns.len() == 1 {
+                    Ok(format!("({},)", patterns[0]))
+                } else {
+                    Ok(format!("({})", patterns.join(", ")))
+                }
+            }
+        }
+    }
+
+    /// The pattern that binds the value of the symbol `name`, whose
+    /// value is then `value`.
+    fn bind(&self,
+            name: &str,
+            value: String,
+            symbols: &[Symbol],
+            bindings: &Map<String, usize>,
+            values: &mut Map<usize, String>)
+            -> Result<String, String> {
+        let index = bindings[name];
+        if self.is_location(strip(&symbols[index])) {
+            return Ok(format!("_"));
+        }
+        if values.insert(index, value).is_some() {
+            return Err(format!("its action uses `{}` more than once", name));
+        }
+        Ok(format!("ref {}", name))
+    }
+
+    /// How to write `symbol`, whose value is `value`.
+    fn item(&mut self, symbol: &Symbol, value: String) -> r::UnparseItem {
+        match symbol.kind {
+            SymbolKind::Terminal(_) => r::UnparseItem::Display(value),
+            SymbolKind::Nonterminal(nt) => r::UnparseItem::Nonterminal(self.index(nt), value),
+            _ => panic!("unexpected symbol `{}`", symbol),
+        }
+    }
+
+    /// How to write `symbol`, whose value the action drops.
+    fn dropped_item(&mut self, symbol: &Symbol) -> Result<r::UnparseItem, String> {
+        match symbol.kind {
+            SymbolKind::Terminal(TerminalString::Literal(TerminalLiteral::Quoted(text))) => {
+                Ok(r::UnparseItem::Text(text.to_string()))
+            }
+            SymbolKind::Nonterminal(nt) if self.types.nonterminal_type(nt).is_unit() => {
+                Ok(r::UnparseItem::Nonterminal(self.index(nt), format!("&()")))
+            }
+            _ => Err(format!("its action drops the value of `{}`", symbol)),
+        }
+    }
+
+    /// True for `@L` and `@R`, whose values are locations rather than
+    /// text.
+    fn is_location(&self, symbol: &Symbol) -> bool {
+        match symbol.kind {
+            SymbolKind::Nonterminal(nt) => {
+                self.nonterminals[&nt].alternatives.iter().any(|alternative| {
+                    match alternative.action {
+                        Some(ActionKind::Lookahead) | Some(ActionKind::Lookbehind) => true,
+                        _ => false,
+                    }
+                })
+            }
+            SymbolKind::Lookahead | SymbolKind::Lookbehind => true,
+            _ => false,
+        }
+    }
+}
+
+fn annotation<'a>(nt: &'a NonterminalData, id: &str) -> Option<&'a r::Annotation> {
+    nt.annotations.iter().find(|annotation| annotation.id == intern(id))
+}
+
+/// `symbol`, without the names and angle brackets that select it.
+fn strip(symbol: &Symbol) -> &Symbol {
+    match symbol.kind {
+        SymbolKind::Name(_, ref symbol) | SymbolKind::Choose(ref symbol) => strip(symbol),
+        _ => symbol,
+    }
+}
+
+fn is_error(symbol: &Symbol) -> bool {
+    match symbol.kind {
+        SymbolKind::Error | SymbolKind::Terminal(TerminalString::Error) => true,
+        _ => false,
+    }
+}
+
+fn is_identifier(path: &str) -> bool {
+    !path.contains("::")
+}
+
+/// Identifiers that name constants by convention, like `None` or
+/// `MAX`, rather than variables.
+fn is_constant(name: &str) -> bool {
+    name.chars().next().map_or(false, |c| c.is_uppercase())
+}
+
+fn is_box_new(path: &str) -> bool {
+    path == "Box::new" || path.ends_with("::boxed::Box::new")
+}
+
+/// The value that `expr` boxes, if it is `Box::new(...)`.
+fn unbox(expr: &Expr) -> Option<Expr> {
+    match *expr {
+        Expr::Call(ref path, ref args) if is_box_new(path) && args.len() == 1 => {
+            Some(args[0].clone())
+        }
+        _ => None,
+    }
+}
+
+/// The action code that can be turned into a pattern.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Expr {
+    /// `a`, `Op::Plus`, `::std::option::Option::None`
+    Path(String),
+
+    /// `1`, `"a"`, `'a'`, `true`
+    Literal(String),
+
+    /// `Expr::Add(l, r)`
+    Call(String, Vec<Expr>),
+
+    /// `Point { x: x, y }`, with `y` expanded to `y: y`
+    Struct(String, Vec<(String, Expr)>),
+
+    /// `(a, b)`; `(a)` is just `a`
+    Tuple(Vec<Expr>),
+
+    /// `*a`
+    Deref(Box<Expr>),
+}
+
+struct ActionParser<'code> {
+    text: &'code str,
+    position: usize,
+}
+
+impl<'code> ActionParser<'code> {
+    fn new(text: &'code str) -> Self {
+        ActionParser {
+            text: text,
+            position: 0,
+        }
+    }
+
+    fn parse(mut self) -> Option<Expr> {
+        let expr = self.expr();
+        self.skip_whitespace();
+        if self.position == self.text.len() {
+            expr
+        } else {
+            None
+        }
+    }
+
+    fn rest(&self) -> &'code str {
+        &self.text[self.position..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_left().len();
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(token) {
+            self.position += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expr(&mut self) -> Option<Expr> {
+        self.skip_whitespace();
+        let c = match self.rest().chars().next() {
+            Some(c) => c,
+            None => return None,
+        };
+        if c == '*' {
+            self.position += 1;
+            return Some(Expr::Deref(Box::new(try_some!(self.expr()))));
+        }
+        if c == '(' {
+            self.position += 1;
+            let (mut elements, trailing_comma) = try_some!(self.list(')'));
+            if elements.len() == 1 && !trailing_comma {
+                return elements.pop();
+            }
+            return Some(Expr::Tuple(elements));
+        }
+        if c == '"' || c == '\'' || c.is_digit(10) || c == '-' {
+            return self.literal();
+        }
+
+        let path = try_some!(self.path());
+        if path == "true" || path == "false" {
+            return Some(Expr::Literal(path));
+        }
+        if self.eat("(") {
+            let (args, _) = try_some!(self.list(')'));
+            Some(Expr::Call(path, args))
+        } else if self.eat("{") {
+            let mut fields = vec![];
+            while !self.eat("}") {
+                let field = try_some!(self.identifier());
+                let value = if self.eat(":") {
+                    try_some!(self.expr())
+                } else {
+                    Expr::Path(field.clone())
+                };
+                fields.push((field, value));
+                if !self.eat(",") {
+                    if !self.eat("}") {
+                        return None;
+                    }
+                    break;
+                }
+            }
+            Some(Expr::Struct(path, fields))
+        } else {
+            Some(Expr::Path(path))
+        }
+    }
+
+    /// The expressions up to `close`, and whether a comma follows the
+    /// last one.
+    fn list(&mut self, close: char) -> Option<(Vec<Expr>, bool)> {
+        let close = close.to_string();
+        let mut elements = vec![];
+        loop {
+            if self.eat(&close) {
+                return Some((elements, true));
+            }
+            elements.push(try_some!(self.expr()));
+            if !self.eat(",") {
+                if self.eat(&close) {
+                    return Some((elements, false));
+                }
+                return None;
+            }
+        }
+    }
+
+    fn path(&mut self) -> Option<String> {
+        let mut path = String::new();
+        if self.eat("::") {
+            path.push_str("::");
+        }
+        path.push_str(&try_some!(self.identifier()));
+        while self.rest().starts_with("::") {
+            self.position += 2;
+            path.push_str("::");
+            path.push_str(&try_some!(self.identifier()));
+        }
+        Some(path)
+    }
+
+    fn identifier(&mut self) -> Option<String> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let length = rest.find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                         .unwrap_or(rest.len());
+        if length == 0 || rest.chars().next().map_or(false, |c| c.is_digit(10)) {
+            return None;
+        }
+        self.position += length;
+        Some(rest[..length].to_string())
+    }
+
+    fn literal(&mut self) -> Option<Expr> {
+        let rest = self.rest();
+        let mut chars = rest.char_indices();
+        let (_, first) = chars.next().unwrap();
+        let length = if first == '"' || first == '\'' {
+            let mut escaped = false;
+            let mut end = None;
+            for (i, c) in chars {
+                if escaped {
+                    escaped = false;
+                } else if c == '\\' {
+                    escaped = true;
+                } else if c == first {
+                    end = Some(i + 1);
+                    break;
+                }
+            }
+            try_some!(end)
+        } else {
+            let number = |c: char| c.is_alphanumeric() || c == '_' || c == '.';
+            let digits = 1 + rest[1..].find(|c: char| !number(c)).unwrap_or(rest.len() - 1);
+            if digits == 1 && first == '-' {
+                return None;
+            }
+            digits
+        };
+        self.position += length;
+        Some(Expr::Literal(rest[..length].to_string()))
+    }
+}
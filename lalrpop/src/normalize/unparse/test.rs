@@ -0,0 +1,127 @@
+use grammar::repr::{UnparseAlternative, UnparseItem, UnparseKind};
+use normalize::normalize_without_validating;
+use parser;
+use test_util::{check_norm_err, normalized_grammar};
+
+#[test]
+fn patterns() {
+    let grammar = normalized_grammar(r#"
+grammar;
+    #[unparse]
+    pub Expr: Box<Expr> = {
+        <l:Expr> "+" <r:Term> => Box::new(Expr::Op(l, Op::Add, r)),
+        Term,
+    };
+    Term: Box<Expr> = {
+        "-" <Term> => Box::new(Expr::Neg(<>)),
+        <name:"Id"> <args:Term*> => Box::new(Expr::Call { name: name, args }),
+        "(" <Expr> ")",
+    };
+"#);
+
+    let unparsers: Vec<_> = grammar.unparsers
+                                   .iter()
+                                   .map(|defn| (defn.nonterminal.to_string(), defn.public))
+                                   .collect();
+    assert_eq!(unparsers,
+               vec![(format!("Expr"), true), (format!("Term"), false), (format!("Term*"), false)]);
+
+    let text = |text: &str| UnparseItem::Text(text.to_string());
+    let nonterminal = |index, value: &str| UnparseItem::Nonterminal(index, value.to_string());
+    assert_eq!(grammar.unparsers[0].kind,
+               UnparseKind::Alternatives(vec![
+                   UnparseAlternative {
+                       pattern: Some(format!("Expr::Op(ref l, Op::Add, ref r)")),
+                       derefs: 1,
+                       items: vec![nonterminal(0, "l"), text("+"), nonterminal(1, "r")],
+                   },
+                   UnparseAlternative {
+                       pattern: None,
+                       derefs: 0,
+                       items: vec![UnparseItem::Passthrough(1, format!("__value"))],
+                   },
+               ]));
+    assert_eq!(grammar.unparsers[1].kind,
+               UnparseKind::Alternatives(vec![
+                   UnparseAlternative {
+                       pattern: Some(format!("Expr::Neg(ref __0)")),
+                       derefs: 1,
+                       items: vec![text("-"), nonterminal(1, "__0")],
+                   },
+                   UnparseAlternative {
+                       pattern: Some(format!("Expr::Call {{ name: ref name, args: ref args }}")),
+                       derefs: 1,
+                       items: vec![UnparseItem::Display(format!("name")),
+                                   nonterminal(2, "args")],
+                   },
+                   UnparseAlternative {
+                       pattern: None,
+                       derefs: 0,
+                       items: vec![text("("),
+                                   UnparseItem::Passthrough(0, format!("__value")),
+                                   text(")")],
+                   },
+               ]));
+    assert_eq!(grammar.unparsers[2].kind,
+               UnparseKind::Repeat { min: 0, element: nonterminal(1, "__e") });
+}
+
+#[test]
+fn format_and_boxed() {
+    let grammar = normalized_grammar(r#"
+grammar;
+    #[unparse]
+    #[boxed]
+    pub Expr: Expr = {
+        Num => Expr::Num(<>),
+        "(" <Expr> ")" => *<>,
+    };
+    #[format = "{:?}"]
+    Num: i32 = "Num" => 0;
+"#);
+
+    assert_eq!(grammar.unparsers[1].kind, UnparseKind::Format("{:?}".to_string()));
+    match grammar.unparsers[0].kind {
+        UnparseKind::Alternatives(ref alternatives) => {
+            assert_eq!(alternatives.len(), 2);
+            assert_eq!(alternatives[0].pattern, Some(format!("Expr::Num(ref __0)")));
+            assert_eq!(alternatives[0].derefs, 1);
+
+            // `*<>` takes off the box that `#[boxed]` puts back on
+            assert_eq!(alternatives[1].pattern, None);
+            assert_eq!(alternatives[1].items[1],
+                       UnparseItem::Passthrough(0, format!("__value")));
+        }
+        ref kind => panic!("unexpected unparser {:?}", kind),
+    }
+}
+
+fn check_err(expected_err: &str, grammar: &str, span: &str) {
+    let parsed_grammar = parser::parse_grammar(&grammar).unwrap();
+    let err = normalize_without_validating(parsed_grammar).unwrap_err();
+    check_norm_err(expected_err, span, err);
+}
+
+#[test]
+fn action_does_more_than_build() {
+    check_err(
+        r#"cannot unparse this alternative of `Num`: its action `i32::from_str\(__0\).unwrap\(\)` does more than build a value from its symbols"#,
+        r#"grammar; #[unparse] pub Num: i32 = "Num" => i32::from_str(<>).unwrap();"#,
+        r#"                                   ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~   "#);
+}
+
+#[test]
+fn dropped_value() {
+    check_err(
+        r#"its action drops the value of `Term`"#,
+        r#"grammar; #[unparse] pub Expr: Expr = <l:Expr> "+" Term => Expr::Op(l); Term: Expr = "x" => Expr::X;"#,
+        r#"                                     ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~                                  "#);
+}
+
+#[test]
+fn symbol_used_twice() {
+    check_err(
+        r#"its action uses `x` more than once"#,
+        r#"grammar; #[unparse] pub Pair: (&'input str, &'input str) = <x:"x"> => (x, x);"#,
+        r#"                                                           ~~~~~~~~~~~~~~~~~  "#);
+}
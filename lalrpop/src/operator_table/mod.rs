@@ -0,0 +1,274 @@
+//! Writes a table of the operators of a grammar (`--emit operators`),
+//! with their precedences and associativities, for documentation.
+//!
+//! Operators are found in the expression tiers of the grammar, which
+//! are the same whether written by hand or expanded from `#[pratt]`:
+//!
+//!     Expr = { Expr "+" Factor, Factor };
+//!     Factor = { Factor "*" Unary, Unary };
+//!     Unary = { "-" Unary, Atom };
+//!     Atom = { Num, "(" Expr ")" };
+//!
+//! A tier is a nonterminal that falls through to exactly one other
+//! nonterminal, the next tier, and has operator productions, which
+//! start or end with an operand from the chain of tiers and have a
+//! terminal in between. The chain ends at a nonterminal that is not a
+//! tier (`Atom` above). Precedence 1 is the tier that binds the most
+//! loosely, and the associativity of an operator follows from which of
+//! its operands stay in its own tier.
+//!
+//! The table also lists the operators whose placement contradicts
+//! their precedence: those with an operand from a looser tier (so that
+//! they bind more loosely than their tier on that side), with both
+//! operands in their own tier (which is ambiguous), with an operand
+//! that skips tiers (so that operands using the operators in between
+//! need parentheses), or that appear in several tiers.
+
+use collections::{map, Map, Set};
+use grammar::repr::*;
+use grammar_doc::{cell, code};
+use std::fmt;
+use std::io::{self, Write};
+
+#[cfg(test)]
+mod test;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OperatorTable {
+    /// The loosest tier, which names the expressions of the table.
+    pub nonterminal: NonterminalString,
+    pub operators: Vec<Operator>,
+    pub problems: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Operator {
+    /// 1 for the loosest tier.
+    pub precedence: usize,
+    /// The symbols of the production other than its operands.
+    pub symbols: String,
+    pub associativity: Associativity,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Associativity {
+    Left,
+    Right,
+    /// An infix operator whose operands are both from tighter tiers,
+    /// so that it cannot be chained without parentheses.
+    None,
+    Prefix,
+    Postfix,
+    /// The placement of the operator contradicts its precedence; see
+    /// the problems of the table.
+    Unknown,
+}
+
+impl fmt::Display for Associativity {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let name = match *self {
+            Associativity::Left => "left",
+            Associativity::Right => "right",
+            Associativity::None => "none",
+            Associativity::Prefix => "prefix",
+            Associativity::Postfix => "postfix",
+            Associativity::Unknown => "?",
+        };
+        write!(fmt, "{}", name)
+    }
+}
+
+pub fn generate_operators<W: Write>(out: &mut W, title: &str, grammar: &Grammar) -> io::Result<()> {
+    let tables = operator_tables(grammar);
+
+    try!(writeln!(out, "# Operators of grammar `{}`", title));
+    try!(writeln!(out, ""));
+    if tables.is_empty() {
+        try!(writeln!(out, "No expression tiers were found."));
+        return Ok(());
+    }
+    try!(writeln!(out, "Precedence 1 binds the most loosely."));
+
+    for table in &tables {
+        try!(writeln!(out, ""));
+        try!(writeln!(out, "## {}", code(&table.nonterminal.to_string())));
+        try!(writeln!(out, ""));
+        try!(writeln!(out, "| Precedence | Operator | Associativity |"));
+        try!(writeln!(out, "|---|---|---|"));
+        for operator in &table.operators {
+            try!(writeln!(out,
+                          "| {} | {} | {} |",
+                          operator.precedence,
+                          cell(&code(&operator.symbols)),
+                          operator.associativity));
+        }
+        if !table.problems.is_empty() {
+            try!(writeln!(out, ""));
+            try!(writeln!(out, "Problems:"));
+            try!(writeln!(out, ""));
+            for problem in &table.problems {
+                try!(writeln!(out, "- {}", problem));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The operator tables of the chains of tiers in `grammar`.
+pub fn operator_tables(grammar: &Grammar) -> Vec<OperatorTable> {
+    let tiers: Map<NonterminalString, NonterminalString> =
+        grammar.nonterminals
+               .keys()
+               .filter_map(|&nt| is_tier(grammar, nt).map(|next| (nt, next)))
+               .collect();
+    let inner: Set<NonterminalString> = tiers.values().cloned().collect();
+
+    let mut tables = vec![];
+    for &start in tiers.keys().filter(|nt| !inner.contains(nt)) {
+        // the tiers from the loosest, and the nonterminal the chain
+        // ends at
+        let mut chain = vec![];
+        let mut current = start;
+        while let Some(&next) = tiers.get(&current) {
+            if chain.contains(&current) {
+                break;
+            }
+            chain.push(current);
+            current = next;
+        }
+        if !chain.contains(&current) {
+            chain.push(current);
+        }
+        tables.push(operator_table(grammar, &chain));
+    }
+    tables
+}
+
+/// If `nt` is a tier, the next tier it falls through to.
+fn is_tier(grammar: &Grammar, nt: NonterminalString) -> Option<NonterminalString> {
+    let productions = grammar.productions_for(nt);
+    let fallthroughs: Vec<NonterminalString> =
+        productions.iter().filter_map(|p| fallthrough(p)).collect();
+    if fallthroughs.len() != 1 || fallthroughs[0] == nt {
+        return None;
+    }
+    let next = fallthroughs[0];
+    let operand = |symbol: &Symbol| *symbol == Symbol::Nonterminal(nt) ||
+                                    *symbol == Symbol::Nonterminal(next);
+    let has_operators = productions.iter().any(|p| {
+        p.symbols.len() >= 2 &&
+        (operand(&p.symbols[0]) || operand(&p.symbols[p.symbols.len() - 1])) &&
+        p.symbols.iter().any(|s| s.is_terminal())
+    });
+    if has_operators {
+        Some(next)
+    } else {
+        None
+    }
+}
+
+fn fallthrough(production: &Production) -> Option<NonterminalString> {
+    match production.symbols.len() {
+        1 => match production.symbols[0] {
+            Symbol::Nonterminal(nt) => Some(nt),
+            Symbol::Terminal(_) => None,
+        },
+        _ => None,
+    }
+}
+
+fn operator_table(grammar: &Grammar, chain: &[NonterminalString]) -> OperatorTable {
+    let precedence = |symbol: &Symbol| match *symbol {
+        Symbol::Nonterminal(nt) => chain.iter().position(|&n| n == nt).map(|i| i + 1),
+        Symbol::Terminal(_) => None,
+    };
+
+    let mut operators = vec![];
+    let mut problems = vec![];
+
+    // where each operator appears, by whether it is infix, prefix or
+    // postfix
+    let mut seen: Map<(String, &'static str), Vec<usize>> = map();
+
+    for (index, &nt) in chain.iter().enumerate() {
+        let tier = index + 1;
+        for production in grammar.productions_for(nt) {
+            let symbols = &production.symbols;
+            if symbols.len() < 2 {
+                continue;
+            }
+            let left = precedence(&symbols[0]);
+            let right = precedence(&symbols[symbols.len() - 1]);
+            let start = if left.is_some() { 1 } else { 0 };
+            let end = if right.is_some() { symbols.len() - 1 } else { symbols.len() };
+            let middle = &symbols[start..end];
+            if (left.is_none() && right.is_none()) || !middle.iter().any(|s| s.is_terminal()) {
+                continue;
+            }
+            let text = middle.iter().map(|s| s.to_string()).collect::<Vec<_>>().join(" ");
+            let name = code(&text);
+
+            let mut associativity = match (left, right) {
+                (Some(l), Some(r)) if l == tier && r == tier => {
+                    problems.push(format!("{} at precedence {} has both operands from its own \
+                                           tier, so it is ambiguous which one it associates to",
+                                          name, tier));
+                    Associativity::Unknown
+                }
+                (Some(l), Some(_)) if l == tier => Associativity::Left,
+                (Some(_), Some(r)) if r == tier => Associativity::Right,
+                (Some(_), Some(_)) => Associativity::None,
+                (None, _) => Associativity::Prefix,
+                (_, None) => Associativity::Postfix,
+            };
+
+            for (side, operand) in vec![("left", left), ("right", right)] {
+                let operand = match operand {
+                    Some(operand) => operand,
+                    None => continue,
+                };
+                if operand < tier {
+                    problems.push(format!("{} at precedence {} takes its {} operand from \
+                                           precedence {}, so it binds more loosely on that \
+                                           side than its precedence says",
+                                          name, tier, side, operand));
+                    associativity = Associativity::Unknown;
+                } else if operand > tier + 1 {
+                    problems.push(format!("{} at precedence {} takes its {} operand from \
+                                           precedence {}, so operands using the operators \
+                                           in between need parentheses",
+                                          name, tier, side, operand));
+                }
+            }
+
+            let kind = match (left, right) {
+                (Some(_), Some(_)) => "an infix",
+                (None, _) => "a prefix",
+                (_, None) => "a postfix",
+            };
+            seen.entry((text.clone(), kind)).or_insert_with(Vec::new).push(tier);
+
+            operators.push(Operator {
+                precedence: tier,
+                symbols: text,
+                associativity: associativity,
+            });
+        }
+    }
+
+    for ((text, kind), tiers) in seen {
+        let distinct: Set<usize> = tiers.iter().cloned().collect();
+        if distinct.len() > 1 {
+            let tiers: Vec<String> = distinct.iter().map(|t| t.to_string()).collect();
+            problems.push(format!("{} is {} operator at precedences {}, so which one applies \
+                                   depends on where it is used",
+                                  code(&text), kind, tiers.join(", ")));
+        }
+    }
+
+    OperatorTable {
+        nonterminal: chain[0],
+        operators: operators,
+        problems: problems,
+    }
+}
@@ -0,0 +1,127 @@
+use intern::intern;
+use grammar::repr::NonterminalString;
+use test_util::normalized_grammar;
+
+use super::{generate_operators, operator_tables, Associativity, OperatorTable};
+
+fn tables(text: &str) -> Vec<OperatorTable> {
+    operator_tables(&normalized_grammar(text))
+}
+
+fn summary(table: &OperatorTable) -> Vec<(usize, &str, Associativity)> {
+    table.operators
+         .iter()
+         .map(|o| (o.precedence, &o.symbols[..], o.associativity))
+         .collect()
+}
+
+#[test]
+fn hand_written_tiers() {
+    let tables = tables(r#"
+grammar;
+    pub Expr: () = { Expr "+" Factor => (), Factor };
+    Factor: () = { Factor "*" Unary => (), Unary };
+    Unary: () = { "-" Unary => (), Power };
+    Power: () = { Atom "^" Power => (), Atom };
+    Atom: () = { "x" => (), "(" Expr ")" => (), Atom "!" => () };
+"#);
+    assert_eq!(tables.len(), 1);
+    assert_eq!(tables[0].nonterminal, NonterminalString(intern("Expr")));
+    assert_eq!(summary(&tables[0]),
+               vec![(1, r#""+""#, Associativity::Left),
+                    (2, r#""*""#, Associativity::Left),
+                    (3, r#""-""#, Associativity::Prefix),
+                    (4, r#""^""#, Associativity::Right),
+                    (5, r#""!""#, Associativity::Postfix)]);
+    assert!(tables[0].problems.is_empty());
+}
+
+#[test]
+fn pratt() {
+    let tables = tables(r#"
+grammar;
+    #[pratt]
+    pub Expr: () = {
+        #[left = "1"] Expr "+" Expr => (),
+        #[left = "1"] Expr "-" Expr => (),
+        #[right = "4"] Expr "^" Expr => (),
+        #[prefix = "3"] "-" Expr => (),
+        "x" => (),
+    };
+"#);
+    assert_eq!(tables.len(), 1);
+    assert_eq!(summary(&tables[0]),
+               vec![(1, r#""+""#, Associativity::Left),
+                    (1, r#""-""#, Associativity::Left),
+                    (2, r#""-""#, Associativity::Prefix),
+                    (3, r#""^""#, Associativity::Right)]);
+    assert!(tables[0].problems.is_empty());
+}
+
+#[test]
+fn contradictions() {
+    let tables = tables(r#"
+grammar;
+    pub Expr: () = { Expr "+" Factor => (), Expr "?" Expr => (), Factor };
+    Factor: () = { Factor "*" Expr => (), Factor "+" Term => (), Term };
+    Term: () = { Term "." Atom => (), Atom };
+    Atom: () = { "x" => (), Expr "<" Atom => () };
+"#);
+    assert_eq!(tables.len(), 1);
+    let problems = &tables[0].problems;
+    assert!(problems.iter().any(|p| p.starts_with(r#"`"?"` at precedence 1 has both operands"#)),
+            "{:?}", problems);
+    assert!(problems.iter().any(|p| {
+        p.starts_with(r#"`"*"` at precedence 2 takes its right operand from precedence 1"#)
+    }));
+    assert!(problems.iter().any(|p| {
+        p.starts_with(r#"`"<"` at precedence 4 takes its left operand from precedence 1"#)
+    }));
+    assert!(problems.iter().any(|p| {
+        p.starts_with(r#"`"+"` is an infix operator at precedences 1, 2"#)
+    }));
+    assert_eq!(problems.len(), 4, "{:?}", problems);
+}
+
+#[test]
+fn skipped_tier() {
+    let tables = tables(r#"
+grammar;
+    pub Expr: () = { Expr "+" Atom => (), Factor };
+    Factor: () = { Factor "*" Atom => (), Atom };
+    Atom: () = "x" => ();
+"#);
+    assert_eq!(tables[0].problems,
+               vec![String::from(r#"`"+"` at precedence 1 takes its right operand from "#) +
+                    "precedence 3, so operands using the operators in between need parentheses"]);
+}
+
+#[test]
+fn report() {
+    let grammar = normalized_grammar(r#"
+grammar;
+    pub Expr: () = { Expr "|" Term => (), Term };
+    Term: () = "x" => ();
+"#);
+    let mut out = vec![];
+    generate_operators(&mut out, "calc", &grammar).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(),
+               r#"# Operators of grammar `calc`
+
+Precedence 1 binds the most loosely.
+
+## `Expr`
+
+| Precedence | Operator | Associativity |
+|---|---|---|
+| 1 | `"\|"` | left |
+"#);
+
+    let grammar = normalized_grammar(r#"
+grammar;
+    pub List: () = { List "x" => (), () => () };
+"#);
+    let mut out = vec![];
+    generate_operators(&mut out, "list", &grammar).unwrap();
+    assert!(String::from_utf8(out).unwrap().ends_with("\nNo expression tiers were found.\n"));
+}
@@ -14,7 +14,15 @@ pub type ParseError<'input> = lalrpop_util::ParseError<usize, tok::Tok<'input>,
 pub fn parse_grammar<'input>(input: &'input str)
                              -> Result<Grammar, ParseError<'input>>
 {
-    let tokenizer = tok::Tokenizer::new(input, 0);
+    parse_grammar_at(input, 0)
+}
+
+/// Like `parse_grammar`, but the spans are offset by `offset`, for a
+/// grammar appended to the text of another (see `FileText::append`).
+pub fn parse_grammar_at<'input>(input: &'input str, offset: usize)
+                                -> Result<Grammar, ParseError<'input>>
+{
+    let tokenizer = tok::Tokenizer::new(input, offset);
     let mut grammar = try!(lrgrammar::parse_Grammar(input, tokenizer));
 
     // find a unique prefix that does not appear anywhere in the input
@@ -32,8 +40,32 @@ fn parse_pattern<'input>(input: &'input str, offset: usize)
     lrgrammar::parse_Pattern(input, tokenizer)
 }
 
+/// Parses the pattern of a conversion like `"+" => Tok::Op(c) if c == '+'`,
+/// splitting off the guard that follows the first `if`, if any.
+fn parse_conversion<'input>(input: &'input str, offset: usize)
+                            -> Result<(Pattern<TypeRef>, Option<String>), ParseError<'input>>
+{
+    let guard_start = tok::Tokenizer::new(input, offset)
+        .take_while(|t| t.is_ok())
+        .filter_map(|t| t.ok())
+        .find(|&(_, ref t, _)| match *t {
+            tok::Tok::If | tok::Tok::IfCode(_) => true,
+            _ => false,
+        })
+        .map(|(lo, _, _)| lo - offset);
+
+    match guard_start {
+        Some(start) => {
+            let pattern = try!(parse_pattern(&input[..start], offset));
+            let guard = input[start + "if".len()..].trim().to_string();
+            Ok((pattern, Some(guard)))
+        }
+        None => Ok((try!(parse_pattern(input, offset)), None)),
+    }
+}
+
 fn parse_match_mapping<'input>(input: &'input str, offset: usize)
-                         -> Result<MatchMapping, ParseError<'input>>
+                         -> Result<(Option<MatchMapping>, Option<Path>), ParseError<'input>>
 {
     let tokenizer = tok::Tokenizer::new(input, offset);
     lrgrammar::parse_MatchMapping(input, tokenizer)
@@ -1,5 +1,7 @@
 use parser;
+use grammar::consts::INLINE;
 use grammar::parse_tree::{GrammarItem, MatchItem};
+use intern::intern;
 
 #[test]
 fn match_block() {
@@ -48,7 +50,7 @@ fn match_complex() {
             // r"(?i)begin" => "BEGIN"
             let item00 = contents0.items.get(0).unwrap();
             match *item00 {
-                MatchItem::Mapped(ref sym, ref mapping, _) => {
+                MatchItem::Mapped(ref sym, ref mapping, _, _, _) => {
                     assert_eq!(format!("{:?}", sym), "r#\"(?i)begin\"#");
                     assert_eq!(format!("{}", mapping), "\"BEGIN\"");
                 },
@@ -57,7 +59,7 @@ fn match_complex() {
             // r"(?i)end" => "END",
             let item01 = contents0.items.get(1).unwrap();
             match *item01 {
-                MatchItem::Mapped(ref sym, ref mapping, _) => {
+                MatchItem::Mapped(ref sym, ref mapping, _, _, _) => {
                     assert_eq!(format!("{:?}", sym), "r#\"(?i)end\"#");
                     assert_eq!(format!("{}", mapping), "\"END\"");
                 },
@@ -68,7 +70,7 @@ fn match_complex() {
             // r"[a-zA-Z_][a-zA-Z0-9_]*" => IDENTIFIER,
             let item10 = contents1.items.get(0).unwrap();
             match *item10 {
-                MatchItem::Mapped(ref sym, ref mapping, _) => {
+                MatchItem::Mapped(ref sym, ref mapping, _, _, _) => {
                     assert_eq!(format!("{:?}", sym), "r#\"[a-zA-Z_][a-zA-Z0-9_]*\"#");
                     assert_eq!(format!("{}", mapping), "IDENTIFIER");
                 },
@@ -79,7 +81,7 @@ fn match_complex() {
             // "other",
             let item20 = contents2.items.get(0).unwrap();
             match *item20 {
-                MatchItem::Unmapped(ref sym, _) => {
+                MatchItem::Unmapped(ref sym, _, _) => {
                     assert_eq!(format!("{:?}", sym), "\"other\"");
                 },
                 _ => panic!("expected MatchItem::Unmapped, but was: {:?}", item20)
@@ -95,6 +97,32 @@ fn match_complex() {
     }
 }
 
+#[test]
+fn match_hooks() {
+    let parsed = parser::parse_grammar(r##"
+grammar;
+    match {
+        r#""[^"]*""# => STRING { unescape::decode_escapes },
+        r"'[^']*'" => { ::unquote },
+    }
+"##).unwrap();
+
+    let items = match parsed.items[0] {
+        GrammarItem::MatchToken(ref data) => &data.contents[0].items,
+        ref item => panic!("expected MatchToken, but was: {:?}", item)
+    };
+    let mappings: Vec<String> =
+        items.iter()
+             .map(|item| match *item {
+                 MatchItem::Mapped(_, ref mapping, _, _, Some(ref hook)) => {
+                     format!("{} {{ {} }}", mapping, hook)
+                 }
+                 _ => panic!("expected MatchItem::Mapped with a hook, but was: {:?}", item)
+             })
+             .collect();
+    assert_eq!(mappings, vec!["STRING { unescape::decode_escapes }", "r#\"'[^']*'\"# { ::unquote }"]);
+}
+
 #[test]
 fn where_clauses() {
     let clauses = vec![
@@ -150,3 +178,107 @@ grammar<F>(logger: &mut F) where F: for<'a> FnMut(&'a str);
         assert!(parser::parse_grammar(g).is_ok());
     }
 }
+
+#[test]
+fn category() {
+    let parsed = parser::parse_grammar(r#"
+grammar;
+    category AssignOp = "+=" | "-=" | "*=";
+"#).unwrap();
+    match parsed.items[0] {
+        GrammarItem::Nonterminal(ref data) => {
+            assert_eq!(format!("{}", data.name), "AssignOp");
+            assert!(!data.public);
+            assert!(data.annotations.iter().any(|a| a.id == intern(INLINE)));
+            let alternatives: Vec<_> = data.alternatives
+                                           .iter()
+                                           .map(|a| format!("{}", a.expr.symbols[0]))
+                                           .collect();
+            assert_eq!(alternatives, vec![r#""+=""#, r#""-=""#, r#""*=""#]);
+        }
+        ref item => panic!("expected Nonterminal, but was {:?}", item),
+    }
+}
+
+#[test]
+fn category_is_not_a_keyword() {
+    assert!(parser::parse_grammar(r#"
+grammar;
+    category = <category:"x"> => category;
+"#).is_ok());
+    assert!(parser::parse_grammar(r#"
+grammar;
+    categroy AssignOp = "+=" | "-=";
+"#).is_err());
+}
+
+#[test]
+fn conversion_guard() {
+    let parsed = parser::parse_grammar(r#"
+grammar;
+    extern {
+        enum Tok {
+            "+" => Tok::Op(c) if c == '+',
+            "-" => Tok::Op(c) if { c == '-' },
+            Num => Tok::Num(<i32>),
+        }
+    }
+"#).unwrap();
+    match parsed.items[0] {
+        GrammarItem::ExternToken(ref data) => {
+            let conversions: Vec<_> = data.enum_token
+                                          .as_ref()
+                                          .unwrap()
+                                          .conversions
+                                          .iter()
+                                          .map(|c| (format!("{}", c.to), c.guard.clone()))
+                                          .collect();
+            assert_eq!(conversions,
+                       vec![(format!("Tok::Op(c)"), Some(format!("c == '+'"))),
+                            (format!("Tok::Op(c)"), Some(format!("{{ c == '-' }}"))),
+                            (format!("Tok::Num(i32)"), None)]);
+        }
+        ref item => panic!("expected ExternToken, but was {:?}", item),
+    }
+}
+
+#[test]
+fn extends() {
+    let parsed = parser::parse_grammar(r#"
+grammar;
+    extend "sql/base.lalrpop";
+    Columns = "*";
+"#).unwrap();
+    assert_eq!(parsed.extends.map(|e| e.path), Some(format!("sql/base.lalrpop")));
+    assert_eq!(parsed.items.len(), 1);
+
+    let parsed = parser::parse_grammar(r#"
+grammar;
+    extend "base.lalrpop" as sql { Expr as SqlExpr, Term as SqlTerm };
+    Columns = sql::Columns;
+"#).unwrap();
+    let extends = parsed.extends.unwrap();
+    assert_eq!(extends.namespace, Some(intern("sql")));
+    let renames: Vec<_> = extends.renames
+                                 .iter()
+                                 .map(|r| format!("{} as {}", r.from, r.to))
+                                 .collect();
+    assert_eq!(renames, vec![format!("Expr as SqlExpr"), format!("Term as SqlTerm")]);
+
+    assert!(parser::parse_grammar(r#"grammar; extend "base.lalrpop" so sql;"#).is_err());
+}
+
+#[test]
+fn token_module() {
+    let module = parser::parse_grammar(r#"grammar; match { "+", r"[0-9]+" => NUM }"#).unwrap();
+    assert!(module.is_token_module());
+
+    let parsed = parser::parse_grammar(r#"
+grammar;
+    match "tokens.lalrpop";
+    Sum = NUM "+" NUM;
+"#).unwrap();
+    assert!(!parsed.is_token_module());
+    assert_eq!(parsed.match_token().and_then(|m| m.module.clone()),
+               Some(format!("tokens.lalrpop")));
+}
@@ -2,7 +2,6 @@
 //! which then gets serialized.
 
 use grammar::repr::Grammar;
-use tls::Tls;
 use std::fmt;
 use std::io::{self, Write};
 
@@ -56,11 +55,14 @@ impl<W:Write> RustWrite<W> {
         writeln!(self.write, "{0:1$}{2}", "", self.indent, out)
     }
 
-    pub fn write_table_row<I, C>(&mut self, iterable: I) -> io::Result<()>
+    /// Writes a row of a parse table: the values, one per line
+    /// followed by its comment if `comments` is true, or all on one
+    /// line otherwise.
+    pub fn write_table_row<I, C>(&mut self, comments: bool, iterable: I) -> io::Result<()>
     where I: IntoIterator<Item=(i32, C)>,
           C: fmt::Display,
     {
-        if Tls::session().emit_comments {
+        if comments {
             for (i, comment) in iterable {
                 try!(self.write_indentation());
                 try!(writeln!(self.write, "{}, {}", i, comment));
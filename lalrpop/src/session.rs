@@ -2,6 +2,7 @@
 //! to `configuration::Configuration`, but it is not exported outside the
 //! crate. Note that all fields are public and so forth for convenience.
 
+use collections::{map, Map, set, Set};
 use std::default::Default;
 use std::path;
 use style::{self, Style};
@@ -22,6 +23,33 @@ pub enum ColorConfig {
     IfTty,
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExampleRendering {
+    /// Draw the reductions as brackets under the symbols (default).
+    Brackets,
+
+    /// Draw the derivation as a tree, indenting each symbol below the
+    /// nonterminal it was reduced to.
+    Tree,
+
+    /// Like `Tree`, but connect the symbols with Unicode box-drawing
+    /// characters.
+    BoxTree,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ConflictBaseline {
+    /// Every conflict is an error (default).
+    Ignore,
+
+    /// Only conflicts missing from the grammar's baseline file are
+    /// errors.
+    Check,
+
+    /// Every conflict is recorded in the grammar's baseline file.
+    Update,
+}
+
 /// Various options to control debug output. Although this struct is
 /// technically part of LALRPOP's exported interface, it is not
 /// considered part of the semver guarantees as end-users are not
@@ -40,11 +68,182 @@ pub struct Session {
     /// forth.
     pub emit_comments: bool,
 
+    /// Emit comments recording which production each piece of
+    /// generated code comes from, and the `//@` markers before action
+    /// code that `rewrite_rustc_output` relies on.
+    pub emit_grammar_comments: bool,
+
+    /// Emit `debug_assert!`s checking the consistency of the stacks
+    /// in the parse loops of table-driven parsers.
+    pub emit_debug_assertions: bool,
+
+    /// Emit code that prints each step a table-driven parser takes.
+    pub emit_trace: bool,
+
+    /// Emit code that counts the states that a table-driven parser
+    /// enters and the reductions it runs, and a function that writes
+    /// these counts out as a profile.
+    pub emit_profile: bool,
+
+    /// Lay out the tables of table-driven parsers according to the
+    /// profile next to the grammar, if there is one.
+    pub use_profile: bool,
+
     /// Emit report file about generated code
     pub emit_report: bool,
 
+    /// Emit a file with the FIRST, FOLLOW and nullable sets of each
+    /// nonterminal
+    pub emit_sets: bool,
+
+    /// Emit a file classifying the grammar as LL(1), SLR(1), LALR(1)
+    /// and/or LR(1)
+    pub emit_classes: bool,
+
+    /// Emit a JSON file describing each conflict found while building
+    /// the LR(1) states, for diffing across grammar revisions
+    pub emit_conflicts: bool,
+
+    /// Emit a criterion benchmark that runs each public parser over
+    /// a corpus of input files
+    pub emit_bench: bool,
+
+    /// Emit `extern "C"` entry points for the public parsers, and a
+    /// C header declaring them
+    pub emit_c_abi: bool,
+
+    /// Emit a Markdown reference of the grammar's nonterminals and
+    /// terminals
+    pub emit_doc: bool,
+
+    /// Emit counts of the grammar's symbols, productions, states and
+    /// conflicts, to keep track of how complex the grammar gets
+    pub emit_stats: bool,
+
+    /// Emit a Markdown table of the operators of the grammar's
+    /// expression tiers, with their precedences and associativities
+    pub emit_operators: bool,
+
+    /// Process all of the grammars in a directory even if some fail,
+    /// and print a summary of them at the end (see
+    /// `Configuration::summarize`)
+    pub summarize: bool,
+
+    /// Derive `Serialize` and `Deserialize` for the `Token` type
+    /// generated for grammars that use the internal tokenizer.
+    pub derive_serde: bool,
+
+    /// Generate lexers that scan simple tokens (and whitespace)
+    /// bytewise instead of matching them with the regex engine twice.
+    pub fast_scanning: bool,
+
+    /// If set, the prefix that the names of the generated items
+    /// start with, instead of `__` (see `Configuration::prefix`).
+    pub prefix: Option<String>,
+
+    /// If set, a cfg predicate (e.g. `feature = "parser"`) that the
+    /// entire generated module is gated on.
+    pub cfg: Option<String>,
+
+    /// cfg predicates that the parsers for particular public
+    /// nonterminals are gated on, keyed by nonterminal name.
+    pub parser_cfgs: Map<String, String>,
+
+    /// The `key = "value"` settings that `#[cfg(...)]` annotations
+    /// in the grammar are checked against.
+    pub grammar_cfgs: Set<(String, String)>,
+
     pub color_config: ColorConfig,
 
+    /// How to draw the example derivations in conflict reports.
+    pub example_rendering: ExampleRendering,
+
+    /// If set, generated parsers return `ParseError::DepthLimit`
+    /// rather than growing their stack beyond this many symbols.
+    pub max_depth: Option<usize>,
+
+    /// If set, generated parsers return `ParseError::ReductionLimit`
+    /// rather than performing more than this many reductions.
+    pub max_reductions: Option<usize>,
+
+    /// If true, Earley parsers keep the values built for nodes of the
+    /// parse forest that a derivation (or `parse_X_all`) uses more
+    /// than once, rather than running their actions again.
+    pub memoize_actions: bool,
+
+    /// If set, when LR(1) construction reports conflicts, search for
+    /// an ambiguous sentence of at most this many tokens.
+    pub ambiguity_search: Option<usize>,
+
+    /// If set, the canonical LR(1) construction is given up on once
+    /// its states take more than this many bytes (see
+    /// `Configuration::lr1_fallback_budget`).
+    pub lr1_fallback_budget: Option<usize>,
+
+    /// If set, the directory that the canonical LR(1) construction
+    /// writes checkpoints to, every `checkpoint_interval` states, and
+    /// resumes from (see `Configuration::checkpoint_dir`).
+    pub checkpoint_dir: Option<path::PathBuf>,
+    pub checkpoint_interval: usize,
+
+    /// If true, grammar warnings (see the `lint` module) are reported
+    /// as errors, unless the grammar explicitly allows them.
+    pub deny_warnings: bool,
+
+    /// If true, keep going when LR(1) construction finds conflicts,
+    /// so that every conflicting state (of every public nonterminal)
+    /// is reported in one run rather than just the first.
+    pub report_all_conflicts: bool,
+
+    /// If true, conflicts are reported as warnings, and the parsers
+    /// are generated anyway, with shifts winning over reductions and
+    /// earlier productions over later ones.
+    pub conflicts_as_warnings: bool,
+
+    /// Whether the conflicts of each grammar are checked against, or
+    /// recorded in, a baseline file next to it.
+    pub conflict_baseline: ConflictBaseline,
+
+    /// Where to write a module declaring the parsers of all of the
+    /// grammars in a directory, if anywhere.
+    pub parsers_module: Option<path::PathBuf>,
+
+    /// If true, inline small, non-recursive nonterminals wherever
+    /// that leaves the parsers with fewer conflicts or states.
+    pub auto_inline: bool,
+
+    /// If true, a conflict report that suggests inlining a nonterminal
+    /// checks whether that resolves the conflict, by rebuilding the
+    /// states, and looks for a nonterminal whose inlining does.
+    pub verify_inlining: bool,
+
+    /// If true, generated parsers never inline the functions that
+    /// reduce each production, so that profiles attribute time to
+    /// them.
+    pub profile_reductions: bool,
+
+    /// If true, generated parsers never panic (nor index out of
+    /// bounds) on any input; they return `ParseError::Internal` where
+    /// other parsers would panic.
+    pub panic_free: bool,
+
+    /// If set, the link section that the parse tables are placed in
+    /// (see `Configuration::table_link_section`).
+    pub table_section: Option<String>,
+
+    /// If true, generated parsers keep their stacks in a buffer that
+    /// the caller supplies (see `Configuration::fixed_stack`).
+    pub fixed_stack: bool,
+
+    /// If true, generated parsers can lex their input into a token
+    /// array up front and parse from it (see
+    /// `Configuration::token_arrays`).
+    pub token_arrays: bool,
+
+    /// If true, table-driven parsers skip the states that only reduce
+    /// a unit production (see `Configuration::eliminate_unit_rules`).
+    pub eliminate_unit_rules: bool,
+
     /// Stop after you find `max_errors` errors. If this value is 0,
     /// report *all* errors. Note that we MAY always report more than
     /// this value if we so choose.
@@ -89,8 +288,49 @@ impl Session {
             out_dir: None,
             force_build: false,
             emit_comments: false,
+            emit_grammar_comments: true,
+            emit_debug_assertions: false,
+            emit_trace: false,
+            emit_profile: false,
+            use_profile: false,
             emit_report: false,
+            emit_sets: false,
+            emit_classes: false,
+            emit_conflicts: false,
+            emit_bench: false,
+            emit_c_abi: false,
+            emit_doc: false,
+            emit_stats: false,
+            emit_operators: false,
+            summarize: false,
+            derive_serde: false,
+            fast_scanning: false,
+            prefix: None,
+            cfg: None,
+            parser_cfgs: map(),
+            grammar_cfgs: set(),
             color_config: ColorConfig::default(),
+            example_rendering: ExampleRendering::Brackets,
+            max_depth: None,
+            max_reductions: None,
+            memoize_actions: false,
+            ambiguity_search: None,
+            lr1_fallback_budget: None,
+            checkpoint_dir: None,
+            checkpoint_interval: 5000,
+            deny_warnings: false,
+            report_all_conflicts: false,
+            conflicts_as_warnings: false,
+            conflict_baseline: ConflictBaseline::Ignore,
+            parsers_module: None,
+            auto_inline: false,
+            verify_inlining: false,
+            profile_reductions: false,
+            panic_free: false,
+            table_section: None,
+            fixed_stack: false,
+            token_arrays: false,
+            eliminate_unit_rules: false,
             max_errors: 1,
             heading: style::FG_WHITE.with(style::BOLD),
             ambig_symbols: style::FG_WHITE,
@@ -113,8 +353,49 @@ impl Session {
             out_dir: None,
             force_build: false,
             emit_comments: false,
+            emit_grammar_comments: true,
+            emit_debug_assertions: false,
+            emit_trace: false,
+            emit_profile: false,
+            use_profile: false,
             emit_report: false,
+            emit_sets: false,
+            emit_classes: false,
+            emit_conflicts: false,
+            emit_bench: false,
+            emit_c_abi: false,
+            emit_doc: false,
+            emit_stats: false,
+            emit_operators: false,
+            summarize: false,
+            derive_serde: false,
+            fast_scanning: false,
+            prefix: None,
+            cfg: None,
+            parser_cfgs: map(),
+            grammar_cfgs: set(),
             color_config: ColorConfig::IfTty,
+            example_rendering: ExampleRendering::Brackets,
+            max_depth: None,
+            max_reductions: None,
+            memoize_actions: false,
+            ambiguity_search: None,
+            lr1_fallback_budget: None,
+            checkpoint_dir: None,
+            checkpoint_interval: 5000,
+            deny_warnings: false,
+            report_all_conflicts: false,
+            conflicts_as_warnings: false,
+            conflict_baseline: ConflictBaseline::Ignore,
+            parsers_module: None,
+            auto_inline: false,
+            verify_inlining: false,
+            profile_reductions: false,
+            panic_free: false,
+            table_section: None,
+            fixed_stack: false,
+            token_arrays: false,
+            eliminate_unit_rules: false,
             max_errors: 1,
             heading: Style::new(),
             ambig_symbols: Style::new(),
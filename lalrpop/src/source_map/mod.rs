@@ -0,0 +1,149 @@
+//! Maps locations in generated parsers back to the grammar they were
+//! generated from.
+//!
+//! When emitting a user action fn, we write a marker comment like
+//! `//@ src/foo.lalrpop:22:17 (2 lines)` just before the action code,
+//! recording where in the grammar the code came from. Given an error
+//! that rustc reports against the generated `.rs` file, we can then
+//! walk back to the nearest marker and compute the corresponding
+//! location in the `.lalrpop` file.
+
+use collections::{map, Map};
+use std::fs::File;
+use std::io::{self, BufRead, Read, Write};
+use std::path::Path;
+
+#[cfg(test)]
+mod test;
+
+const MARKER_PREFIX: &'static str = "//@ ";
+
+/// Returns the marker comment that precedes action code found at
+/// `line`:`col` (both 1-based) of the grammar at `path`, spanning
+/// `lines` lines.
+pub fn marker(path: &Path, line: usize, col: usize, lines: usize) -> String {
+    format!("{}{}:{}:{} ({} lines)", MARKER_PREFIX, path.display(), line, col, lines)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Marker {
+    path: String,
+    line: usize,
+    col: usize,
+    lines: usize,
+}
+
+fn parse_marker(text: &str) -> Option<Marker> {
+    let text = text.trim();
+    if !text.starts_with(MARKER_PREFIX) || !text.ends_with(" lines)") {
+        return None;
+    }
+    let text = &text[MARKER_PREFIX.len()..text.len() - " lines)".len()];
+
+    let (location, lines) = match text.rfind(" (") {
+        Some(index) => (&text[..index], &text[index + 2..]),
+        None => return None,
+    };
+    let (path, line, col) = match split_location(location) {
+        Some(triple) => triple,
+        None => return None,
+    };
+    match lines.parse() {
+        Ok(lines) => Some(Marker { path: path.to_string(), line: line, col: col, lines: lines }),
+        Err(_) => None,
+    }
+}
+
+/// Splits a string like `src/foo.rs:22:17` into its path, line and
+/// column.
+fn split_location(text: &str) -> Option<(&str, usize, usize)> {
+    let mut parts = text.rsplitn(3, ':');
+    let col = parts.next().and_then(|s| s.parse().ok());
+    let line = parts.next().and_then(|s| s.parse().ok());
+    let path = parts.next();
+    match (path, line, col) {
+        (Some(path), Some(line), Some(col)) => Some((path, line, col)),
+        _ => None,
+    }
+}
+
+/// Given the text of a generated parser and a (1-based) line and
+/// column within it, returns the path, line and column in the
+/// grammar that this location corresponds to. Returns `None` if the
+/// location does not fall within any user action code.
+pub fn map_location(generated: &str, line: usize, col: usize) -> Option<(String, usize, usize)> {
+    let lines: Vec<&str> = generated.lines().collect();
+    if line == 0 || line > lines.len() {
+        return None;
+    }
+
+    let index = line - 1;
+    for marker_index in (0..index).rev() {
+        if let Some(marker) = parse_marker(lines[marker_index]) {
+            let offset = index - marker_index - 1;
+            if offset >= marker.lines {
+                return None;
+            }
+
+            // The first line of the action code is indented along
+            // with the rest of the generated code; the remaining
+            // lines are copied verbatim from the grammar.
+            let col = if offset == 0 {
+                let indent = lines[index].len() - lines[index].trim_left().len();
+                marker.col + col.saturating_sub(indent + 1)
+            } else {
+                col
+            };
+
+            return Some((marker.path, marker.line + offset, col));
+        }
+    }
+
+    None
+}
+
+/// Copies the output of rustc from `input` to `output`, rewriting
+/// any locations (`--> src/foo.rs:22:17`) that point into the action
+/// code of a generated parser so that they point at the grammar
+/// instead.
+pub fn rewrite_rustc_output<R, W>(input: R, mut output: W) -> io::Result<()>
+    where R: BufRead, W: Write
+{
+    let mut files = map();
+    for line in input.lines() {
+        let line = try!(line);
+        match rewrite_line(&line, &mut files) {
+            Some(rewritten) => try!(writeln!(output, "{}", rewritten)),
+            None => try!(writeln!(output, "{}", line)),
+        }
+    }
+    Ok(())
+}
+
+fn rewrite_line(line: &str, files: &mut Map<String, Option<String>>) -> Option<String> {
+    let start = match line.find("--> ") {
+        Some(index) => index + "--> ".len(),
+        None => return None,
+    };
+    let (path, line_num, col) = match split_location(line[start..].trim_right()) {
+        Some(triple) => triple,
+        None => return None,
+    };
+
+    let generated = files.entry(path.to_string()).or_insert_with(|| read_file(path));
+    let generated = match *generated {
+        Some(ref text) => text,
+        None => return None,
+    };
+
+    map_location(generated, line_num, col)
+        .map(|(path, line_num, col)| format!("{}{}:{}:{}", &line[..start], path, line_num, col))
+}
+
+fn read_file(path: &str) -> Option<String> {
+    let mut text = String::new();
+    match File::open(path).and_then(|mut f| f.read_to_string(&mut text)) {
+        Ok(_) => Some(text),
+        Err(_) => None,
+    }
+}
@@ -0,0 +1,56 @@
+use std::path::Path;
+
+use super::{map_location, marker, parse_marker, rewrite_rustc_output, Marker};
+
+const GENERATED: &'static str = r#"fn __action1<
+'input,
+>(
+input: &'input str,
+(_, __0, _): (usize, &'input str, usize),
+) -> i32
+{
+    //@ src/calc.lalrpop:22:17 (3 lines)
+    match __0 {
+    _ => i32::from_str(__0).unwrap()
+}
+}
+"#;
+
+#[test]
+fn marker_round_trip() {
+    let text = marker(Path::new("src/calc.lalrpop"), 22, 17, 3);
+    assert_eq!(text, "//@ src/calc.lalrpop:22:17 (3 lines)");
+    assert_eq!(parse_marker(&format!("    {}", text)),
+               Some(Marker { path: "src/calc.lalrpop".to_string(), line: 22, col: 17, lines: 3 }));
+    assert_eq!(parse_marker("// src/calc.lalrpop:22:17"), None);
+}
+
+#[test]
+fn map_first_line() {
+    // the first line of the action is indented by 4
+    assert_eq!(map_location(GENERATED, 9, 11),
+               Some(("src/calc.lalrpop".to_string(), 22, 23)));
+}
+
+#[test]
+fn map_later_lines() {
+    assert_eq!(map_location(GENERATED, 10, 10),
+               Some(("src/calc.lalrpop".to_string(), 23, 10)));
+    assert_eq!(map_location(GENERATED, 11, 1),
+               Some(("src/calc.lalrpop".to_string(), 24, 1)));
+}
+
+#[test]
+fn map_outside_action() {
+    assert_eq!(map_location(GENERATED, 12, 1), None);
+    assert_eq!(map_location(GENERATED, 4, 1), None);
+    assert_eq!(map_location(GENERATED, 100, 1), None);
+}
+
+#[test]
+fn rewrite_leaves_other_lines_alone() {
+    let input = "error[E0308]: mismatched types\n  --> does/not/exist.rs:9:11\n";
+    let mut output = vec![];
+    rewrite_rustc_output(input.as_bytes(), &mut output).unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), input);
+}
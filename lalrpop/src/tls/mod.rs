@@ -1,80 +1,44 @@
-//! Certain bits of environmental state are too annoying to thread
-//! around everywhere, so pack them into TLS.
+//! A thin compatibility shim: the session of the grammar being
+//! processed by `process_root`, stored in TLS for the little code
+//! that has no way to be handed it -- today, just the debug output
+//! of the message rendering code (see `debug!`). Everything else
+//! takes the `Session` explicitly, so that differently configured
+//! builds can run side by side.
 
-use file_text::FileText;
 use session::Session;
 use std::cell::RefCell;
 use std::rc::Rc;
 
-pub struct Tls {
-    _dummy: ()
-}
+#[cfg(test)]
+mod test;
 
-#[derive(Clone)]
-struct TlsFields {
-    session: Rc<Session>,
-    file_text: Rc<FileText>,
+pub struct Tls {
+    previous: Option<Rc<Session>>,
 }
 
 thread_local! {
-    static THE_TLS_FIELDS: RefCell<Option<TlsFields>> =
-        RefCell::new(None)
+    static THE_SESSION: RefCell<Option<Rc<Session>>> = RefCell::new(None)
 }
 
 impl Tls {
-    #[cfg(test)]
-    pub fn test() -> Tls {
-        Self::install(Rc::new(Session::test()), Rc::new(FileText::test()))
-    }
-
-    #[cfg(test)]
-    pub fn test_string(text: &str) -> Tls {
-        use std::path::PathBuf;
-        Self::install(Rc::new(Session::test()),
-                      Rc::new(FileText::new(PathBuf::from("tmp.txt"),
-                                            String::from(text))))
-    }
-
-    /// Installs `Tls` and returns a placeholder value.  When this
-    /// value is dropped, the `Tls` entries will be removed. To access
-    /// the values from `Tls`, call `Tls::session()` or
-    /// `Tls::file_text()`.
-    pub fn install(session: Rc<Session>,
-                   file_text: Rc<FileText>)
-                   -> Tls
-    {
-        let fields = TlsFields {
-            session: session,
-            file_text: file_text
-        };
-
-        THE_TLS_FIELDS.with(|s| {
-            let mut s = s.borrow_mut();
-            assert!(s.is_none());
-            *s = Some(fields.clone());
-        });
-
-        Tls { _dummy: () }
-    }
-
-    fn fields() -> TlsFields {
-        THE_TLS_FIELDS.with(|s| s.borrow().clone().expect("TLS is not installed"))
+    /// Installs `session` and returns a placeholder value. When this
+    /// value is dropped, the session installed before, if any, is
+    /// visible again.
+    pub fn install(session: Rc<Session>) -> Tls {
+        let previous = THE_SESSION.with(|s| s.borrow_mut().take());
+        THE_SESSION.with(|s| *s.borrow_mut() = Some(session));
+        Tls { previous: previous }
     }
 
-    pub fn session() -> Rc<Session> {
-        Self::fields().session
-    }
-
-    pub fn file_text() -> Rc<FileText> {
-        Self::fields().file_text
+    /// The installed session, if any.
+    pub fn session() -> Option<Rc<Session>> {
+        THE_SESSION.with(|s| s.borrow().clone())
     }
 }
 
 impl Drop for Tls {
     fn drop(&mut self) {
-        THE_TLS_FIELDS.with(|s| {
-            *s.borrow_mut() = None;
-        })
+        let previous = self.previous.take();
+        THE_SESSION.with(|s| *s.borrow_mut() = previous);
     }
 }
-
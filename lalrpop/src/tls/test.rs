@@ -0,0 +1,20 @@
+use session::Session;
+use std::rc::Rc;
+use super::Tls;
+
+#[test]
+fn nested_install() {
+    assert!(Tls::session().is_none());
+    {
+        let _outer = Tls::install(Rc::new(Session::test()));
+        assert!(!Tls::session().unwrap().emit_comments);
+        {
+            let mut session = Session::test();
+            session.emit_comments = true;
+            let _inner = Tls::install(Rc::new(session));
+            assert!(Tls::session().unwrap().emit_comments);
+        }
+        assert!(!Tls::session().unwrap().emit_comments);
+    }
+    assert!(Tls::session().is_none());
+}
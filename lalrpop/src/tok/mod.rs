@@ -34,6 +34,7 @@ fn error<T>(c: ErrorCode, l: usize) -> Result<T,Error> {
 pub enum Tok<'input> {
     // Keywords;
     Enum,
+    Extend,
     Extern,
     Grammar,
     Match,
@@ -69,6 +70,7 @@ pub enum Tok<'input> {
     Equals,
     EqualsEquals,
     EqualsGreaterThanCode(&'input str),
+    IfCode(&'input str), // `if { ... }`, excludes the braces
     EqualsGreaterThanQuestionCode(&'input str),
     EqualsGreaterThanLookahead,
     EqualsGreaterThanLookbehind,
@@ -81,6 +83,7 @@ pub enum Tok<'input> {
     Lookahead, // @L
     Lookbehind, // @R
     MinusGreaterThan,
+    Pipe,
     Plus,
     Question,
     RightBrace,
@@ -105,6 +108,7 @@ pub type Spanned<T> = (usize, T, usize);
 
 const KEYWORDS: &'static [(&'static str, Tok<'static>)] = &[
     ("enum", Enum),
+    ("extend", Extend),
     ("extern", Extern),
     ("grammar", Grammar),
     ("match", Match),
@@ -309,6 +313,10 @@ impl<'input> Tokenizer<'input> {
                         }
                     }
                 }
+                Some((idx0, '|')) => {
+                    self.bump();
+                    Some(Ok((idx0, Pipe, idx0+1)))
+                }
                 Some((idx0, '+')) => {
                     self.bump();
                     Some(Ok((idx0, Plus, idx0+1)))
@@ -691,6 +699,26 @@ impl<'input> Tokenizer<'input> {
             return Ok((start, Tok::Use(code), code_end));
         }
 
+        // `if` followed by a block is a guard, whose code we take
+        // as-is; otherwise it starts a macro condition
+        if word == "if" && self.text[end..].trim_left().starts_with('{') {
+            while let Some((_, c)) = self.lookahead {
+                if c == '{' {
+                    break;
+                }
+                self.bump();
+            }
+            let (open, _) = self.lookahead.unwrap();
+            self.bump();
+            let close = try!(self.code(idx0, "([{", "}])"));
+            if close == self.text.len() {
+                return error(UnterminatedCode, idx0);
+            }
+            self.bump(); // consume the `}`
+            let code = &self.text[open+1..close];
+            return Ok((start, Tok::IfCode(code), close+1));
+        }
+
         let tok =
             // search for a keyword first; if none are found, this is
             // either a MacroId or an Id, depending on whether there
@@ -417,6 +417,25 @@ fn use2() {
     ]);
 }
 
+#[test]
+fn if_code() {
+    test(r#"x if { v.0 >= 2 } => v"#, vec![
+        (r#"~                     "#, Id("x")),
+        (r#"  ~~~~~~~~~~~~~~~     "#, IfCode(" v.0 >= 2 ")),
+        (r#"                  ~~~~"#, EqualsGreaterThanCode(" v")),
+    ]);
+}
+
+#[test]
+fn if_condition() {
+    test(r#"if E == "x""#, vec![
+        (r#"~~         "#, If),
+        (r#"   ~       "#, Id("E")),
+        (r#"     ~~    "#, EqualsEquals),
+        (r#"        ~~~"#, StringLiteral("x")),
+    ]);
+}
+
 #[test]
 fn where1() {
     test(r#"where <foo,bar>,baz;"#, vec![
@@ -509,3 +528,16 @@ fn char_literals() {
         (r#"                          ~~"#, Lifetime("'c")),
     ]);
 }
+
+#[test]
+fn pipe() {
+    test(r#"category Op = "+" | "-";"#, vec![
+        (r#"~~~~~~~~                "#, Id("category")),
+        (r#"         ~~             "#, Id("Op")),
+        (r#"            ~           "#, Equals),
+        (r#"              ~~~       "#, StringLiteral("+")),
+        (r#"                  ~     "#, Pipe),
+        (r#"                    ~~~ "#, StringLiteral("-")),
+        (r#"                       ~"#, Semi),
+    ]);
+}
@@ -1,3 +1,4 @@
+use std::cmp::min;
 use std::fmt::{Display, Formatter, Error};
 
 pub use std::collections::btree_map as map;
@@ -50,3 +51,59 @@ impl<'a,S:Display> Display for Prefix<&'a [S]> {
 pub fn strip(s: &str) -> &str {
     s.trim_matches(char::is_whitespace)
 }
+
+/// The number of characters that have to be inserted, deleted or
+/// replaced, or pairs of adjacent characters swapped, to turn `a`
+/// into `b`.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut d = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for i in 0..a.len() + 1 {
+        d[i][0] = i;
+    }
+    for j in 0..b.len() + 1 {
+        d[0][j] = j;
+    }
+    for i in 1..a.len() + 1 {
+        for j in 1..b.len() + 1 {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            d[i][j] = min(d[i - 1][j - 1] + cost, min(d[i - 1][j], d[i][j - 1]) + 1);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = min(d[i][j], d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    d[a.len()][b.len()]
+}
+
+/// Those of `candidates` that are close enough to `name` that `name`
+/// could be a typo of them, closest first, and at most three. Only
+/// letters and digits count towards how many edits are allowed, so
+/// that short names (and punctuation) have none.
+pub fn near_misses<T, I>(name: &str, candidates: I) -> Vec<T>
+    where T: Display, I: IntoIterator<Item = T>
+{
+    let limit = name.chars().filter(|c| c.is_alphanumeric()).count() / 3;
+    let mut close: Vec<(usize, String, T)> =
+        candidates.into_iter()
+                  .map(|c| {
+                      let text = c.to_string();
+                      (edit_distance(name, &text), text, c)
+                  })
+                  .filter(|&(distance, _, _)| distance > 0 && distance <= limit)
+                  .collect();
+    close.sort_by(|a, b| (a.0, &a.1).cmp(&(b.0, &b.1)));
+    close.into_iter().take(3).map(|(_, _, c)| c).collect()
+}
+
+/// A suggestion of `near_misses` to append to an error message, like
+/// "; did you mean `a` or `b`?", or nothing if there are none.
+pub fn did_you_mean<T: Display>(near_misses: &[T]) -> String {
+    let names: Vec<String> = near_misses.iter().map(|c| format!("`{}`", c)).collect();
+    match names.len() {
+        0 => String::new(),
+        1 => format!("; did you mean {}?", names[0]),
+        n => format!("; did you mean {} or {}?", names[..n - 1].join(", "), names[n - 1]),
+    }
+}